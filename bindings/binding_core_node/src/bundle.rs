@@ -96,6 +96,7 @@ impl Task for BundleTask {
                                     .cloned(),
                             )
                             .collect(),
+                        deterministic: self.config.static_items.config.deterministic,
                         ..Default::default()
                     },
                     Box::new(Hook),
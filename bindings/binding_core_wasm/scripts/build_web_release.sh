@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+set -eux
+
+# Size-optimized build for browsers.
+#
+# `swc_core/binding_macro_wasm` does not enable the file system (`.swcrc`,
+# `jsc.paths`), plugin runner or threading, so only the parser, transforms,
+# minifier and codegen are included.
+export CARGO_PROFILE_RELEASE_LTO="fat"
+export CARGO_PROFILE_RELEASE_OPT_LEVEL="z"
+export CARGO_PROFILE_RELEASE_CODEGEN_UNITS="1"
+export CARGO_PROFILE_RELEASE_PANIC="abort"
+wasm-pack build --out-name wasm --release --scope=swc --target web
+
+if command -v wasm-opt >/dev/null; then
+    wasm-opt -Oz --enable-bulk-memory --enable-sign-ext --enable-nontrapping-float-to-int \
+        -o ./pkg/wasm_bg.wasm ./pkg/wasm_bg.wasm
+fi
+
+ls -al ./pkg
@@ -195,6 +195,10 @@ fn default_preserve_comments() -> Option<Vec<CachedRegex>> {
         // Conditional IE comments
         CachedRegex::new("^\\[if\\s[^\\]+]").unwrap(),
         CachedRegex::new("\\[endif]").unwrap(),
+        // Hydration markers of frameworks, i.e. `<!--$-->` and `<!--/$-->` of React
+        // and `<!--[-->` and `<!--]-->` of Vue
+        CachedRegex::new("^/?\\$[?!]?$").unwrap(),
+        CachedRegex::new("^[\\[\\]]$").unwrap(),
     ])
 }
 
@@ -265,6 +265,9 @@ export interface TerserCompressOptions {
   inline?: 0 | 1 | 2 | 3
 
 
+  inline_fns_budget?: number,
+
+
   join_vars?: boolean,
 
 
@@ -307,6 +310,9 @@ export interface TerserCompressOptions {
   sequences?: any,
 
 
+  sequences_lookahead?: number,
+
+
   side_effects?: boolean,
 
 
@@ -364,6 +370,8 @@ export interface TerserCompressOptions {
 export interface TerserMangleOptions {
   props?: TerserManglePropertiesOptions,
 
+  class_props?: TerserMangleClassPropsOptions,
+
   toplevel?: boolean,
 
   keep_classnames?: boolean,
@@ -383,6 +391,10 @@ export interface TerserManglePropertiesOptions {
 
 }
 
+export interface TerserMangleClassPropsOptions {
+  keep?: string,
+}
+
 
 /**
  * Programmatic options.
@@ -841,10 +853,27 @@ export interface ReactConfig {
    * Toggles plugins that aid in development, such as @swc/plugin-transform-react-jsx-self
    * and @swc/plugin-transform-react-jsx-source.
    *
+   * Use an object to choose which of the debug props are added.
+   *
    * Defaults to `false`,
    *
    */
-  development?: boolean;
+  development?:
+    | boolean
+    | {
+          /**
+           * Add `__source` to JSX elements.
+           *
+           * Defaults to `true`
+           */
+          jsxSource?: boolean;
+          /**
+           * Add `__self` to JSX elements.
+           *
+           * Defaults to `true`
+           */
+          jsxSelf?: boolean;
+      };
   /**
    * Use `Object.assign()` instead of `_extends`. Defaults to false.
    * @deprecated
@@ -1087,6 +1116,18 @@ export interface BaseModuleConfig {
    * ```
    */
   importInterop?: "swc" | "babel" | "node" | "none";
+  /**
+   * Matches the CommonJS interop of another toolchain.
+   *
+   * - `babel` - Same as `importInterop: "babel"`.
+   * - `node16` - Same as `importInterop: "node"`.
+   * - `tsc-legacy` - Same as `importInterop: "none"`, but defines `__esModule` and copies named re-exports
+   *   with `exports.foo = _mod.foo` like `tsc` before 3.9.
+   * - `bun` - Same as `importInterop: "babel"`.
+   *
+   * `importInterop` takes precedence over this option.
+   */
+  esModuleInterop?: "babel" | "node16" | "tsc-legacy" | "bun";
   /**
    * Output extension for generated files.
    * 
@@ -1107,6 +1148,22 @@ export interface BaseModuleConfig {
   ignoreDynamic?: boolean;
   allowTopLevelThis?: boolean;
   preserveImportMeta?: boolean;
+  /**
+   * Configures how `import.meta` is lowered for CommonJS, AMD and UMD.
+   */
+  importMeta?: {
+    /**
+     * Expressions replacing properties of `import.meta`, like
+     * `{ "env": "process.env" }`.
+     */
+    replacements?: { [property: string]: string };
+    /**
+     * What to do with uses of `import.meta` which can't be lowered.
+     *
+     * Defaults to `preserve`.
+     */
+    unsupported?: "preserve" | "error";
+  };
 }
 
 export interface Es6Config extends BaseModuleConfig {
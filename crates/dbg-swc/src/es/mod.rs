@@ -4,10 +4,11 @@ use anyhow::Result;
 use clap::Subcommand;
 use swc_common::SourceMap;
 
-use self::{exec_test::ExecForTestingCommand, minifier::MinifierCommand};
+use self::{exec_test::ExecForTestingCommand, minifier::MinifierCommand, reduce::ReduceCommand};
 
 mod exec_test;
 mod minifier;
+mod reduce;
 
 /// Debug modules related to ECMAScript
 #[derive(Debug, Subcommand)]
@@ -16,6 +17,7 @@ pub(crate) enum EsCommand {
     Minifier(MinifierCommand),
     #[clap(subcommand)]
     ExecForTesting(ExecForTestingCommand),
+    Reduce(ReduceCommand),
 }
 
 impl EsCommand {
@@ -23,6 +25,7 @@ impl EsCommand {
         match self {
             Self::Minifier(cmd) => cmd.run(cm),
             Self::ExecForTesting(cmd) => cmd.run(cm),
+            Self::Reduce(cmd) => cmd.run(cm),
         }
     }
 }
@@ -0,0 +1,627 @@
+use std::{
+    fs::{self, File},
+    ops::Range,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::Arc,
+    thread::sleep,
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+use clap::{ArgEnum, Args};
+use swc_common::{util::take::Take, FileName, Mark, SourceFile, SourceMap};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::text_writer::JsWriter;
+use swc_ecma_parser::{parse_file_as_module, Syntax, TsSyntax};
+use swc_ecma_transforms_base::{fixer::fixer, resolver};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+use tempfile::TempDir;
+use tracing::info;
+
+use crate::util::{minifier::minify_module, print_js, wrap_task, ChildGuard, ModuleRecord};
+
+/// Reduce an input file to a minimal case which still satisfies a predicate
+///
+/// Unlike `es minifier reduce`, this command does not depend on `creduce`.
+/// The input is parsed and shrunk by removing statements and list elements,
+/// and by replacing statements and expressions with their children. A
+/// candidate is kept only if the predicate still holds for it. If the input
+/// cannot be parsed, lines are removed instead.
+///
+/// The reduced file is written to `--out`, which defaults to
+/// `<name>.reduced.<ext>` next to the input file.
+#[derive(Debug, Args)]
+pub struct ReduceCommand {
+    /// The path to the input file.
+    pub path: PathBuf,
+
+    /// The condition a candidate should satisfy to be kept.
+    #[clap(long, arg_enum)]
+    pub predicate: ReducePredicate,
+
+    /// The shell command used by `--predicate command`.
+    ///
+    /// The command is executed in a directory containing the candidate, which
+    /// has the same file name as the input. The path of the candidate is also
+    /// available as `SWC_REDUCE_INPUT`. The candidate is kept if the command
+    /// exits with `0`.
+    #[clap(long, required_if_eq("predicate", "command"))]
+    pub command: Option<String>,
+
+    /// Timeout for each process spawned by a predicate, in seconds.
+    #[clap(long, default_value = "10")]
+    pub timeout: u64,
+
+    /// The path to write the reduced file to.
+    #[clap(long)]
+    pub out: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ArgEnum)]
+pub enum ReducePredicate {
+    /// The parser panics.
+    ParserPanic,
+    /// The minifier panics.
+    MinifierPanic,
+    /// The output of `node` differs after minification.
+    MinifierSemantics,
+    /// A shell command specified with `--command` exits with `0`.
+    Command,
+}
+
+impl ReduceCommand {
+    pub fn run(self, cm: Arc<SourceMap>) -> Result<()> {
+        let code = fs::read_to_string(&self.path)
+            .with_context(|| format!("failed to read {}", self.path.display()))?;
+
+        let file_name = self
+            .path
+            .file_name()
+            .context("the input path does not have a file name")?;
+        let dir = TempDir::new().context("failed to create a temp directory")?;
+
+        let reducer = Reducer {
+            cm,
+            syntax: syntax_for(&self.path),
+            predicate: self.predicate,
+            command: self.command.as_deref(),
+            timeout: Duration::from_secs(self.timeout),
+            input: dir.path().join(file_name),
+        };
+
+        // Panics are expected while reducing, so we don't print them.
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(|_| {}));
+        let reduced = reducer.reduce(code);
+        panic::set_hook(hook);
+        let reduced = reduced?;
+
+        let out = self.out.unwrap_or_else(|| {
+            let mut name = self.path.file_stem().unwrap_or_default().to_os_string();
+            name.push(".reduced");
+            if let Some(ext) = self.path.extension() {
+                name.push(".");
+                name.push(ext);
+            }
+            self.path.with_file_name(name)
+        });
+        fs::write(&out, reduced.as_bytes())
+            .with_context(|| format!("failed to write {}", out.display()))?;
+
+        info!("Reduced file is written to {}", out.display());
+
+        Ok(())
+    }
+}
+
+fn syntax_for(path: &Path) -> Syntax {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("ts" | "mts" | "cts") => Syntax::Typescript(Default::default()),
+        Some("tsx") => Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        _ => Syntax::Es(Default::default()),
+    }
+}
+
+struct Reducer<'a> {
+    cm: Arc<SourceMap>,
+    syntax: Syntax,
+    predicate: ReducePredicate,
+    command: Option<&'a str>,
+    timeout: Duration,
+    /// The path of the candidate passed to external processes.
+    input: PathBuf,
+}
+
+impl Reducer<'_> {
+    fn reduce(&self, code: String) -> Result<String> {
+        if !self.is_interesting(&code)? {
+            bail!("the input file does not satisfy the predicate")
+        }
+
+        let fm = self.cm.new_source_file(FileName::Anon.into(), code.clone());
+        // The parser may be the one which panics.
+        let module =
+            panic::catch_unwind(AssertUnwindSafe(|| parse(&fm, self.syntax))).unwrap_or_default();
+
+        let code = match module {
+            Some(module) => self.reduce_ast(module, code)?,
+            None => {
+                info!("Failed to parse the input file; reducing lines instead");
+                self.reduce_lines(code)?
+            }
+        };
+
+        info!("Reduced to {} bytes", code.len());
+
+        Ok(code)
+    }
+
+    fn reduce_ast(&self, mut best: Module, mut best_code: String) -> Result<String> {
+        loop {
+            let mut changed = false;
+
+            for pass in [Pass::Remove, Pass::Unwrap] {
+                // Passes are repeated with smaller chunks, like delta debugging.
+                let count = pass.count(&best);
+                let mut size = count.div_ceil(2).max(1);
+
+                loop {
+                    let mut start = 0;
+
+                    while start < pass.count(&best) {
+                        let mut candidate = best.clone();
+                        candidate.visit_mut_with(&mut pass.apply(start..start + size));
+                        candidate.visit_mut_with(&mut fixer(None));
+
+                        let code = self.print(&candidate)?;
+
+                        if code.len() < best_code.len() && self.is_interesting(&code)? {
+                            info!("Reduced to {} bytes", code.len());
+
+                            best = candidate;
+                            best_code = code;
+                            changed = true;
+                        } else {
+                            start += size;
+                        }
+                    }
+
+                    if size == 1 {
+                        break;
+                    }
+                    size /= 2;
+                }
+            }
+
+            if !changed {
+                return Ok(best_code);
+            }
+        }
+    }
+
+    fn reduce_lines(&self, code: String) -> Result<String> {
+        let mut best = code.lines().map(String::from).collect::<Vec<_>>();
+        let mut size = best.len().div_ceil(2).max(1);
+
+        loop {
+            let mut start = 0;
+
+            while start < best.len() {
+                let mut candidate = best.clone();
+                candidate.drain(start..(start + size).min(best.len()));
+
+                if self.is_interesting(&candidate.join("\n"))? {
+                    info!("Reduced to {} lines", candidate.len());
+                    best = candidate;
+                } else {
+                    start += size;
+                }
+            }
+
+            if size == 1 {
+                break;
+            }
+            size /= 2;
+        }
+
+        Ok(best.join("\n"))
+    }
+
+    fn print(&self, m: &Module) -> Result<String> {
+        let mut buf = Vec::new();
+
+        {
+            let mut e = swc_ecma_codegen::Emitter {
+                cfg: Default::default(),
+                cm: self.cm.clone(),
+                comments: None,
+                wr: JsWriter::new(self.cm.clone(), "\n", &mut buf, None),
+            };
+
+            e.emit_module(m).context("failed to emit module")?;
+        }
+
+        String::from_utf8(buf).context("swc emitted non-utf8 output")
+    }
+
+    fn is_interesting(&self, code: &str) -> Result<bool> {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.new_source_file(FileName::Anon.into(), code.to_string());
+
+        match self.predicate {
+            ReducePredicate::ParserPanic => {
+                Ok(panic::catch_unwind(AssertUnwindSafe(|| parse(&fm, self.syntax))).is_err())
+            }
+
+            ReducePredicate::MinifierPanic => {
+                let Some(m) = parse_for_minifier(&fm, self.syntax) else {
+                    return Ok(false);
+                };
+
+                Ok(panic::catch_unwind(AssertUnwindSafe(|| {
+                    minify_module(cm.clone(), m, Some(Default::default()), None)
+                }))
+                .is_err())
+            }
+
+            ReducePredicate::MinifierSemantics => {
+                let Some(m) = parse_for_minifier(&fm, self.syntax) else {
+                    return Ok(false);
+                };
+
+                let Some(expected) = self.run_node(code)? else {
+                    return Ok(false);
+                };
+
+                let minified = match panic::catch_unwind(AssertUnwindSafe(|| {
+                    minify_module(
+                        cm.clone(),
+                        m,
+                        Some(Default::default()),
+                        Some(Default::default()),
+                    )
+                })) {
+                    Ok(m) => m,
+                    Err(_) => return Ok(false),
+                };
+                let minified = print_js(cm, &minified.module, true)?;
+
+                Ok(self.run_node(&minified)?.as_ref() != Some(&expected))
+            }
+
+            ReducePredicate::Command => {
+                let command = self.command.expect("`--command` is required");
+
+                let mut c = Command::new("sh");
+                c.arg("-c").arg(command);
+                c.env("SWC_REDUCE_INPUT", &self.input);
+
+                Ok(self.run(code, c)?.is_some())
+            }
+        }
+    }
+
+    /// Returns the stdout of `node` if the code runs successfully.
+    fn run_node(&self, code: &str) -> Result<Option<String>> {
+        let mut c = Command::new("node");
+        c.arg(&self.input);
+
+        self.run(code, c)
+    }
+
+    /// Runs `c` after writing `code` as the candidate, and returns its stdout
+    /// if it exits successfully within the timeout.
+    fn run(&self, code: &str, mut c: Command) -> Result<Option<String>> {
+        wrap_task(|| {
+            let dir = self.input.parent().unwrap();
+            fs::write(&self.input, code.as_bytes()).context("failed to write the candidate")?;
+
+            // We use a file instead of a pipe, so a process printing in an
+            // infinite loop does not block.
+            let stdout_path = dir.join(".stdout");
+            let stdout = File::create(&stdout_path).context("failed to create stdout file")?;
+
+            c.current_dir(dir)
+                .stdin(Stdio::null())
+                .stdout(stdout)
+                .stderr(Stdio::null());
+
+            let mut child = ChildGuard(c.spawn().context("failed to spawn")?);
+            let start = Instant::now();
+
+            let status = loop {
+                if let Some(status) = child.0.try_wait().context("failed to wait")? {
+                    break status;
+                }
+                if start.elapsed() > self.timeout {
+                    return Ok(None);
+                }
+                sleep(Duration::from_millis(10));
+            };
+
+            if !status.success() {
+                return Ok(None);
+            }
+
+            Ok(Some(
+                fs::read_to_string(&stdout_path).context("failed to read stdout")?,
+            ))
+        })
+        .with_context(|| format!("failed to run {:?}", c))
+    }
+}
+
+fn parse(fm: &SourceFile, syntax: Syntax) -> Option<Module> {
+    parse_file_as_module(fm, syntax, EsVersion::latest(), None, &mut Vec::new()).ok()
+}
+
+fn parse_for_minifier(fm: &SourceFile, syntax: Syntax) -> Option<ModuleRecord> {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    let mut module = parse(fm, syntax)?;
+    module.visit_mut_with(&mut resolver(
+        unresolved_mark,
+        top_level_mark,
+        syntax.typescript(),
+    ));
+
+    Some(ModuleRecord {
+        module,
+        comments: Default::default(),
+        top_level_mark,
+        unresolved_mark,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pass {
+    /// Remove elements of statement lists and other lists.
+    Remove,
+    /// Replace statements and expressions with one of their children.
+    Unwrap,
+}
+
+impl Pass {
+    /// The number of reduction sites in `m`.
+    fn count(self, m: &Module) -> usize {
+        let mut v = self.apply(0..0);
+        m.clone().visit_mut_with(&mut v);
+        v.count
+    }
+
+    /// Creates a visitor which reduces the sites in `targets`.
+    fn apply(self, targets: Range<usize>) -> ReduceSites {
+        ReduceSites {
+            pass: self,
+            targets,
+            count: 0,
+        }
+    }
+}
+
+/// Each reduction site is numbered in visiting order, and the sites in
+/// `targets` are reduced.
+struct ReduceSites {
+    pass: Pass,
+    targets: Range<usize>,
+    count: usize,
+}
+
+impl ReduceSites {
+    fn hit(&mut self) -> bool {
+        let idx = self.count;
+        self.count += 1;
+        self.targets.contains(&idx)
+    }
+
+    fn remove<T>(&mut self, items: &mut Vec<T>, keep_one: bool) {
+        if self.pass != Pass::Remove {
+            return;
+        }
+
+        let hits = items.iter().map(|_| self.hit()).collect::<Vec<_>>();
+        if keep_one && hits.iter().all(|hit| *hit) {
+            return;
+        }
+
+        let mut hits = hits.into_iter();
+        items.retain(|_| !hits.next().unwrap());
+    }
+
+    /// Returns the index of the child to replace the node with.
+    fn unwrap(&mut self, children: usize) -> Option<usize> {
+        if self.pass != Pass::Unwrap {
+            return None;
+        }
+
+        let hits = (0..children).map(|_| self.hit()).collect::<Vec<_>>();
+        hits.into_iter().position(|hit| hit)
+    }
+}
+
+/// Statements of a block-like statement, which can be inlined into the parent.
+fn inlinable_stmts(s: &mut Stmt) -> Option<&mut Vec<Stmt>> {
+    match s {
+        Stmt::Block(b) => Some(&mut b.stmts),
+        Stmt::Try(t) => Some(&mut t.block.stmts),
+        _ => None,
+    }
+}
+
+fn child_stmts(s: &mut Stmt) -> Vec<&mut Stmt> {
+    match s {
+        Stmt::If(s) => {
+            let mut children = vec![&mut *s.cons];
+            children.extend(s.alt.as_deref_mut());
+            children
+        }
+        Stmt::While(WhileStmt { body, .. })
+        | Stmt::DoWhile(DoWhileStmt { body, .. })
+        | Stmt::For(ForStmt { body, .. })
+        | Stmt::ForIn(ForInStmt { body, .. })
+        | Stmt::ForOf(ForOfStmt { body, .. })
+        | Stmt::Labeled(LabeledStmt { body, .. })
+        | Stmt::With(WithStmt { body, .. }) => vec![&mut **body],
+        _ => Vec::new(),
+    }
+}
+
+fn child_exprs(e: &mut Expr) -> Vec<&mut Expr> {
+    match e {
+        Expr::Bin(e) => vec![&mut *e.left, &mut *e.right],
+        Expr::Unary(UnaryExpr { arg, .. })
+        | Expr::Update(UpdateExpr { arg, .. })
+        | Expr::Await(AwaitExpr { arg, .. })
+        | Expr::Yield(YieldExpr { arg: Some(arg), .. })
+        | Expr::Paren(ParenExpr { expr: arg, .. })
+        | Expr::Assign(AssignExpr { right: arg, .. }) => vec![&mut **arg],
+        Expr::Cond(e) => vec![&mut *e.test, &mut *e.cons, &mut *e.alt],
+        Expr::Seq(e) => e.exprs.iter_mut().map(|e| &mut **e).collect(),
+        Expr::Member(e) => {
+            let mut children = vec![&mut *e.obj];
+            if let MemberProp::Computed(prop) = &mut e.prop {
+                children.push(&mut *prop.expr);
+            }
+            children
+        }
+        Expr::Call(e) => {
+            let mut children = Vec::new();
+            if let Callee::Expr(callee) = &mut e.callee {
+                children.push(&mut **callee);
+            }
+            children.extend(e.args.iter_mut().map(|arg| &mut *arg.expr));
+            children
+        }
+        Expr::New(e) => {
+            let mut children = vec![&mut *e.callee];
+            children.extend(e.args.iter_mut().flatten().map(|arg| &mut *arg.expr));
+            children
+        }
+        Expr::Array(e) => e
+            .elems
+            .iter_mut()
+            .flatten()
+            .map(|elem| &mut *elem.expr)
+            .collect(),
+        Expr::Tpl(e) => e.exprs.iter_mut().map(|e| &mut **e).collect(),
+        Expr::TaggedTpl(e) => {
+            let mut children = vec![&mut *e.tag];
+            children.extend(e.tpl.exprs.iter_mut().map(|e| &mut **e));
+            children
+        }
+        Expr::Arrow(ArrowExpr { body, .. }) => match &mut **body {
+            BlockStmtOrExpr::Expr(body) => vec![&mut **body],
+            BlockStmtOrExpr::BlockStmt(..) => Vec::new(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+impl VisitMut for ReduceSites {
+    fn visit_mut_module_items(&mut self, items: &mut Vec<ModuleItem>) {
+        self.remove(items, false);
+
+        if self.pass == Pass::Unwrap {
+            let mut new = Vec::with_capacity(items.len());
+            for mut item in items.take() {
+                match item.as_mut_stmt().and_then(inlinable_stmts) {
+                    Some(stmts) if self.hit() => {
+                        new.extend(stmts.take().into_iter().map(ModuleItem::Stmt))
+                    }
+                    _ => new.push(item),
+                }
+            }
+            *items = new;
+        }
+
+        items.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmts(&mut self, stmts: &mut Vec<Stmt>) {
+        self.remove(stmts, false);
+
+        if self.pass == Pass::Unwrap {
+            let mut new = Vec::with_capacity(stmts.len());
+            for mut stmt in stmts.take() {
+                match inlinable_stmts(&mut stmt) {
+                    Some(children) if self.hit() => new.append(children),
+                    _ => new.push(stmt),
+                }
+            }
+            *stmts = new;
+        }
+
+        stmts.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        if let Some(idx) = self.unwrap(child_stmts(s).len()) {
+            *s = child_stmts(s).swap_remove(idx).take();
+        }
+
+        s.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        if let Some(idx) = self.unwrap(child_exprs(e).len()) {
+            *e = child_exprs(e).swap_remove(idx).take();
+        }
+
+        e.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_class_members(&mut self, members: &mut Vec<ClassMember>) {
+        self.remove(members, false);
+
+        members.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_prop_or_spreads(&mut self, props: &mut Vec<PropOrSpread>) {
+        self.remove(props, false);
+
+        props.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_expr_or_spreads(&mut self, args: &mut Vec<ExprOrSpread>) {
+        self.remove(args, false);
+
+        args.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_array_lit(&mut self, n: &mut ArrayLit) {
+        self.remove(&mut n.elems, false);
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_params(&mut self, params: &mut Vec<Param>) {
+        self.remove(params, false);
+
+        params.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        self.remove(&mut n.params, false);
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_var_declarators(&mut self, decls: &mut Vec<VarDeclarator>) {
+        // `var;` is not valid.
+        self.remove(decls, true);
+
+        decls.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_switch_cases(&mut self, cases: &mut Vec<SwitchCase>) {
+        self.remove(cases, false);
+
+        cases.visit_mut_children_with(self);
+    }
+}
@@ -45,6 +45,16 @@ pub fn get_minified_with_opts(
 
     let m = parse_js(fm)?;
 
+    Ok(minify_module(cm, m, compress, mangle))
+}
+
+/// Minifies an already parsed module.
+pub fn minify_module(
+    cm: Arc<SourceMap>,
+    m: ModuleRecord,
+    compress: Option<CompressOptions>,
+    mangle: Option<MangleOptions>,
+) -> ModuleRecord {
     let mut module = {
         swc_ecma_minifier::optimize(
             m.module.into(),
@@ -68,7 +78,7 @@ pub fn get_minified_with_opts(
     module.visit_mut_with(&mut Normalizer {});
     module.visit_mut_with(&mut fixer(None));
 
-    Ok(ModuleRecord { module, ..m })
+    ModuleRecord { module, ..m }
 }
 
 pub fn get_terser_output(file: &Path, compress: bool, mangle: bool) -> Result<String> {
@@ -65,7 +65,7 @@ fn full_group(c: &mut Criterion) {
                                         transform: Some(TransformConfig {
                                             react: swc_ecma_transforms::react::Options {
                                                 runtime: Some(Runtime::Automatic),
-                                                development: Some(react_dev),
+                                                development: react_dev.then(Default::default),
                                                 ..Default::default()
                                             },
                                             ..Default::default()
@@ -7,13 +7,13 @@ use swc_atoms::Atom;
 use swc_common::{
     comments::Comments, errors::Handler, sync::Lrc, util::take::Take, FileName, Mark, SourceMap,
 };
-use swc_ecma_ast::{EsVersion, Module, Pass, Script};
+use swc_ecma_ast::{fn_pass, EsVersion, Module, Pass, Script};
 use swc_ecma_minifier::option::{terser::TerserTopLevelOptions, MinifyOptions};
 use swc_ecma_parser::Syntax;
 use swc_ecma_transforms::{
     compat,
     feature::{enable_available_feature_from_es_version, FeatureFlag},
-    fixer::{fixer, paren_remover},
+    fixer::{fixer_with_parens, paren_remover, Parens},
     helpers,
     hygiene::{self, hygiene_with_config},
     modules::{self, path::ImportResolver},
@@ -21,6 +21,7 @@ use swc_ecma_transforms::{
     resolver, Assumptions,
 };
 use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
+use swc_timer::Timer;
 use swc_visit::Optional;
 
 use crate::config::{GlobalPassOption, JsMinifyOptions, ModuleConfig};
@@ -42,6 +43,7 @@ pub struct PassBuilder<'a, 'b, P: Pass> {
     assumptions: Assumptions,
     hygiene: Option<hygiene::Config>,
     fixer: bool,
+    parens: Parens,
     inject_helpers: bool,
     minify: Option<JsMinifyOptions>,
     regenerator: regenerator::Config,
@@ -69,6 +71,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             assumptions,
             hygiene: Some(Default::default()),
             fixer: true,
+            parens: Default::default(),
             inject_helpers: true,
             minify: None,
             regenerator: Default::default(),
@@ -92,6 +95,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             assumptions: self.assumptions,
             hygiene: self.hygiene,
             fixer: self.fixer,
+            parens: self.parens,
             inject_helpers: self.inject_helpers,
             minify: self.minify,
             regenerator: self.regenerator,
@@ -114,6 +118,13 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
         self
     }
 
+    /// Style of parenthesis emitted by the fixer. Defaults to
+    /// [Parens::Minimal].
+    pub fn parens(mut self, parens: Parens) -> Self {
+        self.parens = parens;
+        self
+    }
+
     /// Note: hygiene is enabled by default.
     ///
     /// If you pass [None] to this method, the `hygiene` pass will be disabled.
@@ -261,6 +272,7 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
                             async_to_generator: compat::es2017::async_to_generator::Config {
                                 ignore_function_name: assumptions.ignore_function_name,
                                 ignore_function_length: assumptions.ignore_function_length,
+                                spec: false,
                             },
                         },
                         self.unresolved_mark,
@@ -285,14 +297,20 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
                             },
                             computed_props: compat::es2015::computed_props::Config {
                                 loose: self.loose,
+                                set_computed_properties: assumptions.set_computed_properties,
                             },
                             for_of: compat::es2015::for_of::Config {
-                                assume_array: false,
+                                assume_array: assumptions.iterable_is_array,
                                 loose: self.loose,
+                                skip_iterator_closing: assumptions.skip_for_of_iterator_closing,
+                            },
+                            spread: compat::es2015::spread::Config {
+                                loose: self.loose,
+                                iterable_is_array: assumptions.iterable_is_array,
                             },
-                            spread: compat::es2015::spread::Config { loose: self.loose },
                             destructuring: compat::es2015::destructuring::Config {
                                 loose: self.loose,
+                                iterable_is_array: assumptions.iterable_is_array,
                             },
                             regenerator: self.regenerator,
                             template_literal: compat::es2015::template_literal::Config {
@@ -321,28 +339,40 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
             .unwrap_or(false);
 
         (
-            self.pass,
+            timed("optimization", self.pass),
             Optional::new(
-                paren_remover(comments.map(|v| v as &dyn Comments)),
+                timed(
+                    "paren_remover",
+                    paren_remover(comments.map(|v| v as &dyn Comments)),
+                ),
                 self.fixer,
             ),
-            compat_pass,
+            timed("compat", compat_pass),
             // module / helper
             Optional::new(
-                modules::import_analysis::import_analyzer(import_interop, ignore_dynamic),
+                timed(
+                    "import_analyzer",
+                    modules::import_analysis::import_analyzer(import_interop, ignore_dynamic),
+                ),
                 need_analyzer,
             ),
             Optional::new(
-                helpers::inject_helpers(self.unresolved_mark),
+                timed(
+                    "inject_helpers",
+                    helpers::inject_helpers(self.unresolved_mark),
+                ),
                 self.inject_helpers,
             ),
-            ModuleConfig::build(
-                self.cm.clone(),
-                comments,
-                module,
-                self.unresolved_mark,
-                feature_flag,
-                resolver,
+            timed(
+                "module",
+                ModuleConfig::build(
+                    self.cm.clone(),
+                    comments,
+                    module,
+                    self.unresolved_mark,
+                    feature_flag,
+                    resolver,
+                ),
             ),
             visit_mut_pass(MinifierPass {
                 options: self.minify,
@@ -351,13 +381,22 @@ impl<'a, 'b, P: Pass> PassBuilder<'a, 'b, P> {
                 top_level_mark: self.top_level_mark,
             }),
             Optional::new(
-                hygiene_with_config(swc_ecma_transforms_base::hygiene::Config {
-                    top_level_mark: self.top_level_mark,
-                    ..self.hygiene.clone().unwrap_or_default()
-                }),
+                timed(
+                    "hygiene",
+                    hygiene_with_config(swc_ecma_transforms_base::hygiene::Config {
+                        top_level_mark: self.top_level_mark,
+                        ..self.hygiene.clone().unwrap_or_default()
+                    }),
+                ),
                 self.hygiene.is_some() && !is_mangler_enabled,
             ),
-            Optional::new(fixer(comments.map(|v| v as &dyn Comments)), self.fixer),
+            Optional::new(
+                timed(
+                    "fixer",
+                    fixer_with_parens(comments.map(|v| v as &dyn Comments), self.parens),
+                ),
+                self.fixer,
+            ),
         )
     }
 }
@@ -503,3 +542,16 @@ impl VisitMut for MinifierPass<'_> {
 pub(crate) fn should_enable(target: EsVersion, feature: EsVersion) -> bool {
     target < feature
 }
+
+/// Runs `pass` in a timer named `name`, so it's reported by
+/// [swc_timer::profile].
+pub(crate) fn timed(name: &'static str, mut pass: impl Pass) -> impl Pass {
+    fn_pass(move |program| {
+        let _timer = Timer::with_name(
+            name,
+            tracing::info_span!("Compiler.transform", pass = name).entered(),
+        );
+
+        pass.process(program)
+    })
+}
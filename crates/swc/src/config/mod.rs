@@ -5,7 +5,9 @@ use std::{
     sync::Arc,
 };
 
-use anyhow::{bail, Context, Error};
+#[cfg(feature = "fs")]
+use anyhow::Context;
+use anyhow::{bail, Error};
 use dashmap::DashMap;
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
@@ -26,26 +28,29 @@ use swc_config::{
     merge::Merge,
 };
 use swc_ecma_ast::{noop_pass, EsVersion, Expr, Pass, Program};
+pub use swc_ecma_codegen::LineEnding;
 use swc_ecma_ext_transforms::jest;
 use swc_ecma_lints::{
     config::LintConfig,
     rules::{lint_pass, LintParams},
 };
+#[cfg(feature = "fs")]
 use swc_ecma_loader::resolvers::{
     lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
 };
 pub use swc_ecma_minifier::js::*;
 use swc_ecma_minifier::option::terser::TerserTopLevelOptions;
 use swc_ecma_parser::{parse_file_as_expr, Syntax, TsSyntax};
-pub use swc_ecma_transforms::proposals::DecoratorVersion;
+#[cfg(feature = "fs")]
+use swc_ecma_transforms::modules::{path::NodeImportResolver, util};
 use swc_ecma_transforms::{
     feature::FeatureFlag,
     hygiene,
     modules::{
         self,
-        path::{ImportResolver, NodeImportResolver, Resolver},
+        path::{ImportResolver, Resolver},
         rewriter::import_rewriter,
-        util, EsModuleConfig,
+        EsModuleConfig,
     },
     optimization::{const_modules, json_parse, simplifier},
     proposals::{
@@ -57,6 +62,7 @@ use swc_ecma_transforms::{
     typescript::{self, TsImportExportAssignConfig},
     Assumptions,
 };
+pub use swc_ecma_transforms::{fixer::Parens, proposals::DecoratorVersion};
 use swc_ecma_transforms_compat::es2015::regenerator;
 use swc_ecma_transforms_optimization::{
     inline_globals2,
@@ -64,12 +70,14 @@ use swc_ecma_transforms_optimization::{
     GlobalExprMap,
 };
 use swc_ecma_utils::NodeIgnoringSpan;
-use swc_ecma_visit::VisitMutWith;
 use swc_visit::Optional;
 
 pub use crate::plugin::PluginConfig;
+#[cfg(feature = "fs")]
+use crate::SwcImportResolver;
 use crate::{
-    builder::PassBuilder, dropped_comments_preserver::dropped_comments_preserver, SwcImportResolver,
+    builder::{timed, PassBuilder},
+    dropped_comments_preserver::dropped_comments_preserver,
 };
 
 #[cfg(test)]
@@ -291,16 +299,19 @@ impl Options {
         //
         // We do this before creating custom passes, so custom passses can use the
         // variable management system based on the syntax contexts.
-        if syntax.typescript() {
+        if syntax.typescript() || syntax.flow() {
             assumptions.set_class_methods |= !transform.use_define_for_class_fields.into_bool();
         }
 
         assumptions.set_public_class_fields |= !transform.use_define_for_class_fields.into_bool();
 
-        program.visit_mut_with(&mut resolver(
-            unresolved_mark,
-            top_level_mark,
-            syntax.typescript(),
+        program.mutate(timed(
+            "resolver",
+            resolver(
+                unresolved_mark,
+                top_level_mark,
+                syntax.typescript() || syntax.flow(),
+            ),
         ));
 
         let default_top_level = program.is_module();
@@ -444,7 +455,7 @@ impl Options {
                 })
         };
 
-        if syntax.typescript() {
+        if syntax.typescript() || syntax.flow() {
             transform.legacy_decorator = true.into();
         }
         let optimizer = transform.optimizer;
@@ -535,6 +546,8 @@ impl Options {
         // inline_script by default.
         let codegen_inline_script = js_minify.as_ref().map_or(false, |v| v.format.inline_script);
 
+        let parens = cfg.jsc.output.parens.unwrap_or_default();
+
         let preamble = if !cfg.jsc.output.preamble.is_empty() {
             cfg.jsc.output.preamble
         } else {
@@ -568,6 +581,7 @@ impl Options {
             })
         })
         .fixer(!self.disable_fixer)
+        .parens(parens)
         .preset_env(cfg.env)
         .regenerator(regenerator)
         .finalize(
@@ -666,79 +680,97 @@ impl Options {
             Box::new((
                 (
                     if experimental.run_plugin_first.into_bool() {
-                        plugin_transforms.take()
+                        plugin_transforms.take().map(|pass| timed("plugins", pass))
                     } else {
                         None
                     },
                     Optional::new(
-                        lint_pass(swc_ecma_lints::rules::all(LintParams {
-                            program: &program,
-                            lint_config: &lints,
-                            top_level_ctxt,
-                            unresolved_ctxt,
-                            es_version,
-                            source_map: cm.clone(),
-                        })),
+                        timed(
+                            "lints",
+                            lint_pass(swc_ecma_lints::rules::all(LintParams {
+                                program: &program,
+                                lint_config: &lints,
+                                top_level_ctxt,
+                                unresolved_ctxt,
+                                es_version,
+                                source_map: cm.clone(),
+                            })),
+                        ),
                         !disable_all_lints,
                     ),
                     // Decorators may use type information
-                    Optional::new(decorator_pass, syntax.decorators()),
+                    Optional::new(timed("decorators", decorator_pass), syntax.decorators()),
                     Optional::new(
-                        explicit_resource_management(),
+                        timed(
+                            "explicit_resource_management",
+                            explicit_resource_management(),
+                        ),
                         syntax.explicit_resource_management(),
                     ),
                 ),
                 // The transform strips import assertions, so it's only enabled if
                 // keep_import_assertions is false.
                 (
-                    Optional::new(import_attributes(), !keep_import_attributes),
                     Optional::new(
-                        typescript::tsx::<Option<&dyn Comments>>(
-                            cm.clone(),
-                            typescript::Config {
-                                import_export_assign_config,
-                                verbatim_module_syntax,
-                                ..Default::default()
-                            },
-                            typescript::TsxConfig {
-                                pragma: Some(
-                                    transform
-                                        .react
-                                        .pragma
-                                        .clone()
-                                        .unwrap_or_else(default_pragma),
-                                ),
-                                pragma_frag: Some(
-                                    transform
-                                        .react
-                                        .pragma_frag
-                                        .clone()
-                                        .unwrap_or_else(default_pragma_frag),
-                                ),
-                            },
-                            comments.map(|v| v as _),
-                            unresolved_mark,
-                            top_level_mark,
+                        timed("import_attributes", import_attributes()),
+                        !keep_import_attributes,
+                    ),
+                    Optional::new(
+                        timed(
+                            "typescript",
+                            typescript::tsx::<Option<&dyn Comments>>(
+                                cm.clone(),
+                                typescript::Config {
+                                    import_export_assign_config,
+                                    verbatim_module_syntax,
+                                    ..Default::default()
+                                },
+                                typescript::TsxConfig {
+                                    pragma: Some(
+                                        transform
+                                            .react
+                                            .pragma
+                                            .clone()
+                                            .unwrap_or_else(default_pragma),
+                                    ),
+                                    pragma_frag: Some(
+                                        transform
+                                            .react
+                                            .pragma_frag
+                                            .clone()
+                                            .unwrap_or_else(default_pragma_frag),
+                                    ),
+                                },
+                                comments.map(|v| v as _),
+                                unresolved_mark,
+                                top_level_mark,
+                            ),
                         ),
-                        syntax.typescript(),
+                        syntax.typescript() || syntax.flow(),
                     ),
                 ),
                 (
-                    plugin_transforms.take(),
+                    plugin_transforms.take().map(|pass| timed("plugins", pass)),
                     custom_before_pass(&program),
                     // handle jsx
                     Optional::new(
-                        react::react::<&dyn Comments>(
-                            cm.clone(),
-                            comments.map(|v| v as _),
-                            transform.react,
-                            top_level_mark,
-                            unresolved_mark,
+                        timed(
+                            "react",
+                            react::react::<&dyn Comments>(
+                                cm.clone(),
+                                comments.map(|v| v as _),
+                                transform.react,
+                                top_level_mark,
+                                unresolved_mark,
+                            ),
                         ),
                         syntax.jsx(),
                     ),
                     pass,
-                    Optional::new(jest::jest(), transform.hidden.jest.into_bool()),
+                    Optional::new(
+                        timed("jest", jest::jest()),
+                        transform.hidden.jest.into_bool(),
+                    ),
                     Optional::new(
                         dropped_comments_preserver(comments.cloned()),
                         preserve_all_comments,
@@ -1187,6 +1219,19 @@ pub struct JscOutputConfig {
 
     #[serde(default)]
     pub source_map_url: Option<String>,
+
+    /// Line endings of the output. Defaults to `lf`.
+    #[serde(default)]
+    pub line_ending: Option<LineEnding>,
+
+    /// Preserves up to this number of blank lines between top-level
+    /// statements. Defaults to `0`.
+    #[serde(default)]
+    pub preserve_blank_lines: Option<u32>,
+
+    /// Style of parenthesis in the output. Defaults to `minimal`.
+    #[serde(default)]
+    pub parens: Option<Parens>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -1349,6 +1394,9 @@ impl ModuleConfig {
         }
     }
 
+    /// Returns [None] if imports don't need to be resolved, or if swc is built
+    /// without the `fs` feature.
+    #[cfg(feature = "fs")]
     pub fn get_resolver(
         base_url: &Path,
         paths: CompiledPaths,
@@ -1407,6 +1455,18 @@ impl ModuleConfig {
 
         Some((base, resolver))
     }
+
+    /// Returns [None] if imports don't need to be resolved, or if swc is built
+    /// without the `fs` feature.
+    #[cfg(not(feature = "fs"))]
+    pub fn get_resolver(
+        _: &Path,
+        _: CompiledPaths,
+        _: &FileName,
+        _: Option<&ModuleConfig>,
+    ) -> Option<(FileName, Arc<dyn ImportResolver>)> {
+        None
+    }
 }
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Merge)]
@@ -1712,6 +1772,7 @@ pub(crate) fn default_env_name() -> String {
     }
 }
 
+#[cfg(feature = "fs")]
 fn build_resolver(
     mut base_url: PathBuf,
     paths: CompiledPaths,
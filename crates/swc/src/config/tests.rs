@@ -30,3 +30,17 @@ fn issue_6996() {
     let rc = parse_swcrc(include_str!("issue-6996.json")).expect("failed to parse");
     dbg!(&rc);
 }
+
+#[test]
+fn error_location() {
+    let err = parse_swcrc("{\n  \"jsc\": {\n    \"parser\": 1\n  }\n}").unwrap_err();
+
+    assert!(err.to_string().ends_with(": 3:15"), "{}", err);
+}
+
+#[test]
+fn syntax_error_location() {
+    let err = parse_swcrc("{\n  // comment\n  \"minify\": true true\n}").unwrap_err();
+
+    assert!(err.to_string().ends_with(": 3:18"), "{}", err);
+}
@@ -112,9 +112,11 @@
 pub extern crate swc_atoms as atoms;
 extern crate swc_common as common;
 
+#[cfg(feature = "fs")]
+use std::fs::read_to_string;
 use std::{
     cell::RefCell,
-    fs::{read_to_string, File},
+    fs::File,
     io::ErrorKind,
     path::{Path, PathBuf},
     sync::Arc,
@@ -126,28 +128,31 @@ use common::{
     comments::{Comment, SingleThreadedComments},
     errors::HANDLER,
 };
-use jsonc_parser::{parse_to_serde_value, ParseOptions};
 use once_cell::sync::Lazy;
-use serde_json::error::Category;
+use rustc_hash::FxHashMap;
 pub use sourcemap;
+use swc_atoms::Atom;
 use swc_common::{
-    comments::Comments, errors::Handler, sync::Lrc, FileName, Mark, SourceFile, SourceMap, Spanned,
-    GLOBALS,
+    comments::Comments, errors::Handler, sync::Lrc, BytePos, FileName, Mark, SourceFile, SourceMap,
+    Spanned, GLOBALS,
 };
-pub use swc_compiler_base::{PrintArgs, TransformOutput};
+pub use swc_compiler_base::{scopes::SourceMapScopes, PrintArgs, TransformOutput};
 pub use swc_config::config_types::{BoolConfig, BoolOr, BoolOrDataConfig};
 use swc_ecma_ast::{noop_pass, EsVersion, Pass, Program};
 use swc_ecma_codegen::{to_code_with_comments, Node};
+#[cfg(feature = "fs")]
 use swc_ecma_loader::resolvers::{
     lru::CachingResolver, node::NodeModulesResolver, tsc::TsConfigResolver,
 };
 use swc_ecma_minifier::option::{MangleCache, MinifyOptions, TopLevelOptions};
 use swc_ecma_parser::{EsSyntax, Syntax};
+#[cfg(feature = "fs")]
+use swc_ecma_transforms::modules::path::NodeImportResolver;
 use swc_ecma_transforms::{
     fixer,
     helpers::{self, Helpers},
     hygiene,
-    modules::{path::NodeImportResolver, rewriter::import_rewriter},
+    modules::rewriter::import_rewriter,
     resolver,
 };
 use swc_ecma_transforms_base::fixer::paren_remover;
@@ -155,6 +160,7 @@ use swc_ecma_visit::{FoldWith, VisitMutWith, VisitWith};
 pub use swc_error_reporters::handler::{try_with_handler, HandlerOpts};
 pub use swc_node_comments::SwcComments;
 use swc_timer::timer;
+pub use swc_timer::{profile, Timing};
 use swc_transform_common::output::experimental_emit;
 use swc_typescript::fast_dts::FastDts;
 use tracing::warn;
@@ -165,12 +171,17 @@ use crate::config::{
     BuiltInput, Config, ConfigFile, InputSourceMap, IsModule, JsMinifyCommentOption,
     JsMinifyOptions, Options, OutputCharset, Rc, RootMode, SourceMapsConfig,
 };
+#[cfg(feature = "concurrent")]
+pub use crate::parallel::{FileInput, FileOutput};
 
 mod builder;
 pub mod config;
 mod dropped_comments_preserver;
+#[cfg(feature = "concurrent")]
+mod parallel;
 mod plugin;
 pub mod wasm_analysis;
+#[cfg(feature = "fs")]
 pub mod resolver {
     use std::path::PathBuf;
 
@@ -211,6 +222,7 @@ pub mod resolver {
     }
 }
 
+#[cfg(feature = "fs")]
 type SwcImportResolver = Arc<
     NodeImportResolver<CachingResolver<TsConfigResolver<CachingResolver<NodeModulesResolver>>>>,
 >;
@@ -434,6 +446,8 @@ impl Compiler {
         is_module: IsModule,
         comments: Option<&dyn Comments>,
     ) -> Result<Program, Error> {
+        let _timer = timer!("Compiler.parse_js");
+
         swc_compiler_base::parse_js(
             self.cm.clone(),
             fm,
@@ -672,6 +686,11 @@ impl Compiler {
     ///
     /// This means, you can use `noop_visit_type`, `noop_fold_type` and
     /// `noop_visit_mut_type` in your visitor to reduce the binary size.
+    ///
+    /// # Profiling
+    ///
+    /// Call this in [profile] to get the time spent on parsing, each transform
+    /// pass, phases of the minifier and printing.
     #[tracing::instrument(skip_all)]
     pub fn process_js_with_custom_pass<P1, P2>(
         &self,
@@ -683,6 +702,40 @@ impl Compiler {
         custom_before_pass: impl FnOnce(&Program) -> P1,
         custom_after_pass: impl FnOnce(&Program) -> P2,
     ) -> Result<TransformOutput, Error>
+    where
+        P1: Pass,
+        P2: Pass,
+    {
+        self.run(|| {
+            let transformed = self.transform_js_with_custom_pass(
+                fm,
+                program,
+                handler,
+                opts,
+                comments,
+                custom_before_pass,
+                custom_after_pass,
+            )?;
+
+            self.print_transformed(&transformed)
+        })
+    }
+
+    /// Same as [Compiler::process_js_with_custom_pass], but returns the
+    /// transformed program instead of printing it.
+    ///
+    /// Use [Compiler::print_transformed] to print the result.
+    #[tracing::instrument(skip_all)]
+    pub fn transform_js_with_custom_pass<P1, P2>(
+        &self,
+        fm: Arc<SourceFile>,
+        program: Option<Program>,
+        handler: &Handler,
+        opts: &Options,
+        comments: SingleThreadedComments,
+        custom_before_pass: impl FnOnce(&Program) -> P1,
+        custom_after_pass: impl FnOnce(&Program) -> P2,
+    ) -> Result<TransformedProgram, Error>
     where
         P1: Pass,
         P2: Pass,
@@ -725,7 +778,68 @@ impl Compiler {
                 None
             };
 
-            self.apply_transforms(handler, comments.clone(), fm.clone(), orig.as_ref(), config)
+            self.transform_input(handler, comments.clone(), fm.clone(), orig, config)
+        })
+    }
+
+    /// Applies the transforms configured by `opts` to a program which is
+    /// already parsed, without printing it.
+    ///
+    /// `fm` is the file `program` is parsed from, and `comments` should
+    /// contain the comments collected while parsing it. The returned program
+    /// can be modified or transformed again before it's passed to
+    /// [Compiler::print_transformed], so embedders doing multiple rounds of
+    /// transforms don't need to print and parse the program between rounds.
+    #[tracing::instrument(skip_all)]
+    pub fn transform_program(
+        &self,
+        handler: &Handler,
+        fm: Arc<SourceFile>,
+        program: Program,
+        opts: &Options,
+        comments: SingleThreadedComments,
+    ) -> Result<TransformedProgram, Error> {
+        self.transform_js_with_custom_pass(
+            fm,
+            Some(program),
+            handler,
+            opts,
+            comments,
+            |_| noop_pass(),
+            |_| noop_pass(),
+        )
+    }
+
+    /// Prints a program returned by [Compiler::transform_program], using the
+    /// output options it was transformed with.
+    pub fn print_transformed(
+        &self,
+        transformed: &TransformedProgram,
+    ) -> Result<TransformOutput, Error> {
+        self.run(|| {
+            self.print(
+                &transformed.program,
+                PrintArgs {
+                    source_root: transformed.source_root.as_deref(),
+                    source_file_name: transformed.source_file_name.as_deref(),
+                    output_path: transformed.output_path.clone(),
+                    inline_sources_content: transformed.inline_sources_content,
+                    source_map: transformed.source_maps.clone(),
+                    source_map_names: &transformed.source_map_names,
+                    orig: transformed.orig.as_ref(),
+                    comments: transformed.comments.as_ref().map(|v| v as _),
+                    emit_source_map_columns: transformed.emit_source_map_columns,
+                    preamble: &transformed.preamble,
+                    codegen_config: transformed.codegen_config,
+                    output: if transformed.output.is_empty() {
+                        None
+                    } else {
+                        Some(transformed.output.clone())
+                    },
+                    source_map_url: transformed.source_map_url.as_deref(),
+                    source_map_scopes: None,
+                },
+            )
         })
     }
 
@@ -760,18 +874,23 @@ impl Compiler {
 
             let target = opts.ecma.clone().into();
 
-            let (source_map, orig, source_map_url) = opts
+            let (source_map, orig, source_map_url, emit_scopes) = opts
                 .source_map
                 .as_ref()
                 .map(|obj| -> Result<_, Error> {
                     let orig = obj.content.as_ref().map(|s| s.to_sourcemap()).transpose()?;
 
-                    Ok((SourceMapsConfig::Bool(true), orig, obj.url.as_deref()))
+                    Ok((
+                        SourceMapsConfig::Bool(true),
+                        orig,
+                        obj.url.as_deref(),
+                        obj.scopes,
+                    ))
                 })
                 .unwrap_as_option(|v| {
                     Some(Ok(match v {
-                        Some(true) => (SourceMapsConfig::Bool(true), None, None),
-                        _ => (SourceMapsConfig::Bool(false), None, None),
+                        Some(true) => (SourceMapsConfig::Bool(true), None, None, false),
+                        _ => (SourceMapsConfig::Bool(false), None, None, false),
                     }))
                 })
                 .unwrap()?;
@@ -853,6 +972,12 @@ impl Compiler {
                 Default::default()
             };
 
+            let mut source_map_scopes = if emit_scopes && orig.is_none() {
+                Some(SourceMapScopes::collect(&program))
+            } else {
+                None
+            };
+
             let unresolved_mark = Mark::new();
             let top_level_mark = Mark::new();
 
@@ -883,6 +1008,13 @@ impl Compiler {
                 program
             });
 
+            // The minifier stops early once cancelled, so `program` may be incomplete.
+            swc_common::cancel::check()?;
+
+            if let Some(scopes) = &mut source_map_scopes {
+                scopes.set_generated(&program);
+            }
+
             let preserve_comments = opts
                 .format
                 .comments
@@ -925,6 +1057,7 @@ impl Compiler {
                         ),
                     output: None,
                     source_map_url,
+                    source_map_scopes: source_map_scopes.as_ref(),
                 },
             );
 
@@ -960,15 +1093,15 @@ impl Compiler {
         )
     }
 
-    #[tracing::instrument(name = "swc::Compiler::apply_transforms", skip_all)]
-    fn apply_transforms(
+    #[tracing::instrument(name = "swc::Compiler::transform_input", skip_all)]
+    fn transform_input(
         &self,
         handler: &Handler,
         comments: SingleThreadedComments,
         fm: Arc<SourceFile>,
-        orig: Option<&sourcemap::SourceMap>,
+        orig: Option<sourcemap::SourceMap>,
         config: BuiltInput<impl Pass>,
-    ) -> Result<TransformOutput, Error> {
+    ) -> Result<TransformedProgram, Error> {
         self.run(|| {
             let program = config.program;
 
@@ -1028,12 +1161,17 @@ impl Compiler {
 
                 helpers::HELPERS.set(&Helpers::new(config.external_helpers), || {
                     HANDLER.set(handler, || {
+                        let _timer = timer!("Compiler.transform");
+
                         // Fold module
                         program.apply(pass)
                     })
                 })
             });
 
+            // Passes stop early once cancelled, so `program` may be incomplete.
+            swc_common::cancel::check()?;
+
             if let Some(comments) = &config.comments {
                 swc_compiler_base::minify_file_comments(
                     comments,
@@ -1042,45 +1180,69 @@ impl Compiler {
                 );
             }
 
-            self.print(
-                &program,
-                PrintArgs {
-                    source_root: config.source_root.as_deref(),
-                    source_file_name: config.source_file_name.as_deref(),
-                    output_path: config.output_path,
-                    inline_sources_content: config.inline_sources_content,
-                    source_map: config.source_maps,
-                    source_map_names: &source_map_names,
-                    orig,
-                    comments: config.comments.as_ref().map(|v| v as _),
-                    emit_source_map_columns: config.emit_source_map_columns,
-                    preamble: &config.output.preamble,
-                    codegen_config: swc_ecma_codegen::Config::default()
-                        .with_target(config.target)
-                        .with_minify(config.minify)
-                        .with_ascii_only(
-                            config
-                                .output
-                                .charset
-                                .map(|v| matches!(v, OutputCharset::Ascii))
-                                .unwrap_or(false),
-                        )
-                        .with_emit_assert_for_import_attributes(
-                            config.emit_assert_for_import_attributes,
-                        )
-                        .with_inline_script(config.codegen_inline_script),
-                    output: if output.is_empty() {
-                        None
-                    } else {
-                        Some(output)
-                    },
-                    source_map_url: config.output.source_map_url.as_deref(),
-                },
-            )
+            Ok(TransformedProgram {
+                program,
+                comments: config.comments,
+                source_root: config.source_root,
+                source_file_name: config.source_file_name,
+                output_path: config.output_path,
+                inline_sources_content: config.inline_sources_content,
+                source_maps: config.source_maps,
+                source_map_names,
+                orig,
+                emit_source_map_columns: config.emit_source_map_columns,
+                codegen_config: swc_ecma_codegen::Config::default()
+                    .with_target(config.target)
+                    .with_minify(config.minify)
+                    .with_ascii_only(
+                        config
+                            .output
+                            .charset
+                            .map(|v| matches!(v, OutputCharset::Ascii))
+                            .unwrap_or(false),
+                    )
+                    .with_emit_assert_for_import_attributes(
+                        config.emit_assert_for_import_attributes,
+                    )
+                    .with_inline_script(config.codegen_inline_script)
+                    .with_line_ending(config.output.line_ending.unwrap_or_default())
+                    .with_preserve_blank_lines(
+                        config.output.preserve_blank_lines.unwrap_or_default(),
+                    ),
+                preamble: config.output.preamble,
+                source_map_url: config.output.source_map_url,
+                output,
+            })
         })
     }
 }
 
+/// A program transformed by [Compiler::transform_program], which is not
+/// printed yet.
+///
+/// `program` can be modified before it's passed to
+/// [Compiler::print_transformed].
+pub struct TransformedProgram {
+    pub program: Program,
+    /// Comments which will be printed, if comments are preserved.
+    pub comments: Option<SingleThreadedComments>,
+
+    source_root: Option<String>,
+    source_file_name: Option<String>,
+    output_path: Option<PathBuf>,
+    inline_sources_content: bool,
+    source_maps: SourceMapsConfig,
+    /// Names of identifiers in the original program.
+    source_map_names: FxHashMap<BytePos, Atom>,
+    orig: Option<sourcemap::SourceMap>,
+    emit_source_map_columns: bool,
+    codegen_config: swc_ecma_codegen::Config,
+    preamble: String,
+    source_map_url: Option<String>,
+    /// Experimental output emitted by transforms.
+    output: FxHashMap<String, String>,
+}
+
 #[non_exhaustive]
 #[derive(Clone, Default)]
 pub struct JsMinifyExtras {
@@ -1097,6 +1259,7 @@ impl JsMinifyExtras {
     }
 }
 
+#[cfg(feature = "fs")]
 fn find_swcrc(path: &Path, root: &Path, root_mode: RootMode) -> Option<PathBuf> {
     let mut parent = path.parent();
     while let Some(dir) = parent {
@@ -1115,6 +1278,12 @@ fn find_swcrc(path: &Path, root: &Path, root_mode: RootMode) -> Option<PathBuf>
     None
 }
 
+#[cfg(not(feature = "fs"))]
+fn find_swcrc(_: &Path, _: &Path, _: RootMode) -> Option<PathBuf> {
+    None
+}
+
+#[cfg(feature = "fs")]
 #[tracing::instrument(skip_all)]
 fn load_swcrc(path: &Path) -> Result<Rc, Error> {
     let content = read_to_string(path).context("failed to read config (.swcrc) file")?;
@@ -1122,38 +1291,43 @@ fn load_swcrc(path: &Path) -> Result<Rc, Error> {
     parse_swcrc(&content)
 }
 
+#[cfg(not(feature = "fs"))]
+fn load_swcrc(path: &Path) -> Result<Rc, Error> {
+    bail!(
+        "cannot read config file at `{}` because swc is built without the `fs` feature",
+        path.display()
+    )
+}
+
+#[cfg(feature = "fs")]
 fn parse_swcrc(s: &str) -> Result<Rc, Error> {
-    fn convert_json_err(e: serde_json::Error) -> Error {
-        let line = e.line();
-        let column = e.column();
-
-        let msg = match e.classify() {
-            Category::Io => "io error",
-            Category::Syntax => "syntax error",
-            Category::Data => "unmatched data",
-            Category::Eof => "unexpected eof",
-        };
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Custom(".swcrc".into()).into(), s.to_string());
+
+    let convert_json_err = |e: swc_json::error::Error| {
+        let loc = cm.lookup_char_pos(e.span().lo);
+        let msg = e.message();
+
         Error::new(e).context(format!(
             "failed to deserialize .swcrc (json) file: {}: {}:{}",
-            msg, line, column
+            msg,
+            loc.line,
+            loc.col_display + 1
         ))
+    };
+
+    if fm.src.trim().is_empty() {
+        return Err(Error::msg("failed to deserialize empty .swcrc (json) file"));
     }
 
-    let v = parse_to_serde_value(
-        s.trim_start_matches('\u{feff}'),
-        &ParseOptions {
-            allow_comments: true,
-            allow_trailing_commas: true,
-            allow_loose_object_property_names: false,
-        },
-    )?
-    .ok_or_else(|| Error::msg("failed to deserialize empty .swcrc (json) file"))?;
-
-    if let Ok(rc) = serde_json::from_value(v.clone()) {
+    let v = swc_json::parse_file(&fm, swc_json::parser::ParserConfig::jsonc())
+        .map_err(convert_json_err)?;
+
+    if let Ok(rc) = swc_json::from_value(&v) {
         return Ok(rc);
     }
 
-    serde_json::from_value(v)
+    swc_json::from_value(&v)
         .map(Rc::Single)
         .map_err(convert_json_err)
 }
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use anyhow::Error;
+use par_iter::prelude::*;
+use swc_common::{SourceFile, GLOBALS};
+use swc_error_reporters::handler::{try_with_handler, HandlerOpts};
+
+use crate::{config::Options, Compiler, TransformOutput};
+
+/// A file to process with [Compiler::process_files].
+pub struct FileInput {
+    /// Should be created from [Compiler::cm].
+    pub fm: Arc<SourceFile>,
+    pub opts: Options,
+}
+
+/// The result of processing a [FileInput].
+pub struct FileOutput {
+    /// The index of the input.
+    pub index: usize,
+    pub fm: Arc<SourceFile>,
+    /// Errors are rendered using [HandlerOpts] passed to
+    /// [Compiler::process_files].
+    pub result: Result<TransformOutput, Error>,
+}
+
+impl Compiler {
+    /// Parses, transforms, minifies and prints `inputs` on the thread pool of
+    /// `par-core`.
+    ///
+    /// `on_output` is called from worker threads as soon as a file is done, so
+    /// outputs are not in the order of `inputs`. Use [FileOutput::index] to
+    /// restore the order.
+    ///
+    /// All files share [Compiler::cm] and the [swc_common::Globals] of the
+    /// caller, so this should be called in a scope of [GLOBALS].
+    pub fn process_files<F>(&self, inputs: Vec<FileInput>, handler_opts: HandlerOpts, on_output: F)
+    where
+        F: Fn(FileOutput) + Send + Sync,
+    {
+        self.run(|| {
+            GLOBALS.with(|globals| {
+                inputs
+                    .into_par_iter()
+                    .enumerate()
+                    .for_each(|(index, FileInput { fm, opts })| {
+                        let result = GLOBALS.set(globals, || {
+                            try_with_handler(self.cm.clone(), handler_opts, |handler| {
+                                self.process_js_file(fm.clone(), handler, &opts)
+                            })
+                            .map_err(|e| e.to_pretty_error())
+                        });
+
+                        on_output(FileOutput { index, fm, result });
+                    })
+            })
+        })
+    }
+}
@@ -16,6 +16,7 @@ use swc_common::errors::{DiagnosticId, HANDLER};
 use swc_ecma_ast::Pass;
 #[cfg(feature = "plugin")]
 use swc_ecma_ast::*;
+#[cfg(feature = "plugin")]
 use swc_ecma_loader::{
     resolve::Resolve,
     resolvers::{lru::CachingResolver, node::NodeModulesResolver},
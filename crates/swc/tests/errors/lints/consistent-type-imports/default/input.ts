@@ -0,0 +1,10 @@
+import { A, type B } from "a";
+import { C, d } from "c";
+import E, { F } from "e";
+import type { G } from "g";
+import { h } from "h";
+import { I } from "i";
+
+export { I };
+
+let x: A | B | C | E | F | G = d(h);
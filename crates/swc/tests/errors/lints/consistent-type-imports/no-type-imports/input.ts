@@ -0,0 +1,4 @@
+import type { A } from "a";
+import { type B, c } from "b";
+
+let x: A | B = c;
@@ -0,0 +1,4 @@
+namespace A {}
+declare namespace B {
+    namespace C {}
+}
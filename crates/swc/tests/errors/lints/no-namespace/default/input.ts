@@ -0,0 +1,5 @@
+namespace A {}
+module B {}
+declare namespace C {}
+declare module "d" {}
+declare global {}
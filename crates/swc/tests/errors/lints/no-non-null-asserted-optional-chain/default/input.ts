@@ -0,0 +1,5 @@
+foo?.bar!;
+foo?.bar()!;
+(foo?.bar)!.baz;
+foo!.bar;
+foo?.bar!.baz;
@@ -0,0 +1,10 @@
+import "side-effect";
+import unusedDefault from "a";
+import * as ns from "b";
+import { used, unused1, unused2 } from "c";
+import { Type } from "d";
+import { _ignored } from "e";
+import def, { named } from "f";
+
+const value: Type = used();
+console.log(value, def);
@@ -0,0 +1,4 @@
+import React from "react";
+import { Component, unused } from "./component";
+
+export const App = () => <Component />;
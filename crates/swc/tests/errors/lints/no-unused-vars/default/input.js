@@ -0,0 +1,27 @@
+var unused = 1;
+let a = 1, b = 2;
+console.log(b);
+
+let assigned;
+assigned = 1;
+
+const { c, ...rest } = {};
+console.log(rest);
+
+export const exported = 1;
+
+function unusedFn() {}
+
+function used(first, second, third) {
+    return second;
+}
+used();
+
+try {
+    used();
+} catch (err) {}
+
+for (const key in {}) {}
+
+const counter = 0;
+export { counter };
@@ -0,0 +1,11 @@
+var topLevel = 1;
+
+function outer(first, _second, third) {
+    const { a, ...rest } = {};
+    const ignoredLocal = 1;
+    const local = 1;
+
+    try {
+        outer(rest, third);
+    } catch (err) {}
+}
@@ -0,0 +1,7 @@
+let a = "a" as "a";
+let b = <"b">"b";
+let c: "c" = "c";
+let d: 1 = 1;
+let e = "e" as const;
+let f: "f" = "g";
+let g = "g" as string;
@@ -14,7 +14,7 @@ use swc::{
     },
     try_with_handler, BoolOrDataConfig, Compiler, HandlerOpts,
 };
-use swc_common::{errors::ColorConfig, SourceMap, GLOBALS};
+use swc_common::{errors::ColorConfig, FileName, SourceMap, GLOBALS};
 use swc_ecma_ast::EsVersion;
 use swc_ecma_parser::{Syntax, TsSyntax};
 use swc_ecma_testing::{exec_node_js, JsExecOptions};
@@ -101,17 +101,11 @@ fn create_matrix(entry: &Path) -> Vec<Options> {
         .map(|parent| parent.join(".swcrc"))
         .and_then(|path| fs::read_to_string(path).ok())
         .and_then(|content| {
-            jsonc_parser::parse_to_serde_value(
-                &content,
-                &jsonc_parser::ParseOptions {
-                    allow_comments: true,
-                    allow_trailing_commas: true,
-                    allow_loose_object_property_names: false,
-                },
-            )
-            .ok()?
+            let cm = SourceMap::default();
+            let fm = cm.new_source_file(FileName::Anon.into(), content);
+
+            swc_json::from_file::<Config>(&fm, swc_json::parser::ParserConfig::jsonc()).ok()
         })
-        .and_then(|content| serde_json::from_value::<Config>(content).ok())
         .and_then(|config| config.jsc.transform.into_inner())
         .map(|c| c.use_define_for_class_fields)
         .unwrap_or_default();
@@ -0,0 +1,5 @@
+class A extends B {
+    foo() {
+        return super.foo();
+    }
+}
@@ -0,0 +1,21 @@
+var _call_super = require("@swc/helpers/_/_call_super");
+var _class_call_check = require("@swc/helpers/_/_class_call_check");
+var _create_class = require("@swc/helpers/_/_create_class");
+var _inherits = require("@swc/helpers/_/_inherits");
+var A = /*#__PURE__*/ function(B1) {
+    "use strict";
+    _inherits._(A, B1);
+    function A() {
+        _class_call_check._(this, A);
+        return _call_super._(this, A, arguments);
+    }
+    _create_class._(A, [
+        {
+            key: "foo",
+            value: function foo() {
+                return B1.prototype.foo.call(this);
+            }
+        }
+    ]);
+    return A;
+}(B);
@@ -0,0 +1,6 @@
+function f(a, b = 1, ...rest) {
+    return [a, b, rest];
+}
+async function g(a, b) {
+    await a;
+}
@@ -0,0 +1,31 @@
+var _async_to_generator = require("@swc/helpers/_/_async_to_generator");
+var _ts_generator = require("@swc/helpers/_/_ts_generator");
+function f(a, b) {
+    if (b === void 0) b = 1;
+    for(var _len = arguments.length, rest = new Array(_len > 2 ? _len - 2 : 0), _key = 2; _key < _len; _key++){
+        rest[_key - 2] = arguments[_key];
+    }
+    return [
+        a,
+        b,
+        rest
+    ];
+}
+function g(a, b) {
+    return /*#__PURE__*/ _async_to_generator._(function() {
+        return _ts_generator._(this, function(_state) {
+            switch(_state.label){
+                case 0:
+                    return [
+                        4,
+                        a
+                    ];
+                case 1:
+                    _state.sent();
+                    return [
+                        2
+                    ];
+            }
+        });
+    })();
+}
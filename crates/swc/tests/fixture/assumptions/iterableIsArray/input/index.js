@@ -0,0 +1,5 @@
+const [first, ...rest] = list;
+const merged = [...first, ...rest];
+for (const item of merged) {
+    console.log(item);
+}
@@ -0,0 +1,6 @@
+var _list = list, first = _list[0], rest = _list.slice(1);
+var merged = [].concat(first, rest);
+for(var _i = 0; _i < merged.length; _i++){
+    var item = merged[_i];
+    console.log(item);
+}
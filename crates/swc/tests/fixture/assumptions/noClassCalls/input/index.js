@@ -0,0 +1,5 @@
+class A {
+    constructor() {
+        this.a = 1;
+    }
+}
@@ -0,0 +1,4 @@
+var A = function A() {
+    "use strict";
+    this.a = 1;
+};
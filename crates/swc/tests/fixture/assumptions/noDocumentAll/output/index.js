@@ -0,0 +1,3 @@
+var _foo;
+foo != null ? foo : bar;
+(_foo = foo) == null ? void 0 : _foo.bar;
@@ -0,0 +1,4 @@
+var _object_without_properties_loose = require("@swc/helpers/_/_object_without_properties_loose");
+var a = obj.a, rest = _object_without_properties_loose._(obj, [
+    "a"
+]);
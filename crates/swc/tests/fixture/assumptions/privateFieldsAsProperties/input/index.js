@@ -0,0 +1,6 @@
+class A {
+    #foo = 1;
+    getFoo() {
+        return this.#foo;
+    }
+}
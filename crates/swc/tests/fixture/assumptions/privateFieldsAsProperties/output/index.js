@@ -0,0 +1,24 @@
+var _class_call_check = require("@swc/helpers/_/_class_call_check");
+var _class_private_field_loose_base = require("@swc/helpers/_/_class_private_field_loose_base");
+var _class_private_field_loose_key = require("@swc/helpers/_/_class_private_field_loose_key");
+var _create_class = require("@swc/helpers/_/_create_class");
+var _foo = /*#__PURE__*/ _class_private_field_loose_key._("_foo");
+var A = /*#__PURE__*/ function() {
+    "use strict";
+    function A() {
+        _class_call_check._(this, A);
+        Object.defineProperty(this, _foo, {
+            writable: true,
+            value: 1
+        });
+    }
+    _create_class._(A, [
+        {
+            key: "getFoo",
+            value: function getFoo() {
+                return _class_private_field_loose_base._(this, _foo)[_foo];
+            }
+        }
+    ]);
+    return A;
+}();
@@ -0,0 +1,2 @@
+foo.bar?.();
+const merged = { ...a, b };
@@ -0,0 +1,5 @@
+var _object_spread = require("@swc/helpers/_/_object_spread");
+foo.bar === null || foo.bar === void 0 ? void 0 : foo.bar.call(foo);
+var merged = _object_spread._({}, a, {
+    b: b
+});
@@ -0,0 +1,11 @@
+var _class_call_check = require("@swc/helpers/_/_class_call_check");
+var A = /*#__PURE__*/ function() {
+    "use strict";
+    function A() {
+        _class_call_check._(this, A);
+    }
+    var _proto = A.prototype;
+    _proto.foo = function foo() {};
+    A.bar = function bar() {};
+    return A;
+}();
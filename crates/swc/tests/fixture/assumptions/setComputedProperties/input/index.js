@@ -0,0 +1 @@
+const obj = { [key]: value };
@@ -0,0 +1,2 @@
+var _obj;
+var obj = (_obj = {}, _obj[key] = value, _obj);
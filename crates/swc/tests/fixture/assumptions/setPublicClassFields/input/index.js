@@ -0,0 +1,4 @@
+class A {
+    foo = 1;
+    static bar = 2;
+}
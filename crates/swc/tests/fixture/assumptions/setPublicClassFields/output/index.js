@@ -0,0 +1,7 @@
+var _class_call_check = require("@swc/helpers/_/_class_call_check");
+var A = function A() {
+    "use strict";
+    _class_call_check._(this, A);
+    this.foo = 1;
+};
+A.bar = 2;
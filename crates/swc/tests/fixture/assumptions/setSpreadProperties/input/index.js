@@ -0,0 +1 @@
+const merged = { ...a, b, ...c };
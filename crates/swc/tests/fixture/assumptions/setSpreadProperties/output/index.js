@@ -0,0 +1,5 @@
+var _extends = require("@swc/helpers/_/_extends");
+var _object_spread_props = require("@swc/helpers/_/_object_spread_props");
+var merged = _extends._(_object_spread_props._(_extends._({}, a), {
+    b: b
+}), c);
@@ -0,0 +1,3 @@
+for (const item of list) {
+    console.log(item);
+}
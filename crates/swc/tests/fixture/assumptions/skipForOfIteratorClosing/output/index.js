@@ -0,0 +1,5 @@
+var _create_for_of_iterator_helper_loose = require("@swc/helpers/_/_create_for_of_iterator_helper_loose");
+for(var _iterator = _create_for_of_iterator_helper_loose._(list), _step; !(_step = _iterator()).done;){
+    var item = _step.value;
+    console.log(item);
+}
@@ -0,0 +1,11 @@
+var _class_call_check = require("@swc/helpers/_/_class_call_check");
+var _inherits = require("@swc/helpers/_/_inherits");
+var A = /*#__PURE__*/ function(B1) {
+    "use strict";
+    _inherits._(A, B1);
+    function A() {
+        _class_call_check._(this, A);
+        return B1.call(this) || this;
+    }
+    return A;
+}(B);
@@ -0,0 +1,23 @@
+// @flow
+import type { Node } from './node';
+import { type Options, parse } from './parser';
+
+export type Result = {| ok: boolean, value: ?string |};
+opaque type Id = string;
+
+class Parser {
+    options: Options;
+
+    constructor(options: Options) {
+        this.options = options;
+    }
+
+    parse(source: string): Node {
+        return parse(source, (this.options: any));
+    }
+}
+
+export function run(source: string, id: Id): Result {
+    const value: mixed = new Parser({}).parse(source);
+    return { ok: value != null, value: id };
+}
@@ -0,0 +1,18 @@
+// @flow
+import { parse } from './parser';
+class Parser {
+    options;
+    constructor(options){
+        this.options = options;
+    }
+    parse(source) {
+        return parse(source, this.options);
+    }
+}
+export function run(source, id) {
+    const value = new Parser({}).parse(source);
+    return {
+        ok: value != null,
+        value: id
+    };
+}
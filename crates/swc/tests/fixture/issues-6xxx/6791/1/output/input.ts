@@ -1,3 +1,2 @@
 import { test as o } from "test";
-var r, l = ((r = l || {}).Hello = "World!", r);
 o("World!");
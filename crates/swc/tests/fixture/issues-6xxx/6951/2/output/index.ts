@@ -1,4 +1,5 @@
 var _ts_decorate = require("@swc/helpers/_/_ts_decorate");
+var _ts_metadata = require("@swc/helpers/_/_ts_metadata");
 class A {
     a = true;
     b = false;
@@ -6,8 +7,10 @@ class A {
     constructor(){}
 }
 _ts_decorate._([
-    observable
+    observable,
+    _ts_metadata._("design:type", Object)
 ], A.prototype, "a", void 0);
 _ts_decorate._([
-    foo
+    foo,
+    _ts_metadata._("design:type", Object)
 ], A, "c", void 0);
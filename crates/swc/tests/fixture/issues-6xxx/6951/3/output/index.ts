@@ -1,5 +1,6 @@
 var _define_property = require("@swc/helpers/_/_define_property");
 var _ts_decorate = require("@swc/helpers/_/_ts_decorate");
+var _ts_metadata = require("@swc/helpers/_/_ts_metadata");
 class A {
     constructor(){
         _define_property._(this, "a", true);
@@ -8,8 +9,10 @@ class A {
 }
 _define_property._(A, "c", 1);
 _ts_decorate._([
-    observable
+    observable,
+    _ts_metadata._("design:type", Object)
 ], A.prototype, "a", void 0);
 _ts_decorate._([
-    foo
+    foo,
+    _ts_metadata._("design:type", Object)
 ], A, "c", void 0);
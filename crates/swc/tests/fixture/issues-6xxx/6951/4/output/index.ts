@@ -1,6 +1,7 @@
 var _class_call_check = require("@swc/helpers/_/_class_call_check");
 var _define_property = require("@swc/helpers/_/_define_property");
 var _ts_decorate = require("@swc/helpers/_/_ts_decorate");
+var _ts_metadata = require("@swc/helpers/_/_ts_metadata");
 var A = function A() {
     "use strict";
     _class_call_check._(this, A);
@@ -9,8 +10,10 @@ var A = function A() {
 };
 _define_property._(A, "c", 1);
 _ts_decorate._([
-    observable
+    observable,
+    _ts_metadata._("design:type", Object)
 ], A.prototype, "a", void 0);
 _ts_decorate._([
-    foo
+    foo,
+    _ts_metadata._("design:type", Object)
 ], A, "c", void 0);
@@ -12,6 +12,6 @@ let User = class User {
 };
 _ts_decorate._([
     computed(),
-    _ts_metadata._("design:type", void 0),
+    _ts_metadata._("design:type", Object),
     _ts_metadata._("design:paramtypes", [])
 ], User.prototype, "fullName", null);
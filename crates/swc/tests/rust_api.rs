@@ -133,7 +133,10 @@ fn shopify_2_same_opt() {
                         decorators: false,
                         dts: false,
                         no_early_errors: false,
+                        preserve_parens: false,
                         disallow_ambiguous_jsx_like: false,
+                        ts_version: None,
+                        warn_import_assertions: false,
                     })),
                     transform: None.into(),
                     external_helpers: false.into(),
@@ -332,3 +335,186 @@ fn shopify_4_reduce_more() {
     })
     .unwrap()
 }
+
+#[cfg(feature = "concurrent")]
+#[test]
+fn process_files() {
+    use std::sync::Mutex;
+
+    use swc::{FileInput, HandlerOpts};
+    use swc_common::{errors::ColorConfig, GLOBALS};
+
+    let c = Compiler::new(Default::default());
+    let opts = || Options {
+        config: Config {
+            jsc: JscConfig {
+                syntax: Some(Syntax::Typescript(Default::default())),
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let mut inputs: Vec<_> = (0..32)
+        .map(|i| FileInput {
+            fm: c.cm.new_source_file(
+                FileName::Custom(format!("{}.ts", i)).into(),
+                format!("export const a{}: number = {};", i, i),
+            ),
+            opts: opts(),
+        })
+        .collect();
+    inputs.push(FileInput {
+        fm: c
+            .cm
+            .new_source_file(FileName::Custom("error.ts".into()).into(), "let;".into()),
+        opts: opts(),
+    });
+
+    let outputs = Mutex::new(Vec::new());
+
+    GLOBALS.set(&Default::default(), || {
+        c.process_files(
+            inputs,
+            HandlerOpts {
+                color: ColorConfig::Never,
+                skip_filename: false,
+            },
+            |output| outputs.lock().unwrap().push(output),
+        )
+    });
+
+    let mut outputs = outputs.into_inner().unwrap();
+    outputs.sort_by_key(|output| output.index);
+
+    assert_eq!(outputs.len(), 33);
+    for (i, output) in outputs[..32].iter().enumerate() {
+        assert_eq!(output.index, i);
+        assert_eq!(
+            output.result.as_ref().unwrap().code.trim(),
+            format!("export var a{} = {};", i, i)
+        );
+    }
+
+    let err = outputs[32].result.as_ref().unwrap_err().to_string();
+    assert!(err.contains("error.ts"), "{}", err);
+}
+
+#[test]
+fn profile_process_js() {
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "const a: number = 1; export const b = <div>{a ?? 2}</div>;".into(),
+        );
+
+        let (res, timings) = swc::profile(|| {
+            c.process_js_file(
+                fm,
+                &handler,
+                &Options {
+                    config: Config {
+                        jsc: JscConfig {
+                            syntax: Some(Syntax::Typescript(TsSyntax {
+                                tsx: true,
+                                ..Default::default()
+                            })),
+                            ..Default::default()
+                        },
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            )
+        });
+        res.unwrap();
+
+        let names = timings.iter().map(|t| &*t.name).collect::<Vec<_>>();
+        for name in [
+            "Compiler.parse_js",
+            "typescript",
+            "react",
+            "compat",
+            "hygiene",
+            "Compiler::print",
+        ] {
+            assert!(
+                names.contains(&name),
+                "{} is not recorded: {:?}",
+                name,
+                names
+            );
+        }
+
+        Ok(())
+    })
+    .unwrap()
+}
+
+#[test]
+fn transform_program_without_printing() {
+    testing::run_test2(false, |cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "// keep\nexport const a: number = 1;".into(),
+        );
+        let syntax = Syntax::Typescript(Default::default());
+        let comments = SingleThreadedComments::default();
+
+        let program = c
+            .parse_js(
+                fm.clone(),
+                &handler,
+                EsVersion::latest(),
+                syntax,
+                swc::config::IsModule::Bool(true),
+                Some(&comments),
+            )
+            .unwrap();
+
+        let ts_options = Options {
+            config: Config {
+                jsc: JscConfig {
+                    syntax: Some(syntax),
+                    target: Some(EsVersion::latest()),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let transformed = c
+            .transform_program(&handler, fm.clone(), program, &ts_options, comments.clone())
+            .unwrap();
+
+        // The second round does not parse the output of the first round.
+        let es5_options = Options {
+            config: Config {
+                jsc: JscConfig {
+                    target: Some(EsVersion::Es5),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let (transformed, timings) = swc::profile(|| {
+            c.transform_program(&handler, fm, transformed.program, &es5_options, comments)
+        });
+        let transformed = transformed.unwrap();
+        assert!(
+            timings.iter().all(|t| t.name != "Compiler.parse_js"),
+            "{:?}",
+            timings
+        );
+
+        let output = c.print_transformed(&transformed).unwrap();
+        assert_eq!(output.code.trim(), "// keep\nexport var a = 1;");
+
+        Ok(())
+    })
+    .unwrap()
+}
@@ -536,3 +536,59 @@ export const fixupRiskConfigData = (data: any): types.RiskConfigType => {
         Ok(())
     });
 }
+
+#[test]
+fn minify_with_scopes() {
+    Tester::new().print_errors(|cm, handler| {
+        let c = Compiler::new(cm.clone());
+        let fm = cm.new_source_file(
+            swc_common::FileName::Real("./add.js".into()).into(),
+            r#"function add(first, second) {
+    var total = first + second;
+    if (total) {
+        let doubled = total * 2;
+        console.log(doubled);
+    }
+    return total;
+}
+console.log(add(1, 2));
+"#
+            .to_string(),
+        );
+
+        let output = c
+            .minify(
+                fm,
+                &handler,
+                &serde_json::from_str(r#"{ "compress": false, "sourceMap": { "scopes": true } }"#)
+                    .unwrap(),
+                Default::default(),
+            )
+            .unwrap();
+
+        let map: serde_json::Value = serde_json::from_str(&output.map.unwrap()).unwrap();
+        let names = map["names"].as_array().unwrap();
+        let name = |idx: i64| names[idx as usize].as_str().unwrap().to_string();
+
+        assert_eq!(map["originalScopes"].as_array().unwrap().len(), 1);
+
+        // Bindings of the global scope, `add` and the `if` block.
+        let bindings = map["generatedRanges"]
+            .as_str()
+            .unwrap()
+            .split([',', ';'])
+            .map(|item| sourcemap::vlq::parse_vlq_segment(item).unwrap())
+            .filter(|item| item.len() > 2)
+            .map(|item| item[4..].iter().map(|&idx| name(idx)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            bindings,
+            vec![vec!["add"], vec!["o", "l", "n"], vec!["o"]],
+            "{}",
+            output.code
+        );
+
+        Ok(())
+    });
+}
@@ -1,20 +1,8 @@
 //// [ClassAndModuleThatMergeWithModuleMemberThatUsesClassTypeParameter.ts]
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var clodule1 = function clodule1() {
-    _class_call_check(this, clodule1);
-};
-clodule1 || (clodule1 = {});
-var clodule2 = function clodule2() {
-    _class_call_check(this, clodule2);
-};
-clodule2 || (clodule2 = {});
 var clodule3 = function clodule3() {
     _class_call_check(this, clodule3);
 };
 (clodule3 || (clodule3 = {})).y = {
     id: T
 };
-var clodule4 = function clodule4() {
-    _class_call_check(this, clodule4);
-};
-clodule4 || (clodule4 = {});
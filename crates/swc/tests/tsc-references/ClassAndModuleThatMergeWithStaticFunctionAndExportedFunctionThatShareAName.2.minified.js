@@ -1,6 +1,7 @@
 //// [ClassAndModuleThatMergeWithStaticFunctionAndExportedFunctionThatShareAName.ts]
+var A, A1;
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var A, A1, Point = /*#__PURE__*/ function() {
+(A1 = A || (A = {})).Point = /*#__PURE__*/ function() {
     function Point(x, y) {
         _class_call_check(this, Point), this.x = x, this.y = y;
     }
@@ -10,19 +11,6 @@ var A, A1, Point = /*#__PURE__*/ function() {
             y: 0
         };
     }, Point;
-}();
-(Point || (Point = {})).Origin = function() {
-    return null;
-}, (A = A1 || (A1 = {})).Point = /*#__PURE__*/ function() {
-    function Point(x, y) {
-        _class_call_check(this, Point), this.x = x, this.y = y;
-    }
-    return Point.Origin = function() {
-        return {
-            x: 0,
-            y: 0
-        };
-    }, Point;
-}(), (A.Point || (A.Point = {})).Origin = function() {
+}(), (A1.Point || (A1.Point = {})).Origin = function() {
     return "";
 };
@@ -1,9 +1,5 @@
 //// [class.ts]
-var X, X1;
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-((X1 = X || (X = {})).Y || (X1.Y = {})).Point = function Point(x, y) {
-    _class_call_check(this, Point), this.x = x, this.y = y;
-};
+import "@swc/helpers/_/_class_call_check";
 //// [module.ts]
 var X, X1, Y;
 ((Y = (X1 = X || (X = {})).Y || (X1.Y = {})).Point || (Y.Point = {})).Origin = new Y.Point(0, 0);
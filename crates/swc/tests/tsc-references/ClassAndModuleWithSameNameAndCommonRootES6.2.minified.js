@@ -1,10 +1,4 @@
 //// [class.ts]
-var X, X1;
-((X1 = X || (X = {})).Y || (X1.Y = {})).Point = class {
-    constructor(x, y){
-        this.x = x, this.y = y;
-    }
-};
 //// [module.ts]
 var X, X1, Y;
 ((Y = (X1 = X || (X = {})).Y || (X1.Y = {})).Point || (Y.Point = {})).Origin = new Y.Point(0, 0);
@@ -1,6 +1,2 @@
 //// [ExportClassWithInaccessibleTypeInIndexerTypeAnnotations.ts]
-var A;
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-(A || (A = {})).points = function points() {
-    _class_call_check(this, points);
-};
+import "@swc/helpers/_/_class_call_check";
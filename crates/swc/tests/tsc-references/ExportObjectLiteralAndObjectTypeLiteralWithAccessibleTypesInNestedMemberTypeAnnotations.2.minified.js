@@ -1,4 +1,2 @@
 //// [ExportObjectLiteralAndObjectTypeLiteralWithAccessibleTypesInNestedMemberTypeAnnotations.ts]
-var A;
 import "@swc/helpers/_/_class_call_check";
-(A || (A = {})).UnitSquare = null;
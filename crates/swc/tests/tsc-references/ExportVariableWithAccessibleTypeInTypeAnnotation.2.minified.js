@@ -1,6 +1 @@
 //// [ExportVariableWithAccessibleTypeInTypeAnnotation.ts]
-var A;
-(A || (A = {})).Origin = {
-    x: 0,
-    y: 0
-};
@@ -1,3 +1,2 @@
 //// [TypeGuardWithEnumUnion.ts]
 import "@swc/helpers/_/_type_of";
-var Color, Color1 = ((Color = Color1 || {})[Color.R = 0] = "R", Color[Color.G = 1] = "G", Color[Color.B = 2] = "B", Color);
@@ -1,9 +1,8 @@
 //// [additionOperatorWithAnyAndEveryType.ts]
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, M, C = /*#__PURE__*/ function() {
+new (/*#__PURE__*/ function() {
     function C() {
         _class_call_check(this, C);
     }
     return C.foo = function() {}, C;
-}(), E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E[E.c = 2] = "c", E);
-M || (M = {}), new C();
+}())();
@@ -1,3 +1 @@
 //// [ambientInsideNonAmbient.ts]
-var M, M2;
-M || (M = {}), M2 || (M2 = {});
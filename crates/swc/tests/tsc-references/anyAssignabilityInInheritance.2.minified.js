@@ -1,10 +1,4 @@
 //// [anyAssignabilityInInheritance.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-foo2(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a);
-var E, a, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-function f() {}
-foo3(a), (f || (f = {})).bar = 1, foo3(a);
-var CC = function CC() {
-    _class_call_check(this, CC);
-};
-(CC || (CC = {})).bar = 1, foo3(a), foo3(a), foo3(a);
+var a;
+import "@swc/helpers/_/_class_call_check";
+foo2(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a), foo3(a);
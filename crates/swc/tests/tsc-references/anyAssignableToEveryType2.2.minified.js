@@ -1,9 +1,2 @@
 //// [anyAssignableToEveryType2.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-function f() {}
-(f || (f = {})).bar = 1;
-var c = function c() {
-    _class_call_check(this, c);
-};
-(c || (c = {})).bar = 1;
+import "@swc/helpers/_/_class_call_check";
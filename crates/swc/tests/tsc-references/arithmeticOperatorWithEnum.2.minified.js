@@ -1,2 +1 @@
 //// [arithmeticOperatorWithEnum.ts]
-var E, E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E);
@@ -1,3 +1 @@
 //// [asiPreventsParsingAsNamespace05.ts]
-var a, a1;
-((a1 = a || (a = {})).b || (a1.b = {})).c = 20;
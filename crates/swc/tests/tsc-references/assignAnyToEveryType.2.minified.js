@@ -1,4 +1,2 @@
 //// [assignAnyToEveryType.ts]
 import "@swc/helpers/_/_class_call_check";
-var E, M, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-(M || (M = {})).foo = 1;
@@ -1,6 +1,6 @@
 //// [assignmentCompatWithDiscriminatedUnion.ts]
-var Example1, Example2, Example3, Example4, Example5, GH14865, GH30170, GH12052, GH18421, GH15907, GH20889, GH39357, b1;
+var Example1, Example2, Example3, Example4, Example5, GH14865, GH12052, GH15907, GH39357, b1;
 function getAxisType() {
     return "categorical";
 }
-Example1 || (Example1 = {}), t = s, Example2 || (Example2 = {}), t = s, Example3 || (Example3 = {}), t = s, Example4 || (Example4 = {}), t = s, Example5 || (Example5 = {}), t = s, GH14865 || (GH14865 = {}), b1.type, GH30170 || (GH30170 = {}), GH12052 || (GH12052 = {}), getAxisType(), getAxisType(), GH18421 || (GH18421 = {}), GH15907 || (GH15907 = {}), GH20889 || (GH20889 = {}), GH39357 || (GH39357 = {}), ("a" === b || "b" === b) && b;
+Example1 || (Example1 = {}), t = s, Example2 || (Example2 = {}), t = s, Example3 || (Example3 = {}), t = s, Example4 || (Example4 = {}), t = s, Example5 || (Example5 = {}), t = s, GH14865 || (GH14865 = {}), b1.type, GH12052 || (GH12052 = {}), getAxisType(), getAxisType(), GH15907 || (GH15907 = {}), GH39357 || (GH39357 = {}), ("a" === b || "b" === b) && b;
@@ -1,2 +1 @@
 //// [assignmentCompatWithEnumIndexer.ts]
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
@@ -1,4 +1,2 @@
 //// [assignmentCompatWithGenericCallSignaturesWithOptionalParameters.ts]
-var ClassTypeParam, GenericSignaturesInvalid, GenericSignaturesValid;
 import "@swc/helpers/_/_class_call_check";
-ClassTypeParam || (ClassTypeParam = {}), GenericSignaturesInvalid || (GenericSignaturesInvalid = {}), GenericSignaturesValid || (GenericSignaturesValid = {});
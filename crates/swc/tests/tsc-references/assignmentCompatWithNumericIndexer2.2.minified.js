@@ -1,3 +1 @@
 //// [assignmentCompatWithNumericIndexer2.ts]
-var Generics;
-Generics || (Generics = {});
@@ -1,6 +1,4 @@
 //// [assignmentCompatWithNumericIndexer3.ts]
-var Generics;
 import { _ as _call_super } from "@swc/helpers/_/_call_super";
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _inherits } from "@swc/helpers/_/_inherits";
-Generics || (Generics = {});
@@ -1,4 +1,2 @@
 //// [assignmentCompatWithStringIndexer3.ts]
-var Generics;
 import "@swc/helpers/_/_class_call_check";
-Generics || (Generics = {});
@@ -1,22 +1,22 @@
 //// [assignmentToParenthesizedIdentifiers.ts]
+var M, M2, M21;
 import "@swc/helpers/_/_class_call_check";
 M || (M = {}), M.y = 3, M.y = 3, M.y = 3, M.y = '', M.y = '', M.y = '', M = {
     y: 3
 }, M = {
     y: 3
-}, (M2 = M21 || (M21 = {})).M3 || (M2.M3 = {}), M2.M3 = {
+}, (M21 = M2 || (M2 = {})).M3 || (M21.M3 = {}), M21.M3 = {
     x: 3
-}, M21.M3 = {
+}, M2.M3 = {
     x: 3
-}, M21.M3 = {
+}, M2.M3 = {
     x: 3
-}, M21.M3 = {
+}, M2.M3 = {
     x: 3
-}, M21.M3 = {
+}, M2.M3 = {
     x: ''
-}, M21.M3 = {
+}, M2.M3 = {
     x: ''
-}, M21.M3 = {
+}, M2.M3 = {
     x: ''
 };
-var M2, E, M, M21, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
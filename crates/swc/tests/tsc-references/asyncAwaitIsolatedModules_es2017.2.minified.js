@@ -1,4 +1,2 @@
 //// [asyncAwaitIsolatedModules_es2017.ts]
-var M;
-(M || (M = {})).f1 = async function() {};
 export { };
@@ -1,6 +1,2 @@
 //// [asyncAwaitIsolatedModules_es6.ts]
-var M;
-import { _ as _async_to_generator } from "@swc/helpers/_/_async_to_generator";
-(M || (M = {})).f1 = function() {
-    return /*#__PURE__*/ _async_to_generator(function*() {})();
-};
+import "@swc/helpers/_/_async_to_generator";
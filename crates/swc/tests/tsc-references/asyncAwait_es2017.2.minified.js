@@ -1,3 +1 @@
 //// [asyncAwait_es2017.ts]
-var M;
-(M || (M = {})).f1 = async function() {};
@@ -1,14 +1,4 @@
 //// [asyncAwait_es5.ts]
-var M;
 import { _ as _async_to_generator } from "@swc/helpers/_/_async_to_generator";
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _ts_generator } from "@swc/helpers/_/_ts_generator";
-(M || (M = {})).f1 = function() {
-    return /*#__PURE__*/ _async_to_generator(function() {
-        return _ts_generator(this, function(_state) {
-            return [
-                2
-            ];
-        });
-    })();
-};
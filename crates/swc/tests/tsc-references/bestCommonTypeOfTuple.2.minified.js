@@ -1,2 +1 @@
 //// [bestCommonTypeOfTuple.ts]
-var E1, E2, E11 = ((E1 = E11 || {})[E1.one = 0] = "one", E1), E21 = ((E2 = E21 || {})[E2.two = 0] = "two", E2);
@@ -1,4 +1,2 @@
 //// [classAndInterfaceWithSameName.ts]
-var M;
 import "@swc/helpers/_/_class_call_check";
-M || (M = {});
@@ -1,2 +1 @@
 //// [comparisonOperatorWithOneOperandIsUndefined.ts]
-var E, E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E[E.c = 2] = "c", E);
@@ -1,3 +1,3 @@
 //// [compoundAdditionAssignmentLHSCanBeAssigned.ts]
-var E, a, b, x1, x2, x3, x4, x6, E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E);
+var a, b, x1, x2, x3, x4, x6;
 x1 += a, x1 += b, x1 += !0, x1 += 0, x1 += '', x1 += 0, x1 += {}, x1 += null, x1 += void 0, x2 += a, x2 += b, x2 += !0, x2 += 0, x2 += '', x2 += 0, x2 += {}, x2 += null, x2 += void 0, x3 += a, x3 += 0, x3 += 0, x3 += null, x3 += void 0, x4 += a, x4 += 0, x4 += 0, x4 += null, x4 += void 0, x6 += a, x6 += '';
@@ -1,4 +1,3 @@
 //// [computedPropertyNames47_ES5.ts]
 import { _ as _define_property } from "@swc/helpers/_/_define_property";
-var E1, E2, E11 = ((E1 = E11 || {})[E1.x = 0] = "x", E1), E21 = ((E2 = E21 || {})[E2.x = 0] = "x", E2);
 _define_property({}, 0, 0);
@@ -1,2 +1 @@
 //// [computedPropertyNames47_ES6.ts]
-var E1, E2, E11 = ((E1 = E11 || {})[E1.x = 0] = "x", E1), E21 = ((E2 = E21 || {})[E2.x = 0] = "x", E2);
@@ -1,4 +1,4 @@
 //// [computedPropertyNames48_ES5.ts]
+var a;
 import { _ as _define_property } from "@swc/helpers/_/_define_property";
-var E, a, E1 = ((E = E1 || {})[E.x = 0] = "x", E);
 extractIndexer(_define_property({}, a, "")), extractIndexer(_define_property({}, 0, "")), extractIndexer(_define_property({}, 0, ""));
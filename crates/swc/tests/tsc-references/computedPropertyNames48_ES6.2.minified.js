@@ -1,7 +1,6 @@
 //// [computedPropertyNames48_ES6.ts]
-var E, a, E1 = ((E = E1 || {})[E.x = 0] = "x", E);
 extractIndexer({
-    [a]: ""
+    [void 0]: ""
 }), extractIndexer({
     0: ""
 }), extractIndexer({
@@ -1,4 +1,3 @@
 //// [computedPropertyNames7_ES5.ts]
 import { _ as _define_property } from "@swc/helpers/_/_define_property";
-var E, E1 = ((E = E1 || {})[E.member = 0] = "member", E);
 _define_property({}, 0, 0);
@@ -1,2 +1 @@
 //// [computedPropertyNames7_ES6.ts]
-var E, E1 = ((E = E1 || {})[E.member = 0] = "member", E);
@@ -1,7 +1,9 @@
 //// [decoratorOnClassMethod18.ts]
 import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate";
+import { _ as _ts_metadata } from "@swc/helpers/_/_ts_metadata";
 class Foo {
 }
 _ts_decorate([
-    decorator()
+    decorator(),
+    _ts_metadata("design:type", Object)
 ], Foo.prototype, "p2", void 0);
@@ -1,6 +1,8 @@
 //// [decoratorOnClassMethod18.ts]
 import { _ as _ts_decorate } from "@swc/helpers/_/_ts_decorate";
+import { _ as _ts_metadata } from "@swc/helpers/_/_ts_metadata";
 _ts_decorate([
-    decorator()
+    decorator(),
+    _ts_metadata("design:type", Object)
 ], (class {
 }).prototype, "p2", void 0);
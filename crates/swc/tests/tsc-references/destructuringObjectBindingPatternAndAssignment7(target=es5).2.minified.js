@@ -1,4 +1,4 @@
 //// [destructuringObjectBindingPatternAndAssignment7.ts]
 import { _ as _define_property } from "@swc/helpers/_/_define_property";
-var K, _obj, K1 = ((K = K1 || {}).a = "a", K.b = "b", K), _ref = (_define_property(_obj = {}, "a", 1), _define_property(_obj, "b", 1), _obj);
+var _obj, _ref = (_define_property(_obj = {}, "a", 1), _define_property(_obj, "b", 1), _obj);
 console.log(_ref.a, _ref.b);
@@ -1,5 +1,4 @@
 //// [destructuringObjectBindingPatternAndAssignment7.ts]
-var K, K1 = ((K = K1 || {}).a = "a", K.b = "b", K);
 const { a: aVal, b: bVal } = {
     a: 1,
     b: 1
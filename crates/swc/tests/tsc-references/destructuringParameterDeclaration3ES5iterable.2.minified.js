@@ -6,7 +6,7 @@ function a10(param) {
     var _param = _to_array(param), _param_ = (_param[0], _param[1], _sliced_to_array(_param[2], 1));
     _sliced_to_array(_param_[0], 1)[0], _param.slice(3);
 }
-var _param, _param_, _param1, E, array = [
+var _param, _param_, _param1, array = [
     1,
     2,
     3
@@ -14,6 +14,9 @@ var _param, _param_, _param1, E, array = [
 function foo() {
     for(var _len = arguments.length, a = Array(_len), _key = 0; _key < _len; _key++)a[_key] = arguments[_key];
 }
+function foo1() {
+    for(var _len = arguments.length, a = Array(_len), _key = 0; _key < _len; _key++)a[_key] = arguments[_key];
+}
 !function() {
     for(var _len = arguments.length, a = Array(_len), _key = 0; _key < _len; _key++)a[_key] = arguments[_key];
 }(_to_consumable_array(array)), (function() {
@@ -50,9 +53,4 @@ function foo() {
 ]), (_param1 = _to_array([
     1,
     2
-]))[0], _param1[1], _param1[2], _param1.slice(3), foo("hello", 1, 2), foo("hello", "world");
-var E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E);
-function foo1() {
-    for(var _len = arguments.length, a = Array(_len), _key = 0; _key < _len; _key++)a[_key] = arguments[_key];
-}
-foo1(1, 2, 3, 0), foo1(1, 2, 3, 0, 1);
+]))[0], _param1[1], _param1[2], _param1.slice(3), foo("hello", 1, 2), foo("hello", "world"), foo1(1, 2, 3, 0), foo1(1, 2, 3, 0, 1);
@@ -1,5 +1,5 @@
 //// [destructuringParameterDeclaration3ES6.ts]
-var E, array = [
+var array = [
     1,
     2,
     3
@@ -20,4 +20,3 @@ var E, array = [
     1,
     2
 ]);
-var E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E);
@@ -1,4 +1,2 @@
 //// [duplicateStringIndexers.ts]
-var test;
 import "@swc/helpers/_/_class_call_check";
-test || (test = {});
@@ -1,4 +1,4 @@
 //// [enumAssignability.ts]
+var Others;
 import "@swc/helpers/_/_class_call_check";
-var F, E, Others, E1 = ((E = E1 || {})[E.A = 0] = "A", E), F1 = ((F = F1 || {})[F.B = 0] = "B", F);
 Others || (Others = {});
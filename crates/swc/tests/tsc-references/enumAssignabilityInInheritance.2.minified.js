@@ -1,11 +1,3 @@
 //// [enumAssignabilityInInheritance.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, E2, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-foo(0), foo(1), foo(null), foo2(0), foo3(0), foo4(0), foo5(0), foo6(0), foo7(0), foo8(0), foo9(0), foo10(0), foo11(0), foo12(0);
-var E21 = ((E2 = E21 || {})[E2.A = 0] = "A", E2);
-function f() {}
-foo13(0), (f || (f = {})).bar = 1, foo14(0);
-var CC = function CC() {
-    _class_call_check(this, CC);
-};
-(CC || (CC = {})).bar = 1, foo15(0), foo16(0), foo16(0);
+import "@swc/helpers/_/_class_call_check";
+foo(0), foo(1), foo(null), foo2(0), foo3(0), foo4(0), foo5(0), foo6(0), foo7(0), foo8(0), foo9(0), foo10(0), foo11(0), foo12(0), foo13(0), foo14(0), foo15(0), foo16(0), foo16(0);
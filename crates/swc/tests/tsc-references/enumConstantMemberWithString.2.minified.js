@@ -1,2 +1 @@
 //// [enumConstantMemberWithString.ts]
-var T1, T2, T3, T4, T5, T11 = ((T1 = T11 || {}).a = "1", T1.b = "12", T1.c = "123", T1[T1.d = NaN] = "d", T1.e = "a1", T1), T21 = ((T2 = T21 || {}).a = "1", T2.b = "12", T2), T31 = ((T3 = T31 || {}).a = "1", T3.b = "12", T3[T3.c = 1] = "c", T3[T3.d = 3] = "d", T3), T41 = ((T4 = T41 || {}).a = "1", T4), T51 = ((T5 = T51 || {}).a = "12", T5);
@@ -1,2 +1,2 @@
 //// [enumConstantMemberWithTemplateLiteralsEmitDeclaration.ts]
-var T1, T2, T3, T4, T5, T6, T11 = ((T1 = T11 || {}).a = "1", T1), T21 = ((T2 = T21 || {}).a = "1", T2.b = "2", T2[T2.c = 3] = "c", T2), T31 = ((T3 = T31 || {}).a = "11", T3), T41 = ((T4 = T41 || {}).a = "1", T4.b = "11", T4.c = "12", T4.d = "21", T4.e = "211", T4), T51 = ((T5 = T51 || {}).a = "1", T5.b = "12", T5.c = "123", T5[T5.d = 1] = "d", T5), T61 = ((T6 = T61 || {})[T6.a = 1] = "a", T6[T6.b = 2] = "b", T6);
+var T6, T61 = ((T6 = T61 || {})[T6.a = 1] = "a", T6[T6.b = 2] = "b", T6);
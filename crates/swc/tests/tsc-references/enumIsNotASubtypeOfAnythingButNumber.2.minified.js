@@ -1,9 +1,2 @@
 //// [enumIsNotASubtypeOfAnythingButNumber.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, E2, E1 = ((E = E1 || {})[E.A = 0] = "A", E), E21 = ((E2 = E21 || {})[E2.A = 0] = "A", E2);
-function f() {}
-(f || (f = {})).bar = 1;
-var c = function c() {
-    _class_call_check(this, c);
-};
-(c || (c = {})).bar = 1;
+import "@swc/helpers/_/_class_call_check";
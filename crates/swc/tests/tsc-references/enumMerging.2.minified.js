@@ -1,3 +1,3 @@
 //// [enumMerging.ts]
-var M1, M2, M3, M4, M5, M6, M11, EImpl1, EConst1, EConst11, M21, EComp2, EComp21, EInit, M41, Color, M51, Color1, M61, A, Color2, M62, A1, Color3;
-M11 = M1 || (M1 = {}), ((EImpl1 = {})[EImpl1.A = 0] = "A", EImpl1[EImpl1.B = 1] = "B", EImpl1[EImpl1.C = 2] = "C", EImpl1)[EImpl1.D = 1] = "D", EImpl1[EImpl1.E = 2] = "E", EImpl1[EImpl1.F = 3] = "F", (EConst1 = M11.EConst1 || (M11.EConst1 = {}))[EConst1.A = 3] = "A", EConst1[EConst1.B = 2] = "B", EConst1[EConst1.C = 1] = "C", (EConst11 = M11.EConst1)[EConst11.D = 7] = "D", EConst11[EConst11.E = 9] = "E", EConst11[EConst11.F = 8] = "F", (EComp2 = (M21 = M2 || (M2 = {})).EComp2 || (M21.EComp2 = {}))[EComp2.A = 3] = "A", EComp2[EComp2.B = 3] = "B", EComp2[EComp2.C = 3] = "C", (EComp21 = M21.EComp2)[EComp21.D = 3] = "D", EComp21[EComp21.E = 3] = "E", EComp21[EComp21.F = 3] = "F", M21.EComp2.A, M21.EComp2.B, M21.EComp2.C, M21.EComp2.D, M21.EComp2.E, M21.EComp2.F, M3 || (M3 = {}), ((EInit = {})[EInit.A = 0] = "A", EInit[EInit.B = 1] = "B", EInit)[EInit.C = 1] = "C", EInit[EInit.D = 2] = "D", EInit[EInit.E = 3] = "E", (Color = (M41 = M4 || (M4 = {})).Color || (M41.Color = {}))[Color.Red = 0] = "Red", Color[Color.Green = 1] = "Green", Color[Color.Blue = 2] = "Blue", (Color1 = (M51 = M5 || (M5 = {})).Color || (M51.Color = {}))[Color1.Red = 0] = "Red", Color1[Color1.Green = 1] = "Green", Color1[Color1.Blue = 2] = "Blue", (Color2 = (A = (M61 = M6 || (M6 = {})).A || (M61.A = {})).Color || (A.Color = {}))[Color2.Red = 0] = "Red", Color2[Color2.Green = 1] = "Green", Color2[Color2.Blue = 2] = "Blue", (Color3 = (A1 = (M62 = M6 || (M6 = {})).A || (M62.A = {})).Color || (A1.Color = {}))[Color3.Yellow = 1] = "Yellow", M62.A.Color.Yellow, M62.A.Color.Red;
+var M1, M2, M3, M6, M11, EImpl1, EConst1, EConst11, M21, EComp2, EComp21, EInit, M61, A, Color, M62, A1, Color1;
+M11 = M1 || (M1 = {}), ((EImpl1 = {})[EImpl1.A = 0] = "A", EImpl1[EImpl1.B = 1] = "B", EImpl1[EImpl1.C = 2] = "C", EImpl1)[EImpl1.D = 1] = "D", EImpl1[EImpl1.E = 2] = "E", EImpl1[EImpl1.F = 3] = "F", (EConst1 = M11.EConst1 || (M11.EConst1 = {}))[EConst1.A = 3] = "A", EConst1[EConst1.B = 2] = "B", EConst1[EConst1.C = 1] = "C", (EConst11 = M11.EConst1)[EConst11.D = 7] = "D", EConst11[EConst11.E = 9] = "E", EConst11[EConst11.F = 8] = "F", (EComp2 = (M21 = M2 || (M2 = {})).EComp2 || (M21.EComp2 = {}))[EComp2.A = 3] = "A", EComp2[EComp2.B = 3] = "B", EComp2[EComp2.C = 3] = "C", (EComp21 = M21.EComp2)[EComp21.D = 3] = "D", EComp21[EComp21.E = 3] = "E", EComp21[EComp21.F = 3] = "F", M21.EComp2.A, M21.EComp2.B, M21.EComp2.C, M21.EComp2.D, M21.EComp2.E, M21.EComp2.F, M3 || (M3 = {}), ((EInit = {})[EInit.A = 0] = "A", EInit[EInit.B = 1] = "B", EInit)[EInit.C = 1] = "C", EInit[EInit.D = 2] = "D", EInit[EInit.E = 3] = "E", (Color = (A = (M61 = M6 || (M6 = {})).A || (M61.A = {})).Color || (A.Color = {}))[Color.Red = 0] = "Red", Color[Color.Green = 1] = "Green", Color[Color.Blue = 2] = "Blue", (Color1 = (A1 = (M62 = M6 || (M6 = {})).A || (M62.A = {})).Color || (A1.Color = {}))[Color1.Yellow = 1] = "Yellow", M62.A.Color.Yellow, M62.A.Color.Red;
@@ -1,3 +1,3 @@
 //// [enumMergingErrors.ts]
-var M, M1, M2, M3, E1, E2, E3, M4, E11, E21, E31, M5, E12, E22, E32, M11, E13, M12, E14, M13, E15, M21, E16, M22, E17, M23, E18;
-(E1 = (M3 = M || (M = {})).E1 || (M3.E1 = {}))[E1.A = 0] = "A", (E2 = M3.E2 || (M3.E2 = {}))[E2.C = 0] = "C", (E3 = M3.E3 || (M3.E3 = {}))[E3.A = 0] = "A", (E11 = (M4 = M || (M = {})).E1 || (M4.E1 = {}))[E11.B = 3] = "B", (E21 = M4.E2 || (M4.E2 = {}))[E21.B = 3] = "B", (E31 = M4.E3 || (M4.E3 = {}))[E31.C = 0] = "C", (E12 = (M5 = M || (M = {})).E1 || (M5.E1 = {}))[E12.C = 0] = "C", (E22 = M5.E2 || (M5.E2 = {}))[E22.A = 0] = "A", (E32 = M5.E3 || (M5.E3 = {}))[E32.B = 3] = "B", (E13 = (M11 = M1 || (M1 = {})).E1 || (M11.E1 = {}))[E13.A = 0] = "A", (E14 = (M12 = M1 || (M1 = {})).E1 || (M12.E1 = {}))[E14.B = 0] = "B", (E15 = (M13 = M1 || (M1 = {})).E1 || (M13.E1 = {}))[E15.C = 0] = "C", (E16 = (M21 = M2 || (M2 = {})).E1 || (M21.E1 = {}))[E16.A = 0] = "A", (E17 = (M22 = M2 || (M2 = {})).E1 || (M22.E1 = {}))[E17.B = 0] = "B", (E18 = (M23 = M2 || (M2 = {})).E1 || (M23.E1 = {}))[E18.C = 0] = "C";
+var M, M1, E1, E2, E3, M2, E11, E21, E31, M3, E12, E22, E32;
+(E1 = (M1 = M || (M = {})).E1 || (M1.E1 = {}))[E1.A = 0] = "A", (E2 = M1.E2 || (M1.E2 = {}))[E2.C = 0] = "C", (E3 = M1.E3 || (M1.E3 = {}))[E3.A = 0] = "A", (E11 = (M2 = M || (M = {})).E1 || (M2.E1 = {}))[E11.B = 3] = "B", (E21 = M2.E2 || (M2.E2 = {}))[E21.B = 3] = "B", (E31 = M2.E3 || (M2.E3 = {}))[E31.C = 0] = "C", (E12 = (M3 = M || (M = {})).E1 || (M3.E1 = {}))[E12.C = 0] = "C", (E22 = M3.E2 || (M3.E2 = {}))[E22.A = 0] = "A", (E32 = M3.E3 || (M3.E3 = {}))[E32.B = 3] = "B";
@@ -1,2 +1 @@
 //// [exponentiationOperatorWithEnumUnion.ts]
-var E, F, E1 = ((E = E1 || {})[E.a = 0] = "a", E[E.b = 1] = "b", E), F1 = ((F = F1 || {})[F.c = 0] = "c", F[F.d = 1] = "d", F);
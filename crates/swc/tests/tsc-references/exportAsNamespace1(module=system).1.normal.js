@@ -20,10 +20,10 @@ System.register([
         setters: [
             function(_0) {
                 _ns = _0;
+                _export("ns", _ns);
             }
         ],
         execute: function() {
-            _export("_ns", ns);
             ns.a;
             ns.b;
         }
@@ -13,10 +13,12 @@ System.register([
 ], function(_export, _context) {
     return {
         setters: [
-            function(_0) {}
+            function(_0) {
+                _export("ns", _0);
+            }
         ],
         execute: function() {
-            _export("_ns", ns), ns.a, ns.b;
+            ns.a, ns.b;
         }
     };
 });
@@ -14,10 +14,12 @@ System.register([
     var ns;
     return {
         setters: [
-            function(_0) {}
+            function(_0) {
+                _export("ns", _0);
+            }
         ],
         execute: function() {
-            _export("_ns", ns), ns.a, ns.b, (ns = {
+            ns.a, ns.b, (ns = {
                 a: 1,
                 b: 2
             }).a, ns.b;
@@ -20,11 +20,10 @@ System.register([
         setters: [
             function(_0) {
                 _default = _0;
+                _export("default", _default);
             }
         ],
-        execute: function() {
-            _export("_default", default);
-        }
+        execute: function() {}
     };
 });
 //// [11.ts]
@@ -13,11 +13,11 @@ System.register([
 ], function(_export, _context) {
     return {
         setters: [
-            function(_0) {}
+            function(_0) {
+                _export("default", _0);
+            }
         ],
-        execute: function() {
-            _export("_default", default);
-        }
+        execute: function() {}
     };
 });
 //// [11.ts]
@@ -1,8 +1,2 @@
 //// [exportCodeGen.ts]
-var A, B, C, D, E, F, E1, Color, M;
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-(A || (A = {})).x = 12, B || (B = {}), C || (C = {}), (D || (D = {})).yes = function() {
-    return !0;
-}, (Color = (E1 = E || (E = {})).Color || (E1.Color = {}))[Color.Red = 0] = "Red", E1.fn = function() {}, E1.C = function C() {
-    _class_call_check(this, C);
-}, (E1.M || (E1.M = {})).x = 42, F || (F = {}), M || (M = {});
+import "@swc/helpers/_/_class_call_check";
@@ -1,5 +1,5 @@
 //// [for-of48.ts]
-var x, y, E, E1 = ((E = E1 || {})[E.x = 0] = "x", E);
+var x, y;
 for ({ x, y = 0 } of [
     {
         x: "",
@@ -1,3 +1,2 @@
 //// [functionExpressionContextualTyping1.ts]
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, E1 = ((E = E1 || {})[E.red = 0] = "red", E[E.blue = 1] = "blue", E);
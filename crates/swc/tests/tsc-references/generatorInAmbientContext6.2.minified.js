@@ -1,3 +1 @@
 //// [generatorInAmbientContext6.ts]
-var M;
-(M || (M = {})).generator = function*() {};
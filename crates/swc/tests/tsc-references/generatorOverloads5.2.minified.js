@@ -1,3 +1 @@
 //// [generatorOverloads5.ts]
-var M;
-M || (M = {});
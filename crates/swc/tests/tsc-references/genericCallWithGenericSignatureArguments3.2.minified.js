@@ -1,2 +1 @@
 //// [genericCallWithGenericSignatureArguments3.ts]
-var F, E, E1 = ((E = E1 || {})[E.A = 0] = "A", E), F1 = ((F = F1 || {})[F.A = 0] = "A", F);
@@ -1,3 +1 @@
 //// [globalThisAmbientModules.ts]
-var valueModule;
-(valueModule || (valueModule = {})).val = 1;
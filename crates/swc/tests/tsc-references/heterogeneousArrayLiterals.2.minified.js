@@ -2,7 +2,7 @@
 import { _ as _call_super } from "@swc/helpers/_/_call_super";
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _inherits } from "@swc/helpers/_/_inherits";
-var WithContextualType, Derived = /*#__PURE__*/ function(Base) {
+var Derived = /*#__PURE__*/ function(Base) {
     function Derived() {
         return _class_call_check(this, Derived), _call_super(this, Derived, arguments);
     }
@@ -10,4 +10,4 @@ var WithContextualType, Derived = /*#__PURE__*/ function(Base) {
 }(function Base() {
     _class_call_check(this, Base);
 });
-Derived || (Derived = {}), WithContextualType || (WithContextualType = {});
+Derived || (Derived = {});
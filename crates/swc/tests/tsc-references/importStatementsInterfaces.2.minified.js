@@ -1,5 +1 @@
 //// [importStatementsInterfaces.ts]
-var C, D, E;
-C || (C = {}), D || (D = {}), (E || (E = {})).xDist = function(x) {
-    return 0 - x.x;
-};
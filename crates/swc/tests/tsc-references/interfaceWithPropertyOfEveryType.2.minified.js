@@ -1,7 +1,6 @@
 //// [interfaceWithPropertyOfEveryType.ts]
+var M;
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-(M || (M = {})).y = 1;
-var M, E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-new function C() {
+(M || (M = {})).y = 1, new function C() {
     _class_call_check(this, C);
 }();
@@ -1,4 +1,2 @@
 //// [invalidBooleanAssignments.ts]
 import "@swc/helpers/_/_class_call_check";
-var M, E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-(M || (M = {})).a = 1;
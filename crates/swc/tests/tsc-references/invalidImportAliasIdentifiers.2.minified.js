@@ -1,3 +1,2 @@
 //// [invalidImportAliasIdentifiers.ts]
 import "@swc/helpers/_/_class_call_check";
-var E, E1 = ((E = E1 || {})[E.Red = 0] = "Red", E[E.Blue = 1] = "Blue", E);
@@ -1,4 +1,2 @@
 //// [invalidNumberAssignments.ts]
-var M;
 import "@swc/helpers/_/_class_call_check";
-(M || (M = {})).x = 1;
@@ -1,4 +1,2 @@
 //// [invalidStringAssignments.ts]
 import "@swc/helpers/_/_class_call_check";
-(M || (M = {})).x = 1, M = '';
-var M, E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
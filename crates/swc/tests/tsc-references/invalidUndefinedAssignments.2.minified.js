@@ -1,4 +1,4 @@
 //// [invalidUndefinedAssignments.ts]
 import "@swc/helpers/_/_class_call_check";
-var E, x, M, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-(E1 = x).A = x, I = x, (M || (M = {})).x = 1;
+var E, x, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
+(E1 = x).A = x, I = x;
@@ -1,4 +1,3 @@
 //// [invalidVoidAssignments.ts]
 import "@swc/helpers/_/_class_call_check";
-(M || (M = {})).x = 1;
-var E, M = void 0, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
+var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
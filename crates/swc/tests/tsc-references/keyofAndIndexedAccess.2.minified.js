@@ -27,4 +27,3 @@ one(function() {}), on({
 }(function SampleClass(props) {
     _class_call_check(this, SampleClass), this.props = Object.freeze(props);
 }))({});
-var Flag, Flag1 = ((Flag = Flag1 || {}).FLAG_1 = "flag_1", Flag.FLAG_2 = "flag_2", Flag);
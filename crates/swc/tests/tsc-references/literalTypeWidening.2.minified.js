@@ -11,12 +11,10 @@ export function Set() {
 export function keys(obj) {
     return Object.keys(obj);
 }
-var E, langCodeSet = Set('fr', 'en', 'es', 'it', 'nl');
+var langCodeSet = Set('fr', 'en', 'es', 'it', 'nl');
 export var langCodes = keys(langCodeSet);
 langCodes.map(function(code) {
     return {
         code: code
     };
-});
-var E1 = ((E = E1 || {})[E.A = 0] = "A", E[E.B = 1] = "B", E);
-f(0);
+}), f(0);
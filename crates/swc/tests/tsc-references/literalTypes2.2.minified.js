@@ -1,6 +1,6 @@
 //// [literalTypes2.ts]
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, result, E1 = ((E = E1 || {})[E.A = 0] = "A", E[E.B = 1] = "B", E[E.C = 2] = "C", E), a = [
+var result, a = [
     1,
     2
 ];
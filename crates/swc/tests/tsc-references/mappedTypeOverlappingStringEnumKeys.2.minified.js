@@ -1,2 +1 @@
 //// [mappedTypeOverlappingStringEnumKeys.ts]
-var TerrestrialAnimalTypes, AlienAnimalTypes, TerrestrialAnimalTypes1 = ((TerrestrialAnimalTypes = TerrestrialAnimalTypes1 || {}).CAT = "cat", TerrestrialAnimalTypes.DOG = "dog", TerrestrialAnimalTypes), AlienAnimalTypes1 = ((AlienAnimalTypes = AlienAnimalTypes1 || {}).CAT = "cat", AlienAnimalTypes);
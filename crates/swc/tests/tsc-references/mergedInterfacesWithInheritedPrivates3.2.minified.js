@@ -1,6 +1,4 @@
 //// [mergedInterfacesWithInheritedPrivates3.ts]
-var M;
 import { _ as _call_super } from "@swc/helpers/_/_call_super";
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _inherits } from "@swc/helpers/_/_inherits";
-M || (M = {});
@@ -1,4 +1,4 @@
 //// [mergedInterfacesWithMultipleBases.ts]
-var a, M;
+var a;
 import "@swc/helpers/_/_class_call_check";
-a.a, M || (M = {});
+a.a;
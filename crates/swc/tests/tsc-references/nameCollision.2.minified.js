@@ -1,4 +1,2 @@
 //// [nameCollision.ts]
-var A, B, X, Y, D, X1, Y1, Y2, Y3, Y4;
 import "@swc/helpers/_/_class_call_check";
-A || (A = {}), B || (B = {}), B || (B = {}), (Y1 = (X1 = X || (X = {})).Y || (X1.Y = {})).Z || (Y1.Z = {}), (Y4 = (Y3 = (Y2 = Y || (Y = {})).Y || (Y2.Y = {})).Y || (Y3.Y = {}))[Y4.Red = 0] = "Red", Y4[Y4.Blue = 1] = "Blue", (D || (D = {})).E = 'hello';
@@ -1,3 +1,3 @@
 //// [nestedModules.ts]
-var A, M2, A1, M21;
-(A = A1 || (A1 = {})).B || (A.B = {}), (M2 = M21 || (M21 = {})).X || (M2.X = {}), M21.X.Point;
+var M2, M21;
+(M2 = M21 || (M21 = {})).X || (M2.X = {}), M21.X.Point;
@@ -1,12 +1,5 @@
 //// [nonInstantiatedModule.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import "@swc/helpers/_/_class_call_check";
 (M || (M = {})).a = 1;
-var M2, M, M21, M3, m = M;
-M.a, m.a, ((M2 = M21 || (M21 = {})).Point || (M2.Point = {})).Origin = function() {
-    return {
-        x: 0,
-        y: 0
-    };
-}, (M3 || (M3 = {})).Utils = function Utils() {
-    _class_call_check(this, Utils);
-};
+var M, m = M;
+M.a, m.a;
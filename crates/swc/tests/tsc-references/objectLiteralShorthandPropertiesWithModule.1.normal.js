@@ -2,10 +2,10 @@
 // module export
 (function(m) {})(m || (m = {}));
 (function(m) {
-    var z = x;
+    var z = m.x;
     var y = {
-        a: x,
-        x: x
+        a: m.x,
+        x: m.x
     };
 })(m || (m = {}));
 var m;
@@ -1,10 +1,10 @@
 //// [objectLiteralShorthandPropertiesWithModuleES6.ts]
 (function(m) {})(m || (m = {}));
 (function(m) {
-    var z = x;
+    var z = m.x;
     var y = {
-        a: x,
-        x
+        a: m.x,
+        x: m.x
     };
 })(m || (m = {}));
 var m;
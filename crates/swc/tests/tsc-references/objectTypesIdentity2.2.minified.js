@@ -1,3 +1,2 @@
 //// [objectTypesIdentity2.ts]
 import "@swc/helpers/_/_class_call_check";
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
@@ -1,2 +1 @@
 //// [parserEnum6.ts]
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E[E.B = 1] = "B", E[E.C = 2] = "C", E);
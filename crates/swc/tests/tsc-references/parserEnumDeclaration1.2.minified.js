@@ -1,2 +1 @@
 //// [parserEnumDeclaration1.ts]
-var E, E1 = ((E = E1 || {})[E.Foo = 1] = "Foo", E[E.Bar = 2] = "Bar", E);
@@ -1,2 +1 @@
 //// [parserEnumDeclaration6.ts]
-var E, E1 = ((E = E1 || {})[E.A = 1] = "A", E[E.B = 2] = "B", E[E.C = 2] = "C", E[E.D = 3] = "D", E);
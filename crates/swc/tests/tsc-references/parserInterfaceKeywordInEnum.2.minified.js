@@ -1,2 +1 @@
 //// [parserInterfaceKeywordInEnum.ts]
-var Bar, Bar1 = ((Bar = Bar1 || {})[Bar.interface = 0] = "interface", Bar);
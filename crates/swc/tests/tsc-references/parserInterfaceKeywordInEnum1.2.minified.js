@@ -1,2 +1 @@
 //// [parserInterfaceKeywordInEnum1.ts]
-var Bar, Bar1 = ((Bar = Bar1 || {})[Bar.interface = 0] = "interface", Bar);
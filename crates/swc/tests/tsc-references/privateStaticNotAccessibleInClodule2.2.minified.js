@@ -2,12 +2,3 @@
 import { _ as _call_super } from "@swc/helpers/_/_call_super";
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _inherits } from "@swc/helpers/_/_inherits";
-var D, D1 = /*#__PURE__*/ function(C) {
-    function D() {
-        return _class_call_check(this, D), _call_super(this, D, arguments);
-    }
-    return _inherits(D, C), D;
-}(function C() {
-    _class_call_check(this, C);
-});
-(D = D1 || (D1 = {})).y = D.bar;
@@ -3,4 +3,3 @@ import "@swc/helpers/_/_class_private_field_get";
 import "@swc/helpers/_/_class_private_field_init";
 import "@swc/helpers/_/_class_private_field_set";
 Symbol();
-var E, E1 = ((E = E1 || {}).A = "A", E.B = "B", E);
@@ -1,3 +1,2 @@
 //// [stringEnumInElementAccess01.ts]
-var E, E1 = ((E = E1 || {}).A = "a", E.B = "b", E.C = "c", E);
 item[e];
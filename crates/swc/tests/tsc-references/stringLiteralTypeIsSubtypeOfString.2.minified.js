@@ -1,3 +1,2 @@
 //// [stringLiteralTypeIsSubtypeOfString.ts]
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
@@ -2,10 +2,3 @@
 import { _ as _call_super } from "@swc/helpers/_/_call_super";
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _inherits } from "@swc/helpers/_/_inherits";
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-function f() {}
-(f || (f = {})).bar = 1;
-var c = function c() {
-    _class_call_check(this, c);
-};
-(c || (c = {})).bar = 1;
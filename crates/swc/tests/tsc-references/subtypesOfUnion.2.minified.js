@@ -1,9 +1,2 @@
 //// [subtypesOfUnion.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var E, E1 = ((E = E1 || {})[E.e1 = 0] = "e1", E[E.e2 = 1] = "e2", E);
-function f() {}
-(f || (f = {})).bar = 1;
-var c = function c() {
-    _class_call_check(this, c);
-};
-(c || (c = {})).bar = 1;
+import "@swc/helpers/_/_class_call_check";
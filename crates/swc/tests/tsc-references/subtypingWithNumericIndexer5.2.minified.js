@@ -1,4 +1,2 @@
 //// [subtypingWithNumericIndexer5.ts]
-var Generics;
 import "@swc/helpers/_/_class_call_check";
-Generics || (Generics = {});
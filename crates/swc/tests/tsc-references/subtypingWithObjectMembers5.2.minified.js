@@ -1,4 +1,2 @@
 //// [subtypingWithObjectMembers5.ts]
-var NotOptional, Optional;
 import "@swc/helpers/_/_class_call_check";
-NotOptional || (NotOptional = {}), Optional || (Optional = {});
@@ -1,3 +1 @@
 //// [subtypingWithObjectMembersOptionality.ts]
-var TwoLevels;
-TwoLevels || (TwoLevels = {});
@@ -1,8 +1,8 @@
 //// [file.tsx]
-var M, M1, M2;
+var M, M1, M2, M3, M4;
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-(M1 = M || (M = {})).Foo = function Foo1() {
-    _class_call_check(this, Foo1);
-}, (M1.S || (M1.S = {})).Bar = function Bar1() {
-    _class_call_check(this, Bar1);
-}, Foo, Foo, (M2 = M || (M = {})).S || (M2.S = {}), Foo, Foo, Bar, Bar, M || (M = {}), S.Bar, S.Bar, M || (M = {}), Foo, Foo;
+(M1 = M || (M = {})).Foo = function Foo() {
+    _class_call_check(this, Foo);
+}, (M1.S || (M1.S = {})).Bar = function Bar() {
+    _class_call_check(this, Bar);
+}, (M2 = M || (M = {})).Foo, M2.Foo, M2.S || (M2.S = {}), M2.Foo, M2.Foo, M2.S.Bar, M2.S.Bar, (M3 = M || (M = {})).S.Bar, M3.S.Bar, (M4 = M || (M = {})).Foo, M4.Foo;
@@ -27,7 +27,7 @@ define([
     (function(M) {})(M || (M = {}));
     (function(M) {
         // Should emit 'M.X' in both opening and closing tags
-        var y = /*#__PURE__*/ _react.createElement(X, null);
+        var y = /*#__PURE__*/ _react.createElement(M.X, null);
     })(M || (M = {}));
     var M;
 });
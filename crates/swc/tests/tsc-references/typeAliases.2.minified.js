@@ -1,5 +1,4 @@
 //// [typeAliases.ts]
+var x13_1, x13_2, x;
 import "@swc/helpers/_/_class_call_check";
-foo13(x13_1, x13_2), foo13(x13_2, x13_1);
-var E, x13_1, x13_2, x, E1 = ((E = E1 || {})[E.x = 10] = "x", E);
-f15(10).toLowerCase(), f16(x);
+foo13(x13_1, x13_2), foo13(x13_2, x13_1), f15(10).toLowerCase(), f16(x);
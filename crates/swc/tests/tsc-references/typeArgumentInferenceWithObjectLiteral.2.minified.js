@@ -1,5 +1,4 @@
 //// [typeArgumentInferenceWithObjectLiteral.ts]
-var E2, E1, E11 = ((E1 = E11 || {})[E1.X = 0] = "X", E1), E21 = ((E2 = E21 || {})[E2.X = 0] = "X", E2);
 f1({
     w: function(x) {
         return x;
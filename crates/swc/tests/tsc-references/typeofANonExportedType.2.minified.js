@@ -1,5 +1,5 @@
 //// [typeofANonExportedType.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
+import "@swc/helpers/_/_class_call_check";
 export var r1;
 export var r2;
 export var c;
@@ -9,19 +9,11 @@ export var r4b;
 export var i;
 export var r5;
 export var r5;
-(M = M1 || (M1 = {})).foo = '', M.C = function C() {
-    _class_call_check(this, C);
-};
 export var r6;
 export var r7;
 export var r8;
 export var r9;
-var foo, M, E, M1, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
 export var r10;
 export var r11;
 export var r12;
-function foo1() {}
-(foo = foo1 || (foo1 = {})).y = 1, foo.C = function C() {
-    _class_call_check(this, C);
-};
 export var r13;
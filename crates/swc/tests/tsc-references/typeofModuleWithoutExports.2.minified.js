@@ -1,4 +1,2 @@
 //// [typeofModuleWithoutExports.ts]
-var M;
 import "@swc/helpers/_/_class_call_check";
-M || (M = {});
@@ -1,5 +1,3 @@
 //// [typeofThis.ts]
-var Test6, Test7;
 import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 import { _ as _instanceof } from "@swc/helpers/_/_instanceof";
-(Test6 || (Test6 = {})).f = function() {}, (Test7 || (Test7 = {})).f = function() {};
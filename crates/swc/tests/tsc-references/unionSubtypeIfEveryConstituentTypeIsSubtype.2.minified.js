@@ -1,9 +1,2 @@
 //// [unionSubtypeIfEveryConstituentTypeIsSubtype.ts]
-import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
-var e, E2, e1 = ((e = e1 || {})[e.e1 = 0] = "e1", e[e.e2 = 1] = "e2", e), E21 = ((E2 = E21 || {})[E2.A = 0] = "A", E2);
-function f() {}
-(f || (f = {})).bar = 1;
-var c = function c() {
-    _class_call_check(this, c);
-};
-(c || (c = {})).bar = 1;
+import "@swc/helpers/_/_class_call_check";
@@ -1,2 +1 @@
 //// [validEnumAssignments.ts]
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E[E.B = 1] = "B", E);
@@ -1,4 +1,4 @@
 //// [validNullAssignments.ts]
 import "@swc/helpers/_/_class_call_check";
-var E, M, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
-E1.A = null, I = null, (M || (M = {})).x = 1;
+var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
+E1.A = null, I = null;
@@ -1,2 +1 @@
 //// [validNumberAssignments.ts]
-var E, E1 = ((E = E1 || {})[E.A = 0] = "A", E);
@@ -9,10 +9,10 @@ import { _ as _class_call_check } from "@swc/helpers/_/_class_call_check";
 }(), function fn5() {
     return new (void 0)(fn5);
 }();
-var propAcc1, M2, M21, propAcc1 = {
+var propAcc1, propAcc1 = {
     m: propAcc1.m
 };
-(M21 = M2 || (M2 = {})).x = M21.x, M21.x, new function C2() {
+new function C2() {
     _class_call_check(this, C2), this.n = this.n;
 }().n;
 var C3 = function C3() {
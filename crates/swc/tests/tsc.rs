@@ -390,7 +390,10 @@ fn matrix(input: &Path) -> Vec<TestUnitData> {
                                 decorators,
                                 dts: false,
                                 no_early_errors: false,
+                                preserve_parens: false,
                                 disallow_ambiguous_jsx_like: false,
+                                ts_version: None,
+                                warn_import_assertions: false,
                             })),
                             external_helpers: true.into(),
                             target: Some(target),
@@ -0,0 +1,241 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    ptr,
+};
+
+/// Blocks larger than this are never allocated from the arena, so growing a
+/// large vector doesn't leave its old buffers in the arena.
+const MAX_ARENA_SIZE: usize = 4096;
+
+const CHUNK_ALIGN: usize = 16;
+
+/// Each chunk starts with the pointer to the previous chunk and its size.
+const CHUNK_HEADER: usize = 2 * size_of::<usize>();
+
+const MIN_CHUNK_SIZE: usize = 64 * 1024;
+
+const MAX_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+
+thread_local! {
+    static ARENA: Cell<*const Arena> = const { Cell::new(ptr::null()) };
+}
+
+/// Global allocator which allocates from a per-thread arena.
+///
+/// While an [ArenaScope] is installed on a thread, small blocks allocated on
+/// that thread are bumped from the chunks of the scope and freeing them is a
+/// no-op. Boxed AST nodes are allocated contiguously this way, and all of
+/// them are released at once when the scope is dropped, instead of freeing
+/// each node.
+///
+/// Threads without a scope use `inner` directly.
+///
+/// This should be registered as `#[global_allocator]`.
+///
+/// ```rust,ignore
+/// use swc_allocator::allocators::{ArenaAlloc, ArenaScope};
+///
+/// #[global_allocator]
+/// static GLOBAL: ArenaAlloc = ArenaAlloc::new(std::alloc::System);
+///
+/// let scope = unsafe { ArenaScope::install() };
+/// let module = parser.parse_module()?;
+/// // ...
+/// // Skip dropping each node, the memory is released with the scope.
+/// std::mem::forget(module);
+/// drop(scope);
+/// ```
+pub struct ArenaAlloc<A = System> {
+    inner: A,
+}
+
+impl<A> ArenaAlloc<A> {
+    /// Wraps `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for ArenaAlloc<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if is_arena_layout(layout) {
+            if let Some(ptr) = with_arena(|arena| arena.alloc(&self.inner, layout)) {
+                if !ptr.is_null() {
+                    return ptr;
+                }
+            }
+        }
+
+        self.inner.alloc(layout)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if is_arena_layout(layout) && with_arena(|arena| arena.contains(ptr)) == Some(true) {
+            return;
+        }
+
+        self.inner.dealloc(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if !is_arena_layout(layout) || with_arena(|arena| arena.contains(ptr)) != Some(true) {
+            return self.inner.realloc(ptr, layout, new_size);
+        }
+
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+        let new_ptr = self.alloc(new_layout);
+        if !new_ptr.is_null() {
+            ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+        }
+        new_ptr
+    }
+}
+
+#[inline]
+fn is_arena_layout(layout: Layout) -> bool {
+    layout.size() != 0 && layout.size() <= MAX_ARENA_SIZE && layout.align() <= CHUNK_ALIGN
+}
+
+#[inline]
+fn with_arena<F, Ret>(op: F) -> Option<Ret>
+where
+    F: FnOnce(&Arena) -> Ret,
+{
+    let arena = ARENA.try_with(|arena| arena.get()).ok()?;
+    if arena.is_null() {
+        return None;
+    }
+
+    // Safety: The arena is alive while it's installed.
+    Some(op(unsafe { &*arena }))
+}
+
+/// Chunks are linked through their headers, because the arena can't allocate
+/// a list of chunks from the global allocator.
+struct Arena {
+    /// The last chunk.
+    chunk: Cell<*mut u8>,
+    ptr: Cell<usize>,
+    end: Cell<usize>,
+    next_chunk_size: Cell<usize>,
+    allocated: Cell<usize>,
+}
+
+impl Arena {
+    #[inline]
+    unsafe fn alloc<A: GlobalAlloc>(&self, inner: &A, layout: Layout) -> *mut u8 {
+        let start = self.ptr.get().next_multiple_of(layout.align());
+        if start + layout.size() > self.end.get() && !self.grow(inner) {
+            return ptr::null_mut();
+        }
+
+        let start = self.ptr.get().next_multiple_of(layout.align());
+        self.ptr.set(start + layout.size());
+        self.allocated.set(self.allocated.get() + layout.size());
+
+        start as *mut u8
+    }
+
+    #[cold]
+    unsafe fn grow<A: GlobalAlloc>(&self, inner: &A) -> bool {
+        let size = self.next_chunk_size.get();
+        let chunk = inner.alloc(Layout::from_size_align_unchecked(size, CHUNK_ALIGN));
+        if chunk.is_null() {
+            return false;
+        }
+
+        *(chunk as *mut *mut u8) = self.chunk.get();
+        *(chunk as *mut usize).add(1) = size;
+
+        self.chunk.set(chunk);
+        self.ptr.set(chunk as usize + CHUNK_HEADER);
+        self.end.set(chunk as usize + size);
+        self.next_chunk_size.set((size * 2).min(MAX_CHUNK_SIZE));
+        true
+    }
+
+    #[inline]
+    unsafe fn contains(&self, ptr: *mut u8) -> bool {
+        let ptr = ptr as usize;
+
+        let mut chunk = self.chunk.get();
+        while !chunk.is_null() {
+            let size = *(chunk as *const usize).add(1);
+            if chunk as usize <= ptr && ptr < chunk as usize + size {
+                return true;
+            }
+            chunk = *(chunk as *const *mut u8);
+        }
+
+        false
+    }
+}
+
+/// Arena of [ArenaAlloc] for the current thread.
+///
+/// Memory of the arena is released when it's dropped.
+pub struct ArenaScope {
+    arena: Box<Arena>,
+}
+
+impl ArenaScope {
+    /// Installs an arena on the current thread.
+    ///
+    /// # Safety
+    ///
+    /// Every block allocated on this thread while the scope is installed
+    /// must be freed on this thread before the scope is dropped, or never be
+    /// freed at all (e.g. with [std::mem::forget]). This includes blocks of
+    /// thread-local caches, which are allocated lazily.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an arena is already installed on the current thread.
+    pub unsafe fn install() -> Self {
+        let arena = Box::new(Arena {
+            chunk: Cell::new(ptr::null_mut()),
+            ptr: Cell::new(0),
+            end: Cell::new(0),
+            next_chunk_size: Cell::new(MIN_CHUNK_SIZE),
+            allocated: Default::default(),
+        });
+
+        ARENA.with(|current| {
+            assert!(
+                current.get().is_null(),
+                "an arena is already installed on this thread"
+            );
+            current.set(&*arena);
+        });
+
+        Self { arena }
+    }
+
+    /// The number of bytes allocated from the arena.
+    pub fn allocated(&self) -> usize {
+        self.arena.allocated.get()
+    }
+}
+
+impl Drop for ArenaScope {
+    fn drop(&mut self) {
+        ARENA.with(|current| current.set(ptr::null()));
+
+        let mut chunk = self.arena.chunk.get();
+        while !chunk.is_null() {
+            // Safety: Chunks are allocated with this layout by the inner
+            // allocator, which is used for all blocks after the arena is
+            // uninstalled.
+            unsafe {
+                let prev = *(chunk as *const *mut u8);
+                let size = *(chunk as *const usize).add(1);
+                std::alloc::dealloc(chunk, Layout::from_size_align_unchecked(size, CHUNK_ALIGN));
+                chunk = prev;
+            }
+        }
+    }
+}
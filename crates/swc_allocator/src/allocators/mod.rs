@@ -1,7 +1,15 @@
 //! Various flavors of allocators
 
-pub use self::{arena::Arena, global::Global, scoped::Scoped};
+pub use self::{
+    arena::Arena,
+    arena_alloc::{ArenaAlloc, ArenaScope},
+    global::Global,
+    recycling::{RecyclePool, Recycling},
+    scoped::Scoped,
+};
 
 mod arena;
+mod arena_alloc;
 mod global;
+mod recycling;
 mod scoped;
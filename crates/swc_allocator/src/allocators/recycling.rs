@@ -0,0 +1,213 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    mem::size_of,
+    ptr::{self, null_mut},
+};
+
+const WORD: usize = size_of::<usize>();
+
+/// Blocks larger than this are never pooled.
+const MAX_POOLED_SIZE: usize = 512;
+
+const BINS: usize = MAX_POOLED_SIZE / WORD;
+
+thread_local! {
+    static POOL: Cell<*const Pool> = const { Cell::new(ptr::null()) };
+}
+
+/// Global allocator which recycles small blocks.
+///
+/// While a [RecyclePool] is installed on a thread, small blocks freed on that
+/// thread are kept in the pool and handed out again for allocations of the
+/// same layout. AST nodes and scratch vectors are mostly small and
+/// word-aligned, so compiling many files on a thread reuses the memory of the
+/// previous files instead of going through `inner` again.
+///
+/// Threads without a pool use `inner` directly.
+///
+/// This should be registered as `#[global_allocator]`.
+///
+/// ```rust,ignore
+/// use swc_allocator::allocators::{RecyclePool, Recycling};
+///
+/// #[global_allocator]
+/// static GLOBAL: Recycling = Recycling::new(std::alloc::System);
+///
+/// // On each worker thread
+/// let _pool = RecyclePool::install(1024);
+/// ```
+pub struct Recycling<A = System> {
+    inner: A,
+}
+
+impl<A> Recycling<A> {
+    /// Wraps `inner`.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for Recycling<A> {
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if let Some(bin) = bin_of(layout) {
+            if let Some(ptr) = with_pool(|pool| pool.pop(bin)) {
+                if !ptr.is_null() {
+                    return ptr;
+                }
+            }
+        }
+
+        self.inner.alloc(layout)
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if let Some(bin) = bin_of(layout) {
+            if with_pool(|pool| pool.push(bin, ptr)) == Some(true) {
+                return;
+            }
+        }
+
+        self.inner.dealloc(ptr, layout)
+    }
+
+    #[inline]
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_layout = Layout::from_size_align_unchecked(new_size, layout.align());
+
+        if bin_of(layout).is_none() && bin_of(new_layout).is_none() {
+            return self.inner.realloc(ptr, layout, new_size);
+        }
+
+        let new_ptr = self.alloc(new_layout);
+        if !new_ptr.is_null() {
+            ptr::copy_nonoverlapping(ptr, new_ptr, layout.size().min(new_size));
+            self.dealloc(ptr, layout);
+        }
+        new_ptr
+    }
+}
+
+/// Returns the bin for blocks of `layout`, if they can be pooled.
+///
+/// Only the exact layout is reused, so blocks returned to the inner
+/// allocator always have the layout they were allocated with.
+#[inline]
+fn bin_of(layout: Layout) -> Option<usize> {
+    let size = layout.size();
+
+    if layout.align() == WORD && size != 0 && size % WORD == 0 && size <= MAX_POOLED_SIZE {
+        Some(size / WORD - 1)
+    } else {
+        None
+    }
+}
+
+#[inline]
+fn with_pool<F, Ret>(op: F) -> Option<Ret>
+where
+    F: FnOnce(&Pool) -> Ret,
+{
+    let pool = POOL.try_with(|pool| pool.get()).ok()?;
+    if pool.is_null() {
+        return None;
+    }
+
+    // Safety: The pool is alive while it's installed.
+    Some(op(unsafe { &*pool }))
+}
+
+/// Free blocks are linked through their first word.
+struct Pool {
+    free: [Cell<*mut u8>; BINS],
+    len: [Cell<usize>; BINS],
+    max_blocks_per_size: usize,
+    reused: Cell<usize>,
+}
+
+impl Pool {
+    #[inline]
+    unsafe fn pop(&self, bin: usize) -> *mut u8 {
+        let head = self.free[bin].get();
+        if !head.is_null() {
+            self.free[bin].set(*(head as *mut *mut u8));
+            self.len[bin].set(self.len[bin].get() - 1);
+            self.reused.set(self.reused.get() + 1);
+        }
+        head
+    }
+
+    #[inline]
+    unsafe fn push(&self, bin: usize, ptr: *mut u8) -> bool {
+        if self.len[bin].get() >= self.max_blocks_per_size {
+            return false;
+        }
+
+        *(ptr as *mut *mut u8) = self.free[bin].get();
+        self.free[bin].set(ptr);
+        self.len[bin].set(self.len[bin].get() + 1);
+        true
+    }
+}
+
+/// Pool of [Recycling] for the current thread.
+///
+/// Blocks kept by the pool are released when it's dropped.
+pub struct RecyclePool {
+    pool: Box<Pool>,
+}
+
+impl RecyclePool {
+    /// Installs a pool on the current thread, which keeps at most
+    /// `max_blocks_per_size` free blocks for each size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a pool is already installed on the current thread.
+    pub fn install(max_blocks_per_size: usize) -> Self {
+        let pool = Box::new(Pool {
+            free: std::array::from_fn(|_| Cell::new(null_mut())),
+            len: std::array::from_fn(|_| Cell::new(0)),
+            max_blocks_per_size,
+            reused: Default::default(),
+        });
+
+        POOL.with(|current| {
+            assert!(
+                current.get().is_null(),
+                "a recycle pool is already installed on this thread"
+            );
+            current.set(&*pool);
+        });
+
+        Self { pool }
+    }
+
+    /// The number of allocations served by this pool.
+    pub fn reused(&self) -> usize {
+        self.pool.reused.get()
+    }
+}
+
+impl Drop for RecyclePool {
+    fn drop(&mut self) {
+        POOL.with(|current| current.set(ptr::null()));
+
+        for bin in 0..BINS {
+            // Safety: Blocks in this bin were allocated with this layout.
+            unsafe {
+                let layout = Layout::from_size_align_unchecked((bin + 1) * WORD, WORD);
+
+                loop {
+                    let ptr = self.pool.pop(bin);
+                    if ptr.is_null() {
+                        break;
+                    }
+                    std::alloc::dealloc(ptr, layout);
+                }
+            }
+        }
+    }
+}
@@ -24,6 +24,20 @@
 //!
 //! Recommened way to use this mode is to wrap the whole operations in
 //! a call to [Allocator::scope].
+//!
+//! ## Recycling
+//!
+//! Long-running processes which compile many files can register
+//! [allocators::Recycling] as the global allocator and install an
+//! [allocators::RecyclePool] on each worker thread to reuse the memory of AST
+//! nodes and scratch vectors across files.
+//!
+//! ## Arena
+//!
+//! [allocators::ArenaAlloc] is a global allocator which bumps small blocks
+//! from the [allocators::ArenaScope] of the current thread. AST nodes parsed
+//! in the scope are allocated contiguously, and they can be released at once
+//! by forgetting the AST and dropping the scope.
 
 #![allow(clippy::needless_doctest_main)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
@@ -0,0 +1,61 @@
+use std::{alloc::System, hint::black_box};
+
+use swc_allocator::allocators::{ArenaAlloc, ArenaScope};
+
+#[global_allocator]
+static GLOBAL: ArenaAlloc = ArenaAlloc::new(System);
+
+/// Each node is a separate allocation, like boxed AST nodes.
+#[allow(clippy::vec_box)]
+fn alloc_nodes(n: usize) -> Vec<Box<[usize; 4]>> {
+    (0..n).map(|i| black_box(Box::new([i; 4]))).collect()
+}
+
+#[test]
+fn allocates_from_arena() {
+    let scope = unsafe { ArenaScope::install() };
+
+    let nodes = alloc_nodes(10_000);
+    assert!(scope.allocated() >= 10_000 * 32);
+
+    for (i, node) in nodes.iter().enumerate() {
+        assert_eq!(**node, [i; 4]);
+    }
+
+    std::mem::forget(nodes);
+}
+
+#[test]
+fn frees_are_noop() {
+    let scope = unsafe { ArenaScope::install() };
+
+    drop(alloc_nodes(100));
+    let allocated = scope.allocated();
+    let nodes = alloc_nodes(100);
+
+    // Freed blocks are not reused.
+    assert!(scope.allocated() > allocated);
+    drop(nodes);
+}
+
+#[test]
+fn grows_vectors() {
+    let _scope = unsafe { ArenaScope::install() };
+
+    let mut v = Vec::new();
+    for i in 0..100_000 {
+        v.push(black_box(i));
+    }
+
+    assert_eq!(v.iter().sum::<usize>(), (0..100_000).sum());
+}
+
+#[test]
+fn blocks_before_scope() {
+    let nodes = alloc_nodes(100);
+
+    let scope = unsafe { ArenaScope::install() };
+    // Freed to the inner allocator.
+    drop(nodes);
+    assert_eq!(scope.allocated(), 0);
+}
@@ -0,0 +1,56 @@
+use std::{alloc::System, hint::black_box};
+
+use swc_allocator::allocators::{RecyclePool, Recycling};
+
+#[global_allocator]
+static GLOBAL: Recycling = Recycling::new(System);
+
+/// Each node is a separate allocation, like boxed AST nodes.
+#[allow(clippy::vec_box)]
+fn alloc_nodes(n: usize) -> Vec<Box<[usize; 4]>> {
+    (0..n).map(|i| black_box(Box::new([i; 4]))).collect()
+}
+
+#[test]
+fn reuses_freed_blocks() {
+    let pool = RecyclePool::install(1024);
+
+    drop(alloc_nodes(100));
+    assert_eq!(pool.reused(), 0);
+
+    let nodes = alloc_nodes(100);
+    assert!(pool.reused() >= 100);
+
+    for (i, node) in nodes.iter().enumerate() {
+        assert_eq!(**node, [i; 4]);
+    }
+}
+
+#[test]
+fn keeps_at_most_max_blocks() {
+    let pool = RecyclePool::install(10);
+
+    drop(alloc_nodes(100));
+    let _nodes = alloc_nodes(100);
+
+    assert_eq!(pool.reused(), 10);
+}
+
+#[test]
+fn blocks_escape_pool() {
+    let nodes = {
+        let _pool = RecyclePool::install(1024);
+        drop(alloc_nodes(100));
+        alloc_nodes(100)
+    };
+
+    // Blocks are freed to the inner allocator after the pool is gone.
+    drop(nodes);
+}
+
+#[test]
+#[should_panic = "already installed"]
+fn nested_install() {
+    let _pool = RecyclePool::install(1024);
+    let _nested = RecyclePool::install(1024);
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+use sha2::{Digest, Sha256};
+use swc_estree_ast::File;
+
+use crate::BabelPlugin;
+
+pub(crate) type CacheKey = [u8; 32];
+
+/// Caches the output of each plugin by its input.
+///
+/// This is an in-memory cache which is shared by clones, and it does not
+/// check if plugins are modified on disk.
+#[derive(Clone, Default)]
+pub struct BabelPluginCache {
+    outputs: Arc<Mutex<FxHashMap<CacheKey, File>>>,
+}
+
+impl BabelPluginCache {
+    /// `filename` and `code` are included because plugins can read them, e.g.
+    /// to resolve imports relative to the file.
+    pub(crate) fn key(plugin: &BabelPlugin, filename: &str, code: &str, input: &str) -> CacheKey {
+        let mut hasher = Sha256::new();
+
+        // Lengths are included to avoid collisions between concatenated fields.
+        for field in [
+            &*plugin.name,
+            &plugin.options.to_string(),
+            filename,
+            code,
+            input,
+        ] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field);
+        }
+
+        hasher.finalize().into()
+    }
+
+    pub(crate) fn get(&self, key: &CacheKey) -> Option<File> {
+        self.outputs.lock().get(key).cloned()
+    }
+
+    pub(crate) fn insert(&self, key: CacheKey, output: File) {
+        self.outputs.lock().insert(key, output);
+    }
+
+    pub fn len(&self) -> usize {
+        self.outputs.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outputs.lock().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.outputs.lock().clear();
+    }
+}
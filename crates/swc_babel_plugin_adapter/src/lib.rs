@@ -0,0 +1,139 @@
+//! Runs babel plugins against swc ast, so that a project can migrate to swc
+//! while a few babel plugins are still needed.
+//!
+//! The ast is converted to the babel ast using `swc_estree_compat`, passed to
+//! `@babel/core` in a node.js process, and converted back to swc ast.
+//!
+//! Each plugin is applied in a separate pass and its result is cached by its
+//! input, so a plugin is not invoked again for an unchanged input even if the
+//! output of an earlier plugin was changed.
+//!
+//! The returned ast does not have [SyntaxContext](swc_common::SyntaxContext)s,
+//! so [BabelPluginAdapter] should be applied before the `resolver`.
+
+#![deny(clippy::all)]
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+use anyhow::{anyhow, bail, Context as _, Error};
+use serde::{Deserialize, Serialize};
+use swc_common::{sync::Lrc, SourceFile, SourceMap};
+use swc_ecma_ast::Program;
+use swc_estree_ast::File;
+use swc_estree_compat::{
+    babelify::{self, Babelify},
+    swcify::{self, Swcify},
+};
+use swc_node_comments::SwcComments;
+
+pub use self::{cache::BabelPluginCache, runtime::NodeRuntime};
+use crate::runtime::PluginRequest;
+
+mod cache;
+mod runtime;
+
+/// A babel plugin with its options, like an item of `plugins` of babel
+/// config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BabelPlugin {
+    /// Resolved like babel does, e.g. `@babel/plugin-transform-runtime`,
+    /// `module:my-plugin` or `./plugins/my-plugin.js`.
+    pub name: String,
+
+    #[serde(default)]
+    pub options: serde_json::Value,
+}
+
+pub struct BabelPluginAdapter {
+    runtime: NodeRuntime,
+    cache: BabelPluginCache,
+    plugins: Vec<BabelPlugin>,
+}
+
+impl BabelPluginAdapter {
+    /// `cache` can be shared between adapters using the same plugins.
+    pub fn new(runtime: NodeRuntime, cache: BabelPluginCache, plugins: Vec<BabelPlugin>) -> Self {
+        Self {
+            runtime,
+            cache,
+            plugins,
+        }
+    }
+
+    /// Applies the plugins to `program`, which is parsed from `fm`.
+    ///
+    /// Comments of `program` should be stored in `comments`, and comments of
+    /// the returned program are stored in it.
+    pub fn apply(
+        &self,
+        cm: &Lrc<SourceMap>,
+        fm: &Lrc<SourceFile>,
+        comments: &SwcComments,
+        program: Program,
+    ) -> Result<Program, Error> {
+        if self.plugins.is_empty() {
+            return Ok(program);
+        }
+
+        let mut ast = program.babelify(&babelify::Context {
+            fm: fm.clone(),
+            cm: cm.clone(),
+            comments: comments.clone(),
+        });
+
+        for plugin in &self.plugins {
+            ast = self
+                .apply_plugin(plugin, fm, ast)
+                .with_context(|| format!("failed to apply babel plugin `{}`", plugin.name))?;
+        }
+
+        let ctx = swcify::Context::new_without_alloc(cm.clone(), comments.clone(), fm.clone());
+
+        // Plugins can produce an ast which breaks an invariant of babel ast, like a
+        // function declaration without `id`.
+        let program = catch_unwind(AssertUnwindSafe(|| ast.swcify(&ctx))).map_err(|err| {
+            let msg = err
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| err.downcast_ref::<String>().map(|s| &**s))
+                .unwrap_or("unknown panic");
+
+            anyhow!("failed to convert the output of babel plugins: {}", msg)
+        })?;
+
+        let unsupported = ctx.take_unsupported();
+        if let Some(node) = unsupported.first() {
+            bail!("failed to convert the output of babel plugins: {}", node);
+        }
+
+        Ok(program)
+    }
+
+    fn apply_plugin(
+        &self,
+        plugin: &BabelPlugin,
+        fm: &SourceFile,
+        ast: File,
+    ) -> Result<File, Error> {
+        let ast = serde_json::to_string(&ast).context("failed to serialize babel ast")?;
+        let filename = fm.name.to_string();
+        let key = BabelPluginCache::key(plugin, &filename, &fm.src, &ast);
+
+        if let Some(output) = self.cache.get(&key) {
+            return Ok(output);
+        }
+
+        let output = self.runtime.run(
+            &PluginRequest {
+                plugin,
+                filename,
+                code: &fm.src,
+            },
+            &ast,
+        )?;
+
+        self.cache.insert(key, output.clone());
+
+        Ok(output)
+    }
+}
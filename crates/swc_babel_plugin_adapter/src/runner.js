@@ -0,0 +1,49 @@
+// Applies babel plugins for `swc_babel_plugin_adapter`.
+//
+// Each line of stdin is a request, and each line of stdout is the response to
+// it.
+
+"use strict";
+
+const path = require("path");
+const readline = require("readline");
+const { createRequire } = require("module");
+
+// Plugins and `@babel/core` are resolved like babel config in the working
+// directory.
+const requireFromCwd = createRequire(path.join(process.cwd(), "index.js"));
+
+const write = process.stdout.write.bind(process.stdout);
+
+// Plugins may log messages, but stdout is reserved for responses.
+console.log = console.info = console.debug = console.error;
+
+function run({ request, ast }) {
+    const babel = requireFromCwd("@babel/core");
+    const { plugin, filename, code } = request;
+
+    const result = babel.transformFromAstSync(ast, code, {
+        filename,
+        cwd: process.cwd(),
+        plugins: [[plugin.name, plugin.options ?? {}]],
+        ast: true,
+        code: false,
+        babelrc: false,
+        configFile: false,
+        cloneInputAst: false,
+    });
+
+    return result.ast;
+}
+
+readline.createInterface({ input: process.stdin }).on("line", (line) => {
+    let response;
+
+    try {
+        response = { ast: run(JSON.parse(line)) };
+    } catch (e) {
+        response = { error: String((e && e.stack) || e) };
+    }
+
+    write(JSON.stringify(response) + "\n");
+});
@@ -0,0 +1,140 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+    process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+};
+
+use anyhow::{bail, Context, Error};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use swc_estree_ast::File;
+
+use crate::BabelPlugin;
+
+/// The script which runs in the node.js process.
+const RUNNER: &str = include_str!("runner.js");
+
+/// A node.js process which applies babel plugins.
+///
+/// The process is spawned on the first use and reused for later plugins.
+/// Requests are processed one at a time.
+pub struct NodeRuntime {
+    node: PathBuf,
+    cwd: PathBuf,
+    process: Mutex<Option<NodeProcess>>,
+}
+
+impl NodeRuntime {
+    /// Plugins and `@babel/core` are resolved from `cwd`.
+    pub fn new(cwd: PathBuf) -> Self {
+        Self::with_node("node".into(), cwd)
+    }
+
+    /// Uses `node` instead of the `node` in `PATH`.
+    pub fn with_node(node: PathBuf, cwd: PathBuf) -> Self {
+        Self {
+            node,
+            cwd,
+            process: Default::default(),
+        }
+    }
+
+    /// `ast` is the serialized input.
+    pub(crate) fn run(&self, req: &PluginRequest, ast: &str) -> Result<File, Error> {
+        let mut process = self.process.lock();
+
+        let p = match &mut *process {
+            Some(p) => p,
+            None => process.insert(self.spawn()?),
+        };
+
+        let res = match p.request(req, ast) {
+            Ok(res) => res,
+            Err(err) => {
+                // The process may be broken, so we spawn a new one for the next request.
+                *process = None;
+                return Err(err);
+            }
+        };
+
+        match res {
+            Response::Ast(ast) => Ok(*ast),
+            Response::Error(err) => bail!("{}", err),
+        }
+    }
+
+    fn spawn(&self) -> Result<NodeProcess, Error> {
+        let mut child = Command::new(&self.node)
+            .arg("-e")
+            .arg(RUNNER)
+            .current_dir(&self.cwd)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .with_context(|| format!("failed to spawn `{}`", self.node.display()))?;
+
+        let stdin = child.stdin.take().unwrap();
+        let stdout = BufReader::new(child.stdout.take().unwrap());
+
+        Ok(NodeProcess {
+            child,
+            stdin,
+            stdout,
+        })
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct PluginRequest<'a> {
+    pub plugin: &'a BabelPlugin,
+    pub filename: String,
+    pub code: &'a str,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Response {
+    Ast(Box<File>),
+    Error(String),
+}
+
+struct NodeProcess {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl NodeProcess {
+    fn request(&mut self, req: &PluginRequest, ast: &str) -> Result<Response, Error> {
+        // The ast is already serialized, so it's written as is.
+        let mut line = String::from("{\"request\":");
+        line.push_str(&serde_json::to_string(req)?);
+        line.push_str(",\"ast\":");
+        line.push_str(ast);
+        line.push_str("}\n");
+
+        self.stdin
+            .write_all(line.as_bytes())
+            .and_then(|_| self.stdin.flush())
+            .context("failed to send a request to node")?;
+
+        line.clear();
+        self.stdout
+            .read_line(&mut line)
+            .context("failed to read a response from node")?;
+
+        if line.is_empty() {
+            bail!("node exited unexpectedly");
+        }
+
+        serde_json::from_str(&line).context("failed to deserialize the response from node")
+    }
+}
+
+impl Drop for NodeProcess {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
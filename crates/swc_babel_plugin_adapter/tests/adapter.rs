@@ -0,0 +1,147 @@
+//! `tests/fixture` has a minimal `@babel/core` which doesn't need npm.
+
+use std::{path::PathBuf, sync::Arc};
+
+use serde_json::json;
+use swc_babel_plugin_adapter::{BabelPlugin, BabelPluginAdapter, BabelPluginCache, NodeRuntime};
+use swc_common::{FileName, SourceMap, GLOBALS};
+use swc_ecma_codegen::to_code_default;
+use swc_ecma_parser::parse_file_as_program;
+use swc_node_comments::SwcComments;
+
+fn adapter(cache: BabelPluginCache, plugins: Vec<BabelPlugin>) -> BabelPluginAdapter {
+    let cwd = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixture");
+
+    BabelPluginAdapter::new(NodeRuntime::new(cwd), cache, plugins)
+}
+
+fn plugin(name: &str, options: serde_json::Value) -> BabelPlugin {
+    BabelPlugin {
+        name: name.into(),
+        options,
+    }
+}
+
+fn apply(adapter: &BabelPluginAdapter, src: &str) -> anyhow::Result<String> {
+    apply_file(adapter, FileName::Anon, src)
+}
+
+fn apply_file(
+    adapter: &BabelPluginAdapter,
+    filename: FileName,
+    src: &str,
+) -> anyhow::Result<String> {
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.new_source_file(filename.into(), src.into());
+        let comments = SwcComments::default();
+
+        let program = parse_file_as_program(
+            &fm,
+            Default::default(),
+            Default::default(),
+            Some(&comments),
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let program = adapter.apply(&cm, &fm, &comments, program)?;
+
+        Ok(to_code_default(cm, Some(&comments), &program))
+    })
+}
+
+#[test]
+fn plugins_are_applied_in_order() {
+    let adapter = adapter(
+        Default::default(),
+        vec![
+            plugin("babel-plugin-rename", json!({ "from": "a", "to": "b" })),
+            plugin("babel-plugin-rename", json!({ "from": "b", "to": "c" })),
+        ],
+    );
+
+    assert_eq!(
+        apply(&adapter, "// comment\nconst a = 1;\nfoo(a);").unwrap(),
+        "// comment\nconst c = 1;\nfoo(c);\n"
+    );
+}
+
+#[test]
+fn outputs_are_cached_per_plugin() {
+    let cache = BabelPluginCache::default();
+    let adapter = adapter(
+        cache.clone(),
+        vec![plugin("babel-plugin-counter", Default::default())],
+    );
+
+    assert_eq!(apply(&adapter, "a;").unwrap(), "a1;\n");
+    assert_eq!(apply(&adapter, "a;").unwrap(), "a1;\n");
+    assert_eq!(cache.len(), 1);
+
+    assert_eq!(apply(&adapter, "b;").unwrap(), "b2;\n");
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn outputs_are_cached_per_file() {
+    let cache = BabelPluginCache::default();
+    let adapter = adapter(
+        cache.clone(),
+        vec![plugin("babel-plugin-counter", Default::default())],
+    );
+
+    let a = FileName::Real("a.js".into());
+    let b = FileName::Real("b.js".into());
+
+    assert_eq!(apply_file(&adapter, a.clone(), "a;").unwrap(), "a1;\n");
+    assert_eq!(apply_file(&adapter, b, "a;").unwrap(), "a2;\n");
+    assert_eq!(apply_file(&adapter, a, "a;").unwrap(), "a1;\n");
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn invalid_outputs_are_reported() {
+    let adapter = adapter(
+        Default::default(),
+        vec![plugin("babel-plugin-anonymous", Default::default())],
+    );
+
+    let err = format!("{:#}", apply(&adapter, "function f() {}").unwrap_err());
+    assert_eq!(
+        err,
+        "failed to convert the output of babel plugins: function declarations should have a name"
+    );
+}
+
+#[test]
+fn unsupported_outputs_are_reported() {
+    let adapter = adapter(
+        Default::default(),
+        vec![plugin("babel-plugin-record", Default::default())],
+    );
+
+    let err = format!("{:#}", apply(&adapter, "a;").unwrap_err());
+    assert_eq!(
+        err,
+        "failed to convert the output of babel plugins: swc does not support record expressions"
+    );
+}
+
+#[test]
+fn plugin_errors_are_reported() {
+    let adapter = adapter(
+        Default::default(),
+        vec![
+            plugin("babel-plugin-throw", Default::default()),
+            plugin("babel-plugin-rename", json!({ "from": "a", "to": "b" })),
+        ],
+    );
+
+    let err = format!("{:#}", apply(&adapter, "a;").unwrap_err());
+    assert!(
+        err.starts_with("failed to apply babel plugin `babel-plugin-throw`: Error: plugin error"),
+        "{}",
+        err
+    );
+}
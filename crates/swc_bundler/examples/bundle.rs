@@ -86,6 +86,12 @@ fn do_test(_entry: &Path, entries: HashMap<String, FileName>, inline: bool, mini
                 disable_hygiene: minify,
                 disable_dce: false,
                 module: Default::default(),
+                deterministic: false,
+                code_splitting: false,
+                incremental: false,
+                preserve_license_comments: false,
+                module_banners: false,
+                metadata: false,
             },
             Box::new(Hook),
         );
@@ -0,0 +1,365 @@
+use anyhow::{anyhow, bail, Context, Error};
+use swc_common::{
+    sync::{Lrc, Send, Sync},
+    FileName, SourceFile, SourceMap, SyntaxContext, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_expr, Syntax};
+use swc_ecma_utils::{quote_ident, quote_str, ExprFactory};
+
+use crate::{Load, ModuleData};
+
+/// Converts a file which is not an ECMAScript module into a module, so it can
+/// be imported by other modules.
+///
+/// Closures which take the content of a file and return a module are also
+/// asset loaders.
+pub trait AssetLoader: Send + Sync {
+    fn load(&self, cm: &Lrc<SourceMap>, file: &FileName) -> Result<ModuleData, Error>;
+}
+
+impl<F> AssetLoader for F
+where
+    F: Fn(&Lrc<SourceFile>) -> Result<Module, Error> + Send + Sync,
+{
+    fn load(&self, cm: &Lrc<SourceMap>, file: &FileName) -> Result<ModuleData, Error> {
+        let fm = load_file(cm, file)?;
+        let module = (self)(&fm)?;
+
+        Ok(ModuleData {
+            fm,
+            module,
+            helpers: Default::default(),
+        })
+    }
+}
+
+/// A [Load] which uses [AssetLoader]s for files with registered extensions
+/// and `inner` for other files.
+pub struct AssetLoad<L> {
+    cm: Lrc<SourceMap>,
+    inner: L,
+    loaders: Vec<(String, Box<dyn AssetLoader>)>,
+}
+
+impl<L> AssetLoad<L>
+where
+    L: Load,
+{
+    pub fn new(cm: Lrc<SourceMap>, inner: L) -> Self {
+        AssetLoad {
+            cm,
+            inner,
+            loaders: Vec::new(),
+        }
+    }
+
+    /// Uses `loader` for files with the extension `ext`, which does not
+    /// include the leading dot.
+    ///
+    /// A loader registered later takes precedence over one registered earlier
+    /// for the same extension.
+    pub fn with_loader(mut self, ext: &str, loader: impl AssetLoader + 'static) -> Self {
+        self.loaders.push((ext.to_string(), Box::new(loader)));
+        self
+    }
+
+    /// Uses [CssLoader] for `.css` files and [JsonLoader] for `.json` files.
+    pub fn with_default_loaders(self) -> Self {
+        self.with_loader("css", CssLoader::default())
+            .with_loader("json", JsonLoader)
+    }
+
+    fn loader_for(&self, file: &FileName) -> Option<&dyn AssetLoader> {
+        let ext = match file {
+            FileName::Real(path) => path.extension()?.to_str()?,
+            _ => return None,
+        };
+
+        self.loaders
+            .iter()
+            .rev()
+            .find(|(e, _)| e.eq_ignore_ascii_case(ext))
+            .map(|(_, loader)| &**loader)
+    }
+}
+
+impl<L> Load for AssetLoad<L>
+where
+    L: Load,
+{
+    fn load(&self, file: &FileName) -> Result<ModuleData, Error> {
+        match self.loader_for(file) {
+            Some(loader) => loader.load(&self.cm, file),
+            None => self.inner.load(file),
+        }
+    }
+}
+
+/// Converts a stylesheet into a module which exports the text of the
+/// stylesheet as the default export.
+///
+/// `@import` rules at the start of the stylesheet are converted into imports,
+/// so imported stylesheets are bundled and evaluated before the importer.
+#[derive(Debug, Clone, Copy)]
+pub struct CssLoader {
+    /// Append a `<style>` element with the stylesheet to `document.head` when
+    /// the module is evaluated.
+    ///
+    /// Defaults to `true`.
+    pub inject: bool,
+}
+
+impl Default for CssLoader {
+    fn default() -> Self {
+        CssLoader { inject: true }
+    }
+}
+
+impl AssetLoader for CssLoader {
+    fn load(&self, cm: &Lrc<SourceMap>, file: &FileName) -> Result<ModuleData, Error> {
+        let fm = load_file(cm, file)?;
+        let (imports, css) = split_imports(&fm.src);
+
+        let mut body = imports
+            .into_iter()
+            .map(|src| {
+                ImportDecl {
+                    span: DUMMY_SP,
+                    specifiers: Vec::new(),
+                    src: Box::new(quote_str!(src)),
+                    type_only: false,
+                    with: None,
+                    phase: Default::default(),
+                }
+                .into()
+            })
+            .collect::<Vec<ModuleItem>>();
+
+        let css_ident = quote_ident!(SyntaxContext::empty(), "css");
+        body.push(
+            Stmt::from(
+                Expr::from(quote_str!(css.trim()))
+                    .into_var_decl(VarDeclKind::Const, css_ident.clone().into()),
+            )
+            .into(),
+        );
+        if self.inject {
+            body.push(inject_style(&css_ident).into());
+        }
+        body.push(
+            ExportDefaultExpr {
+                span: DUMMY_SP,
+                expr: css_ident.into(),
+            }
+            .into(),
+        );
+
+        Ok(ModuleData {
+            fm,
+            module: Module {
+                span: DUMMY_SP,
+                body,
+                shebang: None,
+            },
+            helpers: Default::default(),
+        })
+    }
+}
+
+/// Converts a json file into a module which exports the parsed value as the
+/// default export.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonLoader;
+
+impl AssetLoader for JsonLoader {
+    fn load(&self, cm: &Lrc<SourceMap>, file: &FileName) -> Result<ModuleData, Error> {
+        let fm = load_file(cm, file)?;
+        let expr = parse_file_as_expr(
+            &fm,
+            Syntax::default(),
+            EsVersion::Es2020,
+            None,
+            &mut Vec::new(),
+        )
+        .map_err(|err| anyhow!("failed parse json as javascript object: {:#?}", err))?;
+
+        Ok(ModuleData {
+            fm,
+            module: Module {
+                span: DUMMY_SP,
+                body: vec![ExportDefaultExpr {
+                    span: DUMMY_SP,
+                    expr,
+                }
+                .into()],
+                shebang: None,
+            },
+            helpers: Default::default(),
+        })
+    }
+}
+
+/// Converts a file into a module which exports the url of the file as the
+/// default export.
+///
+/// The url is [UrlLoader::public_path] followed by the name of the file. The
+/// file is not read, and copying it to the public path is up to the caller.
+#[derive(Debug, Clone, Default)]
+pub struct UrlLoader {
+    pub public_path: String,
+}
+
+impl AssetLoader for UrlLoader {
+    fn load(&self, cm: &Lrc<SourceMap>, file: &FileName) -> Result<ModuleData, Error> {
+        let name = match file {
+            FileName::Real(path) => path
+                .file_name()
+                .with_context(|| format!("`{}` is not a file", path.display()))?
+                .to_string_lossy(),
+            _ => bail!("url loader can only load real files: {}", file),
+        };
+        let url = format!("{}{}", self.public_path, name);
+
+        Ok(ModuleData {
+            fm: cm.new_source_file(Lrc::new(file.clone()), String::new()),
+            module: Module {
+                span: DUMMY_SP,
+                body: vec![ExportDefaultExpr {
+                    span: DUMMY_SP,
+                    expr: quote_str!(url).into(),
+                }
+                .into()],
+                shebang: None,
+            },
+            helpers: Default::default(),
+        })
+    }
+}
+
+fn load_file(cm: &Lrc<SourceMap>, file: &FileName) -> Result<Lrc<SourceFile>, Error> {
+    match file {
+        FileName::Real(path) => cm
+            .load_file(path)
+            .with_context(|| format!("failed to load `{}`", path.display())),
+        _ => bail!("asset loaders can only load real files: {}", file),
+    }
+}
+
+/// Creates
+///
+/// ```js
+/// if (typeof document !== "undefined") {
+///     const style = document.createElement("style");
+///     style.textContent = css;
+///     document.head.appendChild(style);
+/// }
+/// ```
+fn inject_style(css: &Ident) -> Stmt {
+    let document = quote_ident!(SyntaxContext::empty(), "document");
+    let style = quote_ident!(SyntaxContext::empty(), "style");
+
+    let create = document
+        .clone()
+        .make_member(quote_ident!("createElement"))
+        .as_call(DUMMY_SP, vec![quote_str!("style").as_arg()])
+        .into_var_decl(VarDeclKind::Const, style.clone().into());
+    let set_text = css.clone().make_assign_to(
+        op!("="),
+        style
+            .clone()
+            .make_member(quote_ident!("textContent"))
+            .into(),
+    );
+    let append = document
+        .clone()
+        .make_member(quote_ident!("head"))
+        .make_member(quote_ident!("appendChild"))
+        .as_call(DUMMY_SP, vec![style.as_arg()]);
+
+    IfStmt {
+        span: DUMMY_SP,
+        test: BinExpr {
+            span: DUMMY_SP,
+            op: op!("!=="),
+            left: UnaryExpr {
+                span: DUMMY_SP,
+                op: op!("typeof"),
+                arg: document.into(),
+            }
+            .into(),
+            right: quote_str!("undefined").into(),
+        }
+        .into(),
+        cons: Box::new(
+            BlockStmt {
+                stmts: vec![create.into(), set_text.into_stmt(), append.into_stmt()],
+                ..Default::default()
+            }
+            .into(),
+        ),
+        alt: None,
+    }
+    .into()
+}
+
+/// Splits `@import` rules at the start of a stylesheet from the rest of it.
+///
+/// Rules with media queries or other conditions are kept in the stylesheet.
+fn split_imports(css: &str) -> (Vec<&str>, &str) {
+    let mut imports = Vec::new();
+    let mut rest = skip_trivia(css);
+
+    while let Some(rule) = rest.strip_prefix("@import") {
+        let rule = rule.trim_start();
+        let (src, after) = match rule.strip_prefix("url(") {
+            Some(url) => match url.split_once(')') {
+                Some((src, after)) => (unquote(src.trim()), after),
+                None => break,
+            },
+            None => match unquote_prefix(rule) {
+                Some((src, after)) => (Some(src), after),
+                None => break,
+            },
+        };
+
+        match (src, after.trim_start().strip_prefix(';')) {
+            (Some(src), Some(after)) => {
+                imports.push(src);
+                rest = skip_trivia(after);
+            }
+            _ => break,
+        }
+    }
+
+    (imports, rest)
+}
+
+fn skip_trivia(mut s: &str) -> &str {
+    loop {
+        s = s.trim_start();
+        match s
+            .strip_prefix("/*")
+            .and_then(|comment| comment.split_once("*/"))
+        {
+            Some((_, after)) => s = after,
+            None => return s,
+        }
+    }
+}
+
+/// `"foo"` => `foo`, and `foo` is kept as is.
+fn unquote(s: &str) -> Option<&str> {
+    match unquote_prefix(s) {
+        Some((v, "")) => Some(v),
+        Some(..) => None,
+        None => Some(s),
+    }
+}
+
+/// Returns the content of the string at the start of `s` and the rest of `s`.
+fn unquote_prefix(s: &str) -> Option<(&str, &str)> {
+    let quote = s.chars().next().filter(|&c| c == '"' || c == '\'')?;
+
+    s[1..].split_once(quote)
+}
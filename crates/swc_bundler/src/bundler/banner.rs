@@ -0,0 +1,147 @@
+use std::env::current_dir;
+
+use rustc_hash::FxHashSet;
+use swc_common::{
+    comments::{Comment, CommentKind, SingleThreadedComments, SingleThreadedCommentsMapInner},
+    FileName, Span, Spanned, DUMMY_SP,
+};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{lexer::Lexer, Syntax, TsSyntax};
+
+use crate::{Bundler, Load, ModuleId, Resolve};
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// Adds a `// MODULE: path` comment before the first statement of each
+    /// module.
+    ///
+    /// Statements are attributed to modules by their spans, so injected
+    /// statements are skipped.
+    pub(super) fn add_module_banners(
+        &self,
+        module: &Module,
+        comments: &mut SingleThreadedCommentsMapInner,
+    ) {
+        let mut prev = None;
+
+        for item in &module.body {
+            let span = item.span();
+            if span.is_dummy() {
+                continue;
+            }
+
+            let file = match self.cm.try_lookup_source_file(span.lo) {
+                Ok(fm) => fm.name.clone(),
+                Err(_) => continue,
+            };
+            if prev.as_ref() == Some(&file) {
+                continue;
+            }
+
+            comments.entry(span.lo).or_default().push(Comment {
+                kind: CommentKind::Line,
+                span: DUMMY_SP,
+                text: format!(" MODULE: {}", display_path(&file)).into(),
+            });
+            prev = Some(file);
+        }
+    }
+
+    /// Adds license comments of `modules` to the start of `module`.
+    ///
+    /// The span of `module` is changed, because comments are stored by
+    /// position.
+    pub(super) fn add_license_comments(
+        &self,
+        module: &mut Module,
+        modules: &[ModuleId],
+        comments: &mut SingleThreadedCommentsMapInner,
+    ) {
+        let mut seen = FxHashSet::default();
+        let licenses = modules
+            .iter()
+            .filter_map(|id| self.scope.get_module(*id))
+            .flat_map(|m| license_comments(&m.fm))
+            .filter(|c| seen.insert(c.text.clone()))
+            .collect::<Vec<_>>();
+
+        if licenses.is_empty() {
+            return;
+        }
+
+        // A position which is not used by any node.
+        let pos = self
+            .cm
+            .new_source_file(
+                FileName::Internal("swc_bundler:license".into()).into(),
+                String::new(),
+            )
+            .start_pos;
+
+        module.span = Span::new(pos, pos);
+        comments.insert(pos, licenses);
+    }
+}
+
+/// Returns `/*! ... */` comments and comments containing `@license` or
+/// `@preserve` of a file, in source order.
+fn license_comments(fm: &swc_common::SourceFile) -> Vec<Comment> {
+    let tsx = match &*fm.name {
+        FileName::Real(path) => path
+            .extension()
+            .is_some_and(|ext| ext == "tsx" || ext == "jsx"),
+        _ => false,
+    };
+
+    let comments = SingleThreadedComments::default();
+    let lexer = Lexer::new(
+        Syntax::Typescript(TsSyntax {
+            tsx,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        fm.into(),
+        Some(&comments),
+    );
+    lexer.for_each(drop);
+
+    let (leading, trailing) = comments.take_all();
+    let mut comments = leading
+        .take()
+        .into_values()
+        .chain(trailing.take().into_values())
+        .flatten()
+        .filter(|c| {
+            c.kind == CommentKind::Block
+                && (c.text.starts_with('!')
+                    || c.text.contains("@license")
+                    || c.text.contains("@preserve"))
+        })
+        .collect::<Vec<_>>();
+    comments.sort_by_key(|c| c.span.lo);
+
+    comments
+        .into_iter()
+        .map(|c| Comment {
+            span: DUMMY_SP,
+            ..c
+        })
+        .collect()
+}
+
+/// Path relative to the current directory, if possible.
+pub(super) fn display_path(name: &FileName) -> String {
+    if let FileName::Real(path) = name {
+        if let Some(path) = current_dir()
+            .ok()
+            .and_then(|cwd| path.strip_prefix(cwd).ok())
+        {
+            return path.display().to_string();
+        }
+    }
+
+    name.to_string()
+}
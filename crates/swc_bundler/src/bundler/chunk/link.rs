@@ -0,0 +1,301 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::{Span, SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::private_ident;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+use super::merge::Ctx;
+use crate::{
+    bundler::load::{Specifier, TransformedModule},
+    modules::Modules,
+    util::{ExportMetadata, ExprExt, VarDeclaratorExt},
+    Bundler, Load, ModuleId, Resolve,
+};
+
+const CHUNK_REF_PREFIX: &str = "\0swc_bundler:chunk:";
+
+/// Source used for imports from another chunk until the file name of the
+/// chunk is known.
+pub(crate) fn chunk_ref(id: ModuleId) -> Atom {
+    format!("{}{}", CHUNK_REF_PREFIX, id).into()
+}
+
+pub(crate) fn is_chunk_ref(src: &Atom) -> bool {
+    src.starts_with(CHUNK_REF_PREFIX)
+}
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// Replaces sources of imports, reexports and `import()` which point to
+    /// other chunks with [chunk_ref]s, so they are preserved like imports
+    /// from external modules.
+    pub(super) fn link_chunks(&self, ctx: &Ctx, info: &TransformedModule, module: &mut Modules) {
+        if ctx.chunk_roots.is_empty() {
+            return;
+        }
+
+        let owners = ctx.owners.get(&info.id);
+
+        let static_refs = info
+            .imports
+            .specifiers
+            .iter()
+            .chain(info.exports.reexports.iter())
+            .map(|(src, _)| src)
+            .filter(|src| {
+                ctx.chunk_roots.contains(&src.module_id)
+                    && src.module_id != info.id
+                    && !owners.is_some_and(|owners| owners.contains(&src.module_id))
+            })
+            .map(|src| (src.src.value.clone(), chunk_ref(src.module_id)))
+            .collect::<FxHashMap<_, _>>();
+
+        let dynamic_refs = info
+            .imports
+            .dynamic
+            .iter()
+            .filter(|src| ctx.chunk_roots.contains(&src.module_id))
+            .map(|src| (src.src.value.clone(), chunk_ref(src.module_id)))
+            .collect::<FxHashMap<_, _>>();
+
+        if static_refs.is_empty() && dynamic_refs.is_empty() {
+            return;
+        }
+
+        module.visit_mut_with(&mut ChunkLinker {
+            static_refs,
+            dynamic_refs,
+        });
+    }
+
+    /// Lowers `export { a as b } from 'chunk'` and `export * from 'chunk'` in
+    /// a module merged into another chunk.
+    ///
+    /// The reexported values are imported from the chunk and assigned to the
+    /// exported identifiers, which works like a reexport from a merged module.
+    ///
+    /// `target` is the root of the imported chunk and `specifiers` is [None]
+    /// for `export *`.
+    pub(super) fn import_from_chunk_for_reexport(
+        &self,
+        info: &TransformedModule,
+        target: ModuleId,
+        span: Span,
+        src: &Str,
+        specifiers: Option<&[ExportSpecifier]>,
+    ) -> Vec<ModuleItem> {
+        let injected_ctxt = self.injected_ctxt;
+
+        let mut imports = Vec::new();
+        let mut vars = Vec::new();
+        let mut exports = Vec::new();
+
+        let mut add = |imported: Option<Ident>, exported: Ident| {
+            let local = private_ident!(if exported.is_reserved() {
+                format!("_{}", exported.sym)
+            } else {
+                exported.sym.to_string()
+            });
+
+            imports.push(match imported {
+                Some(imported) => ImportSpecifier::Named(ImportNamedSpecifier {
+                    span: DUMMY_SP,
+                    local: local.clone(),
+                    imported: Some(ModuleExportName::Ident(imported)),
+                    is_type_only: false,
+                }),
+                None => ImportSpecifier::Namespace(ImportStarAsSpecifier {
+                    span: DUMMY_SP,
+                    local: local.clone(),
+                }),
+            });
+            vars.push(
+                local
+                    .clone()
+                    .assign_to(exported.clone())
+                    .into_module_item(injected_ctxt, "prepare -> reexport from chunk"),
+            );
+            exports.push(ExportSpecifier::Named(ExportNamedSpecifier {
+                span: DUMMY_SP,
+                orig: ModuleExportName::Ident(local),
+                exported: Some(ModuleExportName::Ident(exported)),
+                is_type_only: false,
+            }));
+        };
+
+        let specifiers = match specifiers {
+            Some(specifiers) => specifiers,
+            None => {
+                // Names exported explicitly take precedence over `export *`.
+                let own = self.explicit_exports(info.id);
+
+                for name in self.exported_names(target, &mut Default::default()) {
+                    if own.contains(&name) {
+                        continue;
+                    }
+
+                    add(
+                        Some(Ident::new(name.clone(), DUMMY_SP, SyntaxContext::empty())),
+                        Ident::new(name, DUMMY_SP, info.export_ctxt()),
+                    );
+                }
+
+                &[]
+            }
+        };
+
+        for s in specifiers {
+            match s {
+                ExportSpecifier::Named(ExportNamedSpecifier {
+                    orig: ModuleExportName::Ident(orig),
+                    exported: Some(ModuleExportName::Ident(exported)),
+                    ..
+                }) => add(Some(orig.clone()), exported.clone()),
+                ExportSpecifier::Namespace(ExportNamespaceSpecifier {
+                    name: ModuleExportName::Ident(name),
+                    ..
+                }) => add(None, name.clone()),
+                _ => {}
+            }
+        }
+
+        if imports.is_empty() {
+            return Vec::new();
+        }
+
+        let mut items = vec![ImportDecl {
+            span,
+            specifiers: imports,
+            src: Box::new(src.clone()),
+            type_only: false,
+            with: None,
+            phase: Default::default(),
+        }
+        .into()];
+        items.extend(vars);
+        items.push(
+            NamedExport {
+                span,
+                specifiers: exports,
+                src: None,
+                type_only: false,
+                with: Some(
+                    ExportMetadata {
+                        injected: true,
+                        ..Default::default()
+                    }
+                    .into_with(),
+                ),
+            }
+            .into(),
+        );
+
+        items
+    }
+
+    /// Names exported by `export *` from a module.
+    fn exported_names(&self, id: ModuleId, visited: &mut FxHashSet<ModuleId>) -> Vec<Atom> {
+        let mut names = Vec::new();
+        if !visited.insert(id) {
+            return names;
+        }
+
+        names.extend(self.explicit_exports(id));
+        if let Some(m) = self.scope.get_module(id) {
+            for (src, specifiers) in &m.exports.reexports {
+                // `export * from './foo'`
+                if specifiers.is_empty() {
+                    names.extend(self.exported_names(src.module_id, visited));
+                }
+            }
+        }
+
+        // `default` is not exported by `export *`.
+        names.retain(|name| name != "default");
+        names.sort();
+        names.dedup();
+
+        names
+    }
+
+    /// Names exported by a module, excluding names exported with `export *`.
+    fn explicit_exports(&self, id: ModuleId) -> Vec<Atom> {
+        let m = match self.scope.get_module(id) {
+            Some(m) => m,
+            None => return Vec::new(),
+        };
+
+        m.exports
+            .items
+            .iter()
+            .chain(m.exports.reexports.iter().flat_map(|(_, s)| s))
+            .map(|s| {
+                let (Specifier::Specific { local, .. } | Specifier::Namespace { local, .. }) = s;
+                local.sym().clone()
+            })
+            .collect()
+    }
+}
+
+struct ChunkLinker {
+    static_refs: FxHashMap<Atom, Atom>,
+    dynamic_refs: FxHashMap<Atom, Atom>,
+}
+
+impl ChunkLinker {
+    fn link(refs: &FxHashMap<Atom, Atom>, src: &mut Str) -> bool {
+        match refs.get(&src.value) {
+            Some(v) => {
+                *src = Str {
+                    span: src.span,
+                    value: v.clone(),
+                    raw: None,
+                };
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl VisitMut for ChunkLinker {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        if !e.callee.is_import() {
+            return;
+        }
+
+        if let Some(ExprOrSpread { spread: None, expr }) = e.args.first_mut() {
+            if let Expr::Lit(Lit::Str(src)) = &mut **expr {
+                Self::link(&self.dynamic_refs, src);
+            }
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+        if Self::link(&self.static_refs, &mut export.src) {
+            export.with = None;
+        }
+    }
+
+    fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+        if Self::link(&self.static_refs, &mut import.src) {
+            import.with = None;
+        }
+    }
+
+    fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+        if let Some(src) = &mut export.src {
+            if Self::link(&self.static_refs, src) {
+                export.with = None;
+            }
+        }
+    }
+}
@@ -11,6 +11,7 @@ use swc_ecma_utils::{find_pat_ids, prepend_stmt, private_ident, quote_ident, Exp
 use swc_ecma_visit::{VisitMut, VisitMutWith};
 use EdgeDirection::Outgoing;
 
+use super::link::{chunk_ref, is_chunk_ref};
 use crate::{
     bundler::{keywords::KeywordRenamer, load::TransformedModule},
     dep_graph::ModuleGraph,
@@ -29,6 +30,11 @@ pub(super) struct Ctx {
     pub cycles: Vec<Vec<ModuleId>>,
     pub transitive_remap: CloneMap<SyntaxContext, SyntaxContext>,
     pub export_stars_in_wrapped: Lock<FxHashMap<ModuleId, Vec<SyntaxContext>>>,
+    /// Modules emitted as separate chunks. Empty unless code splitting is
+    /// enabled.
+    pub chunk_roots: FxHashSet<ModuleId>,
+    /// Roots of the chunks each module is merged into.
+    pub owners: FxHashMap<ModuleId, FxHashSet<ModuleId>>,
 }
 
 impl Ctx {
@@ -70,6 +76,7 @@ where
                 .get_module(id)
                 .unwrap_or_else(|| unreachable!("Module {} is not registered", id));
             let mut module = self.apply_hooks(id, is_entry)?;
+            self.link_chunks(ctx, &info, &mut module);
             module = self.prepare_for_merging(ctx, &info, module)?;
 
             if !is_entry {
@@ -94,8 +101,7 @@ where
 
             let entry_info = self.scope.get_module(entry_id).unwrap();
 
-            let all_deps_of_entry =
-                self.collect_all_deps(&ctx.graph, entry_id, &mut Default::default());
+            let all_deps_of_entry = self.collect_all_deps(ctx, entry_id, &mut Default::default());
 
             tracing::debug!("Merging dependencies: {:?}", all_deps_of_entry);
 
@@ -137,21 +143,22 @@ where
         })
     }
 
+    /// Modules emitted as other chunks are not included.
     #[allow(clippy::only_used_in_recursion)]
-    fn collect_all_deps(
+    pub(super) fn collect_all_deps(
         &self,
-        graph: &ModuleGraph,
+        ctx: &Ctx,
         start: ModuleId,
         dejavu: &mut FxHashSet<ModuleId>,
     ) -> IndexSet<ModuleId, FxBuildHasher> {
         let mut set = IndexSet::default();
 
-        for dep in graph.neighbors_directed(start, Outgoing) {
-            if !dejavu.insert(dep) {
+        for dep in ctx.graph.neighbors_directed(start, Outgoing) {
+            if ctx.chunk_roots.contains(&dep) || !dejavu.insert(dep) {
                 continue;
             }
             set.insert(dep);
-            set.extend(self.collect_all_deps(graph, dep, dejavu));
+            set.extend(self.collect_all_deps(ctx, dep, dejavu));
         }
 
         set
@@ -445,7 +452,9 @@ where
         entry.retain_mut(|_, item| {
             match item {
                 ModuleItem::ModuleDecl(ModuleDecl::ExportAll(export)) => {
-                    if self.config.external_modules.contains(&export.src.value) {
+                    if self.config.external_modules.contains(&export.src.value)
+                        || is_chunk_ref(&export.src.value)
+                    {
                         return true;
                     }
 
@@ -454,7 +463,9 @@ where
 
                 ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(export)) => {
                     if let Some(src) = &export.src {
-                        if self.config.external_modules.contains(&src.value) {
+                        if self.config.external_modules.contains(&src.value)
+                            || is_chunk_ref(&src.value)
+                        {
                             return true;
                         }
                     }
@@ -467,7 +478,9 @@ where
                 }
 
                 ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.config.external_modules.contains(&import.src.value)
+                        || is_chunk_ref(&import.src.value)
+                    {
                         return true;
                     }
 
@@ -556,7 +569,9 @@ where
     ) -> Result<Modules, Error> {
         self.handle_imports_and_exports(ctx, info, &mut module);
 
-        let wrapped = self.scope.should_be_wrapped_with_a_fn(info.id);
+        // Other chunks import a chunk root directly.
+        let wrapped =
+            self.scope.should_be_wrapped_with_a_fn(info.id) && !ctx.chunk_roots.contains(&info.id);
         if wrapped {
             module = self.wrap_esm(ctx, info.id, module)?;
         }
@@ -584,8 +599,10 @@ where
             for item in items {
                 match item {
                     ModuleItem::ModuleDecl(ModuleDecl::Import(mut import)) => {
-                        // Preserve imports from node.js builtin modules.
-                        if self.config.external_modules.contains(&import.src.value) {
+                        // Preserve imports from node.js builtin modules and other chunks.
+                        if self.config.external_modules.contains(&import.src.value)
+                            || is_chunk_ref(&import.src.value)
+                        {
                             new.push(import.into());
                             continue;
                         }
@@ -969,10 +986,36 @@ where
                     }
 
                     ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport {
+                        span,
                         ref specifiers,
                         ref src,
                         ..
                     })) => {
+                        if let Some(export_src) = src.as_deref().filter(|s| is_chunk_ref(&s.value))
+                        {
+                            // Reexports from other chunks are preserved by chunk roots.
+                            if ctx.chunk_roots.contains(&info.id) {
+                                new.push(item);
+                                continue;
+                            }
+
+                            if let Some((src, _)) = info
+                                .exports
+                                .reexports
+                                .iter()
+                                .find(|s| chunk_ref(s.0.module_id) == export_src.value)
+                            {
+                                new.extend(self.import_from_chunk_for_reexport(
+                                    info,
+                                    src.module_id,
+                                    span,
+                                    export_src,
+                                    Some(specifiers),
+                                ));
+                            }
+                            continue;
+                        }
+
                         if let Some(export_src) = src {
                             if let Some((src, _)) = info
                                 .exports
@@ -1211,6 +1254,30 @@ where
                         new.push(item);
                     }
 
+                    ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ref export))
+                        if is_chunk_ref(&export.src.value) =>
+                    {
+                        if ctx.chunk_roots.contains(&info.id) {
+                            new.push(item);
+                            continue;
+                        }
+
+                        if let Some((src, _)) = info
+                            .exports
+                            .reexports
+                            .iter()
+                            .find(|s| chunk_ref(s.0.module_id) == export.src.value)
+                        {
+                            new.extend(self.import_from_chunk_for_reexport(
+                                info,
+                                src.module_id,
+                                export.span,
+                                &export.src,
+                                None,
+                            ));
+                        }
+                    }
+
                     ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ref export)) => {
                         let metadata = ExportMetadata::decode(export.with.as_deref());
 
@@ -1247,7 +1314,9 @@ where
 
             for stmt in stmts {
                 if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = &stmt {
-                    if self.config.external_modules.contains(&import.src.value) {
+                    if self.config.external_modules.contains(&import.src.value)
+                        || is_chunk_ref(&import.src.value)
+                    {
                         new.push(stmt);
                         continue;
                     }
@@ -8,10 +8,12 @@ use rustc_hash::FxHashMap;
 use super::{load::TransformedModule, Bundler};
 use crate::{
     bundler::chunk::merge::Ctx, load::Load, resolve::Resolve, util::IntoParallelIterator, Bundle,
+    BundleKind, ModuleId,
 };
 
 mod cjs;
 mod computed_key;
+pub(super) mod link;
 mod merge;
 mod plan;
 
@@ -25,10 +27,13 @@ where
     /// # How it works
     ///
     /// For first, we load all dependencies and determine all entries.
+    ///
+    /// Returns the bundles and the modules merged into each bundle.
+    #[allow(clippy::type_complexity)]
     pub(super) fn chunk(
         &self,
         entries: FxHashMap<String, TransformedModule>,
-    ) -> Result<Vec<Bundle>, Error> {
+    ) -> Result<(Vec<Bundle>, FxHashMap<ModuleId, Vec<ModuleId>>), Error> {
         #[cfg(not(target_arch = "wasm32"))]
         let start = Instant::now();
         let (plan, graph, cycles) = self.determine_entries(entries).context("failed to plan")?;
@@ -53,11 +58,19 @@ where
             }
         }
 
+        let chunk_roots = if self.config.code_splitting {
+            plan.entries.keys().copied().collect()
+        } else {
+            Default::default()
+        };
+
         let ctx = Ctx {
             graph,
             cycles,
             transitive_remap: Default::default(),
             export_stars_in_wrapped: Default::default(),
+            chunk_roots,
+            owners: plan.owners,
         };
 
         #[cfg(not(target_arch = "wasm32"))]
@@ -67,7 +80,7 @@ where
             .map(|id| -> Result<_, Error> {
                 self.run(|| {
                     // TODO: is_entry should be false if it's dep of other entry.
-                    let is_entry = plan.entries.contains_key(id);
+                    let is_entry = matches!(plan.entries.get(id), Some(BundleKind::Named { .. }));
                     let module = self.get_for_merging(&ctx, *id, is_entry)?;
 
                     Ok((*id, module))
@@ -80,7 +93,7 @@ where
         #[cfg(not(target_arch = "wasm32"))]
         tracing::debug!("Module preparation took {:?}", dur);
 
-        let entries = all
+        let mut entries = all
             .iter()
             .filter_map(|(id, module)| {
                 if plan.entries.contains_key(id) {
@@ -89,6 +102,21 @@ where
                 None
             })
             .collect::<Vec<_>>();
+        entries.sort_by_key(|(id, _)| *id);
+
+        let members = entries
+            .iter()
+            .map(|(id, _)| {
+                let mut modules = vec![*id];
+                modules.extend(
+                    self.collect_all_deps(&ctx, *id, &mut Default::default())
+                        .into_iter()
+                        .filter(|dep| dep != id),
+                );
+
+                (*id, modules)
+            })
+            .collect();
 
         let merged: Vec<_> = if entries.len() == 1 {
             entries
@@ -112,6 +140,8 @@ where
                         kind,
                         id,
                         module: module.into(),
+                        comments: Default::default(),
+                        metadata: None,
                     }
                 })
                 .collect()
@@ -138,12 +168,14 @@ where
                         kind,
                         id,
                         module: module.into(),
+                        comments: Default::default(),
+                        metadata: None,
                     }
                 })
                 .collect()
         };
 
-        Ok(merged)
+        Ok((merged, members))
     }
 }
 
@@ -175,7 +207,7 @@ mod tests {
                 let mut entries = FxHashMap::default();
                 entries.insert("main.js".to_string(), module);
 
-                let chunked = t.bundler.chunk(entries)?;
+                let (chunked, _) = t.bundler.chunk(entries)?;
                 assert_eq!(chunked.len(), 1);
 
                 Ok(())
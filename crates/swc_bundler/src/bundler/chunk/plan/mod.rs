@@ -1,5 +1,7 @@
 use anyhow::{bail, Error};
-use rustc_hash::FxHashMap;
+use petgraph::EdgeDirection::{Incoming, Outgoing};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_common::FileName;
 use swc_graph_analyzer::{DepGraph, GraphAnalyzer};
 
 use crate::{
@@ -22,6 +24,11 @@ pub(super) struct Plan {
 
     /// Id of all modules.
     pub all: Vec<ModuleId>,
+
+    /// Roots of the chunks each module is merged into.
+    ///
+    /// This is empty unless code splitting is enabled.
+    pub owners: FxHashMap<ModuleId, FxHashSet<ModuleId>>,
 }
 
 impl DepGraph for Scope {
@@ -58,17 +65,165 @@ where
 
             analyzer.load(module.id);
         }
+
+        if self.config.code_splitting {
+            for id in self.add_dynamic_roots(&mut builder.kinds) {
+                analyzer.load(id);
+            }
+        }
+
         let res = analyzer.into_result();
 
         // dbg!(&builder.cycles);
 
+        let owners = if self.config.code_splitting {
+            self.extract_shared_chunks(&res.graph, &res.cycles, &mut builder.kinds)
+        } else {
+            Default::default()
+        };
+
         Ok((
             Plan {
                 entries: builder.kinds,
                 all: res.all,
+                owners,
             },
             res.graph,
             res.cycles,
         ))
     }
+
+    /// Marks modules loaded with `import()` as roots of dynamic chunks and
+    /// returns the newly added roots.
+    fn add_dynamic_roots(&self, kinds: &mut FxHashMap<ModuleId, BundleKind>) -> Vec<ModuleId> {
+        let mut queue = kinds.keys().copied().collect::<Vec<_>>();
+        queue.sort();
+        let mut visited = queue.iter().copied().collect::<FxHashSet<_>>();
+        let mut added = Vec::new();
+
+        while let Some(id) = queue.pop() {
+            let m = self.scope.get_module(id).expect("failed to get module");
+
+            for dep in &m.imports.dynamic {
+                let dep = dep.module_id;
+                if kinds.contains_key(&dep) || !self.can_be_chunk_root(dep) {
+                    continue;
+                }
+
+                kinds.insert(dep, BundleKind::Dynamic);
+                added.push(dep);
+            }
+
+            for dep in m
+                .imports
+                .specifiers
+                .iter()
+                .chain(m.exports.reexports.iter())
+                .map(|v| v.0.module_id)
+                .chain(m.imports.dynamic.iter().map(|v| v.module_id))
+            {
+                if visited.insert(dep) {
+                    queue.push(dep);
+                }
+            }
+        }
+
+        added.sort();
+        added
+    }
+
+    /// Moves modules used by multiple chunks into shared chunks, so they are
+    /// not duplicated.
+    ///
+    /// A module is extracted if it's reachable from two or more chunk roots and
+    /// it's the topmost module with that set of roots. Modules in cycles and
+    /// common js modules are duplicated into each chunk instead.
+    ///
+    /// Returns the roots of the chunks each module is merged into.
+    fn extract_shared_chunks(
+        &self,
+        graph: &ModuleGraph,
+        cycles: &[Vec<ModuleId>],
+        kinds: &mut FxHashMap<ModuleId, BundleKind>,
+    ) -> FxHashMap<ModuleId, FxHashSet<ModuleId>> {
+        let in_cycle = cycles.iter().flatten().copied().collect::<FxHashSet<_>>();
+
+        loop {
+            let owners = collect_owners(graph, kinds);
+
+            let mut shared = owners
+                .iter()
+                .filter(|(id, roots)| {
+                    roots.len() > 1
+                        && !in_cycle.contains(id)
+                        && self.can_be_chunk_root(**id)
+                        && graph
+                            .neighbors_directed(**id, Incoming)
+                            .any(|parent| owners.get(&parent) != Some(*roots))
+                })
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>();
+
+            if shared.is_empty() {
+                return owners;
+            }
+            shared.sort();
+
+            for id in shared {
+                let name = self.chunk_name(id);
+                kinds.insert(id, BundleKind::Lib { name });
+            }
+        }
+    }
+
+    /// Returns `true` if the module can be emitted as a separate chunk.
+    fn can_be_chunk_root(&self, id: ModuleId) -> bool {
+        match self.scope.get_module(id) {
+            Some(m) => m.is_es6 && !self.scope.is_cjs(id),
+            None => false,
+        }
+    }
+
+    /// Name of the chunk created for `id`, before hashing.
+    pub(crate) fn chunk_name(&self, id: ModuleId) -> String {
+        let m = self.scope.get_module(id).expect("failed to get module");
+
+        let stem = match &*m.fm.name {
+            FileName::Real(path) => path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string()),
+            _ => None,
+        };
+
+        format!("{}.js", stem.as_deref().unwrap_or("chunk"))
+    }
+}
+
+/// Returns the chunk roots each module is merged into. Chunk roots are not
+/// merged into other chunks.
+fn collect_owners(
+    graph: &ModuleGraph,
+    kinds: &FxHashMap<ModuleId, BundleKind>,
+) -> FxHashMap<ModuleId, FxHashSet<ModuleId>> {
+    let mut owners = FxHashMap::<_, FxHashSet<_>>::default();
+
+    for &root in kinds.keys() {
+        owners.entry(root).or_default().insert(root);
+
+        let mut visited = FxHashSet::default();
+        let mut queue = vec![root];
+
+        while let Some(id) = queue.pop() {
+            for dep in graph.neighbors_directed(id, Outgoing) {
+                if kinds.contains_key(&dep) || !visited.insert(dep) {
+                    continue;
+                }
+
+                owners.entry(dep).or_default().insert(root);
+                queue.push(dep);
+            }
+        }
+    }
+
+    owners
 }
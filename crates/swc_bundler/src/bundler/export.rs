@@ -106,8 +106,9 @@ where
             _ => return,
         };
         let (id, _, _) = self.bundler.scope.module_id_gen.gen(&path);
+        let (by, _, _) = self.bundler.scope.module_id_gen.gen(self.file_name);
 
-        self.bundler.scope.mark_as_wrapping_required(id);
+        self.bundler.scope.mark_as_wrapping_required(by, id);
     }
 }
 
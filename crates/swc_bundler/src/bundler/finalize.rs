@@ -3,6 +3,7 @@ use std::path::{Path, PathBuf};
 use anyhow::Error;
 use relative_path::RelativePath;
 use rustc_hash::FxHashMap;
+use swc_atoms::Atom;
 use swc_common::{util::move_map::MoveMap, FileName, Mark, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_base::{
@@ -11,9 +12,13 @@ use swc_ecma_transforms_base::{
     hygiene::hygiene,
 };
 use swc_ecma_utils::{contains_top_level_await, find_pat_ids, private_ident, ExprFactory};
-use swc_ecma_visit::{noop_fold_type, Fold, FoldWith, VisitMutWith};
+use swc_ecma_visit::{noop_fold_type, noop_visit_mut_type, Fold, FoldWith, VisitMut, VisitMutWith};
 
-use crate::{hash::calc_hash, Bundle, BundleKind, Bundler, Load, ModuleType, Resolve};
+use super::chunk::link::chunk_ref;
+use crate::{
+    hash::calc_hash, Bundle, BundleKind, Bundler, ChunkManifest, Load, ManifestChunk, ModuleId,
+    ModuleType, Resolve,
+};
 
 impl<L, R> Bundler<'_, L, R>
 where
@@ -25,22 +30,38 @@ where
     /// - inject helpers
     /// - rename chunks
     /// - invoke fixer
+    /// - add license comments, module banners and metadata
+    /// - link chunks created by code splitting
     pub(super) fn finalize(
         &self,
         bundles: Vec<Bundle>,
+        members: FxHashMap<ModuleId, Vec<ModuleId>>,
         unresolved_mark: Mark,
-    ) -> Result<Vec<Bundle>, Error> {
+    ) -> Result<(Vec<Bundle>, ChunkManifest), Error> {
         self.run(|| {
             let mut new = Vec::with_capacity(bundles.len());
             let mut renamed = FxHashMap::default();
+            let mut kinds = Vec::with_capacity(bundles.len());
 
             for mut bundle in bundles {
+                kinds.push(bundle.kind.clone());
+                let modules = members.get(&bundle.id).map_or(&[][..], |v| &v[..]);
+
                 bundle.module = self.optimize(bundle.module);
 
                 if !self.config.disable_hygiene {
                     bundle.module.visit_mut_with(&mut hygiene());
                 }
 
+                // Statements are attributed to modules by their spans, which is easier before
+                // wrapping the module.
+                if self.config.module_banners {
+                    self.add_module_banners(&bundle.module, &mut bundle.comments);
+                }
+                if self.config.metadata {
+                    bundle.metadata = Some(self.metadata(&bundle.module, modules)?);
+                }
+
                 bundle.module = self.may_wrap_with_iife(bundle.module);
 
                 if !self.config.disable_fixer {
@@ -63,59 +84,105 @@ where
                     });
                 }
 
-                match bundle.kind {
-                    BundleKind::Named { .. } => {
-                        // Inject helpers
-                        let helpers = self
-                            .scope
-                            .get_module(bundle.id)
-                            .expect("module should exist at this point")
-                            .helpers;
+                {
+                    // Inject helpers
+                    let helpers = self
+                        .scope
+                        .get_module(bundle.id)
+                        .expect("module should exist at this point")
+                        .helpers;
+
+                    helpers.add_to(&mut bundle.module.body);
+                }
 
-                        helpers.add_to(&mut bundle.module.body);
+                if self.config.preserve_license_comments {
+                    self.add_license_comments(&mut bundle.module, modules, &mut bundle.comments);
+                }
 
+                let name = match bundle.kind {
+                    BundleKind::Named { .. } => {
                         new.push(bundle);
+                        continue;
                     }
-                    BundleKind::Lib { name } => {
-                        let hash = calc_hash(self.cm.clone(), &bundle.module)?;
-                        let mut new_name = PathBuf::from(name);
-                        let key = new_name.clone();
-                        let file_name = new_name
-                            .file_name()
-                            .map(|path| -> PathBuf {
-                                let path = Path::new(path);
-                                let ext = path.extension();
-                                if let Some(ext) = ext {
-                                    return format!(
-                                        "{}-{}.{}",
-                                        path.file_stem().unwrap().to_string_lossy(),
-                                        hash,
-                                        ext.to_string_lossy()
-                                    )
-                                    .into();
-                                }
-                                format!("{}-{}", path.file_stem().unwrap().to_string_lossy(), hash,)
-                                    .into()
-                            })
-                            .expect("javascript file should have name");
-                        new_name.pop();
-                        new_name = new_name.join(file_name.clone());
-
-                        renamed.insert(key, new_name.to_string_lossy().to_string());
-
-                        new.push(Bundle {
-                            kind: BundleKind::Named {
-                                name: file_name.display().to_string(),
-                            },
-                            ..bundle
-                        })
-                    }
-                    _ => new.push(bundle),
+                    BundleKind::Lib { name } => name,
+                    BundleKind::Dynamic => self.chunk_name(bundle.id),
+                };
+
+                let hash = calc_hash(self.cm.clone(), &bundle.module)?;
+                let mut new_name = PathBuf::from(name);
+                let key = new_name.clone();
+                let file_name = new_name
+                    .file_name()
+                    .map(|path| -> PathBuf {
+                        let path = Path::new(path);
+                        let ext = path.extension();
+                        if let Some(ext) = ext {
+                            return format!(
+                                "{}-{}.{}",
+                                path.file_stem().unwrap().to_string_lossy(),
+                                hash,
+                                ext.to_string_lossy()
+                            )
+                            .into();
+                        }
+                        format!("{}-{}", path.file_stem().unwrap().to_string_lossy(), hash,).into()
+                    })
+                    .expect("javascript file should have name");
+                new_name.pop();
+                new_name = new_name.join(file_name.clone());
+
+                renamed.insert(key, new_name.to_string_lossy().to_string());
+
+                new.push(Bundle {
+                    kind: BundleKind::Named {
+                        name: file_name.display().to_string(),
+                    },
+                    ..bundle
+                })
+            }
+
+            let mut manifest = ChunkManifest {
+                chunks: new
+                    .iter()
+                    .zip(kinds)
+                    .map(|(bundle, kind)| ManifestChunk {
+                        file: match &bundle.kind {
+                            BundleKind::Named { name } => name.clone(),
+                            _ => unreachable!("all bundles should be named at this point"),
+                        },
+                        kind,
+                        id: bundle.id,
+                        modules: members
+                            .get(&bundle.id)
+                            .into_iter()
+                            .flatten()
+                            .map(|id| (*self.scope.get_module(*id).unwrap().fm.name).clone())
+                            .collect(),
+                        imports: Vec::new(),
+                        dynamic_imports: Vec::new(),
+                    })
+                    .collect(),
+            };
+
+            if self.config.code_splitting {
+                let files = manifest
+                    .chunks
+                    .iter()
+                    .map(|chunk| (chunk_ref(chunk.id), chunk.file.clone()))
+                    .collect::<FxHashMap<_, _>>();
+
+                for (bundle, chunk) in new.iter_mut().zip(manifest.chunks.iter_mut()) {
+                    bundle.module.visit_mut_with(&mut ChunkRefResolver {
+                        files: &files,
+                        chunk,
+                    });
                 }
+
+                return Ok((new, manifest));
             }
 
             if new.len() == 1 {
-                return Ok(new);
+                return Ok((new, manifest));
             }
 
             new = new.move_map(|bundle| {
@@ -140,7 +207,7 @@ where
                 Bundle { module, ..bundle }
             });
 
-            Ok(new)
+            Ok((new, manifest))
         })
     }
 
@@ -418,16 +485,9 @@ where
                 .unwrap_or(self.base)
                 .as_os_str()
                 .to_string_lossy();
-            let base = RelativePath::new(&*base);
-            let v = base.relative(v);
-            let value = v.as_str();
             return ImportDecl {
                 src: Box::new(Str {
-                    value: if value.starts_with('.') {
-                        value.into()
-                    } else {
-                        format!("./{}", value).into()
-                    },
+                    value: relative_src(&base, v),
                     ..*import.src
                 }),
                 ..import
@@ -437,3 +497,88 @@ where
         import
     }
 }
+
+/// Returns the import path of `file` relative to the directory `base`.
+fn relative_src(base: &str, file: &str) -> Atom {
+    let v = RelativePath::new(base).relative(file);
+    let value = v.as_str();
+    if value.starts_with('.') {
+        value.into()
+    } else {
+        format!("./{}", value).into()
+    }
+}
+
+/// Replaces [chunk_ref]s with paths of the chunk files and records them in
+/// the manifest.
+struct ChunkRefResolver<'a> {
+    /// File names of chunks by [chunk_ref].
+    files: &'a FxHashMap<Atom, String>,
+    chunk: &'a mut ManifestChunk,
+}
+
+impl ChunkRefResolver<'_> {
+    /// Returns the file name of the chunk if `src` is a [chunk_ref].
+    fn resolve(&self, src: &mut Str) -> Option<String> {
+        let file = self.files.get(&src.value)?;
+
+        // Chunks are emitted into the same directory, but names of entries may
+        // contain a directory.
+        let base = Path::new(&self.chunk.file)
+            .parent()
+            .map(|v| v.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        *src = Str {
+            span: src.span,
+            value: relative_src(&base, file),
+            raw: None,
+        };
+
+        Some(file.clone())
+    }
+
+    fn add_import(&mut self, src: &mut Str) {
+        if let Some(file) = self.resolve(src) {
+            if !self.chunk.imports.contains(&file) {
+                self.chunk.imports.push(file);
+            }
+        }
+    }
+}
+
+impl VisitMut for ChunkRefResolver<'_> {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_call_expr(&mut self, e: &mut CallExpr) {
+        e.visit_mut_children_with(self);
+
+        if !e.callee.is_import() {
+            return;
+        }
+
+        if let Some(ExprOrSpread { spread: None, expr }) = e.args.first_mut() {
+            if let Expr::Lit(Lit::Str(src)) = &mut **expr {
+                if let Some(file) = self.resolve(src) {
+                    if !self.chunk.dynamic_imports.contains(&file) {
+                        self.chunk.dynamic_imports.push(file);
+                    }
+                }
+            }
+        }
+    }
+
+    fn visit_mut_export_all(&mut self, export: &mut ExportAll) {
+        self.add_import(&mut export.src);
+    }
+
+    fn visit_mut_import_decl(&mut self, import: &mut ImportDecl) {
+        self.add_import(&mut import.src);
+    }
+
+    fn visit_mut_named_export(&mut self, export: &mut NamedExport) {
+        if let Some(src) = &mut export.src {
+            self.add_import(src);
+        }
+    }
+}
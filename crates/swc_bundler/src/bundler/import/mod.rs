@@ -171,8 +171,9 @@ where
             Err(_) => return,
         };
         let (id, _, _) = self.bundler.scope.module_id_gen.gen(&path);
+        let (by, _, _) = self.bundler.scope.module_id_gen.gen(self.path);
 
-        self.bundler.scope.mark_as_wrapping_required(id);
+        self.bundler.scope.mark_as_wrapping_required(by, id);
     }
 
     fn mark_as_cjs(&self, src: &Atom) {
@@ -182,8 +183,9 @@ where
             Err(_) => return,
         };
         let (id, _, _) = self.bundler.scope.module_id_gen.gen(&path);
+        let (by, _, _) = self.bundler.scope.module_id_gen.gen(self.path);
 
-        self.bundler.scope.mark_as_cjs(id);
+        self.bundler.scope.mark_as_cjs(by, id);
     }
 
     fn add_forced_ns_for(&mut self, id: Id) {
@@ -251,19 +253,21 @@ where
                         self.info.lazy_imports.push(decl);
                     }
 
-                    // TODO: Uncomment this after implementing an option to make swc_bundler
-                    // includes dynamic imports
-                    //
-                    //
-                    // ExprOrSuper::Expr(ref e) => match &**e {
-                    //     Expr::Ident(Ident {
-                    //         sym: "import",
-                    //         ..
-                    //     }) => {
-                    //         self.info.dynamic_imports.push(src.clone());
-                    //     }
-                    //     _ => {}
-                    // },
+                    Callee::Import(_) if self.bundler.config.code_splitting => {
+                        if self.bundler.is_external(&src.value) {
+                            return;
+                        }
+
+                        if !self
+                            .info
+                            .dynamic_imports
+                            .iter()
+                            .any(|v| v.value == src.value)
+                        {
+                            self.info.dynamic_imports.push(src.clone());
+                        }
+                    }
+
                     _ => {}
                 }
             }
@@ -85,6 +85,15 @@ impl VisitMut for KeywordRenamer {
         }
     }
 
+    fn visit_mut_named_export(&mut self, n: &mut NamedExport) {
+        // `export { default as foo } from 'foo'` refers to names of another module.
+        if n.src.is_some() {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
     fn visit_mut_object_pat_prop(&mut self, n: &mut ObjectPatProp) {
         if let ObjectPatProp::Assign(pat) = n {
             if let Some(renamed) = self.renamed(&pat.key) {
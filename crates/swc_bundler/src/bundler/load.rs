@@ -4,6 +4,7 @@ use anyhow::{Context, Error};
 use is_macro::Is;
 #[cfg(feature = "rayon")]
 use rayon::iter::ParallelIterator;
+use rustc_hash::FxHashSet;
 use swc_common::{
     sync::{Lock, Lrc},
     FileName, SourceFile, SyntaxContext,
@@ -75,6 +76,8 @@ where
         self.run(|| {
             tracing::trace!("load_transformed: ({})", file_name);
 
+            self.globals.cancellation().check()?;
+
             // In case of common module
             if let Some(cached) = self.scope.get_module_by_path(file_name) {
                 tracing::debug!("Cached: {}", file_name);
@@ -114,6 +117,52 @@ where
         })
     }
 
+    /// Loads dependencies of cached modules which are not loaded.
+    ///
+    /// This happens if modules are removed from the cache with
+    /// [crate::Bundler::invalidate], as [Bundler::load_transformed] does not
+    /// visit dependencies of cached modules.
+    pub(super) fn load_invalidated(&self, entries: &[ModuleId]) -> Result<(), Error> {
+        let mut visited = FxHashSet::default();
+        let mut queue = entries.to_vec();
+
+        while let Some(id) = queue.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+
+            let info = match self.scope.get_module(id) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let deps = info
+                .imports
+                .specifiers
+                .iter()
+                .chain(info.exports.reexports.iter())
+                .map(|(src, _)| src)
+                .chain(info.imports.dynamic.iter());
+
+            for src in deps {
+                if self.scope.get_module(src.module_id).is_none() {
+                    let file_name = self
+                        .scope
+                        .module_id_gen
+                        .file_name(src.module_id)
+                        .with_context(|| format!("unknown module: {:?}", src.module_id))?;
+
+                    self.load_transformed(&file_name)
+                        .with_context(|| format!("failed to reload {}", file_name))?;
+                }
+
+                queue.push(src.module_id);
+            }
+        }
+
+        Ok(())
+    }
+
     fn load(&self, file_name: &FileName) -> Result<(ModuleId, ModuleData), Error> {
         self.run(|| {
             let (module_id, _, _) = self.scope.module_id_gen.gen(file_name);
@@ -375,6 +424,11 @@ where
                     }
                 }
 
+                if is_dynamic {
+                    merged.dynamic.push(src);
+                    continue;
+                }
+
                 merged.specifiers.push((src, specifiers));
             }
 
@@ -387,6 +441,11 @@ where
 pub(crate) struct Imports {
     /// If imported ids are empty, it is a side-effect import.
     pub specifiers: Vec<(Source, Vec<Specifier>)>,
+
+    /// Modules loaded with `import()`.
+    ///
+    /// This is empty unless [crate::Config::code_splitting] is enabled.
+    pub dynamic: Vec<Source>,
 }
 
 /// Clone is relatively cheap
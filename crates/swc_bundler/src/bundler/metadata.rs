@@ -0,0 +1,144 @@
+use anyhow::{Context, Error};
+use rustc_hash::FxHashMap;
+use serde::Serialize;
+use swc_common::{sync::Lrc, FileName, Spanned, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_utils::find_pat_ids;
+
+use super::banner::display_path;
+use crate::{Bundler, Load, ModuleId, Resolve};
+
+/// Information about a bundle for bundle analysis, which can be serialized
+/// with [BundleMetadata::to_json].
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleMetadata {
+    /// Modules merged into the bundle. The first one is the entry.
+    pub modules: Vec<ModuleMetadata>,
+    /// Names exported by the bundle.
+    pub exports: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleMetadata {
+    /// Path of the module, relative to the current directory if possible.
+    pub file: String,
+    /// Size of the source of the module in bytes.
+    pub original_size: usize,
+    /// Size of the code emitted for the module in bytes, excluding comments.
+    ///
+    /// This is `0` if all of the module is removed by tree shaking.
+    pub rendered_size: usize,
+}
+
+impl BundleMetadata {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("failed to serialize bundle metadata")
+    }
+}
+
+impl<L, R> Bundler<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    pub(super) fn metadata(
+        &self,
+        module: &Module,
+        modules: &[ModuleId],
+    ) -> Result<BundleMetadata, Error> {
+        let mut rendered = FxHashMap::<Lrc<FileName>, usize>::default();
+
+        for item in &module.body {
+            let span = item.span();
+            if span.is_dummy() {
+                continue;
+            }
+
+            let file = match self.cm.try_lookup_source_file(span.lo) {
+                Ok(fm) => fm.name.clone(),
+                Err(_) => continue,
+            };
+
+            let mut buf = Vec::new();
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: self.cm.clone(),
+                    comments: None,
+                    wr: Box::new(JsWriter::new(self.cm.clone(), "\n", &mut buf, None)),
+                };
+
+                emitter
+                    .emit_module(&Module {
+                        span: DUMMY_SP,
+                        body: vec![item.clone()],
+                        shebang: None,
+                    })
+                    .context("failed to emit module to calculate size")?;
+            }
+
+            *rendered.entry(file).or_default() += buf.len();
+        }
+
+        Ok(BundleMetadata {
+            modules: modules
+                .iter()
+                .filter_map(|id| self.scope.get_module(*id))
+                .map(|m| ModuleMetadata {
+                    file: display_path(&m.fm.name),
+                    original_size: m.fm.src.len(),
+                    rendered_size: rendered.get(&m.fm.name).copied().unwrap_or_default(),
+                })
+                .collect(),
+            exports: exported_names(module),
+        })
+    }
+}
+
+fn exported_names(module: &Module) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for item in &module.body {
+        let decl = match item {
+            ModuleItem::ModuleDecl(v) => v,
+            ModuleItem::Stmt(_) => continue,
+        };
+
+        match decl {
+            ModuleDecl::ExportDecl(export) => match &export.decl {
+                Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                    names.push(ident.sym.to_string());
+                }
+                Decl::Var(decl) => {
+                    let ids: Vec<Ident> = find_pat_ids(decl);
+                    names.extend(ids.into_iter().map(|id| id.sym.to_string()));
+                }
+                _ => {}
+            },
+            ModuleDecl::ExportNamed(export) => {
+                for s in &export.specifiers {
+                    let name = match s {
+                        ExportSpecifier::Namespace(s) => &s.name,
+                        ExportSpecifier::Default(s) => {
+                            names.push(s.exported.sym.to_string());
+                            continue;
+                        }
+                        ExportSpecifier::Named(s) => s.exported.as_ref().unwrap_or(&s.orig),
+                    };
+                    names.push(name.atom().to_string());
+                }
+            }
+            ModuleDecl::ExportDefaultDecl(_) | ModuleDecl::ExportDefaultExpr(_) => {
+                names.push("default".into());
+            }
+            _ => {}
+        }
+    }
+
+    names.sort();
+    names.dedup();
+    names
+}
@@ -1,14 +1,19 @@
 use std::collections::HashMap;
 
-use anyhow::{Context, Error};
+use anyhow::{bail, Context, Error};
 use rustc_hash::FxHashMap;
 use swc_atoms::Atom;
-use swc_common::{sync::Lrc, FileName, Globals, Mark, SourceMap, SyntaxContext, GLOBALS};
+use swc_common::{
+    comments::SingleThreadedCommentsMapInner, sync::Lrc, FileName, Globals, Mark, SourceMap,
+    SyntaxContext, GLOBALS,
+};
 use swc_ecma_ast::Module;
 
+pub use self::metadata::{BundleMetadata, ModuleMetadata};
 use self::scope::Scope;
 use crate::{Hook, Load, ModuleId, Resolve};
 
+mod banner;
 mod chunk;
 mod export;
 mod finalize;
@@ -16,6 +21,7 @@ mod helpers;
 mod import;
 mod keywords;
 mod load;
+mod metadata;
 mod optimize;
 mod scope;
 #[cfg(test)]
@@ -45,6 +51,44 @@ pub struct Config {
 
     /// Type of emitted module
     pub module: ModuleType,
+
+    /// Make module ids, names of dynamic chunks and the output byte-identical
+    /// across runs and thread counts.
+    ///
+    /// Modules are loaded and prepared on a single thread if this is true.
+    pub deterministic: bool,
+
+    /// Split the output into chunks at dynamic `import()` boundaries.
+    ///
+    /// If it's true, modules loaded with `import()` are emitted as separate
+    /// chunks and modules used by more than one chunk are extracted into
+    /// shared chunks instead of being duplicated into each bundle.
+    ///
+    /// Requires [ModuleType::Es].
+    pub code_splitting: bool,
+
+    /// Keep loaded modules after bundling, so that later calls to
+    /// [Bundler::bundle] only load new modules and modules invalidated with
+    /// [Bundler::invalidate].
+    ///
+    /// Useful for watch mode.
+    pub incremental: bool,
+
+    /// Keep license comments of bundled modules, which are `/*! ... */`
+    /// comments and block comments containing `@license` or `@preserve`.
+    ///
+    /// The comments are emitted at the start of the bundle, and stored in
+    /// [Bundle::comments].
+    pub preserve_license_comments: bool,
+
+    /// Emit a `// MODULE: path` comment before the code of each bundled
+    /// module.
+    ///
+    /// The comments are stored in [Bundle::comments].
+    pub module_banners: bool,
+
+    /// Compute [Bundle::metadata].
+    pub metadata: bool,
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Default)]
@@ -71,6 +115,46 @@ pub struct Bundle {
     pub id: ModuleId,
     /// Merged module.
     pub module: Module,
+    /// Leading comments of [Bundle::module], by the position of the node they
+    /// precede.
+    ///
+    /// This is empty unless [Config::preserve_license_comments] or
+    /// [Config::module_banners] is enabled, and should be added to the
+    /// comments used to emit the module.
+    pub comments: SingleThreadedCommentsMapInner,
+    /// Modules, sizes and exports of the bundle.
+    ///
+    /// This is [None] unless [Config::metadata] is enabled.
+    pub metadata: Option<BundleMetadata>,
+}
+
+/// Describes the chunks emitted by [Bundler::bundle_with_manifest] and how
+/// they depend on each other.
+#[derive(Debug, Default)]
+pub struct ChunkManifest {
+    /// In the same order as the bundles.
+    pub chunks: Vec<ManifestChunk>,
+}
+
+#[derive(Debug)]
+pub struct ManifestChunk {
+    /// File name of the chunk. This is the name of [BundleKind::Named] of the
+    /// emitted [Bundle].
+    pub file: String,
+    /// Kind of the chunk.
+    ///
+    /// Chunks created by code splitting are [BundleKind::Dynamic] if they are
+    /// loaded with `import()`, and [BundleKind::Lib] if they contain modules
+    /// shared by other chunks. Those are emitted as [BundleKind::Named] with
+    /// a hash in the file name.
+    pub kind: BundleKind,
+    pub id: ModuleId,
+    /// Modules merged into the chunk. The first one is the root of the chunk.
+    pub modules: Vec<FileName>,
+    /// File names of chunks imported statically.
+    pub imports: Vec<String>,
+    /// File names of chunks loaded with `import()`.
+    pub dynamic_imports: Vec<String>,
 }
 
 pub struct Bundler<'a, L, R>
@@ -149,7 +233,62 @@ where
     /// Note: This method will panic if entries references each other in
     /// circular manner. However, it applies only to the provided `entries`, and
     /// dependencies with circular reference is ok.
+    ///
+    /// Fails with [swc_common::cancel::Cancelled] if the cancellation token of
+    /// the [Globals] passed to [Bundler::new] is cancelled.
     pub fn bundle(&mut self, entries: HashMap<String, FileName>) -> Result<Vec<Bundle>, Error> {
+        self.bundle_with_manifest(entries)
+            .map(|(bundles, _)| bundles)
+    }
+
+    /// Removes a module from the modules kept by [Config::incremental], so it's
+    /// loaded again by the next call to [Bundler::bundle].
+    ///
+    /// Modules importing it are not loaded again. If imports of a module are
+    /// resolved differently because of a change, for example because a file
+    /// is created, the importing module should be invalidated too.
+    ///
+    /// Returns `false` if the module is not loaded.
+    pub fn invalidate(&mut self, file: &FileName) -> bool {
+        self.scope.invalidate(file)
+    }
+
+    /// Same as [Bundler::bundle], but also returns a manifest of the emitted
+    /// chunks.
+    ///
+    /// Useful with [Config::code_splitting], as chunks import each other by
+    /// file name and should be emitted into the same directory.
+    pub fn bundle_with_manifest(
+        &mut self,
+        entries: HashMap<String, FileName>,
+    ) -> Result<(Vec<Bundle>, ChunkManifest), Error> {
+        #[cfg(feature = "concurrent")]
+        if self.config.deterministic {
+            // Module ids and marks are allocated in the order modules are
+            // visited, so all work should be done in order.
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(1)
+                .build()
+                .context("failed to create a thread pool")?;
+
+            return pool.install(|| self.bundle_entries(entries));
+        }
+
+        self.bundle_entries(entries)
+    }
+
+    fn bundle_entries(
+        &mut self,
+        entries: HashMap<String, FileName>,
+    ) -> Result<(Vec<Bundle>, ChunkManifest), Error> {
+        if self.config.code_splitting && self.config.module != ModuleType::Es {
+            bail!("code splitting requires `ModuleType::Es`");
+        }
+
+        // The order of a `HashMap` is random.
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
         let results = entries
             .into_iter()
             .map(|(name, path)| -> Result<_, Error> {
@@ -190,17 +329,25 @@ where
             output
         };
 
-        let bundles = self.chunk(local)?;
+        if self.config.incremental {
+            let mut entries = local.values().map(|m| m.id).collect::<Vec<_>>();
+            entries.sort();
+            self.load_invalidated(&entries)?;
+        }
 
-        let bundles = self.finalize(bundles, self.unresolved_mark)?;
+        self.globals.cancellation().check()?;
+        let (bundles, members) = self.chunk(local)?;
 
-        #[cfg(feature = "concurrent")]
-        {
+        self.globals.cancellation().check()?;
+        let (bundles, manifest) = self.finalize(bundles, members, self.unresolved_mark)?;
+
+        if !self.config.incremental {
             let scope = std::mem::take(&mut self.scope);
-            rayon::spawn(move || drop(scope))
+            #[cfg(feature = "concurrent")]
+            rayon::spawn(move || drop(scope));
         }
 
-        Ok(bundles)
+        Ok((bundles, manifest))
     }
 
     /// Sets `swc_common::GLOBALS`
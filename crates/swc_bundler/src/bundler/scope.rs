@@ -1,6 +1,10 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use swc_common::{sync::Lrc, FileName};
+use rustc_hash::FxHashMap;
+use swc_common::{
+    sync::{Lock, Lrc},
+    FileName,
+};
 
 use super::load::TransformedModule;
 use crate::{
@@ -19,6 +23,17 @@ pub(super) struct Scope {
 
     accessed_with_computed_key: CloneMap<ModuleId, Lrc<AtomicBool>>,
     is_cjs: CloneMap<ModuleId, Lrc<AtomicBool>>,
+
+    /// Flags set on dependencies by each module.
+    ///
+    /// Used to recompute flags when a module is invalidated.
+    dep_flags: Lock<FxHashMap<ModuleId, Vec<(ModuleId, DepFlag)>>>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DepFlag {
+    Cjs,
+    WrappingRequired,
 }
 
 impl Scope {
@@ -50,8 +65,52 @@ impl Scope {
         }
     }
 
-    /// Set the module as a common js module
-    pub fn mark_as_cjs(&self, id: ModuleId) {
+    /// Removes a module from the cache, so it's loaded again.
+    ///
+    /// Returns `false` if the module is not loaded.
+    pub fn invalidate(&self, file_name: &FileName) -> bool {
+        let id = match self.module_id_gen.get(file_name) {
+            Some(id) => id,
+            None => return false,
+        };
+        if self.transformed_modules.remove(&id).is_none() {
+            return false;
+        }
+        self.loaded_modules.remove(&id);
+
+        // Flags set by the module may not be valid anymore.
+        let mut dep_flags = self.dep_flags.lock();
+        dep_flags.remove(&id);
+
+        self.is_cjs.clear();
+        self.accessed_with_computed_key.clear();
+        for &(dep, flag) in dep_flags.values().flatten() {
+            match flag {
+                DepFlag::Cjs => self.set_cjs(dep),
+                DepFlag::WrappingRequired => self.set_wrapping_required(dep),
+            }
+        }
+
+        true
+    }
+
+    fn record(&self, by: ModuleId, id: ModuleId, flag: DepFlag) {
+        self.dep_flags
+            .lock()
+            .entry(by)
+            .or_default()
+            .push((id, flag));
+    }
+
+    /// Set the module as a common js module.
+    ///
+    /// `by` is the module which requires the module.
+    pub fn mark_as_cjs(&self, by: ModuleId, id: ModuleId) {
+        self.record(by, id, DepFlag::Cjs);
+        self.set_cjs(id);
+    }
+
+    fn set_cjs(&self, id: ModuleId) {
         if let Some(v) = self.is_cjs.get(&id) {
             v.store(true, Ordering::SeqCst);
             return;
@@ -61,7 +120,14 @@ impl Scope {
     }
 
     /// Set the module as
-    pub fn mark_as_wrapping_required(&self, id: ModuleId) {
+    ///
+    /// `by` is the module which accesses the module with a computed key.
+    pub fn mark_as_wrapping_required(&self, by: ModuleId, id: ModuleId) {
+        self.record(by, id, DepFlag::WrappingRequired);
+        self.set_wrapping_required(id);
+    }
+
+    fn set_wrapping_required(&self, id: ModuleId) {
         if let Some(v) = self.accessed_with_computed_key.get(&id) {
             v.store(true, Ordering::SeqCst);
             return;
@@ -144,6 +144,12 @@ impl TestBuilder {
                         disable_dce: false,
                         external_modules: Vec::new(),
                         module: Default::default(),
+                        deterministic: false,
+                        code_splitting: false,
+                        incremental: false,
+                        preserve_license_comments: false,
+                        module_banners: false,
+                        metadata: false,
                     },
                     Box::new(Hook),
                 );
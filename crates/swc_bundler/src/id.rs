@@ -46,6 +46,20 @@ impl ModuleIdGenerator {
         w.insert(file_name.clone(), v);
         (id, local_mark, export_mark)
     }
+
+    /// Returns the id of a file if it's generated.
+    pub fn get(&self, file_name: &FileName) -> Option<ModuleId> {
+        self.cache.lock().get(file_name).map(|v| v.0)
+    }
+
+    /// Returns the file name of a module which has an id.
+    pub fn file_name(&self, id: ModuleId) -> Option<FileName> {
+        self.cache
+            .lock()
+            .iter()
+            .find(|(_, v)| v.0 == id)
+            .map(|(file_name, _)| file_name.clone())
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
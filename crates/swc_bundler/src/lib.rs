@@ -4,13 +4,19 @@
 #![cfg_attr(not(test), allow(unused))]
 
 pub use self::{
-    bundler::{Bundle, BundleKind, Bundler, Config, ModuleType},
+    asset::{AssetLoad, AssetLoader, CssLoader, JsonLoader, UrlLoader},
+    bundler::{
+        Bundle, BundleKind, BundleMetadata, Bundler, ChunkManifest, Config, ManifestChunk,
+        ModuleMetadata, ModuleType,
+    },
     hook::{Hook, ModuleRecord},
     id::ModuleId,
     load::{Load, ModuleData},
+    prefetch::{AsyncLoad, AsyncResolve, Prefetched, Prefetcher},
     resolve::Resolve,
 };
 
+mod asset;
 mod bundler;
 mod debug;
 mod dep_graph;
@@ -20,5 +26,6 @@ mod id;
 mod inline;
 mod load;
 mod modules;
+mod prefetch;
 mod resolve;
 mod util;
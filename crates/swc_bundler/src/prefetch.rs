@@ -0,0 +1,338 @@
+use anyhow::{anyhow, Error};
+use futures::{
+    future::LocalBoxFuture,
+    stream::{FuturesUnordered, StreamExt},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_common::{
+    sync::{Lock, Lrc},
+    FileName, SourceFile,
+};
+use swc_ecma_ast::{CallExpr, Callee, ExportAll, Expr, ImportDecl, Lit, Module, NamedExport, Str};
+use swc_ecma_loader::resolve::Resolution;
+use swc_ecma_transforms_base::helpers::{HelperData, Helpers};
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{Load, ModuleData, Resolve};
+
+/// Async version of [Resolve], used by [Prefetcher].
+///
+/// The futures are not required to be [Send], so they can be awaited by any
+/// executor.
+pub trait AsyncResolve {
+    fn resolve<'a>(
+        &'a self,
+        base: &'a FileName,
+        module_specifier: &'a str,
+    ) -> LocalBoxFuture<'a, Result<Resolution, Error>>;
+}
+
+/// Async version of [Load], used by [Prefetcher].
+///
+/// The futures are not required to be [Send], so they can be awaited by any
+/// executor.
+pub trait AsyncLoad {
+    fn load<'a>(&'a self, file: &'a FileName) -> LocalBoxFuture<'a, Result<ModuleData, Error>>;
+}
+
+impl<T: ?Sized + AsyncResolve> AsyncResolve for Box<T> {
+    fn resolve<'a>(
+        &'a self,
+        base: &'a FileName,
+        module_specifier: &'a str,
+    ) -> LocalBoxFuture<'a, Result<Resolution, Error>> {
+        (**self).resolve(base, module_specifier)
+    }
+}
+
+impl<T: ?Sized + AsyncResolve> AsyncResolve for &T {
+    fn resolve<'a>(
+        &'a self,
+        base: &'a FileName,
+        module_specifier: &'a str,
+    ) -> LocalBoxFuture<'a, Result<Resolution, Error>> {
+        (**self).resolve(base, module_specifier)
+    }
+}
+
+impl<T: ?Sized + AsyncLoad> AsyncLoad for Box<T> {
+    fn load<'a>(&'a self, file: &'a FileName) -> LocalBoxFuture<'a, Result<ModuleData, Error>> {
+        (**self).load(file)
+    }
+}
+
+impl<T: ?Sized + AsyncLoad> AsyncLoad for &T {
+    fn load<'a>(&'a self, file: &'a FileName) -> LocalBoxFuture<'a, Result<ModuleData, Error>> {
+        (**self).load(file)
+    }
+}
+
+/// Loads all modules reachable from the entries using [AsyncLoad] and
+/// [AsyncResolve], so modules from the network (like `https:` or `data:`
+/// urls, or a package registry) can be bundled.
+///
+/// The [Bundler](crate::Bundler) itself is synchronous, so modules are loaded
+/// before bundling and the [Prefetched] modules are passed to the bundler as
+/// both the loader and the resolver.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let prefetcher = Prefetcher::new(HttpLoader, HttpResolver).with_max_concurrency(8);
+/// // Loaders usually need `GLOBALS` to create marks.
+/// let modules = GLOBALS.set(&globals, || {
+///     block_on(prefetcher.prefetch(entries.values().cloned()))
+/// });
+///
+/// let mut bundler = Bundler::new(&globals, cm, modules.clone(), modules, config, hook);
+/// let bundles = bundler.bundle(entries)?;
+/// ```
+pub struct Prefetcher<L, R>
+where
+    L: AsyncLoad,
+    R: AsyncResolve,
+{
+    loader: L,
+    resolver: R,
+    max_concurrency: usize,
+    modules: Prefetched,
+}
+
+impl<L, R> Prefetcher<L, R>
+where
+    L: AsyncLoad,
+    R: AsyncResolve,
+{
+    pub fn new(loader: L, resolver: R) -> Self {
+        Self {
+            loader,
+            resolver,
+            max_concurrency: 16,
+            modules: Default::default(),
+        }
+    }
+
+    /// Sets the maximum number of loads and resolutions running at the same
+    /// time. Defaults to 16.
+    pub fn with_max_concurrency(mut self, max_concurrency: usize) -> Self {
+        self.max_concurrency = max_concurrency.max(1);
+        self
+    }
+
+    /// Loads `entries` and their dependencies.
+    ///
+    /// Results are cached, so modules loaded by previous calls are not loaded
+    /// again. Errors are not returned here, but by [Prefetched] when the
+    /// bundler asks for the module. This is because the bundler does not load
+    /// every dependency (e.g. external modules).
+    pub async fn prefetch<I>(&self, entries: I) -> Prefetched
+    where
+        I: IntoIterator<Item = FileName>,
+    {
+        let mut queue = entries.into_iter().map(Task::Load).collect::<Vec<_>>();
+        let mut scheduled = FxHashSet::default();
+        let mut pending = FuturesUnordered::new();
+
+        loop {
+            while pending.len() < self.max_concurrency {
+                let Some(task) = queue.pop() else {
+                    break;
+                };
+
+                if self.modules.contains(&task) || !scheduled.insert(task.clone()) {
+                    continue;
+                }
+
+                pending.push(self.run(task));
+            }
+
+            let Some(done) = pending.next().await else {
+                break;
+            };
+
+            match done {
+                Done::Loaded(file, result) => {
+                    let result = result.map(|data| {
+                        for src in collect_deps(&data.module) {
+                            queue.push(Task::Resolve(file.clone(), src));
+                        }
+
+                        CachedModule {
+                            fm: data.fm,
+                            module: data.module,
+                            helpers: data.helpers.data(),
+                        }
+                    });
+
+                    self.modules
+                        .inner
+                        .lock()
+                        .modules
+                        .insert(file, result.map_err(|err| format!("{:?}", err)));
+                }
+                Done::Resolved(base, src, result) => {
+                    if let Ok(resolution) = &result {
+                        queue.push(Task::Load(resolution.filename.clone()));
+                    }
+
+                    self.modules
+                        .inner
+                        .lock()
+                        .resolutions
+                        .insert((base, src), result.map_err(|err| format!("{:?}", err)));
+                }
+            }
+        }
+
+        self.modules.clone()
+    }
+
+    fn run(&self, task: Task) -> LocalBoxFuture<'_, Done> {
+        Box::pin(async move {
+            match task {
+                Task::Load(file) => {
+                    let result = self.loader.load(&file).await;
+                    Done::Loaded(file, result)
+                }
+                Task::Resolve(base, src) => {
+                    let result = self.resolver.resolve(&base, &src).await;
+                    Done::Resolved(base, src, result)
+                }
+            }
+        })
+    }
+}
+
+/// Modules loaded by [Prefetcher].
+///
+/// This implements [Load] and [Resolve] using the results of
+/// [Prefetcher::prefetch], and fails for modules which are not prefetched.
+#[derive(Clone, Default)]
+pub struct Prefetched {
+    inner: Lrc<Lock<Cache>>,
+}
+
+#[derive(Default)]
+struct Cache {
+    modules: FxHashMap<FileName, Result<CachedModule, String>>,
+    resolutions: FxHashMap<(FileName, String), Result<Resolution, String>>,
+}
+
+/// [ModuleData] is not [Sync] because of [Helpers].
+struct CachedModule {
+    fm: Lrc<SourceFile>,
+    module: Module,
+    helpers: HelperData,
+}
+
+impl Prefetched {
+    fn contains(&self, task: &Task) -> bool {
+        let cache = self.inner.lock();
+
+        match task {
+            Task::Load(file) => cache.modules.contains_key(file),
+            Task::Resolve(base, src) => {
+                cache.resolutions.contains_key(&(base.clone(), src.clone()))
+            }
+        }
+    }
+}
+
+impl Load for Prefetched {
+    fn load(&self, file: &FileName) -> Result<ModuleData, Error> {
+        match self.inner.lock().modules.get(file) {
+            Some(Ok(cached)) => Ok(ModuleData {
+                fm: cached.fm.clone(),
+                module: cached.module.clone(),
+                helpers: Helpers::from_data(cached.helpers),
+            }),
+            Some(Err(err)) => Err(anyhow!("{}", err)),
+            None => Err(anyhow!("`{}` is not prefetched", file)),
+        }
+    }
+}
+
+impl Resolve for Prefetched {
+    fn resolve(&self, base: &FileName, module_specifier: &str) -> Result<Resolution, Error> {
+        match self
+            .inner
+            .lock()
+            .resolutions
+            .get(&(base.clone(), module_specifier.to_string()))
+        {
+            Some(Ok(resolution)) => Ok(resolution.clone()),
+            Some(Err(err)) => Err(anyhow!("{}", err)),
+            None => Err(anyhow!(
+                "`{}` imported by `{}` is not prefetched",
+                module_specifier,
+                base
+            )),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Task {
+    Load(FileName),
+    Resolve(FileName, String),
+}
+
+enum Done {
+    Loaded(FileName, Result<ModuleData, Error>),
+    Resolved(FileName, String, Result<Resolution, Error>),
+}
+
+/// Collects specifiers the bundler may resolve.
+///
+/// This includes `require` calls and dynamic imports, because whether they
+/// are bundled depends on the config of the bundler.
+fn collect_deps(module: &Module) -> Vec<String> {
+    let mut v = DepCollector::default();
+    module.visit_with(&mut v);
+    v.deps
+}
+
+#[derive(Default)]
+struct DepCollector {
+    deps: Vec<String>,
+}
+
+impl DepCollector {
+    fn add(&mut self, src: &Str) {
+        self.deps.push(src.value.to_string());
+    }
+}
+
+impl Visit for DepCollector {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        self.add(&n.src);
+    }
+
+    fn visit_named_export(&mut self, n: &NamedExport) {
+        if let Some(src) = &n.src {
+            self.add(src);
+        }
+    }
+
+    fn visit_export_all(&mut self, n: &ExportAll) {
+        self.add(&n.src);
+    }
+
+    fn visit_call_expr(&mut self, n: &CallExpr) {
+        n.visit_children_with(self);
+
+        let is_dep = match &n.callee {
+            Callee::Import(..) => true,
+            Callee::Expr(callee) => matches!(&**callee, Expr::Ident(i) if i.sym == "require"),
+            _ => false,
+        };
+
+        if is_dep && n.args.len() == 1 {
+            if let Expr::Lit(Lit::Str(src)) = &*n.args[0].expr {
+                self.add(src);
+            }
+        }
+    }
+}
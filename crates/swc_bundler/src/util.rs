@@ -147,6 +147,26 @@ where
     pub fn insert(&self, k: K, v: V) -> Option<V> {
         self.inner.borrow_mut().insert(k, v)
     }
+
+    #[cfg(feature = "concurrent")]
+    pub fn remove(&self, k: &K) -> Option<V> {
+        self.inner.remove(k).map(|(_, v)| v)
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn remove(&self, k: &K) -> Option<V> {
+        self.inner.borrow_mut().remove(k)
+    }
+
+    #[cfg(feature = "concurrent")]
+    pub fn clear(&self) {
+        self.inner.clear()
+    }
+
+    #[cfg(not(feature = "concurrent"))]
+    pub fn clear(&self) {
+        self.inner.borrow_mut().clear()
+    }
 }
 
 pub(crate) struct HygieneRemover;
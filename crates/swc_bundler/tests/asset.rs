@@ -0,0 +1,130 @@
+use std::path::PathBuf;
+
+use anyhow::Error;
+use swc_bundler::{AssetLoad, Bundler, Config, CssLoader, ModuleData, ModuleRecord, UrlLoader};
+use swc_common::{errors::HANDLER, sync::Lrc, FileName, Globals, SourceFile, Span, DUMMY_SP};
+use swc_ecma_ast::{ExportDefaultExpr, KeyValueProp, Module, Program};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_transforms_base::fixer::fixer;
+use swc_ecma_utils::quote_str;
+use testing::NormalizedOutput;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+/// Loads text files as a module which exports the content.
+fn load_text(fm: &Lrc<SourceFile>) -> Result<Module, Error> {
+    Ok(Module {
+        span: DUMMY_SP,
+        body: vec![ExportDefaultExpr {
+            span: DUMMY_SP,
+            expr: quote_str!(fm.src.trim()).into(),
+        }
+        .into()],
+        shebang: None,
+    })
+}
+
+#[testing::fixture("tests/asset/**/input/entry.js")]
+fn pass(entry: PathBuf) {
+    let output = entry
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("output/entry.js");
+
+    testing::run_test2(false, |cm, handler| {
+        HANDLER.set(&handler, || {
+            let globals = Globals::default();
+            let loader = AssetLoad::new(cm.clone(), Loader { cm: cm.clone() })
+                .with_default_loaders()
+                .with_loader(
+                    "png",
+                    UrlLoader {
+                        public_path: "/static/".into(),
+                    },
+                )
+                .with_loader("txt", load_text);
+            let mut bundler = Bundler::new(
+                &globals,
+                cm.clone(),
+                loader,
+                NodeResolver,
+                Config {
+                    require: false,
+                    ..Default::default()
+                },
+                Box::new(Hook),
+            );
+
+            let mut bundles = bundler
+                .bundle(
+                    vec![("entry".into(), FileName::Real(entry.clone()))]
+                        .into_iter()
+                        .collect(),
+                )
+                .map_err(|err| println!("{:?}", err))?;
+            assert_eq!(bundles.len(), 1);
+
+            let mut buf = Vec::new();
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: cm.clone(),
+                    comments: None,
+                    wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                };
+
+                emitter
+                    .emit_program(&Program::Module(bundles.remove(0).module).apply(fixer(None)))
+                    .unwrap();
+            }
+
+            NormalizedOutput::from(String::from_utf8_lossy(&buf).to_string())
+                .compare_to_file(&output)
+                .map_err(|_| ())
+        })
+    })
+    .expect("failed to bundle");
+}
+
+#[test]
+fn css_without_injection() {
+    testing::run_test2(false, |cm, _| {
+        let path = PathBuf::from("tests/asset/basic/input/reset.css");
+        let loader = AssetLoad::new(cm.clone(), Loader { cm: cm.clone() })
+            .with_loader("css", CssLoader { inject: false });
+        let ModuleData { module, .. } = swc_bundler::Load::load(&loader, &FileName::Real(path))
+            .map_err(|err| println!("{:?}", err))?;
+
+        let mut buf = Vec::new();
+        {
+            let mut emitter = Emitter {
+                cfg: Default::default(),
+                cm: cm.clone(),
+                comments: None,
+                wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+            };
+            emitter.emit_module(&module).unwrap();
+        }
+
+        assert_eq!(
+            String::from_utf8_lossy(&buf),
+            "const css = \"* {\\n  margin: 0;\\n}\";\nexport default css;\n"
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(Vec::new())
+    }
+}
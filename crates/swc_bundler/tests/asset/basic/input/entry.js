@@ -0,0 +1,6 @@
+import styles from './app.css';
+import data from './data.json';
+import logo from './logo.png';
+import notes from './notes.txt';
+
+console.log(styles, data.name, logo, notes);
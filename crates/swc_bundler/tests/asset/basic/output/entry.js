@@ -0,0 +1,25 @@
+const css = "* {\n  margin: 0;\n}";
+if (typeof document !== "undefined") {
+    const style = document.createElement("style");
+    style.textContent = css;
+    document.head.appendChild(style);
+}
+const css1 = "body {\n  background: white;\n}";
+if (typeof document !== "undefined") {
+    const style = document.createElement("style");
+    style.textContent = css1;
+    document.head.appendChild(style);
+}
+const css2 = '@import "./print.css" print;\n\n.app {\n  color: red;\n}';
+if (typeof document !== "undefined") {
+    const style = document.createElement("style");
+    style.textContent = css2;
+    document.head.appendChild(style);
+}
+const __default = {
+    "name": "app",
+    "version": 1
+};
+const __default1 = "/static/logo.png";
+const __default2 = "Hello from a text file";
+console.log(css2, __default.name, __default1, __default2);
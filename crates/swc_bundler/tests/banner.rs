@@ -0,0 +1,88 @@
+use std::{cell::RefCell, path::PathBuf, rc::Rc};
+
+use anyhow::Error;
+use swc_bundler::{Bundler, Config, ModuleRecord};
+use swc_common::{comments::SingleThreadedComments, errors::HANDLER, FileName, Globals, Span};
+use swc_ecma_ast::{KeyValueProp, Program};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use testing::NormalizedOutput;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+#[testing::fixture("tests/banner/**/input/entry.js")]
+fn pass(entry: PathBuf) {
+    let output_dir = entry.parent().unwrap().parent().unwrap().join("output");
+
+    testing::run_test2(false, |cm, handler| {
+        HANDLER.set(&handler, || {
+            let globals = Globals::default();
+            let mut bundler = Bundler::new(
+                &globals,
+                cm.clone(),
+                Loader { cm: cm.clone() },
+                NodeResolver,
+                Config {
+                    deterministic: true,
+                    preserve_license_comments: true,
+                    module_banners: true,
+                    metadata: true,
+                    ..Default::default()
+                },
+                Box::new(Hook),
+            );
+
+            let mut bundles = bundler
+                .bundle(
+                    vec![("main".into(), FileName::Real(entry.clone()))]
+                        .into_iter()
+                        .collect(),
+                )
+                .map_err(|err| println!("{:?}", err))?;
+            assert_eq!(bundles.len(), 1);
+            let bundle = bundles.remove(0);
+
+            let comments = SingleThreadedComments::from_leading_and_trailing(
+                Rc::new(RefCell::new(bundle.comments)),
+                Default::default(),
+            );
+            let mut buf = Vec::new();
+            {
+                let mut emitter = Emitter {
+                    cfg: Default::default(),
+                    cm: cm.clone(),
+                    comments: Some(&comments),
+                    wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                };
+
+                emitter
+                    .emit_program(&Program::Module(bundle.module))
+                    .unwrap();
+            }
+
+            let mut error = NormalizedOutput::from(String::from_utf8(buf).unwrap())
+                .compare_to_file(output_dir.join("entry.js"))
+                .is_err();
+            error |= NormalizedOutput::from(bundle.metadata.unwrap().to_json())
+                .compare_to_file(output_dir.join("metadata.json"))
+                .is_err();
+
+            if error {
+                return Err(());
+            }
+
+            Ok(())
+        })
+    })
+    .expect("failed to bundle");
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(Vec::new())
+    }
+}
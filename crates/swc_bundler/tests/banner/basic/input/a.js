@@ -0,0 +1,8 @@
+/**
+ * @license a
+ * Licensed under the Apache License, Version 2.0
+ */
+import { b } from './b';
+
+const text = "/*! not a comment */";
+export const a = b + text.length;
@@ -0,0 +1,3 @@
+/*! entry v1.0.0 | MIT */
+export const b = 1;
+export const unused = 2;
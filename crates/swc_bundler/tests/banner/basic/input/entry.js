@@ -0,0 +1,9 @@
+/*! entry v1.0.0 | MIT */
+import { a } from './a';
+import { b } from './b';
+
+// Not a license comment.
+export const sum = a + b;
+export default function main() {
+    return sum;
+}
@@ -0,0 +1,13 @@
+/*! entry v1.0.0 | MIT */ /**
+ * @license a
+ * Licensed under the Apache License, Version 2.0
+ */ // MODULE: tests/banner/basic/input/a.js
+const text = "/*! not a comment */";
+const a = 1 + text.length;
+// MODULE: tests/banner/basic/input/entry.js
+const sum = a + 1;
+function main() {
+    return sum;
+}
+export { sum as sum };
+export { main as default };
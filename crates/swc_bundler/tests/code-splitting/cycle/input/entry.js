@@ -0,0 +1,4 @@
+import { a } from "./a.js";
+
+console.log(a());
+import("./page.js").then(({ page }) => console.log(page()));
@@ -0,0 +1,5 @@
+import { b } from "./b.js";
+
+export function page() {
+    return "page:" + b();
+}
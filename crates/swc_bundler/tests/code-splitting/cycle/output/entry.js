@@ -0,0 +1,8 @@
+function a() {
+    return "a" + b();
+}
+function b() {
+    return typeof a;
+}
+console.log(a());
+import("./page-1jfyu9q8gxv3k.js").then(({ page })=>console.log(page()));
@@ -0,0 +1,10 @@
+function a() {
+    return "a" + b();
+}
+function b() {
+    return typeof a;
+}
+function page() {
+    return "page:" + b();
+}
+export { page as page };
@@ -0,0 +1,8 @@
+import { log } from "./log.js";
+
+export async function main() {
+    const { value } = await import("./lazy.js");
+    log(value);
+}
+
+main();
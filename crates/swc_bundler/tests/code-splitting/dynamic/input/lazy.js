@@ -0,0 +1,5 @@
+import { log } from "./log.js";
+
+log("lazy");
+
+export const value = 42;
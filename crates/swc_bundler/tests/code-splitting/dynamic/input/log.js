@@ -0,0 +1,3 @@
+export function log(v) {
+    console.log(v);
+}
@@ -0,0 +1,7 @@
+import { log as log1 } from "./log-378d9odh4ikmm.js";
+async function main() {
+    const { value } = await import("./lazy-v4zcsjoss871.js");
+    log1(value);
+}
+main();
+export { main as main };
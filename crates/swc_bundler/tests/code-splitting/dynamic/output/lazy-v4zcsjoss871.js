@@ -0,0 +1,4 @@
+import { log as log1 } from "./log-378d9odh4ikmm.js";
+log1("lazy");
+const value = 42;
+export { value as value };
@@ -0,0 +1,4 @@
+function log(v) {
+    console.log(v);
+}
+export { log as log };
@@ -0,0 +1,3 @@
+import { foo, bar, ns } from "./mid.js";
+
+console.log(foo, bar, ns.foo);
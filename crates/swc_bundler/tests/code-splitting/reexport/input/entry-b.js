@@ -0,0 +1,7 @@
+import { foo } from "./shared.js";
+import baz from "./other.js";
+
+console.log(foo, baz);
+
+export * from "./shared.js";
+export { default as qux } from "./other.js";
@@ -0,0 +1,3 @@
+export { foo } from "./shared.js";
+export * as ns from "./shared.js";
+export * from "./other.js";
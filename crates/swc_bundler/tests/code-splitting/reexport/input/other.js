@@ -0,0 +1,3 @@
+export const bar = "bar";
+
+export default "baz";
@@ -0,0 +1,4 @@
+import { foo as foo1 } from "./shared-vwraa7vlj5q.js";
+import * as ns from "./shared-vwraa7vlj5q.js";
+import { bar as bar } from "./other-3b3zrrri8960n.js";
+console.log(foo1, bar, ns.foo);
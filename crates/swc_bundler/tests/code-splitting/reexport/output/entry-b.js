@@ -0,0 +1,5 @@
+import { foo as foo1 } from "./shared-vwraa7vlj5q.js";
+import baz from "./other-3b3zrrri8960n.js";
+console.log(foo1, baz);
+export * from "./shared-vwraa7vlj5q.js";
+export { default as qux } from "./other-3b3zrrri8960n.js";
@@ -0,0 +1,4 @@
+const bar = "bar";
+const __default = "baz";
+export { bar as bar };
+export { __default as default };
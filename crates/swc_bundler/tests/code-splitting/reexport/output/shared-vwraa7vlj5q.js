@@ -0,0 +1,2 @@
+const foo = "foo";
+export { foo as foo };
@@ -0,0 +1,3 @@
+import { format } from "./shared.js";
+
+console.log(format("a"));
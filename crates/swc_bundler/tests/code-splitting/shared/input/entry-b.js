@@ -0,0 +1,3 @@
+import { format, count } from "./shared.js";
+
+console.log(format("b"), count);
@@ -0,0 +1,8 @@
+import { upper } from "./util.js";
+
+export let count = 0;
+
+export function format(v) {
+    count++;
+    return upper(v);
+}
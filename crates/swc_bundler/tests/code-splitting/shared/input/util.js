@@ -0,0 +1,3 @@
+export function upper(v) {
+    return v.toUpperCase();
+}
@@ -0,0 +1,2 @@
+import { format as format1 } from "./shared-1ncfcmrtylzz1.js";
+console.log(format1("a"));
@@ -0,0 +1,10 @@
+function upper(v) {
+    return v.toUpperCase();
+}
+let count = 0;
+function format(v) {
+    count++;
+    return upper(v);
+}
+export { count as count };
+export { format as format };
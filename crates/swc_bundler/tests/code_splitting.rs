@@ -0,0 +1,142 @@
+use std::{collections::HashMap, fmt::Write, fs::read_dir, path::PathBuf};
+
+use anyhow::Error;
+use swc_bundler::{BundleKind, Bundler, Config, ModuleRecord};
+use swc_common::{errors::HANDLER, FileName, Globals, Span};
+use swc_ecma_ast::{KeyValueProp, Program};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_loader::NODE_BUILTINS;
+use swc_ecma_transforms_base::fixer::fixer;
+use testing::NormalizedOutput;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+#[testing::fixture("tests/code-splitting/**/input")]
+fn pass(input_dir: PathBuf) {
+    let entries = read_dir(&input_dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|path| {
+            path.file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("entry")
+        })
+        .map(|path| {
+            (
+                path.file_name().unwrap().to_string_lossy().to_string(),
+                FileName::Real(path),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let output_dir = input_dir.parent().unwrap().join("output");
+
+    testing::run_test2(false, |cm, handler| {
+        HANDLER.set(&handler, || {
+            let globals = Globals::default();
+            let mut bundler = Bundler::new(
+                &globals,
+                cm.clone(),
+                Loader { cm: cm.clone() },
+                NodeResolver,
+                Config {
+                    require: true,
+                    external_modules: NODE_BUILTINS.iter().copied().map(From::from).collect(),
+                    deterministic: true,
+                    code_splitting: true,
+                    ..Default::default()
+                },
+                Box::new(Hook),
+            );
+
+            let (bundles, manifest) = bundler
+                .bundle_with_manifest(entries)
+                .map_err(|err| println!("{:?}", err))?;
+
+            let mut error = false;
+
+            for bundle in bundles {
+                let code = {
+                    let mut buf = Vec::new();
+
+                    {
+                        let mut emitter = Emitter {
+                            cfg: Default::default(),
+                            cm: cm.clone(),
+                            comments: None,
+                            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                        };
+
+                        emitter
+                            .emit_program(&Program::Module(bundle.module).apply(fixer(None)))
+                            .unwrap();
+                    }
+
+                    String::from_utf8_lossy(&buf).to_string()
+                };
+
+                let name = match bundle.kind {
+                    BundleKind::Named { name } => name,
+                    kind => unreachable!("chunks should be renamed: {:?}", kind),
+                };
+
+                if NormalizedOutput::from(code)
+                    .compare_to_file(output_dir.join(name))
+                    .is_err()
+                {
+                    error = true;
+                }
+            }
+
+            let mut s = String::new();
+            for chunk in &manifest.chunks {
+                let kind = match &chunk.kind {
+                    BundleKind::Named { .. } => "named",
+                    BundleKind::Dynamic => "dynamic",
+                    BundleKind::Lib { .. } => "lib",
+                };
+                writeln!(s, "{} ({})", chunk.file, kind).unwrap();
+
+                for module in &chunk.modules {
+                    let path = match module {
+                        FileName::Real(path) => path.strip_prefix(&input_dir).unwrap(),
+                        _ => unreachable!(),
+                    };
+                    writeln!(s, "  module: {}", path.display()).unwrap();
+                }
+                for file in &chunk.imports {
+                    writeln!(s, "  import: {}", file).unwrap();
+                }
+                for file in &chunk.dynamic_imports {
+                    writeln!(s, "  dynamic import: {}", file).unwrap();
+                }
+            }
+
+            if NormalizedOutput::from(s)
+                .compare_to_file(output_dir.join("manifest.txt"))
+                .is_err()
+            {
+                error = true;
+            }
+
+            if error {
+                return Err(());
+            }
+
+            Ok(())
+        })
+    })
+    .expect("failed to bundle");
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,100 @@
+use std::{collections::HashMap, fs::read_dir, path::PathBuf};
+
+use anyhow::Error;
+use swc_bundler::{Bundler, Config, ModuleRecord};
+use swc_common::{errors::HANDLER, FileName, Globals, Span};
+use swc_ecma_ast::{KeyValueProp, Program};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_loader::NODE_BUILTINS;
+use swc_ecma_transforms_base::fixer::fixer;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+/// Bundles `entries` and returns the kind, id and code of each bundle.
+fn bundle(entries: &HashMap<String, FileName>) -> Vec<String> {
+    testing::run_test2(false, |cm, handler| {
+        HANDLER.set(&handler, || {
+            let globals = Globals::default();
+            let mut bundler = Bundler::new(
+                &globals,
+                cm.clone(),
+                Loader { cm: cm.clone() },
+                NodeResolver,
+                Config {
+                    require: true,
+                    external_modules: NODE_BUILTINS.iter().copied().map(From::from).collect(),
+                    deterministic: true,
+                    ..Default::default()
+                },
+                Box::new(Hook),
+            );
+
+            let bundles = bundler.bundle(entries.clone()).expect("failed to bundle");
+
+            Ok(bundles
+                .into_iter()
+                .map(|bundle| {
+                    let mut buf = Vec::new();
+
+                    {
+                        let mut emitter = Emitter {
+                            cfg: Default::default(),
+                            cm: cm.clone(),
+                            comments: None,
+                            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+                        };
+
+                        emitter
+                            .emit_program(&Program::Module(bundle.module).apply(fixer(None)))
+                            .unwrap();
+                    }
+
+                    format!(
+                        "// {:?} {}\n{}",
+                        bundle.kind,
+                        bundle.id,
+                        String::from_utf8_lossy(&buf)
+                    )
+                })
+                .collect())
+        })
+    })
+    .unwrap()
+}
+
+#[testing::fixture("tests/fixture/**/input")]
+fn same_output(input_dir: PathBuf) {
+    let entries = read_dir(&input_dir)
+        .unwrap()
+        .map(|e| e.unwrap().path())
+        .filter(|path| {
+            path.file_name()
+                .unwrap()
+                .to_string_lossy()
+                .starts_with("entry")
+        })
+        .map(|path| {
+            (
+                path.file_name().unwrap().to_string_lossy().to_string(),
+                FileName::Real(path),
+            )
+        })
+        .collect::<HashMap<_, _>>();
+
+    let expected = bundle(&entries);
+
+    for _ in 0..3 {
+        assert_eq!(bundle(&entries), expected);
+    }
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(Vec::new())
+    }
+}
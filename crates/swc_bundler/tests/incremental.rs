@@ -0,0 +1,169 @@
+use std::{
+    collections::HashMap,
+    fs::write,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::Error;
+use swc_bundler::{Bundler, Config, Load, ModuleData, ModuleRecord};
+use swc_common::{sync::Lrc, FileName, Globals, SourceMap, Span};
+use swc_ecma_ast::{KeyValueProp, Program};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_transforms_base::fixer::fixer;
+
+use self::common::*;
+
+#[path = "common/mod.rs"]
+mod common;
+
+/// Records loaded files.
+struct CountingLoader {
+    inner: Loader,
+    loaded: Arc<Mutex<Vec<String>>>,
+}
+
+impl Load for CountingLoader {
+    fn load(&self, file: &FileName) -> Result<ModuleData, Error> {
+        if let FileName::Real(path) = file {
+            self.loaded
+                .lock()
+                .unwrap()
+                .push(path.file_name().unwrap().to_string_lossy().to_string());
+        }
+
+        self.inner.load(file)
+    }
+}
+
+fn config() -> Config {
+    Config {
+        require: true,
+        deterministic: true,
+        incremental: true,
+        ..Default::default()
+    }
+}
+
+fn entries(dir: &Path) -> HashMap<String, FileName> {
+    vec![("main".to_string(), FileName::Real(dir.join("entry.js")))]
+        .into_iter()
+        .collect()
+}
+
+fn print<L: Load>(
+    cm: &Lrc<SourceMap>,
+    bundler: &mut Bundler<L, NodeResolver>,
+    dir: &Path,
+) -> String {
+    let bundle = bundler.bundle(entries(dir)).unwrap().remove(0);
+
+    let mut buf = Vec::new();
+    {
+        let mut emitter = Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: Box::new(JsWriter::new(cm.clone(), "\n", &mut buf, None)),
+        };
+
+        emitter
+            .emit_program(&Program::Module(bundle.module).apply(fixer(None)))
+            .unwrap();
+    }
+
+    String::from_utf8(buf).unwrap()
+}
+
+/// Bundles `dir` with a new [Bundler].
+fn fresh(dir: &Path) -> String {
+    let cm = Lrc::new(SourceMap::default());
+    let globals = Globals::default();
+    let mut bundler = Bundler::new(
+        &globals,
+        cm.clone(),
+        Loader { cm: cm.clone() },
+        NodeResolver,
+        config(),
+        Box::new(Hook),
+    );
+
+    print(&cm, &mut bundler, dir)
+}
+
+#[test]
+fn invalidate() {
+    let dir = tempfile::tempdir().unwrap();
+    let dir = dir.path();
+    write(
+        dir.join("entry.js"),
+        "import { a } from './a';\nconst b = require('./b');\nconsole.log(a, b.b);\n",
+    )
+    .unwrap();
+    write(
+        dir.join("a.js"),
+        "import { c } from './c';\nexport const a = c + 1;\n",
+    )
+    .unwrap();
+    write(dir.join("b.js"), "exports.b = 'b';\n").unwrap();
+    write(dir.join("c.js"), "export const c = 1;\n").unwrap();
+
+    let cm = Lrc::new(SourceMap::default());
+    let globals = Globals::default();
+    let loaded = Arc::new(Mutex::new(Vec::new()));
+    let mut bundler = Bundler::new(
+        &globals,
+        cm.clone(),
+        CountingLoader {
+            inner: Loader { cm: cm.clone() },
+            loaded: loaded.clone(),
+        },
+        NodeResolver,
+        config(),
+        Box::new(Hook),
+    );
+    let take_loaded = || {
+        let mut v = std::mem::take(&mut *loaded.lock().unwrap());
+        v.sort();
+        v
+    };
+
+    assert_eq!(print(&cm, &mut bundler, dir), fresh(dir));
+    assert_eq!(take_loaded(), ["a.js", "b.js", "c.js", "entry.js"]);
+
+    // Nothing changed.
+    assert_eq!(print(&cm, &mut bundler, dir), fresh(dir));
+    assert!(take_loaded().is_empty());
+
+    // A dependency which is not imported by entries.
+    write(
+        dir.join("c.js"),
+        "export const c = 2;\nexport const d = 3;\n",
+    )
+    .unwrap();
+    assert!(bundler.invalidate(&FileName::Real(dir.join("c.js"))));
+    assert_eq!(print(&cm, &mut bundler, dir), fresh(dir));
+    assert_eq!(take_loaded(), ["c.js"]);
+
+    // `b.js` is not required anymore, so it should not be a common js module.
+    write(
+        dir.join("entry.js"),
+        "import { a } from './a';\nimport { b } from './b';\nconsole.log(a, b);\n",
+    )
+    .unwrap();
+    write(dir.join("b.js"), "export const b = 'b';\n").unwrap();
+    assert!(bundler.invalidate(&FileName::Real(dir.join("entry.js"))));
+    assert!(bundler.invalidate(&FileName::Real(dir.join("b.js"))));
+    assert_eq!(print(&cm, &mut bundler, dir), fresh(dir));
+    assert_eq!(take_loaded(), ["b.js", "entry.js"]);
+
+    assert!(!bundler.invalidate(&FileName::Real(dir.join("unknown.js"))));
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(Vec::new())
+    }
+}
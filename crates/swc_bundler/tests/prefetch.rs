@@ -0,0 +1,247 @@
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anyhow::{anyhow, Error};
+use futures::{executor::block_on, future::LocalBoxFuture};
+use swc_bundler::{
+    AsyncLoad, AsyncResolve, Bundler, Config, Load, ModuleData, ModuleRecord, Prefetcher,
+};
+use swc_common::{errors::HANDLER, sync::Lrc, FileName, Globals, SourceMap, Span, GLOBALS};
+use swc_ecma_ast::{EsVersion, KeyValueProp};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_loader::resolve::Resolution;
+use swc_ecma_parser::parse_file_as_module;
+use url::Url;
+
+/// Serves modules from memory as if they are on a server.
+struct Remote {
+    cm: Lrc<SourceMap>,
+    files: HashMap<&'static str, &'static str>,
+    loads: Cell<usize>,
+    running: Cell<usize>,
+    max_running: Cell<usize>,
+}
+
+impl Remote {
+    fn new(cm: Lrc<SourceMap>, files: &[(&'static str, &'static str)]) -> Self {
+        Self {
+            cm,
+            files: files.iter().copied().collect(),
+            loads: Default::default(),
+            running: Default::default(),
+            max_running: Default::default(),
+        }
+    }
+}
+
+impl AsyncLoad for Remote {
+    fn load<'a>(&'a self, file: &'a FileName) -> LocalBoxFuture<'a, Result<ModuleData, Error>> {
+        Box::pin(async move {
+            self.loads.set(self.loads.get() + 1);
+            self.running.set(self.running.get() + 1);
+            self.max_running
+                .set(self.max_running.get().max(self.running.get()));
+
+            // Let other loads start, like a network request.
+            YieldNow(false).await;
+
+            self.running.set(self.running.get() - 1);
+
+            let src = self
+                .files
+                .get(file.to_string().as_str())
+                .ok_or_else(|| anyhow!("404: {}", file))?;
+
+            let fm = self
+                .cm
+                .new_source_file(file.clone().into(), src.to_string());
+            let module = parse_file_as_module(
+                &fm,
+                Default::default(),
+                EsVersion::latest(),
+                None,
+                &mut Vec::new(),
+            )
+            .map_err(|err| anyhow!("failed to parse: {:?}", err))?;
+
+            Ok(ModuleData {
+                fm,
+                module,
+                helpers: Default::default(),
+            })
+        })
+    }
+}
+
+impl AsyncResolve for Remote {
+    fn resolve<'a>(
+        &'a self,
+        base: &'a FileName,
+        module_specifier: &'a str,
+    ) -> LocalBoxFuture<'a, Result<Resolution, Error>> {
+        Box::pin(async move {
+            let FileName::Url(base) = base else {
+                unreachable!("{} is not a url", base)
+            };
+
+            Ok(Resolution {
+                filename: FileName::Url(base.join(module_specifier)?),
+                slug: None,
+            })
+        })
+    }
+}
+
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+fn url(s: &str) -> FileName {
+    FileName::Url(Url::parse(s).unwrap())
+}
+
+const FILES: &[(&str, &str)] = &[
+    (
+        "https://example.com/main.js",
+        "import { a } from './a.js'; import { b } from './b.js'; console.log(a, b);",
+    ),
+    (
+        "https://example.com/a.js",
+        "import { c } from './lib/c.js'; export const a = c + 1;",
+    ),
+    (
+        "https://example.com/b.js",
+        "import { c } from './lib/c.js'; export const b = c + 2;",
+    ),
+    ("https://example.com/lib/c.js", "export const c = 1;"),
+];
+
+#[test]
+fn bundle_remote_modules() {
+    let cm = Lrc::new(SourceMap::default());
+    let remote = Remote::new(cm.clone(), FILES);
+
+    let globals = Globals::default();
+
+    let modules = GLOBALS.set(&globals, || {
+        block_on(Prefetcher::new(&remote, &remote).prefetch([url("https://example.com/main.js")]))
+    });
+
+    // `c.js` is imported twice but loaded once.
+    assert_eq!(remote.loads.get(), 4);
+
+    let handler = swc_common::errors::Handler::with_tty_emitter(
+        swc_common::errors::ColorConfig::Never,
+        true,
+        false,
+        Some(cm.clone()),
+    );
+    let code = HANDLER.set(&handler, || {
+        let mut bundler = Bundler::new(
+            &globals,
+            cm.clone(),
+            modules.clone(),
+            modules,
+            Config::default(),
+            Box::new(Hook),
+        );
+
+        let mut bundles = bundler
+            .bundle(
+                [("main".to_string(), url("https://example.com/main.js"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .unwrap();
+        assert_eq!(bundles.len(), 1);
+
+        let mut buf = Vec::new();
+        Emitter {
+            cfg: Default::default(),
+            cm: cm.clone(),
+            comments: None,
+            wr: JsWriter::new(cm.clone(), "\n", &mut buf, None),
+        }
+        .emit_module(&bundles.pop().unwrap().module)
+        .unwrap();
+
+        String::from_utf8(buf).unwrap()
+    });
+
+    assert!(code.contains("const a = 1 + 1;"), "{}", code);
+    assert!(code.contains("console.log(a, b);"), "{}", code);
+}
+
+#[test]
+fn max_concurrency() {
+    let cm = Lrc::new(SourceMap::default());
+    let files = (0..10)
+        .map(|i| -> (&'static str, &'static str) {
+            (
+                format!("https://example.com/{}.js", i).leak(),
+                format!("import './{}.js';", i + 1).leak(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let main = "https://example.com/main.js";
+    let main_src = (0..10)
+        .map(|i| format!("import './{}.js';", i))
+        .collect::<Vec<_>>()
+        .join("\n")
+        .leak();
+
+    let remote = Remote::new(cm, &[&[(main, &*main_src)], &*files].concat());
+
+    let modules = GLOBALS.set(&Default::default(), || {
+        block_on(
+            Prefetcher::new(&remote, &remote)
+                .with_max_concurrency(3)
+                .prefetch([url(main)]),
+        )
+    });
+
+    assert_eq!(remote.max_running.get(), 3);
+
+    // `10.js` does not exist, but it's reported only if the bundler loads it.
+    assert!(modules.load(&url("https://example.com/9.js")).is_ok());
+    let err = modules.load(&url("https://example.com/10.js")).unwrap_err();
+    assert!(err.to_string().contains("404"), "{}", err);
+}
+
+#[test]
+fn not_prefetched() {
+    let cm = Lrc::new(SourceMap::default());
+    let remote = Remote::new(cm, FILES);
+
+    let modules = block_on(Prefetcher::new(&remote, &remote).prefetch([]));
+
+    let err = modules
+        .load(&url("https://example.com/main.js"))
+        .unwrap_err();
+    assert!(err.to_string().contains("is not prefetched"), "{}", err);
+}
+
+struct Hook;
+
+impl swc_bundler::Hook for Hook {
+    fn get_import_meta_props(&self, _: Span, _: &ModuleRecord) -> Result<Vec<KeyValueProp>, Error> {
+        Ok(Vec::new())
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * C API of swc.
+ *
+ * All strings are nul-terminated UTF-8. Strings passed to swc are borrowed
+ * only for the duration of the call.
+ *
+ * Options are JSON strings in the same format as the options of `@swc/core`.
+ * A null pointer means the default options.
+ */
+
+#ifndef SWC_H
+#define SWC_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/*
+ * Result of a function of the C API.
+ *
+ * Exactly one of `output` and `error` is non-null. Both are owned by the
+ * caller and should be freed with `swc_result_free`.
+ */
+typedef struct SwcResult {
+    char *output;
+    char *error;
+} SwcResult;
+
+/*
+ * Compiles `code` and returns `{ "code": string, "map"?: string }`.
+ *
+ * The error message is formatted as specified by
+ * `options.experimental.errorFormat`.
+ */
+SwcResult swc_transform(const char *code, const char *options);
+
+/* Minifies `code` and returns `{ "code": string, "map"?: string }`. */
+SwcResult swc_minify(const char *code, const char *options);
+
+/* Parses `code` and returns the AST as JSON, which can be passed to `swc_print`. */
+SwcResult swc_parse(const char *code, const char *options);
+
+/* Prints the AST returned by `swc_parse` and returns `{ "code": string, "map"?: string }`. */
+SwcResult swc_print(const char *program, const char *options);
+
+/* Frees the strings of `result`. */
+void swc_result_free(SwcResult result);
+
+/* Returns the version of swc. The string is static and should not be freed. */
+const char *swc_version(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* SWC_H */
@@ -0,0 +1,320 @@
+//! C API of swc, for embedders which can't use the node.js bindings.
+//!
+//! The declarations are in `include/swc.h`.
+//!
+//! # Strings
+//!
+//! All strings are nul-terminated UTF-8. Strings passed to swc are borrowed
+//! only for the duration of the call.
+//!
+//! # Options
+//!
+//! Options are JSON strings in the same format as the options of `@swc/core`.
+//! A null pointer means the default options.
+//!
+//! # Results
+//!
+//! Each function returns a [SwcResult], which owns either the output or the
+//! error message. It should be freed with [swc_result_free].
+//!
+//! # Panics
+//!
+//! Panics are caught and reported as errors.
+#![deny(unused)]
+
+use std::{
+    ffi::{c_char, CStr, CString},
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+    ptr::null_mut,
+    sync::Arc,
+};
+
+use anyhow::{anyhow, Context, Error};
+use once_cell::sync::Lazy;
+use serde::de::DeserializeOwned;
+use swc::{
+    config::{ErrorFormat, JsMinifyOptions, Options, ParseOptions, SourceMapsConfig},
+    try_with_handler, Compiler, HandlerOpts, JsMinifyExtras, PrintArgs,
+};
+use swc_common::{comments::Comments, FileName, FilePathMapping, Mark, SourceMap, GLOBALS};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_visit::VisitMutWith;
+
+/// Result of a function of the C API.
+///
+/// Exactly one of `output` and `error` is non-null.
+#[repr(C)]
+pub struct SwcResult {
+    /// Output of the function.
+    pub output: *mut c_char,
+    /// Error message.
+    pub error: *mut c_char,
+}
+
+/// Compiles `code` and returns `{ "code": string, "map"?: string }`.
+///
+/// The error message is formatted as specified by
+/// `options.experimental.errorFormat`.
+///
+/// # Safety
+///
+/// `code` should be a valid C string, and `options` should be either null or
+/// a valid C string.
+#[no_mangle]
+pub unsafe extern "C" fn swc_transform(code: *const c_char, options: *const c_char) -> SwcResult {
+    run(|| transform(code, options))
+}
+
+/// Minifies `code` and returns `{ "code": string, "map"?: string }`.
+///
+/// # Safety
+///
+/// `code` should be a valid C string, and `options` should be either null or
+/// a valid C string.
+#[no_mangle]
+pub unsafe extern "C" fn swc_minify(code: *const c_char, options: *const c_char) -> SwcResult {
+    run(|| minify(code, options).map_err(format_err))
+}
+
+/// Parses `code` and returns the AST as JSON, which can be passed to
+/// [swc_print].
+///
+/// # Safety
+///
+/// `code` should be a valid C string, and `options` should be either null or
+/// a valid C string.
+#[no_mangle]
+pub unsafe extern "C" fn swc_parse(code: *const c_char, options: *const c_char) -> SwcResult {
+    run(|| parse(code, options).map_err(format_err))
+}
+
+/// Prints the AST returned by [swc_parse] and returns
+/// `{ "code": string, "map"?: string }`.
+///
+/// # Safety
+///
+/// `program` should be a valid C string, and `options` should be either null
+/// or a valid C string.
+#[no_mangle]
+pub unsafe extern "C" fn swc_print(program: *const c_char, options: *const c_char) -> SwcResult {
+    run(|| print(program, options).map_err(format_err))
+}
+
+/// Frees the strings of `result`.
+///
+/// # Safety
+///
+/// `result` should be returned by swc and should not be freed twice.
+#[no_mangle]
+pub unsafe extern "C" fn swc_result_free(result: SwcResult) {
+    if !result.output.is_null() {
+        drop(CString::from_raw(result.output));
+    }
+    if !result.error.is_null() {
+        drop(CString::from_raw(result.error));
+    }
+}
+
+/// Returns the version of swc. The string is static and should not be freed.
+#[no_mangle]
+pub extern "C" fn swc_version() -> *const c_char {
+    static VERSION: Lazy<CString> = Lazy::new(|| CString::new(env!("CARGO_PKG_VERSION")).unwrap());
+
+    VERSION.as_ptr()
+}
+
+/// Used by [swc_parse] and [swc_print], because the spans of the AST refer to
+/// the source map of the compiler which parsed it.
+static COMPILER: Lazy<Compiler> =
+    Lazy::new(|| Compiler::new(Arc::new(SourceMap::new(FilePathMapping::empty()))));
+
+fn fresh_compiler() -> Compiler {
+    Compiler::new(Arc::new(SourceMap::new(FilePathMapping::empty())))
+}
+
+unsafe fn transform(code: *const c_char, options: *const c_char) -> Result<String, String> {
+    let code = read_str(code).map_err(format_err)?;
+    let mut options: Options = read_options(options).map_err(format_err)?;
+
+    if !options.filename.is_empty() {
+        options.config.adjust(Path::new(&options.filename));
+    }
+
+    let error_format = options.experimental.error_format.unwrap_or_default();
+
+    let c = fresh_compiler();
+
+    let output = try_with(&c, |handler| {
+        let fm = c.cm.new_source_file(
+            if options.filename.is_empty() {
+                FileName::Anon.into()
+            } else {
+                FileName::Real(options.filename.clone().into()).into()
+            },
+            code.to_string(),
+        );
+
+        c.process_js_file(fm, handler, &options)
+    })
+    .map_err(|err| error_format.format(&err))?;
+
+    serde_json::to_string(&output).map_err(|err| format_err(err.into()))
+}
+
+unsafe fn minify(code: *const c_char, options: *const c_char) -> Result<String, Error> {
+    let code = read_str(code)?;
+    let options: JsMinifyOptions = read_options(options)?;
+
+    let c = fresh_compiler();
+
+    let output = try_with(&c, |handler| {
+        let fm =
+            c.cm.new_source_file(FileName::Anon.into(), code.to_string());
+
+        c.minify(fm, handler, &options, JsMinifyExtras::default())
+    })?;
+
+    Ok(serde_json::to_string(&output)?)
+}
+
+unsafe fn parse(code: *const c_char, options: *const c_char) -> Result<String, Error> {
+    let code = read_str(code)?;
+    let options: ParseOptions = read_options(options)?;
+
+    let c = &*COMPILER;
+
+    let program = try_with(c, |handler| {
+        let fm =
+            c.cm.new_source_file(FileName::Anon.into(), code.to_string());
+
+        let comments = if options.comments {
+            Some(c.comments() as &dyn Comments)
+        } else {
+            None
+        };
+
+        let mut program = c.parse_js(
+            fm,
+            handler,
+            options.target,
+            options.syntax,
+            options.is_module,
+            comments,
+        )?;
+
+        program.visit_mut_with(&mut resolver(
+            Mark::new(),
+            Mark::new(),
+            options.syntax.typescript(),
+        ));
+
+        Ok(program)
+    })?;
+
+    Ok(serde_json::to_string(&program)?)
+}
+
+unsafe fn print(program: *const c_char, options: *const c_char) -> Result<String, Error> {
+    let program: Program =
+        serde_json::from_str(read_str(program)?).context("failed to deserialize program")?;
+    let options: Options = read_options(options)?;
+
+    let c = &*COMPILER;
+
+    let output = GLOBALS.set(&Default::default(), || {
+        c.print(
+            &program,
+            PrintArgs {
+                output_path: options.output_path.clone(),
+                inline_sources_content: true,
+                source_map: options
+                    .source_maps
+                    .clone()
+                    .unwrap_or(SourceMapsConfig::Bool(false)),
+                emit_source_map_columns: options.config.emit_source_map_columns.into_bool(),
+                codegen_config: swc_ecma_codegen::Config::default()
+                    .with_target(options.config.jsc.target.unwrap_or(EsVersion::Es2020))
+                    .with_minify(options.config.minify.into_bool()),
+                ..Default::default()
+            },
+        )
+    })?;
+
+    Ok(serde_json::to_string(&output)?)
+}
+
+fn try_with<F, Ret>(c: &Compiler, op: F) -> Result<Ret, Error>
+where
+    F: FnOnce(&swc_common::errors::Handler) -> Result<Ret, Error>,
+{
+    GLOBALS
+        .set(&Default::default(), || {
+            try_with_handler(c.cm.clone(), HandlerOpts::default(), |handler| {
+                c.run(|| op(handler))
+            })
+        })
+        .map_err(|e| e.to_pretty_error())
+}
+
+/// Calls `op` and converts the output or the error into a [SwcResult].
+fn run<F>(op: F) -> SwcResult
+where
+    F: FnOnce() -> Result<String, String>,
+{
+    let result = catch_unwind(AssertUnwindSafe(op)).unwrap_or_else(|panic| {
+        Err(if let Some(s) = panic.downcast_ref::<String>() {
+            format!("failed to handle: {}", s)
+        } else if let Some(s) = panic.downcast_ref::<&str>() {
+            format!("failed to handle: {}", s)
+        } else {
+            "failed to handle with unknown panic message".into()
+        })
+    });
+
+    match result {
+        Ok(output) => SwcResult {
+            output: into_raw(output),
+            error: null_mut(),
+        },
+        Err(error) => SwcResult {
+            output: null_mut(),
+            error: into_raw(error),
+        },
+    }
+}
+
+fn format_err(err: Error) -> String {
+    ErrorFormat::Normal.format(&err)
+}
+
+/// Nul bytes can't be represented in C strings, so they are escaped.
+fn into_raw(s: String) -> *mut c_char {
+    let s = CString::new(s).unwrap_or_else(|err| {
+        CString::new(String::from_utf8_lossy(&err.into_vec()).replace('\0', "\\u0000")).unwrap()
+    });
+
+    s.into_raw()
+}
+
+unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str, Error> {
+    if s.is_null() {
+        return Err(anyhow!("unexpected null pointer"));
+    }
+
+    CStr::from_ptr(s)
+        .to_str()
+        .context("input is not valid utf-8")
+}
+
+unsafe fn read_options<T>(options: *const c_char) -> Result<T, Error>
+where
+    T: Default + DeserializeOwned,
+{
+    if options.is_null() {
+        return Ok(Default::default());
+    }
+
+    serde_json::from_str(read_str(options)?).context("failed to deserialize options")
+}
@@ -0,0 +1,105 @@
+use std::{
+    ffi::{CStr, CString},
+    ptr::null,
+};
+
+use serde_json::Value;
+use swc_c_api::*;
+
+/// Returns the output or the error of `result` and frees it.
+fn take(result: SwcResult) -> Result<String, String> {
+    unsafe {
+        let ret = if result.error.is_null() {
+            Ok(CStr::from_ptr(result.output).to_str().unwrap().to_string())
+        } else {
+            assert!(result.output.is_null());
+            Err(CStr::from_ptr(result.error).to_str().unwrap().to_string())
+        };
+
+        swc_result_free(result);
+
+        ret
+    }
+}
+
+fn code_of(output: &str) -> String {
+    let output: Value = serde_json::from_str(output).unwrap();
+
+    output["code"].as_str().unwrap().to_string()
+}
+
+#[test]
+fn transform() {
+    let code = CString::new("const a: number = 1;").unwrap();
+    let options = CString::new(r#"{ "jsc": { "parser": { "syntax": "typescript" } } }"#).unwrap();
+
+    let output = take(unsafe { swc_transform(code.as_ptr(), options.as_ptr()) }).unwrap();
+
+    assert_eq!(code_of(&output), "var a = 1;\n");
+}
+
+#[test]
+fn transform_error() {
+    let code = CString::new("const a: number = 1;").unwrap();
+
+    let error = take(unsafe { swc_transform(code.as_ptr(), null()) }).unwrap_err();
+
+    assert!(error.contains("Expected"), "{}", error);
+}
+
+#[test]
+fn transform_json_error() {
+    let code = CString::new("const = 1;").unwrap();
+    let options = CString::new(r#"{ "experimental": { "errorFormat": "json" } }"#).unwrap();
+
+    let error = take(unsafe { swc_transform(code.as_ptr(), options.as_ptr()) }).unwrap_err();
+    let error: Value = serde_json::from_str(&error).unwrap();
+
+    assert!(error["message"].is_string());
+}
+
+#[test]
+fn invalid_options() {
+    let code = CString::new("foo();").unwrap();
+    let options = CString::new("{").unwrap();
+
+    let error = take(unsafe { swc_minify(code.as_ptr(), options.as_ptr()) }).unwrap_err();
+
+    assert!(error.contains("failed to deserialize options"), "{}", error);
+}
+
+#[test]
+fn minify() {
+    let code = CString::new("function foo() { return 1 + 2; } foo();").unwrap();
+    let options = CString::new(r#"{ "compress": true, "mangle": true }"#).unwrap();
+
+    let output = take(unsafe { swc_minify(code.as_ptr(), options.as_ptr()) }).unwrap();
+
+    assert_eq!(code_of(&output), "function foo(){return 3}foo();");
+}
+
+#[test]
+fn parse_and_print() {
+    let code = CString::new("export const a = 1 + 2;").unwrap();
+
+    let program = take(unsafe { swc_parse(code.as_ptr(), null()) }).unwrap();
+    let program = CString::new(program).unwrap();
+
+    let output = take(unsafe { swc_print(program.as_ptr(), null()) }).unwrap();
+
+    assert_eq!(code_of(&output), "export const a = 1 + 2;\n");
+}
+
+#[test]
+fn null_code() {
+    let error = take(unsafe { swc_parse(null(), null()) }).unwrap_err();
+
+    assert!(error.contains("null pointer"), "{}", error);
+}
+
+#[test]
+fn version() {
+    let version = unsafe { CStr::from_ptr(swc_version()) };
+
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+}
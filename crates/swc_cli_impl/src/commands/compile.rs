@@ -14,7 +14,7 @@ use relative_path::RelativePath;
 use swc_core::{
     base::{
         config::{Config, ConfigFile, Options, PluginConfig, SourceMapsConfig},
-        try_with_handler, Compiler, HandlerOpts, TransformOutput,
+        profile, try_with_handler, Compiler, HandlerOpts, Timing, TransformOutput,
     },
     common::{
         errors::ColorConfig, sync::Lazy, FileName, FilePathMapping, SourceFile, SourceMap, GLOBALS,
@@ -93,6 +93,11 @@ pub struct CompileOptions {
     #[clap(long, default_value_t= String::from("js"))]
     out_file_extension: String,
 
+    /// Print the time spent on parsing, each transform pass, minification and
+    /// printing of each file to stderr.
+    #[clap(long)]
+    profile: bool,
+
     /// Enable experimental trace profiling
     /// generates trace compatible with trace event format.
     #[clap(group = "experimental_trace", long)]
@@ -114,14 +119,15 @@ fn parse_config(s: &str) -> Result<Config, serde_json::Error> {
     serde_json::from_str(s)
 }
 
-static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(|| {
+pub(crate) static COMPILER: Lazy<Arc<Compiler>> = Lazy::new(|| {
     let cm = Arc::new(SourceMap::new(FilePathMapping::empty()));
 
     Arc::new(Compiler::new(cm))
 });
 
 /// List of file extensions supported by default.
-static DEFAULT_EXTENSIONS: &[&str] = &["js", "jsx", "es6", "es", "mjs", "ts", "tsx", "cts", "mts"];
+pub(crate) static DEFAULT_EXTENSIONS: &[&str] =
+    &["js", "jsx", "es6", "es", "mjs", "ts", "tsx", "cts", "mts"];
 
 /// Infer list of files to be transformed from cli arguments.
 /// If given input is a directory, it'll traverse it and collect all supported
@@ -261,6 +267,19 @@ fn emit_output(
     Ok(())
 }
 
+/// Prints `timings` as a tree. The output is written at once, so timings of
+/// files compiled in parallel are not mixed.
+fn print_timings(file_name: &FileName, timings: &[Timing]) {
+    let mut buf = format!("Timings of {}\n", file_name);
+
+    for timing in timings {
+        let name = format!("{:indent$}{}", "", timing.name, indent = timing.depth * 2);
+        buf.push_str(&format!("  {:<48} {:?}\n", name, timing.duration));
+    }
+
+    eprint!("{}", buf);
+}
+
 fn collect_stdin_input() -> Option<String> {
     let stdin = io::stdin();
     if stdin.is_terminal() {
@@ -421,20 +440,29 @@ impl CompileOptions {
         let execute = |compiler: Arc<Compiler>, fm: Arc<SourceFile>, options: Options| {
             let color = ColorConfig::Always;
             let skip_filename = false;
+            let file_name = fm.name.clone();
+
+            let (result, timings) = profile(|| {
+                try_with_handler(
+                    compiler.cm.clone(),
+                    HandlerOpts {
+                        color,
+                        skip_filename,
+                    },
+                    |handler| {
+                        GLOBALS.set(&Default::default(), || {
+                            compiler.process_js_file(fm, handler, &options)
+                        })
+                    },
+                )
+                .map_err(|e| e.to_pretty_error())
+            });
 
-            try_with_handler(
-                compiler.cm.clone(),
-                HandlerOpts {
-                    color,
-                    skip_filename,
-                },
-                |handler| {
-                    GLOBALS.set(&Default::default(), || {
-                        compiler.process_js_file(fm, handler, &options)
-                    })
-                },
-            )
-            .map_err(|e| e.to_pretty_error())
+            if self.profile {
+                print_timings(&file_name, &timings);
+            }
+
+            result
         };
 
         if let Some(single_out_file) = self.out_file.as_ref() {
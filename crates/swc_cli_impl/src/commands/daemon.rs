@@ -0,0 +1,343 @@
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+};
+
+use anyhow::Context;
+use clap::Parser;
+use serde::{Deserialize, Serialize};
+use swc_core::{
+    base::{
+        config::{JsMinifyOptions, Options, ParseOptions},
+        try_with_handler, Compiler, HandlerOpts, JsMinifyExtras,
+    },
+    common::{
+        cancel::CancellationToken, comments::Comments, errors::ColorConfig, FileName, Globals,
+        GLOBALS,
+    },
+    trace_macro::swc_trace,
+};
+
+/// Run swc as a long-running process which serves requests over a socket.
+///
+/// The protocol is newline-delimited JSON. Each line sent by a client is a
+/// [Request] and each line sent back is a [Response] with the same `id`.
+/// Requests are processed concurrently, so responses may arrive out of order.
+#[derive(Parser)]
+pub struct DaemonOptions {
+    /// Address to listen on. Use port `0` to let the OS pick a free port; the
+    /// bound address is printed to stdout once the daemon is ready.
+    #[clap(long, default_value = "127.0.0.1:0")]
+    listen: String,
+
+    /// Serve a single client over stdin / stdout instead of a socket.
+    #[clap(long, conflicts_with = "listen")]
+    stdio: bool,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct Request {
+    id: u64,
+    #[serde(flatten)]
+    method: Method,
+}
+
+#[derive(Deserialize)]
+#[serde(tag = "method", content = "params", rename_all = "camelCase")]
+pub(crate) enum Method {
+    Compile(SourceParams<Box<Options>>),
+    Minify(SourceParams<Box<JsMinifyOptions>>),
+    Parse(SourceParams<ParseOptions>),
+    /// Cancels the request with the given id, if it has not completed yet.
+    Cancel {
+        id: u64,
+    },
+    Ping,
+    /// Stops accepting new connections and exits once this connection closes.
+    Shutdown,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SourceParams<O> {
+    src: String,
+    #[serde(default)]
+    filename: Option<String>,
+    options: O,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Response {
+    id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Response {
+    fn from_result(id: u64, result: anyhow::Result<serde_json::Value>) -> Self {
+        match result {
+            Ok(v) => Response {
+                id,
+                result: Some(v),
+                error: None,
+            },
+            Err(err) => Response {
+                id,
+                result: None,
+                error: Some(format!("{:?}", err)),
+            },
+        }
+    }
+}
+
+/// Requests which are currently being processed, keyed by request id.
+type InFlight = Arc<Mutex<HashMap<u64, CancellationToken>>>;
+
+fn file_name(filename: Option<String>) -> FileName {
+    match filename {
+        Some(v) if !v.is_empty() => FileName::Real(v.into()),
+        _ => FileName::Anon,
+    }
+}
+
+fn with_handler<T>(
+    compiler: &Compiler,
+    token: &CancellationToken,
+    op: impl FnOnce(&swc_core::common::errors::Handler) -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    try_with_handler(
+        compiler.cm.clone(),
+        HandlerOpts {
+            color: ColorConfig::Never,
+            skip_filename: false,
+        },
+        |handler| GLOBALS.set(&Globals::with_cancellation(token.clone()), || op(handler)),
+    )
+    .map_err(|e| e.to_pretty_error())
+}
+
+/// Processes a single compile / minify / parse request.
+///
+/// Each request uses its own [Compiler], so the source map and the comments
+/// of a request are dropped once it completes instead of growing for the
+/// lifetime of the daemon. The parser and the passes stop early once `token`
+/// is cancelled.
+pub(crate) fn process(
+    method: Method,
+    token: &CancellationToken,
+) -> anyhow::Result<serde_json::Value> {
+    let compiler = &Compiler::new(Default::default());
+
+    match method {
+        Method::Compile(SourceParams {
+            src,
+            filename,
+            mut options,
+        }) => {
+            if let Some(filename) = &filename {
+                options.filename.clone_from(filename);
+            }
+
+            let fm = compiler.cm.new_source_file(file_name(filename).into(), src);
+            let output = with_handler(compiler, token, |handler| {
+                compiler.process_js_file(fm, handler, &options)
+            })?;
+
+            Ok(serde_json::to_value(output)?)
+        }
+        Method::Minify(SourceParams {
+            src,
+            filename,
+            options,
+        }) => {
+            let fm = compiler.cm.new_source_file(file_name(filename).into(), src);
+            let output = with_handler(compiler, token, |handler| {
+                compiler.minify(fm, handler, &options, JsMinifyExtras::default())
+            })?;
+
+            Ok(serde_json::to_value(output)?)
+        }
+        Method::Parse(SourceParams {
+            src,
+            filename,
+            options,
+        }) => {
+            let fm = compiler.cm.new_source_file(file_name(filename).into(), src);
+            let program = with_handler(compiler, token, |handler| {
+                let comments = if options.comments {
+                    Some(compiler.comments() as &dyn Comments)
+                } else {
+                    None
+                };
+
+                compiler.parse_js(
+                    fm,
+                    handler,
+                    options.target,
+                    options.syntax,
+                    options.is_module,
+                    comments,
+                )
+            })?;
+
+            Ok(serde_json::to_value(program)?)
+        }
+        Method::Ping => Ok(serde_json::Value::Bool(true)),
+        Method::Cancel { .. } | Method::Shutdown => {
+            unreachable!("control requests are handled by the connection loop")
+        }
+    }
+}
+
+/// Serves one client until it closes the connection.
+///
+/// Returns `true` if the client requested a shutdown.
+fn serve<R, W>(reader: R, writer: W) -> anyhow::Result<bool>
+where
+    R: BufRead,
+    W: Write + Send + 'static,
+{
+    let writer = Arc::new(Mutex::new(writer));
+    let in_flight: InFlight = Default::default();
+    let mut shutdown = false;
+
+    let send = {
+        let writer = writer.clone();
+        move |res: Response| -> io::Result<()> {
+            let mut line = serde_json::to_vec(&res)?;
+            line.push(b'\n');
+
+            let mut w = writer.lock().unwrap();
+            w.write_all(&line)?;
+            w.flush()
+        }
+    };
+
+    rayon::in_place_scope(|s| -> anyhow::Result<()> {
+        for line in reader.lines() {
+            let line = line.context("failed to read a request")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let req: Request = match serde_json::from_str(&line) {
+                Ok(v) => v,
+                Err(err) => {
+                    send(Response::from_result(
+                        0,
+                        Err(anyhow::anyhow!("invalid request: {}", err)),
+                    ))?;
+                    continue;
+                }
+            };
+
+            match req.method {
+                Method::Cancel { id } => {
+                    let found = match in_flight.lock().unwrap().get(&id) {
+                        Some(token) => {
+                            token.cancel();
+                            true
+                        }
+                        None => false,
+                    };
+                    send(Response::from_result(req.id, Ok(found.into())))?;
+                }
+                Method::Shutdown => {
+                    shutdown = true;
+                    send(Response::from_result(req.id, Ok(true.into())))?;
+                }
+                method => {
+                    let token = CancellationToken::new();
+                    in_flight.lock().unwrap().insert(req.id, token.clone());
+
+                    let in_flight = in_flight.clone();
+                    let send = send.clone();
+                    s.spawn(move |_| {
+                        let id = req.id;
+                        let result = if token.is_cancelled() {
+                            Err(anyhow::anyhow!("request {} was cancelled", id))
+                        } else {
+                            process(method, &token)
+                        };
+
+                        in_flight.lock().unwrap().remove(&id);
+
+                        // A cancelled request still gets a response, so clients can
+                        // release their bookkeeping for it.
+                        let result = if token.is_cancelled() {
+                            Err(anyhow::anyhow!("request {} was cancelled", id))
+                        } else {
+                            result
+                        };
+
+                        let _ = send(Response::from_result(id, result));
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(shutdown)
+}
+
+fn serve_tcp(stream: TcpStream) -> anyhow::Result<bool> {
+    let reader = BufReader::new(stream.try_clone()?);
+
+    serve(reader, stream)
+}
+
+#[swc_trace]
+impl super::CommandRunner for DaemonOptions {
+    fn execute(&self) -> anyhow::Result<()> {
+        if self.stdio {
+            serve(io::stdin().lock(), io::stdout())?;
+            return Ok(());
+        }
+
+        let listener = TcpListener::bind(&self.listen)
+            .with_context(|| format!("failed to listen on {}", self.listen))?;
+        println!("{}", listener.local_addr()?);
+        io::stdout().flush()?;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+
+        for stream in listener.incoming() {
+            if shutdown.load(Ordering::Relaxed) {
+                break;
+            }
+
+            let stream = match stream {
+                Ok(v) => v,
+                Err(err) => {
+                    tracing::error!("failed to accept a connection: {}", err);
+                    continue;
+                }
+            };
+
+            let shutdown = shutdown.clone();
+            let addr = listener.local_addr()?;
+            thread::spawn(move || match serve_tcp(stream) {
+                Ok(true) => {
+                    shutdown.store(true, Ordering::Relaxed);
+                    // Wake up the accept loop so it can observe the flag.
+                    let _ = TcpStream::connect(addr);
+                }
+                Ok(false) => {}
+                Err(err) => tracing::error!("daemon connection failed: {:?}", err),
+            });
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    fs,
+    hash::{Hash, Hasher},
+    path::Path,
+};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+use swc_core::ecma::lints::config::LintConfig;
+
+/// Files which had no problems in a previous run, so they are not linted again
+/// until they or their config change.
+#[derive(Default, Serialize, Deserialize)]
+pub(super) struct LintCache {
+    /// The cache is discarded if it's written by another version of swc, as
+    /// rules may have changed.
+    version: String,
+    files: HashMap<String, u64>,
+}
+
+impl LintCache {
+    /// Reads a cache file. A missing or invalid file is treated as an empty
+    /// cache.
+    pub fn load(path: &Path) -> Self {
+        fs::read(path)
+            .ok()
+            .and_then(|content| serde_json::from_slice::<Self>(&content).ok())
+            .filter(|cache| cache.version == env!("CARGO_PKG_VERSION"))
+            .unwrap_or_default()
+    }
+
+    pub fn save(&mut self, path: &Path) -> anyhow::Result<()> {
+        self.version = env!("CARGO_PKG_VERSION").to_string();
+
+        fs::write(path, serde_json::to_vec(self)?)
+            .with_context(|| format!("failed to write lint cache {}", path.display()))
+    }
+
+    pub fn is_clean(&self, path: &Path, key: u64) -> bool {
+        self.files.get(&*path.to_string_lossy()) == Some(&key)
+    }
+
+    pub fn set_clean(&mut self, path: &Path, key: u64) {
+        self.files.insert(path.to_string_lossy().into_owned(), key);
+    }
+
+    pub fn remove(&mut self, path: &Path) {
+        self.files.remove(&*path.to_string_lossy());
+    }
+}
+
+/// Hash of the source code and the lint config of a file.
+///
+/// [DefaultHasher::new] always uses the same keys, so the hash is stable
+/// across runs of the same binary.
+pub(super) fn cache_key(src: &str, config: &LintConfig) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    src.hash(&mut hasher);
+    serde_json::to_string(config)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
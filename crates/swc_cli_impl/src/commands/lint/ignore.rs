@@ -0,0 +1,130 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::Context;
+use glob::{MatchOptions, Pattern};
+
+/// Directories which are never linted unless a pattern re-includes them.
+const DEFAULT_PATTERNS: &[&str] = &["node_modules/", ".git/"];
+
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+struct IgnorePattern {
+    pattern: Pattern,
+    /// `!pattern` re-includes paths ignored by a previous pattern.
+    negated: bool,
+    /// Patterns containing a `/` are matched against the whole path, and others
+    /// against the file name.
+    anchored: bool,
+    /// `pattern/` only matches directories.
+    dir_only: bool,
+}
+
+/// Patterns with the syntax of `.gitignore`, matched against paths relative to
+/// the current directory.
+pub(super) struct IgnorePatterns {
+    base: PathBuf,
+    patterns: Vec<IgnorePattern>,
+}
+
+impl IgnorePatterns {
+    pub fn new(base: PathBuf) -> anyhow::Result<Self> {
+        let mut ignore = Self {
+            base,
+            patterns: Vec::new(),
+        };
+
+        for pattern in DEFAULT_PATTERNS {
+            ignore.add(pattern)?;
+        }
+
+        Ok(ignore)
+    }
+
+    /// Adds patterns from an ignore file, one per line.
+    pub fn add_file(&mut self, path: &Path) -> anyhow::Result<()> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("failed to read ignore file {}", path.display()))?;
+
+        for line in content.lines() {
+            self.add(line)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn add(&mut self, line: &str) -> anyhow::Result<()> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(());
+        }
+
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+        let (dir_only, line) = match line.strip_suffix('/') {
+            Some(line) => (true, line),
+            None => (false, line),
+        };
+        let anchored = line.contains('/');
+        let line = line.trim_start_matches('/');
+
+        self.patterns.push(IgnorePattern {
+            pattern: Pattern::new(line)
+                .with_context(|| format!("invalid ignore pattern `{}`", line))?,
+            negated,
+            anchored,
+            dir_only,
+        });
+
+        Ok(())
+    }
+
+    /// Returns `true` if `path` or one of its parent directories is ignored.
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        let path = path.strip_prefix(&self.base).unwrap_or(path);
+        let components = path
+            .components()
+            .filter(|c| matches!(c, Component::Normal(..)))
+            .collect::<Vec<_>>();
+
+        // A file in an ignored directory can't be re-included, like git.
+        (1..=components.len()).any(|len| {
+            let path = components[..len].iter().collect::<PathBuf>();
+            let is_dir = len < components.len() || self.base.join(&path).is_dir();
+
+            self.matches(&path, is_dir)
+        })
+    }
+
+    fn matches(&self, path: &Path, is_dir: bool) -> bool {
+        let mut ignored = false;
+
+        for p in &self.patterns {
+            if p.dir_only && !is_dir {
+                continue;
+            }
+
+            let matched = if p.anchored {
+                p.pattern.matches_path_with(path, MATCH_OPTIONS)
+            } else {
+                path.file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| p.pattern.matches_with(name, MATCH_OPTIONS))
+            };
+
+            if matched {
+                ignored = !p.negated;
+            }
+        }
+
+        ignored
+    }
+}
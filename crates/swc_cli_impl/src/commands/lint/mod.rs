@@ -0,0 +1,485 @@
+use std::{
+    fs,
+    mem::take,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use anyhow::Context;
+use clap::Parser;
+use glob::glob;
+use path_absolutize::Absolutize;
+use rayon::prelude::*;
+use swc_core::{
+    base::{
+        config::{ConfigFile, IsModule, Options},
+        try_with_handler, Compiler, HandlerOpts,
+    },
+    common::{
+        comments::{Comment, SingleThreadedComments},
+        errors::{ColorConfig, Diagnostic, DiagnosticBuilder, Handler},
+        FileName, Mark, SourceFile, SyntaxContext, GLOBALS,
+    },
+    ecma::{
+        ast::{EsVersion, Program},
+        lints::{
+            config::{LintConfig, LintRuleReaction},
+            fix::{apply_fixes, collect_fixes},
+            format::{LintResult, OutputFormat},
+            import_cycle::{check_import_cycles, ProjectFile},
+            linter::{sorted_comments, LintFile, Linter},
+            registry::RuleRegistry,
+        },
+        loader::{resolvers::node::NodeModulesResolver, TargetEnv},
+        parser::{EsSyntax, Syntax, TsSyntax},
+        transforms::base::resolver,
+    },
+    trace_macro::swc_trace,
+};
+use walkdir::WalkDir;
+
+use self::{
+    cache::{cache_key, LintCache},
+    ignore::IgnorePatterns,
+};
+use crate::commands::compile::{COMPILER, DEFAULT_EXTENSIONS};
+
+mod cache;
+mod ignore;
+
+/// Fixing a problem may reveal another one, so fixes are applied repeatedly.
+/// This limits the number of rounds in case fixes of two rules conflict.
+const MAX_FIX_PASSES: usize = 10;
+
+/// Lint files with the rules configured in `.swcrc`.
+#[derive(Parser)]
+pub struct LintOptions {
+    /// Path to a .swcrc file to use
+    #[clap(long)]
+    config_file: Option<PathBuf>,
+
+    /// Fix problems automatically and write the result back to the files.
+    #[clap(long)]
+    fix: bool,
+
+    /// Also apply fixes which may change the behavior of the code. Implies
+    /// `--fix`.
+    #[clap(long)]
+    fix_unsafe: bool,
+
+    /// Print problems in one of the formats of ESLint instead: `stylish`,
+    /// `compact`, `json` or `sarif`.
+    #[clap(long)]
+    format: Option<OutputFormat>,
+
+    /// Path to a file with patterns of files to ignore, using the syntax of
+    /// `.gitignore`. Defaults to `.swclintignore`, if it exists.
+    #[clap(long)]
+    ignore_path: Option<PathBuf>,
+
+    /// Pattern of files to ignore, in addition to the ones in the ignore
+    /// file. Can be specified multiple times.
+    #[clap(long, multiple_occurrences = true)]
+    ignore_pattern: Vec<String>,
+
+    /// Only lint files which changed since the last run. Files without
+    /// problems are remembered in the cache file.
+    #[clap(long)]
+    cache: bool,
+
+    /// Path to the cache file.
+    #[clap(long, default_value = ".swclintcache")]
+    cache_location: PathBuf,
+
+    /// Files, directories or glob patterns to lint
+    files: Vec<PathBuf>,
+}
+
+struct LintedFile {
+    fm: Arc<SourceFile>,
+    program: Program,
+    comments: Vec<Comment>,
+    /// Diagnostics before `swc-lint-disable` comments are applied.
+    diagnostics: Vec<Diagnostic>,
+}
+
+struct LintedPath {
+    path: PathBuf,
+    linter: Linter,
+    linted: LintedFile,
+    cache_key: u64,
+}
+
+#[swc_trace]
+impl LintOptions {
+    fn read_ignore_patterns(&self) -> anyhow::Result<IgnorePatterns> {
+        let mut ignore = IgnorePatterns::new(std::env::current_dir()?)?;
+
+        match &self.ignore_path {
+            Some(path) => ignore.add_file(path)?,
+            None => {
+                let path = Path::new(".swclintignore");
+                if path.is_file() {
+                    ignore.add_file(path)?;
+                }
+            }
+        }
+
+        for pattern in &self.ignore_pattern {
+            ignore.add(pattern)?;
+        }
+
+        Ok(ignore)
+    }
+
+    /// Expands directories and glob patterns in the inputs.
+    fn collect_files(&self, ignore: &IgnorePatterns) -> anyhow::Result<Vec<PathBuf>> {
+        let is_supported = |path: &Path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| DEFAULT_EXTENSIONS.contains(&ext))
+        };
+
+        let mut files = Vec::new();
+
+        for input in &self.files {
+            if input.is_dir() {
+                files.extend(
+                    WalkDir::new(input)
+                        .into_iter()
+                        .filter_entry(|e| !ignore.is_ignored(e.path()))
+                        .filter_map(|e| e.ok())
+                        .map(|e| e.into_path())
+                        .filter(|path| path.is_file() && is_supported(path)),
+                );
+            } else if input.is_file() {
+                if !ignore.is_ignored(input) {
+                    files.push(input.clone());
+                }
+            } else {
+                let pattern = input.to_string_lossy();
+                let matched = glob(&pattern)
+                    .with_context(|| format!("invalid glob pattern `{}`", pattern))?
+                    .filter_map(|path| path.ok())
+                    .filter(|path| path.is_file() && is_supported(path))
+                    .filter(|path| !ignore.is_ignored(path))
+                    .collect::<Vec<_>>();
+
+                if matched.is_empty() {
+                    anyhow::bail!("No files matching the pattern `{}` were found", pattern);
+                }
+                files.extend(matched);
+            }
+        }
+
+        files.sort();
+        files.dedup();
+
+        Ok(files)
+    }
+
+    fn read_config(
+        &self,
+        compiler: &Compiler,
+        path: &Path,
+    ) -> anyhow::Result<(LintConfig, Syntax, EsVersion)> {
+        let options = Options {
+            swcrc: true,
+            config_file: self
+                .config_file
+                .as_ref()
+                .map(|p| ConfigFile::Str(p.to_string_lossy().to_string())),
+            ..Default::default()
+        };
+
+        let config = compiler
+            .read_config(&options, &FileName::Real(path.to_path_buf()))?
+            .unwrap_or_default();
+
+        let syntax =
+            config
+                .jsc
+                .syntax
+                .unwrap_or_else(|| match path.extension().and_then(|e| e.to_str()) {
+                    Some("ts" | "mts" | "cts") => Syntax::Typescript(Default::default()),
+                    Some("tsx") => Syntax::Typescript(TsSyntax {
+                        tsx: true,
+                        ..Default::default()
+                    }),
+                    _ => Syntax::Es(EsSyntax {
+                        jsx: true,
+                        ..Default::default()
+                    }),
+                });
+
+        Ok((
+            config.jsc.lints,
+            syntax,
+            config.jsc.target.unwrap_or_else(EsVersion::latest),
+        ))
+    }
+
+    /// Parses and lints a source file.
+    fn lint_source(
+        compiler: &Compiler,
+        handler: &Handler,
+        linter: &Linter,
+        fm: Arc<SourceFile>,
+        syntax: Syntax,
+        es_version: EsVersion,
+    ) -> anyhow::Result<LintedFile> {
+        let comments = SingleThreadedComments::default();
+        let mut program = compiler.parse_js(
+            fm.clone(),
+            handler,
+            es_version,
+            syntax,
+            IsModule::Unknown,
+            Some(&comments),
+        )?;
+
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        program.mutate(resolver(
+            unresolved_mark,
+            top_level_mark,
+            syntax.typescript(),
+        ));
+
+        let comments = sorted_comments(&comments);
+        let diagnostics = linter.lint_without_directives(&LintFile {
+            program: &program,
+            unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+            top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+            es_version,
+            comments: &comments,
+        });
+
+        Ok(LintedFile {
+            fm,
+            program,
+            comments,
+            diagnostics,
+        })
+    }
+
+    /// Returns `None` if the file is known to have no problems.
+    fn lint_file(
+        &self,
+        compiler: &Compiler,
+        handler: &Handler,
+        cache: &LintCache,
+        path: &Path,
+    ) -> anyhow::Result<Option<LintedPath>> {
+        let (config, syntax, es_version) = self.read_config(compiler, path)?;
+
+        let fm = compiler
+            .cm
+            .load_file(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        // Files in the import graph are needed even if they have no problems.
+        if matches!(
+            config.import_cycle.get_rule_reaction(),
+            LintRuleReaction::Off
+        ) && cache.is_clean(&cache_path(path), cache_key(&fm.src, &config))
+        {
+            return Ok(None);
+        }
+
+        let linter = Linter::new(RuleRegistry::default(), config, compiler.cm.clone());
+        let mut linted = Self::lint_source(compiler, handler, &linter, fm, syntax, es_version)?;
+
+        if self.fix || self.fix_unsafe {
+            for _ in 0..MAX_FIX_PASSES {
+                let fixes = linter
+                    .apply_disable_directives(
+                        &linted.program,
+                        &linted.comments,
+                        linted.diagnostics.clone(),
+                    )
+                    .iter()
+                    .flat_map(|d| collect_fixes(d, self.fix_unsafe))
+                    .collect::<Vec<_>>();
+                if fixes.is_empty() {
+                    break;
+                }
+
+                let fixed = apply_fixes(&linted.fm, fixes);
+                fs::write(path, &fixed.code)
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+
+                let fm = compiler
+                    .cm
+                    .new_source_file(FileName::Real(path.to_path_buf()).into(), fixed.code);
+                linted = Self::lint_source(compiler, handler, &linter, fm, syntax, es_version)?;
+            }
+        }
+
+        Ok(Some(LintedPath {
+            path: path.to_path_buf(),
+            cache_key: cache_key(&linted.fm.src, linter.config()),
+            linter,
+            linted,
+        }))
+    }
+
+    /// Adds problems which involve multiple files.
+    fn lint_project(files: &mut [LintedPath]) {
+        // The options of the first file which enables the rule are used for
+        // the whole graph.
+        let Some(config) = files
+            .iter()
+            .map(|f| &f.linter.config().import_cycle)
+            .find(|c| !matches!(c.get_rule_reaction(), LintRuleReaction::Off))
+            .cloned()
+        else {
+            return;
+        };
+
+        let resolver = NodeModulesResolver::new(TargetEnv::Node, Default::default(), true);
+        let cycles = check_import_cycles(
+            &config,
+            &files
+                .iter()
+                .map(|f| ProjectFile {
+                    file_name: FileName::Real(f.path.clone()),
+                    program: &f.linted.program,
+                })
+                .collect::<Vec<_>>(),
+            &resolver,
+        );
+
+        for (file, diagnostics) in files.iter_mut().zip(cycles) {
+            if matches!(
+                file.linter.config().import_cycle.get_rule_reaction(),
+                LintRuleReaction::Off
+            ) {
+                continue;
+            }
+
+            file.linted.diagnostics.extend(diagnostics);
+            file.linted
+                .diagnostics
+                .sort_by_key(|d| d.span.primary_span());
+        }
+    }
+
+    /// Removes problems suppressed by `swc-lint-disable` comments.
+    ///
+    /// This runs after [LintOptions::lint_project], so problems involving
+    /// multiple files can be suppressed as well.
+    fn apply_disable_directives(files: &mut [LintedPath]) {
+        for file in files {
+            let linted = &mut file.linted;
+            linted.diagnostics = file.linter.apply_disable_directives(
+                &linted.program,
+                &linted.comments,
+                take(&mut linted.diagnostics),
+            );
+        }
+    }
+}
+
+#[swc_trace]
+impl super::CommandRunner for LintOptions {
+    fn execute(&self) -> anyhow::Result<()> {
+        let ignore = self.read_ignore_patterns()?;
+        let files = self.collect_files(&ignore)?;
+        if files.is_empty() {
+            anyhow::bail!("No files to lint");
+        }
+
+        let compiler = COMPILER.clone();
+        let handler_opts = HandlerOpts {
+            color: ColorConfig::Auto,
+            skip_filename: false,
+        };
+
+        let mut cache = if self.cache {
+            LintCache::load(&self.cache_location)
+        } else {
+            Default::default()
+        };
+
+        let results = files
+            .par_iter()
+            .map(|path| {
+                let result = try_with_handler(compiler.cm.clone(), handler_opts, |handler| {
+                    GLOBALS.set(&Default::default(), || {
+                        self.lint_file(&compiler, handler, &cache, path)
+                    })
+                });
+
+                result
+                    .map_err(|err| eprintln!("{}", err.to_pretty_string()))
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+
+        let mut failed = results.iter().filter(|r| r.is_none()).count();
+        let mut linted = results.into_iter().flatten().flatten().collect::<Vec<_>>();
+
+        Self::lint_project(&mut linted);
+        Self::apply_disable_directives(&mut linted);
+
+        if self.cache {
+            for f in &linted {
+                let path = cache_path(&f.path);
+                if f.linted.diagnostics.is_empty() {
+                    cache.set_clean(&path, f.cache_key);
+                } else {
+                    cache.remove(&path);
+                }
+            }
+            cache.save(&self.cache_location)?;
+        }
+
+        failed += linted
+            .iter()
+            .filter(|f| f.linted.diagnostics.iter().any(|d| d.is_error()))
+            .count();
+
+        match self.format {
+            Some(format) => {
+                let results = linted
+                    .into_iter()
+                    .map(|f| LintResult {
+                        file_path: f.path.display().to_string(),
+                        diagnostics: f.linted.diagnostics,
+                    })
+                    .collect::<Vec<_>>();
+
+                print!("{}", format.format(&compiler.cm, &results));
+            }
+            None => {
+                for f in linted {
+                    let result = try_with_handler(compiler.cm.clone(), handler_opts, |handler| {
+                        for d in f.linted.diagnostics {
+                            DiagnosticBuilder::new_diagnostic(handler, d).emit();
+                        }
+                        Ok(())
+                    });
+
+                    if let Err(err) = result {
+                        eprintln!("{}", err.to_pretty_string());
+                    }
+                }
+            }
+        }
+
+        if failed > 0 {
+            anyhow::bail!("Found problems in {} file(s)", failed);
+        }
+
+        Ok(())
+    }
+}
+
+/// Paths in the cache are absolute, so it does not depend on the directory
+/// `swc` is invoked from.
+fn cache_path(path: &Path) -> PathBuf {
+    path.absolutize()
+        .map(|p| p.into_owned())
+        .unwrap_or_else(|_| path.to_path_buf())
+}
@@ -2,12 +2,14 @@ use clap::{Parser, Subcommand};
 
 mod bundle;
 mod compile;
+mod daemon;
 mod lint;
 mod minify;
 mod plugin;
 
 pub use bundle::*;
 pub use compile::*;
+pub use daemon::DaemonOptions;
 pub use lint::*;
 pub use minify::*;
 pub use plugin::PluginSubcommand;
@@ -23,6 +25,8 @@ pub enum Command {
     Bundle(BundleOptions),
     Minify(MinifyOptions),
     Lint(LintOptions),
+    /// Run SWC as a long-running daemon serving requests over a socket.
+    Daemon(DaemonOptions),
 }
 
 #[derive(Parser)]
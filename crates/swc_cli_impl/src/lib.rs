@@ -1,5 +1,5 @@
 use clap::Parser;
-use commands::{Command, CommandRunner, PluginSubcommand, SwcCliOptions};
+use commands::{Command, CommandRunner, DaemonOptions, PluginSubcommand, SwcCliOptions};
 
 mod commands;
 mod util;
@@ -13,5 +13,11 @@ pub fn run() -> anyhow::Result<()> {
         Command::Minify(options) => options.execute(),
         Command::Bundle(options) => options.execute(),
         Command::Lint(options) => options.execute(),
+        Command::Daemon(options) => options.execute(),
     }
 }
+
+/// Entrypoint of the `swcd` binary, which is a shorthand for `swc daemon`.
+pub fn run_daemon() -> anyhow::Result<()> {
+    DaemonOptions::parse().execute()
+}
@@ -0,0 +1,5 @@
+//! Long-running swc daemon. See `swc daemon --help`.
+
+fn main() -> anyhow::Result<()> {
+    swc_cli_impl::run_daemon()
+}
@@ -0,0 +1,76 @@
+use std::{
+    io::{BufRead, BufReader, Write},
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use serde_json::Value;
+
+fn request_all(lines: &[&str]) -> Result<Vec<Value>> {
+    let mut child = Command::cargo_bin("swcd")
+        .context("Failed to get swcd binary")?
+        .arg("--stdio")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()?;
+
+    {
+        let mut stdin = child.stdin.take().unwrap();
+        for line in lines {
+            writeln!(stdin, "{}", line)?;
+        }
+    }
+
+    let stdout = child.stdout.take().unwrap();
+    let mut responses = BufReader::new(stdout)
+        .lines()
+        .map(|line| Ok(serde_json::from_str::<Value>(&line?)?))
+        .collect::<Result<Vec<_>>>()?;
+    assert!(child.wait()?.success());
+
+    responses.sort_by_key(|v| v["id"].as_u64());
+    Ok(responses)
+}
+
+#[test]
+fn daemon_serves_requests() -> Result<()> {
+    let responses = request_all(&[
+        r#"{"id":1,"method":"ping"}"#,
+        r#"{"id":2,"method":"compile","params":{"src":"const a = () => 1;","filename":"a.js","options":{"jsc":{"target":"es5"}}}}"#,
+        r#"{"id":3,"method":"minify","params":{"src":"function foo() { return 1 + 2; }","options":{"compress":true}}}"#,
+        r#"{"id":4,"method":"parse","params":{"src":"let x: number = 1;","options":{"syntax":"typescript"}}}"#,
+    ])?;
+
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[0]["result"], Value::Bool(true));
+
+    let code = responses[1]["result"]["code"].as_str().unwrap();
+    assert!(code.contains("var a = function()"), "{}", code);
+
+    let code = responses[2]["result"]["code"].as_str().unwrap();
+    assert!(code.contains("return 3"), "{}", code);
+
+    assert_eq!(responses[3]["result"]["type"], "Module");
+
+    Ok(())
+}
+
+#[test]
+fn daemon_reports_errors_per_request() -> Result<()> {
+    let responses = request_all(&[
+        r#"{"id":1,"method":"parse","params":{"src":"let = ;","options":{"syntax":"ecmascript"}}}"#,
+        r#"not json"#,
+    ])?;
+
+    assert_eq!(responses.len(), 2);
+    // Malformed requests are reported with id `0`.
+    assert!(responses[0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("invalid request"));
+    assert!(responses[1]["error"].is_string());
+
+    Ok(())
+}
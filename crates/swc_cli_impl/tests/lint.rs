@@ -0,0 +1,261 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use anyhow::{Context, Result};
+use assert_cmd::prelude::*;
+use assert_fs::TempDir;
+
+fn cli() -> Result<Command> {
+    let mut cmd = Command::cargo_bin("swc").context("Failed to get swc binary")?;
+    cmd.stderr(Stdio::inherit());
+    Ok(cmd)
+}
+
+const SWCRC: &str = r#"{
+    "jsc": {
+        "lints": {
+            "no-unused-imports": ["error"],
+            "no-unused-vars": ["error"]
+        }
+    }
+}"#;
+
+#[test]
+fn lint_fails_on_errors() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(tmp.path().join(".swcrc"), SWCRC)?;
+    fs::write(
+        tmp.path().join("a.js"),
+        "import { a, b } from 'x';\nconsole.log(b);\n",
+    )?;
+
+    cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("a.js")
+        .assert()
+        .failure();
+
+    Ok(())
+}
+
+#[test]
+fn fix_applies_safe_fixes_only() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(tmp.path().join(".swcrc"), SWCRC)?;
+    fs::write(
+        tmp.path().join("a.js"),
+        "import { a, b } from 'x';\nlet unused = 1;\nconsole.log(b);\n",
+    )?;
+
+    // `unused` is only removed with `--fix-unsafe`.
+    cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("--fix")
+        .arg("a.js")
+        .assert()
+        .failure();
+
+    let content = fs::read_to_string(tmp.path().join("a.js"))?;
+    assert_eq!(
+        content,
+        "import { b } from 'x';\nlet unused = 1;\nconsole.log(b);\n"
+    );
+
+    cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("--fix-unsafe")
+        .arg("a.js")
+        .assert()
+        .success();
+
+    let content = fs::read_to_string(tmp.path().join("a.js"))?;
+    assert_eq!(content, "import { b } from 'x';\n\nconsole.log(b);\n");
+
+    Ok(())
+}
+
+#[test]
+fn json_format() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(tmp.path().join(".swcrc"), SWCRC)?;
+    fs::write(
+        tmp.path().join("a.js"),
+        "import { a, b } from 'x';\nconsole.log(b);\n",
+    )?;
+
+    let output = cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("--format")
+        .arg("json")
+        .arg("a.js")
+        .output()?;
+    assert!(!output.status.success());
+
+    let results: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    assert_eq!(results[0]["filePath"], "a.js");
+    assert_eq!(results[0]["errorCount"], 1);
+    assert_eq!(results[0]["messages"][0]["ruleId"], "no-unused-imports");
+    assert_eq!(results[0]["messages"][0]["line"], 1);
+    assert_eq!(results[0]["messages"][0]["column"], 10);
+
+    Ok(())
+}
+
+#[test]
+fn disable_comments() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(tmp.path().join(".swcrc"), SWCRC)?;
+    fs::write(
+        tmp.path().join("a.js"),
+        "// swc-lint-disable-next-line no-unused-imports\nimport { a } from 'x';\n",
+    )?;
+
+    cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("a.js")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn import_cycle() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(
+        tmp.path().join(".swcrc"),
+        r#"{ "jsc": { "lints": { "import-cycle": ["error"] } } }"#,
+    )?;
+    fs::write(tmp.path().join("a.js"), "import './b';\n")?;
+    fs::write(tmp.path().join("b.js"), "import './a';\n")?;
+
+    let output = cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("--format")
+        .arg("compact")
+        .arg("a.js")
+        .arg("b.js")
+        .output()?;
+    assert!(!output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    assert!(
+        stdout.contains(
+            "a.js: line 1, col 1, Error - Dependency cycle detected: a.js -> b.js -> a.js \
+             (import-cycle)"
+        ),
+        "{}",
+        stdout
+    );
+
+    Ok(())
+}
+
+#[test]
+fn import_cycle_disable_directive() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(
+        tmp.path().join(".swcrc"),
+        r#"{ "jsc": { "lints": { "import-cycle": ["error"] } } }"#,
+    )?;
+    fs::write(
+        tmp.path().join("a.js"),
+        "/* swc-lint-disable import-cycle */\nimport './b';\n",
+    )?;
+    fs::write(
+        tmp.path().join("b.js"),
+        "/* swc-lint-disable import-cycle */\nimport './a';\n",
+    )?;
+
+    cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("a.js")
+        .arg("b.js")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[test]
+fn glob_and_ignore_patterns() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(tmp.path().join(".swcrc"), SWCRC)?;
+    fs::create_dir_all(tmp.path().join("src/generated"))?;
+    fs::create_dir_all(tmp.path().join("node_modules/x"))?;
+    fs::write(tmp.path().join("src/a.js"), "console.log(1);\n")?;
+    fs::write(tmp.path().join("src/b.ts"), "console.log(2);\n")?;
+    fs::write(tmp.path().join("src/generated/c.js"), "let unused;\n")?;
+    fs::write(tmp.path().join("src/d.js"), "let unused;\n")?;
+    fs::write(tmp.path().join("node_modules/x/e.js"), "let unused;\n")?;
+    fs::write(tmp.path().join(".swclintignore"), "# comment\ngenerated/\n")?;
+
+    cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("--ignore-pattern")
+        .arg("d.js")
+        .arg("src/**/*.js")
+        .arg(".")
+        .assert()
+        .success();
+
+    let output = cli()?
+        .current_dir(&tmp)
+        .arg("lint")
+        .arg("--format")
+        .arg("json")
+        .arg("src/**/*.js")
+        .output()?;
+    assert!(!output.status.success());
+
+    let results: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+    let files = results
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["filePath"].as_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(files, vec!["src/a.js", "src/d.js"]);
+
+    Ok(())
+}
+
+#[test]
+fn cache_skips_clean_files() -> Result<()> {
+    let tmp = TempDir::new()?;
+    fs::write(tmp.path().join(".swcrc"), SWCRC)?;
+    fs::write(tmp.path().join("a.js"), "console.log(1);\n")?;
+    fs::write(tmp.path().join("b.js"), "let unused;\n")?;
+
+    cli()?
+        .current_dir(&tmp)
+        .args(["lint", "--cache", "a.js", "b.js"])
+        .assert()
+        .failure();
+
+    let cache: serde_json::Value =
+        serde_json::from_slice(&fs::read(tmp.path().join(".swclintcache"))?)?;
+    let files = cache["files"].as_object().unwrap();
+    assert_eq!(files.len(), 1);
+    assert!(files.keys().all(|path| path.ends_with("a.js")));
+
+    // Changing a file invalidates its entry.
+    fs::write(tmp.path().join("a.js"), "let unused;\n")?;
+    cli()?
+        .current_dir(&tmp)
+        .args(["lint", "--cache", "a.js"])
+        .assert()
+        .failure();
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+//! Cancellation of long-running operations.
+//!
+//! The parser, transforms, the minifier and the bundler check the token of
+//! the current [Globals](crate::Globals) at safe points and stop early once
+//! it's cancelled. The output of a cancelled operation is incomplete and should
+//! be discarded.
+//!
+//! # Example
+//!
+//! ```rust
+//! use swc_common::{cancel::CancellationToken, Globals, GLOBALS};
+//!
+//! let token = CancellationToken::new();
+//!
+//! GLOBALS.set(&Globals::with_cancellation(token.clone()), || {
+//!     // Another thread may call `token.cancel()` at any time.
+//! });
+//! ```
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use crate::GLOBALS;
+
+/// A flag which asks operations using a [Globals](crate::Globals) to stop.
+///
+/// Clones share the same flag, so a clone can be kept by the host to cancel
+/// the work later.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels operations using this token. This cannot be undone.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns `Err(Cancelled)` if this token is cancelled.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The error of an operation which stopped because it was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("operation was cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/// Returns true if the token of the current [Globals](crate::Globals) is
+/// cancelled.
+///
+/// Returns false if [GLOBALS] is not set.
+#[inline]
+pub fn is_cancelled() -> bool {
+    GLOBALS.is_set() && GLOBALS.with(|globals| globals.cancellation().is_cancelled())
+}
+
+/// Returns `Err(Cancelled)` if the token of the current
+/// [Globals](crate::Globals) is cancelled.
+#[inline]
+pub fn check() -> Result<(), Cancelled> {
+    if is_cancelled() {
+        Err(Cancelled)
+    } else {
+        Ok(())
+    }
+}
@@ -54,6 +54,7 @@ pub trait AstNode: Debug + PartialEq + Clone + Spanned {
 }
 
 pub mod cache;
+pub mod cancel;
 pub mod comments;
 mod eq;
 pub mod errors;
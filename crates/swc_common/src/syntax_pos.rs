@@ -16,7 +16,10 @@ use url::Url;
 
 use self::hygiene::MarkData;
 pub use self::hygiene::{Mark, SyntaxContext};
-use crate::{cache::CacheCell, rustc_data_structures::stable_hasher::StableHasher, sync::Lrc};
+use crate::{
+    cache::CacheCell, cancel::CancellationToken,
+    rustc_data_structures::stable_hasher::StableHasher, sync::Lrc,
+};
 
 mod analyze_source_file;
 pub mod hygiene;
@@ -104,6 +107,7 @@ pub struct Globals {
     dummy_cnt: AtomicU32,
     #[allow(unused)]
     marks: Mutex<Vec<MarkData>>,
+    cancellation: CancellationToken,
 }
 
 const DUMMY_RESERVE: u32 = u32::MAX - 2_u32.pow(16);
@@ -122,8 +126,23 @@ impl Globals {
                 parent: Mark::root(),
             }]),
             dummy_cnt: AtomicU32::new(DUMMY_RESERVE),
+            cancellation: Default::default(),
+        }
+    }
+
+    /// Creates a [Globals] which can be cancelled using `token`.
+    ///
+    /// See [crate::cancel].
+    pub fn with_cancellation(token: CancellationToken) -> Globals {
+        Globals {
+            cancellation: token,
+            ..Globals::new()
         }
     }
+
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
 }
 
 better_scoped_tls::scoped_tls!(
@@ -26,6 +26,10 @@ use swc_ecma_parser::{parse_file_as_module, parse_file_as_program, parse_file_as
 use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
 use swc_timer::timer;
 
+use crate::scopes::SourceMapScopes;
+
+pub mod scopes;
+
 #[cfg(feature = "node")]
 #[napi_derive::napi(object)]
 #[derive(Debug, Serialize)]
@@ -120,6 +124,9 @@ pub struct PrintArgs<'a> {
     pub codegen_config: swc_ecma_codegen::Config,
     pub output: Option<FxHashMap<String, String>>,
     pub source_map_url: Option<&'a str>,
+    /// Adds scope information to the source map. This is ignored if `orig` is
+    /// given.
+    pub source_map_scopes: Option<&'a SourceMapScopes>,
 }
 
 impl Default for PrintArgs<'_> {
@@ -140,6 +147,7 @@ impl Default for PrintArgs<'_> {
             codegen_config: Default::default(),
             output: None,
             source_map_url: None,
+            source_map_scopes: None,
         }
     }
 }
@@ -171,6 +179,7 @@ pub fn print<T>(
         codegen_config,
         output,
         source_map_url,
+        source_map_scopes,
     }: PrintArgs,
 ) -> Result<TransformOutput, Error>
 where
@@ -185,7 +194,7 @@ where
         {
             let mut w = swc_ecma_codegen::text_writer::JsWriter::new(
                 cm.clone(),
-                "\n",
+                codegen_config.line_ending.as_str(),
                 &mut buf,
                 if source_map.enabled() {
                     Some(&mut src_map_buf)
@@ -223,55 +232,55 @@ where
         panic!("The module contains only dummy spans\n{}", src);
     }
 
-    let mut map = if source_map.enabled() {
-        Some(cm.build_source_map_with_config(
-            &src_map_buf,
-            orig,
-            SwcSourceMapConfig {
-                source_file_name,
-                output_path: output_path.as_deref(),
-                names: source_map_names,
-                inline_sources_content,
-                emit_columns: emit_source_map_columns,
-            },
-        ))
-    } else {
-        None
+    let config = SwcSourceMapConfig {
+        source_file_name,
+        output_path: output_path.as_deref(),
+        names: source_map_names,
+        inline_sources_content,
+        emit_columns: emit_source_map_columns,
     };
 
-    if let Some(map) = &mut map {
+    let map = if source_map.enabled() {
+        let mut map = cm.build_source_map_with_config(&src_map_buf, orig, config);
+
         if source_root.is_some() {
             map.set_source_root(source_root)
         }
-    }
+
+        let mut buf = std::vec::Vec::new();
+        map.to_writer(&mut buf)
+            .context("failed to write source map")?;
+        let mut map = String::from_utf8(buf).context("source map is not utf-8")?;
+
+        if let (Some(scopes), None) = (source_map_scopes, orig) {
+            map = scopes.add_to_source_map(
+                &cm,
+                &src_map_buf,
+                |f| config.file_name_to_source(f),
+                &map,
+            )?;
+        }
+
+        Some(map)
+    } else {
+        None
+    };
 
     let (code, map) = match source_map {
         SourceMapsConfig::Bool(v) => {
             if v {
-                let mut buf = std::vec::Vec::new();
-
-                map.unwrap()
-                    .to_writer(&mut buf)
-                    .context("failed to write source map")?;
-                let map = String::from_utf8(buf).context("source map is not utf-8")?;
-
                 if let Some(source_map_url) = source_map_url {
                     src.push_str("\n//# sourceMappingURL=");
                     src.push_str(source_map_url);
                 }
 
-                (src, Some(map))
+                (src, map)
             } else {
                 (src, None)
             }
         }
         SourceMapsConfig::Str(_) => {
-            let mut buf = std::vec::Vec::new();
-
-            map.unwrap()
-                .to_writer(&mut buf)
-                .context("failed to write source map file")?;
-            let map = String::from_utf8(buf).context("source map is not utf-8")?;
+            let map = map.unwrap();
 
             src.push_str("\n//# sourceMappingURL=data:application/json;base64,");
             BASE64_STANDARD.encode_string(map.as_bytes(), &mut src);
@@ -289,6 +298,7 @@ where
     })
 }
 
+#[derive(Clone, Copy)]
 struct SwcSourceMapConfig<'a> {
     source_file_name: Option<&'a str>,
     /// Output path of the `.map` file.
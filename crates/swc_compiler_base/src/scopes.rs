@@ -0,0 +1,797 @@
+//! Scope information of source maps, which allows debuggers to reconstruct
+//! the original scopes and the values of original variables in minified code.
+//!
+//! This implements the [scopes proposal] of source maps, and adds two fields
+//! to the source map.
+//!
+//! - `originalScopes`: A string for each source, with items separated by `,`. A
+//!   start of a scope is `line, column, flags, name?, kind?, variables*` and an
+//!   end of a scope is `line, column`. `line` is relative to the previous item.
+//!   `flags` is `0x1` if there's a name, `0x2` if there's a kind and `0x4` if
+//!   the scope is a stack frame. `name`, `kind` and `variables` are indices
+//!   into `names`, relative to the previous one of the same field.
+//! - `generatedRanges`: Lines are separated by `;` and items are separated by
+//!   `,`. A start of a range is `column, flags, source?, scope?, bindings*` and
+//!   an end of a range is `column`. `column` is relative to the previous item
+//!   in the same line. `flags` is `0x1` if there's a definition and `0x4` if
+//!   the range is a stack frame. `source` and `scope` are relative to the
+//!   previous definition, and `bindings` contains an absolute index into
+//!   `names` for each variable of the definition, or `-1` if the variable is
+//!   not available.
+//!
+//! [scopes proposal]: https://github.com/tc39/source-map/blob/main/proposals/scopes.md
+
+use anyhow::{Context, Error};
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde_json::Value;
+use sourcemap::vlq::generate_vlq_segment;
+use swc_atoms::Atom;
+use swc_common::{BytePos, FileName, LineCol, SourceMap, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+/// Original scopes of a program and the bindings of them in the generated
+/// code.
+///
+/// Create this with [SourceMapScopes::collect] before transforming the
+/// program, and call [SourceMapScopes::set_generated] with the transformed
+/// program.
+#[derive(Debug)]
+pub struct SourceMapScopes {
+    root: OriginalScope,
+    /// Names of identifiers in the generated code, keyed by their original
+    /// position.
+    generated_names: FxHashMap<BytePos, Atom>,
+    /// Start of scopes which exist in the generated code.
+    generated_scopes: FxHashSet<BytePos>,
+}
+
+#[derive(Debug)]
+struct OriginalScope {
+    kind: ScopeKind,
+    name: Option<Atom>,
+    span: Span,
+    /// Name and position of the declaration.
+    variables: Vec<(Atom, BytePos)>,
+    children: Vec<OriginalScope>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScopeKind {
+    Global,
+    Module,
+    Function,
+    Block,
+}
+
+impl ScopeKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            ScopeKind::Global => "global",
+            ScopeKind::Module => "module",
+            ScopeKind::Function => "function",
+            ScopeKind::Block => "block",
+        }
+    }
+
+    fn is_stack_frame(self) -> bool {
+        self != ScopeKind::Block
+    }
+}
+
+impl SourceMapScopes {
+    /// Collects scopes of the original program.
+    pub fn collect(program: &Program) -> Self {
+        let kind = match program {
+            Program::Module(..) => ScopeKind::Module,
+            Program::Script(..) => ScopeKind::Global,
+        };
+
+        let mut v = OriginalScopeCollector {
+            stack: vec![OriginalScope::new(kind, None, program.span())],
+        };
+        program.visit_with(&mut v);
+
+        SourceMapScopes {
+            root: v.stack.pop().unwrap(),
+            generated_names: Default::default(),
+            generated_scopes: Default::default(),
+        }
+    }
+
+    /// Collects bindings of original variables from the transformed program.
+    ///
+    /// Variables are matched by the position of their declarations, so spans
+    /// of identifiers should be preserved by the transforms.
+    pub fn set_generated(&mut self, program: &Program) {
+        let mut v = GeneratedCollector {
+            names: Default::default(),
+            scopes: Default::default(),
+        };
+        program.visit_with(&mut v);
+
+        self.generated_names = v.names;
+        self.generated_scopes = v.scopes;
+        self.generated_scopes.insert(self.root.span.lo);
+    }
+
+    /// Adds `originalScopes` and `generatedRanges` to `map`, which is a source
+    /// map serialized as json.
+    pub(crate) fn add_to_source_map(
+        &self,
+        cm: &SourceMap,
+        mappings: &[(BytePos, LineCol)],
+        file_name_to_source: impl Fn(&FileName) -> String,
+        map: &str,
+    ) -> Result<String, Error> {
+        let mut map: Value = serde_json::from_str(map).context("invalid source map")?;
+        let map_obj = map.as_object_mut().context("source map is not an object")?;
+
+        let fm = cm.lookup_source_file(self.root.span.lo);
+        let source = file_name_to_source(&fm.name);
+
+        let sources = map_obj
+            .get("sources")
+            .and_then(|v| v.as_array())
+            .map(|v| v.iter().map(|s| s.as_str()).collect::<Vec<_>>())
+            .unwrap_or_default();
+        let source_idx = match sources.iter().position(|s| *s == Some(&*source)) {
+            Some(v) => v,
+            // The file has no mapping.
+            None => return serde_json::to_string(&map).context("failed to write source map"),
+        };
+
+        let mut names = Names::new(map_obj.get("names"));
+
+        let mut original_scopes = vec![String::new(); sources.len()];
+        original_scopes[source_idx] = self.encode_original_scopes(cm, &mut names)?;
+
+        let generated_ranges = self.encode_generated_ranges(mappings, source_idx, &mut names)?;
+
+        map_obj.insert("names".into(), names.into_json());
+        map_obj.insert("originalScopes".into(), original_scopes.into());
+        map_obj.insert("generatedRanges".into(), generated_ranges.into());
+
+        serde_json::to_string(&map).context("failed to write source map")
+    }
+
+    fn encode_original_scopes(&self, cm: &SourceMap, names: &mut Names) -> Result<String, Error> {
+        fn encode(
+            scope: &OriginalScope,
+            cm: &SourceMap,
+            names: &mut Names,
+            state: &mut OriginalState,
+            buf: &mut Vec<String>,
+        ) -> Result<(), Error> {
+            let (line, col) = original_line_col(cm, scope.span.lo);
+            let mut item = vec![line as i64 - state.line as i64, col as i64];
+            state.line = line;
+
+            let flags = if scope.name.is_some() { 0x1 } else { 0 }
+                | 0x2
+                | if scope.kind.is_stack_frame() { 0x4 } else { 0 };
+            item.push(flags);
+
+            if let Some(name) = &scope.name {
+                let idx = names.get(name) as i64;
+                item.push(idx - state.name);
+                state.name = idx;
+            }
+
+            let idx = names.get(scope.kind.as_str()) as i64;
+            item.push(idx - state.kind);
+            state.kind = idx;
+
+            for (name, _) in &scope.variables {
+                let idx = names.get(name) as i64;
+                item.push(idx - state.variable);
+                state.variable = idx;
+            }
+
+            buf.push(generate_vlq_segment(&item)?);
+
+            for child in &scope.children {
+                encode(child, cm, names, state, buf)?;
+            }
+
+            let (line, col) = original_line_col(cm, scope.span.hi);
+            buf.push(generate_vlq_segment(&[
+                line as i64 - state.line as i64,
+                col as i64,
+            ])?);
+            state.line = line;
+
+            Ok(())
+        }
+
+        let mut buf = Vec::new();
+        encode(
+            &self.root,
+            cm,
+            names,
+            &mut OriginalState::default(),
+            &mut buf,
+        )?;
+
+        Ok(buf.join(","))
+    }
+
+    fn encode_generated_ranges(
+        &self,
+        mappings: &[(BytePos, LineCol)],
+        source_idx: usize,
+        names: &mut Names,
+    ) -> Result<String, Error> {
+        let mut positions = mappings
+            .iter()
+            .enumerate()
+            .filter(|(_, (pos, _))| !pos.is_dummy() && !pos.is_reserved_for_comments())
+            .map(|(idx, (pos, _))| (*pos, idx))
+            .collect::<Vec<_>>();
+        positions.sort_unstable();
+
+        let mut ranges = Vec::new();
+        let mut scope_idx = 0;
+        self.build_ranges(
+            &self.root,
+            mappings,
+            &positions,
+            &mut scope_idx,
+            &mut ranges,
+        );
+        normalize_ranges(&mut ranges, None);
+
+        let mut state = GeneratedState::default();
+        for range in &ranges {
+            range.encode(source_idx, names, &mut state)?;
+        }
+
+        Ok(state.buf)
+    }
+
+    fn build_ranges(
+        &self,
+        scope: &OriginalScope,
+        mappings: &[(BytePos, LineCol)],
+        positions: &[(BytePos, usize)],
+        scope_idx: &mut usize,
+        out: &mut Vec<GeneratedRange>,
+    ) {
+        let idx = *scope_idx;
+        *scope_idx += 1;
+
+        let range = if self.generated_scopes.contains(&scope.span.lo) {
+            let start = positions.partition_point(|(pos, _)| *pos < scope.span.lo);
+            let end = positions.partition_point(|(pos, _)| *pos <= scope.span.hi);
+            let in_scope = &positions[start..end];
+
+            let first = in_scope.iter().map(|(_, idx)| *idx).min();
+            let last = in_scope.iter().map(|(_, idx)| *idx).max();
+
+            first.zip(last).map(|(first, last)| {
+                let end = mappings[last].1;
+
+                GeneratedRange {
+                    start: mappings[first].1,
+                    end: LineCol {
+                        line: end.line,
+                        col: end.col + 1,
+                    },
+                    scope_idx: idx,
+                    is_stack_frame: scope.kind.is_stack_frame(),
+                    bindings: scope
+                        .variables
+                        .iter()
+                        .map(|(_, pos)| self.generated_names.get(pos).cloned())
+                        .collect(),
+                    children: Vec::new(),
+                }
+            })
+        } else {
+            None
+        };
+
+        match range {
+            Some(mut range) => {
+                for child in &scope.children {
+                    self.build_ranges(child, mappings, positions, scope_idx, &mut range.children);
+                }
+                out.push(range);
+            }
+            None => {
+                // Ranges of children are attached to the closest ancestor.
+                for child in &scope.children {
+                    self.build_ranges(child, mappings, positions, scope_idx, out);
+                }
+            }
+        }
+    }
+}
+
+impl OriginalScope {
+    fn new(kind: ScopeKind, name: Option<Atom>, span: Span) -> Self {
+        OriginalScope {
+            kind,
+            name,
+            span,
+            variables: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct GeneratedRange {
+    start: LineCol,
+    end: LineCol,
+    /// Index of the original scope, in pre-order.
+    scope_idx: usize,
+    is_stack_frame: bool,
+    bindings: Vec<Option<Atom>>,
+    children: Vec<GeneratedRange>,
+}
+
+impl GeneratedRange {
+    fn encode(
+        &self,
+        source_idx: usize,
+        names: &mut Names,
+        state: &mut GeneratedState,
+    ) -> Result<(), Error> {
+        let mut item = vec![
+            state.column(self.start),
+            0x1 | if self.is_stack_frame { 0x4 } else { 0 },
+            source_idx as i64 - state.source,
+        ];
+        if source_idx as i64 == state.source {
+            item.push(self.scope_idx as i64 - state.scope);
+        } else {
+            item.push(self.scope_idx as i64);
+        }
+        state.source = source_idx as i64;
+        state.scope = self.scope_idx as i64;
+
+        for binding in &self.bindings {
+            item.push(match binding {
+                Some(name) => names.get(name) as i64,
+                None => -1,
+            });
+        }
+        state.push(&item)?;
+
+        for child in &self.children {
+            child.encode(source_idx, names, state)?;
+        }
+
+        let item = [state.column(self.end)];
+        state.push(&item)
+    }
+}
+
+/// Sorts ranges by their start and removes ranges which are not nested
+/// properly, as code may be moved by the minifier.
+fn normalize_ranges(ranges: &mut Vec<GeneratedRange>, parent: Option<(LineCol, LineCol)>) {
+    ranges.sort_by_key(|r| (r.start.line, r.start.col));
+
+    let mut prev_end = parent.map(|(start, _)| start);
+    ranges.retain(|r| {
+        let valid = (r.start.line, r.start.col) <= (r.end.line, r.end.col)
+            && prev_end.map_or(true, |prev| {
+                (prev.line, prev.col) <= (r.start.line, r.start.col)
+            })
+            && parent.map_or(true, |(_, end)| {
+                (r.end.line, r.end.col) <= (end.line, end.col)
+            });
+        if valid {
+            prev_end = Some(r.end);
+        }
+        valid
+    });
+
+    for range in ranges {
+        normalize_ranges(&mut range.children, Some((range.start, range.end)));
+    }
+}
+
+#[derive(Default)]
+struct OriginalState {
+    line: u32,
+    name: i64,
+    kind: i64,
+    variable: i64,
+}
+
+#[derive(Default)]
+struct GeneratedState {
+    buf: String,
+    line: u32,
+    col: u32,
+    /// `true` if there's an item in the current line.
+    has_item: bool,
+    source: i64,
+    scope: i64,
+}
+
+impl GeneratedState {
+    /// Moves to the line of `pos` and returns the relative column.
+    fn column(&mut self, pos: LineCol) -> i64 {
+        if pos.line > self.line {
+            for _ in self.line..pos.line {
+                self.buf.push(';');
+            }
+            self.line = pos.line;
+            self.col = 0;
+            self.has_item = false;
+        }
+
+        let col = pos.col as i64 - self.col as i64;
+        self.col = pos.col;
+        col
+    }
+
+    fn push(&mut self, item: &[i64]) -> Result<(), Error> {
+        if self.has_item {
+            self.buf.push(',');
+        }
+        self.buf.push_str(&generate_vlq_segment(item)?);
+        self.has_item = true;
+        Ok(())
+    }
+}
+
+/// `names` of a source map.
+struct Names {
+    names: Vec<String>,
+    indices: FxHashMap<String, usize>,
+}
+
+impl Names {
+    fn new(names: Option<&Value>) -> Self {
+        let names = names
+            .and_then(|v| v.as_array())
+            .map(|v| {
+                v.iter()
+                    .map(|s| s.as_str().unwrap_or_default().to_string())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let indices = names
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| (name.clone(), idx))
+            .collect();
+
+        Names { names, indices }
+    }
+
+    fn get(&mut self, name: &str) -> usize {
+        if let Some(&idx) = self.indices.get(name) {
+            return idx;
+        }
+
+        let idx = self.names.len();
+        self.names.push(name.to_string());
+        self.indices.insert(name.to_string(), idx);
+        idx
+    }
+
+    fn into_json(self) -> Value {
+        self.names.into()
+    }
+}
+
+/// Returns the line and the UTF-16 column of `pos`, starting from 0.
+fn original_line_col(cm: &SourceMap, pos: BytePos) -> (u32, u32) {
+    let fm = cm.lookup_source_file(pos);
+    let line = fm.lookup_line(pos).unwrap_or_default();
+    let line_start = fm.analyze().lines[line];
+
+    let col = fm.src[(line_start - fm.start_pos).0 as usize..(pos - fm.start_pos).0 as usize]
+        .encode_utf16()
+        .count();
+
+    (line as u32, col as u32)
+}
+
+struct OriginalScopeCollector {
+    stack: Vec<OriginalScope>,
+}
+
+impl OriginalScopeCollector {
+    fn enter(&mut self, kind: ScopeKind, name: Option<Atom>, span: Span) {
+        self.stack.push(OriginalScope::new(kind, name, span));
+    }
+
+    fn exit(&mut self) {
+        let scope = self.stack.pop().unwrap();
+        let parent = self.stack.last_mut().unwrap();
+
+        if scope.kind == ScopeKind::Block && scope.variables.is_empty() {
+            parent.children.extend(scope.children);
+        } else {
+            parent.children.push(scope);
+        }
+    }
+
+    /// Declares a variable in the innermost scope.
+    fn declare_lexical(&mut self, id: &Ident) {
+        self.stack
+            .last_mut()
+            .unwrap()
+            .variables
+            .push((id.sym.clone(), id.span.lo));
+    }
+
+    /// Declares a variable in the innermost function or global scope.
+    fn declare_var(&mut self, id: &Ident) {
+        let scope = self
+            .stack
+            .iter_mut()
+            .rev()
+            .find(|s| s.kind != ScopeKind::Block)
+            .unwrap();
+
+        scope.variables.push((id.sym.clone(), id.span.lo));
+    }
+
+    fn declare_pat(&mut self, pat: &Pat, is_var: bool) {
+        match pat {
+            Pat::Ident(i) => {
+                if is_var {
+                    self.declare_var(&i.id)
+                } else {
+                    self.declare_lexical(&i.id)
+                }
+            }
+            Pat::Array(a) => {
+                for elem in a.elems.iter().flatten() {
+                    self.declare_pat(elem, is_var);
+                }
+            }
+            Pat::Object(o) => {
+                for prop in &o.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(p) => self.declare_pat(&p.value, is_var),
+                        ObjectPatProp::Assign(p) => {
+                            if is_var {
+                                self.declare_var(&p.key.id)
+                            } else {
+                                self.declare_lexical(&p.key.id)
+                            }
+                        }
+                        ObjectPatProp::Rest(p) => self.declare_pat(&p.arg, is_var),
+                    }
+                }
+            }
+            Pat::Rest(r) => self.declare_pat(&r.arg, is_var),
+            Pat::Assign(a) => self.declare_pat(&a.left, is_var),
+            Pat::Invalid(..) | Pat::Expr(..) => {}
+        }
+    }
+
+    fn function(&mut self, name: Option<Atom>, f: &Function) {
+        let params = f.params.iter().map(|p| &p.pat).collect::<Vec<_>>();
+
+        self.function_like(name, f.span, &params, |v| {
+            if let Some(body) = &f.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn function_like(
+        &mut self,
+        name: Option<Atom>,
+        span: Span,
+        params: &[&Pat],
+        visit_body: impl FnOnce(&mut Self),
+    ) {
+        self.enter(ScopeKind::Function, name, span);
+
+        for param in params {
+            self.declare_pat(param, true);
+            param.visit_with(self);
+        }
+        visit_body(self);
+
+        self.exit();
+    }
+}
+
+fn prop_name(key: &PropName) -> Option<Atom> {
+    match key {
+        PropName::Ident(i) => Some(i.sym.clone()),
+        PropName::Str(s) => Some(s.value.clone()),
+        _ => None,
+    }
+}
+
+impl Visit for OriginalScopeCollector {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        let params = n.params.iter().collect::<Vec<_>>();
+
+        self.function_like(None, n.span, &params, |v| match &*n.body {
+            BlockStmtOrExpr::BlockStmt(body) => body.stmts.visit_with(v),
+            BlockStmtOrExpr::Expr(body) => body.visit_with(v),
+        });
+    }
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt) {
+        self.enter(ScopeKind::Block, None, n.span);
+        n.stmts.visit_with(self);
+        self.exit();
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        self.enter(ScopeKind::Block, None, n.span);
+        if let Some(param) = &n.param {
+            self.declare_pat(param, false);
+            param.visit_with(self);
+        }
+        n.body.stmts.visit_with(self);
+        self.exit();
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.declare_lexical(&n.ident);
+        n.class.visit_with(self);
+    }
+
+    fn visit_class_method(&mut self, n: &ClassMethod) {
+        n.key.visit_with(self);
+        self.function(prop_name(&n.key), &n.function);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor) {
+        let params = n
+            .params
+            .iter()
+            .filter_map(|p| match p {
+                ParamOrTsParamProp::Param(p) => Some(&p.pat),
+                ParamOrTsParamProp::TsParamProp(..) => None,
+            })
+            .collect::<Vec<_>>();
+
+        self.function_like(Some("constructor".into()), n.span, &params, |v| {
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        self.declare_lexical(&n.ident);
+        self.function(Some(n.ident.sym.clone()), &n.function);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr) {
+        self.function(n.ident.as_ref().map(|i| i.sym.clone()), &n.function);
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
+        self.enter(ScopeKind::Block, None, n.span);
+        n.visit_children_with(self);
+        self.exit();
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.enter(ScopeKind::Block, None, n.span);
+        n.visit_children_with(self);
+        self.exit();
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt) {
+        self.enter(ScopeKind::Block, None, n.span);
+        n.visit_children_with(self);
+        self.exit();
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp) {
+        n.key.visit_with(self);
+        self.function_like(prop_name(&n.key), n.span, &[], |v| {
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        for s in &n.specifiers {
+            match s {
+                ImportSpecifier::Named(s) => self.declare_lexical(&s.local),
+                ImportSpecifier::Default(s) => self.declare_lexical(&s.local),
+                ImportSpecifier::Namespace(s) => self.declare_lexical(&s.local),
+            }
+        }
+    }
+
+    fn visit_method_prop(&mut self, n: &MethodProp) {
+        n.key.visit_with(self);
+        self.function(prop_name(&n.key), &n.function);
+    }
+
+    fn visit_private_method(&mut self, n: &PrivateMethod) {
+        self.function(Some(n.key.name.clone()), &n.function);
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp) {
+        n.key.visit_with(self);
+        self.function_like(prop_name(&n.key), n.span, &[&n.param], |v| {
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl) {
+        for decl in &n.decls {
+            self.declare_pat(&decl.name, n.kind == VarDeclKind::Var);
+        }
+        n.visit_children_with(self);
+    }
+}
+
+struct GeneratedCollector {
+    names: FxHashMap<BytePos, Atom>,
+    scopes: FxHashSet<BytePos>,
+}
+
+impl Visit for GeneratedCollector {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        self.names.entry(n.span.lo).or_insert_with(|| n.sym.clone());
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp) {
+        self.scopes.insert(n.span.lo);
+        n.visit_children_with(self);
+    }
+}
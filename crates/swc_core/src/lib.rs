@@ -188,6 +188,12 @@ pub mod trace_macro {
 #[cfg_attr(docsrs, doc(cfg(feature = "transform_common")))]
 pub extern crate swc_transform_common as transform_common;
 
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod json {
+    pub use swc_json::*;
+}
+
 #[cfg(feature = "typescript")]
 #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
 pub mod typescript {
@@ -237,6 +243,12 @@ pub mod css {
         pub use swc_css_modules::*;
     }
 
+    #[cfg(feature = "css_bundler")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "css_bundler")))]
+    pub mod bundler {
+        pub use swc_css_bundler::*;
+    }
+
     #[cfg(feature = "css_codegen")]
     #[cfg_attr(docsrs, doc(cfg(feature = "css_codegen")))]
     pub mod codegen {
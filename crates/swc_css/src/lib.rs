@@ -1,4 +1,7 @@
 pub extern crate swc_css_ast as ast;
+#[cfg(feature = "swc_css_bundler")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bundler")))]
+pub extern crate swc_css_bundler as bundler;
 pub extern crate swc_css_codegen as codegen;
 #[cfg(feature = "swc_css_compat")]
 #[cfg_attr(docsrs, doc(cfg(feature = "compat")))]
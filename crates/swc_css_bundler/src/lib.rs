@@ -0,0 +1,387 @@
+//! Bundles a stylesheet by inlining the stylesheets imported with `@import`.
+//!
+//! Conditions of `@import` rules are preserved by wrapping the imported rules
+//! with `@media`, `@supports` and `@layer` rules. Imports of external urls,
+//! e.g. `https://example.com/style.css`, are kept as-is.
+//!
+//! Relative urls in imported files are rebased to the directory of the entry,
+//! so they keep pointing at the same assets.
+
+#![deny(clippy::all)]
+
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{anyhow, Context, Error};
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_common::{FileName, Span};
+use swc_css_ast::*;
+use swc_css_utils::rewrite_urls;
+
+pub use self::{
+    load::{FsLoader, Load},
+    resolve::{FsResolver, Resolve},
+};
+
+mod load;
+mod resolve;
+
+#[derive(Debug)]
+pub struct Bundle {
+    pub stylesheet: Stylesheet,
+    /// All files which were loaded to create the bundle, including the entry.
+    ///
+    /// This can be used to rebuild the bundle when one of them is changed.
+    pub dependencies: Vec<FileName>,
+}
+
+pub struct Bundler<L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    loader: L,
+    resolver: R,
+}
+
+impl<L, R> Bundler<L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    pub fn new(loader: L, resolver: R) -> Self {
+        Bundler { loader, resolver }
+    }
+
+    pub fn bundle(&self, entry: &FileName) -> Result<Bundle, Error> {
+        let mut ctx = Ctx {
+            bundler: self,
+            stack: Default::default(),
+            cache: Default::default(),
+            dependencies: Default::default(),
+            seen: Default::default(),
+            external_imports: Default::default(),
+        };
+
+        let rules = ctx.bundle_file(entry, false)?;
+
+        // `@import` rules must precede all other rules except `@charset` and
+        // `@layer` statements
+        let mut rules = rules.into_iter().peekable();
+        let mut prelude = Vec::new();
+
+        while let Some(rule) = rules
+            .next_if(|rule| matches!(rule, Rule::AtRule(at_rule) if is_at_rule(at_rule, "charset")))
+        {
+            prelude.push(rule);
+        }
+
+        prelude.extend(ctx.external_imports);
+        prelude.extend(rules);
+
+        let span = ctx
+            .cache
+            .get(entry)
+            .map(|stylesheet| stylesheet.span)
+            .unwrap_or_default();
+
+        Ok(Bundle {
+            stylesheet: Stylesheet {
+                span,
+                rules: prelude,
+            },
+            dependencies: ctx.dependencies,
+        })
+    }
+}
+
+struct Ctx<'a, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    bundler: &'a Bundler<L, R>,
+    /// Files which are being bundled, used to detect cycles.
+    stack: Vec<FileName>,
+    cache: FxHashMap<FileName, Stylesheet>,
+    dependencies: Vec<FileName>,
+    seen: FxHashSet<FileName>,
+    /// `@import` rules of external urls found in imported files, which are
+    /// hoisted to the top of the bundle.
+    external_imports: Vec<Rule>,
+}
+
+impl<L, R> Ctx<'_, L, R>
+where
+    L: Load,
+    R: Resolve,
+{
+    /// `is_conditional` is true if the file is imported with conditions.
+    fn bundle_file(&mut self, file: &FileName, is_conditional: bool) -> Result<Vec<Rule>, Error> {
+        if let Some(idx) = self.stack.iter().position(|f| f == file) {
+            let cycle = self.stack[idx..]
+                .iter()
+                .chain([file])
+                .map(|f| f.to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+
+            return Err(anyhow!("circular @import: {}", cycle));
+        }
+
+        if !self.cache.contains_key(file) {
+            let stylesheet = self
+                .bundler
+                .loader
+                .load(file)
+                .with_context(|| format!("failed to load `{}`", file))?;
+
+            self.cache.insert(file.clone(), stylesheet);
+        }
+
+        if self.seen.insert(file.clone()) {
+            self.dependencies.push(file.clone());
+        }
+
+        let is_entry = self.stack.is_empty();
+        let mut rules = self.cache[file].rules.clone();
+
+        if let Some(base) = self
+            .stack
+            .first()
+            .and_then(|entry| relative_dir(entry, file))
+        {
+            rewrite_urls(&mut rules, |reference| rebase_url(&base, &reference.url));
+        }
+
+        let mut bundled = Vec::with_capacity(rules.len());
+        // `@import` rules are ignored by browsers if they follow other rules
+        let mut allow_import = true;
+
+        self.stack.push(file.clone());
+
+        for rule in rules {
+            let at_rule = match rule {
+                Rule::AtRule(at_rule) => at_rule,
+                _ => {
+                    allow_import = false;
+                    bundled.push(rule);
+                    continue;
+                }
+            };
+
+            if is_at_rule(&at_rule, "charset") {
+                // Only the `@charset` of the entry is meaningful
+                if is_entry {
+                    bundled.push(Rule::AtRule(at_rule));
+                }
+
+                continue;
+            }
+
+            let import = match at_rule.prelude.as_deref() {
+                Some(AtRulePrelude::ImportPrelude(import))
+                    if allow_import && is_at_rule(&at_rule, "import") =>
+                {
+                    import
+                }
+                _ => {
+                    if !is_layer_statement(&at_rule) {
+                        allow_import = false;
+                    }
+
+                    bundled.push(Rule::AtRule(at_rule));
+                    continue;
+                }
+            };
+
+            let url = get_url(import).ok_or_else(|| anyhow!("invalid @import in `{}`", file))?;
+
+            if is_external(url) {
+                // Conditions of the parent `@import` can't be applied to a hoisted `@import`
+                if is_conditional {
+                    return Err(anyhow!(
+                        "cannot hoist `@import` of `{}` in `{}`, which is imported with conditions",
+                        url,
+                        file
+                    ));
+                }
+
+                self.external_imports.push(Rule::AtRule(at_rule));
+                continue;
+            }
+
+            let imported = self
+                .bundler
+                .resolver
+                .resolve(file, url)
+                .with_context(|| format!("failed to resolve `{}` from `{}`", url, file))?;
+            let has_conditions = import.layer_name.is_some() || import.import_conditions.is_some();
+            let rules = self.bundle_file(&imported, is_conditional || has_conditions)?;
+
+            bundled.extend(wrap_with_conditions(at_rule.span, import, rules));
+        }
+
+        self.stack.pop();
+
+        Ok(bundled)
+    }
+}
+
+fn is_at_rule(at_rule: &AtRule, name: &str) -> bool {
+    matches!(&at_rule.name, AtRuleName::Ident(ident) if ident.value.eq_ignore_ascii_case(name))
+}
+
+fn is_layer_statement(at_rule: &AtRule) -> bool {
+    is_at_rule(at_rule, "layer") && at_rule.block.is_none()
+}
+
+fn get_url(import: &ImportPrelude) -> Option<&str> {
+    match &*import.href {
+        ImportHref::Str(s) => Some(&s.value),
+        ImportHref::Url(url) => match url.value.as_deref()? {
+            UrlValue::Str(s) => Some(&s.value),
+            UrlValue::Raw(raw) => Some(&raw.value),
+        },
+    }
+}
+
+fn is_external(url: &str) -> bool {
+    url.starts_with("//")
+        || url
+            .split_once(':')
+            // Single letters are drive letters of Windows paths
+            .is_some_and(|(scheme, _)| scheme.len() > 1 && !scheme.contains(['/', '.']))
+}
+
+/// Returns the directory of `file`, relative to the directory of `entry`.
+///
+/// Returns `None` if the directories are the same.
+fn relative_dir(entry: &FileName, file: &FileName) -> Option<PathBuf> {
+    let (FileName::Real(entry), FileName::Real(file)) = (entry, file) else {
+        return None;
+    };
+    let from = entry.parent()?.components().collect::<Vec<_>>();
+    let to = file.parent()?.components().collect::<Vec<_>>();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let dir = from[common..]
+        .iter()
+        .map(|_| Component::ParentDir)
+        .chain(to[common..].iter().copied())
+        .collect::<PathBuf>();
+
+    (dir != Path::new("")).then_some(dir)
+}
+
+fn rebase_url(base: &Path, url: &str) -> Option<swc_atoms::Atom> {
+    // Absolute urls, fragments (e.g. `url(#filter)`) and urls with a scheme
+    // don't depend on the location of the file
+    if url.is_empty() || url.starts_with(['/', '#']) || is_external(url) {
+        return None;
+    }
+
+    let mut dirs = base
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>();
+    let mut url = url.strip_prefix("./").unwrap_or(url);
+
+    while let Some(rest) = url.strip_prefix("../") {
+        match dirs.last() {
+            Some(dir) if dir != ".." => {
+                dirs.pop();
+                url = rest;
+            }
+            _ => break,
+        }
+    }
+
+    dirs.push(url.into());
+
+    let rebased = dirs.join("/");
+
+    Some(rebased.into())
+}
+
+fn create_at_rule(
+    span: Span,
+    name: &str,
+    prelude: Option<AtRulePrelude>,
+    rules: Vec<Rule>,
+) -> Rule {
+    Rule::AtRule(Box::new(AtRule {
+        span,
+        name: AtRuleName::Ident(Ident {
+            span,
+            value: name.into(),
+            raw: None,
+        }),
+        prelude: prelude.map(Box::new),
+        block: Some(SimpleBlock {
+            span,
+            name: TokenAndSpan {
+                span,
+                token: Token::LBrace,
+            },
+            value: rules.into_iter().map(ComponentValue::from).collect(),
+        }),
+    }))
+}
+
+/// Wraps imported rules with `@layer`, `@supports` and `@media`, in that
+/// order.
+fn wrap_with_conditions(span: Span, import: &ImportPrelude, mut rules: Vec<Rule>) -> Vec<Rule> {
+    if let Some(layer_name) = &import.layer_name {
+        let prelude = match &**layer_name {
+            // `layer` creates an anonymous layer
+            ImportLayerName::Ident(..) => None,
+            ImportLayerName::Function(function) => match function.value.first() {
+                Some(ComponentValue::LayerName(name)) => Some(AtRulePrelude::LayerPrelude(
+                    LayerPrelude::Name(*name.clone()),
+                )),
+                _ => None,
+            },
+        };
+
+        rules = vec![create_at_rule(span, "layer", prelude, rules)];
+    }
+
+    let Some(conditions) = &import.import_conditions else {
+        return rules;
+    };
+
+    if let Some(supports) = &conditions.supports {
+        let condition = match supports.value.first() {
+            Some(ComponentValue::SupportsCondition(condition)) => Some(*condition.clone()),
+            Some(ComponentValue::Declaration(declaration)) => Some(SupportsCondition {
+                span: supports.span,
+                conditions: vec![SupportsConditionType::SupportsInParens(
+                    SupportsInParens::Feature(SupportsFeature::Declaration(declaration.clone())),
+                )],
+            }),
+            _ => None,
+        };
+
+        if let Some(condition) = condition {
+            rules = vec![create_at_rule(
+                span,
+                "supports",
+                Some(AtRulePrelude::SupportsPrelude(condition)),
+                rules,
+            )];
+        }
+    }
+
+    if let Some(media) = &conditions.media {
+        if !media.queries.is_empty() {
+            rules = vec![create_at_rule(
+                span,
+                "media",
+                Some(AtRulePrelude::MediaPrelude(*media.clone())),
+                rules,
+            )];
+        }
+    }
+
+    rules
+}
@@ -0,0 +1,55 @@
+use anyhow::{anyhow, Error};
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_css_ast::Stylesheet;
+use swc_css_parser::{parse_file, parser::ParserConfig};
+
+/// Responsible for providing stylesheets to the bundler.
+///
+/// The bundler calls this at most once for each file.
+pub trait Load {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error>;
+}
+
+impl<T: ?Sized + Load> Load for Box<T> {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        (**self).load(file)
+    }
+}
+
+impl<T: ?Sized + Load> Load for &T {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        (**self).load(file)
+    }
+}
+
+/// Loads stylesheets from the file system.
+///
+/// Files are added to `cm`, so the spans of the bundled stylesheet can be used
+/// to generate a source map.
+pub struct FsLoader {
+    pub cm: Lrc<SourceMap>,
+    pub config: ParserConfig,
+}
+
+impl Load for FsLoader {
+    fn load(&self, file: &FileName) -> Result<Stylesheet, Error> {
+        let path = match file {
+            FileName::Real(path) => path,
+            _ => return Err(anyhow!("cannot load `{}` from the file system", file)),
+        };
+        let fm = self
+            .cm
+            .load_file(path)
+            .map_err(|err| anyhow!("failed to load `{}`: {}", file, err))?;
+
+        let mut errors = Vec::new();
+        let stylesheet = parse_file::<Stylesheet>(&fm, None, self.config, &mut errors)
+            .map_err(|err| anyhow!("failed to parse `{}`: {}", file, err.message()))?;
+
+        if let Some(err) = errors.first() {
+            return Err(anyhow!("failed to parse `{}`: {}", file, err.message()));
+        }
+
+        Ok(stylesheet)
+    }
+}
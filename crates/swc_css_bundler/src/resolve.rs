@@ -0,0 +1,47 @@
+use anyhow::{anyhow, Error};
+use swc_common::FileName;
+
+/// Resolves the url of `@import` rules.
+pub trait Resolve {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error>;
+}
+
+impl<T: ?Sized + Resolve> Resolve for Box<T> {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        (**self).resolve(base, specifier)
+    }
+}
+
+impl<T: ?Sized + Resolve> Resolve for &T {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        (**self).resolve(base, specifier)
+    }
+}
+
+/// Resolves urls relative to the directory of the importing file.
+pub struct FsResolver;
+
+impl Resolve for FsResolver {
+    fn resolve(&self, base: &FileName, specifier: &str) -> Result<FileName, Error> {
+        let base = match base {
+            FileName::Real(path) => path,
+            _ => return Err(anyhow!("cannot resolve `{}` from `{}`", specifier, base)),
+        };
+        // Query strings and fragments are not a part of the path
+        let path = specifier.split(['?', '#']).next().unwrap_or_default();
+        let path = match base.parent() {
+            Some(dir) => dir.join(path),
+            None => path.into(),
+        };
+
+        if !path.is_file() {
+            return Err(anyhow!(
+                "cannot resolve `{}` from `{}`",
+                specifier,
+                base.display()
+            ));
+        }
+
+        Ok(FileName::Real(path))
+    }
+}
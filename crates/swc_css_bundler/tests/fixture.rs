@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use swc_common::FileName;
+use swc_css_bundler::{Bundler, FsLoader, FsResolver};
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.css")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+
+    testing::run_test2(false, |cm, _| {
+        let bundler = Bundler::new(
+            FsLoader {
+                cm: cm.clone(),
+                config: Default::default(),
+            },
+            FsResolver,
+        );
+        // Paths are relative to the fixture directory to make outputs portable
+        let relative = |file: &str| file.replace(&format!("{}/", dir.display()), "");
+
+        let bundle = match bundler.bundle(&FileName::Real(input.clone())) {
+            Ok(bundle) => bundle,
+            Err(err) => {
+                NormalizedOutput::from(relative(&format!("{:#}\n", err)))
+                    .compare_to_file(dir.join("output.stderr"))
+                    .unwrap();
+
+                return Ok(());
+            }
+        };
+
+        let mut css = String::new();
+
+        {
+            let wr = BasicCssWriter::new(&mut css, None, BasicCssWriterConfig::default());
+            let mut gen = CodeGenerator::new(wr, CodegenConfig::default());
+
+            gen.emit(&bundle.stylesheet).unwrap();
+        }
+
+        NormalizedOutput::from(css)
+            .compare_to_file(dir.join("output.css"))
+            .unwrap();
+
+        let dependencies = bundle
+            .dependencies
+            .iter()
+            .map(|file| relative(&file.to_string()) + "\n")
+            .collect::<String>();
+
+        NormalizedOutput::from(dependencies)
+            .compare_to_file(dir.join("dependencies.txt"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
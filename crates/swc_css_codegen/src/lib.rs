@@ -2431,8 +2431,7 @@ where
 
     #[emitter]
     fn emit_pseudo_element_selector(&mut self, n: &PseudoElementSelector) -> Result {
-        write_raw!(self, lo_span_offset!(n.span, 1), ":");
-        write_raw!(self, lo_span_offset!(n.span, 2), ":");
+        write_raw!(self, lo_span_offset!(n.span, 2), "::");
         emit!(
             &mut *self.with_ctx(Ctx {
                 allow_to_lowercase: true,
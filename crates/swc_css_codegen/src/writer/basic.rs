@@ -61,6 +61,11 @@ impl<'a, W> BasicCssWriter<'a, W>
 where
     W: Write,
 {
+    /// Mappings of the generated code are collected into `srcmap`, if it's
+    /// provided. They can be converted into a source map with
+    /// `SourceMap::build_source_map_from` of `swc_common`, which also composes
+    /// them with the source map of the input, e.g. one created by a
+    /// preprocessor.
     pub fn new(
         writer: W,
         srcmap: Option<&'a mut Vec<(BytePos, LineCol)>>,
@@ -104,7 +109,8 @@ where
     fn raw_write(&mut self, data: &str) -> Result {
         self.w.write_str(data)?;
         if self.srcmap.is_some() {
-            self.col += data.chars().count();
+            // Columns of source maps are counted in UTF-16 code units
+            self.col += data.encode_utf16().count();
         }
 
         Ok(())
@@ -154,7 +160,13 @@ where
                     col: self.col as _,
                 };
 
-                srcmap.push((byte_pos, loc));
+                // The start of a token is more useful than the end of the previous one,
+                // which is written at the same position when whitespaces are omitted
+                if let Some(last) = srcmap.last_mut().filter(|last| last.1 == loc) {
+                    last.0 = byte_pos;
+                } else {
+                    srcmap.push((byte_pos, loc));
+                }
             }
         }
     }
@@ -200,7 +212,12 @@ where
     fn write_str(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
             if !span.is_dummy() {
-                self.srcmap(span.lo())
+                // Avoid mapping the indentation which is written before `s`
+                if self.line_start {
+                    self.pending_srcmap = Some(span.lo());
+                } else {
+                    self.srcmap(span.lo())
+                }
             }
 
             self.write(None, s)?;
@@ -215,7 +232,7 @@ where
                 let last_line_byte_index = line_start_of_s.last().cloned().unwrap_or(0);
 
                 if self.srcmap.is_some() {
-                    self.col = s[last_line_byte_index..].chars().count();
+                    self.col = s[last_line_byte_index..].encode_utf16().count();
                 }
             }
 
@@ -0,0 +1,122 @@
+use std::{fmt::Write, fs, path::PathBuf};
+
+use sourcemap::SourceMap;
+use swc_common::{source_map::SourceMapGenConfig, FileName};
+use swc_css_ast::Stylesheet;
+use swc_css_codegen::{
+    writer::basic::{BasicCssWriter, BasicCssWriterConfig},
+    CodeGenerator, CodegenConfig, Emit,
+};
+use swc_css_parser::parse_file;
+use testing::{run_test2, NormalizedOutput};
+
+struct SourceMapConfig;
+
+impl SourceMapGenConfig for SourceMapConfig {
+    fn file_name_to_source(&self, f: &FileName) -> String {
+        match f {
+            FileName::Real(path) => path.file_name().unwrap().to_string_lossy().into_owned(),
+            _ => f.to_string(),
+        }
+    }
+
+    fn inline_sources_content(&self, _: &FileName) -> bool {
+        true
+    }
+}
+
+/// Returns up to `len` characters of `code` starting at the given line and
+/// column, where the column is counted in UTF-16 code units.
+fn snippet(code: &str, line: u32, col: u32, len: usize) -> String {
+    let Some(line) = code.lines().nth(line as usize) else {
+        return String::new();
+    };
+    let utf16 = line.encode_utf16().skip(col as usize).collect::<Vec<_>>();
+
+    String::from_utf16_lossy(&utf16).chars().take(len).collect()
+}
+
+fn print_source_map(code: &str, map: &SourceMap) -> String {
+    let mut output = String::new();
+
+    for token in map.tokens() {
+        let source = token.get_source().unwrap_or_default();
+        let source_snippet = map
+            .get_source_contents(token.get_src_id())
+            .map(|contents| snippet(contents, token.get_src_line(), token.get_src_col(), 10))
+            .unwrap_or_default();
+
+        writeln!(
+            output,
+            "{}:{} `{}` => {}:{}:{} `{}`",
+            token.get_dst_line(),
+            token.get_dst_col(),
+            snippet(code, token.get_dst_line(), token.get_dst_col(), 10),
+            source,
+            token.get_src_line(),
+            token.get_src_col(),
+            source_snippet,
+        )
+        .unwrap();
+    }
+
+    output
+}
+
+#[testing::fixture("tests/sourcemap/**/input.css")]
+fn sourcemap(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let input_source_map = dir.join("input.css.map");
+    let input_source_map = input_source_map.exists().then(|| {
+        SourceMap::from_slice(&fs::read(&input_source_map).unwrap())
+            .expect("failed to parse the input source map")
+    });
+
+    for minify in [false, true] {
+        let suffix = if minify { ".min" } else { "" };
+
+        run_test2(false, |cm, handler| {
+            let fm = cm.load_file(&input).unwrap();
+
+            let mut errors = Vec::new();
+            let stylesheet: Stylesheet = parse_file(&fm, None, Default::default(), &mut errors)
+                .map_err(|err| {
+                    err.to_diagnostics(&handler).emit();
+                })?;
+
+            for err in errors {
+                err.to_diagnostics(&handler).emit();
+            }
+
+            let mut css_str = String::new();
+            let mut src_map_buf = Vec::new();
+
+            {
+                let wr = BasicCssWriter::new(
+                    &mut css_str,
+                    Some(&mut src_map_buf),
+                    BasicCssWriterConfig::default(),
+                );
+                let mut gen = CodeGenerator::new(wr, CodegenConfig { minify });
+
+                gen.emit(&stylesheet).unwrap();
+            }
+
+            let source_map = cm.build_source_map_with_config(
+                &src_map_buf,
+                input_source_map.as_ref(),
+                SourceMapConfig,
+            );
+
+            NormalizedOutput::from(css_str.clone())
+                .compare_to_file(dir.join(format!("output{}.css", suffix)))
+                .unwrap();
+            NormalizedOutput::from(print_source_map(&css_str, &source_map))
+                .compare_to_file(dir.join(format!("output{}.mappings", suffix)))
+                .unwrap();
+
+            Ok(())
+        })
+        .unwrap();
+    }
+}
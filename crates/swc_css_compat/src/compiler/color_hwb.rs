@@ -7,7 +7,7 @@ use swc_css_utils::{angle_to_deg, hwb_to_rgb, to_rgb255};
 use crate::compiler::Compiler;
 
 impl Compiler {
-    fn get_hue(&self, hue: Option<&ComponentValue>) -> Option<f64> {
+    pub(crate) fn get_hue(&self, hue: Option<&ComponentValue>) -> Option<f64> {
         match hue {
             Some(ComponentValue::Hue(hue)) => {
                 let mut value = match &**hue {
@@ -34,7 +34,7 @@ impl Compiler {
         }
     }
 
-    fn get_percentage(&self, percentage: Option<&ComponentValue>) -> Option<f64> {
+    pub(crate) fn get_percentage(&self, percentage: Option<&ComponentValue>) -> Option<f64> {
         match percentage {
             Some(ComponentValue::Percentage(percentage)) => {
                 let Number { value, .. } = &percentage.value;
@@ -53,7 +53,7 @@ impl Compiler {
         }
     }
 
-    fn get_alpha_value(&self, alpha_value: Option<&ComponentValue>) -> Option<f64> {
+    pub(crate) fn get_alpha_value(&self, alpha_value: Option<&ComponentValue>) -> Option<f64> {
         let Some(alpha_value) = alpha_value else {
             return Some(1.0);
         };
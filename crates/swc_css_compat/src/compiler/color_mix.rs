@@ -0,0 +1,301 @@
+use swc_css_ast::{
+    AbsoluteColorBase, Color, ComponentValue, DelimiterValue, Function, HexColor, Ident,
+};
+use swc_css_utils::{hsl_to_rgb, hwb_to_rgb, NAMED_COLORS};
+
+use crate::compiler::{
+    color_oklab::{
+        linear_srgb_to_oklab, linear_to_srgb, oklab_to_linear_srgb, oklab_to_oklch, oklch_to_oklab,
+        srgb_to_linear, to_rgb_function,
+    },
+    Compiler,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum ColorSpace {
+    Srgb,
+    SrgbLinear,
+    Oklab,
+    Oklch,
+}
+
+#[derive(Clone, Copy)]
+enum HueInterpolationMethod {
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl ColorSpace {
+    fn convert_from_srgb(self, rgb: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::Srgb => rgb,
+            ColorSpace::SrgbLinear => rgb.map(srgb_to_linear),
+            ColorSpace::Oklab => linear_srgb_to_oklab(rgb.map(srgb_to_linear)),
+            ColorSpace::Oklch => oklab_to_oklch(linear_srgb_to_oklab(rgb.map(srgb_to_linear))),
+        }
+    }
+
+    fn convert_to_srgb(self, color: [f64; 3]) -> [f64; 3] {
+        match self {
+            ColorSpace::Srgb => color,
+            ColorSpace::SrgbLinear => color.map(linear_to_srgb),
+            ColorSpace::Oklab => oklab_to_linear_srgb(color).map(linear_to_srgb),
+            ColorSpace::Oklch => oklab_to_linear_srgb(oklch_to_oklab(color)).map(linear_to_srgb),
+        }
+    }
+}
+
+fn parse_hex(value: &str) -> Option<([f64; 3], f64)> {
+    let channel = |i: usize, len: usize| {
+        let value = u8::from_str_radix(value.get(i * len..(i + 1) * len)?, 16).ok()?;
+        let value = if len == 1 { value * 17 } else { value };
+
+        Some(value as f64 / 255.0)
+    };
+    let len = match value.len() {
+        3 | 4 => 1,
+        6 | 8 => 2,
+        _ => return None,
+    };
+    let alpha = if value.len() % 3 == 0 {
+        1.0
+    } else {
+        channel(3, len)?
+    };
+
+    Some((
+        [channel(0, len)?, channel(1, len)?, channel(2, len)?],
+        alpha,
+    ))
+}
+
+fn get_named_color(ident: &Ident) -> Option<([f64; 3], f64)> {
+    let name = ident.value.to_ascii_lowercase();
+
+    if name == "transparent" {
+        return Some(([0.0; 3], 0.0));
+    }
+
+    let color = NAMED_COLORS.get(&name)?;
+
+    Some((
+        [
+            color.rgb[0] as f64 / 255.0,
+            color.rgb[1] as f64 / 255.0,
+            color.rgb[2] as f64 / 255.0,
+        ],
+        1.0,
+    ))
+}
+
+// Interpolates hues in degrees, see https://drafts.csswg.org/css-color-4/#hue-interpolation
+fn fix_hues(h1: &mut f64, h2: &mut f64, method: HueInterpolationMethod) {
+    let diff = *h2 - *h1;
+
+    match method {
+        HueInterpolationMethod::Shorter if diff > 180.0 => *h1 += 360.0,
+        HueInterpolationMethod::Shorter if diff < -180.0 => *h2 += 360.0,
+        HueInterpolationMethod::Longer if 0.0 < diff && diff < 180.0 => *h1 += 360.0,
+        HueInterpolationMethod::Longer if -180.0 < diff && diff <= 0.0 => *h2 += 360.0,
+        HueInterpolationMethod::Increasing if diff < 0.0 => *h2 += 360.0,
+        HueInterpolationMethod::Decreasing if diff > 0.0 => *h1 += 360.0,
+        _ => {}
+    }
+}
+
+impl Compiler {
+    /// Returns the color in the sRGB color space, and its alpha.
+    fn get_srgb_color(&self, value: &ComponentValue) -> Option<([f64; 3], f64)> {
+        let color = match value {
+            ComponentValue::Color(color) => match &**color {
+                Color::AbsoluteColorBase(color) => color,
+                _ => return None,
+            },
+            ComponentValue::Ident(ident) => return get_named_color(ident),
+            _ => return None,
+        };
+
+        match color {
+            AbsoluteColorBase::HexColor(HexColor { value, .. }) => parse_hex(value),
+            AbsoluteColorBase::NamedColorOrTransparent(ident) => get_named_color(ident),
+            AbsoluteColorBase::Function(function) => {
+                let args = function
+                    .value
+                    .iter()
+                    .filter(|n| {
+                        !matches!(n, ComponentValue::Delimiter(delimiter) if matches!(delimiter.value, DelimiterValue::Comma | DelimiterValue::Solidus))
+                    })
+                    .collect::<Vec<_>>();
+                let alpha = self.get_alpha_value(args.get(3).copied())?;
+
+                if function.name == "rgb" || function.name == "rgba" {
+                    let mut rgb = [0.0; 3];
+
+                    for (i, channel) in rgb.iter_mut().enumerate() {
+                        *channel = match args.get(i) {
+                            Some(ComponentValue::Number(number)) => number.value / 255.0,
+                            Some(ComponentValue::Integer(integer)) => integer.value as f64 / 255.0,
+                            value => self.get_percentage(value.copied())?,
+                        };
+                    }
+
+                    Some((rgb, alpha))
+                } else if function.name == "hsl" || function.name == "hsla" {
+                    let h = self.get_hue(args.first().copied())?;
+                    let s = self.get_percentage(args.get(1).copied())?;
+                    let l = self.get_percentage(args.get(2).copied())?;
+
+                    Some((hsl_to_rgb([h, s, l]), alpha))
+                } else if function.name == "hwb" {
+                    let h = self.get_hue(args.first().copied())?;
+                    let w = self.get_percentage(args.get(1).copied())?;
+                    let b = self.get_percentage(args.get(2).copied())?;
+
+                    Some((hwb_to_rgb([h, w, b]), alpha))
+                } else {
+                    self.get_oklab_color(function)
+                }
+            }
+        }
+    }
+
+    /// Parses `<color> <percentage>?` or `<percentage> <color>`.
+    fn get_mix_component(
+        &self,
+        values: &[ComponentValue],
+    ) -> Option<(([f64; 3], f64), Option<f64>)> {
+        let (color, percentage) = match values {
+            [color] => (color, None),
+            [color, ComponentValue::Percentage(percentage)]
+            | [ComponentValue::Percentage(percentage), color] => {
+                (color, Some(percentage.value.value))
+            }
+            _ => return None,
+        };
+
+        if percentage.is_some_and(|percentage| !(0.0..=100.0).contains(&percentage)) {
+            return None;
+        }
+
+        Some((self.get_srgb_color(color)?, percentage))
+    }
+
+    // https://drafts.csswg.org/css-color-5/#color-mix
+    pub(crate) fn process_color_mix(&mut self, n: &mut AbsoluteColorBase) {
+        let AbsoluteColorBase::Function(Function { name, value, .. }) = n else {
+            return;
+        };
+
+        if name != "color-mix" {
+            return;
+        }
+
+        let args = value
+            .split(|n| {
+                matches!(n, ComponentValue::Delimiter(delimiter) if matches!(delimiter.value, DelimiterValue::Comma))
+            })
+            .collect::<Vec<_>>();
+        let [interpolation, first, second] = &*args else {
+            return;
+        };
+
+        let idents = interpolation
+            .iter()
+            .map(|n| match n {
+                ComponentValue::Ident(ident) => Some(ident.value.to_ascii_lowercase()),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>();
+        let Some(idents) = idents else {
+            return;
+        };
+        let idents = idents.iter().map(|ident| &**ident).collect::<Vec<_>>();
+
+        let (space, method) = match &*idents {
+            ["in", space] | ["in", space, _, "hue"] => {
+                let space = match *space {
+                    "srgb" => ColorSpace::Srgb,
+                    "srgb-linear" => ColorSpace::SrgbLinear,
+                    "oklab" => ColorSpace::Oklab,
+                    "oklch" => ColorSpace::Oklch,
+                    _ => return,
+                };
+                let method = match idents.get(2) {
+                    None => HueInterpolationMethod::Shorter,
+                    Some(_) if space != ColorSpace::Oklch => return,
+                    Some(&"shorter") => HueInterpolationMethod::Shorter,
+                    Some(&"longer") => HueInterpolationMethod::Longer,
+                    Some(&"increasing") => HueInterpolationMethod::Increasing,
+                    Some(&"decreasing") => HueInterpolationMethod::Decreasing,
+                    Some(_) => return,
+                };
+
+                (space, method)
+            }
+            _ => return,
+        };
+
+        let Some(((rgb1, alpha1), p1)) = self.get_mix_component(first) else {
+            return;
+        };
+        let Some(((rgb2, alpha2), p2)) = self.get_mix_component(second) else {
+            return;
+        };
+
+        let (p1, p2) = match (p1, p2) {
+            (None, None) => (50.0, 50.0),
+            (Some(p1), None) => (p1, 100.0 - p1),
+            (None, Some(p2)) => (100.0 - p2, p2),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+        let sum = p1 + p2;
+
+        if sum == 0.0 {
+            return;
+        }
+
+        let (p1, p2) = (p1 / sum, p2 / sum);
+        let alpha_multiplier = if sum < 100.0 { sum / 100.0 } else { 1.0 };
+
+        let mut c1 = space.convert_from_srgb(rgb1);
+        let mut c2 = space.convert_from_srgb(rgb2);
+
+        // Hues of achromatic colors are powerless
+        if space == ColorSpace::Oklch {
+            if c1[1] < 1e-6 {
+                c1[2] = c2[2];
+            } else if c2[1] < 1e-6 {
+                c2[2] = c1[2];
+            }
+
+            let (mut h1, mut h2) = (c1[2], c2[2]);
+
+            fix_hues(&mut h1, &mut h2, method);
+
+            c1[2] = h1;
+            c2[2] = h2;
+        }
+
+        // Interpolate with premultiplied alpha
+        let alpha = alpha1 * p1 + alpha2 * p2;
+        let mut mixed = [0.0; 3];
+
+        for (i, channel) in mixed.iter_mut().enumerate() {
+            let is_hue = space == ColorSpace::Oklch && i == 2;
+
+            *channel = if is_hue || alpha == 0.0 {
+                c1[i] * p1 + c2[i] * p2
+            } else {
+                (c1[i] * alpha1 * p1 + c2[i] * alpha2 * p2) / alpha
+            };
+        }
+
+        if space == ColorSpace::Oklch {
+            mixed[2] %= 360.0;
+        }
+
+        *n = to_rgb_function(space.convert_to_srgb(mixed), alpha * alpha_multiplier);
+    }
+}
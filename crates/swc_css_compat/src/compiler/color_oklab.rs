@@ -0,0 +1,171 @@
+use std::f64::consts::PI;
+
+use swc_common::DUMMY_SP;
+use swc_css_ast::{
+    AbsoluteColorBase, AlphaValue, ComponentValue, Delimiter, DelimiterValue, Function,
+    FunctionName, Ident, Number,
+};
+
+use crate::compiler::Compiler;
+
+// https://bottosson.github.io/posts/oklab/
+pub(crate) fn oklab_to_linear_srgb([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+
+    [
+        4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+        -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+        -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+    ]
+}
+
+pub(crate) fn linear_srgb_to_oklab([r, g, b]: [f64; 3]) -> [f64; 3] {
+    let l_ = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m_ = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s_ = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+
+    [
+        0.2104542553 * l_ + 0.7936177850 * m_ - 0.0040720468 * s_,
+        1.9779984951 * l_ - 2.4285922050 * m_ + 0.4505937099 * s_,
+        0.0259040371 * l_ + 0.7827717662 * m_ - 0.8086757660 * s_,
+    ]
+}
+
+pub(crate) fn oklch_to_oklab([l, c, h]: [f64; 3]) -> [f64; 3] {
+    let h = h * PI / 180.0;
+
+    [l, c * h.cos(), c * h.sin()]
+}
+
+pub(crate) fn oklab_to_oklch([l, a, b]: [f64; 3]) -> [f64; 3] {
+    let mut h = b.atan2(a) * 180.0 / PI;
+
+    if h < 0.0 {
+        h += 360.0;
+    }
+
+    [l, (a * a + b * b).sqrt(), h]
+}
+
+pub(crate) fn srgb_to_linear(value: f64) -> f64 {
+    if value.abs() <= 0.04045 {
+        value / 12.92
+    } else {
+        value.signum() * ((value.abs() + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+pub(crate) fn linear_to_srgb(value: f64) -> f64 {
+    if value.abs() <= 0.0031308 {
+        value * 12.92
+    } else {
+        value.signum() * (1.055 * value.abs().powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+/// Creates `rgb()` or `rgba()` from sRGB channels in the `0..=1` range.
+///
+/// Colors which are out of the sRGB gamut are clamped.
+pub(crate) fn to_rgb_function(rgb: [f64; 3], alpha: f64) -> AbsoluteColorBase {
+    let mut value = Vec::with_capacity(7);
+
+    for channel in rgb {
+        if !value.is_empty() {
+            value.push(ComponentValue::Delimiter(Box::new(Delimiter {
+                span: DUMMY_SP,
+                value: DelimiterValue::Comma,
+            })));
+        }
+
+        value.push(ComponentValue::Number(Box::new(Number {
+            span: DUMMY_SP,
+            value: (channel.clamp(0.0, 1.0) * 255.0).round(),
+            raw: None,
+        })));
+    }
+
+    let alpha = alpha.clamp(0.0, 1.0);
+
+    if alpha != 1.0 {
+        value.push(ComponentValue::Delimiter(Box::new(Delimiter {
+            span: DUMMY_SP,
+            value: DelimiterValue::Comma,
+        })));
+        value.push(ComponentValue::AlphaValue(Box::new(AlphaValue::Number(
+            Number {
+                span: DUMMY_SP,
+                value: (alpha * 1000.0).round() / 1000.0,
+                raw: None,
+            },
+        ))));
+    }
+
+    AbsoluteColorBase::Function(Function {
+        span: DUMMY_SP,
+        name: FunctionName::Ident(Ident {
+            span: DUMMY_SP,
+            value: if alpha == 1.0 { "rgb" } else { "rgba" }.into(),
+            raw: None,
+        }),
+        value,
+    })
+}
+
+impl Compiler {
+    /// `reference` is the value of `100%`.
+    fn get_number_or_percentage(
+        &self,
+        value: Option<&ComponentValue>,
+        reference: f64,
+    ) -> Option<f64> {
+        match value {
+            Some(ComponentValue::Number(number)) => Some(number.value),
+            Some(ComponentValue::Integer(integer)) => Some(integer.value as f64),
+            Some(ComponentValue::Percentage(percentage)) => {
+                Some(percentage.value.value / 100.0 * reference)
+            }
+            Some(ComponentValue::Ident(ident)) if ident.value.eq_ignore_ascii_case("none") => {
+                Some(0.0)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the color of `oklab()` or `oklch()` in the sRGB color space,
+    /// and its alpha.
+    pub(crate) fn get_oklab_color(&self, function: &Function) -> Option<([f64; 3], f64)> {
+        let is_oklch = if function.name == "oklch" {
+            true
+        } else if function.name == "oklab" {
+            false
+        } else {
+            return None;
+        };
+
+        let l = self.get_number_or_percentage(function.value.first(), 1.0)?;
+        let oklab = if is_oklch {
+            let c = self.get_number_or_percentage(function.value.get(1), 0.4)?;
+            let h = self.get_hue(function.value.get(2))?;
+
+            oklch_to_oklab([l, c, h])
+        } else {
+            let a = self.get_number_or_percentage(function.value.get(1), 0.4)?;
+            let b = self.get_number_or_percentage(function.value.get(2), 0.4)?;
+
+            [l, a, b]
+        };
+        let alpha = self.get_alpha_value(function.value.get(4))?;
+
+        Some((oklab_to_linear_srgb(oklab).map(linear_to_srgb), alpha))
+    }
+
+    pub(crate) fn process_color_oklab(&mut self, n: &mut AbsoluteColorBase) {
+        if let AbsoluteColorBase::Function(function) = n {
+            if let Some((rgb, alpha)) = self.get_oklab_color(function) {
+                *n = to_rgb_function(rgb, alpha);
+            }
+        }
+    }
+}
@@ -0,0 +1,423 @@
+use std::collections::{HashMap, HashSet};
+
+use swc_atoms::Atom;
+use swc_common::DUMMY_SP;
+use swc_css_ast::{
+    ComplexSelectorChildren, ComponentValue, Declaration, DeclarationName, DelimiterValue,
+    Function, PseudoClassSelector, QualifiedRule, QualifiedRulePrelude, Rule, Stylesheet,
+    SubclassSelector, Token, TokenAndSpan,
+};
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
+
+use crate::compiler::Compiler;
+
+fn is_root_rule(rule: &QualifiedRule) -> bool {
+    let QualifiedRulePrelude::SelectorList(selector_list) = &rule.prelude else {
+        return false;
+    };
+
+    let [complex] = &*selector_list.children else {
+        return false;
+    };
+    let [ComplexSelectorChildren::CompoundSelector(compound)] = &*complex.children else {
+        return false;
+    };
+
+    compound.nesting_selector.is_none()
+        && compound.type_selector.is_none()
+        && matches!(
+            &*compound.subclass_selectors,
+            [SubclassSelector::PseudoClass(PseudoClassSelector {
+                name,
+                children: None,
+                ..
+            })] if name.value.eq_ignore_ascii_case("root")
+        )
+}
+
+fn get_custom_property_name(declaration: &Declaration) -> Option<&Atom> {
+    match &declaration.name {
+        DeclarationName::DashedIdent(name) => Some(&name.value),
+        DeclarationName::Ident(..) => None,
+    }
+}
+
+fn is_var_function(function: &Function) -> bool {
+    function.name == "var"
+}
+
+fn is_whitespace(value: &ComponentValue) -> bool {
+    matches!(
+        value,
+        ComponentValue::PreservedToken(token) if matches!(token.token, Token::WhiteSpace { .. })
+    )
+}
+
+fn is_comma(value: &ComponentValue) -> bool {
+    match value {
+        ComponentValue::PreservedToken(token) => matches!(token.token, Token::Comma),
+        ComponentValue::Delimiter(delimiter) => delimiter.value == DelimiterValue::Comma,
+        _ => false,
+    }
+}
+
+fn trim_whitespace(values: &[ComponentValue]) -> &[ComponentValue] {
+    let start = values
+        .iter()
+        .position(|value| !is_whitespace(value))
+        .unwrap_or(values.len());
+    let end = values
+        .iter()
+        .rposition(|value| !is_whitespace(value))
+        .map_or(start, |end| end + 1);
+
+    &values[start..end]
+}
+
+fn whitespace() -> ComponentValue {
+    ComponentValue::PreservedToken(Box::new(TokenAndSpan {
+        span: DUMMY_SP,
+        token: Token::WhiteSpace { value: " ".into() },
+    }))
+}
+
+/// Returns the name of the custom property referenced by `var()` without the
+/// leading `--`, and the fallback value.
+///
+/// Arguments of `var()` are tokens in custom properties, and parsed values in
+/// other declarations.
+fn parse_var_arguments(function: &Function) -> Option<(Atom, Option<&[ComponentValue]>)> {
+    let args = trim_whitespace(&function.value);
+    let (name, rest) = args.split_first()?;
+    let name = match name {
+        ComponentValue::DashedIdent(name) => name.value.clone(),
+        ComponentValue::PreservedToken(token) => match &token.token {
+            Token::Ident { value, .. } => value.strip_prefix("--")?.into(),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    match trim_whitespace(rest).split_first() {
+        None => Some((name, None)),
+        Some((comma, fallback)) if is_comma(comma) => Some((name, Some(trim_whitespace(fallback)))),
+        Some(..) => None,
+    }
+}
+
+/// Custom properties of the stylesheet.
+#[derive(Default)]
+struct CustomProperties {
+    /// Custom properties which are only declared in top-level `:root` rules.
+    definitions: HashMap<Atom, Vec<ComponentValue>>,
+    /// Custom properties which are declared in other rules, so their value
+    /// depends on the element.
+    dynamic: HashSet<Atom>,
+    /// Resolved values of `definitions`, `None` if it can't be resolved.
+    resolved: HashMap<Atom, Option<Vec<ComponentValue>>>,
+    /// Custom properties which are being resolved, used to detect cycles.
+    stack: Vec<Atom>,
+}
+
+impl CustomProperties {
+    fn resolve(&mut self, name: &Atom) -> Option<Vec<ComponentValue>> {
+        if let Some(resolved) = self.resolved.get(name) {
+            return resolved.clone();
+        }
+
+        if self.stack.contains(name) {
+            return None;
+        }
+
+        let value = self.definitions.get(name)?.clone();
+
+        self.stack.push(name.clone());
+
+        let resolved = self
+            .substitute(trim_whitespace(&value))
+            .filter(|value| !value.is_empty());
+
+        self.stack.pop();
+        self.resolved.insert(name.clone(), resolved.clone());
+
+        resolved
+    }
+
+    /// Returns the value of `var(--name, fallback)`, or `None` if the value is
+    /// not static.
+    fn resolve_var(
+        &mut self,
+        name: &Atom,
+        fallback: Option<&[ComponentValue]>,
+    ) -> Option<Vec<ComponentValue>> {
+        if self.dynamic.contains(name) {
+            return None;
+        }
+
+        if self.definitions.contains_key(name) {
+            return self.resolve(name);
+        }
+
+        // The fallback is used if the custom property is not defined
+        self.substitute(fallback?)
+    }
+
+    /// Substitutes all `var()` in the tokens of a custom property, or returns
+    /// `None` if one of them can't be resolved.
+    fn substitute(&mut self, values: &[ComponentValue]) -> Option<Vec<ComponentValue>> {
+        let mut substituted = Vec::with_capacity(values.len());
+
+        for value in values {
+            match value {
+                ComponentValue::Function(function) if is_var_function(function) => {
+                    let (name, fallback) = parse_var_arguments(function)?;
+
+                    substituted.extend(self.resolve_var(&name, fallback)?);
+                }
+                ComponentValue::Function(function) => {
+                    let mut function = function.clone();
+
+                    function.value = self.substitute(&function.value)?;
+                    substituted.push(ComponentValue::Function(function));
+                }
+                ComponentValue::SimpleBlock(block) => {
+                    let mut block = block.clone();
+
+                    block.value = self.substitute(&block.value)?;
+                    substituted.push(ComponentValue::SimpleBlock(block));
+                }
+                _ => substituted.push(value.clone()),
+            }
+        }
+
+        Some(substituted)
+    }
+}
+
+/// Collects custom properties which are declared outside of top-level `:root`
+/// rules.
+struct DynamicCollector<'a> {
+    dynamic: &'a mut HashSet<Atom>,
+}
+
+impl Visit for DynamicCollector<'_> {
+    fn visit_declaration(&mut self, n: &Declaration) {
+        if let Some(name) = get_custom_property_name(n) {
+            self.dynamic.insert(name.clone());
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Collects custom properties which are referenced by `var()`.
+#[derive(Default)]
+struct ReferenceCollector {
+    references: HashSet<Atom>,
+}
+
+impl Visit for ReferenceCollector {
+    fn visit_function(&mut self, n: &Function) {
+        if is_var_function(n) {
+            if let Some((name, _)) = parse_var_arguments(n) {
+                self.references.insert(name);
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+struct Substitutor<'a> {
+    custom_properties: &'a mut CustomProperties,
+    in_declaration: bool,
+}
+
+impl Substitutor<'_> {
+    /// Returns the substitution of `var()` in a declaration value.
+    fn substitute_var(&mut self, function: &Function) -> Option<Vec<ComponentValue>> {
+        let (name, fallback) = parse_var_arguments(function)?;
+
+        if self.custom_properties.dynamic.contains(&name) {
+            return None;
+        }
+
+        if self.custom_properties.definitions.contains_key(&name) {
+            return self.custom_properties.resolve(&name);
+        }
+
+        // Unlike the value of a custom property, the fallback is parsed
+        let mut fallback = fallback?.to_vec();
+
+        if fallback.is_empty() {
+            return None;
+        }
+
+        self.visit_mut_component_values(&mut fallback);
+
+        Some(fallback)
+    }
+}
+
+impl VisitMut for Substitutor<'_> {
+    fn visit_mut_declaration(&mut self, n: &mut Declaration) {
+        let old_in_declaration = self.in_declaration;
+
+        self.in_declaration = get_custom_property_name(n).is_none();
+
+        n.visit_mut_children_with(self);
+
+        self.in_declaration = old_in_declaration;
+    }
+
+    fn visit_mut_component_values(&mut self, n: &mut Vec<ComponentValue>) {
+        n.visit_mut_children_with(self);
+
+        if !self.in_declaration {
+            return;
+        }
+
+        let has_var = n.iter().any(|value| {
+            matches!(value, ComponentValue::Function(function) if is_var_function(function))
+        });
+
+        if !has_var {
+            return;
+        }
+
+        let values = std::mem::take(n);
+        let len = values.len();
+
+        for (idx, value) in values.into_iter().enumerate() {
+            let substitution = match &value {
+                ComponentValue::Function(function) if is_var_function(function) => {
+                    self.substitute_var(function)
+                }
+                _ => None,
+            };
+
+            let Some(substitution) = substitution else {
+                n.push(value);
+                continue;
+            };
+
+            // Tokens are printed as-is, so they need to be separated from
+            // other values explicitly
+            let is_tokens = substitution
+                .iter()
+                .any(|value| matches!(value, ComponentValue::PreservedToken(..)));
+
+            if is_tokens && n.last().is_some_and(|last| !is_whitespace(last)) {
+                n.push(whitespace());
+            }
+
+            n.extend(substitution);
+
+            if is_tokens && idx != len - 1 {
+                n.push(whitespace());
+            }
+        }
+
+        // A comma is never preceded by whitespace
+        let mut idx = 1;
+
+        while idx < n.len() {
+            if is_comma(&n[idx]) && is_whitespace(&n[idx - 1]) {
+                n.remove(idx - 1);
+            } else {
+                idx += 1;
+            }
+        }
+    }
+}
+
+impl Compiler {
+    /// Replaces `var()` with the value of the custom property, if it is only
+    /// declared in top-level `:root` rules, or with the fallback if the custom
+    /// property is never declared.
+    ///
+    /// Declarations of custom properties in `:root` are removed if they are
+    /// not referenced anymore, so this assumes that custom properties are not
+    /// set with inline styles or scripts.
+    pub(crate) fn process_custom_properties(&mut self, n: &mut Stylesheet) {
+        let mut custom_properties = CustomProperties::default();
+
+        for rule in &n.rules {
+            match rule {
+                Rule::QualifiedRule(rule) if is_root_rule(rule) => {
+                    for value in &rule.block.value {
+                        match value {
+                            ComponentValue::Declaration(declaration) => {
+                                if let Some(name) = get_custom_property_name(declaration) {
+                                    custom_properties
+                                        .definitions
+                                        .insert(name.clone(), declaration.value.clone());
+                                }
+                            }
+                            // Nested rules
+                            _ => value.visit_with(&mut DynamicCollector {
+                                dynamic: &mut custom_properties.dynamic,
+                            }),
+                        }
+                    }
+                }
+                _ => rule.visit_with(&mut DynamicCollector {
+                    dynamic: &mut custom_properties.dynamic,
+                }),
+            }
+        }
+
+        n.visit_mut_with(&mut Substitutor {
+            custom_properties: &mut custom_properties,
+            in_declaration: false,
+        });
+
+        // Custom properties referenced by other rules are kept, with the custom
+        // properties which they reference
+        let mut references = ReferenceCollector::default();
+
+        for rule in &n.rules {
+            match rule {
+                Rule::QualifiedRule(rule) if is_root_rule(rule) => {}
+                _ => rule.visit_with(&mut references),
+            }
+        }
+
+        let mut queue = references.references.iter().cloned().collect::<Vec<_>>();
+
+        while let Some(name) = queue.pop() {
+            if let Some(value) = custom_properties.definitions.get(&name) {
+                let mut collector = ReferenceCollector::default();
+
+                value.visit_with(&mut collector);
+
+                for name in collector.references {
+                    if references.references.insert(name.clone()) {
+                        queue.push(name);
+                    }
+                }
+            }
+        }
+
+        n.rules.retain_mut(|rule| {
+            let Rule::QualifiedRule(rule) = rule else {
+                return true;
+            };
+
+            if !is_root_rule(rule) {
+                return true;
+            }
+
+            rule.block.value.retain(|value| match value {
+                ComponentValue::Declaration(declaration) => get_custom_property_name(declaration)
+                    .map_or(true, |name| {
+                        custom_properties.dynamic.contains(name)
+                            || references.references.contains(name)
+                    }),
+                _ => true,
+            });
+
+            !rule.block.value.is_empty()
+        });
+    }
+}
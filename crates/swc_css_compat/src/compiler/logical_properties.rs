@@ -0,0 +1,190 @@
+use swc_common::{util::take::Take, Spanned};
+use swc_css_ast::{ComponentValue, Declaration, DeclarationName, Function, Ident, SimpleBlock};
+use swc_css_visit::{Visit, VisitWith};
+
+use crate::compiler::Compiler;
+
+// Logical properties are resolved for the horizontal writing mode with the
+// left-to-right direction, which is the default of browsers
+fn get_physical_side(axis: &str, side: &str) -> Option<&'static str> {
+    Some(match (axis, side) {
+        ("block", "start") => "top",
+        ("block", "end") => "bottom",
+        ("inline", "start") => "left",
+        ("inline", "end") => "right",
+        _ => return None,
+    })
+}
+
+/// Returns the physical properties of a logical property, and whether the
+/// value must be split between them, i.e. `margin-block: 1px 2px`.
+fn get_physical_properties(name: &str) -> Option<(Vec<String>, bool)> {
+    let renamed = match name {
+        "block-size" => Some("height"),
+        "inline-size" => Some("width"),
+        "min-block-size" => Some("min-height"),
+        "min-inline-size" => Some("min-width"),
+        "max-block-size" => Some("max-height"),
+        "max-inline-size" => Some("max-width"),
+        "border-start-start-radius" => Some("border-top-left-radius"),
+        "border-start-end-radius" => Some("border-top-right-radius"),
+        "border-end-start-radius" => Some("border-bottom-left-radius"),
+        "border-end-end-radius" => Some("border-bottom-right-radius"),
+        _ => None,
+    };
+
+    if let Some(renamed) = renamed {
+        return Some((vec![renamed.to_string()], false));
+    }
+
+    if name == "inset" {
+        return Some((
+            ["top", "right", "bottom", "left"].map(String::from).into(),
+            true,
+        ));
+    }
+
+    let (prefix, rest) = name.split_once('-')?;
+
+    if !matches!(prefix, "margin" | "padding" | "inset" | "border") {
+        return None;
+    }
+
+    let (axis, rest) = rest.split_once('-').unwrap_or((rest, ""));
+
+    if !matches!(axis, "block" | "inline") {
+        return None;
+    }
+
+    let (side, suffix) = match rest.split_once('-') {
+        Some((side, suffix)) if matches!(side, "start" | "end") => (Some(side), suffix),
+        None if matches!(rest, "start" | "end") => (Some(rest), ""),
+        _ => (None, rest),
+    };
+
+    match suffix {
+        "" => {}
+        "width" | "style" | "color" if prefix == "border" => {}
+        _ => return None,
+    }
+
+    let to_name = |side: &str| match (prefix, suffix) {
+        ("inset", _) => side.to_string(),
+        (_, "") => format!("{}-{}", prefix, side),
+        _ => format!("{}-{}-{}", prefix, side, suffix),
+    };
+
+    match side {
+        Some(side) => Some((vec![to_name(get_physical_side(axis, side)?)], false)),
+        None => Some((
+            vec![
+                to_name(get_physical_side(axis, "start")?),
+                to_name(get_physical_side(axis, "end")?),
+            ],
+            // `border-block: 1px solid` sets the same border for both sides
+            !(prefix == "border" && suffix.is_empty()),
+        )),
+    }
+}
+
+#[derive(Default)]
+struct VariableChecker {
+    found: bool,
+}
+
+impl Visit for VariableChecker {
+    fn visit_function(&mut self, n: &Function) {
+        if n.name == "var" || n.name == "env" {
+            self.found = true;
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+impl Compiler {
+    fn get_physical_declarations(&self, declaration: &Declaration) -> Option<Vec<Declaration>> {
+        let DeclarationName::Ident(Ident { value: name, .. }) = &declaration.name else {
+            return None;
+        };
+        let (names, split) = get_physical_properties(&name.to_ascii_lowercase())?;
+
+        let values = if split {
+            let mut checker = VariableChecker::default();
+
+            declaration.value.visit_with(&mut checker);
+
+            // We don't know how many values a variable contains
+            if checker.found || declaration.value.is_empty() {
+                return None;
+            }
+
+            let value = &declaration.value;
+
+            match (names.len(), value.len()) {
+                (2, 1..=2) => vec![
+                    vec![value[0].clone()],
+                    vec![value.get(1).unwrap_or(&value[0]).clone()],
+                ],
+                (4, 1..=4) => vec![
+                    vec![value[0].clone()],
+                    vec![value.get(1).unwrap_or(&value[0]).clone()],
+                    vec![value.get(2).unwrap_or(&value[0]).clone()],
+                    vec![value
+                        .get(3)
+                        .or_else(|| value.get(1))
+                        .unwrap_or(&value[0])
+                        .clone()],
+                ],
+                _ => return None,
+            }
+        } else {
+            vec![declaration.value.clone(); names.len()]
+        };
+
+        Some(
+            names
+                .into_iter()
+                .zip(values)
+                .map(|(name, value)| Declaration {
+                    span: declaration.span,
+                    name: DeclarationName::Ident(Ident {
+                        span: declaration.name.span(),
+                        value: name.into(),
+                        raw: None,
+                    }),
+                    value,
+                    important: declaration.important.clone(),
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn process_logical_properties(&mut self, n: &mut SimpleBlock) {
+        if !n.value.iter().any(|node| {
+            matches!(node, ComponentValue::Declaration(declaration) if self.get_physical_declarations(declaration).is_some())
+        }) {
+            return;
+        }
+
+        let mut value = Vec::with_capacity(n.value.len());
+
+        for node in n.value.take() {
+            match node {
+                ComponentValue::Declaration(declaration) => {
+                    match self.get_physical_declarations(&declaration) {
+                        Some(declarations) => {
+                            value.extend(declarations.into_iter().map(|declaration| {
+                                ComponentValue::Declaration(Box::new(declaration))
+                            }))
+                        }
+                        None => value.push(ComponentValue::Declaration(declaration)),
+                    }
+                }
+                _ => value.push(node),
+            }
+        }
+
+        n.value = value;
+    }
+}
@@ -1,8 +1,8 @@
 use swc_common::{util::take::Take, Spanned, DUMMY_SP};
 use swc_css_ast::{
     AbsoluteColorBase, AtRule, ComponentValue, CompoundSelector, MediaAnd, MediaCondition,
-    MediaConditionAllType, MediaConditionWithoutOr, MediaInParens, MediaQuery, Rule,
-    SupportsCondition,
+    MediaConditionAllType, MediaConditionWithoutOr, MediaInParens, MediaQuery, Rule, SelectorList,
+    SimpleBlock, Stylesheet, SupportsCondition,
 };
 use swc_css_visit::{VisitMut, VisitMutWith};
 
@@ -12,11 +12,16 @@ use crate::feature::Features;
 mod color_alpha_parameter;
 mod color_hex_alpha;
 mod color_hwb;
+mod color_mix;
+mod color_oklab;
 mod color_space_separated_parameters;
 mod custom_media;
+mod custom_properties;
 mod legacy_rgb_and_hsl;
+mod logical_properties;
 mod media_query_ranges;
 mod nesting;
+mod selector_is;
 mod selector_not;
 
 /// Compiles a modern CSS file to a CSS file which works with old browsers.
@@ -45,7 +50,21 @@ impl Compiler {
 }
 
 impl VisitMut for Compiler {
+    fn visit_mut_stylesheet(&mut self, n: &mut Stylesheet) {
+        if self.c.process.contains(Features::CUSTOM_PROPERTIES) {
+            self.process_custom_properties(n);
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
     fn visit_mut_at_rule(&mut self, n: &mut AtRule) {
+        if self.c.process.contains(Features::NESTING) {
+            if let Some(block) = &mut n.block {
+                self.flatten_block(block);
+            }
+        }
+
         n.visit_mut_children_with(self);
 
         if self.c.process.contains(Features::CUSTOM_MEDIA) {
@@ -89,29 +108,11 @@ impl VisitMut for Compiler {
 
     fn visit_mut_rules(&mut self, n: &mut Vec<Rule>) {
         if self.c.process.contains(Features::NESTING) {
-            let mut new = Vec::new();
-
-            for n in n.take() {
-                match n {
-                    Rule::QualifiedRule(mut n) => {
-                        let mut rules = self.extract_nested_rules(&mut n);
-
-                        rules.visit_mut_with(self);
-
-                        new.push(Rule::QualifiedRule(n));
-                        new.extend(rules);
-                    }
-                    _ => {
-                        new.push(n);
-                    }
-                }
-            }
-
-            *n = new;
-        } else {
-            n.visit_mut_children_with(self);
+            *n = self.flatten_rules(n.take());
         }
 
+        n.visit_mut_children_with(self);
+
         if self.c.process.contains(Features::CUSTOM_MEDIA) {
             self.custom_media.process_rules(n);
         }
@@ -148,6 +149,26 @@ impl VisitMut for Compiler {
         }
     }
 
+    fn visit_mut_simple_block(&mut self, n: &mut SimpleBlock) {
+        n.visit_mut_children_with(self);
+
+        if self.c.process.contains(Features::LOGICAL_PROPERTIES) {
+            self.process_logical_properties(n);
+        }
+    }
+
+    fn visit_mut_selector_list(&mut self, n: &mut SelectorList) {
+        n.visit_mut_children_with(self);
+
+        if self.in_supports_condition {
+            return;
+        }
+
+        if self.c.process.contains(Features::SELECTOR_IS) {
+            self.process_selector_is(n);
+        }
+    }
+
     fn visit_mut_compound_selector(&mut self, n: &mut CompoundSelector) {
         n.visit_mut_children_with(self);
 
@@ -184,6 +205,14 @@ impl VisitMut for Compiler {
 
         let process = self.c.process;
 
+        if process.contains(Features::COLOR_OKLAB) {
+            self.process_color_oklab(n);
+        }
+
+        if process.contains(Features::COLOR_MIX) {
+            self.process_color_mix(n);
+        }
+
         if process.contains(Features::COLOR_SPACE_SEPARATED_PARAMETERS) {
             self.process_color_space_separated_function_notation(n);
         }
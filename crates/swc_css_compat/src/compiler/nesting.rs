@@ -234,62 +234,10 @@ impl Compiler {
                     continue;
                 }
                 ComponentValue::AtRule(ref at_rule) => {
-                    if let Some(
-                        AtRulePrelude::MediaPrelude(..)
-                        | AtRulePrelude::SupportsPrelude(..)
-                        | AtRulePrelude::ContainerPrelude(..)
-                        | AtRulePrelude::DocumentPrelude(..),
-                    ) = at_rule.prelude.as_deref()
-                    {
-                        if let Some(block) = &at_rule.block {
-                            let mut decls_of_media = Vec::new();
-                            let mut nested_of_media = Vec::new();
-
-                            for n in &block.value {
-                                match n {
-                                    ComponentValue::QualifiedRule(n) => {
-                                        let mut q = n.clone();
-
-                                        self.process_prelude(&rule.prelude, &mut q.prelude);
-
-                                        let rules = self.extract_nested_rules(&mut q);
-
-                                        nested_of_media.extend(
-                                            once(Rule::QualifiedRule(q))
-                                                .chain(rules)
-                                                .map(From::from),
-                                        );
-                                    }
-
-                                    _ => {
-                                        decls_of_media.push(n.clone());
-                                    }
-                                }
-                            }
-
-                            if !decls_of_media.is_empty() {
-                                let rule = Box::new(QualifiedRule {
-                                    span: DUMMY_SP,
-                                    prelude: rule.prelude.clone(),
-                                    block: SimpleBlock {
-                                        value: decls_of_media,
-                                        ..block.clone()
-                                    },
-                                });
-
-                                nested_of_media.insert(0, ComponentValue::QualifiedRule(rule));
-                            }
-
-                            nested_rules.push(Rule::AtRule(Box::new(AtRule {
-                                block: Some(SimpleBlock {
-                                    value: nested_of_media,
-                                    ..block.clone()
-                                }),
-                                ..*at_rule.clone()
-                            })));
+                    if let Some(at_rule) = self.extract_nested_at_rule(&rule.prelude, at_rule) {
+                        nested_rules.push(Rule::AtRule(Box::new(at_rule)));
 
-                            continue;
-                        }
+                        continue;
                     }
                 }
                 _ => {}
@@ -302,4 +250,126 @@ impl Compiler {
 
         nested_rules
     }
+
+    /// Moves a conditional group rule nested in a style rule to the top
+    /// level, wrapping its declarations with the selector of the style rule.
+    fn extract_nested_at_rule(
+        &mut self,
+        prelude: &QualifiedRulePrelude,
+        at_rule: &AtRule,
+    ) -> Option<AtRule> {
+        if !matches!(
+            at_rule.prelude.as_deref(),
+            Some(
+                AtRulePrelude::MediaPrelude(..)
+                    | AtRulePrelude::SupportsPrelude(..)
+                    | AtRulePrelude::ContainerPrelude(..)
+                    | AtRulePrelude::DocumentPrelude(..)
+                    | AtRulePrelude::LayerPrelude(..)
+                    | AtRulePrelude::ScopePrelude(..),
+            )
+        ) {
+            return None;
+        }
+
+        let block = at_rule.block.as_ref()?;
+        let mut decls = Vec::new();
+        let mut nested = Vec::new();
+
+        for n in &block.value {
+            match n {
+                ComponentValue::QualifiedRule(n) => {
+                    let mut q = n.clone();
+
+                    self.process_prelude(prelude, &mut q.prelude);
+
+                    let rules = self.extract_nested_rules(&mut q);
+
+                    nested.extend(once(Rule::QualifiedRule(q)).chain(rules).map(From::from));
+                }
+
+                ComponentValue::AtRule(n) => match self.extract_nested_at_rule(prelude, n) {
+                    Some(at_rule) => nested.push(ComponentValue::AtRule(Box::new(at_rule))),
+                    None => decls.push(ComponentValue::AtRule(n.clone())),
+                },
+
+                _ => {
+                    decls.push(n.clone());
+                }
+            }
+        }
+
+        if !decls.is_empty() {
+            let rule = Box::new(QualifiedRule {
+                span: DUMMY_SP,
+                prelude: prelude.clone(),
+                block: SimpleBlock {
+                    value: decls,
+                    ..block.clone()
+                },
+            });
+
+            nested.insert(0, ComponentValue::QualifiedRule(rule));
+        }
+
+        Some(AtRule {
+            block: Some(SimpleBlock {
+                value: nested,
+                ..block.clone()
+            }),
+            ..at_rule.clone()
+        })
+    }
+
+    /// Flattens style rules, including the ones extracted from other rules.
+    pub(crate) fn flatten_rules(&mut self, rules: Vec<Rule>) -> Vec<Rule> {
+        let mut new = Vec::with_capacity(rules.len());
+
+        for rule in rules {
+            match rule {
+                Rule::QualifiedRule(mut rule) => {
+                    let nested = self.extract_nested_rules(&mut rule);
+
+                    new.push(Rule::QualifiedRule(rule));
+                    new.extend(self.flatten_rules(nested));
+                }
+                _ => {
+                    new.push(rule);
+                }
+            }
+        }
+
+        new
+    }
+
+    /// Flattens style rules in the block of a top-level at-rule, like
+    /// `@media`.
+    pub(crate) fn flatten_block(&mut self, block: &mut SimpleBlock) {
+        if !block
+            .value
+            .iter()
+            .any(|n| matches!(n, ComponentValue::QualifiedRule(..)))
+        {
+            return;
+        }
+
+        let mut new = Vec::with_capacity(block.value.len());
+
+        for n in block.value.take() {
+            match n {
+                ComponentValue::QualifiedRule(rule) => {
+                    new.extend(
+                        self.flatten_rules(vec![Rule::QualifiedRule(rule)])
+                            .into_iter()
+                            .map(ComponentValue::from),
+                    );
+                }
+                _ => {
+                    new.push(n);
+                }
+            }
+        }
+
+        block.value = new;
+    }
 }
@@ -0,0 +1,146 @@
+use swc_common::util::take::Take;
+use swc_css_ast::{
+    ComplexSelector, ComplexSelectorChildren, CompoundSelector, ForgivingComplexSelector,
+    PseudoClassSelector, PseudoClassSelectorChildren, SelectorList, SubclassSelector, TypeSelector,
+};
+
+use crate::compiler::Compiler;
+
+fn get_specificity(n: &CompoundSelector) -> (usize, usize, usize) {
+    let mut specificity = (
+        0,
+        0,
+        matches!(n.type_selector.as_deref(), Some(TypeSelector::TagName(..))) as usize,
+    );
+
+    for selector in &n.subclass_selectors {
+        match selector {
+            SubclassSelector::Id(..) => specificity.0 += 1,
+            SubclassSelector::PseudoElement(..) => specificity.2 += 1,
+            _ => specificity.1 += 1,
+        }
+    }
+
+    specificity
+}
+
+/// Returns the compound selectors of `:is()`, if it can be replaced with
+/// them.
+///
+/// Only simple arguments with the same specificity are supported, because the
+/// specificity of `:is()` is the specificity of its most specific argument.
+fn get_is_arguments(
+    compound: &CompoundSelector,
+    selector: &SubclassSelector,
+) -> Option<Vec<CompoundSelector>> {
+    let SubclassSelector::PseudoClass(PseudoClassSelector {
+        name,
+        children: Some(children),
+        ..
+    }) = selector
+    else {
+        return None;
+    };
+
+    if !name.value.eq_ignore_ascii_case("is") {
+        return None;
+    }
+
+    let complex_selectors = match children.first()? {
+        PseudoClassSelectorChildren::SelectorList(list) => list.children.iter().collect::<Vec<_>>(),
+        PseudoClassSelectorChildren::ForgivingSelectorList(list) => list
+            .children
+            .iter()
+            .map(|child| match child {
+                ForgivingComplexSelector::ComplexSelector(complex) => Some(complex),
+                _ => None,
+            })
+            .collect::<Option<Vec<_>>>()?,
+        _ => return None,
+    };
+
+    let arguments = complex_selectors
+        .into_iter()
+        .map(|complex| match &*complex.children {
+            [ComplexSelectorChildren::CompoundSelector(argument)]
+                if argument.nesting_selector.is_none()
+                    && argument.subclass_selectors.iter().all(|selector| {
+                        !matches!(
+                            selector,
+                            SubclassSelector::PseudoClass(PseudoClassSelector {
+                                children: Some(..),
+                                ..
+                            }) | SubclassSelector::PseudoElement(..)
+                        )
+                    })
+                    // Only one type selector is allowed in a compound selector
+                    && (argument.type_selector.is_none()
+                        || (compound.type_selector.is_none()
+                            && compound.nesting_selector.is_none())) =>
+            {
+                Some(argument.clone())
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let specificity = get_specificity(arguments.first()?);
+
+    if arguments
+        .iter()
+        .any(|argument| get_specificity(argument) != specificity)
+    {
+        return None;
+    }
+
+    Some(arguments)
+}
+
+fn expand_is(selector: ComplexSelector, expanded: &mut Vec<ComplexSelector>) {
+    for (i, child) in selector.children.iter().enumerate() {
+        let ComplexSelectorChildren::CompoundSelector(compound) = child else {
+            continue;
+        };
+
+        for (j, subclass_selector) in compound.subclass_selectors.iter().enumerate() {
+            let Some(arguments) = get_is_arguments(compound, subclass_selector) else {
+                continue;
+            };
+
+            // e.g. `a:is(.b, .c) d` -> `a.b d, a.c d`
+            for argument in arguments {
+                let mut new_selector = selector.clone();
+
+                if let ComplexSelectorChildren::CompoundSelector(compound) =
+                    &mut new_selector.children[i]
+                {
+                    if argument.type_selector.is_some() {
+                        compound.type_selector = argument.type_selector;
+                    }
+
+                    compound
+                        .subclass_selectors
+                        .splice(j..=j, argument.subclass_selectors);
+                }
+
+                expand_is(new_selector, expanded);
+            }
+
+            return;
+        }
+    }
+
+    expanded.push(selector);
+}
+
+impl Compiler {
+    pub(crate) fn process_selector_is(&mut self, n: &mut SelectorList) {
+        let mut children = Vec::with_capacity(n.children.len());
+
+        for selector in n.children.take() {
+            expand_is(selector, &mut children);
+        }
+
+        n.children = children;
+    }
+}
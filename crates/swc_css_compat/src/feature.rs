@@ -1,4 +1,8 @@
+use std::collections::HashMap;
+
 use bitflags::bitflags;
+use once_cell::sync::Lazy;
+use preset_env_base::{version::should_enable, Versions};
 
 bitflags! {
     #[derive(Debug, Clone, Copy)]
@@ -12,5 +16,59 @@ bitflags! {
         const COLOR_LEGACY_RGB_AND_HSL = 1 << 6;
         const COLOR_HWB = 1 << 7;
         const SELECTOR_NOT = 1 << 8;
+        const COLOR_OKLAB = 1 << 9;
+        const COLOR_MIX = 1 << 10;
+        const LOGICAL_PROPERTIES = 1 << 11;
+        const SELECTOR_IS = 1 << 12;
+        /// Inlines custom properties which are statically defined in `:root`.
+        ///
+        /// This is never enabled by [`Features::from_targets`], because it
+        /// assumes that custom properties are not changed at runtime.
+        const CUSTOM_PROPERTIES = 1 << 13;
+    }
+}
+
+/// The minimum version of each browser which supports a feature natively.
+static FEATURES_AND_BROWSERS: Lazy<HashMap<String, Versions>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../data/features.json")).expect("failed to parse json")
+});
+
+impl Features {
+    /// Returns the features which should be processed, because they are not
+    /// supported by some of the `targets`.
+    ///
+    /// All features except [`Features::CUSTOM_PROPERTIES`] are processed if
+    /// there are no targets.
+    pub fn from_targets(targets: &Versions) -> Self {
+        if targets.is_any_target() {
+            return Features::all() - Features::CUSTOM_PROPERTIES;
+        }
+
+        [
+            ("nesting", Features::NESTING),
+            ("custom-media", Features::CUSTOM_MEDIA),
+            ("media-query-ranges", Features::MEDIA_QUERY_RANGES),
+            ("color-hex-alpha", Features::COLOR_HEX_ALPHA),
+            ("color-alpha-parameter", Features::COLOR_ALPHA_PARAMETER),
+            (
+                "color-space-separated-parameters",
+                Features::COLOR_SPACE_SEPARATED_PARAMETERS,
+            ),
+            (
+                "color-legacy-rgb-and-hsl",
+                Features::COLOR_LEGACY_RGB_AND_HSL,
+            ),
+            ("color-hwb", Features::COLOR_HWB),
+            ("selector-not", Features::SELECTOR_NOT),
+            ("color-oklab", Features::COLOR_OKLAB),
+            ("color-mix", Features::COLOR_MIX),
+            ("logical-properties", Features::LOGICAL_PROPERTIES),
+            ("selector-is", Features::SELECTOR_IS),
+        ]
+        .into_iter()
+        .filter(|(name, _)| should_enable(targets, &FEATURES_AND_BROWSERS[*name], true))
+        .fold(Features::empty(), |features, (_, feature)| {
+            features | feature
+        })
     }
 }
@@ -1,8 +1,14 @@
+use std::sync::Arc;
+
+use once_cell::sync::Lazy;
+use preset_env_base::{query::targets_to_versions, version::should_enable, Versions};
+use rustc_hash::FxHashMap;
 use swc_css_ast::*;
 use swc_css_utils::serialize_ident;
 use swc_css_visit::{VisitMut, VisitMutWith};
 
 use self::ctx::Ctx;
+use crate::options::MinifyOptions;
 
 mod alpha_value;
 mod angle;
@@ -20,24 +26,46 @@ mod math;
 mod media;
 mod rules;
 mod selector;
+mod shorthand;
 mod supports;
 mod time;
 mod transform_function;
 mod unicode_range;
 mod url;
 
-pub fn compressor() -> impl VisitMut {
-    Compressor::default()
+/// The minimum version of each browser which supports a feature.
+static FEATURES_AND_BROWSERS: Lazy<FxHashMap<String, Versions>> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../../data/features.json")).expect("failed to parse json")
+});
+
+pub fn compressor(options: MinifyOptions) -> impl VisitMut {
+    Compressor {
+        env: options
+            .env
+            .map(|env| targets_to_versions(Some(env), None).expect("failed to parse targets")),
+        ..Default::default()
+    }
 }
 
 #[derive(Default)]
 struct Compressor {
     ctx: Ctx,
+    /// Target browsers. If [None], only transformations which are safe for
+    /// every browser are applied.
+    env: Option<Arc<Versions>>,
     need_utf8_at_rule: bool,
     in_supports_condition: bool,
 }
 
 impl Compressor {
+    /// Returns `true` if all target browsers are known to support `feature`.
+    fn is_supported(&self, feature: &str) -> bool {
+        match (&self.env, FEATURES_AND_BROWSERS.get(feature)) {
+            (Some(env), Some(versions)) => !should_enable(env, versions, true),
+            _ => false,
+        }
+    }
+
     #[inline]
     fn is_ident_shorter_than_str(&self, input: &str) -> bool {
         let escaped = serialize_ident(input, true);
@@ -365,6 +393,7 @@ impl VisitMut for Compressor {
         n.visit_mut_children_with(self);
 
         self.compress_simple_block(n);
+        self.compress_shorthands(n);
     }
 
     fn visit_mut_size_feature_value(&mut self, n: &mut SizeFeatureValue) {
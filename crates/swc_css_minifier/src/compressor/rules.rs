@@ -20,30 +20,69 @@ enum Name {
     Keyframes(Atom, Atom),
 }
 
-struct CompatibilityChecker {
+struct CompatibilityChecker<'a> {
+    compressor: &'a Compressor,
     pub allow_to_merge: bool,
 }
 
-impl Default for CompatibilityChecker {
-    fn default() -> Self {
+impl<'a> CompatibilityChecker<'a> {
+    fn new(compressor: &'a Compressor) -> Self {
         CompatibilityChecker {
+            compressor,
             allow_to_merge: true,
         }
     }
 }
 
-// TODO improve me https://github.com/cssnano/cssnano/blob/master/packages/postcss-merge-rules/src/lib/ensureCompatibility.js#L62, need browserslist
-impl Visit for CompatibilityChecker {
-    fn visit_pseudo_class_selector(&mut self, _n: &PseudoClassSelector) {
-        self.allow_to_merge = false;
+// Selectors from CSS 2.1 are supported by all browsers, so they never
+// invalidate a merged selector list
+fn is_css2_pseudo_class(name: &str) -> bool {
+    matches!(
+        name,
+        "hover"
+            | "active"
+            | "focus"
+            | "link"
+            | "visited"
+            | "first-child"
+            | "lang"
+            // Legacy syntax for pseudo-elements
+            | "before"
+            | "after"
+            | "first-line"
+            | "first-letter"
+    )
+}
+
+fn is_css2_pseudo_element(name: &str) -> bool {
+    matches!(name, "before" | "after" | "first-line" | "first-letter")
+}
+
+// Rules with selectors which are not supported by some of the target browsers
+// are not merged, because an unsupported selector invalidates the whole rule
+impl Visit for CompatibilityChecker<'_> {
+    fn visit_pseudo_class_selector(&mut self, n: &PseudoClassSelector) {
+        let name = n.name.value.to_ascii_lowercase();
+
+        if !is_css2_pseudo_class(&name) && !self.compressor.is_supported(&format!(":{}", name)) {
+            self.allow_to_merge = false;
+        }
+
+        n.visit_children_with(self);
     }
 
-    fn visit_pseudo_element_selector(&mut self, _n: &PseudoElementSelector) {
-        self.allow_to_merge = false;
+    fn visit_pseudo_element_selector(&mut self, n: &PseudoElementSelector) {
+        let name = n.name.value.to_ascii_lowercase();
+
+        if !is_css2_pseudo_element(&name) && !self.compressor.is_supported(&format!("::{}", name)) {
+            self.allow_to_merge = false;
+        }
+
+        n.visit_children_with(self);
     }
 
     fn visit_attribute_selector(&mut self, n: &AttributeSelector) {
-        if n.modifier.is_some() {
+        if n.modifier.is_some() && !self.compressor.is_supported("attribute-modifier") {
             self.allow_to_merge = false;
         }
     }
@@ -239,7 +278,7 @@ impl Compressor {
                 QualifiedRulePrelude::SelectorList(left_selector_list),
                 QualifiedRulePrelude::SelectorList(right_selector_list),
             ) => {
-                let mut checker = CompatibilityChecker::default();
+                let mut checker = CompatibilityChecker::new(self);
 
                 left_selector_list.visit_with(&mut checker);
                 right_selector_list.visit_with(&mut checker);
@@ -250,7 +289,7 @@ impl Compressor {
                 QualifiedRulePrelude::RelativeSelectorList(left_relative_selector_list),
                 QualifiedRulePrelude::RelativeSelectorList(right_relative_selector_list),
             ) => {
-                let mut checker = CompatibilityChecker::default();
+                let mut checker = CompatibilityChecker::new(self);
 
                 left_relative_selector_list.visit_with(&mut checker);
                 right_relative_selector_list.visit_with(&mut checker);
@@ -509,7 +548,51 @@ impl Compressor {
         }
     }
 
+    // Remove declarations which are repeated later in the same block, the last
+    // one always wins, e.g. `color: red; color: blue; color: red` -> `color: blue;
+    // color: red`
+    fn remove_duplicate_declarations(&self, simple_block: &mut SimpleBlock) {
+        let mut remove_list = Vec::new();
+
+        for (i, left) in simple_block.value.iter().enumerate() {
+            let ComponentValue::Declaration(left) = left else {
+                continue;
+            };
+
+            let is_duplicate = simple_block.value[i + 1..].iter().any(|right| match right {
+                ComponentValue::Declaration(right) => {
+                    self.is_same_declaration_name(left, right)
+                        && left.important.is_some() == right.important.is_some()
+                        && left.value.eq_ignore_span(&right.value)
+                }
+                _ => false,
+            });
+
+            if is_duplicate {
+                remove_list.push(i);
+            }
+        }
+
+        if remove_list.is_empty() {
+            return;
+        }
+
+        simple_block.value = take(&mut simple_block.value)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, value)| {
+                if remove_list.contains(&idx) {
+                    None
+                } else {
+                    Some(value)
+                }
+            })
+            .collect::<Vec<_>>();
+    }
+
     pub(super) fn compress_simple_block(&mut self, simple_block: &mut SimpleBlock) {
+        self.remove_duplicate_declarations(simple_block);
+
         let mut names: FxHashMap<Name, isize> = Default::default();
         let mut prev_rule_idx = None;
         let mut remove_rules_list = Vec::new();
@@ -574,17 +657,6 @@ impl Compressor {
 
                     true
                 }
-                ComponentValue::Declaration(declaration) if prev_rule.is_some() => {
-                    if let Some(ComponentValue::Declaration(prev_rule)) = &mut prev_rule {
-                        if self.is_same_declaration_name(prev_rule, declaration)
-                            && prev_rule.value.eq_ignore_span(&declaration.value)
-                        {
-                            remove_rules_list.push(prev_index);
-                        }
-                    }
-
-                    true
-                }
                 _ => {
                     if let ComponentValue::AtRule(rule) = rule {
                         self.collect_names(rule, &mut names);
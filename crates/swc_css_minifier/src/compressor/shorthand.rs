@@ -0,0 +1,674 @@
+use std::mem::take;
+
+use swc_atoms::Atom;
+use swc_common::{EqIgnoreSpan, Span, DUMMY_SP};
+use swc_css_ast::*;
+use swc_css_visit::{Visit, VisitWith};
+
+use super::Compressor;
+use crate::is_css_wide_keyword;
+
+const SIDES: [&str; 4] = ["top", "right", "bottom", "left"];
+
+// In the order of the `background` shorthand
+const BACKGROUND_LONGHANDS: [&str; 8] = [
+    "background-image",
+    "background-position",
+    "background-size",
+    "background-repeat",
+    "background-attachment",
+    "background-origin",
+    "background-clip",
+    "background-color",
+];
+
+const FONT_LONGHANDS: [&str; 7] = [
+    "font-style",
+    "font-variant",
+    "font-weight",
+    "font-stretch",
+    "font-size",
+    "line-height",
+    "font-family",
+];
+
+fn declaration_name(node: &ComponentValue) -> Option<&Atom> {
+    match node {
+        ComponentValue::Declaration(declaration) => match &declaration.name {
+            DeclarationName::Ident(Ident { value, .. }) => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+#[derive(Default)]
+struct SubstitutionChecker {
+    found: bool,
+}
+
+impl Visit for SubstitutionChecker {
+    fn visit_function(&mut self, n: &Function) {
+        if matches!(&n.name, FunctionName::Ident(Ident { value, .. }) if matches_eq_ignore_ascii_case!(value, "var", "env", "attr"))
+        {
+            self.found = true;
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+// Values with `var()` or CSS-wide keywords can't be moved to another
+// declaration
+fn is_mergeable_value(value: &[ComponentValue]) -> bool {
+    if value.is_empty() {
+        return false;
+    }
+
+    if let [ComponentValue::Ident(ident)] = value {
+        if is_css_wide_keyword(&ident.value) {
+            return false;
+        }
+    }
+
+    let mut checker = SubstitutionChecker::default();
+
+    value.visit_with(&mut checker);
+
+    !checker.found
+}
+
+fn is_ident(node: &ComponentValue, value: &str) -> bool {
+    matches!(node, ComponentValue::Ident(ident) if ident.value.eq_ignore_ascii_case(value))
+}
+
+fn is_zero(node: &ComponentValue) -> bool {
+    match node {
+        ComponentValue::Integer(integer) => integer.value == 0,
+        ComponentValue::Number(number) => number.value == 0.0,
+        ComponentValue::Percentage(percentage) => percentage.value.value == 0.0,
+        ComponentValue::Dimension(dimension) => dimension
+            .as_length()
+            .map(|length| length.value.value == 0.0)
+            .unwrap_or_default(),
+        _ => false,
+    }
+}
+
+fn is_math_function(node: &ComponentValue) -> bool {
+    match node {
+        ComponentValue::Function(function) => {
+            matches!(&function.name, FunctionName::Ident(Ident { value, .. }) if matches_eq_ignore_ascii_case!(value, "calc", "min", "max", "clamp"))
+        }
+        _ => false,
+    }
+}
+
+fn is_length_percentage(node: &ComponentValue) -> bool {
+    match node {
+        ComponentValue::Integer(..)
+        | ComponentValue::Number(..)
+        | ComponentValue::Percentage(..)
+        | ComponentValue::LengthPercentage(..) => true,
+        ComponentValue::Dimension(dimension) => dimension.is_length(),
+        _ => is_math_function(node),
+    }
+}
+
+fn is_side_value(node: &ComponentValue) -> bool {
+    is_length_percentage(node) || is_ident(node, "auto")
+}
+
+fn solidus() -> ComponentValue {
+    ComponentValue::Delimiter(Box::new(Delimiter {
+        span: DUMMY_SP,
+        value: DelimiterValue::Solidus,
+    }))
+}
+
+/// Parts of the `font` shorthand, [None] if a part has its initial value.
+#[derive(Default)]
+struct FontShorthand {
+    style: Option<ComponentValue>,
+    variant: Option<ComponentValue>,
+    weight: Option<ComponentValue>,
+    stretch: Option<ComponentValue>,
+    size: Option<ComponentValue>,
+    line_height: Option<ComponentValue>,
+    family: Vec<ComponentValue>,
+}
+
+fn is_font_style(node: &ComponentValue) -> bool {
+    matches!(node, ComponentValue::Ident(ident) if matches_eq_ignore_ascii_case!(ident.value, "italic", "oblique"))
+}
+
+fn is_font_weight(node: &ComponentValue) -> bool {
+    match node {
+        ComponentValue::Ident(ident) => {
+            matches_eq_ignore_ascii_case!(ident.value, "bold", "bolder", "lighter")
+        }
+        ComponentValue::Integer(..) | ComponentValue::Number(..) => true,
+        _ => false,
+    }
+}
+
+fn is_font_stretch(node: &ComponentValue) -> bool {
+    matches!(node, ComponentValue::Ident(ident) if matches_eq_ignore_ascii_case!(
+        ident.value,
+        "ultra-condensed",
+        "extra-condensed",
+        "condensed",
+        "semi-condensed",
+        "semi-expanded",
+        "expanded",
+        "extra-expanded",
+        "ultra-expanded"
+    ))
+}
+
+fn is_font_size(node: &ComponentValue) -> bool {
+    match node {
+        ComponentValue::Ident(ident) => matches_eq_ignore_ascii_case!(
+            ident.value,
+            "xx-small",
+            "x-small",
+            "small",
+            "medium",
+            "large",
+            "x-large",
+            "xx-large",
+            "xxx-large",
+            "larger",
+            "smaller"
+        ),
+        ComponentValue::Integer(..) | ComponentValue::Number(..) => false,
+        _ => is_length_percentage(node),
+    }
+}
+
+impl FontShorthand {
+    fn parse(value: &[ComponentValue]) -> Option<Self> {
+        let mut font = FontShorthand::default();
+        let mut iter = value.iter().peekable();
+
+        for node in iter.by_ref() {
+            let part = if is_ident(node, "normal") {
+                continue;
+            } else if is_font_style(node) {
+                &mut font.style
+            } else if is_ident(node, "small-caps") {
+                &mut font.variant
+            } else if is_font_weight(node) {
+                &mut font.weight
+            } else if is_font_stretch(node) {
+                &mut font.stretch
+            } else if is_font_size(node) {
+                font.size = Some(node.clone());
+
+                break;
+            } else {
+                // e.g. system fonts or `oblique 10deg`
+                return None;
+            };
+
+            if part.replace(node.clone()).is_some() {
+                return None;
+            }
+        }
+
+        font.size.as_ref()?;
+
+        if let Some(ComponentValue::Delimiter(delimiter)) = iter.peek() {
+            if !matches!(delimiter.value, DelimiterValue::Solidus) {
+                return None;
+            }
+
+            iter.next();
+            font.line_height = Some(iter.next()?.clone()).filter(|node| !is_ident(node, "normal"));
+        }
+
+        font.family = iter.cloned().collect();
+
+        if font.family.is_empty() {
+            return None;
+        }
+
+        Some(font)
+    }
+
+    /// Sets a longhand, returns `false` if the value can't be used in the
+    /// shorthand.
+    fn set(&mut self, name: &str, value: &[ComponentValue]) -> bool {
+        if name == "font-family" {
+            self.family = value.to_vec();
+
+            return true;
+        }
+
+        let [node] = value else {
+            return false;
+        };
+
+        let (part, is_valid) = match name {
+            "font-style" => (&mut self.style, is_font_style(node)),
+            "font-variant" => (&mut self.variant, is_ident(node, "small-caps")),
+            "font-weight" => (&mut self.weight, is_font_weight(node)),
+            "font-stretch" => (&mut self.stretch, is_font_stretch(node)),
+            "font-size" => (&mut self.size, is_font_size(node)),
+            "line-height" => (
+                &mut self.line_height,
+                is_length_percentage(node) && !is_math_function(node),
+            ),
+            _ => return false,
+        };
+
+        if is_ident(node, "normal") && name != "font-size" {
+            *part = None;
+        } else if is_valid {
+            *part = Some(node.clone());
+        } else {
+            return false;
+        }
+
+        true
+    }
+
+    fn into_value(self) -> Vec<ComponentValue> {
+        let mut value: Vec<_> = [
+            self.style,
+            self.variant,
+            self.weight,
+            self.stretch,
+            self.size,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        if let Some(line_height) = self.line_height {
+            value.push(solidus());
+            value.push(line_height);
+        }
+
+        value.extend(self.family);
+        value
+    }
+}
+
+impl Compressor {
+    pub(super) fn compress_shorthands(&mut self, simple_block: &mut SimpleBlock) {
+        if !simple_block
+            .value
+            .iter()
+            .any(|node| matches!(node, ComponentValue::Declaration(..)))
+        {
+            return;
+        }
+
+        for name in ["margin", "padding"] {
+            let longhands = SIDES.map(|side| format!("{}-{}", name, side));
+
+            self.collapse_longhands(simple_block, name, &longhands, |values| {
+                values
+                    .iter()
+                    .all(|value| matches!(value, [node] if is_side_value(node)))
+                    .then(|| values.iter().map(|value| value[0].clone()).collect())
+            });
+            self.merge_into_sides_shorthand(simple_block, name, &longhands);
+        }
+
+        self.collapse_longhands(
+            simple_block,
+            "background",
+            &BACKGROUND_LONGHANDS.map(String::from),
+            |values| self.get_background_shorthand(values),
+        );
+        self.merge_into_font_shorthand(simple_block);
+    }
+
+    fn new_declaration(
+        &self,
+        span: Span,
+        name: &str,
+        value: Vec<ComponentValue>,
+        important: Option<ImportantFlag>,
+    ) -> ComponentValue {
+        let mut declaration = Declaration {
+            span,
+            name: DeclarationName::Ident(Ident {
+                span: DUMMY_SP,
+                value: name.into(),
+                raw: None,
+            }),
+            value,
+            important,
+        };
+
+        self.compress_declaration(&mut declaration);
+
+        ComponentValue::Declaration(Box::new(declaration))
+    }
+
+    // Replace a full set of longhands with the shorthand, e.g. `margin-top: 0;
+    // margin-right: 0; margin-bottom: 0; margin-left: 0` -> `margin: 0`
+    //
+    // Declarations can't be moved over nested rules, and other properties
+    // starting with the name of the shorthand (i.e. logical properties) may
+    // depend on the order of longhands, so we bail out in these cases.
+    fn collapse_longhands(
+        &self,
+        simple_block: &mut SimpleBlock,
+        shorthand: &str,
+        longhands: &[String],
+        get_value: impl FnOnce(&[&[ComponentValue]]) -> Option<Vec<ComponentValue>>,
+    ) {
+        let mut indexes = vec![None; longhands.len()];
+
+        for (index, node) in simple_block.value.iter().enumerate() {
+            let Some(name) = declaration_name(node) else {
+                continue;
+            };
+
+            if let Some(pos) = longhands.iter().position(|longhand| *longhand == **name) {
+                if indexes[pos].replace(index).is_some() {
+                    return;
+                }
+            } else if name.starts_with(shorthand) {
+                return;
+            }
+        }
+
+        let Some(indexes) = indexes.into_iter().collect::<Option<Vec<_>>>() else {
+            return;
+        };
+        let first = *indexes.iter().min().unwrap();
+        let last = *indexes.iter().max().unwrap();
+
+        if simple_block.value[first..=last]
+            .iter()
+            .any(|node| !matches!(node, ComponentValue::Declaration(..)))
+        {
+            return;
+        }
+
+        let declarations = indexes
+            .iter()
+            .filter_map(|index| simple_block.value[*index].as_declaration())
+            .collect::<Vec<_>>();
+        let important = declarations[0].important.clone();
+
+        if declarations.iter().any(|declaration| {
+            declaration.important.is_some() != important.is_some()
+                || !is_mergeable_value(&declaration.value)
+        }) {
+            return;
+        }
+
+        let values = declarations
+            .iter()
+            .map(|declaration| &*declaration.value)
+            .collect::<Vec<_>>();
+        let Some(value) = get_value(&values) else {
+            return;
+        };
+
+        let span = simple_block.value[last].as_declaration().unwrap().span;
+
+        simple_block.value[last] = self.new_declaration(span, shorthand, value, important);
+        simple_block.value = take(&mut simple_block.value)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, node)| {
+                if index != last && indexes.contains(&index) {
+                    None
+                } else {
+                    Some(node)
+                }
+            })
+            .collect();
+    }
+
+    // Remove longhands following the shorthand and merge them into it, e.g.
+    // `margin: 0; margin-top: 10px` -> `margin: 10px 0 0`
+    fn merge_into_sides_shorthand(
+        &self,
+        simple_block: &mut SimpleBlock,
+        shorthand: &str,
+        longhands: &[String],
+    ) {
+        let Some(shorthand_index) = simple_block
+            .value
+            .iter()
+            .rposition(|node| declaration_name(node).is_some_and(|name| name == shorthand))
+        else {
+            return;
+        };
+        let declaration = simple_block.value[shorthand_index]
+            .as_declaration()
+            .unwrap();
+
+        if !is_mergeable_value(&declaration.value)
+            || declaration.value.len() > 4
+            || !declaration.value.iter().all(is_side_value)
+        {
+            return;
+        }
+
+        let value = &declaration.value;
+        let mut sides = [
+            value[0].clone(),
+            value.get(1).unwrap_or(&value[0]).clone(),
+            value.get(2).unwrap_or(&value[0]).clone(),
+            value
+                .get(3)
+                .or_else(|| value.get(1))
+                .unwrap_or(&value[0])
+                .clone(),
+        ];
+        let important = declaration.important.clone();
+        let mut merged = Vec::new();
+
+        for (index, node) in simple_block
+            .value
+            .iter()
+            .enumerate()
+            .skip(shorthand_index + 1)
+        {
+            let ComponentValue::Declaration(longhand) = node else {
+                break;
+            };
+            let Some(name) = declaration_name(node) else {
+                continue;
+            };
+
+            if let Some(pos) = longhands.iter().position(|longhand| *longhand == **name) {
+                match &*longhand.value {
+                    [value]
+                        if is_side_value(value)
+                            && is_mergeable_value(&longhand.value)
+                            && longhand.important.is_some() == important.is_some() =>
+                    {
+                        sides[pos] = value.clone();
+                        merged.push(index);
+                    }
+                    _ => break,
+                }
+            } else if name.starts_with(shorthand) {
+                break;
+            }
+        }
+
+        if merged.is_empty() {
+            return;
+        }
+
+        let span = simple_block.value[shorthand_index]
+            .as_declaration()
+            .unwrap()
+            .span;
+
+        simple_block.value[shorthand_index] =
+            self.new_declaration(span, shorthand, sides.into(), important);
+        simple_block.value = take(&mut simple_block.value)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, node)| {
+                if merged.contains(&index) {
+                    None
+                } else {
+                    Some(node)
+                }
+            })
+            .collect();
+    }
+
+    // Longhands which have their initial values are omitted. `background-size`,
+    // `background-origin` and `background-clip` are not supported in the
+    // shorthand by old browsers.
+    fn get_background_shorthand(
+        &self,
+        values: &[&[ComponentValue]],
+    ) -> Option<Vec<ComponentValue>> {
+        let &[image, position, size, repeat, attachment, origin, clip, color] = values else {
+            return None;
+        };
+
+        // Multiple layers are not supported
+        if values.iter().any(|value| {
+            value.iter().any(|node| {
+                matches!(node, ComponentValue::Delimiter(delimiter) if matches!(delimiter.value, DelimiterValue::Comma))
+            })
+        }) {
+            return None;
+        }
+
+        let ([image], [origin], [clip], [attachment], [color]) =
+            (image, origin, clip, attachment, color)
+        else {
+            return None;
+        };
+
+        if position.len() > 4 || size.len() > 2 || repeat.len() > 2 || is_ident(clip, "text") {
+            return None;
+        }
+
+        let has_size = !size.iter().all(|node| is_ident(node, "auto"));
+        let has_box = !is_ident(origin, "padding-box") || !is_ident(clip, "border-box");
+
+        if (has_size || has_box) && !self.is_supported("background-shorthand-css3") {
+            return None;
+        }
+
+        let mut value = Vec::new();
+
+        if !is_ident(image, "none") {
+            value.push(image.clone());
+        }
+
+        if has_size {
+            value.extend(position.iter().cloned());
+            value.push(solidus());
+            value.extend(size.iter().cloned());
+        } else if !position.iter().all(is_zero) {
+            value.extend(position.iter().cloned());
+        }
+
+        if !repeat.iter().all(|node| is_ident(node, "repeat")) {
+            value.extend(repeat.iter().cloned());
+        }
+
+        if !is_ident(attachment, "scroll") {
+            value.push(attachment.clone());
+        }
+
+        if has_box {
+            value.push(origin.clone());
+
+            if !origin.eq_ignore_span(clip) {
+                value.push(clip.clone());
+            }
+        }
+
+        value.push(color.clone());
+
+        Some(value)
+    }
+
+    // Merge font longhands following the `font` shorthand into it, e.g. `font:
+    // 12px Arial; font-weight: bold` -> `font: bold 12px Arial`
+    //
+    // We don't collapse font longhands into the shorthand, because it also
+    // resets inherited properties like `font-kerning`.
+    fn merge_into_font_shorthand(&self, simple_block: &mut SimpleBlock) {
+        let Some(shorthand_index) = simple_block
+            .value
+            .iter()
+            .rposition(|node| declaration_name(node).is_some_and(|name| name == "font"))
+        else {
+            return;
+        };
+        let declaration = simple_block.value[shorthand_index]
+            .as_declaration()
+            .unwrap();
+
+        if !is_mergeable_value(&declaration.value) {
+            return;
+        }
+
+        let Some(mut font) = FontShorthand::parse(&declaration.value) else {
+            return;
+        };
+        let important = declaration.important.clone();
+        let mut merged = Vec::new();
+
+        for (index, node) in simple_block
+            .value
+            .iter()
+            .enumerate()
+            .skip(shorthand_index + 1)
+        {
+            let ComponentValue::Declaration(longhand) = node else {
+                break;
+            };
+            let Some(name) = declaration_name(node) else {
+                continue;
+            };
+
+            if FONT_LONGHANDS.contains(&&**name) {
+                if longhand.important.is_some() != important.is_some()
+                    || !is_mergeable_value(&longhand.value)
+                    || !font.set(name, &longhand.value)
+                {
+                    break;
+                }
+
+                merged.push(index);
+            } else if name.starts_with("font") {
+                break;
+            }
+        }
+
+        if merged.is_empty() {
+            return;
+        }
+
+        let span = simple_block.value[shorthand_index]
+            .as_declaration()
+            .unwrap()
+            .span;
+
+        simple_block.value[shorthand_index] =
+            self.new_declaration(span, "font", font.into_value(), important);
+        simple_block.value = take(&mut simple_block.value)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, node)| {
+                if merged.contains(&index) {
+                    None
+                } else {
+                    Some(node)
+                }
+            })
+            .collect();
+    }
+}
@@ -13,8 +13,8 @@ mod compressor;
 pub mod options;
 pub(crate) mod util;
 
-pub fn minify(stylesheet: &mut Stylesheet, _options: MinifyOptions) {
-    stylesheet.visit_mut_with(&mut compressor());
+pub fn minify(stylesheet: &mut Stylesheet, options: MinifyOptions) {
+    stylesheet.visit_mut_with(&mut compressor(options));
 }
 
 #[inline]
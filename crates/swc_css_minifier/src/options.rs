@@ -1,6 +1,15 @@
+pub use preset_env_base::query::Targets;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(deny_unknown_fields)]
-pub struct MinifyOptions {}
+pub struct MinifyOptions {
+    /// Browsers which should be supported by the output.
+    ///
+    /// Transformations which may break old browsers, like merging rules with
+    /// selectors which are not supported everywhere, are only applied if all
+    /// targets support them. If not set, they are never applied.
+    #[serde(default, skip_serializing)]
+    pub env: Option<Targets>,
+}
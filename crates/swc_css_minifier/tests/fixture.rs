@@ -1,16 +1,15 @@
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 
 use swc_css_ast::Stylesheet;
 use swc_css_codegen::{
     writer::basic::{BasicCssWriter, BasicCssWriterConfig},
     CodeGenerator, CodegenConfig, Emit,
 };
-use swc_css_minifier::minify;
+use swc_css_minifier::{minify, options::MinifyOptions};
 use swc_css_parser::parse_file;
 use testing::NormalizedOutput;
 
-#[testing::fixture("tests/fixture/**/input.css")]
-fn minify_fixtures(input: PathBuf) {
+fn run(input: PathBuf, options: MinifyOptions) {
     let dir = input.parent().unwrap();
     let output = dir.join(format!(
         "output.min.{}",
@@ -34,7 +33,7 @@ fn minify_fixtures(input: PathBuf) {
         let mut ss = res.unwrap();
 
         // Apply transforms
-        minify(&mut ss, Default::default());
+        minify(&mut ss, options);
 
         let mut css_str = String::new();
         {
@@ -52,3 +51,20 @@ fn minify_fixtures(input: PathBuf) {
     })
     .unwrap();
 }
+
+#[testing::fixture("tests/fixture/**/input.css")]
+fn minify_fixtures(input: PathBuf) {
+    run(input, Default::default());
+}
+
+#[testing::fixture("tests/targets/**/input.css")]
+fn minify_with_targets(input: PathBuf) {
+    let targets = fs::read_to_string(input.with_file_name("targets.json")).unwrap();
+
+    run(
+        input,
+        MinifyOptions {
+            env: Some(serde_json::from_str(&targets).unwrap()),
+        },
+    )
+}
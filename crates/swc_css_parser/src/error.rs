@@ -9,7 +9,7 @@ use swc_common::{
 /// Size is same as a size of a pointer.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Error {
-    inner: Box<(Span, ErrorKind)>,
+    inner: Box<(Span, ErrorKind, Option<Recovery>)>,
 }
 
 impl Error {
@@ -17,16 +17,32 @@ impl Error {
         &self.inner.1
     }
 
+    pub fn span(&self) -> Span {
+        self.inner.0
+    }
+
+    /// Returns how the parser recovered from this error, if it did.
+    pub fn recovery(&self) -> Option<&Recovery> {
+        self.inner.2.as_ref()
+    }
+
     pub fn into_inner(self) -> Box<(Span, ErrorKind)> {
-        self.inner
+        let (span, kind, _) = *self.inner;
+
+        Box::new((span, kind))
     }
 
     pub fn new(span: Span, kind: ErrorKind) -> Self {
         Error {
-            inner: Box::new((span, kind)),
+            inner: Box::new((span, kind, None)),
         }
     }
 
+    pub(crate) fn with_recovery(mut self, kind: RecoveryKind, span: Span) -> Self {
+        self.inner.2 = Some(Recovery { kind, span });
+        self
+    }
+
     pub fn message(&self) -> Cow<'static, str> {
         match &self.inner.1 {
             ErrorKind::Eof => "Unexpected end of file".into(),
@@ -81,6 +97,39 @@ impl Error {
     }
 }
 
+/// Describes how the parser recovered from an error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Recovery {
+    pub kind: RecoveryKind,
+    /// The span of the code which was skipped or preserved, e.g. the whole rule
+    /// containing the error.
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RecoveryKind {
+    /// The rule was dropped, like browsers do.
+    SkippedRule,
+    /// The declaration was dropped, like browsers do.
+    SkippedDeclaration,
+    /// The rule was preserved as a list of component values.
+    PreservedRule,
+    /// The declaration was preserved as a list of component values.
+    PreservedDeclaration,
+    /// The prelude of the rule was preserved as a list of component values.
+    PreservedPrelude,
+}
+
+impl RecoveryKind {
+    pub fn is_skipped(self) -> bool {
+        matches!(
+            self,
+            RecoveryKind::SkippedRule | RecoveryKind::SkippedDeclaration
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ErrorKind {
@@ -639,14 +639,7 @@ where
 
                 rule_list
             }
-            "layer" => {
-                let rule_list = self.parse_as::<Vec<Rule>>()?;
-                let rule_list: Vec<ComponentValue> =
-                    rule_list.into_iter().map(ComponentValue::from).collect();
-
-                rule_list
-            }
-            "media" => match self.ctx.block_contents_grammar {
+            "layer" | "media" => match self.ctx.block_contents_grammar {
                 BlockContentsGrammar::StyleBlock => {
                     let style_blocks: Vec<StyleBlock> = self.parse()?;
                     let style_blocks: Vec<ComponentValue> =
@@ -51,6 +51,16 @@ pub struct ParserConfig {
     /// If this is `true`, the legacy syntax for IE will be parsed.
     #[serde(default)]
     pub legacy_ie: bool,
+
+    /// If this is `true`, invalid rules and declarations are dropped like
+    /// browsers do, instead of being preserved as a list of component values.
+    ///
+    /// In both cases, the errors describe the recovery with
+    /// [Error::recovery](crate::error::Error::recovery).
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub skip_invalid: bool,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
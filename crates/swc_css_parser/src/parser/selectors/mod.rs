@@ -635,7 +635,7 @@ where
                 }
 
                 Ident {
-                    span,
+                    span: Span::new(span.lo + BytePos(1), span.hi),
                     value,
                     raw: Some(raw),
                 }
@@ -99,9 +99,9 @@ where
                     let qualified_rule = self.with_ctx(ctx).parse_as::<Box<QualifiedRule>>();
 
                     match qualified_rule {
+                        Ok(i) if self.is_invalid_qualified_rule(&i) => {}
                         Ok(i) => rules.push(Rule::QualifiedRule(i)),
                         Err(err) => {
-                            self.errors.push(err);
                             self.input.reset(&state);
 
                             let span = self.input.cur_span();
@@ -119,9 +119,15 @@ where
 
                             list_of_component_values.span = span!(self, span.lo);
 
-                            rules.push(Rule::ListOfComponentValues(Box::new(
-                                list_of_component_values,
-                            )));
+                            let err = self.recover_rule(err, list_of_component_values.span);
+
+                            self.errors.push(err);
+
+                            if !self.config.skip_invalid {
+                                rules.push(Rule::ListOfComponentValues(Box::new(
+                                    list_of_component_values,
+                                )));
+                            }
                         }
                     };
                 }
@@ -402,19 +408,22 @@ where
                         temporary_list.children.push(component_value);
                     }
 
-                    let decl_or_list_of_component_values =
-                        match self.parse_declaration_from_temporary_list(&temporary_list) {
-                            Ok(decl) => StyleBlock::Declaration(Box::new(decl)),
-                            Err(err) => {
-                                self.errors.push(err);
+                    match self.parse_declaration_from_temporary_list(&temporary_list) {
+                        Ok(decl) => declarations.push(StyleBlock::Declaration(Box::new(decl))),
+                        Err(err) => {
+                            temporary_list.span = span!(self, span.lo);
+
+                            let err = self.recover_declaration(err, temporary_list.span);
 
-                                temporary_list.span = span!(self, span.lo);
+                            self.errors.push(err);
 
-                                StyleBlock::ListOfComponentValues(Box::new(temporary_list))
+                            if !self.config.skip_invalid {
+                                declarations.push(StyleBlock::ListOfComponentValues(Box::new(
+                                    temporary_list,
+                                )));
                             }
-                        };
-
-                    declarations.push(decl_or_list_of_component_values);
+                        }
+                    }
                 }
                 // anything else
                 // Reconsume the current input token. Consume a qualified rule, with mixed with
@@ -430,16 +439,13 @@ where
                         .parse_as::<Box<QualifiedRule>>();
 
                     match qualified_rule {
+                        Ok(i) if self.is_invalid_qualified_rule(&i) => {}
                         Ok(i) => rules.push(StyleBlock::QualifiedRule(i)),
                         Err(err) => {
-                            self.errors.push(err);
                             self.input.reset(&state);
 
                             let span = self.input.cur_span();
 
-                            self.errors
-                                .push(Error::new(span, ErrorKind::Unexpected("token")));
-
                             // For recovery mode
                             let mut list_of_component_values = ListOfComponentValues {
                                 span: Default::default(),
@@ -454,9 +460,17 @@ where
 
                             list_of_component_values.span = span!(self, span.lo);
 
-                            declarations.push(StyleBlock::ListOfComponentValues(Box::new(
-                                list_of_component_values,
-                            )));
+                            let err = self.recover_rule(err, list_of_component_values.span);
+
+                            self.errors.push(err);
+                            self.errors
+                                .push(Error::new(span, ErrorKind::Unexpected("token")));
+
+                            if !self.config.skip_invalid {
+                                declarations.push(StyleBlock::ListOfComponentValues(Box::new(
+                                    list_of_component_values,
+                                )));
+                            }
                         }
                     };
                 }
@@ -536,19 +550,24 @@ where
                         temporary_list.children.push(component_value);
                     }
 
-                    let decl_or_list_of_component_values =
-                        match self.parse_declaration_from_temporary_list(&temporary_list) {
-                            Ok(decl) => DeclarationOrAtRule::Declaration(Box::new(decl)),
-                            Err(err) => {
-                                self.errors.push(err);
+                    match self.parse_declaration_from_temporary_list(&temporary_list) {
+                        Ok(decl) => {
+                            declarations.push(DeclarationOrAtRule::Declaration(Box::new(decl)))
+                        }
+                        Err(err) => {
+                            temporary_list.span = span!(self, span.lo);
 
-                                temporary_list.span = span!(self, span.lo);
+                            let err = self.recover_declaration(err, temporary_list.span);
 
-                                DeclarationOrAtRule::ListOfComponentValues(Box::new(temporary_list))
-                            }
-                        };
+                            self.errors.push(err);
 
-                    declarations.push(decl_or_list_of_component_values);
+                            if !self.config.skip_invalid {
+                                declarations.push(DeclarationOrAtRule::ListOfComponentValues(
+                                    Box::new(temporary_list),
+                                ));
+                            }
+                        }
+                    }
                 }
                 // anything else
                 // This is a parse error. Reconsume the current input token. As long as the next
@@ -560,11 +579,6 @@ where
                 _ => {
                     let span = self.input.cur_span();
 
-                    self.errors.push(Error::new(
-                        span,
-                        ErrorKind::Expected("whitespace, ';', '@', ident or EOF"),
-                    ));
-
                     // For recovery mode
                     let mut list_of_component_values = ListOfComponentValues {
                         span: Default::default(),
@@ -579,9 +593,21 @@ where
 
                     list_of_component_values.span = span!(self, span.lo);
 
-                    declarations.push(DeclarationOrAtRule::ListOfComponentValues(Box::new(
-                        list_of_component_values,
-                    )));
+                    let err = self.recover_declaration(
+                        Error::new(
+                            span,
+                            ErrorKind::Expected("whitespace, ';', '@', ident or EOF"),
+                        ),
+                        list_of_component_values.span,
+                    );
+
+                    self.errors.push(err);
+
+                    if !self.config.skip_invalid {
+                        declarations.push(DeclarationOrAtRule::ListOfComponentValues(Box::new(
+                            list_of_component_values,
+                        )));
+                    }
                 }
             }
         }
@@ -669,10 +695,15 @@ where
                     if let Some(span) = &exclamation_point_span {
                         is_valid_to_canonicalize = false;
 
-                        self.errors.push(Error::new(
-                            *span,
-                            ErrorKind::Unexpected("'!' in declaration value"),
-                        ));
+                        let err =
+                            Error::new(*span, ErrorKind::Unexpected("'!' in declaration value"));
+
+                        // The whole declaration is invalid
+                        if self.config.skip_invalid {
+                            return Err(err);
+                        }
+
+                        self.errors.push(err);
 
                         important_ident = None;
                         last_whitespaces = (last_whitespaces.2, 0, 0);
@@ -711,6 +742,10 @@ where
                     if let Err(err) = self.validate_declaration_value(&component_value) {
                         is_valid_to_canonicalize = false;
 
+                        if self.config.skip_invalid {
+                            return Err(err);
+                        }
+
                         self.errors.push(err);
                     }
 
@@ -719,10 +754,14 @@ where
                     if let Some(span) = &exclamation_point_span {
                         is_valid_to_canonicalize = false;
 
-                        self.errors.push(Error::new(
-                            *span,
-                            ErrorKind::Unexpected("'!' in declaration value"),
-                        ));
+                        let err =
+                            Error::new(*span, ErrorKind::Unexpected("'!' in declaration value"));
+
+                        if self.config.skip_invalid {
+                            return Err(err);
+                        }
+
+                        self.errors.push(err);
 
                         important_ident = None;
                         exclamation_point_span = None;
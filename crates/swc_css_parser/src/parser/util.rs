@@ -7,7 +7,10 @@ use super::{
     input::{Input, InputType, ParserInput},
     Ctx, Error, PResult, Parse, Parser,
 };
-use crate::{error::ErrorKind, parser::BlockContentsGrammar};
+use crate::{
+    error::{ErrorKind, RecoveryKind},
+    parser::BlockContentsGrammar,
+};
 
 impl<I> Parser<I>
 where
@@ -49,6 +52,51 @@ where
         }
     }
 
+    /// Attaches the recovery of an invalid rule to `err`.
+    pub(super) fn recover_rule(&self, err: Error, span: Span) -> Error {
+        let kind = if self.config.skip_invalid {
+            RecoveryKind::SkippedRule
+        } else {
+            RecoveryKind::PreservedRule
+        };
+
+        err.with_recovery(kind, span)
+    }
+
+    /// Attaches the recovery of an invalid declaration to `err`.
+    pub(super) fn recover_declaration(&self, err: Error, span: Span) -> Error {
+        let kind = if self.config.skip_invalid {
+            RecoveryKind::SkippedDeclaration
+        } else {
+            RecoveryKind::PreservedDeclaration
+        };
+
+        err.with_recovery(kind, span)
+    }
+
+    /// Attaches the recovery of an invalid selector to `err`. Browsers drop the
+    /// whole rule in this case.
+    fn recover_prelude(&self, err: Error, span: Span) -> Error {
+        let kind = if self.config.skip_invalid {
+            RecoveryKind::SkippedRule
+        } else {
+            RecoveryKind::PreservedPrelude
+        };
+
+        err.with_recovery(kind, span)
+    }
+
+    /// Returns `true` if the qualified rule has an invalid selector and should
+    /// be dropped.
+    pub(super) fn is_invalid_qualified_rule(&self, qualified_rule: &QualifiedRule) -> bool {
+        self.config.skip_invalid
+            && !self.ctx.in_keyframes_at_rule
+            && matches!(
+                qualified_rule.prelude,
+                QualifiedRulePrelude::ListOfComponentValues(..)
+            )
+    }
+
     pub(super) fn create_locv(&self, children: Vec<ComponentValue>) -> ListOfComponentValues {
         let span = match (children.first(), children.last()) {
             (Some(first), Some(last)) => Span::new(first.span_lo(), last.span_hi()),
@@ -123,7 +171,8 @@ where
             },
             Err(err) => {
                 if *err.kind() != ErrorKind::Ignore {
-                    self.errors.push(err);
+                    self.errors
+                        .push(err.with_recovery(RecoveryKind::PreservedPrelude, at_rule.span));
                 }
 
                 if !list_of_component_values.children.is_empty() {
@@ -196,6 +245,8 @@ where
                     QualifiedRulePrelude::RelativeSelectorList(relative_selector_list)
                 }
                 Err(err) => {
+                    let err = self.recover_prelude(err, qualified_rule.span);
+
                     self.errors.push(err);
 
                     QualifiedRulePrelude::ListOfComponentValues(list_of_component_values)
@@ -208,6 +259,8 @@ where
                 }) {
                 Ok(selector_list) => QualifiedRulePrelude::SelectorList(selector_list),
                 Err(err) => {
+                    let err = self.recover_prelude(err, qualified_rule.span);
+
                     self.errors.push(err);
 
                     QualifiedRulePrelude::ListOfComponentValues(list_of_component_values)
@@ -301,6 +354,9 @@ where
         });
         declaration.value = match value {
             Ok(values) => values,
+            Err(err) if self.config.skip_invalid && *err.kind() != ErrorKind::Ignore => {
+                return Err(err);
+            }
             Err(err) => {
                 if *err.kind() != ErrorKind::Ignore {
                     self.errors.push(err);
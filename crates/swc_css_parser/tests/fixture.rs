@@ -195,6 +195,44 @@ fn stylesheet_recovery_test(input: PathBuf, config: ParserConfig) {
     stderr.compare_to_file(stderr_path).unwrap();
 }
 
+fn stylesheet_skip_invalid_test(input: PathBuf) {
+    let stderr_path = input.parent().unwrap().join("output.swc-stderr");
+    let ref_json_path = input.parent().unwrap().join("output.json");
+    let config = ParserConfig {
+        skip_invalid: true,
+        ..Default::default()
+    };
+
+    let stderr = testing::run_test2(false, |cm, handler| -> Result<(), ()> {
+        let fm = cm.load_file(&input).unwrap();
+        let lexer = Lexer::new(SourceFileInput::from(&*fm), None, config);
+        let mut parser = Parser::new(lexer, config);
+        let stylesheet = parser.parse_all();
+        let errors = parser.take_errors();
+
+        for err in &errors {
+            let mut d = err.to_diagnostics(&handler);
+
+            if let Some(recovery) = err.recovery() {
+                d.span_note(recovery.span, &format!("recovery: {:?}", recovery.kind));
+            }
+
+            d.emit();
+        }
+
+        let actual_json = serde_json::to_string_pretty(&stylesheet.unwrap())
+            .map(NormalizedOutput::from)
+            .expect("failed to serialize stylesheet");
+
+        actual_json.compare_to_file(&ref_json_path).unwrap();
+
+        Err(())
+    })
+    .unwrap_err();
+
+    stderr.compare_to_file(stderr_path).unwrap();
+}
+
 fn stylesheet_recovery_test_tokens(input: PathBuf, config: ParserConfig) {
     let stderr_path = input.parent().unwrap().join("output.swc-stderr");
     let ref_json_path = input.parent().unwrap().join("output.json");
@@ -646,6 +684,11 @@ fn recovery_2(input: PathBuf) {
     );
 }
 
+#[testing::fixture("tests/recovery-skip-invalid/**/input.css")]
+fn recovery_skip_invalid(input: PathBuf) {
+    stylesheet_skip_invalid_test(input);
+}
+
 #[testing::fixture("tests/fixture/**/input.css")]
 #[testing::fixture("tests/recovery/**/input.css")]
 fn span_visualizer(input: PathBuf) {
@@ -8,6 +8,11 @@ use serde::Deserialize;
 pub struct Options {
     #[serde(default = "default_targets")]
     pub env: Option<Targets>,
+
+    /// Remove prefixed declarations which are not required by `env`, if the
+    /// unprefixed declaration exists in the same block.
+    #[serde(default)]
+    pub remove: bool,
 }
 
 fn default_targets() -> Option<Targets> {
@@ -18,6 +23,7 @@ impl Default for Options {
     fn default() -> Self {
         Self {
             env: default_targets(),
+            remove: false,
         }
     }
 }
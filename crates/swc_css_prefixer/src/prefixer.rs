@@ -115,6 +115,7 @@ pub fn prefixer(options: Options) -> impl VisitMut {
 
     Prefixer {
         env,
+        remove: options.remove,
         ..Default::default()
     }
 }
@@ -772,7 +773,11 @@ pub enum Prefix {
 #[derive(Default)]
 struct Prefixer {
     env: Arc<Versions>,
+    remove: bool,
     in_keyframe_block: bool,
+    /// In at-rules like `@-webkit-keyframes`, where prefixed declarations
+    /// should be kept.
+    in_prefixed_at_rule: bool,
     supports_condition: Option<SupportsCondition>,
     simple_block: Option<SimpleBlock>,
     rule_prefix: Option<Prefix>,
@@ -807,6 +812,41 @@ impl Prefixer {
         false
     }
 
+    /// Removes prefixed declarations which are not required by the targets,
+    /// if the block has the unprefixed declaration.
+    fn remove_outdated_declarations(&self, values: &mut Vec<ComponentValue>) {
+        let names = values
+            .iter()
+            .filter_map(|n| match n {
+                ComponentValue::Declaration(declaration) => match &declaration.name {
+                    DeclarationName::Ident(ident) => Some(ident.value.clone()),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        values.retain(|n| {
+            let ComponentValue::Declaration(declaration) = n else {
+                return true;
+            };
+            let DeclarationName::Ident(ident) = &declaration.name else {
+                return true;
+            };
+
+            let Some(unprefixed) = ["-webkit-", "-moz-", "-ms-", "-o-"]
+                .iter()
+                .find_map(|prefix| ident.value.strip_prefix(prefix))
+            else {
+                return true;
+            };
+
+            !names.iter().any(|name| *name == *unprefixed)
+                || !PREFIXES_AND_BROWSERS.contains_key(&*ident.value)
+                || should_prefix(&ident.value, &self.env, true)
+        });
+    }
+
     fn add_declaration2<'a>(
         &mut self,
         n: &Declaration,
@@ -915,8 +955,16 @@ impl VisitMut for Prefixer {
     fn visit_mut_at_rule(&mut self, at_rule: &mut AtRule) {
         let original_simple_block = at_rule.block.clone();
 
+        let old_in_prefixed_at_rule = self.in_prefixed_at_rule;
+
+        if let AtRuleName::Ident(Ident { value, .. }) = &at_rule.name {
+            self.in_prefixed_at_rule |= value.starts_with('-');
+        }
+
         at_rule.visit_mut_children_with(self);
 
+        self.in_prefixed_at_rule = old_in_prefixed_at_rule;
+
         match &at_rule.name {
             AtRuleName::Ident(Ident { span, value, .. }) if value == "viewport" => {
                 if should_prefix("@-o-viewport", &self.env, false) {
@@ -1494,6 +1542,10 @@ impl VisitMut for Prefixer {
             new.push(n);
         }
 
+        if self.remove && self.rule_prefix.is_none() && !self.in_prefixed_at_rule {
+            self.remove_outdated_declarations(&mut new);
+        }
+
         simple_block.value = new;
 
         self.simple_block = old_simple_block;
@@ -63,7 +63,14 @@ fn prefix(input: PathBuf, options: Options, suffix: Option<&str>) {
 
 #[testing::fixture("tests/fixture/**/input.css")]
 fn test_without_env(input: PathBuf) {
-    prefix(input, Options { env: None }, None)
+    prefix(
+        input,
+        Options {
+            env: None,
+            ..Default::default()
+        },
+        None,
+    )
 }
 
 #[testing::fixture("tests/fixture/**/input.css")]
@@ -74,7 +81,22 @@ fn test_with_env(input: PathBuf) {
             env: Some(Targets::Query(Query::Single(String::from(
                 "defaults, not IE 11",
             )))),
+            ..Default::default()
         },
         Some("defaults-not-ie-11"),
     )
 }
+
+#[testing::fixture("tests/remove/**/input.css")]
+fn test_remove(input: PathBuf) {
+    prefix(
+        input,
+        Options {
+            env: Some(Targets::Query(Query::Single(String::from(
+                "defaults, not IE 11",
+            )))),
+            remove: true,
+        },
+        None,
+    )
+}
@@ -6,8 +6,9 @@ use once_cell::sync::Lazy;
 use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use swc_atoms::{Atom, StaticString};
+use swc_common::Span;
 use swc_css_ast::*;
-use swc_css_visit::{VisitMut, VisitMutWith};
+use swc_css_visit::{Visit, VisitMut, VisitMutWith, VisitWith};
 
 pub struct IdentReplacer<'a> {
     from: &'a str,
@@ -402,3 +403,160 @@ pub fn angle_to_deg(value: f64, from: &Atom) -> f64 {
         }
     }
 }
+
+/// A reference to an external resource, e.g. `url(image.png)` or a string in
+/// `image-set("image.png" 1x)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UrlReference {
+    /// The url, without quotes and escapes.
+    pub url: Atom,
+    /// The span of `url()` or the string.
+    pub span: Span,
+    pub kind: UrlReferenceKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlReferenceKind {
+    /// `url()`
+    Url,
+    /// A string in `image-set()`
+    ImageSet,
+}
+
+fn is_image_set(function: &Function) -> bool {
+    function.name == "image-set" || function.name == "-webkit-image-set"
+}
+
+fn get_url_value(url: &Url) -> Option<&Atom> {
+    match url.value.as_deref()? {
+        UrlValue::Str(s) => Some(&s.value),
+        UrlValue::Raw(raw) => Some(&raw.value),
+    }
+}
+
+#[derive(Default)]
+pub struct UrlCollector {
+    urls: Vec<UrlReference>,
+}
+
+impl Visit for UrlCollector {
+    // Urls of `@import` and `@namespace` are not assets
+    fn visit_import_prelude(&mut self, _: &ImportPrelude) {}
+
+    fn visit_namespace_prelude(&mut self, _: &NamespacePrelude) {}
+
+    fn visit_url(&mut self, n: &Url) {
+        if let Some(url) = get_url_value(n) {
+            self.urls.push(UrlReference {
+                url: url.clone(),
+                span: n.span,
+                kind: UrlReferenceKind::Url,
+            });
+        }
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        if is_image_set(n) {
+            for value in &n.value {
+                if let ComponentValue::Str(s) = value {
+                    self.urls.push(UrlReference {
+                        url: s.value.clone(),
+                        span: s.span,
+                        kind: UrlReferenceKind::ImageSet,
+                    });
+                }
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Returns all urls referenced by `node`, in source order.
+///
+/// Urls of `@import` and `@namespace` rules are not included.
+pub fn collect_urls<N>(node: &N) -> Vec<UrlReference>
+where
+    N: VisitWith<UrlCollector>,
+{
+    let mut collector = UrlCollector::default();
+
+    node.visit_with(&mut collector);
+
+    collector.urls
+}
+
+pub struct UrlRewriter<F>
+where
+    F: FnMut(&UrlReference) -> Option<Atom>,
+{
+    op: F,
+}
+
+impl<F> VisitMut for UrlRewriter<F>
+where
+    F: FnMut(&UrlReference) -> Option<Atom>,
+{
+    fn visit_mut_import_prelude(&mut self, _: &mut ImportPrelude) {}
+
+    fn visit_mut_namespace_prelude(&mut self, _: &mut NamespacePrelude) {}
+
+    fn visit_mut_url(&mut self, n: &mut Url) {
+        let Some(url) = get_url_value(n) else {
+            return;
+        };
+        let reference = UrlReference {
+            url: url.clone(),
+            span: n.span,
+            kind: UrlReferenceKind::Url,
+        };
+
+        if let Some(new_url) = (self.op)(&reference) {
+            match n.value.as_deref_mut() {
+                Some(UrlValue::Raw(raw)) => {
+                    raw.value = new_url;
+                    raw.raw = None;
+                }
+                Some(UrlValue::Str(s)) => {
+                    s.value = new_url;
+                    s.raw = None;
+                }
+                None => {}
+            }
+        }
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        if is_image_set(n) {
+            for value in &mut n.value {
+                if let ComponentValue::Str(s) = value {
+                    let reference = UrlReference {
+                        url: s.value.clone(),
+                        span: s.span,
+                        kind: UrlReferenceKind::ImageSet,
+                    };
+
+                    if let Some(new_url) = (self.op)(&reference) {
+                        s.value = new_url;
+                        s.raw = None;
+                    }
+                }
+            }
+        }
+
+        n.visit_mut_children_with(self);
+    }
+}
+
+/// Rewrites urls referenced by `node`, e.g. to add hashes or a public path.
+///
+/// `op` is called for each url in source order, and the url is replaced if it
+/// returns a new one. Urls of `@import` and `@namespace` rules are not
+/// rewritten.
+pub fn rewrite_urls<N, F>(node: &mut N, op: F)
+where
+    N: VisitMutWith<UrlRewriter<F>>,
+    F: FnMut(&UrlReference) -> Option<Atom>,
+{
+    node.visit_mut_with(&mut UrlRewriter { op });
+}
@@ -0,0 +1,259 @@
+//! Compact binary representation of [Program], used to transfer an ast across
+//! boundaries like the node binding or plugins without going through json.
+//!
+//! The format is a 16-byte header followed by the rkyv archive of the
+//! [Program]. The header contains [MAGIC] and [SCHEMA_VERSION], so a reader can
+//! reject data written with an incompatible layout of the ast instead of
+//! misinterpreting it.
+//!
+//! The archive can be read without deserializing it, using
+//! [BinaryProgram::access].
+
+use std::fmt;
+
+use rkyv::{util::AlignedVec, Archive};
+
+use crate::Program;
+
+/// First bytes of the binary representation.
+pub const MAGIC: [u8; 4] = *b"SWCB";
+
+/// Version of the layout of the ast.
+///
+/// This should be bumped when a change to ast nodes modifies the layout of
+/// the archive.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Length of the header. This is a multiple of the alignment of
+/// [AlignedVec], so the archive following the header is aligned.
+const HEADER_LEN: usize = 16;
+
+/// A [Program] in the binary representation.
+#[derive(Clone)]
+pub struct BinaryProgram {
+    bytes: AlignedVec,
+}
+
+impl BinaryProgram {
+    pub fn serialize(program: &Program) -> Result<Self, BinaryError> {
+        let mut bytes = AlignedVec::with_capacity(HEADER_LEN);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&SCHEMA_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&[0; HEADER_LEN - 8]);
+
+        let bytes = rkyv::api::high::to_bytes_in::<_, rancor::Error>(program, bytes)
+            .map_err(BinaryError::Rkyv)?;
+
+        Ok(BinaryProgram { bytes })
+    }
+
+    /// Copies `bytes` into an aligned buffer, after checking the header.
+    ///
+    /// The archive itself is validated by [BinaryProgram::access].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, BinaryError> {
+        if bytes.len() < HEADER_LEN || bytes[..4] != MAGIC {
+            return Err(BinaryError::InvalidHeader);
+        }
+
+        let version = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if version != SCHEMA_VERSION {
+            return Err(BinaryError::UnsupportedVersion { found: version });
+        }
+
+        let mut aligned = AlignedVec::with_capacity(bytes.len());
+        aligned.extend_from_slice(bytes);
+
+        Ok(BinaryProgram { bytes: aligned })
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Validates the archive and returns it without deserializing it.
+    pub fn access(&self) -> Result<&<Program as Archive>::Archived, BinaryError> {
+        rkyv::access::<<Program as Archive>::Archived, rancor::Error>(&self.bytes[HEADER_LEN..])
+            .map_err(BinaryError::Rkyv)
+    }
+
+    pub fn deserialize(&self) -> Result<Program, BinaryError> {
+        let archived = self.access()?;
+
+        rkyv::deserialize::<Program, rancor::Error>(archived).map_err(BinaryError::Rkyv)
+    }
+}
+
+impl fmt::Debug for BinaryProgram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinaryProgram")
+            .field("len", &self.bytes.len())
+            .finish()
+    }
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum BinaryError {
+    /// The data does not start with [MAGIC].
+    InvalidHeader,
+    /// The data was written with another [SCHEMA_VERSION].
+    UnsupportedVersion {
+        found: u32,
+    },
+    Rkyv(rancor::Error),
+}
+
+impl fmt::Display for BinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryError::InvalidHeader => f.write_str("invalid header of binary ast"),
+            BinaryError::UnsupportedVersion { found } => write!(
+                f,
+                "unsupported schema version of binary ast: expected {}, found {}",
+                SCHEMA_VERSION, found
+            ),
+            BinaryError::Rkyv(err) => write!(f, "invalid binary ast: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for BinaryError {}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::DUMMY_SP;
+
+    use super::*;
+    use crate::*;
+
+    fn program() -> Program {
+        Program::Module(Module {
+            span: DUMMY_SP,
+            body: vec![ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                span: DUMMY_SP,
+                expr: Box::new(Expr::Ident(Ident::new_no_ctxt("foo".into(), DUMMY_SP))),
+            }))],
+            shebang: None,
+        })
+    }
+
+    /// Fingerprints of the layout of the archive, for each [SCHEMA_VERSION].
+    ///
+    /// If [layout_matches_schema_version] fails, the layout of the ast was
+    /// changed. Bump [SCHEMA_VERSION] and append an entry for it, instead of
+    /// editing an existing one.
+    const LAYOUTS: &[(u32, u64)] = &[(1, 0x0cd4_91a7_d28e_9e04), (2, 0xd894_ef1d_1917_7904)];
+
+    /// A program with some common nodes and the last variant of [Expr], so
+    /// that adding or reordering variants changes the archive.
+    fn layout_program() -> Program {
+        let ident = |sym: &str| Ident::new_no_ctxt(sym.into(), DUMMY_SP);
+        let invalid = || Box::new(Expr::Invalid(Invalid { span: DUMMY_SP }));
+
+        Program::Module(Module {
+            span: DUMMY_SP,
+            body: vec![
+                ModuleItem::Stmt(Stmt::Decl(Decl::Var(Box::new(VarDecl {
+                    kind: VarDeclKind::Let,
+                    decls: vec![VarDeclarator {
+                        span: DUMMY_SP,
+                        name: Pat::Ident(ident("a").into()),
+                        init: Some(Box::new(Expr::Lit(Lit::Num(1.0.into())))),
+                        definite: false,
+                    }],
+                    ..Default::default()
+                })))),
+                ModuleItem::Stmt(Stmt::Expr(ExprStmt {
+                    span: DUMMY_SP,
+                    expr: Box::new(Expr::Call(CallExpr {
+                        callee: Callee::Expr(Box::new(Expr::Ident(ident("f")))),
+                        args: vec![ExprOrSpread {
+                            spread: None,
+                            expr: Box::new(Expr::Bin(BinExpr {
+                                span: DUMMY_SP,
+                                op: BinaryOp::NullishCoalescing,
+                                left: Box::new(Expr::Lit(Lit::Str("s".into()))),
+                                right: invalid(),
+                            })),
+                        }],
+                        ..Default::default()
+                    })),
+                })),
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    span: DUMMY_SP,
+                    expr: invalid(),
+                })),
+            ],
+            shebang: None,
+        })
+    }
+
+    fn layout_fingerprint() -> u64 {
+        let binary = BinaryProgram::serialize(&layout_program()).unwrap();
+
+        let mut data = binary.as_bytes()[HEADER_LEN..].to_vec();
+        for size in [
+            size_of::<<Program as Archive>::Archived>(),
+            size_of::<<Stmt as Archive>::Archived>(),
+            size_of::<<Expr as Archive>::Archived>(),
+            size_of::<<Pat as Archive>::Archived>(),
+            size_of::<<ModuleDecl as Archive>::Archived>(),
+        ] {
+            data.extend_from_slice(&(size as u32).to_le_bytes());
+        }
+
+        // FNV-1a, which doesn't depend on the platform or the compiler.
+        data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+            (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+
+    #[test]
+    fn layout_matches_schema_version() {
+        for (i, (version, _)) in LAYOUTS.iter().enumerate() {
+            assert_eq!(
+                *version as usize,
+                i + 1,
+                "versions in LAYOUTS must be consecutive"
+            );
+        }
+
+        let fingerprint = layout_fingerprint();
+        assert_eq!(
+            LAYOUTS.last(),
+            Some(&(SCHEMA_VERSION, fingerprint)),
+            "the layout of the ast changed; bump SCHEMA_VERSION and add ({}, {:#x}) to LAYOUTS",
+            SCHEMA_VERSION + 1,
+            fingerprint
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let program = program();
+        let binary = BinaryProgram::serialize(&program).unwrap();
+
+        let binary = BinaryProgram::from_bytes(binary.as_bytes()).unwrap();
+        assert!(matches!(
+            binary.access().unwrap(),
+            crate::module::ArchivedProgram::Module(..)
+        ));
+        assert_eq!(binary.deserialize().unwrap(), program);
+    }
+
+    #[test]
+    fn version_mismatch() {
+        let binary = BinaryProgram::serialize(&program()).unwrap();
+        let mut bytes = binary.as_bytes().to_vec();
+        bytes[4] = 0;
+
+        assert!(matches!(
+            BinaryProgram::from_bytes(&bytes),
+            Err(BinaryError::UnsupportedVersion { found: 0 })
+        ));
+        assert!(matches!(
+            BinaryProgram::from_bytes(b"{}"),
+            Err(BinaryError::InvalidHeader)
+        ));
+    }
+}
@@ -16,6 +16,7 @@ use crate::{
     lit::Lit,
     operators::{AssignOp, BinaryOp, UnaryOp, UpdateOp},
     pat::Pat,
+    pattern_matching::MatchExpr,
     prop::Prop,
     stmt::BlockStmt,
     typescript::{
@@ -165,6 +166,24 @@ pub enum Expr {
     #[tag("OptionalChainingExpression")]
     OptChain(OptChainExpr),
 
+    /// Hack-style pipeline, like `a |> f(%)`.
+    #[tag("PipelineExpression")]
+    Pipeline(PipelineExpr),
+
+    /// `%` in the body of a pipeline.
+    #[tag("TopicReference")]
+    Topic(TopicExpr),
+
+    /// `match (subject) { ... }` of the pattern matching proposal.
+    #[tag("MatchExpression")]
+    #[is(name = "match_expr")]
+    Match(MatchExpr),
+
+    /// `do { ... }` of the do expressions proposal.
+    #[tag("DoExpression")]
+    #[is(name = "do_expr")]
+    Do(DoExpr),
+
     #[tag("Invalid")]
     Invalid(Invalid),
 }
@@ -375,6 +394,10 @@ impl Expr {
             Expr::JSXFragment(e) => e.span = span,
             Expr::PrivateName(e) => e.span = span,
             Expr::OptChain(e) => e.span = span,
+            Expr::Pipeline(e) => e.span = span,
+            Expr::Topic(e) => e.span = span,
+            Expr::Match(e) => e.span = span,
+            Expr::Do(e) => e.span = span,
             Expr::Lit(e) => e.set_span(span),
         }
     }
@@ -422,6 +445,10 @@ impl Clone for Expr {
             TsInstantiation(e) => TsInstantiation(e.clone()),
             PrivateName(e) => PrivateName(e.clone()),
             OptChain(e) => OptChain(e.clone()),
+            Pipeline(e) => Pipeline(e.clone()),
+            Topic(e) => Topic(e.clone()),
+            Match(e) => Match(e.clone()),
+            Do(e) => Do(e.clone()),
             Invalid(e) => Invalid(e.clone()),
             TsSatisfies(e) => TsSatisfies(e.clone()),
         }
@@ -488,6 +515,10 @@ boxed_expr!(TsAsExpr);
 boxed_expr!(TsInstantiation);
 boxed_expr!(PrivateName);
 boxed_expr!(OptChainExpr);
+boxed_expr!(PipelineExpr);
+boxed_expr!(TopicExpr);
+boxed_expr!(MatchExpr);
+boxed_expr!(DoExpr);
 boxed_expr!(Invalid);
 
 #[ast_node("ThisExpression")]
@@ -753,6 +784,60 @@ impl Take for BinExpr {
     }
 }
 
+/// Hack-style pipeline, like `a |> f(%)`.
+///
+/// `right` is evaluated with the value of `left` as the topic, which is
+/// referenced by [TopicExpr].
+#[ast_node("PipelineExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct PipelineExpr {
+    pub span: Span,
+
+    pub left: Box<Expr>,
+
+    pub right: Box<Expr>,
+}
+
+impl Take for PipelineExpr {
+    fn dummy() -> Self {
+        Default::default()
+    }
+}
+
+/// `%` in the body of a pipeline.
+#[ast_node("TopicReference")]
+#[derive(Eq, Hash, Copy, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct TopicExpr {
+    pub span: Span,
+}
+
+impl Take for TopicExpr {
+    fn dummy() -> Self {
+        Default::default()
+    }
+}
+
+/// `do { ... }`, which evaluates to the completion value of the block.
+#[ast_node("DoExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct DoExpr {
+    pub span: Span,
+
+    pub body: BlockStmt,
+}
+
+impl Take for DoExpr {
+    fn dummy() -> Self {
+        Default::default()
+    }
+}
+
 /// Function expression.
 #[ast_node("FunctionExpression")]
 #[derive(Eq, Hash, EqIgnoreSpan, Default)]
@@ -45,6 +45,11 @@ pub use self::{
     pat::{
         ArrayPat, AssignPat, AssignPatProp, KeyValuePatProp, ObjectPat, ObjectPatProp, Pat, RestPat,
     },
+    pattern_matching::{
+        ArrayMatchPat, BindingMatchPat, CombinedMatchPat, MatchCase, MatchExpr, MatchPat,
+        MatchPatCombinator, MatchRest, NotMatchPat, ObjectMatchPat, ObjectMatchPatProp,
+        RelationalMatchPat, ValueMatchPat,
+    },
     prop::{
         AssignProp, ComputedPropName, GetterProp, KeyValueProp, MethodProp, Prop, PropName,
         SetterProp,
@@ -76,6 +81,9 @@ pub use self::{
 
 #[macro_use]
 mod macros;
+#[cfg(feature = "rkyv-impl")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rkyv-impl")))]
+pub mod binary;
 mod class;
 mod decl;
 mod expr;
@@ -88,6 +96,7 @@ mod module;
 mod module_decl;
 mod operators;
 mod pat;
+mod pattern_matching;
 mod prop;
 mod source_map;
 mod stmt;
@@ -215,6 +224,11 @@ macro_rules! impl_pass_for_tuple {
                 self.$idx.process(program);
 
                 $(
+                    // Remaining passes are skipped, as the output will be discarded.
+                    if swc_common::cancel::is_cancelled() {
+                        return;
+                    }
+
                     self.$idx_rest.process(program);
                 )*
 
@@ -421,13 +435,14 @@ pub use self::{
     expr::{
         ArchivedArrayLit, ArchivedArrowExpr, ArchivedAssignExpr, ArchivedAssignTarget,
         ArchivedAwaitExpr, ArchivedBinExpr, ArchivedBlockStmtOrExpr, ArchivedCallExpr,
-        ArchivedCallee, ArchivedClassExpr, ArchivedCondExpr, ArchivedExpr, ArchivedExprOrSpread,
-        ArchivedFnExpr, ArchivedImport, ArchivedMemberExpr, ArchivedMemberProp,
-        ArchivedMetaPropExpr, ArchivedMetaPropKind, ArchivedNewExpr, ArchivedObjectLit,
-        ArchivedOptCall, ArchivedOptChainBase, ArchivedOptChainExpr, ArchivedParenExpr,
-        ArchivedPropOrSpread, ArchivedSeqExpr, ArchivedSpreadElement, ArchivedSuper,
-        ArchivedSuperProp, ArchivedSuperPropExpr, ArchivedTaggedTpl, ArchivedThisExpr, ArchivedTpl,
-        ArchivedTplElement, ArchivedUnaryExpr, ArchivedUpdateExpr, ArchivedYieldExpr,
+        ArchivedCallee, ArchivedClassExpr, ArchivedCondExpr, ArchivedDoExpr, ArchivedExpr,
+        ArchivedExprOrSpread, ArchivedFnExpr, ArchivedImport, ArchivedMemberExpr,
+        ArchivedMemberProp, ArchivedMetaPropExpr, ArchivedMetaPropKind, ArchivedNewExpr,
+        ArchivedObjectLit, ArchivedOptCall, ArchivedOptChainBase, ArchivedOptChainExpr,
+        ArchivedParenExpr, ArchivedPipelineExpr, ArchivedPropOrSpread, ArchivedSeqExpr,
+        ArchivedSpreadElement, ArchivedSuper, ArchivedSuperProp, ArchivedSuperPropExpr,
+        ArchivedTaggedTpl, ArchivedThisExpr, ArchivedTopicExpr, ArchivedTpl, ArchivedTplElement,
+        ArchivedUnaryExpr, ArchivedUpdateExpr, ArchivedYieldExpr,
     },
     function::{ArchivedFunction, ArchivedParam, ArchivedParamOrTsParamProp},
     ident::{ArchivedBindingIdent, ArchivedIdent, ArchivedIdentName, ArchivedPrivateName},
@@ -457,6 +472,12 @@ pub use self::{
         ArchivedArrayPat, ArchivedAssignPat, ArchivedAssignPatProp, ArchivedKeyValuePatProp,
         ArchivedObjectPat, ArchivedObjectPatProp, ArchivedPat, ArchivedRestPat,
     },
+    pattern_matching::{
+        ArchivedArrayMatchPat, ArchivedBindingMatchPat, ArchivedCombinedMatchPat,
+        ArchivedMatchCase, ArchivedMatchExpr, ArchivedMatchPat, ArchivedMatchPatCombinator,
+        ArchivedMatchRest, ArchivedNotMatchPat, ArchivedObjectMatchPat, ArchivedObjectMatchPatProp,
+        ArchivedRelationalMatchPat, ArchivedValueMatchPat,
+    },
     prop::{
         ArchivedAssignProp, ArchivedComputedPropName, ArchivedGetterProp, ArchivedKeyValueProp,
         ArchivedMethodProp, ArchivedProp, ArchivedPropName, ArchivedSetterProp,
@@ -0,0 +1,211 @@
+//! Nodes of the [pattern matching proposal](https://github.com/tc39/proposal-pattern-matching).
+
+use is_macro::Is;
+use string_enum::StringEnum;
+use swc_common::{ast_node, util::take::Take, EqIgnoreSpan, Span};
+
+use crate::{
+    decl::VarDeclKind, expr::Expr, ident::BindingIdent, operators::BinaryOp, prop::PropName,
+};
+
+/// `match (subject) { when pattern: body; default: body; }`
+#[ast_node("MatchExpression")]
+#[derive(Eq, Hash, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct MatchExpr {
+    pub span: Span,
+
+    pub subject: Box<Expr>,
+
+    pub cases: Vec<MatchCase>,
+}
+
+impl Take for MatchExpr {
+    fn dummy() -> Self {
+        Default::default()
+    }
+}
+
+#[ast_node("MatchCase")]
+#[derive(Eq, Hash, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct MatchCase {
+    pub span: Span,
+
+    /// [None] for `default`.
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub pat: Option<Box<MatchPat>>,
+
+    /// `if (guard)`
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub guard: Option<Box<Expr>>,
+
+    pub body: Box<Expr>,
+}
+
+#[ast_node]
+#[derive(Eq, Hash, Is, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub enum MatchPat {
+    #[tag("MatchBindingPattern")]
+    Binding(BindingMatchPat),
+
+    #[tag("MatchValuePattern")]
+    Value(ValueMatchPat),
+
+    #[tag("MatchRelationalPattern")]
+    Relational(RelationalMatchPat),
+
+    #[tag("MatchArrayPattern")]
+    Array(ArrayMatchPat),
+
+    #[tag("MatchObjectPattern")]
+    Object(ObjectMatchPat),
+
+    #[tag("MatchCombinedPattern")]
+    Combined(CombinedMatchPat),
+
+    #[tag("MatchNotPattern")]
+    Not(NotMatchPat),
+}
+
+/// `let x`, `const x` or `var x`, which always matches and binds the value.
+#[ast_node("MatchBindingPattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct BindingMatchPat {
+    pub span: Span,
+
+    pub kind: VarDeclKind,
+
+    pub id: BindingIdent,
+}
+
+/// A literal or a reference like `Foo.bar`, which matches values equal to it.
+#[ast_node("MatchValuePattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct ValueMatchPat {
+    pub span: Span,
+
+    pub value: Box<Expr>,
+}
+
+/// `< 10` or `instanceof Foo`, which matches if `subject op value` is truthy.
+#[ast_node("MatchRelationalPattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct RelationalMatchPat {
+    pub span: Span,
+
+    #[cfg_attr(feature = "serde-impl", serde(rename = "operator"))]
+    pub op: BinaryOp,
+
+    pub value: Box<Expr>,
+}
+
+/// `[a, b, ...rest]`
+#[ast_node("MatchArrayPattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct ArrayMatchPat {
+    pub span: Span,
+
+    #[cfg_attr(feature = "serde-impl", serde(rename = "elements"))]
+    pub elems: Vec<MatchPat>,
+
+    /// Without it, arrays with more elements don't match.
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub rest: Option<MatchRest>,
+}
+
+/// `...` or `...let rest` at the end of an [ArrayMatchPat].
+#[ast_node("MatchRestElement")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct MatchRest {
+    pub span: Span,
+
+    /// Matched against the remaining elements.
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub arg: Option<Box<MatchPat>>,
+}
+
+/// `{ status: 200, let body }`
+#[ast_node("MatchObjectPattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct ObjectMatchPat {
+    pub span: Span,
+
+    #[cfg_attr(feature = "serde-impl", serde(rename = "properties"))]
+    pub props: Vec<ObjectMatchPatProp>,
+}
+
+/// `key: pattern`, `let key` or `key`.
+#[ast_node("MatchObjectProperty")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct ObjectMatchPatProp {
+    pub span: Span,
+
+    pub key: PropName,
+
+    /// [None] if the property only has to exist. `let key` is stored as a
+    /// [BindingMatchPat].
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub value: Option<Box<MatchPat>>,
+}
+
+/// `a and b` or `a or b`
+#[ast_node("MatchCombinedPattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct CombinedMatchPat {
+    pub span: Span,
+
+    #[cfg_attr(feature = "serde-impl", serde(rename = "operator"))]
+    pub op: MatchPatCombinator,
+
+    #[cfg_attr(feature = "serde-impl", serde(rename = "patterns"))]
+    pub pats: Vec<MatchPat>,
+}
+
+#[derive(StringEnum, Clone, Copy, Eq, PartialEq, PartialOrd, Ord, Hash, EqIgnoreSpan, Default)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(
+    any(feature = "rkyv-impl"),
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize)
+)]
+#[cfg_attr(feature = "rkyv-impl", derive(bytecheck::CheckBytes))]
+#[cfg_attr(feature = "rkyv-impl", repr(u32))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub enum MatchPatCombinator {
+    /// `and`
+    #[default]
+    And,
+    /// `or`
+    Or,
+}
+
+/// `not pattern`
+#[ast_node("MatchNotPattern")]
+#[derive(Eq, Hash, EqIgnoreSpan)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[cfg_attr(feature = "shrink-to-fit", derive(shrink_to_fit::ShrinkToFit))]
+pub struct NotMatchPat {
+    pub span: Span,
+
+    pub arg: Box<MatchPat>,
+}
@@ -51,6 +51,21 @@ pub struct Config {
     /// Defaults to `true`
     #[cfg_attr(feature = "serde-impl", serde(default = "true_by_default"))]
     pub reduce_escaped_newline: bool,
+
+    /// Line endings of the output.
+    ///
+    /// This is not used by the code generator itself. Pass
+    /// [LineEnding::as_str] to the writer, like
+    /// [JsWriter](crate::text_writer::JsWriter).
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub line_ending: LineEnding,
+
+    /// Preserves up to this number of blank lines between top-level
+    /// statements, if there are blank lines in the original source.
+    ///
+    /// This is ignored if `minify` is true. Defaults to `0`.
+    #[cfg_attr(feature = "serde-impl", serde(default))]
+    pub preserve_blank_lines: u32,
 }
 
 impl Default for Config {
@@ -63,6 +78,8 @@ impl Default for Config {
             emit_assert_for_import_attributes: false,
             inline_script: false,
             reduce_escaped_newline: true,
+            line_ending: Default::default(),
+            preserve_blank_lines: 0,
         }
     }
 }
@@ -109,4 +126,43 @@ impl Config {
         self.reduce_escaped_newline = reduce_escaped_newline;
         self
     }
+
+    pub fn with_line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    pub fn with_preserve_blank_lines(mut self, preserve_blank_lines: u32) -> Self {
+        self.preserve_blank_lines = preserve_blank_lines;
+        self
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde-impl", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde-impl", serde(rename_all = "lowercase"))]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+    /// `\r\n` on windows and `\n` on other platforms.
+    Platform,
+}
+
+impl LineEnding {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Platform => {
+                if cfg!(windows) {
+                    "\r\n"
+                } else {
+                    "\n"
+                }
+            }
+        }
+    }
 }
@@ -19,7 +19,7 @@ use swc_common::{
 use swc_ecma_ast::*;
 use swc_ecma_codegen_macros::node_impl;
 
-pub use self::config::Config;
+pub use self::config::{Config, LineEnding};
 use self::{text_writer::WriteJs, util::StartsWithAlphaNum};
 use crate::util::EndsWithAlphaNum;
 
@@ -35,6 +35,7 @@ mod lit;
 mod module_decls;
 mod object;
 mod pat;
+mod pattern_matching;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -147,6 +148,41 @@ where
         node.emit_with(self)
     }
 
+    /// Emits blank lines between two top-level statements, as many as the
+    /// original source has, up to [Config::preserve_blank_lines].
+    fn emit_blank_lines_between(&mut self, prev: Span, next: Span) -> Result {
+        if self.cfg.minify
+            || self.cfg.preserve_blank_lines == 0
+            || prev.is_dummy()
+            || next.is_dummy()
+            || prev.hi >= next.lo
+        {
+            return Ok(());
+        }
+
+        let snippet = match self.cm.span_to_snippet(Span::new(prev.hi, next.lo)) {
+            Ok(v) => v,
+            Err(_) => return Ok(()),
+        };
+
+        // The first line is the rest of the line of `prev` and the last line is
+        // the indentation of `next`.
+        let lines = snippet.split('\n').collect::<Vec<_>>();
+        if lines.len() < 3 {
+            return Ok(());
+        }
+        let blank_lines = lines[1..lines.len() - 1]
+            .iter()
+            .filter(|line| line.trim().is_empty())
+            .count();
+
+        for _ in 0..blank_lines.min(self.cfg.preserve_blank_lines as usize) {
+            self.wr.write_blank_line()?;
+        }
+
+        Ok(())
+    }
+
     fn emit_new(&mut self, node: &NewExpr, should_ignore_empty_args: bool) -> Result {
         self.wr.commit_pending_semi()?;
 
@@ -242,6 +278,8 @@ where
                         (op, node.op),
                         (op!("--"), op!(">") | op!(">>") | op!(">>>") | op!(">="))
                     ),
+                    // `%==` is lexed as `%=` and `=`
+                    Expr::Topic(..) => matches!(node.op, op!("==") | op!("===")),
                     _ => false,
                 }
             }
@@ -1428,7 +1466,10 @@ impl MacroNode for Module {
             emitter.wr.write_str_lit(DUMMY_SP, shebang)?;
             emitter.wr.write_line()?;
         }
-        for stmt in &self.body {
+        for (i, stmt) in self.body.iter().enumerate() {
+            if i > 0 {
+                emitter.emit_blank_lines_between(self.body[i - 1].span(), stmt.span())?;
+            }
             emit!(stmt);
         }
 
@@ -1456,7 +1497,10 @@ impl MacroNode for Script {
             emitter.wr.write_str_lit(DUMMY_SP, shebang)?;
             emitter.wr.write_line()?;
         }
-        for stmt in &self.body {
+        for (i, stmt) in self.body.iter().enumerate() {
+            if i > 0 {
+                emitter.emit_blank_lines_between(self.body[i - 1].span(), stmt.span())?;
+            }
             emit!(stmt);
         }
 
@@ -1573,6 +1617,10 @@ impl MacroNode for Expr {
             Expr::TsConstAssertion(n) => emit!(n),
             Expr::TsInstantiation(n) => emit!(n),
             Expr::OptChain(n) => emit!(n),
+            Expr::Pipeline(n) => emit!(n),
+            Expr::Topic(n) => emit!(n),
+            Expr::Match(n) => emit!(n),
+            Expr::Do(n) => emit!(n),
             Expr::Invalid(n) => emit!(n),
             Expr::TsSatisfies(n) => {
                 emit!(n)
@@ -1955,6 +2003,53 @@ impl MacroNode for CondExpr {
     }
 }
 
+#[node_impl]
+impl MacroNode for PipelineExpr {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        emit!(self.left);
+        formatting_space!(emitter);
+        operator!(emitter, "|>");
+        formatting_space!(emitter);
+        emit!(self.right);
+
+        srcmap!(emitter, self, false);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for TopicExpr {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        punct!(emitter, "%");
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for DoExpr {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        keyword!(emitter, "do");
+        formatting_space!(emitter);
+        emit!(self.body);
+
+        Ok(())
+    }
+}
+
 #[node_impl]
 impl MacroNode for FnExpr {
     fn emit(&mut self, emitter: &mut Macro) -> Result {
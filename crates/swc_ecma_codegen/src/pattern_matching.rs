@@ -0,0 +1,271 @@
+use swc_common::{SourceMapper, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_codegen_macros::node_impl;
+
+use super::{Emitter, Result};
+use crate::text_writer::WriteJs;
+
+impl<W, S: SourceMapper> Emitter<'_, W, S>
+where
+    W: WriteJs,
+    S: SourceMapperExt,
+{
+    /// Emits an operand of `and`, `or` or `not`, which can't be a combined
+    /// pattern without parentheses.
+    fn emit_match_pat_operand(&mut self, pat: &MatchPat) -> Result {
+        if pat.is_combined() {
+            punct!(self, "(");
+            emit!(self, pat);
+            punct!(self, ")");
+        } else {
+            emit!(self, pat);
+        }
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for MatchExpr {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        keyword!(emitter, "match");
+        formatting_space!(emitter);
+
+        punct!(emitter, "(");
+        emit!(self.subject);
+        punct!(emitter, ")");
+        formatting_space!(emitter);
+
+        punct!(emitter, "{");
+        emitter.emit_list(self.span(), Some(&self.cases), ListFormat::CaseBlockClauses)?;
+
+        srcmap!(emitter, self, false, true);
+        punct!(emitter, "}");
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for MatchCase {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        match &self.pat {
+            Some(pat) => {
+                keyword!(emitter, "when");
+                space!(emitter);
+                emit!(pat);
+            }
+            None => keyword!(emitter, "default"),
+        }
+
+        if let Some(guard) = &self.guard {
+            space!(emitter);
+            keyword!(emitter, "if");
+            formatting_space!(emitter);
+            punct!(emitter, "(");
+            emit!(guard);
+            punct!(emitter, ")");
+        }
+
+        punct!(emitter, ":");
+        formatting_space!(emitter);
+        emit!(self.body);
+        punct!(emitter, ";");
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for MatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        match self {
+            MatchPat::Binding(n) => emit!(n),
+            MatchPat::Value(n) => emit!(n),
+            MatchPat::Relational(n) => emit!(n),
+            MatchPat::Array(n) => emit!(n),
+            MatchPat::Object(n) => emit!(n),
+            MatchPat::Combined(n) => emit!(n),
+            MatchPat::Not(n) => emit!(n),
+        }
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for BindingMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        keyword!(emitter, self.kind.as_str());
+        space!(emitter);
+        emit!(self.id);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for ValueMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        emit!(self.value);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for RelationalMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        operator!(emitter, self.op.as_str());
+        space!(emitter);
+        emit!(self.value);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for ArrayMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        punct!(emitter, "[");
+        emitter.emit_list(
+            self.span(),
+            Some(&self.elems),
+            ListFormat::ArrayBindingPatternElements,
+        )?;
+        if let Some(rest) = &self.rest {
+            if !self.elems.is_empty() {
+                punct!(emitter, ",");
+                formatting_space!(emitter);
+            }
+            emit!(rest);
+        }
+        punct!(emitter, "]");
+
+        srcmap!(emitter, self, false);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for MatchRest {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        punct!(emitter, "...");
+        emit!(self.arg);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for ObjectMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        punct!(emitter, "{");
+        emitter.emit_list(
+            self.span(),
+            Some(&self.props),
+            ListFormat::ObjectBindingPatternElements | ListFormat::CanSkipTrailingComma,
+        )?;
+        punct!(emitter, "}");
+
+        srcmap!(emitter, self, false);
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for ObjectMatchPatProp {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        // `let key` is the shorthand of `key: let key`
+        if let (PropName::Ident(key), Some(value)) = (&self.key, &self.value) {
+            if let MatchPat::Binding(binding) = &**value {
+                if binding.id.sym == key.sym {
+                    emit!(binding);
+                    return Ok(());
+                }
+            }
+        }
+
+        emit!(self.key);
+        if let Some(value) = &self.value {
+            punct!(emitter, ":");
+            formatting_space!(emitter);
+            emit!(value);
+        }
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for CombinedMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        for (i, pat) in self.pats.iter().enumerate() {
+            if i > 0 {
+                space!(emitter);
+                keyword!(emitter, self.op.as_str());
+                space!(emitter);
+            }
+            emitter.emit_match_pat_operand(pat)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[node_impl]
+impl MacroNode for NotMatchPat {
+    fn emit(&mut self, emitter: &mut Macro) -> Result {
+        emitter.emit_leading_comments_of_span(self.span(), false)?;
+
+        srcmap!(emitter, self, true);
+
+        keyword!(emitter, "not");
+        space!(emitter);
+        emitter.emit_match_pat_operand(&self.arg)?;
+
+        Ok(())
+    }
+}
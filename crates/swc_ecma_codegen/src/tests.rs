@@ -22,7 +22,8 @@ impl Builder {
         F: for<'aa> FnOnce(&mut Emitter<'aa, Box<(dyn WriteJs + 'aa)>, SourceMap>) -> Ret,
         Ret: 'static,
     {
-        let writer = text_writer::JsWriter::new(self.cm.clone(), "\n", s, None);
+        let writer =
+            text_writer::JsWriter::new(self.cm.clone(), self.cfg.line_ending.as_str(), s, None);
         let writer: Box<dyn WriteJs> = if self.cfg.minify {
             Box::new(omit_trailing_semi(writer))
         } else {
@@ -282,6 +283,63 @@ fn empty_named_export_from_min() {
     );
 }
 
+#[test]
+fn match_expr() {
+    let syntax = Syntax::Es(EsSyntax {
+        explicit_proposals: true,
+        ..Default::default()
+    });
+
+    test_from_to_custom_config(
+        "x = match (a) { when { status: 200, let body }: body; when [1, ...let rest] if (rest): \
+         rest; when not (< 0 or > 9): 1; default: (0, 2); }",
+        "x = match (a) {
+    when { status: 200, let body }: body;
+    when [1, ...let rest] if (rest): rest;
+    when not (< 0 or > 9): 1;
+    default: (0, 2);
+};",
+        Default::default(),
+        syntax,
+    );
+    test_from_to_custom_config(
+        "x = match (a) { when let b and { c }: b; default: 1; }",
+        "x=match(a){when let b and {c}:b;default:1}",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        syntax,
+    );
+}
+
+#[test]
+fn do_expr() {
+    let syntax = Syntax::Es(EsSyntax {
+        do_expressions: true,
+        ..Default::default()
+    });
+
+    test_from_to_custom_config(
+        "x = do { let y = 1; y }",
+        "x = do {
+    let y = 1;
+    y;
+};",
+        Default::default(),
+        syntax,
+    );
+    test_from_to_custom_config(
+        "x = do { let y = 1; y }",
+        "x=do{let y=1;y}",
+        Config {
+            minify: true,
+            ..Default::default()
+        },
+        syntax,
+    );
+}
+
 #[test]
 fn named_export_from() {
     test_from_to("export { bar } from 'foo';", "export { bar } from 'foo';");
@@ -988,6 +1046,47 @@ fn issue_9630() {
     );
 }
 
+#[test]
+fn line_ending_crlf() {
+    let out = parse_then_emit(
+        "function foo() {\n    return 1;\n}\nfoo();\n",
+        Config::default().with_line_ending(LineEnding::CrLf),
+        Default::default(),
+    );
+
+    assert_eq!(
+        DebugUsingDisplay(&out),
+        DebugUsingDisplay("function foo() {\r\n    return 1;\r\n}\r\nfoo();\r\n")
+    );
+}
+
+#[test]
+fn preserve_blank_lines() {
+    let src = "import a from 'a';\n\n\n\nconst b = 1;\n// comment\nconst c = 2;\n\nfunction d() \
+               {\n\n    return 1;\n}\n";
+
+    let out = parse_then_emit(
+        src,
+        Config::default().with_preserve_blank_lines(2),
+        Default::default(),
+    );
+    assert_eq!(
+        DebugUsingDisplay(&out),
+        DebugUsingDisplay(
+            "import a from 'a';\n\n\nconst b = 1;\n// comment\nconst c = 2;\n\nfunction d() {\n    return 1;\n}\n"
+        )
+    );
+
+    let out = parse_then_emit(src, Config::default(), Default::default());
+    assert_eq!(
+        DebugUsingDisplay(&out),
+        DebugUsingDisplay(
+            "import a from 'a';\nconst b = 1;\n// comment\nconst c = 2;\nfunction d() {\n    \
+             return 1;\n}\n"
+        )
+    );
+}
+
 #[testing::fixture("tests/str-lits/**/*.txt")]
 fn test_str_lit(input: PathBuf) {
     test_str_lit_inner(input)
@@ -48,6 +48,13 @@ pub trait WriteJs {
     fn can_ignore_invalid_unicodes(&mut self) -> bool {
         false
     }
+
+    /// Writes a line terminator even if the current line is empty.
+    ///
+    /// Defaults to [WriteJs::write_line].
+    fn write_blank_line(&mut self) -> Result {
+        self.write_line()
+    }
 }
 
 impl<W> WriteJs for Box<W>
@@ -99,6 +106,11 @@ where
         (**self).write_line()
     }
 
+    #[inline]
+    fn write_blank_line(&mut self) -> Result {
+        (**self).write_blank_line()
+    }
+
     #[inline]
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         (**self).write_lit(span, s)
@@ -198,6 +210,11 @@ where
         (**self).write_line()
     }
 
+    #[inline]
+    fn write_blank_line(&mut self) -> Result {
+        (**self).write_blank_line()
+    }
+
     #[inline]
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         (**self).write_lit(span, s)
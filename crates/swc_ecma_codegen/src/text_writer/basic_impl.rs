@@ -210,6 +210,13 @@ impl<W: Write> WriteJs for JsWriter<'_, W> {
         Ok(())
     }
 
+    fn write_blank_line(&mut self) -> Result {
+        self.write_line()?;
+        // `write_line` does nothing at the start of a line.
+        self.line_start = false;
+        self.write_line()
+    }
+
     #[inline]
     fn write_lit(&mut self, span: Span, s: &str) -> Result {
         if !s.is_empty() {
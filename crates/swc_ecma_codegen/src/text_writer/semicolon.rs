@@ -53,6 +53,8 @@ impl<W: WriteJs> WriteJs for OmitTrailingSemi<W> {
 
     with_semi!(write_line());
 
+    with_semi!(write_blank_line());
+
     with_semi!(write_lit(span: Span, s: &str));
 
     with_semi!(write_str_lit(span: Span, s: &str));
@@ -1032,7 +1032,11 @@ impl MacroNode for TsImportCallOptions {
             emitter.wr.increase_indent()?;
         }
 
-        keyword!(emitter, "with");
+        if emitter.cfg.emit_assert_for_import_attributes {
+            keyword!(emitter, "assert");
+        } else {
+            keyword!(emitter, "with")
+        };
         punct!(emitter, ":");
         formatting_space!(emitter);
         emit!(self.with);
@@ -181,9 +181,11 @@ impl StartsWithAlphaNum for Expr {
             | Expr::Yield(_)
             | Expr::New(_)
             | Expr::MetaProp(_)
-            | Expr::SuperProp(_) => true,
+            | Expr::SuperProp(_)
+            | Expr::Match(_)
+            | Expr::Do(_) => true,
 
-            Expr::PrivateName(_) => false,
+            Expr::PrivateName(_) | Expr::Topic(_) => false,
 
             // Handle other literals.
             Expr::Lit(_) => false,
@@ -196,9 +198,9 @@ impl StartsWithAlphaNum for Expr {
             //
             Expr::Assign(AssignExpr { ref left, .. }) => left.starts_with_alpha_num(),
 
-            Expr::Bin(BinExpr { ref left, .. }) | Expr::Cond(CondExpr { test: ref left, .. }) => {
-                left.starts_with_alpha_num()
-            }
+            Expr::Bin(BinExpr { ref left, .. })
+            | Expr::Cond(CondExpr { test: ref left, .. })
+            | Expr::Pipeline(PipelineExpr { ref left, .. }) => left.starts_with_alpha_num(),
             Expr::Call(CallExpr { callee: left, .. }) => left.starts_with_alpha_num(),
             Expr::Member(MemberExpr { obj: ref left, .. }) => left.starts_with_alpha_num(),
 
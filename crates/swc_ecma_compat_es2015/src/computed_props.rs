@@ -51,6 +51,9 @@ pub fn computed_properties(c: Config) -> impl Pass {
 pub struct Config {
     #[serde(default)]
     pub loose: bool,
+    /// Assigns computed properties instead of defining them.
+    #[serde(default)]
+    pub set_computed_properties: bool,
 }
 
 #[derive(Default)]
@@ -75,6 +78,8 @@ impl VisitMut for ComputedProps {
             let mark = Mark::fresh(Mark::root());
             let obj_ident = quote_ident!(SyntaxContext::empty().apply_mark(mark), *span, "_obj");
 
+            let set_computed_properties = self.c.loose || self.c.set_computed_properties;
+
             let mut exprs: Vec<Box<Expr>> = Vec::with_capacity(props.len() + 2);
             let mutator_map = quote_ident!(
                 SyntaxContext::empty().apply_mark(mark),
@@ -96,7 +101,7 @@ impl VisitMut for ComputedProps {
             );
 
             exprs.push(
-                if !self.c.loose && props_cnt == 1 && !self.used_define_enum_props {
+                if !set_computed_properties && props_cnt == 1 && !self.used_define_enum_props {
                     ObjectLit {
                         span: DUMMY_SP,
                         props: obj_props,
@@ -128,7 +133,7 @@ impl VisitMut for ComputedProps {
                     PropOrSpread::Prop(prop) => match *prop {
                         Prop::Shorthand(ident) => (
                             (
-                                if self.c.loose {
+                                if set_computed_properties {
                                     ident.clone().into()
                                 } else {
                                     Lit::Str(Str {
@@ -143,7 +148,7 @@ impl VisitMut for ComputedProps {
                             ident.into(),
                         ),
                         Prop::KeyValue(KeyValueProp { key, value }) => {
-                            (prop_name_to_expr(key, self.c.loose), *value)
+                            (prop_name_to_expr(key, set_computed_properties), *value)
                         }
                         Prop::Assign(..) => {
                             unreachable!("assign property in object literal is invalid")
@@ -242,7 +247,7 @@ impl VisitMut for ComputedProps {
                             // unimplemented!("getter /setter property")
                         }
                         Prop::Method(MethodProp { key, function }) => (
-                            prop_name_to_expr(key, self.c.loose),
+                            prop_name_to_expr(key, set_computed_properties),
                             FnExpr {
                                 ident: None,
                                 function,
@@ -253,7 +258,7 @@ impl VisitMut for ComputedProps {
                     PropOrSpread::Spread(..) => unimplemented!("computed spread property"),
                 };
 
-                if !self.c.loose && props_cnt == 1 {
+                if !set_computed_properties && props_cnt == 1 {
                     single_cnt_prop = Some(
                         CallExpr {
                             span,
@@ -265,7 +270,7 @@ impl VisitMut for ComputedProps {
                     );
                     break;
                 }
-                exprs.push(if self.c.loose {
+                exprs.push(if set_computed_properties {
                     let left = if is_compute {
                         obj_ident.clone().computed_member(key)
                     } else {
@@ -48,6 +48,9 @@ struct Destructuring {
 pub struct Config {
     #[serde(default)]
     pub loose: bool,
+    /// Destructures arrays by index instead of converting iterables.
+    #[serde(default)]
+    pub iterable_is_array: bool,
 }
 
 macro_rules! impl_for_for_stmt {
@@ -751,7 +754,7 @@ impl VisitMut for AssignFolder {
                             span: DUMMY_SP,
                             op: op!("="),
                             left: ref_ident.clone().into(),
-                            right: if self.c.loose {
+                            right: if self.c.loose || self.c.iterable_is_array {
                                 right.take()
                             } else {
                                 match &mut **right {
@@ -1172,7 +1175,7 @@ fn make_ref_ident_for_array(
             span,
             name: ref_ident.clone().into(),
             init: init.map(|v| {
-                if c.loose || matches!(*v, Expr::Array(..)) {
+                if c.loose || c.iterable_is_array || matches!(*v, Expr::Array(..)) {
                     v
                 } else {
                     match elem_cnt {
@@ -1257,7 +1260,10 @@ fn can_be_null(e: &Expr) -> bool {
         | Expr::New(..)
         | Expr::Yield(..)
         | Expr::Await(..)
-        | Expr::MetaProp(..) => true,
+        | Expr::MetaProp(..)
+        | Expr::Topic(..)
+        | Expr::Match(..)
+        | Expr::Do(..) => true,
 
         Expr::Lit(..) => false,
 
@@ -1274,7 +1280,8 @@ fn can_be_null(e: &Expr) -> bool {
         Expr::Seq(SeqExpr { ref exprs, .. }) => {
             exprs.last().map(|e| can_be_null(e)).unwrap_or(true)
         }
-        Expr::Assign(AssignExpr { ref right, .. }) => can_be_null(right),
+        Expr::Assign(AssignExpr { ref right, .. })
+        | Expr::Pipeline(PipelineExpr { ref right, .. }) => can_be_null(right),
         Expr::Cond(CondExpr {
             ref cons, ref alt, ..
         }) => can_be_null(cons) || can_be_null(alt),
@@ -60,6 +60,9 @@ pub fn for_of(c: Config) -> impl Pass {
 pub struct Config {
     pub loose: bool,
     pub assume_array: bool,
+    /// Doesn't call `return` of iterators when the loop exits early.
+    #[serde(default)]
+    pub skip_iterator_closing: bool,
 }
 
 struct ForOf {
@@ -216,7 +219,7 @@ impl ForOf {
         }
 
         // Loose mode
-        if self.c.loose {
+        if self.c.loose || self.c.skip_iterator_closing {
             let iterator = private_ident!("_iterator");
             let step = private_ident!("_step");
 
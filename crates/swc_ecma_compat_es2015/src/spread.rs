@@ -24,6 +24,9 @@ pub fn spread(c: Config) -> impl Pass {
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     pub loose: bool,
+    /// Spreads arrays with `concat` instead of converting iterables.
+    #[serde(default)]
+    pub iterable_is_array: bool,
 }
 
 /// es2015 - `SpreadElement`
@@ -264,7 +267,7 @@ impl Spread {
         // will be incorrectly flattened. The solution is to wrap every
         // contiguous slice of non-spread args in an array, which will protect
         // array args from being flattened.
-        if self.c.loose {
+        if self.c.loose || self.c.iterable_is_array {
             let mut arg_list = Vec::new();
             let mut current_elems = Vec::new();
             for arg in args.flatten() {
@@ -378,7 +381,7 @@ impl Spread {
                             }
                             _ => {
                                 if args_len == 1 && !need_array {
-                                    return if self.c.loose {
+                                    return if self.c.loose || self.c.iterable_is_array {
                                         *expr
                                     } else {
                                         to_consumable_array(expr, span).into()
@@ -386,7 +389,7 @@ impl Spread {
                                 }
                                 // [].concat(arr) is shorter than _to_consumable_array(arr)
                                 if args_len == 1 {
-                                    return if self.c.loose {
+                                    return if self.c.loose || self.c.iterable_is_array {
                                         CallExpr {
                                             span: DUMMY_SP,
                                             callee: ArrayLit {
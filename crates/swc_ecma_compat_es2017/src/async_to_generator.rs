@@ -52,6 +52,18 @@ pub struct Config {
     pub ignore_function_name: bool,
     #[serde(default)]
     pub ignore_function_length: bool,
+    /// Follows the specification more closely, at the cost of larger output.
+    ///
+    /// - The `length` of the wrapper of an async function with complex
+    ///   parameters counts the parameters before the first default or rest
+    ///   parameter, and identifier parameters keep their names, so the
+    ///   parameter list in `toString()` is preserved as far as possible.
+    /// - `for await` loops throw a `TypeError` if the iterator returns a result
+    ///   which isn't an object.
+    /// - `return` statements of async generators await their value before
+    ///   `finally` blocks run, so a rejection can be caught by the generator.
+    #[serde(default)]
+    pub spec: bool,
 }
 
 #[derive(Default, Clone, Debug)]
@@ -126,14 +138,23 @@ impl VisitMut for AsyncToGenerator {
             let mut params = vec![];
 
             if !self.c.ignore_function_length {
-                let fn_len = function
-                    .params
-                    .iter()
-                    .filter(|p| !matches!(p.pat, Pat::Assign(..) | Pat::Rest(..)))
-                    .count();
-                for i in 0..fn_len {
+                let has_length = |p: &&Param| !matches!(p.pat, Pat::Assign(..) | Pat::Rest(..));
+                let fn_params: Vec<_> = if self.c.spec {
+                    // Parameters after the first default or rest parameter
+                    // aren't counted by `length`.
+                    function.params.iter().take_while(has_length).collect()
+                } else {
+                    function.params.iter().filter(has_length).collect()
+                };
+
+                for (i, param) in fn_params.into_iter().enumerate() {
+                    let pat = match &param.pat {
+                        Pat::Ident(BindingIdent { id, .. }) if self.c.spec => id.clone().into(),
+                        _ => private_ident!(format!("_{}", i)).into(),
+                    };
+
                     params.push(Param {
-                        pat: private_ident!(format!("_{}", i)).into(),
+                        pat,
                         span: DUMMY_SP,
                         decorators: vec![],
                     });
@@ -385,7 +406,11 @@ impl VisitMut for AsyncToGenerator {
             ..
         }) = self.fn_state
         {
-            handle_await_for(stmt, is_generator);
+            if is_generator && self.c.spec {
+                await_return_value(stmt);
+            }
+
+            handle_await_for(stmt, is_generator, self.c.spec, self.unresolved_ctxt);
         }
     }
 
@@ -501,7 +526,12 @@ impl Check for ShouldWork {
 }
 
 #[tracing::instrument(level = "info", skip_all)]
-fn handle_await_for(stmt: &mut Stmt, is_async_generator: bool) {
+fn handle_await_for(
+    stmt: &mut Stmt,
+    is_async_generator: bool,
+    spec: bool,
+    unresolved_ctxt: SyntaxContext,
+) {
     let s = match stmt {
         Stmt::ForOf(s @ ForOfStmt { is_await: true, .. }) => s.take(),
         _ => return,
@@ -542,6 +572,13 @@ fn handle_await_for(stmt: &mut Stmt, is_async_generator: bool) {
                 .into(),
             );
         }
+        if spec {
+            // The iterator isn't closed if it returns an invalid result.
+            for_loop_body.insert(
+                0,
+                check_iter_result(&step, Some(&iterator_abrupt_completion), unresolved_ctxt),
+            );
+        }
 
         match s.left {
             ForHead::VarDecl(v) => {
@@ -657,7 +694,7 @@ fn handle_await_for(stmt: &mut Stmt, is_async_generator: bool) {
                 let assign_to_step: Expr = AssignExpr {
                     span: DUMMY_SP,
                     op: op!("="),
-                    left: step.into(),
+                    left: step.clone().into(),
                     right: YieldExpr {
                         span: DUMMY_SP,
                         arg: Some(yield_arg),
@@ -775,27 +812,39 @@ fn handle_await_for(stmt: &mut Stmt, is_async_generator: bool) {
         // yield _iterator.return();
         // or
         // yield _awaitAsyncGenerator(_iterator.return());
-        let yield_stmt = ExprStmt {
+        let yield_return: Box<Expr> = YieldExpr {
             span: DUMMY_SP,
-            expr: YieldExpr {
-                span: DUMMY_SP,
-                delegate: false,
-                arg: Some(if is_async_generator {
-                    CallExpr {
-                        span: DUMMY_SP,
-                        callee: helper!(await_async_generator),
-                        args: vec![iterator_return.as_arg()],
-                        ..Default::default()
-                    }
-                    .into()
-                } else {
-                    iterator_return.into()
-                }),
-            }
-            .into(),
+            delegate: false,
+            arg: Some(if is_async_generator {
+                CallExpr {
+                    span: DUMMY_SP,
+                    callee: helper!(await_async_generator),
+                    args: vec![iterator_return.as_arg()],
+                    ..Default::default()
+                }
+                .into()
+            } else {
+                iterator_return.into()
+            }),
         }
         .into();
 
+        let close_stmts = if spec {
+            // _step = yield _iterator.return();
+            vec![
+                AssignExpr {
+                    span: DUMMY_SP,
+                    op: op!("="),
+                    left: step.clone().into(),
+                    right: yield_return,
+                }
+                .into_stmt(),
+                check_iter_result(&step, None, unresolved_ctxt),
+            ]
+        } else {
+            vec![yield_return.into_stmt()]
+        };
+
         let conditional_yield = IfStmt {
             span: DUMMY_SP,
             // _iteratorAbruptCompletion && _iterator.return != null
@@ -817,7 +866,7 @@ fn handle_await_for(stmt: &mut Stmt, is_async_generator: bool) {
             }
             .into(),
             cons: Box::new(Stmt::Block(BlockStmt {
-                stmts: vec![yield_stmt],
+                stmts: close_stmts,
                 ..Default::default()
             })),
             alt: None,
@@ -890,3 +939,94 @@ fn handle_await_for(stmt: &mut Stmt, is_async_generator: bool) {
     }
     .into()
 }
+
+/// `if (Object(_step) !== _step) throw new TypeError(...);`
+///
+/// `abrupt_completion` is reset before throwing, so the iterator isn't closed.
+fn check_iter_result(
+    step: &Ident,
+    abrupt_completion: Option<&Ident>,
+    unresolved_ctxt: SyntaxContext,
+) -> Stmt {
+    let mut stmts = Vec::new();
+
+    if let Some(abrupt_completion) = abrupt_completion {
+        stmts.push(
+            AssignExpr {
+                span: DUMMY_SP,
+                op: op!("="),
+                left: abrupt_completion.clone().into(),
+                right: false.into(),
+            }
+            .into_stmt(),
+        );
+    }
+
+    stmts.push(
+        ThrowStmt {
+            span: DUMMY_SP,
+            arg: NewExpr {
+                span: DUMMY_SP,
+                callee: quote_ident!(unresolved_ctxt, "TypeError").into(),
+                args: Some(vec![BinExpr {
+                    span: DUMMY_SP,
+                    op: op!(bin, "+"),
+                    left: step.clone().into(),
+                    right: " is not an object".into(),
+                }
+                .as_arg()]),
+                ..Default::default()
+            }
+            .into(),
+        }
+        .into(),
+    );
+
+    IfStmt {
+        span: DUMMY_SP,
+        test: BinExpr {
+            span: DUMMY_SP,
+            op: op!("!=="),
+            left: quote_ident!(unresolved_ctxt, "Object")
+                .as_call(DUMMY_SP, vec![step.clone().as_arg()])
+                .into(),
+            right: step.clone().into(),
+        }
+        .into(),
+        cons: Box::new(
+            BlockStmt {
+                stmts,
+                ..Default::default()
+            }
+            .into(),
+        ),
+        alt: None,
+    }
+    .into()
+}
+
+/// Awaits the value of `return` statements in async generators, before
+/// `finally` blocks run.
+///
+/// `return x;` => `return yield _await_async_generator(x);`
+fn await_return_value(stmt: &mut Stmt) {
+    if let Stmt::Return(ReturnStmt {
+        span,
+        arg: Some(arg),
+    }) = stmt
+    {
+        let await_arg = CallExpr {
+            span: DUMMY_SP,
+            callee: helper!(await_async_generator),
+            args: vec![arg.take().as_arg()],
+            ..Default::default()
+        };
+
+        *arg = YieldExpr {
+            span: *span,
+            delegate: false,
+            arg: Some(await_arg.into()),
+        }
+        .into();
+    }
+}
@@ -0,0 +1,114 @@
+//! Emits declaration files (`.d.ts`) of TypeScript files without type
+//! checking.
+//!
+//! Only files which satisfy the constraints of the `isolatedDeclarations`
+//! option of `tsc` can be processed, as types are never inferred from other
+//! files. A [DtsIssue] is reported for every declaration which requires
+//! inference, like an exported function without a return type annotation.
+//!
+//! ```rust,ignore
+//! let fm = cm.load_file(Path::new("src/index.ts"))?;
+//! let output = swc_ecma_dts::emit(&fm, &Config::default())?;
+//!
+//! for issue in &output.issues {
+//!     handler.struct_span_err(issue.range.span, &issue.message).emit();
+//! }
+//! ```
+
+#![deny(clippy::all)]
+
+use swc_common::{comments::SingleThreadedComments, Mark, SourceFile, GLOBALS};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_codegen::to_code_with_comments;
+use swc_ecma_parser::{error::Error, parse_file_as_program, Syntax, TsSyntax};
+use swc_ecma_transforms_base::{fixer::paren_remover, resolver};
+pub use swc_typescript::diagnostic::{DtsIssue, SourceRange};
+use swc_typescript::fast_dts::{FastDts, FastDtsOptions};
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Parse the file as TSX.
+    pub tsx: bool,
+
+    /// Remove declarations annotated with `/** @internal */`, like the
+    /// `stripInternal` option of `tsc`.
+    pub strip_internal: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct Output {
+    /// Content of the `.d.ts` file.
+    pub code: String,
+
+    /// Declarations which can't be emitted without type inference.
+    ///
+    /// `code` is still emitted if there are issues, but the types of those
+    /// declarations may be missing or wrong.
+    pub issues: Vec<DtsIssue>,
+}
+
+/// Parses a TypeScript file and emits its declarations.
+///
+/// This should be called from [GLOBALS], as it creates [Mark]s.
+pub fn emit(fm: &SourceFile, config: &Config) -> Result<Output, Error> {
+    debug_assert!(GLOBALS.is_set(), "swc_ecma_dts::emit requires GLOBALS");
+
+    let comments = SingleThreadedComments::default();
+    let program = parse_file_as_program(
+        fm,
+        Syntax::Typescript(TsSyntax {
+            tsx: config.tsx,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        Some(&comments),
+        &mut Vec::new(),
+    )?;
+
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    let program = program
+        .apply(resolver(unresolved_mark, top_level_mark, true))
+        .apply(paren_remover(None));
+
+    Ok(emit_program(
+        program,
+        fm,
+        &comments,
+        unresolved_mark,
+        config,
+    ))
+}
+
+/// Emits declarations of a program processed by `resolver` with
+/// `typescript: true`.
+///
+/// `comments` should contain the comments of `fm`, which are preserved in the
+/// declarations.
+pub fn emit_program(
+    mut program: Program,
+    fm: &SourceFile,
+    comments: &SingleThreadedComments,
+    unresolved_mark: Mark,
+    config: &Config,
+) -> Output {
+    let internal_annotations = if config.strip_internal {
+        Some(FastDts::get_internal_annotations(comments))
+    } else {
+        None
+    };
+
+    let mut checker = FastDts::new(
+        fm.name.clone(),
+        unresolved_mark,
+        FastDtsOptions {
+            internal_annotations,
+        },
+    );
+    let issues = checker.transform(&mut program);
+
+    Output {
+        code: to_code_with_comments(Some(comments), &program),
+        issues,
+    }
+}
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use swc_ecma_dts::{emit, Config};
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/**/input.ts")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let config = Config {
+        strip_internal: dir.ends_with("internal"),
+        ..Default::default()
+    };
+
+    let mut code = String::new();
+    let res = testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).expect("failed to load test case");
+        let output = emit(&fm, &config).map_err(|err| err.into_diagnostic(&handler).emit())?;
+
+        for issue in &output.issues {
+            handler
+                .struct_span_err(issue.range.span, &issue.message)
+                .emit();
+        }
+        code = output.code;
+
+        if handler.has_errors() {
+            Err(())
+        } else {
+            Ok(())
+        }
+    });
+
+    NormalizedOutput::from(code)
+        .compare_to_file(dir.join("output.d.ts"))
+        .unwrap();
+
+    let stderr_path = dir.join("output.stderr");
+    match res {
+        Ok(()) => assert!(
+            !stderr_path.exists(),
+            "{} should not exist as there are no issues",
+            stderr_path.display()
+        ),
+        Err(stderr) => stderr.compare_to_file(stderr_path).unwrap(),
+    }
+}
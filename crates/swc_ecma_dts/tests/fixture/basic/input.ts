@@ -0,0 +1,24 @@
+import type { Options } from "./options";
+
+export interface Point {
+    x: number;
+    y: number;
+}
+
+export const origin: Point = { x: 0, y: 0 };
+
+export function distance(a: Point, b: Point = origin): number {
+    return Math.hypot(a.x - b.x, a.y - b.y);
+}
+
+export class Shape {
+    private cache = new Map<string, number>();
+
+    constructor(public readonly name: string, options?: Options) {}
+
+    area(): number {
+        return 0;
+    }
+}
+
+function helper() {}
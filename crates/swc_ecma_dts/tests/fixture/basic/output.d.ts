@@ -0,0 +1,13 @@
+import type { Options } from "./options";
+export interface Point {
+    x: number;
+    y: number;
+}
+export declare const origin: Point;
+export declare function distance(a: Point, b?: Point): number;
+export declare class Shape {
+    readonly name: string;
+    private cache;
+    constructor(name: string, options?: Options);
+    area(): number;
+}
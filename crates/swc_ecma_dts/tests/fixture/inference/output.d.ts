@@ -0,0 +1,2 @@
+export declare function add(a: number, b: number);
+export declare const value: any;
@@ -0,0 +1,9 @@
+export class Api {
+    /** @internal */
+    secret(): void {}
+
+    open(): void {}
+}
+
+/** @internal */
+export function internalOnly(): void {}
@@ -0,0 +1,3 @@
+export declare class Api {
+    open(): void;
+}
@@ -2,13 +2,14 @@
 
 use std::{borrow::Cow, fmt::Debug};
 
+use serde::Serialize;
 use swc_atoms::Atom;
 use swc_common::{
-    errors::{DiagnosticBuilder, Handler},
+    errors::{Applicability, DiagnosticBuilder, Handler},
     Span, Spanned,
 };
 
-use crate::token::Token;
+use crate::{token::Token, TsVersion};
 
 /// Note: this struct is 8 bytes.
 #[derive(Debug, Clone, PartialEq)]
@@ -37,6 +38,110 @@ impl Error {
     pub fn into_kind(self) -> SyntaxError {
         self.error.1
     }
+
+    /// Attaches a suggested fix, which replaces `span` with `replacement`.
+    #[cold]
+    pub fn with_suggestion(
+        self,
+        span: Span,
+        replacement: &'static str,
+        note: &'static str,
+    ) -> Self {
+        Error::new(
+            self.span(),
+            SyntaxError::WithSuggestion {
+                inner: Box::new(self),
+                span,
+                replacement,
+                note,
+            },
+        )
+    }
+
+    /// Machine-readable form of the error, for tools which render diagnostics
+    /// by themselves.
+    pub fn report(&self) -> ErrorReport {
+        let mut report = match self.kind() {
+            SyntaxError::WithLabel { inner, span, note } => {
+                let mut report = inner.report();
+                report.labels.push(ErrorLabel {
+                    span: *span,
+                    message: (*note).into(),
+                });
+                return report;
+            }
+            SyntaxError::WithSuggestion {
+                inner,
+                span,
+                replacement,
+                note,
+            } => {
+                let mut report = inner.report();
+                report.suggestions.push(ErrorSuggestion {
+                    span: *span,
+                    replacement: (*replacement).into(),
+                    message: (*note).into(),
+                });
+                return report;
+            }
+            kind => ErrorReport {
+                code: kind.code(),
+                message: kind.msg(),
+                span: self.span(),
+                labels: Default::default(),
+                suggestions: Default::default(),
+            },
+        };
+
+        match *self.kind() {
+            SyntaxError::ExpectedSemiForExprStmt { expr } => {
+                report.labels.push(ErrorLabel {
+                    span: expr,
+                    message: "This is the expression part of an expression statement".into(),
+                });
+            }
+            SyntaxError::MultipleDefault { previous } => {
+                report.labels.push(ErrorLabel {
+                    span: previous,
+                    message: "previous default case is declared at here".into(),
+                });
+            }
+            _ => {}
+        }
+
+        report
+    }
+}
+
+/// See [Error::report].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorReport {
+    /// See [SyntaxError::code].
+    pub code: String,
+    pub message: Cow<'static, str>,
+    pub span: Span,
+    /// Additional spans related to the error.
+    pub labels: Vec<ErrorLabel>,
+    pub suggestions: Vec<ErrorSuggestion>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorLabel {
+    pub span: Span,
+    pub message: Cow<'static, str>,
+}
+
+/// A fix for the error, which replaces `span` with `replacement`.
+///
+/// An empty `span` means the `replacement` should be inserted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorSuggestion {
+    pub span: Span,
+    pub replacement: Cow<'static, str>,
+    pub message: Cow<'static, str>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -48,6 +153,12 @@ pub enum SyntaxError {
     UsingDeclNotAllowed,
     UsingDeclNotAllowedForForInLoop,
     UsingDeclNotEnabled,
+    /// The syntax was added in a later version of TypeScript than
+    /// [crate::TsSyntax::ts_version].
+    TsVersionRequired {
+        syntax: &'static str,
+        version: TsVersion,
+    },
     InvalidNameInUsingDecl,
     InitRequiredForUsingDecl,
 
@@ -215,6 +326,21 @@ pub enum SyntaxError {
     DuplicatedRegExpFlags(char),
     UnknownRegExpFlags,
 
+    JsonComment,
+    JsonTrailingComma,
+    JsonInvalidString,
+    JsonInvalidNumber,
+
+    ImportAssertionDeprecated,
+
+    PipelineTopicUnused,
+    PipelineTopicOutsideBody,
+    PipelineBodyNotParenthesized,
+
+    MatchDefaultNotLast,
+    MatchMixedCombinators,
+    MatchInvalidValuePattern,
+
     TS1003,
     TS1005,
     TS1009,
@@ -290,13 +416,43 @@ pub enum SyntaxError {
         note: &'static str,
     },
 
+    /// See [Error::with_suggestion].
+    WithSuggestion {
+        inner: Box<Error>,
+        span: Span,
+        replacement: &'static str,
+        note: &'static str,
+    },
+
     ReservedTypeAssertion,
     ReservedArrowTypeParam,
+
+    /// Parsing was stopped by [swc_common::cancel::CancellationToken].
+    Cancelled,
 }
 
 impl SyntaxError {
     #[cold]
     #[inline(never)]
+    /// Stable identifier of the error, which is the name of the variant.
+    ///
+    /// Errors reported by `tsc` as well use its code, like `TS1005`.
+    pub fn code(&self) -> String {
+        match self {
+            SyntaxError::WithLabel { inner, .. } | SyntaxError::WithSuggestion { inner, .. } => {
+                inner.kind().code()
+            }
+            _ => {
+                let debug = format!("{:?}", self);
+                let end = debug
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(debug.len());
+
+                debug[..end].to_string()
+            }
+        }
+    }
+
     pub fn msg(&self) -> Cow<'static, str> {
         match self {
             SyntaxError::PrivateNameInInterface => {
@@ -544,6 +700,11 @@ impl SyntaxError {
             SyntaxError::UsingDeclNotEnabled => "Using declaration is not enabled. Set \
                                                  jsc.parser.explicitResourceManagement to true"
                 .into(),
+            SyntaxError::TsVersionRequired { syntax, version } => format!(
+                "{} requires TypeScript {} or later. Update jsc.parser.tsVersion to use it",
+                syntax, version
+            )
+            .into(),
             SyntaxError::InvalidNameInUsingDecl => {
                 "Using declaration only allows identifiers".into()
             }
@@ -748,7 +909,9 @@ impl SyntaxError {
                 };
                 format!("Unexpected token. Did you mean {}?", did_you_mean).into()
             }
-            SyntaxError::WithLabel { inner, .. } => inner.error.1.msg(),
+            SyntaxError::WithLabel { inner, .. } | SyntaxError::WithSuggestion { inner, .. } => {
+                inner.error.1.msg()
+            }
             SyntaxError::ReservedTypeAssertion => "This syntax is reserved in files with the .mts \
                                                    or .cts extension. Use an `as` expression \
                                                    instead."
@@ -758,6 +921,38 @@ impl SyntaxError {
                                                     as in `<T,>() => ...`."
                 .into(),
             SyntaxError::InvalidAssignTarget => "Invalid assignment target".into(),
+            SyntaxError::JsonComment => "Comments are not allowed in JSON".into(),
+            SyntaxError::JsonTrailingComma => "Trailing comma is not allowed in JSON".into(),
+            SyntaxError::JsonInvalidString => {
+                "Invalid string in JSON. Strings should be double-quoted, and can't contain \
+                 control characters or escape sequences which are only valid in javascript"
+                    .into()
+            }
+            SyntaxError::JsonInvalidNumber => "Invalid number in JSON".into(),
+            SyntaxError::PipelineTopicUnused => {
+                "The body of a pipeline must use the topic reference `%`".into()
+            }
+            SyntaxError::PipelineTopicOutsideBody => {
+                "The topic reference `%` is only allowed in the body of a pipeline".into()
+            }
+            SyntaxError::PipelineBodyNotParenthesized => {
+                "Arrow functions, assignments, conditional and yield expressions must be \
+                 parenthesized in the body of a pipeline"
+                    .into()
+            }
+            SyntaxError::MatchDefaultNotLast => {
+                "`default` must be the last case of a match expression".into()
+            }
+            SyntaxError::MatchMixedCombinators => {
+                "`and` and `or` patterns can't be mixed without parentheses".into()
+            }
+            SyntaxError::MatchInvalidValuePattern => {
+                "Only literals and references like `a.b` can be used as a value pattern".into()
+            }
+            SyntaxError::ImportAssertionDeprecated => {
+                "The `assert` keyword of import attributes is deprecated, use `with` instead".into()
+            }
+            SyntaxError::Cancelled => "Parsing was cancelled".into(),
         }
     }
 }
@@ -766,30 +961,19 @@ impl Error {
     #[cold]
     #[inline(never)]
     pub fn into_diagnostic(self, handler: &Handler) -> DiagnosticBuilder {
-        if let SyntaxError::WithLabel { inner, note, span } = self.error.1 {
-            let mut db = inner.into_diagnostic(handler);
-            db.span_label(span, note);
-            return db;
-        }
-
-        let span = self.span();
-
-        let kind = self.into_kind();
-        let msg = kind.msg();
+        let report = self.report();
 
-        let mut db = handler.struct_span_err(span, &msg);
-
-        match kind {
-            SyntaxError::ExpectedSemiForExprStmt { expr } => {
-                db.span_label(
-                    expr,
-                    "This is the expression part of an expression statement",
-                );
-            }
-            SyntaxError::MultipleDefault { previous } => {
-                db.span_label(previous, "previous default case is declared at here");
-            }
-            _ => {}
+        let mut db = handler.struct_span_err(report.span, &report.message);
+        for label in report.labels {
+            db.span_label(label.span, label.message);
+        }
+        for suggestion in report.suggestions {
+            db.span_suggestion_with_applicability(
+                suggestion.span,
+                &suggestion.message,
+                suggestion.replacement.into(),
+                Applicability::MaybeIncorrect,
+            );
         }
 
         db
@@ -1,7 +1,8 @@
 use std::{cell::RefCell, mem, mem::take, rc::Rc};
 
 use debug_unreachable::debug_unreachable;
-use lexer::TokenContexts;
+use lexer::{TokenContext, TokenContexts};
+use smallvec::smallvec;
 use swc_common::{BytePos, Span};
 use swc_ecma_ast::EsVersion;
 
@@ -16,6 +17,18 @@ use crate::{
 /// Clone should be cheap if you are parsing typescript because typescript
 /// syntax requires backtracking.
 pub trait Tokens: Clone + Iterator<Item = TokenAndSpan> {
+    /// Position of the lexer, saved by [Tokens::checkpoint_save].
+    type Checkpoint;
+
+    /// Saves the position of the lexer, so lexing can be continued from it
+    /// by [Tokens::checkpoint_load].
+    ///
+    /// Unlike cloning, this doesn't copy the state which is shared between
+    /// clones, like errors.
+    fn checkpoint_save(&self) -> Self::Checkpoint;
+
+    fn checkpoint_load(&mut self, checkpoint: Self::Checkpoint);
+
     fn set_ctx(&mut self, ctx: Context);
     fn ctx(&self) -> Context;
     fn syntax(&self) -> Syntax;
@@ -54,11 +67,23 @@ pub trait Tokens: Clone + Iterator<Item = TokenAndSpan> {
     /// If the program was parsed as a script, this contains the module
     /// errors should the program be identified as a module in the future.
     fn take_script_module_errors(&mut self) -> Vec<Error>;
+
+    /// Directive comments of TypeScript, like `// @ts-ignore`, in the order
+    /// they appear.
+    fn take_ts_directives(&mut self) -> Vec<lexer::TsDirective> {
+        Vec::new()
+    }
 }
 
+/// Tokens which are lexed beforehand, like the ones captured by [Capturing].
+///
+/// Tokens are shared between clones, so cloning this is cheap even when the
+/// parser backtracks.
 #[derive(Clone)]
 pub struct TokensInput {
-    iter: <Vec<TokenAndSpan> as IntoIterator>::IntoIter,
+    tokens: Rc<Vec<TokenAndSpan>>,
+    /// Index of the next token.
+    pos: usize,
     ctx: Context,
     syntax: Syntax,
     start_pos: BytePos,
@@ -73,12 +98,13 @@ impl TokensInput {
         let start_pos = tokens.first().map(|t| t.span.lo).unwrap_or(BytePos(0));
 
         TokensInput {
-            iter: tokens.into_iter(),
+            tokens: Rc::new(tokens),
+            pos: 0,
             ctx,
             syntax,
             start_pos,
             target,
-            token_ctx: Default::default(),
+            token_ctx: TokenContexts(smallvec![TokenContext::BraceStmt]),
             errors: Default::default(),
             module_errors: Default::default(),
         }
@@ -89,11 +115,25 @@ impl Iterator for TokensInput {
     type Item = TokenAndSpan;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next()
+        let token = self.tokens.get(self.pos)?.clone();
+        self.pos += 1;
+        Some(token)
     }
 }
 
 impl Tokens for TokensInput {
+    type Checkpoint = (usize, Context, TokenContexts);
+
+    fn checkpoint_save(&self) -> Self::Checkpoint {
+        (self.pos, self.ctx, self.token_ctx.clone())
+    }
+
+    fn checkpoint_load(&mut self, (pos, ctx, token_ctx): Self::Checkpoint) {
+        self.pos = pos;
+        self.ctx = ctx;
+        self.token_ctx = token_ctx;
+    }
+
     fn set_ctx(&mut self, ctx: Context) {
         if ctx.contains(Context::Module) && !self.module_errors.borrow().is_empty() {
             let mut module_errors = self.module_errors.borrow_mut();
@@ -155,8 +195,7 @@ impl Tokens for TokensInput {
     }
 
     fn end_pos(&self) -> BytePos {
-        self.iter
-            .as_slice()
+        self.tokens
             .last()
             .map(|t| t.span.hi)
             .unwrap_or(self.start_pos)
@@ -168,6 +207,8 @@ impl Tokens for TokensInput {
 pub struct Capturing<I: Tokens> {
     inner: I,
     captured: Rc<RefCell<Vec<TokenAndSpan>>>,
+    /// Context of `inner` when capturing started.
+    start_ctx: Context,
 }
 
 impl<I: Tokens> Clone for Capturing<I> {
@@ -175,6 +216,7 @@ impl<I: Tokens> Clone for Capturing<I> {
         Capturing {
             inner: self.inner.clone(),
             captured: self.captured.clone(),
+            start_ctx: self.start_ctx,
         }
     }
 }
@@ -182,11 +224,14 @@ impl<I: Tokens> Clone for Capturing<I> {
 impl<I: Tokens> Capturing<I> {
     pub fn new(input: I) -> Self {
         Capturing {
+            start_ctx: input.ctx(),
             inner: input,
             captured: Default::default(),
         }
     }
 
+    /// Captured tokens, which can be modified before calling
+    /// [Capturing::replay].
     pub fn tokens(&self) -> Rc<RefCell<Vec<TokenAndSpan>>> {
         self.captured.clone()
     }
@@ -195,6 +240,21 @@ impl<I: Tokens> Capturing<I> {
     pub fn take(&mut self) -> Vec<TokenAndSpan> {
         mem::take(&mut *self.captured.borrow_mut())
     }
+
+    /// Creates an input which yields the captured tokens again, so they can
+    /// be parsed by another parser without lexing the source again.
+    ///
+    /// Tokens are already resolved by the parser which captured them, like
+    /// `/` which is lexed as a regular expression, so the replayed tokens
+    /// should be parsed the same way.
+    pub fn replay(&self) -> TokensInput {
+        TokensInput::new(
+            self.captured.borrow().clone(),
+            self.start_ctx,
+            self.inner.syntax(),
+            self.inner.target(),
+        )
+    }
 }
 
 impl<I: Tokens> Iterator for Capturing<I> {
@@ -208,8 +268,13 @@ impl<I: Tokens> Iterator for Capturing<I> {
                 let mut v = self.captured.borrow_mut();
 
                 // remove tokens that could change due to backtracing
+                //
+                // An empty token, like the empty template before `${`, is kept
+                // as the next token starts at the same position.
                 while let Some(last) = v.last() {
-                    if last.span.lo >= ts.span.lo {
+                    if last.span.lo > ts.span.lo
+                        || (last.span.lo == ts.span.lo && last.span.lo != last.span.hi)
+                    {
                         v.pop();
                     } else {
                         break;
@@ -226,6 +291,18 @@ impl<I: Tokens> Iterator for Capturing<I> {
 }
 
 impl<I: Tokens> Tokens for Capturing<I> {
+    type Checkpoint = I::Checkpoint;
+
+    /// Captured tokens are not saved, as tokens which are lexed again after
+    /// [Tokens::checkpoint_load] replace them.
+    fn checkpoint_save(&self) -> Self::Checkpoint {
+        self.inner.checkpoint_save()
+    }
+
+    fn checkpoint_load(&mut self, checkpoint: Self::Checkpoint) {
+        self.inner.checkpoint_load(checkpoint)
+    }
+
     fn set_ctx(&mut self, ctx: Context) {
         self.inner.set_ctx(ctx)
     }
@@ -282,6 +359,10 @@ impl<I: Tokens> Tokens for Capturing<I> {
         self.inner.take_script_module_errors()
     }
 
+    fn take_ts_directives(&mut self) -> Vec<lexer::TsDirective> {
+        self.inner.take_ts_directives()
+    }
+
     fn end_pos(&self) -> BytePos {
         self.inner.end_pos()
     }
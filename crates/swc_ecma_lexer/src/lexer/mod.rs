@@ -12,13 +12,17 @@ use swc_common::{
 };
 use swc_ecma_ast::{op, AssignOp, EsVersion, Ident};
 
-pub use self::state::{TokenContext, TokenContexts};
 use self::{
     comments_buffer::CommentsBuffer,
     state::State,
     table::{ByteHandler, BYTE_HANDLERS},
     util::*,
 };
+pub use self::{
+    state::{LexerCheckpoint, TokenContext, TokenContexts},
+    streaming::{StreamingLexer, StreamingLexerCheckpoint},
+    ts_directive::{TsDirective, TsDirectiveKind},
+};
 use crate::{
     error::{Error, SyntaxError},
     tok,
@@ -30,9 +34,11 @@ mod comments_buffer;
 mod jsx;
 mod number;
 mod state;
+mod streaming;
 mod table;
 #[cfg(test)]
 mod tests;
+mod ts_directive;
 pub mod util;
 mod whitespace;
 
@@ -149,6 +155,7 @@ pub struct Lexer<'a> {
 
     errors: Rc<RefCell<Vec<Error>>>,
     module_errors: Rc<RefCell<Vec<Error>>>,
+    ts_directives: Rc<RefCell<Vec<TsDirective>>>,
 
     buf: Rc<RefCell<String>>,
 
@@ -177,11 +184,36 @@ impl<'a> Lexer<'a> {
             target,
             errors: Default::default(),
             module_errors: Default::default(),
+            ts_directives: Default::default(),
             buf: Rc::new(RefCell::new(String::with_capacity(256))),
             atoms: Default::default(),
         }
     }
 
+    /// Moves the state of the lexer to another input, which should contain
+    /// the current position.
+    fn with_input<'b>(
+        self,
+        input: StringInput<'b>,
+        comments: Option<&'b dyn Comments>,
+    ) -> Lexer<'b> {
+        Lexer {
+            comments,
+            comments_buffer: self.comments_buffer,
+            ctx: self.ctx,
+            input,
+            start_pos: self.start_pos,
+            state: self.state,
+            syntax: self.syntax,
+            target: self.target,
+            errors: self.errors,
+            module_errors: self.module_errors,
+            ts_directives: self.ts_directives,
+            buf: self.buf,
+            atoms: self.atoms,
+        }
+    }
+
     /// Utility method to reuse buffer.
     fn with_buf<F, Ret>(&mut self, op: F) -> LexResult<Ret>
     where
@@ -373,6 +405,15 @@ impl<'a> Lexer<'a> {
             }));
         }
 
+        // '|>'
+        if C == b'|' && self.input.cur() == Some('>') && self.syntax.pipeline_operator() {
+            unsafe {
+                // Safety: cur() is Some('>')
+                self.input.bump();
+            }
+            return Ok(tok!("|>"));
+        }
+
         // '||', '&&'
         if self.input.cur() == Some(C as char) {
             unsafe {
@@ -680,7 +721,7 @@ impl Lexer<'_> {
         let start = self.cur_pos();
         self.bump();
 
-        if self.syntax.typescript()
+        if self.syntax.type_annotations()
             && self.ctx.contains(Context::InType)
             && !self.ctx.contains(Context::ShouldNotLexLtOrGtAsType)
         {
@@ -6,8 +6,8 @@ use swc_ecma_ast::EsVersion;
 use tracing::trace;
 
 use super::{
-    comments_buffer::{BufferedComment, BufferedCommentKind},
-    Context, Input, Lexer,
+    comments_buffer::{BufferedComment, BufferedCommentKind, CommentsBuffer},
+    Context, Input, Lexer, TsDirective,
 };
 use crate::{
     error::{Error, SyntaxError},
@@ -122,7 +122,40 @@ impl From<TokenKind> for TokenType {
     }
 }
 
+/// Position of a [Lexer], saved by [Tokens::checkpoint_save].
+///
+/// This contains the token context stack, but not the buffers shared between
+/// clones of the lexer.
+#[derive(Clone)]
+pub struct LexerCheckpoint {
+    state: State,
+    ctx: Context,
+    input_last_pos: BytePos,
+    comments_buffer: Option<CommentsBuffer>,
+}
+
 impl Tokens for Lexer<'_> {
+    type Checkpoint = LexerCheckpoint;
+
+    fn checkpoint_save(&self) -> Self::Checkpoint {
+        LexerCheckpoint {
+            state: self.state.clone(),
+            ctx: self.ctx,
+            input_last_pos: self.input.last_pos(),
+            comments_buffer: self.comments_buffer.clone(),
+        }
+    }
+
+    fn checkpoint_load(&mut self, checkpoint: Self::Checkpoint) {
+        self.state = checkpoint.state;
+        self.ctx = checkpoint.ctx;
+        self.comments_buffer = checkpoint.comments_buffer;
+        unsafe {
+            // Safety: the position was saved from the same input.
+            self.input.reset_to(checkpoint.input_last_pos);
+        }
+    }
+
     #[inline]
     fn set_ctx(&mut self, ctx: Context) {
         if ctx.contains(Context::Module) && !self.module_errors.borrow().is_empty() {
@@ -197,6 +230,10 @@ impl Tokens for Lexer<'_> {
         take(&mut self.module_errors.borrow_mut())
     }
 
+    fn take_ts_directives(&mut self) -> Vec<TsDirective> {
+        take(&mut self.ts_directives.borrow_mut())
+    }
+
     fn end_pos(&self) -> BytePos {
         self.input.end_pos()
     }
@@ -0,0 +1,509 @@
+//! Lexing from a reader, without loading the whole source first.
+
+use std::{
+    cell::RefCell,
+    io::{self, Read},
+    rc::Rc,
+    str,
+};
+
+use swc_common::{
+    comments::Comments,
+    input::{Input, StringInput},
+    BytePos,
+};
+use swc_ecma_ast::EsVersion;
+
+use super::{comments_buffer::CommentsBuffer, Lexer, LexerCheckpoint, TokenContexts, TsDirective};
+use crate::{error::Error, input::Tokens, token::TokenAndSpan, Context, Syntax};
+
+const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Tokens ending this close to the end of the buffered source are lexed again
+/// after reading more, because the lexer looks a few characters ahead.
+const LOOKAHEAD: u32 = 16;
+
+/// A lexer which reads the source from a [Read] in chunks.
+///
+/// Source which is already lexed is dropped from the buffer, so the memory
+/// usage doesn't depend on the size of the file but on the size of the
+/// largest token. Spans start at `start_pos`, like the spans of a
+/// [StringInput].
+///
+/// This can be passed to the parser like [Lexer]. Reading stops at the first
+/// io error or invalid UTF-8, which can be checked using
+/// [StreamingLexer::take_io_error] after parsing.
+pub struct StreamingLexer<'a, R: Read> {
+    /// The lexer is moved to an input over the buffer for each token, so this
+    /// is [None] only while lexing.
+    lexer: Option<Lexer<'static>>,
+    comments: Option<&'a dyn Comments>,
+    source: Rc<RefCell<Source<R>>>,
+    pos: BytePos,
+    /// Start of the last token. The parser may ask for a regex starting there.
+    keep_from: BytePos,
+}
+
+struct Source<R> {
+    reader: R,
+    chunk_size: usize,
+    buf: String,
+    /// Position of the first byte of `buf`.
+    start: BytePos,
+    /// Bytes of an incomplete UTF-8 sequence at the end of the last chunk.
+    pending: Vec<u8>,
+    eof: bool,
+    io_error: Option<io::Error>,
+}
+
+impl<'a, R: Read> StreamingLexer<'a, R> {
+    pub fn new(
+        syntax: Syntax,
+        target: EsVersion,
+        reader: R,
+        start_pos: BytePos,
+        comments: Option<&'a dyn Comments>,
+    ) -> Self {
+        let mut lexer = Lexer::new(
+            syntax,
+            target,
+            StringInput::new("", start_pos, start_pos),
+            None,
+        );
+        if comments.is_some() {
+            lexer.comments_buffer = Some(CommentsBuffer::new());
+        }
+
+        StreamingLexer {
+            lexer: Some(lexer),
+            comments,
+            source: Rc::new(RefCell::new(Source {
+                reader,
+                chunk_size: DEFAULT_CHUNK_SIZE,
+                buf: String::new(),
+                start: start_pos,
+                pending: Vec::new(),
+                eof: false,
+                io_error: None,
+            })),
+            pos: start_pos,
+            keep_from: start_pos,
+        }
+    }
+
+    /// Sets the number of bytes read at once.
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        self.source.borrow_mut().chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// Returns the error which stopped reading, if any.
+    pub fn take_io_error(&mut self) -> Option<io::Error> {
+        self.source.borrow_mut().io_error.take()
+    }
+
+    fn lexer(&self) -> &Lexer<'static> {
+        self.lexer.as_ref().unwrap()
+    }
+
+    fn lexer_mut(&mut self) -> &mut Lexer<'static> {
+        self.lexer.as_mut().unwrap()
+    }
+
+    /// Lexes a token from the buffered source.
+    fn lex(&mut self) -> Option<TokenAndSpan> {
+        let source = self.source.borrow();
+        let end = source.end();
+
+        let mut input = StringInput::new(&source.buf, source.start, end);
+        unsafe {
+            // Safety: `pos` is in the buffer, as only the source before
+            // `keep_from` is dropped.
+            input.reset_to(self.pos);
+        }
+        // Comments are added to `comments` at the end of the input, which
+        // is not known until the reader is exhausted.
+        let comments = if source.eof { self.comments } else { None };
+
+        let mut lexer = self.lexer.take().unwrap().with_input(input, comments);
+        let token = lexer.next();
+
+        self.pos = lexer.input.cur_pos();
+        self.lexer = Some(lexer.with_input(StringInput::new("", self.pos, self.pos), None));
+
+        token
+    }
+}
+
+impl<R: Read> Source<R> {
+    fn end(&self) -> BytePos {
+        self.start + BytePos(self.buf.len() as u32)
+    }
+
+    /// Drops the source before `keep_from` and reads a chunk.
+    fn fill(&mut self, keep_from: BytePos, can_drop: bool) {
+        if can_drop && keep_from > self.start {
+            self.buf.drain(..(keep_from - self.start).0 as usize);
+            self.start = keep_from;
+        }
+
+        let len = self.pending.len();
+        match (&mut self.reader)
+            .take(self.chunk_size as u64)
+            .read_to_end(&mut self.pending)
+        {
+            Ok(0) => {
+                self.eof = true;
+                if len != 0 {
+                    self.fail(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "stream did not contain valid UTF-8",
+                    ));
+                }
+                return;
+            }
+            Ok(_) => {}
+            Err(err) => {
+                self.fail(err);
+                return;
+            }
+        }
+
+        let valid = match str::from_utf8(&self.pending) {
+            Ok(s) => s.len(),
+            Err(err) => {
+                if err.error_len().is_some() {
+                    self.buf
+                        .push_str(str::from_utf8(&self.pending[..err.valid_up_to()]).unwrap());
+                    self.pending.clear();
+                    self.fail(io::Error::new(io::ErrorKind::InvalidData, err));
+                    return;
+                }
+                err.valid_up_to()
+            }
+        };
+        self.buf
+            .push_str(str::from_utf8(&self.pending[..valid]).unwrap());
+        self.pending.drain(..valid);
+    }
+
+    fn fail(&mut self, err: io::Error) {
+        self.eof = true;
+        self.io_error = Some(err);
+    }
+}
+
+impl<R: Read> Iterator for StreamingLexer<'_, R> {
+    type Item = TokenAndSpan;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (eof, remaining, chunk_size) = {
+                let source = self.source.borrow();
+                (
+                    source.eof,
+                    (source.end() - self.pos).0 as usize,
+                    source.chunk_size,
+                )
+            };
+
+            if eof {
+                let token = self.lex();
+                if let Some(token) = &token {
+                    self.keep_from = token.span.lo;
+                }
+                return token;
+            }
+
+            // Clones of the lexer may backtrack to their own position.
+            let can_drop = Rc::strong_count(&self.source) == 1;
+            if remaining < chunk_size {
+                self.source.borrow_mut().fill(self.keep_from, can_drop);
+                continue;
+            }
+
+            let snapshot = Snapshot::new(self.lexer());
+            let token = self.lex();
+
+            if self.pos + BytePos(LOOKAHEAD) < self.source.borrow().end() {
+                if let Some(token) = &token {
+                    self.keep_from = token.span.lo;
+                }
+                return token;
+            }
+
+            // The token may continue in the next chunk.
+            self.pos = snapshot.restore(self.lexer_mut());
+            self.source.borrow_mut().fill(self.keep_from, can_drop);
+        }
+    }
+}
+
+/// State of the lexer before lexing a token which may be incomplete.
+struct Snapshot {
+    lexer: Lexer<'static>,
+    errors: usize,
+    module_errors: usize,
+    ts_directives: usize,
+}
+
+impl Snapshot {
+    fn new(lexer: &Lexer<'static>) -> Self {
+        Snapshot {
+            lexer: lexer.clone(),
+            errors: lexer.errors.borrow().len(),
+            module_errors: lexer.module_errors.borrow().len(),
+            ts_directives: lexer.ts_directives.borrow().len(),
+        }
+    }
+
+    /// Returns the position to lex from.
+    fn restore(self, lexer: &mut Lexer<'static>) -> BytePos {
+        // Errors are shared with clones, so they are truncated instead.
+        lexer.errors.borrow_mut().truncate(self.errors);
+        lexer
+            .module_errors
+            .borrow_mut()
+            .truncate(self.module_errors);
+        lexer
+            .ts_directives
+            .borrow_mut()
+            .truncate(self.ts_directives);
+
+        *lexer = self.lexer;
+        lexer.input.cur_pos()
+    }
+}
+
+impl<R: Read> Clone for StreamingLexer<'_, R> {
+    fn clone(&self) -> Self {
+        StreamingLexer {
+            lexer: self.lexer.clone(),
+            comments: self.comments,
+            source: self.source.clone(),
+            pos: self.pos,
+            keep_from: self.keep_from,
+        }
+    }
+}
+
+/// Position of a [StreamingLexer], saved by [Tokens::checkpoint_save].
+pub struct StreamingLexerCheckpoint<R> {
+    lexer: LexerCheckpoint,
+    pos: BytePos,
+    keep_from: BytePos,
+    /// Prevents the source after `keep_from` from being dropped.
+    _source: Rc<RefCell<Source<R>>>,
+}
+
+impl<R: Read> Tokens for StreamingLexer<'_, R> {
+    type Checkpoint = StreamingLexerCheckpoint<R>;
+
+    fn checkpoint_save(&self) -> Self::Checkpoint {
+        StreamingLexerCheckpoint {
+            lexer: self.lexer().checkpoint_save(),
+            pos: self.pos,
+            keep_from: self.keep_from,
+            _source: self.source.clone(),
+        }
+    }
+
+    fn checkpoint_load(&mut self, checkpoint: Self::Checkpoint) {
+        self.pos = checkpoint.pos;
+        self.keep_from = checkpoint.keep_from;
+
+        // The lexer is only given the source while lexing a token, so its
+        // input is empty at `pos`, like when it was saved.
+        let lexer = self.lexer.take().unwrap();
+        self.lexer = Some(lexer.with_input(StringInput::new("", self.pos, self.pos), None));
+        self.lexer_mut().checkpoint_load(checkpoint.lexer);
+    }
+
+    fn set_ctx(&mut self, ctx: Context) {
+        self.lexer_mut().set_ctx(ctx)
+    }
+
+    fn ctx(&self) -> Context {
+        self.lexer().ctx()
+    }
+
+    fn syntax(&self) -> Syntax {
+        self.lexer().syntax()
+    }
+
+    fn target(&self) -> EsVersion {
+        self.lexer().target()
+    }
+
+    fn start_pos(&self) -> BytePos {
+        Tokens::start_pos(self.lexer())
+    }
+
+    fn set_expr_allowed(&mut self, allow: bool) {
+        self.lexer_mut().set_expr_allowed(allow)
+    }
+
+    fn set_next_regexp(&mut self, start: Option<BytePos>) {
+        self.lexer_mut().set_next_regexp(start)
+    }
+
+    fn token_context(&self) -> &TokenContexts {
+        self.lexer().token_context()
+    }
+
+    fn token_context_mut(&mut self) -> &mut TokenContexts {
+        self.lexer_mut().token_context_mut()
+    }
+
+    fn set_token_context(&mut self, c: TokenContexts) {
+        self.lexer_mut().set_token_context(c)
+    }
+
+    fn add_error(&self, error: Error) {
+        self.lexer().add_error(error)
+    }
+
+    fn add_module_mode_error(&self, error: Error) {
+        self.lexer().add_module_mode_error(error)
+    }
+
+    /// The end of the source which is read so far, which is the end of the
+    /// file after the last token.
+    fn end_pos(&self) -> BytePos {
+        self.source.borrow().end()
+    }
+
+    fn take_errors(&mut self) -> Vec<Error> {
+        self.lexer_mut().take_errors()
+    }
+
+    fn take_script_module_errors(&mut self) -> Vec<Error> {
+        self.lexer_mut().take_script_module_errors()
+    }
+
+    fn take_ts_directives(&mut self) -> Vec<TsDirective> {
+        self.lexer_mut().take_ts_directives()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::comments::SingleThreadedComments;
+
+    use super::*;
+    use crate::TsSyntax;
+
+    type Lexed = (Vec<TokenAndSpan>, Vec<Error>, Vec<String>);
+
+    fn sorted_comments(comments: SingleThreadedComments) -> Vec<String> {
+        let (leading, trailing) = comments.take_all();
+        let mut comments = leading
+            .take()
+            .into_iter()
+            .map(|(pos, c)| format!("leading {pos:?} {c:?}"))
+            .chain(
+                trailing
+                    .take()
+                    .into_iter()
+                    .map(|(pos, c)| format!("trailing {pos:?} {c:?}")),
+            )
+            .collect::<Vec<_>>();
+        comments.sort();
+        comments
+    }
+
+    fn lex(syntax: Syntax, src: &str) -> Lexed {
+        let comments = SingleThreadedComments::default();
+        let mut lexer = Lexer::new(
+            syntax,
+            Default::default(),
+            StringInput::new(src, BytePos(1), BytePos(1 + src.len() as u32)),
+            Some(&comments),
+        );
+        let tokens = lexer.by_ref().collect();
+
+        (tokens, lexer.take_errors(), sorted_comments(comments))
+    }
+
+    fn lex_streaming(syntax: Syntax, src: &str, chunk_size: usize) -> Lexed {
+        let comments = SingleThreadedComments::default();
+        let mut lexer = StreamingLexer::new(
+            syntax,
+            Default::default(),
+            src.as_bytes(),
+            BytePos(1),
+            Some(&comments),
+        )
+        .with_chunk_size(chunk_size);
+        let tokens = lexer.by_ref().collect();
+        assert!(lexer.take_io_error().is_none());
+
+        (tokens, lexer.take_errors(), sorted_comments(comments))
+    }
+
+    fn assert_same(syntax: Syntax, src: &str) {
+        let expected = lex(syntax, src);
+        for chunk_size in [1, 2, 3, 7, 16, 64, 1024] {
+            assert_eq!(
+                lex_streaming(syntax, src, chunk_size),
+                expected,
+                "chunk size: {}",
+                chunk_size
+            );
+        }
+    }
+
+    #[test]
+    fn same_as_lexer() {
+        assert_same(
+            Default::default(),
+            "#!/usr/bin/env node\n/* leading */ const a = 1.5e3 + 0x1f; // trailing\nfoo(`a${ b \
+             }c${`d`}`, 'str\\n', /re[/]g/u, a / 2);\nlet ünicode = '😀'; <!-- html\n",
+        );
+    }
+
+    #[test]
+    fn long_tokens() {
+        let long = "x".repeat(300);
+        assert_same(
+            Default::default(),
+            &format!("/*{long}*/\nconst {long} = \"{long}\";\n`{long}`;    \n\n"),
+        );
+    }
+
+    #[test]
+    fn typescript() {
+        assert_same(
+            Syntax::Typescript(TsSyntax {
+                tsx: true,
+                ..Default::default()
+            }),
+            "// @ts-ignore\nconst a: Array<string> = <div id=\"a\">{b}</div>;",
+        );
+    }
+
+    #[test]
+    fn errors() {
+        assert_same(
+            Default::default(),
+            "let a = 'unterminated\nlet b = 08;\n/* open",
+        );
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut lexer = StreamingLexer::new(
+            Default::default(),
+            Default::default(),
+            &b"a b\xffc"[..],
+            BytePos(1),
+            None,
+        );
+
+        assert_eq!(lexer.by_ref().count(), 2);
+        assert_eq!(
+            lexer.take_io_error().unwrap().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
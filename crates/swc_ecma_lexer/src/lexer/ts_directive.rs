@@ -0,0 +1,114 @@
+//! Directive comments of TypeScript, like `// @ts-ignore` and
+//! `/// <reference types="node" />`.
+
+use swc_atoms::Atom;
+use swc_common::Span;
+
+/// A directive comment of TypeScript.
+///
+/// TypeScript only respects `@ts-nocheck`, `@ts-check` and triple-slash
+/// references at the top of a file, but the lexer records them wherever they
+/// appear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TsDirective {
+    pub kind: TsDirectiveKind,
+    /// Span of the whole comment.
+    pub span: Span,
+    /// Description of `@ts-ignore` and `@ts-expect-error`, or the value of
+    /// the attribute of a triple-slash reference.
+    pub arg: Option<Atom>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TsDirectiveKind {
+    /// `// @ts-ignore`
+    Ignore,
+    /// `// @ts-expect-error`
+    ExpectError,
+    /// `// @ts-nocheck`
+    NoCheck,
+    /// `// @ts-check`
+    Check,
+    /// `/// <reference path="..." />`
+    ReferencePath,
+    /// `/// <reference types="..." />`
+    ReferenceTypes,
+    /// `/// <reference lib="..." />`
+    ReferenceLib,
+    /// `/// <reference no-default-lib="true" />`
+    ReferenceNoDefaultLib,
+}
+
+/// Parses the text of a line comment, without the leading `//`.
+pub(super) fn parse_line_comment(text: &str) -> Option<(TsDirectiveKind, Option<&str>)> {
+    if let Some(reference) = text.strip_prefix('/') {
+        if let Some(v) = parse_reference(reference) {
+            return Some(v);
+        }
+    }
+
+    parse_pragma(text.trim_start_matches('/'))
+}
+
+/// Parses the text of a block comment, without `/*` and `*/`.
+///
+/// Like TypeScript, only the last line of the comment is checked, and only
+/// `@ts-ignore` and `@ts-expect-error` are allowed.
+pub(super) fn parse_block_comment(text: &str) -> Option<(TsDirectiveKind, Option<&str>)> {
+    let last_line = text.rsplit(['\n', '\r', '\u{2028}', '\u{2029}']).next()?;
+    let last_line = last_line.trim_start().trim_start_matches(['/', '*']);
+
+    parse_pragma(last_line)
+        .filter(|(kind, _)| matches!(kind, TsDirectiveKind::Ignore | TsDirectiveKind::ExpectError))
+}
+
+fn parse_pragma(text: &str) -> Option<(TsDirectiveKind, Option<&str>)> {
+    let text = text.trim_start().strip_prefix("@ts-")?;
+
+    let (kind, rest) = [
+        ("ignore", TsDirectiveKind::Ignore),
+        ("expect-error", TsDirectiveKind::ExpectError),
+        ("nocheck", TsDirectiveKind::NoCheck),
+        ("check", TsDirectiveKind::Check),
+    ]
+    .into_iter()
+    .find_map(|(name, kind)| Some((kind, text.strip_prefix(name)?)))?;
+
+    // `@ts-ignored` is not a directive.
+    if rest.starts_with(|c: char| c == '-' || c.is_alphanumeric()) {
+        return None;
+    }
+
+    let arg = rest.trim();
+    let arg = match kind {
+        TsDirectiveKind::Ignore | TsDirectiveKind::ExpectError if !arg.is_empty() => Some(arg),
+        _ => None,
+    };
+
+    Some((kind, arg))
+}
+
+/// Parses `<reference path="..." />` after `///`.
+fn parse_reference(text: &str) -> Option<(TsDirectiveKind, Option<&str>)> {
+    let text = text.trim_start().strip_prefix("<reference")?;
+    if !text.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let text = text.trim_start();
+
+    let (name, rest) = text.split_once('=')?;
+    let kind = match name.trim_end() {
+        "path" => TsDirectiveKind::ReferencePath,
+        "types" => TsDirectiveKind::ReferenceTypes,
+        "lib" => TsDirectiveKind::ReferenceLib,
+        "no-default-lib" => TsDirectiveKind::ReferenceNoDefaultLib,
+        _ => return None,
+    };
+
+    let rest = rest.trim_start();
+    let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+    let rest = &rest[1..];
+    let value = &rest[..rest.find(quote)?];
+
+    Some((kind, Some(value)))
+}
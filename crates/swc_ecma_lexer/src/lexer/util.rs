@@ -4,6 +4,7 @@
 //! [babylon/util/identifier.js]:https://github.com/babel/babel/blob/master/packages/babylon/src/util/identifier.js
 use std::char;
 
+use swc_atoms::Atom;
 use swc_common::{
     comments::{Comment, CommentKind},
     input::Input,
@@ -12,7 +13,10 @@ use swc_common::{
 use swc_ecma_ast::Ident;
 use tracing::warn;
 
-use super::{comments_buffer::BufferedComment, whitespace::SkipWhitespace, Char, LexResult, Lexer};
+use super::{
+    comments_buffer::BufferedComment, ts_directive, whitespace::SkipWhitespace, Char, LexResult,
+    Lexer, TsDirective, TsDirectiveKind,
+};
 use crate::{
     error::{Error, SyntaxError},
     lexer::comments_buffer::BufferedCommentKind,
@@ -237,6 +241,15 @@ impl Lexer<'_> {
         self.input.bump_bytes(idx);
         let end = self.cur_pos();
 
+        let s = unsafe {
+            // Safety: We know that the start and the end are valid
+            self.input.slice(slice_start, end)
+        };
+        if let Some((kind, arg)) = ts_directive::parse_line_comment(s) {
+            let arg = arg.map(|v| self.atoms.atom(v));
+            self.add_ts_directive(kind, Span::new(start, end), arg);
+        }
+
         // Create and process slice only if comments need to be stored
         if let Some(comments) = self.comments_buffer.as_mut() {
             let s = unsafe {
@@ -305,6 +318,16 @@ impl Lexer<'_> {
 
                 let end = self.cur_pos();
 
+                let src = unsafe {
+                    // Safety: We got slice_start and end from self.input so those are valid.
+                    self.input.slice(slice_start, end)
+                };
+                if let Some((kind, arg)) = ts_directive::parse_block_comment(&src[..src.len() - 2])
+                {
+                    let arg = arg.map(|v| self.atoms.atom(v));
+                    self.add_ts_directive(kind, Span::new(start, end), arg);
+                }
+
                 self.skip_space::<false>();
 
                 // Check if this is a comment before semicolon
@@ -344,6 +367,21 @@ impl Lexer<'_> {
         self.emit_error_span(span, SyntaxError::UnterminatedBlockComment)
     }
 
+    /// Tokens are lexed again if the parser backtracks, so directives which
+    /// are already recorded are ignored.
+    fn add_ts_directive(&self, kind: TsDirectiveKind, span: Span, arg: Option<Atom>) {
+        let mut directives = self.ts_directives.borrow_mut();
+
+        if directives
+            .last()
+            .is_some_and(|last| last.span.lo >= span.lo)
+        {
+            return;
+        }
+
+        directives.push(TsDirective { kind, span, arg });
+    }
+
     #[inline(never)]
     fn store_comment(
         &mut self,
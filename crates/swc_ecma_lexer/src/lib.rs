@@ -33,6 +33,10 @@ pub enum Syntax {
     #[cfg_attr(docsrs, doc(cfg(feature = "typescript")))]
     #[serde(rename = "typescript")]
     Typescript(TsSyntax),
+    /// Flow types are parsed as typescript types, so they can be stripped
+    /// like typescript.
+    #[serde(rename = "flow")]
+    Flow(FlowSyntax),
 }
 
 impl Default for Syntax {
@@ -61,6 +65,22 @@ impl Syntax {
             }) => import_attributes,
             #[cfg(feature = "typescript")]
             Syntax::Typescript(_) => true,
+            Syntax::Flow(_) => false,
+        }
+    }
+
+    pub fn warn_import_assertions(self) -> bool {
+        match self {
+            Syntax::Es(EsSyntax {
+                warn_import_assertions,
+                ..
+            }) => warn_import_assertions,
+            #[cfg(feature = "typescript")]
+            Syntax::Typescript(TsSyntax {
+                warn_import_assertions,
+                ..
+            }) => warn_import_assertions,
+            Syntax::Flow(_) => false,
         }
     }
 
@@ -70,6 +90,7 @@ impl Syntax {
             Syntax::Es(EsSyntax { jsx: true, .. }) => true,
             #[cfg(feature = "typescript")]
             Syntax::Typescript(TsSyntax { tsx: true, .. }) => true,
+            Syntax::Flow(FlowSyntax { jsx: true, .. }) => true,
             _ => false,
         }
     }
@@ -87,6 +108,9 @@ impl Syntax {
             Syntax::Typescript(TsSyntax {
                 decorators: true, ..
             }) => true,
+            Syntax::Flow(FlowSyntax {
+                decorators: true, ..
+            }) => true,
             _ => false,
         }
     }
@@ -115,6 +139,46 @@ impl Syntax {
         matches!(self, Syntax::Typescript(..))
     }
 
+    /// Should we parse flow?
+    pub const fn flow(self) -> bool {
+        matches!(self, Syntax::Flow(..))
+    }
+
+    /// Should we parse type annotations, like `let a: T`?
+    pub const fn type_annotations(self) -> bool {
+        self.typescript() || self.flow()
+    }
+
+    pub fn pipeline_operator(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsSyntax {
+                pipeline_operator: true,
+                ..
+            })
+        )
+    }
+
+    pub fn do_expressions(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsSyntax {
+                do_expressions: true,
+                ..
+            })
+        )
+    }
+
+    pub fn explicit_proposals(self) -> bool {
+        matches!(
+            self,
+            Syntax::Es(EsSyntax {
+                explicit_proposals: true,
+                ..
+            })
+        )
+    }
+
     pub fn export_default_from(self) -> bool {
         matches!(
             self,
@@ -141,6 +205,7 @@ impl Syntax {
             }) => allow_super_outside_method,
             #[cfg(feature = "typescript")]
             Syntax::Typescript(_) => true,
+            Syntax::Flow(_) => false,
         }
     }
 
@@ -152,6 +217,7 @@ impl Syntax {
             }) => allow_return_outside_function,
             #[cfg(feature = "typescript")]
             Syntax::Typescript(_) => false,
+            Syntax::Flow(_) => false,
         }
     }
 
@@ -159,7 +225,7 @@ impl Syntax {
         match self {
             #[cfg(feature = "typescript")]
             Syntax::Typescript(t) => !t.no_early_errors,
-            Syntax::Es(..) => true,
+            Syntax::Es(..) | Syntax::Flow(..) => true,
         }
     }
 
@@ -171,6 +237,21 @@ impl Syntax {
         }
     }
 
+    pub fn preserve_parens(self) -> bool {
+        match self {
+            Syntax::Es(EsSyntax {
+                preserve_parens, ..
+            }) => preserve_parens,
+            #[cfg(feature = "typescript")]
+            Syntax::Typescript(TsSyntax {
+                preserve_parens, ..
+            }) => preserve_parens,
+            Syntax::Flow(FlowSyntax {
+                preserve_parens, ..
+            }) => preserve_parens,
+        }
+    }
+
     pub fn explicit_resource_management(&self) -> bool {
         match self {
             Syntax::Es(EsSyntax {
@@ -178,7 +259,22 @@ impl Syntax {
                 ..
             }) => *using_decl,
             #[cfg(feature = "typescript")]
-            Syntax::Typescript(_) => true,
+            Syntax::Typescript(_) => self.supports_ts_version(TsVersion::USING_DECLARATIONS),
+            Syntax::Flow(_) => false,
+        }
+    }
+
+    /// Returns `false` if [TsSyntax::ts_version] is older than `version`.
+    ///
+    /// Always `true` for non-typescript syntax.
+    pub fn supports_ts_version(self, version: TsVersion) -> bool {
+        match self {
+            #[cfg(feature = "typescript")]
+            Syntax::Typescript(TsSyntax {
+                ts_version: Some(ts_version),
+                ..
+            }) => ts_version >= version,
+            _ => true,
         }
     }
 }
@@ -199,6 +295,10 @@ pub struct TsSyntax {
     #[serde(skip, default)]
     pub no_early_errors: bool,
 
+    /// See [EsSyntax::preserve_parens].
+    #[serde(default)]
+    pub preserve_parens: bool,
+
     /// babel: `disallowAmbiguousJSXLike`
     /// Even when JSX parsing is not enabled, this option disallows using syntax
     /// that would be ambiguous with JSX (`<X> y` type assertions and
@@ -206,6 +306,96 @@ pub struct TsSyntax {
     /// see: https://babeljs.io/docs/en/babel-plugin-transform-typescript#disallowambiguousjsxlike
     #[serde(skip, default)]
     pub disallow_ambiguous_jsx_like: bool,
+
+    /// Version of TypeScript to match, like `"5.0"`.
+    ///
+    /// Syntax added in later versions of TypeScript is rejected. If [None],
+    /// all syntax supported by swc is accepted.
+    #[serde(default)]
+    pub ts_version: Option<TsVersion>,
+
+    /// See [EsSyntax::warn_import_assertions].
+    #[serde(default)]
+    pub warn_import_assertions: bool,
+}
+
+/// Flow syntax.
+///
+/// Flow types are represented with the typescript nodes of
+/// [swc_ecma_ast], like `?T` as `T | null | undefined` and `mixed` as
+/// `unknown`. Unnamed parameters of function types, like `(string) => void`,
+/// are named `_0`, `_1` and so on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlowSyntax {
+    #[serde(default)]
+    pub jsx: bool,
+
+    #[serde(default)]
+    pub decorators: bool,
+
+    /// See [EsSyntax::preserve_parens].
+    #[serde(default)]
+    pub preserve_parens: bool,
+}
+
+/// `major.minor` version of TypeScript.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TsVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl TsVersion {
+    /// `accessor` fields.
+    pub const AUTO_ACCESSORS: Self = Self::new(4, 9);
+    /// `const` type parameters.
+    pub const CONST_TYPE_PARAMS: Self = Self::new(5, 0);
+    /// `satisfies` expressions.
+    pub const SATISFIES: Self = Self::new(4, 9);
+    /// `using` and `await using` declarations.
+    pub const USING_DECLARATIONS: Self = Self::new(5, 2);
+    /// `in` and `out` variance annotations on type parameters.
+    pub const VARIANCE_ANNOTATIONS: Self = Self::new(4, 7);
+
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl std::fmt::Display for TsVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+impl std::str::FromStr for TsVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('.');
+        let mut next = || parts.next().and_then(|v| v.parse().ok());
+
+        match (next(), next()) {
+            (Some(major), minor) => Ok(Self::new(major, minor.unwrap_or(0))),
+            _ => Err(format!("invalid typescript version: `{}`", s)),
+        }
+    }
+}
+
+impl TryFrom<String> for TsVersion {
+    type Error = String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<TsVersion> for String {
+    fn from(v: TsVersion) -> Self {
+        v.to_string()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
@@ -237,6 +427,13 @@ pub struct EsSyntax {
     #[serde(default, alias = "importAssertions")]
     pub import_attributes: bool,
 
+    /// Report the deprecated `assert` keyword of import attributes, like
+    /// `import foo from "./foo.json" assert { type: "json" }`.
+    ///
+    /// The clause is parsed like `with` regardless of this option.
+    #[serde(default)]
+    pub warn_import_assertions: bool,
+
     #[serde(default, rename = "allowSuperOutsideMethod")]
     pub allow_super_outside_method: bool,
 
@@ -248,6 +445,29 @@ pub struct EsSyntax {
 
     #[serde(default)]
     pub explicit_resource_management: bool,
+
+    /// Parse the hack-style pipeline operator, like `a |> f(%)`.
+    #[serde(default)]
+    pub pipeline_operator: bool,
+
+    /// Parse do expressions, like `let x = do { ... }`.
+    ///
+    /// babel: `doExpressions`
+    #[serde(default)]
+    pub do_expressions: bool,
+
+    /// Parse early-stage proposals which are only supported when they are
+    /// requested explicitly. Currently this is the `match` expression of the
+    /// pattern matching proposal.
+    #[serde(default)]
+    pub explicit_proposals: bool,
+
+    /// Keep parentheses as [swc_ecma_ast::ParenExpr] even where they are not
+    /// needed to represent the syntax, like `@(foo)`.
+    ///
+    /// babel: `createParenthesizedExpressions`
+    #[serde(default)]
+    pub preserve_parens: bool,
 }
 
 bitflags::bitflags! {
@@ -377,6 +597,9 @@ macro_rules! tok {
     ("||") => {
         $crate::token::Token::BinOp($crate::token::BinOpToken::LogicalOr)
     };
+    ("|>") => {
+        $crate::token::Token::Pipeline
+    };
     ("&&=") => {
         $crate::token::Token::AssignOp(swc_ecma_ast::AssignOp::AndAssign)
     };
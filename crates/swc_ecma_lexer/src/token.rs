@@ -195,6 +195,7 @@ pub enum TokenKind {
     PlusPlus,
     MinusMinus,
     Tilde,
+    Pipeline,
     Str,
     /// We abuse `token.raw` for flags
     Regex,
@@ -276,6 +277,9 @@ pub enum Token {
     /// `~`
     Tilde,
 
+    /// `|>`
+    Pipeline,
+
     /// String literal. Span of this token contains quote.
     Str {
         value: Atom,
@@ -337,6 +341,7 @@ impl Token {
             Self::PlusPlus => TokenKind::PlusPlus,
             Self::MinusMinus => TokenKind::MinusMinus,
             Self::Tilde => TokenKind::Tilde,
+            Self::Pipeline => TokenKind::Pipeline,
             Self::Str { .. } => TokenKind::Str,
             Self::Regex(..) => TokenKind::Regex,
             Self::Num { .. } => TokenKind::Num,
@@ -372,6 +377,7 @@ impl TokenKind {
             | Self::PlusPlus
             | Self::MinusMinus
             | Self::Tilde
+            | Self::Pipeline
             | Self::JSXText { .. } => true,
             _ => false,
         }
@@ -977,6 +983,7 @@ impl Debug for Token {
             PlusPlus => write!(f, "++")?,
             MinusMinus => write!(f, "--")?,
             Tilde => write!(f, "~")?,
+            Pipeline => write!(f, "|>")?,
             Str { value, raw } => write!(f, "string literal ({}, {})", value, raw)?,
             Regex(exp, flags) => write!(f, "regexp literal ({}, {})", exp, flags)?,
             Num { value, raw, .. } => write!(f, "numeric literal ({}, {})", value, raw)?,
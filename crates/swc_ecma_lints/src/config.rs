@@ -1,13 +1,18 @@
 use std::fmt::Debug;
 
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
 use swc_config::merge::Merge;
 
+#[cfg(feature = "non_critical_lints")]
+use crate::import_cycle::ImportCycleConfig;
 #[cfg(feature = "non_critical_lints")]
 use crate::rules::non_critical_lints::{
-    dot_notation::DotNotationConfig, eqeqeq::EqeqeqConfig, no_bitwise::NoBitwiseConfig,
-    no_console::NoConsoleConfig, no_empty_function::NoEmptyFunctionConfig,
+    consistent_type_imports::ConsistentTypeImportsConfig, dot_notation::DotNotationConfig,
+    eqeqeq::EqeqeqConfig, no_bitwise::NoBitwiseConfig, no_console::NoConsoleConfig,
+    no_empty_function::NoEmptyFunctionConfig, no_namespace::NoNamespaceConfig,
     no_param_reassign::NoParamReassignConfig, no_restricted_syntax::NoRestrictedSyntaxConfig,
+    no_unused_imports::NoUnusedImportsConfig, no_unused_vars::NoUnusedVarsConfig,
     no_use_before_define::NoUseBeforeDefineConfig, prefer_const::PreferConstConfig,
     prefer_regex_literals::PreferRegexLiteralsConfig, quotes::QuotesConfig, radix::RadixConfig,
     symbol_description::SymbolDescriptionConfig, use_is_nan::UseIsNanConfig,
@@ -18,6 +23,7 @@ use crate::rules::non_critical_lints::{
 #[serde(rename_all = "lowercase")]
 pub enum LintRuleReaction {
     Off,
+    #[serde(alias = "warn")]
     Warning,
     Error,
 }
@@ -63,11 +69,11 @@ pub struct RuleConfig<T: Debug + Clone + Serialize + Default>(
 );
 
 impl<T: Debug + Clone + Serialize + Default> RuleConfig<T> {
-    pub(crate) fn get_rule_reaction(&self) -> LintRuleReaction {
+    pub fn get_rule_reaction(&self) -> LintRuleReaction {
         self.0.into()
     }
 
-    pub(crate) fn get_rule_config(&self) -> &T {
+    pub fn get_rule_config(&self) -> &T {
         &self.1
     }
 }
@@ -225,4 +231,46 @@ pub struct LintConfig {
     #[cfg(feature = "non_critical_lints")]
     #[serde(default, alias = "preferObjectSpread")]
     pub prefer_object_spread: RuleConfig<()>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noUnusedVars")]
+    pub no_unused_vars: RuleConfig<NoUnusedVarsConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noUnusedImports")]
+    pub no_unused_imports: RuleConfig<NoUnusedImportsConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "consistentTypeImports")]
+    pub consistent_type_imports: RuleConfig<ConsistentTypeImportsConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noNamespace")]
+    pub no_namespace: RuleConfig<NoNamespaceConfig>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "preferAsConst")]
+    pub prefer_as_const: RuleConfig<()>,
+
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "noNonNullAssertedOptionalChain")]
+    pub no_non_null_asserted_optional_chain: RuleConfig<()>,
+
+    /// Checked by [crate::import_cycle::check_import_cycles].
+    #[cfg(feature = "non_critical_lints")]
+    #[serde(default, alias = "importCycle")]
+    pub import_cycle: RuleConfig<ImportCycleConfig>,
+
+    /// Severity of `swc-lint-disable` comments which don't suppress any
+    /// problem. Defaults to `warning`.
+    #[serde(default, alias = "reportUnusedDisableDirectives")]
+    pub report_unused_disable_directives: Option<LintRuleReaction>,
+
+    /// Configuration of rules which are not built into swc, keyed by the name
+    /// used to register them in [crate::registry::RuleRegistry].
+    ///
+    /// These are kept under their own key, so a misspelled name of a builtin
+    /// rule is not mistaken for a custom rule.
+    #[serde(default)]
+    pub custom: FxHashMap<String, RuleConfig<serde_json::Value>>,
 }
@@ -0,0 +1,193 @@
+//! Comments which disable lint rules.
+//!
+//! - `// swc-lint-disable-next-line [rule, ...]` disables rules for the next
+//!   line.
+//! - `/* swc-lint-disable [rule, ...] */` disables rules for the whole file.
+//!   It's ignored if it's placed after the first statement.
+//!
+//! If no rule is listed, all rules are disabled. Text after `--` is a
+//! description and is ignored.
+
+use swc_common::{
+    comments::Comment,
+    errors::{Diagnostic, DiagnosticId, Level},
+    BytePos, SourceMap, Span, Spanned,
+};
+use swc_ecma_ast::Program;
+
+use crate::config::LintRuleReaction;
+
+const DISABLE_NEXT_LINE: &str = "swc-lint-disable-next-line";
+const DISABLE: &str = "swc-lint-disable";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Scope {
+    File,
+    Line(usize),
+}
+
+#[derive(Debug)]
+struct Directive {
+    span: Span,
+    scope: Scope,
+    /// Rules to disable, and whether a problem of the rule was suppressed.
+    /// Empty if all rules are disabled.
+    rules: Vec<(String, bool)>,
+    used: bool,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct DisableDirectives {
+    directives: Vec<Directive>,
+}
+
+impl DisableDirectives {
+    pub(crate) fn parse(cm: &SourceMap, program: &Program, comments: &[Comment]) -> Self {
+        let body_start = match program {
+            Program::Module(m) => m.body.first().map(|item| item.span_lo()),
+            Program::Script(s) => s.body.first().map(|stmt| stmt.span_lo()),
+        };
+
+        let directives = comments
+            .iter()
+            .filter_map(|c| parse_directive(cm, c, body_start))
+            .collect();
+
+        Self { directives }
+    }
+
+    fn suppress(&mut self, cm: &SourceMap, d: &Diagnostic) -> bool {
+        let Some(span) = d.span.primary_span() else {
+            return false;
+        };
+        let line = if span.is_dummy() {
+            None
+        } else {
+            Some(cm.lookup_char_pos(span.lo).line)
+        };
+        let rule = match &d.code {
+            Some(DiagnosticId::Lint(name)) => Some(&**name),
+            _ => None,
+        };
+
+        let mut suppressed = false;
+
+        for directive in &mut self.directives {
+            let in_scope = match directive.scope {
+                Scope::File => true,
+                Scope::Line(l) => line == Some(l),
+            };
+            if !in_scope {
+                continue;
+            }
+
+            if directive.rules.is_empty() {
+                directive.used = true;
+                suppressed = true;
+            } else if let Some((_, used)) = directive
+                .rules
+                .iter_mut()
+                .find(|(name, _)| Some(&**name) == rule)
+            {
+                *used = true;
+                suppressed = true;
+            }
+        }
+
+        suppressed
+    }
+
+    /// Removes suppressed diagnostics, and adds diagnostics for directives
+    /// which suppressed nothing.
+    pub(crate) fn apply(
+        mut self,
+        cm: &SourceMap,
+        diagnostics: Vec<Diagnostic>,
+        unused_reaction: LintRuleReaction,
+    ) -> Vec<Diagnostic> {
+        if self.directives.is_empty() {
+            return diagnostics;
+        }
+
+        let mut diagnostics = diagnostics
+            .into_iter()
+            .filter(|d| !self.suppress(cm, d))
+            .collect::<Vec<_>>();
+
+        let level = match unused_reaction {
+            LintRuleReaction::Off => return diagnostics,
+            LintRuleReaction::Warning => Level::Warning,
+            LintRuleReaction::Error => Level::Error,
+        };
+
+        for directive in self.directives {
+            if directive.rules.is_empty() {
+                if !directive.used {
+                    diagnostics.push(unused(
+                        level,
+                        directive.span,
+                        "Unused swc-lint-disable directive (no problems were reported)",
+                    ));
+                }
+                continue;
+            }
+
+            for (rule, used) in directive.rules {
+                if !used {
+                    diagnostics.push(unused(
+                        level,
+                        directive.span,
+                        &format!(
+                            "Unused swc-lint-disable directive (no problems were reported from \
+                             '{}')",
+                            rule
+                        ),
+                    ));
+                }
+            }
+        }
+
+        diagnostics.sort_by_key(|d| d.span.primary_span());
+        diagnostics
+    }
+}
+
+fn unused(level: Level, span: Span, message: &str) -> Diagnostic {
+    let mut d = Diagnostic::new(level, message);
+    d.set_span(span);
+    d
+}
+
+fn parse_directive(cm: &SourceMap, c: &Comment, body_start: Option<BytePos>) -> Option<Directive> {
+    let text = c.text.trim();
+
+    let (rest, scope) = if let Some(rest) = text.strip_prefix(DISABLE_NEXT_LINE) {
+        (rest, Scope::Line(cm.lookup_char_pos(c.span.hi).line + 1))
+    } else {
+        // File-level directives must precede the code they apply to.
+        if body_start.is_some_and(|start| c.span.lo > start) {
+            return None;
+        }
+        (text.strip_prefix(DISABLE)?, Scope::File)
+    };
+
+    // `swc-lint-disable-foo` is not a directive.
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let rules = rest.split("--").next().unwrap_or_default();
+    let rules = rules
+        .split(',')
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(|r| (r.to_string(), false))
+        .collect();
+
+    Some(Directive {
+        span: c.span,
+        scope,
+        rules,
+        used: false,
+    })
+}
@@ -0,0 +1,201 @@
+//! Automatic fixes for lint problems.
+//!
+//! Rules attach fixes to their diagnostics as suggestions (see
+//! [emit_with_fix]). [collect_fixes] extracts them again, and [apply_fixes]
+//! performs them on the source text.
+
+use swc_common::{
+    errors::{Applicability, Diagnostic, HANDLER},
+    SourceFile, Span,
+};
+
+use crate::config::LintRuleReaction;
+
+/// Replaces the source text at `span` with `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fix {
+    pub span: Span,
+    pub replacement: String,
+}
+
+impl Fix {
+    pub fn remove(span: Span) -> Self {
+        Self {
+            span,
+            replacement: String::new(),
+        }
+    }
+
+    pub fn replace(span: Span, replacement: impl Into<String>) -> Self {
+        Self {
+            span,
+            replacement: replacement.into(),
+        }
+    }
+
+    pub fn insert_before(span: Span, text: impl Into<String>) -> Self {
+        Self::replace(span.shrink_to_lo(), text)
+    }
+
+    pub fn insert_after(span: Span, text: impl Into<String>) -> Self {
+        Self::replace(span.shrink_to_hi(), text)
+    }
+}
+
+/// A set of edits which fixes a single problem. The edits are applied
+/// together or not at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixSuggestion {
+    pub message: String,
+    pub fixes: Vec<Fix>,
+    /// [Applicability::MachineApplicable] means the fix is safe to apply
+    /// without review. Other values are only applied on request.
+    pub applicability: Applicability,
+}
+
+impl FixSuggestion {
+    pub fn new(message: impl Into<String>, fixes: Vec<Fix>, applicability: Applicability) -> Self {
+        Self {
+            message: message.into(),
+            fixes,
+            applicability,
+        }
+    }
+
+    pub fn is_safe(&self) -> bool {
+        self.applicability == Applicability::MachineApplicable
+    }
+
+    fn range(&self) -> Option<(u32, u32)> {
+        let lo = self.fixes.iter().map(|f| f.span.lo.0).min()?;
+        let hi = self.fixes.iter().map(|f| f.span.hi.0).max()?;
+        Some((lo, hi))
+    }
+}
+
+/// Reports a problem to [HANDLER], attaching `suggestion` so it can be fixed
+/// automatically.
+pub fn emit_with_fix(
+    reaction: LintRuleReaction,
+    span: Span,
+    message: &str,
+    suggestion: Option<FixSuggestion>,
+) {
+    HANDLER.with(|handler| {
+        let mut builder = match reaction {
+            LintRuleReaction::Error => handler.struct_span_err(span, message),
+            LintRuleReaction::Warning => handler.struct_span_warn(span, message),
+            LintRuleReaction::Off => return,
+        };
+
+        if let Some(suggestion) = suggestion {
+            builder.multipart_suggestion_with_applicability(
+                &suggestion.message,
+                suggestion
+                    .fixes
+                    .into_iter()
+                    .map(|fix| (fix.span, fix.replacement))
+                    .collect(),
+                suggestion.applicability,
+            );
+        }
+
+        builder.emit();
+    });
+}
+
+/// Extracts the fixes attached to a diagnostic.
+///
+/// Only the first substitution of each suggestion is used. If `include_unsafe`
+/// is `false`, fixes which are not [Applicability::MachineApplicable] are
+/// ignored.
+pub fn collect_fixes(diagnostic: &Diagnostic, include_unsafe: bool) -> Vec<FixSuggestion> {
+    diagnostic
+        .suggestions
+        .iter()
+        .filter(|s| include_unsafe || s.applicability == Applicability::MachineApplicable)
+        .filter_map(|s| {
+            let substitution = s.substitutions.first()?;
+
+            Some(FixSuggestion {
+                message: s.msg.clone(),
+                fixes: substitution
+                    .parts
+                    .iter()
+                    .map(|part| Fix::replace(part.span, part.snippet.clone()))
+                    .collect(),
+                applicability: s.applicability,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedSource {
+    pub code: String,
+    /// Number of suggestions which were applied.
+    pub applied: usize,
+    /// Number of suggestions which were skipped because they overlap with an
+    /// applied one or point outside of the file. Linting the fixed code again
+    /// may make them applicable.
+    pub skipped: usize,
+}
+
+/// Applies non-overlapping fixes to the source text of `fm`.
+///
+/// Suggestions are applied in the order of their position. If a suggestion
+/// overlaps with one which is already applied, it's skipped entirely.
+pub fn apply_fixes(fm: &SourceFile, mut suggestions: Vec<FixSuggestion>) -> FixedSource {
+    suggestions.retain(|s| !s.fixes.is_empty());
+    suggestions.sort_by_key(|s| s.range());
+
+    let start = fm.start_pos.0;
+    let end = fm.end_pos.0;
+    let src = &**fm.src;
+
+    let mut edits = Vec::new();
+    let mut last_hi = None;
+    let mut skipped = 0;
+    let mut applied = 0;
+
+    for s in suggestions {
+        let (lo, hi) = s.range().unwrap();
+
+        let in_file = lo >= start && hi <= end;
+        let overlaps = matches!(last_hi, Some(last_hi) if lo < last_hi);
+        if !in_file || overlaps {
+            skipped += 1;
+            continue;
+        }
+
+        last_hi = Some(hi);
+        applied += 1;
+        edits.extend(s.fixes);
+    }
+
+    // Edits of a single suggestion may be unordered.
+    edits.sort_by_key(|f| (f.span.lo, f.span.hi));
+
+    let mut code = String::with_capacity(src.len());
+    let mut pos = 0;
+
+    for fix in edits {
+        let lo = (fix.span.lo.0 - start) as usize;
+        let hi = (fix.span.hi.0 - start) as usize;
+
+        if lo < pos {
+            continue;
+        }
+
+        code.push_str(&src[pos..lo]);
+        code.push_str(&fix.replacement);
+        pos = hi;
+    }
+    code.push_str(&src[pos..]);
+
+    FixedSource {
+        code,
+        applied,
+        skipped,
+    }
+}
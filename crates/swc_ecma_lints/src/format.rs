@@ -0,0 +1,406 @@
+//! Output formats for lint results, compatible with the formatters of ESLint.
+//!
+//! Tools which consume the output of ESLint (CI annotations, editor
+//! integrations, code scanning) can use the output of swc without changes.
+
+use std::{fmt::Write, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use swc_common::{
+    errors::{Diagnostic, DiagnosticId, Level},
+    SourceMap, Span,
+};
+
+use crate::fix::collect_fixes;
+
+/// Diagnostics of a single file.
+#[derive(Debug, Clone)]
+pub struct LintResult {
+    pub file_path: String,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    #[default]
+    Stylish,
+    Compact,
+    Json,
+    Sarif,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "stylish" => Ok(Self::Stylish),
+            "compact" => Ok(Self::Compact),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            _ => Err(format!(
+                "unknown format `{}`; expected one of stylish, compact, json, sarif",
+                s
+            )),
+        }
+    }
+}
+
+impl OutputFormat {
+    pub fn format(self, cm: &SourceMap, results: &[LintResult]) -> String {
+        let messages = results
+            .iter()
+            .map(|r| FileMessages::new(cm, r))
+            .collect::<Vec<_>>();
+
+        match self {
+            OutputFormat::Stylish => stylish(&messages),
+            OutputFormat::Compact => compact(&messages),
+            OutputFormat::Json => json(&messages),
+            OutputFormat::Sarif => sarif(&messages),
+        }
+    }
+}
+
+/// A diagnostic with resolved positions.
+struct Message {
+    rule_id: Option<String>,
+    is_error: bool,
+    message: String,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    fix: Option<JsonFix>,
+}
+
+struct FileMessages<'a> {
+    file_path: &'a str,
+    messages: Vec<Message>,
+}
+
+impl<'a> FileMessages<'a> {
+    fn new(cm: &SourceMap, result: &'a LintResult) -> Self {
+        let messages = result
+            .diagnostics
+            .iter()
+            .filter(|d| !matches!(d.level, Level::Cancelled))
+            .map(|d| Message::new(cm, d))
+            .collect();
+
+        Self {
+            file_path: &result.file_path,
+            messages,
+        }
+    }
+
+    fn count(&self, is_error: bool) -> usize {
+        self.messages
+            .iter()
+            .filter(|m| m.is_error == is_error)
+            .count()
+    }
+
+    fn fixable_count(&self, is_error: bool) -> usize {
+        self.messages
+            .iter()
+            .filter(|m| m.is_error == is_error && m.fix.is_some())
+            .count()
+    }
+}
+
+impl Message {
+    fn new(cm: &SourceMap, d: &Diagnostic) -> Self {
+        let span = d.span.primary_span().unwrap_or_default();
+        let (line, column, end_line, end_column) = if span.is_dummy() {
+            (0, 0, 0, 0)
+        } else {
+            let lo = cm.lookup_char_pos(span.lo);
+            let hi = cm.lookup_char_pos(span.hi);
+            (lo.line, lo.col.0 + 1, hi.line, hi.col.0 + 1)
+        };
+
+        Self {
+            rule_id: match &d.code {
+                Some(DiagnosticId::Lint(name)) => Some(name.clone()),
+                _ => None,
+            },
+            is_error: d.is_error(),
+            message: d.message(),
+            line,
+            column,
+            end_line,
+            end_column,
+            fix: single_fix(cm, d),
+        }
+    }
+
+    fn severity(&self) -> &'static str {
+        if self.is_error {
+            "error"
+        } else {
+            "warning"
+        }
+    }
+}
+
+/// ESLint represents a fix as a single replaced range, so only safe fixes
+/// with one edit are reported.
+fn single_fix(cm: &SourceMap, d: &Diagnostic) -> Option<JsonFix> {
+    let suggestion = collect_fixes(d, false).into_iter().next()?;
+    let [fix] = &*suggestion.fixes else {
+        return None;
+    };
+
+    Some(JsonFix {
+        range: [offset(cm, fix.span)?, offset(cm, fix.span.shrink_to_hi())?],
+        text: fix.replacement.clone(),
+    })
+}
+
+fn offset(cm: &SourceMap, span: Span) -> Option<u32> {
+    let fm = cm.try_lookup_source_file(span.lo).ok()?;
+    Some(span.lo.0 - fm.start_pos.0)
+}
+
+fn pluralize(word: &str, count: usize) -> String {
+    if count == 1 {
+        word.to_string()
+    } else {
+        format!("{}s", word)
+    }
+}
+
+fn stylish(files: &[FileMessages]) -> String {
+    let mut out = String::new();
+    let (mut errors, mut warnings) = (0, 0);
+    let (mut fixable_errors, mut fixable_warnings) = (0, 0);
+
+    for file in files.iter().filter(|f| !f.messages.is_empty()) {
+        errors += file.count(true);
+        warnings += file.count(false);
+        fixable_errors += file.fixable_count(true);
+        fixable_warnings += file.fixable_count(false);
+
+        let rows = file
+            .messages
+            .iter()
+            .map(|m| {
+                [
+                    format!("{}:{}", m.line, m.column),
+                    m.severity().to_string(),
+                    m.message.clone(),
+                    m.rule_id.clone().unwrap_or_default(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = [0; 4];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let _ = writeln!(out, "\n{}", file.file_path);
+        for [pos, severity, message, rule] in rows {
+            let _ = writeln!(
+                out,
+                "  {:<w0$}  {:<w1$}  {:<w2$}  {}",
+                pos,
+                severity,
+                message,
+                rule,
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+            );
+        }
+    }
+
+    let total = errors + warnings;
+    if total > 0 {
+        let _ = writeln!(
+            out,
+            "\n\u{2716} {} {} ({} {}, {} {})",
+            total,
+            pluralize("problem", total),
+            errors,
+            pluralize("error", errors),
+            warnings,
+            pluralize("warning", warnings),
+        );
+
+        if fixable_errors > 0 || fixable_warnings > 0 {
+            let _ = writeln!(
+                out,
+                "  {} {} and {} {} potentially fixable with the `--fix` option.",
+                fixable_errors,
+                pluralize("error", fixable_errors),
+                fixable_warnings,
+                pluralize("warning", fixable_warnings),
+            );
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
+fn compact(files: &[FileMessages]) -> String {
+    let mut out = String::new();
+    let mut total = 0;
+
+    for file in files {
+        for m in &file.messages {
+            total += 1;
+
+            let _ = write!(
+                out,
+                "{}: line {}, col {}, {} - {}",
+                file.file_path,
+                m.line,
+                m.column,
+                if m.is_error { "Error" } else { "Warning" },
+                m.message,
+            );
+            if let Some(rule_id) = &m.rule_id {
+                let _ = write!(out, " ({})", rule_id);
+            }
+            out.push('\n');
+        }
+    }
+
+    if total > 0 {
+        let _ = writeln!(out, "\n{} {}", total, pluralize("problem", total));
+    }
+
+    out
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonResult<'a> {
+    file_path: &'a str,
+    messages: Vec<JsonMessage<'a>>,
+    error_count: usize,
+    fatal_error_count: usize,
+    warning_count: usize,
+    fixable_error_count: usize,
+    fixable_warning_count: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonMessage<'a> {
+    rule_id: Option<&'a str>,
+    /// `1` for warnings and `2` for errors.
+    severity: u8,
+    message: &'a str,
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fix: Option<&'a JsonFix>,
+}
+
+#[derive(Serialize)]
+struct JsonFix {
+    range: [u32; 2],
+    text: String,
+}
+
+fn json(files: &[FileMessages]) -> String {
+    let results = files
+        .iter()
+        .map(|file| JsonResult {
+            file_path: file.file_path,
+            messages: file
+                .messages
+                .iter()
+                .map(|m| JsonMessage {
+                    rule_id: m.rule_id.as_deref(),
+                    severity: if m.is_error { 2 } else { 1 },
+                    message: &m.message,
+                    line: m.line,
+                    column: m.column,
+                    end_line: m.end_line,
+                    end_column: m.end_column,
+                    fix: m.fix.as_ref(),
+                })
+                .collect(),
+            error_count: file.count(true),
+            fatal_error_count: 0,
+            warning_count: file.count(false),
+            fixable_error_count: file.fixable_count(true),
+            fixable_warning_count: file.fixable_count(false),
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::to_string(&results).unwrap()
+}
+
+fn sarif(files: &[FileMessages]) -> String {
+    let mut rules = Vec::<&str>::new();
+    for m in files.iter().flat_map(|f| &f.messages) {
+        if let Some(rule_id) = &m.rule_id {
+            if !rules.contains(&&**rule_id) {
+                rules.push(rule_id);
+            }
+        }
+    }
+
+    let mut results = Vec::new();
+    for (index, file) in files.iter().enumerate() {
+        for m in &file.messages {
+            let mut result = serde_json::json!({
+                "level": m.severity(),
+                "message": { "text": m.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": file.file_path, "index": index },
+                        "region": {
+                            "startLine": m.line,
+                            "startColumn": m.column,
+                            "endLine": m.end_line,
+                            "endColumn": m.end_column,
+                        },
+                    },
+                }],
+            });
+
+            if let Some(rule_id) = &m.rule_id {
+                result["ruleId"] = rule_id.as_str().into();
+                result["ruleIndex"] = rules.iter().position(|r| r == rule_id).into();
+            }
+
+            results.push(result);
+        }
+    }
+
+    let log = serde_json::json!({
+        "version": "2.1.0",
+        "$schema": "http://json.schemastore.org/sarif-2.1.0-rtm.5",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "swc",
+                    "informationUri": "https://swc.rs",
+                    "rules": rules
+                        .iter()
+                        .map(|id| serde_json::json!({ "id": id }))
+                        .collect::<Vec<_>>(),
+                },
+            },
+            "artifacts": files
+                .iter()
+                .map(|f| serde_json::json!({ "location": { "uri": f.file_path } }))
+                .collect::<Vec<_>>(),
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&log).unwrap()
+}
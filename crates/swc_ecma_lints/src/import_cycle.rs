@@ -0,0 +1,149 @@
+//! Detection of import cycles between the files of a project.
+//!
+//! Unlike other rules, this one needs all files at once, so it's not part of
+//! [crate::registry::RuleRegistry]. Use [check_import_cycles] after linting
+//! the files.
+
+use regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+use serde::{Deserialize, Serialize};
+use swc_common::{
+    errors::{Diagnostic, DiagnosticId, Level},
+    FileName,
+};
+use swc_ecma_ast::*;
+use swc_ecma_loader::resolve::Resolve;
+use swc_graph_analyzer::module_graph::{Import, ImportKind, ModuleGraphBuilder};
+
+use crate::config::{LintRuleReaction, RuleConfig};
+
+pub const RULE_NAME: &str = "import-cycle";
+
+const INVALID_REGEX_MESSAGE: &str = "import-cycle: invalid regex pattern in allowedCycles. Check syntax documentation https://docs.rs/regex/latest/regex/#syntax";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportCycleConfig {
+    /// Cycles which are not reported. Each entry is a list of regex patterns
+    /// for file paths. A cycle is allowed if each of its files matches a
+    /// pattern and each pattern matches a file.
+    allowed_cycles: Option<Vec<Vec<String>>>,
+    /// Report cycles formed by type-only imports and exports. They are erased
+    /// during compilation, so they are ignored by default.
+    include_type_imports: Option<bool>,
+}
+
+/// A file of the project, already linted.
+pub struct ProjectFile<'a> {
+    pub file_name: FileName,
+    pub program: &'a Program,
+}
+
+/// Finds import cycles between `files`.
+///
+/// Imports are resolved with `resolver`, and imports of files which are not in
+/// `files` are ignored. Each import which is part of a cycle is reported with
+/// the full chain of files. The returned vector has the same order as `files`.
+pub fn check_import_cycles(
+    config: &RuleConfig<ImportCycleConfig>,
+    files: &[ProjectFile],
+    resolver: &dyn Resolve,
+) -> Vec<Vec<Diagnostic>> {
+    let mut results = Vec::with_capacity(files.len());
+    results.resize_with(files.len(), Vec::new);
+
+    let level = match config.get_rule_reaction() {
+        LintRuleReaction::Off => return results,
+        LintRuleReaction::Warning => Level::Warning,
+        LintRuleReaction::Error => Level::Error,
+    };
+
+    let rule_config = config.get_rule_config();
+    let include_type_imports = rule_config.include_type_imports.unwrap_or(false);
+    let allowed_cycles = rule_config
+        .allowed_cycles
+        .iter()
+        .flatten()
+        .map(|patterns| {
+            patterns
+                .iter()
+                .map(|p| Regex::new(p).expect(INVALID_REGEX_MESSAGE))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut builder = ModuleGraphBuilder::new(resolver);
+    let ids = files
+        .iter()
+        .map(|f| builder.add_module(&f.file_name, f.program))
+        .collect::<Vec<_>>();
+    let graph = builder.build();
+
+    // Imported modules which are not in `files` don't have imports, so they are
+    // not part of cycles.
+    let indices = ids
+        .iter()
+        .enumerate()
+        .map(|(idx, id)| (*id, idx))
+        .collect::<FxHashMap<_, _>>();
+
+    let follow = |import: &Import| {
+        import.kind == ImportKind::Static && (include_type_imports || !import.type_only)
+    };
+    let cycles = graph.cycles(follow);
+
+    let mut reported = FxHashSet::default();
+
+    for cycle in cycles {
+        let cycle = cycle.iter().map(|id| indices[id]).collect::<Vec<_>>();
+
+        let names = cycle
+            .iter()
+            .map(|&idx| files[idx].file_name.to_string())
+            .collect::<Vec<_>>();
+
+        if allowed_cycles
+            .iter()
+            .any(|patterns| is_allowed(patterns, &names))
+        {
+            continue;
+        }
+
+        for (pos, &from) in cycle.iter().enumerate() {
+            let to = cycle[(pos + 1) % cycle.len()];
+            let Some(edge) = graph
+                .dependencies(ids[from])
+                .find(|import| import.to == ids[to] && follow(import))
+            else {
+                continue;
+            };
+            if !reported.insert(edge.span) {
+                continue;
+            }
+
+            let chain = (0..=cycle.len())
+                .map(|i| &*names[(pos + i) % cycle.len()])
+                .collect::<Vec<_>>()
+                .join(" -> ");
+
+            let mut d = Diagnostic::new_with_code(
+                level,
+                Some(DiagnosticId::Lint(RULE_NAME.to_string())),
+                &format!("Dependency cycle detected: {}", chain),
+            );
+            d.set_span(edge.span);
+            results[from].push(d);
+        }
+    }
+
+    for diagnostics in &mut results {
+        diagnostics.sort_by_key(|d| d.span.primary_span());
+    }
+
+    results
+}
+
+fn is_allowed(patterns: &[Regex], names: &[String]) -> bool {
+    names.iter().all(|n| patterns.iter().any(|p| p.is_match(n)))
+        && patterns.iter().all(|p| names.iter().any(|n| p.is_match(n)))
+}
@@ -4,5 +4,12 @@
 #![allow(dead_code)]
 
 pub mod config;
+mod directives;
+pub mod fix;
+pub mod format;
+#[cfg(feature = "non_critical_lints")]
+pub mod import_cycle;
+pub mod linter;
+pub mod registry;
 pub mod rule;
 pub mod rules;
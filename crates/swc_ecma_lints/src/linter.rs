@@ -0,0 +1,194 @@
+use std::{borrow::Cow, mem::take, sync::Arc};
+
+use par_core::join;
+use swc_common::{
+    comments::{Comment, SingleThreadedComments},
+    errors::{Diagnostic, DiagnosticBuilder, DiagnosticId, Handler, HANDLER},
+    SourceMap, SyntaxContext, GLOBALS,
+};
+use swc_ecma_ast::{EsVersion, Module, Program, Script};
+
+use crate::{
+    config::{LintConfig, LintRuleReaction},
+    directives::DisableDirectives,
+    registry::RuleRegistry,
+    rule::{Capturing, Rule},
+    rules::LintParams,
+};
+
+/// A program to lint, already processed by `resolver`.
+pub struct LintFile<'a> {
+    pub program: &'a Program,
+    pub unresolved_ctxt: SyntaxContext,
+    pub top_level_ctxt: SyntaxContext,
+    pub es_version: EsVersion,
+    /// Comments of the program, used to find `swc-lint-disable` comments.
+    /// See [sorted_comments].
+    pub comments: &'a [Comment],
+}
+
+/// Runs the rules of a [RuleRegistry] with a fixed configuration.
+///
+/// Diagnostics are returned instead of being emitted to [HANDLER], so callers
+/// can decide how to report them.
+pub struct Linter {
+    registry: RuleRegistry,
+    config: LintConfig,
+    cm: Arc<SourceMap>,
+}
+
+impl Linter {
+    pub fn new(registry: RuleRegistry, config: LintConfig, cm: Arc<SourceMap>) -> Self {
+        Self {
+            registry,
+            config,
+            cm,
+        }
+    }
+
+    pub fn registry(&self) -> &RuleRegistry {
+        &self.registry
+    }
+
+    pub fn config(&self) -> &LintConfig {
+        &self.config
+    }
+
+    /// Lints a single program. Diagnostics are sorted by their span.
+    ///
+    /// The code of each diagnostic is set to [DiagnosticId::Lint] with the
+    /// name of the rule which reported it.
+    pub fn lint(&self, file: &LintFile) -> Vec<Diagnostic> {
+        let diagnostics = self.lint_without_directives(file);
+
+        self.apply_disable_directives(file.program, file.comments, diagnostics)
+    }
+
+    /// Like [Linter::lint], but `swc-lint-disable` comments are not applied.
+    ///
+    /// This allows adding diagnostics which need more than one file, like
+    /// import cycles, before calling [Linter::apply_disable_directives].
+    pub fn lint_without_directives(&self, file: &LintFile) -> Vec<Diagnostic> {
+        let mut rules = self
+            .registry
+            .build_named(&LintParams {
+                program: file.program,
+                lint_config: &self.config,
+                unresolved_ctxt: file.unresolved_ctxt,
+                top_level_ctxt: file.top_level_ctxt,
+                es_version: file.es_version,
+                source_map: self.cm.clone(),
+            })
+            .into_iter()
+            .map(|(name, rule)| NamedRule { name, rule })
+            .collect::<Vec<_>>();
+
+        let capturing = Capturing::default();
+        let handler = Handler::with_emitter(true, false, Box::new(capturing.clone()));
+
+        HANDLER.set(&handler, || match file.program {
+            Program::Module(m) => rules.lint_module(m),
+            Program::Script(s) => rules.lint_script(s),
+        });
+
+        let mut errors = take(&mut *capturing.errors.lock());
+        errors.sort_by_key(|error| error.span.primary_span());
+        errors
+    }
+
+    /// Removes diagnostics suppressed by `swc-lint-disable` comments in
+    /// `comments`, and reports comments which suppressed nothing.
+    pub fn apply_disable_directives(
+        &self,
+        program: &Program,
+        comments: &[Comment],
+        diagnostics: Vec<Diagnostic>,
+    ) -> Vec<Diagnostic> {
+        DisableDirectives::parse(&self.cm, program, comments).apply(
+            &self.cm,
+            diagnostics,
+            self.config
+                .report_unused_disable_directives
+                .unwrap_or(LintRuleReaction::Warning),
+        )
+    }
+
+    /// Lints programs in parallel.
+    ///
+    /// The returned vector has the same order as `files`.
+    pub fn lint_all(&self, files: &[LintFile]) -> Vec<Vec<Diagnostic>> {
+        let mut results = Vec::with_capacity(files.len());
+        results.resize_with(files.len(), Vec::new);
+
+        GLOBALS.with(|globals| self.lint_all_inner(globals, files, &mut results));
+
+        results
+    }
+
+    fn lint_all_inner(
+        &self,
+        globals: &swc_common::Globals,
+        files: &[LintFile],
+        results: &mut [Vec<Diagnostic>],
+    ) {
+        match files.len() {
+            0 => {}
+            1 => results[0] = self.lint(&files[0]),
+            len => {
+                let (fa, fb) = files.split_at(len / 2);
+                let (ra, rb) = results.split_at_mut(len / 2);
+
+                join(
+                    || GLOBALS.set(globals, || self.lint_all_inner(globals, fa, ra)),
+                    || GLOBALS.set(globals, || self.lint_all_inner(globals, fb, rb)),
+                );
+            }
+        }
+    }
+}
+
+/// Returns all comments of a program in source order.
+pub fn sorted_comments(comments: &SingleThreadedComments) -> Vec<Comment> {
+    let (leading, trailing) = comments.borrow_all();
+
+    let mut comments = leading
+        .values()
+        .chain(trailing.values())
+        .flatten()
+        .cloned()
+        .collect::<Vec<_>>();
+    comments.sort_by_key(|c| c.span.lo);
+    comments.dedup_by_key(|c| c.span);
+    comments
+}
+
+/// Sets the code of diagnostics reported by `rule` to its name.
+#[derive(Debug)]
+struct NamedRule {
+    name: Cow<'static, str>,
+    rule: Box<dyn Rule>,
+}
+
+impl Rule for NamedRule {
+    fn lint_module(&mut self, program: &Module) {
+        with_rule_name(&self.name, || self.rule.lint_module(program));
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        with_rule_name(&self.name, || self.rule.lint_script(program));
+    }
+}
+
+fn with_rule_name(name: &str, op: impl FnOnce()) {
+    let capturing = Capturing::default();
+    let handler = Handler::with_emitter(true, false, Box::new(capturing.clone()));
+    HANDLER.set(&handler, op);
+
+    let errors = take(&mut *capturing.errors.lock());
+    HANDLER.with(|handler| {
+        for mut error in errors {
+            error.code(DiagnosticId::Lint(name.to_string()));
+            DiagnosticBuilder::new_diagnostic(handler, error).emit();
+        }
+    });
+}
@@ -0,0 +1,119 @@
+use std::{
+    borrow::Cow,
+    sync::{Arc, OnceLock},
+};
+
+use swc_ecma_scope_analysis::ScopeAnalysis;
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::Rule,
+    rules::{register_builtin_rules, LintParams},
+};
+
+/// Creates a rule for a program, or returns [None] if the rule is disabled.
+pub type RuleFactory = Arc<dyn Fn(&RuleContext) -> Option<Box<dyn Rule>> + Send + Sync>;
+
+/// Data passed to [RuleFactory]s.
+pub struct RuleContext<'a> {
+    pub params: &'a LintParams<'a>,
+    scope: OnceLock<Arc<ScopeAnalysis>>,
+}
+
+impl<'a> RuleContext<'a> {
+    pub fn new(params: &'a LintParams<'a>) -> Self {
+        Self {
+            params,
+            scope: Default::default(),
+        }
+    }
+
+    /// Scope analysis of the program. It's computed on the first call and
+    /// shared by all rules.
+    pub fn scope(&self) -> &Arc<ScopeAnalysis> {
+        self.scope
+            .get_or_init(|| Arc::new(ScopeAnalysis::for_program(self.params.program)))
+    }
+
+    /// Configuration of a rule which is not built into swc.
+    pub fn custom_config(&self, name: &str) -> Option<&RuleConfig<serde_json::Value>> {
+        self.params.lint_config.custom.get(name)
+    }
+
+    /// Severity of a rule which is not built into swc.
+    pub fn custom_reaction(&self, name: &str) -> LintRuleReaction {
+        self.custom_config(name)
+            .map(|c| c.get_rule_reaction())
+            .unwrap_or_default()
+    }
+}
+
+/// A set of named lint rules.
+///
+/// [RuleRegistry::default] returns a registry with all rules built into swc.
+/// Additional rules can be added with [RuleRegistry::register] and are
+/// configured from [crate::config::LintConfig::custom].
+#[derive(Clone)]
+pub struct RuleRegistry {
+    rules: Vec<(Cow<'static, str>, RuleFactory)>,
+}
+
+impl Default for RuleRegistry {
+    fn default() -> Self {
+        let mut registry = Self::empty();
+        register_builtin_rules(&mut registry);
+        registry
+    }
+}
+
+impl RuleRegistry {
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Adds a rule. If a rule with the same name exists, it's replaced.
+    pub fn register<F>(&mut self, name: impl Into<Cow<'static, str>>, factory: F) -> &mut Self
+    where
+        F: 'static + Fn(&RuleContext) -> Option<Box<dyn Rule>> + Send + Sync,
+    {
+        let name = name.into();
+        let factory: RuleFactory = Arc::new(factory);
+
+        match self.rules.iter_mut().find(|(n, _)| *n == name) {
+            Some(slot) => slot.1 = factory,
+            None => self.rules.push((name, factory)),
+        }
+
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.rules.iter().any(|(n, _)| n == name)
+    }
+
+    /// Names of the rules, in registration order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.rules.iter().map(|(n, _)| &**n)
+    }
+
+    /// Creates all enabled rules for a program.
+    pub fn build(&self, params: &LintParams) -> Vec<Box<dyn Rule>> {
+        self.build_named(params)
+            .into_iter()
+            .map(|(_, rule)| rule)
+            .collect()
+    }
+
+    /// Like [RuleRegistry::build], but also returns the names of the rules.
+    pub(crate) fn build_named(
+        &self,
+        params: &LintParams,
+    ) -> Vec<(Cow<'static, str>, Box<dyn Rule>)> {
+        let ctx = RuleContext::new(params);
+
+        self.rules
+            .iter()
+            .filter_map(|(name, factory)| Some((name.clone(), factory(&ctx)?)))
+            .collect()
+    }
+}
@@ -102,8 +102,8 @@ fn lint_rules<N: LintNode<R>, R: Rule>(rules: &mut Vec<R>, program: &N) {
 }
 
 #[derive(Default, Clone)]
-struct Capturing {
-    errors: Arc<Mutex<Vec<Diagnostic>>>,
+pub(crate) struct Capturing {
+    pub(crate) errors: Arc<Mutex<Vec<Diagnostic>>>,
 }
 
 impl Emitter for Capturing {
@@ -0,0 +1,232 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use swc_common::{errors::Applicability, BytePos, SourceMap, SourceMapper, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_scope_analysis::ScopeAnalysis;
+use swc_ecma_visit::{noop_visit_type, Visit};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    fix::{emit_with_fix, Fix, FixSuggestion},
+    registry::RuleContext,
+    rule::{visitor_rule, Rule},
+    rules::utils::BindingUsage,
+};
+
+const ALL_TYPES_MESSAGE: &str = "All imports in the declaration are only used as types";
+const NO_TYPE_IMPORTS_MESSAGE: &str = "Use an `import` instead of an `import type`";
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Prefer {
+    #[default]
+    TypeImports,
+    NoTypeImports,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistentTypeImportsConfig {
+    prefer: Option<Prefer>,
+}
+
+pub fn consistent_type_imports(
+    config: &RuleConfig<ConsistentTypeImportsConfig>,
+    ctx: &RuleContext,
+) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(ConsistentTypeImports::new(config, ctx))),
+    }
+}
+
+#[derive(Default)]
+struct ConsistentTypeImports {
+    expected_reaction: LintRuleReaction,
+    prefer: Prefer,
+    scope: Option<Arc<ScopeAnalysis>>,
+    source_map: Arc<SourceMap>,
+}
+
+impl Debug for ConsistentTypeImports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConsistentTypeImports")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("prefer", &self.prefer)
+            .finish()
+    }
+}
+
+impl ConsistentTypeImports {
+    fn new(config: &RuleConfig<ConsistentTypeImportsConfig>, ctx: &RuleContext) -> Self {
+        let prefer = config.get_rule_config().prefer.unwrap_or_default();
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            prefer,
+            // Scope analysis is only needed to find imports used as types.
+            scope: match prefer {
+                Prefer::TypeImports => Some(ctx.scope().clone()),
+                Prefer::NoTypeImports => None,
+            },
+            source_map: ctx.params.source_map.clone(),
+        }
+    }
+
+    /// Returns `true` if the binding is referenced, but only in types.
+    fn is_only_used_as_type(&self, local: &Ident) -> bool {
+        self.scope
+            .as_deref()
+            .and_then(|scope| BindingUsage::of(scope, &local.to_id()))
+            .map(|b| !b.exported && b.reads > 0 && b.reads == b.type_reads)
+            .unwrap_or(false)
+    }
+
+    /// Span of the `type` keyword after `import`, including the whitespace
+    /// following it.
+    fn type_keyword_span(&self, span: Span, prefix: &str) -> Option<Span> {
+        let snippet = self.source_map.span_to_snippet(span).ok()?;
+        let rest = snippet.strip_prefix(prefix)?.trim_start();
+        let offset = snippet.len() - rest.len();
+        let rest_after_type = rest.strip_prefix("type")?;
+        let len = rest.len() - rest_after_type.trim_start().len();
+
+        let lo = span.lo + BytePos(offset as u32);
+        Some(Span::new(lo, lo + BytePos(len as u32)))
+    }
+
+    fn check_type_imports(&self, n: &ImportDecl) {
+        if n.type_only {
+            return;
+        }
+
+        let type_only_specifiers = n
+            .specifiers
+            .iter()
+            .filter(|s| !s.is_type_only() && self.is_only_used_as_type(s.local()))
+            .collect::<Vec<_>>();
+        if type_only_specifiers.is_empty() {
+            return;
+        }
+
+        let all_types = n
+            .specifiers
+            .iter()
+            .all(|s| s.is_type_only() || self.is_only_used_as_type(s.local()));
+
+        if all_types {
+            // `import type A, { B }` is not valid.
+            let has_default = n
+                .specifiers
+                .iter()
+                .any(|s| matches!(s, ImportSpecifier::Default(..)));
+            let has_named = n
+                .specifiers
+                .iter()
+                .any(|s| !matches!(s, ImportSpecifier::Default(..)));
+
+            let mut fixes = vec![Fix::insert_after(
+                Span::new(n.span.lo, n.span.lo + BytePos(6)),
+                " type",
+            )];
+            // `import { type A }` becomes `import type { A }`.
+            fixes.extend(
+                n.specifiers
+                    .iter()
+                    .filter(|s| s.is_type_only())
+                    .filter_map(|s| self.type_keyword_span(s.span(), ""))
+                    .map(Fix::remove),
+            );
+
+            emit_with_fix(
+                self.expected_reaction,
+                n.span,
+                ALL_TYPES_MESSAGE,
+                (!(has_default && has_named)).then(|| {
+                    FixSuggestion::new("use `import type`", fixes, Applicability::MachineApplicable)
+                }),
+            );
+            return;
+        }
+
+        let names = type_only_specifiers
+            .iter()
+            .map(|s| format!("'{}'", s.local().sym))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let message = if type_only_specifiers.len() == 1 {
+            format!("Import {} is only used as a type", names)
+        } else {
+            format!("Imports {} are only used as types", names)
+        };
+
+        // Only named specifiers can be marked with inline `type` modifiers.
+        let fixable = type_only_specifiers
+            .iter()
+            .all(|s| matches!(s, ImportSpecifier::Named(..)));
+
+        emit_with_fix(
+            self.expected_reaction,
+            n.span,
+            &message,
+            fixable.then(|| {
+                FixSuggestion::new(
+                    "add `type` modifiers",
+                    type_only_specifiers
+                        .iter()
+                        .map(|s| Fix::insert_before(s.span(), "type "))
+                        .collect(),
+                    Applicability::MachineApplicable,
+                )
+            }),
+        );
+    }
+
+    fn check_no_type_imports(&self, n: &ImportDecl) {
+        if n.type_only {
+            emit_with_fix(
+                self.expected_reaction,
+                n.span,
+                NO_TYPE_IMPORTS_MESSAGE,
+                self.type_keyword_span(n.span, "import").map(|span| {
+                    FixSuggestion::new(
+                        "remove `type`",
+                        vec![Fix::remove(span)],
+                        Applicability::MachineApplicable,
+                    )
+                }),
+            );
+            return;
+        }
+
+        for s in n.specifiers.iter().filter(|s| s.is_type_only()) {
+            emit_with_fix(
+                self.expected_reaction,
+                s.span(),
+                NO_TYPE_IMPORTS_MESSAGE,
+                self.type_keyword_span(s.span(), "").map(|span| {
+                    FixSuggestion::new(
+                        "remove `type`",
+                        vec![Fix::remove(span)],
+                        Applicability::MachineApplicable,
+                    )
+                }),
+            );
+        }
+    }
+}
+
+impl Visit for ConsistentTypeImports {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        match self.prefer {
+            Prefer::TypeImports => self.check_type_imports(n),
+            Prefer::NoTypeImports => self.check_no_type_imports(n),
+        }
+    }
+}
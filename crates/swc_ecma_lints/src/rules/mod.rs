@@ -3,7 +3,7 @@ use std::sync::Arc;
 use swc_common::{SourceMap, SyntaxContext};
 use swc_ecma_ast::*;
 
-use crate::{config::LintConfig, rule::Rule};
+use crate::{config::LintConfig, registry::RuleRegistry, rule::Rule};
 
 mod const_assign;
 mod duplicate_bindings;
@@ -14,6 +14,7 @@ mod utils;
 #[cfg(feature = "non_critical_lints")]
 #[path = ""]
 pub(crate) mod non_critical_lints {
+    pub mod consistent_type_imports;
     pub mod constructor_super;
     pub mod default_case_last;
     pub mod default_param_last;
@@ -29,17 +30,22 @@ pub(crate) mod non_critical_lints {
     pub mod no_empty_function;
     pub mod no_empty_pattern;
     pub mod no_loop_func;
+    pub mod no_namespace;
     pub mod no_new;
     pub mod no_new_object;
     pub mod no_new_symbol;
+    pub mod no_non_null_asserted_optional_chain;
     pub mod no_obj_calls;
     pub mod no_param_reassign;
     pub mod no_prototype_builtins;
     pub mod no_restricted_syntax;
     pub mod no_sparse_arrays;
     pub mod no_throw_literal;
+    pub mod no_unused_imports;
+    pub mod no_unused_vars;
     pub mod no_use_before_define;
     pub mod no_var;
+    pub mod prefer_as_const;
     pub mod prefer_const;
     pub mod prefer_object_spread;
     pub mod prefer_regex_literals;
@@ -63,157 +69,187 @@ pub struct LintParams<'a> {
     pub source_map: Arc<SourceMap>,
 }
 
+/// Creates all rules built into swc.
+///
+/// See [RuleRegistry] to add custom rules.
 pub fn all(lint_params: LintParams) -> Vec<Box<dyn Rule>> {
-    let mut rules = vec![
-        const_assign::const_assign(),
-        duplicate_bindings::duplicate_bindings(),
-        duplicate_exports::duplicate_exports(),
-        no_dupe_args::no_dupe_args(),
-    ];
+    RuleRegistry::default().build(&lint_params)
+}
+
+pub(crate) fn register_builtin_rules(r: &mut RuleRegistry) {
+    r.register("const-assign", |_| Some(const_assign::const_assign()));
+    r.register("duplicate-bindings", |_| {
+        Some(duplicate_bindings::duplicate_bindings())
+    });
+    r.register("duplicate-exports", |_| {
+        Some(duplicate_exports::duplicate_exports())
+    });
+    r.register("no-dupe-args", |_| Some(no_dupe_args::no_dupe_args()));
 
     #[cfg(feature = "non_critical_lints")]
     {
-        let LintParams {
-            program,
-            lint_config,
-            unresolved_ctxt,
-            top_level_ctxt: _,
-            es_version,
-            source_map,
-        } = lint_params;
-
-        rules.extend(no_use_before_define::no_use_before_define(
-            &lint_params.lint_config.no_use_before_define,
-        ));
-
-        rules.extend(no_console::no_console(
-            &lint_config.no_console,
-            unresolved_ctxt,
-        ));
-
-        rules.extend(no_alert::no_alert(
-            &lint_config.no_alert,
-            unresolved_ctxt,
-            es_version,
-        ));
-
-        rules.extend(no_debugger::no_debugger(&lint_config.no_debugger));
-
-        rules.extend(quotes::quotes(&lint_config.quotes));
-
-        rules.extend(prefer_regex_literals::prefer_regex_literals(
-            &lint_config.prefer_regex_literals,
-            unresolved_ctxt,
-            es_version,
-        ));
-
-        rules.extend(dot_notation::dot_notation(
-            program,
-            &lint_config.dot_notation,
-        ));
-
-        rules.extend(no_empty_function::no_empty_function(
-            &source_map,
-            &lint_config.no_empty_function,
-        ));
-
-        rules.extend(no_empty_pattern::no_empty_pattern(
-            &lint_config.no_empty_pattern,
-        ));
-
-        rules.extend(eqeqeq::eqeqeq(&lint_config.eqeqeq));
-
-        rules.extend(no_loop_func::no_loop_func(&lint_config.no_loop_func));
-
-        rules.extend(no_new::no_new(&lint_config.no_new));
-
-        rules.extend(no_restricted_syntax::no_restricted_syntax(
-            &lint_config.no_restricted_syntax,
-        ));
-
-        rules.extend(radix::radix(unresolved_ctxt, &lint_config.radix));
-
-        rules.extend(no_bitwise::no_bitwise(&lint_config.no_bitwise));
-
-        rules.extend(default_param_last::default_param_last(
-            &lint_config.default_param_last,
-        ));
-
-        rules.extend(yoda::yoda(&lint_config.yoda));
-
-        rules.extend(no_new_symbol::no_new_symbol(
-            unresolved_ctxt,
-            &lint_config.no_new_symbol,
-        ));
-
-        rules.extend(use_is_nan::use_is_nan(
-            unresolved_ctxt,
-            &lint_config.use_isnan,
-        ));
-
-        rules.extend(valid_typeof::valid_typeof(&lint_config.valid_typeof));
-
-        rules.extend(no_param_reassign::no_param_reassign(
-            &lint_config.no_param_reassign,
-        ));
-
-        rules.extend(symbol_description::symbol_description(
-            unresolved_ctxt,
-            &lint_config.symbol_description,
-        ));
-
-        rules.extend(no_obj_calls::no_obj_calls(
-            unresolved_ctxt,
-            &lint_config.no_obj_calls,
-        ));
-
-        rules.extend(no_throw_literal::no_throw_literal(
-            &lint_config.no_throw_literal,
-        ));
-
-        rules.extend(no_var::no_var(&lint_config.no_var));
-
-        rules.extend(prefer_const::prefer_const(&lint_config.prefer_const));
-
-        rules.extend(no_compare_neg_zero::no_compare_neg_zero(
-            &lint_config.no_compare_neg_zero,
-        ));
-
-        rules.extend(constructor_super::constructor_super(
-            &lint_config.constructor_super,
-        ));
-
-        rules.extend(no_sparse_arrays::no_sparse_arrays(
-            &lint_config.no_sparse_arrays,
-        ));
-
-        rules.extend(default_case_last::default_case_last(
-            &lint_config.default_case_last,
-        ));
-
-        rules.extend(no_await_in_loop::no_await_in_loop(
-            &lint_config.no_await_in_loop,
-        ));
-
-        rules.extend(no_cond_assign::no_cond_assign(&lint_config.no_cond_assign));
-
-        rules.extend(no_prototype_builtins::no_prototype_builtins(
-            &lint_config.no_prototype_builtins,
-        ));
-
-        rules.extend(no_new_object::no_new_object(
-            unresolved_ctxt,
-            &lint_config.no_new_object,
-        ));
-
-        rules.extend(prefer_object_spread::prefer_object_spread(
-            &lint_config.prefer_object_spread,
-            unresolved_ctxt,
-            es_version,
-        ));
+        r.register("no-use-before-define", |ctx| {
+            no_use_before_define::no_use_before_define(&ctx.params.lint_config.no_use_before_define)
+        });
+        r.register("no-console", |ctx| {
+            no_console::no_console(
+                &ctx.params.lint_config.no_console,
+                ctx.params.unresolved_ctxt,
+            )
+        });
+        r.register("no-alert", |ctx| {
+            no_alert::no_alert(
+                &ctx.params.lint_config.no_alert,
+                ctx.params.unresolved_ctxt,
+                ctx.params.es_version,
+            )
+        });
+        r.register("no-debugger", |ctx| {
+            no_debugger::no_debugger(&ctx.params.lint_config.no_debugger)
+        });
+        r.register("quotes", |ctx| {
+            quotes::quotes(&ctx.params.lint_config.quotes)
+        });
+        r.register("prefer-regex-literals", |ctx| {
+            prefer_regex_literals::prefer_regex_literals(
+                &ctx.params.lint_config.prefer_regex_literals,
+                ctx.params.unresolved_ctxt,
+                ctx.params.es_version,
+            )
+        });
+        r.register("dot-notation", |ctx| {
+            dot_notation::dot_notation(ctx.params.program, &ctx.params.lint_config.dot_notation)
+        });
+        r.register("no-empty-function", |ctx| {
+            no_empty_function::no_empty_function(
+                &ctx.params.source_map,
+                &ctx.params.lint_config.no_empty_function,
+            )
+        });
+        r.register("no-empty-pattern", |ctx| {
+            no_empty_pattern::no_empty_pattern(&ctx.params.lint_config.no_empty_pattern)
+        });
+        r.register("eqeqeq", |ctx| {
+            eqeqeq::eqeqeq(&ctx.params.lint_config.eqeqeq)
+        });
+        r.register("no-loop-func", |ctx| {
+            no_loop_func::no_loop_func(&ctx.params.lint_config.no_loop_func)
+        });
+        r.register("no-new", |ctx| {
+            no_new::no_new(&ctx.params.lint_config.no_new)
+        });
+        r.register("no-restricted-syntax", |ctx| {
+            no_restricted_syntax::no_restricted_syntax(&ctx.params.lint_config.no_restricted_syntax)
+        });
+        r.register("radix", |ctx| {
+            radix::radix(ctx.params.unresolved_ctxt, &ctx.params.lint_config.radix)
+        });
+        r.register("no-bitwise", |ctx| {
+            no_bitwise::no_bitwise(&ctx.params.lint_config.no_bitwise)
+        });
+        r.register("default-param-last", |ctx| {
+            default_param_last::default_param_last(&ctx.params.lint_config.default_param_last)
+        });
+        r.register("yoda", |ctx| yoda::yoda(&ctx.params.lint_config.yoda));
+        r.register("no-new-symbol", |ctx| {
+            no_new_symbol::no_new_symbol(
+                ctx.params.unresolved_ctxt,
+                &ctx.params.lint_config.no_new_symbol,
+            )
+        });
+        r.register("use-isnan", |ctx| {
+            use_is_nan::use_is_nan(
+                ctx.params.unresolved_ctxt,
+                &ctx.params.lint_config.use_isnan,
+            )
+        });
+        r.register("valid-typeof", |ctx| {
+            valid_typeof::valid_typeof(&ctx.params.lint_config.valid_typeof)
+        });
+        r.register("no-param-reassign", |ctx| {
+            no_param_reassign::no_param_reassign(&ctx.params.lint_config.no_param_reassign)
+        });
+        r.register("symbol-description", |ctx| {
+            symbol_description::symbol_description(
+                ctx.params.unresolved_ctxt,
+                &ctx.params.lint_config.symbol_description,
+            )
+        });
+        r.register("no-obj-calls", |ctx| {
+            no_obj_calls::no_obj_calls(
+                ctx.params.unresolved_ctxt,
+                &ctx.params.lint_config.no_obj_calls,
+            )
+        });
+        r.register("no-throw-literal", |ctx| {
+            no_throw_literal::no_throw_literal(&ctx.params.lint_config.no_throw_literal)
+        });
+        r.register("no-var", |ctx| {
+            no_var::no_var(&ctx.params.lint_config.no_var)
+        });
+        r.register("prefer-const", |ctx| {
+            prefer_const::prefer_const(&ctx.params.lint_config.prefer_const)
+        });
+        r.register("no-compare-neg-zero", |ctx| {
+            no_compare_neg_zero::no_compare_neg_zero(&ctx.params.lint_config.no_compare_neg_zero)
+        });
+        r.register("constructor-super", |ctx| {
+            constructor_super::constructor_super(&ctx.params.lint_config.constructor_super)
+        });
+        r.register("no-sparse-arrays", |ctx| {
+            no_sparse_arrays::no_sparse_arrays(&ctx.params.lint_config.no_sparse_arrays)
+        });
+        r.register("default-case-last", |ctx| {
+            default_case_last::default_case_last(&ctx.params.lint_config.default_case_last)
+        });
+        r.register("no-await-in-loop", |ctx| {
+            no_await_in_loop::no_await_in_loop(&ctx.params.lint_config.no_await_in_loop)
+        });
+        r.register("no-cond-assign", |ctx| {
+            no_cond_assign::no_cond_assign(&ctx.params.lint_config.no_cond_assign)
+        });
+        r.register("no-prototype-builtins", |ctx| {
+            no_prototype_builtins::no_prototype_builtins(
+                &ctx.params.lint_config.no_prototype_builtins,
+            )
+        });
+        r.register("no-new-object", |ctx| {
+            no_new_object::no_new_object(
+                ctx.params.unresolved_ctxt,
+                &ctx.params.lint_config.no_new_object,
+            )
+        });
+        r.register("no-unused-vars", |ctx| {
+            no_unused_vars::no_unused_vars(&ctx.params.lint_config.no_unused_vars, ctx)
+        });
+        r.register("no-unused-imports", |ctx| {
+            no_unused_imports::no_unused_imports(&ctx.params.lint_config.no_unused_imports, ctx)
+        });
+        r.register("prefer-object-spread", |ctx| {
+            prefer_object_spread::prefer_object_spread(
+                &ctx.params.lint_config.prefer_object_spread,
+                ctx.params.unresolved_ctxt,
+                ctx.params.es_version,
+            )
+        });
+        r.register("consistent-type-imports", |ctx| {
+            consistent_type_imports::consistent_type_imports(
+                &ctx.params.lint_config.consistent_type_imports,
+                ctx,
+            )
+        });
+        r.register("no-namespace", |ctx| {
+            no_namespace::no_namespace(&ctx.params.source_map, &ctx.params.lint_config.no_namespace)
+        });
+        r.register("prefer-as-const", |ctx| {
+            prefer_as_const::prefer_as_const(&ctx.params.lint_config.prefer_as_const)
+        });
+        r.register("no-non-null-asserted-optional-chain", |ctx| {
+            no_non_null_asserted_optional_chain::no_non_null_asserted_optional_chain(
+                &ctx.params.lint_config.no_non_null_asserted_optional_chain,
+            )
+        });
     }
-
-    rules
 }
 
 pub fn lint_pass<R>(r: R) -> impl Pass
@@ -0,0 +1,107 @@
+use std::{
+    fmt::{self, Debug},
+    sync::Arc,
+};
+
+use serde::{Deserialize, Serialize};
+use swc_common::{errors::HANDLER, FileName, SourceMap, Span};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    rule::{visitor_rule, Rule},
+};
+
+const MESSAGE: &str = "ES2015 module syntax is preferred over namespaces";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoNamespaceConfig {
+    /// Allow `declare namespace` and namespaces nested in declarations.
+    allow_declarations: Option<bool>,
+    /// Allow namespaces in `.d.ts` files. Defaults to `true`.
+    allow_definition_files: Option<bool>,
+}
+
+pub fn no_namespace(
+    source_map: &Arc<SourceMap>,
+    config: &RuleConfig<NoNamespaceConfig>,
+) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoNamespace::new(source_map.clone(), config))),
+    }
+}
+
+#[derive(Default)]
+struct NoNamespace {
+    expected_reaction: LintRuleReaction,
+    source_map: Arc<SourceMap>,
+    allow_declarations: bool,
+    allow_definition_files: bool,
+    in_declaration: bool,
+}
+
+impl Debug for NoNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NoNamespace")
+            .field("expected_reaction", &self.expected_reaction)
+            .field("allow_declarations", &self.allow_declarations)
+            .field("allow_definition_files", &self.allow_definition_files)
+            .finish()
+    }
+}
+
+impl NoNamespace {
+    fn new(source_map: Arc<SourceMap>, config: &RuleConfig<NoNamespaceConfig>) -> Self {
+        let rule_config = config.get_rule_config();
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            source_map,
+            allow_declarations: rule_config.allow_declarations.unwrap_or(false),
+            allow_definition_files: rule_config.allow_definition_files.unwrap_or(true),
+            in_declaration: false,
+        }
+    }
+
+    fn is_definition_file(&self, span: Span) -> bool {
+        match &*self.source_map.lookup_char_pos(span.lo).file.name {
+            FileName::Real(path) => path.to_string_lossy().ends_with(".d.ts"),
+            _ => false,
+        }
+    }
+
+    fn emit_report(&self, span: Span) {
+        HANDLER.with(|handler| match self.expected_reaction {
+            LintRuleReaction::Error => {
+                handler.struct_span_err(span, MESSAGE).emit();
+            }
+            LintRuleReaction::Warning => {
+                handler.struct_span_warn(span, MESSAGE).emit();
+            }
+            _ => {}
+        });
+    }
+}
+
+impl Visit for NoNamespace {
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        // `declare module 'foo'` and `declare global` are not namespaces.
+        let is_namespace = !n.global && matches!(n.id, TsModuleName::Ident(..));
+        let in_declaration = self.in_declaration || n.declare;
+
+        if is_namespace
+            && !(self.allow_declarations && in_declaration)
+            && !(self.allow_definition_files && self.is_definition_file(n.span))
+        {
+            self.emit_report(n.span);
+        }
+
+        let old = self.in_declaration;
+        self.in_declaration = in_declaration;
+        n.visit_children_with(self);
+        self.in_declaration = old;
+    }
+}
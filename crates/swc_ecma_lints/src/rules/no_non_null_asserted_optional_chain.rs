@@ -0,0 +1,52 @@
+use swc_common::{errors::Applicability, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    fix::{emit_with_fix, Fix, FixSuggestion},
+    rule::{visitor_rule, Rule},
+};
+
+const MESSAGE: &str = "Optional chain expressions can return undefined by design - using a \
+                       non-null assertion is unsafe and wrong";
+
+pub fn no_non_null_asserted_optional_chain(config: &RuleConfig<()>) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        reaction => Some(visitor_rule(NoNonNullAssertedOptionalChain {
+            expected_reaction: reaction,
+        })),
+    }
+}
+
+#[derive(Debug, Default)]
+struct NoNonNullAssertedOptionalChain {
+    expected_reaction: LintRuleReaction,
+}
+
+impl Visit for NoNonNullAssertedOptionalChain {
+    fn visit_ts_non_null_expr(&mut self, n: &TsNonNullExpr) {
+        let mut expr = &*n.expr;
+        while let Expr::Paren(paren) = expr {
+            expr = &paren.expr;
+        }
+
+        if let Expr::OptChain(..) = expr {
+            // Removing the assertion changes the type of the expression, so
+            // the fix must be reviewed.
+            emit_with_fix(
+                self.expected_reaction,
+                n.span,
+                MESSAGE,
+                Some(FixSuggestion::new(
+                    "remove the non-null assertion",
+                    vec![Fix::remove(Span::new(n.expr.span().hi, n.span.hi))],
+                    Applicability::MaybeIncorrect,
+                )),
+            );
+        }
+
+        n.visit_children_with(self);
+    }
+}
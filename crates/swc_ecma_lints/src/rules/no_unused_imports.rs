@@ -0,0 +1,162 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use swc_common::errors::Applicability;
+use swc_ecma_ast::*;
+use swc_ecma_scope_analysis::ScopeAnalysis;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    fix::{emit_with_fix, Fix, FixSuggestion},
+    registry::RuleContext,
+    rule::{visitor_rule, Rule},
+    rules::utils::{list_item_removal_span, BindingUsage},
+};
+
+const INVALID_REGEX_MESSAGE: &str = "no-unused-imports: invalid regex pattern in ignorePattern. Check syntax documentation https://docs.rs/regex/latest/regex/#syntax";
+
+/// Names which are referenced implicitly by JSX with the classic runtime.
+const JSX_PRAGMAS: &[&str] = &["React", "h", "Fragment"];
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoUnusedImportsConfig {
+    ignore_pattern: Option<String>,
+}
+
+pub fn no_unused_imports(
+    config: &RuleConfig<NoUnusedImportsConfig>,
+    ctx: &RuleContext,
+) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoUnusedImports::new(config, ctx))),
+    }
+}
+
+#[derive(Debug, Default)]
+struct NoUnusedImports {
+    expected_reaction: LintRuleReaction,
+    scope: Option<Arc<ScopeAnalysis>>,
+    /// JSX may implicitly reference a pragma like `React`.
+    has_jsx: bool,
+    ignore_pattern: Option<Regex>,
+}
+
+impl NoUnusedImports {
+    fn new(config: &RuleConfig<NoUnusedImportsConfig>, ctx: &RuleContext) -> Self {
+        let rule_config = config.get_rule_config();
+
+        let mut jsx_finder = JsxFinder::default();
+        ctx.params.program.visit_with(&mut jsx_finder);
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            scope: Some(ctx.scope().clone()),
+            has_jsx: jsx_finder.found,
+            ignore_pattern: rule_config
+                .ignore_pattern
+                .as_deref()
+                .map(|p| Regex::new(p).expect(INVALID_REGEX_MESSAGE)),
+        }
+    }
+
+    fn is_unused(&self, local: &Ident) -> bool {
+        if self
+            .ignore_pattern
+            .as_ref()
+            .map(|p| p.is_match(&local.sym))
+            .unwrap_or(false)
+        {
+            return false;
+        }
+
+        if self.has_jsx && JSX_PRAGMAS.contains(&&*local.sym) {
+            return false;
+        }
+
+        self.scope
+            .as_deref()
+            .and_then(|scope| BindingUsage::of(scope, &local.to_id()))
+            .map(|b| !b.is_used())
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Default)]
+struct JsxFinder {
+    found: bool,
+}
+
+impl Visit for JsxFinder {
+    noop_visit_type!();
+
+    fn visit_jsx_element(&mut self, _: &JSXElement) {
+        self.found = true;
+    }
+
+    fn visit_jsx_fragment(&mut self, _: &JSXFragment) {
+        self.found = true;
+    }
+}
+
+fn local_of(s: &ImportSpecifier) -> &Ident {
+    match s {
+        ImportSpecifier::Named(s) => &s.local,
+        ImportSpecifier::Default(s) => &s.local,
+        ImportSpecifier::Namespace(s) => &s.local,
+    }
+}
+
+impl Visit for NoUnusedImports {
+    noop_visit_type!();
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        let unused = n
+            .specifiers
+            .iter()
+            .map(|s| self.is_unused(local_of(s)))
+            .collect::<Vec<_>>();
+
+        // Side-effect only imports (`import 'x'`) have no specifiers and are
+        // never reported.
+        let all_unused = unused.iter().all(|v| *v);
+
+        for (idx, s) in n.specifiers.iter().enumerate() {
+            if !unused[idx] {
+                continue;
+            }
+
+            let local = local_of(s);
+            let is_named =
+                |idx: usize| matches!(n.specifiers.get(idx), Some(ImportSpecifier::Named(..)));
+
+            // A default or namespace specifier can't be removed by itself
+            // without rewriting the braces around the named ones.
+            let removal = if all_unused {
+                Some(n.span)
+            } else if is_named(idx)
+                && ((idx > 0 && is_named(idx - 1)) || (idx == 0 && is_named(idx + 1)))
+            {
+                Some(list_item_removal_span(&n.specifiers, idx))
+            } else {
+                None
+            };
+
+            emit_with_fix(
+                self.expected_reaction,
+                local.span,
+                &format!("'{}' is imported but never used", local.sym),
+                removal.map(|span| {
+                    FixSuggestion::new(
+                        format!("remove unused import '{}'", local.sym),
+                        vec![Fix::remove(span)],
+                        Applicability::MachineApplicable,
+                    )
+                }),
+            );
+        }
+    }
+}
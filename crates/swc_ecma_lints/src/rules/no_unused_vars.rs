@@ -0,0 +1,325 @@
+use std::sync::Arc;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use swc_common::{errors::Applicability, Span};
+use swc_ecma_ast::*;
+use swc_ecma_scope_analysis::ScopeAnalysis;
+use swc_ecma_visit::{noop_visit_type, Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    fix::{emit_with_fix, Fix, FixSuggestion},
+    registry::RuleContext,
+    rule::{visitor_rule, Rule},
+    rules::utils::{list_item_removal_span, pat_binding_idents, BindingUsage},
+};
+
+const INVALID_REGEX_MESSAGE: &str = "no-unused-vars: invalid regex pattern in ignore pattern. Check syntax documentation https://docs.rs/regex/latest/regex/#syntax";
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum VarsMode {
+    #[default]
+    All,
+    /// Top-level bindings are not checked.
+    Local,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArgsMode {
+    /// Only parameters after the last used parameter are checked.
+    #[default]
+    AfterUsed,
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CaughtErrorsMode {
+    #[default]
+    All,
+    None,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NoUnusedVarsConfig {
+    vars: Option<VarsMode>,
+    vars_ignore_pattern: Option<String>,
+    args: Option<ArgsMode>,
+    args_ignore_pattern: Option<String>,
+    caught_errors: Option<CaughtErrorsMode>,
+    caught_errors_ignore_pattern: Option<String>,
+    ignore_rest_siblings: Option<bool>,
+}
+
+pub fn no_unused_vars(
+    config: &RuleConfig<NoUnusedVarsConfig>,
+    ctx: &RuleContext,
+) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        _ => Some(visitor_rule(NoUnusedVars::new(config, ctx.scope().clone()))),
+    }
+}
+
+fn compile(pattern: &Option<String>) -> Option<Regex> {
+    pattern
+        .as_deref()
+        .map(|p| Regex::new(p).expect(INVALID_REGEX_MESSAGE))
+}
+
+#[derive(Debug, Default)]
+struct NoUnusedVars {
+    expected_reaction: LintRuleReaction,
+    scope: Option<Arc<ScopeAnalysis>>,
+    vars: VarsMode,
+    vars_ignore_pattern: Option<Regex>,
+    args: ArgsMode,
+    args_ignore_pattern: Option<Regex>,
+    caught_errors: CaughtErrorsMode,
+    caught_errors_ignore_pattern: Option<Regex>,
+    ignore_rest_siblings: bool,
+}
+
+impl NoUnusedVars {
+    fn new(config: &RuleConfig<NoUnusedVarsConfig>, scope: Arc<ScopeAnalysis>) -> Self {
+        let rule_config = config.get_rule_config();
+
+        Self {
+            expected_reaction: config.get_rule_reaction(),
+            scope: Some(scope),
+            vars: rule_config.vars.unwrap_or_default(),
+            vars_ignore_pattern: compile(&rule_config.vars_ignore_pattern),
+            args: rule_config.args.unwrap_or_default(),
+            args_ignore_pattern: compile(&rule_config.args_ignore_pattern),
+            caught_errors: rule_config.caught_errors.unwrap_or_default(),
+            caught_errors_ignore_pattern: compile(&rule_config.caught_errors_ignore_pattern),
+            ignore_rest_siblings: rule_config.ignore_rest_siblings.unwrap_or(false),
+        }
+    }
+
+    fn usage(&self, id: &Ident) -> Option<BindingUsage> {
+        BindingUsage::of(self.scope.as_deref()?, &id.to_id())
+    }
+
+    fn is_unused(&self, id: &Ident) -> bool {
+        self.usage(id).map(|b| !b.is_used()).unwrap_or(false)
+    }
+
+    fn is_ignored(pattern: &Option<Regex>, id: &Ident) -> bool {
+        pattern
+            .as_ref()
+            .map(|p| p.is_match(&id.sym))
+            .unwrap_or(false)
+    }
+
+    fn report(&self, id: &Ident, removal: Option<Span>) {
+        let assigned = self.usage(id).map(|b| b.writes > 0).unwrap_or(false);
+
+        let message = if assigned {
+            format!("'{}' is assigned a value but never used", id.sym)
+        } else {
+            format!("'{}' is defined but never used", id.sym)
+        };
+
+        // Removing a declaration may break code which relies on it being
+        // evaluated, so the fix is not applied by default.
+        emit_with_fix(
+            self.expected_reaction,
+            id.span,
+            &message,
+            removal.map(|span| {
+                FixSuggestion::new(
+                    format!("remove unused variable '{}'", id.sym),
+                    vec![Fix::remove(span)],
+                    Applicability::MaybeIncorrect,
+                )
+            }),
+        );
+    }
+
+    fn check_var(&self, id: &Ident, removal: Option<Span>) {
+        if !self.is_unused(id) || Self::is_ignored(&self.vars_ignore_pattern, id) {
+            return;
+        }
+
+        if self.vars == VarsMode::Local && self.usage(id).map(|b| b.is_top_level).unwrap_or(false) {
+            return;
+        }
+
+        self.report(id, removal);
+    }
+
+    fn check_pat(&self, pat: &Pat, removal: Option<Span>) {
+        let ids = pat_binding_idents(pat);
+
+        for (id, has_rest_sibling) in &ids {
+            if *has_rest_sibling && self.ignore_rest_siblings {
+                continue;
+            }
+
+            self.check_var(
+                &id.id,
+                if ids.len() == 1 && matches!(pat, Pat::Ident(..)) {
+                    removal
+                } else {
+                    None
+                },
+            );
+        }
+    }
+
+    fn check_params<'a>(&self, params: impl Iterator<Item = &'a Pat>) {
+        if self.args == ArgsMode::None {
+            return;
+        }
+
+        let params = params
+            .filter(|p| !matches!(p, Pat::Ident(i) if &*i.id.sym == "this"))
+            .map(pat_binding_idents)
+            .collect::<Vec<_>>();
+
+        let start = match self.args {
+            ArgsMode::AfterUsed => params
+                .iter()
+                .rposition(|ids| ids.iter().any(|(id, _)| !self.is_unused(&id.id)))
+                .map(|i| i + 1)
+                .unwrap_or(0),
+            _ => 0,
+        };
+
+        for (id, has_rest_sibling) in params[start..].iter().flatten() {
+            if !self.is_unused(&id.id)
+                || (*has_rest_sibling && self.ignore_rest_siblings)
+                || Self::is_ignored(&self.args_ignore_pattern, &id.id)
+            {
+                continue;
+            }
+
+            self.report(&id.id, None);
+        }
+    }
+
+    fn check_var_decl(&self, n: &VarDecl, removable: bool) {
+        if n.declare {
+            return;
+        }
+
+        for (idx, decl) in n.decls.iter().enumerate() {
+            let removal = if !removable || !is_removable_init(decl.init.as_deref()) {
+                None
+            } else if n.decls.len() == 1 {
+                Some(n.span)
+            } else {
+                Some(list_item_removal_span(&n.decls, idx))
+            };
+
+            self.check_pat(&decl.name, removal);
+        }
+    }
+}
+
+/// Returns `true` if removing the declarator does not drop a side effect.
+fn is_removable_init(init: Option<&Expr>) -> bool {
+    match init {
+        None => true,
+        Some(e) => matches!(
+            e,
+            Expr::Lit(..) | Expr::Ident(..) | Expr::Fn(..) | Expr::Arrow(..)
+        ),
+    }
+}
+
+impl Visit for NoUnusedVars {
+    noop_visit_type!();
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.check_params(n.params.iter());
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        if let (CaughtErrorsMode::All, Some(param)) = (self.caught_errors, &n.param) {
+            for (id, _) in pat_binding_idents(param) {
+                if self.is_unused(&id.id)
+                    && !Self::is_ignored(&self.caught_errors_ignore_pattern, &id.id)
+                {
+                    self.report(&id.id, None);
+                }
+            }
+        }
+
+        n.body.visit_with(self);
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        if !n.declare {
+            self.check_var(&n.ident, Some(n.class.span));
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor) {
+        if n.body.is_some() {
+            self.check_params(n.params.iter().filter_map(|p| match p {
+                ParamOrTsParamProp::Param(p) => Some(&p.pat),
+                ParamOrTsParamProp::TsParamProp(..) => None,
+            }));
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        if !n.declare {
+            self.check_var(&n.ident, Some(n.function.span));
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
+        if let ForHead::VarDecl(v) = &n.left {
+            self.check_var_decl(v, false);
+        }
+
+        n.right.visit_with(self);
+        n.body.visit_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        if let ForHead::VarDecl(v) = &n.left {
+            self.check_var_decl(v, false);
+        }
+
+        n.right.visit_with(self);
+        n.body.visit_with(self);
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        if n.body.is_some() {
+            self.check_params(n.params.iter().map(|p| &p.pat));
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        // Ambient declarations describe bindings defined elsewhere.
+        if !n.declare {
+            n.visit_children_with(self);
+        }
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl) {
+        self.check_var_decl(n, true);
+
+        n.visit_children_with(self);
+    }
+}
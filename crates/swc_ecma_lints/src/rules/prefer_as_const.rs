@@ -0,0 +1,105 @@
+use swc_common::{errors::Applicability, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{
+    config::{LintRuleReaction, RuleConfig},
+    fix::{emit_with_fix, Fix, FixSuggestion},
+    rule::{visitor_rule, Rule},
+};
+
+const ASSERTION_MESSAGE: &str = "Expected a `const` assertion instead of a literal type assertion";
+const ANNOTATION_MESSAGE: &str =
+    "Expected a `const` assertion instead of a literal type annotation";
+
+pub fn prefer_as_const(config: &RuleConfig<()>) -> Option<Box<dyn Rule>> {
+    match config.get_rule_reaction() {
+        LintRuleReaction::Off => None,
+        reaction => Some(visitor_rule(PreferAsConst {
+            expected_reaction: reaction,
+        })),
+    }
+}
+
+#[derive(Debug, Default)]
+struct PreferAsConst {
+    expected_reaction: LintRuleReaction,
+}
+
+/// Returns `true` if `ty` is the literal type of `expr`, like `'a'` for
+/// `'a'`.
+fn is_same_literal(expr: &Expr, ty: &TsType) -> bool {
+    let TsType::TsLitType(TsLitType { lit: ty, .. }) = ty else {
+        return false;
+    };
+
+    match (expr, ty) {
+        (Expr::Lit(Lit::Str(e)), TsLit::Str(t)) => e.value == t.value,
+        (Expr::Lit(Lit::Num(e)), TsLit::Number(t)) => e.value == t.value,
+        _ => false,
+    }
+}
+
+impl PreferAsConst {
+    fn check_assertion(&self, expr: &Expr, ty: &TsType) {
+        if !is_same_literal(expr, ty) {
+            return;
+        }
+
+        emit_with_fix(
+            self.expected_reaction,
+            ty.span(),
+            ASSERTION_MESSAGE,
+            Some(FixSuggestion::new(
+                "use `const`",
+                vec![Fix::replace(ty.span(), "const")],
+                Applicability::MachineApplicable,
+            )),
+        );
+    }
+}
+
+impl Visit for PreferAsConst {
+    fn visit_ts_as_expr(&mut self, n: &TsAsExpr) {
+        self.check_assertion(&n.expr, &n.type_ann);
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_type_assertion(&mut self, n: &TsTypeAssertion) {
+        self.check_assertion(&n.expr, &n.type_ann);
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        if let (
+            Pat::Ident(BindingIdent {
+                id,
+                type_ann: Some(type_ann),
+            }),
+            Some(init),
+        ) = (&n.name, &n.init)
+        {
+            if is_same_literal(init, &type_ann.type_ann) {
+                // Changes the type of the variable if it's reassigned, so the
+                // fix must be reviewed.
+                emit_with_fix(
+                    self.expected_reaction,
+                    type_ann.type_ann.span(),
+                    ANNOTATION_MESSAGE,
+                    Some(FixSuggestion::new(
+                        "use `as const`",
+                        vec![
+                            Fix::remove(Span::new(id.span.hi, type_ann.span.hi)),
+                            Fix::insert_after(init.span(), " as const"),
+                        ],
+                        Applicability::MaybeIncorrect,
+                    )),
+                );
+            }
+        }
+
+        n.visit_children_with(self);
+    }
+}
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
 use swc_atoms::Atom;
-use swc_common::SyntaxContext;
-use swc_ecma_ast::{Expr, Lit, MemberExpr, MemberProp, Number, Regex, Str, TaggedTpl, Tpl};
+use swc_common::{Span, Spanned, SyntaxContext};
+use swc_ecma_ast::{
+    BindingIdent, Expr, Id, Lit, MemberExpr, MemberProp, Number, ObjectPatProp, Pat, Regex, Str,
+    TaggedTpl, Tpl,
+};
+use swc_ecma_scope_analysis::{ReferenceKind, ScopeAnalysis};
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -91,3 +95,97 @@ pub fn extract_arg_val(unresolved_ctxt: SyntaxContext, expr: &Expr) -> ArgValue
         _ => ArgValue::Other,
     }
 }
+
+/// Collects identifiers bound by a pattern. The second element of each item is
+/// `true` if the identifier is a sibling of an object rest element.
+pub fn pat_binding_idents(pat: &Pat) -> Vec<(&BindingIdent, bool)> {
+    fn collect<'a>(pat: &'a Pat, has_rest_sibling: bool, out: &mut Vec<(&'a BindingIdent, bool)>) {
+        match pat {
+            Pat::Ident(i) => out.push((i, has_rest_sibling)),
+            Pat::Array(a) => {
+                for elem in a.elems.iter().flatten() {
+                    collect(elem, false, out);
+                }
+            }
+            Pat::Rest(r) => collect(&r.arg, false, out),
+            Pat::Object(o) => {
+                let has_rest = o.props.iter().any(|p| matches!(p, ObjectPatProp::Rest(..)));
+
+                for prop in &o.props {
+                    match prop {
+                        ObjectPatProp::KeyValue(kv) => collect(&kv.value, has_rest, out),
+                        ObjectPatProp::Assign(a) => out.push((&a.key, has_rest)),
+                        ObjectPatProp::Rest(r) => collect(&r.arg, false, out),
+                    }
+                }
+            }
+            Pat::Assign(a) => collect(&a.left, has_rest_sibling, out),
+            Pat::Expr(..) | Pat::Invalid(..) => {}
+        }
+    }
+
+    let mut out = Vec::new();
+    collect(pat, false, &mut out);
+    out
+}
+
+/// Span to remove an element of a comma-separated list, including one of the
+/// separators next to it.
+pub fn list_item_removal_span<T: Spanned>(items: &[T], idx: usize) -> Span {
+    let span = items[idx].span();
+
+    if idx > 0 {
+        span.with_lo(items[idx - 1].span().hi)
+    } else if let Some(next) = items.get(idx + 1) {
+        span.with_hi(next.span().lo)
+    } else {
+        span
+    }
+}
+
+/// How a binding is referenced. Like eslint, compound assignments and updates
+/// like `a += 1` don't count as reads.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BindingUsage {
+    pub reads: u32,
+    /// Number of reads from type annotations.
+    pub type_reads: u32,
+    /// Number of writes other than the declaration itself.
+    pub writes: u32,
+    pub exported: bool,
+    pub is_top_level: bool,
+}
+
+impl BindingUsage {
+    /// Returns [None] if `id` is not declared in the program.
+    pub fn of(scope: &ScopeAnalysis, id: &Id) -> Option<Self> {
+        let binding = scope.binding(scope.binding_of(id)?);
+
+        let mut usage = BindingUsage {
+            exported: binding.exported,
+            is_top_level: binding.scope == scope.root(),
+            ..Default::default()
+        };
+
+        for &r in &binding.references {
+            let r = scope.reference(r);
+
+            match r.kind {
+                ReferenceKind::Read => {
+                    usage.reads += 1;
+                    if r.is_type {
+                        usage.type_reads += 1;
+                    }
+                }
+                ReferenceKind::Write | ReferenceKind::ReadWrite => usage.writes += 1,
+            }
+        }
+
+        Some(usage)
+    }
+
+    #[inline]
+    pub fn is_used(&self) -> bool {
+        self.exported || self.reads > 0
+    }
+}
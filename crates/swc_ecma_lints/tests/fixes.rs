@@ -0,0 +1,86 @@
+#![cfg(feature = "non_critical_lints")]
+
+use swc_common::{FileName, Mark, SyntaxContext};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_lints::{
+    config::LintConfig,
+    fix::{apply_fixes, collect_fixes},
+    linter::{LintFile, Linter},
+    registry::RuleRegistry,
+};
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+use swc_ecma_transforms_base::resolver;
+
+/// Lints `src` with a single rule and applies all of its fixes.
+fn fix(rule: &str, src: &str) -> String {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig =
+            serde_json::from_str(&format!(r#"{{ "{}": ["error"] }}"#, rule)).unwrap();
+        let linter = Linter::new(RuleRegistry::default(), config, cm.clone());
+
+        let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+        let mut program = parse_file_as_program(
+            &fm,
+            Syntax::Typescript(Default::default()),
+            EsVersion::latest(),
+            None,
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+        program.mutate(resolver(unresolved_mark, top_level_mark, true));
+
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt: SyntaxContext::empty().apply_mark(unresolved_mark),
+            top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+            es_version: EsVersion::latest(),
+            comments: &[],
+        });
+        assert!(!diagnostics.is_empty(), "no problem was reported");
+
+        let fixes = diagnostics
+            .iter()
+            .flat_map(|d| collect_fixes(d, true))
+            .collect();
+
+        Ok(apply_fixes(&fm, fixes).code)
+    })
+    .unwrap()
+}
+
+#[test]
+fn consistent_type_imports() {
+    assert_eq!(
+        fix(
+            "consistent-type-imports",
+            "import { A, type B } from 'a';\nlet x: A | B;"
+        ),
+        "import type { A, B } from 'a';\nlet x: A | B;"
+    );
+    assert_eq!(
+        fix(
+            "consistent-type-imports",
+            "import { A, b } from 'a';\nlet x: A = b;"
+        ),
+        "import { type A, b } from 'a';\nlet x: A = b;"
+    );
+}
+
+#[test]
+fn prefer_as_const() {
+    assert_eq!(
+        fix("prefer-as-const", "let a = 'a' as 'a';\nlet b: 1 = 1;"),
+        "let a = 'a' as const;\nlet b = 1 as const;"
+    );
+}
+
+#[test]
+fn no_non_null_asserted_optional_chain() {
+    assert_eq!(
+        fix("no-non-null-asserted-optional-chain", "a?.b!;\n(a?.b)!.c;"),
+        "a?.b;\n(a?.b).c;"
+    );
+}
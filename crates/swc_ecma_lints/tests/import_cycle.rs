@@ -0,0 +1,131 @@
+#![cfg(feature = "non_critical_lints")]
+
+use swc_common::{errors::Level, FileName, SourceMap};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_lints::{
+    config::LintConfig,
+    import_cycle::{check_import_cycles, ProjectFile},
+};
+use swc_ecma_loader::resolve::{Resolution, Resolve};
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+
+/// Resolves `./name` to a file named `name`.
+struct TestResolver;
+
+impl Resolve for TestResolver {
+    fn resolve(&self, _: &FileName, src: &str) -> anyhow::Result<Resolution> {
+        Ok(Resolution {
+            filename: FileName::Custom(src.trim_start_matches("./").to_string()),
+            slug: None,
+        })
+    }
+}
+
+fn check(config: &str, files: &[(&str, &str)]) -> Vec<Vec<(Level, String)>> {
+    let cm = SourceMap::default();
+    let config: LintConfig = serde_json::from_str(config).unwrap();
+
+    let programs = files
+        .iter()
+        .map(|(name, src)| {
+            let fm = cm.new_source_file(FileName::Custom(name.to_string()).into(), src.to_string());
+            parse_file_as_program(
+                &fm,
+                Syntax::Typescript(Default::default()),
+                EsVersion::latest(),
+                None,
+                &mut Vec::new(),
+            )
+            .unwrap()
+        })
+        .collect::<Vec<Program>>();
+
+    let project = files
+        .iter()
+        .zip(&programs)
+        .map(|((name, _), program)| ProjectFile {
+            file_name: FileName::Custom(name.to_string()),
+            program,
+        })
+        .collect::<Vec<_>>();
+
+    check_import_cycles(&config.import_cycle, &project, &TestResolver)
+        .into_iter()
+        .map(|diagnostics| {
+            diagnostics
+                .into_iter()
+                .map(|d| (d.level, d.message()))
+                .collect()
+        })
+        .collect()
+}
+
+#[test]
+fn reports_full_chain() {
+    let results = check(
+        r#"{ "import-cycle": ["error"] }"#,
+        &[
+            ("a", "import { b } from './b'; export const a = 1;"),
+            ("b", "export * from './c'; export const b = 1;"),
+            ("c", "import { a } from './a'; import 'external';"),
+            ("d", "import './a';"),
+        ],
+    );
+
+    assert_eq!(
+        results,
+        vec![
+            vec![(
+                Level::Error,
+                "Dependency cycle detected: a -> b -> c -> a".to_string()
+            )],
+            vec![(
+                Level::Error,
+                "Dependency cycle detected: b -> c -> a -> b".to_string()
+            )],
+            vec![(
+                Level::Error,
+                "Dependency cycle detected: c -> a -> b -> c".to_string()
+            )],
+            vec![],
+        ]
+    );
+}
+
+#[test]
+fn type_only_imports() {
+    let files = [
+        ("a", "import type { B } from './b'; export type A = 1;"),
+        ("b", "import { type A } from './a'; export type B = 1;"),
+    ];
+
+    let results = check(r#"{ "import-cycle": ["warn"] }"#, &files);
+    assert!(results.iter().all(|r| r.is_empty()));
+
+    let results = check(
+        r#"{ "import-cycle": ["warn", { "includeTypeImports": true }] }"#,
+        &files,
+    );
+    assert_eq!(results[0].len(), 1);
+    assert_eq!(results[1].len(), 1);
+}
+
+#[test]
+fn allowed_cycles() {
+    let files = [
+        ("a", "import './b';"),
+        ("b", "import './a';"),
+        ("c", "import './d';"),
+        ("d", "import './c';"),
+    ];
+
+    let results = check(
+        r#"{ "import-cycle": ["error", { "allowedCycles": [["^a$", "^b$"]] }] }"#,
+        &files,
+    );
+
+    assert_eq!(
+        results.iter().map(|r| r.len()).collect::<Vec<_>>(),
+        vec![0, 0, 1, 1]
+    );
+}
@@ -0,0 +1,566 @@
+use std::sync::Arc;
+
+use swc_common::{
+    comments::{Comments, SingleThreadedComments},
+    errors::{Applicability, DiagnosticId, Level},
+    FileName, Mark, SourceFile, SourceMap, Spanned, SyntaxContext,
+};
+use swc_ecma_ast::{EsVersion, Ident, Module, Program, Script};
+use swc_ecma_lints::{
+    config::{LintConfig, LintRuleReaction},
+    fix::{apply_fixes, collect_fixes, emit_with_fix, Fix, FixSuggestion},
+    format::{LintResult, OutputFormat},
+    linter::{sorted_comments, LintFile, Linter},
+    registry::{RuleContext, RuleRegistry},
+    rule::Rule,
+    rules::LintParams,
+};
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+use swc_ecma_scope_analysis::{BindingKind, ReferenceKind};
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Reports every identifier named `forbidden`.
+#[derive(Debug)]
+struct NoForbidden(LintRuleReaction);
+
+impl Visit for NoForbidden {
+    fn visit_ident(&mut self, i: &Ident) {
+        if &*i.sym == "forbidden" {
+            emit_with_fix(
+                self.0,
+                i.span,
+                "forbidden",
+                Some(FixSuggestion::new(
+                    "rename to 'allowed'",
+                    vec![Fix::replace(i.span, "allowed")],
+                    Applicability::MachineApplicable,
+                )),
+            );
+        }
+    }
+}
+
+impl Rule for NoForbidden {
+    fn lint_module(&mut self, program: &Module) {
+        program.visit_with(self);
+    }
+
+    fn lint_script(&mut self, program: &Script) {
+        program.visit_with(self);
+    }
+}
+
+fn registry() -> RuleRegistry {
+    let mut registry = RuleRegistry::default();
+    registry.register("no-forbidden", |ctx| {
+        match ctx.custom_reaction("no-forbidden") {
+            LintRuleReaction::Off => None,
+            reaction => Some(Box::new(NoForbidden(reaction))),
+        }
+    });
+    registry
+}
+
+fn parse(src: &str) -> (Program, SyntaxContext, SyntaxContext) {
+    let (_, program, unresolved_ctxt, top_level_ctxt) = parse_with(&Default::default(), src);
+    (program, unresolved_ctxt, top_level_ctxt)
+}
+
+fn parse_with(
+    cm: &SourceMap,
+    src: &str,
+) -> (Arc<SourceFile>, Program, SyntaxContext, SyntaxContext) {
+    parse_with_comments(cm, src, None)
+}
+
+fn parse_with_comments(
+    cm: &SourceMap,
+    src: &str,
+    comments: Option<&dyn Comments>,
+) -> (Arc<SourceFile>, Program, SyntaxContext, SyntaxContext) {
+    let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+    let mut program = parse_file_as_program(
+        &fm,
+        Syntax::Typescript(Default::default()),
+        EsVersion::latest(),
+        comments,
+        &mut Vec::new(),
+    )
+    .unwrap();
+
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+    program.mutate(resolver(unresolved_mark, top_level_mark, true));
+
+    (
+        fm,
+        program,
+        SyntaxContext::empty().apply_mark(unresolved_mark),
+        SyntaxContext::empty().apply_mark(top_level_mark),
+    )
+}
+
+#[test]
+fn custom_rule_severity_from_config() {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig =
+            serde_json::from_str(r#"{ "custom": { "no-forbidden": ["warn"] } }"#).unwrap();
+        let linter = Linter::new(registry(), config, cm);
+
+        let (program, unresolved_ctxt, top_level_ctxt) = parse("let forbidden = 1;");
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &[],
+        });
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].level, Level::Warning);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn misspelled_rule_is_not_custom() {
+    let config: LintConfig = serde_json::from_str(r#"{ "no-consle": ["error"] }"#).unwrap();
+
+    assert!(config.custom.is_empty());
+}
+
+#[test]
+fn disabled_custom_rule() {
+    testing::run_test(false, |cm, _| {
+        let linter = Linter::new(registry(), LintConfig::default(), cm);
+        assert!(linter.registry().contains("no-forbidden"));
+        assert!(linter.registry().contains("no-dupe-args"));
+
+        let (program, unresolved_ctxt, top_level_ctxt) = parse("let forbidden = 1;");
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &[],
+        });
+
+        assert!(diagnostics.is_empty());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn lint_all_preserves_order() {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig =
+            serde_json::from_str(r#"{ "custom": { "no-forbidden": [2] } }"#).unwrap();
+        let linter = Linter::new(registry(), config, cm);
+
+        let programs = [
+            parse("forbidden; forbidden;"),
+            parse("let ok = 1;"),
+            parse("forbidden;"),
+        ];
+        let files = programs
+            .iter()
+            .map(|(program, unresolved_ctxt, top_level_ctxt)| LintFile {
+                program,
+                unresolved_ctxt: *unresolved_ctxt,
+                top_level_ctxt: *top_level_ctxt,
+                es_version: EsVersion::latest(),
+                comments: &[],
+            })
+            .collect::<Vec<_>>();
+
+        let results = linter.lint_all(&files);
+
+        assert_eq!(
+            results.iter().map(|v| v.len()).collect::<Vec<_>>(),
+            vec![2, 0, 1]
+        );
+        assert!(results[0].iter().all(|d| d.level == Level::Error));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn rule_context_scope() {
+    testing::run_test(false, |cm, _| {
+        let (program, unresolved_ctxt, top_level_ctxt) = parse(
+            "
+            import { a, b } from 'x';
+            import type { T } from 'y';
+            export function f(p: T, q) { return p; }
+            let x = 1;
+            x = 2;
+            b;
+            ",
+        );
+        let lint_config = LintConfig::default();
+        let params = LintParams {
+            program: &program,
+            lint_config: &lint_config,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            source_map: cm,
+        };
+
+        let ctx = RuleContext::new(&params);
+        let scope = ctx.scope();
+        assert!(Arc::ptr_eq(scope, ctx.scope()));
+
+        let find = |name: &str| {
+            scope
+                .bindings()
+                .find(|(_, b)| &*b.id.0 == name)
+                .map(|(_, b)| b)
+                .unwrap()
+        };
+        let reference_kinds = |name: &str| {
+            find(name)
+                .references
+                .iter()
+                .map(|&r| scope.reference(r).kind)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(find("a").kind, BindingKind::Import);
+        assert!(find("a").references.is_empty());
+        assert_eq!(reference_kinds("b"), [ReferenceKind::Read]);
+        assert!(find("T")
+            .references
+            .iter()
+            .all(|&r| scope.reference(r).is_type));
+        assert!(find("f").exported);
+        assert_eq!(reference_kinds("q"), []);
+        assert_eq!(reference_kinds("x"), [ReferenceKind::Write]);
+        assert_eq!(find("x").scope, scope.root());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn apply_collected_fixes() {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig =
+            serde_json::from_str(r#"{ "custom": { "no-forbidden": ["error"] } }"#).unwrap();
+        let linter = Linter::new(registry(), config, cm.clone());
+
+        let (fm, program, unresolved_ctxt, top_level_ctxt) =
+            parse_with(&cm, "let forbidden = 1;\nconsole.log(forbidden);\n");
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &[],
+        });
+
+        let fixes = diagnostics
+            .iter()
+            .flat_map(|d| collect_fixes(d, false))
+            .collect::<Vec<_>>();
+        let fixed = apply_fixes(&fm, fixes);
+
+        assert_eq!(fixed.code, "let allowed = 1;\nconsole.log(allowed);\n");
+        assert_eq!(fixed.applied, 2);
+        assert_eq!(fixed.skipped, 0);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn overlapping_fixes_are_skipped() {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon.into(), "abcdef".into());
+        let span = |lo: u32, hi: u32| {
+            swc_common::Span::new(
+                fm.start_pos + swc_common::BytePos(lo),
+                fm.start_pos + swc_common::BytePos(hi),
+            )
+        };
+
+        let fixed = apply_fixes(
+            &fm,
+            vec![
+                FixSuggestion::new(
+                    "",
+                    vec![Fix::replace(span(2, 4), "X")],
+                    Applicability::MachineApplicable,
+                ),
+                FixSuggestion::new(
+                    "",
+                    vec![Fix::remove(span(0, 3))],
+                    Applicability::MachineApplicable,
+                ),
+                FixSuggestion::new(
+                    "",
+                    vec![Fix::insert_after(span(5, 6), "!"), Fix::remove(span(4, 5))],
+                    Applicability::MachineApplicable,
+                ),
+            ],
+        );
+
+        assert_eq!(fixed.code, "df!");
+        assert_eq!(fixed.applied, 2);
+        assert_eq!(fixed.skipped, 1);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+fn lint_results(cm: Arc<SourceMap>) -> Vec<LintResult> {
+    let config: LintConfig =
+        serde_json::from_str(r#"{ "custom": { "no-forbidden": ["warn"] } }"#).unwrap();
+    let linter = Linter::new(registry(), config, cm.clone());
+
+    let (_, program, unresolved_ctxt, top_level_ctxt) =
+        parse_with(&cm, "let ok = 1;\nlet forbidden = 2;\n");
+    let diagnostics = linter.lint(&LintFile {
+        program: &program,
+        unresolved_ctxt,
+        top_level_ctxt,
+        es_version: EsVersion::latest(),
+        comments: &[],
+    });
+
+    vec![LintResult {
+        file_path: "src/a.ts".into(),
+        diagnostics,
+    }]
+}
+
+#[test]
+fn format_stylish_and_compact() {
+    testing::run_test(false, |cm, _| {
+        let results = lint_results(cm.clone());
+
+        assert_eq!(
+            OutputFormat::Stylish.format(&cm, &results),
+            "\nsrc/a.ts\n  2:5  warning  forbidden  no-forbidden\n\n\u{2716} 1 problem (0 errors, \
+             1 warning)\n  0 errors and 1 warning potentially fixable with the `--fix` option.\n\n"
+        );
+        assert_eq!(
+            OutputFormat::Compact.format(&cm, &results),
+            "src/a.ts: line 2, col 5, Warning - forbidden (no-forbidden)\n\n1 problem\n"
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn format_json_and_sarif() {
+    testing::run_test(false, |cm, _| {
+        let results = lint_results(cm.clone());
+
+        let json: serde_json::Value =
+            serde_json::from_str(&OutputFormat::Json.format(&cm, &results)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "filePath": "src/a.ts",
+                "messages": [{
+                    "ruleId": "no-forbidden",
+                    "severity": 1,
+                    "message": "forbidden",
+                    "line": 2,
+                    "column": 5,
+                    "endLine": 2,
+                    "endColumn": 14,
+                    "fix": { "range": [16, 25], "text": "allowed" },
+                }],
+                "errorCount": 0,
+                "fatalErrorCount": 0,
+                "warningCount": 1,
+                "fixableErrorCount": 0,
+                "fixableWarningCount": 1,
+            }])
+        );
+
+        let sarif: serde_json::Value =
+            serde_json::from_str(&OutputFormat::Sarif.format(&cm, &results)).unwrap();
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "no-forbidden");
+        assert_eq!(run["results"][0]["level"], "warning");
+        assert_eq!(run["results"][0]["ruleIndex"], 0);
+        assert_eq!(
+            run["results"][0]["locations"][0]["physicalLocation"]["region"]["startLine"],
+            2
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn disable_directives() {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig =
+            serde_json::from_str(r#"{ "custom": { "no-forbidden": ["error"] } }"#).unwrap();
+        let linter = Linter::new(registry(), config, cm.clone());
+
+        let comments = SingleThreadedComments::default();
+        let (_, program, unresolved_ctxt, top_level_ctxt) = parse_with_comments(
+            &cm,
+            "
+            // swc-lint-disable-next-line no-forbidden -- known issue
+            let forbidden = 1;
+            forbidden;
+            // swc-lint-disable-next-line
+            forbidden;
+            // swc-lint-disable-next-line no-forbidden, no-console
+            forbidden;
+            // swc-lint-disable-next-line no-forbidden
+            let ok = 1;
+            ",
+            Some(&comments),
+        );
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &sorted_comments(&comments),
+        });
+
+        let messages = diagnostics
+            .iter()
+            .map(|d| (d.level, d.message()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            messages,
+            vec![
+                (Level::Error, "forbidden".into()),
+                (
+                    Level::Warning,
+                    "Unused swc-lint-disable directive (no problems were reported from \
+                     'no-console')"
+                        .into()
+                ),
+                (
+                    Level::Warning,
+                    "Unused swc-lint-disable directive (no problems were reported from \
+                     'no-forbidden')"
+                        .into()
+                ),
+            ]
+        );
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn file_level_disable_directive() {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig = serde_json::from_str(
+            r#"{ "custom": { "no-forbidden": ["error"] }, "reportUnusedDisableDirectives": "off" }"#,
+        )
+        .unwrap();
+        let linter = Linter::new(registry(), config, cm.clone());
+
+        let comments = SingleThreadedComments::default();
+        let (_, program, unresolved_ctxt, top_level_ctxt) = parse_with_comments(
+            &cm,
+            "/* swc-lint-disable no-forbidden */\nforbidden;\n/* swc-lint-disable no-console \
+             */\nforbidden;\n",
+            Some(&comments),
+        );
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &sorted_comments(&comments),
+        });
+
+        assert!(diagnostics.is_empty());
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn file_level_disable_directive_after_code() {
+    testing::run_test(false, |cm, _| {
+        let config: LintConfig = serde_json::from_str(
+            r#"{ "custom": { "no-forbidden": ["error"] }, "reportUnusedDisableDirectives": "off" }"#,
+        )
+        .unwrap();
+        let linter = Linter::new(registry(), config, cm.clone());
+
+        let comments = SingleThreadedComments::default();
+        let (_, program, unresolved_ctxt, top_level_ctxt) = parse_with_comments(
+            &cm,
+            "forbidden;\n/* swc-lint-disable no-forbidden */\nforbidden;\n",
+            Some(&comments),
+        );
+        let diagnostics = linter.lint(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &sorted_comments(&comments),
+        });
+
+        assert_eq!(diagnostics.len(), 2);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[test]
+fn disable_directive_applies_to_added_diagnostics() {
+    testing::run_test(false, |cm, handler| {
+        let config: LintConfig =
+            serde_json::from_str(r#"{ "reportUnusedDisableDirectives": "error" }"#).unwrap();
+        let linter = Linter::new(registry(), config, cm.clone());
+
+        let comments = SingleThreadedComments::default();
+        let (_, program, unresolved_ctxt, top_level_ctxt) = parse_with_comments(
+            &cm,
+            "/* swc-lint-disable import-cycle */\nimport './a';\n",
+            Some(&comments),
+        );
+        let comments = sorted_comments(&comments);
+        let mut diagnostics = linter.lint_without_directives(&LintFile {
+            program: &program,
+            unresolved_ctxt,
+            top_level_ctxt,
+            es_version: EsVersion::latest(),
+            comments: &comments,
+        });
+
+        let mut cycle = handler
+            .struct_span_err(program.span(), "import cycle")
+            .take();
+        cycle.code(DiagnosticId::Lint("import-cycle".into()));
+        diagnostics.push(cycle);
+
+        let diagnostics = linter.apply_disable_directives(&program, &comments, diagnostics);
+        assert!(diagnostics.is_empty());
+
+        Ok(())
+    })
+    .unwrap();
+}
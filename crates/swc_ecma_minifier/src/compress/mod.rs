@@ -4,7 +4,10 @@ use std::thread;
 
 #[cfg(feature = "pretty_assertions")]
 use pretty_assertions::assert_eq;
-use swc_common::pass::{CompilerPass, Repeated};
+use swc_common::{
+    cancel,
+    pass::{CompilerPass, Repeated},
+};
 use swc_ecma_ast::*;
 use swc_ecma_usage_analyzer::marks::Marks;
 use swc_ecma_visit::VisitMutWith;
@@ -95,6 +98,10 @@ impl Compressor<'_> {
         }
 
         loop {
+            if cancel::is_cancelled() {
+                break;
+            }
+
             self.changed = false;
             self.optimize_unit(n);
             self.pass += 1;
@@ -1,12 +1,25 @@
-use std::num::FpCategory;
+use std::{fmt, num::FpCategory};
 
+use serde::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer,
+};
 use swc_atoms::atom;
 use swc_common::{util::take::Take, Spanned, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
-use swc_ecma_utils::{ExprExt, Value::Known};
+use swc_ecma_utils::{
+    ExprExt, Type,
+    Value::{Known, Unknown},
+};
 
 use super::{BitCtx, Optimizer};
-use crate::{compress::util::eval_as_number, maybe_par, DISABLE_BUGGY_PASSES};
+use crate::{
+    compress::util::{eval_as_number, is_js_whitespace},
+    maybe_par, DISABLE_BUGGY_PASSES,
+};
+
+/// Maximum length of JSON texts evaluated by `JSON.parse`.
+const MAX_FOLDED_JSON_LEN: usize = 1024;
 
 /// Methods related to the option `evaluate`.
 impl Optimizer<'_> {
@@ -323,6 +336,145 @@ impl Optimizer<'_> {
                     }
                 }
 
+                Expr::Ident(Ident { sym, .. }) if &**sym == "Array" => {
+                    if &*prop.sym == "isArray" {
+                        if args.len() != 1 {
+                            return;
+                        }
+
+                        let value = match &*args[0].expr {
+                            Expr::Array(..) => true,
+                            Expr::Object(..)
+                            | Expr::Fn(..)
+                            | Expr::Arrow(..)
+                            | Expr::Class(..)
+                            | Expr::Lit(..)
+                            | Expr::Tpl(..) => false,
+                            arg => match arg.get_type(self.ctx.expr_ctx) {
+                                Known(Type::Obj) | Unknown => return,
+                                Known(..) => false,
+                            },
+                        };
+
+                        self.changed = true;
+                        report_change!("evaluate: Evaluated `Array.isArray` as `{}`", value);
+
+                        *e = Lit::Bool(Bool { span, value }).into();
+                    }
+                }
+
+                Expr::Ident(Ident { sym, .. }) if &**sym == "Number" => match &*prop.sym {
+                    "isFinite" | "isInteger" | "isNaN" | "isSafeInteger" => {
+                        if args.len() != 1 {
+                            return;
+                        }
+
+                        // These methods don't convert the argument to a number.
+                        let value = match args[0].expr.get_type(self.ctx.expr_ctx) {
+                            Known(Type::Num) => {
+                                let Known(v) = args[0].expr.as_pure_number(self.ctx.expr_ctx)
+                                else {
+                                    return;
+                                };
+
+                                match &*prop.sym {
+                                    "isFinite" => v.is_finite(),
+                                    "isInteger" => v.is_finite() && v.trunc() == v,
+                                    "isNaN" => v.is_nan(),
+                                    _ => {
+                                        v.is_finite()
+                                            && v.trunc() == v
+                                            && v.abs() <= 9007199254740991.0
+                                    }
+                                }
+                            }
+                            Known(..) => false,
+                            Unknown => return,
+                        };
+
+                        self.changed = true;
+                        report_change!("evaluate: Evaluated `Number.{}` as `{}`", prop.sym, value);
+
+                        *e = Lit::Bool(Bool { span, value }).into();
+                    }
+
+                    "parseFloat" | "parseInt" => {
+                        let Some(Expr::Lit(Lit::Str(s))) = args.first().map(|arg| &*arg.expr)
+                        else {
+                            return;
+                        };
+
+                        let value = if &*prop.sym == "parseFloat" {
+                            parse_float(&s.value)
+                        } else {
+                            let radix = match args.get(1) {
+                                Some(arg) => match arg.expr.as_pure_number(self.ctx.expr_ctx) {
+                                    Known(v) if v == 0.0 || (2.0..=36.0).contains(&v) => {
+                                        if v.fract() != 0.0 {
+                                            return;
+                                        }
+                                        v as u32
+                                    }
+                                    _ => return,
+                                },
+                                None => 0,
+                            };
+
+                            parse_int(&s.value, radix)
+                        };
+                        let Some(value) = value else {
+                            return;
+                        };
+
+                        self.changed = true;
+                        report_change!("evaluate: Evaluated `Number.{}` as `{}`", prop.sym, value);
+
+                        if value.is_nan() {
+                            *e = Ident::new(
+                                "NaN".into(),
+                                span,
+                                SyntaxContext::empty().apply_mark(self.marks.unresolved_mark),
+                            )
+                            .into();
+                            return;
+                        }
+
+                        *e = Lit::Num(Number {
+                            span,
+                            value,
+                            raw: None,
+                        })
+                        .into();
+                    }
+
+                    _ => {}
+                },
+
+                Expr::Ident(Ident { sym, .. }) if &**sym == "JSON" => {
+                    if &*prop.sym == "parse" {
+                        if args.len() != 1 {
+                            return;
+                        }
+
+                        let Expr::Lit(Lit::Str(s)) = &*args[0].expr else {
+                            return;
+                        };
+
+                        // Large payloads are kept, as engines parse them faster than object
+                        // literals.
+                        if s.value.len() > MAX_FOLDED_JSON_LEN {
+                            return;
+                        }
+
+                        if let Ok(JsonExpr(value)) = serde_json::from_str(&s.value) {
+                            self.changed = true;
+                            report_change!("evaluate: Evaluated `JSON.parse` of a string literal");
+
+                            *e = *value;
+                        }
+                    }
+                }
+
                 Expr::Ident(Ident { sym, .. }) => {
                     if &**sym == "console" && &*prop.sym == "log" {
                         for arg in args {
@@ -508,3 +660,219 @@ impl Optimizer<'_> {
         }
     }
 }
+
+/// Implements `parseFloat` of a string.
+///
+/// Returns [None] for `-0`, which can't be represented by a numeric literal.
+fn parse_float(s: &str) -> Option<f64> {
+    let s = s.trim_start_matches(is_js_whitespace);
+    let (negative, unsigned) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    let value = if unsigned.starts_with("Infinity") {
+        f64::INFINITY
+    } else {
+        let bytes = unsigned.as_bytes();
+        let digits = |mut i: usize| {
+            while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+                i += 1;
+            }
+            i
+        };
+
+        let int_end = digits(0);
+        let mut end = int_end;
+        if bytes.get(end) == Some(&b'.') {
+            end = digits(end + 1);
+        }
+        // `.` alone is not a number.
+        if end == 0 || (int_end == 0 && end == 1) {
+            return Some(f64::NAN);
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let exp_start = match bytes.get(end + 1) {
+                Some(b'+' | b'-') => end + 2,
+                _ => end + 1,
+            };
+            let exp_end = digits(exp_start);
+            if exp_end > exp_start {
+                end = exp_end;
+            }
+        }
+
+        unsigned[..end].parse::<f64>().ok()?
+    };
+
+    if value == 0.0 && negative {
+        return None;
+    }
+
+    Some(if negative { -value } else { value })
+}
+
+/// Implements `parseInt` of a string, `radix` being `0` if omitted.
+///
+/// Returns [None] for `-0` and values which may be imprecise.
+fn parse_int(s: &str, mut radix: u32) -> Option<f64> {
+    let s = s.trim_start_matches(is_js_whitespace);
+    let (negative, mut s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+
+    if (radix == 0 || radix == 16) && (s.starts_with("0x") || s.starts_with("0X")) {
+        s = &s[2..];
+        radix = 16;
+    }
+    if radix == 0 {
+        radix = 10;
+    }
+
+    let digits = s
+        .chars()
+        .map_while(|c| c.to_digit(radix))
+        .collect::<Vec<_>>();
+    if digits.is_empty() {
+        return Some(f64::NAN);
+    }
+
+    let value = digits
+        .into_iter()
+        .fold(0.0, |value, digit| value * radix as f64 + digit as f64);
+
+    // Engines may approximate numbers which are not safe integers.
+    if value > 9007199254740991.0 || (value == 0.0 && negative) {
+        return None;
+    }
+
+    Some(if negative { -value } else { value })
+}
+
+/// Expression creating the value of a JSON text.
+struct JsonExpr(Box<Expr>);
+
+impl<'de> Deserialize<'de> for JsonExpr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(JsonExprVisitor)
+    }
+}
+
+struct JsonExprVisitor;
+
+impl JsonExprVisitor {
+    fn num<E: de::Error>(value: f64) -> Result<JsonExpr, E> {
+        // `-0` can't be represented by a numeric literal.
+        if value == 0.0 && value.is_sign_negative() {
+            return Err(E::custom("negative zero"));
+        }
+
+        Ok(JsonExpr(
+            Lit::Num(Number {
+                span: DUMMY_SP,
+                value,
+                raw: None,
+            })
+            .into(),
+        ))
+    }
+}
+
+impl<'de> Visitor<'de> for JsonExprVisitor {
+    type Value = JsonExpr;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a JSON value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(JsonExpr(Lit::Null(Null { span: DUMMY_SP }).into()))
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(JsonExpr(
+            Lit::Bool(Bool {
+                span: DUMMY_SP,
+                value,
+            })
+            .into(),
+        ))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Self::num(value as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Self::num(value as f64)
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Self::num(value)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(JsonExpr(
+            Lit::Str(Str {
+                span: DUMMY_SP,
+                value: value.into(),
+                raw: None,
+            })
+            .into(),
+        ))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut elems = Vec::new();
+        while let Some(JsonExpr(expr)) = seq.next_element()? {
+            elems.push(Some(expr.into()));
+        }
+
+        Ok(JsonExpr(
+            ArrayLit {
+                span: DUMMY_SP,
+                elems,
+            }
+            .into(),
+        ))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut props = Vec::new();
+        while let Some(key) = map.next_key::<String>()? {
+            // `JSON.parse` creates an own property, but an object literal sets the
+            // prototype.
+            if key == "__proto__" {
+                return Err(de::Error::custom("`__proto__` key"));
+            }
+
+            let JsonExpr(value) = map.next_value()?;
+
+            props.push(PropOrSpread::Prop(
+                Prop::KeyValue(KeyValueProp {
+                    key: PropName::Str(Str {
+                        span: DUMMY_SP,
+                        value: key.into(),
+                        raw: None,
+                    }),
+                    value,
+                })
+                .into(),
+            ));
+        }
+
+        Ok(JsonExpr(
+            ObjectLit {
+                span: DUMMY_SP,
+                props,
+            }
+            .into(),
+        ))
+    }
+}
@@ -2,7 +2,10 @@ use rustc_hash::{FxHashMap, FxHashSet};
 use swc_common::{util::take::Take, EqIgnoreSpan, Mark};
 use swc_ecma_ast::*;
 use swc_ecma_usage_analyzer::alias::{collect_infects_from, AliasConfig};
-use swc_ecma_utils::{class_has_side_effect, collect_decls, find_pat_ids, ExprExt, Remapper};
+use swc_ecma_utils::{
+    class_has_side_effect, collect_decls, contains_arguments, contains_this_expr, find_pat_ids,
+    ExprExt, Remapper,
+};
 use swc_ecma_visit::VisitMutWith;
 
 use super::Optimizer;
@@ -539,30 +542,46 @@ impl Optimizer<'_> {
         } as usize;
         let cost_limit = 3 + param_cost + func_body_cost;
 
-        if body.stmts.len() == 1 {
-            match &body.stmts[0] {
-                Stmt::Expr(ExprStmt { expr, .. })
-                    if expr.size(self.ctx.expr_ctx.unresolved_ctxt) < cost_limit =>
-                {
-                    return true
-                }
+        if body.stmts.len() != 1 {
+            return false;
+        }
 
-                Stmt::Return(ReturnStmt { arg: Some(arg), .. })
-                    if arg.size(self.ctx.expr_ctx.unresolved_ctxt) < cost_limit =>
-                {
-                    return true
-                }
+        let size = match &body.stmts[0] {
+            Stmt::Expr(ExprStmt { expr, .. }) => expr.size(self.ctx.expr_ctx.unresolved_ctxt),
+            Stmt::Return(ReturnStmt { arg: Some(arg), .. }) => {
+                arg.size(self.ctx.expr_ctx.unresolved_ctxt)
+            }
+            // size of void 0
+            Stmt::Return(ReturnStmt { arg: None, .. }) => 6,
+            _ => return false,
+        };
 
-                Stmt::Return(ReturnStmt { arg: None, .. }) => {
-                    // size of void 0
-                    return 6 < cost_limit;
-                }
+        if size < cost_limit {
+            return true;
+        }
 
-                _ => {}
-            }
+        let Some(budget) = self.options.inline_fns_budget else {
+            return false;
+        };
+
+        // The function expression is inlined only if it can be invoked, which
+        // is not the case if it depends on the way it's called.
+        if contains_this_expr(body) || contains_arguments(body) {
+            return false;
         }
 
-        false
+        let callee_count = usage.callee_count as usize;
+        // Every call site is replaced by the body, without the call itself.
+        let added = callee_count * size.saturating_sub(3 + param_count);
+        // The declaration is removed if all references are calls.
+        let removed = if usage.ref_count == usage.callee_count {
+            // length of "function c(){return }"
+            21 + param_cost + size
+        } else {
+            0
+        };
+
+        added <= removed + budget
     }
 
     /// Stores `typeof` of [ClassDecl] and [FnDecl].
@@ -714,7 +714,21 @@ impl Optimizer<'_> {
         loop {
             let mut changed = false;
             for a_idx in 0..exprs.len().saturating_sub(1) {
-                for b_idx in (a_idx + 1)..exprs.len() {
+                // Scanning again is useless unless an expression compared in the previous
+                // scan was modified since, so we don't revisit the whole sequence on each pass.
+                if !merge_seq_cache.needs_scan(a_idx) {
+                    continue;
+                }
+
+                let b_end = match self.options.sequences_lookahead {
+                    Some(lookahead) => exprs.len().min(a_idx + 1 + lookahead),
+                    None => exprs.len(),
+                };
+                let mut scan = merge_seq_cache.start_scan(a_idx);
+
+                for b_idx in (a_idx + 1)..b_end {
+                    scan.end = b_idx;
+
                     let (a1, a2) = exprs.split_at_mut(a_idx + 1);
                     let a = a1.last_mut().unwrap();
                     let b = &mut a2[b_idx - a_idx - 1];
@@ -761,6 +775,7 @@ impl Optimizer<'_> {
                                                     an.id
                                                 );
                                                 av.name.take();
+                                                merge_seq_cache.invalidate(a_idx);
                                                 break;
                                             }
                                         }
@@ -946,6 +961,8 @@ impl Optimizer<'_> {
                         Mergable::Drop => break,
                     }
                 }
+
+                merge_seq_cache.end_scan(a_idx, scan);
             }
 
             if !changed {
@@ -1415,7 +1432,11 @@ impl Optimizer<'_> {
             | Expr::TsNonNull(_)
             | Expr::TsAs(_)
             | Expr::TsInstantiation(_)
-            | Expr::TsSatisfies(_) => false,
+            | Expr::TsSatisfies(_)
+            | Expr::Pipeline(_)
+            | Expr::Topic(_)
+            | Expr::Match(_)
+            | Expr::Do(_) => false,
         }
     }
 
@@ -2670,6 +2691,20 @@ impl Mergable<'_> {
 struct MergeSequenceCache {
     ident_usage_cache: Vec<Option<FxHashSet<Id>>>,
     top_retain_cache: Vec<Option<bool>>,
+    /// Number of modifications, used as a timestamp.
+    modifications: usize,
+    modified_at: Vec<usize>,
+    /// The last scan of each expression.
+    scans: Vec<Option<MergeScan>>,
+}
+
+/// Expressions compared with an expression while merging it.
+#[derive(Debug, Clone, Copy)]
+struct MergeScan {
+    /// Value of [MergeSequenceCache::modifications] when the scan started.
+    started_at: usize,
+    /// Index of the last compared expression.
+    end: usize,
 }
 
 impl MergeSequenceCache {
@@ -2677,9 +2712,35 @@ impl MergeSequenceCache {
         Self {
             ident_usage_cache: vec![None; cap],
             top_retain_cache: vec![None; cap],
+            modifications: 0,
+            modified_at: vec![0; cap],
+            scans: vec![None; cap],
         }
     }
 
+    /// Returns `false` if none of the expressions compared with `node_id` in
+    /// the previous scan were modified since, so the scan would have no
+    /// effect.
+    fn needs_scan(&self, node_id: usize) -> bool {
+        match self.scans[node_id] {
+            Some(scan) => self.modified_at[node_id..=scan.end]
+                .iter()
+                .any(|&modified_at| modified_at > scan.started_at),
+            None => true,
+        }
+    }
+
+    fn start_scan(&self, node_id: usize) -> MergeScan {
+        MergeScan {
+            started_at: self.modifications,
+            end: node_id,
+        }
+    }
+
+    fn end_scan(&mut self, node_id: usize, scan: MergeScan) {
+        self.scans[node_id] = Some(scan);
+    }
+
     fn is_ident_used_by<N: VisitWith<IdentUsageCollector>>(
         &mut self,
         ident: &Id,
@@ -2692,6 +2753,8 @@ impl MergeSequenceCache {
 
     fn invalidate(&mut self, node_id: usize) {
         self.ident_usage_cache[node_id] = None;
+        self.modifications += 1;
+        self.modified_at[node_id] = self.modifications;
     }
 
     fn is_top_retain(&mut self, optimizer: &Optimizer, a: &Mergable, node_id: usize) -> bool {
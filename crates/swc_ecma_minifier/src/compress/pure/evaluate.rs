@@ -7,7 +7,7 @@ use super::Pure;
 #[cfg(feature = "debug")]
 use crate::debug::dump;
 use crate::{
-    compress::util::{eval_as_number, is_pure_undefined_or_null},
+    compress::util::{eval_as_number, is_js_whitespace, is_pure_undefined_or_null},
     util::ValueExt,
 };
 
@@ -911,6 +911,132 @@ impl Pure<'_> {
                 }
                 return;
             }
+            "trim" if call.args.is_empty() => s.value.trim_matches(is_js_whitespace).to_string(),
+            "trimStart" if call.args.is_empty() => {
+                s.value.trim_start_matches(is_js_whitespace).to_string()
+            }
+            "trimEnd" if call.args.is_empty() => {
+                s.value.trim_end_matches(is_js_whitespace).to_string()
+            }
+            "concat" => {
+                let mut value = s.value.to_string();
+                for arg in &call.args {
+                    match (&arg.spread, &*arg.expr) {
+                        (None, Expr::Lit(Lit::Str(arg))) => value.push_str(&arg.value),
+                        _ => return,
+                    }
+                }
+                value
+            }
+            "repeat" => {
+                let count = match self.eval_str_method_int_args::<1>(&call.args) {
+                    Some([Some(count)]) if (0.0..=f64::from(u16::MAX)).contains(&count) => {
+                        count as usize
+                    }
+                    _ => return,
+                };
+                // Don't make the code larger.
+                if s.value.len() * count > s.value.len() + "repeat()".len() {
+                    return;
+                }
+                s.value.repeat(count)
+            }
+            "charAt" | "at" | "slice" | "substring" => {
+                let units = s.value.encode_utf16().collect::<Vec<_>>();
+                let len = units.len() as f64;
+
+                let range = match (&*method, self.eval_str_method_int_args::<2>(&call.args)) {
+                    ("charAt", Some([idx, None])) => {
+                        let idx = idx.unwrap_or(0.0);
+                        if idx < 0.0 || idx >= len {
+                            0.0..0.0
+                        } else {
+                            idx..idx + 1.0
+                        }
+                    }
+                    ("at", Some([idx, None])) => {
+                        let idx = idx.unwrap_or(0.0);
+                        let idx = if idx < 0.0 { len + idx } else { idx };
+                        if idx < 0.0 || idx >= len {
+                            // `undefined`
+                            return;
+                        }
+                        idx..idx + 1.0
+                    }
+                    ("slice", Some([start, end])) => {
+                        let relative = |v: f64| {
+                            if v < 0.0 {
+                                (len + v).max(0.0)
+                            } else {
+                                v.min(len)
+                            }
+                        };
+                        let start = relative(start.unwrap_or(0.0));
+                        let end = relative(end.unwrap_or(len));
+                        start..end.max(start)
+                    }
+                    ("substring", Some([start, end])) => {
+                        let start = start.unwrap_or(0.0).clamp(0.0, len);
+                        let end = end.unwrap_or(len).clamp(0.0, len);
+                        start.min(end)..start.max(end)
+                    }
+                    _ => return,
+                };
+
+                match String::from_utf16(&units[range.start as usize..range.end as usize]) {
+                    Ok(value) => value,
+                    // Lone surrogates can't be represented by a string literal.
+                    Err(..) => return,
+                }
+            }
+            "indexOf" | "lastIndexOf" | "includes" | "startsWith" | "endsWith" => {
+                let search = match &*call.args {
+                    [ExprOrSpread { spread: None, expr }] => match &**expr {
+                        Expr::Lit(Lit::Str(search)) => search.value.clone(),
+                        _ => return,
+                    },
+                    _ => return,
+                };
+
+                let value = match &*method {
+                    "indexOf" => s.value.find(&*search),
+                    "lastIndexOf" => s.value.rfind(&*search),
+                    _ => {
+                        let value = match &*method {
+                            "includes" => s.value.contains(&*search),
+                            "startsWith" => s.value.starts_with(&*search),
+                            _ => s.value.ends_with(&*search),
+                        };
+
+                        self.changed = true;
+                        report_change!(
+                            "evaluate: Evaluated `{method}` of a string literal as `{value}`"
+                        );
+                        *e = Lit::Bool(Bool {
+                            span: call.span,
+                            value,
+                        })
+                        .into();
+                        return;
+                    }
+                };
+
+                // Indices are counted in UTF-16 code units.
+                let value = match value {
+                    Some(idx) => s.value[..idx].encode_utf16().count() as f64,
+                    None => -1.0,
+                };
+
+                self.changed = true;
+                report_change!("evaluate: Evaluated `{method}` of a string literal as `{value}`");
+                *e = Lit::Num(Number {
+                    span: call.span,
+                    value,
+                    raw: None,
+                })
+                .into();
+                return;
+            }
             _ => return,
         };
 
@@ -925,6 +1051,35 @@ impl Pure<'_> {
     }
 }
 
+impl Pure<'_> {
+    /// Evaluates integer arguments of a string method, which are `None` if
+    /// omitted.
+    fn eval_str_method_int_args<const N: usize>(
+        &self,
+        args: &[ExprOrSpread],
+    ) -> Option<[Option<f64>; N]> {
+        if args.len() > N {
+            return None;
+        }
+
+        let mut values = [None; N];
+        for (value, arg) in values.iter_mut().zip(args) {
+            if arg.spread.is_some() {
+                return None;
+            }
+
+            // https://tc39.es/ecma262/#sec-tointegerorinfinity
+            let v = match arg.expr.as_pure_number(self.expr_ctx) {
+                Value::Known(v) => v,
+                Value::Unknown => return None,
+            };
+            *value = Some(if v.is_nan() { 0.0 } else { v.trunc() });
+        }
+
+        Some(values)
+    }
+}
+
 // Code from boa
 // https://github.com/boa-dev/boa/blob/f8b682085d7fe0bbfcd0333038e93cf2f5aee710/boa_engine/src/builtins/number/mod.rs#L408
 fn f64_to_precision(value: f64, precision: usize) -> String {
@@ -501,6 +501,67 @@ pub(crate) fn eval_as_number(expr_ctx: ExprCtx, e: &Expr) -> Option<f64> {
                             return Some(base.pow(exponent).into());
                         }
 
+                        "abs" | "ceil" | "floor" | "round" | "trunc" | "sign" | "sqrt"
+                        | "fround" | "clz32" => {
+                            let v = eval_as_number(expr_ctx, &args.first()?.expr)?;
+
+                            let value = match &*prop.sym {
+                                "abs" => v.abs(),
+                                "ceil" => v.ceil(),
+                                "floor" => v.floor(),
+                                "round" => {
+                                    // Halves are rounded up, unlike `f64::round`.
+                                    let floor = v.floor();
+                                    if v - floor >= 0.5 {
+                                        floor + 1.0
+                                    } else {
+                                        floor
+                                    }
+                                }
+                                "trunc" => v.trunc(),
+                                "sign" => {
+                                    if v.is_nan() || v == 0.0 {
+                                        v
+                                    } else {
+                                        v.signum()
+                                    }
+                                }
+                                "sqrt" => {
+                                    let root = v.sqrt();
+                                    // Irrational roots would make the code larger.
+                                    if root * root != v {
+                                        return None;
+                                    }
+                                    root
+                                }
+                                "fround" => v as f32 as f64,
+                                "clz32" => {
+                                    let v = *JsNumber::from(v).unsigned_shr(0.0.into());
+                                    (v as u32).leading_zeros() as f64
+                                }
+                                _ => unreachable!(),
+                            };
+
+                            // `-0` can't be represented by a numeric literal.
+                            if value == 0.0 && (value.is_sign_negative() || v < 0.0) {
+                                return None;
+                            }
+
+                            return Some(value);
+                        }
+
+                        "imul" => {
+                            if args.len() != 2 {
+                                return None;
+                            }
+                            let a = *(JsNumber::from(eval_as_number(expr_ctx, &args[0].expr)?)
+                                | 0.0.into());
+                            let b = *(JsNumber::from(eval_as_number(expr_ctx, &args[1].expr)?)
+                                | 0.0.into());
+
+                            return Some((a as i32).wrapping_mul(b as i32) as f64);
+                        }
+
                         _ => {}
                     },
                     _ => {}
@@ -517,6 +578,17 @@ pub(crate) fn eval_as_number(expr_ctx: ExprCtx, e: &Expr) -> Option<f64> {
                 "PI" => return Some(f64::consts::PI),
                 "E" => return Some(f64::consts::E),
                 "LN10" => return Some(f64::consts::LN_10),
+                "LN2" => return Some(f64::consts::LN_2),
+                "LOG2E" => return Some(f64::consts::LOG2_E),
+                "LOG10E" => return Some(f64::consts::LOG10_E),
+                "SQRT2" => return Some(f64::consts::SQRT_2),
+                "SQRT1_2" => return Some(f64::consts::FRAC_1_SQRT_2),
+                _ => {}
+            },
+            Expr::Ident(obj) if &*obj.sym == "Number" => match &*prop.sym {
+                "MAX_SAFE_INTEGER" => return Some(9007199254740991.0),
+                "MIN_SAFE_INTEGER" => return Some(-9007199254740991.0),
+                "EPSILON" => return Some(f64::EPSILON),
                 _ => {}
             },
             _ => {}
@@ -532,6 +604,31 @@ pub(crate) fn eval_as_number(expr_ctx: ExprCtx, e: &Expr) -> Option<f64> {
     None
 }
 
+/// Returns `true` for characters removed by `String.prototype.trim`.
+///
+/// https://tc39.es/ecma262/#sec-white-space
+pub(crate) fn is_js_whitespace(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0009}'
+            | '\u{000A}'
+            | '\u{000B}'
+            | '\u{000C}'
+            | '\u{000D}'
+            | '\u{0020}'
+            | '\u{00A0}'
+            | '\u{1680}'
+            | '\u{2000}'
+            ..='\u{200A}'
+                | '\u{2028}'
+                | '\u{2029}'
+                | '\u{202F}'
+                | '\u{205F}'
+                | '\u{3000}'
+                | '\u{FEFF}'
+    )
+}
+
 pub(crate) fn is_ident_used_by<N>(id: Id, node: &N) -> bool
 where
     N: for<'aa> VisitWith<IdentUsageFinder<'aa>>,
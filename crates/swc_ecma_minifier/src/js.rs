@@ -76,6 +76,11 @@ pub struct TerserSourceMapOption {
 
     #[serde(default)]
     pub content: Option<SourceMapContent>,
+
+    /// Emits `originalScopes` and `generatedRanges` of the scopes proposal, so
+    /// debuggers can show original variables. Ignored if `content` is given.
+    #[serde(default)]
+    pub scopes: bool,
 }
 
 /// Parser options for `minify()`, which should have the same API as terser.
@@ -40,7 +40,7 @@
 
 use once_cell::sync::Lazy;
 use pass::mangle_names::mangle_names;
-use swc_common::{comments::Comments, pass::Repeated, sync::Lrc, SourceMap, SyntaxContext};
+use swc_common::{cancel, comments::Comments, pass::Repeated, sync::Lrc, SourceMap, SyntaxContext};
 use swc_ecma_ast::*;
 use swc_ecma_transforms_optimization::debug_assert_valid;
 use swc_ecma_usage_analyzer::marks::Marks;
@@ -48,7 +48,6 @@ use swc_ecma_utils::ExprCtx;
 use swc_ecma_visit::VisitMutWith;
 use swc_timer::timer;
 
-pub use crate::pass::global_defs::globals_defs;
 use crate::{
     compress::{compressor, pure_optimizer, PureOptimizerConfig},
     metadata::info_marker,
@@ -63,6 +62,10 @@ use crate::{
     timing::Timings,
     util::base54::CharFreq,
 };
+pub use crate::{
+    pass::global_defs::globals_defs,
+    snippet::{minify_expr, minify_snippet},
+};
 
 #[macro_use]
 mod macros;
@@ -77,6 +80,7 @@ pub mod option;
 mod pass;
 mod program_data;
 mod size_hint;
+mod snippet;
 pub mod timing;
 mod util;
 
@@ -90,6 +94,8 @@ pub(crate) static CPU_COUNT: Lazy<usize> = Lazy::new(num_cpus::get);
 pub(crate) static HEAVY_TASK_PARALLELS: Lazy<usize> = Lazy::new(|| *CPU_COUNT * 8);
 pub(crate) static LIGHT_TASK_PARALLELS: Lazy<usize> = Lazy::new(|| *CPU_COUNT * 100);
 
+/// If [swc_common::cancel] reports cancellation, this returns early and the
+/// returned program is only partially optimized.
 pub fn optimize(
     mut n: Program,
     _cm: Lrc<SourceMap>,
@@ -182,6 +188,9 @@ pub fn optimize(
     if let Some(ref mut t) = timings {
         t.section("compress");
     }
+    if cancel::is_cancelled() {
+        return n;
+    }
     if let Some(c) = &options.compress {
         {
             let _timer = timer!("compress ast");
@@ -221,6 +230,9 @@ pub fn optimize(
         t.section("mangle");
     }
 
+    if cancel::is_cancelled() {
+        return n;
+    }
     if let Some(mangle) = &options.mangle {
         let _timer = timer!("mangle names");
         // TODO: base54.reset();
@@ -247,8 +259,14 @@ pub fn optimize(
             extra.mangle_name_cache.clone(),
         );
 
-        if let Some(property_mangle_options) = &mangle.props {
-            mangle_properties(&mut n, property_mangle_options.clone(), chars);
+        if mangle.props.is_some() || mangle.class_props.is_some() {
+            mangle_properties(
+                &mut n,
+                mangle.props.clone(),
+                mangle.class_props.clone(),
+                chars,
+                extra.mangle_name_cache.clone(),
+            );
         }
     }
 
@@ -276,6 +294,10 @@ fn perform_dce(m: &mut Program, options: &CompressOptions, extra: &ExtraOptions)
     );
 
     loop {
+        if cancel::is_cancelled() {
+            break;
+        }
+
         #[cfg(feature = "debug")]
         let start = crate::debug::dump(&*m, false);
 
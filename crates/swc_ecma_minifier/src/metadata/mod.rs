@@ -239,9 +239,36 @@ impl Visit for InfoCollector<'_> {
     fn visit_export_decl(&mut self, f: &ExportDecl) {
         f.visit_children_with(self);
 
-        if let Decl::Fn(f) = &f.decl {
-            if has_flag(self.comments, f.function.span, NO_SIDE_EFFECTS_FLAG) {
-                self.pure_callees.insert(f.ident.to_id());
+        // `/*#__NO_SIDE_EFFECTS__*/ export function foo() {}`
+        let is_annotated = has_flag(self.comments, f.span, NO_SIDE_EFFECTS_FLAG);
+
+        match &f.decl {
+            Decl::Fn(f) => {
+                if is_annotated || has_flag(self.comments, f.function.span, NO_SIDE_EFFECTS_FLAG) {
+                    self.pure_callees.insert(f.ident.to_id());
+                }
+            }
+            Decl::Var(v) if is_annotated => {
+                for v in &v.decls {
+                    if let (Pat::Ident(ident), Some(..)) = (&v.name, &v.init) {
+                        self.pure_callees.insert(ident.to_id());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_export_default_decl(&mut self, f: &ExportDefaultDecl) {
+        f.visit_children_with(self);
+
+        // `/*#__NO_SIDE_EFFECTS__*/ export default function foo() {}`
+        if let DefaultDecl::Fn(FnExpr {
+            ident: Some(ident), ..
+        }) = &f.decl
+        {
+            if has_flag(self.comments, f.span, NO_SIDE_EFFECTS_FLAG) {
+                self.pure_callees.insert(ident.to_id());
             }
         }
     }
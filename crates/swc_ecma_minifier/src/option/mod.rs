@@ -66,6 +66,11 @@ pub struct MangleOptions {
     #[serde(default, alias = "properties")]
     pub props: Option<ManglePropertiesOptions>,
 
+    /// Mangle names of class fields and methods, without mangling the other
+    /// properties.
+    #[serde(default, alias = "class_props")]
+    pub class_props: Option<MangleClassPropsOptions>,
+
     #[serde(default, alias = "toplevel")]
     pub top_level: Option<bool>,
 
@@ -108,6 +113,15 @@ pub struct ManglePropertiesOptions {
     pub regex: Option<CachedRegex>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MangleClassPropsOptions {
+    /// Class members and `#private` names matching this regex are not
+    /// mangled.
+    #[serde(default)]
+    pub keep: Option<CachedRegex>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 #[serde(untagged)]
@@ -257,6 +271,16 @@ pub struct CompressOptions {
     #[cfg_attr(feature = "extra-serde", serde(alias = "inline"))]
     pub inline: u8,
 
+    /// Number of bytes the output may grow by when inlining a function,
+    /// which is used more than once, into all of its call sites.
+    ///
+    /// Such functions are inlined only if their body is a single expression
+    /// and doesn't use `this` or `arguments`. If not set, only functions
+    /// small enough to be inlined without a cost are inlined.
+    #[cfg_attr(feature = "extra-serde", serde(default))]
+    #[cfg_attr(feature = "extra-serde", serde(alias = "inline_fns_budget"))]
+    pub inline_fns_budget: Option<usize>,
+
     #[cfg_attr(feature = "extra-serde", serde(default = "true_by_default"))]
     #[cfg_attr(feature = "extra-serde", serde(alias = "join_vars"))]
     pub join_vars: bool,
@@ -317,6 +341,15 @@ pub struct CompressOptions {
     #[cfg_attr(feature = "extra-serde", serde(alias = "sequences"))]
     pub sequences: u8,
 
+    /// Maximum number of following expressions an expression is compared
+    /// with when merging sequential expressions.
+    ///
+    /// This bounds the time spent on very long sequences, like the ones of
+    /// generated code, at the cost of some merges. Unlimited if not set.
+    #[cfg_attr(feature = "extra-serde", serde(default))]
+    #[cfg_attr(feature = "extra-serde", serde(alias = "sequences_lookahead"))]
+    pub sequences_lookahead: Option<usize>,
+
     #[cfg_attr(feature = "extra-serde", serde(default = "true_by_default"))]
     #[cfg_attr(feature = "extra-serde", serde(alias = "side_effects"))]
     pub side_effects: bool,
@@ -444,6 +477,7 @@ impl Default for CompressOptions {
             ie8: false,
             if_return: true,
             inline: 3,
+            inline_fns_budget: None,
             join_vars: true,
             keep_classnames: false,
             keep_fargs: true,
@@ -459,6 +493,7 @@ impl Default for CompressOptions {
             reduce_fns: true,
             reduce_vars: false,
             sequences: 3,
+            sequences_lookahead: None,
             side_effects: true,
             switches: true,
             top_retain: Default::default(),
@@ -151,6 +151,9 @@ pub struct TerserCompressorOptions {
     #[serde(default)]
     pub inline: Option<TerserInlineOption>,
 
+    #[serde(default)]
+    pub inline_fns_budget: Option<usize>,
+
     #[serde(default)]
     pub join_vars: Option<bool>,
 
@@ -193,6 +196,9 @@ pub struct TerserCompressorOptions {
     #[serde(default)]
     pub sequences: Option<TerserSequenceOptions>,
 
+    #[serde(default)]
+    pub sequences_lookahead: Option<usize>,
+
     #[serde(default)]
     pub side_effects: Option<bool>,
 
@@ -341,6 +347,7 @@ impl TerserCompressorOptions {
                     TerserInlineOption::Num(n) => n,
                 })
                 .unwrap_or(if self.defaults { 3 } else { 0 }),
+            inline_fns_budget: self.inline_fns_budget,
             join_vars: self.join_vars.unwrap_or(self.defaults),
             keep_classnames: self.keep_classnames,
             keep_fargs: self.keep_fargs,
@@ -373,6 +380,7 @@ impl TerserCompressorOptions {
                     TerserSequenceOptions::Num(v) => v,
                 })
                 .unwrap_or(if self.defaults { 3 } else { 0 }),
+            sequences_lookahead: self.sequences_lookahead,
             side_effects: self.side_effects.unwrap_or(self.defaults),
             switches: self.switches.unwrap_or(self.defaults),
             top_retain: self.top_retain.map(From::from).unwrap_or_default(),
@@ -32,6 +32,10 @@ pub(crate) fn mangle_names(
 
     program.visit_mut_with(&mut self::private_name::private_name_mangler(
         options.keep_private_props,
+        options
+            .class_props
+            .as_ref()
+            .and_then(|class_props| class_props.keep.clone()),
         chars,
     ));
 
@@ -49,6 +53,7 @@ pub(crate) fn mangle_names(
             top_level_mark,
             ignore_eval: options.eval,
             preserved_symbols: options.reserved.iter().cloned().collect(),
+            ..Default::default()
         },
         ManglingRenamer {
             chars,
@@ -1,13 +1,19 @@
 use rustc_hash::FxHashMap;
 use swc_atoms::Atom;
+use swc_config::CachedRegex;
 use swc_ecma_ast::*;
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use super::Base54Chars;
 
-pub(crate) fn private_name_mangler(keep_private_props: bool, chars: Base54Chars) -> impl VisitMut {
+pub(crate) fn private_name_mangler(
+    keep_private_props: bool,
+    keep: Option<CachedRegex>,
+    chars: Base54Chars,
+) -> impl VisitMut {
     PrivateNameMangler {
         keep_private_props,
+        keep,
         private_n: Default::default(),
         renamed_private: Default::default(),
         chars,
@@ -17,6 +23,8 @@ pub(crate) fn private_name_mangler(keep_private_props: bool, chars: Base54Chars)
 struct PrivateNameMangler {
     chars: Base54Chars,
     keep_private_props: bool,
+    /// Private names matching this regex are not mangled.
+    keep: Option<CachedRegex>,
     private_n: usize,
 
     renamed_private: FxHashMap<Atom, Atom>,
@@ -53,7 +61,15 @@ impl VisitMut for PrivateNameMangler {
     }
 
     fn visit_mut_private_name(&mut self, private_name: &mut PrivateName) {
-        if !self.keep_private_props {
+        if self.keep_private_props {
+            return;
+        }
+
+        if self
+            .keep
+            .as_ref()
+            .map_or(true, |keep| !keep.is_match(&private_name.name))
+        {
             self.rename_private(private_name);
         }
     }
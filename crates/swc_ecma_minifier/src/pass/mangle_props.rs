@@ -1,16 +1,17 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, sync::Arc};
 
 use once_cell::sync::Lazy;
 use rustc_hash::{FxHashMap, FxHashSet};
 use swc_atoms::Atom;
+use swc_config::CachedRegex;
 use swc_ecma_ast::{
-    CallExpr, Callee, Expr, IdentName, KeyValueProp, Lit, MemberExpr, MemberProp, Program, Prop,
-    PropName, Str, SuperProp, SuperPropExpr,
+    BinExpr, BinaryOp, CallExpr, Callee, ClassMember, Expr, IdentName, KeyValueProp, Lit,
+    MemberExpr, MemberProp, ObjectPatProp, Program, Prop, PropName, Str, SuperProp, SuperPropExpr,
 };
 use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
 
 use crate::{
-    option::ManglePropertiesOptions,
+    option::{MangleCache, MangleClassPropsOptions, ManglePropertiesOptions},
     program_data::{analyze, ProgramData},
     util::base54::Base54Chars,
 };
@@ -34,12 +35,18 @@ pub static JS_ENVIRONMENT_PROPS: Lazy<FxHashSet<Atom>> = Lazy::new(|| {
 struct ManglePropertiesState {
     chars: Base54Chars,
     options: ManglePropertiesOptions,
+    /// Only names of class members are collected.
+    class_members_only: bool,
+    /// Names matching this regex are not mangled.
+    keep: Option<CachedRegex>,
 
     names_to_mangle: FxHashSet<Atom>,
     unmangleable: FxHashSet<Atom>,
 
     // Cache of already mangled names
     cache: FxHashMap<Atom, Atom>,
+    // Mangled names in `cache`
+    used_names: FxHashSet<Atom>,
 
     // Numbers to pass to base54()
     n: usize,
@@ -56,6 +63,12 @@ impl ManglePropertiesState {
         }
     }
 
+    /// Marks a name as used in a way the mangler can't rewrite.
+    fn block(&mut self, name: &Atom) {
+        self.names_to_mangle.remove(name);
+        self.unmangleable.insert(name.clone());
+    }
+
     fn can_mangle(&self, name: &Atom) -> bool {
         !(self.unmangleable.contains(name) || self.is_reserved(name))
     }
@@ -77,7 +90,9 @@ impl ManglePropertiesState {
     }
 
     fn is_reserved(&self, name: &Atom) -> bool {
-        JS_ENVIRONMENT_PROPS.contains(name) || self.options.reserved.contains(name)
+        JS_ENVIRONMENT_PROPS.contains(name)
+            || self.options.reserved.contains(name)
+            || self.keep.as_ref().is_some_and(|keep| keep.is_match(name))
     }
 
     fn gen_name(&mut self, name: &Atom) -> Option<Atom> {
@@ -85,8 +100,16 @@ impl ManglePropertiesState {
             if let Some(cached) = self.cache.get(name) {
                 Some(cached.clone())
             } else {
-                let mangled_name = self.chars.encode(&mut self.n, true);
+                let mangled_name = loop {
+                    let mangled_name = self.chars.encode(&mut self.n, true);
+
+                    // Names from a previous run may be taken.
+                    if !self.used_names.contains(&mangled_name) {
+                        break mangled_name;
+                    }
+                };
 
+                self.used_names.insert(mangled_name.clone());
                 self.cache.insert(name.clone(), mangled_name.clone());
                 Some(mangled_name)
             }
@@ -98,18 +121,33 @@ impl ManglePropertiesState {
 
 pub(crate) fn mangle_properties(
     m: &mut Program,
-    options: ManglePropertiesOptions,
+    options: Option<ManglePropertiesOptions>,
+    class_options: Option<MangleClassPropsOptions>,
     chars: Base54Chars,
+    mangle_name_cache: Option<Arc<dyn MangleCache>>,
 ) {
     let mut state = ManglePropertiesState {
-        options,
+        class_members_only: options.is_none(),
+        options: options.unwrap_or_default(),
+        keep: class_options.and_then(|class_options| class_options.keep),
         chars,
         names_to_mangle: Default::default(),
         unmangleable: Default::default(),
         cache: Default::default(),
+        used_names: Default::default(),
         n: 0,
     };
 
+    // Reuse names of previous runs, so properties shared between them match.
+    if let Some(mangle_cache) = &mangle_name_cache {
+        mangle_cache.props_cache(&mut |v| {
+            state
+                .cache
+                .extend(v.iter().map(|(k, v)| (k.clone(), v.clone())));
+        });
+        state.used_names = state.cache.values().cloned().collect();
+    }
+
     let data = analyze(&*m, None);
     m.visit_mut_with(&mut PropertyCollector {
         state: &mut state,
@@ -117,6 +155,10 @@ pub(crate) fn mangle_properties(
     });
 
     m.visit_mut_with(&mut Mangler { state: &mut state });
+
+    if let Some(mangle_cache) = &mangle_name_cache {
+        mangle_cache.update_props_cache(&state.cache);
+    }
 }
 
 // Step 1 -- collect candidates to mangle
@@ -125,18 +167,77 @@ pub struct PropertyCollector<'a> {
     state: &'a mut ManglePropertiesState,
 }
 
+impl PropertyCollector<'_> {
+    fn add_prop_name(&mut self, name: &PropName) {
+        match name {
+            PropName::Ident(ident) => {
+                self.state.add(&ident.sym);
+            }
+            PropName::Str(s) => {
+                self.state.add(&s.value);
+            }
+            _ => {}
+        };
+    }
+}
+
 impl VisitMut for PropertyCollector<'_> {
+    fn visit_mut_bin_expr(&mut self, e: &mut BinExpr) {
+        e.visit_mut_children_with(self);
+
+        if self.state.class_members_only && e.op == BinaryOp::In {
+            if let Expr::Lit(Lit::Str(s)) = &*e.left {
+                self.state.block(&s.value);
+            }
+        }
+    }
+
     fn visit_mut_call_expr(&mut self, call: &mut CallExpr) {
         call.visit_mut_children_with(self);
 
+        if self.state.class_members_only {
+            return;
+        }
+
         if let Some(prop_name) = get_object_define_property_name_arg(call) {
             self.state.add(&prop_name.value);
         }
     }
 
+    fn visit_mut_class_member(&mut self, member: &mut ClassMember) {
+        member.visit_mut_children_with(self);
+
+        if !self.state.class_members_only {
+            return;
+        }
+
+        let key = match member {
+            ClassMember::Method(m) => &m.key,
+            ClassMember::ClassProp(p) if !p.declare => &p.key,
+            _ => return,
+        };
+
+        if let PropName::Ident(IdentName { sym, .. }) = key {
+            if *sym == *"constructor" {
+                return;
+            }
+        }
+
+        self.add_prop_name(key);
+    }
+
     fn visit_mut_member_expr(&mut self, member_expr: &mut MemberExpr) {
         member_expr.visit_mut_children_with(self);
 
+        if self.state.class_members_only {
+            if let MemberProp::Computed(c) = &member_expr.prop {
+                if let Expr::Lit(Lit::Str(s)) = &*c.expr {
+                    self.state.block(&s.value);
+                }
+            }
+            return;
+        }
+
         let is_root_declared = is_root_of_member_expr_declared(member_expr, &self.data);
 
         if is_root_declared {
@@ -146,9 +247,24 @@ impl VisitMut for PropertyCollector<'_> {
         }
     }
 
+    fn visit_mut_object_pat_prop(&mut self, prop: &mut ObjectPatProp) {
+        prop.visit_mut_children_with(self);
+
+        // The mangler doesn't rewrite shorthand patterns like `{ x } = this`.
+        if self.state.class_members_only {
+            if let ObjectPatProp::Assign(p) = prop {
+                self.state.block(&p.key.sym);
+            }
+        }
+    }
+
     fn visit_mut_prop(&mut self, prop: &mut Prop) {
         prop.visit_mut_children_with(self);
 
+        if self.state.class_members_only {
+            return;
+        }
+
         if let Prop::Shorthand(ident) = prop {
             self.state.add(&ident.sym);
         }
@@ -157,15 +273,21 @@ impl VisitMut for PropertyCollector<'_> {
     fn visit_mut_prop_name(&mut self, name: &mut PropName) {
         name.visit_mut_children_with(self);
 
-        match name {
-            PropName::Ident(ident) => {
-                self.state.add(&ident.sym);
-            }
-            PropName::Str(s) => {
-                self.state.add(&s.value);
+        if !self.state.class_members_only {
+            self.add_prop_name(name);
+        }
+    }
+
+    fn visit_mut_super_prop_expr(&mut self, super_expr: &mut SuperPropExpr) {
+        super_expr.visit_mut_children_with(self);
+
+        if self.state.class_members_only {
+            if let SuperProp::Computed(c) = &super_expr.prop {
+                if let Expr::Lit(Lit::Str(s)) = &*c.expr {
+                    self.state.block(&s.value);
+                }
             }
-            _ => {}
-        };
+        }
     }
 }
 
@@ -0,0 +1,295 @@
+//! Entry points for minifying code which is not a whole program, like an
+//! expression of a template or the body of an inline event handler.
+//!
+//! Unlike [crate::optimize], these never assume that top-level bindings are
+//! private to the input, so they are neither dropped nor mangled.
+
+use swc_atoms::atom;
+use swc_common::{
+    comments::{Comments, SingleThreadedComments},
+    sync::Lrc,
+    FileName, Globals, Mark, SourceMap, SyntaxContext, DUMMY_SP, GLOBALS,
+};
+use swc_ecma_ast::*;
+use swc_ecma_codegen::{
+    text_writer::{omit_trailing_semi, JsWriter, WriteJs},
+    Emitter,
+};
+use swc_ecma_parser::{error::Error, parse_file_as_script, EsSyntax, Syntax};
+use swc_ecma_transforms_base::{fixer::fixer, hygiene::hygiene, resolver};
+use swc_ecma_utils::ExprFactory;
+use swc_ecma_visit::VisitMutWith;
+
+use crate::{
+    optimize,
+    option::{ExtraOptions, MinifyOptions},
+};
+
+/// Name of the global the expression passed to [minify_expr] is assigned to,
+/// so the compressor can't drop it.
+const SENTINEL: &str = "__swc_minify_expr__";
+
+/// Minifies a single expression.
+///
+/// `expr` should not be resolved yet. All identifiers it references are
+/// treated as globals.
+///
+/// This requires [swc_common::GLOBALS] to be configured.
+pub fn minify_expr(expr: Box<Expr>, options: &MinifyOptions) -> Box<Expr> {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    let sentinel = Ident::new(
+        atom!("__swc_minify_expr__"),
+        DUMMY_SP,
+        SyntaxContext::empty().apply_mark(unresolved_mark),
+    );
+    let script = Script {
+        span: DUMMY_SP,
+        body: vec![AssignExpr {
+            span: DUMMY_SP,
+            op: op!("="),
+            left: sentinel.into(),
+            right: expr.clone(),
+        }
+        .into_stmt()],
+        shebang: None,
+    };
+
+    let program = minify_program(
+        script.into(),
+        None,
+        options,
+        unresolved_mark,
+        top_level_mark,
+    );
+
+    match program {
+        Program::Script(script) => extract_expr(script, unresolved_mark).unwrap_or(expr),
+        Program::Module(..) => unreachable!(),
+    }
+}
+
+/// Minifies a script, which may contain `return` statements outside of a
+/// function, like the body of an event handler attribute.
+pub fn minify_snippet(src: &str, options: &MinifyOptions) -> Result<String, Error> {
+    GLOBALS.set(&Globals::new(), || {
+        let cm: Lrc<SourceMap> = Default::default();
+        let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+        let comments = SingleThreadedComments::default();
+
+        let mut errors = Vec::new();
+        let script = parse_file_as_script(
+            &fm,
+            Syntax::Es(EsSyntax {
+                allow_return_outside_function: true,
+                ..Default::default()
+            }),
+            Default::default(),
+            Some(&comments),
+            &mut errors,
+        )?;
+        if let Some(err) = errors.into_iter().next() {
+            return Err(err);
+        }
+
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        let program = minify_program(
+            script.into(),
+            Some(&comments),
+            options,
+            unresolved_mark,
+            top_level_mark,
+        );
+
+        let mut buf = Vec::new();
+        {
+            let wr = Box::new(omit_trailing_semi(JsWriter::new(
+                cm.clone(),
+                "\n",
+                &mut buf,
+                None,
+            ))) as Box<dyn WriteJs>;
+
+            let mut emitter = Emitter {
+                cfg: swc_ecma_codegen::Config::default()
+                    .with_minify(true)
+                    .with_omit_last_semi(true),
+                cm,
+                comments: None,
+                wr,
+            };
+
+            emitter.emit_program(&program).unwrap();
+        }
+
+        Ok(String::from_utf8(buf).expect("codegen should emit valid utf8"))
+    })
+}
+
+fn minify_program(
+    mut program: Program,
+    comments: Option<&dyn Comments>,
+    options: &MinifyOptions,
+    unresolved_mark: Mark,
+    top_level_mark: Mark,
+) -> Program {
+    let mut options = options.clone();
+    if let Some(compress) = &mut options.compress {
+        compress.top_level = None;
+        compress.module = false;
+    }
+    if let Some(mangle) = &mut options.mangle {
+        mangle.top_level = Some(false);
+    }
+
+    program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+
+    let mut program = optimize(
+        program,
+        Default::default(),
+        comments,
+        None,
+        &options,
+        &ExtraOptions {
+            unresolved_mark,
+            top_level_mark,
+            mangle_name_cache: None,
+        },
+    );
+
+    if options.mangle.is_none() {
+        program.visit_mut_with(&mut hygiene());
+    }
+    program.visit_mut_with(&mut fixer(comments));
+
+    program
+}
+
+/// Extracts the expression assigned to [SENTINEL], keeping side effects the
+/// compressor moved out of the assignment.
+///
+/// Returns [None] if the compressor changed the script in an unexpected way.
+fn extract_expr(script: Script, unresolved_mark: Mark) -> Option<Box<Expr>> {
+    let mut exprs = Vec::new();
+    for stmt in script.body {
+        match stmt {
+            Stmt::Expr(ExprStmt { expr, .. }) => match *expr {
+                Expr::Seq(seq) => exprs.extend(seq.exprs),
+                _ => exprs.push(expr),
+            },
+            _ => return None,
+        }
+    }
+
+    let last = exprs.pop()?;
+    let value = match *last {
+        Expr::Assign(AssignExpr {
+            op: op!("="),
+            left: AssignTarget::Simple(SimpleAssignTarget::Ident(left)),
+            right,
+            ..
+        }) if left.sym == SENTINEL && left.ctxt.outer() == unresolved_mark => right,
+        _ => return None,
+    };
+    // The fixer wraps sequences assigned to the sentinel.
+    let value = match *value {
+        Expr::Paren(ParenExpr { expr, .. }) => expr,
+        _ => value,
+    };
+
+    if exprs.is_empty() {
+        return Some(value);
+    }
+
+    exprs.push(value);
+    Some(
+        SeqExpr {
+            span: DUMMY_SP,
+            exprs,
+        }
+        .into(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::GLOBALS;
+    use swc_ecma_codegen::Node;
+    use swc_ecma_parser::parse_file_as_expr;
+
+    use super::*;
+    use crate::option::{CompressOptions, MangleOptions};
+
+    fn options() -> MinifyOptions {
+        MinifyOptions {
+            compress: Some(CompressOptions {
+                unused: true,
+                ..Default::default()
+            }),
+            mangle: Some(MangleOptions::default()),
+            ..Default::default()
+        }
+    }
+
+    fn print_expr(expr: &Expr) -> String {
+        let cm: Lrc<SourceMap> = Default::default();
+        let mut buf = Vec::new();
+        {
+            let mut emitter = Emitter {
+                cfg: swc_ecma_codegen::Config::default().with_minify(true),
+                cm: cm.clone(),
+                comments: None,
+                wr: JsWriter::new(cm, "\n", &mut buf, None),
+            };
+            expr.emit_with(&mut emitter).unwrap();
+        }
+        String::from_utf8(buf).unwrap()
+    }
+
+    fn expr(src: &str) -> String {
+        GLOBALS.set(&Globals::new(), || {
+            let cm: Lrc<SourceMap> = Default::default();
+            let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+            let expr = parse_file_as_expr(
+                &fm,
+                Default::default(),
+                Default::default(),
+                None,
+                &mut Vec::new(),
+            )
+            .unwrap();
+
+            print_expr(&minify_expr(expr, &options()))
+        })
+    }
+
+    #[test]
+    fn expr_constants() {
+        assert_eq!(expr("1 + 2 + a"), "3+a");
+        assert_eq!(expr("!!(a && b) ? 'yes' : 'yes'"), "a&&b,\"yes\"");
+    }
+
+    #[test]
+    fn expr_function() {
+        assert_eq!(
+            expr("function (value) { var result = value * 2; return result; }"),
+            "function(_){return 2*_;}"
+        );
+    }
+
+    #[test]
+    fn snippet_return() {
+        assert_eq!(
+            minify_snippet("var count = 1 + 1; return handle(count);", &options()).unwrap(),
+            "var count=2;return handle(count)"
+        );
+    }
+
+    #[test]
+    fn snippet_error() {
+        assert!(minify_snippet("return (", &options()).is_err());
+    }
+}
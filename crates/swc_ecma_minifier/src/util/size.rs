@@ -186,6 +186,9 @@ impl SizeWithCtxt for Expr {
             },
 
             Expr::Paren(p) => 2 + p.expr.size(unresolved),
+            Expr::Pipeline(p) => p.left.size(unresolved) + 2 + p.right.size(unresolved),
+            Expr::Topic(_) => 1,
+            Expr::Match(_) | Expr::Do(_) => TODO,
             Expr::Invalid(_) => 0,
 
             Expr::JSXMember(_) => TODO,
@@ -3706,8 +3706,7 @@
 	 * 1) key is the dataType
 	 * 2) the catchall symbol "*" can be used
 	 * 3) selection will start with transport dataType and THEN go to "*" if needed
-	 */ transports = {}, // Avoid comment-prolog char sequence (#10098); must appease lint and evade compression
-    allTypes = "*/".concat("*"), // Anchor tag for parsing the document origin
+	 */ transports = {}, // Anchor tag for parsing the document origin
     originAnchor = document.createElement("a");
     // Base "constructor" for jQuery.ajaxPrefilter and jQuery.ajaxTransport
     function addToPrefiltersOrTransports(structure) {
@@ -3765,7 +3764,7 @@
 		traditional: false,
 		headers: {},
 		*/ accepts: {
-                "*": allTypes,
+                "*": "*/*",
                 text: "text/plain",
                 html: "text/html",
                 xml: "application/xml, text/xml",
@@ -3912,7 +3911,7 @@
             uncached = s.url.slice(cacheURL.length), s.data && (s.processData || "string" == typeof s.data) && (cacheURL += (rquery.test(cacheURL) ? "&" : "?") + s.data, // #9682: remove data so that it's not used in an eventual retry
             delete s.data), !1 === s.cache && (cacheURL = cacheURL.replace(rantiCache, "$1"), uncached = (rquery.test(cacheURL) ? "&" : "?") + "_=" + nonce.guid++ + uncached), // Put hash and anti-cache on the URL that will be requested (gh-1732)
             s.url = cacheURL + uncached), s.ifModified && (jQuery.lastModified[cacheURL] && jqXHR.setRequestHeader("If-Modified-Since", jQuery.lastModified[cacheURL]), jQuery.etag[cacheURL] && jqXHR.setRequestHeader("If-None-Match", jQuery.etag[cacheURL])), (s.data && s.hasContent && !1 !== s.contentType || options.contentType) && jqXHR.setRequestHeader("Content-Type", s.contentType), // Set the Accepts header for the server, depending on the dataType
-            jqXHR.setRequestHeader("Accept", s.dataTypes[0] && s.accepts[s.dataTypes[0]] ? s.accepts[s.dataTypes[0]] + ("*" !== s.dataTypes[0] ? ", " + allTypes + "; q=0.01" : "") : s.accepts["*"]), s.headers)jqXHR.setRequestHeader(i, s.headers[i]);
+            jqXHR.setRequestHeader("Accept", s.dataTypes[0] && s.accepts[s.dataTypes[0]] ? s.accepts[s.dataTypes[0]] + ("*" !== s.dataTypes[0] ? ", */*; q=0.01" : "") : s.accepts["*"]), s.headers)jqXHR.setRequestHeader(i, s.headers[i]);
             // Allow custom headers/mimetypes and early abort
             if (s.beforeSend && (!1 === s.beforeSend.call(callbackContext, jqXHR, s) || completed)) // Abort if not done already and return
             return jqXHR.abort();
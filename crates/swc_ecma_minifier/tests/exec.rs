@@ -11477,3 +11477,70 @@ fn issue_10133() {
     ",
     );
 }
+
+#[test]
+fn evaluate_well_known_global_methods() {
+    run_default_exec_test(
+        r#"
+    console.log(
+        "   foo ﻿".trim(),
+        " foo ".trimStart(),
+        " foo ".trimEnd(),
+        "foo".concat("bar", "baz"),
+        "ab".repeat(3),
+        "-".repeat(40),
+        "foo".charAt(1),
+        "foo".charAt(5),
+        "foo".at(-1),
+        "foo".at(5),
+        "foobar".slice(1, -1),
+        "foobar".slice(-3),
+        "foobar".substring(4, 1),
+        "💩!".slice(1),
+        "💩!".indexOf("!"),
+        "foobar".lastIndexOf("o"),
+        "foobar".includes("ob"),
+        "foobar".startsWith("bar"),
+        "foobar".endsWith("bar"),
+    );
+    console.log(
+        Math.abs(-2),
+        Math.ceil(1.2),
+        Math.floor(-1.2),
+        Math.round(2.5),
+        Math.round(-2.5),
+        Math.round(-0.2),
+        Math.trunc(-1.7),
+        Math.sign(-3),
+        Math.sqrt(16),
+        Math.fround(5.5),
+        Math.clz32(1),
+        Math.imul(3, 4),
+        Math.sign(-0),
+    );
+    console.log(
+        Number.isInteger(5),
+        Number.isInteger(5.5),
+        Number.isInteger("5"),
+        Number.isNaN(NaN),
+        Number.isFinite(1 / 0),
+        Number.isSafeInteger(2 ** 53),
+        Number.parseFloat(" 1.5e3px"),
+        Number.parseFloat("-.5"),
+        Number.parseFloat("px"),
+        Number.parseInt("42px"),
+        Number.parseInt("0x1f"),
+        Number.parseInt("z", 36),
+        Number.parseInt("-0"),
+    );
+    console.log(
+        Array.isArray([1, 2]),
+        Array.isArray({ length: 0 }),
+        Array.isArray("foo"),
+        JSON.parse('{"b":[1,-2.5,"x",null],"a":true}'),
+        JSON.parse('{"__proto__":null}'),
+        JSON.parse('"\\ud800"'),
+    );
+    "#,
+    );
+}
@@ -0,0 +1,9 @@
+console.log("foo", "foo ", " foo", "foobarbaz", "ababab", "-".repeat(40), "o", "", "o", "foo".at(5), "ooba", "bar", "oob", "💩!".slice(1), 2, 2, !0, !1, !0), console.log(2, 2, -2, 3, -2, Math.round(-0.2), -1, -1, 4, 5.5, 31, 12, Math.sign(-0)), console.log(!0, !1, !1, !0, Number.isFinite(1 / 0), !1, 1500, -0.5, NaN, 42, 31, 35, Number.parseInt("-0")), console.log(!0, !1, !1, {
+    b: [
+        1,
+        -2.5,
+        "x",
+        null
+    ],
+    a: !0
+}, JSON.parse('{"__proto__":null}'), JSON.parse('"\\ud800"'));
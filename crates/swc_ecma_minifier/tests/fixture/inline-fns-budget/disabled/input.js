@@ -0,0 +1,14 @@
+function area(width, height) {
+    return width * height + width * 2 + height * 2;
+}
+function self() {
+    return this && this.width * this.height * 2;
+}
+function count() {
+    return arguments.length * 2 + arguments.length * 3;
+}
+export function compute(a, b, c, d) {
+    console.log(area(a, b), area(c, d), area(a, d));
+    console.log(self(), self());
+    console.log(count(a, b), count(c));
+}
@@ -0,0 +1,12 @@
+function area(width, height) {
+    return width * height + 2 * width + 2 * height;
+}
+function self() {
+    return this && this.width * this.height * 2;
+}
+function count() {
+    return 2 * arguments.length + 3 * arguments.length;
+}
+export function compute(a, b, c, d) {
+    console.log(area(a, b), area(c, d), area(a, d)), console.log(self(), self()), console.log(count(a, b), count(c));
+}
@@ -0,0 +1,9 @@
+function self() {
+    return this && this.width * this.height * 2;
+}
+function count() {
+    return 2 * arguments.length + 3 * arguments.length;
+}
+export function compute(a, b, c, d) {
+    console.log(a * b + 2 * a + 2 * b, c * d + 2 * c + 2 * d, a * d + 2 * a + 2 * d), console.log(self(), self()), console.log(count(a, b), count(c));
+}
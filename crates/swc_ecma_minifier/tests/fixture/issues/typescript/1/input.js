@@ -1,4 +1,4 @@
-var ts;
+export var ts;
 (function (ts) {
     function transformModule(context) {
         function getTransformModuleDelegate(moduleKind) {
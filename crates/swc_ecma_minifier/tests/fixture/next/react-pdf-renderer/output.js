@@ -17657,7 +17657,7 @@
                     "use strict";
                     var n = r(720);
                     e.exports = function() {
-                        return Number.isNaN && Number.isNaN(NaN) && !Number.isNaN("a") ? Number.isNaN : n;
+                        return Number.isNaN && 1 ? Number.isNaN : n;
                     };
                 },
                 81: function(e, t, r) {
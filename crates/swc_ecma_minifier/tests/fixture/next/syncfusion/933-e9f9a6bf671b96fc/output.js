@@ -10161,7 +10161,7 @@
                 }
             });
             // EXTERNAL MODULE: ./node_modules/react/index.js
-            var extendStatics, extendStatics1, extendStatics2, extendStatics3, extendStatics4, extendStatics5, extendStatics6, extendStatics7, extendStatics8, extendStatics9, extendStatics10, extendStatics11, extendStatics12, extendStatics13, targetElement, selectedHandler, minHeight, maxHeight, minWidth, maxWidth, containerElement, resizeWestWidth, proxy, DialogUtility, react = __webpack_require__(7294), ej2_base = __webpack_require__(1807), popup = __webpack_require__(9486), constant = __webpack_require__(3386), classes = __webpack_require__(9805), base_enum = __webpack_require__(809), Render = /** @class */ function() {
+            var extendStatics, extendStatics1, extendStatics2, extendStatics3, extendStatics4, extendStatics5, extendStatics6, extendStatics7, extendStatics8, extendStatics9, extendStatics10, extendStatics11, extendStatics12, extendStatics13, targetElement, selectedHandler, minHeight, maxHeight, minWidth, maxWidth, containerElement, resizeWestWidth, proxy, react = __webpack_require__(7294), ej2_base = __webpack_require__(1807), popup = __webpack_require__(9486), constant = __webpack_require__(3386), classes = __webpack_require__(9805), base_enum = __webpack_require__(809), Render = /** @class */ function() {
                 /**
      * Constructor for render module
      *
@@ -11945,7 +11945,7 @@
                 ], AnimationSettings.prototype, "duration", void 0), dialog_decorate([
                     (0, ej2_base /* Property */ .Z9)(0)
                 ], AnimationSettings.prototype, "delay", void 0), AnimationSettings;
-            }(ej2_base /* ChildProperty */ .rt), ROOT = 'e-dialog', RTL = 'e-rtl', DLG_HEADER_CONTENT = 'e-dlg-header-content', DLG_HEADER = 'e-dlg-header', DLG_FOOTER_CONTENT = 'e-footer-content', MODAL_DLG = 'e-dlg-modal', DLG_CLOSE_ICON = 'e-icon-dlg-close', DLG_TARGET = 'e-dlg-target', SCROLL_DISABLED = 'e-scroll-disabled', DEVICE = 'e-device', FULLSCREEN = 'e-dlg-fullscreen', DLG_CLOSE_ICON_BTN = 'e-dlg-closeicon-btn', DLG_SHOW = 'e-popup-open', DLG_UTIL_DEFAULT_TITLE = 'Information', DLG_UTIL_ROOT = 'e-scroll-disabled', DLG_UTIL_ALERT = 'e-alert-dialog', DLG_UTIL_CONFIRM = 'e-confirm-dialog', DLG_RESIZABLE = 'e-dlg-resizable', DLG_RESTRICT_LEFT_VALUE = 'e-restrict-left', DLG_RESTRICT_WIDTH_VALUE = 'e-resize-viewport', DLG_USER_ACTION_CLOSED = 'user action', Dialog = /** @class */ function(_super) {
+            }(ej2_base /* ChildProperty */ .rt), ROOT = 'e-dialog', RTL = 'e-rtl', DLG_HEADER_CONTENT = 'e-dlg-header-content', DLG_HEADER = 'e-dlg-header', DLG_FOOTER_CONTENT = 'e-footer-content', MODAL_DLG = 'e-dlg-modal', DLG_CLOSE_ICON = 'e-icon-dlg-close', DLG_TARGET = 'e-dlg-target', SCROLL_DISABLED = 'e-scroll-disabled', DEVICE = 'e-device', FULLSCREEN = 'e-dlg-fullscreen', DLG_CLOSE_ICON_BTN = 'e-dlg-closeicon-btn', DLG_SHOW = 'e-popup-open', DLG_RESIZABLE = 'e-dlg-resizable', DLG_RESTRICT_LEFT_VALUE = 'e-restrict-left', DLG_RESTRICT_WIDTH_VALUE = 'e-resize-viewport', DLG_USER_ACTION_CLOSED = 'user action', Dialog = /** @class */ function(_super) {
                 /*
      * * Constructor for creating the widget
      *
@@ -12140,7 +12140,7 @@
                         this.dlgContainer = this.element.parentElement;
                         for(var i = 0, childNodes = this.dlgContainer.children; i < childNodes.length; i++)childNodes[i].classList.contains('e-dlg-overlay') && (this.dlgOverlay = childNodes[i]);
                     }
-                    if (!0 !== this.element.classList.contains(DLG_UTIL_ALERT) && !0 !== this.element.classList.contains(DLG_UTIL_CONFIRM) && !(0, ej2_base /* isNullOrUndefined */ .le)(this.element.parentElement)) {
+                    if (!0 !== this.element.classList.contains('e-alert-dialog') && !0 !== this.element.classList.contains('e-confirm-dialog') && !(0, ej2_base /* isNullOrUndefined */ .le)(this.element.parentElement)) {
                         var parentEle = this.isModal ? this.dlgContainer.parentElement : this.element.parentElement;
                         this.refElement = this.createElement('div', {
                             className: 'e-dlg-ref-element'
@@ -12784,138 +12784,7 @@
                 ], Dialog.prototype, "destroyed", void 0), Dialog = dialog_decorate([
                     ej2_base /* NotifyPropertyChanges */ .Zl
                 ], Dialog);
-            }(ej2_base /* Component */ .wA);
-            !function(DialogUtility) {
-                // eslint-disable-next-line
-                function createDialog(options, element) {
-                    var dialogObject = new Dialog(options);
-                    return dialogObject.appendTo(element), dialogObject;
-                }
-                // eslint-disable-next-line
-                function formOptions(options, option) {
-                    return options.header = (0, ej2_base /* isNullOrUndefined */ .le)(option.title) ? DLG_UTIL_DEFAULT_TITLE : option.title, options.content = (0, ej2_base /* isNullOrUndefined */ .le)(option.content) ? '' : option.content, options.isModal = !!(0, ej2_base /* isNullOrUndefined */ .le)(option.isModal) || option.isModal, options.showCloseIcon = !(0, ej2_base /* isNullOrUndefined */ .le)(option.showCloseIcon) && option.showCloseIcon, options.allowDragging = !(0, ej2_base /* isNullOrUndefined */ .le)(option.isDraggable) && option.isDraggable, options.closeOnEscape = !(0, ej2_base /* isNullOrUndefined */ .le)(option.closeOnEscape) && option.closeOnEscape, options.position = (0, ej2_base /* isNullOrUndefined */ .le)(option.position) ? {
-                        X: 'center',
-                        Y: 'top'
-                    } : option.position, options.animationSettings = (0, ej2_base /* isNullOrUndefined */ .le)(option.animationSettings) ? {
-                        effect: 'Fade',
-                        duration: 400,
-                        delay: 0
-                    } : option.animationSettings, options.cssClass = (0, ej2_base /* isNullOrUndefined */ .le)(option.cssClass) ? '' : option.cssClass, options.zIndex = (0, ej2_base /* isNullOrUndefined */ .le)(option.zIndex) ? 1000 : option.zIndex, options.open = (0, ej2_base /* isNullOrUndefined */ .le)(option.open) ? null : option.open, options;
-                }
-                // eslint-disable-next-line
-                function formButtonModel(buttonModel, option, buttonPropModel) {
-                    return (0, ej2_base /* isNullOrUndefined */ .le)(option.text) || (buttonPropModel.buttonModel.content = option.text), (0, ej2_base /* isNullOrUndefined */ .le)(option.icon) || (buttonPropModel.buttonModel.iconCss = option.icon), (0, ej2_base /* isNullOrUndefined */ .le)(option.cssClass) || (buttonPropModel.buttonModel.cssClass = option.cssClass), (0, ej2_base /* isNullOrUndefined */ .le)(option.click) || (buttonPropModel.click = option.click), buttonPropModel;
-                }
-                DialogUtility.alert = /**
-     * An alert dialog box is used to display warning like messages to the users.
-     * ```
-     * Eg : DialogUtility.alert('Alert message');
-     *
-     * ```
-     */ /* istanbul ignore next */ /**
-     *
-     * @param {AlertDialogArgs} args - specifies the string
-     * @returns {Dialog} - returns the dialog element.
-     */ function(args) {
-                    var options, options1, alertButtonModel, alertDialogObj, dialogElement = (0, ej2_base /* createElement */ .az)('div', {
-                        className: DLG_UTIL_ALERT
-                    });
-                    return document.body.appendChild(dialogElement), (alertDialogObj = 'string' == typeof args ? createDialog({
-                        content: args,
-                        position: {
-                            X: 'center',
-                            Y: 'top'
-                        },
-                        isModal: !0,
-                        header: DLG_UTIL_DEFAULT_TITLE,
-                        buttons: [
-                            {
-                                buttonModel: {
-                                    isPrimary: !0,
-                                    content: 'OK'
-                                },
-                                click: function() {
-                                    this.hide();
-                                }
-                            }
-                        ]
-                    }, dialogElement) : createDialog(((options = {}).buttons = [], options1 = options = formOptions(options, args), alertButtonModel = [
-                        {
-                            buttonModel: {
-                                isPrimary: !0,
-                                content: 'OK'
-                            },
-                            click: function() {
-                                this.hide();
-                            }
-                        }
-                    ], (0, ej2_base /* isNullOrUndefined */ .le)(args.okButton) ? options1.buttons = alertButtonModel : options1.buttons[0] = formButtonModel(options1.buttons[0], args.okButton, alertButtonModel[0]), options = options1), dialogElement)).close = function() {
-                        args && args.close && args.close.apply(alertDialogObj), alertDialogObj.destroy(), alertDialogObj.element.classList.contains('e-dlg-modal') ? (alertDialogObj.element.parentElement.remove(), alertDialogObj.target.classList.remove(DLG_UTIL_ROOT)) : alertDialogObj.element.remove();
-                    }, alertDialogObj;
-                }, DialogUtility.confirm = /**
-     * A confirm dialog displays a specified message along with ‘OK’ and ‘Cancel’ button.
-     * ```
-     * Eg : DialogUtility.confirm('Confirm dialog message');
-     *
-     * ```
-     */ /* istanbul ignore next */ /**
-     *
-     * @param {ConfirmDialogArgs} args - specifies the args
-     * @returns {Dialog} - returns te element
-     */ function(args) {
-                    var options, options1, okButtonModel, cancelButtonModel, confirmDialogObj, dialogElement = (0, ej2_base /* createElement */ .az)('div', {
-                        className: DLG_UTIL_CONFIRM
-                    });
-                    return document.body.appendChild(dialogElement), (confirmDialogObj = 'string' == typeof args ? createDialog({
-                        position: {
-                            X: 'center',
-                            Y: 'top'
-                        },
-                        content: args,
-                        isModal: !0,
-                        header: DLG_UTIL_DEFAULT_TITLE,
-                        buttons: [
-                            {
-                                buttonModel: {
-                                    isPrimary: !0,
-                                    content: 'OK'
-                                },
-                                click: function() {
-                                    this.hide();
-                                }
-                            },
-                            {
-                                buttonModel: {
-                                    content: 'Cancel'
-                                },
-                                click: function() {
-                                    this.hide();
-                                }
-                            }
-                        ]
-                    }, dialogElement) : createDialog(((options = {}).buttons = [], options1 = options = formOptions(options, args), okButtonModel = {
-                        buttonModel: {
-                            isPrimary: !0,
-                            content: 'OK'
-                        },
-                        click: function() {
-                            this.hide();
-                        }
-                    }, cancelButtonModel = {
-                        buttonModel: {
-                            content: 'Cancel'
-                        },
-                        click: function() {
-                            this.hide();
-                        }
-                    }, (0, ej2_base /* isNullOrUndefined */ .le)(args.okButton) ? options1.buttons[0] = okButtonModel : options1.buttons[0] = formButtonModel(options1.buttons[0], args.okButton, okButtonModel), (0, ej2_base /* isNullOrUndefined */ .le)(args.cancelButton) ? options1.buttons[1] = cancelButtonModel : options1.buttons[1] = formButtonModel(options1.buttons[1], args.cancelButton, cancelButtonModel), options = options1), dialogElement)).close = function() {
-                        args && args.close && args.close.apply(confirmDialogObj), confirmDialogObj.destroy(), confirmDialogObj.element.classList.contains('e-dlg-modal') ? (confirmDialogObj.element.parentElement.remove(), confirmDialogObj.target.classList.remove(DLG_UTIL_ROOT)) : confirmDialogObj.element.remove();
-                    }, confirmDialogObj;
-                };
-            }(DialogUtility || (DialogUtility = {}));
-            /**
- * Dialog Renderer
- */ var DialogRenderer = /** @class */ function() {
+            }(ej2_base /* Component */ .wA), DialogRenderer = /** @class */ function() {
                 function DialogRenderer(parent) {
                     this.parent = parent, this.addEventListener();
                 }
@@ -28525,11 +28525,124 @@ class Zip {
         /***/ },
         /***/ 8575: /***/ function(module) {
             "use strict";
-            module.exports = JSON.parse('{"block":"block_type","loop":"block_type","if":"block_type","br":"varuint32","br_if":"varuint32","br_table":"br_table","call":"varuint32","call_indirect":"call_indirect","get_local":"varuint32","set_local":"varuint32","tee_local":"varuint32","get_global":"varuint32","set_global":"varuint32","load":"memory_immediate","load8_s":"memory_immediate","load8_u":"memory_immediate","load16_s":"memory_immediate","load16_u":"memory_immediate","load32_s":"memory_immediate","load32_u":"memory_immediate","store":"memory_immediate","store8":"memory_immediate","store16":"memory_immediate","store32":"memory_immediate","current_memory":"varuint1","grow_memory":"varuint1","i32":"varint32","i64":"varint64","f32":"uint32","f64":"uint64"}');
+            module.exports = {
+                block: "block_type",
+                loop: "block_type",
+                if: "block_type",
+                br: "varuint32",
+                br_if: "varuint32",
+                br_table: "br_table",
+                call: "varuint32",
+                call_indirect: "call_indirect",
+                get_local: "varuint32",
+                set_local: "varuint32",
+                tee_local: "varuint32",
+                get_global: "varuint32",
+                set_global: "varuint32",
+                load: "memory_immediate",
+                load8_s: "memory_immediate",
+                load8_u: "memory_immediate",
+                load16_s: "memory_immediate",
+                load16_u: "memory_immediate",
+                load32_s: "memory_immediate",
+                load32_u: "memory_immediate",
+                store: "memory_immediate",
+                store8: "memory_immediate",
+                store16: "memory_immediate",
+                store32: "memory_immediate",
+                current_memory: "varuint1",
+                grow_memory: "varuint1",
+                i32: "varint32",
+                i64: "varint64",
+                f32: "uint32",
+                f64: "uint64"
+            };
         /***/ },
         /***/ 5936: /***/ function(module) {
             "use strict";
-            module.exports = JSON.parse('{"start":0,"type":{"params":{"DEFAULT":0},"return_type":{"DEFAULT":0}},"import":0,"code":{"locals":{"DEFAULT":1},"code":{"get_local":120,"set_local":120,"tee_local":120,"get_global":120,"set_global":120,"load8_s":120,"load8_u":120,"load16_s":120,"load16_u":120,"load32_s":120,"load32_u":120,"load":120,"store8":120,"store16":120,"store32":120,"store":120,"grow_memory":10000,"current_memory":100,"nop":1,"block":1,"loop":1,"if":1,"then":90,"else":90,"br":90,"br_if":90,"br_table":120,"return":90,"call":90,"call_indirect":10000,"const":1,"add":45,"sub":45,"mul":45,"div_s":36000,"div_u":36000,"rem_s":36000,"rem_u":36000,"and":45,"or":45,"xor":45,"shl":67,"shr_u":67,"shr_s":67,"rotl":90,"rotr":90,"eq":45,"eqz":45,"ne":45,"lt_s":45,"lt_u":45,"le_s":45,"le_u":45,"gt_s":45,"gt_u":45,"ge_s":45,"ge_u":45,"clz":45,"ctz":45,"popcnt":45,"drop":120,"select":120,"unreachable":1}},"data":0}');
+            module.exports = {
+                start: 0,
+                type: {
+                    params: {
+                        DEFAULT: 0
+                    },
+                    return_type: {
+                        DEFAULT: 0
+                    }
+                },
+                import: 0,
+                code: {
+                    locals: {
+                        DEFAULT: 1
+                    },
+                    code: {
+                        get_local: 120,
+                        set_local: 120,
+                        tee_local: 120,
+                        get_global: 120,
+                        set_global: 120,
+                        load8_s: 120,
+                        load8_u: 120,
+                        load16_s: 120,
+                        load16_u: 120,
+                        load32_s: 120,
+                        load32_u: 120,
+                        load: 120,
+                        store8: 120,
+                        store16: 120,
+                        store32: 120,
+                        store: 120,
+                        grow_memory: 10000,
+                        current_memory: 100,
+                        nop: 1,
+                        block: 1,
+                        loop: 1,
+                        if: 1,
+                        then: 90,
+                        else: 90,
+                        br: 90,
+                        br_if: 90,
+                        br_table: 120,
+                        return: 90,
+                        call: 90,
+                        call_indirect: 10000,
+                        const: 1,
+                        add: 45,
+                        sub: 45,
+                        mul: 45,
+                        div_s: 36000,
+                        div_u: 36000,
+                        rem_s: 36000,
+                        rem_u: 36000,
+                        and: 45,
+                        or: 45,
+                        xor: 45,
+                        shl: 67,
+                        shr_u: 67,
+                        shr_s: 67,
+                        rotl: 90,
+                        rotr: 90,
+                        eq: 45,
+                        eqz: 45,
+                        ne: 45,
+                        lt_s: 45,
+                        lt_u: 45,
+                        le_s: 45,
+                        le_u: 45,
+                        gt_s: 45,
+                        gt_u: 45,
+                        ge_s: 45,
+                        ge_u: 45,
+                        clz: 45,
+                        ctz: 45,
+                        popcnt: 45,
+                        drop: 120,
+                        select: 120,
+                        unreachable: 1
+                    }
+                },
+                data: 0
+            };
         /***/ }
     }
 ]);
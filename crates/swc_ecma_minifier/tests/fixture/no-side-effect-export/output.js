@@ -0,0 +1,16 @@
+/*#__NO_SIDE_EFFECTS__*/ export function fnA() {
+    console.log("A");
+}
+export /*#__NO_SIDE_EFFECTS__*/ function fnB() {
+    console.log("B");
+}
+/*#__NO_SIDE_EFFECTS__*/ export const fnC = ()=>{
+    console.log("C");
+};
+/*#__NO_SIDE_EFFECTS__*/ export default function fnD() {
+    console.log("D");
+}
+export const fnE = /*#__NO_SIDE_EFFECTS__*/ function() {
+    console.log("E");
+};
+console.log("G");
@@ -0,0 +1,7 @@
+export function f(p, q, r) {
+    p.x++;
+    q = q * 2;
+    r = r * 3;
+    p.y = q;
+    p.z = r;
+}
@@ -0,0 +1,3 @@
+export function f(p, q, r) {
+    p.x++, q *= 2, r *= 3, p.y = q, p.z = r;
+}
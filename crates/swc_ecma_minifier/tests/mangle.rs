@@ -3,6 +3,7 @@
 use std::{
     path::{Path, PathBuf},
     process::{Command, Stdio},
+    sync::Arc,
 };
 
 use swc_common::{errors::Handler, sync::Lrc, FileName, Mark, SourceFile, SourceMap};
@@ -13,7 +14,10 @@ use swc_ecma_codegen::{
 };
 use swc_ecma_minifier::{
     optimize,
-    option::{ExtraOptions, MangleOptions, ManglePropertiesOptions, MinifyOptions},
+    option::{
+        ExtraOptions, MangleCache, MangleClassPropsOptions, MangleOptions, ManglePropertiesOptions,
+        MinifyOptions, SimpleMangleCache,
+    },
 };
 use swc_ecma_parser::parse_file_as_program;
 use swc_ecma_transforms_base::{fixer::paren_remover, resolver};
@@ -184,6 +188,15 @@ fn fixture(input: PathBuf) {
 
 #[track_caller]
 fn assert_mangled(src: &str, expected: &str, opts: MangleOptions) {
+    assert_mangled_with_cache(src, expected, opts, None)
+}
+
+fn assert_mangled_with_cache(
+    src: &str,
+    expected: &str,
+    opts: MangleOptions,
+    mangle_name_cache: Option<Arc<dyn MangleCache>>,
+) {
     testing::run_test2(false, |cm, handler| {
         let fm = cm.new_source_file(FileName::Anon.into(), src.into());
 
@@ -205,7 +218,7 @@ fn assert_mangled(src: &str, expected: &str, opts: MangleOptions) {
             &ExtraOptions {
                 unresolved_mark,
                 top_level_mark,
-                mangle_name_cache: None,
+                mangle_name_cache,
             },
         );
 
@@ -359,3 +372,88 @@ class s {
         },
     )
 }
+
+#[test]
+fn class_props() {
+    let src = "class Counter {
+    #count = 0;
+    #keepMe = 1;
+    stride = 1;
+    keepStride = 2;
+    quoted = 3;
+    constructor(){
+        this.quoted = 4;
+    }
+    increment() {
+        this.#count += this.stride + this.keepStride;
+        return this['quoted'];
+    }
+}
+const obj = { stride: 1, other: 2 };
+console.log(new Counter().increment(), obj.stride, obj.other);";
+
+    let expected = "class e {
+    #e = 0;
+    #keepMe = 1;
+    e = 1;
+    keepStride = 2;
+    quoted = 3;
+    constructor(){
+        this.quoted = 4;
+    }
+    t() {
+        this.#e += this.e + this.keepStride;
+        return this['quoted'];
+    }
+}
+const t = {
+    e: 1,
+    other: 2
+};
+console.log(new e().t(), t.e, t.other);";
+
+    assert_mangled(
+        src,
+        expected,
+        MangleOptions {
+            class_props: Some(MangleClassPropsOptions {
+                keep: Some(serde_json::from_str("\"^keep\"").unwrap()),
+            }),
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn props_name_cache() {
+    let cache: Arc<dyn MangleCache> = Arc::new(SimpleMangleCache::default());
+    let opts = MangleOptions {
+        props: Some(Default::default()),
+        ..Default::default()
+    };
+
+    assert_mangled_with_cache(
+        "const chunk1 = { shared: 1, first: 2 };
+console.log(chunk1.shared, chunk1.first);",
+        "const s = {
+    s: 1,
+    r: 2
+};
+console.log(s.s, s.r);",
+        opts.clone(),
+        Some(cache.clone()),
+    );
+
+    // `shared` keeps its name, and `second` doesn't reuse the name of `first`.
+    assert_mangled_with_cache(
+        "const chunk2 = { second: 1, shared: 2 };
+console.log(chunk2.second, chunk2.shared);",
+        "const s = {
+    d: 1,
+    s: 2
+};
+console.log(s.d, s.s);",
+        opts,
+        Some(cache),
+    );
+}
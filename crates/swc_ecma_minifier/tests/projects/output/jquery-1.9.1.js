@@ -3190,8 +3190,7 @@
      * 1) key is the dataType
      * 2) the catchall symbol "*" can be used
      * 3) selection will start with transport dataType and THEN go to "*" if needed
-     */ transports = {}, // Avoid comment-prolog char sequence (#10098); must appease lint and evade compression
-    allTypes = "*/".concat("*");
+     */ transports = {};
     // #8138, IE may throw an exception when accessing
     // a field from window.location if document.domain has been set
     try {
@@ -3304,7 +3303,7 @@
 		traditional: false,
 		headers: {},
 		*/ accepts: {
-                "*": allTypes,
+                "*": "*/*",
                 text: "text/plain",
                 html: "text/html",
                 xml: "application/xml, text/xml",
@@ -3429,7 +3428,7 @@
             // and/or If-None-Match header later on
             cacheURL = s.url, s.hasContent || (s.data && (cacheURL = s.url += (ajax_rquery.test(cacheURL) ? "&" : "?") + s.data, // #9682: remove data so that it's not used in an eventual retry
             delete s.data), !1 === s.cache && (s.url = rts.test(cacheURL) ? cacheURL.replace(rts, "$1_=" + ajax_nonce++) : cacheURL + (ajax_rquery.test(cacheURL) ? "&" : "?") + "_=" + ajax_nonce++)), s.ifModified && (jQuery.lastModified[cacheURL] && jqXHR.setRequestHeader("If-Modified-Since", jQuery.lastModified[cacheURL]), jQuery.etag[cacheURL] && jqXHR.setRequestHeader("If-None-Match", jQuery.etag[cacheURL])), (s.data && s.hasContent && !1 !== s.contentType || options.contentType) && jqXHR.setRequestHeader("Content-Type", s.contentType), // Set the Accepts header for the server, depending on the dataType
-            jqXHR.setRequestHeader("Accept", s.dataTypes[0] && s.accepts[s.dataTypes[0]] ? s.accepts[s.dataTypes[0]] + ("*" !== s.dataTypes[0] ? ", " + allTypes + "; q=0.01" : "") : s.accepts["*"]), s.headers)jqXHR.setRequestHeader(i, s.headers[i]);
+            jqXHR.setRequestHeader("Accept", s.dataTypes[0] && s.accepts[s.dataTypes[0]] ? s.accepts[s.dataTypes[0]] + ("*" !== s.dataTypes[0] ? ", */*; q=0.01" : "") : s.accepts["*"]), s.headers)jqXHR.setRequestHeader(i, s.headers[i]);
             // Allow custom headers/mimetypes and early abort
             if (s.beforeSend && (!1 === s.beforeSend.call(callbackContext, jqXHR, s) || 2 === state)) // Abort if not done already and return
             return jqXHR.abort();
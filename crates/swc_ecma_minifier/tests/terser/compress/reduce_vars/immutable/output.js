@@ -1 +1 @@
-console.log("test".indexOf("e"));
+console.log(1);
@@ -0,0 +1,274 @@
+//! Lossless representation of the source, built alongside the ast.
+//!
+//! The ast drops tokens like parentheses and commas, and the comment map
+//! doesn't record whitespace. [Cst] keeps every token with the whitespace and
+//! comments before it, so tools like formatters can access the exact source
+//! of a node using its span.
+
+use swc_common::{comments::Comments, sync::Lrc, BytePos, SourceFile, Span};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_lexer::{
+    error::Error,
+    input::Capturing,
+    token::{Token, TokenAndSpan},
+    Lexer, Syntax,
+};
+
+use crate::{PResult, Parser, StringInput};
+
+/// Parses a file like [crate::parse_file_as_program], and also returns the
+/// [Cst] of the file.
+pub fn parse_file_as_program_with_cst(
+    fm: &SourceFile,
+    syntax: Syntax,
+    target: EsVersion,
+    comments: Option<&dyn Comments>,
+    recovered_errors: &mut Vec<Error>,
+) -> PResult<(Program, Cst)> {
+    let lexer = Capturing::new(Lexer::new(syntax, target, StringInput::from(fm), comments));
+    let tokens = lexer.tokens();
+
+    let mut p = Parser::new_from(lexer);
+    let ret = p.parse_program();
+    recovered_errors.append(&mut p.take_errors());
+    let program = ret?;
+
+    let tokens = tokens.take();
+    Ok((program, Cst::new(fm.src.clone(), fm.start_pos, tokens)))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TriviaKind {
+    Whitespace,
+    /// `// ...`, including html-like comments.
+    LineComment,
+    /// `/* ... */`
+    BlockComment,
+    /// Source text which is not part of any token or comment, which exists
+    /// only if the source has syntax errors.
+    Skipped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CstToken {
+    pub token: Token,
+    pub span: Span,
+    /// Whitespace and comments between the previous token and this token.
+    pub leading: Vec<Trivia>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Cst {
+    src: Lrc<String>,
+    start_pos: BytePos,
+    tokens: Vec<CstToken>,
+    /// Whitespace and comments after the last token.
+    trailing: Vec<Trivia>,
+}
+
+impl Cst {
+    fn new(src: Lrc<String>, start_pos: BytePos, tokens: Vec<TokenAndSpan>) -> Self {
+        let mut pos = start_pos;
+        let mut cst_tokens = Vec::with_capacity(tokens.len());
+
+        for TokenAndSpan { token, span, .. } in tokens {
+            // Tokens re-lexed by the parser are not captured in order.
+            if span.lo < pos {
+                continue;
+            }
+
+            let leading = trivia(&src, start_pos, Span::new(pos, span.lo));
+            cst_tokens.push(CstToken {
+                token,
+                span,
+                leading,
+            });
+            pos = span.hi;
+        }
+
+        let end_pos = start_pos + BytePos(src.len() as u32);
+        let trailing = trivia(&src, start_pos, Span::new(pos, end_pos));
+
+        Cst {
+            src,
+            start_pos,
+            tokens: cst_tokens,
+            trailing,
+        }
+    }
+
+    pub fn tokens(&self) -> &[CstToken] {
+        &self.tokens
+    }
+
+    /// Whitespace and comments after the last token.
+    pub fn trailing(&self) -> &[Trivia] {
+        &self.trailing
+    }
+
+    /// Returns the tokens contained in `span`, which is usually the span of
+    /// an ast node.
+    pub fn tokens_of(&self, span: Span) -> &[CstToken] {
+        let start = self.tokens.partition_point(|t| t.span.lo < span.lo);
+        let end = self.tokens.partition_point(|t| t.span.hi <= span.hi);
+
+        &self.tokens[start..end.max(start)]
+    }
+
+    /// Returns whitespace and comments before the first token in `span`.
+    pub fn leading_trivia_of(&self, span: Span) -> &[Trivia] {
+        self.tokens_of(span)
+            .first()
+            .map(|t| &*t.leading)
+            .unwrap_or_default()
+    }
+
+    /// Returns whitespace and comments after the last token in `span`.
+    pub fn trailing_trivia_of(&self, span: Span) -> &[Trivia] {
+        let end = self.tokens.partition_point(|t| t.span.hi <= span.hi);
+
+        match self.tokens.get(end) {
+            Some(next) => &next.leading,
+            None => &self.trailing,
+        }
+    }
+
+    /// Returns the source text of `span`.
+    pub fn text(&self, span: Span) -> &str {
+        let lo = (span.lo - self.start_pos).0 as usize;
+        let hi = (span.hi - self.start_pos).0 as usize;
+
+        &self.src[lo..hi]
+    }
+
+    /// Prints the tokens and trivia, which is the same as the source.
+    pub fn to_source(&self) -> String {
+        let mut buf = String::with_capacity(self.src.len());
+
+        for token in &self.tokens {
+            for trivia in &token.leading {
+                buf.push_str(self.text(trivia.span));
+            }
+            buf.push_str(self.text(token.span));
+        }
+        for trivia in &self.trailing {
+            buf.push_str(self.text(trivia.span));
+        }
+
+        buf
+    }
+}
+
+/// Splits the text between two tokens into whitespace and comments.
+fn trivia(src: &str, start_pos: BytePos, span: Span) -> Vec<Trivia> {
+    let base = (span.lo - start_pos).0 as usize;
+    let text = &src[base..(span.hi - start_pos).0 as usize];
+
+    let mut trivia = Vec::new();
+    let mut offset = 0;
+
+    while offset < text.len() {
+        let rest = &text[offset..];
+
+        let (kind, len) = if let Some(body) = rest.strip_prefix("/*") {
+            let len = body.find("*/").map_or(rest.len(), |i| i + 4);
+            (TriviaKind::BlockComment, len)
+        } else if rest.starts_with("//") || rest.starts_with("<!--") || rest.starts_with("-->") {
+            let len = rest
+                .find(['\n', '\r', '\u{2028}', '\u{2029}'])
+                .unwrap_or(rest.len());
+            (TriviaKind::LineComment, len)
+        } else {
+            let len = rest
+                .find(|c: char| !c.is_whitespace() && c != '\u{feff}')
+                .unwrap_or(rest.len());
+            if len == 0 {
+                let c = rest.chars().next().unwrap();
+                (TriviaKind::Skipped, c.len_utf8())
+            } else {
+                (TriviaKind::Whitespace, len)
+            }
+        };
+
+        let lo = span.lo + BytePos(offset as u32);
+        trivia.push(Trivia {
+            kind,
+            span: Span::new(lo, lo + BytePos(len as u32)),
+        });
+        offset += len;
+    }
+
+    trivia
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::{FileName, SourceMap};
+
+    use super::*;
+
+    fn parse(src: &str) -> (Program, Cst) {
+        let cm = SourceMap::default();
+        let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+
+        parse_file_as_program_with_cst(
+            &fm,
+            Default::default(),
+            Default::default(),
+            None,
+            &mut Vec::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn roundtrip() {
+        let src = "#!/usr/bin/env node\n// leading\nconst a = ( 1 + /* two */ 2 ) ;\n\nfoo(a, \
+                   `x${ a }y`, /re/g)  // trailing\n";
+        let (_, cst) = parse(src);
+
+        assert_eq!(cst.to_source(), src);
+        assert!(cst
+            .tokens()
+            .iter()
+            .flat_map(|t| &t.leading)
+            .chain(cst.trailing())
+            .all(|t| t.kind != TriviaKind::Skipped));
+    }
+
+    #[test]
+    fn node_trivia() {
+        let src = "a;\n/* doc */\nlet b = (1);  // b\n";
+        let (program, cst) = parse(src);
+
+        let decl = &program.expect_script().body[1];
+        let span = swc_common::Spanned::span(decl);
+
+        assert_eq!(cst.text(span), "let b = (1);");
+        assert_eq!(cst.tokens_of(span).len(), 7);
+        assert_eq!(
+            cst.leading_trivia_of(span)
+                .iter()
+                .map(|t| t.kind)
+                .collect::<Vec<_>>(),
+            vec![
+                TriviaKind::Whitespace,
+                TriviaKind::BlockComment,
+                TriviaKind::Whitespace
+            ]
+        );
+        assert_eq!(
+            cst.trailing_trivia_of(span)
+                .iter()
+                .map(|t| cst.text(t.span))
+                .collect::<Vec<_>>(),
+            vec!["  ", "// b", "\n"]
+        );
+    }
+}
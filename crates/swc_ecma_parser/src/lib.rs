@@ -125,16 +125,23 @@
 #![allow(clippy::match_like_matches_macro)]
 
 pub use swc_common::input::{Input, StringInput};
-use swc_common::{comments::Comments, input::SourceFileInput, SourceFile};
+use swc_common::{
+    comments::{Comments, SingleThreadedComments},
+    input::SourceFileInput,
+    SourceFile,
+};
 use swc_ecma_ast::*;
-use swc_ecma_lexer::error::Error;
+use swc_ecma_lexer::error::{Error, SyntaxError};
 
 pub use self::parser::*;
 
 #[macro_use]
 mod macros;
+pub mod cst;
 use swc_ecma_lexer::Lexer;
-pub use swc_ecma_lexer::{error, lexer, token, Context, EsSyntax, Syntax, TsSyntax};
+pub use swc_ecma_lexer::{
+    error, lexer, token, Context, EsSyntax, FlowSyntax, Syntax, TsSyntax, TsVersion,
+};
 
 mod parser;
 
@@ -200,6 +207,54 @@ expose!(parse_file_as_module, Module, |p| { p.parse_module() });
 expose!(parse_file_as_script, Script, |p| { p.parse_script() });
 expose!(parse_file_as_program, Program, |p| { p.parse_program() });
 
+/// Parses a JSON file. See [Parser::parse_json].
+///
+/// Comments are reported as errors as well.
+pub fn parse_file_as_json(
+    fm: &SourceFile,
+    recovered_errors: &mut Vec<Error>,
+) -> PResult<Box<Expr>> {
+    let comments = SingleThreadedComments::default();
+    let value = with_file_parser(
+        fm,
+        Syntax::Es(Default::default()),
+        EsVersion::latest(),
+        Some(&comments),
+        recovered_errors,
+        |p| p.parse_json(),
+    )?;
+
+    let (leading, trailing) = comments.borrow_all();
+    let first_comment = leading
+        .values()
+        .chain(trailing.values())
+        .flatten()
+        .map(|c| c.span)
+        .min_by_key(|span| span.lo);
+    if let Some(span) = first_comment {
+        recovered_errors.push(Error::new(span, SyntaxError::JsonComment));
+    }
+
+    Ok(value)
+}
+
+/// Parses a JSON file with comments, like `tsconfig.json`. See
+/// [Parser::parse_jsonc].
+pub fn parse_file_as_jsonc(
+    fm: &SourceFile,
+    comments: Option<&dyn Comments>,
+    recovered_errors: &mut Vec<Error>,
+) -> PResult<Box<Expr>> {
+    with_file_parser(
+        fm,
+        Syntax::Es(Default::default()),
+        EsVersion::latest(),
+        comments,
+        recovered_errors,
+        |p| p.parse_jsonc(),
+    )
+}
+
 #[inline(always)]
 #[cfg(any(
     target_arch = "wasm32",
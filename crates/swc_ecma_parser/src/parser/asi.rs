@@ -0,0 +1,87 @@
+use super::*;
+
+/// A location where automatic semicolon insertion is applied.
+///
+/// See [Parser::record_asi_decisions].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AsiDecision {
+    pub kind: AsiKind,
+    /// The position where a semicolon is inserted, which is the end of the
+    /// previous token.
+    pub pos: BytePos,
+    /// The span of the token after `pos`, or [None] at the end of the input.
+    pub next: Option<Span>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AsiKind {
+    /// The next token is on a new line.
+    LineBreak,
+    /// The next token is `}`.
+    CloseBrace,
+    /// The input ends.
+    Eof,
+    /// A line break after `return`, `break`, `continue` or `yield`, or before
+    /// a postfix `++` or `--`, ends the statement or the expression even
+    /// though the next token could continue it.
+    RestrictedProduction,
+}
+
+impl<I: Tokens> Parser<I> {
+    /// Records locations where automatic semicolon insertion is applied while
+    /// parsing. Use [Parser::take_asi_decisions] to get them.
+    pub fn record_asi_decisions(&mut self) {
+        self.state
+            .asi_decisions
+            .get_or_insert_with(Default::default);
+    }
+
+    /// Takes locations where automatic semicolon insertion is applied, in the
+    /// order they appear.
+    ///
+    /// This returns nothing unless [Parser::record_asi_decisions] is called
+    /// before parsing.
+    pub fn take_asi_decisions(&mut self) -> Vec<AsiDecision> {
+        self.state
+            .asi_decisions
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Called by `eat!(p, ';')` if the current token is not `;`.
+    pub(super) fn record_asi(&mut self, kind: AsiKind) {
+        if self.state.asi_decisions.is_none() {
+            return;
+        }
+
+        let pos = self.input.prev_span().hi;
+        let next = self.input.cur().is_some().then(|| self.input.cur_span());
+
+        let decisions = self.state.asi_decisions.as_mut().unwrap();
+        // A restricted production is recorded before the semicolon is inserted
+        // at the same position.
+        if decisions.last().is_some_and(|last| last.pos == pos) {
+            return;
+        }
+
+        decisions.push(AsiDecision { kind, pos, next });
+    }
+
+    /// Records [AsiKind::RestrictedProduction] if the current token is on a new
+    /// line and it can start an expression.
+    pub(super) fn record_restricted_production(&mut self) {
+        if self.state.asi_decisions.is_none() {
+            return;
+        }
+
+        let is_restricted = match self.input.cur() {
+            Some(t) => t.kind().starts_expr() && self.input.had_line_break_before_cur(),
+            None => false,
+        };
+
+        if is_restricted {
+            self.record_asi(AsiKind::RestrictedProduction);
+        }
+    }
+}
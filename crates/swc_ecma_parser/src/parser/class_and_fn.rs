@@ -94,6 +94,8 @@ impl<I: Tokens> Parser<I> {
 
             let type_params = if p.input.syntax().typescript() {
                 p.try_parse_ts_type_params(true, true)?
+            } else if p.input.syntax().flow() {
+                p.try_parse_flow_type_params()?
             } else {
                 None
             };
@@ -123,6 +125,8 @@ impl<I: Tokens> Parser<I> {
 
             let implements = if p.input.syntax().typescript() && eat!(p, "implements") {
                 p.parse_ts_heritage_clause()?
+            } else if p.input.syntax().flow() && eat!(p, "implements") {
+                p.parse_flow_heritage_clause()?
             } else {
                 Vec::with_capacity(4)
             };
@@ -236,6 +240,8 @@ impl<I: Tokens> Parser<I> {
                 // but it's a super class with type params, for example, in JSX.
                 if self.syntax().typescript() && is!(self, '<') {
                     Ok((super_class, self.parse_ts_type_args().map(Some)?))
+                } else if self.syntax().flow() && is!(self, '<') {
+                    Ok((super_class, self.parse_flow_type_args().map(Some)?))
                 } else {
                     Ok((super_class, None))
                 }
@@ -287,10 +293,21 @@ impl<I: Tokens> Parser<I> {
 
         assert_and_bump!(self, '@');
 
-        let expr = if eat!(self, '(') {
+        let expr = if is!(self, '(') {
+            let paren_start = cur_pos!(self);
+            bump!(self);
             let expr = self.parse_expr()?;
             expect!(self, ')');
-            expr
+
+            if self.input.syntax().preserve_parens() {
+                ParenExpr {
+                    span: span!(self, paren_start),
+                    expr,
+                }
+                .into()
+            } else {
+                expr
+            }
         } else {
             let expr = self
                 .parse_ident(false, false)
@@ -512,6 +529,16 @@ impl<I: Tokens> Parser<I> {
                     false,
                 );
             }
+
+            if !self.syntax().supports_ts_version(TsVersion::AUTO_ACCESSORS) {
+                self.emit_err(
+                    accessor_token,
+                    SyntaxError::TsVersionRequired {
+                        syntax: "'accessor' modifier",
+                        version: TsVersion::AUTO_ACCESSORS,
+                    },
+                );
+            }
         }
 
         if let Some(static_token) = static_token {
@@ -1066,7 +1093,7 @@ impl<I: Tokens> Parser<I> {
         }
         let definite = self.input.syntax().typescript() && !is_optional && eat!(self, '!');
 
-        let type_ann = self.try_parse_ts_type_ann()?;
+        let type_ann = self.try_parse_type_ann()?;
 
         let ctx = self.ctx() | Context::IncludeInExpr | Context::InClassField;
         self.with_ctx(ctx).parse_with(|p| {
@@ -1147,12 +1174,13 @@ impl<I: Tokens> Parser<I> {
 
     fn is_class_method(&mut self) -> bool {
         is!(self, '(')
-            || (self.input.syntax().typescript() && is!(self, '<'))
-            || (self.input.syntax().typescript() && is!(self, JSXTagStart))
+            || (self.input.syntax().type_annotations() && is!(self, '<'))
+            || (self.input.syntax().type_annotations() && is!(self, JSXTagStart))
     }
 
     fn is_class_property(&mut self, asi: bool) -> bool {
         (self.input.syntax().typescript() && is_one_of!(self, '!', ':'))
+            || (self.input.syntax().flow() && is!(self, ':'))
             || is_one_of!(self, '=', '}')
             || if asi {
                 is!(self, ';')
@@ -1270,12 +1298,12 @@ impl<I: Tokens> Parser<I> {
         ctx.set(Context::InGenerator, is_generator);
 
         self.with_ctx(ctx).parse_with(|p| {
-            let type_params = if p.syntax().typescript() {
+            let type_params = if p.syntax().type_annotations() {
                 p.in_type().parse_with(|p| {
                     trace_cur!(p, parse_fn_args_body__type_params);
 
                     Ok(if is!(p, '<') {
-                        Some(p.parse_ts_type_params(false, true)?)
+                        Some(p.parse_fn_type_params()?)
                     } else if is!(p, JSXTagStart) {
                         debug_assert_eq!(
                             p.input.token_context().current(),
@@ -1288,7 +1316,7 @@ impl<I: Tokens> Parser<I> {
                         );
                         p.input.token_context_mut().pop();
 
-                        Some(p.parse_ts_type_params(false, true)?)
+                        Some(p.parse_fn_type_params()?)
                     } else {
                         None
                     })
@@ -1310,6 +1338,8 @@ impl<I: Tokens> Parser<I> {
             let return_type = if p.syntax().typescript() && is!(p, ':') {
                 p.parse_ts_type_or_type_predicate_ann(&tok!(':'))
                     .map(Some)?
+            } else if p.syntax().flow() && is!(p, ':') {
+                p.parse_flow_return_type(false)?
             } else {
                 None
             };
@@ -1620,7 +1650,7 @@ impl OutputType for Decl {
 pub(super) trait FnBodyParser<Body> {
     fn parse_fn_body_inner(&mut self, is_simple_parameter_list: bool) -> PResult<Body>;
 }
-fn has_use_strict(block: &BlockStmt) -> Option<Span> {
+pub(super) fn has_use_strict(block: &BlockStmt) -> Option<Span> {
     block
         .stmts
         .iter()
@@ -1639,6 +1669,10 @@ impl<I: Tokens> FnBodyParser<Box<BlockStmtOrExpr>> for Parser<I> {
         is_simple_parameter_list: bool,
     ) -> PResult<Box<BlockStmtOrExpr>> {
         if is!(self, '{') {
+            if let Some(block_stmt) = self.skip_lazy_body(false, is_simple_parameter_list)? {
+                return Ok(Box::new(BlockStmtOrExpr::BlockStmt(block_stmt)));
+            }
+
             self.parse_block(false)
                 .map(|block_stmt| {
                     if !is_simple_parameter_list {
@@ -1663,9 +1697,18 @@ impl<I: Tokens> FnBodyParser<Option<BlockStmt>> for Parser<I> {
         is_simple_parameter_list: bool,
     ) -> PResult<Option<BlockStmt>> {
         // allow omitting body and allow placing `{` on next line
-        if self.input.syntax().typescript() && !is!(self, '{') && eat!(self, ';') {
+        let can_omit_body = self.input.syntax().typescript()
+            || (self.input.syntax().flow() && self.ctx().contains(Context::InDeclare));
+        if can_omit_body && !is!(self, '{') && eat!(self, ';') {
             return Ok(None);
         }
+        if let Some(block_stmt) = self
+            .include_in_expr(true)
+            .skip_lazy_body(true, is_simple_parameter_list)?
+        {
+            return Ok(Some(block_stmt));
+        }
+
         let block = self.include_in_expr(true).parse_block(true);
         block.map(|block_stmt| {
             if !is_simple_parameter_list {
@@ -122,6 +122,30 @@ impl<I: Tokens> Parser<I> {
             }
         }
 
+        // `<T>(x: T) => x`
+        if self.input.syntax().flow() && is!(self, '<') && peeked_is!(self, IdentName) {
+            let ctx = self.ctx() & !Context::WillExpectColonForCond;
+            let res = self.with_ctx(ctx).try_parse_flow(|p| {
+                let type_parameters = p.parse_flow_type_params()?;
+                let mut arrow = p.parse_assignment_expr_base()?;
+                match *arrow {
+                    Expr::Arrow(ArrowExpr {
+                        ref mut span,
+                        ref mut type_params,
+                        ..
+                    }) => {
+                        *span = Span::new(type_parameters.span.lo, span.hi);
+                        *type_params = Some(type_parameters);
+                    }
+                    _ => unexpected!(p, "("),
+                }
+                Ok(Some(arrow))
+            });
+            if let Some(res) = res {
+                return Ok(res);
+            }
+        }
+
         if self.ctx().contains(Context::InGenerator) && is!(self, "yield") {
             return self.parse_yield_expr();
         }
@@ -252,6 +276,36 @@ impl<I: Tokens> Parser<I> {
             .map(|s| s == start)
             .unwrap_or(false);
 
+        if let Some(expr) = self.try_parse_match_expr()? {
+            return Ok(expr);
+        }
+
+        if self.input.syntax().pipeline_operator() && is!(self, '%') {
+            self.input.bump();
+            // `%` is an operand, so `/` after it is a division.
+            self.input.set_expr_allowed(false);
+
+            let span = span!(self, start);
+            match &mut self.state.topic {
+                Some(used) => *used = true,
+                None => self.emit_err(span, SyntaxError::PipelineTopicOutsideBody),
+            }
+            return Ok(TopicExpr { span }.into());
+        }
+
+        if self.input.syntax().do_expressions() && is!(self, "do") && peeked_is!(self, '{') {
+            self.input.bump();
+            let body = self.parse_block(false)?;
+            // `do { }` is an operand, so `/` after it is a division.
+            self.input.set_expr_allowed(false);
+
+            return Ok(DoExpr {
+                span: span!(self, start),
+                body,
+            }
+            .into());
+        }
+
         if let Some(tok) = self.input.cur() {
             match tok {
                 tok!("this") => {
@@ -835,6 +889,20 @@ impl<I: Tokens> Parser<I> {
 
                 Ok(Some(return_type))
             })
+        } else if !self.ctx().contains(Context::WillExpectColonForCond)
+            && self.input.syntax().flow()
+            && is!(self, ':')
+        {
+            self.try_parse_flow(|p| {
+                let return_type = p.parse_flow_return_type(true)?;
+
+                if !is!(p, "=>") {
+                    unexpected!(p, "fail")
+                }
+
+                Ok(Some(return_type))
+            })
+            .flatten()
         } else {
             None
         };
@@ -1725,7 +1793,7 @@ impl<I: Tokens> Parser<I> {
             let pat_start = cur_pos!(self);
 
             let mut arg = {
-                if self.input.syntax().typescript()
+                if self.input.syntax().type_annotations()
                     && (is!(self, IdentRef) || (is!(self, "...") && peeked_is!(self, IdentRef)))
                 {
                     let spread = if eat!(self, "...") {
@@ -1754,7 +1822,7 @@ impl<I: Tokens> Parser<I> {
                 }
             };
 
-            let optional = if self.input.syntax().typescript() {
+            let optional = if self.input.syntax().type_annotations() {
                 if is!(self, '?') {
                     if peeked_is!(self, ',')
                         || peeked_is!(self, ':')
@@ -1857,7 +1925,7 @@ impl<I: Tokens> Parser<I> {
                         ref mut span,
                         ..
                     }) => {
-                        let new_type_ann = self.try_parse_ts_type_ann()?;
+                        let new_type_ann = self.try_parse_type_ann()?;
                         if new_type_ann.is_some() {
                             *span = Span::new(pat_start, self.input.prev_span().hi);
                         }
@@ -1889,6 +1957,8 @@ impl<I: Tokens> Parser<I> {
                 }
 
                 items.push(AssignTargetOrSpread::Pat(pat))
+            } else if self.input.syntax().flow() && is!(self, ':') {
+                items.push(self.parse_flow_paren_item_type(arg, pat_start)?);
             } else {
                 if has_modifier {
                     self.emit_err(span!(self, modifier_start), SyntaxError::TS2369);
@@ -1971,6 +2041,8 @@ impl<I: Tokens> Parser<I> {
             syntax_error!(self, self.input.prev_span(), SyntaxError::YieldParamInGen)
         }
 
+        self.record_restricted_production();
+
         if is!(self, ';')
             || (!is!(self, '<')
                 && !is!(self, '*')
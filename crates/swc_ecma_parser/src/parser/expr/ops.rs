@@ -32,7 +32,49 @@ impl<I: Tokens> Parser<I> {
         };
 
         return_if_arrow!(self, left);
-        self.parse_bin_op_recursively(left, 0)
+        let expr = self.parse_bin_op_recursively(left, 0)?;
+
+        if self.input.syntax().pipeline_operator() && is!(self, "|>") {
+            return self.parse_pipeline_expr(expr);
+        }
+
+        Ok(expr)
+    }
+
+    /// Parses `|> body` after `left`.
+    ///
+    /// `a |> f(%) |> g(%)` is parsed as `a |> (f(%) |> g(%))`, which is
+    /// equivalent because `%` in the left side of the inner pipeline refers to
+    /// the topic of the outer one.
+    fn parse_pipeline_expr(&mut self, left: Box<Expr>) -> PResult<Box<Expr>> {
+        trace_cur!(self, parse_pipeline_expr);
+
+        let start = left.span_lo();
+        assert_and_bump!(self, "|>");
+        let body_start = cur_pos!(self);
+
+        let outer_topic = self.state.topic.replace(false);
+        let right = self.parse_assignment_expr();
+        let topic_used = std::mem::replace(&mut self.state.topic, outer_topic);
+        let right = right?;
+
+        if matches!(
+            *right,
+            Expr::Arrow(..) | Expr::Assign(..) | Expr::Cond(..) | Expr::Yield(..)
+        ) && right.span_lo() == body_start
+        {
+            self.emit_err(right.span(), SyntaxError::PipelineBodyNotParenthesized);
+        }
+        if topic_used != Some(true) {
+            self.emit_err(right.span(), SyntaxError::PipelineTopicUnused);
+        }
+
+        Ok(PipelineExpr {
+            span: span!(self, start),
+            left,
+            right,
+        }
+        .into())
     }
 
     /// Parse binary operators with the operator precedence parsing
@@ -119,6 +161,16 @@ impl<I: Tokens> Parser<I> {
             && !self.input.had_line_break_before_cur()
             && is!(self, "satisfies")
         {
+            if !self.syntax().supports_ts_version(TsVersion::SATISFIES) {
+                self.emit_err(
+                    self.input.cur_span(),
+                    SyntaxError::TsVersionRequired {
+                        syntax: "'satisfies' expression",
+                        version: TsVersion::SATISFIES,
+                    },
+                );
+            }
+
             let start = left.span_lo();
             let expr = left;
             let node = {
@@ -346,6 +398,9 @@ impl<I: Tokens> Parser<I> {
 
         // Line terminator isn't allowed here.
         if self.input.had_line_break_before_cur() {
+            if is_one_of!(self, "++", "--") {
+                self.record_restricted_production();
+            }
             return Ok(expr);
         }
 
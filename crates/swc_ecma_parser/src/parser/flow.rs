@@ -0,0 +1,1222 @@
+//! Flow types are parsed into the typescript nodes, so they can be stripped by
+//! the same passes as typescript types.
+
+use swc_common::Spanned;
+
+use super::{expr::AssignTargetOrSpread, pat::PatType, *};
+
+impl<I: Tokens> Parser<I> {
+    /// Returns `true` if the current token is a contextual keyword of flow,
+    /// like `opaque`.
+    fn is_flow_word(&mut self, word: &str) -> bool {
+        matches!(self.input.cur(), Some(Token::Word(Word::Ident(i))) if i.as_ref() == word)
+    }
+
+    /// Returns `true` if the current token is `%` and the next one is
+    /// `checks`.
+    fn is_flow_checks(&mut self) -> bool {
+        is!(self, '%')
+            && matches!(
+                peek!(self),
+                Some(Token::Word(Word::Ident(i))) if i.as_ref() == "checks"
+            )
+    }
+
+    /// Same as [Parser::try_parse_ts], but for flow.
+    pub(super) fn try_parse_flow<T, F>(&mut self, op: F) -> Option<T>
+    where
+        F: FnOnce(&mut Self) -> PResult<Option<T>>,
+    {
+        if !self.input.syntax().flow() {
+            return None;
+        }
+        trace_cur!(self, try_parse_flow);
+
+        let prev_ignore_error = self.input.get_ctx().contains(Context::IgnoreError);
+        let checkpoint = self.checkpoint();
+        self.set_ctx(self.ctx() | Context::IgnoreError);
+        match op(self) {
+            Ok(Some(res)) => {
+                let mut ctx = self.ctx();
+                ctx.set(Context::IgnoreError, prev_ignore_error);
+                self.input.set_ctx(ctx);
+
+                Some(res)
+            }
+            Ok(None) | Err(..) => {
+                self.rewind(checkpoint);
+
+                None
+            }
+        }
+    }
+
+    /// `flowParseTypeAnnotation`
+    pub(super) fn parse_flow_type_ann(
+        &mut self,
+        eat_colon: bool,
+        start: BytePos,
+    ) -> PResult<Box<TsTypeAnn>> {
+        debug_assert!(self.input.syntax().flow());
+
+        self.in_type().parse_with(|p| {
+            if eat_colon {
+                assert_and_bump!(p, ':');
+            }
+
+            let type_ann = p.parse_flow_type()?;
+
+            Ok(Box::new(TsTypeAnn {
+                span: span!(p, start),
+                type_ann,
+            }))
+        })
+    }
+
+    pub(super) fn try_parse_flow_type_ann(&mut self) -> PResult<Option<Box<TsTypeAnn>>> {
+        if is!(self, ':') {
+            let start = cur_pos!(self);
+            return self.parse_flow_type_ann(true, start).map(Some);
+        }
+
+        Ok(None)
+    }
+
+    /// Parses `: T`, `: %checks` or `: T %checks` after the parameters of a
+    /// function.
+    ///
+    /// The predicate is not a part of the AST, so it's dropped. Function types
+    /// without parentheses are not allowed in the return type of an arrow
+    /// function, because `=>` starts the body.
+    pub(super) fn parse_flow_return_type(
+        &mut self,
+        is_arrow: bool,
+    ) -> PResult<Option<Box<TsTypeAnn>>> {
+        debug_assert!(self.input.syntax().flow());
+
+        self.in_type().parse_with(|p| {
+            let start = cur_pos!(p);
+            expect!(p, ':');
+
+            let type_ann = if p.is_flow_checks() {
+                None
+            } else {
+                let type_ann = p.parse_flow_union_type(is_arrow)?;
+                Some(Box::new(TsTypeAnn {
+                    span: span!(p, start),
+                    type_ann,
+                }))
+            };
+
+            if p.is_flow_checks() {
+                assert_and_bump!(p, '%');
+                let _ = cur!(p, true);
+                bump!(p); // 'checks'
+
+                // `declare function f(x: mixed): boolean %checks(typeof x === "string");`
+                if is!(p, '(') {
+                    let ctx = p.ctx() & !Context::InType;
+                    p.with_ctx(ctx).parse_with(|p| {
+                        expect!(p, '(');
+                        p.include_in_expr(true).parse_expr()?;
+                        expect!(p, ')');
+                        Ok(())
+                    })?;
+                }
+            }
+
+            Ok(type_ann)
+        })
+    }
+
+    pub(super) fn try_parse_flow_type_params(&mut self) -> PResult<Option<Box<TsTypeParamDecl>>> {
+        if is!(self, '<') {
+            return self.parse_flow_type_params().map(Some);
+        }
+
+        Ok(None)
+    }
+
+    /// `flowParseTypeParameterDeclaration`
+    pub(super) fn parse_flow_type_params(&mut self) -> PResult<Box<TsTypeParamDecl>> {
+        self.in_type().parse_with(|p| {
+            let start = cur_pos!(p);
+
+            if !is!(p, '<') && !is!(p, JSXTagStart) {
+                unexpected!(p, "< (jsx tag start)")
+            }
+            bump!(p); // '<'
+
+            let mut params = Vec::new();
+            while !eof!(p) && !is!(p, '>') {
+                params.push(p.parse_flow_type_param()?);
+
+                if !is!(p, '>') {
+                    expect!(p, ',');
+                }
+            }
+            expect!(p, '>');
+
+            Ok(Box::new(TsTypeParamDecl {
+                span: span!(p, start),
+                params,
+            }))
+        })
+    }
+
+    /// `flowParseTypeParameter`
+    ///
+    /// Covariant parameters (`+T`) are marked as `out` and contravariant ones
+    /// (`-T`) as `in`.
+    fn parse_flow_type_param(&mut self) -> PResult<TsTypeParam> {
+        let start = cur_pos!(self);
+
+        let (is_in, is_out) = if eat!(self, '+') {
+            (false, true)
+        } else if eat!(self, '-') {
+            (true, false)
+        } else {
+            (false, false)
+        };
+
+        let name = self.parse_ident_name()?.into();
+        let constraint = if eat!(self, ':') {
+            Some(self.parse_flow_type()?)
+        } else {
+            None
+        };
+        let default = if eat!(self, '=') {
+            Some(self.parse_flow_type()?)
+        } else {
+            None
+        };
+
+        Ok(TsTypeParam {
+            span: span!(self, start),
+            name,
+            is_in,
+            is_out,
+            is_const: false,
+            constraint,
+            default,
+        })
+    }
+
+    /// `flowParseTypeParameterInstantiation`
+    pub(super) fn parse_flow_type_args(&mut self) -> PResult<Box<TsTypeParamInstantiation>> {
+        self.in_type().parse_with(|p| {
+            let start = cur_pos!(p);
+            expect!(p, '<');
+
+            let mut params = Vec::new();
+            while !eof!(p) && !is!(p, '>') {
+                params.push(p.parse_flow_type()?);
+
+                if !is!(p, '>') {
+                    expect!(p, ',');
+                }
+            }
+            expect!(p, '>');
+
+            Ok(Box::new(TsTypeParamInstantiation {
+                span: span!(p, start),
+                params,
+            }))
+        })
+    }
+
+    /// `flowParseType`
+    fn parse_flow_type(&mut self) -> PResult<Box<TsType>> {
+        debug_assert!(self.ctx().contains(Context::InType));
+
+        self.parse_flow_union_type(false)
+    }
+
+    /// `flowParseUnionType`
+    fn parse_flow_union_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        eat!(self, '|');
+
+        let ty = self.parse_flow_intersection_type(no_anon_fn)?;
+        // `|}` closes an exact object type.
+        if !is!(self, '|') || peeked_is!(self, '}') {
+            return Ok(ty);
+        }
+
+        let mut types = vec![ty];
+        while is!(self, '|') && !peeked_is!(self, '}') {
+            bump!(self);
+            types.push(self.parse_flow_intersection_type(no_anon_fn)?);
+        }
+
+        Ok(Box::new(
+            TsUnionType {
+                span: span!(self, start),
+                types,
+            }
+            .into(),
+        ))
+    }
+
+    /// `flowParseIntersectionType`
+    fn parse_flow_intersection_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        eat!(self, '&');
+
+        let ty = self.parse_flow_anon_fn_type(no_anon_fn)?;
+        if !is!(self, '&') {
+            return Ok(ty);
+        }
+
+        let mut types = vec![ty];
+        while eat!(self, '&') {
+            types.push(self.parse_flow_anon_fn_type(no_anon_fn)?);
+        }
+
+        Ok(Box::new(
+            TsIntersectionType {
+                span: span!(self, start),
+                types,
+            }
+            .into(),
+        ))
+    }
+
+    /// `flowParseAnonFunctionWithoutParens`
+    ///
+    /// Parses function types with a single unnamed parameter, like
+    /// `string => void`.
+    fn parse_flow_anon_fn_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        let ty = self.parse_flow_prefix_type(no_anon_fn)?;
+
+        if !no_anon_fn && is!(self, "=>") {
+            let params = vec![unnamed_flow_fn_param(ty, 0).into()];
+            return self.parse_flow_fn_type_return(start, None, params);
+        }
+
+        Ok(ty)
+    }
+
+    /// `flowParsePrefixType`
+    ///
+    /// Maybe types (`?T`) are represented as `T | null | undefined`.
+    fn parse_flow_prefix_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+
+        if eat!(self, '?') {
+            let question_span = self.input.prev_span();
+            let ty = self.parse_flow_prefix_type(no_anon_fn)?;
+            let keyword = |kind| {
+                Box::new(TsType::TsKeywordType(TsKeywordType {
+                    span: question_span,
+                    kind,
+                }))
+            };
+
+            return Ok(Box::new(
+                TsUnionType {
+                    span: span!(self, start),
+                    types: vec![
+                        ty,
+                        keyword(TsKeywordTypeKind::TsNullKeyword),
+                        keyword(TsKeywordTypeKind::TsUndefinedKeyword),
+                    ],
+                }
+                .into(),
+            ));
+        }
+
+        self.parse_flow_postfix_type(no_anon_fn)
+    }
+
+    /// `flowParsePostfixType`
+    fn parse_flow_postfix_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        let mut ty = self.parse_flow_primary_type(no_anon_fn)?;
+
+        while is!(self, '[') && !self.input.had_line_break_before_cur() {
+            bump!(self);
+
+            ty = if eat!(self, ']') {
+                Box::new(
+                    TsArrayType {
+                        span: span!(self, start),
+                        elem_type: ty,
+                    }
+                    .into(),
+                )
+            } else {
+                let index_type = self.parse_flow_type()?;
+                expect!(self, ']');
+
+                Box::new(
+                    TsIndexedAccessType {
+                        span: span!(self, start),
+                        readonly: false,
+                        obj_type: ty,
+                        index_type,
+                    }
+                    .into(),
+                )
+            };
+        }
+
+        Ok(ty)
+    }
+
+    /// `flowParsePrimaryType`
+    fn parse_flow_primary_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+
+        let _ = cur!(self, true);
+        let is_qualified = peeked_is!(self, '.');
+        let keyword = match self.input.cur() {
+            Some(Token::Word(Word::Ident(i))) if !is_qualified => match i.as_ref() {
+                "any" => Some(TsKeywordTypeKind::TsAnyKeyword),
+                "mixed" => Some(TsKeywordTypeKind::TsUnknownKeyword),
+                "empty" => Some(TsKeywordTypeKind::TsNeverKeyword),
+                "number" => Some(TsKeywordTypeKind::TsNumberKeyword),
+                "string" => Some(TsKeywordTypeKind::TsStringKeyword),
+                "boolean" | "bool" => Some(TsKeywordTypeKind::TsBooleanKeyword),
+                "symbol" => Some(TsKeywordTypeKind::TsSymbolKeyword),
+                "bigint" => Some(TsKeywordTypeKind::TsBigIntKeyword),
+                _ => None,
+            },
+            Some(tok!("void")) => Some(TsKeywordTypeKind::TsVoidKeyword),
+            Some(tok!("null")) => Some(TsKeywordTypeKind::TsNullKeyword),
+            // Existential type
+            Some(tok!('*')) => Some(TsKeywordTypeKind::TsAnyKeyword),
+            _ => None,
+        };
+        if let Some(kind) = keyword {
+            bump!(self);
+
+            return Ok(Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: span!(self, start),
+                kind,
+            })));
+        }
+
+        match *cur!(self, true) {
+            tok!('{') => return self.parse_flow_object_type(),
+
+            tok!('[') => return self.parse_flow_tuple_type(),
+
+            tok!('<') => {
+                let type_params = self.parse_flow_type_params()?;
+                expect!(self, '(');
+                let params = self.parse_flow_fn_type_params(None)?;
+                expect!(self, ')');
+
+                return self.parse_flow_fn_type_return(start, Some(type_params), params);
+            }
+
+            tok!('(') => return self.parse_flow_paren_or_fn_type(no_anon_fn),
+
+            Token::Str { .. }
+            | Token::Num { .. }
+            | Token::BigInt { .. }
+            | tok!("true")
+            | tok!("false") => {
+                let lit = match self.parse_lit()? {
+                    Lit::Str(s) => TsLit::Str(s),
+                    Lit::Num(n) => TsLit::Number(n),
+                    Lit::BigInt(n) => TsLit::BigInt(n),
+                    Lit::Bool(b) => TsLit::Bool(b),
+                    _ => unreachable!(),
+                };
+
+                return Ok(Box::new(TsType::TsLitType(TsLitType {
+                    span: span!(self, start),
+                    lit,
+                })));
+            }
+
+            tok!('-') => {
+                bump!(self);
+
+                if !is_one_of!(self, Num, BigInt) {
+                    unexpected!(self, "numeric literal or bigint literal")
+                }
+
+                let lit = match self.parse_lit()? {
+                    Lit::Num(Number { span, value, raw }) => TsLit::Number(Number {
+                        span,
+                        value: -value,
+                        raw: raw.map(|raw| format!("-{}", raw).into()),
+                    }),
+                    Lit::BigInt(BigInt { span, value, raw }) => TsLit::BigInt(BigInt {
+                        span,
+                        value: Box::new(-*value),
+                        raw: raw.map(|raw| format!("-{}", raw).into()),
+                    }),
+                    _ => unreachable!(),
+                };
+
+                return Ok(Box::new(TsType::TsLitType(TsLitType {
+                    span: span!(self, start),
+                    lit,
+                })));
+            }
+
+            tok!("typeof") => {
+                bump!(self);
+                let expr_name = self.parse_flow_entity_name()?;
+
+                return Ok(Box::new(TsType::TsTypeQuery(TsTypeQuery {
+                    span: span!(self, start),
+                    expr_name: TsTypeQueryExpr::TsEntityName(expr_name),
+                    type_args: None,
+                })));
+            }
+
+            tok!("this") => {
+                bump!(self);
+
+                return Ok(Box::new(TsType::TsThisType(TsThisType {
+                    span: span!(self, start),
+                })));
+            }
+
+            Token::Word(Word::Ident(..)) => {
+                let type_name = self.parse_flow_entity_name()?;
+                let type_params = if is!(self, '<') {
+                    Some(self.parse_flow_type_args()?)
+                } else {
+                    None
+                };
+
+                return Ok(Box::new(TsType::TsTypeRef(TsTypeRef {
+                    span: span!(self, start),
+                    type_name,
+                    type_params,
+                })));
+            }
+
+            _ => {}
+        }
+
+        unexpected!(self, "a type")
+    }
+
+    /// Parses `A` or `A.B.C`.
+    fn parse_flow_entity_name(&mut self) -> PResult<TsEntityName> {
+        let start = cur_pos!(self);
+        let mut entity = TsEntityName::Ident(self.parse_ident_name()?.into());
+
+        while eat!(self, '.') {
+            let right = self.parse_ident_name()?;
+            entity = TsEntityName::TsQualifiedName(Box::new(TsQualifiedName {
+                span: span!(self, start),
+                left: entity,
+                right,
+            }));
+        }
+
+        Ok(entity)
+    }
+
+    /// `flowParseTupleType`
+    fn parse_flow_tuple_type(&mut self) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        expect!(self, '[');
+
+        let mut elem_types = Vec::new();
+        while !eof!(self) && !is!(self, ']') {
+            let elem_start = cur_pos!(self);
+            let ty = self.parse_flow_type()?;
+            elem_types.push(TsTupleElement {
+                span: span!(self, elem_start),
+                label: None,
+                ty,
+            });
+
+            if !is!(self, ']') {
+                expect!(self, ',');
+            }
+        }
+        expect!(self, ']');
+
+        Ok(Box::new(TsType::TsTupleType(TsTupleType {
+            span: span!(self, start),
+            elem_types,
+        })))
+    }
+
+    /// Parses a parenthesized type or a function type.
+    ///
+    /// If `no_anon_fn` is `true`, `(A) => B` is not parsed as a function type,
+    /// because it's the return type of an arrow function.
+    fn parse_flow_paren_or_fn_type(&mut self, no_anon_fn: bool) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        expect!(self, '(');
+
+        let is_grouped_type = if is_one_of!(self, ')', "...") {
+            false
+        } else if is!(self, IdentName) {
+            !peeked_is!(self, ':') && !peeked_is!(self, '?')
+        } else {
+            true
+        };
+
+        let first = if is_grouped_type {
+            let ty = self.parse_flow_type()?;
+
+            if no_anon_fn || !(is!(self, ',') || (is!(self, ')') && peeked_is!(self, "=>"))) {
+                expect!(self, ')');
+
+                return Ok(Box::new(TsType::TsParenthesizedType(TsParenthesizedType {
+                    span: span!(self, start),
+                    type_ann: ty,
+                })));
+            }
+            eat!(self, ',');
+
+            Some(ty)
+        } else {
+            None
+        };
+
+        let params = self.parse_flow_fn_type_params(first)?;
+        expect!(self, ')');
+
+        self.parse_flow_fn_type_return(start, None, params)
+    }
+
+    /// `flowParseFunctionTypeParams`
+    ///
+    /// Parses parameters of a function type until `)`. `first` is the type of
+    /// the first parameter if it's already parsed.
+    fn parse_flow_fn_type_params(&mut self, first: Option<Box<TsType>>) -> PResult<Vec<TsFnParam>> {
+        let mut params: Vec<TsFnParam> = first
+            .map(|ty| unnamed_flow_fn_param(ty, 0).into())
+            .into_iter()
+            .collect();
+
+        while !eof!(self) && !is!(self, ')') {
+            let start = cur_pos!(self);
+            let dot3_token = if eat!(self, "...") {
+                Some(self.input.prev_span())
+            } else {
+                None
+            };
+
+            let param = if is!(self, IdentName) && (peeked_is!(self, ':') || peeked_is!(self, '?'))
+            {
+                let mut id: Ident = self.parse_ident_name()?.into();
+                id.optional = eat!(self, '?');
+                let type_ann = self.expect_flow_type_ann()?;
+
+                BindingIdent {
+                    id,
+                    type_ann: Some(type_ann),
+                }
+            } else {
+                let ty = self.parse_flow_type()?;
+                unnamed_flow_fn_param(ty, params.len())
+            };
+
+            params.push(match dot3_token {
+                Some(dot3_token) => {
+                    let BindingIdent { id, type_ann } = param;
+                    TsFnParam::Rest(RestPat {
+                        span: span!(self, start),
+                        dot3_token,
+                        arg: Box::new(id.into()),
+                        type_ann,
+                    })
+                }
+                None => param.into(),
+            });
+
+            if !is!(self, ')') {
+                expect!(self, ',');
+            }
+        }
+
+        Ok(params)
+    }
+
+    /// Parses `=> R` of a function type.
+    fn parse_flow_fn_type_return(
+        &mut self,
+        start: BytePos,
+        type_params: Option<Box<TsTypeParamDecl>>,
+        params: Vec<TsFnParam>,
+    ) -> PResult<Box<TsType>> {
+        let arrow_start = cur_pos!(self);
+        expect!(self, "=>");
+        let type_ann = self.parse_flow_type()?;
+
+        Ok(Box::new(
+            TsFnType {
+                span: span!(self, start),
+                params,
+                type_params,
+                type_ann: Box::new(TsTypeAnn {
+                    span: span!(self, arrow_start),
+                    type_ann,
+                }),
+            }
+            .into(),
+        ))
+    }
+
+    /// Parses `: T` in a type context.
+    fn expect_flow_type_ann(&mut self) -> PResult<Box<TsTypeAnn>> {
+        let start = cur_pos!(self);
+        expect!(self, ':');
+        let type_ann = self.parse_flow_type()?;
+
+        Ok(Box::new(TsTypeAnn {
+            span: span!(self, start),
+            type_ann,
+        }))
+    }
+
+    /// `flowParseObjectType`
+    ///
+    /// Spread properties are represented as an intersection, so
+    /// `{ ...A, b: B }` becomes `A & { b: B }`.
+    fn parse_flow_object_type(&mut self) -> PResult<Box<TsType>> {
+        let start = cur_pos!(self);
+        let (members, mut types) = self.parse_flow_object_type_members(true)?;
+        let span = span!(self, start);
+
+        if !members.is_empty() || types.is_empty() {
+            types.push(Box::new(TsType::TsTypeLit(TsTypeLit { span, members })));
+        }
+        if types.len() == 1 {
+            return Ok(types.remove(0));
+        }
+
+        Ok(Box::new(TsIntersectionType { span, types }.into()))
+    }
+
+    /// Parses `{ ... }` or `{| ... |}`, and returns the members and the types
+    /// of spread properties.
+    fn parse_flow_object_type_members(
+        &mut self,
+        allow_spread: bool,
+    ) -> PResult<(Vec<TsTypeElement>, Vec<Box<TsType>>)> {
+        expect!(self, '{');
+
+        let mut members = Vec::new();
+        let mut spreads = Vec::new();
+
+        // `{||}`
+        if eat!(self, "||") {
+            expect!(self, '}');
+            return Ok((members, spreads));
+        }
+        let exact = eat!(self, '|');
+
+        loop {
+            if exact && eat!(self, '|') {
+                break;
+            }
+            if eof!(self) || (!exact && is!(self, '}')) {
+                break;
+            }
+
+            if allow_spread && eat!(self, "...") {
+                spreads.push(self.parse_flow_type()?);
+            } else {
+                members.push(self.parse_flow_object_type_member()?);
+            }
+
+            if !is!(self, '}') && !(exact && is!(self, '|')) && !eat!(self, ',') {
+                expect!(self, ';');
+            }
+        }
+        expect!(self, '}');
+
+        Ok((members, spreads))
+    }
+
+    /// Parses a property, a method, an indexer or a call property of an object
+    /// type.
+    ///
+    /// Covariant properties (`+p: T`) are marked as `readonly`.
+    fn parse_flow_object_type_member(&mut self) -> PResult<TsTypeElement> {
+        let start = cur_pos!(self);
+
+        let readonly = if eat!(self, '+') {
+            true
+        } else {
+            eat!(self, '-');
+            false
+        };
+
+        // `[K]: V` or `[k: K]: V`
+        if eat!(self, '[') {
+            let name = if is!(self, IdentName) && peeked_is!(self, ':') {
+                let name = self.parse_ident_name()?;
+                assert_and_bump!(self, ':');
+                Some(name)
+            } else {
+                None
+            };
+            let key = self.parse_flow_type()?;
+            expect!(self, ']');
+            let type_ann = self.expect_flow_type_ann()?;
+
+            let key_span = key.span();
+            let id = match name {
+                Some(name) => name.into(),
+                None => Ident::new_no_ctxt("key".into(), key_span),
+            };
+
+            return Ok(TsIndexSignature {
+                span: span!(self, start),
+                params: vec![BindingIdent {
+                    id,
+                    type_ann: Some(Box::new(TsTypeAnn {
+                        span: key_span,
+                        type_ann: key,
+                    })),
+                }
+                .into()],
+                type_ann: Some(type_ann),
+                readonly,
+                is_static: false,
+            }
+            .into());
+        }
+
+        // Call property
+        if is_one_of!(self, '(', '<') {
+            let (type_params, params, type_ann) = self.parse_flow_method_signature()?;
+
+            return Ok(TsCallSignatureDecl {
+                span: span!(self, start),
+                params,
+                type_ann: Some(type_ann),
+                type_params,
+            }
+            .into());
+        }
+
+        let key: Box<Expr> = if is_one_of!(self, Str, Num) {
+            Box::new(self.parse_lit()?.into())
+        } else {
+            Box::new(Ident::from(self.parse_ident_name()?).into())
+        };
+
+        if is_one_of!(self, '(', '<') {
+            let (type_params, params, type_ann) = self.parse_flow_method_signature()?;
+
+            return Ok(TsMethodSignature {
+                span: span!(self, start),
+                key,
+                computed: false,
+                optional: false,
+                params,
+                type_ann: Some(type_ann),
+                type_params,
+            }
+            .into());
+        }
+
+        let optional = eat!(self, '?');
+        let type_ann = self.expect_flow_type_ann()?;
+
+        Ok(TsPropertySignature {
+            span: span!(self, start),
+            readonly,
+            key,
+            computed: false,
+            optional,
+            type_ann: Some(type_ann),
+        }
+        .into())
+    }
+
+    /// Parses `<T>(a: A): R` of a method or a call property.
+    #[allow(clippy::type_complexity)]
+    fn parse_flow_method_signature(
+        &mut self,
+    ) -> PResult<(Option<Box<TsTypeParamDecl>>, Vec<TsFnParam>, Box<TsTypeAnn>)> {
+        let type_params = self.try_parse_flow_type_params()?;
+        expect!(self, '(');
+        let params = self.parse_flow_fn_type_params(None)?;
+        expect!(self, ')');
+        let type_ann = self.expect_flow_type_ann()?;
+
+        Ok((type_params, params, type_ann))
+    }
+
+    /// Parses `A, B.C<T>` after `extends` of an interface or `implements` of a
+    /// class.
+    pub(super) fn parse_flow_heritage_clause(&mut self) -> PResult<Vec<TsExprWithTypeArgs>> {
+        let mut types = Vec::new();
+
+        loop {
+            let start = cur_pos!(self);
+            let mut expr: Box<Expr> = Box::new(Ident::from(self.parse_ident_name()?).into());
+            while eat!(self, '.') {
+                let prop = self.parse_ident_name()?;
+                expr = Box::new(
+                    MemberExpr {
+                        span: span!(self, start),
+                        obj: expr,
+                        prop: MemberProp::Ident(prop),
+                    }
+                    .into(),
+                );
+            }
+
+            let type_args = if is!(self, '<') {
+                Some(self.parse_flow_type_args()?)
+            } else {
+                None
+            };
+
+            types.push(TsExprWithTypeArgs {
+                span: span!(self, start),
+                expr,
+                type_args,
+            });
+
+            if !eat!(self, ',') {
+                break;
+            }
+        }
+
+        Ok(types)
+    }
+
+    /// `flowParseTypeAlias`
+    pub(super) fn parse_flow_type_alias(
+        &mut self,
+        start: BytePos,
+    ) -> PResult<Box<TsTypeAliasDecl>> {
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_flow_type_params()?;
+        let type_ann = self.in_type().parse_with(|p| {
+            expect!(p, '=');
+            p.parse_flow_type()
+        })?;
+        expect!(self, ';');
+
+        Ok(Box::new(TsTypeAliasDecl {
+            span: span!(self, start),
+            declare: false,
+            id: id.into(),
+            type_params,
+            type_ann,
+        }))
+    }
+
+    /// `flowParseOpaqueType`
+    ///
+    /// The supertype is dropped, unless the opaque type is declared without
+    /// the right-hand side. Such types are aliased to their supertype, or to
+    /// `unknown` if there's none.
+    fn parse_flow_opaque_type(
+        &mut self,
+        start: BytePos,
+        declare: bool,
+    ) -> PResult<Box<TsTypeAliasDecl>> {
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_flow_type_params()?;
+
+        let (supertype, type_ann) = self.in_type().parse_with(|p| {
+            let supertype = if eat!(p, ':') {
+                Some(p.parse_flow_type()?)
+            } else {
+                None
+            };
+            let type_ann = if !declare || is!(p, '=') {
+                expect!(p, '=');
+                Some(p.parse_flow_type()?)
+            } else {
+                None
+            };
+
+            Ok((supertype, type_ann))
+        })?;
+        expect!(self, ';');
+
+        let type_ann = type_ann.or(supertype).unwrap_or_else(|| {
+            Box::new(TsType::TsKeywordType(TsKeywordType {
+                span: id.span,
+                kind: TsKeywordTypeKind::TsUnknownKeyword,
+            }))
+        });
+
+        Ok(Box::new(TsTypeAliasDecl {
+            span: span!(self, start),
+            declare,
+            id: id.into(),
+            type_params,
+            type_ann,
+        }))
+    }
+
+    /// `flowParseInterface`
+    pub(super) fn parse_flow_interface(&mut self, start: BytePos) -> PResult<Box<TsInterfaceDecl>> {
+        let id = self.parse_ident_name()?;
+        let type_params = self.try_parse_flow_type_params()?;
+        let extends = if eat!(self, "extends") {
+            self.parse_flow_heritage_clause()?
+        } else {
+            Vec::new()
+        };
+
+        let body_start = cur_pos!(self);
+        let (body, _) = self
+            .in_type()
+            .parse_with(|p| p.parse_flow_object_type_members(false))?;
+
+        Ok(Box::new(TsInterfaceDecl {
+            span: span!(self, start),
+            id: id.into(),
+            declare: false,
+            type_params,
+            extends,
+            body: TsInterfaceBody {
+                span: span!(self, body_start),
+                body,
+            },
+        }))
+    }
+
+    /// Parses declarations which start with a contextual keyword, like
+    /// `opaque type T = string;` or `declare var x: T;`.
+    pub(super) fn parse_flow_expr_stmt(&mut self, expr: Ident) -> PResult<Option<Decl>> {
+        let start = expr.span_lo();
+        if self.input.had_line_break_before_cur() {
+            return Ok(None);
+        }
+
+        match &*expr.sym {
+            "opaque" if is!(self, "type") => {
+                bump!(self);
+                self.parse_flow_opaque_type(start, false)
+                    .map(|decl| Some(decl.into()))
+            }
+            "declare" => self.parse_flow_declare(start),
+            _ => Ok(None),
+        }
+    }
+
+    /// `flowParseDeclare`
+    fn parse_flow_declare(&mut self, start: BytePos) -> PResult<Option<Decl>> {
+        let ctx = self.ctx() | Context::InDeclare;
+
+        self.with_ctx(ctx).parse_with(|p| {
+            let decl = if is!(p, "function") {
+                p.parse_fn_decl(Vec::new())?
+            } else if is!(p, "class") {
+                p.parse_class_decl(start, start, Vec::new(), false)?
+            } else if is_one_of!(p, "var", "let", "const") {
+                p.parse_var_stmt(false)?.into()
+            } else if is!(p, "type") && peeked_is!(p, IdentRef) {
+                bump!(p);
+                p.parse_flow_type_alias(start)?.into()
+            } else if p.is_flow_word("opaque") && peeked_is!(p, "type") {
+                bump!(p);
+                assert_and_bump!(p, "type");
+                p.parse_flow_opaque_type(start, true)?.into()
+            } else if is!(p, "interface") && peeked_is!(p, IdentRef) {
+                bump!(p);
+                p.parse_flow_interface(start)?.into()
+            } else if p.is_flow_word("module") && peeked_is!(p, Str) {
+                bump!(p);
+                p.parse_flow_declare_module(start)?.into()
+            } else {
+                return Ok(None);
+            };
+
+            Ok(Some(make_flow_decl_declare(decl, start)))
+        })
+    }
+
+    /// `flowParseDeclareModule`
+    fn parse_flow_declare_module(&mut self, start: BytePos) -> PResult<Box<TsModuleDecl>> {
+        let id = match self.parse_lit()? {
+            Lit::Str(s) => TsModuleName::Str(s),
+            _ => unreachable!(),
+        };
+
+        let body_start = cur_pos!(self);
+        expect!(self, '{');
+        let body = self
+            .with_ctx(self.ctx() | Context::TopLevel)
+            .parse_block_body(false, Some(&tok!('}')))?;
+
+        Ok(Box::new(TsModuleDecl {
+            span: span!(self, start),
+            declare: true,
+            global: false,
+            namespace: false,
+            id,
+            body: Some(TsNamespaceBody::TsModuleBlock(TsModuleBlock {
+                span: span!(self, body_start),
+                body,
+            })),
+        }))
+    }
+
+    /// Parses declarations of types after `export`, like
+    /// `export type A = B;`.
+    pub(super) fn try_parse_flow_export_decl(&mut self) -> PResult<Option<Decl>> {
+        let start = cur_pos!(self);
+        let declare = self.ctx().contains(Context::InDeclare);
+
+        if is!(self, "type") && peeked_is!(self, IdentRef) {
+            bump!(self);
+            return self
+                .parse_flow_type_alias(start)
+                .map(|decl| Some(decl.into()));
+        }
+
+        if self.is_flow_word("opaque") && peeked_is!(self, "type") {
+            bump!(self);
+            assert_and_bump!(self, "type");
+            return self
+                .parse_flow_opaque_type(start, declare)
+                .map(|decl| Some(decl.into()));
+        }
+
+        if is!(self, "interface") && peeked_is!(self, IdentRef) {
+            bump!(self);
+            return self
+                .parse_flow_interface(start)
+                .map(|decl| Some(decl.into()));
+        }
+
+        Ok(None)
+    }
+
+    /// Parses `: T` after an item in parentheses.
+    ///
+    /// It's a type cast like `(x: T)` unless the parentheses turn out to be the
+    /// parameters of an arrow function, in which case
+    /// [Parser::parse_paren_items_as_params] converts it to a parameter.
+    pub(super) fn parse_flow_paren_item_type(
+        &mut self,
+        arg: ExprOrSpread,
+        start: BytePos,
+    ) -> PResult<AssignTargetOrSpread> {
+        let type_ann_start = cur_pos!(self);
+        let type_ann = self.parse_flow_type_ann(true, type_ann_start)?;
+
+        // `(x: T = 1) => {}`
+        if eat!(self, '=') {
+            let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, arg.expr)?;
+            set_flow_pat_type_ann(&mut pat, type_ann);
+            let right = self.parse_assignment_expr()?;
+
+            return Ok(AssignTargetOrSpread::Pat(
+                AssignPat {
+                    span: span!(self, start),
+                    left: Box::new(pat),
+                    right,
+                }
+                .into(),
+            ));
+        }
+
+        Ok(AssignTargetOrSpread::ExprOrSpread(ExprOrSpread {
+            spread: arg.spread,
+            expr: Box::new(
+                TsAsExpr {
+                    span: span!(self, start),
+                    expr: arg.expr,
+                    type_ann: type_ann.type_ann,
+                }
+                .into(),
+            ),
+        }))
+    }
+
+    /// Converts a type cast parsed by [Parser::parse_flow_paren_item_type] to
+    /// a parameter of an arrow function.
+    pub(super) fn flow_paren_item_to_param(
+        &mut self,
+        item: AssignTargetOrSpread,
+    ) -> PResult<AssignTargetOrSpread> {
+        let (spread, expr) = match item {
+            AssignTargetOrSpread::ExprOrSpread(ExprOrSpread { spread, expr })
+                if expr.is_ts_as() =>
+            {
+                (spread, expr)
+            }
+            _ => return Ok(item),
+        };
+        let TsAsExpr {
+            span,
+            expr,
+            type_ann,
+        } = expr.expect_ts_as();
+
+        let type_ann = Box::new(TsTypeAnn {
+            span: Span::new(expr.span_hi(), span.hi),
+            type_ann,
+        });
+        let mut pat = self.reparse_expr_as_pat(PatType::BindingPat, expr)?;
+
+        Ok(AssignTargetOrSpread::Pat(match spread {
+            Some(dot3_token) => RestPat {
+                span: Span::new(dot3_token.lo, span.hi),
+                dot3_token,
+                arg: Box::new(pat),
+                type_ann: Some(type_ann),
+            }
+            .into(),
+            None => {
+                set_flow_pat_type_ann(&mut pat, type_ann);
+                pat
+            }
+        }))
+    }
+}
+
+/// Creates a parameter of a function type without a name, like `string` in
+/// `(string) => void`. Such parameters are named after their index.
+fn unnamed_flow_fn_param(ty: Box<TsType>, index: usize) -> BindingIdent {
+    let span = ty.span();
+
+    BindingIdent {
+        id: Ident::new_no_ctxt(format!("_{}", index).into(), span),
+        type_ann: Some(Box::new(TsTypeAnn { span, type_ann: ty })),
+    }
+}
+
+fn set_flow_pat_type_ann(pat: &mut Pat, new_type_ann: Box<TsTypeAnn>) {
+    match pat {
+        Pat::Ident(BindingIdent { type_ann, .. })
+        | Pat::Array(ArrayPat { type_ann, .. })
+        | Pat::Object(ObjectPat { type_ann, .. }) => *type_ann = Some(new_type_ann),
+        _ => {}
+    }
+}
+
+/// Marks a declaration as `declare`, and extends it to include `declare`.
+pub(super) fn make_flow_decl_declare(mut decl: Decl, start: BytePos) -> Decl {
+    match &mut decl {
+        Decl::Class(ClassDecl { class, declare, .. }) => {
+            *declare = true;
+            class.span = Span::new(start, class.span.hi);
+        }
+        Decl::Fn(FnDecl {
+            function, declare, ..
+        }) => {
+            *declare = true;
+            function.span = Span::new(start, function.span.hi);
+        }
+        Decl::Var(var) => {
+            var.declare = true;
+            var.span = Span::new(start, var.span.hi);
+        }
+        Decl::TsInterface(decl) => decl.declare = true,
+        Decl::TsTypeAlias(decl) => decl.declare = true,
+        Decl::TsModule(decl) => decl.declare = true,
+        _ => {}
+    }
+
+    decl
+}
@@ -0,0 +1,222 @@
+use super::*;
+
+impl<I: Tokens> Parser<I> {
+    /// Parses the whole input as a JSON value.
+    ///
+    /// Literals are validated more strictly than in javascript, so
+    /// single-quoted strings, hexadecimal numbers, trailing commas and such are
+    /// reported as errors.
+    ///
+    /// Note: Comments are skipped by the lexer, so they are not reported by
+    /// this method. Use [crate::parse_file_as_json] to reject them.
+    pub fn parse_json(&mut self) -> PResult<Box<Expr>> {
+        self.parse_json_document(false)
+    }
+
+    /// Same as [Parser::parse_json], but trailing commas are allowed, like in
+    /// `tsconfig.json`.
+    pub fn parse_jsonc(&mut self) -> PResult<Box<Expr>> {
+        self.parse_json_document(true)
+    }
+
+    fn parse_json_document(&mut self, allow_trailing_comma: bool) -> PResult<Box<Expr>> {
+        let value = self.parse_json_value(allow_trailing_comma)?;
+
+        if !eof!(self) {
+            unexpected!(self, "eof")
+        }
+
+        Ok(value)
+    }
+
+    fn parse_json_value(&mut self, allow_trailing_comma: bool) -> PResult<Box<Expr>> {
+        let start = cur_pos!(self);
+
+        match *cur!(self, true) {
+            tok!('{') => self.parse_json_object(allow_trailing_comma),
+            tok!('[') => self.parse_json_array(allow_trailing_comma),
+            Token::Str { .. } => Ok(Lit::Str(self.parse_json_str()?).into()),
+            Token::Num { .. } => Ok(self.parse_json_num()?.into()),
+            tok!('-') => {
+                bump!(self);
+                if !is!(self, Num) {
+                    unexpected!(self, "a number")
+                }
+                let arg = self.parse_json_num()?.into();
+
+                Ok(UnaryExpr {
+                    span: span!(self, start),
+                    op: op!(unary, "-"),
+                    arg,
+                }
+                .into())
+            }
+            tok!("true") | tok!("false") | tok!("null") => Ok(self.parse_lit()?.into()),
+            _ => unexpected!(self, "a JSON value"),
+        }
+    }
+
+    fn parse_json_object(&mut self, allow_trailing_comma: bool) -> PResult<Box<Expr>> {
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '{');
+
+        let mut props = Vec::new();
+        while !eat!(self, '}') {
+            if !is!(self, Str) {
+                unexpected!(self, "a string")
+            }
+            let key = self.parse_json_str()?;
+            expect!(self, ':');
+            let value = self.parse_json_value(allow_trailing_comma)?;
+
+            props.push(PropOrSpread::Prop(Box::new(Prop::KeyValue(KeyValueProp {
+                key: PropName::Str(key),
+                value,
+            }))));
+
+            if !eat!(self, ',') {
+                expect!(self, '}');
+                break;
+            }
+            if !allow_trailing_comma && is!(self, '}') {
+                self.emit_json_trailing_comma_err();
+            }
+        }
+
+        Ok(ObjectLit {
+            span: span!(self, start),
+            props,
+        }
+        .into())
+    }
+
+    fn parse_json_array(&mut self, allow_trailing_comma: bool) -> PResult<Box<Expr>> {
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '[');
+
+        let mut elems = Vec::new();
+        while !eat!(self, ']') {
+            let expr = self.parse_json_value(allow_trailing_comma)?;
+            elems.push(Some(ExprOrSpread { spread: None, expr }));
+
+            if !eat!(self, ',') {
+                expect!(self, ']');
+                break;
+            }
+            if !allow_trailing_comma && is!(self, ']') {
+                self.emit_json_trailing_comma_err();
+            }
+        }
+
+        Ok(ArrayLit {
+            span: span!(self, start),
+            elems,
+        }
+        .into())
+    }
+
+    fn parse_json_str(&mut self) -> PResult<Str> {
+        let s = match self.parse_lit()? {
+            Lit::Str(s) => s,
+            _ => unreachable!(),
+        };
+
+        if !s.raw.as_deref().is_some_and(is_valid_json_str) {
+            self.emit_err(s.span, SyntaxError::JsonInvalidString);
+        }
+
+        Ok(s)
+    }
+
+    fn parse_json_num(&mut self) -> PResult<Lit> {
+        let lit = self.parse_lit()?;
+
+        if let Lit::Num(Number { span, raw, .. }) = &lit {
+            if !raw.as_deref().is_some_and(is_valid_json_num) {
+                self.emit_err(*span, SyntaxError::JsonInvalidNumber);
+            }
+        }
+
+        Ok(lit)
+    }
+
+    /// Expects the previous token to be the trailing comma.
+    fn emit_json_trailing_comma_err(&mut self) {
+        let span = self.input.prev_span();
+
+        self.emit_error(
+            Error::new(span, SyntaxError::JsonTrailingComma).with_suggestion(
+                span,
+                "",
+                "remove the comma",
+            ),
+        );
+    }
+}
+
+/// Returns `true` if `raw` is a string literal of JSON, which is double-quoted
+/// and only contains the escape sequences of JSON.
+fn is_valid_json_str(raw: &str) -> bool {
+    let Some(body) = raw.strip_prefix('"').and_then(|raw| raw.strip_suffix('"')) else {
+        return false;
+    };
+
+    let mut chars = body.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some('"' | '\\' | '/' | 'b' | 'f' | 'n' | 'r' | 't') => {}
+                Some('u') => {
+                    if !(0..4).all(|_| chars.next().is_some_and(|c| c.is_ascii_hexdigit())) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            },
+            '\0'..='\u{1f}' => return false,
+            _ => {}
+        }
+    }
+
+    true
+}
+
+/// Returns `true` if `raw` is a number literal of JSON, without the sign.
+fn is_valid_json_num(raw: &str) -> bool {
+    let bytes = raw.as_bytes();
+    let mut i = 0;
+    let eat_digits = |i: &mut usize| {
+        let start = *i;
+        while bytes.get(*i).is_some_and(u8::is_ascii_digit) {
+            *i += 1;
+        }
+        *i > start
+    };
+
+    match bytes.first() {
+        Some(b'0') => i += 1,
+        Some(b'1'..=b'9') => {
+            eat_digits(&mut i);
+        }
+        _ => return false,
+    }
+
+    if bytes.get(i) == Some(&b'.') {
+        i += 1;
+        if !eat_digits(&mut i) {
+            return false;
+        }
+    }
+
+    if matches!(bytes.get(i), Some(b'e' | b'E')) {
+        i += 1;
+        if matches!(bytes.get(i), Some(b'+' | b'-')) {
+            i += 1;
+        }
+        if !eat_digits(&mut i) {
+            return false;
+        }
+    }
+
+    i == bytes.len()
+}
@@ -0,0 +1,308 @@
+use rustc_hash::FxHashMap;
+use swc_common::comments::SingleThreadedComments;
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+use super::{class_and_fn::has_use_strict, *};
+use crate::token::Keyword;
+
+/// A function body which is skipped by [Parser::parse_module_lazy].
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LazyBody {
+    span: Span,
+    /// Context of the parser at `{`.
+    ctx: Context,
+    allow_directives: bool,
+    is_simple_parameter_list: bool,
+}
+
+/// A module whose function bodies are parsed on demand.
+///
+/// See [Parser::parse_module_lazy].
+pub struct LazyModule<'a> {
+    /// The module, where deferred function bodies are empty.
+    pub module: Module,
+    syntax: Syntax,
+    target: EsVersion,
+    src: &'a str,
+    start_pos: BytePos,
+    bodies: Vec<LazyBody>,
+}
+
+impl<'a> Parser<Lexer<'a>> {
+    /// Parses `input` as a module without parsing the bodies of functions.
+    ///
+    /// The tokens of function bodies are only skipped, and the bodies are
+    /// replaced by empty blocks with the same span. Use
+    /// [LazyModule::parse_body] to parse the body of a function on demand,
+    /// or [LazyModule::into_module] to parse all of them in parallel.
+    ///
+    /// JSX is always parsed eagerly, as its tokens can't be skipped without
+    /// parsing.
+    pub fn parse_module_lazy(
+        syntax: Syntax,
+        target: EsVersion,
+        input: StringInput<'a>,
+        comments: Option<&'a dyn Comments>,
+        recovered_errors: &mut Vec<Error>,
+    ) -> PResult<LazyModule<'a>> {
+        let start_pos = input.start_pos();
+        let src = input.as_str();
+
+        let lazy = if syntax.jsx() {
+            None
+        } else {
+            // Comments are buffered, because the input is parsed again if
+            // skipping fails.
+            let lazy_comments = comments.map(|_| SingleThreadedComments::default());
+
+            let mut p = Parser::new_from(Lexer::new(
+                syntax,
+                target,
+                input.clone(),
+                lazy_comments.as_ref().map(|c| c as &dyn Comments),
+            ));
+            p.state.lazy_bodies = Some(Default::default());
+            let module = p.parse_module();
+
+            match module {
+                Ok(module) if p.input().take_errors().is_empty() => {
+                    Some((module, p.state.lazy_bodies.take(), lazy_comments))
+                }
+                _ => None,
+            }
+        };
+
+        // Skipping a function body may fail because a `/` is not tokenized as
+        // the parser would do, so the input is parsed eagerly instead.
+        let Some((module, bodies, lazy_comments)) = lazy else {
+            return Ok(LazyModule {
+                module: Self::parse_full(syntax, target, input, comments, recovered_errors)?,
+                syntax,
+                target,
+                src,
+                start_pos,
+                bodies: Default::default(),
+            });
+        };
+
+        if let (Some(comments), Some(lazy_comments)) = (comments, lazy_comments) {
+            let (leading, trailing) = lazy_comments.take_all();
+            for (pos, cmts) in leading.take() {
+                comments.add_leading_comments(pos, cmts);
+            }
+            for (pos, cmts) in trailing.take() {
+                comments.add_trailing_comments(pos, cmts);
+            }
+        }
+
+        let mut bodies = bodies.unwrap_or_default();
+        // A function may be parsed more than once while looking ahead.
+        bodies.sort_by_key(|body| body.span.lo);
+        bodies.dedup_by_key(|body| body.span);
+
+        Ok(LazyModule {
+            module,
+            syntax,
+            target,
+            src,
+            start_pos,
+            bodies,
+        })
+    }
+}
+
+impl<I: Tokens> Parser<I> {
+    /// Skips the function body at `{` if [Parser::parse_module_lazy] is used.
+    pub(super) fn skip_lazy_body(
+        &mut self,
+        allow_directives: bool,
+        is_simple_parameter_list: bool,
+    ) -> PResult<Option<BlockStmt>> {
+        if self.state.lazy_bodies.is_none() || !is!(self, '{') {
+            return Ok(None);
+        }
+
+        let ctx = self.ctx();
+        let start = cur_pos!(self);
+
+        let mut depth = 0usize;
+        // Whether the previous token ends an expression, which makes `/` a
+        // division instead of a regular expression.
+        let mut after_expr = false;
+        loop {
+            let token_start = cur_pos!(self);
+            match cur!(self, true) {
+                tok!('{') | Token::DollarLBrace => depth += 1,
+                tok!('}') => depth -= 1,
+                tok!('/') | tok!("/=") if !after_expr => {
+                    bump!(self);
+
+                    self.input.set_next_regexp(Some(token_start));
+                    if let Some(Token::Regex(..)) = self.input.cur() {
+                        self.input.set_next_regexp(None);
+                        bump!(self);
+                        after_expr = true;
+                        continue;
+                    }
+                    self.input.set_next_regexp(None);
+
+                    unexpected!(self, "regexp")
+                }
+                _ => {}
+            }
+
+            after_expr = matches!(
+                cur!(self, true),
+                Token::Word(
+                    Word::Ident(..)
+                        | Word::Null
+                        | Word::True
+                        | Word::False
+                        | Word::Keyword(Keyword::This | Keyword::Super)
+                ) | Token::Num { .. }
+                    | Token::BigInt { .. }
+                    | Token::Str { .. }
+                    | Token::Regex(..)
+                    | tok!(')')
+                    | tok!(']')
+                    | tok!('`')
+                    | tok!("++")
+                    | tok!("--")
+            );
+            bump!(self);
+
+            if depth == 0 {
+                break;
+            }
+        }
+
+        let span = span!(self, start);
+        if let Some(bodies) = &mut self.state.lazy_bodies {
+            bodies.push(LazyBody {
+                span,
+                ctx,
+                allow_directives,
+                is_simple_parameter_list,
+            });
+        }
+
+        Ok(Some(BlockStmt {
+            span,
+            ..Default::default()
+        }))
+    }
+}
+
+impl LazyModule<'_> {
+    /// Spans of the function bodies which are not parsed yet.
+    pub fn deferred_bodies(&self) -> impl '_ + Iterator<Item = Span> {
+        self.bodies.iter().map(|body| body.span)
+    }
+
+    /// Parses the function body with `span`.
+    ///
+    /// Panics if `span` is not one of [LazyModule::deferred_bodies].
+    pub fn parse_body(&self, span: Span, recovered_errors: &mut Vec<Error>) -> PResult<BlockStmt> {
+        let body = self
+            .bodies
+            .binary_search_by_key(&span.lo, |body| body.span.lo)
+            .ok()
+            .map(|idx| self.bodies[idx])
+            .filter(|body| body.span == span)
+            .unwrap_or_else(|| panic!("{:?} is not a deferred function body", span));
+
+        self.parse(body, recovered_errors)
+    }
+
+    /// Parses all deferred function bodies in parallel, and returns the
+    /// complete module.
+    pub fn into_module(self, recovered_errors: &mut Vec<Error>) -> PResult<Module> {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(self.bodies.len());
+
+        let results = if threads <= 1 {
+            vec![self.parse_all(&self.bodies)]
+        } else {
+            let chunk_size = self.bodies.len().div_ceil(threads);
+            std::thread::scope(|s| {
+                let handles = self
+                    .bodies
+                    .chunks(chunk_size)
+                    .map(|chunk| s.spawn(|| self.parse_all(chunk)))
+                    .collect::<Vec<_>>();
+
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect()
+            })
+        };
+
+        let mut parsed = FxHashMap::default();
+        for result in results {
+            let (blocks, mut errors) = result?;
+            recovered_errors.append(&mut errors);
+            parsed.extend(blocks.into_iter().map(|block| (block.span, block)));
+        }
+
+        let mut module = self.module;
+        module.visit_mut_with(&mut BodyFiller { parsed });
+
+        Ok(module)
+    }
+
+    fn parse_all(&self, bodies: &[LazyBody]) -> PResult<(Vec<BlockStmt>, Vec<Error>)> {
+        let mut errors = Vec::new();
+        let blocks = bodies
+            .iter()
+            .map(|&body| self.parse(body, &mut errors))
+            .collect::<PResult<_>>()?;
+
+        Ok((blocks, errors))
+    }
+
+    fn parse(&self, body: LazyBody, recovered_errors: &mut Vec<Error>) -> PResult<BlockStmt> {
+        let lo = (body.span.lo.0 - self.start_pos.0) as usize;
+        let hi = (body.span.hi.0 - self.start_pos.0) as usize;
+
+        let mut p = Parser::new_from(Lexer::new(
+            self.syntax,
+            self.target,
+            StringInput::new(&self.src[lo..hi], body.span.lo, body.span.hi),
+            None,
+        ));
+        p.set_ctx(body.ctx);
+
+        let block = p.parse_block(body.allow_directives);
+        if let Ok(block) = &block {
+            if !body.is_simple_parameter_list {
+                if let Some(span) = has_use_strict(block) {
+                    p.emit_err(span, SyntaxError::IllegalLanguageModeDirective);
+                }
+            }
+        }
+        recovered_errors.append(&mut p.take_errors());
+
+        block
+    }
+}
+
+/// Puts parsed bodies into the empty blocks created by
+/// [Parser::skip_lazy_body].
+struct BodyFiller {
+    parsed: FxHashMap<Span, BlockStmt>,
+}
+
+impl VisitMut for BodyFiller {
+    fn visit_mut_block_stmt(&mut self, block: &mut BlockStmt) {
+        if block.stmts.is_empty() {
+            if let Some(parsed) = self.parsed.remove(&block.span) {
+                *block = parsed;
+                return;
+            }
+        }
+
+        block.visit_mut_children_with(self);
+    }
+}
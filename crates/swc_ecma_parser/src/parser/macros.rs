@@ -176,8 +176,22 @@ macro_rules! eat {
                 $p.input.bump();
                 true
             }
-            None | Some(&tok!('}')) => true,
-            _ => $p.input.had_line_break_before_cur(),
+            None => {
+                $p.record_asi($crate::parser::AsiKind::Eof);
+                true
+            }
+            Some(&tok!('}')) => {
+                $p.record_asi($crate::parser::AsiKind::CloseBrace);
+                true
+            }
+            _ => {
+                if $p.input.had_line_break_before_cur() {
+                    $p.record_asi($crate::parser::AsiKind::LineBreak);
+                    true
+                } else {
+                    false
+                }
+            }
         }
     }};
 
@@ -11,12 +11,17 @@ pub use swc_ecma_lexer::input::{Capturing, Tokens, TokensInput};
 use swc_ecma_lexer::{
     error::SyntaxError,
     input::Buffer,
-    token::{Token, Word},
+    token::{Token, TokenAndSpan, Word},
     Lexer, *,
 };
 
 use self::util::ParseObject;
-use crate::{Context, EsVersion, Syntax, TsSyntax};
+pub use self::{
+    asi::{AsiDecision, AsiKind},
+    lazy::LazyModule,
+    reparse::Edit,
+};
+use crate::{Context, EsVersion, Syntax, TsSyntax, TsVersion};
 #[cfg(test)]
 extern crate test;
 #[cfg(test)]
@@ -26,13 +31,19 @@ use crate::error::Error;
 
 #[macro_use]
 mod macros;
+mod asi;
 mod class_and_fn;
 mod expr;
+mod flow;
 mod ident;
 pub mod input;
+mod json;
 mod jsx;
+mod lazy;
 mod object;
 mod pat;
+mod pattern_matching;
+mod reparse;
 mod stmt;
 #[cfg(test)]
 mod tests;
@@ -58,6 +69,15 @@ struct State {
     potential_arrow_start: Option<BytePos>,
     /// Start position of an AST node and the span of its trailing comma.
     trailing_commas: FxHashMap<BytePos, Span>,
+    /// [Some] if [Parser::record_asi_decisions] is called.
+    asi_decisions: Option<Vec<AsiDecision>>,
+    /// `true` if [Parser::enable_recovery] is called.
+    recovery: bool,
+    /// [Some] if function bodies are skipped by [Parser::parse_module_lazy].
+    lazy_bodies: Option<Vec<lazy::LazyBody>>,
+    /// [Some] while parsing the body of a pipeline, with `true` if the topic
+    /// reference is used.
+    topic: Option<bool>,
 }
 
 impl<'a> Parser<Lexer<'a>> {
@@ -86,6 +106,17 @@ impl<I: Tokens> Parser<I> {
         }
     }
 
+    /// Makes the parser recover from errors in statements instead of
+    /// returning the first fatal error.
+    ///
+    /// The tokens of a statement which can't be parsed are skipped and
+    /// replaced with an expression statement of [Expr::Invalid], and the error
+    /// is reported by [Parser::take_errors]. This is meant for tools like
+    /// editors which need an ast even for files with syntax errors.
+    pub fn enable_recovery(&mut self) {
+        self.state.recovery = true;
+    }
+
     pub fn take_errors(&mut self) -> Vec<Error> {
         self.input().take_errors()
     }
@@ -94,6 +125,14 @@ impl<I: Tokens> Parser<I> {
         self.input().take_script_module_errors()
     }
 
+    /// Takes directive comments of TypeScript, like `// @ts-ignore` and
+    /// triple-slash references, which are found while parsing.
+    ///
+    /// They are recorded even if comments are not collected.
+    pub fn take_ts_directives(&mut self) -> Vec<TsDirective> {
+        self.input().take_ts_directives()
+    }
+
     pub fn parse_script(&mut self) -> PResult<Script> {
         trace_cur!(self, parse_script);
 
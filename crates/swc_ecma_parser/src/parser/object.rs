@@ -201,7 +201,7 @@ impl<I: Tokens> ParseObject<Expr> for Parser<I> {
         }
 
         // Handle `a(){}` (and async(){} / get(){} / set(){})
-        if (self.input.syntax().typescript() && is!(self, '<')) || is!(self, '(') {
+        if (self.input.syntax().type_annotations() && is!(self, '<')) || is!(self, '(') {
             return self
                 .with_ctx((self.ctx() | Context::AllowDirectSuper) & !Context::InClassField)
                 .parse_fn_args_body(
@@ -191,7 +191,7 @@ impl<I: Tokens> Parser<I> {
         let mut pat = self.parse_binding_element()?;
         let mut opt = false;
 
-        if self.input.syntax().typescript() {
+        if self.input.syntax().type_annotations() {
             if eat!(self, '?') {
                 match pat {
                     Pat::Ident(BindingIdent {
@@ -237,7 +237,7 @@ impl<I: Tokens> Parser<I> {
                     ref mut span,
                     ..
                 }) => {
-                    let new_type_ann = self.try_parse_ts_type_ann()?;
+                    let new_type_ann = self.try_parse_type_ann()?;
                     if new_type_ann.is_some() {
                         *span = Span::new(pat_start, self.input.prev_span().hi);
                     }
@@ -247,12 +247,12 @@ impl<I: Tokens> Parser<I> {
                 Pat::Ident(BindingIdent {
                     ref mut type_ann, ..
                 }) => {
-                    let new_type_ann = self.try_parse_ts_type_ann()?;
+                    let new_type_ann = self.try_parse_type_ann()?;
                     *type_ann = new_type_ann;
                 }
 
                 Pat::Assign(AssignPat { ref mut span, .. }) => {
-                    if (self.try_parse_ts_type_ann()?).is_some() {
+                    if (self.try_parse_type_ann()?).is_some() {
                         *span = Span::new(pat_start, self.input.prev_span().hi);
                         self.emit_err(*span, SyntaxError::TSTypeAnnotationAfterAssign);
                     }
@@ -310,9 +310,9 @@ impl<I: Tokens> Parser<I> {
                 let dot3_token = span!(self, pat_start);
 
                 let pat = self.parse_binding_pat_or_ident(false)?;
-                let type_ann = if self.input.syntax().typescript() && is!(self, ':') {
+                let type_ann = if self.input.syntax().type_annotations() && is!(self, ':') {
                     let cur_pos = cur_pos!(self);
-                    Some(self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?)
+                    Some(self.parse_type_ann(/* eat_colon */ true, cur_pos)?)
                 } else {
                     None
                 };
@@ -435,9 +435,9 @@ impl<I: Tokens> Parser<I> {
                     .into();
                 }
 
-                let type_ann = if self.input.syntax().typescript() && is!(self, ':') {
+                let type_ann = if self.input.syntax().type_annotations() && is!(self, ':') {
                     let cur_pos = cur_pos!(self);
-                    let ty = self.parse_ts_type_ann(/* eat_colon */ true, cur_pos)?;
+                    let ty = self.parse_type_ann(/* eat_colon */ true, cur_pos)?;
                     Some(ty)
                 } else {
                     None
@@ -828,6 +828,13 @@ impl<I: Tokens> Parser<I> {
     ) -> PResult<Vec<Pat>> {
         let pat_ty = PatType::BindingPat;
 
+        if self.input.syntax().flow() {
+            exprs = exprs
+                .into_iter()
+                .map(|expr| self.flow_paren_item_to_param(expr))
+                .collect::<PResult<_>>()?;
+        }
+
         let len = exprs.len();
         if len == 0 {
             return Ok(Vec::new());
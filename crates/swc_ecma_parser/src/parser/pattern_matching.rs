@@ -0,0 +1,323 @@
+//! Parser for the `match` expression of the pattern matching proposal.
+
+use swc_common::Spanned;
+
+use super::*;
+
+impl<I: Tokens> Parser<I> {
+    /// Returns `true` if the current token is a contextual keyword of pattern
+    /// matching, like `when`.
+    fn is_match_word(&mut self, word: &str) -> bool {
+        matches!(self.input.cur(), Some(Token::Word(Word::Ident(i))) if i.as_ref() == word)
+    }
+
+    /// Parses `match (subject) { ... }` if the current token starts it.
+    ///
+    /// `match (a)` is a call unless it's followed by `{` on the same line, so
+    /// the subject is parsed speculatively first.
+    pub(super) fn try_parse_match_expr(&mut self) -> PResult<Option<Box<Expr>>> {
+        if !self.input.syntax().explicit_proposals()
+            || !self.is_match_word("match")
+            || !peeked_is!(self, '(')
+            || self.input.has_linebreak_between_cur_and_peeked()
+        {
+            return Ok(None);
+        }
+
+        let ctx = self.ctx();
+        let checkpoint = self.checkpoint();
+        self.set_ctx(ctx | Context::IgnoreError);
+        let is_match_expr = self.parse_match_subject().is_ok()
+            && is!(self, '{')
+            && !self.input.had_line_break_before_cur();
+        self.rewind(checkpoint);
+        self.set_ctx(ctx);
+
+        if !is_match_expr {
+            return Ok(None);
+        }
+
+        let start = cur_pos!(self);
+        let subject = self.parse_match_subject()?;
+
+        expect!(self, '{');
+        let mut cases: Vec<MatchCase> = Vec::new();
+        while !eat!(self, '}') {
+            let case = self.parse_match_case()?;
+            if let Some(prev) = cases.last().filter(|prev| prev.pat.is_none()) {
+                self.emit_err(prev.span, SyntaxError::MatchDefaultNotLast);
+            }
+            cases.push(case);
+        }
+
+        Ok(Some(
+            MatchExpr {
+                span: span!(self, start),
+                subject,
+                cases,
+            }
+            .into(),
+        ))
+    }
+
+    /// Parses `match (subject)`.
+    fn parse_match_subject(&mut self) -> PResult<Box<Expr>> {
+        let _ = cur!(self, true);
+        self.input.bump();
+
+        expect!(self, '(');
+        let subject = self.include_in_expr(true).parse_expr()?;
+        expect!(self, ')');
+
+        Ok(subject)
+    }
+
+    /// Parses `when pattern if (guard): body;` or `default: body;`.
+    fn parse_match_case(&mut self) -> PResult<MatchCase> {
+        let start = cur_pos!(self);
+
+        let pat = if eat!(self, "default") {
+            None
+        } else {
+            if !self.is_match_word("when") {
+                unexpected!(self, "when or default")
+            }
+            self.input.bump();
+
+            Some(Box::new(self.parse_match_pat()?))
+        };
+
+        let guard = if pat.is_some() && eat!(self, "if") {
+            expect!(self, '(');
+            let guard = self.include_in_expr(true).parse_expr()?;
+            expect!(self, ')');
+            Some(guard)
+        } else {
+            None
+        };
+
+        expect!(self, ':');
+        let body = self.include_in_expr(true).parse_assignment_expr()?;
+        expect!(self, ';');
+
+        Ok(MatchCase {
+            span: span!(self, start),
+            pat,
+            guard,
+            body,
+        })
+    }
+
+    /// Parses a pattern, including `and` and `or` combinators.
+    ///
+    /// The combinators can't be mixed without parentheses, so `a and b or c`
+    /// is reported.
+    pub(super) fn parse_match_pat(&mut self) -> PResult<MatchPat> {
+        let start = cur_pos!(self);
+        let first = self.parse_match_pat_unary()?;
+
+        let (op, other) = if self.is_match_word("and") {
+            (MatchPatCombinator::And, MatchPatCombinator::Or)
+        } else if self.is_match_word("or") {
+            (MatchPatCombinator::Or, MatchPatCombinator::And)
+        } else {
+            return Ok(first);
+        };
+
+        let mut pats = vec![first];
+        loop {
+            if self.is_match_word(other.as_str()) {
+                let span = self.input.cur_span();
+                self.emit_err(span, SyntaxError::MatchMixedCombinators);
+            } else if !self.is_match_word(op.as_str()) {
+                break;
+            }
+            self.input.bump();
+
+            pats.push(self.parse_match_pat_unary()?);
+        }
+
+        Ok(MatchPat::Combined(CombinedMatchPat {
+            span: span!(self, start),
+            op,
+            pats,
+        }))
+    }
+
+    fn parse_match_pat_unary(&mut self) -> PResult<MatchPat> {
+        let start = cur_pos!(self);
+
+        if self.is_match_word("not") {
+            self.input.bump();
+            let arg = self.parse_match_pat_unary()?;
+
+            return Ok(MatchPat::Not(NotMatchPat {
+                span: span!(self, start),
+                arg: Box::new(arg),
+            }));
+        }
+
+        let op = match cur!(self, true) {
+            tok!('(') => {
+                bump!(self);
+                let pat = self.parse_match_pat()?;
+                expect!(self, ')');
+                return Ok(pat);
+            }
+            tok!('[') => return self.parse_array_match_pat().map(MatchPat::Array),
+            tok!('{') => return self.parse_object_match_pat().map(MatchPat::Object),
+            tok!("let") | tok!("const") | tok!("var") => {
+                return self.parse_binding_match_pat().map(MatchPat::Binding)
+            }
+
+            tok!('<') => op!("<"),
+            tok!("<=") => op!("<="),
+            tok!('>') => op!(">"),
+            tok!(">=") => op!(">="),
+            tok!("==") => op!("=="),
+            tok!("!=") => op!("!="),
+            tok!("===") => op!("==="),
+            tok!("!==") => op!("!=="),
+            tok!("instanceof") => op!("instanceof"),
+            tok!("in") => op!("in"),
+
+            _ => {
+                let value = self.parse_unary_expr()?;
+                if !is_valid_match_value(&value) {
+                    self.emit_err(value.span(), SyntaxError::MatchInvalidValuePattern);
+                }
+
+                return Ok(MatchPat::Value(ValueMatchPat {
+                    span: span!(self, start),
+                    value,
+                }));
+            }
+        };
+
+        bump!(self);
+        let value = self.parse_unary_expr()?;
+
+        Ok(MatchPat::Relational(RelationalMatchPat {
+            span: span!(self, start),
+            op,
+            value,
+        }))
+    }
+
+    /// Parses `let x`, `const x` or `var x`.
+    fn parse_binding_match_pat(&mut self) -> PResult<BindingMatchPat> {
+        let start = cur_pos!(self);
+
+        let kind = match bump!(self) {
+            tok!("let") => VarDeclKind::Let,
+            tok!("const") => VarDeclKind::Const,
+            tok!("var") => VarDeclKind::Var,
+            _ => unreachable!(),
+        };
+        let id = self.parse_binding_ident(kind != VarDeclKind::Var)?;
+
+        Ok(BindingMatchPat {
+            span: span!(self, start),
+            kind,
+            id,
+        })
+    }
+
+    fn parse_array_match_pat(&mut self) -> PResult<ArrayMatchPat> {
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '[');
+
+        let mut elems = Vec::new();
+        let mut rest = None;
+        while !eat!(self, ']') {
+            if is!(self, "...") {
+                let rest_start = cur_pos!(self);
+                bump!(self);
+
+                let arg = if is!(self, ']') {
+                    None
+                } else {
+                    Some(Box::new(self.parse_match_pat()?))
+                };
+                rest = Some(MatchRest {
+                    span: span!(self, rest_start),
+                    arg,
+                });
+
+                expect!(self, ']');
+                break;
+            }
+
+            elems.push(self.parse_match_pat()?);
+
+            if !eat!(self, ',') {
+                expect!(self, ']');
+                break;
+            }
+        }
+
+        Ok(ArrayMatchPat {
+            span: span!(self, start),
+            elems,
+            rest,
+        })
+    }
+
+    fn parse_object_match_pat(&mut self) -> PResult<ObjectMatchPat> {
+        let start = cur_pos!(self);
+        assert_and_bump!(self, '{');
+
+        let mut props = Vec::new();
+        while !eat!(self, '}') {
+            let prop_start = cur_pos!(self);
+
+            let (key, value) = if is_one_of!(self, "let", "const", "var") {
+                let binding = self.parse_binding_match_pat()?;
+                let key = PropName::Ident(binding.id.id.clone().into());
+                (key, Some(Box::new(MatchPat::Binding(binding))))
+            } else {
+                let key = self.parse_prop_name()?;
+                let value = if eat!(self, ':') {
+                    Some(Box::new(self.parse_match_pat()?))
+                } else {
+                    None
+                };
+                (key, value)
+            };
+
+            props.push(ObjectMatchPatProp {
+                span: span!(self, prop_start),
+                key,
+                value,
+            });
+
+            if !eat!(self, ',') {
+                expect!(self, '}');
+                break;
+            }
+        }
+
+        Ok(ObjectMatchPat {
+            span: span!(self, start),
+            props,
+        })
+    }
+}
+
+/// Returns `true` if `e` is a literal or a reference like `a.b`.
+fn is_valid_match_value(e: &Expr) -> bool {
+    match e {
+        Expr::Lit(..) | Expr::Ident(..) | Expr::This(..) => true,
+        Expr::Tpl(tpl) => tpl.exprs.is_empty(),
+        Expr::Unary(UnaryExpr {
+            op: op!(unary, "-") | op!(unary, "+"),
+            arg,
+            ..
+        }) => matches!(&**arg, Expr::Lit(Lit::Num(..) | Lit::BigInt(..))),
+        Expr::Member(MemberExpr { obj, .. }) => {
+            matches!(&**obj, Expr::Ident(..) | Expr::This(..) | Expr::Member(..))
+                && is_valid_match_value(obj)
+        }
+        _ => false,
+    }
+}
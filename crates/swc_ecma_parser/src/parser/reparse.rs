@@ -0,0 +1,177 @@
+use swc_common::{comments::SingleThreadedComments, Spanned};
+use swc_ecma_visit::{VisitMut, VisitMutWith};
+
+use super::*;
+
+/// A change of the source text.
+///
+/// See [Parser::reparse].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Edit {
+    /// The replaced range of the old source.
+    pub span: Span,
+    /// Length of the text which replaces `span`, in bytes.
+    pub new_len: u32,
+}
+
+impl<'a> Parser<Lexer<'a>> {
+    /// Parses `input`, which is the source of `old` after applying `edits`,
+    /// reusing the module items of `old` which are not affected by the edits.
+    ///
+    /// `input` should start at the same position as the old source. Module
+    /// items before the edits are reused as-is, and the spans of module items
+    /// after the edits are shifted. Only the items around the edits are parsed
+    /// again, and the whole input is parsed if it fails.
+    ///
+    /// Comments are added to `comments` only for the parsed range, so comments
+    /// after the edits keep their old positions.
+    pub fn reparse(
+        syntax: Syntax,
+        target: EsVersion,
+        input: StringInput<'a>,
+        comments: Option<&'a dyn Comments>,
+        old: Module,
+        edits: &[Edit],
+        recovered_errors: &mut Vec<Error>,
+    ) -> PResult<Module> {
+        let (Some(lo), Some(hi)) = (
+            edits.iter().map(|edit| edit.span.lo).min(),
+            edits.iter().map(|edit| edit.span.hi).max(),
+        ) else {
+            return Ok(old);
+        };
+        let delta = edits
+            .iter()
+            .map(|edit| edit.new_len as i64 - (edit.span.hi.0 - edit.span.lo.0) as i64)
+            .sum::<i64>();
+
+        let start = input.start_pos();
+        let end = input.end_pos();
+        let src = input.as_str();
+
+        // Items touching the edits are parsed again along with their
+        // neighbors, because the edited tokens may be joined with them.
+        let prefix = old
+            .body
+            .iter()
+            .take_while(|item| item.span_hi() <= lo)
+            .count()
+            .saturating_sub(1);
+        let suffix = old
+            .body
+            .iter()
+            .rev()
+            .take_while(|item| item.span_lo() >= hi)
+            .count()
+            .saturating_sub(1);
+
+        let region_lo = match prefix {
+            0 => start,
+            _ => old.body[prefix - 1].span_hi(),
+        };
+        let region_hi = match suffix {
+            0 => end,
+            _ => shift(old.body[old.body.len() - suffix].span_lo(), delta),
+        };
+        if region_lo > region_hi || region_hi > end {
+            return Self::parse_full(syntax, target, input, comments, recovered_errors);
+        }
+
+        let region_src = &src[(region_lo.0 - start.0) as usize..(region_hi.0 - start.0) as usize];
+        let region_comments = comments.map(|_| SingleThreadedComments::default());
+
+        let region = {
+            let lexer = Lexer::new(
+                syntax,
+                target,
+                StringInput::new(region_src, region_lo, region_hi),
+                region_comments.as_ref().map(|c| c as &dyn Comments),
+            );
+            let mut p = Parser::new_from(lexer);
+            let region = p.parse_module();
+            let errors = p.take_errors();
+
+            match region {
+                Ok(region) if errors.is_empty() => region,
+                _ => return Self::parse_full(syntax, target, input, comments, recovered_errors),
+            }
+        };
+
+        if let (Some(comments), Some(region_comments)) = (comments, region_comments) {
+            let (leading, trailing) = region_comments.take_all();
+            for (pos, cmts) in leading.take() {
+                comments.add_leading_comments(pos, cmts);
+            }
+            for (pos, cmts) in trailing.take() {
+                comments.add_trailing_comments(pos, cmts);
+            }
+        }
+
+        let span = Span::new(
+            if prefix == 0 {
+                region.span.lo
+            } else {
+                old.span.lo
+            },
+            if suffix == 0 {
+                region.span.hi
+            } else {
+                shift(old.span.hi, delta)
+            },
+        );
+        let shebang = if prefix == 0 {
+            region.shebang
+        } else {
+            old.shebang
+        };
+
+        let mut body = old.body;
+        let mut tail = body.split_off(body.len() - suffix);
+        body.truncate(prefix);
+        body.extend(region.body);
+        if delta != 0 {
+            tail.visit_mut_with(&mut Shifter { delta });
+        }
+        body.extend(tail);
+
+        Ok(Module {
+            span,
+            body,
+            shebang,
+        })
+    }
+
+    pub(super) fn parse_full(
+        syntax: Syntax,
+        target: EsVersion,
+        input: StringInput<'a>,
+        comments: Option<&'a dyn Comments>,
+        recovered_errors: &mut Vec<Error>,
+    ) -> PResult<Module> {
+        let mut p = Parser::new_from(Lexer::new(syntax, target, input, comments));
+        let module = p.parse_module();
+        recovered_errors.append(&mut p.take_errors());
+
+        module
+    }
+}
+
+fn shift(pos: BytePos, delta: i64) -> BytePos {
+    BytePos((pos.0 as i64 + delta) as u32)
+}
+
+/// Moves all spans by `delta`.
+struct Shifter {
+    delta: i64,
+}
+
+impl VisitMut for Shifter {
+    fn visit_mut_span(&mut self, span: &mut Span) {
+        if span.is_dummy() {
+            return;
+        }
+
+        span.lo = shift(span.lo, self.delta);
+        span.hi = shift(span.hi, self.delta);
+    }
+}
@@ -1,4 +1,4 @@
-use swc_common::Spanned;
+use swc_common::{cancel, Spanned};
 use typed_arena::Arena;
 
 use super::{pat::PatType, *};
@@ -39,7 +39,23 @@ impl<'a, I: Tokens> Parser<I> {
                 c != end
             }
         } {
-            let stmt = self.parse_stmt_like(true)?;
+            if cancel::is_cancelled() {
+                syntax_error!(self, SyntaxError::Cancelled);
+            }
+
+            let start = cur_pos!(self);
+            let ctx = self.ctx();
+            let labels = self.state.labels.len();
+            let stmt = match self.parse_stmt_like(true) {
+                Ok(stmt) => stmt,
+                Err(err) if self.state.recovery => {
+                    self.input_ref().add_error(err);
+                    self.set_ctx(ctx);
+                    self.state.labels.truncate(labels);
+                    self.skip_invalid_stmt(start, end).into()
+                }
+                Err(err) => return Err(err),
+            };
             if allow_directives {
                 allow_directives = false;
                 if stmt.is_use_strict() {
@@ -66,6 +82,41 @@ impl<'a, I: Tokens> Parser<I> {
         Ok(stmts.into_vec())
     }
 
+    /// Skips the tokens of a statement which failed to parse, up to the next
+    /// line which doesn't continue the statement, a `;` or the end of the
+    /// block.
+    ///
+    /// At least one token is skipped so the parser always makes progress.
+    fn skip_invalid_stmt(&mut self, start: BytePos, end: Option<&'static Token>) -> Stmt {
+        let mut depth = 0usize;
+        let mut skipped = self.input.last_pos() > start;
+
+        while self.input.cur().is_some() {
+            let line_break = self.input.had_line_break_before_cur();
+            match self.input.cur().unwrap() {
+                Token::LBrace | Token::LBracket | Token::LParen => depth += 1,
+                Token::RBrace | Token::RBracket | Token::RParen if depth > 0 => depth -= 1,
+                Token::RBrace if end.is_some() && skipped => break,
+                Token::Semi if depth == 0 => {
+                    self.input.bump();
+                    break;
+                }
+                _ if depth == 0 && skipped && line_break => break,
+                _ => {}
+            }
+
+            self.input.bump();
+            skipped = true;
+        }
+
+        let span = Span::new(start, self.input.prev_span().hi.max(start));
+        ExprStmt {
+            span,
+            expr: Invalid { span }.into(),
+        }
+        .into()
+    }
+
     /// Parse a statement but not a declaration.
     pub fn parse_stmt(&mut self) -> PResult<Stmt> {
         trace_cur!(self, parse_stmt);
@@ -91,7 +142,9 @@ impl<'a, I: Tokens> Parser<I> {
         let start = cur_pos!(self);
         let decorators = self.parse_decorators(true)?;
 
-        if is_one_of!(self, "import", "export") {
+        if is_one_of!(self, "import", "export")
+            || (self.input.syntax().flow() && is!(self, "declare") && peeked_is!(self, "export"))
+        {
             return self.handle_import_export(decorators);
         }
 
@@ -152,6 +205,7 @@ impl<'a, I: Tokens> Parser<I> {
             tok!("break") | tok!("continue") => {
                 let is_break = is!(self, "break");
                 bump!(self);
+                self.record_restricted_production();
 
                 let label = if eat!(self, ';') {
                     None
@@ -315,6 +369,15 @@ impl<'a, I: Tokens> Parser<I> {
                     bump!(self);
                     return Ok(self.parse_ts_interface_decl(start)?.into());
                 }
+
+                if self.input.syntax().flow()
+                    && peeked_is!(self, IdentRef)
+                    && !self.input.has_linebreak_between_cur_and_peeked()
+                {
+                    let start = self.input.cur_pos();
+                    bump!(self);
+                    return Ok(self.parse_flow_interface(start)?.into());
+                }
             }
 
             tok!("type") => {
@@ -326,6 +389,15 @@ impl<'a, I: Tokens> Parser<I> {
                     bump!(self);
                     return Ok(self.parse_ts_type_alias_decl(start)?.into());
                 }
+
+                if self.input.syntax().flow()
+                    && peeked_is!(self, IdentRef)
+                    && !self.input.has_linebreak_between_cur_and_peeked()
+                {
+                    let start = self.input.cur_pos();
+                    bump!(self);
+                    return Ok(self.parse_flow_type_alias(start)?.into());
+                }
             }
 
             tok!("enum") => {
@@ -401,6 +473,12 @@ impl<'a, I: Tokens> Parser<I> {
                     return Ok(decl.into());
                 }
             }
+
+            if self.input.syntax().flow() {
+                if let Some(decl) = self.parse_flow_expr_stmt(ident.clone())? {
+                    return Ok(decl.into());
+                }
+            }
         }
 
         if let Expr::Ident(Ident { ref sym, span, .. }) = *expr {
@@ -568,6 +646,7 @@ impl<'a, I: Tokens> Parser<I> {
 
         let stmt = self.parse_with(|p| {
             assert_and_bump!(p, "return");
+            p.record_restricted_production();
 
             let arg = if is!(p, ';') {
                 None
@@ -793,7 +872,15 @@ impl<'a, I: Tokens> Parser<I> {
         }
 
         if !self.syntax().explicit_resource_management() {
-            self.emit_err(span!(self, start), SyntaxError::UsingDeclNotEnabled);
+            let err = if self.input.syntax().typescript() {
+                SyntaxError::TsVersionRequired {
+                    syntax: "Using declaration",
+                    version: TsVersion::USING_DECLARATIONS,
+                }
+            } else {
+                SyntaxError::UsingDeclNotEnabled
+            };
+            self.emit_err(span!(self, start), err);
         }
 
         if !self.ctx().contains(Context::AllowUsingDecl) {
@@ -943,8 +1030,8 @@ impl<'a, I: Tokens> Parser<I> {
         };
 
         // Typescript extension
-        if self.input.syntax().typescript() && is!(self, ':') {
-            let type_annotation = self.try_parse_ts_type_ann()?;
+        if self.input.syntax().type_annotations() && is!(self, ':') {
+            let type_annotation = self.try_parse_type_ann()?;
             match name {
                 Pat::Array(ArrayPat {
                     ref mut type_ann, ..
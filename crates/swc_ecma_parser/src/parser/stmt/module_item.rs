@@ -1,4 +1,5 @@
 use super::*;
+use crate::parser::flow::make_flow_decl_declare;
 
 impl<I: Tokens> Parser<I> {
     fn parse_import(&mut self) -> PResult<ModuleItem> {
@@ -49,18 +50,7 @@ impl<I: Tokens> Parser<I> {
                 }),
                 _ => unreachable!(),
             };
-            let _ = cur!(self, false);
-            let with = if self.input.syntax().import_attributes()
-                && !self.input.had_line_break_before_cur()
-                && (eat!(self, "assert") || eat!(self, "with"))
-            {
-                match self.parse_object::<Expr>()? {
-                    Expr::Object(v) => Some(Box::new(v)),
-                    _ => unreachable!(),
-                }
-            } else {
-                None
-            };
+            let with = self.parse_import_attributes()?;
             expect!(self, ';');
             return Ok(ImportDecl {
                 span: span!(self, start),
@@ -77,11 +67,16 @@ impl<I: Tokens> Parser<I> {
         let mut phase = ImportPhase::Evaluation;
         let mut specifiers = Vec::with_capacity(4);
 
+        // Flow: `import typeof A from 'mod'`
+        if self.input.syntax().flow() && eat!(self, "typeof") {
+            type_only = true;
+        }
+
         'import_maybe_ident: {
             if is!(self, BindingIdent) {
                 let mut local = self.parse_imported_default_binding()?;
 
-                if self.input.syntax().typescript() && local.sym == "type" {
+                if self.input.syntax().type_annotations() && !type_only && local.sym == "type" {
                     if is_one_of!(self, '*', '{') {
                         type_only = true;
                         break 'import_maybe_ident;
@@ -174,18 +169,7 @@ impl<I: Tokens> Parser<I> {
             }
         };
 
-        let _ = cur!(self, false);
-        let with = if self.input.syntax().import_attributes()
-            && !self.input.had_line_break_before_cur()
-            && (eat!(self, "assert") || eat!(self, "with"))
-        {
-            match self.parse_object::<Expr>()? {
-                Expr::Object(v) => Some(Box::new(v)),
-                _ => unreachable!(),
-            }
-        } else {
-            None
-        };
+        let with = self.parse_import_attributes()?;
 
         expect!(self, ';');
 
@@ -212,7 +196,11 @@ impl<I: Tokens> Parser<I> {
                 // `import { type as } from 'mod'`
                 // `import { type as as } from 'mod'`
                 // `import { type as as as } from 'mod'`
-                if self.syntax().typescript() && orig_name.sym == "type" && is!(self, IdentName) {
+                if self.syntax().type_annotations()
+                    && (orig_name.sym == "type"
+                        || (self.syntax().flow() && orig_name.sym == "typeof"))
+                    && is!(self, IdentName)
+                {
                     let possibly_orig_name = self.parse_ident_name().map(Ident::from)?;
                     if possibly_orig_name.sym == "as" {
                         // `import { type as } from 'mod'`
@@ -339,6 +327,24 @@ impl<I: Tokens> Parser<I> {
         Ok(self.with_ctx(ctx).parse_binding_ident(false)?.into())
     }
 
+    /// Parses `declare export ...` of flow.
+    fn parse_flow_declare_export(&mut self, decorators: Vec<Decorator>) -> PResult<ModuleDecl> {
+        let start = cur_pos!(self);
+        assert_and_bump!(self, "declare");
+
+        let ctx = self.ctx() | Context::InDeclare;
+        let decl = self.with_ctx(ctx).parse_export(decorators)?;
+
+        Ok(match decl {
+            ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => ExportDecl {
+                span: span!(self, start),
+                decl: make_flow_decl_declare(decl, start),
+            }
+            .into(),
+            decl => decl,
+        })
+    }
+
     fn parse_export(&mut self, mut decorators: Vec<Decorator>) -> PResult<ModuleDecl> {
         if !self.ctx().contains(Context::Module) {
             // Switch to module mode
@@ -380,6 +386,16 @@ impl<I: Tokens> Parser<I> {
             }
         }
 
+        if self.input.syntax().flow() {
+            if let Some(decl) = self.try_parse_flow_export_decl()? {
+                return Ok(ExportDecl {
+                    span: span!(self, start),
+                    decl,
+                }
+                .into());
+            }
+        }
+
         if self.input.syntax().typescript() {
             if eat!(self, "import") {
                 let is_type_only = is!(self, "type") && peeked_is!(self, IdentRef);
@@ -428,7 +444,7 @@ impl<I: Tokens> Parser<I> {
 
         let ns_export_specifier_start = cur_pos!(self);
 
-        let type_only = self.input.syntax().typescript() && eat!(self, "type");
+        let type_only = self.input.syntax().type_annotations() && eat!(self, "type");
 
         // Some("default") if default is exported from 'src'
         let mut export_default = None;
@@ -841,21 +857,49 @@ impl<I: Tokens> Parser<I> {
             },
             _ => unexpected!(self, "a string literal"),
         };
-        let _ = cur!(self, false);
-        let with = if self.input.syntax().import_attributes()
-            && !self.input.had_line_break_before_cur()
-            && (eat!(self, "assert") || eat!(self, "with"))
-        {
-            match self.parse_object::<Expr>()? {
-                Expr::Object(v) => Some(Box::new(v)),
-                _ => unreachable!(),
-            }
-        } else {
-            None
-        };
+        let with = self.parse_import_attributes()?;
         expect!(self, ';');
         Ok((src, with))
     }
+
+    /// Parses `with { type: "json" }` after the source of an import or an
+    /// export.
+    ///
+    /// The deprecated `assert` keyword is parsed like `with`, and reported if
+    /// [Syntax::warn_import_assertions] is enabled.
+    fn parse_import_attributes(&mut self) -> PResult<Option<Box<ObjectLit>>> {
+        let _ = cur!(self, false);
+        if !self.input.syntax().import_attributes() || self.input.had_line_break_before_cur() {
+            return Ok(None);
+        }
+
+        if eat!(self, "assert") {
+            self.report_import_assertion();
+        } else if !eat!(self, "with") {
+            return Ok(None);
+        }
+
+        match self.parse_object::<Expr>()? {
+            Expr::Object(v) => Ok(Some(Box::new(v))),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Expects the previous token to be `assert`.
+    pub(in crate::parser) fn report_import_assertion(&mut self) {
+        if !self.input.syntax().warn_import_assertions() {
+            return;
+        }
+        let span = self.input.prev_span();
+
+        self.emit_error(
+            Error::new(span, SyntaxError::ImportAssertionDeprecated).with_suggestion(
+                span,
+                "with",
+                "use `with` instead",
+            ),
+        );
+    }
 }
 
 impl IsDirective for ModuleItem {
@@ -877,6 +921,9 @@ impl<I: Tokens> StmtLikeParser<'_, ModuleItem> for Parser<I> {
             self.parse_import()?
         } else if is!(self, "export") {
             self.parse_export(decorators).map(ModuleItem::from)?
+        } else if self.input.syntax().flow() && is!(self, "declare") {
+            self.parse_flow_declare_export(decorators)
+                .map(ModuleItem::from)?
         } else {
             unreachable!(
                 "handle_import_export should not be called if current token isn't import nor \
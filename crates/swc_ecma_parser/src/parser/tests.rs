@@ -1,7 +1,7 @@
-use swc_common::comments::SingleThreadedComments;
+use swc_common::{comments::SingleThreadedComments, DUMMY_SP};
 
 use super::*;
-use crate::EsSyntax;
+use crate::{EsSyntax, TsSyntax};
 
 fn program(src: &'static str) -> Program {
     test_parser(src, Default::default(), |p| p.parse_program())
@@ -348,6 +348,174 @@ fn parse_non_strict_for_loop() {
     script("for (var v1 = 1 in v3) {}");
 }
 
+#[test]
+fn parse_program_take_ts_directives() {
+    let src = r#"/// <reference types="node" />
+/// <reference path='./a.d.ts'/>
+// @ts-nocheck
+// @ts-ignore
+let a: number = "";
+const f = (
+    // @ts-expect-error: not a number
+    b: number = "",
+) => b;
+/* @ts-ignore */ f();
+// @ts-ignored
+/// <reference foo="bar" />
+"#;
+
+    test_parser(src, Syntax::Typescript(Default::default()), |p| {
+        let program = p.parse_program()?;
+
+        let directives = p
+            .take_ts_directives()
+            .into_iter()
+            .map(|d| (d.kind, d.arg.as_deref().map(str::to_string)))
+            .collect::<Vec<_>>();
+
+        // The arrow function is parsed after backtracking, but the directive in it
+        // is recorded once.
+        assert_eq!(
+            directives,
+            vec![
+                (TsDirectiveKind::ReferenceTypes, Some("node".into())),
+                (TsDirectiveKind::ReferencePath, Some("./a.d.ts".into())),
+                (TsDirectiveKind::NoCheck, None),
+                (TsDirectiveKind::Ignore, None),
+                (TsDirectiveKind::ExpectError, Some(": not a number".into())),
+                (TsDirectiveKind::Ignore, None),
+            ]
+        );
+        assert_eq!(p.take_ts_directives(), vec![]);
+
+        Ok(program)
+    });
+}
+
+#[test]
+fn parse_program_take_asi_decisions() {
+    let src = "let a = 1
+function f() {
+    return
+    a
+}
+a
+++a
+if (a) { a }
+a";
+
+    test_parser(src, Default::default(), |p| {
+        p.record_asi_decisions();
+        let program = p.parse_program()?;
+
+        let decisions = p
+            .take_asi_decisions()
+            .into_iter()
+            .map(|d| {
+                (
+                    d.kind,
+                    &src[..d.pos.0 as usize - 1],
+                    d.next
+                        .map(|span| &src[span.lo.0 as usize - 1..span.hi.0 as usize - 1]),
+                )
+            })
+            .map(|(kind, before, next)| (kind, before.lines().last().unwrap(), next))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            decisions,
+            vec![
+                (AsiKind::LineBreak, "let a = 1", Some("function")),
+                (AsiKind::RestrictedProduction, "    return", Some("a")),
+                (AsiKind::CloseBrace, "    a", Some("}")),
+                (AsiKind::RestrictedProduction, "a", Some("++")),
+                (AsiKind::LineBreak, "++a", Some("if")),
+                (AsiKind::CloseBrace, "if (a) { a", Some("}")),
+                (AsiKind::Eof, "a", None),
+            ]
+        );
+
+        Ok(program)
+    });
+}
+
+#[test]
+fn parse_program_asi_decisions_disabled() {
+    test_parser("a\nb", Default::default(), |p| {
+        let program = p.parse_program()?;
+
+        assert_eq!(p.take_asi_decisions(), vec![]);
+
+        Ok(program)
+    });
+}
+
+#[test]
+fn parse_program_recovery() {
+    let src = "let a = ;
+let b = 1;
+function f() {
+    let c = (1 +;
+    return c;
+}
+let d = 2";
+
+    test_parser(src, Default::default(), |p| {
+        p.enable_recovery();
+        let program = p.parse_program()?;
+
+        let errors = p.take_errors();
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+
+        let script = program.clone().expect_script();
+        let invalid =
+            |stmt: &Stmt| matches!(stmt, Stmt::Expr(ExprStmt { expr, .. }) if expr.is_invalid());
+
+        assert_eq!(script.body.len(), 4);
+        assert!(invalid(&script.body[0]));
+        assert!(script.body[1].is_decl());
+        assert!(script.body[3].is_decl());
+
+        let body = &script.body[2]
+            .as_decl()
+            .and_then(|decl| decl.as_fn_decl())
+            .and_then(|f| f.function.body.as_ref())
+            .unwrap()
+            .stmts;
+        assert_eq!(body.len(), 2);
+        assert!(invalid(&body[0]));
+        assert!(body[1].is_return_stmt());
+
+        Ok(program)
+    });
+}
+
+#[test]
+fn parse_program_recovery_unclosed() {
+    test_parser("a(;\n}\nb", Default::default(), |p| {
+        p.enable_recovery();
+        let program = p.parse_program()?;
+
+        assert_eq!(p.take_errors().len(), 2);
+
+        // `a(;` and the stray `}` are skipped separately.
+        let body = program.clone().expect_script().body;
+        assert_eq!(body.len(), 3);
+        assert!(body[2].as_expr().unwrap().expr.is_ident());
+
+        Ok(program)
+    });
+}
+
+#[test]
+fn parse_program_recovery_disabled() {
+    test_parser("let a = ;", Default::default(), |p| {
+        assert!(p.parse_program().is_err());
+
+        Ok(())
+    });
+}
+
 #[test]
 fn parse_program_take_script_module_errors() {
     test_parser(r#"077;"#, Default::default(), |p| {
@@ -369,3 +537,610 @@ fn parse_program_take_script_module_errors() {
         Ok(program)
     });
 }
+
+fn parse_module_at(src: &str) -> Module {
+    let input = StringInput::new(src, BytePos(1), BytePos(1 + src.len() as u32));
+    Parser::new(Default::default(), input, None)
+        .parse_module()
+        .unwrap()
+}
+
+/// Asserts that reparsing `old` with the replacement of `old[lo..hi]` by
+/// `text` produces the same module as parsing the new source.
+#[track_caller]
+fn assert_reparse(old: &str, lo: usize, hi: usize, text: &str) {
+    let new = format!("{}{}{}", &old[..lo], text, &old[hi..]);
+    let edit = Edit {
+        span: Span::new(BytePos(1 + lo as u32), BytePos(1 + hi as u32)),
+        new_len: text.len() as u32,
+    };
+
+    let reparsed = Parser::reparse(
+        Default::default(),
+        Default::default(),
+        StringInput::new(&new, BytePos(1), BytePos(1 + new.len() as u32)),
+        None,
+        parse_module_at(old),
+        &[edit],
+        &mut Vec::new(),
+    )
+    .unwrap();
+
+    assert_eq!(reparsed, parse_module_at(&new));
+}
+
+#[test]
+fn reparse_edit_item() {
+    let src = "let a = 1;\nfunction f() {\n    return a;\n}\nlet b = a + 2;\nexport { b };";
+
+    assert_reparse(src, 50, 51, "b * 3");
+    assert_reparse(src, 8, 9, "100");
+    assert_reparse(src, src.len(), src.len(), "\nlet c;");
+    assert_reparse(src, 11, 42, "");
+}
+
+#[test]
+fn reparse_join_items() {
+    let src = "a\n(b)\nc;\nd;";
+
+    // `a` becomes a call with `(b)` as arguments.
+    assert_reparse(src, 1, 2, "");
+}
+
+#[test]
+fn reparse_comment() {
+    let src = "a; /* b; */ c; d; e;";
+
+    assert_reparse(src, 9, 11, "");
+}
+
+#[test]
+fn parse_streaming() {
+    let src = "import a from 'a';\nconst b = <T,>(x: T) => x / 2 / 3;\nexport default \
+               /re/g.test(`${a}${b}`);\nfunction f(this: Window) {}\n";
+    let syntax = Syntax::Typescript(crate::TsSyntax {
+        tsx: false,
+        ..Default::default()
+    });
+
+    let expected = Parser::new(
+        syntax,
+        StringInput::new(src, BytePos(1), BytePos(1 + src.len() as u32)),
+        None,
+    )
+    .parse_module()
+    .unwrap();
+
+    for chunk_size in [1, 5, 64] {
+        let lexer = crate::lexer::StreamingLexer::new(
+            syntax,
+            Default::default(),
+            src.as_bytes(),
+            BytePos(1),
+            None,
+        )
+        .with_chunk_size(chunk_size);
+
+        assert_eq!(Parser::new_from(lexer).parse_module().unwrap(), expected);
+    }
+}
+
+#[test]
+fn preserve_parens_in_decorator() {
+    let decorator = |preserve_parens| {
+        let syntax = Syntax::Es(EsSyntax {
+            decorators: true,
+            preserve_parens,
+            ..Default::default()
+        });
+
+        test_parser("@(foo.bar) class A {}", syntax, |p| {
+            let module = p.parse_module()?;
+            let class = module.body[0]
+                .as_stmt()
+                .and_then(|s| s.as_decl())
+                .and_then(|d| d.as_class())
+                .unwrap();
+
+            Ok(class.class.decorators[0].expr.clone())
+        })
+    };
+
+    assert!(decorator(false).is_member());
+    let paren = decorator(true).expect_paren();
+    assert_eq!(paren.span, Span::new(BytePos(2), BytePos(11)));
+    assert!(paren.expr.is_member());
+}
+
+#[test]
+fn ts_version_rejects_newer_syntax() {
+    let errors = |src: &'static str, ts_version| {
+        let syntax = Syntax::Typescript(TsSyntax {
+            ts_version,
+            ..Default::default()
+        });
+
+        test_parser(src, syntax, |p| {
+            p.parse_module()?;
+
+            Ok(p.take_errors()
+                .into_iter()
+                .map(|e| e.into_kind())
+                .collect::<Vec<_>>())
+        })
+    };
+    let required = |syntax, version| vec![SyntaxError::TsVersionRequired { syntax, version }];
+
+    let cases = [
+        (
+            "function f<const T>() {}",
+            "'const' modifier on a type parameter",
+            TsVersion::CONST_TYPE_PARAMS,
+        ),
+        (
+            "interface I<in T> {}",
+            "'in' modifier on a type parameter",
+            TsVersion::VARIANCE_ANNOTATIONS,
+        ),
+        (
+            "a satisfies B;",
+            "'satisfies' expression",
+            TsVersion::SATISFIES,
+        ),
+        (
+            "class A { accessor a = 1; }",
+            "'accessor' modifier",
+            TsVersion::AUTO_ACCESSORS,
+        ),
+        (
+            "{ using a = b; }",
+            "Using declaration",
+            TsVersion::USING_DECLARATIONS,
+        ),
+    ];
+
+    for (src, syntax, version) in cases {
+        assert_eq!(
+            errors(src, Some(TsVersion::new(4, 6))),
+            required(syntax, version)
+        );
+        assert_eq!(errors(src, Some(version)), Vec::new());
+        assert_eq!(errors(src, None), Vec::new());
+    }
+}
+
+#[test]
+fn ts_version_from_str() {
+    assert_eq!("5.0".parse(), Ok(TsVersion::new(5, 0)));
+    assert_eq!("4".parse(), Ok(TsVersion::new(4, 0)));
+    assert!("next".parse::<TsVersion>().is_err());
+    assert_eq!(
+        serde_json::from_str::<TsSyntax>(r#"{ "tsVersion": "4.9" }"#)
+            .unwrap()
+            .ts_version,
+        Some(TsVersion::new(4, 9))
+    );
+}
+
+#[test]
+fn error_report() {
+    let reports = |src: &'static str| {
+        test_parser(src, Syntax::Typescript(Default::default()), |p| {
+            p.parse_module()?;
+
+            Ok(p.take_errors()
+                .iter()
+                .map(|e| e.report())
+                .collect::<Vec<_>>())
+        })
+    };
+
+    let report = &reports("interface I extends A extends B {}")[0];
+    assert_eq!(report.code, "TS1172");
+    assert_eq!(report.message, "`extends` clause already seen.");
+    assert_eq!(report.span, Span::new(BytePos(23), BytePos(30)));
+    assert_eq!(report.suggestions[0].span, report.span);
+    assert_eq!(report.suggestions[0].replacement, ",");
+
+    let report = &reports("enum E { A B }")[0];
+    assert_eq!(report.code, "TS1005");
+    assert_eq!(
+        report.suggestions[0].span,
+        Span::new(BytePos(12), BytePos(12))
+    );
+    assert_eq!(report.suggestions[0].replacement, ",");
+}
+
+#[test]
+fn error_code() {
+    assert_eq!(SyntaxError::Eof.code(), "Eof");
+    assert_eq!(SyntaxError::TS1005.code(), "TS1005");
+    assert_eq!(
+        SyntaxError::ExpectedSemiForExprStmt { expr: DUMMY_SP }.code(),
+        "ExpectedSemiForExprStmt"
+    );
+    assert_eq!(
+        SyntaxError::WithLabel {
+            inner: Box::new(Error::new(DUMMY_SP, SyntaxError::TS1172)),
+            span: DUMMY_SP,
+            note: "note",
+        }
+        .code(),
+        "TS1172"
+    );
+}
+
+#[test]
+fn parse_module_lazy() {
+    let src = "
+        import a from 'a';
+        function f(x) {
+            if (x) { return `${x}}` + /}/.source; }
+            return function g() { return { a }; };
+        }
+        export const h = async () => { await f(1); };
+        class C {
+            m() { 'use strict'; return this; }
+        }
+    ";
+    let input = || StringInput::new(src, BytePos(1), BytePos(1 + src.len() as u32));
+
+    let mut errors = Vec::new();
+    let lazy = Parser::parse_module_lazy(
+        Syntax::Es(Default::default()),
+        EsVersion::latest(),
+        input(),
+        None,
+        &mut errors,
+    )
+    .unwrap();
+    assert_eq!(lazy.deferred_bodies().count(), 3);
+
+    let first = lazy.deferred_bodies().next().unwrap();
+    let body = lazy.parse_body(first, &mut errors).unwrap();
+    assert_eq!(body.stmts.len(), 2);
+
+    let module = lazy.into_module(&mut errors).unwrap();
+    assert_eq!(errors, Vec::new());
+
+    let expected = Parser::new(Syntax::Es(Default::default()), input(), None)
+        .parse_module()
+        .unwrap();
+    assert_eq!(module, expected);
+}
+
+#[test]
+fn parse_module_lazy_errors_in_body() {
+    let src = "function f() { let a = 1 2; } function g() { yield 1; }";
+
+    let mut errors = Vec::new();
+    let lazy = Parser::parse_module_lazy(
+        Syntax::Es(Default::default()),
+        EsVersion::latest(),
+        StringInput::new(src, BytePos(1), BytePos(1 + src.len() as u32)),
+        None,
+        &mut errors,
+    )
+    .unwrap();
+    assert_eq!(errors, Vec::new());
+
+    assert!(lazy.into_module(&mut errors).is_err());
+}
+
+#[test]
+fn replay_captured_tokens() {
+    let src = "
+        const re = /}[a-z]/g.test(`${a / 2}`);
+        const f = <T,>(a: Array<Array<T>>): T => a[0][0];
+        f<number>(x) >> 1;
+        if (a < b && c > d) {}
+    ";
+    let syntax = Syntax::Typescript(Default::default());
+
+    let lexer = Capturing::new(Lexer::new(
+        syntax,
+        Default::default(),
+        StringInput::new(src, BytePos(1), BytePos(1 + src.len() as u32)),
+        None,
+    ));
+    let mut parser = Parser::new_from(lexer);
+    let expected = parser.parse_module().unwrap();
+
+    let replayed = Parser::new_from(parser.input().replay())
+        .parse_module()
+        .unwrap();
+    assert_eq!(replayed, expected);
+
+    // Replace `1` with `2`
+    let tokens = parser.input().tokens();
+    let one = tokens
+        .borrow()
+        .iter()
+        .position(|t| matches!(t.token, Token::Num { value, .. } if value == 1.0))
+        .unwrap();
+    tokens.borrow_mut()[one].token = Token::Num {
+        value: 2.0,
+        raw: "2".into(),
+    };
+
+    let modified = Parser::new_from(parser.input().replay())
+        .parse_module()
+        .unwrap();
+    assert_ne!(modified, expected);
+}
+
+fn json_errors(src: &str, jsonc: bool) -> Vec<String> {
+    let cm = swc_common::SourceMap::default();
+    let fm = cm.new_source_file(swc_common::FileName::Anon.into(), src.to_string());
+
+    let mut errors = Vec::new();
+    let res = if jsonc {
+        crate::parse_file_as_jsonc(&fm, None, &mut errors)
+    } else {
+        crate::parse_file_as_json(&fm, &mut errors)
+    };
+    if let Err(err) = res {
+        errors.push(err);
+    }
+
+    errors.iter().map(|err| err.kind().code()).collect()
+}
+
+#[test]
+fn parse_json() {
+    let src = r#"{ "a": [1, -2.5e-3, true, null, "é\n"], "b": {} }"#;
+    let expr = test_parser(src, Default::default(), |p| p.parse_json());
+
+    let obj = expr.expect_object();
+    assert_eq!(obj.props.len(), 2);
+    assert_eq!(
+        obj.span,
+        Span::new(BytePos(1), BytePos(1 + src.len() as u32))
+    );
+}
+
+#[test]
+fn json_errors_are_reported() {
+    assert_eq!(json_errors("{ 'a': 1 }", false), ["JsonInvalidString"]);
+    assert_eq!(json_errors(r#"["\x41"]"#, false), ["JsonInvalidString"]);
+    assert_eq!(json_errors("[0x10, .5]", false), ["JsonInvalidNumber"; 2]);
+    assert_eq!(json_errors("[1, 2,]", false), ["JsonTrailingComma"]);
+    assert_eq!(json_errors("// comment\n1", false), ["JsonComment"]);
+    assert_eq!(json_errors("{ a: 1 }", false), ["Unexpected"]);
+    assert_eq!(json_errors("1 2", false), ["Unexpected"]);
+}
+
+#[test]
+fn jsonc_allows_comments_and_trailing_commas() {
+    let src = r#"{
+        // comment
+        "a": [1, 2,], /* comment */
+    }"#;
+
+    assert_eq!(json_errors(src, true), Vec::<String>::new());
+}
+
+fn import_assertion_errors(src: &str, syntax: Syntax) -> (Module, Vec<String>) {
+    let cm = swc_common::SourceMap::default();
+    let fm = cm.new_source_file(swc_common::FileName::Anon.into(), src.to_string());
+
+    let mut errors = Vec::new();
+    let module = crate::parse_file_as_module(&fm, syntax, EsVersion::latest(), None, &mut errors)
+        .unwrap_or_else(|err| panic!("failed to parse: {:?}", err));
+
+    (module, errors.iter().map(|err| err.kind().code()).collect())
+}
+
+#[test]
+fn import_assertions_are_parsed_as_attributes() {
+    let src = r#"
+import a from "./a.json" assert { type: "json" };
+export { b } from "./b.json" assert { type: "json" };
+export * from "./c.json" assert { type: "json" };
+"#;
+
+    for warn_import_assertions in [false, true] {
+        let (module, errors) = import_assertion_errors(
+            src,
+            Syntax::Es(EsSyntax {
+                import_attributes: true,
+                warn_import_assertions,
+                ..Default::default()
+            }),
+        );
+
+        assert!(module.body.iter().all(|item| match item {
+            ModuleItem::ModuleDecl(ModuleDecl::Import(ImportDecl { with, .. }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportNamed(NamedExport { with, .. }))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportAll(ExportAll { with, .. })) =>
+                with.is_some(),
+            _ => false,
+        }));
+
+        if warn_import_assertions {
+            assert_eq!(errors, ["ImportAssertionDeprecated"; 3]);
+        } else {
+            assert_eq!(errors, Vec::<String>::new());
+        }
+    }
+}
+
+#[test]
+fn import_assertions_in_import_type() {
+    let src = r#"type A = typeof import("./a.json", { assert: { type: "json" } });"#;
+
+    let (module, errors) = import_assertion_errors(
+        src,
+        Syntax::Typescript(TsSyntax {
+            warn_import_assertions: true,
+            ..Default::default()
+        }),
+    );
+
+    let alias = module.body[0]
+        .as_stmt()
+        .and_then(Stmt::as_decl)
+        .and_then(Decl::as_ts_type_alias)
+        .unwrap();
+    let import = alias
+        .type_ann
+        .as_ts_type_query()
+        .and_then(|query| query.expr_name.as_import())
+        .unwrap();
+    assert!(import.attributes.is_some());
+    assert_eq!(errors, ["ImportAssertionDeprecated"]);
+}
+
+fn pipeline_errors(src: &str) -> (Module, Vec<String>) {
+    let cm = swc_common::SourceMap::default();
+    let fm = cm.new_source_file(swc_common::FileName::Anon.into(), src.to_string());
+
+    let mut errors = Vec::new();
+    let module = crate::parse_file_as_module(
+        &fm,
+        Syntax::Es(EsSyntax {
+            pipeline_operator: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut errors,
+    )
+    .unwrap_or_else(|err| panic!("failed to parse: {:?}", err));
+
+    (module, errors.iter().map(|err| err.kind().code()).collect())
+}
+
+#[test]
+fn pipeline_is_right_nested() {
+    let (module, errors) = pipeline_errors("a + b |> f(%) |> % / 2;");
+    assert_eq!(errors, Vec::<String>::new());
+
+    let expr = &module.body[0].as_stmt().unwrap().as_expr().unwrap().expr;
+    let outer = expr.as_pipeline().unwrap();
+    assert!(outer.left.is_bin());
+
+    let inner = outer.right.as_pipeline().unwrap();
+    assert!(inner.left.is_call());
+    // `%` followed by `/` is a division, not a regex.
+    assert!(inner.right.as_bin().unwrap().left.is_topic());
+}
+
+#[test]
+fn pipeline_errors_are_reported() {
+    assert_eq!(pipeline_errors("a |> f();").1, ["PipelineTopicUnused"]);
+    assert_eq!(pipeline_errors("f(%);").1, ["PipelineTopicOutsideBody"]);
+    assert_eq!(
+        pipeline_errors("a |> function () { return %; };").1,
+        ["PipelineTopicOutsideBody", "PipelineTopicUnused"]
+    );
+    assert_eq!(
+        pipeline_errors("a |> % ? 1 : 2;").1,
+        ["PipelineBodyNotParenthesized"]
+    );
+    assert_eq!(pipeline_errors("a |> (% ? 1 : 2);").1, Vec::<String>::new());
+}
+
+fn match_errors(src: &str) -> (Module, Vec<String>) {
+    let cm = swc_common::SourceMap::default();
+    let fm = cm.new_source_file(swc_common::FileName::Anon.into(), src.to_string());
+
+    let mut errors = Vec::new();
+    let module = crate::parse_file_as_module(
+        &fm,
+        Syntax::Es(EsSyntax {
+            explicit_proposals: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut errors,
+    )
+    .unwrap_or_else(|err| panic!("failed to parse: {:?}", err));
+
+    (module, errors.iter().map(|err| err.kind().code()).collect())
+}
+
+#[test]
+fn match_expr() {
+    let (module, errors) = match_errors(
+        "const x = match (res) {
+            when { status: 200, let body }: body;
+            when [1, ...let rest] if (rest.length): rest;
+            when < 0 or > 10: 1;
+            default: 2;
+        };",
+    );
+    assert_eq!(errors, Vec::<String>::new());
+
+    let decl = module.body[0].as_stmt().unwrap().as_decl().unwrap();
+    let init = decl.as_var().unwrap().decls[0].init.as_ref().unwrap();
+    let cases = &init.as_match_expr().unwrap().cases;
+    assert_eq!(cases.len(), 4);
+
+    assert!(cases[0].pat.as_ref().unwrap().is_object());
+    assert!(cases[1].guard.is_some());
+    let array = cases[1].pat.as_ref().unwrap().as_array().unwrap();
+    assert!(array.rest.as_ref().unwrap().arg.is_some());
+    assert!(cases[2].pat.as_ref().unwrap().is_combined());
+    assert!(cases[3].pat.is_none());
+}
+
+#[test]
+fn match_requires_block_on_same_line() {
+    let (module, errors) = match_errors("match (a)\n{}");
+    assert_eq!(errors, Vec::<String>::new());
+
+    assert!(module.body[0]
+        .as_stmt()
+        .unwrap()
+        .as_expr()
+        .unwrap()
+        .expr
+        .is_call());
+    assert!(module.body[1].as_stmt().unwrap().is_block());
+}
+
+#[test]
+fn match_errors_are_reported() {
+    assert_eq!(
+        match_errors("match (a) { default: 1; when 1: 2; };").1,
+        ["MatchDefaultNotLast"]
+    );
+    assert_eq!(
+        match_errors("match (a) { when 1 and 2 or 3: 1; };").1,
+        ["MatchMixedCombinators"]
+    );
+    assert_eq!(
+        match_errors("match (a) { when f(): 1; };").1,
+        ["MatchInvalidValuePattern"]
+    );
+    assert_eq!(
+        match_errors("match (a) { when (1 and 2) or 3: 1; };").1,
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn do_expr() {
+    let syntax = Syntax::Es(EsSyntax {
+        do_expressions: true,
+        ..Default::default()
+    });
+
+    let expr = test_parser("x = do { let y = 1; y } / 2", syntax, |p| p.parse_expr());
+    let right = &expr.as_assign().unwrap().right;
+    // `/` after the block is a division, not a regex.
+    let div = right.as_bin().unwrap();
+    assert_eq!(div.left.as_do_expr().unwrap().body.stmts.len(), 2);
+
+    // A statement starting with `do` is still a `do`-`while` loop.
+    let stmt = test_parser("do { x } while (y)", syntax, |p| p.parse_stmt());
+    assert!(stmt.is_do_while());
+}
+
+#[test]
+#[should_panic(expected = "Expression expected")]
+fn do_expr_requires_flag() {
+    test_parser("x = do { 1 }", Default::default(), |p| p.parse_expr());
+}
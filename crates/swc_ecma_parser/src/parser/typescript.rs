@@ -315,8 +315,6 @@ impl<I: Tokens> Parser<I> {
             }
         };
 
-        // the "assert" keyword is deprecated and this syntax is niche, so
-        // don't support it
         let attributes =
             if eat!(self, ',') && self.input.syntax().import_attributes() && is!(self, '{') {
                 Some(self.parse_ts_call_options()?)
@@ -354,7 +352,11 @@ impl<I: Tokens> Parser<I> {
         let start = cur_pos!(self);
         assert_and_bump!(self, '{');
 
-        expect!(self, "with");
+        if eat!(self, "assert") {
+            self.report_import_assertion();
+        } else {
+            expect!(self, "with");
+        }
         expect!(self, ':');
 
         let value = match self.parse_object::<Expr>()? {
@@ -434,11 +436,33 @@ impl<I: Tokens> Parser<I> {
                     is_const = true;
                     if !permit_const {
                         self.emit_err(self.input.prev_span(), SyntaxError::TS1277("const".into()));
+                    } else if !self
+                        .syntax()
+                        .supports_ts_version(TsVersion::CONST_TYPE_PARAMS)
+                    {
+                        self.emit_err(
+                            self.input.prev_span(),
+                            SyntaxError::TsVersionRequired {
+                                syntax: "'const' modifier on a type parameter",
+                                version: TsVersion::CONST_TYPE_PARAMS,
+                            },
+                        );
                     }
                 }
                 "in" => {
                     if !permit_in_out {
                         self.emit_err(self.input.prev_span(), SyntaxError::TS1274("in".into()));
+                    } else if !self
+                        .syntax()
+                        .supports_ts_version(TsVersion::VARIANCE_ANNOTATIONS)
+                    {
+                        self.emit_err(
+                            self.input.prev_span(),
+                            SyntaxError::TsVersionRequired {
+                                syntax: "'in' modifier on a type parameter",
+                                version: TsVersion::VARIANCE_ANNOTATIONS,
+                            },
+                        );
                     } else if is_in {
                         self.emit_err(self.input.prev_span(), SyntaxError::TS1030("in".into()));
                     } else if is_out {
@@ -452,6 +476,17 @@ impl<I: Tokens> Parser<I> {
                 "out" => {
                     if !permit_in_out {
                         self.emit_err(self.input.prev_span(), SyntaxError::TS1274("out".into()));
+                    } else if !self
+                        .syntax()
+                        .supports_ts_version(TsVersion::VARIANCE_ANNOTATIONS)
+                    {
+                        self.emit_err(
+                            self.input.prev_span(),
+                            SyntaxError::TsVersionRequired {
+                                syntax: "'out' modifier on a type parameter",
+                                version: TsVersion::VARIANCE_ANNOTATIONS,
+                            },
+                        );
                     } else if is_out {
                         self.emit_err(self.input.prev_span(), SyntaxError::TS1030("out".into()));
                     }
@@ -576,19 +611,20 @@ impl<I: Tokens> Parser<I> {
             return Ok(false);
         }
         let prev_ignore_error = self.input.get_ctx().contains(Context::IgnoreError);
-        let mut cloned = self.clone();
-        cloned.set_ctx(self.ctx() | Context::IgnoreError);
-        let res = op(&mut cloned);
+        let checkpoint = self.checkpoint();
+        self.set_ctx(self.ctx() | Context::IgnoreError);
+        let res = op(self);
         match res {
             Ok(Some(res)) if res => {
-                *self = cloned;
                 let mut ctx = self.ctx();
                 ctx.set(Context::IgnoreError, prev_ignore_error);
                 self.input.set_ctx(ctx);
                 Ok(res)
             }
-            Err(..) => Ok(false),
-            _ => Ok(false),
+            _ => {
+                self.rewind(checkpoint);
+                Ok(false)
+            }
         }
     }
 
@@ -632,12 +668,11 @@ impl<I: Tokens> Parser<I> {
         trace_cur!(self, try_parse_ts);
 
         let prev_ignore_error = self.input.get_ctx().contains(Context::IgnoreError);
-        let mut cloned = self.clone();
-        cloned.set_ctx(self.ctx() | Context::IgnoreError);
-        let res = op(&mut cloned);
+        let checkpoint = self.checkpoint();
+        self.set_ctx(self.ctx() | Context::IgnoreError);
+        let res = op(self);
         match res {
             Ok(Some(res)) => {
-                *self = cloned;
                 trace_cur!(self, try_parse_ts__success_value);
                 let mut ctx = self.ctx();
                 ctx.set(Context::IgnoreError, prev_ignore_error);
@@ -646,11 +681,13 @@ impl<I: Tokens> Parser<I> {
                 Some(res)
             }
             Ok(None) => {
+                self.rewind(checkpoint);
                 trace_cur!(self, try_parse_ts__success_no_value);
 
                 None
             }
             Err(..) => {
+                self.rewind(checkpoint);
                 trace_cur!(self, try_parse_ts__fail);
 
                 None
@@ -800,7 +837,12 @@ impl<I: Tokens> Parser<I> {
             let start = cur_pos!(self);
             bump!(self);
             store!(self, ',');
-            self.emit_err(Span::new(start, start), SyntaxError::TS1005);
+            let span = Span::new(start, start);
+            self.emit_error(Error::new(span, SyntaxError::TS1005).with_suggestion(
+                span,
+                ",",
+                "Enum members are separated by commas",
+            ));
             None
         };
 
@@ -1109,7 +1151,12 @@ impl<I: Tokens> Parser<I> {
         //
         //     interface I extends A extends B {}
         if is!(self, "extends") {
-            self.emit_err(self.input.cur_span(), SyntaxError::TS1172);
+            let span = self.input.cur_span();
+            self.emit_error(Error::new(span, SyntaxError::TS1172).with_suggestion(
+                span,
+                ",",
+                "Interfaces can extend multiple types in one `extends` clause",
+            ));
 
             while !eof!(self) && !is!(self, '{') {
                 bump!(self);
@@ -1224,9 +1271,11 @@ impl<I: Tokens> Parser<I> {
     {
         debug_assert!(self.input.syntax().typescript());
 
-        let mut cloned = self.clone();
-        cloned.set_ctx(self.ctx() | Context::IgnoreError);
-        op(&mut cloned)
+        let checkpoint = self.checkpoint();
+        self.set_ctx(self.ctx() | Context::IgnoreError);
+        let res = op(self);
+        self.rewind(checkpoint);
+        res
     }
 
     /// `tsIsUnambiguouslyStartOfFunctionType`
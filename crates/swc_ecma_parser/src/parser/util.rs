@@ -12,7 +12,13 @@ impl<I: Tokens> Parser<I> {
     }
 
     /// Original state is restored when returned guard is dropped.
-    pub(super) fn with_state(&mut self, state: State) -> WithState<I> {
+    ///
+    /// ASI decisions and skipped function bodies are not a part of the scope,
+    /// so they are kept.
+    pub(super) fn with_state(&mut self, mut state: State) -> WithState<I> {
+        state.asi_decisions = self.state.asi_decisions.take();
+        state.lazy_bodies = self.state.lazy_bodies.take();
+        state.recovery = self.state.recovery;
         let orig_state = std::mem::replace(&mut self.state, state);
         WithState {
             orig_state,
@@ -20,6 +26,82 @@ impl<I: Tokens> Parser<I> {
         }
     }
 
+    /// Saves the current position so it can be restored by
+    /// [Parser::rewind].
+    ///
+    /// Only the position and the token context stack of the lexer are saved,
+    /// along with the buffered tokens. Growing state, like ASI decisions, is
+    /// saved as a length and truncated on rewind.
+    pub(super) fn checkpoint(&self) -> Checkpoint<I> {
+        Checkpoint {
+            lexer: self.input.iter.checkpoint_save(),
+            prev_span: self.input.prev_span,
+            cur: self.input.cur.clone(),
+            next: self.input.next.clone(),
+            labels: self.state.labels.clone(),
+            potential_arrow_start: self.state.potential_arrow_start,
+            topic: self.state.topic,
+            asi_decisions: self.state.asi_decisions.as_ref().map(Vec::len),
+            lazy_bodies: self.state.lazy_bodies.as_ref().map(Vec::len),
+            found_module_item: self.found_module_item,
+        }
+    }
+
+    /// Restores the position saved by [Parser::checkpoint].
+    ///
+    /// Trailing commas recorded after the checkpoint are kept, because they
+    /// are keyed by the start of the object literal and parsing the same
+    /// tokens again records the same value.
+    pub(super) fn rewind(&mut self, checkpoint: Checkpoint<I>) {
+        self.input.iter.checkpoint_load(checkpoint.lexer);
+        self.input.prev_span = checkpoint.prev_span;
+        self.input.cur = checkpoint.cur;
+        self.input.next = checkpoint.next;
+        self.state.labels = checkpoint.labels;
+        self.state.potential_arrow_start = checkpoint.potential_arrow_start;
+        self.state.topic = checkpoint.topic;
+        if let (Some(v), Some(len)) = (&mut self.state.asi_decisions, checkpoint.asi_decisions) {
+            v.truncate(len);
+        }
+        if let (Some(v), Some(len)) = (&mut self.state.lazy_bodies, checkpoint.lazy_bodies) {
+            v.truncate(len);
+        }
+        self.found_module_item = checkpoint.found_module_item;
+    }
+
+    /// Parses a type annotation of typescript or flow.
+    pub(super) fn parse_type_ann(
+        &mut self,
+        eat_colon: bool,
+        start: BytePos,
+    ) -> PResult<Box<TsTypeAnn>> {
+        if self.syntax().flow() {
+            self.parse_flow_type_ann(eat_colon, start)
+        } else {
+            self.parse_ts_type_ann(eat_colon, start)
+        }
+    }
+
+    /// Parses a type annotation of typescript or flow if the current token is
+    /// `:`.
+    pub(super) fn try_parse_type_ann(&mut self) -> PResult<Option<Box<TsTypeAnn>>> {
+        if self.syntax().flow() {
+            self.try_parse_flow_type_ann()
+        } else {
+            self.try_parse_ts_type_ann()
+        }
+    }
+
+    /// Parses type parameters of a function, like `<T>` in `function f<T>()
+    /// {}`.
+    pub(super) fn parse_fn_type_params(&mut self) -> PResult<Box<TsTypeParamDecl>> {
+        if self.syntax().flow() {
+            self.parse_flow_type_params()
+        } else {
+            self.parse_ts_type_params(false, true)
+        }
+    }
+
     pub(super) fn set_ctx(&mut self, ctx: Context) {
         self.input.set_ctx(ctx);
     }
@@ -66,6 +148,20 @@ pub trait ParseObject<Obj> {
     fn parse_object_prop(&mut self) -> PResult<Self::Prop>;
 }
 
+/// Position of the parser returned by [Parser::checkpoint].
+pub(super) struct Checkpoint<I: Tokens> {
+    lexer: I::Checkpoint,
+    prev_span: Span,
+    cur: Option<TokenAndSpan>,
+    next: Option<TokenAndSpan>,
+    labels: Vec<Atom>,
+    potential_arrow_start: Option<BytePos>,
+    topic: Option<bool>,
+    asi_decisions: Option<usize>,
+    lazy_bodies: Option<usize>,
+    found_module_item: bool,
+}
+
 pub struct WithState<'w, I: 'w + Tokens> {
     inner: &'w mut Parser<I>,
     orig_state: State,
@@ -85,6 +181,8 @@ impl<I: Tokens> DerefMut for WithState<'_, I> {
 impl<I: Tokens> Drop for WithState<'_, I> {
     fn drop(&mut self) {
         std::mem::swap(&mut self.inner.state, &mut self.orig_state);
+        self.inner.state.asi_decisions = self.orig_state.asi_decisions.take();
+        self.inner.state.lazy_bodies = self.orig_state.lazy_bodies.take();
     }
 }
 
@@ -160,6 +258,8 @@ pub(super) trait ExprExt {
 
             Expr::OptChain(..) => false,
 
+            Expr::Pipeline(..) | Expr::Topic(..) | Expr::Match(..) | Expr::Do(..) => false,
+
             // MemberExpression is valid assignment target
             Expr::PrivateName(..) => false,
 
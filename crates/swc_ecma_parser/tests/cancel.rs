@@ -0,0 +1,28 @@
+use swc_common::{cancel::CancellationToken, FileName, Globals, SourceMap, GLOBALS};
+use swc_ecma_parser::{error::SyntaxError, parse_file_as_module, Syntax};
+
+fn parse(token: &CancellationToken) -> Result<(), SyntaxError> {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), "function f() { a; }".into());
+
+    GLOBALS.set(&Globals::with_cancellation(token.clone()), || {
+        parse_file_as_module(
+            &fm,
+            Syntax::default(),
+            Default::default(),
+            None,
+            &mut Vec::new(),
+        )
+        .map(drop)
+        .map_err(|err| err.into_kind())
+    })
+}
+
+#[test]
+fn stops_when_cancelled() {
+    let token = CancellationToken::new();
+    assert_eq!(parse(&token), Ok(()));
+
+    token.cancel();
+    assert!(matches!(parse(&token), Err(SyntaxError::Cancelled)));
+}
@@ -0,0 +1,11 @@
+let a: number = 1;
+const b: ?string = null;
+var c: Array<?number> = [];
+function f(x: number, y?: string, ...rest: Array<mixed>): void {}
+function g<T>(x: T): T[] {
+  return [x];
+}
+let d: typeof a = 2;
+let e: "a" | 'b' | -1 | true = "a";
+let h: [number, string] = [1, "a"];
+let i: A.B.C<*> = x;
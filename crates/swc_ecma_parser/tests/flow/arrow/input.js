@@ -0,0 +1,5 @@
+const a = (x: number, y?: string): boolean => true;
+const b = (x: number = 1, ...rest: Array<number>) => x;
+const c = <T>(x: T): T => x;
+const d = (x): (number => string) => f;
+const e = async (x: number) => x;
@@ -0,0 +1,6 @@
+class A<T> extends B<T> implements C, D<T> {
+  a: number;
+  b: string = "";
+  static c: boolean;
+  m<U>(x: U): T {}
+}
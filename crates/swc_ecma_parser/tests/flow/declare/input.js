@@ -0,0 +1,16 @@
+declare var a: number;
+declare function f(x: number): string;
+declare class C<T> extends D<T> {
+  m(): void;
+  p: T;
+}
+declare type T = string;
+declare opaque type O;
+declare opaque type P: string;
+declare interface I {
+  x: number;
+}
+declare module 'mod' {
+  declare export function g(): void;
+}
+declare export var b: number;
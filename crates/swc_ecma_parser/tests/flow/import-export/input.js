@@ -0,0 +1,8 @@
+import type A from 'a';
+import type { B, C } from 'b';
+import typeof D from 'd';
+import { type E, typeof F } from 'e';
+export type G = string;
+export opaque type H = number;
+export interface I {}
+export type { A, B };
@@ -0,0 +1,7 @@
+interface A {
+  a: string;
+  m(): void;
+}
+interface B<T> extends A, C.D<T> {
+  b: T,
+}
@@ -0,0 +1,13 @@
+type A = {
+  a: string,
+  +b?: number;
+  -c: boolean,
+  [key: string]: mixed,
+  [number]: string,
+  (x: number): string,
+  m<T>(x: T): void,
+  'quoted': null,
+};
+type Exact = {| a: string, b: number |};
+type EmptyExact = {||};
+type Spread = { ...A, c: number };
@@ -0,0 +1,3 @@
+opaque type A = string;
+opaque type B: string = string;
+opaque type C<T> = Array<T>;
@@ -0,0 +1,7 @@
+function isString(x: mixed): boolean %checks {
+  return typeof x === "string";
+}
+function isNumber(x: mixed): %checks {
+  return typeof x === "number";
+}
+declare function isBool(x: mixed): boolean %checks(typeof x === "boolean");
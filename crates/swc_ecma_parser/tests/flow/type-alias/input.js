@@ -0,0 +1,9 @@
+type A = string;
+type B<+T, -U: Object = {}> = Map<T, U>;
+type C = | 'a' | 'b';
+type D = A & B<string, number>;
+type Fn = (string, y: number, ...Array<boolean>) => void;
+type Curried = string => number => boolean;
+type Generic = <T>(x: T) => T;
+type Grouped = (string | number)[];
+type Indexed = Obj['key'];
@@ -0,0 +1,3 @@
+const a = (b: any);
+const c = ((d: any): string);
+foo((x: number), y);
@@ -2,9 +2,9 @@
 #![allow(dead_code)]
 #![recursion_limit = "256"]
 
-use std::{path::PathBuf, sync::Arc};
+use std::{fmt, path::PathBuf, sync::Arc};
 
-use preset_env_base::query::targets_to_versions;
+use preset_env_base::{query::targets_to_versions, version::should_enable};
 pub use preset_env_base::{query::Targets, version::Version, BrowserData, Versions};
 use rustc_hash::FxHashSet;
 use serde::Deserialize;
@@ -20,11 +20,13 @@ use swc_ecma_transforms::{
         regexp::{self, regexp},
     },
     feature::FeatureFlag,
+    syntax_features::{SyntaxFeatureScan, SyntaxFeatures},
     Assumptions,
 };
 use swc_ecma_utils::{prepend_stmts, ExprFactory};
 use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith, VisitWith};
 
+use self::transform_data::CompatData;
 pub use self::transform_data::Feature;
 
 #[macro_use]
@@ -51,8 +53,16 @@ where
     let (include, included_modules) = FeatureOrModule::split(c.include);
     let (exclude, excluded_modules) = FeatureOrModule::split(c.exclude);
 
+    let compat_data = match &c.compat_data {
+        Some(path) => CompatData::load(path).expect("failed to load compat data"),
+        None => Default::default(),
+    };
+
     let pass = noop_pass();
 
+    // Passes for syntax which is not used by the file are skipped.
+    let scan = SyntaxFeatureScan::default();
+
     macro_rules! should_enable {
         ($feature:ident, $default:expr) => {{
             let f = transform_data::Feature::$feature;
@@ -60,7 +70,7 @@ where
                 && (c.force_all_transforms
                     || (is_any_target
                         || include.contains(&f)
-                        || f.should_enable(&targets, c.bugfixes, $default)))
+                        || compat_data.should_enable(f, &targets, c.bugfixes, $default)))
         }};
     }
 
@@ -80,18 +90,52 @@ where
             if c.debug {
                 println!("{}: {:?}", f.as_str(), enable);
             }
-            ($prev, Optional::new($pass, enable))
+            (
+                $prev,
+                Optional::new(scan.requires(required_syntax(f), $pass), enable),
+            )
         }};
     }
 
     let pass = (
         pass,
         Optional::new(
-            class_fields_use_set(assumptions.pure_getters),
+            scan.requires(
+                SyntaxFeatures::ClassFields,
+                class_fields_use_set(assumptions.pure_getters),
+            ),
             assumptions.set_public_class_fields,
         ),
     );
 
+    // Custom features are usually newer than the built-in ones, so they run
+    // first.
+    let pass = {
+        let mut custom_passes = Vec::new();
+
+        for feature in &c.custom_features {
+            let versions = compat_data
+                .custom(&feature.name)
+                .unwrap_or(&feature.versions);
+
+            let enable = !excluded_modules.contains(&feature.name)
+                && (c.force_all_transforms
+                    || is_any_target
+                    || included_modules.contains(&feature.name)
+                    || should_enable(&targets, versions, false));
+
+            if c.debug {
+                println!("{}: {:?}", feature.name, enable);
+            }
+
+            if enable {
+                custom_passes.push((feature.transform)(unresolved_mark));
+            }
+        }
+
+        (pass, CustomPasses(custom_passes))
+    };
+
     let pass = {
         let enable_dot_all_regex = should_enable!(DotAllRegex, false);
         let enable_named_capturing_groups_regex = should_enable!(NamedCapturingGroupsRegex, false);
@@ -109,18 +153,21 @@ where
         (
             pass,
             Optional::new(
-                regexp(regexp::Config {
-                    dot_all_regex: enable_dot_all_regex,
-                    // TODO: add Feature:HasIndicesRegex
-                    has_indices: false,
-                    // TODO: add Feature::LookbehindAssertion
-                    lookbehind_assertion: false,
-                    named_capturing_groups_regex: enable_named_capturing_groups_regex,
-                    sticky_regex: enable_sticky_regex,
-                    unicode_property_regex: enable_unicode_property_regex,
-                    unicode_regex: enable_unicode_regex,
-                    unicode_sets_regex: enable_unicode_sets_regex,
-                }),
+                scan.requires(
+                    SyntaxFeatures::RegExp,
+                    regexp(regexp::Config {
+                        dot_all_regex: enable_dot_all_regex,
+                        // TODO: add Feature:HasIndicesRegex
+                        has_indices: false,
+                        // TODO: add Feature::LookbehindAssertion
+                        lookbehind_assertion: false,
+                        named_capturing_groups_regex: enable_named_capturing_groups_regex,
+                        sticky_regex: enable_sticky_regex,
+                        unicode_property_regex: enable_unicode_property_regex,
+                        unicode_regex: enable_unicode_regex,
+                        unicode_sets_regex: enable_unicode_sets_regex,
+                    }),
+                ),
                 enable,
             ),
         )
@@ -201,6 +248,7 @@ where
             es2017::async_to_generator::Config {
                 ignore_function_name: loose || assumptions.ignore_function_name,
                 ignore_function_length: loose || assumptions.ignore_function_length,
+                spec: false,
             },
             unresolved_mark
         )
@@ -233,7 +281,10 @@ where
     let pass = add!(
         pass,
         Spread,
-        es2015::spread(es2015::spread::Config { loose }),
+        es2015::spread(es2015::spread::Config {
+            loose,
+            iterable_is_array: assumptions.iterable_is_array
+        }),
         true
     );
     let pass = add!(pass, ObjectSuper, es2015::object_super());
@@ -259,20 +310,27 @@ where
         ForOf,
         es2015::for_of(es2015::for_of::Config {
             loose,
-            assume_array: loose || assumptions.iterable_is_array
+            assume_array: loose || assumptions.iterable_is_array,
+            skip_iterator_closing: assumptions.skip_for_of_iterator_closing
         }),
         true
     );
     let pass = add!(
         pass,
         ComputedProperties,
-        es2015::computed_properties(es2015::computed_props::Config { loose }),
+        es2015::computed_properties(es2015::computed_props::Config {
+            loose,
+            set_computed_properties: assumptions.set_computed_properties
+        }),
         true
     );
     let pass = add!(
         pass,
         Destructuring,
-        es2015::destructuring(es2015::destructuring::Config { loose }),
+        es2015::destructuring(es2015::destructuring::Config {
+            loose,
+            iterable_is_array: assumptions.iterable_is_array
+        }),
         true
     );
     let pass = add!(
@@ -350,6 +408,42 @@ where
     )
 }
 
+/// Returns the syntax which should be present for the pass of `f` to do
+/// anything.
+///
+/// Passes which are not listed here always run.
+fn required_syntax(f: Feature) -> SyntaxFeatures {
+    match f {
+        Feature::ClassStaticBlock => SyntaxFeatures::StaticBlocks,
+        Feature::ClassProperties => {
+            SyntaxFeatures::ClassFields
+                | SyntaxFeatures::PrivateNames
+                | SyntaxFeatures::StaticBlocks
+        }
+        Feature::PrivatePropertyInObject => SyntaxFeatures::PrivateNames,
+        Feature::LogicalAssignmentOperators => SyntaxFeatures::LogicalAssignment,
+        Feature::ExportNamespaceFrom => SyntaxFeatures::ExportNamespaceFrom,
+        Feature::NullishCoalescing => SyntaxFeatures::NullishCoalescing,
+        Feature::OptionalChaining => SyntaxFeatures::OptionalChaining,
+        Feature::OptionalCatchBinding => SyntaxFeatures::OptionalCatchBinding,
+        Feature::ObjectRestSpread => SyntaxFeatures::ObjectRestSpread,
+        Feature::AsyncToGenerator => SyntaxFeatures::AsyncFunctions,
+        Feature::ExponentiationOperator => SyntaxFeatures::Exponentiation,
+        Feature::TemplateLiterals => SyntaxFeatures::TemplateLiterals,
+        Feature::Classes => SyntaxFeatures::Classes,
+        Feature::Spread => SyntaxFeatures::Spread,
+        Feature::ShorthandProperties => SyntaxFeatures::ShorthandProperties,
+        Feature::ArrowFunctions => SyntaxFeatures::ArrowFunctions,
+        Feature::StickyRegex => SyntaxFeatures::RegExp,
+        Feature::ForOf => SyntaxFeatures::ForOf,
+        Feature::ComputedProperties => SyntaxFeatures::ComputedProperties,
+        Feature::Destructuring => SyntaxFeatures::Destructuring,
+        Feature::Regenerator => SyntaxFeatures::Generators,
+        Feature::NewTarget => SyntaxFeatures::NewTarget,
+        _ => SyntaxFeatures::empty(),
+    }
+}
+
 #[derive(Debug)]
 struct Polyfills {
     mode: Option<Mode>,
@@ -553,6 +647,16 @@ impl VisitMut for Polyfills {
     }
 }
 
+struct CustomPasses(Vec<Box<dyn Pass>>);
+
+impl Pass for CustomPasses {
+    fn process(&mut self, program: &mut Program) {
+        for pass in &mut self.0 {
+            pass.process(program);
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
 pub enum Mode {
     #[serde(rename = "usage")]
@@ -607,6 +711,44 @@ pub struct Config {
 
     #[serde(default)]
     pub bugfixes: bool,
+
+    /// Path to a file in the format of `plugins.json` of `@babel/compat-data`.
+    ///
+    /// Data of the file takes precedence over the built-in data, so targets
+    /// released after swc can be supported. Features missing in the file use
+    /// the built-in data.
+    #[serde(default)]
+    pub compat_data: Option<PathBuf>,
+
+    /// Features unknown to swc, with transforms for them.
+    #[serde(skip)]
+    pub custom_features: Vec<CustomFeature>,
+}
+
+/// A feature which is not known to swc.
+///
+/// Like built-in features, it can be enabled by `include` and disabled by
+/// `exclude` using [CustomFeature::name].
+#[derive(Clone)]
+pub struct CustomFeature {
+    /// Name of the feature, which is also the key in the compat data.
+    pub name: String,
+
+    /// The first versions supporting the feature. Data in
+    /// [Config::compat_data] takes precedence over this.
+    pub versions: Versions,
+
+    /// Creates the transform for the feature. Called with `unresolved_mark`.
+    pub transform: Arc<dyn Fn(Mark) -> Box<dyn Pass> + Send + Sync>,
+}
+
+impl fmt::Debug for CustomFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CustomFeature")
+            .field("name", &self.name)
+            .field("versions", &self.versions)
+            .finish_non_exhaustive()
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, FromVariant)]
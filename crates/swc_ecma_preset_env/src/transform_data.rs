@@ -1,3 +1,6 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
 use preset_env_base::{
     version::{should_enable, Version},
@@ -21,6 +24,80 @@ impl Feature {
     }
 }
 
+/// Compat data loaded at runtime, which takes precedence over the built-in
+/// data.
+#[derive(Debug, Default)]
+pub(crate) struct CompatData {
+    features: FxHashMap<Feature, Versions>,
+    /// Data of features unknown to swc, keyed by name.
+    custom: FxHashMap<String, Versions>,
+}
+
+impl CompatData {
+    /// Loads a file in the format of `plugins.json` of `@babel/compat-data`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("failed to read compat data at {}", path.display()))?;
+        let map: FxHashMap<String, BrowserData<Option<String>>> = serde_json::from_str(&json)
+            .with_context(|| format!("failed to parse compat data at {}", path.display()))?;
+
+        let mut data = CompatData::default();
+
+        for (name, versions) in map {
+            let versions = parse_versions(versions)
+                .with_context(|| format!("invalid compat data for `{}`", name))?;
+
+            match name.parse::<Feature>() {
+                Ok(feature) => {
+                    data.features.insert(feature, versions);
+                }
+                Err(..) => {
+                    data.custom.insert(name, versions);
+                }
+            }
+        }
+
+        Ok(data)
+    }
+
+    pub fn should_enable(
+        &self,
+        feature: Feature,
+        target: &Versions,
+        bugfixes: bool,
+        default: bool,
+    ) -> bool {
+        match self.features.get(&feature) {
+            Some(versions) => should_enable(target, versions, default),
+            None => feature.should_enable(target, bugfixes, default),
+        }
+    }
+
+    pub fn custom(&self, name: &str) -> Option<&Versions> {
+        self.custom.get(name)
+    }
+}
+
+fn parse_versions(versions: BrowserData<Option<String>>) -> Result<Versions> {
+    let mut error = None;
+
+    let versions = versions.map_value(|version| match version.as_deref() {
+        None | Some("tp") => None,
+        Some(v) => match v.parse() {
+            Ok(v) => Some(v),
+            Err(err) => {
+                error.get_or_insert_with(|| format!("failed to parse `{v}` as a version: {err:?}"));
+                None
+            }
+        },
+    });
+
+    match error {
+        Some(error) => Err(anyhow::anyhow!(error)),
+        None => Ok(versions),
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq, StringEnum, Hash)]
 #[non_exhaustive]
 pub enum Feature {
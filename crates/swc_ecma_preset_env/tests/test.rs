@@ -14,12 +14,15 @@ use rustc_hash::FxHashMap;
 use serde::Deserialize;
 use serde_json::Value;
 use swc_common::{
-    comments::SingleThreadedComments, errors::HANDLER, input::StringInput, FromVariant, Mark,
+    comments::SingleThreadedComments, errors::HANDLER, input::StringInput, sync::Lrc, FileName,
+    FromVariant, Mark, SourceMap,
 };
 use swc_ecma_ast::*;
 use swc_ecma_codegen::Emitter;
 use swc_ecma_parser::{Parser, Syntax};
-use swc_ecma_preset_env::{preset_env, Config, FeatureOrModule, Mode, Targets, Version};
+use swc_ecma_preset_env::{
+    preset_env, Config, CustomFeature, FeatureOrModule, Mode, Targets, Version,
+};
 use swc_ecma_transforms::{fixer, helpers};
 use swc_ecma_utils::drop_span;
 use swc_ecma_visit::{visit_mut_pass, VisitMut};
@@ -148,6 +151,8 @@ fn exec(c: PresetConfig, dir: PathBuf) -> Result<(), Error> {
                         shipped_proposals: c.shipped_proposals,
                         targets: c.targets,
                         path: std::env::current_dir().ok(),
+                        compat_data: None,
+                        custom_features: Vec::new(),
                     },
                     Default::default(),
                     &mut Default::default(),
@@ -308,3 +313,127 @@ fn fixture(input: PathBuf) {
 struct Normalizer;
 
 impl VisitMut for Normalizer {}
+
+fn print(cm: Lrc<SourceMap>, program: &Program) -> String {
+    let mut buf = Vec::new();
+    {
+        let mut emitter = Emitter {
+            cfg: swc_ecma_codegen::Config::default(),
+            comments: None,
+            cm: cm.clone(),
+            wr: Box::new(swc_ecma_codegen::text_writer::JsWriter::new(
+                cm, "\n", &mut buf, None,
+            )),
+        };
+
+        emitter.emit_program(program).unwrap();
+    }
+    String::from_utf8(buf).unwrap()
+}
+
+fn transform(src: &str, config: Config) -> String {
+    Tester::new()
+        .print_errors(|cm, handler| {
+            let fm = cm.new_source_file(FileName::Anon.into(), src.into());
+            let module = Parser::new(Syntax::default(), StringInput::from(&*fm), None)
+                .parse_module()
+                .map_err(|e| e.into_diagnostic(&handler).emit())?;
+
+            let pass = (
+                preset_env(
+                    Mark::new(),
+                    None::<SingleThreadedComments>,
+                    config,
+                    Default::default(),
+                    &mut Default::default(),
+                ),
+                fixer(None),
+            );
+
+            let program = helpers::HELPERS.set(&Default::default(), || {
+                HANDLER.set(&handler, || Program::Module(module).apply(pass))
+            });
+
+            Ok(print(cm, &program))
+        })
+        .unwrap()
+}
+
+fn chrome_40() -> Option<Targets> {
+    Some(serde_json::from_str(r#""chrome 40""#).unwrap())
+}
+
+#[test]
+fn compat_data() {
+    let src = "const f = () => 1;";
+
+    let output = transform(
+        src,
+        Config {
+            targets: chrome_40(),
+            ..Default::default()
+        },
+    );
+    assert!(!output.contains("=>"), "{}", output);
+
+    let output = transform(
+        src,
+        Config {
+            targets: chrome_40(),
+            compat_data: Some("tests/compat-data/plugins.json".into()),
+            ..Default::default()
+        },
+    );
+    assert!(output.contains("=>"), "{}", output);
+}
+
+#[test]
+fn custom_feature() {
+    let feature = CustomFeature {
+        name: "custom-feature".into(),
+        versions: swc_ecma_preset_env::BrowserData {
+            chrome: Some("50".parse().unwrap()),
+            ..Default::default()
+        },
+        transform: std::sync::Arc::new(|_| {
+            Box::new(fn_pass(|program| {
+                if let Program::Module(m) = program {
+                    m.body.clear();
+                }
+            }))
+        }),
+    };
+    let src = "foo();";
+
+    let output = transform(
+        src,
+        Config {
+            targets: chrome_40(),
+            custom_features: vec![feature.clone()],
+            ..Default::default()
+        },
+    );
+    assert_eq!(output, "");
+
+    let output = transform(
+        src,
+        Config {
+            targets: chrome_40(),
+            exclude: vec![FeatureOrModule::CoreJsModule("custom-feature".into())],
+            custom_features: vec![feature.clone()],
+            ..Default::default()
+        },
+    );
+    assert_eq!(output, "foo();\n");
+
+    let output = transform(
+        src,
+        Config {
+            targets: chrome_40(),
+            compat_data: Some("tests/compat-data/plugins.json".into()),
+            custom_features: vec![feature],
+            ..Default::default()
+        },
+    );
+    assert_eq!(output, "foo();\n");
+}
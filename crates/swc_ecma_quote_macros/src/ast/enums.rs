@@ -18,6 +18,7 @@ macro_rules! impl_simple_enum {
 }
 
 impl_simple_enum!(VarDeclKind, [Var, Const, Let]);
+impl_simple_enum!(MatchPatCombinator, [And, Or]);
 impl_simple_enum!(UnaryOp, [Minus, Plus, Bang, Tilde, TypeOf, Void, Delete]);
 impl_simple_enum!(UpdateOp, [PlusPlus, MinusMinus]);
 impl_simple_enum!(
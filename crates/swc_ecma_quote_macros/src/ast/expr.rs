@@ -58,6 +58,10 @@ impl_enum!(
         TsSatisfies,
         PrivateName,
         OptChain,
+        Pipeline,
+        Topic,
+        Match,
+        Do,
         Invalid
     ],
     true
@@ -109,6 +113,24 @@ impl_struct!(JSXFragment, [span, opening, closing, children]);
 impl_struct!(OptChainExpr, [span, optional, base]);
 
 impl_struct!(ParenExpr, [span, expr]);
+impl_struct!(PipelineExpr, [span, left, right]);
+impl_struct!(TopicExpr, [span]);
+impl_struct!(DoExpr, [span, body]);
+impl_struct!(MatchExpr, [span, subject, cases]);
+impl_struct!(MatchCase, [span, pat, guard, body]);
+impl_enum!(
+    MatchPat,
+    [Binding, Value, Relational, Array, Object, Combined, Not]
+);
+impl_struct!(BindingMatchPat, [span, kind, id]);
+impl_struct!(ValueMatchPat, [span, value]);
+impl_struct!(RelationalMatchPat, [span, op, value]);
+impl_struct!(ArrayMatchPat, [span, elems, rest]);
+impl_struct!(MatchRest, [span, arg]);
+impl_struct!(ObjectMatchPat, [span, props]);
+impl_struct!(ObjectMatchPatProp, [span, key, value]);
+impl_struct!(CombinedMatchPat, [span, op, pats]);
+impl_struct!(NotMatchPat, [span, arg]);
 impl_struct!(
     Function,
     [
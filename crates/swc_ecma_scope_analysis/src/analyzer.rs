@@ -0,0 +1,568 @@
+use swc_common::{BytePos, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{
+    Binding, BindingId, BindingKind, Reference, ReferenceKind, Scope, ScopeAnalysis, ScopeId,
+    ScopeKind,
+};
+
+#[derive(Debug, Clone, Copy)]
+enum Ctx {
+    Read,
+    Write,
+    ReadWrite,
+    Declare(BindingKind),
+}
+
+pub(crate) struct Analyzer {
+    data: ScopeAnalysis,
+    scope: ScopeId,
+    /// Identifies the function being visited. Class field initializers are
+    /// treated as functions, as they are evaluated later.
+    closure: u32,
+    next_closure: u32,
+    ctx: Ctx,
+    in_type: bool,
+    in_export_decl: bool,
+    /// End of the `let`, `const` or `using` declarator being visited.
+    decl_end: Option<BytePos>,
+    /// Bindings exported by `export { a }`, which may precede the declaration.
+    exported: Vec<Id>,
+}
+
+impl Analyzer {
+    pub fn new(program: &Program) -> Self {
+        let kind = match program {
+            Program::Module(..) => ScopeKind::Module,
+            Program::Script(..) => ScopeKind::Script,
+        };
+
+        Analyzer {
+            data: ScopeAnalysis {
+                scopes: vec![Scope {
+                    kind,
+                    span: program.span(),
+                    parent: None,
+                    children: Vec::new(),
+                    bindings: Vec::new(),
+                }],
+                bindings: Vec::new(),
+                references: Vec::new(),
+                ids: Default::default(),
+            },
+            scope: ScopeId(0),
+            closure: 0,
+            next_closure: 1,
+            ctx: Ctx::Read,
+            in_type: false,
+            in_export_decl: false,
+            decl_end: None,
+            exported: Vec::new(),
+        }
+    }
+
+    pub fn into_analysis(self) -> ScopeAnalysis {
+        let mut data = self.data;
+        for id in &self.exported {
+            if let Some(&b) = data.ids.get(id) {
+                data.bindings[b.0 as usize].exported = true;
+            }
+        }
+        data.references.sort_by_key(|r| r.span.lo);
+        data
+    }
+
+    fn with_scope(&mut self, kind: ScopeKind, span: Span, op: impl FnOnce(&mut Self)) {
+        let id = ScopeId(self.data.scopes.len() as u32);
+        self.data.scopes.push(Scope {
+            kind,
+            span,
+            parent: Some(self.scope),
+            children: Vec::new(),
+            bindings: Vec::new(),
+        });
+        self.data.scopes[self.scope.0 as usize].children.push(id);
+
+        let old = self.scope;
+        self.scope = id;
+        op(self);
+        self.scope = old;
+    }
+
+    fn with_closure(&mut self, op: impl FnOnce(&mut Self)) {
+        let old = self.closure;
+        self.closure = self.next_closure;
+        self.next_closure += 1;
+        op(self);
+        self.closure = old;
+    }
+
+    fn with_ctx(&mut self, ctx: Ctx, op: impl FnOnce(&mut Self)) {
+        let old = self.ctx;
+        self.ctx = ctx;
+        op(self);
+        self.ctx = old;
+    }
+
+    fn without_export(&mut self, op: impl FnOnce(&mut Self)) {
+        let old = self.in_export_decl;
+        self.in_export_decl = false;
+        op(self);
+        self.in_export_decl = old;
+    }
+
+    fn declare(&mut self, i: &Ident, kind: BindingKind, tdz_end: Option<BytePos>) {
+        let id = i.to_id();
+        if let Some(&b) = self.data.ids.get(&id) {
+            self.data.bindings[b.0 as usize].exported |= self.in_export_decl;
+            return;
+        }
+
+        let mut scope = self.scope;
+        if kind == BindingKind::Var {
+            while !self.data.scopes[scope.0 as usize].kind.is_var_scope() {
+                scope = self.data.scopes[scope.0 as usize].parent.unwrap();
+            }
+        }
+
+        let binding = BindingId(self.data.bindings.len() as u32);
+        self.data.bindings.push(Binding {
+            id: id.clone(),
+            kind,
+            scope,
+            span: i.span,
+            exported: self.in_export_decl,
+            references: Vec::new(),
+            tdz_end,
+            closure: self.closure,
+        });
+        self.data.scopes[scope.0 as usize].bindings.push(binding);
+        self.data.ids.insert(id, binding);
+    }
+
+    fn reference(&mut self, i: &Ident, kind: ReferenceKind) {
+        self.data.references.push(Reference {
+            id: i.to_id(),
+            span: i.span,
+            scope: self.scope,
+            binding: None,
+            kind,
+            is_type: self.in_type,
+            in_tdz: false,
+            closure: self.closure,
+        });
+    }
+
+    fn visit_fn_like(&mut self, span: Span, name: Option<&Ident>, op: impl FnOnce(&mut Self)) {
+        self.without_export(|v| {
+            v.with_closure(|v| {
+                v.with_scope(ScopeKind::Function, span, |v| {
+                    if let Some(name) = name {
+                        v.declare(name, BindingKind::Function, None);
+                    }
+                    op(v);
+                })
+            })
+        })
+    }
+
+    fn visit_function_parts(&mut self, n: &Function) {
+        n.decorators.visit_with(self);
+        n.type_params.visit_with(self);
+        n.params.visit_with(self);
+        n.return_type.visit_with(self);
+        // The body of a function is in the scope of the function.
+        if let Some(body) = &n.body {
+            body.stmts.visit_with(self);
+        }
+    }
+
+    fn visit_class_like(&mut self, name: Option<&Ident>, n: &Class) {
+        self.without_export(|v| {
+            v.with_scope(ScopeKind::Class, n.span, |v| {
+                if let Some(name) = name {
+                    v.declare(name, BindingKind::Class, Some(n.span.hi));
+                }
+                n.visit_children_with(v);
+            })
+        })
+    }
+}
+
+impl Visit for Analyzer {
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.visit_fn_like(n.span, None, |v| {
+            v.with_ctx(Ctx::Declare(BindingKind::Param), |v| n.params.visit_with(v));
+            n.type_params.visit_with(v);
+            n.return_type.visit_with(v);
+            match &*n.body {
+                BlockStmtOrExpr::BlockStmt(body) => body.stmts.visit_with(v),
+                BlockStmtOrExpr::Expr(body) => body.visit_with(v),
+            }
+        });
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        let ctx = if n.op == op!("=") {
+            Ctx::Write
+        } else {
+            Ctx::ReadWrite
+        };
+
+        self.with_ctx(ctx, |v| n.left.visit_with(v));
+        n.right.visit_with(self);
+    }
+
+    fn visit_binding_ident(&mut self, n: &BindingIdent) {
+        match self.ctx {
+            Ctx::Declare(kind) => {
+                let tdz_end = if kind.has_tdz() { self.decl_end } else { None };
+                self.declare(&n.id, kind, tdz_end)
+            }
+            Ctx::Write => self.reference(&n.id, ReferenceKind::Write),
+            Ctx::ReadWrite => self.reference(&n.id, ReferenceKind::ReadWrite),
+            Ctx::Read => self.reference(&n.id, ReferenceKind::Read),
+        }
+
+        self.with_ctx(Ctx::Read, |v| n.type_ann.visit_with(v));
+    }
+
+    fn visit_block_stmt(&mut self, n: &BlockStmt) {
+        self.with_scope(ScopeKind::Block, n.span, |v| n.stmts.visit_with(v));
+    }
+
+    fn visit_break_stmt(&mut self, _: &BreakStmt) {}
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        self.with_scope(ScopeKind::Catch, n.span, |v| {
+            v.with_ctx(Ctx::Declare(BindingKind::CatchParam), |v| {
+                n.param.visit_with(v)
+            });
+            n.body.stmts.visit_with(v);
+        });
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.declare(&n.ident, BindingKind::Class, Some(n.class.span.hi));
+        self.visit_class_like(None, &n.class);
+    }
+
+    fn visit_class_expr(&mut self, n: &ClassExpr) {
+        self.visit_class_like(n.ident.as_ref(), &n.class);
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp) {
+        n.decorators.visit_with(self);
+        n.key.visit_with(self);
+        n.type_ann.visit_with(self);
+        self.with_closure(|v| n.value.visit_with(v));
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor) {
+        n.key.visit_with(self);
+        self.visit_fn_like(n.span, None, |v| {
+            n.params.visit_with(v);
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_continue_stmt(&mut self, _: &ContinueStmt) {}
+
+    fn visit_export_decl(&mut self, n: &ExportDecl) {
+        let old = self.in_export_decl;
+        self.in_export_decl = true;
+        n.decl.visit_with(self);
+        self.in_export_decl = old;
+    }
+
+    fn visit_export_default_decl(&mut self, n: &ExportDefaultDecl) {
+        let old = self.in_export_decl;
+        self.in_export_decl = true;
+        match &n.decl {
+            DefaultDecl::Class(ClassExpr {
+                ident: Some(ident),
+                class,
+            }) => {
+                self.declare(ident, BindingKind::Class, Some(class.span.hi));
+                self.visit_class_like(None, class);
+            }
+            DefaultDecl::Fn(FnExpr {
+                ident: Some(ident),
+                function,
+            }) => {
+                self.declare(ident, BindingKind::Function, None);
+                function.visit_with(self);
+            }
+            _ => n.decl.visit_with(self),
+        }
+        self.in_export_decl = old;
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        self.with_ctx(Ctx::Read, |v| n.visit_children_with(v));
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        self.declare(&n.ident, BindingKind::Function, None);
+        n.function.visit_with(self);
+    }
+
+    fn visit_fn_expr(&mut self, n: &FnExpr) {
+        self.visit_fn_like(n.function.span, n.ident.as_ref(), |v| {
+            v.visit_function_parts(&n.function)
+        });
+    }
+
+    fn visit_for_head(&mut self, n: &ForHead) {
+        match n {
+            ForHead::Pat(p) => self.with_ctx(Ctx::Write, |v| p.visit_with(v)),
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_for_in_stmt(&mut self, n: &ForInStmt) {
+        self.with_scope(ScopeKind::Block, n.span, |v| n.visit_children_with(v));
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.with_scope(ScopeKind::Block, n.span, |v| n.visit_children_with(v));
+    }
+
+    fn visit_for_stmt(&mut self, n: &ForStmt) {
+        self.with_scope(ScopeKind::Block, n.span, |v| n.visit_children_with(v));
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        self.visit_fn_like(n.span, None, |v| v.visit_function_parts(n));
+    }
+
+    fn visit_getter_prop(&mut self, n: &GetterProp) {
+        n.key.visit_with(self);
+        self.visit_fn_like(n.span, None, |v| {
+            n.type_ann.visit_with(v);
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        let kind = match self.ctx {
+            Ctx::Write => ReferenceKind::Write,
+            Ctx::ReadWrite => ReferenceKind::ReadWrite,
+            _ => ReferenceKind::Read,
+        };
+        self.reference(n, kind);
+    }
+
+    fn visit_import_default_specifier(&mut self, n: &ImportDefaultSpecifier) {
+        self.declare(&n.local, BindingKind::Import, None);
+    }
+
+    fn visit_import_named_specifier(&mut self, n: &ImportNamedSpecifier) {
+        self.declare(&n.local, BindingKind::Import, None);
+    }
+
+    fn visit_import_star_as_specifier(&mut self, n: &ImportStarAsSpecifier) {
+        self.declare(&n.local, BindingKind::Import, None);
+    }
+
+    fn visit_jsx_element_name(&mut self, n: &JSXElementName) {
+        match n {
+            // Intrinsic elements like `<div />`.
+            JSXElementName::Ident(i) if i.sym.starts_with(|c: char| c.is_ascii_lowercase()) => {}
+            _ => n.visit_children_with(self),
+        }
+    }
+
+    fn visit_labeled_stmt(&mut self, n: &LabeledStmt) {
+        n.body.visit_with(self);
+    }
+
+    fn visit_member_prop(&mut self, n: &MemberProp) {
+        if let MemberProp::Computed(c) = n {
+            c.visit_with(self);
+        }
+    }
+
+    fn visit_named_export(&mut self, n: &NamedExport) {
+        // Re-exports don't reference local bindings.
+        if n.src.is_some() {
+            return;
+        }
+
+        for s in &n.specifiers {
+            if let ExportSpecifier::Named(ExportNamedSpecifier {
+                orig: ModuleExportName::Ident(orig),
+                ..
+            }) = s
+            {
+                self.reference(orig, ReferenceKind::Read);
+                self.exported.push(orig.to_id());
+            }
+        }
+    }
+
+    fn visit_param(&mut self, n: &Param) {
+        n.decorators.visit_with(self);
+        self.with_ctx(Ctx::Declare(BindingKind::Param), |v| n.pat.visit_with(v));
+    }
+
+    fn visit_private_prop(&mut self, n: &PrivateProp) {
+        n.decorators.visit_with(self);
+        n.type_ann.visit_with(self);
+        self.with_closure(|v| n.value.visit_with(v));
+    }
+
+    fn visit_prop_name(&mut self, n: &PropName) {
+        if let PropName::Computed(c) = n {
+            c.visit_with(self);
+        }
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp) {
+        n.key.visit_with(self);
+        self.visit_fn_like(n.span, None, |v| {
+            v.with_ctx(Ctx::Declare(BindingKind::Param), |v| n.param.visit_with(v));
+            if let Some(body) = &n.body {
+                body.stmts.visit_with(v);
+            }
+        });
+    }
+
+    fn visit_static_block(&mut self, n: &StaticBlock) {
+        self.visit_fn_like(n.span, None, |v| n.body.stmts.visit_with(v));
+    }
+
+    fn visit_super_prop(&mut self, n: &SuperProp) {
+        if let SuperProp::Computed(c) = n {
+            c.visit_with(self);
+        }
+    }
+
+    fn visit_switch_stmt(&mut self, n: &SwitchStmt) {
+        n.discriminant.visit_with(self);
+        self.with_scope(ScopeKind::Block, n.span, |v| n.cases.visit_with(v));
+    }
+
+    fn visit_ts_entity_name(&mut self, n: &TsEntityName) {
+        match n {
+            TsEntityName::Ident(i) => self.reference(i, ReferenceKind::Read),
+            TsEntityName::TsQualifiedName(q) => q.left.visit_with(self),
+        }
+    }
+
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+        self.declare(&n.id, BindingKind::Type, None);
+        self.without_export(|v| n.members.visit_with(v));
+    }
+
+    fn visit_ts_enum_member_id(&mut self, _: &TsEnumMemberId) {}
+
+    fn visit_ts_interface_decl(&mut self, n: &TsInterfaceDecl) {
+        self.declare(&n.id, BindingKind::Type, None);
+
+        let old = self.in_type;
+        self.in_type = true;
+        self.without_export(|v| {
+            n.type_params.visit_with(v);
+            n.extends.visit_with(v);
+            n.body.visit_with(v);
+        });
+        self.in_type = old;
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        if let TsModuleName::Ident(i) = &n.id {
+            self.declare(i, BindingKind::Type, None);
+        }
+        self.without_export(|v| {
+            v.with_scope(ScopeKind::TsModule, n.span, |v| n.body.visit_with(v))
+        });
+    }
+
+    fn visit_ts_method_signature(&mut self, n: &TsMethodSignature) {
+        if n.computed {
+            n.key.visit_with(self);
+        }
+        n.type_params.visit_with(self);
+        n.params.visit_with(self);
+        n.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_namespace_decl(&mut self, n: &TsNamespaceDecl) {
+        n.body.visit_with(self);
+    }
+
+    fn visit_ts_param_prop(&mut self, n: &TsParamProp) {
+        n.decorators.visit_with(self);
+        self.with_ctx(Ctx::Declare(BindingKind::Param), |v| n.param.visit_with(v));
+    }
+
+    fn visit_ts_property_signature(&mut self, n: &TsPropertySignature) {
+        if n.computed {
+            n.key.visit_with(self);
+        }
+        n.type_ann.visit_with(self);
+    }
+
+    fn visit_ts_type(&mut self, n: &TsType) {
+        let old = self.in_type;
+        self.in_type = true;
+        self.with_ctx(Ctx::Read, |v| n.visit_children_with(v));
+        self.in_type = old;
+    }
+
+    fn visit_ts_type_alias_decl(&mut self, n: &TsTypeAliasDecl) {
+        self.declare(&n.id, BindingKind::Type, None);
+        self.without_export(|v| {
+            n.type_params.visit_with(v);
+            n.type_ann.visit_with(v);
+        });
+    }
+
+    fn visit_ts_type_param(&mut self, n: &TsTypeParam) {
+        self.declare(&n.name, BindingKind::Type, None);
+        n.constraint.visit_with(self);
+        n.default.visit_with(self);
+    }
+
+    fn visit_update_expr(&mut self, n: &UpdateExpr) {
+        match &*n.arg {
+            Expr::Ident(i) => self.reference(i, ReferenceKind::ReadWrite),
+            _ => n.arg.visit_with(self),
+        }
+    }
+
+    fn visit_using_decl(&mut self, n: &UsingDecl) {
+        for decl in &n.decls {
+            self.visit_declarator(decl, BindingKind::Using);
+        }
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl) {
+        let kind = match n.kind {
+            VarDeclKind::Var => BindingKind::Var,
+            VarDeclKind::Let => BindingKind::Let,
+            VarDeclKind::Const => BindingKind::Const,
+        };
+
+        for decl in &n.decls {
+            self.visit_declarator(decl, kind);
+        }
+    }
+}
+
+impl Analyzer {
+    fn visit_declarator(&mut self, decl: &VarDeclarator, kind: BindingKind) {
+        let old = self.decl_end;
+        self.decl_end = Some(decl.span.hi);
+        self.with_ctx(Ctx::Declare(kind), |v| decl.name.visit_with(v));
+        self.decl_end = old;
+
+        self.without_export(|v| decl.init.visit_with(v));
+    }
+}
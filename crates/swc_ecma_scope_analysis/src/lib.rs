@@ -0,0 +1,267 @@
+//! Scope analysis for tools which inspect programs, like linters.
+//!
+//! [ScopeAnalysis] describes the scopes of a program, the bindings declared in
+//! each scope and the references to them, which is what the `resolver` pass
+//! uses to assign [SyntaxContext]s.
+//!
+//! The program must be processed by `resolver` before it's analyzed, as
+//! bindings are identified by their [Id].
+//!
+//! ```rust,ignore
+//! program.visit_mut_with(&mut resolver(unresolved_mark, top_level_mark, false));
+//!
+//! let analysis = ScopeAnalysis::for_program(&program);
+//!
+//! for reference in analysis.references().filter(|r| r.in_tdz) {
+//!     // `reference` is used before it's initialized.
+//! }
+//! ```
+//!
+//! [SyntaxContext]: swc_common::SyntaxContext
+
+#![deny(clippy::all)]
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_common::{BytePos, Span};
+use swc_ecma_ast::{Id, Program};
+use swc_ecma_visit::VisitWith;
+
+use self::analyzer::Analyzer;
+
+mod analyzer;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ScopeId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BindingId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ReferenceId(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+    /// The root scope of a module.
+    Module,
+    /// The root scope of a script.
+    Script,
+    /// Parameters and body of a function, including arrow functions, methods,
+    /// getters, setters and class static blocks.
+    Function,
+    /// Block statements, `for` loops and `switch` statements.
+    Block,
+    /// Parameter and body of a `catch` clause.
+    Catch,
+    /// Body of a class, including the name of a class expression.
+    Class,
+    /// Body of a TypeScript namespace.
+    TsModule,
+}
+
+impl ScopeKind {
+    /// Returns `true` if `var` declarations in the scope are hoisted to it.
+    pub fn is_var_scope(self) -> bool {
+        matches!(
+            self,
+            ScopeKind::Module | ScopeKind::Script | ScopeKind::Function | ScopeKind::TsModule
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingKind {
+    Var,
+    Let,
+    Const,
+    Using,
+    Function,
+    Class,
+    Param,
+    CatchParam,
+    Import,
+    /// Enums, namespaces, interfaces, type aliases and type parameters.
+    Type,
+}
+
+impl BindingKind {
+    /// Returns `true` if the binding can't be accessed before its
+    /// declaration is evaluated.
+    pub fn has_tdz(self) -> bool {
+        matches!(
+            self,
+            BindingKind::Let | BindingKind::Const | BindingKind::Using | BindingKind::Class
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceKind {
+    Read,
+    Write,
+    /// Compound assignments and updates, like `a += 1` and `a++`.
+    ReadWrite,
+}
+
+#[derive(Debug, Clone)]
+pub struct Scope {
+    pub kind: ScopeKind,
+    pub span: Span,
+    /// [None] for the root scope.
+    pub parent: Option<ScopeId>,
+    pub children: Vec<ScopeId>,
+    /// Bindings declared in the scope, in the order they are declared.
+    pub bindings: Vec<BindingId>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub id: Id,
+    pub kind: BindingKind,
+    /// The scope the binding is declared in. `var` declarations are hoisted
+    /// to the nearest [ScopeKind::is_var_scope] scope.
+    pub scope: ScopeId,
+    /// Span of the identifier of the first declaration.
+    pub span: Span,
+    pub exported: bool,
+    /// References to the binding, in source order.
+    pub references: Vec<ReferenceId>,
+
+    /// End of the declaration, where the temporal dead zone ends.
+    tdz_end: Option<BytePos>,
+    /// The function the binding is declared in.
+    closure: u32,
+}
+
+#[derive(Debug, Clone)]
+pub struct Reference {
+    pub id: Id,
+    pub span: Span,
+    /// The innermost scope containing the reference.
+    pub scope: ScopeId,
+    /// [None] if the reference is not resolved, like references to globals.
+    pub binding: Option<BindingId>,
+    pub kind: ReferenceKind,
+    /// `true` for references from type annotations.
+    pub is_type: bool,
+    /// `true` if the reference is evaluated before the declaration of the
+    /// binding, which throws a `ReferenceError`.
+    ///
+    /// References from functions are never in the temporal dead zone, as the
+    /// function may be called after the declaration.
+    pub in_tdz: bool,
+
+    /// The function the reference is in.
+    closure: u32,
+}
+
+/// Scopes, bindings and references of a program.
+#[derive(Debug, Clone)]
+pub struct ScopeAnalysis {
+    scopes: Vec<Scope>,
+    bindings: Vec<Binding>,
+    references: Vec<Reference>,
+    ids: FxHashMap<Id, BindingId>,
+}
+
+impl ScopeAnalysis {
+    /// Analyzes a program processed by `resolver`.
+    pub fn for_program(program: &Program) -> Self {
+        let mut v = Analyzer::new(program);
+        program.visit_children_with(&mut v);
+
+        let mut analysis = v.into_analysis();
+        analysis.resolve();
+        analysis
+    }
+
+    fn resolve(&mut self) {
+        for (i, r) in self.references.iter_mut().enumerate() {
+            let binding = match self.ids.get(&r.id) {
+                Some(&v) => v,
+                None => continue,
+            };
+            r.binding = Some(binding);
+
+            let b = &mut self.bindings[binding.0 as usize];
+            b.references.push(ReferenceId(i as u32));
+            r.in_tdz = b.kind.has_tdz()
+                && b.closure == r.closure
+                && b.tdz_end.is_some_and(|end| r.span.lo < end);
+        }
+    }
+
+    pub fn root(&self) -> ScopeId {
+        ScopeId(0)
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.0 as usize]
+    }
+
+    /// Iterates over scopes, with parents before children.
+    pub fn scopes(&self) -> impl Iterator<Item = (ScopeId, &Scope)> {
+        self.scopes
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (ScopeId(i as u32), s))
+    }
+
+    pub fn binding(&self, id: BindingId) -> &Binding {
+        &self.bindings[id.0 as usize]
+    }
+
+    /// Iterates over bindings in the order they are declared.
+    pub fn bindings(&self) -> impl Iterator<Item = (BindingId, &Binding)> {
+        self.bindings
+            .iter()
+            .enumerate()
+            .map(|(i, b)| (BindingId(i as u32), b))
+    }
+
+    /// Returns the binding declared with `id`.
+    pub fn binding_of(&self, id: &Id) -> Option<BindingId> {
+        self.ids.get(id).copied()
+    }
+
+    pub fn reference(&self, id: ReferenceId) -> &Reference {
+        &self.references[id.0 as usize]
+    }
+
+    /// Iterates over references in source order.
+    pub fn references(&self) -> impl Iterator<Item = &Reference> {
+        self.references.iter()
+    }
+
+    /// Iterates over references which are not resolved to a binding, like
+    /// references to globals.
+    pub fn unresolved_references(&self) -> impl Iterator<Item = &Reference> {
+        self.references.iter().filter(|r| r.binding.is_none())
+    }
+
+    /// Returns `true` if `scope` is `ancestor` or is nested in it.
+    pub fn is_within(&self, scope: ScopeId, ancestor: ScopeId) -> bool {
+        let mut cur = Some(scope);
+        while let Some(id) = cur {
+            if id == ancestor {
+                return true;
+            }
+            cur = self.scope(id).parent;
+        }
+
+        false
+    }
+
+    /// Returns the bindings declared outside of `scope` which are referenced
+    /// from it, like variables captured by a closure.
+    pub fn captures(&self, scope: ScopeId) -> Vec<BindingId> {
+        let mut seen = FxHashSet::default();
+
+        self.references
+            .iter()
+            .filter(|r| self.is_within(r.scope, scope))
+            .filter_map(|r| r.binding)
+            .filter(|&b| !self.is_within(self.binding(b).scope, scope))
+            .filter(|&b| seen.insert(b))
+            .collect()
+    }
+}
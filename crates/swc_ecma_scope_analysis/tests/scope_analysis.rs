@@ -0,0 +1,245 @@
+use swc_common::{FileName, Mark};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_module, Syntax};
+use swc_ecma_scope_analysis::{BindingId, BindingKind, ReferenceKind, ScopeAnalysis, ScopeKind};
+use swc_ecma_transforms_base::resolver;
+
+fn analyze(src: &str, op: impl FnOnce(&ScopeAnalysis)) {
+    testing::run_test(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Anon.into(), src.to_string());
+        let module = parse_file_as_module(
+            &fm,
+            Syntax::Typescript(Default::default()),
+            EsVersion::latest(),
+            None,
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let program = Program::Module(module).apply(resolver(Mark::new(), Mark::new(), true));
+        op(&ScopeAnalysis::for_program(&program));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+/// Returns the only binding named `name`.
+fn binding(analysis: &ScopeAnalysis, name: &str) -> BindingId {
+    let mut bindings = analysis.bindings().filter(|(_, b)| b.id.0 == *name);
+    let (id, _) = bindings
+        .next()
+        .unwrap_or_else(|| panic!("`{}` is not declared", name));
+    assert!(bindings.next().is_none(), "`{}` is declared twice", name);
+    id
+}
+
+fn names(analysis: &ScopeAnalysis, ids: impl IntoIterator<Item = BindingId>) -> Vec<String> {
+    ids.into_iter()
+        .map(|id| analysis.binding(id).id.0.to_string())
+        .collect()
+}
+
+#[test]
+fn scope_tree() {
+    analyze(
+        "
+        var a = 1;
+        function f(b) {
+            if (b) {
+                var c;
+                let d;
+            }
+        }
+        try {} catch (e) {}
+        ",
+        |analysis| {
+            let root = analysis.root();
+            assert_eq!(analysis.scope(root).kind, ScopeKind::Module);
+            assert_eq!(
+                names(analysis, analysis.scope(root).bindings.clone()),
+                ["a", "f"]
+            );
+
+            let f = analysis.binding(binding(analysis, "b")).scope;
+            assert_eq!(analysis.scope(f).kind, ScopeKind::Function);
+            assert_eq!(analysis.scope(f).parent, Some(root));
+            // `var` is hoisted out of the block.
+            assert_eq!(
+                names(analysis, analysis.scope(f).bindings.clone()),
+                ["b", "c"]
+            );
+
+            let d = analysis.binding(binding(analysis, "d"));
+            assert_eq!(d.kind, BindingKind::Let);
+            assert_eq!(analysis.scope(d.scope).kind, ScopeKind::Block);
+            assert!(analysis.is_within(d.scope, f));
+
+            let e = analysis.binding(binding(analysis, "e"));
+            assert_eq!(e.kind, BindingKind::CatchParam);
+            assert_eq!(analysis.scope(e.scope).kind, ScopeKind::Catch);
+            assert!(!analysis.is_within(e.scope, f));
+        },
+    );
+}
+
+#[test]
+fn references() {
+    analyze(
+        "
+        let a = 1;
+        a = 2;
+        a += 3;
+        a++;
+        console.log(a, b.c);
+        ",
+        |analysis| {
+            let a = analysis.binding(binding(analysis, "a"));
+            let kinds = a
+                .references
+                .iter()
+                .map(|&r| analysis.reference(r).kind)
+                .collect::<Vec<_>>();
+            assert_eq!(
+                kinds,
+                [
+                    ReferenceKind::Write,
+                    ReferenceKind::ReadWrite,
+                    ReferenceKind::ReadWrite,
+                    ReferenceKind::Read
+                ]
+            );
+
+            let unresolved = analysis
+                .unresolved_references()
+                .map(|r| r.id.0.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(unresolved, ["console", "b"]);
+        },
+    );
+}
+
+#[test]
+fn shadowing() {
+    analyze(
+        "
+        const a = 1;
+        function f() {
+            const a = 2;
+            return a;
+        }
+        a;
+        ",
+        |analysis| {
+            let outer = analysis
+                .bindings()
+                .find(|(_, b)| b.id.0 == *"a" && b.scope == analysis.root())
+                .unwrap()
+                .1;
+            let inner = analysis
+                .bindings()
+                .find(|(_, b)| b.id.0 == *"a" && b.scope != analysis.root())
+                .unwrap()
+                .1;
+
+            assert_ne!(outer.id, inner.id);
+            assert_eq!(outer.references.len(), 1);
+            assert_eq!(inner.references.len(), 1);
+        },
+    );
+}
+
+#[test]
+fn captures() {
+    analyze(
+        "
+        const a = 1, b = 2;
+        function f(c) {
+            const d = c + a;
+            return () => a + b + d;
+        }
+        ",
+        |analysis| {
+            let f = analysis.binding(binding(analysis, "c")).scope;
+            assert_eq!(names(analysis, analysis.captures(f)), ["a", "b"]);
+
+            let arrow = analysis
+                .scopes()
+                .find(|(id, s)| s.kind == ScopeKind::Function && *id != f)
+                .unwrap()
+                .0;
+            assert_eq!(names(analysis, analysis.captures(arrow)), ["a", "b", "d"]);
+        },
+    );
+}
+
+#[test]
+fn tdz() {
+    analyze(
+        "
+        a;
+        let a = a;
+        a;
+        function f() {
+            return b;
+        }
+        f();
+        const b = new C();
+        class C {
+            static c = C;
+        }
+        ",
+        |analysis| {
+            let in_tdz = analysis
+                .references()
+                .filter(|r| r.in_tdz)
+                .map(|r| r.id.0.to_string())
+                .collect::<Vec<_>>();
+            // The reference from `f` and the class field are evaluated later.
+            assert_eq!(in_tdz, ["a", "a", "C"]);
+        },
+    );
+}
+
+#[test]
+fn exports() {
+    analyze(
+        "
+        export { b };
+        export const a = 1;
+        const b = 2, c = 3;
+        export default function d() {}
+        export function e(f) {}
+        ",
+        |analysis| {
+            let exported = analysis
+                .bindings()
+                .filter(|(_, b)| b.exported)
+                .map(|(id, _)| id);
+            assert_eq!(names(analysis, exported), ["a", "b", "d", "e"]);
+        },
+    );
+}
+
+#[test]
+fn types() {
+    analyze(
+        "
+        import { T } from 'a';
+        interface I<U> {
+            a: T;
+            b: U;
+        }
+        let x: I<number>;
+        ",
+        |analysis| {
+            let t = analysis.binding(binding(analysis, "T"));
+            assert_eq!(t.kind, BindingKind::Import);
+            assert_eq!(t.references.len(), 1);
+            assert!(analysis.reference(t.references[0]).is_type);
+
+            assert_eq!(analysis.binding(binding(analysis, "U")).references.len(), 1);
+            assert_eq!(analysis.binding(binding(analysis, "I")).references.len(), 1);
+        },
+    );
+}
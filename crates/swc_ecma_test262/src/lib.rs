@@ -0,0 +1,441 @@
+//! Runs [test262](https://github.com/tc39/test262) against swc.
+//!
+//! [Test262] discovers tests in a checkout of test262, runs the selected
+//! [Mode] against each of them and returns a [Report]. Reports can be saved
+//! and compared with a baseline to find fixed and regressed tests.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::path::Path;
+//!
+//! use swc_ecma_test262::{Engine, Mode, Report, Test262};
+//!
+//! let report = Test262::new("test262")
+//!     .filter("language/expressions/class")
+//!     .skip_feature("decorators")
+//!     .mode(Mode::Exec(Engine::node()))
+//!     .transform(|program| program)
+//!     .run()
+//!     .unwrap();
+//!
+//! let baseline = Report::load(Path::new("baseline.json")).unwrap();
+//! println!("{}", report.compare(&baseline));
+//! ```
+#![deny(clippy::all)]
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{Arc, Mutex},
+};
+
+use anyhow::{bail, Context, Result};
+use rayon::prelude::*;
+use swc_common::{
+    errors::{Handler, HANDLER},
+    sync::Lrc,
+    FileName, Globals, SourceMap, GLOBALS,
+};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_parser::{parse_file_as_module, parse_file_as_script, Syntax};
+use walkdir::WalkDir;
+
+pub use self::{
+    metadata::{Flags, Metadata, Negative, Phase},
+    report::{Delta, Outcome, Report},
+};
+
+pub mod metadata;
+mod report;
+
+/// What to check for each test.
+#[derive(Debug, Clone)]
+pub enum Mode {
+    /// Tests which are syntactically valid should be parsed without errors.
+    ///
+    /// If a transform is configured, it's applied and the generated code
+    /// should be parsed again without errors.
+    Parse,
+    /// Tests which are expected to fail while parsing should be rejected by
+    /// the parser.
+    EarlyErrors,
+    /// Tests are parsed, transformed, printed and evaluated with a JavaScript
+    /// engine.
+    Exec(Engine),
+}
+
+/// A command line JavaScript engine used by [Mode::Exec].
+///
+/// The engine is invoked with `args` followed by the path to the test, and
+/// should exit with a non-zero status on an uncaught error. Modules are
+/// written to files with the `.mjs` extension.
+#[derive(Debug, Clone)]
+pub struct Engine {
+    pub program: PathBuf,
+    pub args: Vec<String>,
+}
+
+impl Engine {
+    pub fn node() -> Self {
+        Engine {
+            program: "node".into(),
+            args: Vec::new(),
+        }
+    }
+}
+
+type Transform = dyn Fn(Program) -> Program + Send + Sync;
+
+/// Runs test262.
+///
+/// `root` is the root directory of test262, which contains `harness` and
+/// `test`.
+pub struct Test262 {
+    root: PathBuf,
+    mode: Mode,
+    syntax: Syntax,
+    filters: Vec<String>,
+    skip_features: Vec<String>,
+    transform: Option<Box<Transform>>,
+}
+
+impl Test262 {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Test262 {
+            root: root.into(),
+            mode: Mode::Parse,
+            syntax: Default::default(),
+            filters: Vec::new(),
+            skip_features: Vec::new(),
+            transform: None,
+        }
+    }
+
+    /// Defaults to [Mode::Parse].
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn syntax(mut self, syntax: Syntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    /// Only runs tests whose path relative to `test/` starts with `prefix`,
+    /// like `language/expressions`.
+    ///
+    /// If called multiple times, tests matching any of the prefixes are run.
+    pub fn filter(mut self, prefix: impl Into<String>) -> Self {
+        self.filters.push(prefix.into());
+        self
+    }
+
+    /// Skips tests which use `feature`.
+    pub fn skip_feature(mut self, feature: impl Into<String>) -> Self {
+        self.skip_features.push(feature.into());
+        self
+    }
+
+    /// Applies `transform` to each test before printing it.
+    ///
+    /// [HANDLER] is configured while `transform` is called, and emitting an
+    /// error fails the test.
+    pub fn transform(
+        mut self,
+        transform: impl Fn(Program) -> Program + Send + Sync + 'static,
+    ) -> Self {
+        self.transform = Some(Box::new(transform));
+        self
+    }
+
+    pub fn run(&self) -> Result<Report> {
+        let test_dir = self.root.join("test");
+        if !test_dir.is_dir() {
+            bail!("{} is not a directory", test_dir.display());
+        }
+
+        let mut tests = Vec::new();
+        for entry in WalkDir::new(&test_dir).sort_by_file_name() {
+            let entry = entry?;
+            let path = entry.path();
+
+            if !entry.file_type().is_file()
+                || path.extension().map_or(true, |ext| ext != "js")
+                || path.to_string_lossy().contains("_FIXTURE")
+            {
+                continue;
+            }
+
+            let name = path
+                .strip_prefix(&test_dir)?
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            if !self.filters.is_empty() && !self.filters.iter().any(|f| name.starts_with(&**f)) {
+                continue;
+            }
+
+            tests.push((name, path.to_path_buf()));
+        }
+
+        let results = tests
+            .par_iter()
+            .map(|(name, path)| self.run_test(name, path))
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut report = Report::default();
+        for (name, outcome, failure) in results.into_iter().flatten() {
+            if let Some(failure) = failure {
+                report.failures.insert(name.clone(), failure);
+            }
+            report.outcomes.insert(name, outcome);
+        }
+
+        Ok(report)
+    }
+
+    fn run_test(&self, name: &str, path: &Path) -> Result<Vec<(String, Outcome, Option<String>)>> {
+        let src = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let meta = Metadata::parse(&src).unwrap_or_default();
+
+        let mut cases = Vec::new();
+        if meta.flags.module || meta.flags.raw || meta.flags.no_strict {
+            cases.push((name.to_string(), false));
+        } else if meta.flags.only_strict {
+            cases.push((format!("{} (strict)", name), true));
+        } else {
+            cases.push((name.to_string(), false));
+            cases.push((format!("{} (strict)", name), true));
+        }
+
+        cases
+            .into_iter()
+            .map(|(name, strict)| {
+                let result = if self.should_skip(&meta, &src) {
+                    Ok((Outcome::Skip, None))
+                } else {
+                    self.run_case(path, &src, &meta, strict)
+                };
+
+                result.map(|(outcome, failure)| (name, outcome, failure))
+            })
+            .collect()
+    }
+
+    fn should_skip(&self, meta: &Metadata, src: &str) -> bool {
+        if meta.features.iter().any(|f| self.skip_features.contains(f)) {
+            return true;
+        }
+
+        let is_parse_negative = matches!(&meta.negative, Some(n) if n.phase == Phase::Parse);
+        match self.mode {
+            Mode::Parse => is_parse_negative,
+            Mode::EarlyErrors => !is_parse_negative,
+            // Fixtures of module tests are not resolved, and resolution is not
+            // done by swc.
+            Mode::Exec(..) => {
+                (meta.flags.module && src.contains("_FIXTURE"))
+                    || matches!(&meta.negative, Some(n) if n.phase == Phase::Resolution)
+            }
+        }
+    }
+
+    fn run_case(
+        &self,
+        path: &Path,
+        src: &str,
+        meta: &Metadata,
+        strict: bool,
+    ) -> Result<(Outcome, Option<String>)> {
+        let src = if strict {
+            format!("\"use strict\";\n{}", src)
+        } else {
+            src.to_string()
+        };
+
+        let output = compile(
+            path,
+            src,
+            meta.flags.module,
+            self.syntax,
+            |program| match &self.transform {
+                Some(transform) => transform(program),
+                None => program,
+            },
+        );
+
+        let code = match (&self.mode, output) {
+            (Mode::EarlyErrors, Ok(..)) => {
+                return Ok((Outcome::Fail, Some("parsed without errors".into())))
+            }
+            (Mode::EarlyErrors, Err(..)) => return Ok((Outcome::Pass, None)),
+            (Mode::Exec(..), Err(..)) if matches!(&meta.negative, Some(n) if n.phase == Phase::Parse) => {
+                return Ok((Outcome::Pass, None))
+            }
+            (_, Err(err)) => return Ok((Outcome::Fail, Some(err))),
+            (_, Ok(code)) => code,
+        };
+
+        match &self.mode {
+            Mode::Parse => {
+                if self.transform.is_some() {
+                    if let Err(err) = compile(path, code, meta.flags.module, self.syntax, |p| p) {
+                        return Ok((Outcome::Fail, Some(format!("invalid output: {}", err))));
+                    }
+                }
+                Ok((Outcome::Pass, None))
+            }
+            Mode::EarlyErrors => unreachable!(),
+            Mode::Exec(engine) => self.exec(engine, meta, strict, &code),
+        }
+    }
+
+    fn exec(
+        &self,
+        engine: &Engine,
+        meta: &Metadata,
+        strict: bool,
+        code: &str,
+    ) -> Result<(Outcome, Option<String>)> {
+        if matches!(&meta.negative, Some(n) if n.phase == Phase::Parse) {
+            return Ok((Outcome::Fail, Some("parsed without errors".into())));
+        }
+
+        let mut script = String::new();
+        if strict {
+            script.push_str("\"use strict\";\n");
+        }
+        if !meta.flags.raw {
+            let mut includes = vec!["assert.js", "sta.js"];
+            if meta.flags.is_async {
+                includes.push("doneprintHandle.js");
+            }
+            includes.extend(meta.includes.iter().map(|s| &**s));
+
+            for include in includes {
+                let path = self.root.join("harness").join(include);
+                let harness = fs::read_to_string(&path)
+                    .with_context(|| format!("failed to read {}", path.display()))?;
+                script.push_str(&harness);
+                script.push('\n');
+            }
+        }
+        script.push_str(code);
+
+        let file = tempfile::Builder::new()
+            .suffix(if meta.flags.module { ".mjs" } else { ".js" })
+            .tempfile()?;
+        fs::write(file.path(), script)?;
+
+        let output = Command::new(&engine.program)
+            .args(&engine.args)
+            .arg(file.path())
+            .output()
+            .with_context(|| format!("failed to run {}", engine.program.display()))?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+
+        let passed = match &meta.negative {
+            Some(negative) => {
+                !output.status.success()
+                    && (stderr.contains(&*negative.error_type)
+                        || stdout.contains(&*negative.error_type))
+            }
+            None => {
+                output.status.success()
+                    && (!meta.flags.is_async || stdout.contains("Test262:AsyncTestComplete"))
+            }
+        };
+
+        if passed {
+            Ok((Outcome::Pass, None))
+        } else {
+            Ok((Outcome::Fail, Some(format!("{}{}", stdout, stderr))))
+        }
+    }
+}
+
+/// Parses `src`, applies `transform` and prints the result.
+///
+/// Returns the diagnostics on error.
+fn compile(
+    path: &Path,
+    src: String,
+    is_module: bool,
+    syntax: Syntax,
+    transform: impl FnOnce(Program) -> Program,
+) -> Result<String, String> {
+    let cm: Lrc<SourceMap> = Default::default();
+    let buf = SharedBuf::default();
+    let handler = Handler::with_emitter_writer(Box::new(buf.clone()), Some(cm.clone()));
+
+    let fm = cm.new_source_file(FileName::Real(path.to_path_buf()).into(), src);
+
+    let result = GLOBALS.set(&Globals::new(), || {
+        HANDLER.set(&handler, || {
+            let mut errors = Vec::new();
+            let program = if is_module {
+                parse_file_as_module(&fm, syntax, EsVersion::latest(), None, &mut errors)
+                    .map(Program::Module)
+            } else {
+                parse_file_as_script(&fm, syntax, EsVersion::latest(), None, &mut errors)
+                    .map(Program::Script)
+            };
+
+            for err in errors {
+                err.into_diagnostic(&handler).emit();
+            }
+
+            let program = match program {
+                Ok(program) => program,
+                Err(err) => {
+                    err.into_diagnostic(&handler).emit();
+                    return None;
+                }
+            };
+            if handler.has_errors() {
+                return None;
+            }
+
+            let program = transform(program);
+            if handler.has_errors() {
+                return None;
+            }
+
+            Some(swc_ecma_codegen::to_code_default(
+                cm.clone(),
+                None,
+                &program,
+            ))
+        })
+    });
+
+    result.ok_or_else(|| buf.into_string())
+}
+
+#[derive(Clone, Default)]
+struct SharedBuf(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuf {
+    fn into_string(self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+//! The frontmatter of a test262 test, which is a YAML document between `/*---`
+//! and `---*/`.
+//!
+//! Only the keys used by the runner are parsed.
+
+/// Metadata of a test.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    pub negative: Option<Negative>,
+    pub flags: Flags,
+    /// Files in `harness/` which should be evaluated before the test.
+    pub includes: Vec<String>,
+    pub features: Vec<String>,
+}
+
+/// The test is expected to fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Negative {
+    pub phase: Phase,
+    /// The name of the expected error constructor, like `SyntaxError`.
+    pub error_type: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Resolution,
+    Runtime,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Flags {
+    pub only_strict: bool,
+    pub no_strict: bool,
+    pub module: bool,
+    pub raw: bool,
+    pub is_async: bool,
+}
+
+impl Metadata {
+    /// Parses the frontmatter of `src`.
+    ///
+    /// Returns [None] if there's no frontmatter.
+    pub fn parse(src: &str) -> Option<Self> {
+        let start = src.find("/*---")? + "/*---".len();
+        let end = start + src[start..].find("---*/")?;
+
+        let mut meta = Metadata::default();
+        let mut key = "";
+
+        for line in src[start..end].lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if !line.starts_with(char::is_whitespace) {
+                let (k, value) = match line.split_once(':') {
+                    Some(v) => v,
+                    None => continue,
+                };
+                key = k.trim();
+
+                let value = value.trim();
+                if value.starts_with('[') {
+                    for item in parse_inline_list(value) {
+                        meta.add_item(key, item);
+                    }
+                }
+                continue;
+            }
+
+            let line = line.trim();
+            if let Some(item) = line.strip_prefix("- ") {
+                meta.add_item(key, item.trim());
+            } else if key == "negative" {
+                if let Some((k, value)) = line.split_once(':') {
+                    meta.add_negative(k.trim(), value.trim());
+                }
+            }
+        }
+
+        Some(meta)
+    }
+
+    fn add_item(&mut self, key: &str, item: &str) {
+        match key {
+            "flags" => match item {
+                "onlyStrict" => self.flags.only_strict = true,
+                "noStrict" => self.flags.no_strict = true,
+                "module" => self.flags.module = true,
+                "raw" => self.flags.raw = true,
+                "async" => self.flags.is_async = true,
+                _ => {}
+            },
+            "includes" => self.includes.push(item.to_string()),
+            "features" => self.features.push(item.to_string()),
+            _ => {}
+        }
+    }
+
+    fn add_negative(&mut self, key: &str, value: &str) {
+        let negative = self.negative.get_or_insert_with(|| Negative {
+            phase: Phase::Runtime,
+            error_type: Default::default(),
+        });
+
+        match key {
+            "phase" => {
+                negative.phase = match value {
+                    "parse" => Phase::Parse,
+                    "resolution" => Phase::Resolution,
+                    _ => Phase::Runtime,
+                }
+            }
+            "type" => negative.error_type = value.to_string(),
+            _ => {}
+        }
+    }
+}
+
+/// Parses `[a, b]`.
+fn parse_inline_list(s: &str) -> impl Iterator<Item = &str> {
+    s.trim_start_matches('[')
+        .split(']')
+        .next()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let src = r#"// Copyright
+/*---
+esid: sec-let
+description: >
+  `let` declarations are not allowed
+  in a statement position.
+negative:
+  phase: parse
+  type: SyntaxError
+flags: [onlyStrict, async]
+includes:
+  - compareArray.js
+  - propertyHelper.js
+features: [let]
+---*/
+
+$DONOTEVALUATE();
+"#;
+
+        assert_eq!(
+            Metadata::parse(src),
+            Some(Metadata {
+                negative: Some(Negative {
+                    phase: Phase::Parse,
+                    error_type: "SyntaxError".into(),
+                }),
+                flags: Flags {
+                    only_strict: true,
+                    is_async: true,
+                    ..Default::default()
+                },
+                includes: vec!["compareArray.js".into(), "propertyHelper.js".into()],
+                features: vec!["let".into()],
+            })
+        );
+    }
+
+    #[test]
+    fn no_frontmatter() {
+        assert_eq!(Metadata::parse("var a = 1;"), None);
+    }
+}
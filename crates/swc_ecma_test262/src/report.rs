@@ -0,0 +1,110 @@
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Skip,
+}
+
+/// Results of a run, keyed by the name of each case.
+///
+/// A report can be saved and used as the baseline of later runs. See
+/// [Report::compare].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Report {
+    pub outcomes: BTreeMap<String, Outcome>,
+    /// Reasons of failures. These are not saved.
+    #[serde(skip)]
+    pub failures: BTreeMap<String, String>,
+}
+
+impl Report {
+    pub fn count(&self, outcome: Outcome) -> usize {
+        self.outcomes.values().filter(|&&o| o == outcome).count()
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+
+        serde_json::from_str(&json).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+
+        fs::write(path, json).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// Compares this report with `baseline`.
+    ///
+    /// Cases which don't exist in `baseline` are treated as failed in the
+    /// baseline, and skipped cases are ignored.
+    pub fn compare(&self, baseline: &Report) -> Delta {
+        let mut delta = Delta::default();
+
+        for (name, &outcome) in &self.outcomes {
+            let prev = baseline.outcomes.get(name).copied();
+
+            match (prev, outcome) {
+                (Some(Outcome::Pass), Outcome::Fail) => delta.regressed.push(name.clone()),
+                (Some(Outcome::Fail) | None, Outcome::Pass) => delta.fixed.push(name.clone()),
+                _ => {}
+            }
+        }
+
+        delta
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} skipped",
+            self.count(Outcome::Pass),
+            self.count(Outcome::Fail),
+            self.count(Outcome::Skip)
+        )
+    }
+}
+
+/// Difference between two [Report]s.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Delta {
+    /// Cases which failed in the baseline but pass now.
+    pub fixed: Vec<String>,
+    /// Cases which passed in the baseline but fail now.
+    pub regressed: Vec<String>,
+}
+
+impl Delta {
+    pub fn is_empty(&self) -> bool {
+        self.fixed.is_empty() && self.regressed.is_empty()
+    }
+}
+
+impl fmt::Display for Delta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} fixed, {} regressed",
+            self.fixed.len(),
+            self.regressed.len()
+        )?;
+
+        for name in &self.fixed {
+            writeln!(f, "  fixed: {}", name)?;
+        }
+        for name in &self.regressed {
+            writeln!(f, "  regressed: {}", name)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+
+use swc_ecma_ast::Program;
+use swc_ecma_test262::{Engine, Mode, Outcome, Report, Test262};
+
+fn outcomes(report: &Report) -> Vec<(&str, Outcome)> {
+    report
+        .outcomes
+        .iter()
+        .map(|(name, &outcome)| (&**name, outcome))
+        .collect()
+}
+
+fn test262() -> Test262 {
+    Test262::new("tests/test262").skip_feature("unsupported-feature")
+}
+
+#[test]
+fn parse() {
+    let report = test262().mode(Mode::Parse).run().unwrap();
+
+    assert_eq!(
+        outcomes(&report),
+        vec![
+            ("language/async.js", Outcome::Pass),
+            ("language/async.js (strict)", Outcome::Pass),
+            ("language/early-error.js", Outcome::Skip),
+            ("language/early-error.js (strict)", Outcome::Skip),
+            ("language/module.js", Outcome::Pass),
+            ("language/only-strict.js (strict)", Outcome::Pass),
+            ("language/pass.js", Outcome::Pass),
+            ("language/pass.js (strict)", Outcome::Pass),
+            ("language/runtime-error.js", Outcome::Pass),
+            ("language/runtime-error.js (strict)", Outcome::Pass),
+            ("language/skipped.js", Outcome::Skip),
+            ("language/skipped.js (strict)", Outcome::Skip),
+        ]
+    );
+}
+
+#[test]
+fn early_errors() {
+    let report = test262()
+        .filter("language/early-error.js")
+        .mode(Mode::EarlyErrors)
+        .run()
+        .unwrap();
+
+    assert_eq!(
+        outcomes(&report),
+        vec![
+            ("language/early-error.js", Outcome::Pass),
+            ("language/early-error.js (strict)", Outcome::Pass),
+        ]
+    );
+}
+
+#[test]
+fn exec() {
+    let baseline = test262().mode(Mode::Exec(Engine::node())).run().unwrap();
+
+    assert_eq!(baseline.count(Outcome::Fail), 0, "{:#?}", baseline.failures);
+    assert_eq!(baseline.count(Outcome::Skip), 2);
+
+    // Removes all statements of scripts.
+    let report = test262()
+        .mode(Mode::Exec(Engine::node()))
+        .transform(|mut program| {
+            if let Program::Script(script) = &mut program {
+                script.body.clear();
+            }
+            program
+        })
+        .run()
+        .unwrap();
+
+    let delta = report.compare(&baseline);
+    assert_eq!(delta.fixed, Vec::<String>::new());
+    assert_eq!(
+        delta.regressed,
+        vec![
+            "language/async.js",
+            "language/async.js (strict)",
+            "language/runtime-error.js",
+            "language/runtime-error.js (strict)",
+        ]
+    );
+}
+
+#[test]
+fn save_and_load() {
+    let report = Report {
+        outcomes: BTreeMap::from([
+            ("a.js".to_string(), Outcome::Pass),
+            ("b.js".to_string(), Outcome::Fail),
+        ]),
+        failures: Default::default(),
+    };
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.json");
+    report.save(&path).unwrap();
+
+    let loaded = Report::load(&path).unwrap();
+    assert_eq!(loaded.outcomes, report.outcomes);
+    assert!(report.compare(&loaded).is_empty());
+}
@@ -0,0 +1,11 @@
+function assert(value, message) {
+  if (value !== true) {
+    throw new Test262Error(message);
+  }
+}
+
+assert.sameValue = function (actual, expected, message) {
+  if (actual !== expected) {
+    throw new Test262Error(message || "Expected " + expected + " but got " + actual);
+  }
+};
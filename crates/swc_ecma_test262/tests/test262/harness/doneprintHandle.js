@@ -0,0 +1,7 @@
+function $DONE(error) {
+  if (error) {
+    console.log("Test262:AsyncTestFailure:" + error);
+  } else {
+    console.log("Test262:AsyncTestComplete");
+  }
+}
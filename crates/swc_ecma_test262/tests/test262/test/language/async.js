@@ -0,0 +1,8 @@
+/*---
+description: Asynchronous tests call $DONE.
+flags: [async]
+---*/
+
+Promise.resolve(1).then(function (v) {
+  assert.sameValue(v, 1);
+}).then($DONE, $DONE);
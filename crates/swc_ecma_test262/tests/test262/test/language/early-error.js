@@ -0,0 +1,10 @@
+/*---
+description: A variable declaration requires a binding.
+negative:
+  phase: parse
+  type: SyntaxError
+---*/
+
+$DONOTEVALUATE();
+
+var = 1;
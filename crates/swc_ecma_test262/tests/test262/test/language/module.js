@@ -0,0 +1,7 @@
+/*---
+description: Modules are strict.
+flags: [module]
+---*/
+
+export var a = 1;
+assert.sameValue(this, undefined);
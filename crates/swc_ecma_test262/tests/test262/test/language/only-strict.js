@@ -0,0 +1,8 @@
+/*---
+description: `this` is undefined in strict functions.
+flags: [onlyStrict]
+---*/
+
+(function () {
+  assert.sameValue(this, undefined);
+})();
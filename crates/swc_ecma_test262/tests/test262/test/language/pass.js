@@ -0,0 +1,6 @@
+/*---
+description: A test which passes in both modes.
+---*/
+
+var a = 1;
+assert.sameValue(a, 1);
@@ -0,0 +1,8 @@
+/*---
+description: Properties of null cannot be read.
+negative:
+  phase: runtime
+  type: TypeError
+---*/
+
+null.a;
@@ -0,0 +1,6 @@
+/*---
+description: Tests using skipped features are not run.
+features: [unsupported-feature]
+---*/
+
+unsupported syntax;
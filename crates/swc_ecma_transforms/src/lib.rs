@@ -3,7 +3,7 @@
 #![deny(unused)]
 
 pub use swc_ecma_transforms_base::{
-    assumptions::Assumptions, feature, fixer, helpers, hygiene, perf, resolver,
+    assumptions::Assumptions, feature, fixer, helpers, hygiene, perf, resolver, syntax_features,
 };
 // TODO: May remove these reexports once swc_core directly reexports all
 #[cfg(feature = "swc_ecma_transforms_compat")]
@@ -6,5 +6,6 @@ class A {
     }
 }
 _ts_decorate([
-    foo
+    foo,
+    _ts_metadata("design:type", Object)
 ], A.prototype, "x", void 0);
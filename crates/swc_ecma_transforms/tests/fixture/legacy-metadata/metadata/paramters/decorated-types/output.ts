@@ -20,7 +20,7 @@ _ts_decorate([
     _ts_metadata("design:type", Function),
     _ts_metadata("design:paramtypes", [
         String,
-        void 0
+        Object
     ]),
     _ts_metadata("design:returntype", void 0)
 ], MyOtherClass.prototype, "methodUndecorated", null);
@@ -0,0 +1,44 @@
+import { Inject, Prop, Get } from "./decorators";
+
+enum Role {
+    Admin = "admin",
+    User = "user",
+}
+
+enum Level {
+    Low,
+    High,
+}
+
+@Inject()
+class Controller {
+    @Prop()
+    untyped;
+
+    @Prop()
+    role: Role;
+
+    @Prop()
+    big: 10n;
+
+    @Prop()
+    imported: import("./service").Service;
+
+    constructor(private readonly level: Level, name, ...rest: string[]) {}
+
+    @Get()
+    find(this: Controller, role: Role, ...ids: Array<number>): Level {
+        return Level.Low;
+    }
+
+    @Get()
+    async load(id): Promise<string> {
+        return "";
+    }
+
+    @Get()
+    async save(...args) {}
+
+    @Get()
+    set value(this: Controller, v: Level) {}
+}
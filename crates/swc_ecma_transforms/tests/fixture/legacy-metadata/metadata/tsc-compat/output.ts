@@ -0,0 +1,81 @@
+import { Inject, Prop, Get } from "./decorators";
+enum Role {
+    Admin = "admin",
+    User = "user"
+}
+enum Level {
+    Low,
+    High
+}
+class Controller {
+    untyped;
+    role: Role;
+    big: 10n;
+    imported: import("./service").Service;
+    constructor(private readonly level: Level, name, ...rest: string[]){}
+    find(this: Controller, role: Role, ...ids: Array<number>): Level {
+        return Level.Low;
+    }
+    async load(id): Promise<string> {
+        return "";
+    }
+    async save(...args) {}
+    set value(this: Controller, v: Level) {}
+}
+_ts_decorate([
+    Prop(),
+    _ts_metadata("design:type", Object)
+], Controller.prototype, "untyped", void 0);
+_ts_decorate([
+    Prop(),
+    _ts_metadata("design:type", String)
+], Controller.prototype, "role", void 0);
+_ts_decorate([
+    Prop(),
+    _ts_metadata("design:type", typeof BigInt === "undefined" ? Object : BigInt)
+], Controller.prototype, "big", void 0);
+_ts_decorate([
+    Prop(),
+    _ts_metadata("design:type", Object)
+], Controller.prototype, "imported", void 0);
+_ts_decorate([
+    Get(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        String,
+        Number
+    ]),
+    _ts_metadata("design:returntype", Number)
+], Controller.prototype, "find", null);
+_ts_decorate([
+    Get(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        Object
+    ]),
+    _ts_metadata("design:returntype", Promise)
+], Controller.prototype, "load", null);
+_ts_decorate([
+    Get(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        Object
+    ]),
+    _ts_metadata("design:returntype", Promise)
+], Controller.prototype, "save", null);
+_ts_decorate([
+    Get(),
+    _ts_metadata("design:type", Number),
+    _ts_metadata("design:paramtypes", [
+        Number
+    ])
+], Controller.prototype, "value", null);
+Controller = _ts_decorate([
+    Inject(),
+    _ts_metadata("design:type", Function),
+    _ts_metadata("design:paramtypes", [
+        Number,
+        Object,
+        String
+    ])
+], Controller);
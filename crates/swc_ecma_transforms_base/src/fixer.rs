@@ -2,6 +2,7 @@ use std::{hash::BuildHasherDefault, mem, ops::RangeFull};
 
 use indexmap::IndexMap;
 use rustc_hash::FxHasher;
+use serde::{Deserialize, Serialize};
 use swc_common::{comments::Comments, util::take::Take, Span, Spanned, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::stack_size::maybe_grow_default;
@@ -13,6 +14,14 @@ use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith
 /// The pass will insert parenthesis as needed. In other words, it's
 /// okay to store `a * (b + c)` as `Bin { a * Bin { b + c } }`.
 pub fn fixer(comments: Option<&dyn Comments>) -> impl '_ + Pass + VisitMut {
+    fixer_with_parens(comments, Parens::Minimal)
+}
+
+/// [fixer] with the style of parenthesis to emit.
+pub fn fixer_with_parens(
+    comments: Option<&dyn Comments>,
+    parens: Parens,
+) -> impl '_ + Pass + VisitMut {
     visit_mut_pass(Fixer {
         comments,
         ctx: Default::default(),
@@ -20,9 +29,26 @@ pub fn fixer(comments: Option<&dyn Comments>) -> impl '_ + Pass + VisitMut {
         in_for_stmt_head: Default::default(),
         in_opt_chain: Default::default(),
         remove_only: false,
+        parens,
     })
 }
 
+/// Style of parenthesis emitted by [fixer_with_parens].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Parens {
+    /// Emit only parenthesis required to preserve semantics.
+    #[default]
+    Minimal,
+    /// Additionally wrap expressions which are hard to read without
+    /// parenthesis.
+    ///
+    ///  - `a && b || c` becomes `(a && b) || c`
+    ///  - `() => a ? b : c` becomes `() => (a ? b : c)`
+    ///  - `() => a = b` becomes `() => (a = b)`
+    Readable,
+}
+
 pub fn paren_remover(comments: Option<&dyn Comments>) -> impl '_ + Pass + VisitMut {
     visit_mut_pass(Fixer {
         comments,
@@ -31,6 +57,7 @@ pub fn paren_remover(comments: Option<&dyn Comments>) -> impl '_ + Pass + VisitM
         in_for_stmt_head: Default::default(),
         in_opt_chain: Default::default(),
         remove_only: true,
+        parens: Parens::Minimal,
     })
 }
 
@@ -47,6 +74,8 @@ struct Fixer<'a> {
     in_opt_chain: bool,
 
     remove_only: bool,
+
+    parens: Parens,
 }
 
 #[repr(u8)]
@@ -81,7 +110,9 @@ impl Fixer<'_> {
             | Expr::Unary(..)
             | Expr::Object(..)
             | Expr::Await(..)
-            | Expr::Yield(..) => self.wrap(e),
+            | Expr::Yield(..)
+            | Expr::Pipeline(..)
+            | Expr::Do(..) => self.wrap(e),
             _ => (),
         }
     }
@@ -109,12 +140,16 @@ impl VisitMut for Fixer<'_> {
 
             BlockStmtOrExpr::Expr(e) if e.is_assign() => {
                 if let Expr::Assign(assign) = &**e {
-                    if let AssignTarget::Pat(..) = &assign.left {
+                    if self.parens == Parens::Readable || assign.left.is_pat() {
                         self.wrap(e);
                     }
                 }
             }
 
+            BlockStmtOrExpr::Expr(e) if e.is_cond() && self.parens == Parens::Readable => {
+                self.wrap(e);
+            }
+
             _ => {}
         };
         self.ctx = old;
@@ -213,9 +248,11 @@ impl VisitMut for Fixer<'_> {
         self.ctx = old;
 
         match &*expr.arg {
-            Expr::Cond(..) | Expr::Assign(..) | Expr::Bin(..) | Expr::Yield(..) => {
-                self.wrap(&mut expr.arg)
-            }
+            Expr::Cond(..)
+            | Expr::Assign(..)
+            | Expr::Bin(..)
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut expr.arg),
             _ => {}
         }
     }
@@ -227,6 +264,14 @@ impl VisitMut for Fixer<'_> {
         expr.right.visit_mut_with(self);
         self.ctx = ctx;
 
+        if self.parens == Parens::Readable && expr.op == op!("||") {
+            for operand in [&mut expr.left, &mut expr.right] {
+                if let Expr::Bin(BinExpr { op: op!("&&"), .. }) = &**operand {
+                    self.wrap(operand);
+                }
+            }
+        }
+
         match expr.op {
             op!("||") | op!("&&") => match (&*expr.left, &*expr.right) {
                 (Expr::Update(..), Expr::Call(..)) => {
@@ -267,7 +312,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Seq(..)
             | Expr::Yield(..)
             | Expr::Cond(..)
-            | Expr::Arrow(..) => {
+            | Expr::Arrow(..)
+            | Expr::Pipeline(..) => {
                 self.wrap(&mut expr.right);
             }
             Expr::Bin(BinExpr { op: op_of_rhs, .. }) => {
@@ -318,7 +364,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Yield(..)
             | Expr::Cond(..)
             | Expr::Assign(..)
-            | Expr::Arrow(..) => {
+            | Expr::Arrow(..)
+            | Expr::Pipeline(..) => {
                 self.wrap(&mut expr.left);
             }
             Expr::Object(..)
@@ -396,7 +443,8 @@ impl VisitMut for Fixer<'_> {
                     || e.is_bin()
                     || e.is_assign()
                     || e.is_cond()
-                    || e.is_unary() =>
+                    || e.is_unary()
+                    || e.is_pipeline() =>
             {
                 self.wrap(e)
             }
@@ -563,6 +611,18 @@ impl VisitMut for Fixer<'_> {
         }
     }
 
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        node.pat.visit_mut_with(self);
+
+        let old = self.ctx;
+        self.ctx = Context::FreeExpr;
+        node.guard.visit_mut_with(self);
+
+        self.ctx = Context::ForcedExpr;
+        node.body.visit_mut_with(self);
+        self.ctx = old;
+    }
+
     fn visit_mut_member_expr(&mut self, n: &mut MemberExpr) {
         n.visit_mut_children_with(self);
 
@@ -580,6 +640,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Class(..)
             | Expr::Yield(..)
             | Expr::Await(..)
+            | Expr::Pipeline(..)
+            | Expr::Do(..)
             | Expr::New(NewExpr { args: None, .. }) => {
                 self.wrap(&mut n.obj);
             }
@@ -660,6 +722,35 @@ impl VisitMut for Fixer<'_> {
         self.ctx = old;
     }
 
+    fn visit_mut_pipeline_expr(&mut self, expr: &mut PipelineExpr) {
+        expr.left.visit_mut_with(self);
+        let ctx = self.ctx;
+        self.ctx = Context::FreeExpr;
+        expr.right.visit_mut_with(self);
+        self.ctx = ctx;
+
+        // The body of a pipeline can't be one of these without parens.
+        match &*expr.right {
+            Expr::Seq(..)
+            | Expr::Assign(..)
+            | Expr::Cond(..)
+            | Expr::Arrow(..)
+            | Expr::Yield(..) => self.wrap(&mut expr.right),
+            _ => {}
+        }
+
+        // Pipelines are right-associative, so `a |> b |> c` is `a |> (b |> c)`.
+        match &*expr.left {
+            Expr::Seq(..)
+            | Expr::Assign(..)
+            | Expr::Cond(..)
+            | Expr::Arrow(..)
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut expr.left),
+            _ => {}
+        }
+    }
+
     fn visit_mut_prop_name(&mut self, name: &mut PropName) {
         name.visit_mut_children_with(self);
 
@@ -671,6 +762,25 @@ impl VisitMut for Fixer<'_> {
         }
     }
 
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        let old = self.ctx;
+        self.ctx = Context::FreeExpr;
+        node.value.visit_mut_with(self);
+        self.ctx = old;
+
+        // The operand of a relational pattern is a unary expression.
+        match &*node.value {
+            Expr::Bin(..)
+            | Expr::Seq(..)
+            | Expr::Assign(..)
+            | Expr::Cond(..)
+            | Expr::Arrow(..)
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut node.value),
+            _ => {}
+        }
+    }
+
     fn visit_mut_script(&mut self, n: &mut Script) {
         debug_assert!(self.span_map.is_empty());
         self.span_map.clear();
@@ -728,6 +838,7 @@ impl VisitMut for Fixer<'_> {
             | Expr::Bin(..)
             | Expr::Seq(..)
             | Expr::Fn(..)
+            | Expr::Do(..)
             | Expr::Assign(..)
             | Expr::Unary(..) => {
                 self.wrap(&mut e.tag);
@@ -761,7 +872,8 @@ impl VisitMut for Fixer<'_> {
             | Expr::Seq(..)
             | Expr::Cond(..)
             | Expr::Arrow(..)
-            | Expr::Yield(..) => self.wrap(&mut n.arg),
+            | Expr::Yield(..)
+            | Expr::Pipeline(..) => self.wrap(&mut n.arg),
 
             _ => {}
         }
@@ -825,9 +937,12 @@ impl Fixer<'_> {
                 self.wrap(e);
             }
 
-            Expr::Bin(BinExpr { left, .. })
+            Expr::Bin(BinExpr { left, .. }) | Expr::Pipeline(PipelineExpr { left, .. })
                 if self.ctx == Context::Default
-                    && matches!(&**left, Expr::Object(..) | Expr::Fn(..) | Expr::Class(..)) =>
+                    && matches!(
+                        &**left,
+                        Expr::Object(..) | Expr::Fn(..) | Expr::Class(..) | Expr::Do(..)
+                    ) =>
             {
                 self.wrap(left);
             }
@@ -944,9 +1059,10 @@ impl Fixer<'_> {
                     | Expr::Assign(..)
                     | Expr::Cond(..)
                     | Expr::Arrow(..)
-                    | Expr::Yield(..) => self.wrap(&mut expr.test),
+                    | Expr::Yield(..)
+                    | Expr::Pipeline(..) => self.wrap(&mut expr.test),
 
-                    Expr::Object(..) | Expr::Fn(..) | Expr::Class(..) => {
+                    Expr::Object(..) | Expr::Fn(..) | Expr::Class(..) | Expr::Do(..) => {
                         if self.ctx == Context::Default {
                             self.wrap(&mut expr.test)
                         }
@@ -1071,6 +1187,9 @@ impl Fixer<'_> {
             // It's important for arrow pass to work properly.
             Expr::Object(..) | Expr::Class(..) | Expr::Fn(..) => self.wrap(expr),
 
+            // `do { }` would be a `do`-`while` statement.
+            Expr::Do(..) => self.wrap(expr),
+
             // ({ a } = foo)
             Expr::Assign(AssignExpr {
                 left: AssignTarget::Pat(left),
@@ -1181,7 +1300,9 @@ fn will_eat_else_token(s: &Stmt) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use swc_ecma_ast::noop_pass;
+    use swc_ecma_ast::{noop_pass, Program};
+
+    use super::{fixer_with_parens, Parens};
 
     fn run_test(from: &str, to: &str) {
         crate::tests::test_transform(
@@ -1805,4 +1926,39 @@ var store = global[SHARED] || (global[SHARED] = {});
     identical!(issue_5417, "console.log(a ?? b ?? c)");
 
     identical!(bin_and_unary, "console.log(a++ && b--)");
+
+    fn run_readable_test(from: &str, to: &str) {
+        crate::tests::Tester::run(|tester| {
+            let mut program = Program::Module(tester.parse_module("input.js", from)?);
+            program.mutate(fixer_with_parens(None, Parens::Readable));
+            let actual = tester.print(&program);
+
+            let expected = Program::Module(tester.parse_module("expected.js", to)?);
+            let expected = tester.print(&expected);
+
+            assert_eq!(actual, expected);
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn readable_mixed_logical() {
+        run_readable_test("a && b || c && d", "(a && b) || (c && d)");
+        run_readable_test("a || (b || c)", "a || b || c");
+        run_readable_test("(a && b) && c", "a && b && c");
+        run_readable_test("a ?? (b || c)", "a ?? (b || c)");
+    }
+
+    #[test]
+    fn readable_arrow_body() {
+        run_readable_test("() => a ? b : c", "() => (a ? b : c)");
+        run_readable_test("() => a = b", "() => (a = b)");
+        run_readable_test("() => (a + b)", "() => a + b");
+    }
+
+    #[test]
+    fn minimal() {
+        run_test("(a && b) || c", "a && b || c");
+        run_test("() => (a ? b : c)", "() => a ? b : c");
+    }
 }
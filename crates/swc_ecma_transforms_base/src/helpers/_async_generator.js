@@ -6,19 +6,35 @@ function _async_generator(gen) {
             if (back) back = back.next = request;
             else {
                 front = back = request;
-                resume(key, arg);
+                start(request);
             }
         });
     }
+    function start(request) {
+        // `return()` awaits its argument before resuming the generator, so a
+        // rejection is thrown at the paused `yield`.
+        if (request.key === "return") {
+            Promise.resolve(request.arg).then(function(arg) {
+                resume("return", arg);
+            }, function(err) {
+                resume("throw", err);
+            });
+        } else resume(request.key, request.arg);
+    }
     function resume(key, arg) {
         try {
             var result = gen[key](arg);
             var value = result.value;
             var overloaded = value instanceof _overload_yield;
+            // Only thenables returned by the generator need to be awaited, as
+            // `return()` awaits its argument before resuming the generator.
+            if (result.done && !(value && typeof value.then === "function")) return settle("return", value);
             Promise.resolve(overloaded ? value.v : value).then(function(arg) {
                 if (overloaded) {
+                    // The generator continues normally after an `await`.
+                    if (!value.k) return resume("next", arg);
                     var nextKey = key === "return" ? "return" : "next";
-                    if (!value.k || arg.done) return resume(nextKey, arg);
+                    if (arg.done) return resume(nextKey, arg);
                     else arg = gen[nextKey](arg).value;
                 }
                 settle(result.done ? "return" : "normal", arg);
@@ -42,7 +58,7 @@ function _async_generator(gen) {
                 break;
         }
         front = front.next;
-        if (front) resume(front.key, front.arg);
+        if (front) start(front);
         else back = null;
     }
 
@@ -31,7 +31,7 @@ function AsyncFromSyncIterator(s) {
                     : AsyncFromSyncIteratorContinuation(ret.apply(this.s, arguments));
             },
             throw: function(value) {
-                var thr = this.s.return;
+                var thr = this.s.throw;
                 return void 0 === thr ? Promise.reject(value) : AsyncFromSyncIteratorContinuation(thr.apply(this.s, arguments));
             }
         },
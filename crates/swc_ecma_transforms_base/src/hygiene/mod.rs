@@ -1,3 +1,8 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
 use rustc_hash::FxHashSet;
 use swc_atoms::Atom;
 use swc_common::Mark;
@@ -6,7 +11,7 @@ use swc_ecma_utils::stack_size::maybe_grow_default;
 use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
 
 pub use crate::rename::rename;
-use crate::rename::{renamer, Renamer};
+use crate::rename::{renamer, RenameMap, Renamer};
 
 #[cfg(test)]
 mod tests;
@@ -24,7 +29,162 @@ pub struct Config {
     pub ignore_eval: bool,
 
     /// Used for preventing mangler from renaming variables to reserved names.
+    ///
+    /// This can be also used for identifiers reserved project-wide, e.g.
+    /// globals injected by a bundler.
     pub preserved_symbols: FxHashSet<Atom>,
+
+    /// Words which should not be used as a new name of a renamed identifier,
+    /// in addition to [Config::preserved_symbols].
+    pub reserved_words: ReservedWords,
+
+    /// How the `hygiene` pass makes a new name for a conflicting identifier.
+    ///
+    /// This is ignored by the mangler.
+    pub suffix: Suffix,
+
+    /// If [Some], all renames are recorded to it.
+    pub rename_log: Option<RenameLog>,
+}
+
+/// Lists of reserved words, which are avoided while renaming identifiers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReservedWords {
+    #[default]
+    None,
+    /// Words which can't be used as a binding in strict mode, like `let` or
+    /// `arguments`.
+    Strict,
+    /// Future reserved words of ES3, in addition to [ReservedWords::Strict].
+    ///
+    /// Useful if the output is consumed by old engines.
+    Es3,
+}
+
+impl ReservedWords {
+    const ES3: &'static [&'static str] = &[
+        "abstract",
+        "boolean",
+        "byte",
+        "char",
+        "double",
+        "final",
+        "float",
+        "goto",
+        "int",
+        "long",
+        "native",
+        "short",
+        "synchronized",
+        "throws",
+        "transient",
+        "volatile",
+    ];
+    const STRICT: &'static [&'static str] = &[
+        "arguments",
+        "eval",
+        "implements",
+        "interface",
+        "let",
+        "package",
+        "private",
+        "protected",
+        "public",
+        "static",
+        "yield",
+    ];
+
+    pub fn words(self) -> impl Iterator<Item = &'static str> {
+        let (strict, es3): (&[_], &[_]) = match self {
+            ReservedWords::None => (&[], &[]),
+            ReservedWords::Strict => (Self::STRICT, &[]),
+            ReservedWords::Es3 => (Self::STRICT, Self::ES3),
+        };
+
+        strict.iter().chain(es3).copied()
+    }
+}
+
+/// Naming scheme of renamed identifiers.
+///
+/// The first identifier keeps the original name, and others get a suffix
+/// made from a counter starting from 1.
+#[derive(Clone, Default)]
+pub enum Suffix {
+    /// `a`, `a1`, `a2`
+    #[default]
+    Number,
+    /// `a`, `a_1`, `a_2`
+    Underscore,
+    /// `a`, `a$1`, `a$2`
+    Dollar,
+    /// Called with the original name and the counter.
+    Custom(Arc<dyn Fn(&Atom, usize) -> Atom + Send + Sync>),
+}
+
+impl Suffix {
+    fn apply(&self, sym: &Atom, n: usize) -> Atom {
+        match self {
+            Suffix::Number => format!("{}{}", sym, n).into(),
+            Suffix::Underscore => format!("{}_{}", sym, n).into(),
+            Suffix::Dollar => format!("{}${}", sym, n).into(),
+            Suffix::Custom(f) => f(sym, n),
+        }
+    }
+}
+
+impl fmt::Debug for Suffix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Suffix::Number => f.write_str("Number"),
+            Suffix::Underscore => f.write_str("Underscore"),
+            Suffix::Dollar => f.write_str("Dollar"),
+            Suffix::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Renames done by the `hygiene` pass (or the mangler), for debugging.
+///
+/// This is cheap to clone, and clones share the entries.
+#[derive(Debug, Clone, Default)]
+pub struct RenameLog(Arc<Mutex<Vec<RenameLogEntry>>>);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameLogEntry {
+    pub from: Id,
+    pub to: Atom,
+}
+
+impl RenameLog {
+    pub fn entries(&self) -> Vec<RenameLogEntry> {
+        self.0.lock().unwrap().clone()
+    }
+
+    pub(crate) fn record(&self, map: &RenameMap) {
+        let mut renamed = map
+            .iter()
+            .filter(|(from, to)| from.0 != **to)
+            .map(|(from, to)| RenameLogEntry {
+                from: from.clone(),
+                to: to.clone(),
+            })
+            .collect::<Vec<_>>();
+        // Order of the map is not stable.
+        renamed.sort_by(|a, b| a.to.cmp(&b.to));
+
+        self.0.lock().unwrap().extend(renamed);
+    }
+}
+
+impl fmt::Display for RenameLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.0.lock().unwrap().iter() {
+            writeln!(f, "{}{:?} -> {}", entry.from.0, entry.from.1, entry.to)?;
+        }
+
+        Ok(())
+    }
 }
 
 /// See [hygiene_with_config] for doc. Creates a `hygiene` pass with default
@@ -60,13 +220,17 @@ pub fn hygiene() -> impl Pass + VisitMut {
 ///
 ///  At third phase, we rename all identifiers in the queue.
 pub fn hygiene_with_config(config: Config) -> impl 'static + Pass + VisitMut {
+    let suffix = config.suffix.clone();
+
     (
-        renamer(config, HygieneRenamer),
+        renamer(config, HygieneRenamer { suffix }),
         visit_mut_pass(HygieneRemover),
     )
 }
 
-struct HygieneRenamer;
+struct HygieneRenamer {
+    suffix: Suffix,
+}
 
 impl Renamer for HygieneRenamer {
     const MANGLE: bool = false;
@@ -76,7 +240,7 @@ impl Renamer for HygieneRenamer {
         let res = if *n == 0 {
             orig.0.clone()
         } else {
-            format!("{}{}", orig.0, n).into()
+            self.suffix.apply(&orig.0, *n)
         };
         *n += 1;
         res
@@ -1756,3 +1756,122 @@ fn issue_2539() {
         ",
     );
 }
+
+/// `var foo = 1; var foo = 2; var goto = 3; var goto = 4;` where all of them
+/// are different bindings.
+fn conflicting_vars(tester: &mut Tester<'_>) -> Result<Module, ()> {
+    let mark1 = Mark::fresh(Mark::root());
+    let mark2 = Mark::fresh(Mark::root());
+
+    let stmts = vec![
+        tester
+            .parse_stmt("actual1.js", "var foo = 1;")?
+            .fold_with(&mut marker(&[("foo", mark1)])),
+        tester
+            .parse_stmt("actual2.js", "var foo = 2;")?
+            .fold_with(&mut marker(&[("foo", mark2)])),
+        tester
+            .parse_stmt("actual3.js", "var goto = 3;")?
+            .fold_with(&mut marker(&[("goto", mark1)])),
+        tester
+            .parse_stmt("actual4.js", "var goto = 4;")?
+            .fold_with(&mut marker(&[("goto", mark2)])),
+    ];
+
+    Ok(Module {
+        span: DUMMY_SP,
+        body: stmts.into_iter().map(ModuleItem::Stmt).collect(),
+        shebang: None,
+    })
+}
+
+#[test]
+fn suffix_underscore() {
+    test_module(
+        conflicting_vars,
+        "
+        var foo = 1;
+        var foo_1 = 2;
+        var goto = 3;
+        var goto_1 = 4;
+        ",
+        || Config {
+            suffix: Suffix::Underscore,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn suffix_custom() {
+    test_module(
+        conflicting_vars,
+        "
+        var foo = 1;
+        var foo__v1 = 2;
+        var goto = 3;
+        var goto__v1 = 4;
+        ",
+        || Config {
+            suffix: Suffix::Custom(Arc::new(|sym, n| format!("{}__v{}", sym, n).into())),
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn reserved_words() {
+    test_module(
+        conflicting_vars,
+        "
+        var foo = 1;
+        var foo2 = 2;
+        var goto1 = 3;
+        var goto2 = 4;
+        ",
+        || Config {
+            reserved_words: ReservedWords::Es3,
+            preserved_symbols: ["foo1".into()].into_iter().collect(),
+            ..Default::default()
+        },
+    );
+
+    assert!(ReservedWords::Strict.words().any(|w| w == "yield"));
+    assert!(!ReservedWords::Strict.words().any(|w| w == "goto"));
+    assert!(ReservedWords::Es3.words().any(|w| w == "goto"));
+}
+
+#[test]
+fn rename_log() {
+    let log = RenameLog::default();
+
+    test_module(
+        conflicting_vars,
+        "
+        var foo = 1;
+        var foo1 = 2;
+        var goto = 3;
+        var goto1 = 4;
+        ",
+        {
+            let log = log.clone();
+            move || Config {
+                rename_log: Some(log),
+                ..Default::default()
+            }
+        },
+    );
+
+    let renamed = log
+        .entries()
+        .into_iter()
+        .map(|entry| (entry.from.0, entry.to))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        renamed,
+        vec![
+            ("foo".into(), "foo1".into()),
+            ("goto".into(), "goto1".into())
+        ]
+    );
+}
@@ -2,7 +2,7 @@
 #![allow(clippy::mutable_key_type)]
 #![cfg_attr(not(test), allow(unused))]
 
-pub use self::resolver::resolver;
+pub use self::resolver::{incremental_resolver, resolver};
 
 #[doc(hidden)]
 pub mod ext;
@@ -19,5 +19,6 @@ pub mod quote;
 pub mod rename;
 mod resolver;
 pub mod scope;
+pub mod syntax_features;
 #[cfg(test)]
 mod tests;
@@ -20,7 +20,7 @@ use self::{
     eval::contains_eval,
     ops::Operator,
 };
-use crate::hygiene::Config;
+use crate::hygiene::{Config, ReservedWords};
 
 mod analyzer;
 mod collector;
@@ -181,6 +181,12 @@ where
                 .extend(self.config.preserved_symbols.iter().cloned());
         }
 
+        if self.config.reserved_words != ReservedWords::None {
+            unresolved
+                .to_mut()
+                .extend(self.config.reserved_words.words().map(Atom::from));
+        }
+
         if R::MANGLE {
             let cost = scope.rename_cost();
             scope.rename_in_mangle_mode(
@@ -203,6 +209,10 @@ where
             );
         }
 
+        if let Some(log) = &self.config.rename_log {
+            log.record(&map);
+        }
+
         if let Some(total_map) = &mut self.total_map {
             total_map.reserve(map.len());
 
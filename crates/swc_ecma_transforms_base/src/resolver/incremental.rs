@@ -0,0 +1,109 @@
+use swc_common::{Span, Spanned, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Removes syntax contexts assigned by the resolver from the statements
+/// covered by `dirty`, so the resolver can assign them again.
+///
+/// A statement is cleared if it's contained in a dirty span. If a statement
+/// only overlaps a dirty span, its nested statements are checked instead, and
+/// the statement is cleared as a whole if none of them is. Switch statements
+/// and typescript namespaces are always cleared as a whole because their
+/// scopes don't have a syntax context the resolver can reuse.
+pub(super) struct DirtyContextRemover {
+    dirty: Vec<Span>,
+    /// `true` if a statement was cleared while visiting the current one.
+    cleared: bool,
+}
+
+impl DirtyContextRemover {
+    pub fn new(dirty: Vec<Span>) -> Self {
+        DirtyContextRemover {
+            dirty,
+            cleared: false,
+        }
+    }
+
+    fn is_contained(&self, span: Span) -> bool {
+        self.dirty.iter().any(|dirty| dirty.contains(span))
+    }
+
+    fn overlaps(&self, span: Span) -> bool {
+        self.dirty
+            .iter()
+            .any(|dirty| dirty.lo < span.hi && span.lo < dirty.hi)
+    }
+
+    fn clear_if_dirty<N>(&mut self, n: &mut N, is_opaque: bool)
+    where
+        N: Spanned + VisitMutWith<ContextRemover> + VisitMutWith<Self>,
+    {
+        let span = n.span();
+
+        if span.is_dummy() || !self.overlaps(span) {
+            return;
+        }
+
+        if is_opaque || self.is_contained(span) {
+            n.visit_mut_with(&mut ContextRemover);
+            self.cleared = true;
+            return;
+        }
+
+        self.cleared = false;
+        n.visit_mut_children_with(self);
+
+        if !self.cleared {
+            n.visit_mut_with(&mut ContextRemover);
+        }
+        self.cleared = true;
+    }
+}
+
+impl VisitMut for DirtyContextRemover {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_item(&mut self, n: &mut ModuleItem) {
+        let is_opaque = matches!(
+            n,
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsModule(..)))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::TsModule(..),
+                    ..
+                }))
+        );
+
+        self.clear_if_dirty(n, is_opaque);
+    }
+
+    fn visit_mut_stmt(&mut self, n: &mut Stmt) {
+        let is_opaque = matches!(n, Stmt::Switch(..) | Stmt::Decl(Decl::TsModule(..)));
+
+        self.clear_if_dirty(n, is_opaque);
+    }
+}
+
+/// Resets all syntax contexts the resolver assigns, including the ones of
+/// identifiers in types.
+pub(super) struct ContextRemover;
+
+impl VisitMut for ContextRemover {
+    fn visit_mut_arrow_expr(&mut self, n: &mut ArrowExpr) {
+        n.ctxt = SyntaxContext::empty();
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_block_stmt(&mut self, n: &mut BlockStmt) {
+        n.ctxt = SyntaxContext::empty();
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_function(&mut self, n: &mut Function) {
+        n.ctxt = SyntaxContext::empty();
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        n.ctxt = SyntaxContext::empty();
+    }
+}
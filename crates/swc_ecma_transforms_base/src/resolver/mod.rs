@@ -1,6 +1,6 @@
 use rustc_hash::{FxHashMap, FxHashSet};
 use swc_atoms::Atom;
-use swc_common::{Mark, SyntaxContext};
+use swc_common::{Mark, Span, SyntaxContext};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{find_pat_ids, stack_size::maybe_grow_default};
 use swc_ecma_visit::{
@@ -8,8 +8,10 @@ use swc_ecma_visit::{
 };
 use tracing::{debug, span, Level};
 
+use self::incremental::DirtyContextRemover;
 use crate::scope::{DeclKind, IdentType, ScopeKind};
 
+mod incremental;
 #[cfg(test)]
 mod tests;
 
@@ -141,20 +143,58 @@ pub fn resolver(
     let _ = SyntaxContext::empty().apply_mark(unresolved_mark);
     let _ = SyntaxContext::empty().apply_mark(top_level_mark);
 
-    visit_mut_pass(Resolver {
-        current: Scope::new(ScopeKind::Fn, top_level_mark, None),
-        ident_type: IdentType::Ref,
-        in_type: false,
-        is_module: false,
-        in_ts_module: false,
-        decl_kind: DeclKind::Lexical,
-        strict_mode: false,
-        config: InnerConfig {
+    visit_mut_pass(Resolver::root(InnerConfig {
+        handle_types: typescript,
+        unresolved_mark,
+        top_level_mark,
+        incremental: false,
+    }))
+}
+
+/// Re-runs [resolver] on an ast which was resolved before and then modified.
+///
+/// Instead of renumbering the whole program, only the statements (or module
+/// items) covered by `dirty` are resolved again. Syntax contexts of all other
+/// nodes are preserved, and the marks of existing scopes are reused, so
+/// references in dirty statements are resolved to the same syntax contexts
+/// as existing bindings.
+///
+/// `unresolved_mark`, `top_level_mark` and `typescript` should be the values
+/// used for the previous run.
+///
+/// # Dirty spans
+///
+/// `dirty` should contain the spans of modified statements. A statement
+/// contained in a dirty span is cleared and resolved from scratch, while a
+/// statement which only overlaps a dirty span is cleared only if it doesn't
+/// have any nested statement which can be cleared instead. Nodes with empty
+/// [SyntaxContext], like ones created by a transform, are resolved regardless
+/// of `dirty`.
+///
+/// Declarations added or removed by a dirty statement are not visible to
+/// clean references, so the statement of the enclosing scope (e.g. the whole
+/// function) should be marked as dirty in that case.
+pub fn incremental_resolver(
+    unresolved_mark: Mark,
+    top_level_mark: Mark,
+    typescript: bool,
+    dirty: Vec<Span>,
+) -> impl 'static + Pass {
+    assert_ne!(
+        unresolved_mark,
+        Mark::root(),
+        "Marker provided to resolver should not be the root mark"
+    );
+
+    (
+        visit_mut_pass(DirtyContextRemover::new(dirty)),
+        visit_mut_pass(Resolver::root(InnerConfig {
             handle_types: typescript,
             unresolved_mark,
             top_level_mark,
-        },
-    })
+            incremental: true,
+        })),
+    )
 }
 
 #[derive(Debug, Clone)]
@@ -173,6 +213,12 @@ struct Scope<'a> {
 
     /// All types declared in the scope
     declared_types: FxHashSet<Atom>,
+
+    /// `true` if [Scope::mark] is used by a binding or a block, and can't be
+    /// replaced by the mark of a binding resolved by the previous run.
+    ///
+    /// Used only by [incremental_resolver].
+    fixed_mark: bool,
 }
 
 impl<'a> Scope<'a> {
@@ -183,9 +229,23 @@ impl<'a> Scope<'a> {
             mark,
             declared_symbols: Default::default(),
             declared_types: Default::default(),
+            fixed_mark: false,
         }
     }
 
+    fn has_ancestor_with_mark(&self, mark: Mark) -> bool {
+        let mut scope = self.parent;
+
+        while let Some(cur) = scope {
+            if cur.mark == mark {
+                return true;
+            }
+            scope = cur.parent;
+        }
+
+        false
+    }
+
     fn is_declared(&self, symbol: &Atom) -> Option<&DeclKind> {
         self.declared_symbols
             .get(symbol)
@@ -215,10 +275,28 @@ struct InnerConfig {
     handle_types: bool,
     unresolved_mark: Mark,
     top_level_mark: Mark,
+    /// Reuse syntax contexts assigned by the previous run.
+    incremental: bool,
 }
 
 #[allow(clippy::needless_lifetimes)]
 impl<'a> Resolver<'a> {
+    fn root(config: InnerConfig) -> Self {
+        let mut current = Scope::new(ScopeKind::Fn, config.top_level_mark, None);
+        current.fixed_mark = true;
+
+        Resolver {
+            current,
+            ident_type: IdentType::Ref,
+            in_type: false,
+            is_module: false,
+            in_ts_module: false,
+            decl_kind: DeclKind::Lexical,
+            strict_mode: false,
+            config,
+        }
+    }
+
     #[cfg(test)]
     fn new(current: Scope<'a>, config: InnerConfig) -> Self {
         Resolver {
@@ -340,6 +418,9 @@ impl<'a> Resolver<'a> {
         }
 
         if id.ctxt != SyntaxContext::empty() {
+            if self.config.incremental {
+                self.declare_resolved(id, kind);
+            }
             return;
         }
 
@@ -350,18 +431,56 @@ impl<'a> Resolver<'a> {
         }
 
         let mark = self.current.mark;
+        self.current.fixed_mark = true;
 
         if mark != Mark::root() {
             id.ctxt = id.ctxt.apply_mark(mark);
         }
     }
 
+    /// Declares a binding resolved by the previous run, if it belongs to the
+    /// current scope.
+    ///
+    /// If no binding or block is assigned the mark of the current scope yet,
+    /// the scope adopts the mark of the binding.
+    fn declare_resolved(&mut self, id: &Ident, kind: DeclKind) {
+        let mark = id.ctxt.outer();
+
+        if mark == self.config.unresolved_mark || mark == Mark::root() {
+            return;
+        }
+
+        if !self.current.fixed_mark && !self.current.has_ancestor_with_mark(mark) {
+            self.current.mark = mark;
+            self.current.fixed_mark = true;
+        }
+
+        if mark != self.current.mark {
+            return;
+        }
+
+        if self.in_type {
+            self.current.declared_types.insert(id.sym.clone());
+        } else {
+            self.current.declared_symbols.insert(id.sym.clone(), kind);
+        }
+    }
+
     fn mark_block(&mut self, ctxt: &mut SyntaxContext) {
         if *ctxt != SyntaxContext::empty() {
+            if self.config.incremental && !self.current.fixed_mark {
+                let mark = ctxt.outer();
+
+                if mark != Mark::root() {
+                    self.current.mark = mark;
+                    self.current.fixed_mark = true;
+                }
+            }
             return;
         }
 
         let mark = self.current.mark;
+        self.current.fixed_mark = true;
 
         if mark != Mark::root() {
             *ctxt = ctxt.apply_mark(mark)
@@ -945,6 +1064,9 @@ impl VisitMut for Resolver<'_> {
 
     fn visit_mut_ident(&mut self, i: &mut Ident) {
         if i.ctxt != SyntaxContext::empty() {
+            if self.config.incremental && self.ident_type == IdentType::Binding {
+                self.declare_resolved(i, self.decl_kind);
+            }
             return;
         }
 
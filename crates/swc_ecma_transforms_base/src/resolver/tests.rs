@@ -1,3 +1,4 @@
+use swc_common::Spanned;
 use swc_ecma_parser::Syntax;
 
 use super::*;
@@ -67,6 +68,7 @@ fn test_mark_for() {
                 handle_types: true,
                 unresolved_mark: Mark::fresh(Mark::root()),
                 top_level_mark: mark1,
+                incremental: false,
             },
         );
         let mut folder2 = Resolver::new(
@@ -75,6 +77,7 @@ fn test_mark_for() {
                 handle_types: true,
                 unresolved_mark: Mark::fresh(Mark::root()),
                 top_level_mark: mark2,
+                incremental: false,
             },
         );
         folder2
@@ -88,6 +91,7 @@ fn test_mark_for() {
                 handle_types: true,
                 unresolved_mark: Mark::fresh(Mark::root()),
                 top_level_mark: mark3,
+                incremental: false,
             },
         );
         folder3
@@ -102,6 +106,7 @@ fn test_mark_for() {
                 handle_types: true,
                 unresolved_mark: Mark::fresh(Mark::root()),
                 top_level_mark: mark4,
+                incremental: false,
             },
         );
         folder4
@@ -191,3 +196,123 @@ fn issue_2516() {
         },
     );
 }
+
+/// Returns all identifiers in `program`, in the order of appearance.
+fn idents(program: &Program) -> Vec<(Atom, SyntaxContext)> {
+    struct Collector(Vec<(Atom, SyntaxContext)>);
+
+    impl swc_ecma_visit::Visit for Collector {
+        fn visit_ident(&mut self, i: &Ident) {
+            self.0.push((i.sym.clone(), i.ctxt));
+        }
+    }
+
+    let mut v = Collector(Vec::new());
+    swc_ecma_visit::VisitWith::visit_with(program, &mut v);
+    v.0
+}
+
+#[test]
+fn incremental_new_stmt() {
+    crate::tests::Tester::run(|tester| {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        let module = tester.parse_module(
+            "input.js",
+            "let a = 1;
+            function foo(b) {
+                let c = a + b;
+                use(c);
+            }",
+        )?;
+        let mut program =
+            Program::Module(module).apply(resolver(unresolved_mark, top_level_mark, false));
+        let before = idents(&program);
+
+        // Replace `use(c)` with a statement which is not resolved yet.
+        let stmt = tester.parse_stmt("edit.js", "use(a, b, c, d);")?;
+        let Program::Module(module) = &mut program else {
+            unreachable!()
+        };
+        let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(foo))) = &mut module.body[1] else {
+            unreachable!()
+        };
+        foo.function.body.as_mut().unwrap().stmts[1] = stmt;
+
+        let program = program.apply(incremental_resolver(
+            unresolved_mark,
+            top_level_mark,
+            false,
+            Vec::new(),
+        ));
+        let after = idents(&program);
+
+        // `a`, `foo`, `b`, `c`, `a` and `b` are not modified.
+        assert_eq!(before[..6], after[..6]);
+
+        assert_eq!(after[6].1.outer(), unresolved_mark);
+        assert_eq!(after[7], before[0]);
+        assert_eq!(after[8], before[2]);
+        assert_eq!(after[9], before[3]);
+        assert_eq!(after[10].1.outer(), unresolved_mark);
+
+        Ok(())
+    });
+}
+
+#[test]
+fn incremental_dirty_stmt() {
+    crate::tests::Tester::run(|tester| {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        let module = tester.parse_module(
+            "input.js",
+            "let a = 1;
+            function foo() {
+                {
+                    let a = 2;
+                    use(a);
+                }
+                use(a);
+            }",
+        )?;
+        let mut program =
+            Program::Module(module).apply(resolver(unresolved_mark, top_level_mark, false));
+        let before = idents(&program);
+        assert_ne!(before[5], before[0]);
+
+        // Rename the binding in the block, so `use(a)` in the block should now
+        // reference the top-level `a`.
+        let Program::Module(module) = &mut program else {
+            unreachable!()
+        };
+        let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(foo))) = &mut module.body[1] else {
+            unreachable!()
+        };
+        let block = &mut foo.function.body.as_mut().unwrap().stmts[0];
+        let dirty = block.span();
+        let Stmt::Block(BlockStmt { stmts, .. }) = block else {
+            unreachable!()
+        };
+        let Stmt::Decl(Decl::Var(var)) = &mut stmts[0] else {
+            unreachable!()
+        };
+        var.decls[0].name.as_mut_ident().unwrap().sym = "x".into();
+
+        let program = program.apply(incremental_resolver(
+            unresolved_mark,
+            top_level_mark,
+            false,
+            vec![dirty],
+        ));
+        let after = idents(&program);
+
+        assert_eq!(before[..2], after[..2]);
+        assert_eq!(after[4], before[0]);
+        assert_eq!(before[5..], after[5..]);
+
+        Ok(())
+    });
+}
@@ -0,0 +1,548 @@
+#![allow(non_upper_case_globals)]
+//! Detection of syntax used by a program.
+//!
+//! Most lowering passes are no-ops for a file which does not use the syntax
+//! they lower, but they still traverse the whole file. A single read-only
+//! [SyntaxFeatures::scan] is cheaper than those traversals, so passes can be
+//! skipped using [SyntaxFeatureScan].
+
+use std::{cell::Cell, rc::Rc};
+
+use bitflags::bitflags;
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+bitflags! {
+    /// Syntax used by a program.
+    ///
+    /// Flags are conservative: a flag may be set for a file which does not
+    /// need the related pass, but it's never unset for a file which does.
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+    pub struct SyntaxFeatures: u32 {
+        /// Type annotations, type-only declarations and other syntax removed
+        /// by the typescript strip pass.
+        const TypeScript = 1 << 0;
+
+        /// JSX elements and fragments.
+        const Jsx = 1 << 1;
+
+        /// Public or private class properties and accessors.
+        const ClassFields = 1 << 2;
+
+        /// `#name`
+        const PrivateNames = 1 << 3;
+
+        /// `static { }`
+        const StaticBlocks = 1 << 4;
+
+        /// `&&=`, `||=` and `??=`
+        const LogicalAssignment = 1 << 5;
+
+        /// `export * as ns from 'mod'`
+        const ExportNamespaceFrom = 1 << 6;
+
+        /// `??`
+        const NullishCoalescing = 1 << 7;
+
+        /// `a?.b`
+        const OptionalChaining = 1 << 8;
+
+        /// `catch {}`
+        const OptionalCatchBinding = 1 << 9;
+
+        /// `{ ...a }` in object literals and patterns.
+        const ObjectRestSpread = 1 << 10;
+
+        /// Async functions and `for await`.
+        const AsyncFunctions = 1 << 11;
+
+        /// `**` and `**=`
+        const Exponentiation = 1 << 12;
+
+        /// Template literals, including tagged ones.
+        const TemplateLiterals = 1 << 13;
+
+        const Classes = 1 << 14;
+
+        /// `...a` in calls and array literals.
+        const Spread = 1 << 15;
+
+        const ArrowFunctions = 1 << 16;
+
+        /// Shorthand properties and methods in object literals.
+        const ShorthandProperties = 1 << 17;
+
+        /// `[key]` in object literals and classes.
+        const ComputedProperties = 1 << 18;
+
+        const ForOf = 1 << 19;
+
+        /// Array and object patterns.
+        const Destructuring = 1 << 20;
+
+        const Generators = 1 << 21;
+
+        /// `new.target`
+        const NewTarget = 1 << 22;
+
+        /// Regular expression literals.
+        const RegExp = 1 << 23;
+    }
+}
+
+impl SyntaxFeatures {
+    /// Returns all syntax used by `program`.
+    pub fn scan(program: &Program) -> Self {
+        let mut v = Scanner {
+            found: Default::default(),
+            interest: Self::empty(),
+        };
+        program.visit_with(&mut v);
+        v.found
+    }
+
+    /// Returns true if `program` uses any of `features`.
+    ///
+    /// This is faster than [SyntaxFeatures::scan] as it stops at the first
+    /// match.
+    pub fn is_used_by(self, program: &Program) -> bool {
+        let mut v = Scanner {
+            found: Default::default(),
+            interest: self,
+        };
+        program.visit_with(&mut v);
+        v.found.intersects(self)
+    }
+}
+
+/// Shares a [SyntaxFeatures::scan] between passes applied to the same
+/// program.
+///
+/// Passes may add syntax, so the program is scanned again before the next
+/// check once a pass has run. A feature which was found is assumed to stay,
+/// as running a no-op pass is always correct.
+#[derive(Clone)]
+pub struct SyntaxFeatureScan {
+    /// Features found by the last scan, and whether a pass ran after it.
+    state: Rc<Cell<(SyntaxFeatures, bool)>>,
+}
+
+impl Default for SyntaxFeatureScan {
+    fn default() -> Self {
+        Self {
+            state: Rc::new(Cell::new((SyntaxFeatures::empty(), true))),
+        }
+    }
+}
+
+impl SyntaxFeatureScan {
+    /// Runs `pass` only if the program uses any of `required`.
+    ///
+    /// If `required` is empty, `pass` always runs. This should be used for
+    /// passes which don't lower specific syntax, so that syntax added by them
+    /// is detected.
+    pub fn requires<P>(&self, required: SyntaxFeatures, pass: P) -> impl Pass
+    where
+        P: Pass,
+    {
+        Gated {
+            scan: self.clone(),
+            required,
+            pass,
+        }
+    }
+
+    fn uses(&self, program: &Program, required: SyntaxFeatures) -> bool {
+        let (found, stale) = self.state.get();
+        if found.intersects(required) {
+            return true;
+        }
+        if !stale {
+            return false;
+        }
+
+        let found = SyntaxFeatures::scan(program);
+        self.state.set((found, false));
+        found.intersects(required)
+    }
+
+    fn invalidate(&self) {
+        let (found, _) = self.state.get();
+        self.state.set((found, true));
+    }
+}
+
+struct Gated<P> {
+    scan: SyntaxFeatureScan,
+    required: SyntaxFeatures,
+    pass: P,
+}
+
+impl<P> Pass for Gated<P>
+where
+    P: Pass,
+{
+    fn process(&mut self, program: &mut Program) {
+        if !self.required.is_empty() && !self.scan.uses(program, self.required) {
+            return;
+        }
+
+        self.pass.process(program);
+        self.scan.invalidate();
+    }
+}
+
+struct Scanner {
+    found: SyntaxFeatures,
+    /// Scanning stops once any of these is found.
+    interest: SyntaxFeatures,
+}
+
+impl Scanner {
+    fn add(&mut self, features: SyntaxFeatures) {
+        self.found |= features;
+    }
+
+    fn add_if(&mut self, cond: bool, features: SyntaxFeatures) {
+        if cond {
+            self.found |= features;
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.found.intersects(self.interest)
+    }
+}
+
+/// `$T` is typescript syntax. Children of types are not visited as they are
+/// removed.
+macro_rules! typescript {
+    ($($name:ident: $T:ty),* $(,)?) => {
+        $(
+            fn $name(&mut self, _: &$T) {
+                self.add(SyntaxFeatures::TypeScript);
+            }
+        )*
+    };
+    ($($name:ident: $T:ty),* $(,)?; visit children) => {
+        $(
+            fn $name(&mut self, n: &$T) {
+                self.add(SyntaxFeatures::TypeScript);
+                n.visit_children_with(self);
+            }
+        )*
+    };
+}
+
+impl Visit for Scanner {
+    typescript!(
+        visit_ts_type_ann: TsTypeAnn,
+        visit_ts_type: TsType,
+        visit_ts_type_param_decl: TsTypeParamDecl,
+        visit_ts_type_param_instantiation: TsTypeParamInstantiation,
+        visit_ts_interface_decl: TsInterfaceDecl,
+        visit_ts_type_alias_decl: TsTypeAliasDecl,
+        visit_ts_import_equals_decl: TsImportEqualsDecl,
+        visit_ts_namespace_export_decl: TsNamespaceExportDecl,
+        visit_ts_index_signature: TsIndexSignature,
+        visit_ts_expr_with_type_args: TsExprWithTypeArgs,
+    );
+
+    typescript!(
+        visit_ts_enum_decl: TsEnumDecl,
+        visit_ts_module_decl: TsModuleDecl,
+        visit_ts_export_assignment: TsExportAssignment,
+        visit_ts_param_prop: TsParamProp,
+        visit_ts_as_expr: TsAsExpr,
+        visit_ts_const_assertion: TsConstAssertion,
+        visit_ts_instantiation: TsInstantiation,
+        visit_ts_non_null_expr: TsNonNullExpr,
+        visit_ts_satisfies_expr: TsSatisfiesExpr,
+        visit_ts_type_assertion: TsTypeAssertion;
+        visit children
+    );
+
+    fn visit_array_pat(&mut self, n: &ArrayPat) {
+        self.add(SyntaxFeatures::Destructuring);
+        self.add_if(n.optional, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        self.add(SyntaxFeatures::ArrowFunctions);
+        self.add_if(n.is_async, SyntaxFeatures::AsyncFunctions);
+        n.visit_children_with(self);
+    }
+
+    fn visit_assign_expr(&mut self, n: &AssignExpr) {
+        match n.op {
+            op!("&&=") | op!("||=") => self.add(SyntaxFeatures::LogicalAssignment),
+            op!("??=") => {
+                self.add(SyntaxFeatures::LogicalAssignment | SyntaxFeatures::NullishCoalescing)
+            }
+            op!("**=") => self.add(SyntaxFeatures::Exponentiation),
+            _ => {}
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_auto_accessor(&mut self, n: &AutoAccessor) {
+        self.add(SyntaxFeatures::ClassFields);
+        self.add_if(
+            n.accessibility.is_some() || n.is_abstract || n.is_override || n.definite,
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_bin_expr(&mut self, n: &BinExpr) {
+        match n.op {
+            op!("??") => self.add(SyntaxFeatures::NullishCoalescing),
+            op!("**") => self.add(SyntaxFeatures::Exponentiation),
+            _ => {}
+        }
+        n.visit_children_with(self);
+    }
+
+    fn visit_catch_clause(&mut self, n: &CatchClause) {
+        self.add_if(n.param.is_none(), SyntaxFeatures::OptionalCatchBinding);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class(&mut self, n: &Class) {
+        self.add(SyntaxFeatures::Classes);
+        self.add_if(n.is_abstract, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_decl(&mut self, n: &ClassDecl) {
+        self.add_if(n.declare, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_method(&mut self, n: &ClassMethod) {
+        self.add_if(
+            n.accessibility.is_some() || n.is_abstract || n.is_optional || n.is_override,
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_class_prop(&mut self, n: &ClassProp) {
+        self.add(SyntaxFeatures::ClassFields);
+        self.add_if(
+            n.declare
+                || n.readonly
+                || n.is_override
+                || n.is_optional
+                || n.is_abstract
+                || n.definite
+                || n.accessibility.is_some(),
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_computed_prop_name(&mut self, n: &ComputedPropName) {
+        self.add(SyntaxFeatures::ComputedProperties);
+        n.visit_children_with(self);
+    }
+
+    fn visit_constructor(&mut self, n: &Constructor) {
+        self.add_if(
+            n.accessibility.is_some() || n.body.is_none(),
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_export_all(&mut self, n: &ExportAll) {
+        self.add_if(n.type_only, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_export_specifier(&mut self, n: &ExportSpecifier) {
+        match n {
+            ExportSpecifier::Namespace(..) => self.add(SyntaxFeatures::ExportNamespaceFrom),
+            ExportSpecifier::Named(named) => {
+                self.add_if(named.is_type_only, SyntaxFeatures::TypeScript)
+            }
+            ExportSpecifier::Default(..) => {}
+        }
+    }
+
+    fn visit_expr(&mut self, n: &Expr) {
+        if self.is_done() {
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_expr_or_spread(&mut self, n: &ExprOrSpread) {
+        self.add_if(n.spread.is_some(), SyntaxFeatures::Spread);
+        n.visit_children_with(self);
+    }
+
+    fn visit_fn_decl(&mut self, n: &FnDecl) {
+        self.add_if(n.declare, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.add(SyntaxFeatures::ForOf);
+        self.add_if(n.is_await, SyntaxFeatures::AsyncFunctions);
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        self.add_if(n.is_async, SyntaxFeatures::AsyncFunctions);
+        self.add_if(n.is_generator, SyntaxFeatures::Generators);
+        // Overloads and declared functions
+        self.add_if(n.body.is_none(), SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_ident(&mut self, n: &Ident) {
+        self.add_if(n.optional, SyntaxFeatures::TypeScript);
+    }
+
+    fn visit_import_decl(&mut self, n: &ImportDecl) {
+        self.add_if(n.type_only, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_import_named_specifier(&mut self, n: &ImportNamedSpecifier) {
+        self.add_if(n.is_type_only, SyntaxFeatures::TypeScript);
+    }
+
+    fn visit_jsx_element(&mut self, n: &JSXElement) {
+        self.add(SyntaxFeatures::Jsx);
+        n.visit_children_with(self);
+    }
+
+    fn visit_jsx_fragment(&mut self, n: &JSXFragment) {
+        self.add(SyntaxFeatures::Jsx);
+        n.visit_children_with(self);
+    }
+
+    fn visit_meta_prop_expr(&mut self, n: &MetaPropExpr) {
+        self.add_if(n.kind == MetaPropKind::NewTarget, SyntaxFeatures::NewTarget);
+    }
+
+    fn visit_module_item(&mut self, n: &ModuleItem) {
+        if self.is_done() {
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_named_export(&mut self, n: &NamedExport) {
+        self.add_if(n.type_only, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_object_pat(&mut self, n: &ObjectPat) {
+        self.add(SyntaxFeatures::Destructuring);
+        self.add_if(n.optional, SyntaxFeatures::TypeScript);
+        self.add_if(
+            n.props.iter().any(|p| matches!(p, ObjectPatProp::Rest(..))),
+            SyntaxFeatures::ObjectRestSpread,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_opt_chain_expr(&mut self, n: &OptChainExpr) {
+        self.add(SyntaxFeatures::OptionalChaining);
+        n.visit_children_with(self);
+    }
+
+    fn visit_params(&mut self, n: &[Param]) {
+        // `this` parameters
+        self.add_if(
+            n.first().map_or(
+                false,
+                |p| matches!(&p.pat, Pat::Ident(i) if &*i.id.sym == "this"),
+            ),
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_private_method(&mut self, n: &PrivateMethod) {
+        self.add_if(
+            n.accessibility.is_some() || n.is_abstract || n.is_optional || n.is_override,
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_private_name(&mut self, _: &PrivateName) {
+        self.add(SyntaxFeatures::PrivateNames);
+    }
+
+    fn visit_private_prop(&mut self, n: &PrivateProp) {
+        self.add(SyntaxFeatures::ClassFields);
+        self.add_if(
+            n.readonly || n.is_override || n.is_optional || n.definite || n.accessibility.is_some(),
+            SyntaxFeatures::TypeScript,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_prop(&mut self, n: &Prop) {
+        self.add_if(
+            matches!(n, Prop::Shorthand(..) | Prop::Method(..)),
+            SyntaxFeatures::ShorthandProperties,
+        );
+        n.visit_children_with(self);
+    }
+
+    fn visit_prop_or_spread(&mut self, n: &PropOrSpread) {
+        self.add_if(n.is_spread(), SyntaxFeatures::ObjectRestSpread);
+        n.visit_children_with(self);
+    }
+
+    fn visit_regex(&mut self, _: &Regex) {
+        self.add(SyntaxFeatures::RegExp);
+    }
+
+    fn visit_setter_prop(&mut self, n: &SetterProp) {
+        self.add_if(n.this_param.is_some(), SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_static_block(&mut self, n: &StaticBlock) {
+        self.add(SyntaxFeatures::StaticBlocks);
+        n.visit_children_with(self);
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt) {
+        if self.is_done() {
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_tagged_tpl(&mut self, n: &TaggedTpl) {
+        self.add(SyntaxFeatures::TemplateLiterals);
+        n.visit_children_with(self);
+    }
+
+    fn visit_tpl(&mut self, n: &Tpl) {
+        self.add(SyntaxFeatures::TemplateLiterals);
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_decl(&mut self, n: &VarDecl) {
+        self.add_if(n.declare, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+
+    fn visit_var_declarator(&mut self, n: &VarDeclarator) {
+        self.add_if(n.definite, SyntaxFeatures::TypeScript);
+        n.visit_children_with(self);
+    }
+}
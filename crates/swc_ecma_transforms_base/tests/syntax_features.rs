@@ -0,0 +1,123 @@
+use std::{cell::Cell, rc::Rc};
+
+use swc_common::{FileName, SourceMap};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_program, Syntax, TsSyntax};
+use swc_ecma_transforms_base::syntax_features::{SyntaxFeatureScan, SyntaxFeatures};
+
+fn parse(src: &str) -> Program {
+    let cm = SourceMap::default();
+    let fm = cm.new_source_file(FileName::Anon.into(), src.into());
+
+    parse_file_as_program(
+        &fm,
+        Syntax::Typescript(TsSyntax {
+            tsx: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        None,
+        &mut Vec::new(),
+    )
+    .unwrap()
+}
+
+fn scan(src: &str) -> SyntaxFeatures {
+    SyntaxFeatures::scan(&parse(src))
+}
+
+#[test]
+fn detects_syntax() {
+    assert_eq!(scan("var a = 1; foo(a);"), SyntaxFeatures::empty());
+
+    assert_eq!(
+        scan("const f = async () => a ?? b?.c;"),
+        SyntaxFeatures::ArrowFunctions
+            | SyntaxFeatures::AsyncFunctions
+            | SyntaxFeatures::NullishCoalescing
+            | SyntaxFeatures::OptionalChaining
+    );
+    assert_eq!(
+        scan("class A { #a = 1; static { this.#a } }"),
+        SyntaxFeatures::Classes
+            | SyntaxFeatures::ClassFields
+            | SyntaxFeatures::PrivateNames
+            | SyntaxFeatures::StaticBlocks
+    );
+    assert_eq!(
+        scan("function* g() { const { a, ...b } = { ...c, d, [e]: 1 }; a **= 2; }"),
+        SyntaxFeatures::Generators
+            | SyntaxFeatures::Destructuring
+            | SyntaxFeatures::ObjectRestSpread
+            | SyntaxFeatures::ShorthandProperties
+            | SyntaxFeatures::ComputedProperties
+            | SyntaxFeatures::Exponentiation
+    );
+    assert_eq!(
+        scan("for (const a of `${b}`) try { f(...a, /x/y) } catch {}"),
+        SyntaxFeatures::ForOf
+            | SyntaxFeatures::TemplateLiterals
+            | SyntaxFeatures::Spread
+            | SyntaxFeatures::RegExp
+            | SyntaxFeatures::OptionalCatchBinding
+    );
+    assert_eq!(scan("<div />"), SyntaxFeatures::Jsx);
+}
+
+#[test]
+fn detects_typescript() {
+    for src in [
+        "let a: number;",
+        "let a = b as C;",
+        "let a = b!;",
+        "interface A {}",
+        "enum A {}",
+        "function f(this) {}",
+        "function f(a?) {}",
+        "class A { private a; }",
+        "class A { constructor(public a) {} }",
+        "import type A from 'a';",
+        "export { type A } from 'a';",
+        "declare const a;",
+        "f<T>();",
+        "<div>{(): unknown => null}</div>;",
+    ] {
+        assert!(
+            SyntaxFeatures::TypeScript.is_used_by(&parse(src)),
+            "`{}` should be detected",
+            src
+        );
+    }
+
+    assert!(!SyntaxFeatures::TypeScript.is_used_by(&parse("class A { a = 1; f(b = 1) {} }")));
+}
+
+#[test]
+fn skips_passes_for_unused_syntax() {
+    let count = |runs: &Rc<Cell<usize>>| {
+        let runs = runs.clone();
+        move |_: &mut Program| runs.set(runs.get() + 1)
+    };
+
+    let scan = SyntaxFeatureScan::default();
+    let classes = Rc::new(Cell::new(0));
+    let arrows = Rc::new(Cell::new(0));
+
+    let mut program = parse("class A {}");
+    program.mutate((
+        scan.requires(SyntaxFeatures::Classes, fn_pass(count(&classes))),
+        scan.requires(SyntaxFeatures::ArrowFunctions, fn_pass(count(&arrows))),
+        // Syntax added by this pass should be detected by the next one.
+        scan.requires(
+            SyntaxFeatures::empty(),
+            fn_pass(|program: &mut Program| {
+                let arrow = parse("() => {};").expect_script().body;
+                program.as_mut_script().unwrap().body.extend(arrow);
+            }),
+        ),
+        scan.requires(SyntaxFeatures::ArrowFunctions, fn_pass(count(&arrows))),
+    ));
+
+    assert_eq!(classes.get(), 1);
+    assert_eq!(arrows.get(), 1);
+}
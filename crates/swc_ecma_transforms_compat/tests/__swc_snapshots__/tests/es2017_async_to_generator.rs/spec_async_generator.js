@@ -0,0 +1,39 @@
+function gen(iterable) {
+    return /*#__PURE__*/ _wrap_async_generator(function*() {
+        try {
+            {
+                var _iteratorAbruptCompletion = false, _didIteratorError = false, _iteratorError;
+                try {
+                    for(var _iterator = _async_iterator(iterable), _step; _iteratorAbruptCompletion = !(_step = yield _await_async_generator(_iterator.next())).done; _iteratorAbruptCompletion = false){
+                        if (Object(_step) !== _step) {
+                            _iteratorAbruptCompletion = false;
+                            throw new TypeError(_step + " is not an object");
+                        }
+                        let _value = _step.value;
+                        const value = _value;
+                        yield value;
+                    }
+                } catch (err) {
+                    _didIteratorError = true;
+                    _iteratorError = err;
+                } finally{
+                    try {
+                        if (_iteratorAbruptCompletion && _iterator.return != null) {
+                            _step = yield _await_async_generator(_iterator.return());
+                            if (Object(_step) !== _step) {
+                                throw new TypeError(_step + " is not an object");
+                            }
+                        }
+                    } finally{
+                        if (_didIteratorError) {
+                            throw _iteratorError;
+                        }
+                    }
+                }
+            }
+            return yield _await_async_generator(getResult());
+        } finally{
+            yield _await_async_generator(cleanup());
+        }
+    })();
+}
@@ -0,0 +1,10 @@
+function inject($http, _1) {
+    return /*#__PURE__*/ _async_to_generator(function*($http, [first], $q = load(), ...rest) {
+        yield $http.get(first, $q, rest);
+    }).apply(this, arguments);
+}
+const handler = function(req, res, _2) {
+    return /*#__PURE__*/ _async_to_generator(function*(req, res, { user }) {
+        yield res.send(user);
+    }).apply(this, arguments);
+};
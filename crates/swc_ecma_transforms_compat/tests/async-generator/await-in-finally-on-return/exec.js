@@ -0,0 +1,17 @@
+async function* gen() {
+    try {
+        yield 1;
+    } finally {
+        await null;
+        console.log("after await in finally");
+        await Promise.resolve();
+        console.log("finally done");
+    }
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.return(5));
+    console.log(await it.next());
+})();
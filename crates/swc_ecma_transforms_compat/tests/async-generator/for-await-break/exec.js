@@ -0,0 +1,46 @@
+async function* gen() {
+    try {
+        yield 1;
+        yield 2;
+    } finally {
+        console.log("gen finally");
+    }
+}
+
+const iterable = {
+    [Symbol.asyncIterator]() {
+        let i = 0;
+        return {
+            next() {
+                return Promise.resolve({ value: i++, done: i > 3 });
+            },
+            return() {
+                console.log("return called");
+                return Promise.resolve({ done: true });
+            },
+        };
+    },
+};
+
+(async () => {
+    for await (const v of gen()) {
+        console.log(v);
+        break;
+    }
+
+    try {
+        for await (const v of iterable) {
+            console.log(v);
+            throw new Error("boom");
+        }
+    } catch (e) {
+        console.log("caught", e.message);
+    }
+
+    label: for (const x of [1, 2]) {
+        for await (const v of iterable) {
+            console.log(x, v);
+            continue label;
+        }
+    }
+})();
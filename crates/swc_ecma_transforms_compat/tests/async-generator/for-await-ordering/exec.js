@@ -0,0 +1,21 @@
+async function* gen() {
+    yield 1;
+    yield 2;
+}
+
+(async () => {
+    for await (const v of gen()) {
+        console.log("value", v);
+    }
+    console.log("done");
+})();
+
+Promise.resolve()
+    .then(() => console.log("tick 1"))
+    .then(() => console.log("tick 2"))
+    .then(() => console.log("tick 3"))
+    .then(() => console.log("tick 4"))
+    .then(() => console.log("tick 5"))
+    .then(() => console.log("tick 6"))
+    .then(() => console.log("tick 7"))
+    .then(() => console.log("tick 8"));
@@ -0,0 +1,23 @@
+const iterable = {
+    [Symbol.asyncIterator]() {
+        return {
+            next() {
+                return Promise.resolve({ value: 1, done: false });
+            },
+            return() {
+                return Promise.resolve(1);
+            },
+        };
+    },
+};
+
+(async () => {
+    try {
+        for await (const v of iterable) {
+            console.log(v);
+            break;
+        }
+    } catch (e) {
+        console.log(e.constructor.name);
+    }
+})();
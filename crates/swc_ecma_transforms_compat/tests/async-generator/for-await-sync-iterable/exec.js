@@ -0,0 +1,19 @@
+(async () => {
+    for await (const v of [Promise.resolve(1), 2, { then: (r) => r(3) }]) {
+        console.log(v);
+    }
+
+    function* gen() {
+        try {
+            yield 1;
+            yield 2;
+        } finally {
+            console.log("sync finally");
+        }
+    }
+
+    for await (const v of gen()) {
+        console.log(v);
+        break;
+    }
+})();
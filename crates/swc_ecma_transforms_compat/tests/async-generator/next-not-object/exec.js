@@ -0,0 +1,19 @@
+const iterable = {
+    [Symbol.asyncIterator]() {
+        return {
+            next() {
+                return Promise.resolve(1);
+            },
+        };
+    },
+};
+
+(async () => {
+    try {
+        for await (const v of iterable) {
+            console.log(v);
+        }
+    } catch (e) {
+        console.log(e.constructor.name);
+    }
+})();
@@ -0,0 +1,16 @@
+async function* gen() {
+    console.log("gen start");
+    yield 1;
+    console.log("gen resumed");
+    yield await Promise.resolve(2);
+}
+
+const it = gen();
+it.next().then((v) => console.log("next 1", v.value));
+it.next().then((v) => console.log("next 2", v.value));
+Promise.resolve()
+    .then(() => console.log("tick 1"))
+    .then(() => console.log("tick 2"))
+    .then(() => console.log("tick 3"))
+    .then(() => console.log("tick 4"))
+    .then(() => console.log("tick 5"));
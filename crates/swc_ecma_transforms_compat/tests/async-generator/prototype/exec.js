@@ -0,0 +1,6 @@
+async function* gen() {}
+
+const it = gen();
+console.log(typeof it.next, typeof it.return, typeof it.throw);
+console.log(it[Symbol.asyncIterator]() === it);
+console.log(Object.prototype.toString.call(it.next()));
@@ -0,0 +1,16 @@
+async function* gen() {
+    console.log("start");
+    yield 1;
+    console.log("after 1");
+    yield 2;
+    console.log("after 2");
+    return 3;
+}
+
+const it = gen();
+const log = (label) => (v) => console.log(label, JSON.stringify(v));
+it.next().then(log("first"));
+it.next().then(log("second"));
+it.return(10).then(log("return"));
+it.next().then(log("after return"));
+console.log("sync end");
@@ -0,0 +1,16 @@
+async function* gen() {
+    try {
+        return Promise.reject(new Error("rejected"));
+    } catch (e) {
+        console.log("caught", e.message);
+    } finally {
+        console.log("finally");
+    }
+    return Promise.resolve("resolved");
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.next());
+})();
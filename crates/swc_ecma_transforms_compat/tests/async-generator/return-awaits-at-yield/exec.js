@@ -0,0 +1,21 @@
+async function* gen() {
+    try {
+        yield 1;
+    } catch (e) {
+        console.log("caught in generator", e);
+        yield "from catch";
+    } finally {
+        console.log("finally");
+    }
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.return(Promise.resolve("resolved")));
+
+    const it2 = gen();
+    console.log(await it2.next());
+    console.log(await it2.return(Promise.reject("rejected")));
+    console.log(await it2.next());
+})();
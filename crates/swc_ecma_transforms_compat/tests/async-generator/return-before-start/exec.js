@@ -0,0 +1,18 @@
+async function* gen() {
+    console.log("never");
+    yield 1;
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.return(Promise.resolve(42)));
+    console.log(await it.next());
+
+    const it2 = gen();
+    try {
+        await it2.return(Promise.reject(new Error("boom")));
+    } catch (e) {
+        console.log("rejected", e.message);
+    }
+    console.log(await it2.next());
+})();
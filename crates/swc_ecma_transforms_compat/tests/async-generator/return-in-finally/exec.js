@@ -0,0 +1,16 @@
+async function* gen() {
+    try {
+        yield 1;
+    } finally {
+        yield "cleanup";
+        console.log("after cleanup");
+    }
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.return("done"));
+    console.log(await it.next());
+    console.log(await it.next());
+})();
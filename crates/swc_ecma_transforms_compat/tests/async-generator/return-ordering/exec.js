@@ -0,0 +1,23 @@
+async function* explicit() {
+    return Promise.resolve(1);
+}
+
+async function* implicit() {
+    yield 1;
+}
+
+const it = explicit();
+it.next().then((v) => console.log("explicit", v));
+const it2 = implicit();
+it2.next().then((v) => console.log("implicit 1", v));
+it2.next().then((v) => console.log("implicit 2", v));
+const it3 = implicit();
+it3.next().then((v) => console.log("return 1", v));
+it3.return("early").then((v) => console.log("return 2", v));
+Promise.resolve()
+    .then(() => console.log("tick 1"))
+    .then(() => console.log("tick 2"))
+    .then(() => console.log("tick 3"))
+    .then(() => console.log("tick 4"))
+    .then(() => console.log("tick 5"))
+    .then(() => console.log("tick 6"));
@@ -0,0 +1,17 @@
+async function* gen() {
+    try {
+        yield 1;
+    } catch (e) {
+        console.log("caught", e);
+        yield 2;
+    }
+    return 3;
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.throw("err"));
+    console.log(await it.next());
+    console.log(await it.next());
+})();
@@ -0,0 +1,17 @@
+async function* gen() {
+    try {
+        yield 1;
+    } finally {
+        console.log("never");
+    }
+}
+
+(async () => {
+    const it = gen();
+    try {
+        await it.throw(new Error("boom"));
+    } catch (e) {
+        console.log("rejected", e.message);
+    }
+    console.log(await it.next());
+})();
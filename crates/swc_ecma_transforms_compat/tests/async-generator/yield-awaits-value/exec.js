@@ -0,0 +1,18 @@
+async function* gen() {
+    const a = yield Promise.resolve(1);
+    console.log("a", a);
+    try {
+        yield Promise.reject(new Error("rejected"));
+    } catch (e) {
+        console.log("caught", e.message);
+    }
+    yield { then: (resolve) => resolve(3) };
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.next("x"));
+    console.log(await it.next());
+    console.log(await it.next());
+})();
@@ -0,0 +1,29 @@
+async function* inner() {
+    try {
+        const x = yield "a";
+        console.log("inner got", x);
+        yield "b";
+    } finally {
+        console.log("inner finally");
+    }
+    return "inner result";
+}
+
+async function* outer() {
+    const result = yield* inner();
+    console.log("result", result);
+    yield "c";
+}
+
+(async () => {
+    const it = outer();
+    console.log(await it.next());
+    console.log(await it.next("sent"));
+    console.log(await it.return("early"));
+    console.log(await it.next());
+
+    const it2 = outer();
+    for await (const v of it2) {
+        console.log("value", v);
+    }
+})();
@@ -0,0 +1,26 @@
+function* syncGen() {
+    try {
+        yield Promise.resolve(1);
+        yield 2;
+    } catch (e) {
+        console.log("sync caught", e);
+        yield 3;
+    } finally {
+        console.log("sync finally");
+    }
+}
+
+async function* gen() {
+    yield* syncGen();
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    console.log(await it.throw("err"));
+    console.log(await it.next());
+
+    const it2 = gen();
+    console.log(await it2.next());
+    console.log(await it2.return("ret"));
+})();
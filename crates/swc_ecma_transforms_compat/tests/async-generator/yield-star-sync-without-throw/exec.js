@@ -0,0 +1,28 @@
+const iterable = {
+    [Symbol.iterator]() {
+        return {
+            next() {
+                return { value: 1, done: false };
+            },
+            return() {
+                console.log("return called");
+                return { done: true };
+            },
+        };
+    },
+};
+
+async function* gen() {
+    yield* iterable;
+}
+
+(async () => {
+    const it = gen();
+    console.log(await it.next());
+    try {
+        await it.throw(new Error("boom"));
+    } catch (e) {
+        console.log(e.constructor.name);
+    }
+    console.log(await it.next());
+})();
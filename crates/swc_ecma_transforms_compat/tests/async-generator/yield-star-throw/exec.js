@@ -0,0 +1,24 @@
+async function* inner() {
+    try {
+        yield 1;
+    } catch (e) {
+        console.log("inner caught", e);
+        yield 2;
+    }
+}
+
+async function* outer() {
+    try {
+        yield* inner();
+    } catch (e) {
+        console.log("outer caught", e);
+    }
+}
+
+(async () => {
+    const it = outer();
+    console.log(await it.next());
+    console.log(await it.throw("first"));
+    console.log(await it.throw("second"));
+    console.log(await it.next());
+})();
@@ -460,7 +460,10 @@ expect(foo[k]).toBe(k)
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_assignment,
     r#"
 foo = {
@@ -471,7 +474,10 @@ foo = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_argument,
     r#"
 foo({
@@ -482,7 +488,10 @@ foo({
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_coerce,
     r#"
 var obj = {
@@ -494,7 +503,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_method,
     r#"
 var obj = {
@@ -510,7 +522,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_mixed,
     r#"
 var obj = {
@@ -524,7 +539,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_multiple,
     r#"
 var obj = {
@@ -536,7 +554,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_single,
     r#"
 var obj = {
@@ -547,7 +568,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_this,
     r#"
 var obj = {
@@ -558,7 +582,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_two,
     r#"
 var obj = {
@@ -570,7 +597,10 @@ var obj = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_variable,
     r#"
 var foo = {
@@ -581,7 +611,10 @@ var foo = {
 
 test!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_str_lit,
     r#"
 var foo = {
@@ -592,7 +625,10 @@ var foo = {
 
 test_exec!(
     syntax(),
-    |_| computed_properties(Config { loose: true }),
+    |_| computed_properties(Config {
+        loose: true,
+        ..Default::default()
+    }),
     loose_symbol,
     r#"
 var k = Symbol();
@@ -22,7 +22,10 @@ fn syntax() -> Syntax {
 fn tr() -> impl Pass {
     (
         resolver(Mark::new(), Mark::new(), false),
-        destructuring(Config { loose: true }),
+        destructuring(Config {
+            loose: true,
+            ..Default::default()
+        }),
     )
 }
 
@@ -42,21 +45,30 @@ test!(
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: false }),
+    |_| destructuring(Config {
+        loose: false,
+        ..Default::default()
+    }),
     need_to_array,
     r#"const [x, y, ...z] = o;"#
 );
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     need_to_array_loose,
     r#"const [x, y, ...z] = o;"#
 );
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: false }),
+    |_| destructuring(Config {
+        loose: false,
+        ..Default::default()
+    }),
     issue_2841,
     r#"function foo(a,b)
     {
@@ -66,7 +78,10 @@ test!(
 
 test!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     issue_2841_loose,
     r#"function foo(a,b)
     {
@@ -294,7 +309,10 @@ test!(
 
 test_exec!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     chained,
     r#"var a, b, c, d;
 ({ a, b } = ({ c, d } = { a: 1, b: 2, c: 3, d: 4}));
@@ -402,7 +420,10 @@ test!(
 test_exec!(
     ignore,
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     fn_key_with_obj_rest_spread,
     r#"const { [(() => 1)()]: a, ...rest } = { 1: "a" };
 
@@ -422,7 +443,10 @@ for (let i = 0, { length } = list; i < length; i++) {
 
 test_exec!(
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     babel_issue_5090,
     r#"const assign = function([...arr], index, value) {
   arr[index] = value;
@@ -492,7 +516,10 @@ var { x, y } = coords,
 test_exec!(
     ignore,
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     number_key_with_object_spread,
     r#"const foo = {
   1: "a"
@@ -509,7 +536,10 @@ expect(rest).toEqual({ 2: "b" 3: "c" });"#
 test_exec!(
     ignore,
     syntax(),
-    |_| destructuring(Config { loose: true }),
+    |_| destructuring(Config {
+        loose: true,
+        ..Default::default()
+    }),
     spread_generator,
     r#"function* f() {
   for (var i = 0; i < 3; i++) {
@@ -1242,7 +1272,10 @@ test!(
 test!(
     syntax(),
     |_| (
-        destructuring(Config { loose: true }),
+        destructuring(Config {
+            loose: true,
+            ..Default::default()
+        }),
         spread(Default::default()),
         block_scoping(Mark::new()),
         object_rest_spread(Default::default())
@@ -1383,7 +1416,10 @@ test!(
     syntax(),
     |_| (
         spread(Default::default()),
-        destructuring(Config { loose: true }),
+        destructuring(Config {
+            loose: true,
+            ..Default::default()
+        }),
         block_scoping(Mark::new()),
         object_rest_spread(Default::default()),
     ),
@@ -23,7 +23,7 @@ fn tr(c: Config) -> impl Pass {
     (
         resolver(unresolved_mark, top_level_mark, false),
         parameters(c, unresolved_mark),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config::default()),
         block_scoping(unresolved_mark),
     )
 }
@@ -480,7 +480,7 @@ test!(
             arrow(unresolved_mark),
             resolver(unresolved_mark, top_level_mark, false),
             parameters(Default::default(), unresolved_mark),
-            destructuring(destructuring::Config { loose: false }),
+            destructuring(destructuring::Config::default()),
             block_scoping(unresolved_mark),
         )
     },
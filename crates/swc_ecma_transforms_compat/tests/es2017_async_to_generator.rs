@@ -47,7 +47,7 @@ fn tr() -> impl Pass {
         fold_pass(ParenRemover),
         arrow(unresolved_mark),
         parameters(Default::default(), unresolved_mark),
-        destructuring(destructuring::Config { loose: false }),
+        destructuring(destructuring::Config::default()),
         function_name(),
         async_to_generator(Default::default(), unresolved_mark),
         fixer(None),
@@ -928,7 +928,7 @@ test!(
             resolver(unresolved_mark, top_level_mark, false),
             async_to_generator(Default::default(), unresolved_mark),
             parameters(Default::default(), unresolved_mark),
-            destructuring(destructuring::Config { loose: false }),
+            destructuring(destructuring::Config::default()),
         )
     },
     regression_4943,
@@ -2103,6 +2103,78 @@ class Foo extends Bar {
 "#
 );
 
+fn spec() -> impl Pass {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    (
+        resolver(unresolved_mark, top_level_mark, false),
+        async_to_generator(
+            async_to_generator::Config {
+                spec: true,
+                ..Default::default()
+            },
+            unresolved_mark,
+        ),
+    )
+}
+
+test!(
+    syntax(),
+    |_| spec(),
+    spec_complex_params,
+    r#"
+async function inject($http, [first], $q = load(), ...rest) {
+    await $http.get(first, $q, rest);
+}
+
+const handler = async function (req, res, { user }) {
+    await res.send(user);
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| spec(),
+    spec_async_generator,
+    r#"
+async function* gen(iterable) {
+    try {
+        for await (const value of iterable) {
+            yield value;
+        }
+        return getResult();
+    } finally {
+        await cleanup();
+    }
+}
+"#
+);
+
+test_exec!(
+    syntax(),
+    |_| spec(),
+    spec_length_and_name_exec,
+    r#"
+async function inject($http, $q = load(), $timeout) {}
+const handler = async function (req, res, { user }) {};
+const arrow = async (a, b) => {};
+const obj = { async method(a, [b] = []) {} };
+
+expect(inject.length).toBe(1);
+expect(inject.name).toBe("inject");
+expect(inject.toString()).toContain("$http");
+expect(handler.length).toBe(3);
+expect(handler.name).toBe("handler");
+expect(handler.toString()).toContain("req, res");
+expect(arrow.length).toBe(2);
+expect(arrow.name).toBe("arrow");
+expect(obj.method.length).toBe(1);
+expect(obj.method.name).toBe("method");
+"#
+);
+
 #[testing::fixture("tests/async-to-generator/**/exec.js")]
 fn exec(input: PathBuf) {
     let input = read_to_string(input).unwrap();
@@ -2143,3 +2215,9 @@ fn exec_regenerator(input: PathBuf) {
         &input,
     );
 }
+
+#[testing::fixture("tests/async-generator/**/exec.js")]
+fn exec_async_generator(input: PathBuf) {
+    let input = read_to_string(input).unwrap();
+    compare_stdout(Default::default(), |_| spec(), &input);
+}
@@ -17,6 +17,7 @@ use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith
 
 pub use super::util::Config as InnerConfig;
 use crate::{
+    import_meta::ImportMeta,
     module_decl_strip::{Export, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip},
     module_ref_rewriter::{rewrite_import_bindings, ImportMap},
     path::Resolver,
@@ -52,6 +53,7 @@ where
 
     visit_mut_pass(Amd {
         module_id,
+        import_meta: ImportMeta::new(&config.import_meta, unresolved_mark, "AMD"),
         config,
         unresolved_mark,
         resolver,
@@ -81,6 +83,7 @@ where
 {
     module_id: Option<String>,
     config: InnerConfig,
+    import_meta: ImportMeta,
     unresolved_mark: Mark,
     resolver: Resolver,
     comments: Option<C>,
@@ -127,8 +130,6 @@ where
             top_level_this(&mut n.body, *Expr::undefined(DUMMY_SP));
         }
 
-        let import_interop = self.config.import_interop();
-
         let mut strip = ModuleDeclStrip::new(self.const_var_kind);
         n.body.visit_mut_with(&mut strip);
 
@@ -142,7 +143,7 @@ where
 
         let is_export_assign = export_assign.is_some();
 
-        if has_module_decl && !import_interop.is_none() && !is_export_assign {
+        if has_module_decl && self.config.es_module_flag() && !is_export_assign {
             stmts.push(define_es_module(self.exports()))
         }
 
@@ -293,10 +294,18 @@ where
                     MemberProp::Ident(IdentName { sym, .. }) => &**sym,
                     MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
                         Expr::Lit(Lit::Str(s)) => &s.value,
-                        _ => return,
+                        _ => return self.import_meta.unsupported(*span, None),
                     },
-                    MemberProp::PrivateName(..) => return,
+                    MemberProp::PrivateName(..) => {
+                        return self.import_meta.unsupported(*span, None)
+                    }
                 };
+
+                if let Some(replacement) = self.import_meta.replacement(p) {
+                    *n = *replacement;
+                    return;
+                }
+
                 self.found_import_meta = true;
 
                 match p {
@@ -350,9 +359,13 @@ where
 
                         *n = n.take().as_call(n.span(), vec![quote_str!(".").as_arg()]);
                     }
-                    _ => {}
+                    _ => self.import_meta.unsupported(*span, Some(p)),
                 }
             }
+            Expr::MetaProp(MetaPropExpr {
+                span,
+                kind: MetaPropKind::ImportMeta,
+            }) if !self.config.preserve_import_meta => self.import_meta.unsupported(*span, None),
             _ => n.visit_mut_children_with(self),
         }
     }
@@ -11,6 +11,7 @@ use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith
 
 pub use super::util::Config;
 use crate::{
+    import_meta::ImportMeta,
     module_decl_strip::{
         Export, ExportKV, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip,
     },
@@ -30,6 +31,7 @@ pub fn common_js(
     available_features: FeatureFlag,
 ) -> impl Pass {
     visit_mut_pass(Cjs {
+        import_meta: ImportMeta::new(&config.import_meta, unresolved_mark, "CommonJS"),
         config,
         resolver,
         unresolved_mark,
@@ -45,6 +47,7 @@ pub fn common_js(
 
 pub struct Cjs {
     config: Config,
+    import_meta: ImportMeta,
     resolver: Resolver,
     unresolved_mark: Mark,
     available_features: FeatureFlag,
@@ -76,8 +79,6 @@ impl VisitMut for Cjs {
             top_level_this(&mut n.body, *Expr::undefined(DUMMY_SP));
         }
 
-        let import_interop = self.config.import_interop();
-
         let mut module_map = Default::default();
 
         let mut has_ts_import_equals = false;
@@ -108,7 +109,7 @@ impl VisitMut for Cjs {
 
         let is_export_assign = export_assign.is_some();
 
-        if has_module_decl && !import_interop.is_none() && !is_export_assign {
+        if has_module_decl && self.config.es_module_flag() && !is_export_assign {
             stmts.push(define_es_module(self.exports()).into())
         }
 
@@ -212,11 +213,18 @@ impl VisitMut for Cjs {
                     MemberProp::Ident(IdentName { sym, .. }) => &**sym,
                     MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
                         Expr::Lit(Lit::Str(s)) => &s.value,
-                        _ => return,
+                        _ => return self.import_meta.unsupported(*span, None),
                     },
-                    MemberProp::PrivateName(..) => return,
+                    MemberProp::PrivateName(..) => {
+                        return self.import_meta.unsupported(*span, None)
+                    }
                 };
 
+                if let Some(replacement) = self.import_meta.replacement(p) {
+                    *n = *replacement;
+                    return;
+                }
+
                 match p {
                     "url" => {
                         let require = quote_ident!(
@@ -250,9 +258,13 @@ impl VisitMut for Cjs {
                         )
                         .into();
                     }
-                    _ => {}
+                    _ => self.import_meta.unsupported(*span, Some(p)),
                 }
             }
+            Expr::MetaProp(MetaPropExpr {
+                span,
+                kind: MetaPropKind::ImportMeta,
+            }) if !self.config.preserve_import_meta => self.import_meta.unsupported(*span, None),
             _ => n.visit_mut_children_with(self),
         }
     }
@@ -270,6 +282,7 @@ impl Cjs {
         let import_interop = self.config.import_interop();
         let export_interop_annotation = self.config.export_interop_annotation();
         let is_node = import_interop.is_node();
+        let reexport_getters = self.config.reexport_getters();
 
         let mut stmts = Vec::with_capacity(link.len());
 
@@ -293,9 +306,15 @@ impl Cjs {
 
                 let mut decl_mod_ident = false;
 
+                let mut reexports = Vec::new();
+
                 link_specifier_set.reduce(
                     import_map,
-                    &mut export_obj_prop_list,
+                    if reexport_getters {
+                        &mut export_obj_prop_list
+                    } else {
+                        &mut reexports
+                    },
                     &mod_ident,
                     &None,
                     &mut decl_mod_ident,
@@ -354,6 +373,20 @@ impl Cjs {
                 } else {
                     stmts.push(import_expr.into_stmt());
                 }
+
+                // exports.foo = _mod.foo;
+                reexports.sort_by_cached_key(|(key, ..)| key.clone());
+                stmts.extend(reexports.into_iter().map(|(export_name, export_item)| {
+                    let export_binding = MemberExpr {
+                        obj: Box::new(self.exports().into()),
+                        span: export_item.export_name_span().0,
+                        prop: prop_name(&export_name, Default::default()).into(),
+                    };
+
+                    Expr::from(export_item.into_local_ident())
+                        .make_assign_to(op!("="), export_binding.into())
+                        .into_stmt()
+                }));
             },
         );
 
@@ -0,0 +1,74 @@
+use swc_common::Mark;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::{
+    feature::FeatureFlag,
+    helpers::{inject_helpers, Helpers, HELPERS},
+};
+
+use crate::{
+    common_js::common_js, import_analysis::import_analyzer, path::Resolver,
+    rewriter::import_rewriter, util::Config,
+};
+
+/// Programs emitted by [dual].
+pub struct DualOutput {
+    /// ES module, with imports resolved by the ES module resolver.
+    pub esm: Program,
+    /// CommonJS module, with imports resolved by the CommonJS resolver.
+    pub cjs: Program,
+}
+
+/// Emits an ES module and a CommonJS module from the same program, for
+/// packages publishing both formats.
+///
+/// Passes applied to `program` before this one run once for both outputs.
+/// As import paths depend on the output format, each output has its own
+/// resolver, like one built with `out_file_extension` set to `mjs` and
+/// another with `cjs`.
+///
+/// Helpers required by previous passes must be injected before calling this,
+/// as `swc` does before module transforms. Helpers required by the CommonJS
+/// transform are injected into the CommonJS module only.
+///
+/// Unless configured otherwise, the CommonJS module is annotated for
+/// `cjs-module-lexer`, so ES modules importing it from Node.js see its named
+/// exports.
+///
+/// Scripts are returned unchanged in both outputs.
+pub fn dual(
+    program: Program,
+    esm_resolver: Resolver,
+    cjs_resolver: Resolver,
+    unresolved_mark: Mark,
+    mut config: Config,
+    available_features: FeatureFlag,
+) -> DualOutput {
+    if program.is_script() {
+        return DualOutput {
+            esm: program.clone(),
+            cjs: program,
+        };
+    }
+
+    config.export_interop_annotation.get_or_insert(true);
+
+    let import_interop = config.import_interop();
+    let ignore_dynamic = config.ignore_dynamic;
+
+    // Keep interop helpers out of the ES module.
+    let helpers = Helpers::new(HELPERS.with(|helpers| helpers.external()));
+    let cjs = HELPERS.set(&helpers, || {
+        program.clone().apply((
+            import_analyzer(import_interop, ignore_dynamic),
+            inject_helpers(unresolved_mark),
+            common_js(cjs_resolver, unresolved_mark, config, available_features),
+        ))
+    });
+
+    let esm = match esm_resolver {
+        Resolver::Real { base, resolver } => program.apply(import_rewriter(base, resolver)),
+        Resolver::Default => program,
+    };
+
+    DualOutput { esm, cjs }
+}
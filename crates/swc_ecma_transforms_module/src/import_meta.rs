@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use swc_common::{errors::HANDLER, FileName, Mark, SourceMap, Span, SyntaxContext};
+use swc_ecma_ast::*;
+use swc_ecma_parser::{parse_file_as_expr, Syntax};
+use swc_ecma_utils::drop_span;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+/// Configures how `import.meta` is lowered by the CommonJS, AMD and UMD
+/// transforms.
+///
+/// Ignored if `preserve_import_meta` is enabled.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+pub struct ImportMetaConfig {
+    /// Expressions replacing properties of `import.meta`, like
+    /// `{ "env": "process.env" }`.
+    ///
+    /// They take precedence over the lowering of the module format, which
+    /// supports `url`, `resolve`, `filename` and `dirname` for CommonJS and
+    /// AMD.
+    #[serde(default)]
+    pub replacements: HashMap<String, String>,
+
+    /// What to do with uses of `import.meta` which can't be lowered.
+    #[serde(default)]
+    pub unsupported: UnsupportedImportMeta,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UnsupportedImportMeta {
+    /// Keep them as is.
+    #[default]
+    Preserve,
+    /// Report an error.
+    Error,
+}
+
+/// [ImportMetaConfig] with parsed replacements.
+#[derive(Clone)]
+pub(crate) struct ImportMeta {
+    replacements: HashMap<String, Box<Expr>>,
+    unsupported: UnsupportedImportMeta,
+    module_format: &'static str,
+}
+
+impl ImportMeta {
+    pub fn new(
+        config: &ImportMetaConfig,
+        unresolved_mark: Mark,
+        module_format: &'static str,
+    ) -> Self {
+        let unresolved_ctxt = SyntaxContext::empty().apply_mark(unresolved_mark);
+        let cm = SourceMap::default();
+
+        let replacements = config
+            .replacements
+            .iter()
+            .map(|(prop, src)| {
+                let fm = cm.new_source_file(
+                    FileName::Internal(format!("<import-meta-{}.js>", prop)).into(),
+                    src.clone(),
+                );
+
+                let mut expr = parse_file_as_expr(
+                    &fm,
+                    Syntax::default(),
+                    Default::default(),
+                    None,
+                    &mut Vec::new(),
+                )
+                .map_err(|e| {
+                    if HANDLER.is_set() {
+                        HANDLER.with(|h| e.into_diagnostic(h).emit())
+                    }
+                })
+                .unwrap();
+
+                expr.visit_mut_with(&mut Unresolved(unresolved_ctxt));
+
+                (prop.clone(), drop_span(expr))
+            })
+            .collect();
+
+        Self {
+            replacements,
+            unsupported: config.unsupported,
+            module_format,
+        }
+    }
+
+    /// Returns the replacement of `import.meta.{prop}`.
+    pub fn replacement(&self, prop: &str) -> Option<Box<Expr>> {
+        self.replacements.get(prop).cloned()
+    }
+
+    /// Handles a use of `import.meta` which can't be lowered.
+    ///
+    /// `prop` is `None` for `import.meta` itself and computed properties.
+    pub fn unsupported(&self, span: Span, prop: Option<&str>) {
+        if self.unsupported == UnsupportedImportMeta::Preserve {
+            return;
+        }
+
+        let name = match prop {
+            Some(prop) => format!("`import.meta.{}`", prop),
+            None => "`import.meta`".into(),
+        };
+
+        HANDLER.with(|handler| {
+            handler
+                .struct_span_err(
+                    span,
+                    &format!(
+                        "{} is not supported when emitting {} modules",
+                        name, self.module_format
+                    ),
+                )
+                .note("A replacement can be configured with `importMeta.replacements`")
+                .emit()
+        });
+    }
+}
+
+/// Makes identifiers of replacements refer to globals.
+struct Unresolved(SyntaxContext);
+
+impl VisitMut for Unresolved {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_ident(&mut self, n: &mut Ident) {
+        n.ctxt = self.0;
+    }
+}
@@ -7,18 +7,24 @@ use serde::{Deserialize, Serialize};
 use swc_common::{Span, SyntaxContext};
 use util::Config;
 
-pub use self::{amd::amd, common_js::common_js, system_js::system_js, umd::umd};
+pub use self::{
+    amd::amd, common_js::common_js, dual::dual, system_js::system_js,
+    top_level_await::top_level_await, umd::umd,
+};
 
 #[macro_use]
 pub mod util;
 pub mod amd;
 pub mod common_js;
+pub mod dual;
 pub mod import_analysis;
+pub mod import_meta;
 pub(crate) mod module_decl_strip;
 pub(crate) mod module_ref_rewriter;
 pub mod path;
 pub mod rewriter;
 pub mod system_js;
+pub mod top_level_await;
 mod top_level_this;
 pub mod umd;
 
@@ -5,7 +5,8 @@ use swc_atoms::Atom;
 use swc_common::{Mark, Span, SyntaxContext, DUMMY_SP};
 use swc_ecma_ast::*;
 use swc_ecma_utils::{
-    member_expr, private_ident, quote_ident, quote_str, var::VarCollector, ExprFactory,
+    contains_top_level_await, member_expr, private_ident, quote_ident, quote_str,
+    var::VarCollector, ExprFactory,
 };
 use swc_ecma_visit::{fold_pass, standard_only_fold, Fold, FoldWith, VisitWith};
 
@@ -35,10 +36,10 @@ struct SystemJs {
     export_names: Vec<Atom>,
     export_values: Vec<Box<Expr>>,
     tla: bool,
-    enter_async_fn: u32,
     root_fn_decl_idents: Vec<Ident>,
     module_item_meta_list: Vec<ModuleItemMeta>,
-    import_idents: Vec<Id>,
+    /// Sources of imported bindings.
+    import_srcs: FxHashMap<Id, Atom>,
     export_ident: Ident,
     context_ident: Ident,
 }
@@ -53,10 +54,9 @@ pub fn system_js(resolver: Resolver, unresolved_mark: Mark, config: Config) -> i
         export_names: Vec::new(),
         export_values: Vec::new(),
         tla: false,
-        enter_async_fn: 0,
         root_fn_decl_idents: Vec::new(),
         module_item_meta_list: Vec::new(),
-        import_idents: Vec::new(),
+        import_srcs: Default::default(),
         export_ident: private_ident!("_export"),
         context_ident: private_ident!("_context"),
     })
@@ -71,6 +71,16 @@ struct ModuleItemMeta {
 }
 
 impl SystemJs {
+    fn resolve(&self, src: Atom) -> Atom {
+        match &self.resolver {
+            Resolver::Real { resolver, base } => resolver
+                .resolve_import(base, &src)
+                .with_context(|| format!("failed to resolve import `{}`", src))
+                .unwrap(),
+            Resolver::Default => src,
+        }
+    }
+
     fn export_call(&self, name: Atom, span: Span, expr: Expr) -> CallExpr {
         CallExpr {
             span,
@@ -573,29 +583,10 @@ impl Fold for SystemJs {
                     .into(),
                 _ => meta_prop_expr.into(),
             },
-            Expr::Await(await_expr) => {
-                if self.enter_async_fn == 0 {
-                    self.tla = true;
-                }
-
-                await_expr.into()
-            }
             _ => expr,
         }
     }
 
-    fn fold_fn_decl(&mut self, fn_decl: FnDecl) -> FnDecl {
-        let is_async = fn_decl.function.is_async;
-        if is_async {
-            self.enter_async_fn += 1;
-        }
-        let fold_fn_expr = fn_decl.fold_children_with(self);
-        if is_async {
-            self.enter_async_fn -= 1;
-        }
-        fold_fn_expr
-    }
-
     fn fold_prop(&mut self, prop: Prop) -> Prop {
         let prop = prop.fold_children_with(self);
 
@@ -626,10 +617,35 @@ impl Fold for SystemJs {
         let mut before_body_stmts: Vec<Stmt> = Vec::new();
         let mut execute_stmts = Vec::new();
 
-        // collect top level fn decl
+        // `execute` is async if the module uses top-level await
+        self.tla = contains_top_level_await(&module);
+
+        // collect top level fn decl and imported bindings
         for item in &module.body {
-            if let ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) = item {
-                self.root_fn_decl_idents.push(fn_decl.ident.clone());
+            match item {
+                ModuleItem::Stmt(Stmt::Decl(Decl::Fn(FnDecl { ident, .. })))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Fn(FnDecl { ident, .. }),
+                    ..
+                }))
+                | ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl:
+                        DefaultDecl::Fn(FnExpr {
+                            ident: Some(ident), ..
+                        }),
+                    ..
+                })) => {
+                    self.root_fn_decl_idents.push(ident.clone());
+                }
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => {
+                    let src = self.resolve(import.src.value.clone());
+
+                    for specifier in &import.specifiers {
+                        self.import_srcs
+                            .insert(specifier.local().to_id(), src.clone());
+                    }
+                }
+                _ => {}
             }
         }
 
@@ -637,15 +653,7 @@ impl Fold for SystemJs {
             match item {
                 ModuleItem::ModuleDecl(decl) => match decl {
                     ModuleDecl::Import(import) => {
-                        let src = match &self.resolver {
-                            Resolver::Real { resolver, base } => resolver
-                                .resolve_import(base, &import.src.value)
-                                .with_context(|| {
-                                    format!("failed to resolve import `{}`", import.src.value)
-                                })
-                                .unwrap(),
-                            Resolver::Default => import.src.value,
-                        };
+                        let src = self.resolve(import.src.value);
 
                         let source_alias = local_name_for_src(&src);
 
@@ -654,7 +662,6 @@ impl Fold for SystemJs {
                         for specifier in import.specifiers {
                             match specifier {
                                 ImportSpecifier::Default(specifier) => {
-                                    self.import_idents.push(specifier.local.to_id());
                                     self.add_declare_var_idents(&specifier.local);
                                     setter_fn_stmts.push(
                                         AssignExpr {
@@ -693,7 +700,6 @@ impl Fold for SystemJs {
                                     );
                                 }
                                 ImportSpecifier::Namespace(specifier) => {
-                                    self.import_idents.push(specifier.local.to_id());
                                     self.add_declare_var_idents(&specifier.local);
                                     setter_fn_stmts.push(
                                         AssignExpr {
@@ -718,15 +724,7 @@ impl Fold for SystemJs {
                     }
                     ModuleDecl::ExportNamed(decl) => match decl.src {
                         Some(s) => {
-                            let src = match &self.resolver {
-                                Resolver::Real { resolver, base } => resolver
-                                    .resolve_import(base, &s.value)
-                                    .with_context(|| {
-                                        format!("failed to resolve import `{}`", s.value)
-                                    })
-                                    .unwrap(),
-                                Resolver::Default => s.value,
-                            };
+                            let src = self.resolve(s.value);
                             for specifier in decl.specifiers {
                                 let source_alias = local_name_for_src(&src);
                                 let mut export_names = Vec::new();
@@ -791,18 +789,23 @@ impl Fold for SystemJs {
                                             &specifier.orig,
                                         )));
                                     }
-                                    if self.import_idents.iter().any(|i| id == *i) {
-                                        execute_stmts.push(
-                                            self.export_call(
-                                                id.0.clone(),
-                                                DUMMY_SP,
-                                                match &specifier.exported {
-                                                    Some(m) => get_module_export_expr(m),
-                                                    None => get_module_export_expr(&specifier.orig),
-                                                },
-                                            )
-                                            .into_stmt(),
-                                        );
+                                    if let Some(src) = self.import_srcs.get(&id) {
+                                        // imported binding, exported by the setter whenever
+                                        // the dependency updates it
+                                        let src = src.clone();
+                                        self.add_module_item_meta(ModuleItemMeta {
+                                            export_names: vec![match &specifier.exported {
+                                                Some(m) => get_module_export_name(m).0,
+                                                None => id.0.clone(),
+                                            }],
+                                            export_values: vec![Box::new(get_module_export_expr(
+                                                &specifier.orig,
+                                            ))],
+                                            has_export_all: false,
+                                            src,
+                                            setter_fn_stmts: Vec::new(),
+                                        });
+                                        continue;
                                     }
                                     self.add_export_name(
                                         id,
@@ -919,7 +922,7 @@ impl Fold for SystemJs {
                             export_names: Vec::new(),
                             export_values: Vec::new(),
                             has_export_all: true,
-                            src: decl.src.value,
+                            src: self.resolve(decl.src.value),
                             setter_fn_stmts: Vec::new(),
                         });
                     }
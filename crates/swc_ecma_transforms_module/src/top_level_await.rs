@@ -0,0 +1,230 @@
+use swc_common::{util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{
+    contains_top_level_await, find_pat_ids, private_ident, ExprFactory, IsDirective,
+};
+use swc_ecma_visit::{visit_mut_pass, VisitMut};
+
+/// Moves the body of a module using top-level await into an async IIFE, for
+/// module formats and runtimes which don't support top-level await.
+///
+/// Imports, re-exports and function declarations stay at the top level.
+/// Other declarations are hoisted out of the IIFE and initialized inside it, so
+/// exports keep their live bindings.
+///
+/// Modules importing the output don't wait for the IIFE, so they may observe
+/// bindings which aren't initialized yet.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// import { load } from "./load";
+///
+/// export const config = await load();
+/// export default class App {}
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// import { load } from "./load";
+///
+/// export let config;
+/// let App;
+/// export { App as default };
+/// (async () => {
+///     config = await load();
+///     App = class App {};
+/// })();
+/// ```
+pub fn top_level_await() -> impl Pass {
+    visit_mut_pass(TopLevelAwait)
+}
+
+struct TopLevelAwait;
+
+impl VisitMut for TopLevelAwait {
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        if !contains_top_level_await(n) {
+            return;
+        }
+
+        let mut body: Vec<ModuleItem> = Vec::with_capacity(n.body.len() + 1);
+        let mut stmts: Vec<Stmt> = Vec::new();
+
+        let mut items = n.body.take().into_iter().peekable();
+
+        // Directives
+        while let Some(item) = items.next_if(|item| item.directive_continue()) {
+            body.push(item);
+        }
+
+        for item in items {
+            match item {
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { span, decl })) => {
+                    match hoist_decl(decl, &mut stmts) {
+                        Ok(decl) => body.push(ExportDecl { span, decl }.into()),
+                        Err(stmt) => stmts.push(stmt),
+                    }
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    span,
+                    decl: DefaultDecl::Class(ClassExpr { ident, class }),
+                })) => {
+                    let local = ident.clone().unwrap_or_else(|| private_ident!("_default"));
+
+                    body.push(hoisted_var(VarDeclKind::Let, vec![local.clone()]).into());
+                    body.push(export_default_as(span, local.clone()));
+                    stmts.push(assign(local.into(), ClassExpr { ident, class }.into()));
+                }
+
+                ModuleItem::ModuleDecl(ModuleDecl::ExportDefaultExpr(ExportDefaultExpr {
+                    span,
+                    expr,
+                })) => {
+                    let local = private_ident!("_default");
+
+                    body.push(hoisted_var(VarDeclKind::Let, vec![local.clone()]).into());
+                    body.push(export_default_as(span, local.clone()));
+                    stmts.push(assign(local.into(), expr));
+                }
+
+                ModuleItem::ModuleDecl(decl) => body.push(decl.into()),
+
+                ModuleItem::Stmt(Stmt::Decl(decl)) => match hoist_decl(decl, &mut stmts) {
+                    Ok(decl) => body.push(decl.into()),
+                    Err(stmt) => stmts.push(stmt),
+                },
+
+                ModuleItem::Stmt(stmt) => stmts.push(stmt),
+            }
+        }
+
+        body.push(
+            ArrowExpr {
+                is_async: true,
+                body: Box::new(BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    stmts,
+                    ..Default::default()
+                })),
+                ..Default::default()
+            }
+            .as_iife()
+            .into_stmt()
+            .into(),
+        );
+
+        n.body = body;
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {
+        // Scripts can't use top-level await.
+    }
+}
+
+/// Returns the declaration which is kept at the top level, and pushes its
+/// initialization to `stmts`.
+///
+/// Declarations which can't be hoisted are returned as `Err`, so they're
+/// moved into the IIFE.
+fn hoist_decl(decl: Decl, stmts: &mut Vec<Stmt>) -> Result<Decl, Stmt> {
+    match decl {
+        Decl::Fn(..) => Ok(decl),
+
+        Decl::Class(ClassDecl {
+            ident,
+            declare: false,
+            class,
+        }) => {
+            stmts.push(assign(
+                ident.clone().into(),
+                ClassExpr {
+                    ident: Some(ident.clone()),
+                    class,
+                }
+                .into(),
+            ));
+
+            Ok(hoisted_var(VarDeclKind::Let, vec![ident]).into())
+        }
+
+        Decl::Var(var) => {
+            let var = *var;
+            let kind = match var.kind {
+                VarDeclKind::Var => VarDeclKind::Var,
+                // Bindings are assigned in the IIFE.
+                VarDeclKind::Let | VarDeclKind::Const => VarDeclKind::Let,
+            };
+
+            // Patterns can't be declared without an initializer.
+            let names = find_pat_ids(&var.decls);
+
+            for decl in var.decls {
+                if let Some(init) = decl.init {
+                    stmts.push(assign(decl.name, init));
+                }
+            }
+
+            Ok(hoisted_var(kind, names).into())
+        }
+
+        // `using` declarations are disposed at the end of the IIFE.
+        _ => Err(decl.into()),
+    }
+}
+
+/// `let a, b;`
+fn hoisted_var(kind: VarDeclKind, names: Vec<Ident>) -> Box<VarDecl> {
+    Box::new(VarDecl {
+        kind,
+        decls: names
+            .into_iter()
+            .map(|name| VarDeclarator {
+                span: DUMMY_SP,
+                name: name.into(),
+                init: None,
+                definite: false,
+            })
+            .collect(),
+        ..Default::default()
+    })
+}
+
+/// `left = right;`
+fn assign(left: Pat, right: Box<Expr>) -> Stmt {
+    let is_pat = !left.is_ident();
+    let expr = AssignExpr {
+        span: DUMMY_SP,
+        op: op!("="),
+        left: left.try_into().unwrap(),
+        right,
+    };
+
+    if is_pat {
+        // `({ a } = b);`
+        expr.wrap_with_paren().into_stmt()
+    } else {
+        expr.into_stmt()
+    }
+}
+
+/// `export { local as default };`
+fn export_default_as(span: Span, local: Ident) -> ModuleItem {
+    NamedExport {
+        span,
+        specifiers: vec![ExportNamedSpecifier {
+            span: DUMMY_SP,
+            orig: local.into(),
+            exported: Some(Ident::new_no_ctxt("default".into(), DUMMY_SP).into()),
+            is_type_only: false,
+        }
+        .into()],
+        src: None,
+        type_only: false,
+        with: None,
+    }
+    .into()
+}
@@ -14,6 +14,7 @@ use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith
 use self::config::BuiltConfig;
 pub use self::config::Config;
 use crate::{
+    import_meta::ImportMeta,
     module_decl_strip::{Export, Link, LinkFlag, LinkItem, LinkSpecifierReducer, ModuleDeclStrip},
     module_ref_rewriter::{rewrite_import_bindings, ImportMap},
     path::Resolver,
@@ -35,6 +36,7 @@ pub fn umd(
     available_features: FeatureFlag,
 ) -> impl Pass {
     visit_mut_pass(Umd {
+        import_meta: ImportMeta::new(&config.config.import_meta, unresolved_mark, "UMD"),
         config: config.build(cm.clone()),
         unresolved_mark,
         cm,
@@ -56,6 +58,7 @@ pub struct Umd {
     cm: Lrc<SourceMap>,
     unresolved_mark: Mark,
     config: BuiltConfig,
+    import_meta: ImportMeta,
     resolver: Resolver,
 
     const_var_kind: VarDeclKind,
@@ -91,7 +94,9 @@ impl VisitMut for Umd {
             top_level_this(module_items, *Expr::undefined(DUMMY_SP));
         }
 
-        let import_interop = self.config.config.import_interop();
+        if !self.config.config.preserve_import_meta {
+            module_items.visit_mut_children_with(self);
+        }
 
         let mut strip = ModuleDeclStrip::new(self.const_var_kind);
         module_items.visit_mut_with(&mut strip);
@@ -106,7 +111,7 @@ impl VisitMut for Umd {
 
         let is_export_assign = export_assign.is_some();
 
-        if has_module_decl && !import_interop.is_none() && !is_export_assign {
+        if has_module_decl && self.config.config.es_module_flag() && !is_export_assign {
             stmts.push(define_es_module(self.exports()))
         }
 
@@ -164,6 +169,38 @@ impl VisitMut for Umd {
             .into_stmt()
             .into()]
     }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        match n {
+            Expr::Member(MemberExpr { span, obj, prop })
+                if obj
+                    .as_meta_prop()
+                    .map(|p| p.kind == MetaPropKind::ImportMeta)
+                    .unwrap_or_default() =>
+            {
+                let p = match prop {
+                    MemberProp::Ident(IdentName { sym, .. }) => &**sym,
+                    MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
+                        Expr::Lit(Lit::Str(s)) => &s.value,
+                        _ => return self.import_meta.unsupported(*span, None),
+                    },
+                    MemberProp::PrivateName(..) => {
+                        return self.import_meta.unsupported(*span, None)
+                    }
+                };
+
+                match self.import_meta.replacement(p) {
+                    Some(replacement) => *n = *replacement,
+                    None => self.import_meta.unsupported(*span, Some(p)),
+                }
+            }
+            Expr::MetaProp(MetaPropExpr {
+                span,
+                kind: MetaPropKind::ImportMeta,
+            }) => self.import_meta.unsupported(*span, None),
+            _ => n.visit_mut_children_with(self),
+        }
+    }
 }
 
 impl Umd {
@@ -10,6 +10,7 @@ use swc_ecma_utils::{
 };
 
 use crate::{
+    import_meta::ImportMetaConfig,
     module_decl_strip::{ExportItem, ExportKV},
     SpanCtx,
 };
@@ -27,6 +28,11 @@ pub struct Config {
     pub lazy: Lazy,
     #[serde(default)]
     pub import_interop: Option<ImportInterop>,
+    /// Matches the interop of another toolchain.
+    ///
+    /// `import_interop` takes precedence over the interop of the toolchain.
+    #[serde(default)]
+    pub es_module_interop: Option<EsModuleInterop>,
     /// Emits `cjs-module-lexer` annotation
     /// `cjs-module-lexer` is used in Node.js core for detecting the named
     /// exports available when importing a CJS module into ESM.
@@ -43,6 +49,8 @@ pub struct Config {
     pub ignore_dynamic: bool,
     #[serde(default)]
     pub preserve_import_meta: bool,
+    #[serde(default)]
+    pub import_meta: ImportMetaConfig,
 
     #[serde(default)]
     pub resolve_fully: bool,
@@ -65,10 +73,12 @@ impl Default for Config {
             strict_mode: default_strict_mode(),
             lazy: Lazy::default(),
             import_interop: None,
+            es_module_interop: None,
             export_interop_annotation: None,
             no_interop: false,
             ignore_dynamic: false,
             preserve_import_meta: false,
+            import_meta: Default::default(),
             resolve_fully: false,
             out_file_extension: "js".to_string(),
         }
@@ -98,17 +108,65 @@ impl From<bool> for ImportInterop {
     }
 }
 
+/// Interop of toolchains producing CommonJS.
+///
+/// | | default import | `__esModule` | re-exports |
+/// |---|---|---|---|
+/// | `babel` | `_interop_require_default` | defined | getters |
+/// | `node16` | `module.exports` | defined, with `cjs-module-lexer` annotations | getters |
+/// | `tsc-legacy` | `exports.default` | defined | assignments |
+/// | `bun` | `_interop_require_default` | defined | getters |
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Is, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EsModuleInterop {
+    /// `@babel/plugin-transform-modules-commonjs`.
+    Babel,
+    /// Node.js importing CommonJS from ES modules.
+    Node16,
+    /// `tsc` before 3.9, without `esModuleInterop`.
+    ///
+    /// Named re-exports are copied with `exports.foo = _mod.foo`, while
+    /// `export *` still uses getters.
+    TscLegacy,
+    /// Bun, which applies the rules of Babel when importing CommonJS.
+    Bun,
+}
+
+impl From<EsModuleInterop> for ImportInterop {
+    fn from(interop: EsModuleInterop) -> Self {
+        match interop {
+            EsModuleInterop::Babel | EsModuleInterop::Bun => ImportInterop::Swc,
+            EsModuleInterop::Node16 => ImportInterop::Node,
+            EsModuleInterop::TscLegacy => ImportInterop::None,
+        }
+    }
+}
+
 impl Config {
     #[inline(always)]
     pub fn import_interop(&self) -> ImportInterop {
         self.import_interop
+            .or_else(|| self.es_module_interop.map(From::from))
             .unwrap_or_else(|| self.no_interop.into())
     }
 
     #[inline(always)]
     pub fn export_interop_annotation(&self) -> bool {
         self.export_interop_annotation
-            .unwrap_or_else(|| self.import_interop == Some(ImportInterop::Node))
+            .unwrap_or_else(|| self.import_interop().is_node())
+    }
+
+    /// Whether `exports.__esModule` is defined.
+    #[inline(always)]
+    pub fn es_module_flag(&self) -> bool {
+        !self.import_interop().is_none()
+            || self.es_module_interop == Some(EsModuleInterop::TscLegacy)
+    }
+
+    /// Whether named re-exports are live bindings.
+    #[inline(always)]
+    pub fn reexport_getters(&self) -> bool {
+        self.es_module_interop != Some(EsModuleInterop::TscLegacy)
     }
 }
 
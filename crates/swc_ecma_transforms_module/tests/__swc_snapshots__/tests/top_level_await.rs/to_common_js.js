@@ -0,0 +1,27 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    db: function() {
+        return db;
+    },
+    query: function() {
+        return query;
+    }
+});
+var _db = require("./db");
+_export_star(require("./models"), exports);
+let db;
+function query(sql) {
+    return db.query(sql);
+}
+(async ()=>{
+    db = await (0, _db.connect)();
+})();
@@ -60,7 +60,7 @@ fn esm_to_cjs(input: PathBuf) {
         FixtureTestConfig {
             sourcemap: false,
             module: Some(true),
-            ..Default::default()
+            allow_error: true,
         },
     );
 }
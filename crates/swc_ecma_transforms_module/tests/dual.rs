@@ -0,0 +1,52 @@
+use std::path::PathBuf;
+
+use swc_common::Mark;
+use swc_ecma_ast::Program;
+use swc_ecma_transforms_base::{
+    feature::FeatureFlag, fixer::fixer, helpers::inject_helpers, hygiene::hygiene, resolver,
+};
+use swc_ecma_transforms_module::dual;
+use swc_ecma_transforms_testing::Tester;
+use testing::NormalizedOutput;
+
+#[testing::fixture("tests/fixture/dual/**/input.js")]
+fn fixture(input: PathBuf) {
+    let dir = input.parent().unwrap();
+    let src = std::fs::read_to_string(&input).unwrap();
+
+    let (esm, cjs) = Tester::run(|tester| {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        let program = Program::Module(tester.parse_module("input.js", &src)?).apply((
+            resolver(unresolved_mark, top_level_mark, false),
+            inject_helpers(unresolved_mark),
+        ));
+
+        let output = dual(
+            program,
+            Default::default(),
+            Default::default(),
+            unresolved_mark,
+            Default::default(),
+            FeatureFlag::all(),
+        );
+
+        let mut print = |program: Program| {
+            let program = program
+                .apply(hygiene())
+                .apply(fixer(Some(&tester.comments)));
+
+            tester.print(&program, &tester.comments.clone())
+        };
+
+        Ok((print(output.esm), print(output.cjs)))
+    });
+
+    NormalizedOutput::from(esm)
+        .compare_to_file(dir.join("output.mjs"))
+        .unwrap();
+    NormalizedOutput::from(cjs)
+        .compare_to_file(dir.join("output.cjs"))
+        .unwrap();
+}
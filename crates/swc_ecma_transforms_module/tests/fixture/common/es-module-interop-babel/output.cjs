@@ -0,0 +1,32 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    all: function() {
+        return _all;
+    },
+    baz: function() {
+        return _baz.baz;
+    },
+    local: function() {
+        return local;
+    },
+    quux: function() {
+        return _baz.qux;
+    }
+});
+const _foo = /*#__PURE__*/ _interop_require_default(require("foo"));
+const _ns = /*#__PURE__*/ _interop_require_wildcard(require("ns"));
+const _bar = require("bar");
+const _baz = require("baz");
+const _all = /*#__PURE__*/ _interop_require_wildcard(require("all"));
+_export_star(require("star"), exports);
+const local = 1;
+console.log(_foo.default, _ns, _bar.bar);
@@ -0,0 +1,44 @@
+(function(global, factory) {
+    if (typeof module === "object" && typeof module.exports === "object") factory(exports, require("foo"), require("ns"), require("bar"), require("baz"), require("all"), require("star"));
+    else if (typeof define === "function" && define.amd) define([
+        "exports",
+        "foo",
+        "ns",
+        "bar",
+        "baz",
+        "all",
+        "star"
+    ], factory);
+    else if (global = typeof globalThis !== "undefined" ? globalThis : global || self) factory(global.input = {}, global.foo, global.ns, global.bar, global.baz, global.all, global.star);
+})(this, function(exports, _foo, _ns, _bar, _baz, _all, _star) {
+    "use strict";
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    function _export(target, all) {
+        for(var name in all)Object.defineProperty(target, name, {
+            enumerable: true,
+            get: all[name]
+        });
+    }
+    _export(exports, {
+        all: function() {
+            return _all;
+        },
+        baz: function() {
+            return _baz.baz;
+        },
+        local: function() {
+            return local;
+        },
+        quux: function() {
+            return _baz.qux;
+        }
+    });
+    _foo = /*#__PURE__*/ _interop_require_default(_foo);
+    _ns = /*#__PURE__*/ _interop_require_wildcard(_ns);
+    _all = /*#__PURE__*/ _interop_require_wildcard(_all);
+    _export_star(_star, exports);
+    const local = 1;
+    console.log(_foo.default, _ns, _bar.bar);
+});
@@ -0,0 +1,9 @@
+import foo from "foo";
+import * as ns from "ns";
+import { bar } from "bar";
+export { baz, qux as quux } from "baz";
+export * as all from "all";
+export * from "star";
+export const local = 1;
+
+console.log(foo, ns, bar);
@@ -0,0 +1,39 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+0 && (module.exports = {
+    all: null,
+    baz: null,
+    local: null,
+    quux: null
+});
+function _export(target, all) {
+    for(var name in all)Object.defineProperty(target, name, {
+        enumerable: true,
+        get: all[name]
+    });
+}
+_export(exports, {
+    all: function() {
+        return _all;
+    },
+    baz: function() {
+        return _baz.baz;
+    },
+    local: function() {
+        return local;
+    },
+    quux: function() {
+        return _baz.qux;
+    }
+});
+0 && __export(require("star"));
+const _foo = require("foo");
+const _ns = /*#__PURE__*/ _interop_require_wildcard(require("ns"), true);
+const _bar = require("bar");
+const _baz = require("baz");
+const _all = /*#__PURE__*/ _interop_require_wildcard(require("all"), true);
+_export_star(require("star"), exports);
+const local = 1;
+console.log(_foo, _ns, _bar.bar);
@@ -0,0 +1,38 @@
+define([
+    "require",
+    "exports",
+    "foo",
+    "ns",
+    "bar",
+    "baz",
+    "all",
+    "star"
+], function(require, exports, _foo, _ns, _bar, _baz, _all, _star) {
+    "use strict";
+    Object.defineProperty(exports, "__esModule", {
+        value: true
+    });
+    function _export(target, all) {
+        for(var name in all)Object.defineProperty(target, name, {
+            enumerable: true,
+            get: all[name]
+        });
+    }
+    _export(exports, {
+        all: function() {
+            return _all;
+        },
+        baz: function() {
+            return _baz.baz;
+        },
+        local: function() {
+            return local;
+        },
+        quux: function() {
+            return _baz.qux;
+        }
+    });
+    _export_star(_star, exports);
+    const local = 1;
+    console.log(_foo.default, _ns, _bar.bar);
+});
@@ -0,0 +1,21 @@
+"use strict";
+Object.defineProperty(exports, "__esModule", {
+    value: true
+});
+Object.defineProperty(exports, "local", {
+    enumerable: true,
+    get: function() {
+        return local;
+    }
+});
+const _foo = require("foo");
+const _ns = require("ns");
+const _bar = require("bar");
+const _baz = require("baz");
+exports.baz = _baz.baz;
+exports.quux = _baz.qux;
+const _all = require("all");
+exports.all = _all;
+_export_star(require("star"), exports);
+const local = 1;
+console.log(_foo.default, _ns, _bar.bar);
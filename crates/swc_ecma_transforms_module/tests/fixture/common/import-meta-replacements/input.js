@@ -0,0 +1,7 @@
+const url = import.meta.url;
+const env = import.meta.env.NODE_ENV;
+const hot = import.meta["hot"];
+const react = import.meta.resolve("react");
+const custom = import.meta.custom;
+
+console.log(url, env, hot, react, custom);
@@ -0,0 +1,11 @@
+define([
+    "require"
+], function(require) {
+    "use strict";
+    const url = location.href;
+    const env = process.env.NODE_ENV;
+    const hot = module.hot;
+    const react = require.toUrl("react");
+    const custom = import.meta.custom;
+    console.log(url, env, hot, react, custom);
+});
@@ -0,0 +1,7 @@
+"use strict";
+const url = location.href;
+const env = process.env.NODE_ENV;
+const hot = module.hot;
+const react = require.resolve("react");
+const custom = import.meta.custom;
+console.log(url, env, hot, react, custom);
@@ -0,0 +1,13 @@
+(function(global, factory) {
+    if (typeof module === "object" && typeof module.exports === "object") factory();
+    else if (typeof define === "function" && define.amd) define([], factory);
+    else if (global = typeof globalThis !== "undefined" ? globalThis : global || self) factory();
+})(this, function() {
+    "use strict";
+    const url = location.href;
+    const env = process.env.NODE_ENV;
+    const hot = module.hot;
+    const react = import.meta.resolve("react");
+    const custom = import.meta.custom;
+    console.log(url, env, hot, react, custom);
+});
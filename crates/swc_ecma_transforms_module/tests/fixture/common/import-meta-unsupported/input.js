@@ -0,0 +1,5 @@
+const url = import.meta.url;
+const hot = import.meta.hot;
+const value = import.meta[key];
+
+console.log(url, hot, value, import.meta);
@@ -0,0 +1,10 @@
+define([
+    "require",
+    "module"
+], function(require, module) {
+    "use strict";
+    const url = new URL(module.uri, document.baseURI).href;
+    const hot = import.meta.hot;
+    const value = import.meta[key];
+    console.log(url, hot, value, import.meta);
+});
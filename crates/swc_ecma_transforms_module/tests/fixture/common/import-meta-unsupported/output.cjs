@@ -0,0 +1,5 @@
+"use strict";
+const url = require("url").pathToFileURL(__filename).toString();
+const hot = import.meta.hot;
+const value = import.meta[key];
+console.log(url, hot, value, import.meta);
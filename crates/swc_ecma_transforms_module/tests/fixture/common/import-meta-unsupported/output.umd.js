@@ -0,0 +1,11 @@
+(function(global, factory) {
+    if (typeof module === "object" && typeof module.exports === "object") factory();
+    else if (typeof define === "function" && define.amd) define([], factory);
+    else if (global = typeof globalThis !== "undefined" ? globalThis : global || self) factory();
+})(this, function() {
+    "use strict";
+    const url = import.meta.url;
+    const hot = import.meta.hot;
+    const value = import.meta[key];
+    console.log(url, hot, value, import.meta);
+});
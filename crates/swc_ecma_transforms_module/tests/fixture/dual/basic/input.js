@@ -0,0 +1,8 @@
+import { readFile } from "fs";
+import * as path from "path";
+
+export const name = path.basename("a/b");
+export function read(file) {
+    return readFile(file);
+}
+export { join } from "path";
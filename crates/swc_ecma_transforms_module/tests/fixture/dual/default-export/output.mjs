@@ -0,0 +1,4 @@
+import config from "./config";
+export default class Loader {
+    config = config;
+}
@@ -0,0 +1,4 @@
+import { x } from "a";
+import y from "b";
+import * as ns from "c";
+export { x, y as z, ns };
@@ -0,0 +1,25 @@
+System.register([
+    "a",
+    "b",
+    "c"
+], function(_export, _context) {
+    "use strict";
+    var x, y, ns;
+    return {
+        setters: [
+            function(_a) {
+                x = _a.x;
+                _export("x", x);
+            },
+            function(_b) {
+                y = _b.default;
+                _export("z", y);
+            },
+            function(_c) {
+                ns = _c;
+                _export("ns", ns);
+            }
+        ],
+        execute: function() {}
+    };
+});
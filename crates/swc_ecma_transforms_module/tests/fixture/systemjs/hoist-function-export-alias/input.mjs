@@ -0,0 +1,6 @@
+export function f() {}
+export { f as g };
+export default function foo() {}
+export { foo as bar };
+function* gen() {}
+export { gen };
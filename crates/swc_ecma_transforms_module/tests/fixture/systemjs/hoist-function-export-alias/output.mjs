@@ -0,0 +1,17 @@
+System.register([], function(_export, _context) {
+    "use strict";
+    function f() {}
+    function foo() {}
+    function* gen() {}
+    _export({
+        f: f,
+        g: f,
+        "default": foo,
+        bar: foo,
+        gen: gen
+    });
+    return {
+        setters: [],
+        execute: function() {}
+    };
+});
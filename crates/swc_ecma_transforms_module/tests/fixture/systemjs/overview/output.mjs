@@ -12,12 +12,12 @@ System.register([
                 foo2 = _foo;
                 bar = _foo.bar;
                 bar2 = _foo.foo;
+                _export("foo", foo);
             },
             function(_foobar) {},
             function(_foobar) {}
         ],
         execute: function() {
-            _export("foo", foo);
             _export("test2", test2 = 5);
             _export("default", foo);
         }
@@ -0,0 +1 @@
+for await (const x of y) {}
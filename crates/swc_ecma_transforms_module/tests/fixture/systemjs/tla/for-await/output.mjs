@@ -0,0 +1,9 @@
+System.register([], function(_export, _context) {
+    "use strict";
+    return {
+        setters: [],
+        execute: async function() {
+            for await (const x of y){}
+        }
+    };
+});
@@ -0,0 +1,3 @@
+const f = async () => { await 1; };
+const o = { async m() { await 2; } };
+class A { async m() { await 3; } }
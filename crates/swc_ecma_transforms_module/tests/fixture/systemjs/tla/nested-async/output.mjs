@@ -0,0 +1,22 @@
+System.register([], function(_export, _context) {
+    "use strict";
+    var A, f, o;
+    return {
+        setters: [],
+        execute: function() {
+            f = async ()=>{
+                await 1;
+            };
+            o = {
+                async m () {
+                    await 2;
+                }
+            };
+            A = class A {
+                async m() {
+                    await 3;
+                }
+            };
+        }
+    };
+});
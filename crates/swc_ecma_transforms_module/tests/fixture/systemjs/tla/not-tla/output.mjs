@@ -1,10 +1,9 @@
 System.register([], function(_export, _context) {
-  "use strict";
-  return {
-      setters: [],
-      execute: async function() {
-          async ()=>await test
-          ;
-      }
-  };
-});
\ No newline at end of file
+    "use strict";
+    return {
+        setters: [],
+        execute: function() {
+            async ()=>await test;
+        }
+    };
+});
@@ -0,0 +1,15 @@
+import { load } from "./load";
+
+const config = await load();
+let count = 0;
+var legacy;
+
+for await (const item of config.items) {
+    count++;
+}
+
+console.log(count, legacy, helper());
+
+function helper() {
+    return config;
+}
@@ -0,0 +1,15 @@
+import { load } from "./load";
+let config;
+let count;
+var legacy;
+function helper() {
+    return config;
+}
+(async ()=>{
+    config = await load();
+    count = 0;
+    for await (const item of config.items){
+        count++;
+    }
+    console.log(count, legacy, helper());
+})();
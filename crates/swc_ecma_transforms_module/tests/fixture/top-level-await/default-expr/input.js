@@ -0,0 +1,3 @@
+const data = await fetch("/data.json");
+
+export default await data.json();
@@ -0,0 +1,7 @@
+let data;
+let _default;
+export { _default as default };
+(async ()=>{
+    data = await fetch("/data.json");
+    _default = await data.json();
+})();
@@ -0,0 +1,2 @@
+export const { a, b: [c, d = 1], ...rest } = await import("./values");
+export let [x, , y] = await Promise.all([1, 2, 3]), z;
@@ -0,0 +1,10 @@
+export let a, c, d, rest;
+export let x, y, z;
+(async ()=>{
+    ({ a, b: [c, d = 1], ...rest } = await import("./values"));
+    [x, , y] = await Promise.all([
+        1,
+        2,
+        3
+    ]);
+})();
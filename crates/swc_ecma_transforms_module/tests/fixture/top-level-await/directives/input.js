@@ -0,0 +1,5 @@
+"use client";
+
+export default class {}
+
+await Promise.resolve();
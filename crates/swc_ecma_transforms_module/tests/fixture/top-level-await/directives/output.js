@@ -0,0 +1,8 @@
+"use client";
+let _default;
+export { _default as default };
+(async ()=>{
+    _default = class {
+    };
+    await Promise.resolve();
+})();
@@ -0,0 +1,16 @@
+"use strict";
+import { load } from "./load";
+export { foo } from "./foo";
+export * as bar from "./bar";
+
+export const config = await load();
+export class App {
+    static config = config;
+}
+export function getConfig() {
+    return config;
+}
+export default class Main extends App {}
+
+const local = 1;
+export { local, local as alias };
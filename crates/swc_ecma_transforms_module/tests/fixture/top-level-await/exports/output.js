@@ -0,0 +1,22 @@
+"use strict";
+import { load } from "./load";
+export { foo } from "./foo";
+export * as bar from "./bar";
+export let config;
+export let App;
+export function getConfig() {
+    return config;
+}
+let Main;
+export { Main as default };
+let local;
+export { local, local as alias };
+(async ()=>{
+    config = await load();
+    App = class App {
+        static config = config;
+    };
+    Main = class Main extends App {
+    };
+    local = 1;
+})();
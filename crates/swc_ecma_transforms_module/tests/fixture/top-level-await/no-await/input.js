@@ -0,0 +1,11 @@
+export const value = 1;
+
+async function load() {
+    await fetch("/");
+}
+
+export default class {
+    async method() {
+        for await (const x of y);
+    }
+}
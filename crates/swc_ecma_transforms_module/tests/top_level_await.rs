@@ -0,0 +1,68 @@
+use std::path::PathBuf;
+
+use swc_common::Mark;
+use swc_ecma_ast::Pass;
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_base::resolver;
+use swc_ecma_transforms_module::{common_js, top_level_await};
+use swc_ecma_transforms_testing::{test, test_fixture, FixtureTestConfig};
+
+fn syntax() -> Syntax {
+    Default::default()
+}
+
+fn tr() -> impl Pass {
+    let unresolved_mark = Mark::new();
+    let top_level_mark = Mark::new();
+
+    (
+        resolver(unresolved_mark, top_level_mark, false),
+        top_level_await(),
+    )
+}
+
+#[testing::fixture("tests/fixture/top-level-await/**/input.js")]
+fn fixture(input: PathBuf) {
+    let output = input.with_file_name("output.js");
+
+    test_fixture(
+        syntax(),
+        &|_| tr(),
+        &input,
+        &output,
+        FixtureTestConfig {
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+}
+
+test!(
+    module,
+    syntax(),
+    |_| {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        (
+            resolver(unresolved_mark, top_level_mark, false),
+            top_level_await(),
+            common_js(
+                Default::default(),
+                unresolved_mark,
+                Default::default(),
+                Default::default(),
+            ),
+        )
+    },
+    to_common_js,
+    r#"
+import { connect } from "./db";
+export * from "./models";
+
+export const db = await connect();
+export function query(sql) {
+    return db.query(sql);
+}
+"#
+);
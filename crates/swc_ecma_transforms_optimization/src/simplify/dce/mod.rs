@@ -13,7 +13,7 @@ use swc_ecma_ast::*;
 use swc_ecma_transforms_base::perf::{cpu_count, Parallel};
 use swc_ecma_utils::{
     collect_decls, find_pat_ids, parallel::ParallelExt, ExprCtx, ExprExt, IsEmpty, ModuleItemLike,
-    StmtLike, Value::Known,
+    StmtExt, StmtLike, Value::Known,
 };
 use swc_ecma_visit::{
     noop_visit_mut_type, noop_visit_type, visit_mut_pass, Visit, VisitMut, VisitMutWith, VisitWith,
@@ -208,6 +208,7 @@ struct VarInfo {
 struct Analyzer<'a> {
     #[allow(dead_code)]
     config: &'a Config,
+    expr_ctx: ExprCtx,
     in_var_decl: bool,
     scope: Scope<'a>,
     data: &'a mut Data,
@@ -306,6 +307,16 @@ impl Analyzer<'_> {
         }
     }
 
+    /// The parameter of the IIFE usually shadows `id`, so references to `id`
+    /// in the body are self-references.
+    fn visit_ts_iife_callee(&mut self, iife: &CallExpr, id: Id) {
+        self.with_ast_path(vec![id.clone()], |v| {
+            let old = v.cur_fn_id.replace(id);
+            iife.callee.visit_with(v);
+            v.cur_fn_id = old;
+        })
+    }
+
     /// Mark `id` as used
     fn add(&mut self, id: Id, assign: bool) {
         if id.0 == atom!("arguments") {
@@ -533,6 +544,17 @@ impl Visit for Analyzer<'_> {
         }
     }
 
+    fn visit_expr_stmt(&mut self, n: &ExprStmt) {
+        // References to the namespace object in the argument of a TypeScript
+        // `enum` / `namespace` IIFE don't keep it alive.
+        if let Some((iife, TsIifeArg::Binding(id))) = as_removable_ts_iife(&n.expr, self.expr_ctx) {
+            self.visit_ts_iife_callee(iife, id.to_id());
+            return;
+        }
+
+        n.visit_children_with(self);
+    }
+
     fn visit_var_declarator(&mut self, n: &VarDeclarator) {
         let old = self.in_var_decl;
 
@@ -540,7 +562,22 @@ impl Visit for Analyzer<'_> {
         n.name.visit_with(self);
 
         self.in_var_decl = false;
-        n.init.visit_with(self);
+        match (&n.name, n.init.as_deref()) {
+            (Pat::Ident(name), Some(init)) => match as_removable_ts_iife(init, self.expr_ctx) {
+                Some((iife, TsIifeArg::Binding(id))) if id.to_id() == name.to_id() => {
+                    self.visit_ts_iife_callee(iife, name.to_id());
+                }
+                Some((iife, TsIifeArg::Fresh)) => {
+                    self.visit_ts_iife_callee(iife, name.to_id());
+                }
+                _ => {
+                    n.init.visit_with(self);
+                }
+            },
+            _ => {
+                n.init.visit_with(self);
+            }
+        }
 
         self.in_var_decl = old;
     }
@@ -911,6 +948,7 @@ impl VisitMut for TreeShaker {
         {
             let mut analyzer = Analyzer {
                 config: &self.config,
+                expr_ctx: self.expr_ctx,
                 in_var_decl: false,
                 scope: Default::default(),
                 data: &mut data,
@@ -972,6 +1010,7 @@ impl VisitMut for TreeShaker {
         {
             let mut analyzer = Analyzer {
                 config: &self.config,
+                expr_ctx: self.expr_ctx,
                 in_var_decl: false,
                 scope: Default::default(),
                 data: &mut data,
@@ -987,6 +1026,21 @@ impl VisitMut for TreeShaker {
     }
 
     fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        if let Stmt::Expr(ExprStmt { expr, .. }) = s {
+            if let Some((_, TsIifeArg::Binding(id))) = as_removable_ts_iife(expr, self.expr_ctx) {
+                if self.bindings.contains(&id.to_id())
+                    && self.can_drop_binding(id.to_id(), true)
+                    && self.can_drop_binding(id.to_id(), false)
+                {
+                    debug!("Dropping an IIFE of `{}` as it's not used", id);
+                    self.changed = true;
+
+                    *s = EmptyStmt { span: DUMMY_SP }.into();
+                    return;
+                }
+            }
+        }
+
         s.visit_mut_children_with(self);
 
         if let Stmt::Decl(Decl::Var(v)) = s {
@@ -1079,11 +1133,20 @@ impl VisitMut for TreeShaker {
     }
 
     fn visit_mut_var_declarator(&mut self, v: &mut VarDeclarator) {
+        let is_ts_iife = match (&v.name, v.init.as_deref()) {
+            (Pat::Ident(name), Some(init)) => match as_removable_ts_iife(init, self.expr_ctx) {
+                Some((_, TsIifeArg::Binding(id))) => id.to_id() == name.to_id(),
+                Some((_, TsIifeArg::Fresh)) => true,
+                None => false,
+            },
+            _ => false,
+        };
+
         v.visit_mut_children_with(self);
 
         if let Pat::Ident(i) = &v.name {
             let can_drop = if let Some(init) = &v.init {
-                !init.may_have_side_effects(self.expr_ctx)
+                is_ts_iife || !init.may_have_side_effects(self.expr_ctx)
             } else {
                 true
             };
@@ -1127,3 +1190,184 @@ impl Scope<'_> {
         }
     }
 }
+
+/// The argument of an IIFE emitted for a TypeScript `enum` or `namespace`.
+enum TsIifeArg<'a> {
+    /// `{}`
+    Fresh,
+    /// `N || {}` or `N || (N = {})`
+    ///
+    /// A bare `N` is not matched, as it may be an alias of an object which is
+    /// used elsewhere.
+    Binding(&'a Ident),
+}
+
+/// Matches an IIFE emitted for a TypeScript `enum` or `namespace`, if the only
+/// effect of the IIFE is populating the object passed to it.
+fn as_removable_ts_iife(e: &Expr, expr_ctx: ExprCtx) -> Option<(&CallExpr, TsIifeArg)> {
+    let call = e.unwrap_parens().as_call()?;
+    if !is_ts_iife_populating_only(call, expr_ctx) {
+        return None;
+    }
+
+    let arg = match call.args[0].expr.unwrap_parens() {
+        Expr::Object(obj) if obj.props.is_empty() => TsIifeArg::Fresh,
+        Expr::Bin(BinExpr {
+            op: op!("||"),
+            left,
+            right,
+            ..
+        }) => {
+            let left = left.as_ident()?;
+
+            match right.unwrap_parens() {
+                Expr::Object(obj) if obj.props.is_empty() => {}
+                Expr::Assign(AssignExpr {
+                    op: op!("="),
+                    left: assign_left,
+                    right,
+                    ..
+                }) if assign_left.as_ident()?.to_id() == left.to_id()
+                    && matches!(&**right, Expr::Object(obj) if obj.props.is_empty()) => {}
+                _ => return None,
+            }
+
+            TsIifeArg::Binding(left)
+        }
+        _ => return None,
+    };
+
+    Some((call, arg))
+}
+
+/// Returns true if the body of `function (N) { ... }(arg)` only populates `N`.
+fn is_ts_iife_populating_only(call: &CallExpr, expr_ctx: ExprCtx) -> bool {
+    let Callee::Expr(callee) = &call.callee else {
+        return false;
+    };
+
+    let Expr::Fn(FnExpr {
+        ident: None,
+        function,
+    }) = callee.unwrap_parens()
+    else {
+        return false;
+    };
+
+    if function.is_async
+        || function.is_generator
+        || !function.decorators.is_empty()
+        || function.params.len() != 1
+        || call.args.len() != 1
+        || call.args[0].spread.is_some()
+        || !function.params[0].decorators.is_empty()
+    {
+        return false;
+    }
+
+    let (Pat::Ident(param), Some(body)) = (&function.params[0].pat, &function.body) else {
+        return false;
+    };
+
+    body.stmts
+        .iter()
+        .all(|stmt| is_ts_iife_body_stmt_removable(stmt, &param.id, expr_ctx))
+}
+
+fn is_ts_iife_body_stmt_removable(stmt: &Stmt, ns: &Ident, expr_ctx: ExprCtx) -> bool {
+    match stmt {
+        Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+            Expr::Assign(AssignExpr {
+                op: op!("="),
+                left: AssignTarget::Simple(SimpleAssignTarget::Member(left)),
+                right,
+                ..
+            }) => {
+                is_ns_member(left, ns, expr_ctx) && is_ts_iife_expr_removable(right, ns, expr_ctx)
+            }
+            // Nested `enum` / `namespace`: `(function (E) { ... })(N.E || (N.E = {}))`
+            Expr::Call(call) => {
+                is_ts_iife_populating_only(call, expr_ctx)
+                    && match call.args[0].expr.unwrap_parens() {
+                        Expr::Member(m) => is_ns_member(m, ns, expr_ctx),
+                        Expr::Bin(BinExpr {
+                            op: op!("||"),
+                            left,
+                            right,
+                            ..
+                        }) => {
+                            left.as_member()
+                                .is_some_and(|m| is_ns_member(m, ns, expr_ctx))
+                                && match right.unwrap_parens() {
+                                    Expr::Assign(AssignExpr {
+                                        op: op!("="),
+                                        left: AssignTarget::Simple(SimpleAssignTarget::Member(m)),
+                                        right,
+                                        ..
+                                    }) => {
+                                        is_ns_member(m, ns, expr_ctx)
+                                            && matches!(&**right, Expr::Object(obj) if obj.props.is_empty())
+                                    }
+                                    _ => false,
+                                }
+                        }
+                        _ => false,
+                    }
+            }
+            _ => false,
+        },
+        Stmt::Decl(Decl::Var(v)) => v.decls.iter().all(|d| {
+            d.init.as_deref().map_or(true, |init| {
+                matches!(
+                    as_removable_ts_iife(init, expr_ctx),
+                    Some((_, TsIifeArg::Fresh))
+                ) || is_ts_iife_expr_removable(init, ns, expr_ctx)
+            })
+        }),
+        // Declared in the function scope of the IIFE.
+        Stmt::Decl(Decl::Fn(..)) => true,
+        Stmt::Return(ReturnStmt { arg: Some(arg), .. }) => {
+            arg.as_ident().is_some_and(|i| i.to_id() == ns.to_id())
+        }
+        _ => !stmt.may_have_side_effects(expr_ctx),
+    }
+}
+
+/// `N.x`, `N["x"]` or `N[N["x"] = 0]`
+fn is_ns_member(m: &MemberExpr, ns: &Ident, expr_ctx: ExprCtx) -> bool {
+    if !m.obj.as_ident().is_some_and(|i| i.to_id() == ns.to_id()) {
+        return false;
+    }
+
+    match &m.prop {
+        MemberProp::Ident(..) => true,
+        MemberProp::Computed(c) => match &*c.expr {
+            Expr::Assign(AssignExpr {
+                op: op!("="),
+                left: AssignTarget::Simple(SimpleAssignTarget::Member(left)),
+                right,
+                ..
+            }) => {
+                is_ns_member(left, ns, expr_ctx) && is_ts_iife_expr_removable(right, ns, expr_ctx)
+            }
+            e => !e.may_have_side_effects(expr_ctx),
+        },
+        MemberProp::PrivateName(..) => false,
+    }
+}
+
+/// Values of `enum` members may refer to earlier members, like `N.A + 1`.
+fn is_ts_iife_expr_removable(e: &Expr, ns: &Ident, expr_ctx: ExprCtx) -> bool {
+    match e {
+        Expr::Member(m) => is_ns_member(m, ns, expr_ctx),
+        Expr::Paren(p) => is_ts_iife_expr_removable(&p.expr, ns, expr_ctx),
+        Expr::Unary(UnaryExpr { op, arg, .. }) if *op != op!("delete") => {
+            is_ts_iife_expr_removable(arg, ns, expr_ctx)
+        }
+        Expr::Bin(BinExpr { left, right, .. }) => {
+            is_ts_iife_expr_removable(left, ns, expr_ctx)
+                && is_ts_iife_expr_removable(right, ns, expr_ctx)
+        }
+        _ => !e.may_have_side_effects(expr_ctx),
+    }
+}
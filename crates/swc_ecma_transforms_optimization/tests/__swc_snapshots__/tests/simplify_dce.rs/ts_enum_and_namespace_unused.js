@@ -0,0 +1,24 @@
+var Unused = /*#__PURE__*/ function(Unused) {
+    Unused[Unused["A"] = 0] = "A";
+    return Unused;
+}(Unused || {});
+(function(Unused) {
+    Unused[Unused["B"] = 2] = "B";
+})(Unused);
+(function(UsedNs) {
+    UsedNs.y = 2;
+})(UsedNs || (UsedNs = {}));
+(function(Effect) {
+    console.log('side effect');
+})(Effect || (Effect = {}));
+var Computed = /*#__PURE__*/ function(Computed) {
+    Computed[Computed["A"] = Math.random()] = "A";
+    Computed[Computed["B"] = Computed.A << 1] = "B";
+    return Computed;
+}(Computed || {});
+(function(Exported) {
+    Exported.z = 1;
+})(Exported || (Exported = {}));
+console.log(0, UsedNs.y);
+var UsedNs, Effect;
+export var Exported;
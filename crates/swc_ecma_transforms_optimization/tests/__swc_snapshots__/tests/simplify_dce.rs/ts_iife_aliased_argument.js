@@ -0,0 +1,6 @@
+var foo = {};
+var alias = foo;
+(function(N) {
+    N.x = 1;
+})(alias);
+console.log(foo.x);
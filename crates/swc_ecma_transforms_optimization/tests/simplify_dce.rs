@@ -807,3 +807,66 @@ noop!(
     wt()
     "
 );
+
+test!(
+    Syntax::Typescript(Default::default()),
+    |_| {
+        let unresolved_mark = Mark::new();
+        let top_level_mark = Mark::new();
+
+        (
+            resolver(unresolved_mark, top_level_mark, true),
+            strip(unresolved_mark, top_level_mark),
+            tr(),
+        )
+    },
+    ts_enum_and_namespace_unused,
+    "
+    enum Unused {
+        A,
+    }
+    enum Unused {
+        B = 2,
+    }
+    enum Used {
+        C,
+    }
+    namespace UnusedNs {
+        export const x = 1;
+        export namespace Inner {
+            export enum E {
+                D,
+            }
+        }
+    }
+    namespace UnusedNs {
+        export function f() {}
+    }
+    namespace UsedNs {
+        export const y = 2;
+    }
+    namespace Effect {
+        console.log('side effect');
+    }
+    enum Computed {
+        A = Math.random(),
+        B = A << 1,
+    }
+    export namespace Exported {
+        export const z = 1;
+    }
+    console.log(Used.C, UsedNs.y);
+    "
+);
+
+to!(
+    ts_iife_aliased_argument,
+    "
+    var foo = {};
+    var alias = foo;
+    (function (N) {
+        N.x = 1;
+    })(alias);
+    console.log(foo.x);
+    "
+);
@@ -64,6 +64,17 @@ struct ClassState {
     super_class: Option<Ident>,
 }
 
+impl ClassState {
+    /// Returns `true` if the class has decorators to be applied.
+    fn has_decorators(&self) -> bool {
+        !self.init_proto_args.is_empty()
+            || !self.init_static_args.is_empty()
+            || self.init_proto.is_some()
+            || self.init_static.is_some()
+            || !self.class_decorators.is_empty()
+    }
+}
+
 impl DecoratorPass {
     fn preserve_side_effect_of_decorators(
         &mut self,
@@ -102,12 +113,7 @@ impl DecoratorPass {
 
     /// Moves `cur_inits` to `extra_stmts`.
     fn consume_inits(&mut self) {
-        if self.state.init_proto_args.is_empty()
-            && self.state.init_static_args.is_empty()
-            && self.state.init_proto.is_none()
-            && self.state.init_static.is_none()
-            && self.state.class_decorators.is_empty()
-        {
+        if !self.state.has_decorators() {
             return;
         }
 
@@ -858,6 +864,12 @@ impl VisitMut for DecoratorPass {
             }
         }
 
+        // The super class is required to inherit the metadata, even if only
+        // the members are decorated.
+        if self.state.super_class.is_none() && self.state.has_decorators() {
+            self.handle_super_class(n);
+        }
+
         self.consume_inits();
 
         if !self.state.extra_stmts.is_empty() {
@@ -875,6 +887,7 @@ impl VisitMut for DecoratorPass {
         }
 
         self.state.init_proto = None;
+        self.state.super_class = None;
 
         self.state.extra_stmts = old_stmts;
     }
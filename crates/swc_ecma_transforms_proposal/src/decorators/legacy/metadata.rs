@@ -114,9 +114,8 @@ impl Deref for EnumMap<'_> {
 }
 
 impl EnumMap<'_> {
-    fn get_kind_as_str(&self, param: Option<&TsTypeAnn>) -> Option<&'static str> {
-        param
-            .and_then(|t| t.type_ann.as_ts_type_ref())
+    fn get_kind_as_str(&self, ty: Option<&TsType>) -> Option<&'static str> {
+        ty.and_then(|t| t.as_ts_type_ref())
             .and_then(|t| t.type_name.as_ident())
             .and_then(|t| self.get(&t.sym))
             .map(|kind| match kind {
@@ -158,28 +157,13 @@ impl VisitMut for Metadata<'_> {
         {
             let dec = self.create_metadata_design_decorator(
                 "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: constructor
-                        .as_ref()
-                        .unwrap()
-                        .params
-                        .iter()
-                        .map(|v| match v {
-                            ParamOrTsParamProp::TsParamProp(p) => {
-                                let ann = match &p.param {
-                                    TsParamPropParam::Ident(i) => i.type_ann.as_deref(),
-                                    TsParamPropParam::Assign(a) => get_type_ann_of_pat(&a.left),
-                                };
-                                Some(serialize_type(self.class_name, ann).as_arg())
-                            }
-                            ParamOrTsParamProp::Param(p) => Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&p.pat))
-                                    .as_arg(),
-                            ),
-                        })
-                        .collect(),
-                }
+                self.serialize_param_types(constructor.unwrap().params.iter().map(|v| match v {
+                    ParamOrTsParamProp::TsParamProp(p) => match &p.param {
+                        TsParamPropParam::Ident(i) => Param::from(Pat::Ident(i.clone())),
+                        TsParamPropParam::Assign(a) => Param::from(Pat::Assign(a.clone())),
+                    },
+                    ParamOrTsParamProp::Param(p) => p.clone(),
+                }))
                 .as_arg(),
             );
             c.decorators.push(dec);
@@ -194,20 +178,19 @@ impl VisitMut for Metadata<'_> {
         {
             let type_arg = match m.kind {
                 MethodKind::Method => quote_ident!("Function").as_arg(),
-                MethodKind::Getter => {
-                    let return_type = m.function.return_type.as_deref();
-
-                    if let Some(kind) = self.enums.get_kind_as_str(return_type) {
-                        quote_ident!(kind).as_arg()
-                    } else {
-                        serialize_type(self.class_name, return_type).as_arg()
-                    }
-                }
-                MethodKind::Setter => serialize_type(
-                    self.class_name,
-                    get_type_ann_of_pat(&m.function.params[0].pat),
-                )
-                .as_arg(),
+                MethodKind::Getter => self
+                    .serialize(m.function.return_type.as_deref().map(|t| &*t.type_ann))
+                    .as_arg(),
+                MethodKind::Setter => self
+                    .serialize(
+                        m.function
+                            .params
+                            .iter()
+                            .find(|p| !is_this_param(p))
+                            .and_then(|p| get_type_ann_of_pat(&p.pat))
+                            .map(|t| &*t.type_ann),
+                    )
+                    .as_arg(),
             };
 
             let dec = self.create_metadata_design_decorator("design:type", type_arg);
@@ -216,21 +199,8 @@ impl VisitMut for Metadata<'_> {
         {
             let dec = self.create_metadata_design_decorator(
                 "design:paramtypes",
-                ArrayLit {
-                    span: DUMMY_SP,
-                    elems: m
-                        .function
-                        .params
-                        .iter()
-                        .map(|v| {
-                            Some(
-                                serialize_type(self.class_name, get_type_ann_of_pat(&v.pat))
-                                    .as_arg(),
-                            )
-                        })
-                        .collect(),
-                }
-                .as_arg(),
+                self.serialize_param_types(m.function.params.iter().cloned())
+                    .as_arg(),
             );
             m.function.decorators.push(dec);
         }
@@ -244,12 +214,9 @@ impl VisitMut for Metadata<'_> {
                 if m.function.is_async {
                     quote_ident!("Promise").as_arg()
                 } else {
-                    let return_type = m.function.return_type.as_deref();
-
-                    if let Some(kind) = self.enums.get_kind_as_str(return_type) {
-                        quote_ident!(kind).as_arg()
-                    } else {
-                        serialize_type(self.class_name, return_type).as_arg()
+                    match m.function.return_type.as_deref() {
+                        Some(return_type) => self.serialize(Some(&return_type.type_ann)).as_arg(),
+                        None => Expr::undefined(DUMMY_SP).as_arg(),
                     }
                 },
             );
@@ -258,19 +225,15 @@ impl VisitMut for Metadata<'_> {
     }
 
     fn visit_mut_class_prop(&mut self, p: &mut ClassProp) {
-        if p.decorators.is_empty() || p.type_ann.is_none() {
+        if p.decorators.is_empty() {
             return;
         }
 
-        let dec = self.create_metadata_design_decorator("design:type", {
-            let prop_type = p.type_ann.as_deref();
-
-            if let Some(kind) = self.enums.get_kind_as_str(prop_type) {
-                quote_ident!(kind).as_arg()
-            } else {
-                serialize_type(self.class_name, prop_type).as_arg()
-            }
-        });
+        let dec = self.create_metadata_design_decorator(
+            "design:type",
+            self.serialize(p.type_ann.as_deref().map(|t| &*t.type_ann))
+                .as_arg(),
+        );
         p.decorators.push(dec);
     }
 }
@@ -283,6 +246,37 @@ impl<'a> Metadata<'a> {
         }
     }
 
+    /// Serializes a type like tsc, using `Object` for a missing type.
+    fn serialize(&self, ty: Option<&TsType>) -> Expr {
+        match self.enums.get_kind_as_str(ty) {
+            Some(kind) => quote_ident!(kind).into(),
+            None => serialize_type(self.class_name, ty),
+        }
+    }
+
+    /// Serializes the types of parameters, skipping `this` parameters.
+    ///
+    /// The type of a rest parameter is the type of its elements.
+    fn serialize_param_types(&self, params: impl Iterator<Item = Param>) -> ArrayLit {
+        ArrayLit {
+            span: DUMMY_SP,
+            elems: params
+                .filter(|p| !is_this_param(p))
+                .map(|p| {
+                    let ty = match &p.pat {
+                        Pat::Rest(rest) => rest
+                            .type_ann
+                            .as_deref()
+                            .and_then(|t| rest_element_type(&t.type_ann)),
+                        pat => get_type_ann_of_pat(pat).map(|t| &*t.type_ann),
+                    };
+
+                    Some(self.serialize(ty).as_arg())
+                })
+                .collect(),
+        }
+    }
+
     fn create_metadata_design_decorator(&self, design: &str, type_arg: ExprOrSpread) -> Decorator {
         Decorator {
             span: DUMMY_SP,
@@ -297,7 +291,7 @@ impl<'a> Metadata<'a> {
     }
 }
 
-fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr {
+fn serialize_type(class_name: Option<&Ident>, ty: Option<&TsType>) -> Expr {
     fn check_object_existed(expr: Box<Expr>) -> Box<Expr> {
         match *expr {
             Expr::Member(ref member_expr) => {
@@ -510,10 +504,22 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             }
             .into(),
 
-            TsType::TsLitType(ty) => {
-                // TODO: Proper error reporting
-                panic!("Bad type for decoration: {:?}", ty);
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::BigInt(..),
+                ..
+            }) => CondExpr {
+                span: DUMMY_SP,
+                test: check_object_existed(quote_ident!("BigInt").into()),
+                cons: quote_ident!("Object").into(),
+                alt: quote_ident!("BigInt").into(),
             }
+            .into(),
+
+            TsType::TsLitType(..)
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsStringKeyword,
+                ..
+            }) => quote_ident!("String").into(),
 
             TsType::TsKeywordType(TsKeywordType {
                 kind: TsKeywordTypeKind::TsSymbolKeyword,
@@ -525,6 +531,11 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
             | TsType::TsIndexedAccessType(_)
             | TsType::TsTypeLit(_)
             | TsType::TsMappedType(_)
+            | TsType::TsImportType(_)
+            | TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsIntrinsicKeyword,
+                ..
+            })
             | TsType::TsKeywordType(TsKeywordType {
                 kind: TsKeywordTypeKind::TsAnyKeyword,
                 ..
@@ -550,16 +561,35 @@ fn serialize_type(class_name: Option<&Ident>, param: Option<&TsTypeAnn>) -> Expr
 
             TsType::TsTypeRef(ty) => serialize_type_ref(class_name, ty),
 
-            _ => panic!("Bad type for decorator: {:?}", ty),
+            // These only appear in tuples or conditional types.
+            TsType::TsOptionalType(_) | TsType::TsRestType(_) | TsType::TsInferType(_) => {
+                quote_ident!("Object").into()
+            }
         }
     }
 
-    let param = match param {
-        Some(v) => &v.type_ann,
-        None => return *Expr::undefined(DUMMY_SP),
-    };
+    match ty {
+        Some(ty) => serialize_type_node(class_name.map(|v| &*v.sym).unwrap_or(""), ty),
+        None => quote_ident!("Object").into(),
+    }
+}
+
+/// `T` of `T[]` or `Array<T>`.
+fn rest_element_type(ty: &TsType) -> Option<&TsType> {
+    match ty {
+        TsType::TsArrayType(ty) => Some(&ty.elem_type),
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(name),
+            type_params: Some(params),
+            ..
+        }) if name.sym == "Array" && params.params.len() == 1 => Some(&params.params[0]),
+        TsType::TsParenthesizedType(ty) => rest_element_type(&ty.type_ann),
+        _ => None,
+    }
+}
 
-    serialize_type_node(class_name.map(|v| &*v.sym).unwrap_or(""), param)
+fn is_this_param(p: &Param) -> bool {
+    matches!(&p.pat, Pat::Ident(i) if i.sym == "this")
 }
 
 fn ts_entity_to_member_expr(type_name: &TsEntityName) -> Expr {
@@ -0,0 +1,200 @@
+use swc_common::{util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{contains_arguments, contains_this_expr, quote_ident, ExprFactory};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, visit_mut_pass, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Lowers do expressions to immediately invoked functions, which return the
+/// completion value of the block.
+///
+/// Bodies which use `await` or `yield` are wrapped in async or generator
+/// functions. Statements which exit the enclosing function or loop, like
+/// `return` or `break`, only exit the generated function.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// let x = do {
+///     if (a) {
+///         1;
+///     } else {
+///         2;
+///     }
+/// };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// let x = (() => {
+///     if (a) {
+///         return 1;
+///     } else {
+///         return 2;
+///     }
+/// })();
+/// ```
+pub fn do_expressions() -> impl Pass {
+    visit_mut_pass(DoExpressions)
+}
+
+struct DoExpressions;
+
+impl VisitMut for DoExpressions {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Do(DoExpr { span, body }) = e {
+            *e = lower_do_expr(*span, body.take());
+        }
+    }
+}
+
+fn lower_do_expr(span: Span, mut body: BlockStmt) -> Expr {
+    let mut finder = AwaitYieldFinder::default();
+    body.visit_with(&mut finder);
+
+    return_completion(&mut body.stmts);
+
+    if finder.found_yield {
+        // Generators can't be arrow functions, so `this` and `arguments` are
+        // passed explicitly.
+        let uses_arguments = contains_arguments(&body);
+        let uses_this = uses_arguments || contains_this_expr(&body);
+
+        let f = FnExpr {
+            ident: None,
+            function: Box::new(Function {
+                body: Some(body),
+                is_generator: true,
+                is_async: finder.found_await,
+                ..Default::default()
+            }),
+        };
+
+        let call = if uses_arguments {
+            f.make_member(quote_ident!("apply")).as_call(
+                DUMMY_SP,
+                vec![
+                    ThisExpr { span: DUMMY_SP }.as_arg(),
+                    quote_ident!(Default::default(), "arguments").as_arg(),
+                ],
+            )
+        } else if uses_this {
+            f.make_member(quote_ident!("call"))
+                .as_call(DUMMY_SP, vec![ThisExpr { span: DUMMY_SP }.as_arg()])
+        } else {
+            f.as_iife().into()
+        };
+
+        return YieldExpr {
+            span,
+            arg: Some(Box::new(call)),
+            delegate: true,
+        }
+        .into();
+    }
+
+    let call = ArrowExpr {
+        body: Box::new(BlockStmtOrExpr::BlockStmt(body)),
+        is_async: finder.found_await,
+        ..Default::default()
+    }
+    .as_iife();
+
+    if finder.found_await {
+        AwaitExpr {
+            span,
+            arg: Box::new(call.into()),
+        }
+        .into()
+    } else {
+        CallExpr { span, ..call }.into()
+    }
+}
+
+/// Makes `stmts` return their completion value.
+///
+/// Only the statements which end the block are handled, so the completion
+/// value of loops and `switch` statements is lost.
+fn return_completion(stmts: &mut [Stmt]) {
+    // Hoisted functions don't change the completion value.
+    if let Some(last) = stmts
+        .iter_mut()
+        .rev()
+        .find(|s| !matches!(s, Stmt::Empty(..) | Stmt::Decl(Decl::Fn(..))))
+    {
+        return_completion_of(last);
+    }
+}
+
+fn return_completion_of(stmt: &mut Stmt) {
+    match stmt {
+        Stmt::Expr(ExprStmt { span, expr }) => {
+            *stmt = ReturnStmt {
+                span: *span,
+                arg: Some(expr.take()),
+            }
+            .into();
+        }
+
+        Stmt::Block(BlockStmt { stmts, .. }) => return_completion(stmts),
+
+        Stmt::If(IfStmt { cons, alt, .. }) => {
+            return_completion_of(cons);
+            if let Some(alt) = alt {
+                return_completion_of(alt);
+            }
+        }
+
+        Stmt::Try(s) => {
+            return_completion(&mut s.block.stmts);
+            if let Some(handler) = &mut s.handler {
+                return_completion(&mut handler.body.stmts);
+            }
+        }
+
+        _ => {}
+    }
+}
+
+/// Finds `await` and `yield` which belong to the function containing the do
+/// expression.
+#[derive(Default)]
+struct AwaitYieldFinder {
+    found_await: bool,
+    found_yield: bool,
+}
+
+impl Visit for AwaitYieldFinder {
+    noop_visit_type!(fail);
+
+    fn visit_arrow_expr(&mut self, f: &ArrowExpr) {
+        f.params.visit_with(self);
+    }
+
+    fn visit_await_expr(&mut self, n: &AwaitExpr) {
+        self.found_await = true;
+        n.visit_children_with(self);
+    }
+
+    fn visit_for_of_stmt(&mut self, n: &ForOfStmt) {
+        self.found_await |= n.is_await;
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, f: &Function) {
+        f.decorators.visit_with(self);
+        f.params.visit_with(self);
+    }
+
+    fn visit_yield_expr(&mut self, n: &YieldExpr) {
+        self.found_yield = true;
+        n.visit_children_with(self);
+    }
+}
@@ -4,8 +4,9 @@
 use serde::{Deserialize, Serialize};
 
 pub use self::{
-    decorators::decorators, export_default_from::export_default_from,
-    import_attributes::import_attributes,
+    decorators::decorators, do_expressions::do_expressions,
+    export_default_from::export_default_from, import_attributes::import_attributes,
+    pattern_matching::pattern_matching, pipeline_operator::pipeline_operator,
 };
 
 #[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
@@ -25,6 +26,9 @@ pub enum DecoratorVersion {
 pub mod decorator_2022_03;
 mod decorator_impl;
 pub mod decorators;
+mod do_expressions;
 pub mod explicit_resource_management;
 mod export_default_from;
 mod import_attributes;
+mod pattern_matching;
+mod pipeline_operator;
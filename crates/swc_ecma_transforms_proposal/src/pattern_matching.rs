@@ -0,0 +1,410 @@
+use std::mem::take;
+
+use rustc_hash::FxHashMap;
+use swc_common::{util::take::Take, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_transforms_base::helper;
+use swc_ecma_utils::{
+    member_expr, private_ident, prop_name_to_expr_value, quote_ident, quote_str, ExprFactory,
+    IdentRenamer, StmtLike,
+};
+use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
+
+/// Lowers the `match` expression of the pattern matching proposal to
+/// conditional expressions.
+///
+/// Bindings of a case are renamed and hoisted as `var`s, and a `match` without
+/// a `default` case throws a `TypeError` if nothing matches.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// const x = match (res) {
+///     when { status: 200, let body }: body;
+///     default: null;
+/// };
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _subject, body;
+/// const x = (_subject = res, _subject != null && _subject.status === 200
+///     && (body = _subject.body, true) ? body : null);
+/// ```
+pub fn pattern_matching() -> impl Pass {
+    visit_mut_pass(PatternMatching::default())
+}
+
+#[derive(Default)]
+struct PatternMatching {
+    vars: Vec<VarDeclarator>,
+}
+
+impl PatternMatching {
+    fn visit_mut_stmt_like<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: VisitMutWith<Self> + StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for mut stmt in stmts.take() {
+            stmt.visit_mut_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from(
+                    VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Var,
+                        decls: take(&mut self.vars),
+                        declare: false,
+                        ..Default::default()
+                    }
+                    .into(),
+                ));
+            }
+
+            buf.push(stmt);
+        }
+
+        *stmts = buf
+    }
+
+    fn declare(&mut self, id: Ident) {
+        self.vars.push(VarDeclarator {
+            span: DUMMY_SP,
+            name: id.into(),
+            init: None,
+            definite: false,
+        });
+    }
+
+    /// Stores `value` in a new variable unless it's already an identifier, as
+    /// it's used more than once.
+    ///
+    /// Returns the assignment which has to run before the value is used.
+    fn alias(&mut self, value: Box<Expr>) -> (Box<Expr>, Option<Box<Expr>>) {
+        if value.is_ident() {
+            return (value, None);
+        }
+
+        let id = private_ident!("_value");
+        self.declare(id.clone());
+
+        let init = value.make_assign_to(op!("="), id.clone().into());
+        (id.into(), Some(Box::new(init)))
+    }
+
+    fn lower_match(&mut self, n: MatchExpr) -> Expr {
+        let subject = private_ident!("_subject");
+        self.declare(subject.clone());
+
+        let mut alt: Box<Expr> = Box::new(
+            CallExpr {
+                span: DUMMY_SP,
+                callee: helper!(throw),
+                args: vec![NewExpr {
+                    span: DUMMY_SP,
+                    callee: quote_ident!(Default::default(), "TypeError").into(),
+                    args: Some(vec![quote_str!("No match").as_arg()]),
+                    ..Default::default()
+                }
+                .as_arg()],
+                ..Default::default()
+            }
+            .into(),
+        );
+
+        for case in n.cases.into_iter().rev() {
+            let MatchCase {
+                span,
+                pat,
+                mut guard,
+                mut body,
+            } = case;
+
+            let Some(pat) = pat else {
+                // Cases after `default` are unreachable.
+                alt = body;
+                continue;
+            };
+
+            let mut renames = FxHashMap::default();
+            let mut test = self.lower_pat(*pat, subject.clone().into(), &mut renames);
+
+            if !renames.is_empty() {
+                let mut renamer = IdentRenamer::new(&renames);
+                guard.visit_mut_with(&mut renamer);
+                body.visit_mut_with(&mut renamer);
+            }
+
+            if let Some(guard) = guard {
+                test = and(test, guard);
+            }
+
+            alt = CondExpr {
+                span,
+                test,
+                cons: body,
+                alt,
+            }
+            .into();
+        }
+
+        SeqExpr {
+            span: n.span,
+            exprs: vec![
+                n.subject.make_assign_to(op!("="), subject.into()).into(),
+                alt,
+            ],
+        }
+        .into()
+    }
+
+    /// Returns an expression which is truthy if `value` matches `pat`.
+    ///
+    /// `value` is evaluated at most once unless it's an identifier.
+    fn lower_pat(
+        &mut self,
+        pat: MatchPat,
+        value: Box<Expr>,
+        renames: &mut FxHashMap<Id, Id>,
+    ) -> Box<Expr> {
+        match pat {
+            MatchPat::Binding(BindingMatchPat { span, id, .. }) => {
+                let var = private_ident!(id.span, id.sym.clone());
+                renames.insert(id.to_id(), var.to_id());
+                self.declare(var.clone());
+
+                SeqExpr {
+                    span,
+                    exprs: vec![
+                        value.make_assign_to(op!("="), var.into()).into(),
+                        Lit::Bool(true.into()).into(),
+                    ],
+                }
+                .into()
+            }
+
+            MatchPat::Value(ValueMatchPat { span, value: right }) => BinExpr {
+                span,
+                op: op!("==="),
+                left: value,
+                right,
+            }
+            .into(),
+
+            MatchPat::Relational(RelationalMatchPat {
+                span,
+                op,
+                value: right,
+            }) => BinExpr {
+                span,
+                op,
+                left: value,
+                right,
+            }
+            .into(),
+
+            MatchPat::Not(NotMatchPat { span, arg }) => UnaryExpr {
+                span,
+                op: op!("!"),
+                arg: self.lower_pat(*arg, value, renames),
+            }
+            .into(),
+
+            MatchPat::Combined(CombinedMatchPat { op, pats, .. }) => {
+                let (value, init) = self.alias(value);
+                let op = match op {
+                    MatchPatCombinator::And => op!("&&"),
+                    MatchPatCombinator::Or => op!("||"),
+                };
+
+                let test = pats
+                    .into_iter()
+                    .map(|pat| self.lower_pat(pat, value.clone(), renames))
+                    .reduce(|left, right| {
+                        BinExpr {
+                            span: DUMMY_SP,
+                            op,
+                            left,
+                            right,
+                        }
+                        .into()
+                    })
+                    .expect("combined pattern without patterns");
+
+                with_init(init, test)
+            }
+
+            MatchPat::Array(ArrayMatchPat { elems, rest, .. }) => {
+                let (value, init) = self.alias(value);
+                let len = elems.len();
+
+                // Array.isArray(v) && v.length === n
+                let mut test = and(
+                    member_expr!(Default::default(), DUMMY_SP, Array.isArray)
+                        .as_call(DUMMY_SP, vec![value.clone().as_arg()])
+                        .into(),
+                    BinExpr {
+                        span: DUMMY_SP,
+                        op: if rest.is_some() {
+                            op!(">=")
+                        } else {
+                            op!("===")
+                        },
+                        left: value.clone().make_member(quote_ident!("length")).into(),
+                        right: (len as f64).into(),
+                    }
+                    .into(),
+                );
+
+                for (i, elem) in elems.into_iter().enumerate() {
+                    let elem_value = value.clone().computed_member(i as f64);
+                    test = and(test, self.lower_pat(elem, elem_value.into(), renames));
+                }
+
+                if let Some(MatchRest { arg: Some(arg), .. }) = rest {
+                    // v.slice(n)
+                    let rest_value = value
+                        .make_member(quote_ident!("slice"))
+                        .as_call(DUMMY_SP, vec![(len as f64).as_arg()]);
+                    test = and(test, self.lower_pat(*arg, rest_value.into(), renames));
+                }
+
+                with_init(init, test)
+            }
+
+            MatchPat::Object(ObjectMatchPat { props, .. }) => {
+                let (value, init) = self.alias(value);
+
+                // v != null
+                let mut test: Box<Expr> = BinExpr {
+                    span: DUMMY_SP,
+                    op: op!("!="),
+                    left: value.clone(),
+                    right: Lit::Null(Null { span: DUMMY_SP }).into(),
+                }
+                .into();
+
+                for ObjectMatchPatProp {
+                    key, value: pat, ..
+                } in props
+                {
+                    let prop_test = match pat {
+                        Some(pat) => {
+                            let prop_value = match key {
+                                PropName::Ident(key) => value.clone().make_member(key),
+                                key => value.clone().computed_member(prop_name_to_expr_value(key)),
+                            };
+                            self.lower_pat(*pat, prop_value.into(), renames)
+                        }
+
+                        // "key" in Object(v)
+                        None => BinExpr {
+                            span: DUMMY_SP,
+                            op: op!("in"),
+                            left: prop_name_to_expr_value(key).into(),
+                            right: quote_ident!(Default::default(), "Object")
+                                .as_call(DUMMY_SP, vec![value.clone().as_arg()])
+                                .into(),
+                        }
+                        .into(),
+                    };
+
+                    test = and(test, prop_test);
+                }
+
+                with_init(init, test)
+            }
+        }
+    }
+}
+
+fn and(left: Box<Expr>, right: Box<Expr>) -> Box<Expr> {
+    BinExpr {
+        span: DUMMY_SP,
+        op: op!("&&"),
+        left,
+        right,
+    }
+    .into()
+}
+
+fn with_init(init: Option<Box<Expr>>, test: Box<Expr>) -> Box<Expr> {
+    match init {
+        Some(init) => SeqExpr {
+            span: DUMMY_SP,
+            exprs: vec![init, test],
+        }
+        .into(),
+        None => test,
+    }
+}
+
+impl VisitMut for PatternMatching {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_block_stmt(&mut self, s: &mut BlockStmt) {
+        let old_vars = self.vars.take();
+        s.visit_mut_children_with(self);
+        self.vars = old_vars;
+    }
+
+    fn visit_mut_block_stmt_or_expr(&mut self, n: &mut BlockStmtOrExpr) {
+        let vars = self.vars.take();
+        n.visit_mut_children_with(self);
+
+        if !self.vars.is_empty() {
+            if let BlockStmtOrExpr::Expr(expr) = n {
+                // { var _subject; return expr; }
+                let stmts = vec![
+                    VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Var,
+                        decls: self.vars.take(),
+                        declare: false,
+                        ..Default::default()
+                    }
+                    .into(),
+                    Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(expr.take()),
+                    }),
+                ];
+                *n = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                    ..Default::default()
+                });
+            }
+        }
+
+        self.vars = vars;
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        e.visit_mut_children_with(self);
+
+        if let Expr::Match(n) = e {
+            *e = self.lower_match(n.take());
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_stmts(&mut self, n: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_switch_case(&mut self, s: &mut SwitchCase) {
+        s.test.visit_mut_with(self);
+        let old_vars = self.vars.take();
+        s.cons.visit_mut_with(self);
+        self.vars = old_vars;
+    }
+}
@@ -0,0 +1,167 @@
+use std::mem::take;
+
+use swc_common::{util::take::Take, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{private_ident, StmtLike};
+use swc_ecma_visit::{noop_visit_mut_type, visit_mut_pass, VisitMut, VisitMutWith};
+
+/// Lowers the hack-style pipeline operator.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// a |> f(%) |> g(%, %);
+/// ```
+///
+/// ## Out
+///
+/// ```js
+/// var _topic, _topic1;
+/// _topic = a, _topic1 = f(_topic), g(_topic1, _topic1);
+/// ```
+pub fn pipeline_operator() -> impl Pass {
+    visit_mut_pass(PipelineOperator::default())
+}
+
+#[derive(Default)]
+struct PipelineOperator {
+    vars: Vec<VarDeclarator>,
+    /// The variable which holds the topic of the pipeline being visited.
+    topic: Option<Ident>,
+}
+
+impl PipelineOperator {
+    fn visit_mut_stmt_like<T>(&mut self, stmts: &mut Vec<T>)
+    where
+        T: VisitMutWith<Self> + StmtLike,
+    {
+        let mut buf = Vec::with_capacity(stmts.len() + 2);
+
+        for mut stmt in stmts.take() {
+            stmt.visit_mut_with(self);
+
+            if !self.vars.is_empty() {
+                buf.push(T::from(
+                    VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Var,
+                        decls: take(&mut self.vars),
+                        declare: false,
+                        ..Default::default()
+                    }
+                    .into(),
+                ));
+            }
+
+            buf.push(stmt);
+        }
+
+        *stmts = buf
+    }
+}
+
+impl VisitMut for PipelineOperator {
+    noop_visit_mut_type!(fail);
+
+    fn visit_mut_block_stmt(&mut self, s: &mut BlockStmt) {
+        let old_vars = self.vars.take();
+        s.visit_mut_children_with(self);
+        self.vars = old_vars;
+    }
+
+    fn visit_mut_block_stmt_or_expr(&mut self, n: &mut BlockStmtOrExpr) {
+        let vars = self.vars.take();
+        n.visit_mut_children_with(self);
+
+        if !self.vars.is_empty() {
+            if let BlockStmtOrExpr::Expr(expr) = n {
+                // { var _topic; return expr; }
+                let stmts = vec![
+                    VarDecl {
+                        span: DUMMY_SP,
+                        kind: VarDeclKind::Var,
+                        decls: self.vars.take(),
+                        declare: false,
+                        ..Default::default()
+                    }
+                    .into(),
+                    Stmt::Return(ReturnStmt {
+                        span: DUMMY_SP,
+                        arg: Some(expr.take()),
+                    }),
+                ];
+                *n = BlockStmtOrExpr::BlockStmt(BlockStmt {
+                    span: DUMMY_SP,
+                    stmts,
+                    ..Default::default()
+                });
+            }
+        }
+
+        self.vars = vars;
+    }
+
+    fn visit_mut_expr(&mut self, e: &mut Expr) {
+        match e {
+            Expr::Pipeline(PipelineExpr { span, left, right }) => {
+                left.visit_mut_with(self);
+
+                let topic = private_ident!("_topic");
+                self.vars.push(VarDeclarator {
+                    span: DUMMY_SP,
+                    name: topic.clone().into(),
+                    init: None,
+                    definite: false,
+                });
+
+                let outer_topic = self.topic.replace(topic.clone());
+                right.visit_mut_with(self);
+                self.topic = outer_topic;
+
+                *e = SeqExpr {
+                    span: *span,
+                    exprs: vec![
+                        AssignExpr {
+                            span: DUMMY_SP,
+                            op: op!("="),
+                            left: topic.into(),
+                            right: left.take(),
+                        }
+                        .into(),
+                        right.take(),
+                    ],
+                }
+                .into();
+            }
+
+            Expr::Topic(TopicExpr { span }) => {
+                if let Some(topic) = &self.topic {
+                    *e = Ident {
+                        span: *span,
+                        ..topic.clone()
+                    }
+                    .into();
+                }
+            }
+
+            _ => e.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_module_items(&mut self, n: &mut Vec<ModuleItem>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_stmts(&mut self, n: &mut Vec<Stmt>) {
+        self.visit_mut_stmt_like(n)
+    }
+
+    fn visit_mut_switch_case(&mut self, s: &mut SwitchCase) {
+        s.test.visit_mut_with(self);
+        let old_vars = self.vars.take();
+        s.cons.visit_mut_with(self);
+        self.vars = old_vars;
+    }
+}
@@ -0,0 +1,6 @@
+async function f() {
+    return await (async ()=>{
+        const res = await fetch(url);
+        return res.ok;
+    })();
+}
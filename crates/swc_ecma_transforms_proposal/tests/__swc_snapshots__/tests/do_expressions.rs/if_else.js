@@ -0,0 +1,12 @@
+let x = (()=>{
+    if (a) {
+        return "a";
+    } else if (b) return "b";
+    else {
+        try {
+            return f();
+        } catch  {
+            return "c";
+        }
+    }
+})();
@@ -0,0 +1,5 @@
+let x = (()=>{
+    return f((()=>{
+        return 1;
+    })() + 1);
+})();
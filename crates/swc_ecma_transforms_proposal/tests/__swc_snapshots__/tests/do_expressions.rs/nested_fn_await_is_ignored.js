@@ -0,0 +1,5 @@
+let x = (()=>{
+    return async ()=>{
+        await 1;
+    };
+})();
@@ -0,0 +1,4 @@
+let x = (()=>{
+    let y = 1;
+    return y + 1;
+})();
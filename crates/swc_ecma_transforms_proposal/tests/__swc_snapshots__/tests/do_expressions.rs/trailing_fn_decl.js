@@ -0,0 +1,5 @@
+let x = (()=>{
+    return g();
+    function g() {}
+    ;
+})();
@@ -0,0 +1,8 @@
+function* f() {
+    const x = yield* (function*() {
+        return yield this.a;
+    }).call(this);
+    const y = yield* (function*() {
+        return yield arguments[0];
+    }).apply(this, arguments);
+}
@@ -0,0 +1,2 @@
+var _subject, _value, a, rest, first;
+const x = (_subject = list, Array.isArray(_subject) && _subject.length === 0 ? 0 : Array.isArray(_subject) && _subject.length >= 1 && (first = _subject[0], true) ? first : Array.isArray(_subject) && _subject.length >= 1 && (_value = _subject[0], Array.isArray(_value) && _value.length === 1 && (a = _value[0], true)) && (rest = _subject.slice(1), true) ? a + rest.length : _throw(new TypeError("No match")));
@@ -0,0 +1,4 @@
+const f = (v)=>{
+    var _subject, x;
+    return _subject = v, (x = _subject, true) ? x : _throw(new TypeError("No match"));
+};
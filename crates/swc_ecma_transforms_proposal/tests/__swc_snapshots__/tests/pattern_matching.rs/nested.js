@@ -0,0 +1,2 @@
+var _subject, b, _subject1, b1;
+const x = (_subject1 = a, (b1 = _subject1, true) ? (_subject = b1, (b = _subject, true) ? b : _throw(new TypeError("No match"))) : _throw(new TypeError("No match")));
@@ -0,0 +1,2 @@
+var _subject, y;
+const x = (_subject = f(), (y = _subject, true) && y > 1 ? y : _throw(new TypeError("No match")));
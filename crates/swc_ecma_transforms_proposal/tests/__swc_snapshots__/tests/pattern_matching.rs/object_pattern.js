@@ -0,0 +1,2 @@
+var _subject, _value, body;
+const x = (_subject = res, _subject != null && _subject.status === 200 && (body = _subject.body, true) && "headers" in Object(_subject) ? body : _subject != null && (_value = _subject.status, _value >= 400 && _value < 500) ? null : res);
@@ -0,0 +1,2 @@
+var _subject;
+const x = (_subject = a, _subject === 1 ? "one" : _subject < 0 ? "negative" : !(_subject === 2 || _subject === 3) ? "other" : "two or three");
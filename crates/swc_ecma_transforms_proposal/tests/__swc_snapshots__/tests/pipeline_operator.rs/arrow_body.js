@@ -0,0 +1,4 @@
+const f = (x)=>{
+    var _topic;
+    return _topic = x, _topic * 2;
+};
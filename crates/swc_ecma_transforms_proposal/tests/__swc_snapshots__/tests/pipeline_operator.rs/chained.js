@@ -0,0 +1,2 @@
+var _topic, _topic1;
+const x = (_topic = a, _topic1 = f(_topic), g(_topic1) + _topic1);
@@ -0,0 +1,4 @@
+if (a) {
+    var _topic;
+    b((_topic = a, _topic ? 1 : 2));
+}
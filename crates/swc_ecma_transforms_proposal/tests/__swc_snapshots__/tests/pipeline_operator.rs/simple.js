@@ -0,0 +1,2 @@
+var _topic;
+_topic = a, f(_topic, _topic);
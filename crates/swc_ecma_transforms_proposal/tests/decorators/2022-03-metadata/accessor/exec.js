@@ -0,0 +1,19 @@
+function dec(_, ctx) {
+  ctx.metadata[ctx.name] = ctx.kind;
+}
+
+Symbol.metadata = Symbol();
+
+class A {
+  @dec accessor a;
+  @dec static accessor b;
+  @dec accessor #c;
+  @dec static m() {}
+}
+
+expect(A[Symbol.metadata]).toEqual({
+  a: "accessor",
+  b: "accessor",
+  "#c": "accessor",
+  m: "method",
+});
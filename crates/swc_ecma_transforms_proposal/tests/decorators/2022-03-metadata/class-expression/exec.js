@@ -0,0 +1,12 @@
+function dec(v) {
+  return (_, ctx) => {
+    ctx.metadata.foo = v;
+  };
+}
+
+Symbol.metadata = Symbol();
+
+const A = @dec(1) class {};
+
+expect(A[Symbol.metadata]).toEqual({ foo: 1 });
+expect(Object.getPrototypeOf(A[Symbol.metadata])).toBe(null);
@@ -0,0 +1,14 @@
+function dec(v) {
+  return (_, ctx) => {
+    ctx.metadata.foo = v;
+  };
+}
+
+Symbol.metadata = Symbol();
+
+const B = @dec(2) class {};
+
+const A = @dec(3) class extends B {};
+
+expect(A[Symbol.metadata]).toEqual({ foo: 3 });
+expect(Object.getPrototypeOf(A[Symbol.metadata])).toBe(B[Symbol.metadata]);
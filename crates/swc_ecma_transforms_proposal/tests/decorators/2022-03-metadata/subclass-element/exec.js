@@ -0,0 +1,22 @@
+function dec(_, ctx) {
+  ctx.metadata[ctx.name] = ctx.kind;
+}
+
+Symbol.metadata = Symbol();
+
+class B {
+  @dec a;
+}
+
+class A extends B {
+  @dec b() {}
+}
+
+const C = class extends A {
+  @dec static c;
+};
+
+expect(A[Symbol.metadata]).toEqual({ b: "method" });
+expect(Object.getPrototypeOf(A[Symbol.metadata])).toBe(B[Symbol.metadata]);
+expect(C[Symbol.metadata]).toEqual({ c: "field" });
+expect(Object.getPrototypeOf(C[Symbol.metadata])).toBe(A[Symbol.metadata]);
@@ -1,6 +1,6 @@
-var _initProto;
+var _B, _initProto;
 const dec = ()=>{};
-class A extends B {
+class A extends (_B = B) {
     method() {}
     constructor(){
         let a = 2;
@@ -14,4 +14,4 @@ class A extends B {
         2,
         "method"
     ]
-], []));
+], [], _B));
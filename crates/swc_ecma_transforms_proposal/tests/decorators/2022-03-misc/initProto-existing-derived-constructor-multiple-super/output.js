@@ -1,6 +1,6 @@
-var _initProto, _initProto1;
+var _B, _initProto, _B1, _initProto1;
 const dec = ()=>{};
-class A extends B {
+class A extends (_B = B) {
     static{
         ({ e: [_initProto] } = _apply_decs_2203_r(this, [
             [
@@ -8,7 +8,7 @@ class A extends B {
                 2,
                 "method"
             ]
-        ], []));
+        ], [], _B));
     }
     constructor(){
         if (Math.random() > 0.5) {
@@ -19,7 +19,7 @@ class A extends B {
     }
     method() {}
 }
-class C extends B {
+class C extends (_B1 = B) {
     static{
         ({ e: [_initProto1] } = _apply_decs_2203_r(this, [
             [
@@ -27,7 +27,7 @@ class C extends B {
                 2,
                 "method"
             ]
-        ], []));
+        ], [], _B1));
     }
     constructor(){
         try {
@@ -1,6 +1,6 @@
-var _initProto;
+var _B, _initProto;
 const dec = ()=>{};
-class A extends B {
+class A extends (_B = B) {
     static{
         ({ e: [_initProto] } = _apply_decs_2203_r(this, [
             [
@@ -8,7 +8,7 @@ class A extends B {
                 2,
                 "method"
             ]
-        ], []));
+        ], [], _B));
     }
     constructor(){
         let a = 2;
@@ -0,0 +1,95 @@
+use swc_ecma_ast::Pass;
+use swc_ecma_parser::{EsSyntax, Syntax};
+use swc_ecma_transforms_proposal::do_expressions;
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsSyntax {
+        do_expressions: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Pass {
+    do_expressions()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    simple,
+    "let x = do { let y = 1; y + 1 };"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    if_else,
+    r#"
+let x = do {
+    if (a) {
+        "a";
+    } else if (b) "b";
+    else {
+        try {
+            f();
+        } catch {
+            "c";
+        }
+    }
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    trailing_fn_decl,
+    "let x = do { g(); function g() {}; };"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    await_in_body,
+    r#"
+async function f() {
+    return do {
+        const res = await fetch(url);
+        res.ok;
+    };
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    yield_in_body,
+    r#"
+function* f() {
+    const x = do {
+        yield this.a;
+    };
+    const y = do {
+        yield arguments[0];
+    };
+}
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_fn_await_is_ignored,
+    "let x = do { async () => { await 1; }; };"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested,
+    "let x = do { f(do { 1 } + 1) };"
+);
+
+test!(syntax(), |_| tr(), expr_stmt, "(do { 1 });");
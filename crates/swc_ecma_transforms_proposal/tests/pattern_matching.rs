@@ -0,0 +1,90 @@
+use swc_ecma_ast::Pass;
+use swc_ecma_parser::{EsSyntax, Syntax};
+use swc_ecma_transforms_proposal::pattern_matching;
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsSyntax {
+        explicit_proposals: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Pass {
+    pattern_matching()
+}
+
+test!(
+    syntax(),
+    |_| tr(),
+    values_and_default,
+    r#"
+const x = match (a) {
+    when 1: "one";
+    when < 0: "negative";
+    when not (2 or 3): "other";
+    default: "two or three";
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    no_default,
+    r#"
+const x = match (f()) {
+    when let y if (y > 1): y;
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    object_pattern,
+    r#"
+const x = match (res) {
+    when { status: 200, let body, headers }: body;
+    when { status: >= 400 and < 500 }: null;
+    default: res;
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    array_pattern,
+    r#"
+const x = match (list) {
+    when []: 0;
+    when [let first, ...]: first;
+    when [[let a], ...let rest]: a + rest.length;
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    arrow_body,
+    r#"
+const f = (v) => match (v) {
+    when let x: x;
+};
+"#
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested,
+    r#"
+const x = match (a) {
+    when let b: match (b) {
+        when let b: b;
+    };
+};
+"#
+);
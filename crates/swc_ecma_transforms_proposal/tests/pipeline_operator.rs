@@ -0,0 +1,45 @@
+use swc_ecma_ast::Pass;
+use swc_ecma_parser::{EsSyntax, Syntax};
+use swc_ecma_transforms_proposal::pipeline_operator;
+use swc_ecma_transforms_testing::test;
+
+fn syntax() -> Syntax {
+    Syntax::Es(EsSyntax {
+        pipeline_operator: true,
+        ..Default::default()
+    })
+}
+
+fn tr() -> impl Pass {
+    pipeline_operator()
+}
+
+test!(syntax(), |_| tr(), simple, "a |> f(%, %);");
+
+test!(
+    syntax(),
+    |_| tr(),
+    chained,
+    "const x = a |> f(%) |> g(%) + %;"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    nested_body,
+    "const x = a |> f(% |> g(%, %));"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    arrow_body,
+    "const f = (x) => x |> % * 2;"
+);
+
+test!(
+    syntax(),
+    |_| tr(),
+    in_block,
+    "if (a) { b(a |> (% ? 1 : 2)); }"
+);
@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 use string_enum::StringEnum;
 use swc_atoms::{atom, Atom};
 use swc_common::{
-    comments::{Comment, CommentKind, Comments},
+    comments::{Comment, Comments},
     errors::HANDLER,
     iter::IdentifyLast,
     sync::Lrc,
@@ -72,8 +72,12 @@ pub struct Options {
     #[serde(default)]
     pub throw_if_namespace: Option<bool>,
 
-    #[serde(default)]
-    pub development: Option<bool>,
+    /// Enables the development transforms, like `jsxDEV` of the automatic
+    /// runtime, `__source`, `__self` and fast refresh.
+    ///
+    /// This can be `true`, or an object to choose the props which are added.
+    #[serde(default, deserialize_with = "deserialize_development")]
+    pub development: Option<DevelopmentOptions>,
 
     // @babel/plugin-transform-react-jsx: Since "useBuiltIns" is removed in Babel 8, you can remove
     // it from the config.
@@ -99,6 +103,52 @@ pub struct Options {
     pub refresh: Option<RefreshOptions>,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct DevelopmentOptions {
+    /// Add `__source` to JSX elements, like
+    /// `@babel/plugin-transform-react-jsx-source`.
+    #[serde(default = "true_by_default")]
+    pub jsx_source: bool,
+
+    /// Add `__self` to JSX elements, like
+    /// `@babel/plugin-transform-react-jsx-self`.
+    #[serde(default = "true_by_default")]
+    pub jsx_self: bool,
+}
+
+impl Default for DevelopmentOptions {
+    fn default() -> Self {
+        DevelopmentOptions {
+            jsx_source: true,
+            jsx_self: true,
+        }
+    }
+}
+
+fn true_by_default() -> bool {
+    true
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BoolOrDevelopment {
+    Bool(bool),
+    Development(DevelopmentOptions),
+}
+
+fn deserialize_development<'de, D>(deserializer: D) -> Result<Option<DevelopmentOptions>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    match Option::<BoolOrDevelopment>::deserialize(deserializer)? {
+        Some(BoolOrDevelopment::Development(development)) => Ok(Some(development)),
+        Some(BoolOrDevelopment::Bool(true)) => Ok(Some(Default::default())),
+        Some(BoolOrDevelopment::Bool(false)) | None => Ok(None),
+    }
+}
+
 #[cfg(feature = "concurrent")]
 macro_rules! static_str {
     ($s:expr) => {{
@@ -234,7 +284,7 @@ where
             options.pragma_frag.unwrap_or_else(default_pragma_frag),
             top_level_mark,
         )),
-        development: options.development.unwrap_or_default(),
+        development: options.development.is_some(),
         throw_if_namespace: options
             .throw_if_namespace
             .unwrap_or_else(default_throw_if_namespace),
@@ -307,11 +357,8 @@ impl JsxDirectives {
     ) -> Self {
         let mut res = JsxDirectives::default();
 
+        // Line comments are accepted like babel and esbuild do.
         for cmt in comments {
-            if cmt.kind != CommentKind::Block {
-                continue;
-            }
-
             for line in cmt.text.lines() {
                 let mut line = line.trim();
                 if line.starts_with('*') {
@@ -6,6 +6,7 @@
 
 use swc_common::{comments::Comments, sync::Lrc, Mark, SourceMap};
 use swc_ecma_ast::Pass;
+use swc_ecma_transforms_base::syntax_features::{SyntaxFeatureScan, SyntaxFeatures};
 
 pub use self::{
     display_name::display_name,
@@ -14,6 +15,7 @@ pub use self::{
     jsx_src::jsx_src,
     pure_annotations::pure_annotations,
     refresh::{options::RefreshOptions, refresh},
+    server_components::{analyze_directives, server_components, Directive, DirectiveInfo},
 };
 
 mod display_name;
@@ -22,6 +24,7 @@ mod jsx_self;
 mod jsx_src;
 mod pure_annotations;
 mod refresh;
+mod server_components;
 
 /// `@babel/preset-react`
 ///
@@ -47,13 +50,23 @@ where
     C: Comments + Clone,
 {
     let Options { development, .. } = options;
-    let development = development.unwrap_or(false);
+    let DevelopmentOptions {
+        jsx_source,
+        jsx_self: add_jsx_self,
+    } = development.unwrap_or(DevelopmentOptions {
+        jsx_source: false,
+        jsx_self: false,
+    });
+    let development = development.is_some();
 
     let refresh_options = options.refresh.take();
 
+    // Passes for jsx are skipped for files without jsx.
+    let scan = SyntaxFeatureScan::default();
+
     (
-        jsx_src(development, cm.clone()),
-        jsx_self(development),
+        scan.requires(SyntaxFeatures::Jsx, jsx_src(jsx_source, cm.clone())),
+        scan.requires(SyntaxFeatures::Jsx, jsx_self(add_jsx_self)),
         refresh(
             development,
             refresh_options.clone(),
@@ -61,12 +74,15 @@ where
             comments.clone(),
             top_level_mark,
         ),
-        jsx(
-            cm.clone(),
-            comments.clone(),
-            options,
-            top_level_mark,
-            unresolved_mark,
+        scan.requires(
+            SyntaxFeatures::Jsx,
+            jsx(
+                cm.clone(),
+                comments.clone(),
+                options,
+                top_level_mark,
+                unresolved_mark,
+            ),
         ),
         display_name(),
         pure_annotations(comments.clone()),
@@ -0,0 +1,410 @@
+use swc_atoms::Atom;
+use swc_common::{errors::HANDLER, util::take::Take, Span, DUMMY_SP};
+use swc_ecma_ast::*;
+use swc_ecma_utils::{find_pat_ids, private_ident};
+use swc_ecma_visit::{noop_visit_type, visit_mut_pass, Visit, VisitMut, VisitWith};
+
+#[cfg(test)]
+mod tests;
+
+/// A directive of React Server Components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Directive {
+    /// `"use client"`
+    UseClient,
+    /// `"use server"`
+    UseServer,
+}
+
+impl Directive {
+    fn from_stmt(stmt: &Stmt) -> Option<(Self, Span)> {
+        let Stmt::Expr(ExprStmt { expr, .. }) = stmt else {
+            return None;
+        };
+        let Expr::Lit(Lit::Str(s)) = &**expr else {
+            return None;
+        };
+
+        match &*s.value {
+            "use client" => Some((Directive::UseClient, s.span)),
+            "use server" => Some((Directive::UseServer, s.span)),
+            _ => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Directive::UseClient => "\"use client\"",
+            Directive::UseServer => "\"use server\"",
+        }
+    }
+}
+
+/// Directives of React Server Components found in a module.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DirectiveInfo {
+    /// The directive at the top of the module.
+    pub module: Option<Directive>,
+
+    /// Spans of the functions with a `"use server"` directive, also known as
+    /// inline server actions.
+    pub server_functions: Vec<Span>,
+}
+
+/// Finds the directives of React Server Components in `module`.
+///
+/// Misplaced or conflicting directives are reported, as well as server
+/// actions which aren't async functions.
+///
+/// # Note
+///
+/// This function uses [swc_ecma_utils::HANDLER].
+pub fn analyze_directives(module: &Module) -> DirectiveInfo {
+    let mut analyzer = Analyzer::default();
+    module.visit_with(&mut analyzer);
+    analyzer.info
+}
+
+/// Validates the directives of React Server Components and, if `is_server` is
+/// `false`, replaces the exports of a `"use server"` module with references
+/// created by `create_reference`.
+///
+/// `create_reference` is called with the name of each export, which is
+/// `default` for the default export. The rest of the module is removed, as it
+/// must only run on the server.
+///
+/// # Example
+///
+/// ## In
+///
+/// ```js
+/// "use server";
+/// import { db } from "./db";
+///
+/// export async function save(data) {
+///     await db.save(data);
+/// }
+/// ```
+///
+/// ## Out
+///
+/// With a callback which creates `createServerReference("save")`:
+///
+/// ```js
+/// export const save = createServerReference("save");
+/// ```
+///
+/// # Note
+///
+/// This pass uses [swc_ecma_utils::HANDLER].
+pub fn server_components<F>(is_server: bool, create_reference: F) -> impl Pass
+where
+    F: FnMut(&Atom) -> Box<Expr>,
+{
+    visit_mut_pass(ServerComponents {
+        is_server,
+        create_reference,
+    })
+}
+
+struct ServerComponents<F> {
+    is_server: bool,
+    create_reference: F,
+}
+
+impl<F> VisitMut for ServerComponents<F>
+where
+    F: FnMut(&Atom) -> Box<Expr>,
+{
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        let info = analyze_directives(n);
+
+        if self.is_server || info.module != Some(Directive::UseServer) {
+            return;
+        }
+
+        let mut body = Vec::new();
+
+        for item in n.body.take() {
+            let ModuleItem::ModuleDecl(decl) = item else {
+                continue;
+            };
+
+            match decl {
+                ModuleDecl::ExportDecl(ExportDecl { decl, .. }) => {
+                    let names = match decl {
+                        Decl::Fn(FnDecl { ident, .. }) => vec![ident],
+                        Decl::Var(var) => find_pat_ids::<_, Ident>(&var.decls),
+                        _ => Vec::new(),
+                    };
+
+                    for name in names {
+                        body.push(self.export_reference(name.sym.clone(), name));
+                    }
+                }
+
+                ModuleDecl::ExportDefaultDecl(..) | ModuleDecl::ExportDefaultExpr(..) => {
+                    body.push(
+                        ExportDefaultExpr {
+                            span: DUMMY_SP,
+                            expr: (self.create_reference)(&"default".into()),
+                        }
+                        .into(),
+                    );
+                }
+
+                ModuleDecl::ExportNamed(NamedExport {
+                    src: None,
+                    specifiers,
+                    type_only: false,
+                    ..
+                }) => {
+                    for specifier in specifiers {
+                        let ExportSpecifier::Named(ExportNamedSpecifier {
+                            orig,
+                            exported,
+                            is_type_only: false,
+                            ..
+                        }) = specifier
+                        else {
+                            continue;
+                        };
+
+                        match exported.unwrap_or(orig) {
+                            ModuleExportName::Ident(name) => {
+                                body.push(self.export_reference(name.sym.clone(), name));
+                            }
+                            ModuleExportName::Str(name) => {
+                                // export { _ref as "name" }
+                                let local = private_ident!("_ref");
+                                body.push(self.declare_reference(&name.value, local.clone()));
+                                body.push(
+                                    NamedExport {
+                                        span: DUMMY_SP,
+                                        specifiers: vec![ExportNamedSpecifier {
+                                            span: DUMMY_SP,
+                                            orig: local.into(),
+                                            exported: Some(ModuleExportName::Str(name)),
+                                            is_type_only: false,
+                                        }
+                                        .into()],
+                                        src: None,
+                                        type_only: false,
+                                        with: None,
+                                    }
+                                    .into(),
+                                );
+                            }
+                        }
+                    }
+                }
+
+                // Re-exported modules have their own directives.
+                decl @ (ModuleDecl::ExportNamed(NamedExport { src: Some(..), .. })
+                | ModuleDecl::ExportAll(..)) => body.push(decl.into()),
+
+                _ => {}
+            }
+        }
+
+        n.body = body;
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {}
+}
+
+impl<F> ServerComponents<F>
+where
+    F: FnMut(&Atom) -> Box<Expr>,
+{
+    /// `const local = reference`
+    fn declare_reference(&mut self, name: &Atom, local: Ident) -> ModuleItem {
+        VarDecl {
+            kind: VarDeclKind::Const,
+            decls: vec![VarDeclarator {
+                span: DUMMY_SP,
+                name: local.into(),
+                init: Some((self.create_reference)(name)),
+                definite: false,
+            }],
+            ..Default::default()
+        }
+        .into()
+    }
+
+    /// `export const local = reference`
+    fn export_reference(&mut self, name: Atom, local: Ident) -> ModuleItem {
+        let ModuleItem::Stmt(Stmt::Decl(decl)) = self.declare_reference(&name, local) else {
+            unreachable!()
+        };
+
+        ExportDecl {
+            span: DUMMY_SP,
+            decl,
+        }
+        .into()
+    }
+}
+
+#[derive(Default)]
+struct Analyzer {
+    info: DirectiveInfo,
+}
+
+impl Analyzer {
+    /// Checks the directives of a function body.
+    fn check_fn_body(&mut self, body: &BlockStmt, is_async: bool, span: Span) {
+        for (directive, directive_span) in prologue_directives(body.stmts.iter().map(Some)) {
+            match directive {
+                Directive::UseClient => emit_error(
+                    directive_span,
+                    "The \"use client\" directive is only allowed at the top of a module",
+                ),
+                Directive::UseServer => {
+                    if self.info.module == Some(Directive::UseClient) {
+                        emit_error(
+                            directive_span,
+                            "Server actions can't be defined in a \"use client\" module",
+                        );
+                    } else if !is_async {
+                        emit_error(span, "Server actions must be async functions");
+                    }
+
+                    self.info.server_functions.push(span);
+                }
+            }
+        }
+    }
+
+    /// Reports exports of a `"use server"` module which are known not to be
+    /// async functions.
+    fn check_server_exports(&self, module: &Module) {
+        for item in &module.body {
+            let ModuleItem::ModuleDecl(decl) = item else {
+                continue;
+            };
+
+            let sync_fn_span = match decl {
+                ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Fn(FnDecl { function, .. }),
+                    ..
+                })
+                | ModuleDecl::ExportDefaultDecl(ExportDefaultDecl {
+                    decl: DefaultDecl::Fn(FnExpr { function, .. }),
+                    ..
+                }) => (!function.is_async).then_some(function.span),
+
+                ModuleDecl::ExportDecl(ExportDecl {
+                    decl: Decl::Var(var),
+                    ..
+                }) => var
+                    .decls
+                    .iter()
+                    .filter_map(|decl| decl.init.as_deref())
+                    .find_map(sync_fn_span),
+
+                ModuleDecl::ExportDefaultExpr(ExportDefaultExpr { expr, .. }) => sync_fn_span(expr),
+
+                _ => None,
+            };
+
+            if let Some(span) = sync_fn_span {
+                emit_error(
+                    span,
+                    "Only async functions can be exported from a \"use server\" module",
+                );
+            }
+        }
+    }
+}
+
+impl Visit for Analyzer {
+    noop_visit_type!();
+
+    fn visit_module(&mut self, n: &Module) {
+        let directives = prologue_directives(n.body.iter().map(ModuleItem::as_stmt));
+
+        for (directive, span) in directives {
+            match self.info.module {
+                None => self.info.module = Some(directive),
+                Some(prev) if prev != directive => emit_error(
+                    span,
+                    "A module can't have both \"use client\" and \"use server\" directives",
+                ),
+                Some(..) => {}
+            }
+        }
+
+        if self.info.module == Some(Directive::UseServer) {
+            self.check_server_exports(n);
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_function(&mut self, n: &Function) {
+        if let Some(body) = &n.body {
+            self.check_fn_body(body, n.is_async, n.span);
+        }
+
+        n.visit_children_with(self);
+    }
+
+    fn visit_arrow_expr(&mut self, n: &ArrowExpr) {
+        if let BlockStmtOrExpr::BlockStmt(body) = &*n.body {
+            self.check_fn_body(body, n.is_async, n.span);
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+/// Returns the directives in the directive prologue of `stmts`, where `None`
+/// is a module declaration.
+///
+/// Directives after other statements are reported, as they are ignored.
+fn prologue_directives<'a>(
+    stmts: impl IntoIterator<Item = Option<&'a Stmt>>,
+) -> Vec<(Directive, Span)> {
+    let mut in_prologue = true;
+    let mut directives = Vec::new();
+
+    for stmt in stmts {
+        let is_string = matches!(
+            stmt,
+            Some(Stmt::Expr(ExprStmt { expr, .. })) if matches!(&**expr, Expr::Lit(Lit::Str(..)))
+        );
+        in_prologue &= is_string;
+
+        let Some((directive, span)) = stmt.and_then(Directive::from_stmt) else {
+            continue;
+        };
+
+        if in_prologue {
+            directives.push((directive, span));
+        } else {
+            emit_error(
+                span,
+                &format!(
+                    "The {} directive must be placed before other statements",
+                    directive.as_str()
+                ),
+            );
+        }
+    }
+
+    directives
+}
+
+/// Returns the span of `e` if it's a function which isn't async.
+fn sync_fn_span(e: &Expr) -> Option<Span> {
+    match e {
+        Expr::Fn(FnExpr { function, .. }) if !function.is_async => Some(function.span),
+        Expr::Arrow(arrow) if !arrow.is_async => Some(arrow.span),
+        _ => None,
+    }
+}
+
+fn emit_error(span: Span, msg: &str) {
+    HANDLER.with(|handler| handler.struct_span_err(span, msg).emit());
+}
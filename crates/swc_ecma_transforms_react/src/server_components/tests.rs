@@ -0,0 +1,34 @@
+use std::path::PathBuf;
+
+use swc_ecma_parser::{EsSyntax, Syntax};
+use swc_ecma_transforms_testing::{test_fixture, FixtureTestConfig};
+use swc_ecma_utils::{quote_ident, quote_str, ExprFactory};
+
+use super::*;
+
+#[testing::fixture("tests/server-components/**/input.js")]
+fn fixture(input: PathBuf) {
+    let output = input.with_file_name("output.js");
+    let is_server = input.components().any(|c| c.as_os_str() == "server");
+
+    test_fixture(
+        Syntax::Es(EsSyntax {
+            jsx: true,
+            ..Default::default()
+        }),
+        &|_| {
+            server_components(is_server, |name| {
+                quote_ident!("createServerReference")
+                    .as_call(DUMMY_SP, vec![quote_str!(name.clone()).as_arg()])
+                    .into()
+            })
+        },
+        &input,
+        &output,
+        FixtureTestConfig {
+            allow_error: true,
+            module: Some(true),
+            ..Default::default()
+        },
+    );
+}
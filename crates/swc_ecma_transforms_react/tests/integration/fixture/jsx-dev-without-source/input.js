@@ -0,0 +1,8 @@
+const App = (
+    <div>
+        <div />
+        <>
+            <div key={1}>hoge</div>
+        </>
+    </div>
+);
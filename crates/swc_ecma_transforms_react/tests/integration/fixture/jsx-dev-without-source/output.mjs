@@ -0,0 +1,11 @@
+import { jsxDEV as _jsxDEV, Fragment as _Fragment } from "react/jsx-dev-runtime";
+const App = /*#__PURE__*/ _jsxDEV("div", {
+    children: [
+        /*#__PURE__*/ _jsxDEV("div", {}, void 0, false, void 0, this),
+        /*#__PURE__*/ _jsxDEV(_Fragment, {
+            children: /*#__PURE__*/ _jsxDEV("div", {
+                children: "hoge"
+            }, 1, false, void 0, this)
+        }, void 0, false)
+    ]
+}, void 0, true, void 0, this);
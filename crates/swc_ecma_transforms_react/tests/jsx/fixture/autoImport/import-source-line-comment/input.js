@@ -0,0 +1,6 @@
+// @jsxImportSource preact
+var x = (
+    <div>
+        <span />
+    </div>
+);
@@ -0,0 +1,5 @@
+// @jsxImportSource preact
+import { jsx as _jsx } from "preact/jsx-runtime";
+var x = /*#__PURE__*/ _jsx("div", {
+    children: /*#__PURE__*/ _jsx("span", {})
+});
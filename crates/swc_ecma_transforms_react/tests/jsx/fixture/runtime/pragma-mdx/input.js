@@ -0,0 +1,6 @@
+/*@jsxRuntime automatic @jsxImportSource react*/
+export default function MDXContent() {
+    return <>
+        <h1>Hello</h1>
+    </>;
+}
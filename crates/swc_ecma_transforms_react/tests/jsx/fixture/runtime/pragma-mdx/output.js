@@ -0,0 +1,8 @@
+/*@jsxRuntime automatic @jsxImportSource react*/ import { jsx as _jsx, Fragment as _Fragment } from "react/jsx-runtime";
+export default function MDXContent() {
+    return /*#__PURE__*/ _jsx(_Fragment, {
+        children: /*#__PURE__*/ _jsx("h1", {
+            children: "Hello"
+        })
+    });
+}
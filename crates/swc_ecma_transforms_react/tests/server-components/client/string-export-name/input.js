@@ -0,0 +1,5 @@
+"use server";
+
+async function action() {}
+
+export { action as "my action" };
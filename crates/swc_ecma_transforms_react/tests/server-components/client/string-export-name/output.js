@@ -0,0 +1,2 @@
+const _ref = createServerReference("my action");
+export { _ref as "my action" };
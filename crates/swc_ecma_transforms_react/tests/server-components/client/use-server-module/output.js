@@ -0,0 +1,7 @@
+export const save = createServerReference("save");
+export const remove = createServerReference("remove");
+export const update = createServerReference("update");
+export const load = createServerReference("load");
+export const fetch = createServerReference("fetch");
+export default createServerReference("default");
+export * from "./other-actions";
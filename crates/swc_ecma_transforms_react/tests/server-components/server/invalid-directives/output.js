@@ -0,0 +1,15 @@
+import { db } from "./db";
+"use client";
+export function Page() {
+    async function submit() {
+        "use server";
+        await db.save();
+    }
+    function notAsync() {
+        "use server";
+    }
+    function client() {
+        "use client";
+    }
+    return <form action={submit}/>;
+}
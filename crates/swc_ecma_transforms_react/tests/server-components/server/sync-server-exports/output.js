@@ -0,0 +1,5 @@
+"use server";
+export function a() {}
+export const b = ()=>{};
+export const c = async ()=>{};
+export default function() {}
@@ -0,0 +1,10 @@
+"use client";
+"use server";
+
+export function Button() {
+    const onClick = async () => {
+        "use server";
+    };
+
+    return <button onClick={onClick} />;
+}
@@ -0,0 +1,15 @@
+"use server";
+import { db } from "./db";
+const secret = process.env.SECRET;
+export async function save(data) {
+    await db.save(data, secret);
+}
+export const remove = async (id)=>{
+    await db.remove(id);
+}, update = async ()=>{};
+async function load() {
+    return db.load();
+}
+export { load, load as fetch };
+export default async function() {}
+export * from "./other-actions";
@@ -0,0 +1,255 @@
+use std::{fs::read_to_string, path::Path};
+
+use ansi_term::Color;
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use swc_ecma_ast::{EsVersion, Pass};
+use swc_ecma_codegen::{text_writer::JsWriter, Emitter};
+use swc_ecma_parser::Syntax;
+use swc_ecma_transforms_base::{fixer::fixer, hygiene::hygiene};
+use testing::NormalizedOutput;
+
+use crate::{visualizer_url, SourceMapConfigImpl, Tester};
+
+/// Snapshot tests for passes, which can be used outside of swc.
+///
+/// A fixture is a directory with an input file, like `input.js`. The pass is
+/// applied to the input, followed by [hygiene] and [fixer], and the result is
+/// compared with files in the same directory:
+///
+/// - `output.js`: The generated code.
+/// - `output.stderr`: Diagnostics emitted to
+///   [HANDLER](swc_common::errors::HANDLER), if errors are allowed.
+/// - `output.map`: The source map, if enabled.
+///
+/// Run tests with `UPDATE=1` to create or update those files.
+///
+/// # Options
+///
+/// `options.json` in the directory of the fixture overrides settings of the
+/// test, and contains the options of the pass.
+///
+/// ```json
+/// {
+///   "syntax": { "syntax": "typescript", "tsx": true },
+///   "isModule": true,
+///   "allowError": true,
+///   "sourceMap": true,
+///   "codegen": { "minify": true, "target": "es5" },
+///   "options": { "loose": true }
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[testing::fixture("tests/fixture/**/input.js")]
+/// fn fixture(input: PathBuf) {
+///     FixtureTest::new(&input).run(|_, options: MyOptions| my_pass(options));
+/// }
+/// ```
+pub struct FixtureTest<'a> {
+    input: &'a Path,
+
+    /// Defaults to [`Syntax::default`]
+    syntax: Syntax,
+    module: Option<bool>,
+    codegen: swc_ecma_codegen::Config,
+    source_map: bool,
+    allow_error: bool,
+}
+
+impl<'a> FixtureTest<'a> {
+    pub fn new(input: &'a Path) -> Self {
+        Self {
+            input,
+            syntax: Default::default(),
+            module: None,
+            codegen: Default::default(),
+            source_map: false,
+            allow_error: false,
+        }
+    }
+
+    pub fn syntax(mut self, syntax: Syntax) -> Self {
+        self.syntax = syntax;
+        self
+    }
+
+    /// See [FixtureTestConfig::module](crate::FixtureTestConfig::module).
+    pub fn module(mut self, module: Option<bool>) -> Self {
+        self.module = module;
+        self
+    }
+
+    pub fn codegen(mut self, codegen: swc_ecma_codegen::Config) -> Self {
+        self.codegen = codegen;
+        self
+    }
+
+    pub fn source_map(mut self) -> Self {
+        self.source_map = true;
+        self
+    }
+
+    /// Compare diagnostics with `output.stderr` instead of failing the test.
+    pub fn allow_error(mut self) -> Self {
+        self.allow_error = true;
+        self
+    }
+
+    /// Applies the pass created by `tr` to the fixture.
+    ///
+    /// `tr` receives `options` of `options.json`, or [Default::default] if
+    /// there is no such field.
+    pub fn run<O, P>(self, tr: impl FnOnce(&mut Tester, O) -> P)
+    where
+        O: DeserializeOwned + Default,
+        P: Pass,
+    {
+        let _logger = testing::init();
+
+        let dir = self.input.parent().unwrap();
+        let config = FixtureOptions::read(dir);
+
+        let syntax = config.syntax.unwrap_or(self.syntax);
+        let module = config.is_module.or(self.module);
+        let source_map = config.source_map.unwrap_or(self.source_map);
+        let allow_error = config.allow_error.unwrap_or(self.allow_error);
+        let codegen = config.codegen.unwrap_or_default().apply(self.codegen);
+        let options = match config.options {
+            Some(v) => serde_json::from_value(v)
+                .unwrap_or_else(|err| panic!("failed to deserialize `options`: {}", err)),
+            None => O::default(),
+        };
+
+        let input = read_to_string(self.input).unwrap();
+        let file_name = self.input.file_name().unwrap().to_string_lossy();
+
+        let mut sourcemap = None;
+
+        let (actual, stderr) = Tester::run_captured(|tester| {
+            eprintln!("----- {} -----\n{}", Color::Green.paint("Input"), input);
+
+            let tr = tr(tester, options);
+
+            let actual = tester
+                .apply_transform(tr, &file_name, syntax, module, &input)?
+                .apply(hygiene())
+                .apply(fixer(Some(&tester.comments)));
+
+            let mut buf = Vec::new();
+            let mut src_map = if source_map { Some(Vec::new()) } else { None };
+
+            {
+                let mut emitter = Emitter {
+                    cfg: codegen,
+                    cm: tester.cm.clone(),
+                    wr: JsWriter::new(tester.cm.clone(), "\n", &mut buf, src_map.as_mut()),
+                    comments: Some(&tester.comments),
+                };
+
+                emitter.emit_program(&actual).unwrap();
+            }
+
+            if let Some(src_map) = &src_map {
+                sourcemap = Some(tester.cm.build_source_map_with_config(
+                    src_map,
+                    None,
+                    SourceMapConfigImpl,
+                ));
+            }
+
+            Ok(String::from_utf8(buf).expect("codegen generated non-utf8 output"))
+        });
+
+        if allow_error {
+            stderr.compare_to_file(dir.join("output.stderr")).unwrap();
+        } else if !stderr.is_empty() {
+            panic!("stderr: {}", stderr);
+        }
+
+        if let Some(actual) = actual {
+            eprintln!("----- {} -----\n{}", Color::Green.paint("Actual"), actual);
+
+            if let Some(sourcemap) = &sourcemap {
+                eprintln!("SourceMap: {}", visualizer_url(&actual, sourcemap));
+            }
+
+            NormalizedOutput::from(actual)
+                .compare_to_file(dir.join("output.js"))
+                .unwrap();
+        }
+
+        if let Some(sourcemap) = sourcemap {
+            let mut buf = Vec::new();
+            sourcemap.to_writer(&mut buf).unwrap();
+
+            NormalizedOutput::from(String::from_utf8(buf).unwrap())
+                .compare_to_file(dir.join("output.map"))
+                .unwrap();
+        }
+    }
+}
+
+/// `options.json` of a fixture.
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct FixtureOptions {
+    #[serde(default)]
+    syntax: Option<Syntax>,
+    #[serde(default)]
+    is_module: Option<bool>,
+    #[serde(default)]
+    source_map: Option<bool>,
+    #[serde(default)]
+    allow_error: Option<bool>,
+    #[serde(default)]
+    codegen: Option<CodegenOptions>,
+    #[serde(default)]
+    options: Option<Value>,
+}
+
+impl FixtureOptions {
+    fn read(dir: &Path) -> Self {
+        let file = dir.join("options.json");
+
+        match read_to_string(&file) {
+            Ok(v) => serde_json::from_str(&v)
+                .unwrap_or_else(|err| panic!("failed to deserialize {}: {}", file.display(), err)),
+            Err(_) => Default::default(),
+        }
+    }
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct CodegenOptions {
+    #[serde(default)]
+    target: Option<EsVersion>,
+    #[serde(default)]
+    minify: Option<bool>,
+    #[serde(default)]
+    ascii_only: Option<bool>,
+    #[serde(default)]
+    omit_last_semi: Option<bool>,
+}
+
+impl CodegenOptions {
+    fn apply(self, mut config: swc_ecma_codegen::Config) -> swc_ecma_codegen::Config {
+        if let Some(target) = self.target {
+            config.target = target;
+        }
+        if let Some(minify) = self.minify {
+            config.minify = minify;
+        }
+        if let Some(ascii_only) = self.ascii_only {
+            config.ascii_only = ascii_only;
+        }
+        if let Some(omit_last_semi) = self.omit_last_semi {
+            config.omit_last_semi = omit_last_semi;
+        }
+
+        config
+    }
+}
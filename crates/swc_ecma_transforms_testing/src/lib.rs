@@ -43,6 +43,7 @@ use testing::{
 };
 
 pub mod babel_like;
+pub mod fixture;
 
 pub struct Tester<'a> {
     pub cm: Lrc<SourceMap>,
@@ -0,0 +1,45 @@
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use swc_common::errors::HANDLER;
+use swc_ecma_ast::*;
+use swc_ecma_transforms_testing::fixture::FixtureTest;
+use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith};
+
+#[derive(Default, Deserialize)]
+struct Options {
+    #[serde(default)]
+    rename: Option<(String, String)>,
+}
+
+/// Renames an identifier and reports `debugger` statements.
+struct TestPass {
+    options: Options,
+}
+
+impl VisitMut for TestPass {
+    fn visit_mut_ident(&mut self, i: &mut Ident) {
+        if let Some((from, to)) = &self.options.rename {
+            if i.sym == **from {
+                i.sym = to.as_str().into();
+            }
+        }
+    }
+
+    fn visit_mut_stmt(&mut self, s: &mut Stmt) {
+        s.visit_mut_children_with(self);
+
+        if let Stmt::Debugger(d) = s {
+            HANDLER.with(|handler| {
+                handler
+                    .struct_span_err(d.span, "debugger is not allowed")
+                    .emit()
+            });
+        }
+    }
+}
+
+#[testing::fixture("tests/fixture/**/input.*")]
+fn fixture(input: PathBuf) {
+    FixtureTest::new(&input).run(|_, options: Options| visit_mut_pass(TestPass { options }));
+}
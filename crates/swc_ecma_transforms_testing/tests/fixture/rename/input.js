@@ -0,0 +1,2 @@
+const foo = 1;
+console.log(foo);
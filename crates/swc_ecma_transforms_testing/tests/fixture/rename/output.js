@@ -0,0 +1,2 @@
+const bar = 1;
+console.log(bar);
@@ -1,9 +1,14 @@
+use rustc_hash::FxHashMap;
 use serde::{Deserialize, Serialize};
+use swc_atoms::Atom;
 use swc_common::sync::Lrc;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
+    /// Only remove imports and exports which are explicitly marked with
+    /// `type`, and keep value imports even if they are unused.
+    ///
     /// https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax
     #[serde(default)]
     pub verbatim_module_syntax: bool,
@@ -34,6 +39,24 @@ pub struct Config {
     /// Defaults to false.
     #[serde(default)]
     pub ts_enum_is_mutable: bool,
+
+    /// Member values of `const enum`s declared in other modules, keyed by
+    /// module specifier and enum name.
+    ///
+    /// Member accesses of the enums imported from these modules are inlined,
+    /// as `tsc` does when `isolatedModules` is disabled, and imports which
+    /// are no longer used are removed.
+    #[serde(default)]
+    pub const_enum_values: FxHashMap<Atom, FxHashMap<Atom, ConstEnumMembers>>,
+}
+
+pub type ConstEnumMembers = FxHashMap<Atom, ConstEnumValue>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ConstEnumValue {
+    Number(f64),
+    String(Atom),
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -0,0 +1,128 @@
+use rustc_hash::FxHashMap;
+use swc_atoms::Atom;
+use swc_ecma_ast::*;
+use swc_ecma_utils::stack_size::maybe_grow_default;
+use swc_ecma_visit::{noop_visit_mut_type, VisitMut, VisitMutWith};
+
+use crate::{
+    config::{ConstEnumMembers, ConstEnumValue},
+    transform::get_member_key,
+    ts_enum::TsEnumRecordValue,
+};
+
+/// Inlines members of `const enum`s imported from other modules, using the
+/// values provided by the user.
+///
+/// This runs before imports are stripped, so imports which are only used by
+/// inlined members get removed.
+pub(crate) struct InlineImportedConstEnum<'a> {
+    values: &'a FxHashMap<Atom, FxHashMap<Atom, ConstEnumMembers>>,
+
+    /// `import { E } from "./enums"`
+    enums: FxHashMap<Id, &'a ConstEnumMembers>,
+    /// `import * as enums from "./enums"`
+    namespaces: FxHashMap<Id, &'a FxHashMap<Atom, ConstEnumMembers>>,
+}
+
+impl<'a> InlineImportedConstEnum<'a> {
+    pub fn new(values: &'a FxHashMap<Atom, FxHashMap<Atom, ConstEnumMembers>>) -> Self {
+        Self {
+            values,
+            enums: Default::default(),
+            namespaces: Default::default(),
+        }
+    }
+
+    fn collect_import(&mut self, n: &ImportDecl) {
+        if n.type_only {
+            return;
+        }
+
+        let Some(module) = self.values.get(&n.src.value) else {
+            return;
+        };
+
+        for specifier in &n.specifiers {
+            match specifier {
+                ImportSpecifier::Named(ImportNamedSpecifier {
+                    local,
+                    imported,
+                    is_type_only: false,
+                    ..
+                }) => {
+                    let name = match imported {
+                        Some(ModuleExportName::Ident(ident)) => &ident.sym,
+                        Some(ModuleExportName::Str(s)) => &s.value,
+                        None => &local.sym,
+                    };
+
+                    if let Some(members) = module.get(name) {
+                        self.enums.insert(local.to_id(), members);
+                    }
+                }
+                ImportSpecifier::Namespace(ImportStarAsSpecifier { local, .. }) => {
+                    self.namespaces.insert(local.to_id(), module);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn get_members(&self, obj: &Expr) -> Option<&'a ConstEnumMembers> {
+        match obj {
+            Expr::Ident(ident) => self.enums.get(&ident.to_id()).copied(),
+            Expr::Member(MemberExpr { obj, prop, .. }) => {
+                let Expr::Ident(ns) = &**obj else {
+                    return None;
+                };
+
+                self.namespaces
+                    .get(&ns.to_id())?
+                    .get(&get_member_key(prop)?)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for InlineImportedConstEnum<'_> {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module(&mut self, n: &mut Module) {
+        for item in &n.body {
+            if let ModuleItem::ModuleDecl(ModuleDecl::Import(import)) = item {
+                self.collect_import(import);
+            }
+        }
+
+        if self.enums.is_empty() && self.namespaces.is_empty() {
+            return;
+        }
+
+        n.visit_mut_children_with(self);
+    }
+
+    fn visit_mut_script(&mut self, _: &mut Script) {
+        // Scripts can't import enums.
+    }
+
+    fn visit_mut_expr(&mut self, n: &mut Expr) {
+        if let Expr::Member(MemberExpr { obj, prop, .. }) = n {
+            let value = self
+                .get_members(obj)
+                .zip(get_member_key(prop))
+                .and_then(|(members, member_name)| members.get(&member_name));
+
+            if let Some(value) = value {
+                *n = match value {
+                    ConstEnumValue::Number(num) => TsEnumRecordValue::Number((*num).into()),
+                    ConstEnumValue::String(string) => TsEnumRecordValue::String(string.clone()),
+                }
+                .into();
+                return;
+            }
+        }
+
+        maybe_grow_default(|| n.visit_mut_children_with(self));
+    }
+}
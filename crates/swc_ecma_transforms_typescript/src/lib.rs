@@ -4,7 +4,9 @@
 
 pub use self::{strip_type::*, typescript::*};
 mod config;
+mod const_enum;
 mod macros;
+mod merged_namespace;
 mod strip_import_export;
 mod strip_type;
 mod transform;
@@ -0,0 +1,291 @@
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::*;
+use swc_ecma_utils::{collect_decls, find_pat_ids, ExprFactory, QueryRef, RefRewriter};
+use swc_ecma_visit::{
+    noop_visit_mut_type, noop_visit_type, Visit, VisitMut, VisitMutWith, VisitWith,
+};
+
+/// Path of a namespace, like `A.B` for `namespace A.B {}`.
+///
+/// Only namespaces declared in the same scope are merged, so the root is an
+/// [Id].
+type NamespacePath = (Id, Vec<Atom>);
+
+/// Qualifies references to members exported by other declarations of a merged
+/// namespace.
+///
+/// Input:
+/// ```TypeScript
+/// namespace A {
+///     export const x = 1;
+/// }
+/// namespace A {
+///     export const y = x;
+/// }
+/// ```
+///
+/// Output:
+/// ```TypeScript
+/// namespace A {
+///     export const x = 1;
+/// }
+/// namespace A {
+///     export const y = A.x;
+/// }
+/// ```
+///
+/// A local binding of the same name shadows the member, like in `tsc`.
+pub(crate) fn qualify_merged_namespace_refs(n: &mut Program) {
+    let mut collector = ExportCollector::default();
+    n.visit_with(&mut collector);
+
+    // Members of a namespace declared once are handled by `Transform`.
+    let exports: FxHashMap<_, _> = collector
+        .exports
+        .into_iter()
+        .filter(|(_, exports)| exports.declarations > 1 && !exports.names.is_empty())
+        .map(|(path, exports)| (path, exports.names))
+        .collect();
+
+    if exports.is_empty() {
+        return;
+    }
+
+    n.visit_mut_with(&mut Qualifier {
+        exports,
+        current: None,
+    });
+}
+
+fn namespace_path(parent: Option<&NamespacePath>, id: &Ident, is_export: bool) -> NamespacePath {
+    match parent {
+        Some((root, names)) if is_export => {
+            let mut names = names.clone();
+            names.push(id.sym.clone());
+            (root.clone(), names)
+        }
+        _ => (id.to_id(), Vec::new()),
+    }
+}
+
+fn exported_names(block: &TsModuleBlock) -> impl Iterator<Item = Atom> + '_ {
+    block.body.iter().flat_map(|item| -> Vec<Atom> {
+        match item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => match decl {
+                Decl::Class(ClassDecl { ident, .. }) | Decl::Fn(FnDecl { ident, .. }) => {
+                    vec![ident.sym.clone()]
+                }
+                Decl::TsEnum(decl) => vec![decl.id.sym.clone()],
+                Decl::TsModule(decl) => match &decl.id {
+                    TsModuleName::Ident(ident) => vec![ident.sym.clone()],
+                    TsModuleName::Str(..) => Vec::new(),
+                },
+                Decl::Var(var_decl) => find_pat_ids::<_, Ident>(&var_decl.decls)
+                    .into_iter()
+                    .map(|ident| ident.sym)
+                    .collect(),
+                _ => Vec::new(),
+            },
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(decl)) if decl.is_export => {
+                vec![decl.id.sym.clone()]
+            }
+            _ => Vec::new(),
+        }
+    })
+}
+
+#[derive(Default)]
+struct NamespaceExports {
+    declarations: usize,
+    names: FxHashSet<Atom>,
+}
+
+#[derive(Default)]
+struct ExportCollector {
+    exports: FxHashMap<NamespacePath, NamespaceExports>,
+    path: Option<NamespacePath>,
+    is_export: bool,
+}
+
+impl ExportCollector {
+    fn visit_namespace_body(&mut self, path: NamespacePath, body: &TsNamespaceBody) {
+        let parent = self.path.replace(path);
+        body.visit_with(self);
+        self.path = parent;
+    }
+}
+
+impl Visit for ExportCollector {
+    noop_visit_type!();
+
+    fn visit_export_decl(&mut self, n: &ExportDecl) {
+        self.is_export = true;
+        n.visit_children_with(self);
+        self.is_export = false;
+    }
+
+    fn visit_stmt(&mut self, n: &Stmt) {
+        self.is_export = false;
+        n.visit_children_with(self);
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        let (TsModuleName::Ident(id), Some(body)) = (&n.id, &n.body) else {
+            return;
+        };
+
+        let path = namespace_path(self.path.as_ref(), id, self.is_export);
+        self.visit_namespace_body(path, body);
+    }
+
+    fn visit_ts_namespace_decl(&mut self, n: &TsNamespaceDecl) {
+        let path = namespace_path(self.path.as_ref(), &n.id, true);
+        self.visit_namespace_body(path, &n.body);
+    }
+
+    fn visit_ts_module_block(&mut self, n: &TsModuleBlock) {
+        if let Some(path) = &self.path {
+            let exports = self.exports.entry(path.clone()).or_default();
+            exports.declarations += 1;
+            exports.names.extend(exported_names(n));
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+struct Qualifier {
+    exports: FxHashMap<NamespacePath, FxHashSet<Atom>>,
+    /// The namespace whose body is being visited.
+    current: Option<(NamespacePath, Ident)>,
+}
+
+impl Qualifier {
+    fn visit_mut_namespace_body(
+        &mut self,
+        id: &Ident,
+        is_export: bool,
+        body: &mut TsNamespaceBody,
+    ) {
+        let path = namespace_path(self.current.as_ref().map(|(path, _)| path), id, is_export);
+        let parent = self.current.replace((path, id.clone()));
+        body.visit_mut_with(self);
+        self.current = parent;
+    }
+}
+
+impl VisitMut for Qualifier {
+    noop_visit_mut_type!();
+
+    fn visit_mut_module_item(&mut self, n: &mut ModuleItem) {
+        match n {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl {
+                decl: Decl::TsModule(decl),
+                ..
+            })) => {
+                if let (TsModuleName::Ident(id), Some(body)) = (&decl.id, &mut decl.body) {
+                    self.visit_mut_namespace_body(id, true, body);
+                }
+            }
+            _ => n.visit_mut_children_with(self),
+        }
+    }
+
+    fn visit_mut_ts_module_decl(&mut self, n: &mut TsModuleDecl) {
+        if let (TsModuleName::Ident(id), Some(body)) = (&n.id, &mut n.body) {
+            self.visit_mut_namespace_body(id, false, body);
+        }
+    }
+
+    fn visit_mut_ts_namespace_decl(&mut self, n: &mut TsNamespaceDecl) {
+        self.visit_mut_namespace_body(&n.id, true, &mut n.body);
+    }
+
+    fn visit_mut_ts_module_block(&mut self, n: &mut TsModuleBlock) {
+        // Nested namespaces are handled first, as their members shadow the
+        // members of this namespace.
+        n.visit_mut_children_with(self);
+
+        let Some((path, namespace)) = &self.current else {
+            return;
+        };
+        let Some(exports) = self.exports.get(path) else {
+            return;
+        };
+
+        let mut decls: FxHashSet<Id> = collect_decls(&*n);
+        n.visit_with(&mut TsDeclCollector(&mut decls));
+
+        n.visit_mut_with(&mut RefRewriter {
+            query: MergedExportQuery {
+                namespace,
+                exports,
+                decls: &decls,
+            },
+        });
+    }
+}
+
+/// Collects the TypeScript declarations which aren't collected by
+/// [collect_decls].
+struct TsDeclCollector<'a>(&'a mut FxHashSet<Id>);
+
+impl Visit for TsDeclCollector<'_> {
+    noop_visit_type!();
+
+    fn visit_ts_enum_decl(&mut self, n: &TsEnumDecl) {
+        self.0.insert(n.id.to_id());
+    }
+
+    fn visit_ts_import_equals_decl(&mut self, n: &TsImportEqualsDecl) {
+        self.0.insert(n.id.to_id());
+    }
+
+    fn visit_ts_module_decl(&mut self, n: &TsModuleDecl) {
+        if let TsModuleName::Ident(id) = &n.id {
+            self.0.insert(id.to_id());
+        }
+
+        n.visit_children_with(self);
+    }
+}
+
+struct MergedExportQuery<'a> {
+    namespace: &'a Ident,
+    exports: &'a FxHashSet<Atom>,
+    decls: &'a FxHashSet<Id>,
+}
+
+impl QueryRef for MergedExportQuery<'_> {
+    fn query_ref(&self, ident: &Ident) -> Option<Box<Expr>> {
+        if !self.exports.contains(&ident.sym) || self.decls.contains(&ident.to_id()) {
+            return None;
+        }
+
+        Some(
+            self.namespace
+                .clone()
+                .make_member(ident.clone().into())
+                .into(),
+        )
+    }
+
+    fn query_lhs(&self, ident: &Ident) -> Option<Box<Expr>> {
+        self.query_ref(ident)
+    }
+
+    fn query_jsx(&self, ident: &Ident) -> Option<JSXElementName> {
+        self.query_ref(ident)?;
+
+        Some(
+            JSXMemberExpr {
+                span: DUMMY_SP,
+                obj: JSXObject::Ident(self.namespace.clone()),
+                prop: ident.clone().into(),
+            }
+            .into(),
+        )
+    }
+}
@@ -18,6 +18,7 @@ use swc_ecma_visit::{
 
 use crate::{
     config::TsImportExportAssignConfig,
+    merged_namespace::qualify_merged_namespace_refs,
     ts_enum::{EnumValueComputer, TsEnumRecord, TsEnumRecordKey, TsEnumRecordValue},
     utils::{assign_value_to_this_private_prop, assign_value_to_this_prop, Factory},
 };
@@ -211,6 +212,8 @@ impl VisitMut for Transform {
     noop_visit_mut_type!();
 
     fn visit_mut_program(&mut self, node: &mut Program) {
+        qualify_merged_namespace_refs(node);
+
         node.visit_with(self);
 
         if !self.exported_binding.is_empty() {
@@ -995,9 +998,10 @@ impl Transform {
                                 }
                                 .into()
                             } else {
-                                // const foo = bar.baz
+                                // var foo = bar.baz
+                                // tsc emits `var`, which can be referenced before the alias.
                                 let mut var_decl =
-                                    init.into_var_decl(VarDeclKind::Const, decl.id.clone().into());
+                                    init.into_var_decl(VarDeclKind::Var, decl.id.clone().into());
 
                                 var_decl.span = decl.span;
 
@@ -1523,7 +1527,7 @@ fn get_enum_id(e: &Expr) -> Option<Id> {
     }
 }
 
-fn get_member_key(prop: &MemberProp) -> Option<Atom> {
+pub(crate) fn get_member_key(prop: &MemberProp) -> Option<Atom> {
     match prop {
         MemberProp::Ident(ident) => Some(ident.sym.clone()),
         MemberProp::Computed(ComputedPropName { expr, .. }) => match &**expr {
@@ -3,11 +3,15 @@ use std::mem;
 use rustc_hash::FxHashSet;
 use swc_common::{comments::Comments, sync::Lrc, util::take::Take, Mark, SourceMap, Span, Spanned};
 use swc_ecma_ast::*;
+use swc_ecma_transforms_base::syntax_features::SyntaxFeatures;
 use swc_ecma_transforms_react::{parse_expr_for_jsx, JsxDirectives};
 use swc_ecma_visit::{visit_mut_pass, VisitMut, VisitMutWith};
 
 pub use crate::config::*;
-use crate::{strip_import_export::StripImportExport, strip_type::StripType, transform::transform};
+use crate::{
+    const_enum::InlineImportedConstEnum, strip_import_export::StripImportExport,
+    strip_type::StripType, transform::transform,
+};
 
 #[cfg(feature = "concurrent")]
 macro_rules! static_str {
@@ -52,6 +56,12 @@ impl VisitMut for TypeScript {
     fn visit_mut_program(&mut self, n: &mut Program) {
         let was_module = n.as_module().and_then(|m| self.get_last_module_span(m));
 
+        if !self.config.const_enum_values.is_empty() {
+            n.visit_mut_with(&mut InlineImportedConstEnum::new(
+                &self.config.const_enum_values,
+            ));
+        }
+
         if !self.config.verbatim_module_syntax {
             n.visit_mut_with(&mut StripImportExport {
                 import_not_used_as_values: self.config.import_not_used_as_values,
@@ -60,7 +70,13 @@ impl VisitMut for TypeScript {
             });
         }
 
-        n.visit_mut_with(&mut StripType::default());
+        if SyntaxFeatures::TypeScript.is_used_by(n) {
+            n.visit_mut_with(&mut StripType::default());
+        } else if let Program::Module(m) = n {
+            // `StripType` also removes empty statements from module items.
+            m.body
+                .retain(|item| !matches!(item, ModuleItem::Stmt(Stmt::Empty(..))));
+        }
 
         n.mutate(transform(
             self.unresolved_mark,
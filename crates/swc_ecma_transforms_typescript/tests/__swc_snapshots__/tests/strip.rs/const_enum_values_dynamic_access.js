@@ -0,0 +1,8 @@
+import { Direction } from "./enums";
+function f(key, Color) {
+    return [
+        0,
+        Direction[key],
+        Color.Red
+    ];
+}
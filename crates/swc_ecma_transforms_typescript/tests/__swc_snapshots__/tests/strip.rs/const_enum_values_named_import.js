@@ -0,0 +1,2 @@
+import { other } from "./other";
+console.log(0, -1, "red", other);
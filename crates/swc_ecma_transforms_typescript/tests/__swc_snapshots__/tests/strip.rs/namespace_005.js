@@ -6,7 +6,7 @@
 (function(B) {
     B.a = A;
     console.log(B.a.Test);
-    const b = A;
+    var b = A;
     console.log(b.Test);
 })(B || (B = {}));
 var A, B;
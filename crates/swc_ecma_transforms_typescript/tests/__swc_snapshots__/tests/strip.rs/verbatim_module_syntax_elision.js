@@ -0,0 +1,7 @@
+import { b } from "bcd";
+import "xyz";
+import Unused, * as ns from "unused";
+const Foo = require("foo");
+export { v } from "uv";
+const v2 = 1;
+export { v2 };
@@ -0,0 +1,57 @@
+namespace A {
+    export const x = 1;
+}
+namespace A {
+    export const y = x;
+    export function f() {
+        return y;
+    }
+}
+
+function g() {}
+namespace g {
+    export const z = 1;
+}
+
+class C {}
+namespace C {
+    export const w = 1;
+}
+
+enum E { A }
+namespace E {
+    export const v = 1;
+}
+
+namespace M.N {
+    export const a = 1;
+}
+namespace M.N {
+    export const b = a;
+}
+
+namespace Other {
+    export import X = A.x;
+    import Y = A.y;
+    export const z = X + Y;
+    import Unused = A.f;
+}
+
+export namespace Exported {
+    export const a = 1;
+}
+export namespace Exported {
+    export const b = a;
+}
+
+namespace A {
+    export function h(x: number) {
+        return x + y;
+    }
+    namespace Inner {
+        export const y = 2;
+    }
+    namespace Inner {
+        console.log(x, y);
+    }
+}
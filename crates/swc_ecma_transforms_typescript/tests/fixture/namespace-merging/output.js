@@ -0,0 +1,62 @@
+(function(A) {
+    A.x = 1;
+})(A || (A = {}));
+(function(A) {
+    A.y = A.x;
+    function f() {
+        return A.y;
+    }
+    A.f = f;
+})(A || (A = {}));
+function g() {}
+(function(g) {
+    g.z = 1;
+})(g || (g = {}));
+class C {
+}
+(function(C) {
+    C.w = 1;
+})(C || (C = {}));
+var E = /*#__PURE__*/ function(E) {
+    E[E["A"] = 0] = "A";
+    return E;
+}(E || {});
+(function(E) {
+    E.v = 1;
+})(E || (E = {}));
+(function(M) {
+    (function(N) {
+        N.a = 1;
+    })(M.N || (M.N = {}));
+})(M || (M = {}));
+(function(M) {
+    (function(N) {
+        N.b = N.a;
+    })(M.N || (M.N = {}));
+})(M || (M = {}));
+(function(Other) {
+    Other.X = A.x;
+    var Y = A.y;
+    Other.z = Other.X + Y;
+})(Other || (Other = {}));
+(function(Exported) {
+    Exported.a = 1;
+})(Exported || (Exported = {}));
+(function(Exported) {
+    Exported.b = Exported.a;
+})(Exported || (Exported = {}));
+(function(A) {
+    function h(x) {
+        return x + A.y;
+    }
+    A.h = h;
+    (function(Inner) {
+        Inner.y = 2;
+    })(Inner || (Inner = {}));
+    (function(Inner) {
+        console.log(A.x, Inner.y);
+    })(Inner || (Inner = {}));
+    var Inner;
+})(A || (A = {}));
+var A, M, Other;
+export var Exported;
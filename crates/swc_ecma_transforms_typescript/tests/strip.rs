@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 
+use rustc_hash::FxHashMap;
 use swc_common::{comments::NoopComments, pass::Optional, Mark};
 use swc_ecma_ast::Pass;
 use swc_ecma_parser::{Syntax, TsSyntax};
@@ -15,7 +16,7 @@ use swc_ecma_transforms_proposal::decorators;
 use swc_ecma_transforms_react::jsx;
 use swc_ecma_transforms_testing::{test, test_exec, test_fixture, Tester};
 use swc_ecma_transforms_typescript::{
-    tsx, typescript, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
+    tsx, typescript, ConstEnumValue, ImportsNotUsedAsValues, TsImportExportAssignConfig, TsxConfig,
 };
 
 fn tr(t: &mut Tester) -> impl Pass {
@@ -147,7 +148,7 @@ test!(
                 },
                 unresolved_mark,
             ),
-            destructuring(destructuring::Config { loose: false }),
+            destructuring(destructuring::Config::default()),
             block_scoping(unresolved_mark),
         )
     },
@@ -2833,3 +2834,97 @@ test!(
     ts_jsx_bad_pragma,
     r#"/** @jsx bad-pragma */"#
 );
+
+fn const_enum_values_config() -> typescript::Config {
+    let members = |values: &[(&str, ConstEnumValue)]| {
+        values
+            .iter()
+            .map(|(name, value)| ((*name).into(), value.clone()))
+            .collect()
+    };
+
+    let mut enums = FxHashMap::default();
+    enums.insert(
+        "Direction".into(),
+        members(&[
+            ("Up", ConstEnumValue::Number(0.0)),
+            ("Down", ConstEnumValue::Number(-1.0)),
+        ]),
+    );
+    enums.insert(
+        "Color".into(),
+        members(&[("Red", ConstEnumValue::String("red".into()))]),
+    );
+
+    let mut const_enum_values = FxHashMap::default();
+    const_enum_values.insert("./enums".into(), enums);
+
+    typescript::Config {
+        no_empty_export: true,
+        const_enum_values,
+        ..Default::default()
+    }
+}
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(t, Some(const_enum_values_config()), None, false),
+    const_enum_values_named_import,
+    r#"
+    import { Direction, Color as C } from "./enums";
+    import { other } from "./other";
+    console.log(Direction.Up, Direction["Down"], C.Red, other);
+    "#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(t, Some(const_enum_values_config()), None, false),
+    const_enum_values_namespace_import,
+    r#"
+    import * as enums from "./enums";
+    console.log(enums.Direction.Down, enums.Color.Red);
+    "#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(t, Some(const_enum_values_config()), None, false),
+    const_enum_values_dynamic_access,
+    r#"
+    import { Direction } from "./enums";
+    function f(key: string, Color: any) {
+        return [Direction.Up, Direction[key], Color.Red];
+    }
+    "#
+);
+
+test!(
+    Syntax::Typescript(TsSyntax::default()),
+    |t| tr_config(
+        t,
+        Some(typescript::Config {
+            verbatim_module_syntax: true,
+            no_empty_export: true,
+            ..Default::default()
+        }),
+        None,
+        false,
+    ),
+    verbatim_module_syntax_elision,
+    r#"
+    import type { A } from "a";
+    import { b, type c, type d } from "bcd";
+    import { type xyz } from "xyz";
+    import Unused, * as ns from "unused";
+    import type * as types from "types";
+    import Foo = require("foo");
+    import type Bar = require("bar");
+    export type { T } from "t";
+    export { type U, v } from "uv";
+    export type * from "all";
+    const v2 = 1;
+    type W = string;
+    export { type W, v2 };
+    "#
+);
@@ -86,7 +86,10 @@ fn identity(entry: PathBuf) {
                 decorators: true,
                 dts: false,
                 no_early_errors: false,
+                preserve_parens: false,
                 disallow_ambiguous_jsx_like: false,
+                ts_version: None,
+                warn_import_assertions: false,
             }),
             (&*src).into(),
             None,
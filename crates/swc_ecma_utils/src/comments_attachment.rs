@@ -0,0 +1,334 @@
+//! Attaches comments to AST nodes, like babel does.
+//!
+//! The parser stores comments by the position of the nearest token, which is
+//! not enough for tools that want to know which node a comment belongs to.
+//! [CommentsAttachment] resolves each comment to the node it precedes
+//! (leading), follows (trailing), or is the only content of (dangling).
+
+use rustc_hash::FxHashMap;
+use swc_common::{
+    comments::{Comment, SingleThreadedComments},
+    BytePos, Span, Spanned,
+};
+use swc_ecma_ast::*;
+use swc_ecma_visit::{Visit, VisitWith};
+
+/// Where a comment is placed relative to the node it is attached to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommentPosition {
+    /// The comment is placed before the node.
+    Leading,
+    /// The comment is placed after the node.
+    Trailing,
+    /// The comment is placed inside of a node which has no children around
+    /// it, like `{ /* comment */ }`.
+    Dangling,
+}
+
+#[derive(Debug, Clone)]
+pub struct AttachedComment {
+    pub comment: Comment,
+    pub position: CommentPosition,
+    /// Span of the node the comment is attached to.
+    pub node: Span,
+}
+
+/// Comments of a program, attached to the nodes they belong to.
+///
+/// Nodes are identified by their span. If several nested nodes share a span,
+/// like a statement and its expression, the comment is reported for that
+/// span once.
+#[derive(Debug, Clone, Default)]
+pub struct CommentsAttachment {
+    /// Sorted by the position of the comment.
+    comments: Vec<AttachedComment>,
+    by_node: FxHashMap<Span, Vec<usize>>,
+}
+
+impl CommentsAttachment {
+    pub fn new(program: &Program, comments: &SingleThreadedComments) -> Self {
+        let tree = SpanTree::new(program);
+
+        let (leading, trailing) = comments.borrow_all();
+        let mut found = leading
+            .iter()
+            .flat_map(|(pos, cmts)| cmts.iter().map(move |c| (Stored::Leading(*pos), c)))
+            .chain(
+                trailing
+                    .iter()
+                    .flat_map(|(pos, cmts)| cmts.iter().map(move |c| (Stored::Trailing(*pos), c))),
+            )
+            .collect::<Vec<_>>();
+        found.sort_by_key(|(_, c)| c.span.lo);
+
+        let mut attachment = CommentsAttachment::default();
+        for (stored, comment) in found {
+            let (node, position) = tree.attach(comment.span, stored);
+
+            attachment
+                .by_node
+                .entry(node)
+                .or_default()
+                .push(attachment.comments.len());
+            attachment.comments.push(AttachedComment {
+                comment: comment.clone(),
+                position,
+                node,
+            });
+        }
+
+        attachment
+    }
+
+    /// All comments, in source order.
+    pub fn comments(&self) -> &[AttachedComment] {
+        &self.comments
+    }
+
+    /// Comments attached to the node with `span`, in source order.
+    pub fn attached_to(&self, span: Span) -> impl '_ + Iterator<Item = &AttachedComment> {
+        self.by_node
+            .get(&span)
+            .into_iter()
+            .flatten()
+            .map(|&idx| &self.comments[idx])
+    }
+
+    pub fn leading(&self, span: Span) -> impl '_ + Iterator<Item = &Comment> {
+        self.with_position(span, CommentPosition::Leading)
+    }
+
+    pub fn trailing(&self, span: Span) -> impl '_ + Iterator<Item = &Comment> {
+        self.with_position(span, CommentPosition::Trailing)
+    }
+
+    pub fn dangling(&self, span: Span) -> impl '_ + Iterator<Item = &Comment> {
+        self.with_position(span, CommentPosition::Dangling)
+    }
+
+    fn with_position(
+        &self,
+        span: Span,
+        position: CommentPosition,
+    ) -> impl '_ + Iterator<Item = &Comment> {
+        self.attached_to(span)
+            .filter(move |c| c.position == position)
+            .map(|c| &c.comment)
+    }
+}
+
+/// Where the parser stored a comment.
+#[derive(Clone, Copy)]
+enum Stored {
+    /// Before the token starting at the position.
+    Leading(BytePos),
+    /// After the token ending at the position.
+    Trailing(BytePos),
+}
+
+struct SpanNode {
+    span: Span,
+    /// Sorted by position. Children never overlap each other.
+    children: Vec<usize>,
+}
+
+/// Spans of the nodes of a program, nested by containment.
+struct SpanTree {
+    nodes: Vec<SpanNode>,
+}
+
+impl SpanTree {
+    fn new(program: &Program) -> Self {
+        let mut collector = SpanCollector::default();
+        // The program itself is the root, so comments before the first
+        // statement are attached to the statement.
+        match program {
+            Program::Module(m) => m.body.visit_with(&mut collector),
+            Program::Script(s) => s.body.visit_with(&mut collector),
+        }
+
+        let mut spans = collector.spans;
+        spans.sort_by_key(|s| (s.lo, std::cmp::Reverse(s.hi)));
+        spans.dedup();
+
+        // Comments outside of any node are dangling in the program.
+        let mut nodes = vec![SpanNode {
+            span: program.span(),
+            children: Default::default(),
+        }];
+        let mut stack = vec![0];
+
+        for span in spans {
+            while let Some(&top) = stack.last() {
+                let parent = nodes[top].span;
+                if top == 0 || (parent.lo <= span.lo && span.hi <= parent.hi) {
+                    break;
+                }
+                stack.pop();
+            }
+
+            let parent = *stack.last().unwrap();
+            // Skip spans that partially overlap a sibling, which can only
+            // happen for malformed spans.
+            if let Some(&prev) = nodes[parent].children.last() {
+                if nodes[prev].span.hi > span.lo {
+                    continue;
+                }
+            }
+
+            let idx = nodes.len();
+            nodes.push(SpanNode {
+                span,
+                children: Default::default(),
+            });
+            nodes[parent].children.push(idx);
+            stack.push(idx);
+        }
+
+        SpanTree { nodes }
+    }
+
+    fn attach(&self, comment: Span, stored: Stored) -> (Span, CommentPosition) {
+        let mut enclosing = 0;
+        loop {
+            let children = &self.nodes[enclosing].children;
+            let idx = children.partition_point(|&c| self.nodes[c].span.hi <= comment.lo);
+            match children.get(idx) {
+                Some(&c)
+                    if self.nodes[c].span.lo <= comment.lo
+                        && comment.hi <= self.nodes[c].span.hi =>
+                {
+                    enclosing = c;
+                }
+                _ => break,
+            }
+        }
+
+        let children = &self.nodes[enclosing].children;
+        let idx = children.partition_point(|&c| self.nodes[c].span.hi <= comment.lo);
+        let preceding = idx.checked_sub(1).map(|i| self.nodes[children[i]].span);
+        let following = children.get(idx).map(|&c| self.nodes[c].span);
+
+        match (stored, preceding, following) {
+            // `a /* c */`
+            (Stored::Trailing(pos), Some(preceding), _) if preceding.hi == pos => {
+                (preceding, CommentPosition::Trailing)
+            }
+            (Stored::Leading(pos), _, Some(following)) if following.lo == pos => {
+                (following, CommentPosition::Leading)
+            }
+            // There's a token like `(` or `,` between the comment and the node.
+            (_, _, Some(following)) => (following, CommentPosition::Leading),
+            (_, Some(preceding), None) => (preceding, CommentPosition::Trailing),
+            (_, None, None) => (self.nodes[enclosing].span, CommentPosition::Dangling),
+        }
+    }
+}
+
+#[derive(Default)]
+struct SpanCollector {
+    spans: Vec<Span>,
+}
+
+impl Visit for SpanCollector {
+    fn visit_span(&mut self, span: &Span) {
+        if !span.is_dummy() && span.lo < span.hi {
+            self.spans.push(*span);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::{comments::Comments, input::StringInput};
+    use swc_ecma_parser::{Parser, Syntax};
+
+    use super::*;
+
+    fn attach(src: &str) -> (Program, CommentsAttachment) {
+        let comments = SingleThreadedComments::default();
+        let mut p = Parser::new(
+            Syntax::Typescript(Default::default()),
+            StringInput::new(src, BytePos(0), BytePos(src.len() as u32)),
+            Some(&comments as &dyn Comments),
+        );
+        let program = p.parse_program().unwrap();
+        let attachment = CommentsAttachment::new(&program, &comments);
+
+        (program, attachment)
+    }
+
+    fn texts<'a>(comments: impl Iterator<Item = &'a Comment>) -> Vec<&'a str> {
+        comments.map(|c| &*c.text).collect()
+    }
+
+    fn stmts(program: &Program) -> Vec<Span> {
+        match program {
+            Program::Module(m) => m.body.iter().map(|s| s.span()).collect(),
+            Program::Script(s) => s.body.iter().map(|s| s.span()).collect(),
+        }
+    }
+
+    #[test]
+    fn leading_and_trailing() {
+        let (program, attachment) = attach(
+            "
+            // @ts-ignore
+            foo(); // after foo
+            /* before bar */ bar();
+            ",
+        );
+        let stmts = stmts(&program);
+
+        assert_eq!(texts(attachment.leading(stmts[0])), [" @ts-ignore"]);
+        assert_eq!(texts(attachment.trailing(stmts[0])), [" after foo"]);
+        assert_eq!(texts(attachment.leading(stmts[1])), [" before bar "]);
+        assert_eq!(attachment.trailing(stmts[1]).count(), 0);
+    }
+
+    #[test]
+    fn dangling() {
+        let (program, attachment) = attach("function f() { /* empty */ }");
+        let body = match &program {
+            Program::Script(Script { body, .. }) => match &body[0] {
+                Stmt::Decl(Decl::Fn(f)) => f.function.body.as_ref().unwrap().span,
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        assert_eq!(texts(attachment.dangling(body)), [" empty "]);
+    }
+
+    #[test]
+    fn inside_call_arguments() {
+        let (program, attachment) =
+            attach("import(/* webpackChunkName: \"a\" */ './a', /* second */ b);");
+        let args = match &program {
+            Program::Script(Script { body, .. }) => match &body[0] {
+                Stmt::Expr(ExprStmt { expr, .. }) => match &**expr {
+                    Expr::Call(call) => call.args.iter().map(|a| a.span()).collect::<Vec<_>>(),
+                    _ => unreachable!(),
+                },
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        assert_eq!(
+            texts(attachment.leading(args[0])),
+            [" webpackChunkName: \"a\" "]
+        );
+        assert_eq!(texts(attachment.leading(args[1])), [" second "]);
+    }
+
+    #[test]
+    fn empty_program() {
+        let (program, attachment) = attach("// only a comment");
+
+        assert_eq!(
+            texts(attachment.dangling(program.span())),
+            [" only a comment"]
+        );
+    }
+}
@@ -42,10 +42,12 @@ use crate::ident::IdentLike;
 
 #[macro_use]
 mod macros;
+pub mod comments_attachment;
 pub mod constructor;
 mod factory;
 pub mod function;
 pub mod ident;
+pub mod module_summary;
 pub mod parallel;
 mod value;
 pub mod var;
@@ -1887,7 +1889,12 @@ impl ExprCtx {
             | Expr::TsSatisfies(TsSatisfiesExpr { expr, .. }) => {
                 self.extract_side_effects_to(to, *expr)
             }
-            Expr::OptChain(..) => to.push(Box::new(expr)),
+            Expr::OptChain(..) | Expr::Pipeline(..) | Expr::Match(..) | Expr::Do(..) => {
+                to.push(Box::new(expr))
+            }
+
+            // It's only valid in the body of a pipeline.
+            Expr::Topic(..) => {}
 
             Expr::Invalid(..) => unreachable!(),
         }
@@ -3301,8 +3308,14 @@ fn may_have_side_effects(expr: &Expr, ctx: ExprCtx) -> bool {
             }
         }
 
-        Expr::Lit(..) | Expr::This(..) | Expr::PrivateName(..) | Expr::TsConstAssertion(..) => {
-            false
+        Expr::Lit(..)
+        | Expr::This(..)
+        | Expr::PrivateName(..)
+        | Expr::TsConstAssertion(..)
+        | Expr::Topic(..) => false,
+
+        Expr::Pipeline(PipelineExpr { left, right, .. }) => {
+            left.may_have_side_effects(ctx) || right.may_have_side_effects(ctx)
         }
 
         Expr::Paren(e) => e.expr.may_have_side_effects(ctx),
@@ -3390,7 +3403,9 @@ fn may_have_side_effects(expr: &Expr, ctx: ExprCtx) -> bool {
         | Expr::Member(_)
         | Expr::SuperProp(_)
         | Expr::Update(_)
-        | Expr::Assign(_) => true,
+        | Expr::Assign(_)
+        | Expr::Match(_)
+        | Expr::Do(_) => true,
 
         Expr::OptChain(OptChainExpr { base, .. }) if matches!(&**base, OptChainBase::Member(_)) => {
             true
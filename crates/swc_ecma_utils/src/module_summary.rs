@@ -0,0 +1,532 @@
+//! Summary of imports and exports of a module.
+
+use rustc_hash::FxHashMap;
+use swc_atoms::Atom;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::*;
+
+use crate::find_pat_ids;
+
+/// Imports and exports of a module, returned by [summarize_module].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleSummary {
+    /// Exported names, in the order they appear.
+    pub exports: Vec<ExportedSymbol>,
+    /// Sources of `export * from 'src'`.
+    pub star_reexports: Vec<StarReExport>,
+    /// Shape of the default export, if any.
+    ///
+    /// The default export is also included in `exports` as `default`.
+    pub default_export: Option<DefaultExport>,
+    /// Bindings created by imports, in the order they appear.
+    pub imports: Vec<ImportedBinding>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportedSymbol {
+    pub name: Atom,
+    pub kind: SymbolKind,
+    /// [Some] if the symbol is exported from another module, including
+    /// `import { a } from 'src'; export { a }`.
+    pub reexport: Option<ReExport>,
+    pub span: Span,
+}
+
+/// Whether a symbol can be used as a value or a type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SymbolKind {
+    /// Variables and functions.
+    Value,
+    /// Interfaces, type aliases, namespaces without values and type-only
+    /// imports or exports.
+    Type,
+    /// Classes, enums, namespaces with values and merged declarations.
+    Both,
+    /// The symbol is declared in another module.
+    Unknown,
+}
+
+impl SymbolKind {
+    fn merge(self, other: SymbolKind) -> SymbolKind {
+        if self == other {
+            self
+        } else {
+            SymbolKind::Both
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReExport {
+    pub src: Atom,
+    pub imported: ImportedName,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StarReExport {
+    pub src: Atom,
+    pub type_only: bool,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefaultExport {
+    /// `export default function f() {}`
+    Fn(Option<Atom>),
+    /// `export default class C {}`
+    Class(Option<Atom>),
+    /// `export default interface I {}`
+    Interface(Atom),
+    /// `export default a` or `export { a as default }`
+    Ident(Atom),
+    /// `export { default } from 'src'`
+    ReExport(ReExport),
+    /// `export default` with an expression which is not an identifier.
+    Expr,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedBinding {
+    pub local: Atom,
+    pub src: Atom,
+    pub imported: ImportedName,
+    /// `true` for `import type`, `import { type a }` and `import type a =
+    /// require()`.
+    ///
+    /// Usages of the binding are not checked, so a value import used only as
+    /// a type is not type-only.
+    pub type_only: bool,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportedName {
+    Named(Atom),
+    /// `import a from 'src'`
+    Default,
+    /// `import * as ns from 'src'`, `export * as ns from 'src'` and `import ns
+    /// = require('src')`
+    Namespace,
+}
+
+/// Collects imports and exports of `module` in one pass over the top-level
+/// items.
+///
+/// Kinds of local symbols are inferred from their declarations, so a
+/// function and an interface with the same name are [SymbolKind::Both].
+pub fn summarize_module(module: &Module) -> ModuleSummary {
+    let mut summary = ModuleSummary::default();
+    let mut locals = FxHashMap::<Atom, SymbolKind>::default();
+    // Exported local bindings, which are resolved after all declarations are
+    // collected.
+    let mut local_exports = Vec::new();
+
+    for item in &module.body {
+        match item {
+            ModuleItem::Stmt(Stmt::Decl(decl)) => {
+                for (name, kind) in decl_symbols(decl) {
+                    add_local(&mut locals, name, kind);
+                }
+            }
+            ModuleItem::Stmt(_) => {}
+            ModuleItem::ModuleDecl(decl) => match decl {
+                ModuleDecl::Import(import) => {
+                    for specifier in &import.specifiers {
+                        let (local, imported, type_only) = match specifier {
+                            ImportSpecifier::Named(s) => (
+                                &s.local,
+                                ImportedName::Named(
+                                    s.imported
+                                        .as_ref()
+                                        .map_or(&s.local.sym, |v| v.atom())
+                                        .clone(),
+                                ),
+                                s.is_type_only,
+                            ),
+                            ImportSpecifier::Default(s) => (&s.local, ImportedName::Default, false),
+                            ImportSpecifier::Namespace(s) => {
+                                (&s.local, ImportedName::Namespace, false)
+                            }
+                        };
+
+                        summary.imports.push(ImportedBinding {
+                            local: local.sym.clone(),
+                            src: import.src.value.clone(),
+                            imported,
+                            type_only: import.type_only || type_only,
+                            span: specifier.span(),
+                        });
+                    }
+                }
+                ModuleDecl::ExportDecl(export) => {
+                    for (name, kind) in decl_symbols(&export.decl) {
+                        add_local(&mut locals, name.clone(), kind);
+                        local_exports.push((name.clone(), name, false, export.span));
+                    }
+                }
+                ModuleDecl::ExportNamed(export) => {
+                    for specifier in &export.specifiers {
+                        let (exported, orig, type_only) = match specifier {
+                            ExportSpecifier::Named(s) => (
+                                s.exported.as_ref().unwrap_or(&s.orig).atom().clone(),
+                                ImportedName::Named(s.orig.atom().clone()),
+                                s.is_type_only,
+                            ),
+                            ExportSpecifier::Default(s) => {
+                                (s.exported.sym.clone(), ImportedName::Default, false)
+                            }
+                            ExportSpecifier::Namespace(s) => {
+                                (s.name.atom().clone(), ImportedName::Namespace, false)
+                            }
+                        };
+                        let type_only = export.type_only || type_only;
+
+                        match (&export.src, orig) {
+                            (Some(src), imported) => {
+                                let reexport = ReExport {
+                                    src: src.value.clone(),
+                                    imported,
+                                };
+
+                                if exported == "default" {
+                                    summary.default_export =
+                                        Some(DefaultExport::ReExport(reexport.clone()));
+                                }
+
+                                summary.exports.push(ExportedSymbol {
+                                    name: exported,
+                                    kind: if type_only {
+                                        SymbolKind::Type
+                                    } else {
+                                        SymbolKind::Unknown
+                                    },
+                                    reexport: Some(reexport),
+                                    span: specifier.span(),
+                                });
+                            }
+                            (None, ImportedName::Named(orig)) => {
+                                local_exports.push((exported, orig, type_only, specifier.span()));
+                            }
+                            // `export v` and `export * as ns` require `from`.
+                            (None, _) => {}
+                        }
+                    }
+                }
+                ModuleDecl::ExportDefaultDecl(export) => {
+                    let (shape, kind) = match &export.decl {
+                        DefaultDecl::Class(c) => (
+                            DefaultExport::Class(c.ident.as_ref().map(|i| i.sym.clone())),
+                            SymbolKind::Both,
+                        ),
+                        DefaultDecl::Fn(f) => (
+                            DefaultExport::Fn(f.ident.as_ref().map(|i| i.sym.clone())),
+                            SymbolKind::Value,
+                        ),
+                        DefaultDecl::TsInterfaceDecl(i) => {
+                            (DefaultExport::Interface(i.id.sym.clone()), SymbolKind::Type)
+                        }
+                    };
+
+                    summary.default_export = Some(shape);
+                    summary.exports.push(ExportedSymbol {
+                        name: "default".into(),
+                        kind,
+                        reexport: None,
+                        span: export.span,
+                    });
+                }
+                ModuleDecl::ExportDefaultExpr(export) => match &*export.expr {
+                    Expr::Ident(i) => {
+                        local_exports.push(("default".into(), i.sym.clone(), false, export.span));
+                    }
+                    _ => {
+                        summary.default_export = Some(DefaultExport::Expr);
+                        summary.exports.push(ExportedSymbol {
+                            name: "default".into(),
+                            kind: SymbolKind::Value,
+                            reexport: None,
+                            span: export.span,
+                        });
+                    }
+                },
+                ModuleDecl::ExportAll(export) => {
+                    summary.star_reexports.push(StarReExport {
+                        src: export.src.value.clone(),
+                        type_only: export.type_only,
+                        span: export.span,
+                    });
+                }
+                ModuleDecl::TsImportEquals(import) => {
+                    match &import.module_ref {
+                        TsModuleRef::TsExternalModuleRef(r) => {
+                            summary.imports.push(ImportedBinding {
+                                local: import.id.sym.clone(),
+                                src: r.expr.value.clone(),
+                                imported: ImportedName::Namespace,
+                                type_only: import.is_type_only,
+                                span: import.span,
+                            });
+                        }
+                        // `import a = N.b` is an alias of a local symbol.
+                        TsModuleRef::TsEntityName(..) => {
+                            add_local(&mut locals, import.id.sym.clone(), SymbolKind::Both);
+                        }
+                    }
+
+                    if import.is_export {
+                        local_exports.push((
+                            import.id.sym.clone(),
+                            import.id.sym.clone(),
+                            import.is_type_only,
+                            import.span,
+                        ));
+                    }
+                }
+                ModuleDecl::TsExportAssignment(..) | ModuleDecl::TsNamespaceExport(..) => {}
+            },
+        }
+    }
+
+    let imports = summary
+        .imports
+        .iter()
+        .map(|import| (&import.local, import))
+        .collect::<FxHashMap<_, _>>();
+
+    let mut exports = Vec::with_capacity(local_exports.len());
+    for (name, local, type_only, span) in local_exports {
+        let (kind, reexport) = match (locals.get(&local), imports.get(&local)) {
+            (Some(&kind), _) => (kind, None),
+            (None, Some(import)) => (
+                if import.type_only {
+                    SymbolKind::Type
+                } else {
+                    SymbolKind::Unknown
+                },
+                Some(ReExport {
+                    src: import.src.clone(),
+                    imported: import.imported.clone(),
+                }),
+            ),
+            // A global variable.
+            (None, None) => (SymbolKind::Value, None),
+        };
+
+        if name == "default" {
+            summary.default_export = Some(match &reexport {
+                Some(reexport) => DefaultExport::ReExport(reexport.clone()),
+                None => DefaultExport::Ident(local),
+            });
+        }
+
+        exports.push(ExportedSymbol {
+            name,
+            kind: if type_only { SymbolKind::Type } else { kind },
+            reexport,
+            span,
+        });
+    }
+
+    summary.exports.extend(exports);
+    summary.exports.sort_by_key(|export| export.span.lo);
+
+    summary
+}
+
+fn add_local(locals: &mut FxHashMap<Atom, SymbolKind>, name: Atom, kind: SymbolKind) {
+    locals
+        .entry(name)
+        .and_modify(|v| *v = v.merge(kind))
+        .or_insert(kind);
+}
+
+fn decl_symbols(decl: &Decl) -> Vec<(Atom, SymbolKind)> {
+    match decl {
+        Decl::Class(c) => vec![(c.ident.sym.clone(), SymbolKind::Both)],
+        Decl::Fn(f) => vec![(f.ident.sym.clone(), SymbolKind::Value)],
+        Decl::Var(v) => find_pat_ids::<_, Ident>(&v.decls)
+            .into_iter()
+            .map(|i| (i.sym, SymbolKind::Value))
+            .collect(),
+        Decl::Using(v) => find_pat_ids::<_, Ident>(&v.decls)
+            .into_iter()
+            .map(|i| (i.sym, SymbolKind::Value))
+            .collect(),
+        Decl::TsInterface(i) => vec![(i.id.sym.clone(), SymbolKind::Type)],
+        Decl::TsTypeAlias(a) => vec![(a.id.sym.clone(), SymbolKind::Type)],
+        Decl::TsEnum(e) => vec![(e.id.sym.clone(), SymbolKind::Both)],
+        Decl::TsModule(m) => match &m.id {
+            TsModuleName::Ident(id) if !m.global => {
+                let kind = if m.body.as_ref().is_some_and(is_instantiated) {
+                    SymbolKind::Both
+                } else {
+                    SymbolKind::Type
+                };
+
+                vec![(id.sym.clone(), kind)]
+            }
+            _ => Vec::new(),
+        },
+    }
+}
+
+/// Returns `true` if the namespace has values, like TypeScript does.
+fn is_instantiated(body: &TsNamespaceBody) -> bool {
+    match body {
+        TsNamespaceBody::TsModuleBlock(block) => block.body.iter().any(|item| match item {
+            ModuleItem::Stmt(Stmt::Decl(decl))
+            | ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                decl_symbols(decl)
+                    .iter()
+                    .any(|(_, kind)| *kind != SymbolKind::Type)
+            }
+            ModuleItem::ModuleDecl(ModuleDecl::TsImportEquals(import)) => !import.is_type_only,
+            ModuleItem::ModuleDecl(ModuleDecl::Import(..) | ModuleDecl::ExportNamed(..)) => false,
+            _ => true,
+        }),
+        TsNamespaceBody::TsNamespaceDecl(decl) => is_instantiated(&decl.body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use swc_common::{input::StringInput, BytePos};
+    use swc_ecma_parser::{Parser, Syntax};
+
+    use super::*;
+
+    fn summarize(src: &str) -> ModuleSummary {
+        let mut p = Parser::new(
+            Syntax::Typescript(Default::default()),
+            StringInput::new(src, BytePos(0), BytePos(src.len() as u32)),
+            None,
+        );
+
+        summarize_module(&p.parse_module().unwrap())
+    }
+
+    fn exports(summary: &ModuleSummary) -> Vec<(&str, SymbolKind, Option<&str>)> {
+        summary
+            .exports
+            .iter()
+            .map(|e| (&*e.name, e.kind, e.reexport.as_ref().map(|r| &*r.src)))
+            .collect()
+    }
+
+    #[test]
+    fn local_exports() {
+        let summary = summarize(
+            "
+            export { a, I as J, F };
+            const a = 1;
+            interface I {}
+            function F() {}
+            interface F {}
+            export class C {}
+            export const enum E {}
+            export namespace T { export type X = 1; }
+            export namespace V { export const x = 1; }
+            export type { a as b };
+            export default I;
+            ",
+        );
+
+        assert_eq!(
+            exports(&summary),
+            vec![
+                ("a", SymbolKind::Value, None),
+                ("J", SymbolKind::Type, None),
+                ("F", SymbolKind::Both, None),
+                ("C", SymbolKind::Both, None),
+                ("E", SymbolKind::Both, None),
+                ("T", SymbolKind::Type, None),
+                ("V", SymbolKind::Both, None),
+                ("b", SymbolKind::Type, None),
+                ("default", SymbolKind::Type, None),
+            ]
+        );
+        assert_eq!(
+            summary.default_export,
+            Some(DefaultExport::Ident("I".into()))
+        );
+    }
+
+    #[test]
+    fn reexports_and_imports() {
+        let summary = summarize(
+            "
+            import a, { b, type c, d as e } from './a';
+            import type * as ns from './ns';
+            import f = require('./f');
+            export { b, c };
+            export { x, type y, default } from './x';
+            export * from './all';
+            export type * as types from './types';
+            ",
+        );
+
+        assert_eq!(
+            exports(&summary),
+            vec![
+                ("b", SymbolKind::Unknown, Some("./a")),
+                ("c", SymbolKind::Type, Some("./a")),
+                ("x", SymbolKind::Unknown, Some("./x")),
+                ("y", SymbolKind::Type, Some("./x")),
+                ("default", SymbolKind::Unknown, Some("./x")),
+                ("types", SymbolKind::Type, Some("./types")),
+            ]
+        );
+        assert_eq!(
+            summary.default_export,
+            Some(DefaultExport::ReExport(ReExport {
+                src: "./x".into(),
+                imported: ImportedName::Named("default".into()),
+            }))
+        );
+        assert_eq!(
+            summary
+                .star_reexports
+                .iter()
+                .map(|e| (&*e.src, e.type_only))
+                .collect::<Vec<_>>(),
+            vec![("./all", false)]
+        );
+
+        assert_eq!(
+            summary
+                .imports
+                .iter()
+                .map(|i| (&*i.local, &*i.src, i.imported.clone(), i.type_only))
+                .collect::<Vec<_>>(),
+            vec![
+                ("a", "./a", ImportedName::Default, false),
+                ("b", "./a", ImportedName::Named("b".into()), false),
+                ("c", "./a", ImportedName::Named("c".into()), true),
+                ("e", "./a", ImportedName::Named("d".into()), false),
+                ("ns", "./ns", ImportedName::Namespace, true),
+                ("f", "./f", ImportedName::Namespace, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn default_export_shape() {
+        assert_eq!(
+            summarize("export default function () {}").default_export,
+            Some(DefaultExport::Fn(None))
+        );
+        assert_eq!(
+            summarize("export default class C {}").default_export,
+            Some(DefaultExport::Class(Some("C".into())))
+        );
+        assert_eq!(
+            summarize("export default interface I {}").default_export,
+            Some(DefaultExport::Interface("I".into()))
+        );
+        assert_eq!(
+            summarize("export default 1 + 1").default_export,
+            Some(DefaultExport::Expr)
+        );
+        assert_eq!(summarize("export {}").default_export, None);
+    }
+}
@@ -19,6 +19,13 @@ pub trait Visit {
     fn visit_array_lit(&mut self, node: &ArrayLit) {
         <ArrayLit as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        <ArrayMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -137,6 +144,13 @@ pub trait Visit {
     fn visit_binding_ident(&mut self, node: &BindingIdent) {
         <BindingIdent as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        <BindingMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -232,6 +246,13 @@ pub trait Visit {
     fn visit_class_prop(&mut self, node: &ClassProp) {
         <ClassProp as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        <CombinedMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_children_with`]. If you want to recurse, you need to call \
              it manually."]
@@ -294,6 +315,12 @@ pub trait Visit {
     fn visit_default_decl(&mut self, node: &DefaultDecl) {
         <DefaultDecl as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_children_with`]. If you want to recurse, you need to call it manually."]
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        <DoExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -748,6 +775,55 @@ pub trait Visit {
     fn visit_lit(&mut self, node: &Lit) {
         <Lit as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        <MatchCase as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        <[MatchCase] as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        <MatchExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        <MatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        <MatchPatCombinator as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        <[MatchPat] as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        <MatchRest as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -838,6 +914,13 @@ pub trait Visit {
     fn visit_new_expr(&mut self, node: &NewExpr) {
         <NewExpr as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        <NotMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -857,6 +940,27 @@ pub trait Visit {
     fn visit_object_lit(&mut self, node: &ObjectLit) {
         <ObjectLit as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        <ObjectMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        <ObjectMatchPatProp as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        <[ObjectMatchPatProp] as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -968,6 +1072,20 @@ pub trait Visit {
     fn visit_opt_jsx_closing_element(&mut self, node: &Option<JSXClosingElement>) {
         <Option<JSXClosingElement> as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_children_with`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        <Option<Box<MatchPat>> as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        <Option<MatchRest> as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_children_with`]. If you want to recurse, you \
              need to call it manually."]
@@ -1134,6 +1252,13 @@ pub trait Visit {
     fn visit_pats(&mut self, node: &[Pat]) {
         <[Pat] as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <PipelineExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -1195,6 +1320,13 @@ pub trait Visit {
     fn visit_regex(&mut self, node: &Regex) {
         <Regex as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        <RelationalMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -1344,6 +1476,13 @@ pub trait Visit {
     fn visit_throw_stmt(&mut self, node: &ThrowStmt) {
         <ThrowStmt as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        <TopicExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -1971,6 +2110,13 @@ pub trait Visit {
     fn visit_using_decl(&mut self, node: &UsingDecl) {
         <UsingDecl as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        <ValueMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -2042,6 +2188,11 @@ where
         <V as Visit>::visit_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        <V as Visit>::visit_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         <V as Visit>::visit_array_pat(&mut **self, node)
@@ -2127,6 +2278,11 @@ where
         <V as Visit>::visit_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        <V as Visit>::visit_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         <V as Visit>::visit_block_stmt(&mut **self, node)
@@ -2197,6 +2353,11 @@ where
         <V as Visit>::visit_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        <V as Visit>::visit_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         <V as Visit>::visit_computed_prop_name(&mut **self, node)
@@ -2242,6 +2403,11 @@ where
         <V as Visit>::visit_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        <V as Visit>::visit_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         <V as Visit>::visit_do_while_stmt(&mut **self, node)
@@ -2572,6 +2738,41 @@ where
         <V as Visit>::visit_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        <V as Visit>::visit_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        <V as Visit>::visit_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        <V as Visit>::visit_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        <V as Visit>::visit_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        <V as Visit>::visit_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        <V as Visit>::visit_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        <V as Visit>::visit_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         <V as Visit>::visit_member_expr(&mut **self, node)
@@ -2637,6 +2838,11 @@ where
         <V as Visit>::visit_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        <V as Visit>::visit_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         <V as Visit>::visit_null(&mut **self, node)
@@ -2652,6 +2858,21 @@ where
         <V as Visit>::visit_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        <V as Visit>::visit_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        <V as Visit>::visit_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        <V as Visit>::visit_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         <V as Visit>::visit_object_pat(&mut **self, node)
@@ -2732,6 +2953,16 @@ where
         <V as Visit>::visit_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        <V as Visit>::visit_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        <V as Visit>::visit_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         <V as Visit>::visit_opt_module_export_name(&mut **self, node)
@@ -2855,6 +3086,11 @@ where
         <V as Visit>::visit_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <V as Visit>::visit_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         <V as Visit>::visit_private_method(&mut **self, node)
@@ -2900,6 +3136,11 @@ where
         <V as Visit>::visit_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        <V as Visit>::visit_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         <V as Visit>::visit_rest_pat(&mut **self, node)
@@ -3010,6 +3251,11 @@ where
         <V as Visit>::visit_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        <V as Visit>::visit_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         <V as Visit>::visit_tpl(&mut **self, node)
@@ -3460,6 +3706,11 @@ where
         <V as Visit>::visit_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        <V as Visit>::visit_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         <V as Visit>::visit_var_decl(&mut **self, node)
@@ -3514,6 +3765,11 @@ where
         <V as Visit>::visit_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        <V as Visit>::visit_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         <V as Visit>::visit_array_pat(&mut **self, node)
@@ -3599,6 +3855,11 @@ where
         <V as Visit>::visit_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        <V as Visit>::visit_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         <V as Visit>::visit_block_stmt(&mut **self, node)
@@ -3669,6 +3930,11 @@ where
         <V as Visit>::visit_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        <V as Visit>::visit_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         <V as Visit>::visit_computed_prop_name(&mut **self, node)
@@ -3714,6 +3980,11 @@ where
         <V as Visit>::visit_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        <V as Visit>::visit_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         <V as Visit>::visit_do_while_stmt(&mut **self, node)
@@ -4044,6 +4315,41 @@ where
         <V as Visit>::visit_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        <V as Visit>::visit_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        <V as Visit>::visit_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        <V as Visit>::visit_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        <V as Visit>::visit_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        <V as Visit>::visit_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        <V as Visit>::visit_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        <V as Visit>::visit_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         <V as Visit>::visit_member_expr(&mut **self, node)
@@ -4109,6 +4415,11 @@ where
         <V as Visit>::visit_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        <V as Visit>::visit_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         <V as Visit>::visit_null(&mut **self, node)
@@ -4124,6 +4435,21 @@ where
         <V as Visit>::visit_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        <V as Visit>::visit_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        <V as Visit>::visit_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        <V as Visit>::visit_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         <V as Visit>::visit_object_pat(&mut **self, node)
@@ -4204,6 +4530,16 @@ where
         <V as Visit>::visit_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        <V as Visit>::visit_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        <V as Visit>::visit_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         <V as Visit>::visit_opt_module_export_name(&mut **self, node)
@@ -4327,6 +4663,11 @@ where
         <V as Visit>::visit_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <V as Visit>::visit_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         <V as Visit>::visit_private_method(&mut **self, node)
@@ -4372,6 +4713,11 @@ where
         <V as Visit>::visit_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        <V as Visit>::visit_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         <V as Visit>::visit_rest_pat(&mut **self, node)
@@ -4482,6 +4828,11 @@ where
         <V as Visit>::visit_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        <V as Visit>::visit_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         <V as Visit>::visit_tpl(&mut **self, node)
@@ -4932,6 +5283,11 @@ where
         <V as Visit>::visit_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        <V as Visit>::visit_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         <V as Visit>::visit_var_decl(&mut **self, node)
@@ -4993,6 +5349,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_array_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_array_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         match self {
@@ -5129,6 +5493,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_binding_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_binding_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         match self {
@@ -5241,6 +5613,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_combined_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_combined_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         match self {
@@ -5313,6 +5693,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_do_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_do_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         match self {
@@ -5857,6 +6245,62 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_case(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_case(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_cases(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_cases(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_expr(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_pat_combinator(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_pat_combinator(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_pats(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_pats(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         match self {
@@ -5961,6 +6405,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_not_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_not_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         match self {
@@ -5985,6 +6437,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_object_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_object_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_object_match_pat_prop(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_object_match_pat_prop(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_object_match_pat_props(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_object_match_pat_props(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         match self {
@@ -6115,6 +6591,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_opt_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_opt_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_opt_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_opt_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         match self {
@@ -6322,6 +6814,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         match self {
@@ -6394,6 +6894,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_relational_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_relational_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         match self {
@@ -6570,6 +7078,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_topic_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_topic_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         match self {
@@ -7312,6 +7828,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_value_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_value_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         match self {
@@ -7396,6 +7920,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_array_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         if self.enabled {
@@ -7532,6 +8064,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_binding_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         if self.enabled {
@@ -7644,6 +8184,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_combined_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         if self.enabled {
@@ -7716,6 +8264,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        if self.enabled {
+            <V as Visit>::visit_do_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         if self.enabled {
@@ -8244,6 +8800,62 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        if self.enabled {
+            <V as Visit>::visit_match_case(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        if self.enabled {
+            <V as Visit>::visit_match_cases(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        if self.enabled {
+            <V as Visit>::visit_match_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        if self.enabled {
+            <V as Visit>::visit_match_pat_combinator(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        if self.enabled {
+            <V as Visit>::visit_match_pats(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        if self.enabled {
+            <V as Visit>::visit_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         if self.enabled {
@@ -8348,6 +8960,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_not_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         if self.enabled {
@@ -8372,6 +8992,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_object_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        if self.enabled {
+            <V as Visit>::visit_object_match_pat_prop(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        if self.enabled {
+            <V as Visit>::visit_object_match_pat_props(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         if self.enabled {
@@ -8500,6 +9144,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        if self.enabled {
+            <V as Visit>::visit_opt_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        if self.enabled {
+            <V as Visit>::visit_opt_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         if self.enabled {
@@ -8695,6 +9355,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        if self.enabled {
+            <V as Visit>::visit_pipeline_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         if self.enabled {
@@ -8767,6 +9435,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_relational_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         if self.enabled {
@@ -8943,6 +9619,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        if self.enabled {
+            <V as Visit>::visit_topic_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         if self.enabled {
@@ -9663,6 +10347,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_value_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         if self.enabled {
@@ -9767,6 +10459,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for ArrayLit {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for ArrayMatchPat {
+    #[doc = "Calls [Visit`::visit_array_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_array_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitWith<V>>::visit_with(elems, visitor)
+                };
+                {
+                    <Option<MatchRest> as VisitWith<V>>::visit_with(rest, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for ArrayPat {
     #[doc = "Calls [Visit`::visit_array_pat`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10162,6 +10876,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for BindingIdent {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for BindingMatchPat {
+    #[doc = "Calls [Visit`::visit_binding_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_binding_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <VarDeclKind as VisitWith<V>>::visit_with(kind, visitor)
+                };
+                {
+                    <BindingIdent as VisitWith<V>>::visit_with(id, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for BlockStmt {
     #[doc = "Calls [Visit`::visit_block_stmt`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10527,6 +11263,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for ClassProp {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for CombinedMatchPat {
+    #[doc = "Calls [Visit`::visit_combined_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_combined_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <MatchPatCombinator as VisitWith<V>>::visit_with(op, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitWith<V>>::visit_with(pats, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for ComputedPropName {
     #[doc = "Calls [Visit`::visit_computed_prop_name`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10724,6 +11482,25 @@ impl<V: ?Sized + Visit> VisitWith<V> for DefaultDecl {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for DoExpr {
+    #[doc = "Calls [Visit`::visit_do_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_do_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <BlockStmt as VisitWith<V>>::visit_with(body, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for DoWhileStmt {
     #[doc = "Calls [Visit`::visit_do_while_stmt`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -11047,6 +11824,18 @@ impl<V: ?Sized + Visit> VisitWith<V> for Expr {
             Expr::OptChain { 0: _field_0 } => {
                 <OptChainExpr as VisitWith<V>>::visit_with(_field_0, visitor);
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                <PipelineExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::Topic { 0: _field_0 } => {
+                <TopicExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::Match { 0: _field_0 } => {
+                <MatchExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::Do { 0: _field_0 } => {
+                <DoExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
             Expr::Invalid { 0: _field_0 } => {
                 <Invalid as VisitWith<V>>::visit_with(_field_0, visitor);
             }
@@ -12137,6 +12926,126 @@ impl<V: ?Sized + Visit> VisitWith<V> for Lit {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for MatchCase {
+    #[doc = "Calls [Visit`::visit_match_case`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_case(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitWith<V>>::visit_with(pat, visitor)
+                };
+                {
+                    <Option<Box<Expr>> as VisitWith<V>>::visit_with(guard, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(body, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchExpr {
+    #[doc = "Calls [Visit`::visit_match_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(subject, visitor)
+                };
+                {
+                    <Vec<MatchCase> as VisitWith<V>>::visit_with(cases, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchPat {
+    #[doc = "Calls [Visit`::visit_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                <BindingMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                <ValueMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                <RelationalMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                <ArrayMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                <ObjectMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                <CombinedMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                <NotMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchPatCombinator {
+    #[doc = "Calls [Visit`::visit_match_pat_combinator`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_pat_combinator(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchRest {
+    #[doc = "Calls [Visit`::visit_match_rest`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_rest(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitWith<V>>::visit_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for MemberExpr {
     #[doc = "Calls [Visit`::visit_member_expr`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -12409,6 +13318,25 @@ impl<V: ?Sized + Visit> VisitWith<V> for NewExpr {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for NotMatchPat {
+    #[doc = "Calls [Visit`::visit_not_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_not_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<MatchPat> as VisitWith<V>>::visit_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Null {
     #[doc = "Calls [Visit`::visit_null`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -12463,6 +13391,47 @@ impl<V: ?Sized + Visit> VisitWith<V> for ObjectLit {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for ObjectMatchPat {
+    #[doc = "Calls [Visit`::visit_object_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_object_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Vec<ObjectMatchPatProp> as VisitWith<V>>::visit_with(props, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for ObjectMatchPatProp {
+    #[doc = "Calls [Visit`::visit_object_match_pat_prop`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_object_match_pat_prop(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <PropName as VisitWith<V>>::visit_with(key, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitWith<V>>::visit_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for ObjectPat {
     #[doc = "Calls [Visit`::visit_object_pat`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -12680,6 +13649,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for PipelineExpr {
+    #[doc = "Calls [Visit`::visit_pipeline_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_pipeline_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(left, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(right, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for PrivateMethod {
     #[doc = "Calls [Visit`::visit_private_method`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -12895,6 +13886,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for Regex {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for RelationalMatchPat {
+    #[doc = "Calls [Visit`::visit_relational_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_relational_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <BinaryOp as VisitWith<V>>::visit_with(op, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for RestPat {
     #[doc = "Calls [Visit`::visit_rest_pat`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -13370,6 +14383,22 @@ impl<V: ?Sized + Visit> VisitWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for TopicExpr {
+    #[doc = "Calls [Visit`::visit_topic_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_topic_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Tpl {
     #[doc = "Calls [Visit`::visit_tpl`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -15341,6 +16370,25 @@ impl<V: ?Sized + Visit> VisitWith<V> for UsingDecl {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for ValueMatchPat {
+    #[doc = "Calls [Visit`::visit_value_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_value_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for VarDecl {
     #[doc = "Calls [Visit`::visit_var_decl`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -15638,6 +16686,32 @@ impl<V: ?Sized + Visit> VisitWith<V> for [JSXElementChild] {
             .for_each(|item| <JSXElementChild as VisitWith<V>>::visit_with(item, visitor))
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for [MatchCase] {
+    #[doc = "Calls [Visit`::visit_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_cases(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.iter()
+            .for_each(|item| <MatchCase as VisitWith<V>>::visit_with(item, visitor))
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for [MatchPat] {
+    #[doc = "Calls [Visit`::visit_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_pats(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.iter()
+            .for_each(|item| <MatchPat as VisitWith<V>>::visit_with(item, visitor))
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for [ModuleItem] {
     #[doc = "Calls [Visit`::visit_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -15651,6 +16725,19 @@ impl<V: ?Sized + Visit> VisitWith<V> for [ModuleItem] {
             .for_each(|item| <ModuleItem as VisitWith<V>>::visit_with(item, visitor))
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for [ObjectMatchPatProp] {
+    #[doc = "Calls [Visit`::visit_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_object_match_pat_props(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.iter()
+            .for_each(|item| <ObjectMatchPatProp as VisitWith<V>>::visit_with(item, visitor))
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for [ObjectPatProp] {
     #[doc = "Calls [Visit`::visit_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -15814,6 +16901,36 @@ impl<V: ?Sized + Visit> VisitWith<V> for Option<JSXClosingElement> {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [Visit`::visit_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_opt_match_pat(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitWith<V>>::visit_with(inner, visitor),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for Option<MatchRest> {
+    #[doc = "Calls [Visit`::visit_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_opt_match_rest(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            Some(inner) => <MatchRest as VisitWith<V>>::visit_with(inner, visitor),
+            None => {}
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Option<ModuleExportName> {
     #[doc = "Calls [Visit`::visit_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -16348,6 +17465,19 @@ pub trait VisitAstPath {
     ) {
         <ArrayLit as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ArrayMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -16549,6 +17679,19 @@ pub trait VisitAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <BindingMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -16701,6 +17844,19 @@ pub trait VisitAstPath {
     ) {
         <ClassProp as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <CombinedMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -16808,6 +17964,17 @@ pub trait VisitAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <DoExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -17592,6 +18759,87 @@ pub trait VisitAstPath {
     fn visit_lit<'ast: 'r, 'r>(&mut self, node: &'ast Lit, __ast_path: &mut AstNodePath<'r>) {
         <Lit as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchCase as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <[MatchCase] as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchPatCombinator as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <[MatchPat] as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchRest as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -17741,6 +18989,19 @@ pub trait VisitAstPath {
     ) {
         <NewExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <NotMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -17766,6 +19027,45 @@ pub trait VisitAstPath {
     ) {
         <ObjectLit as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ObjectMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ObjectMatchPatProp as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <[ObjectMatchPatProp] as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -17970,6 +19270,32 @@ pub trait VisitAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_children_with_ast_path`]. If you want to \
+             recurse, you need to call it manually."]
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <Option<Box<MatchPat>> as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <Option<MatchRest> as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_children_with_ast_path`]. If you want to \
              recurse, you need to call it manually."]
@@ -18259,6 +19585,19 @@ pub trait VisitAstPath {
     fn visit_pats<'ast: 'r, 'r>(&mut self, node: &'ast [Pat], __ast_path: &mut AstNodePath<'r>) {
         <[Pat] as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <PipelineExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -18360,6 +19699,19 @@ pub trait VisitAstPath {
     fn visit_regex<'ast: 'r, 'r>(&mut self, node: &'ast Regex, __ast_path: &mut AstNodePath<'r>) {
         <Regex as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <RelationalMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -18596,6 +19948,17 @@ pub trait VisitAstPath {
     ) {
         <ThrowStmt as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <TopicExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -19718,6 +21081,19 @@ pub trait VisitAstPath {
     ) {
         <UsingDecl as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ValueMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -19839,6 +21215,15 @@ where
         <V as VisitAstPath>::visit_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -19992,6 +21377,15 @@ where
         <V as VisitAstPath>::visit_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -20106,6 +21500,15 @@ where
         <V as VisitAstPath>::visit_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -20183,6 +21586,15 @@ where
         <V as VisitAstPath>::visit_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -20757,6 +22169,69 @@ where
         <V as VisitAstPath>::visit_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -20870,6 +22345,15 @@ where
         <V as VisitAstPath>::visit_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_null(&mut **self, node, __ast_path)
@@ -20889,6 +22373,33 @@ where
         <V as VisitAstPath>::visit_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -21033,6 +22544,24 @@ where
         <V as VisitAstPath>::visit_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -21237,6 +22766,15 @@ where
         <V as VisitAstPath>::visit_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -21310,6 +22848,15 @@ where
         <V as VisitAstPath>::visit_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -21488,6 +23035,15 @@ where
         <V as VisitAstPath>::visit_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_tpl(&mut **self, node, __ast_path)
@@ -22290,6 +23846,15 @@ where
         <V as VisitAstPath>::visit_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -22386,6 +23951,15 @@ where
         <V as VisitAstPath>::visit_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -22539,6 +24113,15 @@ where
         <V as VisitAstPath>::visit_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -22653,6 +24236,15 @@ where
         <V as VisitAstPath>::visit_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -22730,6 +24322,15 @@ where
         <V as VisitAstPath>::visit_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -23304,6 +24905,69 @@ where
         <V as VisitAstPath>::visit_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -23417,6 +25081,15 @@ where
         <V as VisitAstPath>::visit_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_null(&mut **self, node, __ast_path)
@@ -23436,6 +25109,33 @@ where
         <V as VisitAstPath>::visit_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -23580,6 +25280,24 @@ where
         <V as VisitAstPath>::visit_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -23784,6 +25502,15 @@ where
         <V as VisitAstPath>::visit_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -23857,6 +25584,15 @@ where
         <V as VisitAstPath>::visit_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -24035,6 +25771,15 @@ where
         <V as VisitAstPath>::visit_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_tpl(&mut **self, node, __ast_path)
@@ -24837,6 +26582,15 @@ where
         <V as VisitAstPath>::visit_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -24948,6 +26702,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_array_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_array_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -25218,6 +26988,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_binding_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_binding_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -25428,6 +27214,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_combined_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_combined_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -25566,6 +27368,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_do_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_do_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -26592,6 +28410,118 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_case(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_case(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_cases(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_cases(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_pat_combinator(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_pat_combinator(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_pats(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_pats(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -26796,6 +28726,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_not_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_not_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         match self {
@@ -26834,6 +28780,54 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_object_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_object_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_object_match_pat_prop(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_object_match_pat_prop(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_object_match_pat_props(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_object_match_pat_props(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -27090,6 +29084,38 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_opt_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_opt_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_opt_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_opt_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -27456,6 +29482,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -27590,6 +29632,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_relational_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_relational_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -27914,6 +29972,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_topic_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_topic_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         match self {
@@ -29342,6 +31416,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_value_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_value_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -29500,6 +31590,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_array_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -29704,6 +31806,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_binding_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -29860,6 +31974,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_combined_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -29964,6 +32090,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_do_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -30740,6 +32878,90 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_case(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_cases(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_pat_combinator(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_pats(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -30892,6 +33114,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_not_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         if self.enabled {
@@ -30920,6 +33154,42 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_object_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_object_match_pat_prop(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_object_match_pat_props(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -31112,6 +33382,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_opt_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_opt_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -31396,6 +33690,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -31496,6 +33802,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_relational_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -31740,6 +34058,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_topic_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         if self.enabled {
@@ -32828,6 +35158,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_value_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -33012,6 +35354,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ArrayLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ArrayMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_array_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_array_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ArrayMatchPat(
+                        self,
+                        self::fields::ArrayMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ArrayMatchPat(
+                        self,
+                        self::fields::ArrayMatchPatField::Elems(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        elems,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ArrayMatchPat(
+                        self,
+                        self::fields::ArrayMatchPatField::Rest,
+                    ));
+                    <Option<MatchRest> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        rest,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ArrayPat {
     #[doc = "Calls [VisitAstPath`::visit_array_pat`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -33909,6 +36307,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for BindingIdent {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for BindingMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_binding_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_binding_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::BindingMatchPat(
+                        self,
+                        self::fields::BindingMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::BindingMatchPat(
+                        self,
+                        self::fields::BindingMatchPatField::Kind,
+                    ));
+                    <VarDeclKind as VisitWithAstPath<V>>::visit_with_ast_path(
+                        kind,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::BindingMatchPat(
+                        self,
+                        self::fields::BindingMatchPatField::Id,
+                    ));
+                    <BindingIdent as VisitWithAstPath<V>>::visit_with_ast_path(
+                        id,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for BlockStmt {
     #[doc = "Calls [VisitAstPath`::visit_block_stmt`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -34797,6 +37251,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ClassProp {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for CombinedMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_combined_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_combined_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::CombinedMatchPat(
+                        self,
+                        self::fields::CombinedMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::CombinedMatchPat(
+                        self,
+                        self::fields::CombinedMatchPatField::Op,
+                    ));
+                    <MatchPatCombinator as VisitWithAstPath<V>>::visit_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::CombinedMatchPat(
+                        self,
+                        self::fields::CombinedMatchPatField::Pats(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        pats,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ComputedPropName {
     #[doc = "Calls [VisitAstPath`::visit_computed_prop_name`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -35290,6 +37800,51 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DefaultDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DoExpr {
+    #[doc = "Calls [VisitAstPath`::visit_do_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_do_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::DoExpr(
+                        self,
+                        self::fields::DoExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::DoExpr(
+                        self,
+                        self::fields::DoExprField::Body,
+                    ));
+                    <BlockStmt as VisitWithAstPath<V>>::visit_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DoWhileStmt {
     #[doc = "Calls [VisitAstPath`::visit_do_while_stmt`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -36167,6 +38722,44 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Expr {
                     &mut *__ast_path,
                 );
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Expr(
+                    self,
+                    self::fields::ExprField::Pipeline,
+                ));
+                <PipelineExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentNodeRef::Expr(self, self::fields::ExprField::Topic));
+                <TopicExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Match { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentNodeRef::Expr(self, self::fields::ExprField::Match));
+                <MatchExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Do { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentNodeRef::Expr(self, self::fields::ExprField::Do));
+                <DoExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Expr(
                     self,
@@ -38819,6 +41412,304 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Lit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchCase {
+    #[doc = "Calls [VisitAstPath`::visit_match_case`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_case(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Pat,
+                    ));
+                    <Option<Box<MatchPat>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        pat,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Guard,
+                    ));
+                    <Option<Box<Expr>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        guard,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Body,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchExpr {
+    #[doc = "Calls [VisitAstPath`::visit_match_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchExpr(
+                        self,
+                        self::fields::MatchExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchExpr(
+                        self,
+                        self::fields::MatchExprField::Subject,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        subject,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchExpr(
+                        self,
+                        self::fields::MatchExprField::Cases(usize::MAX),
+                    ));
+                    <Vec<MatchCase> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        cases,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Binding,
+                ));
+                <BindingMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Value,
+                ));
+                <ValueMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Relational,
+                ));
+                <RelationalMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Array,
+                ));
+                <ArrayMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Object,
+                ));
+                <ObjectMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Combined,
+                ));
+                <CombinedMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Not,
+                ));
+                <NotMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchPatCombinator {
+    #[doc = "Calls [VisitAstPath`::visit_match_pat_combinator`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat_combinator(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchRest {
+    #[doc = "Calls [VisitAstPath`::visit_match_rest`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_rest(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchRest(
+                        self,
+                        self::fields::MatchRestField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchRest(
+                        self,
+                        self::fields::MatchRestField::Arg,
+                    ));
+                    <Option<Box<MatchPat>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MemberExpr {
     #[doc = "Calls [VisitAstPath`::visit_member_expr`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -39485,6 +42376,51 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for NewExpr {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for NotMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_not_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_not_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::NotMatchPat(
+                        self,
+                        self::fields::NotMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::NotMatchPat(
+                        self,
+                        self::fields::NotMatchPatField::Arg,
+                    ));
+                    <Box<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Null {
     #[doc = "Calls [VisitAstPath`::visit_null`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -39607,6 +42543,110 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_object_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ObjectMatchPat(
+                        self,
+                        self::fields::ObjectMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ObjectMatchPat(
+                        self,
+                        self::fields::ObjectMatchPatField::Props(usize::MAX),
+                    ));
+                    <Vec<ObjectMatchPatProp> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        props,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectMatchPatProp {
+    #[doc = "Calls [VisitAstPath`::visit_object_match_pat_prop`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_prop(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::ObjectMatchPatProp(
+                            self,
+                            self::fields::ObjectMatchPatPropField::Span,
+                        ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::ObjectMatchPatProp(
+                            self,
+                            self::fields::ObjectMatchPatPropField::Key,
+                        ));
+                    <PropName as VisitWithAstPath<V>>::visit_with_ast_path(
+                        key,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::ObjectMatchPatProp(
+                            self,
+                            self::fields::ObjectMatchPatPropField::Value,
+                        ));
+                    <Option<Box<MatchPat>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectPat {
     #[doc = "Calls [VisitAstPath`::visit_object_pat`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -40124,6 +43164,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [VisitAstPath`::visit_pipeline_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Left,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        left,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Right,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        right,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [VisitAstPath`::visit_private_method`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -40673,6 +43769,65 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Regex {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for RelationalMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_relational_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_relational_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::RelationalMatchPat(
+                            self,
+                            self::fields::RelationalMatchPatField::Span,
+                        ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::RelationalMatchPat(
+                            self,
+                            self::fields::RelationalMatchPatField::Op,
+                        ));
+                    <BinaryOp as VisitWithAstPath<V>>::visit_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::RelationalMatchPat(
+                            self,
+                            self::fields::RelationalMatchPatField::Value,
+                        ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for RestPat {
     #[doc = "Calls [VisitAstPath`::visit_rest_pat`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -41873,6 +45028,40 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for TopicExpr {
+    #[doc = "Calls [VisitAstPath`::visit_topic_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::TopicExpr(
+                        self,
+                        self::fields::TopicExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Tpl {
     #[doc = "Calls [VisitAstPath`::visit_tpl`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -46603,6 +49792,51 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for UsingDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ValueMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_value_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_value_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ValueMatchPat(
+                        self,
+                        self::fields::ValueMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ValueMatchPat(
+                        self,
+                        self::fields::ValueMatchPatField::Value,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for VarDecl {
     #[doc = "Calls [VisitAstPath`::visit_var_decl`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -47262,6 +50496,56 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [JSXElementChild] {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [MatchCase] {
+    #[doc = "Calls [VisitAstPath`::visit_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_cases(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        self.iter().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchCase as VisitWithAstPath<V>>::visit_with_ast_path(item, visitor, &mut *__ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [MatchPat] {
+    #[doc = "Calls [VisitAstPath`::visit_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pats(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        self.iter().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchPat as VisitWithAstPath<V>>::visit_with_ast_path(item, visitor, &mut *__ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ModuleItem] {
     #[doc = "Calls [VisitAstPath`::visit_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -47291,6 +50575,35 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ModuleItem] {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ObjectMatchPatProp] {
+    #[doc = "Calls [VisitAstPath`::visit_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_props(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        self.iter().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <ObjectMatchPatProp as VisitWithAstPath<V>>::visit_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ObjectPatProp] {
     #[doc = "Calls [VisitAstPath`::visit_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -47590,6 +50903,60 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<JSXClosingElement>
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [VisitAstPath`::visit_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_pat(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                inner, visitor, __ast_path,
+            ),
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<MatchRest> {
+    #[doc = "Calls [VisitAstPath`::visit_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_rest(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            Some(inner) => {
+                <MatchRest as VisitWithAstPath<V>>::visit_with_ast_path(inner, visitor, __ast_path)
+            }
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<ModuleExportName> {
     #[doc = "Calls [VisitAstPath`::visit_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -48589,6 +51956,13 @@ pub trait VisitMut {
     fn visit_mut_array_lit(&mut self, node: &mut ArrayLit) {
         <ArrayLit as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        <ArrayMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -48708,6 +52082,13 @@ pub trait VisitMut {
     fn visit_mut_binding_ident(&mut self, node: &mut BindingIdent) {
         <BindingIdent as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        <BindingMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -48806,6 +52187,13 @@ pub trait VisitMut {
     fn visit_mut_class_prop(&mut self, node: &mut ClassProp) {
         <ClassProp as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        <CombinedMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_mut_children_with`]. If you want to recurse, you need to \
              call it manually."]
@@ -48869,6 +52257,13 @@ pub trait VisitMut {
     fn visit_mut_default_decl(&mut self, node: &mut DefaultDecl) {
         <DefaultDecl as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        <DoExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -49331,6 +52726,55 @@ pub trait VisitMut {
     fn visit_mut_lit(&mut self, node: &mut Lit) {
         <Lit as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        <MatchCase as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        <Vec<MatchCase> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        <MatchExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        <MatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        <MatchPatCombinator as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        <Vec<MatchPat> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        <MatchRest as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -49422,6 +52866,13 @@ pub trait VisitMut {
     fn visit_mut_new_expr(&mut self, node: &mut NewExpr) {
         <NewExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        <NotMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -49443,6 +52894,27 @@ pub trait VisitMut {
     fn visit_mut_object_lit(&mut self, node: &mut ObjectLit) {
         <ObjectLit as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        <ObjectMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        <ObjectMatchPatProp as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_mut_children_with`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        <Vec<ObjectMatchPatProp> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -49555,6 +53027,20 @@ pub trait VisitMut {
     fn visit_mut_opt_jsx_closing_element(&mut self, node: &mut Option<JSXClosingElement>) {
         <Option<JSXClosingElement> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_mut_children_with`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        <Option<Box<MatchPat>> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_mut_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        <Option<MatchRest> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_mut_children_with`]. If you want to recurse, you \
              need to call it manually."]
@@ -49726,6 +53212,13 @@ pub trait VisitMut {
     fn visit_mut_pats(&mut self, node: &mut Vec<Pat>) {
         <Vec<Pat> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <PipelineExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_mut_children_with`]. If you want to recurse, you need to call \
              it manually."]
@@ -49789,6 +53282,13 @@ pub trait VisitMut {
     fn visit_mut_regex(&mut self, node: &mut Regex) {
         <Regex as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        <RelationalMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -49942,6 +53442,13 @@ pub trait VisitMut {
     fn visit_mut_throw_stmt(&mut self, node: &mut ThrowStmt) {
         <ThrowStmt as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        <TopicExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -50572,6 +54079,13 @@ pub trait VisitMut {
     fn visit_mut_using_decl(&mut self, node: &mut UsingDecl) {
         <UsingDecl as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        <ValueMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -50643,6 +54157,11 @@ where
         <V as VisitMut>::visit_mut_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        <V as VisitMut>::visit_mut_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         <V as VisitMut>::visit_mut_array_pat(&mut **self, node)
@@ -50728,6 +54247,11 @@ where
         <V as VisitMut>::visit_mut_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        <V as VisitMut>::visit_mut_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         <V as VisitMut>::visit_mut_block_stmt(&mut **self, node)
@@ -50798,6 +54322,11 @@ where
         <V as VisitMut>::visit_mut_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        <V as VisitMut>::visit_mut_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         <V as VisitMut>::visit_mut_computed_prop_name(&mut **self, node)
@@ -50843,6 +54372,11 @@ where
         <V as VisitMut>::visit_mut_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        <V as VisitMut>::visit_mut_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         <V as VisitMut>::visit_mut_do_while_stmt(&mut **self, node)
@@ -51173,6 +54707,41 @@ where
         <V as VisitMut>::visit_mut_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        <V as VisitMut>::visit_mut_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        <V as VisitMut>::visit_mut_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        <V as VisitMut>::visit_mut_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        <V as VisitMut>::visit_mut_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        <V as VisitMut>::visit_mut_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        <V as VisitMut>::visit_mut_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        <V as VisitMut>::visit_mut_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         <V as VisitMut>::visit_mut_member_expr(&mut **self, node)
@@ -51238,6 +54807,11 @@ where
         <V as VisitMut>::visit_mut_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        <V as VisitMut>::visit_mut_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         <V as VisitMut>::visit_mut_null(&mut **self, node)
@@ -51253,6 +54827,21 @@ where
         <V as VisitMut>::visit_mut_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        <V as VisitMut>::visit_mut_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        <V as VisitMut>::visit_mut_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        <V as VisitMut>::visit_mut_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         <V as VisitMut>::visit_mut_object_pat(&mut **self, node)
@@ -51333,6 +54922,16 @@ where
         <V as VisitMut>::visit_mut_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        <V as VisitMut>::visit_mut_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        <V as VisitMut>::visit_mut_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         <V as VisitMut>::visit_mut_opt_module_export_name(&mut **self, node)
@@ -51456,6 +55055,11 @@ where
         <V as VisitMut>::visit_mut_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <V as VisitMut>::visit_mut_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         <V as VisitMut>::visit_mut_private_method(&mut **self, node)
@@ -51501,6 +55105,11 @@ where
         <V as VisitMut>::visit_mut_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        <V as VisitMut>::visit_mut_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         <V as VisitMut>::visit_mut_rest_pat(&mut **self, node)
@@ -51611,6 +55220,11 @@ where
         <V as VisitMut>::visit_mut_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        <V as VisitMut>::visit_mut_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         <V as VisitMut>::visit_mut_tpl(&mut **self, node)
@@ -52061,6 +55675,11 @@ where
         <V as VisitMut>::visit_mut_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        <V as VisitMut>::visit_mut_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         <V as VisitMut>::visit_mut_var_decl(&mut **self, node)
@@ -52115,6 +55734,11 @@ where
         <V as VisitMut>::visit_mut_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        <V as VisitMut>::visit_mut_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         <V as VisitMut>::visit_mut_array_pat(&mut **self, node)
@@ -52200,6 +55824,11 @@ where
         <V as VisitMut>::visit_mut_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        <V as VisitMut>::visit_mut_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         <V as VisitMut>::visit_mut_block_stmt(&mut **self, node)
@@ -52270,6 +55899,11 @@ where
         <V as VisitMut>::visit_mut_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        <V as VisitMut>::visit_mut_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         <V as VisitMut>::visit_mut_computed_prop_name(&mut **self, node)
@@ -52315,6 +55949,11 @@ where
         <V as VisitMut>::visit_mut_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        <V as VisitMut>::visit_mut_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         <V as VisitMut>::visit_mut_do_while_stmt(&mut **self, node)
@@ -52645,6 +56284,41 @@ where
         <V as VisitMut>::visit_mut_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        <V as VisitMut>::visit_mut_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        <V as VisitMut>::visit_mut_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        <V as VisitMut>::visit_mut_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        <V as VisitMut>::visit_mut_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        <V as VisitMut>::visit_mut_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        <V as VisitMut>::visit_mut_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        <V as VisitMut>::visit_mut_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         <V as VisitMut>::visit_mut_member_expr(&mut **self, node)
@@ -52710,6 +56384,11 @@ where
         <V as VisitMut>::visit_mut_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        <V as VisitMut>::visit_mut_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         <V as VisitMut>::visit_mut_null(&mut **self, node)
@@ -52725,6 +56404,21 @@ where
         <V as VisitMut>::visit_mut_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        <V as VisitMut>::visit_mut_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        <V as VisitMut>::visit_mut_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        <V as VisitMut>::visit_mut_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         <V as VisitMut>::visit_mut_object_pat(&mut **self, node)
@@ -52805,6 +56499,16 @@ where
         <V as VisitMut>::visit_mut_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        <V as VisitMut>::visit_mut_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        <V as VisitMut>::visit_mut_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         <V as VisitMut>::visit_mut_opt_module_export_name(&mut **self, node)
@@ -52928,6 +56632,11 @@ where
         <V as VisitMut>::visit_mut_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <V as VisitMut>::visit_mut_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         <V as VisitMut>::visit_mut_private_method(&mut **self, node)
@@ -52973,6 +56682,11 @@ where
         <V as VisitMut>::visit_mut_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        <V as VisitMut>::visit_mut_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         <V as VisitMut>::visit_mut_rest_pat(&mut **self, node)
@@ -53083,6 +56797,11 @@ where
         <V as VisitMut>::visit_mut_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        <V as VisitMut>::visit_mut_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         <V as VisitMut>::visit_mut_tpl(&mut **self, node)
@@ -53533,6 +57252,11 @@ where
         <V as VisitMut>::visit_mut_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        <V as VisitMut>::visit_mut_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         <V as VisitMut>::visit_mut_var_decl(&mut **self, node)
@@ -53594,6 +57318,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_array_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_array_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         match self {
@@ -53734,6 +57466,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_binding_match_pat(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_binding_match_pat(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         match self {
@@ -53850,6 +57594,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_combined_match_pat(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_combined_match_pat(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         match self {
@@ -53926,6 +57682,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_do_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_do_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         match self {
@@ -54552,6 +58316,66 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_case(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_case(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_cases(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_cases(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_expr(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_match_pat_combinator(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_match_pat_combinator(visitor, node)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_pats(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_pats(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         match self {
@@ -54660,6 +58484,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_not_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_not_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         match self {
@@ -54684,6 +58516,40 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_object_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_object_match_pat(visitor, node)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_object_match_pat_prop(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_object_match_pat_prop(visitor, node)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_object_match_pat_props(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_object_match_pat_props(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         match self {
@@ -54836,6 +58702,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_opt_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_opt_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_opt_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_opt_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         match self {
@@ -55075,6 +58957,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         match self {
@@ -55147,6 +59037,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_relational_match_pat(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_relational_match_pat(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         match self {
@@ -55327,6 +59229,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_topic_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_topic_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         match self {
@@ -56219,6 +60129,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_value_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_value_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         match self {
@@ -56305,6 +60223,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_array_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         if self.enabled {
@@ -56441,6 +60367,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_binding_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         if self.enabled {
@@ -56553,6 +60487,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_combined_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         if self.enabled {
@@ -56625,6 +60567,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_do_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         if self.enabled {
@@ -57153,6 +61103,62 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_case(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_cases(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_pat_combinator(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_pats(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         if self.enabled {
@@ -57257,6 +61263,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_not_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         if self.enabled {
@@ -57281,6 +61295,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_object_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_object_match_pat_prop(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_object_match_pat_props(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         if self.enabled {
@@ -57409,6 +61447,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_opt_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_opt_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         if self.enabled {
@@ -57604,6 +61658,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_pipeline_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         if self.enabled {
@@ -57676,6 +61738,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_relational_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         if self.enabled {
@@ -57852,6 +61922,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_topic_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         if self.enabled {
@@ -58572,6 +62650,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_value_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         if self.enabled {
@@ -58676,6 +62762,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ArrayLit {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ArrayMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_array_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_array_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitMutWith<V>>::visit_mut_with(elems, visitor)
+                };
+                {
+                    <Option<MatchRest> as VisitMutWith<V>>::visit_mut_with(rest, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for ArrayPat {
     #[doc = "Calls [VisitMut`::visit_mut_array_pat`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -59080,6 +63188,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for BindingIdent {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for BindingMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_binding_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_binding_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <VarDeclKind as VisitMutWith<V>>::visit_mut_with(kind, visitor)
+                };
+                {
+                    <BindingIdent as VisitMutWith<V>>::visit_mut_with(id, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for BlockStmt {
     #[doc = "Calls [VisitMut`::visit_mut_block_stmt`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -59456,6 +63586,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ClassProp {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for CombinedMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_combined_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_combined_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <MatchPatCombinator as VisitMutWith<V>>::visit_mut_with(op, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitMutWith<V>>::visit_mut_with(pats, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for ComputedPropName {
     #[doc = "Calls [VisitMut`::visit_mut_computed_prop_name`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -59656,6 +63808,25 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for DefaultDecl {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for DoExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_do_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_do_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <BlockStmt as VisitMutWith<V>>::visit_mut_with(body, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for DoWhileStmt {
     #[doc = "Calls [VisitMut`::visit_mut_do_while_stmt`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -59979,6 +64150,18 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Expr {
             Expr::OptChain { 0: _field_0 } => {
                 <OptChainExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                <PipelineExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::Topic { 0: _field_0 } => {
+                <TopicExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::Match { 0: _field_0 } => {
+                <MatchExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::Do { 0: _field_0 } => {
+                <DoExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
             Expr::Invalid { 0: _field_0 } => {
                 <Invalid as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
             }
@@ -61075,6 +65258,126 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Lit {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchCase {
+    #[doc = "Calls [VisitMut`::visit_mut_match_case`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_case(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitMutWith<V>>::visit_mut_with(pat, visitor)
+                };
+                {
+                    <Option<Box<Expr>> as VisitMutWith<V>>::visit_mut_with(guard, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(body, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_match_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(subject, visitor)
+                };
+                {
+                    <Vec<MatchCase> as VisitMutWith<V>>::visit_mut_with(cases, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                <BindingMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                <ValueMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                <RelationalMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                <ArrayMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                <ObjectMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                <CombinedMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                <NotMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchPatCombinator {
+    #[doc = "Calls [VisitMut`::visit_mut_match_pat_combinator`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_pat_combinator(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchRest {
+    #[doc = "Calls [VisitMut`::visit_mut_match_rest`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_rest(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitMutWith<V>>::visit_mut_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for MemberExpr {
     #[doc = "Calls [VisitMut`::visit_mut_member_expr`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -61347,6 +65650,25 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for NewExpr {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for NotMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_not_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_not_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<MatchPat> as VisitMutWith<V>>::visit_mut_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Null {
     #[doc = "Calls [VisitMut`::visit_mut_null`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -61401,6 +65723,47 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectLit {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_object_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_object_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Vec<ObjectMatchPatProp> as VisitMutWith<V>>::visit_mut_with(props, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectMatchPatProp {
+    #[doc = "Calls [VisitMut`::visit_mut_object_match_pat_prop`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_object_match_pat_prop(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <PropName as VisitMutWith<V>>::visit_mut_with(key, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitMutWith<V>>::visit_mut_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectPat {
     #[doc = "Calls [VisitMut`::visit_mut_object_pat`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -61618,6 +65981,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for PipelineExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_pipeline_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_pipeline_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(left, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(right, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for PrivateMethod {
     #[doc = "Calls [VisitMut`::visit_mut_private_method`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -61839,6 +66224,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Regex {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for RelationalMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_relational_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_relational_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <BinaryOp as VisitMutWith<V>>::visit_mut_with(op, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for RestPat {
     #[doc = "Calls [VisitMut`::visit_mut_rest_pat`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -62314,6 +66721,22 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for TopicExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_topic_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_topic_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Tpl {
     #[doc = "Calls [VisitMut`::visit_mut_tpl`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -64313,6 +68736,25 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for UsingDecl {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ValueMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_value_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_value_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for VarDecl {
     #[doc = "Calls [VisitMut`::visit_mut_var_decl`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -64610,6 +69052,32 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<JSXElementChild> {
             .for_each(|item| <JSXElementChild as VisitMutWith<V>>::visit_mut_with(item, visitor))
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<MatchCase> {
+    #[doc = "Calls [VisitMut`::visit_mut_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_cases(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.iter_mut()
+            .for_each(|item| <MatchCase as VisitMutWith<V>>::visit_mut_with(item, visitor))
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<MatchPat> {
+    #[doc = "Calls [VisitMut`::visit_mut_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_pats(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.iter_mut()
+            .for_each(|item| <MatchPat as VisitMutWith<V>>::visit_mut_with(item, visitor))
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ModuleItem> {
     #[doc = "Calls [VisitMut`::visit_mut_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -64623,6 +69091,19 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ModuleItem> {
             .for_each(|item| <ModuleItem as VisitMutWith<V>>::visit_mut_with(item, visitor))
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [VisitMut`::visit_mut_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_object_match_pat_props(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.iter_mut()
+            .for_each(|item| <ObjectMatchPatProp as VisitMutWith<V>>::visit_mut_with(item, visitor))
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [VisitMut`::visit_mut_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -64786,6 +69267,36 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<JSXClosingElement> {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [VisitMut`::visit_mut_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_opt_match_pat(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitMutWith<V>>::visit_mut_with(inner, visitor),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<MatchRest> {
+    #[doc = "Calls [VisitMut`::visit_mut_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_opt_match_rest(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            Some(inner) => <MatchRest as VisitMutWith<V>>::visit_mut_with(inner, visitor),
+            None => {}
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<ModuleExportName> {
     #[doc = "Calls [VisitMut`::visit_mut_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -65301,6 +69812,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ArrayMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -65462,6 +69986,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <BindingMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -65596,6 +70133,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <CombinedMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_mut_children_with_ast_path`]. If you want to recurse, you \
              need to call it manually."]
@@ -65681,6 +70231,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        <DoExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -66387,6 +70946,73 @@ pub trait VisitMutAstPath {
     fn visit_mut_lit(&mut self, node: &mut Lit, __ast_path: &mut AstKindPath) {
         <Lit as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        <MatchCase as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        <Vec<MatchCase> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        <MatchExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        <MatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <MatchPatCombinator as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        <Vec<MatchPat> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        <MatchRest as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -66508,6 +71134,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        <NotMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_mut_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -66535,6 +71170,45 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ObjectMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ObjectMatchPatProp as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_mut_children_with_ast_path`]. If you want to \
+             recurse, you need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <Vec<ObjectMatchPatProp> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -66719,6 +71393,32 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_mut_children_with_ast_path`]. If you want to \
+             recurse, you need to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <Option<Box<MatchPat>> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_mut_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <Option<MatchRest> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_mut_children_with_ast_path`]. If you want to \
              recurse, you need to call it manually."]
@@ -66994,6 +71694,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <PipelineExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -67079,6 +71788,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <RelationalMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -67287,6 +72009,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        <TopicExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_mut_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -68303,6 +73034,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ValueMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -68400,6 +73144,15 @@ where
         <V as VisitMutAstPath>::visit_mut_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_array_pat(&mut **self, node, __ast_path)
@@ -68493,6 +73246,15 @@ where
         <V as VisitMutAstPath>::visit_mut_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_block_stmt(&mut **self, node, __ast_path)
@@ -68571,6 +73333,15 @@ where
         <V as VisitMutAstPath>::visit_mut_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -68620,6 +73391,11 @@ where
         <V as VisitMutAstPath>::visit_mut_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_do_while_stmt(&mut **self, node, __ast_path)
@@ -69066,6 +73842,45 @@ where
         <V as VisitMutAstPath>::visit_mut_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_member_expr(&mut **self, node, __ast_path)
@@ -69135,6 +73950,11 @@ where
         <V as VisitMutAstPath>::visit_mut_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_null(&mut **self, node, __ast_path)
@@ -69150,6 +73970,33 @@ where
         <V as VisitMutAstPath>::visit_mut_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_object_pat(&mut **self, node, __ast_path)
@@ -69270,6 +74117,24 @@ where
         <V as VisitMutAstPath>::visit_mut_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -69458,6 +74323,11 @@ where
         <V as VisitMutAstPath>::visit_mut_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_private_method(&mut **self, node, __ast_path)
@@ -69507,6 +74377,15 @@ where
         <V as VisitMutAstPath>::visit_mut_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_rest_pat(&mut **self, node, __ast_path)
@@ -69629,6 +74508,11 @@ where
         <V as VisitMutAstPath>::visit_mut_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_tpl(&mut **self, node, __ast_path)
@@ -70291,6 +75175,15 @@ where
         <V as VisitMutAstPath>::visit_mut_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_var_decl(&mut **self, node, __ast_path)
@@ -70355,6 +75248,15 @@ where
         <V as VisitMutAstPath>::visit_mut_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_array_pat(&mut **self, node, __ast_path)
@@ -70448,6 +75350,15 @@ where
         <V as VisitMutAstPath>::visit_mut_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_block_stmt(&mut **self, node, __ast_path)
@@ -70526,6 +75437,15 @@ where
         <V as VisitMutAstPath>::visit_mut_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -70575,6 +75495,11 @@ where
         <V as VisitMutAstPath>::visit_mut_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_do_while_stmt(&mut **self, node, __ast_path)
@@ -71021,6 +75946,45 @@ where
         <V as VisitMutAstPath>::visit_mut_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_member_expr(&mut **self, node, __ast_path)
@@ -71090,6 +76054,11 @@ where
         <V as VisitMutAstPath>::visit_mut_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_null(&mut **self, node, __ast_path)
@@ -71105,6 +76074,33 @@ where
         <V as VisitMutAstPath>::visit_mut_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_object_pat(&mut **self, node, __ast_path)
@@ -71225,6 +76221,24 @@ where
         <V as VisitMutAstPath>::visit_mut_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -71413,6 +76427,11 @@ where
         <V as VisitMutAstPath>::visit_mut_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_private_method(&mut **self, node, __ast_path)
@@ -71462,6 +76481,15 @@ where
         <V as VisitMutAstPath>::visit_mut_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_rest_pat(&mut **self, node, __ast_path)
@@ -71584,6 +76612,11 @@ where
         <V as VisitMutAstPath>::visit_mut_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_tpl(&mut **self, node, __ast_path)
@@ -72246,6 +77279,15 @@ where
         <V as VisitMutAstPath>::visit_mut_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_var_decl(&mut **self, node, __ast_path)
@@ -72325,6 +77367,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_array_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_array_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         match self {
@@ -72537,6 +77595,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_binding_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_binding_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         match self {
@@ -72713,6 +77787,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_combined_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_combined_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -72825,6 +77915,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_do_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_do_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         match self {
@@ -73733,6 +78835,94 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_case(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_case(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_cases(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_cases(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat_combinator(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat_combinator(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_pats(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_pats(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         match self {
@@ -73893,6 +79083,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_not_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_not_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         match self {
@@ -73929,6 +79131,54 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_prop(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_prop(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_props(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_props(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         match self {
@@ -74161,6 +79411,38 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -74517,6 +79799,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         match self {
@@ -74629,6 +79923,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_relational_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_relational_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         match self {
@@ -74905,6 +80215,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_topic_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_topic_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         match self {
@@ -76193,6 +81515,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_value_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_value_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         match self {
@@ -76319,6 +81657,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_array_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -76463,6 +81813,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_binding_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -76587,6 +81949,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_combined_match_pat(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -76667,6 +82045,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_do_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -77383,6 +82769,70 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_case(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_cases(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_pat_combinator(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_pats(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -77495,6 +82945,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_not_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -77519,6 +82977,50 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_object_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_object_match_pat_props(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -77703,6 +83205,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_opt_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_opt_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -78003,6 +83529,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -78079,6 +83613,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_relational_match_pat(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -78271,6 +83821,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_topic_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -79319,6 +84877,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_value_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -79452,6 +85022,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ArrayLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ArrayMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_array_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_array_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Elems(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        elems,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Rest,
+                    ));
+                    <Option<MatchRest> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        rest,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ArrayPat {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_array_pat`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -80165,6 +85780,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for BindingIdent {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for BindingMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_binding_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_binding_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Kind,
+                    ));
+                    <VarDeclKind as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        kind,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Id,
+                    ));
+                    <BindingIdent as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        id,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for BlockStmt {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_block_stmt`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -80880,6 +86540,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ClassProp {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for CombinedMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_combined_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_combined_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Op,
+                    ));
+                    <MatchPatCombinator as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Pats(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        pats,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ComputedPropName {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_computed_prop_name`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -81275,6 +86980,39 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DefaultDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DoExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_do_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_do_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Body));
+                    <BlockStmt as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DoWhileStmt {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_do_while_stmt`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -82000,6 +87738,42 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Expr {
                     &mut *__ast_path,
                 );
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Pipeline));
+                <PipelineExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Topic));
+                <TopicExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Match { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Match));
+                <MatchExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Do { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Do));
+                <DoExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let mut __ast_path =
                     __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Invalid));
@@ -84086,6 +89860,238 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Lit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchCase {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_case`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_case(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Pat));
+                    <Option<Box<MatchPat>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        pat,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchCase(
+                        self::fields::MatchCaseField::Guard,
+                    ));
+                    <Option<Box<Expr>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        guard,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Body));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchExpr(self::fields::MatchExprField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Subject,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        subject,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Cases(usize::MAX),
+                    ));
+                    <Vec<MatchCase> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        cases,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Binding,
+                ));
+                <BindingMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Value));
+                <ValueMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Relational,
+                ));
+                <RelationalMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Array));
+                <ArrayMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Object));
+                <ObjectMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Combined,
+                ));
+                <CombinedMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Not));
+                <NotMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchPatCombinator {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_pat_combinator`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat_combinator(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchRest {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_rest`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_rest(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Arg));
+                    <Option<Box<MatchPat>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MemberExpr {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_member_expr`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -84615,6 +90621,41 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for NewExpr {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for NotMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_not_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_not_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Arg,
+                    ));
+                    <Box<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Null {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_null`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -84706,6 +90747,86 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_object_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Props(usize::MAX),
+                    ));
+                    <Vec<ObjectMatchPatProp> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        props,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectMatchPatProp {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_object_match_pat_prop`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Key,
+                    ));
+                    <PropName as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        key,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Value,
+                    ));
+                    <Option<Box<MatchPat>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectPat {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_object_pat`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -85122,6 +91243,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_pipeline_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Left,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        left,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Right,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        right,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_private_method`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -85562,6 +91728,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Regex {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for RelationalMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_relational_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_relational_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Op,
+                    ));
+                    <BinaryOp as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Value,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for RestPat {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_rest_pat`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -86541,6 +92752,30 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for TopicExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_topic_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::TopicExpr(self::fields::TopicExprField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Tpl {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_tpl`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -90273,6 +96508,41 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for UsingDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ValueMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_value_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_value_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Value,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for VarDecl {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_var_decl`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -90768,6 +97038,48 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<JSXElementChild
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<MatchCase> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_cases(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        self.iter_mut().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchCase as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<MatchPat> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pats(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        self.iter_mut().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ModuleItem> {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -90789,6 +97101,27 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ModuleItem> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_props(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        self.iter_mut().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <ObjectMatchPatProp as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -91000,6 +97333,44 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<JSXClosingEl
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_pat(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                inner, visitor, __ast_path,
+            ),
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<MatchRest> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_rest(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            Some(inner) => <MatchRest as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                inner, visitor, __ast_path,
+            ),
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<ModuleExportName> {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -91719,6 +98090,13 @@ pub trait Fold {
     fn fold_array_lit(&mut self, node: ArrayLit) -> ArrayLit {
         <ArrayLit as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        <ArrayMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -91836,6 +98214,13 @@ pub trait Fold {
     fn fold_binding_ident(&mut self, node: BindingIdent) -> BindingIdent {
         <BindingIdent as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        <BindingMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -91931,6 +98316,13 @@ pub trait Fold {
     fn fold_class_prop(&mut self, node: ClassProp) -> ClassProp {
         <ClassProp as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        <CombinedMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -91993,6 +98385,12 @@ pub trait Fold {
     fn fold_default_decl(&mut self, node: DefaultDecl) -> DefaultDecl {
         <DefaultDecl as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::fold_children_with`]. If you want to recurse, you need to call it manually."]
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        <DoExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -92453,6 +98851,55 @@ pub trait Fold {
     fn fold_lit(&mut self, node: Lit) -> Lit {
         <Lit as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        <MatchCase as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        <Vec<MatchCase> as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        <MatchExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        <MatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::fold_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        <MatchPatCombinator as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        <Vec<MatchPat> as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        <MatchRest as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -92542,6 +98989,13 @@ pub trait Fold {
     fn fold_new_expr(&mut self, node: NewExpr) -> NewExpr {
         <NewExpr as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        <NotMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -92561,6 +99015,30 @@ pub trait Fold {
     fn fold_object_lit(&mut self, node: ObjectLit) -> ObjectLit {
         <ObjectLit as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        <ObjectMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::fold_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        <ObjectMatchPatProp as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::fold_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        <Vec<ObjectMatchPatProp> as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -92677,6 +99155,20 @@ pub trait Fold {
     ) -> Option<JSXClosingElement> {
         <Option<JSXClosingElement> as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::fold_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        <Option<Box<MatchPat>> as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::fold_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        <Option<MatchRest> as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::fold_children_with`]. If you want to recurse, you need \
              to call it manually."]
@@ -92861,6 +99353,13 @@ pub trait Fold {
     fn fold_pats(&mut self, node: Vec<Pat>) -> Vec<Pat> {
         <Vec<Pat> as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <PipelineExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -92921,6 +99420,13 @@ pub trait Fold {
     fn fold_regex(&mut self, node: Regex) -> Regex {
         <Regex as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::fold_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        <RelationalMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -93071,6 +99577,13 @@ pub trait Fold {
     fn fold_throw_stmt(&mut self, node: ThrowStmt) -> ThrowStmt {
         <ThrowStmt as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        <TopicExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -93714,6 +100227,13 @@ pub trait Fold {
     fn fold_using_decl(&mut self, node: UsingDecl) -> UsingDecl {
         <UsingDecl as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        <ValueMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -93784,6 +100304,11 @@ where
         <V as Fold>::fold_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        <V as Fold>::fold_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         <V as Fold>::fold_array_pat(&mut **self, node)
@@ -93869,6 +100394,11 @@ where
         <V as Fold>::fold_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        <V as Fold>::fold_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         <V as Fold>::fold_block_stmt(&mut **self, node)
@@ -93939,6 +100469,11 @@ where
         <V as Fold>::fold_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        <V as Fold>::fold_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         <V as Fold>::fold_computed_prop_name(&mut **self, node)
@@ -93984,6 +100519,11 @@ where
         <V as Fold>::fold_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        <V as Fold>::fold_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         <V as Fold>::fold_do_while_stmt(&mut **self, node)
@@ -94326,6 +100866,41 @@ where
         <V as Fold>::fold_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        <V as Fold>::fold_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        <V as Fold>::fold_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        <V as Fold>::fold_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        <V as Fold>::fold_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        <V as Fold>::fold_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        <V as Fold>::fold_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        <V as Fold>::fold_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         <V as Fold>::fold_member_expr(&mut **self, node)
@@ -94391,6 +100966,11 @@ where
         <V as Fold>::fold_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        <V as Fold>::fold_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         <V as Fold>::fold_null(&mut **self, node)
@@ -94406,6 +100986,24 @@ where
         <V as Fold>::fold_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        <V as Fold>::fold_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        <V as Fold>::fold_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as Fold>::fold_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         <V as Fold>::fold_object_pat(&mut **self, node)
@@ -94492,6 +101090,16 @@ where
         <V as Fold>::fold_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        <V as Fold>::fold_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        <V as Fold>::fold_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -94633,6 +101241,11 @@ where
         <V as Fold>::fold_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <V as Fold>::fold_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         <V as Fold>::fold_private_method(&mut **self, node)
@@ -94678,6 +101291,11 @@ where
         <V as Fold>::fold_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        <V as Fold>::fold_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         <V as Fold>::fold_rest_pat(&mut **self, node)
@@ -94791,6 +101409,11 @@ where
         <V as Fold>::fold_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        <V as Fold>::fold_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         <V as Fold>::fold_tpl(&mut **self, node)
@@ -95259,6 +101882,11 @@ where
         <V as Fold>::fold_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        <V as Fold>::fold_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         <V as Fold>::fold_var_decl(&mut **self, node)
@@ -95313,6 +101941,11 @@ where
         <V as Fold>::fold_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        <V as Fold>::fold_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         <V as Fold>::fold_array_pat(&mut **self, node)
@@ -95398,6 +102031,11 @@ where
         <V as Fold>::fold_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        <V as Fold>::fold_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         <V as Fold>::fold_block_stmt(&mut **self, node)
@@ -95468,6 +102106,11 @@ where
         <V as Fold>::fold_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        <V as Fold>::fold_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         <V as Fold>::fold_computed_prop_name(&mut **self, node)
@@ -95513,6 +102156,11 @@ where
         <V as Fold>::fold_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        <V as Fold>::fold_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         <V as Fold>::fold_do_while_stmt(&mut **self, node)
@@ -95855,6 +102503,41 @@ where
         <V as Fold>::fold_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        <V as Fold>::fold_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        <V as Fold>::fold_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        <V as Fold>::fold_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        <V as Fold>::fold_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        <V as Fold>::fold_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        <V as Fold>::fold_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        <V as Fold>::fold_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         <V as Fold>::fold_member_expr(&mut **self, node)
@@ -95920,6 +102603,11 @@ where
         <V as Fold>::fold_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        <V as Fold>::fold_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         <V as Fold>::fold_null(&mut **self, node)
@@ -95935,6 +102623,24 @@ where
         <V as Fold>::fold_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        <V as Fold>::fold_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        <V as Fold>::fold_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as Fold>::fold_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         <V as Fold>::fold_object_pat(&mut **self, node)
@@ -96021,6 +102727,16 @@ where
         <V as Fold>::fold_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        <V as Fold>::fold_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        <V as Fold>::fold_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -96162,6 +102878,11 @@ where
         <V as Fold>::fold_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <V as Fold>::fold_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         <V as Fold>::fold_private_method(&mut **self, node)
@@ -96207,6 +102928,11 @@ where
         <V as Fold>::fold_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        <V as Fold>::fold_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         <V as Fold>::fold_rest_pat(&mut **self, node)
@@ -96320,6 +103046,11 @@ where
         <V as Fold>::fold_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        <V as Fold>::fold_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         <V as Fold>::fold_tpl(&mut **self, node)
@@ -96788,6 +103519,11 @@ where
         <V as Fold>::fold_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        <V as Fold>::fold_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         <V as Fold>::fold_var_decl(&mut **self, node)
@@ -96849,6 +103585,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_array_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_array_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         match self {
@@ -96985,6 +103729,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_binding_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_binding_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         match self {
@@ -97097,6 +103849,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_combined_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_combined_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         match self {
@@ -97169,6 +103929,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_do_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_do_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         match self {
@@ -97713,6 +104481,62 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_case(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_case(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_cases(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_cases(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_expr(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_pat_combinator(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_pat_combinator(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_pats(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_pats(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         match self {
@@ -97817,6 +104641,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_not_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_not_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         match self {
@@ -97841,6 +104673,33 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_object_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_object_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_object_match_pat_prop(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_object_match_pat_prop(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_object_match_pat_props(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_object_match_pat_props(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         match self {
@@ -97975,6 +104834,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_opt_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_opt_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_opt_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_opt_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -98196,6 +105071,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         match self {
@@ -98268,6 +105151,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_relational_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_relational_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         match self {
@@ -98447,6 +105338,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_topic_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_topic_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         match self {
@@ -99199,6 +106098,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_value_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_value_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         match self {
@@ -99285,6 +106192,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_array_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         if self.enabled {
@@ -99438,6 +106354,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_binding_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         if self.enabled {
@@ -99564,6 +106489,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_combined_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         if self.enabled {
@@ -99645,6 +106579,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        if self.enabled {
+            <V as Fold>::fold_do_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         if self.enabled {
@@ -100251,6 +107194,69 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        if self.enabled {
+            <V as Fold>::fold_match_case(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        if self.enabled {
+            <V as Fold>::fold_match_cases(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        if self.enabled {
+            <V as Fold>::fold_match_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        if self.enabled {
+            <V as Fold>::fold_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        if self.enabled {
+            <V as Fold>::fold_match_pat_combinator(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        if self.enabled {
+            <V as Fold>::fold_match_pats(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        if self.enabled {
+            <V as Fold>::fold_match_rest(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         if self.enabled {
@@ -100368,6 +107374,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_not_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         if self.enabled {
@@ -100395,6 +107410,36 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_object_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        if self.enabled {
+            <V as Fold>::fold_object_match_pat_prop(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        if self.enabled {
+            <V as Fold>::fold_object_match_pat_props(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         if self.enabled {
@@ -100545,6 +107590,24 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        if self.enabled {
+            <V as Fold>::fold_opt_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        if self.enabled {
+            <V as Fold>::fold_opt_match_rest(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -100782,6 +107845,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        if self.enabled {
+            <V as Fold>::fold_pipeline_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         if self.enabled {
@@ -100863,6 +107935,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_relational_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         if self.enabled {
@@ -101064,6 +108145,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        if self.enabled {
+            <V as Fold>::fold_topic_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         if self.enabled {
@@ -101892,6 +108982,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_value_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         if self.enabled {
@@ -102002,6 +109101,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for ArrayLit {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for ArrayMatchPat {
+    #[doc = "Calls [Fold`::fold_array_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_array_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let elems = { <Vec<MatchPat> as FoldWith<V>>::fold_with(elems, visitor) };
+                let rest = { <Option<MatchRest> as FoldWith<V>>::fold_with(rest, visitor) };
+                ArrayMatchPat { span, elems, rest }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for ArrayPat {
     #[doc = "Calls [Fold`::fold_array_pat`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -102378,6 +109494,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for BindingIdent {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for BindingMatchPat {
+    #[doc = "Calls [Fold`::fold_binding_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_binding_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let kind = { <VarDeclKind as FoldWith<V>>::fold_with(kind, visitor) };
+                let id = { <BindingIdent as FoldWith<V>>::fold_with(id, visitor) };
+                BindingMatchPat { span, kind, id }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for BlockStmt {
     #[doc = "Calls [Fold`::fold_block_stmt`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -102750,6 +109883,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for ClassProp {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for CombinedMatchPat {
+    #[doc = "Calls [Fold`::fold_combined_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_combined_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let op = { <MatchPatCombinator as FoldWith<V>>::fold_with(op, visitor) };
+                let pats = { <Vec<MatchPat> as FoldWith<V>>::fold_with(pats, visitor) };
+                CombinedMatchPat { span, op, pats }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for ComputedPropName {
     #[doc = "Calls [Fold`::fold_computed_prop_name`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -102945,6 +110095,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for DefaultDecl {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for DoExpr {
+    #[doc = "Calls [Fold`::fold_do_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_do_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            DoExpr { span, body } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let body = { <BlockStmt as FoldWith<V>>::fold_with(body, visitor) };
+                DoExpr { span, body }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for DoWhileStmt {
     #[doc = "Calls [Fold`::fold_do_while_stmt`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -103292,6 +110458,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for Expr {
                 let _field_0 = <OptChainExpr as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::OptChain { 0: _field_0 }
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let _field_0 = <PipelineExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Pipeline { 0: _field_0 }
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let _field_0 = <TopicExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Topic { 0: _field_0 }
+            }
+            Expr::Match { 0: _field_0 } => {
+                let _field_0 = <MatchExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Match { 0: _field_0 }
+            }
+            Expr::Do { 0: _field_0 } => {
+                let _field_0 = <DoExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Do { 0: _field_0 }
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let _field_0 = <Invalid as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::Invalid { 0: _field_0 }
@@ -104342,6 +111524,127 @@ impl<V: ?Sized + Fold> FoldWith<V> for Lit {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for MatchCase {
+    #[doc = "Calls [Fold`::fold_match_case`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_case(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let pat = { <Option<Box<MatchPat>> as FoldWith<V>>::fold_with(pat, visitor) };
+                let guard = { <Option<Box<Expr>> as FoldWith<V>>::fold_with(guard, visitor) };
+                let body = { <Box<Expr> as FoldWith<V>>::fold_with(body, visitor) };
+                MatchCase {
+                    span,
+                    pat,
+                    guard,
+                    body,
+                }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchExpr {
+    #[doc = "Calls [Fold`::fold_match_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let subject = { <Box<Expr> as FoldWith<V>>::fold_with(subject, visitor) };
+                let cases = { <Vec<MatchCase> as FoldWith<V>>::fold_with(cases, visitor) };
+                MatchExpr {
+                    span,
+                    subject,
+                    cases,
+                }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchPat {
+    #[doc = "Calls [Fold`::fold_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let _field_0 = <BindingMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Binding { 0: _field_0 }
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let _field_0 = <ValueMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Value { 0: _field_0 }
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let _field_0 = <RelationalMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Relational { 0: _field_0 }
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let _field_0 = <ArrayMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Array { 0: _field_0 }
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let _field_0 = <ObjectMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Object { 0: _field_0 }
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let _field_0 = <CombinedMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Combined { 0: _field_0 }
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let _field_0 = <NotMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Not { 0: _field_0 }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchPatCombinator {
+    #[doc = "Calls [Fold`::fold_match_pat_combinator`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_pat_combinator(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchPatCombinator::And => MatchPatCombinator::And,
+            MatchPatCombinator::Or => MatchPatCombinator::Or,
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchRest {
+    #[doc = "Calls [Fold`::fold_match_rest`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_rest(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchRest { span, arg } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let arg = { <Option<Box<MatchPat>> as FoldWith<V>>::fold_with(arg, visitor) };
+                MatchRest { span, arg }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for MemberExpr {
     #[doc = "Calls [Fold`::fold_member_expr`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -104619,6 +111922,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for NewExpr {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for NotMatchPat {
+    #[doc = "Calls [Fold`::fold_not_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_not_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            NotMatchPat { span, arg } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let arg = { <Box<MatchPat> as FoldWith<V>>::fold_with(arg, visitor) };
+                NotMatchPat { span, arg }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Null {
     #[doc = "Calls [Fold`::fold_null`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -104666,6 +111985,39 @@ impl<V: ?Sized + Fold> FoldWith<V> for ObjectLit {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for ObjectMatchPat {
+    #[doc = "Calls [Fold`::fold_object_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_object_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ObjectMatchPat { span, props } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let props = { <Vec<ObjectMatchPatProp> as FoldWith<V>>::fold_with(props, visitor) };
+                ObjectMatchPat { span, props }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for ObjectMatchPatProp {
+    #[doc = "Calls [Fold`::fold_object_match_pat_prop`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_object_match_pat_prop(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let key = { <PropName as FoldWith<V>>::fold_with(key, visitor) };
+                let value = { <Option<Box<MatchPat>> as FoldWith<V>>::fold_with(value, visitor) };
+                ObjectMatchPatProp { span, key, value }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for ObjectPat {
     #[doc = "Calls [Fold`::fold_object_pat`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -104895,6 +112247,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for PipelineExpr {
+    #[doc = "Calls [Fold`::fold_pipeline_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_pipeline_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            PipelineExpr { span, left, right } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let left = { <Box<Expr> as FoldWith<V>>::fold_with(left, visitor) };
+                let right = { <Box<Expr> as FoldWith<V>>::fold_with(right, visitor) };
+                PipelineExpr { span, left, right }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for PrivateMethod {
     #[doc = "Calls [Fold`::fold_private_method`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -105122,6 +112491,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for Regex {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for RelationalMatchPat {
+    #[doc = "Calls [Fold`::fold_relational_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_relational_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let op = { <BinaryOp as FoldWith<V>>::fold_with(op, visitor) };
+                let value = { <Box<Expr> as FoldWith<V>>::fold_with(value, visitor) };
+                RelationalMatchPat { span, op, value }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for RestPat {
     #[doc = "Calls [Fold`::fold_rest_pat`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -105593,6 +112979,21 @@ impl<V: ?Sized + Fold> FoldWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for TopicExpr {
+    #[doc = "Calls [Fold`::fold_topic_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_topic_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            TopicExpr { span } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                TopicExpr { span }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Tpl {
     #[doc = "Calls [Fold`::fold_tpl`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -107555,6 +114956,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for UsingDecl {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for ValueMatchPat {
+    #[doc = "Calls [Fold`::fold_value_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_value_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ValueMatchPat { span, value } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let value = { <Box<Expr> as FoldWith<V>>::fold_with(value, visitor) };
+                ValueMatchPat { span, value }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for VarDecl {
     #[doc = "Calls [Fold`::fold_var_decl`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -107853,6 +115270,34 @@ impl<V: ?Sized + Fold> FoldWith<V> for Vec<JSXElementChild> {
         })
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for Vec<MatchCase> {
+    #[doc = "Calls [Fold`::fold_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_cases(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        swc_visit::util::move_map::MoveMap::move_map(self, |item| {
+            <MatchCase as FoldWith<V>>::fold_with(item, visitor)
+        })
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for Vec<MatchPat> {
+    #[doc = "Calls [Fold`::fold_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_pats(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        swc_visit::util::move_map::MoveMap::move_map(self, |item| {
+            <MatchPat as FoldWith<V>>::fold_with(item, visitor)
+        })
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Vec<ModuleItem> {
     #[doc = "Calls [Fold`::fold_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -107867,6 +115312,20 @@ impl<V: ?Sized + Fold> FoldWith<V> for Vec<ModuleItem> {
         })
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [Fold`::fold_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_object_match_pat_props(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        swc_visit::util::move_map::MoveMap::move_map(self, |item| {
+            <ObjectMatchPatProp as FoldWith<V>>::fold_with(item, visitor)
+        })
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [Fold`::fold_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -108001,6 +115460,30 @@ impl<V: ?Sized + Fold> FoldWith<V> for Option<JSXClosingElement> {
         self.map(|inner| <JSXClosingElement as FoldWith<V>>::fold_with(inner, visitor))
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [Fold`::fold_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_opt_match_pat(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        self.map(|inner| <Box<MatchPat> as FoldWith<V>>::fold_with(inner, visitor))
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for Option<MatchRest> {
+    #[doc = "Calls [Fold`::fold_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_opt_match_rest(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        self.map(|inner| <MatchRest as FoldWith<V>>::fold_with(inner, visitor))
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Option<ModuleExportName> {
     #[doc = "Calls [Fold`::fold_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -108484,6 +115967,19 @@ pub trait FoldAstPath {
     fn fold_array_lit(&mut self, node: ArrayLit, __ast_path: &mut AstKindPath) -> ArrayLit {
         <ArrayLit as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        <ArrayMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -108637,6 +116133,19 @@ pub trait FoldAstPath {
     ) -> BindingIdent {
         <BindingIdent as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        <BindingMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -108759,6 +116268,19 @@ pub trait FoldAstPath {
     fn fold_class_prop(&mut self, node: ClassProp, __ast_path: &mut AstKindPath) -> ClassProp {
         <ClassProp as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        <CombinedMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::fold_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -108846,6 +116368,13 @@ pub trait FoldAstPath {
     ) -> DefaultDecl {
         <DefaultDecl as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        <DoExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::fold_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -109520,6 +117049,73 @@ pub trait FoldAstPath {
     fn fold_lit(&mut self, node: Lit, __ast_path: &mut AstKindPath) -> Lit {
         <Lit as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        <MatchCase as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        <Vec<MatchCase> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        <MatchExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        <MatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        <MatchPatCombinator as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        <Vec<MatchPat> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        <MatchRest as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -109635,6 +117231,17 @@ pub trait FoldAstPath {
     fn fold_new_expr(&mut self, node: NewExpr, __ast_path: &mut AstKindPath) -> NewExpr {
         <NewExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        <NotMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -109656,6 +117263,45 @@ pub trait FoldAstPath {
     fn fold_object_lit(&mut self, node: ObjectLit, __ast_path: &mut AstKindPath) -> ObjectLit {
         <ObjectLit as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        <ObjectMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        <ObjectMatchPatProp as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::fold_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        <Vec<ObjectMatchPatProp> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -109848,6 +117494,32 @@ pub trait FoldAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::fold_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        <Option<Box<MatchPat>> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::fold_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        <Option<MatchRest> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::fold_children_with_ast_path`]. If you want to recurse, \
              you need to call it manually."]
@@ -110123,6 +117795,17 @@ pub trait FoldAstPath {
     fn fold_pats(&mut self, node: Vec<Pat>, __ast_path: &mut AstKindPath) -> Vec<Pat> {
         <Vec<Pat> as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <PipelineExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::fold_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -110210,6 +117893,19 @@ pub trait FoldAstPath {
     fn fold_regex(&mut self, node: Regex, __ast_path: &mut AstKindPath) -> Regex {
         <Regex as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        <RelationalMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -110404,6 +118100,13 @@ pub trait FoldAstPath {
     fn fold_throw_stmt(&mut self, node: ThrowStmt, __ast_path: &mut AstKindPath) -> ThrowStmt {
         <ThrowStmt as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        <TopicExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -111424,6 +119127,19 @@ pub trait FoldAstPath {
     fn fold_using_decl(&mut self, node: UsingDecl, __ast_path: &mut AstKindPath) -> UsingDecl {
         <UsingDecl as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        <ValueMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -111523,6 +119239,15 @@ where
         <V as FoldAstPath>::fold_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        <V as FoldAstPath>::fold_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         <V as FoldAstPath>::fold_array_pat(&mut **self, node, __ast_path)
@@ -111636,6 +119361,15 @@ where
         <V as FoldAstPath>::fold_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        <V as FoldAstPath>::fold_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         <V as FoldAstPath>::fold_block_stmt(&mut **self, node, __ast_path)
@@ -111726,6 +119460,15 @@ where
         <V as FoldAstPath>::fold_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        <V as FoldAstPath>::fold_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -111791,6 +119534,11 @@ where
         <V as FoldAstPath>::fold_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        <V as FoldAstPath>::fold_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -112273,6 +120021,53 @@ where
         <V as FoldAstPath>::fold_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        <V as FoldAstPath>::fold_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        <V as FoldAstPath>::fold_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        <V as FoldAstPath>::fold_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        <V as FoldAstPath>::fold_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        <V as FoldAstPath>::fold_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        <V as FoldAstPath>::fold_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        <V as FoldAstPath>::fold_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         <V as FoldAstPath>::fold_member_expr(&mut **self, node, __ast_path)
@@ -112358,6 +120153,15 @@ where
         <V as FoldAstPath>::fold_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        <V as FoldAstPath>::fold_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         <V as FoldAstPath>::fold_null(&mut **self, node, __ast_path)
@@ -112373,6 +120177,33 @@ where
         <V as FoldAstPath>::fold_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        <V as FoldAstPath>::fold_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        <V as FoldAstPath>::fold_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as FoldAstPath>::fold_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         <V as FoldAstPath>::fold_object_pat(&mut **self, node, __ast_path)
@@ -112509,6 +120340,24 @@ where
         <V as FoldAstPath>::fold_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        <V as FoldAstPath>::fold_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        <V as FoldAstPath>::fold_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -112701,6 +120550,15 @@ where
         <V as FoldAstPath>::fold_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <V as FoldAstPath>::fold_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -112766,6 +120624,15 @@ where
         <V as FoldAstPath>::fold_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        <V as FoldAstPath>::fold_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         <V as FoldAstPath>::fold_rest_pat(&mut **self, node, __ast_path)
@@ -112904,6 +120771,11 @@ where
         <V as FoldAstPath>::fold_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        <V as FoldAstPath>::fold_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         <V as FoldAstPath>::fold_tpl(&mut **self, node, __ast_path)
@@ -113634,6 +121506,15 @@ where
         <V as FoldAstPath>::fold_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        <V as FoldAstPath>::fold_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         <V as FoldAstPath>::fold_var_decl(&mut **self, node, __ast_path)
@@ -113710,6 +121591,15 @@ where
         <V as FoldAstPath>::fold_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        <V as FoldAstPath>::fold_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         <V as FoldAstPath>::fold_array_pat(&mut **self, node, __ast_path)
@@ -113823,6 +121713,15 @@ where
         <V as FoldAstPath>::fold_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        <V as FoldAstPath>::fold_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         <V as FoldAstPath>::fold_block_stmt(&mut **self, node, __ast_path)
@@ -113913,6 +121812,15 @@ where
         <V as FoldAstPath>::fold_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        <V as FoldAstPath>::fold_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -113978,6 +121886,11 @@ where
         <V as FoldAstPath>::fold_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        <V as FoldAstPath>::fold_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -114460,6 +122373,53 @@ where
         <V as FoldAstPath>::fold_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        <V as FoldAstPath>::fold_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        <V as FoldAstPath>::fold_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        <V as FoldAstPath>::fold_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        <V as FoldAstPath>::fold_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        <V as FoldAstPath>::fold_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        <V as FoldAstPath>::fold_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        <V as FoldAstPath>::fold_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         <V as FoldAstPath>::fold_member_expr(&mut **self, node, __ast_path)
@@ -114545,6 +122505,15 @@ where
         <V as FoldAstPath>::fold_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        <V as FoldAstPath>::fold_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         <V as FoldAstPath>::fold_null(&mut **self, node, __ast_path)
@@ -114560,6 +122529,33 @@ where
         <V as FoldAstPath>::fold_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        <V as FoldAstPath>::fold_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        <V as FoldAstPath>::fold_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as FoldAstPath>::fold_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         <V as FoldAstPath>::fold_object_pat(&mut **self, node, __ast_path)
@@ -114696,6 +122692,24 @@ where
         <V as FoldAstPath>::fold_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        <V as FoldAstPath>::fold_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        <V as FoldAstPath>::fold_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -114888,6 +122902,15 @@ where
         <V as FoldAstPath>::fold_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <V as FoldAstPath>::fold_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -114953,6 +122976,15 @@ where
         <V as FoldAstPath>::fold_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        <V as FoldAstPath>::fold_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         <V as FoldAstPath>::fold_rest_pat(&mut **self, node, __ast_path)
@@ -115091,6 +123123,11 @@ where
         <V as FoldAstPath>::fold_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        <V as FoldAstPath>::fold_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         <V as FoldAstPath>::fold_tpl(&mut **self, node, __ast_path)
@@ -115821,6 +123858,15 @@ where
         <V as FoldAstPath>::fold_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        <V as FoldAstPath>::fold_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         <V as FoldAstPath>::fold_var_decl(&mut **self, node, __ast_path)
@@ -115912,6 +123958,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_array_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_array_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         match self {
@@ -116140,6 +124202,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_binding_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_binding_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         match self {
@@ -116318,6 +124396,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_combined_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_combined_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -116442,6 +124536,18 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_do_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_do_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -117364,6 +125470,102 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_case(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_case(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_cases(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_cases(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_pat_combinator(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_pat_combinator(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_pats(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_pats(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         match self {
@@ -117538,6 +125740,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_not_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_not_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         match self {
@@ -117568,6 +125786,54 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_object_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_object_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_object_match_pat_prop(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_object_match_pat_prop(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_object_match_pat_props(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_object_match_pat_props(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         match self {
@@ -117816,6 +126082,38 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_opt_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_opt_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_opt_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_opt_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -118162,6 +126460,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -118282,6 +126596,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_relational_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_relational_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         match self {
@@ -118552,6 +126882,18 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_topic_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_topic_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         match self {
@@ -119906,6 +128248,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_value_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_value_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         match self {
@@ -120046,6 +128404,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_array_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         if self.enabled {
@@ -120227,6 +128598,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_binding_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         if self.enabled {
@@ -120373,6 +128757,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_combined_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -120474,6 +128871,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_do_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -121220,6 +129626,81 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_case(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_cases(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_pat_combinator(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_pats(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         if self.enabled {
@@ -121357,6 +129838,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_not_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         if self.enabled {
@@ -121384,6 +129878,45 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_object_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        if self.enabled {
+            <V as FoldAstPath>::fold_object_match_pat_prop(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_object_match_pat_props(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         if self.enabled {
@@ -121584,6 +130117,32 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_opt_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_opt_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -121876,6 +130435,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -121977,6 +130549,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_relational_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         if self.enabled {
@@ -122203,6 +130788,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_topic_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         if self.enabled {
@@ -123305,6 +131899,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_value_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         if self.enabled {
@@ -123455,6 +132062,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ArrayLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ArrayMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_array_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_array_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let elems = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Elems(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        elems,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let rest = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Rest,
+                    ));
+                    <Option<MatchRest> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        rest,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ArrayMatchPat { span, elems, rest }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ArrayPat {
     #[doc = "Calls [FoldAstPath`::fold_array_pat`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -124215,6 +132868,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for BindingIdent {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for BindingMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_binding_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_binding_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let kind = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Kind,
+                    ));
+                    <VarDeclKind as FoldWithAstPath<V>>::fold_with_ast_path(
+                        kind,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let id = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Id,
+                    ));
+                    <BindingIdent as FoldWithAstPath<V>>::fold_with_ast_path(
+                        id,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                BindingMatchPat { span, kind, id }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for BlockStmt {
     #[doc = "Calls [FoldAstPath`::fold_block_stmt`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -125002,6 +133701,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ClassProp {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for CombinedMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_combined_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_combined_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let op = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Op,
+                    ));
+                    <MatchPatCombinator as FoldWithAstPath<V>>::fold_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let pats = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Pats(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        pats,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                CombinedMatchPat { span, op, pats }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ComputedPropName {
     #[doc = "Calls [FoldAstPath`::fold_computed_prop_name`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -125427,6 +134172,40 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DefaultDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DoExpr {
+    #[doc = "Calls [FoldAstPath`::fold_do_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_do_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            DoExpr { span, body } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let body = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Body));
+                    <BlockStmt as FoldWithAstPath<V>>::fold_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                DoExpr { span, body }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DoWhileStmt {
     #[doc = "Calls [FoldAstPath`::fold_do_while_stmt`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -126211,6 +134990,46 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Expr {
                 );
                 Expr::OptChain { 0: _field_0 }
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Pipeline));
+                let _field_0 = <PipelineExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Pipeline { 0: _field_0 }
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Topic));
+                let _field_0 = <TopicExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Topic { 0: _field_0 }
+            }
+            Expr::Match { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Match));
+                let _field_0 = <MatchExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Match { 0: _field_0 }
+            }
+            Expr::Do { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Do));
+                let _field_0 = <DoExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Do { 0: _field_0 }
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let mut __ast_path =
                     __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Invalid));
@@ -128447,6 +137266,257 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Lit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchCase {
+    #[doc = "Calls [FoldAstPath`::fold_match_case`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_case(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let pat = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Pat));
+                    <Option<Box<MatchPat>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        pat,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let guard = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchCase(
+                        self::fields::MatchCaseField::Guard,
+                    ));
+                    <Option<Box<Expr>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        guard,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let body = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Body));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                MatchCase {
+                    span,
+                    pat,
+                    guard,
+                    body,
+                }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchExpr {
+    #[doc = "Calls [FoldAstPath`::fold_match_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchExpr(self::fields::MatchExprField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let subject = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Subject,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        subject,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let cases = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Cases(usize::MAX),
+                    ));
+                    <Vec<MatchCase> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        cases,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                MatchExpr {
+                    span,
+                    subject,
+                    cases,
+                }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Binding,
+                ));
+                let _field_0 = <BindingMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Binding { 0: _field_0 }
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Value));
+                let _field_0 = <ValueMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Value { 0: _field_0 }
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Relational,
+                ));
+                let _field_0 = <RelationalMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Relational { 0: _field_0 }
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Array));
+                let _field_0 = <ArrayMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Array { 0: _field_0 }
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Object));
+                let _field_0 = <ObjectMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Object { 0: _field_0 }
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Combined,
+                ));
+                let _field_0 = <CombinedMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Combined { 0: _field_0 }
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Not));
+                let _field_0 = <NotMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Not { 0: _field_0 }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchPatCombinator {
+    #[doc = "Calls [FoldAstPath`::fold_match_pat_combinator`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_pat_combinator(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchPatCombinator::And => MatchPatCombinator::And,
+            MatchPatCombinator::Or => MatchPatCombinator::Or,
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchRest {
+    #[doc = "Calls [FoldAstPath`::fold_match_rest`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_rest(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchRest { span, arg } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let arg = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Arg));
+                    <Option<Box<MatchPat>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                MatchRest { span, arg }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MemberExpr {
     #[doc = "Calls [FoldAstPath`::fold_member_expr`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -129014,6 +138084,42 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for NewExpr {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for NotMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_not_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_not_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            NotMatchPat { span, arg } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let arg = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Arg,
+                    ));
+                    <Box<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                NotMatchPat { span, arg }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Null {
     #[doc = "Calls [FoldAstPath`::fold_null`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -129108,6 +138214,88 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_object_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_object_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ObjectMatchPat { span, props } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let props = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Props(usize::MAX),
+                    ));
+                    <Vec<ObjectMatchPatProp> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        props,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ObjectMatchPat { span, props }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectMatchPatProp {
+    #[doc = "Calls [FoldAstPath`::fold_object_match_pat_prop`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_object_match_pat_prop(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let key = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Key,
+                    ));
+                    <PropName as FoldWithAstPath<V>>::fold_with_ast_path(
+                        key,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let value = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Value,
+                    ));
+                    <Option<Box<MatchPat>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ObjectMatchPatProp { span, key, value }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectPat {
     #[doc = "Calls [FoldAstPath`::fold_object_pat`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -129558,6 +138746,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [FoldAstPath`::fold_pipeline_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            PipelineExpr { span, left, right } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let left = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Left,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        left,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let right = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Right,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        right,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                PipelineExpr { span, left, right }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [FoldAstPath`::fold_private_method`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -130040,6 +139274,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Regex {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for RelationalMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_relational_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_relational_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let op = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Op,
+                    ));
+                    <BinaryOp as FoldWithAstPath<V>>::fold_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let value = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Value,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                RelationalMatchPat { span, op, value }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for RestPat {
     #[doc = "Calls [FoldAstPath`::fold_rest_pat`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -131091,6 +140371,31 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for TopicExpr {
+    #[doc = "Calls [FoldAstPath`::fold_topic_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_topic_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            TopicExpr { span } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::TopicExpr(self::fields::TopicExprField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                TopicExpr { span }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Tpl {
     #[doc = "Calls [FoldAstPath`::fold_tpl`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -135132,6 +144437,42 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for UsingDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ValueMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_value_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_value_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ValueMatchPat { span, value } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let value = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Value,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ValueMatchPat { span, value }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for VarDecl {
     #[doc = "Calls [FoldAstPath`::fold_var_decl`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -135672,6 +145013,54 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<JSXElementChild> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<MatchCase> {
+    #[doc = "Calls [FoldAstPath`::fold_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_cases(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.into_iter()
+            .enumerate()
+            .map(|(__idx, item)| {
+                let mut __ast_path = __ast_path.with_index_guard(__idx);
+                <MatchCase as FoldWithAstPath<V>>::fold_with_ast_path(
+                    item,
+                    visitor,
+                    &mut *__ast_path,
+                )
+            })
+            .collect()
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<MatchPat> {
+    #[doc = "Calls [FoldAstPath`::fold_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_pats(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.into_iter()
+            .enumerate()
+            .map(|(__idx, item)| {
+                let mut __ast_path = __ast_path.with_index_guard(__idx);
+                <MatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    item,
+                    visitor,
+                    &mut *__ast_path,
+                )
+            })
+            .collect()
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ModuleItem> {
     #[doc = "Calls [FoldAstPath`::fold_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -135696,6 +145085,30 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ModuleItem> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [FoldAstPath`::fold_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_object_match_pat_props(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.into_iter()
+            .enumerate()
+            .map(|(__idx, item)| {
+                let mut __ast_path = __ast_path.with_index_guard(__idx);
+                <ObjectMatchPatProp as FoldWithAstPath<V>>::fold_with_ast_path(
+                    item,
+                    visitor,
+                    &mut *__ast_path,
+                )
+            })
+            .collect()
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [FoldAstPath`::fold_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -135884,6 +145297,38 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<JSXClosingElement> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [FoldAstPath`::fold_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_opt_match_pat(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.map(|inner| {
+            <Box<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(inner, visitor, __ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<MatchRest> {
+    #[doc = "Calls [FoldAstPath`::fold_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_opt_match_rest(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.map(|inner| {
+            <MatchRest as FoldWithAstPath<V>>::fold_with_ast_path(inner, visitor, __ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<ModuleExportName> {
     #[doc = "Calls [FoldAstPath`::fold_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -136624,6 +146069,27 @@ pub mod fields {
         #[doc = "Represents [`ArrayLit::elems`]"]
         Elems(usize),
     }
+    impl ArrayMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Elems(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ArrayMatchPatField {
+        #[doc = "Represents [`ArrayMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`ArrayMatchPat::elems`]"]
+        Elems(usize),
+        #[doc = "Represents [`ArrayMatchPat::rest`]"]
+        Rest,
+    }
     impl ArrayPatField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -136981,6 +146447,23 @@ pub mod fields {
         #[doc = "Represents [`BindingIdent::type_ann`]"]
         TypeAnn,
     }
+    impl BindingMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum BindingMatchPatField {
+        #[doc = "Represents [`BindingMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`BindingMatchPat::kind`]"]
+        Kind,
+        #[doc = "Represents [`BindingMatchPat::id`]"]
+        Id,
+    }
     impl BlockStmtField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -137275,6 +146758,27 @@ pub mod fields {
         #[doc = "Represents [`ClassProp::definite`]"]
         Definite,
     }
+    impl CombinedMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Pats(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum CombinedMatchPatField {
+        #[doc = "Represents [`CombinedMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`CombinedMatchPat::op`]"]
+        Op,
+        #[doc = "Represents [`CombinedMatchPat::pats`]"]
+        Pats(usize),
+    }
     impl ComputedPropNameField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -137423,6 +146927,21 @@ pub mod fields {
         #[doc = "Represents [`DefaultDecl::TsInterfaceDecl`]"]
         TsInterfaceDecl,
     }
+    impl DoExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum DoExprField {
+        #[doc = "Represents [`DoExpr::span`]"]
+        Span,
+        #[doc = "Represents [`DoExpr::body`]"]
+        Body,
+    }
     impl DoWhileStmtField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -137663,6 +147182,14 @@ pub mod fields {
         PrivateName,
         #[doc = "Represents [`Expr::OptChain`]"]
         OptChain,
+        #[doc = "Represents [`Expr::Pipeline`]"]
+        Pipeline,
+        #[doc = "Represents [`Expr::Topic`]"]
+        Topic,
+        #[doc = "Represents [`Expr::Match`]"]
+        Match,
+        #[doc = "Represents [`Expr::Do`]"]
+        Do,
         #[doc = "Represents [`Expr::Invalid`]"]
         Invalid,
     }
@@ -138502,6 +148029,99 @@ pub mod fields {
         #[doc = "Represents [`Lit::JSXText`]"]
         Jsxtext,
     }
+    impl MatchCaseField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchCaseField {
+        #[doc = "Represents [`MatchCase::span`]"]
+        Span,
+        #[doc = "Represents [`MatchCase::pat`]"]
+        Pat,
+        #[doc = "Represents [`MatchCase::guard`]"]
+        Guard,
+        #[doc = "Represents [`MatchCase::body`]"]
+        Body,
+    }
+    impl MatchExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Cases(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchExprField {
+        #[doc = "Represents [`MatchExpr::span`]"]
+        Span,
+        #[doc = "Represents [`MatchExpr::subject`]"]
+        Subject,
+        #[doc = "Represents [`MatchExpr::cases`]"]
+        Cases(usize),
+    }
+    impl MatchPatField {
+        #[inline(always)]
+        pub(crate) fn set_index(&mut self, _: usize) {
+            swc_visit::wrong_ast_path();
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchPatField {
+        #[doc = "Represents [`MatchPat::Binding`]"]
+        Binding,
+        #[doc = "Represents [`MatchPat::Value`]"]
+        Value,
+        #[doc = "Represents [`MatchPat::Relational`]"]
+        Relational,
+        #[doc = "Represents [`MatchPat::Array`]"]
+        Array,
+        #[doc = "Represents [`MatchPat::Object`]"]
+        Object,
+        #[doc = "Represents [`MatchPat::Combined`]"]
+        Combined,
+        #[doc = "Represents [`MatchPat::Not`]"]
+        Not,
+    }
+    impl MatchPatCombinatorField {
+        #[inline(always)]
+        pub(crate) fn set_index(&mut self, _: usize) {
+            swc_visit::wrong_ast_path();
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchPatCombinatorField {
+        #[doc = "Represents [`MatchPatCombinator::And`]"]
+        And,
+        #[doc = "Represents [`MatchPatCombinator::Or`]"]
+        Or,
+    }
+    impl MatchRestField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchRestField {
+        #[doc = "Represents [`MatchRest::span`]"]
+        Span,
+        #[doc = "Represents [`MatchRest::arg`]"]
+        Arg,
+    }
     impl MemberExprField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -138722,6 +148342,21 @@ pub mod fields {
         #[doc = "Represents [`NewExpr::type_args`]"]
         TypeArgs,
     }
+    impl NotMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum NotMatchPatField {
+        #[doc = "Represents [`NotMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`NotMatchPat::arg`]"]
+        Arg,
+    }
     impl NullField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -138771,6 +148406,42 @@ pub mod fields {
         #[doc = "Represents [`ObjectLit::props`]"]
         Props(usize),
     }
+    impl ObjectMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Props(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ObjectMatchPatField {
+        #[doc = "Represents [`ObjectMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`ObjectMatchPat::props`]"]
+        Props(usize),
+    }
+    impl ObjectMatchPatPropField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ObjectMatchPatPropField {
+        #[doc = "Represents [`ObjectMatchPatProp::span`]"]
+        Span,
+        #[doc = "Represents [`ObjectMatchPatProp::key`]"]
+        Key,
+        #[doc = "Represents [`ObjectMatchPatProp::value`]"]
+        Value,
+    }
     impl ObjectPatField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -138940,6 +148611,23 @@ pub mod fields {
         #[doc = "Represents [`Pat::Expr`]"]
         Expr,
     }
+    impl PipelineExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum PipelineExprField {
+        #[doc = "Represents [`PipelineExpr::span`]"]
+        Span,
+        #[doc = "Represents [`PipelineExpr::left`]"]
+        Left,
+        #[doc = "Represents [`PipelineExpr::right`]"]
+        Right,
+    }
     impl PrivateMethodField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -139110,6 +148798,23 @@ pub mod fields {
         #[doc = "Represents [`Regex::flags`]"]
         Flags,
     }
+    impl RelationalMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum RelationalMatchPatField {
+        #[doc = "Represents [`RelationalMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`RelationalMatchPat::op`]"]
+        Op,
+        #[doc = "Represents [`RelationalMatchPat::value`]"]
+        Value,
+    }
     impl RestPatField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -139467,6 +149172,19 @@ pub mod fields {
         #[doc = "Represents [`ThrowStmt::arg`]"]
         Arg,
     }
+    impl TopicExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum TopicExprField {
+        #[doc = "Represents [`TopicExpr::span`]"]
+        Span,
+    }
     impl TplField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -141034,6 +150752,21 @@ pub mod fields {
         #[doc = "Represents [`UsingDecl::decls`]"]
         Decls(usize),
     }
+    impl ValueMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ValueMatchPatField {
+        #[doc = "Represents [`ValueMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`ValueMatchPat::value`]"]
+        Value,
+    }
     impl VarDeclField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -141164,6 +150897,7 @@ pub mod fields {
     pub enum AstParentKind {
         Accessibility(AccessibilityField),
         ArrayLit(ArrayLitField),
+        ArrayMatchPat(ArrayMatchPatField),
         ArrayPat(ArrayPatField),
         ArrowExpr(ArrowExprField),
         AssignExpr(AssignExprField),
@@ -141179,6 +150913,7 @@ pub mod fields {
         BinExpr(BinExprField),
         BinaryOp(BinaryOpField),
         BindingIdent(BindingIdentField),
+        BindingMatchPat(BindingMatchPatField),
         BlockStmt(BlockStmtField),
         BlockStmtOrExpr(BlockStmtOrExprField),
         Bool(BoolField),
@@ -141192,6 +150927,7 @@ pub mod fields {
         ClassMember(ClassMemberField),
         ClassMethod(ClassMethodField),
         ClassProp(ClassPropField),
+        CombinedMatchPat(CombinedMatchPatField),
         ComputedPropName(ComputedPropNameField),
         CondExpr(CondExprField),
         Constructor(ConstructorField),
@@ -141200,6 +150936,7 @@ pub mod fields {
         Decl(DeclField),
         Decorator(DecoratorField),
         DefaultDecl(DefaultDeclField),
+        DoExpr(DoExprField),
         DoWhileStmt(DoWhileStmtField),
         EmptyStmt(EmptyStmtField),
         ExportAll(ExportAllField),
@@ -141259,6 +150996,11 @@ pub mod fields {
         KeyValueProp(KeyValuePropField),
         LabeledStmt(LabeledStmtField),
         Lit(LitField),
+        MatchCase(MatchCaseField),
+        MatchExpr(MatchExprField),
+        MatchPat(MatchPatField),
+        MatchPatCombinator(MatchPatCombinatorField),
+        MatchRest(MatchRestField),
         MemberExpr(MemberExprField),
         MemberProp(MemberPropField),
         MetaPropExpr(MetaPropExprField),
@@ -141271,9 +151013,12 @@ pub mod fields {
         ModuleItem(ModuleItemField),
         NamedExport(NamedExportField),
         NewExpr(NewExprField),
+        NotMatchPat(NotMatchPatField),
         Null(NullField),
         Number(NumberField),
         ObjectLit(ObjectLitField),
+        ObjectMatchPat(ObjectMatchPatField),
+        ObjectMatchPatProp(ObjectMatchPatPropField),
         ObjectPat(ObjectPatField),
         ObjectPatProp(ObjectPatPropField),
         OptCall(OptCallField),
@@ -141283,6 +151028,7 @@ pub mod fields {
         ParamOrTsParamProp(ParamOrTsParamPropField),
         ParenExpr(ParenExprField),
         Pat(PatField),
+        PipelineExpr(PipelineExprField),
         PrivateMethod(PrivateMethodField),
         PrivateName(PrivateNameField),
         PrivateProp(PrivatePropField),
@@ -141291,6 +151037,7 @@ pub mod fields {
         PropName(PropNameField),
         PropOrSpread(PropOrSpreadField),
         Regex(RegexField),
+        RelationalMatchPat(RelationalMatchPatField),
         RestPat(RestPatField),
         ReturnStmt(ReturnStmtField),
         Script(ScriptField),
@@ -141309,6 +151056,7 @@ pub mod fields {
         TaggedTpl(TaggedTplField),
         ThisExpr(ThisExprField),
         ThrowStmt(ThrowStmtField),
+        TopicExpr(TopicExprField),
         Tpl(TplField),
         TplElement(TplElementField),
         TruePlusMinus(TruePlusMinusField),
@@ -141391,6 +151139,7 @@ pub mod fields {
         UpdateExpr(UpdateExprField),
         UpdateOp(UpdateOpField),
         UsingDecl(UsingDeclField),
+        ValueMatchPat(ValueMatchPatField),
         VarDecl(VarDeclField),
         VarDeclKind(VarDeclKindField),
         VarDeclOrExpr(VarDeclOrExprField),
@@ -141405,6 +151154,7 @@ pub mod fields {
             match self {
                 Self::Accessibility(v) => v.set_index(index),
                 Self::ArrayLit(v) => v.set_index(index),
+                Self::ArrayMatchPat(v) => v.set_index(index),
                 Self::ArrayPat(v) => v.set_index(index),
                 Self::ArrowExpr(v) => v.set_index(index),
                 Self::AssignExpr(v) => v.set_index(index),
@@ -141420,6 +151170,7 @@ pub mod fields {
                 Self::BinExpr(v) => v.set_index(index),
                 Self::BinaryOp(v) => v.set_index(index),
                 Self::BindingIdent(v) => v.set_index(index),
+                Self::BindingMatchPat(v) => v.set_index(index),
                 Self::BlockStmt(v) => v.set_index(index),
                 Self::BlockStmtOrExpr(v) => v.set_index(index),
                 Self::Bool(v) => v.set_index(index),
@@ -141433,6 +151184,7 @@ pub mod fields {
                 Self::ClassMember(v) => v.set_index(index),
                 Self::ClassMethod(v) => v.set_index(index),
                 Self::ClassProp(v) => v.set_index(index),
+                Self::CombinedMatchPat(v) => v.set_index(index),
                 Self::ComputedPropName(v) => v.set_index(index),
                 Self::CondExpr(v) => v.set_index(index),
                 Self::Constructor(v) => v.set_index(index),
@@ -141441,6 +151193,7 @@ pub mod fields {
                 Self::Decl(v) => v.set_index(index),
                 Self::Decorator(v) => v.set_index(index),
                 Self::DefaultDecl(v) => v.set_index(index),
+                Self::DoExpr(v) => v.set_index(index),
                 Self::DoWhileStmt(v) => v.set_index(index),
                 Self::EmptyStmt(v) => v.set_index(index),
                 Self::ExportAll(v) => v.set_index(index),
@@ -141500,6 +151253,11 @@ pub mod fields {
                 Self::KeyValueProp(v) => v.set_index(index),
                 Self::LabeledStmt(v) => v.set_index(index),
                 Self::Lit(v) => v.set_index(index),
+                Self::MatchCase(v) => v.set_index(index),
+                Self::MatchExpr(v) => v.set_index(index),
+                Self::MatchPat(v) => v.set_index(index),
+                Self::MatchPatCombinator(v) => v.set_index(index),
+                Self::MatchRest(v) => v.set_index(index),
                 Self::MemberExpr(v) => v.set_index(index),
                 Self::MemberProp(v) => v.set_index(index),
                 Self::MetaPropExpr(v) => v.set_index(index),
@@ -141512,9 +151270,12 @@ pub mod fields {
                 Self::ModuleItem(v) => v.set_index(index),
                 Self::NamedExport(v) => v.set_index(index),
                 Self::NewExpr(v) => v.set_index(index),
+                Self::NotMatchPat(v) => v.set_index(index),
                 Self::Null(v) => v.set_index(index),
                 Self::Number(v) => v.set_index(index),
                 Self::ObjectLit(v) => v.set_index(index),
+                Self::ObjectMatchPat(v) => v.set_index(index),
+                Self::ObjectMatchPatProp(v) => v.set_index(index),
                 Self::ObjectPat(v) => v.set_index(index),
                 Self::ObjectPatProp(v) => v.set_index(index),
                 Self::OptCall(v) => v.set_index(index),
@@ -141524,6 +151285,7 @@ pub mod fields {
                 Self::ParamOrTsParamProp(v) => v.set_index(index),
                 Self::ParenExpr(v) => v.set_index(index),
                 Self::Pat(v) => v.set_index(index),
+                Self::PipelineExpr(v) => v.set_index(index),
                 Self::PrivateMethod(v) => v.set_index(index),
                 Self::PrivateName(v) => v.set_index(index),
                 Self::PrivateProp(v) => v.set_index(index),
@@ -141532,6 +151294,7 @@ pub mod fields {
                 Self::PropName(v) => v.set_index(index),
                 Self::PropOrSpread(v) => v.set_index(index),
                 Self::Regex(v) => v.set_index(index),
+                Self::RelationalMatchPat(v) => v.set_index(index),
                 Self::RestPat(v) => v.set_index(index),
                 Self::ReturnStmt(v) => v.set_index(index),
                 Self::Script(v) => v.set_index(index),
@@ -141550,6 +151313,7 @@ pub mod fields {
                 Self::TaggedTpl(v) => v.set_index(index),
                 Self::ThisExpr(v) => v.set_index(index),
                 Self::ThrowStmt(v) => v.set_index(index),
+                Self::TopicExpr(v) => v.set_index(index),
                 Self::Tpl(v) => v.set_index(index),
                 Self::TplElement(v) => v.set_index(index),
                 Self::TruePlusMinus(v) => v.set_index(index),
@@ -141632,6 +151396,7 @@ pub mod fields {
                 Self::UpdateExpr(v) => v.set_index(index),
                 Self::UpdateOp(v) => v.set_index(index),
                 Self::UsingDecl(v) => v.set_index(index),
+                Self::ValueMatchPat(v) => v.set_index(index),
                 Self::VarDecl(v) => v.set_index(index),
                 Self::VarDeclKind(v) => v.set_index(index),
                 Self::VarDeclOrExpr(v) => v.set_index(index),
@@ -141646,6 +151411,7 @@ pub mod fields {
     pub enum AstParentNodeRef<'ast> {
         Accessibility(&'ast Accessibility, AccessibilityField),
         ArrayLit(&'ast ArrayLit, ArrayLitField),
+        ArrayMatchPat(&'ast ArrayMatchPat, ArrayMatchPatField),
         ArrayPat(&'ast ArrayPat, ArrayPatField),
         ArrowExpr(&'ast ArrowExpr, ArrowExprField),
         AssignExpr(&'ast AssignExpr, AssignExprField),
@@ -141661,6 +151427,7 @@ pub mod fields {
         BinExpr(&'ast BinExpr, BinExprField),
         BinaryOp(&'ast BinaryOp, BinaryOpField),
         BindingIdent(&'ast BindingIdent, BindingIdentField),
+        BindingMatchPat(&'ast BindingMatchPat, BindingMatchPatField),
         BlockStmt(&'ast BlockStmt, BlockStmtField),
         BlockStmtOrExpr(&'ast BlockStmtOrExpr, BlockStmtOrExprField),
         Bool(&'ast Bool, BoolField),
@@ -141674,6 +151441,7 @@ pub mod fields {
         ClassMember(&'ast ClassMember, ClassMemberField),
         ClassMethod(&'ast ClassMethod, ClassMethodField),
         ClassProp(&'ast ClassProp, ClassPropField),
+        CombinedMatchPat(&'ast CombinedMatchPat, CombinedMatchPatField),
         ComputedPropName(&'ast ComputedPropName, ComputedPropNameField),
         CondExpr(&'ast CondExpr, CondExprField),
         Constructor(&'ast Constructor, ConstructorField),
@@ -141682,6 +151450,7 @@ pub mod fields {
         Decl(&'ast Decl, DeclField),
         Decorator(&'ast Decorator, DecoratorField),
         DefaultDecl(&'ast DefaultDecl, DefaultDeclField),
+        DoExpr(&'ast DoExpr, DoExprField),
         DoWhileStmt(&'ast DoWhileStmt, DoWhileStmtField),
         EmptyStmt(&'ast EmptyStmt, EmptyStmtField),
         ExportAll(&'ast ExportAll, ExportAllField),
@@ -141744,6 +151513,11 @@ pub mod fields {
         KeyValueProp(&'ast KeyValueProp, KeyValuePropField),
         LabeledStmt(&'ast LabeledStmt, LabeledStmtField),
         Lit(&'ast Lit, LitField),
+        MatchCase(&'ast MatchCase, MatchCaseField),
+        MatchExpr(&'ast MatchExpr, MatchExprField),
+        MatchPat(&'ast MatchPat, MatchPatField),
+        MatchPatCombinator(&'ast MatchPatCombinator, MatchPatCombinatorField),
+        MatchRest(&'ast MatchRest, MatchRestField),
         MemberExpr(&'ast MemberExpr, MemberExprField),
         MemberProp(&'ast MemberProp, MemberPropField),
         MetaPropExpr(&'ast MetaPropExpr, MetaPropExprField),
@@ -141756,9 +151530,12 @@ pub mod fields {
         ModuleItem(&'ast ModuleItem, ModuleItemField),
         NamedExport(&'ast NamedExport, NamedExportField),
         NewExpr(&'ast NewExpr, NewExprField),
+        NotMatchPat(&'ast NotMatchPat, NotMatchPatField),
         Null(&'ast Null, NullField),
         Number(&'ast Number, NumberField),
         ObjectLit(&'ast ObjectLit, ObjectLitField),
+        ObjectMatchPat(&'ast ObjectMatchPat, ObjectMatchPatField),
+        ObjectMatchPatProp(&'ast ObjectMatchPatProp, ObjectMatchPatPropField),
         ObjectPat(&'ast ObjectPat, ObjectPatField),
         ObjectPatProp(&'ast ObjectPatProp, ObjectPatPropField),
         OptCall(&'ast OptCall, OptCallField),
@@ -141768,6 +151545,7 @@ pub mod fields {
         ParamOrTsParamProp(&'ast ParamOrTsParamProp, ParamOrTsParamPropField),
         ParenExpr(&'ast ParenExpr, ParenExprField),
         Pat(&'ast Pat, PatField),
+        PipelineExpr(&'ast PipelineExpr, PipelineExprField),
         PrivateMethod(&'ast PrivateMethod, PrivateMethodField),
         PrivateName(&'ast PrivateName, PrivateNameField),
         PrivateProp(&'ast PrivateProp, PrivatePropField),
@@ -141776,6 +151554,7 @@ pub mod fields {
         PropName(&'ast PropName, PropNameField),
         PropOrSpread(&'ast PropOrSpread, PropOrSpreadField),
         Regex(&'ast Regex, RegexField),
+        RelationalMatchPat(&'ast RelationalMatchPat, RelationalMatchPatField),
         RestPat(&'ast RestPat, RestPatField),
         ReturnStmt(&'ast ReturnStmt, ReturnStmtField),
         Script(&'ast Script, ScriptField),
@@ -141794,6 +151573,7 @@ pub mod fields {
         TaggedTpl(&'ast TaggedTpl, TaggedTplField),
         ThisExpr(&'ast ThisExpr, ThisExprField),
         ThrowStmt(&'ast ThrowStmt, ThrowStmtField),
+        TopicExpr(&'ast TopicExpr, TopicExprField),
         Tpl(&'ast Tpl, TplField),
         TplElement(&'ast TplElement, TplElementField),
         TruePlusMinus(&'ast TruePlusMinus, TruePlusMinusField),
@@ -141885,6 +151665,7 @@ pub mod fields {
         UpdateExpr(&'ast UpdateExpr, UpdateExprField),
         UpdateOp(&'ast UpdateOp, UpdateOpField),
         UsingDecl(&'ast UsingDecl, UsingDeclField),
+        ValueMatchPat(&'ast ValueMatchPat, ValueMatchPatField),
         VarDecl(&'ast VarDecl, VarDeclField),
         VarDeclKind(&'ast VarDeclKind, VarDeclKindField),
         VarDeclOrExpr(&'ast VarDeclOrExpr, VarDeclOrExprField),
@@ -141905,6 +151686,7 @@ pub mod fields {
             match self {
                 Self::Accessibility(_, __field_kind) => __field_kind.set_index(index),
                 Self::ArrayLit(_, __field_kind) => __field_kind.set_index(index),
+                Self::ArrayMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ArrayPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ArrowExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::AssignExpr(_, __field_kind) => __field_kind.set_index(index),
@@ -141920,6 +151702,7 @@ pub mod fields {
                 Self::BinExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::BinaryOp(_, __field_kind) => __field_kind.set_index(index),
                 Self::BindingIdent(_, __field_kind) => __field_kind.set_index(index),
+                Self::BindingMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::BlockStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::BlockStmtOrExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Bool(_, __field_kind) => __field_kind.set_index(index),
@@ -141933,6 +151716,7 @@ pub mod fields {
                 Self::ClassMember(_, __field_kind) => __field_kind.set_index(index),
                 Self::ClassMethod(_, __field_kind) => __field_kind.set_index(index),
                 Self::ClassProp(_, __field_kind) => __field_kind.set_index(index),
+                Self::CombinedMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ComputedPropName(_, __field_kind) => __field_kind.set_index(index),
                 Self::CondExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Constructor(_, __field_kind) => __field_kind.set_index(index),
@@ -141941,6 +151725,7 @@ pub mod fields {
                 Self::Decl(_, __field_kind) => __field_kind.set_index(index),
                 Self::Decorator(_, __field_kind) => __field_kind.set_index(index),
                 Self::DefaultDecl(_, __field_kind) => __field_kind.set_index(index),
+                Self::DoExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::DoWhileStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::EmptyStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::ExportAll(_, __field_kind) => __field_kind.set_index(index),
@@ -142000,6 +151785,11 @@ pub mod fields {
                 Self::KeyValueProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::LabeledStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::Lit(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchCase(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchExpr(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchPat(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchPatCombinator(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchRest(_, __field_kind) => __field_kind.set_index(index),
                 Self::MemberExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::MemberProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::MetaPropExpr(_, __field_kind) => __field_kind.set_index(index),
@@ -142012,9 +151802,12 @@ pub mod fields {
                 Self::ModuleItem(_, __field_kind) => __field_kind.set_index(index),
                 Self::NamedExport(_, __field_kind) => __field_kind.set_index(index),
                 Self::NewExpr(_, __field_kind) => __field_kind.set_index(index),
+                Self::NotMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::Null(_, __field_kind) => __field_kind.set_index(index),
                 Self::Number(_, __field_kind) => __field_kind.set_index(index),
                 Self::ObjectLit(_, __field_kind) => __field_kind.set_index(index),
+                Self::ObjectMatchPat(_, __field_kind) => __field_kind.set_index(index),
+                Self::ObjectMatchPatProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::ObjectPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ObjectPatProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::OptCall(_, __field_kind) => __field_kind.set_index(index),
@@ -142024,6 +151817,7 @@ pub mod fields {
                 Self::ParamOrTsParamProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::ParenExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Pat(_, __field_kind) => __field_kind.set_index(index),
+                Self::PipelineExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateMethod(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateName(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateProp(_, __field_kind) => __field_kind.set_index(index),
@@ -142032,6 +151826,7 @@ pub mod fields {
                 Self::PropName(_, __field_kind) => __field_kind.set_index(index),
                 Self::PropOrSpread(_, __field_kind) => __field_kind.set_index(index),
                 Self::Regex(_, __field_kind) => __field_kind.set_index(index),
+                Self::RelationalMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::RestPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ReturnStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::Script(_, __field_kind) => __field_kind.set_index(index),
@@ -142050,6 +151845,7 @@ pub mod fields {
                 Self::TaggedTpl(_, __field_kind) => __field_kind.set_index(index),
                 Self::ThisExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::ThrowStmt(_, __field_kind) => __field_kind.set_index(index),
+                Self::TopicExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Tpl(_, __field_kind) => __field_kind.set_index(index),
                 Self::TplElement(_, __field_kind) => __field_kind.set_index(index),
                 Self::TruePlusMinus(_, __field_kind) => __field_kind.set_index(index),
@@ -142132,6 +151928,7 @@ pub mod fields {
                 Self::UpdateExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::UpdateOp(_, __field_kind) => __field_kind.set_index(index),
                 Self::UsingDecl(_, __field_kind) => __field_kind.set_index(index),
+                Self::ValueMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::VarDecl(_, __field_kind) => __field_kind.set_index(index),
                 Self::VarDeclKind(_, __field_kind) => __field_kind.set_index(index),
                 Self::VarDeclOrExpr(_, __field_kind) => __field_kind.set_index(index),
@@ -142149,6 +151946,7 @@ pub mod fields {
             match self {
                 Self::Accessibility(_, __field_kind) => AstParentKind::Accessibility(*__field_kind),
                 Self::ArrayLit(_, __field_kind) => AstParentKind::ArrayLit(*__field_kind),
+                Self::ArrayMatchPat(_, __field_kind) => AstParentKind::ArrayMatchPat(*__field_kind),
                 Self::ArrayPat(_, __field_kind) => AstParentKind::ArrayPat(*__field_kind),
                 Self::ArrowExpr(_, __field_kind) => AstParentKind::ArrowExpr(*__field_kind),
                 Self::AssignExpr(_, __field_kind) => AstParentKind::AssignExpr(*__field_kind),
@@ -142166,6 +151964,9 @@ pub mod fields {
                 Self::BinExpr(_, __field_kind) => AstParentKind::BinExpr(*__field_kind),
                 Self::BinaryOp(_, __field_kind) => AstParentKind::BinaryOp(*__field_kind),
                 Self::BindingIdent(_, __field_kind) => AstParentKind::BindingIdent(*__field_kind),
+                Self::BindingMatchPat(_, __field_kind) => {
+                    AstParentKind::BindingMatchPat(*__field_kind)
+                }
                 Self::BlockStmt(_, __field_kind) => AstParentKind::BlockStmt(*__field_kind),
                 Self::BlockStmtOrExpr(_, __field_kind) => {
                     AstParentKind::BlockStmtOrExpr(*__field_kind)
@@ -142181,6 +151982,9 @@ pub mod fields {
                 Self::ClassMember(_, __field_kind) => AstParentKind::ClassMember(*__field_kind),
                 Self::ClassMethod(_, __field_kind) => AstParentKind::ClassMethod(*__field_kind),
                 Self::ClassProp(_, __field_kind) => AstParentKind::ClassProp(*__field_kind),
+                Self::CombinedMatchPat(_, __field_kind) => {
+                    AstParentKind::CombinedMatchPat(*__field_kind)
+                }
                 Self::ComputedPropName(_, __field_kind) => {
                     AstParentKind::ComputedPropName(*__field_kind)
                 }
@@ -142191,6 +151995,7 @@ pub mod fields {
                 Self::Decl(_, __field_kind) => AstParentKind::Decl(*__field_kind),
                 Self::Decorator(_, __field_kind) => AstParentKind::Decorator(*__field_kind),
                 Self::DefaultDecl(_, __field_kind) => AstParentKind::DefaultDecl(*__field_kind),
+                Self::DoExpr(_, __field_kind) => AstParentKind::DoExpr(*__field_kind),
                 Self::DoWhileStmt(_, __field_kind) => AstParentKind::DoWhileStmt(*__field_kind),
                 Self::EmptyStmt(_, __field_kind) => AstParentKind::EmptyStmt(*__field_kind),
                 Self::ExportAll(_, __field_kind) => AstParentKind::ExportAll(*__field_kind),
@@ -142294,6 +152099,13 @@ pub mod fields {
                 Self::KeyValueProp(_, __field_kind) => AstParentKind::KeyValueProp(*__field_kind),
                 Self::LabeledStmt(_, __field_kind) => AstParentKind::LabeledStmt(*__field_kind),
                 Self::Lit(_, __field_kind) => AstParentKind::Lit(*__field_kind),
+                Self::MatchCase(_, __field_kind) => AstParentKind::MatchCase(*__field_kind),
+                Self::MatchExpr(_, __field_kind) => AstParentKind::MatchExpr(*__field_kind),
+                Self::MatchPat(_, __field_kind) => AstParentKind::MatchPat(*__field_kind),
+                Self::MatchPatCombinator(_, __field_kind) => {
+                    AstParentKind::MatchPatCombinator(*__field_kind)
+                }
+                Self::MatchRest(_, __field_kind) => AstParentKind::MatchRest(*__field_kind),
                 Self::MemberExpr(_, __field_kind) => AstParentKind::MemberExpr(*__field_kind),
                 Self::MemberProp(_, __field_kind) => AstParentKind::MemberProp(*__field_kind),
                 Self::MetaPropExpr(_, __field_kind) => AstParentKind::MetaPropExpr(*__field_kind),
@@ -142308,9 +152120,16 @@ pub mod fields {
                 Self::ModuleItem(_, __field_kind) => AstParentKind::ModuleItem(*__field_kind),
                 Self::NamedExport(_, __field_kind) => AstParentKind::NamedExport(*__field_kind),
                 Self::NewExpr(_, __field_kind) => AstParentKind::NewExpr(*__field_kind),
+                Self::NotMatchPat(_, __field_kind) => AstParentKind::NotMatchPat(*__field_kind),
                 Self::Null(_, __field_kind) => AstParentKind::Null(*__field_kind),
                 Self::Number(_, __field_kind) => AstParentKind::Number(*__field_kind),
                 Self::ObjectLit(_, __field_kind) => AstParentKind::ObjectLit(*__field_kind),
+                Self::ObjectMatchPat(_, __field_kind) => {
+                    AstParentKind::ObjectMatchPat(*__field_kind)
+                }
+                Self::ObjectMatchPatProp(_, __field_kind) => {
+                    AstParentKind::ObjectMatchPatProp(*__field_kind)
+                }
                 Self::ObjectPat(_, __field_kind) => AstParentKind::ObjectPat(*__field_kind),
                 Self::ObjectPatProp(_, __field_kind) => AstParentKind::ObjectPatProp(*__field_kind),
                 Self::OptCall(_, __field_kind) => AstParentKind::OptCall(*__field_kind),
@@ -142322,6 +152141,7 @@ pub mod fields {
                 }
                 Self::ParenExpr(_, __field_kind) => AstParentKind::ParenExpr(*__field_kind),
                 Self::Pat(_, __field_kind) => AstParentKind::Pat(*__field_kind),
+                Self::PipelineExpr(_, __field_kind) => AstParentKind::PipelineExpr(*__field_kind),
                 Self::PrivateMethod(_, __field_kind) => AstParentKind::PrivateMethod(*__field_kind),
                 Self::PrivateName(_, __field_kind) => AstParentKind::PrivateName(*__field_kind),
                 Self::PrivateProp(_, __field_kind) => AstParentKind::PrivateProp(*__field_kind),
@@ -142330,6 +152150,9 @@ pub mod fields {
                 Self::PropName(_, __field_kind) => AstParentKind::PropName(*__field_kind),
                 Self::PropOrSpread(_, __field_kind) => AstParentKind::PropOrSpread(*__field_kind),
                 Self::Regex(_, __field_kind) => AstParentKind::Regex(*__field_kind),
+                Self::RelationalMatchPat(_, __field_kind) => {
+                    AstParentKind::RelationalMatchPat(*__field_kind)
+                }
                 Self::RestPat(_, __field_kind) => AstParentKind::RestPat(*__field_kind),
                 Self::ReturnStmt(_, __field_kind) => AstParentKind::ReturnStmt(*__field_kind),
                 Self::Script(_, __field_kind) => AstParentKind::Script(*__field_kind),
@@ -142350,6 +152173,7 @@ pub mod fields {
                 Self::TaggedTpl(_, __field_kind) => AstParentKind::TaggedTpl(*__field_kind),
                 Self::ThisExpr(_, __field_kind) => AstParentKind::ThisExpr(*__field_kind),
                 Self::ThrowStmt(_, __field_kind) => AstParentKind::ThrowStmt(*__field_kind),
+                Self::TopicExpr(_, __field_kind) => AstParentKind::TopicExpr(*__field_kind),
                 Self::Tpl(_, __field_kind) => AstParentKind::Tpl(*__field_kind),
                 Self::TplElement(_, __field_kind) => AstParentKind::TplElement(*__field_kind),
                 Self::TruePlusMinus(_, __field_kind) => AstParentKind::TruePlusMinus(*__field_kind),
@@ -142516,6 +152340,7 @@ pub mod fields {
                 Self::UpdateExpr(_, __field_kind) => AstParentKind::UpdateExpr(*__field_kind),
                 Self::UpdateOp(_, __field_kind) => AstParentKind::UpdateOp(*__field_kind),
                 Self::UsingDecl(_, __field_kind) => AstParentKind::UsingDecl(*__field_kind),
+                Self::ValueMatchPat(_, __field_kind) => AstParentKind::ValueMatchPat(*__field_kind),
                 Self::VarDecl(_, __field_kind) => AstParentKind::VarDecl(*__field_kind),
                 Self::VarDeclKind(_, __field_kind) => AstParentKind::VarDeclKind(*__field_kind),
                 Self::VarDeclOrExpr(_, __field_kind) => AstParentKind::VarDeclOrExpr(*__field_kind),
@@ -142537,6 +152362,11 @@ impl<'ast> From<&'ast ArrayLit> for NodeRef<'ast> {
         NodeRef::ArrayLit(node)
     }
 }
+impl<'ast> From<&'ast ArrayMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast ArrayMatchPat) -> Self {
+        NodeRef::ArrayMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast ArrayPat> for NodeRef<'ast> {
     fn from(node: &'ast ArrayPat) -> Self {
         NodeRef::ArrayPat(node)
@@ -142612,6 +152442,11 @@ impl<'ast> From<&'ast BindingIdent> for NodeRef<'ast> {
         NodeRef::BindingIdent(node)
     }
 }
+impl<'ast> From<&'ast BindingMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast BindingMatchPat) -> Self {
+        NodeRef::BindingMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast BlockStmt> for NodeRef<'ast> {
     fn from(node: &'ast BlockStmt) -> Self {
         NodeRef::BlockStmt(node)
@@ -142677,6 +152512,11 @@ impl<'ast> From<&'ast ClassProp> for NodeRef<'ast> {
         NodeRef::ClassProp(node)
     }
 }
+impl<'ast> From<&'ast CombinedMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast CombinedMatchPat) -> Self {
+        NodeRef::CombinedMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast ComputedPropName> for NodeRef<'ast> {
     fn from(node: &'ast ComputedPropName) -> Self {
         NodeRef::ComputedPropName(node)
@@ -142717,6 +152557,11 @@ impl<'ast> From<&'ast DefaultDecl> for NodeRef<'ast> {
         NodeRef::DefaultDecl(node)
     }
 }
+impl<'ast> From<&'ast DoExpr> for NodeRef<'ast> {
+    fn from(node: &'ast DoExpr) -> Self {
+        NodeRef::DoExpr(node)
+    }
+}
 impl<'ast> From<&'ast DoWhileStmt> for NodeRef<'ast> {
     fn from(node: &'ast DoWhileStmt) -> Self {
         NodeRef::DoWhileStmt(node)
@@ -143012,6 +152857,31 @@ impl<'ast> From<&'ast Lit> for NodeRef<'ast> {
         NodeRef::Lit(node)
     }
 }
+impl<'ast> From<&'ast MatchCase> for NodeRef<'ast> {
+    fn from(node: &'ast MatchCase) -> Self {
+        NodeRef::MatchCase(node)
+    }
+}
+impl<'ast> From<&'ast MatchExpr> for NodeRef<'ast> {
+    fn from(node: &'ast MatchExpr) -> Self {
+        NodeRef::MatchExpr(node)
+    }
+}
+impl<'ast> From<&'ast MatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast MatchPat) -> Self {
+        NodeRef::MatchPat(node)
+    }
+}
+impl<'ast> From<&'ast MatchPatCombinator> for NodeRef<'ast> {
+    fn from(node: &'ast MatchPatCombinator) -> Self {
+        NodeRef::MatchPatCombinator(node)
+    }
+}
+impl<'ast> From<&'ast MatchRest> for NodeRef<'ast> {
+    fn from(node: &'ast MatchRest) -> Self {
+        NodeRef::MatchRest(node)
+    }
+}
 impl<'ast> From<&'ast MemberExpr> for NodeRef<'ast> {
     fn from(node: &'ast MemberExpr) -> Self {
         NodeRef::MemberExpr(node)
@@ -143072,6 +152942,11 @@ impl<'ast> From<&'ast NewExpr> for NodeRef<'ast> {
         NodeRef::NewExpr(node)
     }
 }
+impl<'ast> From<&'ast NotMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast NotMatchPat) -> Self {
+        NodeRef::NotMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast Null> for NodeRef<'ast> {
     fn from(node: &'ast Null) -> Self {
         NodeRef::Null(node)
@@ -143087,6 +152962,16 @@ impl<'ast> From<&'ast ObjectLit> for NodeRef<'ast> {
         NodeRef::ObjectLit(node)
     }
 }
+impl<'ast> From<&'ast ObjectMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast ObjectMatchPat) -> Self {
+        NodeRef::ObjectMatchPat(node)
+    }
+}
+impl<'ast> From<&'ast ObjectMatchPatProp> for NodeRef<'ast> {
+    fn from(node: &'ast ObjectMatchPatProp) -> Self {
+        NodeRef::ObjectMatchPatProp(node)
+    }
+}
 impl<'ast> From<&'ast ObjectPat> for NodeRef<'ast> {
     fn from(node: &'ast ObjectPat) -> Self {
         NodeRef::ObjectPat(node)
@@ -143132,6 +153017,11 @@ impl<'ast> From<&'ast Pat> for NodeRef<'ast> {
         NodeRef::Pat(node)
     }
 }
+impl<'ast> From<&'ast PipelineExpr> for NodeRef<'ast> {
+    fn from(node: &'ast PipelineExpr) -> Self {
+        NodeRef::PipelineExpr(node)
+    }
+}
 impl<'ast> From<&'ast PrivateMethod> for NodeRef<'ast> {
     fn from(node: &'ast PrivateMethod) -> Self {
         NodeRef::PrivateMethod(node)
@@ -143172,6 +153062,11 @@ impl<'ast> From<&'ast Regex> for NodeRef<'ast> {
         NodeRef::Regex(node)
     }
 }
+impl<'ast> From<&'ast RelationalMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast RelationalMatchPat) -> Self {
+        NodeRef::RelationalMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast RestPat> for NodeRef<'ast> {
     fn from(node: &'ast RestPat) -> Self {
         NodeRef::RestPat(node)
@@ -143262,6 +153157,11 @@ impl<'ast> From<&'ast ThrowStmt> for NodeRef<'ast> {
         NodeRef::ThrowStmt(node)
     }
 }
+impl<'ast> From<&'ast TopicExpr> for NodeRef<'ast> {
+    fn from(node: &'ast TopicExpr) -> Self {
+        NodeRef::TopicExpr(node)
+    }
+}
 impl<'ast> From<&'ast Tpl> for NodeRef<'ast> {
     fn from(node: &'ast Tpl) -> Self {
         NodeRef::Tpl(node)
@@ -143672,6 +153572,11 @@ impl<'ast> From<&'ast UsingDecl> for NodeRef<'ast> {
         NodeRef::UsingDecl(node)
     }
 }
+impl<'ast> From<&'ast ValueMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast ValueMatchPat) -> Self {
+        NodeRef::ValueMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast VarDecl> for NodeRef<'ast> {
     fn from(node: &'ast VarDecl) -> Self {
         NodeRef::VarDecl(node)
@@ -143711,6 +153616,7 @@ impl<'ast> From<&'ast YieldExpr> for NodeRef<'ast> {
 pub enum NodeRef<'ast> {
     Accessibility(&'ast Accessibility),
     ArrayLit(&'ast ArrayLit),
+    ArrayMatchPat(&'ast ArrayMatchPat),
     ArrayPat(&'ast ArrayPat),
     ArrowExpr(&'ast ArrowExpr),
     AssignExpr(&'ast AssignExpr),
@@ -143726,6 +153632,7 @@ pub enum NodeRef<'ast> {
     BinExpr(&'ast BinExpr),
     BinaryOp(&'ast BinaryOp),
     BindingIdent(&'ast BindingIdent),
+    BindingMatchPat(&'ast BindingMatchPat),
     BlockStmt(&'ast BlockStmt),
     BlockStmtOrExpr(&'ast BlockStmtOrExpr),
     Bool(&'ast Bool),
@@ -143739,6 +153646,7 @@ pub enum NodeRef<'ast> {
     ClassMember(&'ast ClassMember),
     ClassMethod(&'ast ClassMethod),
     ClassProp(&'ast ClassProp),
+    CombinedMatchPat(&'ast CombinedMatchPat),
     ComputedPropName(&'ast ComputedPropName),
     CondExpr(&'ast CondExpr),
     Constructor(&'ast Constructor),
@@ -143747,6 +153655,7 @@ pub enum NodeRef<'ast> {
     Decl(&'ast Decl),
     Decorator(&'ast Decorator),
     DefaultDecl(&'ast DefaultDecl),
+    DoExpr(&'ast DoExpr),
     DoWhileStmt(&'ast DoWhileStmt),
     EmptyStmt(&'ast EmptyStmt),
     ExportAll(&'ast ExportAll),
@@ -143806,6 +153715,11 @@ pub enum NodeRef<'ast> {
     KeyValueProp(&'ast KeyValueProp),
     LabeledStmt(&'ast LabeledStmt),
     Lit(&'ast Lit),
+    MatchCase(&'ast MatchCase),
+    MatchExpr(&'ast MatchExpr),
+    MatchPat(&'ast MatchPat),
+    MatchPatCombinator(&'ast MatchPatCombinator),
+    MatchRest(&'ast MatchRest),
     MemberExpr(&'ast MemberExpr),
     MemberProp(&'ast MemberProp),
     MetaPropExpr(&'ast MetaPropExpr),
@@ -143818,9 +153732,12 @@ pub enum NodeRef<'ast> {
     ModuleItem(&'ast ModuleItem),
     NamedExport(&'ast NamedExport),
     NewExpr(&'ast NewExpr),
+    NotMatchPat(&'ast NotMatchPat),
     Null(&'ast Null),
     Number(&'ast Number),
     ObjectLit(&'ast ObjectLit),
+    ObjectMatchPat(&'ast ObjectMatchPat),
+    ObjectMatchPatProp(&'ast ObjectMatchPatProp),
     ObjectPat(&'ast ObjectPat),
     ObjectPatProp(&'ast ObjectPatProp),
     OptCall(&'ast OptCall),
@@ -143830,6 +153747,7 @@ pub enum NodeRef<'ast> {
     ParamOrTsParamProp(&'ast ParamOrTsParamProp),
     ParenExpr(&'ast ParenExpr),
     Pat(&'ast Pat),
+    PipelineExpr(&'ast PipelineExpr),
     PrivateMethod(&'ast PrivateMethod),
     PrivateName(&'ast PrivateName),
     PrivateProp(&'ast PrivateProp),
@@ -143838,6 +153756,7 @@ pub enum NodeRef<'ast> {
     PropName(&'ast PropName),
     PropOrSpread(&'ast PropOrSpread),
     Regex(&'ast Regex),
+    RelationalMatchPat(&'ast RelationalMatchPat),
     RestPat(&'ast RestPat),
     ReturnStmt(&'ast ReturnStmt),
     Script(&'ast Script),
@@ -143856,6 +153775,7 @@ pub enum NodeRef<'ast> {
     TaggedTpl(&'ast TaggedTpl),
     ThisExpr(&'ast ThisExpr),
     ThrowStmt(&'ast ThrowStmt),
+    TopicExpr(&'ast TopicExpr),
     Tpl(&'ast Tpl),
     TplElement(&'ast TplElement),
     TruePlusMinus(&'ast TruePlusMinus),
@@ -143938,6 +153858,7 @@ pub enum NodeRef<'ast> {
     UpdateExpr(&'ast UpdateExpr),
     UpdateOp(&'ast UpdateOp),
     UsingDecl(&'ast UsingDecl),
+    ValueMatchPat(&'ast ValueMatchPat),
     VarDecl(&'ast VarDecl),
     VarDeclKind(&'ast VarDeclKind),
     VarDeclOrExpr(&'ast VarDeclOrExpr),
@@ -143963,6 +153884,20 @@ impl<'ast> NodeRef<'ast> {
                 );
                 Box::new(iterator)
             }
+            NodeRef::ArrayMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(
+                        node.elems
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchPat(&item))),
+                    )
+                    .chain(
+                        node.rest
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchRest(&item))),
+                    );
+                Box::new(iterator)
+            }
             NodeRef::ArrayPat(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(node.elems.iter().flat_map(|item| {
@@ -144111,6 +154046,12 @@ impl<'ast> NodeRef<'ast> {
                     }));
                 Box::new(iterator)
             }
+            NodeRef::BindingMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::VarDeclKind(&node.kind)))
+                    .chain(::std::iter::once(NodeRef::BindingIdent(&node.id)));
+                Box::new(iterator)
+            }
             NodeRef::BlockStmt(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain(
                     node.stmts
@@ -144282,6 +154223,16 @@ impl<'ast> NodeRef<'ast> {
                     );
                 Box::new(iterator)
             }
+            NodeRef::CombinedMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::MatchPatCombinator(&node.op)))
+                    .chain(
+                        node.pats
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchPat(&item))),
+                    );
+                Box::new(iterator)
+            }
             NodeRef::ComputedPropName(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
                     let item = &*node.expr;
@@ -144363,6 +154314,11 @@ impl<'ast> NodeRef<'ast> {
                 }
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::DoExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::BlockStmt(&node.body)));
+                Box::new(iterator)
+            }
             NodeRef::DoWhileStmt(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain({
@@ -144486,6 +154442,10 @@ impl<'ast> NodeRef<'ast> {
                 Expr::TsSatisfies(v0) => Box::new(::std::iter::once(NodeRef::TsSatisfiesExpr(v0))),
                 Expr::PrivateName(v0) => Box::new(::std::iter::once(NodeRef::PrivateName(v0))),
                 Expr::OptChain(v0) => Box::new(::std::iter::once(NodeRef::OptChainExpr(v0))),
+                Expr::Pipeline(v0) => Box::new(::std::iter::once(NodeRef::PipelineExpr(v0))),
+                Expr::Topic(v0) => Box::new(::std::iter::once(NodeRef::TopicExpr(v0))),
+                Expr::Match(v0) => Box::new(::std::iter::once(NodeRef::MatchExpr(v0))),
+                Expr::Do(v0) => Box::new(::std::iter::once(NodeRef::DoExpr(v0))),
                 Expr::Invalid(v0) => Box::new(::std::iter::once(NodeRef::Invalid(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
@@ -144925,6 +154885,60 @@ impl<'ast> NodeRef<'ast> {
                 Lit::JSXText(v0) => Box::new(::std::iter::once(NodeRef::JSXText(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::MatchCase(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(node.pat.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::MatchPat(&item))
+                    }))
+                    .chain(node.guard.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    }))
+                    .chain({
+                        let item = &*node.body;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
+            NodeRef::MatchExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain({
+                        let item = &*node.subject;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    })
+                    .chain(
+                        node.cases
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchCase(&item))),
+                    );
+                Box::new(iterator)
+            }
+            NodeRef::MatchPat(node) => match node {
+                MatchPat::Binding(v0) => Box::new(::std::iter::once(NodeRef::BindingMatchPat(v0))),
+                MatchPat::Value(v0) => Box::new(::std::iter::once(NodeRef::ValueMatchPat(v0))),
+                MatchPat::Relational(v0) => {
+                    Box::new(::std::iter::once(NodeRef::RelationalMatchPat(v0)))
+                }
+                MatchPat::Array(v0) => Box::new(::std::iter::once(NodeRef::ArrayMatchPat(v0))),
+                MatchPat::Object(v0) => Box::new(::std::iter::once(NodeRef::ObjectMatchPat(v0))),
+                MatchPat::Combined(v0) => {
+                    Box::new(::std::iter::once(NodeRef::CombinedMatchPat(v0)))
+                }
+                MatchPat::Not(v0) => Box::new(::std::iter::once(NodeRef::NotMatchPat(v0))),
+                _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
+            },
+            NodeRef::MatchPatCombinator(node) => match node {
+                _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
+            },
+            NodeRef::MatchRest(node) => {
+                let iterator =
+                    ::std::iter::empty::<NodeRef<'ast>>().chain(node.arg.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::MatchPat(&item))
+                    }));
+                Box::new(iterator)
+            }
             NodeRef::MemberExpr(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain({
@@ -145039,6 +155053,13 @@ impl<'ast> NodeRef<'ast> {
                     }));
                 Box::new(iterator)
             }
+            NodeRef::NotMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
+                    let item = &*node.arg;
+                    ::std::iter::once(NodeRef::MatchPat(&item))
+                });
+                Box::new(iterator)
+            }
             NodeRef::Null(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>();
                 Box::new(iterator)
@@ -145055,6 +155076,23 @@ impl<'ast> NodeRef<'ast> {
                 );
                 Box::new(iterator)
             }
+            NodeRef::ObjectMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain(
+                    node.props
+                        .iter()
+                        .flat_map(|item| ::std::iter::once(NodeRef::ObjectMatchPatProp(&item))),
+                );
+                Box::new(iterator)
+            }
+            NodeRef::ObjectMatchPatProp(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::PropName(&node.key)))
+                    .chain(node.value.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::MatchPat(&item))
+                    }));
+                Box::new(iterator)
+            }
             NodeRef::ObjectPat(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(
@@ -145141,6 +155179,18 @@ impl<'ast> NodeRef<'ast> {
                 Pat::Expr(v0) => Box::new(::std::iter::once(NodeRef::Expr(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::PipelineExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain({
+                        let item = &*node.left;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    })
+                    .chain({
+                        let item = &*node.right;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
             NodeRef::PrivateMethod(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(::std::iter::once(NodeRef::PrivateName(&node.key)))
@@ -145216,6 +155266,15 @@ impl<'ast> NodeRef<'ast> {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>();
                 Box::new(iterator)
             }
+            NodeRef::RelationalMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::BinaryOp(&node.op)))
+                    .chain({
+                        let item = &*node.value;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
             NodeRef::RestPat(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain({
@@ -145414,6 +155473,10 @@ impl<'ast> NodeRef<'ast> {
                 });
                 Box::new(iterator)
             }
+            NodeRef::TopicExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>();
+                Box::new(iterator)
+            }
             NodeRef::Tpl(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(node.exprs.iter().flat_map(|item| {
@@ -146240,6 +156303,13 @@ impl<'ast> NodeRef<'ast> {
                 );
                 Box::new(iterator)
             }
+            NodeRef::ValueMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
+                    let item = &*node.value;
+                    ::std::iter::once(NodeRef::Expr(&item))
+                });
+                Box::new(iterator)
+            }
             NodeRef::VarDecl(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(::std::iter::once(NodeRef::VarDeclKind(&node.kind)))
@@ -12,6 +12,13 @@ pub trait Visit {
     fn visit_array_lit(&mut self, node: &ArrayLit) {
         <ArrayLit as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        <ArrayMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -130,6 +137,13 @@ pub trait Visit {
     fn visit_binding_ident(&mut self, node: &BindingIdent) {
         <BindingIdent as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        <BindingMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -225,6 +239,13 @@ pub trait Visit {
     fn visit_class_prop(&mut self, node: &ClassProp) {
         <ClassProp as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        <CombinedMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_children_with`]. If you want to recurse, you need to call \
              it manually."]
@@ -287,6 +308,12 @@ pub trait Visit {
     fn visit_default_decl(&mut self, node: &DefaultDecl) {
         <DefaultDecl as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_children_with`]. If you want to recurse, you need to call it manually."]
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        <DoExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -741,6 +768,55 @@ pub trait Visit {
     fn visit_lit(&mut self, node: &Lit) {
         <Lit as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        <MatchCase as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        <[MatchCase] as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        <MatchExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        <MatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        <MatchPatCombinator as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        <[MatchPat] as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        <MatchRest as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -831,6 +907,13 @@ pub trait Visit {
     fn visit_new_expr(&mut self, node: &NewExpr) {
         <NewExpr as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        <NotMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -850,6 +933,27 @@ pub trait Visit {
     fn visit_object_lit(&mut self, node: &ObjectLit) {
         <ObjectLit as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        <ObjectMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        <ObjectMatchPatProp as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        <[ObjectMatchPatProp] as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -954,6 +1058,20 @@ pub trait Visit {
     fn visit_opt_jsx_closing_element(&mut self, node: &Option<JSXClosingElement>) {
         <Option<JSXClosingElement> as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_children_with`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        <Option<Box<MatchPat>> as VisitWith<Self>>::visit_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        <Option<MatchRest> as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_children_with`]. If you want to recurse, you \
              need to call it manually."]
@@ -1061,6 +1179,13 @@ pub trait Visit {
     fn visit_pats(&mut self, node: &[Pat]) {
         <[Pat] as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <PipelineExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -1122,6 +1247,13 @@ pub trait Visit {
     fn visit_regex(&mut self, node: &Regex) {
         <Regex as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        <RelationalMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -1271,6 +1403,13 @@ pub trait Visit {
     fn visit_throw_stmt(&mut self, node: &ThrowStmt) {
         <ThrowStmt as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        <TopicExpr as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -1340,6 +1479,13 @@ pub trait Visit {
     fn visit_using_decl(&mut self, node: &UsingDecl) {
         <UsingDecl as VisitWith<Self>>::visit_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        <ValueMatchPat as VisitWith<Self>>::visit_children_with(node, self)
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -1406,6 +1552,11 @@ where
         <V as Visit>::visit_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        <V as Visit>::visit_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         <V as Visit>::visit_array_pat(&mut **self, node)
@@ -1491,6 +1642,11 @@ where
         <V as Visit>::visit_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        <V as Visit>::visit_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         <V as Visit>::visit_block_stmt(&mut **self, node)
@@ -1561,6 +1717,11 @@ where
         <V as Visit>::visit_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        <V as Visit>::visit_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         <V as Visit>::visit_computed_prop_name(&mut **self, node)
@@ -1606,6 +1767,11 @@ where
         <V as Visit>::visit_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        <V as Visit>::visit_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         <V as Visit>::visit_do_while_stmt(&mut **self, node)
@@ -1936,6 +2102,41 @@ where
         <V as Visit>::visit_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        <V as Visit>::visit_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        <V as Visit>::visit_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        <V as Visit>::visit_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        <V as Visit>::visit_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        <V as Visit>::visit_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        <V as Visit>::visit_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        <V as Visit>::visit_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         <V as Visit>::visit_member_expr(&mut **self, node)
@@ -2001,6 +2202,11 @@ where
         <V as Visit>::visit_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        <V as Visit>::visit_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         <V as Visit>::visit_null(&mut **self, node)
@@ -2016,6 +2222,21 @@ where
         <V as Visit>::visit_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        <V as Visit>::visit_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        <V as Visit>::visit_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        <V as Visit>::visit_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         <V as Visit>::visit_object_pat(&mut **self, node)
@@ -2091,6 +2312,16 @@ where
         <V as Visit>::visit_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        <V as Visit>::visit_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        <V as Visit>::visit_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         <V as Visit>::visit_opt_module_export_name(&mut **self, node)
@@ -2171,6 +2402,11 @@ where
         <V as Visit>::visit_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <V as Visit>::visit_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         <V as Visit>::visit_private_method(&mut **self, node)
@@ -2216,6 +2452,11 @@ where
         <V as Visit>::visit_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        <V as Visit>::visit_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         <V as Visit>::visit_rest_pat(&mut **self, node)
@@ -2326,6 +2567,11 @@ where
         <V as Visit>::visit_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        <V as Visit>::visit_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         <V as Visit>::visit_tpl(&mut **self, node)
@@ -2376,6 +2622,11 @@ where
         <V as Visit>::visit_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        <V as Visit>::visit_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         <V as Visit>::visit_var_decl(&mut **self, node)
@@ -2425,6 +2676,11 @@ where
         <V as Visit>::visit_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        <V as Visit>::visit_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         <V as Visit>::visit_array_pat(&mut **self, node)
@@ -2510,6 +2766,11 @@ where
         <V as Visit>::visit_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        <V as Visit>::visit_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         <V as Visit>::visit_block_stmt(&mut **self, node)
@@ -2580,6 +2841,11 @@ where
         <V as Visit>::visit_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        <V as Visit>::visit_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         <V as Visit>::visit_computed_prop_name(&mut **self, node)
@@ -2625,6 +2891,11 @@ where
         <V as Visit>::visit_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        <V as Visit>::visit_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         <V as Visit>::visit_do_while_stmt(&mut **self, node)
@@ -2955,6 +3226,41 @@ where
         <V as Visit>::visit_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        <V as Visit>::visit_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        <V as Visit>::visit_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        <V as Visit>::visit_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        <V as Visit>::visit_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        <V as Visit>::visit_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        <V as Visit>::visit_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        <V as Visit>::visit_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         <V as Visit>::visit_member_expr(&mut **self, node)
@@ -3020,6 +3326,11 @@ where
         <V as Visit>::visit_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        <V as Visit>::visit_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         <V as Visit>::visit_null(&mut **self, node)
@@ -3035,6 +3346,21 @@ where
         <V as Visit>::visit_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        <V as Visit>::visit_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        <V as Visit>::visit_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        <V as Visit>::visit_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         <V as Visit>::visit_object_pat(&mut **self, node)
@@ -3110,6 +3436,16 @@ where
         <V as Visit>::visit_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        <V as Visit>::visit_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        <V as Visit>::visit_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         <V as Visit>::visit_opt_module_export_name(&mut **self, node)
@@ -3190,6 +3526,11 @@ where
         <V as Visit>::visit_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        <V as Visit>::visit_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         <V as Visit>::visit_private_method(&mut **self, node)
@@ -3235,6 +3576,11 @@ where
         <V as Visit>::visit_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        <V as Visit>::visit_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         <V as Visit>::visit_rest_pat(&mut **self, node)
@@ -3345,6 +3691,11 @@ where
         <V as Visit>::visit_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        <V as Visit>::visit_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         <V as Visit>::visit_tpl(&mut **self, node)
@@ -3395,6 +3746,11 @@ where
         <V as Visit>::visit_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        <V as Visit>::visit_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         <V as Visit>::visit_var_decl(&mut **self, node)
@@ -3448,6 +3804,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_array_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_array_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         match self {
@@ -3584,6 +3948,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_binding_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_binding_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         match self {
@@ -3696,6 +4068,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_combined_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_combined_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         match self {
@@ -3768,6 +4148,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_do_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_do_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         match self {
@@ -4312,6 +4700,62 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_case(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_case(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_cases(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_cases(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_expr(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_pat_combinator(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_pat_combinator(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_pats(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_pats(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         match self {
@@ -4416,6 +4860,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_not_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_not_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         match self {
@@ -4440,6 +4892,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_object_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_object_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_object_match_pat_prop(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_object_match_pat_prop(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_object_match_pat_props(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_object_match_pat_props(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         match self {
@@ -4562,6 +5038,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_opt_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_opt_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_opt_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_opt_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         match self {
@@ -4694,6 +5186,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         match self {
@@ -4766,6 +5266,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_relational_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_relational_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         match self {
@@ -4942,6 +5450,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_topic_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_topic_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         match self {
@@ -5022,6 +5538,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => Visit::visit_value_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Visit::visit_value_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         match self {
@@ -5098,6 +5622,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat(&mut self, node: &ArrayMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_array_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_array_pat(&mut self, node: &ArrayPat) {
         if self.enabled {
@@ -5234,6 +5766,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat(&mut self, node: &BindingMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_binding_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_block_stmt(&mut self, node: &BlockStmt) {
         if self.enabled {
@@ -5346,6 +5886,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat(&mut self, node: &CombinedMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_combined_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name(&mut self, node: &ComputedPropName) {
         if self.enabled {
@@ -5418,6 +5966,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr(&mut self, node: &DoExpr) {
+        if self.enabled {
+            <V as Visit>::visit_do_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt(&mut self, node: &DoWhileStmt) {
         if self.enabled {
@@ -5946,6 +6502,62 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case(&mut self, node: &MatchCase) {
+        if self.enabled {
+            <V as Visit>::visit_match_case(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases(&mut self, node: &[MatchCase]) {
+        if self.enabled {
+            <V as Visit>::visit_match_cases(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr(&mut self, node: &MatchExpr) {
+        if self.enabled {
+            <V as Visit>::visit_match_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat(&mut self, node: &MatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator(&mut self, node: &MatchPatCombinator) {
+        if self.enabled {
+            <V as Visit>::visit_match_pat_combinator(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats(&mut self, node: &[MatchPat]) {
+        if self.enabled {
+            <V as Visit>::visit_match_pats(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest(&mut self, node: &MatchRest) {
+        if self.enabled {
+            <V as Visit>::visit_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_member_expr(&mut self, node: &MemberExpr) {
         if self.enabled {
@@ -6050,6 +6662,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat(&mut self, node: &NotMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_not_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_null(&mut self, node: &Null) {
         if self.enabled {
@@ -6074,6 +6694,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat(&mut self, node: &ObjectMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_object_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop(&mut self, node: &ObjectMatchPatProp) {
+        if self.enabled {
+            <V as Visit>::visit_object_match_pat_prop(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props(&mut self, node: &[ObjectMatchPatProp]) {
+        if self.enabled {
+            <V as Visit>::visit_object_match_pat_props(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_object_pat(&mut self, node: &ObjectPat) {
         if self.enabled {
@@ -6194,6 +6838,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat(&mut self, node: &Option<Box<MatchPat>>) {
+        if self.enabled {
+            <V as Visit>::visit_opt_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest(&mut self, node: &Option<MatchRest>) {
+        if self.enabled {
+            <V as Visit>::visit_opt_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name(&mut self, node: &Option<ModuleExportName>) {
         if self.enabled {
@@ -6322,6 +6982,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr(&mut self, node: &PipelineExpr) {
+        if self.enabled {
+            <V as Visit>::visit_pipeline_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_private_method(&mut self, node: &PrivateMethod) {
         if self.enabled {
@@ -6394,6 +7062,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat(&mut self, node: &RelationalMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_relational_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_rest_pat(&mut self, node: &RestPat) {
         if self.enabled {
@@ -6570,6 +7246,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr(&mut self, node: &TopicExpr) {
+        if self.enabled {
+            <V as Visit>::visit_topic_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_tpl(&mut self, node: &Tpl) {
         if self.enabled {
@@ -6650,6 +7334,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat(&mut self, node: &ValueMatchPat) {
+        if self.enabled {
+            <V as Visit>::visit_value_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_var_decl(&mut self, node: &VarDecl) {
         if self.enabled {
@@ -6740,6 +7432,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for ArrayLit {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for ArrayMatchPat {
+    #[doc = "Calls [Visit`::visit_array_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_array_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitWith<V>>::visit_with(elems, visitor)
+                };
+                {
+                    <Option<MatchRest> as VisitWith<V>>::visit_with(rest, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for ArrayPat {
     #[doc = "Calls [Visit`::visit_array_pat`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -7117,6 +7831,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for BindingIdent {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for BindingMatchPat {
+    #[doc = "Calls [Visit`::visit_binding_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_binding_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <VarDeclKind as VisitWith<V>>::visit_with(kind, visitor)
+                };
+                {
+                    <BindingIdent as VisitWith<V>>::visit_with(id, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for BlockStmt {
     #[doc = "Calls [Visit`::visit_block_stmt`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -7454,6 +8190,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for ClassProp {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for CombinedMatchPat {
+    #[doc = "Calls [Visit`::visit_combined_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_combined_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <MatchPatCombinator as VisitWith<V>>::visit_with(op, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitWith<V>>::visit_with(pats, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for ComputedPropName {
     #[doc = "Calls [Visit`::visit_computed_prop_name`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -7638,6 +8396,25 @@ impl<V: ?Sized + Visit> VisitWith<V> for DefaultDecl {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for DoExpr {
+    #[doc = "Calls [Visit`::visit_do_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_do_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <BlockStmt as VisitWith<V>>::visit_with(body, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for DoWhileStmt {
     #[doc = "Calls [Visit`::visit_do_while_stmt`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -7949,6 +8726,18 @@ impl<V: ?Sized + Visit> VisitWith<V> for Expr {
             Expr::OptChain { 0: _field_0 } => {
                 <OptChainExpr as VisitWith<V>>::visit_with(_field_0, visitor);
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                <PipelineExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::Topic { 0: _field_0 } => {
+                <TopicExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::Match { 0: _field_0 } => {
+                <MatchExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            Expr::Do { 0: _field_0 } => {
+                <DoExpr as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
             Expr::Invalid { 0: _field_0 } => {
                 <Invalid as VisitWith<V>>::visit_with(_field_0, visitor);
             }
@@ -9025,6 +9814,126 @@ impl<V: ?Sized + Visit> VisitWith<V> for Lit {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for MatchCase {
+    #[doc = "Calls [Visit`::visit_match_case`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_case(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitWith<V>>::visit_with(pat, visitor)
+                };
+                {
+                    <Option<Box<Expr>> as VisitWith<V>>::visit_with(guard, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(body, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchExpr {
+    #[doc = "Calls [Visit`::visit_match_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(subject, visitor)
+                };
+                {
+                    <Vec<MatchCase> as VisitWith<V>>::visit_with(cases, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchPat {
+    #[doc = "Calls [Visit`::visit_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                <BindingMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                <ValueMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                <RelationalMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                <ArrayMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                <ObjectMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                <CombinedMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                <NotMatchPat as VisitWith<V>>::visit_with(_field_0, visitor);
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchPatCombinator {
+    #[doc = "Calls [Visit`::visit_match_pat_combinator`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_pat_combinator(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for MatchRest {
+    #[doc = "Calls [Visit`::visit_match_rest`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_rest(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitWith<V>>::visit_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for MemberExpr {
     #[doc = "Calls [Visit`::visit_member_expr`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -9286,6 +10195,25 @@ impl<V: ?Sized + Visit> VisitWith<V> for NewExpr {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for NotMatchPat {
+    #[doc = "Calls [Visit`::visit_not_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_not_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<MatchPat> as VisitWith<V>>::visit_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Null {
     #[doc = "Calls [Visit`::visit_null`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -9340,6 +10268,47 @@ impl<V: ?Sized + Visit> VisitWith<V> for ObjectLit {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for ObjectMatchPat {
+    #[doc = "Calls [Visit`::visit_object_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_object_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Vec<ObjectMatchPatProp> as VisitWith<V>>::visit_with(props, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for ObjectMatchPatProp {
+    #[doc = "Calls [Visit`::visit_object_match_pat_prop`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_object_match_pat_prop(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <PropName as VisitWith<V>>::visit_with(key, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitWith<V>>::visit_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for ObjectPat {
     #[doc = "Calls [Visit`::visit_object_pat`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -9547,6 +10516,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for PipelineExpr {
+    #[doc = "Calls [Visit`::visit_pipeline_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_pipeline_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(left, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(right, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for PrivateMethod {
     #[doc = "Calls [Visit`::visit_private_method`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -9753,6 +10744,28 @@ impl<V: ?Sized + Visit> VisitWith<V> for Regex {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for RelationalMatchPat {
+    #[doc = "Calls [Visit`::visit_relational_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_relational_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <BinaryOp as VisitWith<V>>::visit_with(op, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for RestPat {
     #[doc = "Calls [Visit`::visit_rest_pat`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10209,6 +11222,22 @@ impl<V: ?Sized + Visit> VisitWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for TopicExpr {
+    #[doc = "Calls [Visit`::visit_topic_expr`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_topic_expr(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Tpl {
     #[doc = "Calls [Visit`::visit_tpl`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10409,6 +11438,25 @@ impl<V: ?Sized + Visit> VisitWith<V> for UsingDecl {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for ValueMatchPat {
+    #[doc = "Calls [Visit`::visit_value_match_pat`] with `self`."]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_value_match_pat(visitor, self)
+    }
+
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitWith<V>>::visit_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for VarDecl {
     #[doc = "Calls [Visit`::visit_var_decl`] with `self`."]
     fn visit_with(&self, visitor: &mut V) {
@@ -10706,6 +11754,32 @@ impl<V: ?Sized + Visit> VisitWith<V> for [JSXElementChild] {
             .for_each(|item| <JSXElementChild as VisitWith<V>>::visit_with(item, visitor))
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for [MatchCase] {
+    #[doc = "Calls [Visit`::visit_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_cases(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.iter()
+            .for_each(|item| <MatchCase as VisitWith<V>>::visit_with(item, visitor))
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for [MatchPat] {
+    #[doc = "Calls [Visit`::visit_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_match_pats(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.iter()
+            .for_each(|item| <MatchPat as VisitWith<V>>::visit_with(item, visitor))
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for [ModuleItem] {
     #[doc = "Calls [Visit`::visit_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -10719,6 +11793,19 @@ impl<V: ?Sized + Visit> VisitWith<V> for [ModuleItem] {
             .for_each(|item| <ModuleItem as VisitWith<V>>::visit_with(item, visitor))
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for [ObjectMatchPatProp] {
+    #[doc = "Calls [Visit`::visit_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_object_match_pat_props(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        self.iter()
+            .for_each(|item| <ObjectMatchPatProp as VisitWith<V>>::visit_with(item, visitor))
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for [ObjectPatProp] {
     #[doc = "Calls [Visit`::visit_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -10867,6 +11954,36 @@ impl<V: ?Sized + Visit> VisitWith<V> for Option<JSXClosingElement> {
         }
     }
 }
+impl<V: ?Sized + Visit> VisitWith<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [Visit`::visit_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_opt_match_pat(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitWith<V>>::visit_with(inner, visitor),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + Visit> VisitWith<V> for Option<MatchRest> {
+    #[doc = "Calls [Visit`::visit_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with(&self, visitor: &mut V) {
+        <V as Visit>::visit_opt_match_rest(visitor, self)
+    }
+
+    #[inline]
+    fn visit_children_with(&self, visitor: &mut V) {
+        match self {
+            Some(inner) => <MatchRest as VisitWith<V>>::visit_with(inner, visitor),
+            None => {}
+        }
+    }
+}
 impl<V: ?Sized + Visit> VisitWith<V> for Option<ModuleExportName> {
     #[doc = "Calls [Visit`::visit_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -11175,6 +12292,19 @@ pub trait VisitAstPath {
     ) {
         <ArrayLit as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ArrayMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -11376,6 +12506,19 @@ pub trait VisitAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <BindingMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -11528,6 +12671,19 @@ pub trait VisitAstPath {
     ) {
         <ClassProp as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <CombinedMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -11635,6 +12791,17 @@ pub trait VisitAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <DoExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -12419,6 +13586,87 @@ pub trait VisitAstPath {
     fn visit_lit<'ast: 'r, 'r>(&mut self, node: &'ast Lit, __ast_path: &mut AstNodePath<'r>) {
         <Lit as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchCase as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <[MatchCase] as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchPatCombinator as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <[MatchPat] as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <MatchRest as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -12568,6 +13816,19 @@ pub trait VisitAstPath {
     ) {
         <NewExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <NotMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -12593,6 +13854,45 @@ pub trait VisitAstPath {
     ) {
         <ObjectLit as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ObjectMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ObjectMatchPatProp as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <[ObjectMatchPatProp] as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -12784,6 +14084,32 @@ pub trait VisitAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_children_with_ast_path`]. If you want to \
+             recurse, you need to call it manually."]
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <Option<Box<MatchPat>> as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <Option<MatchRest> as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_children_with_ast_path`]. If you want to \
              recurse, you need to call it manually."]
@@ -12970,6 +14296,19 @@ pub trait VisitAstPath {
     fn visit_pats<'ast: 'r, 'r>(&mut self, node: &'ast [Pat], __ast_path: &mut AstNodePath<'r>) {
         <[Pat] as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <PipelineExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -13071,6 +14410,19 @@ pub trait VisitAstPath {
     fn visit_regex<'ast: 'r, 'r>(&mut self, node: &'ast Regex, __ast_path: &mut AstNodePath<'r>) {
         <Regex as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <RelationalMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -13307,6 +14659,17 @@ pub trait VisitAstPath {
     ) {
         <ThrowStmt as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <TopicExpr as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -13417,6 +14780,19 @@ pub trait VisitAstPath {
     ) {
         <UsingDecl as VisitWithAstPath<Self>>::visit_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <ValueMatchPat as VisitWithAstPath<Self>>::visit_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -13529,6 +14905,15 @@ where
         <V as VisitAstPath>::visit_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -13682,6 +15067,15 @@ where
         <V as VisitAstPath>::visit_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -13796,6 +15190,15 @@ where
         <V as VisitAstPath>::visit_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -13873,6 +15276,15 @@ where
         <V as VisitAstPath>::visit_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -14447,6 +15859,69 @@ where
         <V as VisitAstPath>::visit_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -14560,6 +16035,15 @@ where
         <V as VisitAstPath>::visit_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_null(&mut **self, node, __ast_path)
@@ -14579,6 +16063,33 @@ where
         <V as VisitAstPath>::visit_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -14714,6 +16225,24 @@ where
         <V as VisitAstPath>::visit_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -14846,6 +16375,15 @@ where
         <V as VisitAstPath>::visit_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -14919,6 +16457,15 @@ where
         <V as VisitAstPath>::visit_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -15097,6 +16644,15 @@ where
         <V as VisitAstPath>::visit_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_tpl(&mut **self, node, __ast_path)
@@ -15183,6 +16739,15 @@ where
         <V as VisitAstPath>::visit_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -15270,6 +16835,15 @@ where
         <V as VisitAstPath>::visit_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -15423,6 +16997,15 @@ where
         <V as VisitAstPath>::visit_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -15537,6 +17120,15 @@ where
         <V as VisitAstPath>::visit_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -15614,6 +17206,15 @@ where
         <V as VisitAstPath>::visit_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -16188,6 +17789,69 @@ where
         <V as VisitAstPath>::visit_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -16301,6 +17965,15 @@ where
         <V as VisitAstPath>::visit_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_null(&mut **self, node, __ast_path)
@@ -16320,6 +17993,33 @@ where
         <V as VisitAstPath>::visit_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -16455,6 +18155,24 @@ where
         <V as VisitAstPath>::visit_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -16587,6 +18305,15 @@ where
         <V as VisitAstPath>::visit_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -16660,6 +18387,15 @@ where
         <V as VisitAstPath>::visit_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -16838,6 +18574,15 @@ where
         <V as VisitAstPath>::visit_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         <V as VisitAstPath>::visit_tpl(&mut **self, node, __ast_path)
@@ -16924,6 +18669,15 @@ where
         <V as VisitAstPath>::visit_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -17019,6 +18773,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_array_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_array_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -17289,6 +19059,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_binding_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_binding_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -17499,6 +19285,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_combined_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_combined_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -17637,6 +19439,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_do_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_do_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -18663,6 +20481,118 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_case(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_case(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_cases(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_cases(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_pat_combinator(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_pat_combinator(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_pats(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_pats(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -18867,6 +20797,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_not_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_not_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         match self {
@@ -18905,6 +20851,54 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_object_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_object_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_object_match_pat_prop(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_object_match_pat_prop(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_object_match_pat_props(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_object_match_pat_props(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -19145,6 +21139,38 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_opt_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_opt_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_opt_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_opt_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -19383,6 +21409,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -19517,6 +21559,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_relational_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_relational_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -19841,6 +21899,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_topic_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_topic_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         match self {
@@ -19993,6 +22067,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitAstPath::visit_value_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitAstPath::visit_value_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -20139,6 +22229,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_array_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ArrayMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_array_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_array_pat<'ast: 'r, 'r>(
         &mut self,
@@ -20343,6 +22445,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_binding_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast BindingMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_binding_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_block_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -20499,6 +22613,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_combined_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast CombinedMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_combined_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_computed_prop_name<'ast: 'r, 'r>(
         &mut self,
@@ -20603,6 +22729,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_do_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast DoExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_do_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_do_while_stmt<'ast: 'r, 'r>(
         &mut self,
@@ -21379,6 +23517,90 @@ where
         }
     }
 
+    #[inline]
+    fn visit_match_case<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchCase,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_case(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_cases<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchCase],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_cases(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pat_combinator<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchPatCombinator,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_pat_combinator(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_pats<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [MatchPat],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_pats(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast MatchRest,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_member_expr<'ast: 'r, 'r>(
         &mut self,
@@ -21531,6 +23753,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_not_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast NotMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_not_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_null<'ast: 'r, 'r>(&mut self, node: &'ast Null, __ast_path: &mut AstNodePath<'r>) {
         if self.enabled {
@@ -21559,6 +23793,42 @@ where
         }
     }
 
+    #[inline]
+    fn visit_object_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_object_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_prop<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ObjectMatchPatProp,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_object_match_pat_prop(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_object_match_pat_props<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast [ObjectMatchPatProp],
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_object_match_pat_props(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_object_pat<'ast: 'r, 'r>(
         &mut self,
@@ -21739,6 +24009,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_opt_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<Box<MatchPat>>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_opt_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_opt_match_rest<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast Option<MatchRest>,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_opt_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_opt_module_export_name<'ast: 'r, 'r>(
         &mut self,
@@ -21919,6 +24213,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_pipeline_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast PipelineExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_private_method<'ast: 'r, 'r>(
         &mut self,
@@ -22019,6 +24325,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_relational_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast RelationalMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_relational_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_rest_pat<'ast: 'r, 'r>(
         &mut self,
@@ -22263,6 +24581,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_topic_expr<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast TopicExpr,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_topic_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_tpl<'ast: 'r, 'r>(&mut self, node: &'ast Tpl, __ast_path: &mut AstNodePath<'r>) {
         if self.enabled {
@@ -22379,6 +24709,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_value_match_pat<'ast: 'r, 'r>(
+        &mut self,
+        node: &'ast ValueMatchPat,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        if self.enabled {
+            <V as VisitAstPath>::visit_value_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_var_decl<'ast: 'r, 'r>(
         &mut self,
@@ -22539,6 +24881,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ArrayLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ArrayMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_array_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_array_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ArrayMatchPat(
+                        self,
+                        self::fields::ArrayMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ArrayMatchPat(
+                        self,
+                        self::fields::ArrayMatchPatField::Elems(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        elems,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ArrayMatchPat(
+                        self,
+                        self::fields::ArrayMatchPatField::Rest,
+                    ));
+                    <Option<MatchRest> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        rest,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ArrayPat {
     #[doc = "Calls [VisitAstPath`::visit_array_pat`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -23370,6 +25768,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for BindingIdent {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for BindingMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_binding_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_binding_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::BindingMatchPat(
+                        self,
+                        self::fields::BindingMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::BindingMatchPat(
+                        self,
+                        self::fields::BindingMatchPatField::Kind,
+                    ));
+                    <VarDeclKind as VisitWithAstPath<V>>::visit_with_ast_path(
+                        kind,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::BindingMatchPat(
+                        self,
+                        self::fields::BindingMatchPatField::Id,
+                    ));
+                    <BindingIdent as VisitWithAstPath<V>>::visit_with_ast_path(
+                        id,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for BlockStmt {
     #[doc = "Calls [VisitAstPath`::visit_block_stmt`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -24184,6 +26638,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ClassProp {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for CombinedMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_combined_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_combined_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::CombinedMatchPat(
+                        self,
+                        self::fields::CombinedMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::CombinedMatchPat(
+                        self,
+                        self::fields::CombinedMatchPatField::Op,
+                    ));
+                    <MatchPatCombinator as VisitWithAstPath<V>>::visit_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::CombinedMatchPat(
+                        self,
+                        self::fields::CombinedMatchPatField::Pats(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        pats,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ComputedPropName {
     #[doc = "Calls [VisitAstPath`::visit_computed_prop_name`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -24641,6 +27151,51 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DefaultDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DoExpr {
+    #[doc = "Calls [VisitAstPath`::visit_do_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_do_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::DoExpr(
+                        self,
+                        self::fields::DoExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::DoExpr(
+                        self,
+                        self::fields::DoExprField::Body,
+                    ));
+                    <BlockStmt as VisitWithAstPath<V>>::visit_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DoWhileStmt {
     #[doc = "Calls [VisitAstPath`::visit_do_while_stmt`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -25488,6 +28043,44 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Expr {
                     &mut *__ast_path,
                 );
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Expr(
+                    self,
+                    self::fields::ExprField::Pipeline,
+                ));
+                <PipelineExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentNodeRef::Expr(self, self::fields::ExprField::Topic));
+                <TopicExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Match { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentNodeRef::Expr(self, self::fields::ExprField::Match));
+                <MatchExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Do { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentNodeRef::Expr(self, self::fields::ExprField::Do));
+                <DoExpr as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Expr(
                     self,
@@ -28099,6 +30692,304 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Lit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchCase {
+    #[doc = "Calls [VisitAstPath`::visit_match_case`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_case(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Pat,
+                    ));
+                    <Option<Box<MatchPat>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        pat,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Guard,
+                    ));
+                    <Option<Box<Expr>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        guard,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchCase(
+                        self,
+                        self::fields::MatchCaseField::Body,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchExpr {
+    #[doc = "Calls [VisitAstPath`::visit_match_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchExpr(
+                        self,
+                        self::fields::MatchExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchExpr(
+                        self,
+                        self::fields::MatchExprField::Subject,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        subject,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchExpr(
+                        self,
+                        self::fields::MatchExprField::Cases(usize::MAX),
+                    ));
+                    <Vec<MatchCase> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        cases,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Binding,
+                ));
+                <BindingMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Value,
+                ));
+                <ValueMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Relational,
+                ));
+                <RelationalMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Array,
+                ));
+                <ArrayMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Object,
+                ));
+                <ObjectMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Combined,
+                ));
+                <CombinedMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchPat(
+                    self,
+                    self::fields::MatchPatField::Not,
+                ));
+                <NotMatchPat as VisitWithAstPath<V>>::visit_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchPatCombinator {
+    #[doc = "Calls [VisitAstPath`::visit_match_pat_combinator`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pat_combinator(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MatchRest {
+    #[doc = "Calls [VisitAstPath`::visit_match_rest`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_rest(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchRest(
+                        self,
+                        self::fields::MatchRestField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::MatchRest(
+                        self,
+                        self::fields::MatchRestField::Arg,
+                    ));
+                    <Option<Box<MatchPat>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for MemberExpr {
     #[doc = "Calls [VisitAstPath`::visit_member_expr`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -28743,6 +31634,51 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for NewExpr {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for NotMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_not_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_not_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::NotMatchPat(
+                        self,
+                        self::fields::NotMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::NotMatchPat(
+                        self,
+                        self::fields::NotMatchPatField::Arg,
+                    ));
+                    <Box<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Null {
     #[doc = "Calls [VisitAstPath`::visit_null`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -28865,6 +31801,110 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_object_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ObjectMatchPat(
+                        self,
+                        self::fields::ObjectMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ObjectMatchPat(
+                        self,
+                        self::fields::ObjectMatchPatField::Props(usize::MAX),
+                    ));
+                    <Vec<ObjectMatchPatProp> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        props,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectMatchPatProp {
+    #[doc = "Calls [VisitAstPath`::visit_object_match_pat_prop`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_prop(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::ObjectMatchPatProp(
+                            self,
+                            self::fields::ObjectMatchPatPropField::Span,
+                        ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::ObjectMatchPatProp(
+                            self,
+                            self::fields::ObjectMatchPatPropField::Key,
+                        ));
+                    <PropName as VisitWithAstPath<V>>::visit_with_ast_path(
+                        key,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::ObjectMatchPatProp(
+                            self,
+                            self::fields::ObjectMatchPatPropField::Value,
+                        ));
+                    <Option<Box<MatchPat>> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ObjectPat {
     #[doc = "Calls [VisitAstPath`::visit_object_pat`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -29359,6 +32399,62 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [VisitAstPath`::visit_pipeline_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Left,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        left,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::PipelineExpr(
+                        self,
+                        self::fields::PipelineExprField::Right,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        right,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [VisitAstPath`::visit_private_method`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -29875,6 +32971,65 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Regex {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for RelationalMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_relational_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_relational_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::RelationalMatchPat(
+                            self,
+                            self::fields::RelationalMatchPatField::Span,
+                        ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::RelationalMatchPat(
+                            self,
+                            self::fields::RelationalMatchPatField::Op,
+                        ));
+                    <BinaryOp as VisitWithAstPath<V>>::visit_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path =
+                        __ast_path.with_guard(AstParentNodeRef::RelationalMatchPat(
+                            self,
+                            self::fields::RelationalMatchPatField::Value,
+                        ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for RestPat {
     #[doc = "Calls [VisitAstPath`::visit_rest_pat`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -31032,6 +34187,40 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for TopicExpr {
+    #[doc = "Calls [VisitAstPath`::visit_topic_expr`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_topic_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::TopicExpr(
+                        self,
+                        self::fields::TopicExprField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Tpl {
     #[doc = "Calls [VisitAstPath`::visit_tpl`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -31464,6 +34653,51 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for UsingDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for ValueMatchPat {
+    #[doc = "Calls [VisitAstPath`::visit_value_match_pat`] with `self`."]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_value_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ValueMatchPat(
+                        self,
+                        self::fields::ValueMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitWithAstPath<V>>::visit_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::ValueMatchPat(
+                        self,
+                        self::fields::ValueMatchPatField::Value,
+                    ));
+                    <Box<Expr> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for VarDecl {
     #[doc = "Calls [VisitAstPath`::visit_var_decl`] with `self`."]
     fn visit_with_ast_path<'ast: 'r, 'r>(
@@ -32123,6 +35357,56 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [JSXElementChild] {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [MatchCase] {
+    #[doc = "Calls [VisitAstPath`::visit_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_cases(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        self.iter().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchCase as VisitWithAstPath<V>>::visit_with_ast_path(item, visitor, &mut *__ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [MatchPat] {
+    #[doc = "Calls [VisitAstPath`::visit_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_match_pats(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        self.iter().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchPat as VisitWithAstPath<V>>::visit_with_ast_path(item, visitor, &mut *__ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ModuleItem] {
     #[doc = "Calls [VisitAstPath`::visit_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -32152,6 +35436,35 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ModuleItem] {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ObjectMatchPatProp] {
+    #[doc = "Calls [VisitAstPath`::visit_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_object_match_pat_props(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        self.iter().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <ObjectMatchPatProp as VisitWithAstPath<V>>::visit_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for [ObjectPatProp] {
     #[doc = "Calls [VisitAstPath`::visit_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -32424,6 +35737,60 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<JSXClosingElement>
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [VisitAstPath`::visit_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_pat(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitWithAstPath<V>>::visit_with_ast_path(
+                inner, visitor, __ast_path,
+            ),
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<MatchRest> {
+    #[doc = "Calls [VisitAstPath`::visit_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        <V as VisitAstPath>::visit_opt_match_rest(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_children_with_ast_path<'ast: 'r, 'r>(
+        &'ast self,
+        visitor: &mut V,
+        __ast_path: &mut AstNodePath<'r>,
+    ) {
+        match self {
+            Some(inner) => {
+                <MatchRest as VisitWithAstPath<V>>::visit_with_ast_path(inner, visitor, __ast_path)
+            }
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Option<ModuleExportName> {
     #[doc = "Calls [VisitAstPath`::visit_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -32998,6 +36365,13 @@ pub trait VisitMut {
     fn visit_mut_array_lit(&mut self, node: &mut ArrayLit) {
         <ArrayLit as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        <ArrayMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -33117,6 +36491,13 @@ pub trait VisitMut {
     fn visit_mut_binding_ident(&mut self, node: &mut BindingIdent) {
         <BindingIdent as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        <BindingMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -33215,6 +36596,13 @@ pub trait VisitMut {
     fn visit_mut_class_prop(&mut self, node: &mut ClassProp) {
         <ClassProp as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        <CombinedMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_mut_children_with`]. If you want to recurse, you need to \
              call it manually."]
@@ -33278,6 +36666,13 @@ pub trait VisitMut {
     fn visit_mut_default_decl(&mut self, node: &mut DefaultDecl) {
         <DefaultDecl as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        <DoExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -33740,6 +37135,55 @@ pub trait VisitMut {
     fn visit_mut_lit(&mut self, node: &mut Lit) {
         <Lit as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        <MatchCase as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        <Vec<MatchCase> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        <MatchExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        <MatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        <MatchPatCombinator as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        <Vec<MatchPat> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        <MatchRest as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -33831,6 +37275,13 @@ pub trait VisitMut {
     fn visit_mut_new_expr(&mut self, node: &mut NewExpr) {
         <NewExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        <NotMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -33852,6 +37303,27 @@ pub trait VisitMut {
     fn visit_mut_object_lit(&mut self, node: &mut ObjectLit) {
         <ObjectLit as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        <ObjectMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        <ObjectMatchPatProp as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_mut_children_with`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        <Vec<ObjectMatchPatProp> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -33957,6 +37429,20 @@ pub trait VisitMut {
     fn visit_mut_opt_jsx_closing_element(&mut self, node: &mut Option<JSXClosingElement>) {
         <Option<JSXClosingElement> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_mut_children_with`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        <Option<Box<MatchPat>> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_mut_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        <Option<MatchRest> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_mut_children_with`]. If you want to recurse, you \
              need to call it manually."]
@@ -34067,6 +37553,13 @@ pub trait VisitMut {
     fn visit_mut_pats(&mut self, node: &mut Vec<Pat>) {
         <Vec<Pat> as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <PipelineExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_mut_children_with`]. If you want to recurse, you need to call \
              it manually."]
@@ -34130,6 +37623,13 @@ pub trait VisitMut {
     fn visit_mut_regex(&mut self, node: &mut Regex) {
         <Regex as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_mut_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        <RelationalMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -34283,6 +37783,13 @@ pub trait VisitMut {
     fn visit_mut_throw_stmt(&mut self, node: &mut ThrowStmt) {
         <ThrowStmt as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_mut_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        <TopicExpr as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -34353,6 +37860,13 @@ pub trait VisitMut {
     fn visit_mut_using_decl(&mut self, node: &mut UsingDecl) {
         <UsingDecl as VisitMutWith<Self>>::visit_mut_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_mut_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        <ValueMatchPat as VisitMutWith<Self>>::visit_mut_children_with(node, self)
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_mut_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -34419,6 +37933,11 @@ where
         <V as VisitMut>::visit_mut_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        <V as VisitMut>::visit_mut_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         <V as VisitMut>::visit_mut_array_pat(&mut **self, node)
@@ -34504,6 +38023,11 @@ where
         <V as VisitMut>::visit_mut_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        <V as VisitMut>::visit_mut_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         <V as VisitMut>::visit_mut_block_stmt(&mut **self, node)
@@ -34574,6 +38098,11 @@ where
         <V as VisitMut>::visit_mut_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        <V as VisitMut>::visit_mut_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         <V as VisitMut>::visit_mut_computed_prop_name(&mut **self, node)
@@ -34619,6 +38148,11 @@ where
         <V as VisitMut>::visit_mut_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        <V as VisitMut>::visit_mut_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         <V as VisitMut>::visit_mut_do_while_stmt(&mut **self, node)
@@ -34949,6 +38483,41 @@ where
         <V as VisitMut>::visit_mut_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        <V as VisitMut>::visit_mut_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        <V as VisitMut>::visit_mut_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        <V as VisitMut>::visit_mut_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        <V as VisitMut>::visit_mut_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        <V as VisitMut>::visit_mut_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        <V as VisitMut>::visit_mut_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        <V as VisitMut>::visit_mut_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         <V as VisitMut>::visit_mut_member_expr(&mut **self, node)
@@ -35014,6 +38583,11 @@ where
         <V as VisitMut>::visit_mut_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        <V as VisitMut>::visit_mut_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         <V as VisitMut>::visit_mut_null(&mut **self, node)
@@ -35029,6 +38603,21 @@ where
         <V as VisitMut>::visit_mut_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        <V as VisitMut>::visit_mut_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        <V as VisitMut>::visit_mut_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        <V as VisitMut>::visit_mut_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         <V as VisitMut>::visit_mut_object_pat(&mut **self, node)
@@ -35104,6 +38693,16 @@ where
         <V as VisitMut>::visit_mut_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        <V as VisitMut>::visit_mut_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        <V as VisitMut>::visit_mut_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         <V as VisitMut>::visit_mut_opt_module_export_name(&mut **self, node)
@@ -35184,6 +38783,11 @@ where
         <V as VisitMut>::visit_mut_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <V as VisitMut>::visit_mut_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         <V as VisitMut>::visit_mut_private_method(&mut **self, node)
@@ -35229,6 +38833,11 @@ where
         <V as VisitMut>::visit_mut_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        <V as VisitMut>::visit_mut_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         <V as VisitMut>::visit_mut_rest_pat(&mut **self, node)
@@ -35339,6 +38948,11 @@ where
         <V as VisitMut>::visit_mut_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        <V as VisitMut>::visit_mut_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         <V as VisitMut>::visit_mut_tpl(&mut **self, node)
@@ -35389,6 +39003,11 @@ where
         <V as VisitMut>::visit_mut_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        <V as VisitMut>::visit_mut_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         <V as VisitMut>::visit_mut_var_decl(&mut **self, node)
@@ -35438,6 +39057,11 @@ where
         <V as VisitMut>::visit_mut_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        <V as VisitMut>::visit_mut_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         <V as VisitMut>::visit_mut_array_pat(&mut **self, node)
@@ -35523,6 +39147,11 @@ where
         <V as VisitMut>::visit_mut_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        <V as VisitMut>::visit_mut_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         <V as VisitMut>::visit_mut_block_stmt(&mut **self, node)
@@ -35593,6 +39222,11 @@ where
         <V as VisitMut>::visit_mut_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        <V as VisitMut>::visit_mut_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         <V as VisitMut>::visit_mut_computed_prop_name(&mut **self, node)
@@ -35638,6 +39272,11 @@ where
         <V as VisitMut>::visit_mut_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        <V as VisitMut>::visit_mut_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         <V as VisitMut>::visit_mut_do_while_stmt(&mut **self, node)
@@ -35968,6 +39607,41 @@ where
         <V as VisitMut>::visit_mut_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        <V as VisitMut>::visit_mut_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        <V as VisitMut>::visit_mut_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        <V as VisitMut>::visit_mut_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        <V as VisitMut>::visit_mut_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        <V as VisitMut>::visit_mut_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        <V as VisitMut>::visit_mut_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        <V as VisitMut>::visit_mut_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         <V as VisitMut>::visit_mut_member_expr(&mut **self, node)
@@ -36033,6 +39707,11 @@ where
         <V as VisitMut>::visit_mut_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        <V as VisitMut>::visit_mut_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         <V as VisitMut>::visit_mut_null(&mut **self, node)
@@ -36048,6 +39727,21 @@ where
         <V as VisitMut>::visit_mut_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        <V as VisitMut>::visit_mut_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        <V as VisitMut>::visit_mut_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        <V as VisitMut>::visit_mut_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         <V as VisitMut>::visit_mut_object_pat(&mut **self, node)
@@ -36123,6 +39817,16 @@ where
         <V as VisitMut>::visit_mut_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        <V as VisitMut>::visit_mut_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        <V as VisitMut>::visit_mut_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         <V as VisitMut>::visit_mut_opt_module_export_name(&mut **self, node)
@@ -36203,6 +39907,11 @@ where
         <V as VisitMut>::visit_mut_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        <V as VisitMut>::visit_mut_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         <V as VisitMut>::visit_mut_private_method(&mut **self, node)
@@ -36248,6 +39957,11 @@ where
         <V as VisitMut>::visit_mut_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        <V as VisitMut>::visit_mut_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         <V as VisitMut>::visit_mut_rest_pat(&mut **self, node)
@@ -36358,6 +40072,11 @@ where
         <V as VisitMut>::visit_mut_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        <V as VisitMut>::visit_mut_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         <V as VisitMut>::visit_mut_tpl(&mut **self, node)
@@ -36408,6 +40127,11 @@ where
         <V as VisitMut>::visit_mut_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        <V as VisitMut>::visit_mut_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         <V as VisitMut>::visit_mut_var_decl(&mut **self, node)
@@ -36461,6 +40185,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_array_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_array_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         match self {
@@ -36601,6 +40333,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_binding_match_pat(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_binding_match_pat(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         match self {
@@ -36717,6 +40461,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_combined_match_pat(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_combined_match_pat(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         match self {
@@ -36793,6 +40549,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_do_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_do_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         match self {
@@ -37419,6 +41183,66 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_case(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_case(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_cases(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_cases(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_expr(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_match_pat_combinator(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_match_pat_combinator(visitor, node)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_pats(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_pats(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         match self {
@@ -37527,6 +41351,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_not_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_not_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         match self {
@@ -37551,6 +41383,40 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_object_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_object_match_pat(visitor, node)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_object_match_pat_prop(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_object_match_pat_prop(visitor, node)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_object_match_pat_props(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_object_match_pat_props(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         match self {
@@ -37691,6 +41557,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_opt_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_opt_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_opt_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_opt_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         match self {
@@ -37839,6 +41721,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         match self {
@@ -37911,6 +41801,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMut::visit_mut_relational_match_pat(visitor, node)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMut::visit_mut_relational_match_pat(visitor, node)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         match self {
@@ -38091,6 +41993,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_topic_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_topic_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         match self {
@@ -38171,6 +42081,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        match self {
+            swc_visit::Either::Left(visitor) => VisitMut::visit_mut_value_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => VisitMut::visit_mut_value_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         match self {
@@ -38249,6 +42167,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(&mut self, node: &mut ArrayMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_array_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat) {
         if self.enabled {
@@ -38385,6 +42311,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(&mut self, node: &mut BindingMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_binding_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt) {
         if self.enabled {
@@ -38497,6 +42431,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(&mut self, node: &mut CombinedMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_combined_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(&mut self, node: &mut ComputedPropName) {
         if self.enabled {
@@ -38569,6 +42511,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_do_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt) {
         if self.enabled {
@@ -39097,6 +43047,62 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_case(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_cases(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(&mut self, node: &mut MatchPatCombinator) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_pat_combinator(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_pats(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr) {
         if self.enabled {
@@ -39201,6 +43207,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_not_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null) {
         if self.enabled {
@@ -39225,6 +43239,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(&mut self, node: &mut ObjectMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_object_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(&mut self, node: &mut ObjectMatchPatProp) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_object_match_pat_prop(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(&mut self, node: &mut Vec<ObjectMatchPatProp>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_object_match_pat_props(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat) {
         if self.enabled {
@@ -39345,6 +43383,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(&mut self, node: &mut Option<Box<MatchPat>>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_opt_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(&mut self, node: &mut Option<MatchRest>) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_opt_match_rest(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(&mut self, node: &mut Option<ModuleExportName>) {
         if self.enabled {
@@ -39473,6 +43527,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_pipeline_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod) {
         if self.enabled {
@@ -39545,6 +43607,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(&mut self, node: &mut RelationalMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_relational_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat) {
         if self.enabled {
@@ -39721,6 +43791,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_topic_expr(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl) {
         if self.enabled {
@@ -39801,6 +43879,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(&mut self, node: &mut ValueMatchPat) {
+        if self.enabled {
+            <V as VisitMut>::visit_mut_value_match_pat(&mut self.visitor, node)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl) {
         if self.enabled {
@@ -39891,6 +43977,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ArrayLit {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ArrayMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_array_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_array_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitMutWith<V>>::visit_mut_with(elems, visitor)
+                };
+                {
+                    <Option<MatchRest> as VisitMutWith<V>>::visit_mut_with(rest, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for ArrayPat {
     #[doc = "Calls [VisitMut`::visit_mut_array_pat`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -40268,6 +44376,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for BindingIdent {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for BindingMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_binding_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_binding_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <VarDeclKind as VisitMutWith<V>>::visit_mut_with(kind, visitor)
+                };
+                {
+                    <BindingIdent as VisitMutWith<V>>::visit_mut_with(id, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for BlockStmt {
     #[doc = "Calls [VisitMut`::visit_mut_block_stmt`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -40605,6 +44735,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ClassProp {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for CombinedMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_combined_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_combined_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <MatchPatCombinator as VisitMutWith<V>>::visit_mut_with(op, visitor)
+                };
+                {
+                    <Vec<MatchPat> as VisitMutWith<V>>::visit_mut_with(pats, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for ComputedPropName {
     #[doc = "Calls [VisitMut`::visit_mut_computed_prop_name`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -40789,6 +44941,25 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for DefaultDecl {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for DoExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_do_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_do_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <BlockStmt as VisitMutWith<V>>::visit_mut_with(body, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for DoWhileStmt {
     #[doc = "Calls [VisitMut`::visit_mut_do_while_stmt`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -41100,6 +45271,18 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Expr {
             Expr::OptChain { 0: _field_0 } => {
                 <OptChainExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                <PipelineExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::Topic { 0: _field_0 } => {
+                <TopicExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::Match { 0: _field_0 } => {
+                <MatchExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            Expr::Do { 0: _field_0 } => {
+                <DoExpr as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
             Expr::Invalid { 0: _field_0 } => {
                 <Invalid as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
             }
@@ -42176,6 +46359,126 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Lit {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchCase {
+    #[doc = "Calls [VisitMut`::visit_mut_match_case`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_case(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitMutWith<V>>::visit_mut_with(pat, visitor)
+                };
+                {
+                    <Option<Box<Expr>> as VisitMutWith<V>>::visit_mut_with(guard, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(body, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_match_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(subject, visitor)
+                };
+                {
+                    <Vec<MatchCase> as VisitMutWith<V>>::visit_mut_with(cases, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                <BindingMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                <ValueMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                <RelationalMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                <ArrayMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                <ObjectMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                <CombinedMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                <NotMatchPat as VisitMutWith<V>>::visit_mut_with(_field_0, visitor);
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchPatCombinator {
+    #[doc = "Calls [VisitMut`::visit_mut_match_pat_combinator`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_pat_combinator(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for MatchRest {
+    #[doc = "Calls [VisitMut`::visit_mut_match_rest`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_rest(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitMutWith<V>>::visit_mut_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for MemberExpr {
     #[doc = "Calls [VisitMut`::visit_mut_member_expr`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -42437,6 +46740,25 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for NewExpr {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for NotMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_not_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_not_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<MatchPat> as VisitMutWith<V>>::visit_mut_with(arg, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Null {
     #[doc = "Calls [VisitMut`::visit_mut_null`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -42491,6 +46813,47 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectLit {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_object_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_object_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Vec<ObjectMatchPatProp> as VisitMutWith<V>>::visit_mut_with(props, visitor)
+                };
+            }
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectMatchPatProp {
+    #[doc = "Calls [VisitMut`::visit_mut_object_match_pat_prop`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_object_match_pat_prop(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <PropName as VisitMutWith<V>>::visit_mut_with(key, visitor)
+                };
+                {
+                    <Option<Box<MatchPat>> as VisitMutWith<V>>::visit_mut_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for ObjectPat {
     #[doc = "Calls [VisitMut`::visit_mut_object_pat`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -42698,6 +47061,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for PipelineExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_pipeline_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_pipeline_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(left, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(right, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for PrivateMethod {
     #[doc = "Calls [VisitMut`::visit_mut_private_method`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -42904,6 +47289,28 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Regex {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for RelationalMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_relational_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_relational_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <BinaryOp as VisitMutWith<V>>::visit_mut_with(op, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for RestPat {
     #[doc = "Calls [VisitMut`::visit_mut_rest_pat`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -43360,6 +47767,22 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for TopicExpr {
+    #[doc = "Calls [VisitMut`::visit_mut_topic_expr`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_topic_expr(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Tpl {
     #[doc = "Calls [VisitMut`::visit_mut_tpl`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -43560,6 +47983,25 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for UsingDecl {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for ValueMatchPat {
+    #[doc = "Calls [VisitMut`::visit_mut_value_match_pat`] with `self`."]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_value_match_pat(visitor, self)
+    }
+
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
+                };
+                {
+                    <Box<Expr> as VisitMutWith<V>>::visit_mut_with(value, visitor)
+                };
+            }
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for VarDecl {
     #[doc = "Calls [VisitMut`::visit_mut_var_decl`] with `self`."]
     fn visit_mut_with(&mut self, visitor: &mut V) {
@@ -43857,6 +48299,32 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<JSXElementChild> {
             .for_each(|item| <JSXElementChild as VisitMutWith<V>>::visit_mut_with(item, visitor))
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<MatchCase> {
+    #[doc = "Calls [VisitMut`::visit_mut_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_cases(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.iter_mut()
+            .for_each(|item| <MatchCase as VisitMutWith<V>>::visit_mut_with(item, visitor))
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<MatchPat> {
+    #[doc = "Calls [VisitMut`::visit_mut_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_match_pats(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.iter_mut()
+            .for_each(|item| <MatchPat as VisitMutWith<V>>::visit_mut_with(item, visitor))
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ModuleItem> {
     #[doc = "Calls [VisitMut`::visit_mut_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -43870,6 +48338,19 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ModuleItem> {
             .for_each(|item| <ModuleItem as VisitMutWith<V>>::visit_mut_with(item, visitor))
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [VisitMut`::visit_mut_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_object_match_pat_props(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        self.iter_mut()
+            .for_each(|item| <ObjectMatchPatProp as VisitMutWith<V>>::visit_mut_with(item, visitor))
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [VisitMut`::visit_mut_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -44018,6 +48499,36 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<JSXClosingElement> {
         }
     }
 }
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [VisitMut`::visit_mut_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_opt_match_pat(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitMutWith<V>>::visit_mut_with(inner, visitor),
+            None => {}
+        }
+    }
+}
+impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<MatchRest> {
+    #[doc = "Calls [VisitMut`::visit_mut_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with(&mut self, visitor: &mut V) {
+        <V as VisitMut>::visit_mut_opt_match_rest(visitor, self)
+    }
+
+    #[inline]
+    fn visit_mut_children_with(&mut self, visitor: &mut V) {
+        match self {
+            Some(inner) => <MatchRest as VisitMutWith<V>>::visit_mut_with(inner, visitor),
+            None => {}
+        }
+    }
+}
 impl<V: ?Sized + VisitMut> VisitMutWith<V> for Option<ModuleExportName> {
     #[doc = "Calls [VisitMut`::visit_mut_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -44308,6 +48819,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ArrayMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -44469,6 +48993,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <BindingMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -44603,6 +49140,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <CombinedMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::visit_mut_children_with_ast_path`]. If you want to recurse, you \
              need to call it manually."]
@@ -44688,6 +49238,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        <DoExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -45394,6 +49953,73 @@ pub trait VisitMutAstPath {
     fn visit_mut_lit(&mut self, node: &mut Lit, __ast_path: &mut AstKindPath) {
         <Lit as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        <MatchCase as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        <Vec<MatchCase> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        <MatchExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        <MatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <MatchPatCombinator as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        <Vec<MatchPat> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        <MatchRest as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -45515,6 +50141,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        <NotMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::visit_mut_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -45542,6 +50177,45 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ObjectMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ObjectMatchPatProp as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::visit_mut_children_with_ast_path`]. If you want to \
+             recurse, you need to call it manually."]
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <Vec<ObjectMatchPatProp> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -45713,6 +50387,32 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::visit_mut_children_with_ast_path`]. If you want to \
+             recurse, you need to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <Option<Box<MatchPat>> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::visit_mut_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <Option<MatchRest> as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::visit_mut_children_with_ast_path`]. If you want to \
              recurse, you need to call it manually."]
@@ -45887,6 +50587,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <PipelineExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -45972,6 +50681,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <RelationalMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -46180,6 +50902,15 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        <TopicExpr as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::visit_mut_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -46272,6 +51003,19 @@ pub trait VisitMutAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::visit_mut_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <ValueMatchPat as VisitMutWithAstPath<Self>>::visit_mut_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::visit_mut_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -46364,6 +51108,15 @@ where
         <V as VisitMutAstPath>::visit_mut_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_array_pat(&mut **self, node, __ast_path)
@@ -46457,6 +51210,15 @@ where
         <V as VisitMutAstPath>::visit_mut_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_block_stmt(&mut **self, node, __ast_path)
@@ -46535,6 +51297,15 @@ where
         <V as VisitMutAstPath>::visit_mut_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -46584,6 +51355,11 @@ where
         <V as VisitMutAstPath>::visit_mut_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_do_while_stmt(&mut **self, node, __ast_path)
@@ -47030,6 +51806,45 @@ where
         <V as VisitMutAstPath>::visit_mut_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_member_expr(&mut **self, node, __ast_path)
@@ -47099,6 +51914,11 @@ where
         <V as VisitMutAstPath>::visit_mut_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_null(&mut **self, node, __ast_path)
@@ -47114,6 +51934,33 @@ where
         <V as VisitMutAstPath>::visit_mut_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_object_pat(&mut **self, node, __ast_path)
@@ -47225,6 +52072,24 @@ where
         <V as VisitMutAstPath>::visit_mut_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -47337,6 +52202,11 @@ where
         <V as VisitMutAstPath>::visit_mut_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_private_method(&mut **self, node, __ast_path)
@@ -47386,6 +52256,15 @@ where
         <V as VisitMutAstPath>::visit_mut_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_rest_pat(&mut **self, node, __ast_path)
@@ -47508,6 +52387,11 @@ where
         <V as VisitMutAstPath>::visit_mut_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_tpl(&mut **self, node, __ast_path)
@@ -47562,6 +52446,15 @@ where
         <V as VisitMutAstPath>::visit_mut_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_var_decl(&mut **self, node, __ast_path)
@@ -47621,6 +52514,15 @@ where
         <V as VisitMutAstPath>::visit_mut_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_array_pat(&mut **self, node, __ast_path)
@@ -47714,6 +52616,15 @@ where
         <V as VisitMutAstPath>::visit_mut_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_block_stmt(&mut **self, node, __ast_path)
@@ -47792,6 +52703,15 @@ where
         <V as VisitMutAstPath>::visit_mut_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -47841,6 +52761,11 @@ where
         <V as VisitMutAstPath>::visit_mut_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_do_while_stmt(&mut **self, node, __ast_path)
@@ -48287,6 +53212,45 @@ where
         <V as VisitMutAstPath>::visit_mut_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_member_expr(&mut **self, node, __ast_path)
@@ -48356,6 +53320,11 @@ where
         <V as VisitMutAstPath>::visit_mut_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_null(&mut **self, node, __ast_path)
@@ -48371,6 +53340,33 @@ where
         <V as VisitMutAstPath>::visit_mut_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_object_pat(&mut **self, node, __ast_path)
@@ -48482,6 +53478,24 @@ where
         <V as VisitMutAstPath>::visit_mut_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -48594,6 +53608,11 @@ where
         <V as VisitMutAstPath>::visit_mut_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_private_method(&mut **self, node, __ast_path)
@@ -48643,6 +53662,15 @@ where
         <V as VisitMutAstPath>::visit_mut_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_rest_pat(&mut **self, node, __ast_path)
@@ -48765,6 +53793,11 @@ where
         <V as VisitMutAstPath>::visit_mut_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_tpl(&mut **self, node, __ast_path)
@@ -48819,6 +53852,15 @@ where
         <V as VisitMutAstPath>::visit_mut_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        <V as VisitMutAstPath>::visit_mut_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         <V as VisitMutAstPath>::visit_mut_var_decl(&mut **self, node, __ast_path)
@@ -48886,6 +53928,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_array_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_array_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         match self {
@@ -49098,6 +54156,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_binding_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_binding_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         match self {
@@ -49274,6 +54348,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_combined_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_combined_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -49386,6 +54476,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_do_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_do_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         match self {
@@ -50294,6 +55396,94 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_case(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_case(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_cases(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_cases(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat_combinator(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_pat_combinator(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_pats(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_pats(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         match self {
@@ -50454,6 +55644,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_not_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_not_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         match self {
@@ -50490,6 +55692,54 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_prop(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_prop(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_props(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_object_match_pat_props(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         match self {
@@ -50706,6 +55956,38 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_opt_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -50930,6 +56212,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         match self {
@@ -51042,6 +56336,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_relational_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_relational_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         match self {
@@ -51318,6 +56628,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_topic_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_topic_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         match self {
@@ -51442,6 +56764,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                VisitMutAstPath::visit_mut_value_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                VisitMutAstPath::visit_mut_value_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         match self {
@@ -51560,6 +56898,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_array_match_pat(
+        &mut self,
+        node: &mut ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_array_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_array_pat(&mut self, node: &mut ArrayPat, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -51704,6 +57054,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_binding_match_pat(
+        &mut self,
+        node: &mut BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_binding_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_block_stmt(&mut self, node: &mut BlockStmt, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -51828,6 +57190,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_combined_match_pat(
+        &mut self,
+        node: &mut CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_combined_match_pat(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_computed_prop_name(
         &mut self,
@@ -51908,6 +57286,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_do_expr(&mut self, node: &mut DoExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_do_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_do_while_stmt(&mut self, node: &mut DoWhileStmt, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -52624,6 +58010,70 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_match_case(&mut self, node: &mut MatchCase, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_case(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_cases(&mut self, node: &mut Vec<MatchCase>, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_cases(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_expr(&mut self, node: &mut MatchExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat(&mut self, node: &mut MatchPat, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pat_combinator(
+        &mut self,
+        node: &mut MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_pat_combinator(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_pats(&mut self, node: &mut Vec<MatchPat>, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_pats(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_match_rest(&mut self, node: &mut MatchRest, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_member_expr(&mut self, node: &mut MemberExpr, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -52736,6 +58186,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_not_match_pat(&mut self, node: &mut NotMatchPat, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_not_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_null(&mut self, node: &mut Null, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -52760,6 +58218,50 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_object_match_pat(
+        &mut self,
+        node: &mut ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_object_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_prop(
+        &mut self,
+        node: &mut ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_object_match_pat_props(
+        &mut self,
+        node: &mut Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_object_match_pat_props(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_object_pat(&mut self, node: &mut ObjectPat, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -52932,6 +58434,30 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_opt_match_pat(
+        &mut self,
+        node: &mut Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_opt_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
+    #[inline]
+    fn visit_mut_opt_match_rest(
+        &mut self,
+        node: &mut Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_opt_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_opt_module_export_name(
         &mut self,
@@ -53112,6 +58638,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_pipeline_expr(&mut self, node: &mut PipelineExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_private_method(&mut self, node: &mut PrivateMethod, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -53188,6 +58722,22 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_relational_match_pat(
+        &mut self,
+        node: &mut RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_relational_match_pat(
+                &mut self.visitor,
+                node,
+                __ast_path,
+            )
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_rest_pat(&mut self, node: &mut RestPat, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -53380,6 +58930,14 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_topic_expr(&mut self, node: &mut TopicExpr, __ast_path: &mut AstKindPath) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_topic_expr(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_tpl(&mut self, node: &mut Tpl, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -53464,6 +59022,18 @@ where
         }
     }
 
+    #[inline]
+    fn visit_mut_value_match_pat(
+        &mut self,
+        node: &mut ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) {
+        if self.enabled {
+            <V as VisitMutAstPath>::visit_mut_value_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+        }
+    }
+
     #[inline]
     fn visit_mut_var_decl(&mut self, node: &mut VarDecl, __ast_path: &mut AstKindPath) {
         if self.enabled {
@@ -53581,6 +59151,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ArrayLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ArrayMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_array_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_array_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Elems(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        elems,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Rest,
+                    ));
+                    <Option<MatchRest> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        rest,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ArrayPat {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_array_pat`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -54238,6 +59853,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for BindingIdent {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for BindingMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_binding_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_binding_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Kind,
+                    ));
+                    <VarDeclKind as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        kind,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Id,
+                    ));
+                    <BindingIdent as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        id,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for BlockStmt {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_block_stmt`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -54891,6 +60551,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ClassProp {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for CombinedMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_combined_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_combined_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Op,
+                    ));
+                    <MatchPatCombinator as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Pats(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        pats,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ComputedPropName {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_computed_prop_name`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -55251,6 +60956,39 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DefaultDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DoExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_do_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_do_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            DoExpr { span, body } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Body));
+                    <BlockStmt as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DoWhileStmt {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_do_while_stmt`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -55946,6 +61684,42 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Expr {
                     &mut *__ast_path,
                 );
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Pipeline));
+                <PipelineExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Topic));
+                <TopicExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Match { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Match));
+                <MatchExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            Expr::Do { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Do));
+                <DoExpr as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let mut __ast_path =
                     __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Invalid));
@@ -58000,6 +63774,238 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Lit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchCase {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_case`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_case(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Pat));
+                    <Option<Box<MatchPat>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        pat,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchCase(
+                        self::fields::MatchCaseField::Guard,
+                    ));
+                    <Option<Box<Expr>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        guard,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Body));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchExpr(self::fields::MatchExprField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Subject,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        subject,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Cases(usize::MAX),
+                    ));
+                    <Vec<MatchCase> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        cases,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Binding,
+                ));
+                <BindingMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Value));
+                <ValueMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Relational,
+                ));
+                <RelationalMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Array));
+                <ArrayMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Object));
+                <ObjectMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Combined,
+                ));
+                <CombinedMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Not));
+                <NotMatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchPatCombinator {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_pat_combinator`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pat_combinator(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchPatCombinator::And => {}
+            MatchPatCombinator::Or => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MatchRest {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_rest`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_rest(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            MatchRest { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Arg));
+                    <Option<Box<MatchPat>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for MemberExpr {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_member_expr`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -58509,6 +64515,41 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for NewExpr {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for NotMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_not_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_not_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            NotMatchPat { span, arg } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Arg,
+                    ));
+                    <Box<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Null {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_null`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -58600,6 +64641,86 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_object_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ObjectMatchPat { span, props } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Props(usize::MAX),
+                    ));
+                    <Vec<ObjectMatchPatProp> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        props,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectMatchPatProp {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_object_match_pat_prop`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_prop(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Key,
+                    ));
+                    <PropName as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        key,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Value,
+                    ));
+                    <Option<Box<MatchPat>> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ObjectPat {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_object_pat`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -58996,6 +65117,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_pipeline_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            PipelineExpr { span, left, right } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Left,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        left,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Right,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        right,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_private_method`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -59406,6 +65572,51 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Regex {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for RelationalMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_relational_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_relational_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Op,
+                    ));
+                    <BinaryOp as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Value,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for RestPat {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_rest_pat`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -60345,6 +66556,30 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for TopicExpr {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_topic_expr`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_topic_expr(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            TopicExpr { span } => {
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::TopicExpr(self::fields::TopicExprField::Span));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Tpl {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_tpl`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -60680,6 +66915,41 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for UsingDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for ValueMatchPat {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_value_match_pat`] with `self`."]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_value_match_pat(visitor, self, __ast_path)
+    }
+
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            ValueMatchPat { span, value } => {
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Span,
+                    ));
+                    <swc_common::Span as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Value,
+                    ));
+                    <Box<Expr> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for VarDecl {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_var_decl`] with `self`."]
     fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
@@ -61175,6 +67445,48 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<JSXElementChild
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<MatchCase> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_cases(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        self.iter_mut().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchCase as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<MatchPat> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_match_pats(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        self.iter_mut().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <MatchPat as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ModuleItem> {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -61196,6 +67508,27 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ModuleItem> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_object_match_pat_props(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        self.iter_mut().enumerate().for_each(|(__idx, item)| {
+            let mut __ast_path = __ast_path.with_index_guard(__idx);
+            <ObjectMatchPatProp as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                item,
+                visitor,
+                &mut *__ast_path,
+            )
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -61388,6 +67721,44 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<JSXClosingEl
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_pat(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            Some(inner) => <Box<MatchPat> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                inner, visitor, __ast_path,
+            ),
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<MatchRest> {
+    #[doc = "Calls [VisitMutAstPath`::visit_mut_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn visit_mut_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        <V as VisitMutAstPath>::visit_mut_opt_match_rest(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn visit_mut_children_with_ast_path(&mut self, visitor: &mut V, __ast_path: &mut AstKindPath) {
+        match self {
+            Some(inner) => <MatchRest as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                inner, visitor, __ast_path,
+            ),
+            None => {}
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Option<ModuleExportName> {
     #[doc = "Calls [VisitMutAstPath`::visit_mut_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -61793,6 +68164,13 @@ pub trait Fold {
     fn fold_array_lit(&mut self, node: ArrayLit) -> ArrayLit {
         <ArrayLit as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        <ArrayMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -61910,6 +68288,13 @@ pub trait Fold {
     fn fold_binding_ident(&mut self, node: BindingIdent) -> BindingIdent {
         <BindingIdent as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        <BindingMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -62005,6 +68390,13 @@ pub trait Fold {
     fn fold_class_prop(&mut self, node: ClassProp) -> ClassProp {
         <ClassProp as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        <CombinedMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -62067,6 +68459,12 @@ pub trait Fold {
     fn fold_default_decl(&mut self, node: DefaultDecl) -> DefaultDecl {
         <DefaultDecl as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::fold_children_with`]. If you want to recurse, you need to call it manually."]
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        <DoExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -62527,6 +68925,55 @@ pub trait Fold {
     fn fold_lit(&mut self, node: Lit) -> Lit {
         <Lit as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        <MatchCase as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        <Vec<MatchCase> as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        <MatchExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        <MatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::fold_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        <MatchPatCombinator as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        <Vec<MatchPat> as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        <MatchRest as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -62616,6 +69063,13 @@ pub trait Fold {
     fn fold_new_expr(&mut self, node: NewExpr) -> NewExpr {
         <NewExpr as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        <NotMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -62635,6 +69089,30 @@ pub trait Fold {
     fn fold_object_lit(&mut self, node: ObjectLit) -> ObjectLit {
         <ObjectLit as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        <ObjectMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::fold_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        <ObjectMatchPatProp as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::fold_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        <Vec<ObjectMatchPatProp> as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -62744,6 +69222,20 @@ pub trait Fold {
     ) -> Option<JSXClosingElement> {
         <Option<JSXClosingElement> as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::fold_children_with`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        <Option<Box<MatchPat>> as FoldWith<Self>>::fold_children_with(node, self)
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::fold_children_with`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        <Option<MatchRest> as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::fold_children_with`]. If you want to recurse, you need \
              to call it manually."]
@@ -62860,6 +69352,13 @@ pub trait Fold {
     fn fold_pats(&mut self, node: Vec<Pat>) -> Vec<Pat> {
         <Vec<Pat> as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <PipelineExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::fold_children_with`]. If you want to recurse, you need to call it \
              manually."]
@@ -62920,6 +69419,13 @@ pub trait Fold {
     fn fold_regex(&mut self, node: Regex) -> Regex {
         <Regex as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::fold_children_with`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        <RelationalMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -63070,6 +69576,13 @@ pub trait Fold {
     fn fold_throw_stmt(&mut self, node: ThrowStmt) -> ThrowStmt {
         <ThrowStmt as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        <TopicExpr as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -63137,6 +69650,13 @@ pub trait Fold {
     fn fold_using_decl(&mut self, node: UsingDecl) -> UsingDecl {
         <UsingDecl as FoldWith<Self>>::fold_children_with(node, self)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::fold_children_with`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        <ValueMatchPat as FoldWith<Self>>::fold_children_with(node, self)
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::fold_children_with`]. If you want to recurse, you need to call it manually."]
     #[inline]
@@ -63202,6 +69722,11 @@ where
         <V as Fold>::fold_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        <V as Fold>::fold_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         <V as Fold>::fold_array_pat(&mut **self, node)
@@ -63287,6 +69812,11 @@ where
         <V as Fold>::fold_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        <V as Fold>::fold_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         <V as Fold>::fold_block_stmt(&mut **self, node)
@@ -63357,6 +69887,11 @@ where
         <V as Fold>::fold_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        <V as Fold>::fold_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         <V as Fold>::fold_computed_prop_name(&mut **self, node)
@@ -63402,6 +69937,11 @@ where
         <V as Fold>::fold_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        <V as Fold>::fold_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         <V as Fold>::fold_do_while_stmt(&mut **self, node)
@@ -63744,6 +70284,41 @@ where
         <V as Fold>::fold_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        <V as Fold>::fold_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        <V as Fold>::fold_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        <V as Fold>::fold_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        <V as Fold>::fold_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        <V as Fold>::fold_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        <V as Fold>::fold_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        <V as Fold>::fold_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         <V as Fold>::fold_member_expr(&mut **self, node)
@@ -63809,6 +70384,11 @@ where
         <V as Fold>::fold_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        <V as Fold>::fold_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         <V as Fold>::fold_null(&mut **self, node)
@@ -63824,6 +70404,24 @@ where
         <V as Fold>::fold_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        <V as Fold>::fold_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        <V as Fold>::fold_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as Fold>::fold_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         <V as Fold>::fold_object_pat(&mut **self, node)
@@ -63905,6 +70503,16 @@ where
         <V as Fold>::fold_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        <V as Fold>::fold_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        <V as Fold>::fold_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -63994,6 +70602,11 @@ where
         <V as Fold>::fold_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <V as Fold>::fold_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         <V as Fold>::fold_private_method(&mut **self, node)
@@ -64039,6 +70652,11 @@ where
         <V as Fold>::fold_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        <V as Fold>::fold_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         <V as Fold>::fold_rest_pat(&mut **self, node)
@@ -64152,6 +70770,11 @@ where
         <V as Fold>::fold_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        <V as Fold>::fold_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         <V as Fold>::fold_tpl(&mut **self, node)
@@ -64202,6 +70825,11 @@ where
         <V as Fold>::fold_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        <V as Fold>::fold_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         <V as Fold>::fold_var_decl(&mut **self, node)
@@ -64251,6 +70879,11 @@ where
         <V as Fold>::fold_array_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        <V as Fold>::fold_array_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         <V as Fold>::fold_array_pat(&mut **self, node)
@@ -64336,6 +70969,11 @@ where
         <V as Fold>::fold_binding_ident(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        <V as Fold>::fold_binding_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         <V as Fold>::fold_block_stmt(&mut **self, node)
@@ -64406,6 +71044,11 @@ where
         <V as Fold>::fold_class_prop(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        <V as Fold>::fold_combined_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         <V as Fold>::fold_computed_prop_name(&mut **self, node)
@@ -64451,6 +71094,11 @@ where
         <V as Fold>::fold_default_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        <V as Fold>::fold_do_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         <V as Fold>::fold_do_while_stmt(&mut **self, node)
@@ -64793,6 +71441,41 @@ where
         <V as Fold>::fold_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        <V as Fold>::fold_match_case(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        <V as Fold>::fold_match_cases(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        <V as Fold>::fold_match_expr(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        <V as Fold>::fold_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        <V as Fold>::fold_match_pat_combinator(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        <V as Fold>::fold_match_pats(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        <V as Fold>::fold_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         <V as Fold>::fold_member_expr(&mut **self, node)
@@ -64858,6 +71541,11 @@ where
         <V as Fold>::fold_new_expr(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        <V as Fold>::fold_not_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         <V as Fold>::fold_null(&mut **self, node)
@@ -64873,6 +71561,24 @@ where
         <V as Fold>::fold_object_lit(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        <V as Fold>::fold_object_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        <V as Fold>::fold_object_match_pat_prop(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as Fold>::fold_object_match_pat_props(&mut **self, node)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         <V as Fold>::fold_object_pat(&mut **self, node)
@@ -64954,6 +71660,16 @@ where
         <V as Fold>::fold_opt_jsx_closing_element(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        <V as Fold>::fold_opt_match_pat(&mut **self, node)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        <V as Fold>::fold_opt_match_rest(&mut **self, node)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -65043,6 +71759,11 @@ where
         <V as Fold>::fold_pats(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        <V as Fold>::fold_pipeline_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         <V as Fold>::fold_private_method(&mut **self, node)
@@ -65088,6 +71809,11 @@ where
         <V as Fold>::fold_regex(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        <V as Fold>::fold_relational_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         <V as Fold>::fold_rest_pat(&mut **self, node)
@@ -65201,6 +71927,11 @@ where
         <V as Fold>::fold_throw_stmt(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        <V as Fold>::fold_topic_expr(&mut **self, node)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         <V as Fold>::fold_tpl(&mut **self, node)
@@ -65251,6 +71982,11 @@ where
         <V as Fold>::fold_using_decl(&mut **self, node)
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        <V as Fold>::fold_value_match_pat(&mut **self, node)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         <V as Fold>::fold_var_decl(&mut **self, node)
@@ -65304,6 +72040,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_array_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_array_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         match self {
@@ -65440,6 +72184,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_binding_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_binding_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         match self {
@@ -65552,6 +72304,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_combined_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_combined_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         match self {
@@ -65624,6 +72384,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_do_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_do_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         match self {
@@ -66168,6 +72936,62 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_case(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_case(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_cases(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_cases(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_expr(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_pat_combinator(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_pat_combinator(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_pats(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_pats(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         match self {
@@ -66272,6 +73096,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_not_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_not_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         match self {
@@ -66296,6 +73128,33 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_object_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_object_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_object_match_pat_prop(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_object_match_pat_prop(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_object_match_pat_props(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_object_match_pat_props(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         match self {
@@ -66422,6 +73281,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_opt_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_opt_match_pat(visitor, node),
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_opt_match_rest(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_opt_match_rest(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -66559,6 +73434,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_pipeline_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_pipeline_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         match self {
@@ -66631,6 +73514,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_relational_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_relational_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         match self {
@@ -66810,6 +73701,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_topic_expr(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_topic_expr(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         match self {
@@ -66890,6 +73789,14 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => Fold::fold_value_match_pat(visitor, node),
+            swc_visit::Either::Right(visitor) => Fold::fold_value_match_pat(visitor, node),
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         match self {
@@ -66967,6 +73874,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(&mut self, node: ArrayMatchPat) -> ArrayMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_array_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat) -> ArrayPat {
         if self.enabled {
@@ -67120,6 +74036,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(&mut self, node: BindingMatchPat) -> BindingMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_binding_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt) -> BlockStmt {
         if self.enabled {
@@ -67246,6 +74171,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(&mut self, node: CombinedMatchPat) -> CombinedMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_combined_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(&mut self, node: ComputedPropName) -> ComputedPropName {
         if self.enabled {
@@ -67327,6 +74261,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr) -> DoExpr {
+        if self.enabled {
+            <V as Fold>::fold_do_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(&mut self, node: DoWhileStmt) -> DoWhileStmt {
         if self.enabled {
@@ -67933,6 +74876,69 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase) -> MatchCase {
+        if self.enabled {
+            <V as Fold>::fold_match_case(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(&mut self, node: Vec<MatchCase>) -> Vec<MatchCase> {
+        if self.enabled {
+            <V as Fold>::fold_match_cases(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr) -> MatchExpr {
+        if self.enabled {
+            <V as Fold>::fold_match_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat) -> MatchPat {
+        if self.enabled {
+            <V as Fold>::fold_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(&mut self, node: MatchPatCombinator) -> MatchPatCombinator {
+        if self.enabled {
+            <V as Fold>::fold_match_pat_combinator(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(&mut self, node: Vec<MatchPat>) -> Vec<MatchPat> {
+        if self.enabled {
+            <V as Fold>::fold_match_pats(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest) -> MatchRest {
+        if self.enabled {
+            <V as Fold>::fold_match_rest(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr) -> MemberExpr {
         if self.enabled {
@@ -68050,6 +75056,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(&mut self, node: NotMatchPat) -> NotMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_not_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null) -> Null {
         if self.enabled {
@@ -68077,6 +75092,36 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(&mut self, node: ObjectMatchPat) -> ObjectMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_object_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(&mut self, node: ObjectMatchPatProp) -> ObjectMatchPatProp {
+        if self.enabled {
+            <V as Fold>::fold_object_match_pat_prop(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+    ) -> Vec<ObjectMatchPatProp> {
+        if self.enabled {
+            <V as Fold>::fold_object_match_pat_props(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat) -> ObjectPat {
         if self.enabled {
@@ -68218,6 +75263,24 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(&mut self, node: Option<Box<MatchPat>>) -> Option<Box<MatchPat>> {
+        if self.enabled {
+            <V as Fold>::fold_opt_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(&mut self, node: Option<MatchRest>) -> Option<MatchRest> {
+        if self.enabled {
+            <V as Fold>::fold_opt_match_rest(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -68371,6 +75434,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(&mut self, node: PipelineExpr) -> PipelineExpr {
+        if self.enabled {
+            <V as Fold>::fold_pipeline_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_private_method(&mut self, node: PrivateMethod) -> PrivateMethod {
         if self.enabled {
@@ -68452,6 +75524,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(&mut self, node: RelationalMatchPat) -> RelationalMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_relational_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat) -> RestPat {
         if self.enabled {
@@ -68653,6 +75734,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr) -> TopicExpr {
+        if self.enabled {
+            <V as Fold>::fold_topic_expr(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl) -> Tpl {
         if self.enabled {
@@ -68743,6 +75833,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(&mut self, node: ValueMatchPat) -> ValueMatchPat {
+        if self.enabled {
+            <V as Fold>::fold_value_match_pat(&mut self.visitor, node)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl) -> VarDecl {
         if self.enabled {
@@ -68839,6 +75938,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for ArrayLit {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for ArrayMatchPat {
+    #[doc = "Calls [Fold`::fold_array_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_array_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let elems = { <Vec<MatchPat> as FoldWith<V>>::fold_with(elems, visitor) };
+                let rest = { <Option<MatchRest> as FoldWith<V>>::fold_with(rest, visitor) };
+                ArrayMatchPat { span, elems, rest }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for ArrayPat {
     #[doc = "Calls [Fold`::fold_array_pat`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -69202,6 +76318,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for BindingIdent {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for BindingMatchPat {
+    #[doc = "Calls [Fold`::fold_binding_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_binding_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let kind = { <VarDeclKind as FoldWith<V>>::fold_with(kind, visitor) };
+                let id = { <BindingIdent as FoldWith<V>>::fold_with(id, visitor) };
+                BindingMatchPat { span, kind, id }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for BlockStmt {
     #[doc = "Calls [Fold`::fold_block_stmt`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -69551,6 +76684,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for ClassProp {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for CombinedMatchPat {
+    #[doc = "Calls [Fold`::fold_combined_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_combined_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let op = { <MatchPatCombinator as FoldWith<V>>::fold_with(op, visitor) };
+                let pats = { <Vec<MatchPat> as FoldWith<V>>::fold_with(pats, visitor) };
+                CombinedMatchPat { span, op, pats }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for ComputedPropName {
     #[doc = "Calls [Fold`::fold_computed_prop_name`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -69731,6 +76881,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for DefaultDecl {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for DoExpr {
+    #[doc = "Calls [Fold`::fold_do_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_do_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            DoExpr { span, body } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let body = { <BlockStmt as FoldWith<V>>::fold_with(body, visitor) };
+                DoExpr { span, body }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for DoWhileStmt {
     #[doc = "Calls [Fold`::fold_do_while_stmt`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -70060,6 +77226,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for Expr {
                 let _field_0 = <OptChainExpr as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::OptChain { 0: _field_0 }
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let _field_0 = <PipelineExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Pipeline { 0: _field_0 }
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let _field_0 = <TopicExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Topic { 0: _field_0 }
+            }
+            Expr::Match { 0: _field_0 } => {
+                let _field_0 = <MatchExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Match { 0: _field_0 }
+            }
+            Expr::Do { 0: _field_0 } => {
+                let _field_0 = <DoExpr as FoldWith<V>>::fold_with(_field_0, visitor);
+                Expr::Do { 0: _field_0 }
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let _field_0 = <Invalid as FoldWith<V>>::fold_with(_field_0, visitor);
                 Expr::Invalid { 0: _field_0 }
@@ -71098,6 +78280,127 @@ impl<V: ?Sized + Fold> FoldWith<V> for Lit {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for MatchCase {
+    #[doc = "Calls [Fold`::fold_match_case`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_case(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let pat = { <Option<Box<MatchPat>> as FoldWith<V>>::fold_with(pat, visitor) };
+                let guard = { <Option<Box<Expr>> as FoldWith<V>>::fold_with(guard, visitor) };
+                let body = { <Box<Expr> as FoldWith<V>>::fold_with(body, visitor) };
+                MatchCase {
+                    span,
+                    pat,
+                    guard,
+                    body,
+                }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchExpr {
+    #[doc = "Calls [Fold`::fold_match_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let subject = { <Box<Expr> as FoldWith<V>>::fold_with(subject, visitor) };
+                let cases = { <Vec<MatchCase> as FoldWith<V>>::fold_with(cases, visitor) };
+                MatchExpr {
+                    span,
+                    subject,
+                    cases,
+                }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchPat {
+    #[doc = "Calls [Fold`::fold_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let _field_0 = <BindingMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Binding { 0: _field_0 }
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let _field_0 = <ValueMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Value { 0: _field_0 }
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let _field_0 = <RelationalMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Relational { 0: _field_0 }
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let _field_0 = <ArrayMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Array { 0: _field_0 }
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let _field_0 = <ObjectMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Object { 0: _field_0 }
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let _field_0 = <CombinedMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Combined { 0: _field_0 }
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let _field_0 = <NotMatchPat as FoldWith<V>>::fold_with(_field_0, visitor);
+                MatchPat::Not { 0: _field_0 }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchPatCombinator {
+    #[doc = "Calls [Fold`::fold_match_pat_combinator`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_pat_combinator(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchPatCombinator::And => MatchPatCombinator::And,
+            MatchPatCombinator::Or => MatchPatCombinator::Or,
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for MatchRest {
+    #[doc = "Calls [Fold`::fold_match_rest`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_rest(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            MatchRest { span, arg } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let arg = { <Option<Box<MatchPat>> as FoldWith<V>>::fold_with(arg, visitor) };
+                MatchRest { span, arg }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for MemberExpr {
     #[doc = "Calls [Fold`::fold_member_expr`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -71366,6 +78669,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for NewExpr {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for NotMatchPat {
+    #[doc = "Calls [Fold`::fold_not_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_not_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            NotMatchPat { span, arg } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let arg = { <Box<MatchPat> as FoldWith<V>>::fold_with(arg, visitor) };
+                NotMatchPat { span, arg }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Null {
     #[doc = "Calls [Fold`::fold_null`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -71413,6 +78732,39 @@ impl<V: ?Sized + Fold> FoldWith<V> for ObjectLit {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for ObjectMatchPat {
+    #[doc = "Calls [Fold`::fold_object_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_object_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ObjectMatchPat { span, props } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let props = { <Vec<ObjectMatchPatProp> as FoldWith<V>>::fold_with(props, visitor) };
+                ObjectMatchPat { span, props }
+            }
+        }
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for ObjectMatchPatProp {
+    #[doc = "Calls [Fold`::fold_object_match_pat_prop`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_object_match_pat_prop(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let key = { <PropName as FoldWith<V>>::fold_with(key, visitor) };
+                let value = { <Option<Box<MatchPat>> as FoldWith<V>>::fold_with(value, visitor) };
+                ObjectMatchPatProp { span, key, value }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for ObjectPat {
     #[doc = "Calls [Fold`::fold_object_pat`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -71634,6 +78986,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for Pat {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for PipelineExpr {
+    #[doc = "Calls [Fold`::fold_pipeline_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_pipeline_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            PipelineExpr { span, left, right } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let left = { <Box<Expr> as FoldWith<V>>::fold_with(left, visitor) };
+                let right = { <Box<Expr> as FoldWith<V>>::fold_with(right, visitor) };
+                PipelineExpr { span, left, right }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for PrivateMethod {
     #[doc = "Calls [Fold`::fold_private_method`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -71855,6 +79224,23 @@ impl<V: ?Sized + Fold> FoldWith<V> for Regex {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for RelationalMatchPat {
+    #[doc = "Calls [Fold`::fold_relational_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_relational_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let op = { <BinaryOp as FoldWith<V>>::fold_with(op, visitor) };
+                let value = { <Box<Expr> as FoldWith<V>>::fold_with(value, visitor) };
+                RelationalMatchPat { span, op, value }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for RestPat {
     #[doc = "Calls [Fold`::fold_rest_pat`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -72311,6 +79697,21 @@ impl<V: ?Sized + Fold> FoldWith<V> for ThrowStmt {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for TopicExpr {
+    #[doc = "Calls [Fold`::fold_topic_expr`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_topic_expr(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            TopicExpr { span } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                TopicExpr { span }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Tpl {
     #[doc = "Calls [Fold`::fold_tpl`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -72506,6 +79907,22 @@ impl<V: ?Sized + Fold> FoldWith<V> for UsingDecl {
         }
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for ValueMatchPat {
+    #[doc = "Calls [Fold`::fold_value_match_pat`] with `self`."]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_value_match_pat(visitor, self)
+    }
+
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        match self {
+            ValueMatchPat { span, value } => {
+                let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let value = { <Box<Expr> as FoldWith<V>>::fold_with(value, visitor) };
+                ValueMatchPat { span, value }
+            }
+        }
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for VarDecl {
     #[doc = "Calls [Fold`::fold_var_decl`] with `self`."]
     fn fold_with(self, visitor: &mut V) -> Self {
@@ -72804,6 +80221,34 @@ impl<V: ?Sized + Fold> FoldWith<V> for Vec<JSXElementChild> {
         })
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for Vec<MatchCase> {
+    #[doc = "Calls [Fold`::fold_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_cases(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        swc_visit::util::move_map::MoveMap::move_map(self, |item| {
+            <MatchCase as FoldWith<V>>::fold_with(item, visitor)
+        })
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for Vec<MatchPat> {
+    #[doc = "Calls [Fold`::fold_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_match_pats(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        swc_visit::util::move_map::MoveMap::move_map(self, |item| {
+            <MatchPat as FoldWith<V>>::fold_with(item, visitor)
+        })
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Vec<ModuleItem> {
     #[doc = "Calls [Fold`::fold_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -72818,6 +80263,20 @@ impl<V: ?Sized + Fold> FoldWith<V> for Vec<ModuleItem> {
         })
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [Fold`::fold_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_object_match_pat_props(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        swc_visit::util::move_map::MoveMap::move_map(self, |item| {
+            <ObjectMatchPatProp as FoldWith<V>>::fold_with(item, visitor)
+        })
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [Fold`::fold_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -72940,6 +80399,30 @@ impl<V: ?Sized + Fold> FoldWith<V> for Option<JSXClosingElement> {
         self.map(|inner| <JSXClosingElement as FoldWith<V>>::fold_with(inner, visitor))
     }
 }
+impl<V: ?Sized + Fold> FoldWith<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [Fold`::fold_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_opt_match_pat(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        self.map(|inner| <Box<MatchPat> as FoldWith<V>>::fold_with(inner, visitor))
+    }
+}
+impl<V: ?Sized + Fold> FoldWith<V> for Option<MatchRest> {
+    #[doc = "Calls [Fold`::fold_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with(self, visitor: &mut V) -> Self {
+        <V as Fold>::fold_opt_match_rest(visitor, self)
+    }
+
+    #[inline]
+    fn fold_children_with(self, visitor: &mut V) -> Self {
+        self.map(|inner| <MatchRest as FoldWith<V>>::fold_with(inner, visitor))
+    }
+}
 impl<V: ?Sized + Fold> FoldWith<V> for Option<ModuleExportName> {
     #[doc = "Calls [Fold`::fold_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -73216,6 +80699,19 @@ pub trait FoldAstPath {
     fn fold_array_lit(&mut self, node: ArrayLit, __ast_path: &mut AstKindPath) -> ArrayLit {
         <ArrayLit as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ArrayMatchPat`.\n\nBy default, this method calls \
+             [`ArrayMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        <ArrayMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ArrayPat`.\n\nBy default, this method calls \
              [`ArrayPat::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -73369,6 +80865,19 @@ pub trait FoldAstPath {
     ) -> BindingIdent {
         <BindingIdent as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `BindingMatchPat`.\n\nBy default, this method calls \
+             [`BindingMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        <BindingMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `BlockStmt`.\n\nBy default, this method calls \
              [`BlockStmt::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -73491,6 +81000,19 @@ pub trait FoldAstPath {
     fn fold_class_prop(&mut self, node: ClassProp, __ast_path: &mut AstKindPath) -> ClassProp {
         <ClassProp as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `CombinedMatchPat`.\n\nBy default, this method calls \
+             [`CombinedMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        <CombinedMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ComputedPropName`.\n\nBy default, this method calls \
              [`ComputedPropName::fold_children_with_ast_path`]. If you want to recurse, you need \
              to call it manually."]
@@ -73578,6 +81100,13 @@ pub trait FoldAstPath {
     ) -> DefaultDecl {
         <DefaultDecl as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `DoExpr`.\n\nBy default, this method calls \
+             [`DoExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
+             manually."]
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        <DoExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `DoWhileStmt`.\n\nBy default, this method calls \
              [`DoWhileStmt::fold_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -74252,6 +81781,73 @@ pub trait FoldAstPath {
     fn fold_lit(&mut self, node: Lit, __ast_path: &mut AstKindPath) -> Lit {
         <Lit as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `MatchCase`.\n\nBy default, this method calls \
+             [`MatchCase::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        <MatchCase as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `Vec < MatchCase >`.\n\nBy default, this method calls [`Vec < \
+             MatchCase >::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        <Vec<MatchCase> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchExpr`.\n\nBy default, this method calls \
+             [`MatchExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        <MatchExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPat`.\n\nBy default, this method calls \
+             [`MatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        <MatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
+    #[doc = "Visit a node of type `MatchPatCombinator`.\n\nBy default, this method calls \
+             [`MatchPatCombinator::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        <MatchPatCombinator as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < MatchPat >`.\n\nBy default, this method calls [`Vec < \
+             MatchPat >::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        <Vec<MatchPat> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `MatchRest`.\n\nBy default, this method calls \
+             [`MatchRest::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        <MatchRest as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `MemberExpr`.\n\nBy default, this method calls \
              [`MemberExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -74367,6 +81963,17 @@ pub trait FoldAstPath {
     fn fold_new_expr(&mut self, node: NewExpr, __ast_path: &mut AstKindPath) -> NewExpr {
         <NewExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `NotMatchPat`.\n\nBy default, this method calls \
+             [`NotMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        <NotMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Null`.\n\nBy default, this method calls \
              [`Null::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -74388,6 +81995,45 @@ pub trait FoldAstPath {
     fn fold_object_lit(&mut self, node: ObjectLit, __ast_path: &mut AstKindPath) -> ObjectLit {
         <ObjectLit as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ObjectMatchPat`.\n\nBy default, this method calls \
+             [`ObjectMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        <ObjectMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `ObjectMatchPatProp`.\n\nBy default, this method calls \
+             [`ObjectMatchPatProp::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        <ObjectMatchPatProp as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Vec < ObjectMatchPatProp >`.\n\nBy default, this method calls \
+             [`Vec < ObjectMatchPatProp >::fold_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        <Vec<ObjectMatchPatProp> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `ObjectPat`.\n\nBy default, this method calls \
              [`ObjectPat::fold_children_with_ast_path`]. If you want to recurse, you need to call \
              it manually."]
@@ -74567,6 +82213,32 @@ pub trait FoldAstPath {
             node, self, __ast_path,
         )
     }
+    #[doc = "Visit a node of type `Option < Box < MatchPat > >`.\n\nBy default, this method calls \
+             [`Option < Box < MatchPat > >::fold_children_with_ast_path`]. If you want to recurse, \
+             you need to call it manually."]
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        <Option<Box<MatchPat>> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
+    #[doc = "Visit a node of type `Option < MatchRest >`.\n\nBy default, this method calls \
+             [`Option < MatchRest >::fold_children_with_ast_path`]. If you want to recurse, you \
+             need to call it manually."]
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        <Option<MatchRest> as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `Option < ModuleExportName >`.\n\nBy default, this method calls \
              [`Option < ModuleExportName >::fold_children_with_ast_path`]. If you want to recurse, \
              you need to call it manually."]
@@ -74739,6 +82411,17 @@ pub trait FoldAstPath {
     fn fold_pats(&mut self, node: Vec<Pat>, __ast_path: &mut AstKindPath) -> Vec<Pat> {
         <Vec<Pat> as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `PipelineExpr`.\n\nBy default, this method calls \
+             [`PipelineExpr::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <PipelineExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `PrivateMethod`.\n\nBy default, this method calls \
              [`PrivateMethod::fold_children_with_ast_path`]. If you want to recurse, you need to \
              call it manually."]
@@ -74826,6 +82509,19 @@ pub trait FoldAstPath {
     fn fold_regex(&mut self, node: Regex, __ast_path: &mut AstKindPath) -> Regex {
         <Regex as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `RelationalMatchPat`.\n\nBy default, this method calls \
+             [`RelationalMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need \
+             to call it manually."]
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        <RelationalMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `RestPat`.\n\nBy default, this method calls \
              [`RestPat::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -75020,6 +82716,13 @@ pub trait FoldAstPath {
     fn fold_throw_stmt(&mut self, node: ThrowStmt, __ast_path: &mut AstKindPath) -> ThrowStmt {
         <ThrowStmt as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `TopicExpr`.\n\nBy default, this method calls \
+             [`TopicExpr::fold_children_with_ast_path`]. If you want to recurse, you need to call \
+             it manually."]
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        <TopicExpr as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
+    }
     #[doc = "Visit a node of type `Tpl`.\n\nBy default, this method calls \
              [`Tpl::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -75102,6 +82805,19 @@ pub trait FoldAstPath {
     fn fold_using_decl(&mut self, node: UsingDecl, __ast_path: &mut AstKindPath) -> UsingDecl {
         <UsingDecl as FoldWithAstPath<Self>>::fold_children_with_ast_path(node, self, __ast_path)
     }
+    #[doc = "Visit a node of type `ValueMatchPat`.\n\nBy default, this method calls \
+             [`ValueMatchPat::fold_children_with_ast_path`]. If you want to recurse, you need to \
+             call it manually."]
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        <ValueMatchPat as FoldWithAstPath<Self>>::fold_children_with_ast_path(
+            node, self, __ast_path,
+        )
+    }
     #[doc = "Visit a node of type `VarDecl`.\n\nBy default, this method calls \
              [`VarDecl::fold_children_with_ast_path`]. If you want to recurse, you need to call it \
              manually."]
@@ -75192,6 +82908,15 @@ where
         <V as FoldAstPath>::fold_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        <V as FoldAstPath>::fold_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         <V as FoldAstPath>::fold_array_pat(&mut **self, node, __ast_path)
@@ -75305,6 +83030,15 @@ where
         <V as FoldAstPath>::fold_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        <V as FoldAstPath>::fold_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         <V as FoldAstPath>::fold_block_stmt(&mut **self, node, __ast_path)
@@ -75395,6 +83129,15 @@ where
         <V as FoldAstPath>::fold_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        <V as FoldAstPath>::fold_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -75460,6 +83203,11 @@ where
         <V as FoldAstPath>::fold_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        <V as FoldAstPath>::fold_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -75942,6 +83690,53 @@ where
         <V as FoldAstPath>::fold_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        <V as FoldAstPath>::fold_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        <V as FoldAstPath>::fold_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        <V as FoldAstPath>::fold_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        <V as FoldAstPath>::fold_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        <V as FoldAstPath>::fold_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        <V as FoldAstPath>::fold_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        <V as FoldAstPath>::fold_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         <V as FoldAstPath>::fold_member_expr(&mut **self, node, __ast_path)
@@ -76027,6 +83822,15 @@ where
         <V as FoldAstPath>::fold_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        <V as FoldAstPath>::fold_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         <V as FoldAstPath>::fold_null(&mut **self, node, __ast_path)
@@ -76042,6 +83846,33 @@ where
         <V as FoldAstPath>::fold_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        <V as FoldAstPath>::fold_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        <V as FoldAstPath>::fold_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as FoldAstPath>::fold_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         <V as FoldAstPath>::fold_object_pat(&mut **self, node, __ast_path)
@@ -76169,6 +84000,24 @@ where
         <V as FoldAstPath>::fold_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        <V as FoldAstPath>::fold_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        <V as FoldAstPath>::fold_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -76289,6 +84138,15 @@ where
         <V as FoldAstPath>::fold_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <V as FoldAstPath>::fold_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -76354,6 +84212,15 @@ where
         <V as FoldAstPath>::fold_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        <V as FoldAstPath>::fold_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         <V as FoldAstPath>::fold_rest_pat(&mut **self, node, __ast_path)
@@ -76492,6 +84359,11 @@ where
         <V as FoldAstPath>::fold_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        <V as FoldAstPath>::fold_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         <V as FoldAstPath>::fold_tpl(&mut **self, node, __ast_path)
@@ -76550,6 +84422,15 @@ where
         <V as FoldAstPath>::fold_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        <V as FoldAstPath>::fold_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         <V as FoldAstPath>::fold_var_decl(&mut **self, node, __ast_path)
@@ -76617,6 +84498,15 @@ where
         <V as FoldAstPath>::fold_array_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        <V as FoldAstPath>::fold_array_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         <V as FoldAstPath>::fold_array_pat(&mut **self, node, __ast_path)
@@ -76730,6 +84620,15 @@ where
         <V as FoldAstPath>::fold_binding_ident(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        <V as FoldAstPath>::fold_binding_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         <V as FoldAstPath>::fold_block_stmt(&mut **self, node, __ast_path)
@@ -76820,6 +84719,15 @@ where
         <V as FoldAstPath>::fold_class_prop(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        <V as FoldAstPath>::fold_combined_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -76885,6 +84793,11 @@ where
         <V as FoldAstPath>::fold_default_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        <V as FoldAstPath>::fold_do_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -77367,6 +85280,53 @@ where
         <V as FoldAstPath>::fold_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        <V as FoldAstPath>::fold_match_case(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        <V as FoldAstPath>::fold_match_cases(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        <V as FoldAstPath>::fold_match_expr(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        <V as FoldAstPath>::fold_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        <V as FoldAstPath>::fold_match_pat_combinator(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        <V as FoldAstPath>::fold_match_pats(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        <V as FoldAstPath>::fold_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         <V as FoldAstPath>::fold_member_expr(&mut **self, node, __ast_path)
@@ -77452,6 +85412,15 @@ where
         <V as FoldAstPath>::fold_new_expr(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        <V as FoldAstPath>::fold_not_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         <V as FoldAstPath>::fold_null(&mut **self, node, __ast_path)
@@ -77467,6 +85436,33 @@ where
         <V as FoldAstPath>::fold_object_lit(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        <V as FoldAstPath>::fold_object_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        <V as FoldAstPath>::fold_object_match_pat_prop(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        <V as FoldAstPath>::fold_object_match_pat_props(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         <V as FoldAstPath>::fold_object_pat(&mut **self, node, __ast_path)
@@ -77594,6 +85590,24 @@ where
         <V as FoldAstPath>::fold_opt_jsx_closing_element(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        <V as FoldAstPath>::fold_opt_match_pat(&mut **self, node, __ast_path)
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        <V as FoldAstPath>::fold_opt_match_rest(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -77714,6 +85728,15 @@ where
         <V as FoldAstPath>::fold_pats(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        <V as FoldAstPath>::fold_pipeline_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -77779,6 +85802,15 @@ where
         <V as FoldAstPath>::fold_regex(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        <V as FoldAstPath>::fold_relational_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         <V as FoldAstPath>::fold_rest_pat(&mut **self, node, __ast_path)
@@ -77917,6 +85949,11 @@ where
         <V as FoldAstPath>::fold_throw_stmt(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        <V as FoldAstPath>::fold_topic_expr(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         <V as FoldAstPath>::fold_tpl(&mut **self, node, __ast_path)
@@ -77975,6 +86012,15 @@ where
         <V as FoldAstPath>::fold_using_decl(&mut **self, node, __ast_path)
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        <V as FoldAstPath>::fold_value_match_pat(&mut **self, node, __ast_path)
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         <V as FoldAstPath>::fold_var_decl(&mut **self, node, __ast_path)
@@ -78050,6 +86096,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_array_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_array_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         match self {
@@ -78278,6 +86340,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_binding_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_binding_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         match self {
@@ -78456,6 +86534,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_combined_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_combined_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -78580,6 +86674,18 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_do_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_do_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -79502,6 +87608,102 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_case(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_case(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_cases(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_cases(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_pat_combinator(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_pat_combinator(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_pats(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_pats(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         match self {
@@ -79676,6 +87878,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_not_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_not_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         match self {
@@ -79706,6 +87924,54 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_object_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_object_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_object_match_pat_prop(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_object_match_pat_prop(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_object_match_pat_props(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_object_match_pat_props(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         match self {
@@ -79938,6 +88204,38 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_opt_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_opt_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_opt_match_rest(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_opt_match_rest(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -80156,6 +88454,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_pipeline_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_pipeline_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -80276,6 +88590,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_relational_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_relational_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         match self {
@@ -80546,6 +88876,18 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_topic_expr(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_topic_expr(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         match self {
@@ -80670,6 +89012,22 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        match self {
+            swc_visit::Either::Left(visitor) => {
+                FoldAstPath::fold_value_match_pat(visitor, node, __ast_path)
+            }
+            swc_visit::Either::Right(visitor) => {
+                FoldAstPath::fold_value_match_pat(visitor, node, __ast_path)
+            }
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         match self {
@@ -80797,6 +89155,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_array_match_pat(
+        &mut self,
+        node: ArrayMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ArrayMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_array_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_array_pat(&mut self, node: ArrayPat, __ast_path: &mut AstKindPath) -> ArrayPat {
         if self.enabled {
@@ -80978,6 +89349,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_binding_match_pat(
+        &mut self,
+        node: BindingMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> BindingMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_binding_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_block_stmt(&mut self, node: BlockStmt, __ast_path: &mut AstKindPath) -> BlockStmt {
         if self.enabled {
@@ -81124,6 +89508,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_combined_match_pat(
+        &mut self,
+        node: CombinedMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> CombinedMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_combined_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_computed_prop_name(
         &mut self,
@@ -81225,6 +89622,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_do_expr(&mut self, node: DoExpr, __ast_path: &mut AstKindPath) -> DoExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_do_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_do_while_stmt(
         &mut self,
@@ -81971,6 +90377,81 @@ where
         }
     }
 
+    #[inline]
+    fn fold_match_case(&mut self, node: MatchCase, __ast_path: &mut AstKindPath) -> MatchCase {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_case(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_cases(
+        &mut self,
+        node: Vec<MatchCase>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchCase> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_cases(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_expr(&mut self, node: MatchExpr, __ast_path: &mut AstKindPath) -> MatchExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat(&mut self, node: MatchPat, __ast_path: &mut AstKindPath) -> MatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pat_combinator(
+        &mut self,
+        node: MatchPatCombinator,
+        __ast_path: &mut AstKindPath,
+    ) -> MatchPatCombinator {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_pat_combinator(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_pats(
+        &mut self,
+        node: Vec<MatchPat>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<MatchPat> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_pats(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_match_rest(&mut self, node: MatchRest, __ast_path: &mut AstKindPath) -> MatchRest {
+        if self.enabled {
+            <V as FoldAstPath>::fold_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_member_expr(&mut self, node: MemberExpr, __ast_path: &mut AstKindPath) -> MemberExpr {
         if self.enabled {
@@ -82108,6 +90589,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_not_match_pat(
+        &mut self,
+        node: NotMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> NotMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_not_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_null(&mut self, node: Null, __ast_path: &mut AstKindPath) -> Null {
         if self.enabled {
@@ -82135,6 +90629,45 @@ where
         }
     }
 
+    #[inline]
+    fn fold_object_match_pat(
+        &mut self,
+        node: ObjectMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_object_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_prop(
+        &mut self,
+        node: ObjectMatchPatProp,
+        __ast_path: &mut AstKindPath,
+    ) -> ObjectMatchPatProp {
+        if self.enabled {
+            <V as FoldAstPath>::fold_object_match_pat_prop(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_object_match_pat_props(
+        &mut self,
+        node: Vec<ObjectMatchPatProp>,
+        __ast_path: &mut AstKindPath,
+    ) -> Vec<ObjectMatchPatProp> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_object_match_pat_props(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_object_pat(&mut self, node: ObjectPat, __ast_path: &mut AstKindPath) -> ObjectPat {
         if self.enabled {
@@ -82322,6 +90855,32 @@ where
         }
     }
 
+    #[inline]
+    fn fold_opt_match_pat(
+        &mut self,
+        node: Option<Box<MatchPat>>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<Box<MatchPat>> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_opt_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
+    #[inline]
+    fn fold_opt_match_rest(
+        &mut self,
+        node: Option<MatchRest>,
+        __ast_path: &mut AstKindPath,
+    ) -> Option<MatchRest> {
+        if self.enabled {
+            <V as FoldAstPath>::fold_opt_match_rest(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_opt_module_export_name(
         &mut self,
@@ -82506,6 +91065,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_pipeline_expr(
+        &mut self,
+        node: PipelineExpr,
+        __ast_path: &mut AstKindPath,
+    ) -> PipelineExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_pipeline_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_private_method(
         &mut self,
@@ -82607,6 +91179,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_relational_match_pat(
+        &mut self,
+        node: RelationalMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> RelationalMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_relational_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_rest_pat(&mut self, node: RestPat, __ast_path: &mut AstKindPath) -> RestPat {
         if self.enabled {
@@ -82833,6 +91418,15 @@ where
         }
     }
 
+    #[inline]
+    fn fold_topic_expr(&mut self, node: TopicExpr, __ast_path: &mut AstKindPath) -> TopicExpr {
+        if self.enabled {
+            <V as FoldAstPath>::fold_topic_expr(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_tpl(&mut self, node: Tpl, __ast_path: &mut AstKindPath) -> Tpl {
         if self.enabled {
@@ -82931,6 +91525,19 @@ where
         }
     }
 
+    #[inline]
+    fn fold_value_match_pat(
+        &mut self,
+        node: ValueMatchPat,
+        __ast_path: &mut AstKindPath,
+    ) -> ValueMatchPat {
+        if self.enabled {
+            <V as FoldAstPath>::fold_value_match_pat(&mut self.visitor, node, __ast_path)
+        } else {
+            node
+        }
+    }
+
     #[inline]
     fn fold_var_decl(&mut self, node: VarDecl, __ast_path: &mut AstKindPath) -> VarDecl {
         if self.enabled {
@@ -83065,6 +91672,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ArrayLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ArrayMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_array_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_array_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ArrayMatchPat { span, elems, rest } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let elems = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Elems(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        elems,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let rest = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ArrayMatchPat(
+                        self::fields::ArrayMatchPatField::Rest,
+                    ));
+                    <Option<MatchRest> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        rest,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ArrayMatchPat { span, elems, rest }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ArrayPat {
     #[doc = "Calls [FoldAstPath`::fold_array_pat`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -83765,6 +92418,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for BindingIdent {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for BindingMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_binding_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_binding_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            BindingMatchPat { span, kind, id } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let kind = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Kind,
+                    ));
+                    <VarDeclKind as FoldWithAstPath<V>>::fold_with_ast_path(
+                        kind,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let id = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::BindingMatchPat(
+                        self::fields::BindingMatchPatField::Id,
+                    ));
+                    <BindingIdent as FoldWithAstPath<V>>::fold_with_ast_path(
+                        id,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                BindingMatchPat { span, kind, id }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for BlockStmt {
     #[doc = "Calls [FoldAstPath`::fold_block_stmt`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -84486,6 +93185,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ClassProp {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for CombinedMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_combined_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_combined_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            CombinedMatchPat { span, op, pats } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let op = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Op,
+                    ));
+                    <MatchPatCombinator as FoldWithAstPath<V>>::fold_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let pats = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::CombinedMatchPat(
+                        self::fields::CombinedMatchPatField::Pats(usize::MAX),
+                    ));
+                    <Vec<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        pats,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                CombinedMatchPat { span, op, pats }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ComputedPropName {
     #[doc = "Calls [FoldAstPath`::fold_computed_prop_name`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -84876,6 +93621,40 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DefaultDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DoExpr {
+    #[doc = "Calls [FoldAstPath`::fold_do_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_do_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            DoExpr { span, body } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let body = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::DoExpr(self::fields::DoExprField::Body));
+                    <BlockStmt as FoldWithAstPath<V>>::fold_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                DoExpr { span, body }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DoWhileStmt {
     #[doc = "Calls [FoldAstPath`::fold_do_while_stmt`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -85630,6 +94409,46 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Expr {
                 );
                 Expr::OptChain { 0: _field_0 }
             }
+            Expr::Pipeline { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Pipeline));
+                let _field_0 = <PipelineExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Pipeline { 0: _field_0 }
+            }
+            Expr::Topic { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Topic));
+                let _field_0 = <TopicExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Topic { 0: _field_0 }
+            }
+            Expr::Match { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Match));
+                let _field_0 = <MatchExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Match { 0: _field_0 }
+            }
+            Expr::Do { 0: _field_0 } => {
+                let mut __ast_path =
+                    __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Do));
+                let _field_0 = <DoExpr as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                Expr::Do { 0: _field_0 }
+            }
             Expr::Invalid { 0: _field_0 } => {
                 let mut __ast_path =
                     __ast_path.with_guard(AstParentKind::Expr(self::fields::ExprField::Invalid));
@@ -87830,6 +96649,257 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Lit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchCase {
+    #[doc = "Calls [FoldAstPath`::fold_match_case`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_case(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchCase {
+                span,
+                pat,
+                guard,
+                body,
+            } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let pat = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Pat));
+                    <Option<Box<MatchPat>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        pat,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let guard = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchCase(
+                        self::fields::MatchCaseField::Guard,
+                    ));
+                    <Option<Box<Expr>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        guard,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let body = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchCase(self::fields::MatchCaseField::Body));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        body,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                MatchCase {
+                    span,
+                    pat,
+                    guard,
+                    body,
+                }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchExpr {
+    #[doc = "Calls [FoldAstPath`::fold_match_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchExpr {
+                span,
+                subject,
+                cases,
+            } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchExpr(self::fields::MatchExprField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let subject = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Subject,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        subject,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let cases = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchExpr(
+                        self::fields::MatchExprField::Cases(usize::MAX),
+                    ));
+                    <Vec<MatchCase> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        cases,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                MatchExpr {
+                    span,
+                    subject,
+                    cases,
+                }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchPat::Binding { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Binding,
+                ));
+                let _field_0 = <BindingMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Binding { 0: _field_0 }
+            }
+            MatchPat::Value { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Value));
+                let _field_0 = <ValueMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Value { 0: _field_0 }
+            }
+            MatchPat::Relational { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Relational,
+                ));
+                let _field_0 = <RelationalMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Relational { 0: _field_0 }
+            }
+            MatchPat::Array { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Array));
+                let _field_0 = <ArrayMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Array { 0: _field_0 }
+            }
+            MatchPat::Object { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Object));
+                let _field_0 = <ObjectMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Object { 0: _field_0 }
+            }
+            MatchPat::Combined { 0: _field_0 } => {
+                let mut __ast_path = __ast_path.with_guard(AstParentKind::MatchPat(
+                    self::fields::MatchPatField::Combined,
+                ));
+                let _field_0 = <CombinedMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Combined { 0: _field_0 }
+            }
+            MatchPat::Not { 0: _field_0 } => {
+                let mut __ast_path = __ast_path
+                    .with_guard(AstParentKind::MatchPat(self::fields::MatchPatField::Not));
+                let _field_0 = <NotMatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    _field_0,
+                    visitor,
+                    &mut *__ast_path,
+                );
+                MatchPat::Not { 0: _field_0 }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchPatCombinator {
+    #[doc = "Calls [FoldAstPath`::fold_match_pat_combinator`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_pat_combinator(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchPatCombinator::And => MatchPatCombinator::And,
+            MatchPatCombinator::Or => MatchPatCombinator::Or,
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MatchRest {
+    #[doc = "Calls [FoldAstPath`::fold_match_rest`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_rest(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            MatchRest { span, arg } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let arg = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::MatchRest(self::fields::MatchRestField::Arg));
+                    <Option<Box<MatchPat>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                MatchRest { span, arg }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for MemberExpr {
     #[doc = "Calls [FoldAstPath`::fold_member_expr`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -88377,6 +97447,42 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for NewExpr {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for NotMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_not_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_not_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            NotMatchPat { span, arg } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let arg = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::NotMatchPat(
+                        self::fields::NotMatchPatField::Arg,
+                    ));
+                    <Box<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        arg,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                NotMatchPat { span, arg }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Null {
     #[doc = "Calls [FoldAstPath`::fold_null`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -88471,6 +97577,88 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectLit {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_object_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_object_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ObjectMatchPat { span, props } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let props = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPat(
+                        self::fields::ObjectMatchPatField::Props(usize::MAX),
+                    ));
+                    <Vec<ObjectMatchPatProp> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        props,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ObjectMatchPat { span, props }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectMatchPatProp {
+    #[doc = "Calls [FoldAstPath`::fold_object_match_pat_prop`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_object_match_pat_prop(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ObjectMatchPatProp { span, key, value } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let key = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Key,
+                    ));
+                    <PropName as FoldWithAstPath<V>>::fold_with_ast_path(
+                        key,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let value = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ObjectMatchPatProp(
+                        self::fields::ObjectMatchPatPropField::Value,
+                    ));
+                    <Option<Box<MatchPat>> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ObjectMatchPatProp { span, key, value }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ObjectPat {
     #[doc = "Calls [FoldAstPath`::fold_object_pat`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -88901,6 +98089,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Pat {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for PipelineExpr {
+    #[doc = "Calls [FoldAstPath`::fold_pipeline_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_pipeline_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            PipelineExpr { span, left, right } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let left = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Left,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        left,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let right = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::PipelineExpr(
+                        self::fields::PipelineExprField::Right,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        right,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                PipelineExpr { span, left, right }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for PrivateMethod {
     #[doc = "Calls [FoldAstPath`::fold_private_method`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -89353,6 +98587,52 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Regex {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for RelationalMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_relational_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_relational_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            RelationalMatchPat { span, op, value } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let op = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Op,
+                    ));
+                    <BinaryOp as FoldWithAstPath<V>>::fold_with_ast_path(
+                        op,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let value = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::RelationalMatchPat(
+                        self::fields::RelationalMatchPatField::Value,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                RelationalMatchPat { span, op, value }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for RestPat {
     #[doc = "Calls [FoldAstPath`::fold_rest_pat`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -90364,6 +99644,31 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ThrowStmt {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for TopicExpr {
+    #[doc = "Calls [FoldAstPath`::fold_topic_expr`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_topic_expr(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            TopicExpr { span } => {
+                let span = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::TopicExpr(self::fields::TopicExprField::Span));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                TopicExpr { span }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Tpl {
     #[doc = "Calls [FoldAstPath`::fold_tpl`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -90728,6 +100033,42 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for UsingDecl {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for ValueMatchPat {
+    #[doc = "Calls [FoldAstPath`::fold_value_match_pat`] with `self`."]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_value_match_pat(visitor, self, __ast_path)
+    }
+
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        match self {
+            ValueMatchPat { span, value } => {
+                let span = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Span,
+                    ));
+                    <swc_common::Span as FoldWithAstPath<V>>::fold_with_ast_path(
+                        span,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let value = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::ValueMatchPat(
+                        self::fields::ValueMatchPatField::Value,
+                    ));
+                    <Box<Expr> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        value,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                ValueMatchPat { span, value }
+            }
+        }
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for VarDecl {
     #[doc = "Calls [FoldAstPath`::fold_var_decl`] with `self`."]
     fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
@@ -91268,6 +100609,54 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<JSXElementChild> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<MatchCase> {
+    #[doc = "Calls [FoldAstPath`::fold_match_cases`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_cases(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.into_iter()
+            .enumerate()
+            .map(|(__idx, item)| {
+                let mut __ast_path = __ast_path.with_index_guard(__idx);
+                <MatchCase as FoldWithAstPath<V>>::fold_with_ast_path(
+                    item,
+                    visitor,
+                    &mut *__ast_path,
+                )
+            })
+            .collect()
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<MatchPat> {
+    #[doc = "Calls [FoldAstPath`::fold_match_pats`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_match_pats(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.into_iter()
+            .enumerate()
+            .map(|(__idx, item)| {
+                let mut __ast_path = __ast_path.with_index_guard(__idx);
+                <MatchPat as FoldWithAstPath<V>>::fold_with_ast_path(
+                    item,
+                    visitor,
+                    &mut *__ast_path,
+                )
+            })
+            .collect()
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ModuleItem> {
     #[doc = "Calls [FoldAstPath`::fold_module_items`] with `self`. (Extra impl)"]
     #[inline]
@@ -91292,6 +100681,30 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ModuleItem> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ObjectMatchPatProp> {
+    #[doc = "Calls [FoldAstPath`::fold_object_match_pat_props`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_object_match_pat_props(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.into_iter()
+            .enumerate()
+            .map(|(__idx, item)| {
+                let mut __ast_path = __ast_path.with_index_guard(__idx);
+                <ObjectMatchPatProp as FoldWithAstPath<V>>::fold_with_ast_path(
+                    item,
+                    visitor,
+                    &mut *__ast_path,
+                )
+            })
+            .collect()
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Vec<ObjectPatProp> {
     #[doc = "Calls [FoldAstPath`::fold_object_pat_props`] with `self`. (Extra impl)"]
     #[inline]
@@ -91464,6 +100877,38 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<JSXClosingElement> {
 }
 #[cfg(any(docsrs, feature = "path"))]
 #[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<Box<MatchPat>> {
+    #[doc = "Calls [FoldAstPath`::fold_opt_match_pat`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_opt_match_pat(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.map(|inner| {
+            <Box<MatchPat> as FoldWithAstPath<V>>::fold_with_ast_path(inner, visitor, __ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
+impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<MatchRest> {
+    #[doc = "Calls [FoldAstPath`::fold_opt_match_rest`] with `self`. (Extra impl)"]
+    #[inline]
+    fn fold_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        <V as FoldAstPath>::fold_opt_match_rest(visitor, self, __ast_path)
+    }
+
+    #[inline]
+    fn fold_children_with_ast_path(self, visitor: &mut V, __ast_path: &mut AstKindPath) -> Self {
+        self.map(|inner| {
+            <MatchRest as FoldWithAstPath<V>>::fold_with_ast_path(inner, visitor, __ast_path)
+        })
+    }
+}
+#[cfg(any(docsrs, feature = "path"))]
+#[cfg_attr(docsrs, doc(cfg(feature = "path")))]
 impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Option<ModuleExportName> {
     #[doc = "Calls [FoldAstPath`::fold_opt_module_export_name`] with `self`. (Extra impl)"]
     #[inline]
@@ -91886,6 +101331,27 @@ pub mod fields {
         #[doc = "Represents [`ArrayLit::elems`]"]
         Elems(usize),
     }
+    impl ArrayMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Elems(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ArrayMatchPatField {
+        #[doc = "Represents [`ArrayMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`ArrayMatchPat::elems`]"]
+        Elems(usize),
+        #[doc = "Represents [`ArrayMatchPat::rest`]"]
+        Rest,
+    }
     impl ArrayPatField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -92243,6 +101709,23 @@ pub mod fields {
         #[doc = "Represents [`BindingIdent::type_ann`]"]
         TypeAnn,
     }
+    impl BindingMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum BindingMatchPatField {
+        #[doc = "Represents [`BindingMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`BindingMatchPat::kind`]"]
+        Kind,
+        #[doc = "Represents [`BindingMatchPat::id`]"]
+        Id,
+    }
     impl BlockStmtField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -92537,6 +102020,27 @@ pub mod fields {
         #[doc = "Represents [`ClassProp::definite`]"]
         Definite,
     }
+    impl CombinedMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Pats(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum CombinedMatchPatField {
+        #[doc = "Represents [`CombinedMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`CombinedMatchPat::op`]"]
+        Op,
+        #[doc = "Represents [`CombinedMatchPat::pats`]"]
+        Pats(usize),
+    }
     impl ComputedPropNameField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -92685,6 +102189,21 @@ pub mod fields {
         #[doc = "Represents [`DefaultDecl::TsInterfaceDecl`]"]
         TsInterfaceDecl,
     }
+    impl DoExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum DoExprField {
+        #[doc = "Represents [`DoExpr::span`]"]
+        Span,
+        #[doc = "Represents [`DoExpr::body`]"]
+        Body,
+    }
     impl DoWhileStmtField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -92925,6 +102444,14 @@ pub mod fields {
         PrivateName,
         #[doc = "Represents [`Expr::OptChain`]"]
         OptChain,
+        #[doc = "Represents [`Expr::Pipeline`]"]
+        Pipeline,
+        #[doc = "Represents [`Expr::Topic`]"]
+        Topic,
+        #[doc = "Represents [`Expr::Match`]"]
+        Match,
+        #[doc = "Represents [`Expr::Do`]"]
+        Do,
         #[doc = "Represents [`Expr::Invalid`]"]
         Invalid,
     }
@@ -93764,6 +103291,99 @@ pub mod fields {
         #[doc = "Represents [`Lit::JSXText`]"]
         Jsxtext,
     }
+    impl MatchCaseField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchCaseField {
+        #[doc = "Represents [`MatchCase::span`]"]
+        Span,
+        #[doc = "Represents [`MatchCase::pat`]"]
+        Pat,
+        #[doc = "Represents [`MatchCase::guard`]"]
+        Guard,
+        #[doc = "Represents [`MatchCase::body`]"]
+        Body,
+    }
+    impl MatchExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Cases(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchExprField {
+        #[doc = "Represents [`MatchExpr::span`]"]
+        Span,
+        #[doc = "Represents [`MatchExpr::subject`]"]
+        Subject,
+        #[doc = "Represents [`MatchExpr::cases`]"]
+        Cases(usize),
+    }
+    impl MatchPatField {
+        #[inline(always)]
+        pub(crate) fn set_index(&mut self, _: usize) {
+            swc_visit::wrong_ast_path();
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchPatField {
+        #[doc = "Represents [`MatchPat::Binding`]"]
+        Binding,
+        #[doc = "Represents [`MatchPat::Value`]"]
+        Value,
+        #[doc = "Represents [`MatchPat::Relational`]"]
+        Relational,
+        #[doc = "Represents [`MatchPat::Array`]"]
+        Array,
+        #[doc = "Represents [`MatchPat::Object`]"]
+        Object,
+        #[doc = "Represents [`MatchPat::Combined`]"]
+        Combined,
+        #[doc = "Represents [`MatchPat::Not`]"]
+        Not,
+    }
+    impl MatchPatCombinatorField {
+        #[inline(always)]
+        pub(crate) fn set_index(&mut self, _: usize) {
+            swc_visit::wrong_ast_path();
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchPatCombinatorField {
+        #[doc = "Represents [`MatchPatCombinator::And`]"]
+        And,
+        #[doc = "Represents [`MatchPatCombinator::Or`]"]
+        Or,
+    }
+    impl MatchRestField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum MatchRestField {
+        #[doc = "Represents [`MatchRest::span`]"]
+        Span,
+        #[doc = "Represents [`MatchRest::arg`]"]
+        Arg,
+    }
     impl MemberExprField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -93984,6 +103604,21 @@ pub mod fields {
         #[doc = "Represents [`NewExpr::type_args`]"]
         TypeArgs,
     }
+    impl NotMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum NotMatchPatField {
+        #[doc = "Represents [`NotMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`NotMatchPat::arg`]"]
+        Arg,
+    }
     impl NullField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -94033,6 +103668,42 @@ pub mod fields {
         #[doc = "Represents [`ObjectLit::props`]"]
         Props(usize),
     }
+    impl ObjectMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                Self::Props(idx) => {
+                    assert_initial_index(*idx, index);
+                    *idx = index;
+                }
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ObjectMatchPatField {
+        #[doc = "Represents [`ObjectMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`ObjectMatchPat::props`]"]
+        Props(usize),
+    }
+    impl ObjectMatchPatPropField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ObjectMatchPatPropField {
+        #[doc = "Represents [`ObjectMatchPatProp::span`]"]
+        Span,
+        #[doc = "Represents [`ObjectMatchPatProp::key`]"]
+        Key,
+        #[doc = "Represents [`ObjectMatchPatProp::value`]"]
+        Value,
+    }
     impl ObjectPatField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -94202,6 +103873,23 @@ pub mod fields {
         #[doc = "Represents [`Pat::Expr`]"]
         Expr,
     }
+    impl PipelineExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum PipelineExprField {
+        #[doc = "Represents [`PipelineExpr::span`]"]
+        Span,
+        #[doc = "Represents [`PipelineExpr::left`]"]
+        Left,
+        #[doc = "Represents [`PipelineExpr::right`]"]
+        Right,
+    }
     impl PrivateMethodField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -94372,6 +104060,23 @@ pub mod fields {
         #[doc = "Represents [`Regex::flags`]"]
         Flags,
     }
+    impl RelationalMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum RelationalMatchPatField {
+        #[doc = "Represents [`RelationalMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`RelationalMatchPat::op`]"]
+        Op,
+        #[doc = "Represents [`RelationalMatchPat::value`]"]
+        Value,
+    }
     impl RestPatField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -94729,6 +104434,19 @@ pub mod fields {
         #[doc = "Represents [`ThrowStmt::arg`]"]
         Arg,
     }
+    impl TopicExprField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum TopicExprField {
+        #[doc = "Represents [`TopicExpr::span`]"]
+        Span,
+    }
     impl TplField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -94903,6 +104621,21 @@ pub mod fields {
         #[doc = "Represents [`UsingDecl::decls`]"]
         Decls(usize),
     }
+    impl ValueMatchPatField {
+        pub(crate) fn set_index(&mut self, index: usize) {
+            match self {
+                _ => swc_visit::wrong_ast_path(),
+            }
+        }
+    }
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
+    pub enum ValueMatchPatField {
+        #[doc = "Represents [`ValueMatchPat::span`]"]
+        Span,
+        #[doc = "Represents [`ValueMatchPat::value`]"]
+        Value,
+    }
     impl VarDeclField {
         pub(crate) fn set_index(&mut self, index: usize) {
             match self {
@@ -95032,6 +104765,7 @@ pub mod fields {
     #[cfg_attr(feature = "serde-impl", derive(serde::Serialize, serde::Deserialize))]
     pub enum AstParentKind {
         ArrayLit(ArrayLitField),
+        ArrayMatchPat(ArrayMatchPatField),
         ArrayPat(ArrayPatField),
         ArrowExpr(ArrowExprField),
         AssignExpr(AssignExprField),
@@ -95047,6 +104781,7 @@ pub mod fields {
         BinExpr(BinExprField),
         BinaryOp(BinaryOpField),
         BindingIdent(BindingIdentField),
+        BindingMatchPat(BindingMatchPatField),
         BlockStmt(BlockStmtField),
         BlockStmtOrExpr(BlockStmtOrExprField),
         Bool(BoolField),
@@ -95060,6 +104795,7 @@ pub mod fields {
         ClassMember(ClassMemberField),
         ClassMethod(ClassMethodField),
         ClassProp(ClassPropField),
+        CombinedMatchPat(CombinedMatchPatField),
         ComputedPropName(ComputedPropNameField),
         CondExpr(CondExprField),
         Constructor(ConstructorField),
@@ -95068,6 +104804,7 @@ pub mod fields {
         Decl(DeclField),
         Decorator(DecoratorField),
         DefaultDecl(DefaultDeclField),
+        DoExpr(DoExprField),
         DoWhileStmt(DoWhileStmtField),
         EmptyStmt(EmptyStmtField),
         ExportAll(ExportAllField),
@@ -95127,6 +104864,11 @@ pub mod fields {
         KeyValueProp(KeyValuePropField),
         LabeledStmt(LabeledStmtField),
         Lit(LitField),
+        MatchCase(MatchCaseField),
+        MatchExpr(MatchExprField),
+        MatchPat(MatchPatField),
+        MatchPatCombinator(MatchPatCombinatorField),
+        MatchRest(MatchRestField),
         MemberExpr(MemberExprField),
         MemberProp(MemberPropField),
         MetaPropExpr(MetaPropExprField),
@@ -95139,9 +104881,12 @@ pub mod fields {
         ModuleItem(ModuleItemField),
         NamedExport(NamedExportField),
         NewExpr(NewExprField),
+        NotMatchPat(NotMatchPatField),
         Null(NullField),
         Number(NumberField),
         ObjectLit(ObjectLitField),
+        ObjectMatchPat(ObjectMatchPatField),
+        ObjectMatchPatProp(ObjectMatchPatPropField),
         ObjectPat(ObjectPatField),
         ObjectPatProp(ObjectPatPropField),
         OptCall(OptCallField),
@@ -95151,6 +104896,7 @@ pub mod fields {
         ParamOrTsParamProp(ParamOrTsParamPropField),
         ParenExpr(ParenExprField),
         Pat(PatField),
+        PipelineExpr(PipelineExprField),
         PrivateMethod(PrivateMethodField),
         PrivateName(PrivateNameField),
         PrivateProp(PrivatePropField),
@@ -95159,6 +104905,7 @@ pub mod fields {
         PropName(PropNameField),
         PropOrSpread(PropOrSpreadField),
         Regex(RegexField),
+        RelationalMatchPat(RelationalMatchPatField),
         RestPat(RestPatField),
         ReturnStmt(ReturnStmtField),
         Script(ScriptField),
@@ -95177,6 +104924,7 @@ pub mod fields {
         TaggedTpl(TaggedTplField),
         ThisExpr(ThisExprField),
         ThrowStmt(ThrowStmtField),
+        TopicExpr(TopicExprField),
         Tpl(TplField),
         TplElement(TplElementField),
         TruePlusMinus(TruePlusMinusField),
@@ -95186,6 +104934,7 @@ pub mod fields {
         UpdateExpr(UpdateExprField),
         UpdateOp(UpdateOpField),
         UsingDecl(UsingDeclField),
+        ValueMatchPat(ValueMatchPatField),
         VarDecl(VarDeclField),
         VarDeclKind(VarDeclKindField),
         VarDeclOrExpr(VarDeclOrExprField),
@@ -95199,6 +104948,7 @@ pub mod fields {
         fn set_index(&mut self, index: usize) {
             match self {
                 Self::ArrayLit(v) => v.set_index(index),
+                Self::ArrayMatchPat(v) => v.set_index(index),
                 Self::ArrayPat(v) => v.set_index(index),
                 Self::ArrowExpr(v) => v.set_index(index),
                 Self::AssignExpr(v) => v.set_index(index),
@@ -95214,6 +104964,7 @@ pub mod fields {
                 Self::BinExpr(v) => v.set_index(index),
                 Self::BinaryOp(v) => v.set_index(index),
                 Self::BindingIdent(v) => v.set_index(index),
+                Self::BindingMatchPat(v) => v.set_index(index),
                 Self::BlockStmt(v) => v.set_index(index),
                 Self::BlockStmtOrExpr(v) => v.set_index(index),
                 Self::Bool(v) => v.set_index(index),
@@ -95227,6 +104978,7 @@ pub mod fields {
                 Self::ClassMember(v) => v.set_index(index),
                 Self::ClassMethod(v) => v.set_index(index),
                 Self::ClassProp(v) => v.set_index(index),
+                Self::CombinedMatchPat(v) => v.set_index(index),
                 Self::ComputedPropName(v) => v.set_index(index),
                 Self::CondExpr(v) => v.set_index(index),
                 Self::Constructor(v) => v.set_index(index),
@@ -95235,6 +104987,7 @@ pub mod fields {
                 Self::Decl(v) => v.set_index(index),
                 Self::Decorator(v) => v.set_index(index),
                 Self::DefaultDecl(v) => v.set_index(index),
+                Self::DoExpr(v) => v.set_index(index),
                 Self::DoWhileStmt(v) => v.set_index(index),
                 Self::EmptyStmt(v) => v.set_index(index),
                 Self::ExportAll(v) => v.set_index(index),
@@ -95294,6 +105047,11 @@ pub mod fields {
                 Self::KeyValueProp(v) => v.set_index(index),
                 Self::LabeledStmt(v) => v.set_index(index),
                 Self::Lit(v) => v.set_index(index),
+                Self::MatchCase(v) => v.set_index(index),
+                Self::MatchExpr(v) => v.set_index(index),
+                Self::MatchPat(v) => v.set_index(index),
+                Self::MatchPatCombinator(v) => v.set_index(index),
+                Self::MatchRest(v) => v.set_index(index),
                 Self::MemberExpr(v) => v.set_index(index),
                 Self::MemberProp(v) => v.set_index(index),
                 Self::MetaPropExpr(v) => v.set_index(index),
@@ -95306,9 +105064,12 @@ pub mod fields {
                 Self::ModuleItem(v) => v.set_index(index),
                 Self::NamedExport(v) => v.set_index(index),
                 Self::NewExpr(v) => v.set_index(index),
+                Self::NotMatchPat(v) => v.set_index(index),
                 Self::Null(v) => v.set_index(index),
                 Self::Number(v) => v.set_index(index),
                 Self::ObjectLit(v) => v.set_index(index),
+                Self::ObjectMatchPat(v) => v.set_index(index),
+                Self::ObjectMatchPatProp(v) => v.set_index(index),
                 Self::ObjectPat(v) => v.set_index(index),
                 Self::ObjectPatProp(v) => v.set_index(index),
                 Self::OptCall(v) => v.set_index(index),
@@ -95318,6 +105079,7 @@ pub mod fields {
                 Self::ParamOrTsParamProp(v) => v.set_index(index),
                 Self::ParenExpr(v) => v.set_index(index),
                 Self::Pat(v) => v.set_index(index),
+                Self::PipelineExpr(v) => v.set_index(index),
                 Self::PrivateMethod(v) => v.set_index(index),
                 Self::PrivateName(v) => v.set_index(index),
                 Self::PrivateProp(v) => v.set_index(index),
@@ -95326,6 +105088,7 @@ pub mod fields {
                 Self::PropName(v) => v.set_index(index),
                 Self::PropOrSpread(v) => v.set_index(index),
                 Self::Regex(v) => v.set_index(index),
+                Self::RelationalMatchPat(v) => v.set_index(index),
                 Self::RestPat(v) => v.set_index(index),
                 Self::ReturnStmt(v) => v.set_index(index),
                 Self::Script(v) => v.set_index(index),
@@ -95344,6 +105107,7 @@ pub mod fields {
                 Self::TaggedTpl(v) => v.set_index(index),
                 Self::ThisExpr(v) => v.set_index(index),
                 Self::ThrowStmt(v) => v.set_index(index),
+                Self::TopicExpr(v) => v.set_index(index),
                 Self::Tpl(v) => v.set_index(index),
                 Self::TplElement(v) => v.set_index(index),
                 Self::TruePlusMinus(v) => v.set_index(index),
@@ -95353,6 +105117,7 @@ pub mod fields {
                 Self::UpdateExpr(v) => v.set_index(index),
                 Self::UpdateOp(v) => v.set_index(index),
                 Self::UsingDecl(v) => v.set_index(index),
+                Self::ValueMatchPat(v) => v.set_index(index),
                 Self::VarDecl(v) => v.set_index(index),
                 Self::VarDeclKind(v) => v.set_index(index),
                 Self::VarDeclOrExpr(v) => v.set_index(index),
@@ -95366,6 +105131,7 @@ pub mod fields {
     #[derive(Debug, Clone, Copy)]
     pub enum AstParentNodeRef<'ast> {
         ArrayLit(&'ast ArrayLit, ArrayLitField),
+        ArrayMatchPat(&'ast ArrayMatchPat, ArrayMatchPatField),
         ArrayPat(&'ast ArrayPat, ArrayPatField),
         ArrowExpr(&'ast ArrowExpr, ArrowExprField),
         AssignExpr(&'ast AssignExpr, AssignExprField),
@@ -95381,6 +105147,7 @@ pub mod fields {
         BinExpr(&'ast BinExpr, BinExprField),
         BinaryOp(&'ast BinaryOp, BinaryOpField),
         BindingIdent(&'ast BindingIdent, BindingIdentField),
+        BindingMatchPat(&'ast BindingMatchPat, BindingMatchPatField),
         BlockStmt(&'ast BlockStmt, BlockStmtField),
         BlockStmtOrExpr(&'ast BlockStmtOrExpr, BlockStmtOrExprField),
         Bool(&'ast Bool, BoolField),
@@ -95394,6 +105161,7 @@ pub mod fields {
         ClassMember(&'ast ClassMember, ClassMemberField),
         ClassMethod(&'ast ClassMethod, ClassMethodField),
         ClassProp(&'ast ClassProp, ClassPropField),
+        CombinedMatchPat(&'ast CombinedMatchPat, CombinedMatchPatField),
         ComputedPropName(&'ast ComputedPropName, ComputedPropNameField),
         CondExpr(&'ast CondExpr, CondExprField),
         Constructor(&'ast Constructor, ConstructorField),
@@ -95402,6 +105170,7 @@ pub mod fields {
         Decl(&'ast Decl, DeclField),
         Decorator(&'ast Decorator, DecoratorField),
         DefaultDecl(&'ast DefaultDecl, DefaultDeclField),
+        DoExpr(&'ast DoExpr, DoExprField),
         DoWhileStmt(&'ast DoWhileStmt, DoWhileStmtField),
         EmptyStmt(&'ast EmptyStmt, EmptyStmtField),
         ExportAll(&'ast ExportAll, ExportAllField),
@@ -95464,6 +105233,11 @@ pub mod fields {
         KeyValueProp(&'ast KeyValueProp, KeyValuePropField),
         LabeledStmt(&'ast LabeledStmt, LabeledStmtField),
         Lit(&'ast Lit, LitField),
+        MatchCase(&'ast MatchCase, MatchCaseField),
+        MatchExpr(&'ast MatchExpr, MatchExprField),
+        MatchPat(&'ast MatchPat, MatchPatField),
+        MatchPatCombinator(&'ast MatchPatCombinator, MatchPatCombinatorField),
+        MatchRest(&'ast MatchRest, MatchRestField),
         MemberExpr(&'ast MemberExpr, MemberExprField),
         MemberProp(&'ast MemberProp, MemberPropField),
         MetaPropExpr(&'ast MetaPropExpr, MetaPropExprField),
@@ -95476,9 +105250,12 @@ pub mod fields {
         ModuleItem(&'ast ModuleItem, ModuleItemField),
         NamedExport(&'ast NamedExport, NamedExportField),
         NewExpr(&'ast NewExpr, NewExprField),
+        NotMatchPat(&'ast NotMatchPat, NotMatchPatField),
         Null(&'ast Null, NullField),
         Number(&'ast Number, NumberField),
         ObjectLit(&'ast ObjectLit, ObjectLitField),
+        ObjectMatchPat(&'ast ObjectMatchPat, ObjectMatchPatField),
+        ObjectMatchPatProp(&'ast ObjectMatchPatProp, ObjectMatchPatPropField),
         ObjectPat(&'ast ObjectPat, ObjectPatField),
         ObjectPatProp(&'ast ObjectPatProp, ObjectPatPropField),
         OptCall(&'ast OptCall, OptCallField),
@@ -95488,6 +105265,7 @@ pub mod fields {
         ParamOrTsParamProp(&'ast ParamOrTsParamProp, ParamOrTsParamPropField),
         ParenExpr(&'ast ParenExpr, ParenExprField),
         Pat(&'ast Pat, PatField),
+        PipelineExpr(&'ast PipelineExpr, PipelineExprField),
         PrivateMethod(&'ast PrivateMethod, PrivateMethodField),
         PrivateName(&'ast PrivateName, PrivateNameField),
         PrivateProp(&'ast PrivateProp, PrivatePropField),
@@ -95496,6 +105274,7 @@ pub mod fields {
         PropName(&'ast PropName, PropNameField),
         PropOrSpread(&'ast PropOrSpread, PropOrSpreadField),
         Regex(&'ast Regex, RegexField),
+        RelationalMatchPat(&'ast RelationalMatchPat, RelationalMatchPatField),
         RestPat(&'ast RestPat, RestPatField),
         ReturnStmt(&'ast ReturnStmt, ReturnStmtField),
         Script(&'ast Script, ScriptField),
@@ -95514,6 +105293,7 @@ pub mod fields {
         TaggedTpl(&'ast TaggedTpl, TaggedTplField),
         ThisExpr(&'ast ThisExpr, ThisExprField),
         ThrowStmt(&'ast ThrowStmt, ThrowStmtField),
+        TopicExpr(&'ast TopicExpr, TopicExprField),
         Tpl(&'ast Tpl, TplField),
         TplElement(&'ast TplElement, TplElementField),
         TruePlusMinus(&'ast TruePlusMinus, TruePlusMinusField),
@@ -95523,6 +105303,7 @@ pub mod fields {
         UpdateExpr(&'ast UpdateExpr, UpdateExprField),
         UpdateOp(&'ast UpdateOp, UpdateOpField),
         UsingDecl(&'ast UsingDecl, UsingDeclField),
+        ValueMatchPat(&'ast ValueMatchPat, ValueMatchPatField),
         VarDecl(&'ast VarDecl, VarDeclField),
         VarDeclKind(&'ast VarDeclKind, VarDeclKindField),
         VarDeclOrExpr(&'ast VarDeclOrExpr, VarDeclOrExprField),
@@ -95542,6 +105323,7 @@ pub mod fields {
         fn set_index(&mut self, index: usize) {
             match self {
                 Self::ArrayLit(_, __field_kind) => __field_kind.set_index(index),
+                Self::ArrayMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ArrayPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ArrowExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::AssignExpr(_, __field_kind) => __field_kind.set_index(index),
@@ -95557,6 +105339,7 @@ pub mod fields {
                 Self::BinExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::BinaryOp(_, __field_kind) => __field_kind.set_index(index),
                 Self::BindingIdent(_, __field_kind) => __field_kind.set_index(index),
+                Self::BindingMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::BlockStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::BlockStmtOrExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Bool(_, __field_kind) => __field_kind.set_index(index),
@@ -95570,6 +105353,7 @@ pub mod fields {
                 Self::ClassMember(_, __field_kind) => __field_kind.set_index(index),
                 Self::ClassMethod(_, __field_kind) => __field_kind.set_index(index),
                 Self::ClassProp(_, __field_kind) => __field_kind.set_index(index),
+                Self::CombinedMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ComputedPropName(_, __field_kind) => __field_kind.set_index(index),
                 Self::CondExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Constructor(_, __field_kind) => __field_kind.set_index(index),
@@ -95578,6 +105362,7 @@ pub mod fields {
                 Self::Decl(_, __field_kind) => __field_kind.set_index(index),
                 Self::Decorator(_, __field_kind) => __field_kind.set_index(index),
                 Self::DefaultDecl(_, __field_kind) => __field_kind.set_index(index),
+                Self::DoExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::DoWhileStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::EmptyStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::ExportAll(_, __field_kind) => __field_kind.set_index(index),
@@ -95637,6 +105422,11 @@ pub mod fields {
                 Self::KeyValueProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::LabeledStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::Lit(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchCase(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchExpr(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchPat(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchPatCombinator(_, __field_kind) => __field_kind.set_index(index),
+                Self::MatchRest(_, __field_kind) => __field_kind.set_index(index),
                 Self::MemberExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::MemberProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::MetaPropExpr(_, __field_kind) => __field_kind.set_index(index),
@@ -95649,9 +105439,12 @@ pub mod fields {
                 Self::ModuleItem(_, __field_kind) => __field_kind.set_index(index),
                 Self::NamedExport(_, __field_kind) => __field_kind.set_index(index),
                 Self::NewExpr(_, __field_kind) => __field_kind.set_index(index),
+                Self::NotMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::Null(_, __field_kind) => __field_kind.set_index(index),
                 Self::Number(_, __field_kind) => __field_kind.set_index(index),
                 Self::ObjectLit(_, __field_kind) => __field_kind.set_index(index),
+                Self::ObjectMatchPat(_, __field_kind) => __field_kind.set_index(index),
+                Self::ObjectMatchPatProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::ObjectPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ObjectPatProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::OptCall(_, __field_kind) => __field_kind.set_index(index),
@@ -95661,6 +105454,7 @@ pub mod fields {
                 Self::ParamOrTsParamProp(_, __field_kind) => __field_kind.set_index(index),
                 Self::ParenExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Pat(_, __field_kind) => __field_kind.set_index(index),
+                Self::PipelineExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateMethod(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateName(_, __field_kind) => __field_kind.set_index(index),
                 Self::PrivateProp(_, __field_kind) => __field_kind.set_index(index),
@@ -95669,6 +105463,7 @@ pub mod fields {
                 Self::PropName(_, __field_kind) => __field_kind.set_index(index),
                 Self::PropOrSpread(_, __field_kind) => __field_kind.set_index(index),
                 Self::Regex(_, __field_kind) => __field_kind.set_index(index),
+                Self::RelationalMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::RestPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::ReturnStmt(_, __field_kind) => __field_kind.set_index(index),
                 Self::Script(_, __field_kind) => __field_kind.set_index(index),
@@ -95687,6 +105482,7 @@ pub mod fields {
                 Self::TaggedTpl(_, __field_kind) => __field_kind.set_index(index),
                 Self::ThisExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::ThrowStmt(_, __field_kind) => __field_kind.set_index(index),
+                Self::TopicExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::Tpl(_, __field_kind) => __field_kind.set_index(index),
                 Self::TplElement(_, __field_kind) => __field_kind.set_index(index),
                 Self::TruePlusMinus(_, __field_kind) => __field_kind.set_index(index),
@@ -95696,6 +105492,7 @@ pub mod fields {
                 Self::UpdateExpr(_, __field_kind) => __field_kind.set_index(index),
                 Self::UpdateOp(_, __field_kind) => __field_kind.set_index(index),
                 Self::UsingDecl(_, __field_kind) => __field_kind.set_index(index),
+                Self::ValueMatchPat(_, __field_kind) => __field_kind.set_index(index),
                 Self::VarDecl(_, __field_kind) => __field_kind.set_index(index),
                 Self::VarDeclKind(_, __field_kind) => __field_kind.set_index(index),
                 Self::VarDeclOrExpr(_, __field_kind) => __field_kind.set_index(index),
@@ -95712,6 +105509,7 @@ pub mod fields {
         pub fn kind(&self) -> AstParentKind {
             match self {
                 Self::ArrayLit(_, __field_kind) => AstParentKind::ArrayLit(*__field_kind),
+                Self::ArrayMatchPat(_, __field_kind) => AstParentKind::ArrayMatchPat(*__field_kind),
                 Self::ArrayPat(_, __field_kind) => AstParentKind::ArrayPat(*__field_kind),
                 Self::ArrowExpr(_, __field_kind) => AstParentKind::ArrowExpr(*__field_kind),
                 Self::AssignExpr(_, __field_kind) => AstParentKind::AssignExpr(*__field_kind),
@@ -95729,6 +105527,9 @@ pub mod fields {
                 Self::BinExpr(_, __field_kind) => AstParentKind::BinExpr(*__field_kind),
                 Self::BinaryOp(_, __field_kind) => AstParentKind::BinaryOp(*__field_kind),
                 Self::BindingIdent(_, __field_kind) => AstParentKind::BindingIdent(*__field_kind),
+                Self::BindingMatchPat(_, __field_kind) => {
+                    AstParentKind::BindingMatchPat(*__field_kind)
+                }
                 Self::BlockStmt(_, __field_kind) => AstParentKind::BlockStmt(*__field_kind),
                 Self::BlockStmtOrExpr(_, __field_kind) => {
                     AstParentKind::BlockStmtOrExpr(*__field_kind)
@@ -95744,6 +105545,9 @@ pub mod fields {
                 Self::ClassMember(_, __field_kind) => AstParentKind::ClassMember(*__field_kind),
                 Self::ClassMethod(_, __field_kind) => AstParentKind::ClassMethod(*__field_kind),
                 Self::ClassProp(_, __field_kind) => AstParentKind::ClassProp(*__field_kind),
+                Self::CombinedMatchPat(_, __field_kind) => {
+                    AstParentKind::CombinedMatchPat(*__field_kind)
+                }
                 Self::ComputedPropName(_, __field_kind) => {
                     AstParentKind::ComputedPropName(*__field_kind)
                 }
@@ -95754,6 +105558,7 @@ pub mod fields {
                 Self::Decl(_, __field_kind) => AstParentKind::Decl(*__field_kind),
                 Self::Decorator(_, __field_kind) => AstParentKind::Decorator(*__field_kind),
                 Self::DefaultDecl(_, __field_kind) => AstParentKind::DefaultDecl(*__field_kind),
+                Self::DoExpr(_, __field_kind) => AstParentKind::DoExpr(*__field_kind),
                 Self::DoWhileStmt(_, __field_kind) => AstParentKind::DoWhileStmt(*__field_kind),
                 Self::EmptyStmt(_, __field_kind) => AstParentKind::EmptyStmt(*__field_kind),
                 Self::ExportAll(_, __field_kind) => AstParentKind::ExportAll(*__field_kind),
@@ -95857,6 +105662,13 @@ pub mod fields {
                 Self::KeyValueProp(_, __field_kind) => AstParentKind::KeyValueProp(*__field_kind),
                 Self::LabeledStmt(_, __field_kind) => AstParentKind::LabeledStmt(*__field_kind),
                 Self::Lit(_, __field_kind) => AstParentKind::Lit(*__field_kind),
+                Self::MatchCase(_, __field_kind) => AstParentKind::MatchCase(*__field_kind),
+                Self::MatchExpr(_, __field_kind) => AstParentKind::MatchExpr(*__field_kind),
+                Self::MatchPat(_, __field_kind) => AstParentKind::MatchPat(*__field_kind),
+                Self::MatchPatCombinator(_, __field_kind) => {
+                    AstParentKind::MatchPatCombinator(*__field_kind)
+                }
+                Self::MatchRest(_, __field_kind) => AstParentKind::MatchRest(*__field_kind),
                 Self::MemberExpr(_, __field_kind) => AstParentKind::MemberExpr(*__field_kind),
                 Self::MemberProp(_, __field_kind) => AstParentKind::MemberProp(*__field_kind),
                 Self::MetaPropExpr(_, __field_kind) => AstParentKind::MetaPropExpr(*__field_kind),
@@ -95871,9 +105683,16 @@ pub mod fields {
                 Self::ModuleItem(_, __field_kind) => AstParentKind::ModuleItem(*__field_kind),
                 Self::NamedExport(_, __field_kind) => AstParentKind::NamedExport(*__field_kind),
                 Self::NewExpr(_, __field_kind) => AstParentKind::NewExpr(*__field_kind),
+                Self::NotMatchPat(_, __field_kind) => AstParentKind::NotMatchPat(*__field_kind),
                 Self::Null(_, __field_kind) => AstParentKind::Null(*__field_kind),
                 Self::Number(_, __field_kind) => AstParentKind::Number(*__field_kind),
                 Self::ObjectLit(_, __field_kind) => AstParentKind::ObjectLit(*__field_kind),
+                Self::ObjectMatchPat(_, __field_kind) => {
+                    AstParentKind::ObjectMatchPat(*__field_kind)
+                }
+                Self::ObjectMatchPatProp(_, __field_kind) => {
+                    AstParentKind::ObjectMatchPatProp(*__field_kind)
+                }
                 Self::ObjectPat(_, __field_kind) => AstParentKind::ObjectPat(*__field_kind),
                 Self::ObjectPatProp(_, __field_kind) => AstParentKind::ObjectPatProp(*__field_kind),
                 Self::OptCall(_, __field_kind) => AstParentKind::OptCall(*__field_kind),
@@ -95885,6 +105704,7 @@ pub mod fields {
                 }
                 Self::ParenExpr(_, __field_kind) => AstParentKind::ParenExpr(*__field_kind),
                 Self::Pat(_, __field_kind) => AstParentKind::Pat(*__field_kind),
+                Self::PipelineExpr(_, __field_kind) => AstParentKind::PipelineExpr(*__field_kind),
                 Self::PrivateMethod(_, __field_kind) => AstParentKind::PrivateMethod(*__field_kind),
                 Self::PrivateName(_, __field_kind) => AstParentKind::PrivateName(*__field_kind),
                 Self::PrivateProp(_, __field_kind) => AstParentKind::PrivateProp(*__field_kind),
@@ -95893,6 +105713,9 @@ pub mod fields {
                 Self::PropName(_, __field_kind) => AstParentKind::PropName(*__field_kind),
                 Self::PropOrSpread(_, __field_kind) => AstParentKind::PropOrSpread(*__field_kind),
                 Self::Regex(_, __field_kind) => AstParentKind::Regex(*__field_kind),
+                Self::RelationalMatchPat(_, __field_kind) => {
+                    AstParentKind::RelationalMatchPat(*__field_kind)
+                }
                 Self::RestPat(_, __field_kind) => AstParentKind::RestPat(*__field_kind),
                 Self::ReturnStmt(_, __field_kind) => AstParentKind::ReturnStmt(*__field_kind),
                 Self::Script(_, __field_kind) => AstParentKind::Script(*__field_kind),
@@ -95913,6 +105736,7 @@ pub mod fields {
                 Self::TaggedTpl(_, __field_kind) => AstParentKind::TaggedTpl(*__field_kind),
                 Self::ThisExpr(_, __field_kind) => AstParentKind::ThisExpr(*__field_kind),
                 Self::ThrowStmt(_, __field_kind) => AstParentKind::ThrowStmt(*__field_kind),
+                Self::TopicExpr(_, __field_kind) => AstParentKind::TopicExpr(*__field_kind),
                 Self::Tpl(_, __field_kind) => AstParentKind::Tpl(*__field_kind),
                 Self::TplElement(_, __field_kind) => AstParentKind::TplElement(*__field_kind),
                 Self::TruePlusMinus(_, __field_kind) => AstParentKind::TruePlusMinus(*__field_kind),
@@ -95922,6 +105746,7 @@ pub mod fields {
                 Self::UpdateExpr(_, __field_kind) => AstParentKind::UpdateExpr(*__field_kind),
                 Self::UpdateOp(_, __field_kind) => AstParentKind::UpdateOp(*__field_kind),
                 Self::UsingDecl(_, __field_kind) => AstParentKind::UsingDecl(*__field_kind),
+                Self::ValueMatchPat(_, __field_kind) => AstParentKind::ValueMatchPat(*__field_kind),
                 Self::VarDecl(_, __field_kind) => AstParentKind::VarDecl(*__field_kind),
                 Self::VarDeclKind(_, __field_kind) => AstParentKind::VarDeclKind(*__field_kind),
                 Self::VarDeclOrExpr(_, __field_kind) => AstParentKind::VarDeclOrExpr(*__field_kind),
@@ -95938,6 +105763,11 @@ impl<'ast> From<&'ast ArrayLit> for NodeRef<'ast> {
         NodeRef::ArrayLit(node)
     }
 }
+impl<'ast> From<&'ast ArrayMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast ArrayMatchPat) -> Self {
+        NodeRef::ArrayMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast ArrayPat> for NodeRef<'ast> {
     fn from(node: &'ast ArrayPat) -> Self {
         NodeRef::ArrayPat(node)
@@ -96013,6 +105843,11 @@ impl<'ast> From<&'ast BindingIdent> for NodeRef<'ast> {
         NodeRef::BindingIdent(node)
     }
 }
+impl<'ast> From<&'ast BindingMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast BindingMatchPat) -> Self {
+        NodeRef::BindingMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast BlockStmt> for NodeRef<'ast> {
     fn from(node: &'ast BlockStmt) -> Self {
         NodeRef::BlockStmt(node)
@@ -96078,6 +105913,11 @@ impl<'ast> From<&'ast ClassProp> for NodeRef<'ast> {
         NodeRef::ClassProp(node)
     }
 }
+impl<'ast> From<&'ast CombinedMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast CombinedMatchPat) -> Self {
+        NodeRef::CombinedMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast ComputedPropName> for NodeRef<'ast> {
     fn from(node: &'ast ComputedPropName) -> Self {
         NodeRef::ComputedPropName(node)
@@ -96118,6 +105958,11 @@ impl<'ast> From<&'ast DefaultDecl> for NodeRef<'ast> {
         NodeRef::DefaultDecl(node)
     }
 }
+impl<'ast> From<&'ast DoExpr> for NodeRef<'ast> {
+    fn from(node: &'ast DoExpr) -> Self {
+        NodeRef::DoExpr(node)
+    }
+}
 impl<'ast> From<&'ast DoWhileStmt> for NodeRef<'ast> {
     fn from(node: &'ast DoWhileStmt) -> Self {
         NodeRef::DoWhileStmt(node)
@@ -96413,6 +106258,31 @@ impl<'ast> From<&'ast Lit> for NodeRef<'ast> {
         NodeRef::Lit(node)
     }
 }
+impl<'ast> From<&'ast MatchCase> for NodeRef<'ast> {
+    fn from(node: &'ast MatchCase) -> Self {
+        NodeRef::MatchCase(node)
+    }
+}
+impl<'ast> From<&'ast MatchExpr> for NodeRef<'ast> {
+    fn from(node: &'ast MatchExpr) -> Self {
+        NodeRef::MatchExpr(node)
+    }
+}
+impl<'ast> From<&'ast MatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast MatchPat) -> Self {
+        NodeRef::MatchPat(node)
+    }
+}
+impl<'ast> From<&'ast MatchPatCombinator> for NodeRef<'ast> {
+    fn from(node: &'ast MatchPatCombinator) -> Self {
+        NodeRef::MatchPatCombinator(node)
+    }
+}
+impl<'ast> From<&'ast MatchRest> for NodeRef<'ast> {
+    fn from(node: &'ast MatchRest) -> Self {
+        NodeRef::MatchRest(node)
+    }
+}
 impl<'ast> From<&'ast MemberExpr> for NodeRef<'ast> {
     fn from(node: &'ast MemberExpr) -> Self {
         NodeRef::MemberExpr(node)
@@ -96473,6 +106343,11 @@ impl<'ast> From<&'ast NewExpr> for NodeRef<'ast> {
         NodeRef::NewExpr(node)
     }
 }
+impl<'ast> From<&'ast NotMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast NotMatchPat) -> Self {
+        NodeRef::NotMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast Null> for NodeRef<'ast> {
     fn from(node: &'ast Null) -> Self {
         NodeRef::Null(node)
@@ -96488,6 +106363,16 @@ impl<'ast> From<&'ast ObjectLit> for NodeRef<'ast> {
         NodeRef::ObjectLit(node)
     }
 }
+impl<'ast> From<&'ast ObjectMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast ObjectMatchPat) -> Self {
+        NodeRef::ObjectMatchPat(node)
+    }
+}
+impl<'ast> From<&'ast ObjectMatchPatProp> for NodeRef<'ast> {
+    fn from(node: &'ast ObjectMatchPatProp) -> Self {
+        NodeRef::ObjectMatchPatProp(node)
+    }
+}
 impl<'ast> From<&'ast ObjectPat> for NodeRef<'ast> {
     fn from(node: &'ast ObjectPat) -> Self {
         NodeRef::ObjectPat(node)
@@ -96533,6 +106418,11 @@ impl<'ast> From<&'ast Pat> for NodeRef<'ast> {
         NodeRef::Pat(node)
     }
 }
+impl<'ast> From<&'ast PipelineExpr> for NodeRef<'ast> {
+    fn from(node: &'ast PipelineExpr) -> Self {
+        NodeRef::PipelineExpr(node)
+    }
+}
 impl<'ast> From<&'ast PrivateMethod> for NodeRef<'ast> {
     fn from(node: &'ast PrivateMethod) -> Self {
         NodeRef::PrivateMethod(node)
@@ -96573,6 +106463,11 @@ impl<'ast> From<&'ast Regex> for NodeRef<'ast> {
         NodeRef::Regex(node)
     }
 }
+impl<'ast> From<&'ast RelationalMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast RelationalMatchPat) -> Self {
+        NodeRef::RelationalMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast RestPat> for NodeRef<'ast> {
     fn from(node: &'ast RestPat) -> Self {
         NodeRef::RestPat(node)
@@ -96663,6 +106558,11 @@ impl<'ast> From<&'ast ThrowStmt> for NodeRef<'ast> {
         NodeRef::ThrowStmt(node)
     }
 }
+impl<'ast> From<&'ast TopicExpr> for NodeRef<'ast> {
+    fn from(node: &'ast TopicExpr) -> Self {
+        NodeRef::TopicExpr(node)
+    }
+}
 impl<'ast> From<&'ast Tpl> for NodeRef<'ast> {
     fn from(node: &'ast Tpl) -> Self {
         NodeRef::Tpl(node)
@@ -96708,6 +106608,11 @@ impl<'ast> From<&'ast UsingDecl> for NodeRef<'ast> {
         NodeRef::UsingDecl(node)
     }
 }
+impl<'ast> From<&'ast ValueMatchPat> for NodeRef<'ast> {
+    fn from(node: &'ast ValueMatchPat) -> Self {
+        NodeRef::ValueMatchPat(node)
+    }
+}
 impl<'ast> From<&'ast VarDecl> for NodeRef<'ast> {
     fn from(node: &'ast VarDecl) -> Self {
         NodeRef::VarDecl(node)
@@ -96746,6 +106651,7 @@ impl<'ast> From<&'ast YieldExpr> for NodeRef<'ast> {
 #[derive(Debug, Clone, Copy)]
 pub enum NodeRef<'ast> {
     ArrayLit(&'ast ArrayLit),
+    ArrayMatchPat(&'ast ArrayMatchPat),
     ArrayPat(&'ast ArrayPat),
     ArrowExpr(&'ast ArrowExpr),
     AssignExpr(&'ast AssignExpr),
@@ -96761,6 +106667,7 @@ pub enum NodeRef<'ast> {
     BinExpr(&'ast BinExpr),
     BinaryOp(&'ast BinaryOp),
     BindingIdent(&'ast BindingIdent),
+    BindingMatchPat(&'ast BindingMatchPat),
     BlockStmt(&'ast BlockStmt),
     BlockStmtOrExpr(&'ast BlockStmtOrExpr),
     Bool(&'ast Bool),
@@ -96774,6 +106681,7 @@ pub enum NodeRef<'ast> {
     ClassMember(&'ast ClassMember),
     ClassMethod(&'ast ClassMethod),
     ClassProp(&'ast ClassProp),
+    CombinedMatchPat(&'ast CombinedMatchPat),
     ComputedPropName(&'ast ComputedPropName),
     CondExpr(&'ast CondExpr),
     Constructor(&'ast Constructor),
@@ -96782,6 +106690,7 @@ pub enum NodeRef<'ast> {
     Decl(&'ast Decl),
     Decorator(&'ast Decorator),
     DefaultDecl(&'ast DefaultDecl),
+    DoExpr(&'ast DoExpr),
     DoWhileStmt(&'ast DoWhileStmt),
     EmptyStmt(&'ast EmptyStmt),
     ExportAll(&'ast ExportAll),
@@ -96841,6 +106750,11 @@ pub enum NodeRef<'ast> {
     KeyValueProp(&'ast KeyValueProp),
     LabeledStmt(&'ast LabeledStmt),
     Lit(&'ast Lit),
+    MatchCase(&'ast MatchCase),
+    MatchExpr(&'ast MatchExpr),
+    MatchPat(&'ast MatchPat),
+    MatchPatCombinator(&'ast MatchPatCombinator),
+    MatchRest(&'ast MatchRest),
     MemberExpr(&'ast MemberExpr),
     MemberProp(&'ast MemberProp),
     MetaPropExpr(&'ast MetaPropExpr),
@@ -96853,9 +106767,12 @@ pub enum NodeRef<'ast> {
     ModuleItem(&'ast ModuleItem),
     NamedExport(&'ast NamedExport),
     NewExpr(&'ast NewExpr),
+    NotMatchPat(&'ast NotMatchPat),
     Null(&'ast Null),
     Number(&'ast Number),
     ObjectLit(&'ast ObjectLit),
+    ObjectMatchPat(&'ast ObjectMatchPat),
+    ObjectMatchPatProp(&'ast ObjectMatchPatProp),
     ObjectPat(&'ast ObjectPat),
     ObjectPatProp(&'ast ObjectPatProp),
     OptCall(&'ast OptCall),
@@ -96865,6 +106782,7 @@ pub enum NodeRef<'ast> {
     ParamOrTsParamProp(&'ast ParamOrTsParamProp),
     ParenExpr(&'ast ParenExpr),
     Pat(&'ast Pat),
+    PipelineExpr(&'ast PipelineExpr),
     PrivateMethod(&'ast PrivateMethod),
     PrivateName(&'ast PrivateName),
     PrivateProp(&'ast PrivateProp),
@@ -96873,6 +106791,7 @@ pub enum NodeRef<'ast> {
     PropName(&'ast PropName),
     PropOrSpread(&'ast PropOrSpread),
     Regex(&'ast Regex),
+    RelationalMatchPat(&'ast RelationalMatchPat),
     RestPat(&'ast RestPat),
     ReturnStmt(&'ast ReturnStmt),
     Script(&'ast Script),
@@ -96891,6 +106810,7 @@ pub enum NodeRef<'ast> {
     TaggedTpl(&'ast TaggedTpl),
     ThisExpr(&'ast ThisExpr),
     ThrowStmt(&'ast ThrowStmt),
+    TopicExpr(&'ast TopicExpr),
     Tpl(&'ast Tpl),
     TplElement(&'ast TplElement),
     TruePlusMinus(&'ast TruePlusMinus),
@@ -96900,6 +106820,7 @@ pub enum NodeRef<'ast> {
     UpdateExpr(&'ast UpdateExpr),
     UpdateOp(&'ast UpdateOp),
     UsingDecl(&'ast UsingDecl),
+    ValueMatchPat(&'ast ValueMatchPat),
     VarDecl(&'ast VarDecl),
     VarDeclKind(&'ast VarDeclKind),
     VarDeclOrExpr(&'ast VarDeclOrExpr),
@@ -96922,6 +106843,20 @@ impl<'ast> NodeRef<'ast> {
                 );
                 Box::new(iterator)
             }
+            NodeRef::ArrayMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(
+                        node.elems
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchPat(&item))),
+                    )
+                    .chain(
+                        node.rest
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchRest(&item))),
+                    );
+                Box::new(iterator)
+            }
             NodeRef::ArrayPat(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain(
                     node.elems.iter().flat_map(|item| {
@@ -97046,6 +106981,12 @@ impl<'ast> NodeRef<'ast> {
                     .chain(::std::iter::once(NodeRef::Ident(&node.id)));
                 Box::new(iterator)
             }
+            NodeRef::BindingMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::VarDeclKind(&node.kind)))
+                    .chain(::std::iter::once(NodeRef::BindingIdent(&node.id)));
+                Box::new(iterator)
+            }
             NodeRef::BlockStmt(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain(
                     node.stmts
@@ -97184,6 +107125,16 @@ impl<'ast> NodeRef<'ast> {
                     );
                 Box::new(iterator)
             }
+            NodeRef::CombinedMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::MatchPatCombinator(&node.op)))
+                    .chain(
+                        node.pats
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchPat(&item))),
+                    );
+                Box::new(iterator)
+            }
             NodeRef::ComputedPropName(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
                     let item = &*node.expr;
@@ -97253,6 +107204,11 @@ impl<'ast> NodeRef<'ast> {
                 DefaultDecl::Fn(v0) => Box::new(::std::iter::once(NodeRef::FnExpr(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::DoExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::BlockStmt(&node.body)));
+                Box::new(iterator)
+            }
             NodeRef::DoWhileStmt(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain({
@@ -97364,6 +107320,10 @@ impl<'ast> NodeRef<'ast> {
                 Expr::JSXFragment(v0) => Box::new(::std::iter::once(NodeRef::JSXFragment(v0))),
                 Expr::PrivateName(v0) => Box::new(::std::iter::once(NodeRef::PrivateName(v0))),
                 Expr::OptChain(v0) => Box::new(::std::iter::once(NodeRef::OptChainExpr(v0))),
+                Expr::Pipeline(v0) => Box::new(::std::iter::once(NodeRef::PipelineExpr(v0))),
+                Expr::Topic(v0) => Box::new(::std::iter::once(NodeRef::TopicExpr(v0))),
+                Expr::Match(v0) => Box::new(::std::iter::once(NodeRef::MatchExpr(v0))),
+                Expr::Do(v0) => Box::new(::std::iter::once(NodeRef::DoExpr(v0))),
                 Expr::Invalid(v0) => Box::new(::std::iter::once(NodeRef::Invalid(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
@@ -97787,6 +107747,60 @@ impl<'ast> NodeRef<'ast> {
                 Lit::JSXText(v0) => Box::new(::std::iter::once(NodeRef::JSXText(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::MatchCase(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(node.pat.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::MatchPat(&item))
+                    }))
+                    .chain(node.guard.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    }))
+                    .chain({
+                        let item = &*node.body;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
+            NodeRef::MatchExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain({
+                        let item = &*node.subject;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    })
+                    .chain(
+                        node.cases
+                            .iter()
+                            .flat_map(|item| ::std::iter::once(NodeRef::MatchCase(&item))),
+                    );
+                Box::new(iterator)
+            }
+            NodeRef::MatchPat(node) => match node {
+                MatchPat::Binding(v0) => Box::new(::std::iter::once(NodeRef::BindingMatchPat(v0))),
+                MatchPat::Value(v0) => Box::new(::std::iter::once(NodeRef::ValueMatchPat(v0))),
+                MatchPat::Relational(v0) => {
+                    Box::new(::std::iter::once(NodeRef::RelationalMatchPat(v0)))
+                }
+                MatchPat::Array(v0) => Box::new(::std::iter::once(NodeRef::ArrayMatchPat(v0))),
+                MatchPat::Object(v0) => Box::new(::std::iter::once(NodeRef::ObjectMatchPat(v0))),
+                MatchPat::Combined(v0) => {
+                    Box::new(::std::iter::once(NodeRef::CombinedMatchPat(v0)))
+                }
+                MatchPat::Not(v0) => Box::new(::std::iter::once(NodeRef::NotMatchPat(v0))),
+                _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
+            },
+            NodeRef::MatchPatCombinator(node) => match node {
+                _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
+            },
+            NodeRef::MatchRest(node) => {
+                let iterator =
+                    ::std::iter::empty::<NodeRef<'ast>>().chain(node.arg.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::MatchPat(&item))
+                    }));
+                Box::new(iterator)
+            }
             NodeRef::MemberExpr(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain({
@@ -97888,6 +107902,13 @@ impl<'ast> NodeRef<'ast> {
                     }));
                 Box::new(iterator)
             }
+            NodeRef::NotMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
+                    let item = &*node.arg;
+                    ::std::iter::once(NodeRef::MatchPat(&item))
+                });
+                Box::new(iterator)
+            }
             NodeRef::Null(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>();
                 Box::new(iterator)
@@ -97904,6 +107925,23 @@ impl<'ast> NodeRef<'ast> {
                 );
                 Box::new(iterator)
             }
+            NodeRef::ObjectMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain(
+                    node.props
+                        .iter()
+                        .flat_map(|item| ::std::iter::once(NodeRef::ObjectMatchPatProp(&item))),
+                );
+                Box::new(iterator)
+            }
+            NodeRef::ObjectMatchPatProp(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::PropName(&node.key)))
+                    .chain(node.value.iter().flat_map(|item| {
+                        let item = &*item;
+                        ::std::iter::once(NodeRef::MatchPat(&item))
+                    }));
+                Box::new(iterator)
+            }
             NodeRef::ObjectPat(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain(
                     node.props
@@ -97978,6 +108016,18 @@ impl<'ast> NodeRef<'ast> {
                 Pat::Expr(v0) => Box::new(::std::iter::once(NodeRef::Expr(v0))),
                 _ => Box::new(::std::iter::empty::<NodeRef<'ast>>()),
             },
+            NodeRef::PipelineExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain({
+                        let item = &*node.left;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    })
+                    .chain({
+                        let item = &*node.right;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
             NodeRef::PrivateMethod(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(::std::iter::once(NodeRef::PrivateName(&node.key)))
@@ -98039,6 +108089,15 @@ impl<'ast> NodeRef<'ast> {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>();
                 Box::new(iterator)
             }
+            NodeRef::RelationalMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>()
+                    .chain(::std::iter::once(NodeRef::BinaryOp(&node.op)))
+                    .chain({
+                        let item = &*node.value;
+                        ::std::iter::once(NodeRef::Expr(&item))
+                    });
+                Box::new(iterator)
+            }
             NodeRef::RestPat(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
                     let item = &*node.arg;
@@ -98215,6 +108274,10 @@ impl<'ast> NodeRef<'ast> {
                 });
                 Box::new(iterator)
             }
+            NodeRef::TopicExpr(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>();
+                Box::new(iterator)
+            }
             NodeRef::Tpl(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(node.exprs.iter().flat_map(|item| {
@@ -98282,6 +108345,13 @@ impl<'ast> NodeRef<'ast> {
                 );
                 Box::new(iterator)
             }
+            NodeRef::ValueMatchPat(node) => {
+                let iterator = ::std::iter::empty::<NodeRef<'ast>>().chain({
+                    let item = &*node.value;
+                    ::std::iter::once(NodeRef::Expr(&item))
+                });
+                Box::new(iterator)
+            }
             NodeRef::VarDecl(node) => {
                 let iterator = ::std::iter::empty::<NodeRef<'ast>>()
                     .chain(::std::iter::once(NodeRef::VarDeclKind(&node.kind)))
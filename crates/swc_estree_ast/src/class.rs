@@ -76,6 +76,8 @@ pub struct ClassMethod {
     pub decorators: Option<Vec<Decorator>>,
     #[serde(default)]
     pub optional: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
     #[serde(default)]
     pub return_type: Option<Box<TypeAnnotOrNoop>>,
     #[serde(default)]
@@ -127,6 +129,8 @@ struct BabelClassMethod<'a> {
     pub decorators: Option<&'a [Decorator]>,
     #[serde(default)]
     pub optional: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
     #[serde(default)]
     pub return_type: Option<&'a TypeAnnotOrNoop>,
     #[serde(default)]
@@ -173,6 +177,7 @@ impl Serialize for ClassMethod {
                     accessibility: self.accessibility.as_ref(),
                     decorators: self.decorators.as_deref(),
                     optional: self.optional,
+                    is_override: self.is_override,
                     return_type: self.return_type.as_deref(),
                     type_parameters: self.type_parameters.as_ref(),
                 };
@@ -288,6 +293,8 @@ pub struct ClassProperty {
     pub optional: Option<bool>,
     #[serde(default)]
     pub readonly: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
 }
 #[derive(Serialize)]
 struct BabelClassProperty<'a> {
@@ -318,6 +325,8 @@ struct BabelClassProperty<'a> {
     pub optional: Option<bool>,
     #[serde(default)]
     pub readonly: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
 }
 
 impl Serialize for ClassProperty {
@@ -342,6 +351,7 @@ impl Serialize for ClassProperty {
                     definite: self.definite,
                     optional: self.optional,
                     readonly: self.readonly,
+                    is_override: self.is_override,
                 };
                 actual.serialize(serializer)
             }
@@ -372,6 +382,44 @@ impl Serialize for ClassProperty {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+#[ast_serde]
+pub enum ClassAccessorPropertyKey {
+    #[tag("PrivateName")]
+    Private(PrivateName),
+    #[tag("*")]
+    Key(ObjectKey),
+}
+
+/// A property declared with `accessor`, e.g. `accessor foo = 1;`
+#[derive(Debug, Clone, PartialEq)]
+#[ast_serde("ClassAccessorProperty")]
+pub struct ClassAccessorProperty {
+    #[serde(flatten)]
+    pub base: BaseNode,
+    pub key: ClassAccessorPropertyKey,
+    #[serde(default)]
+    pub value: Option<Box<Expression>>,
+    #[serde(default)]
+    pub type_annotation: Option<Box<TypeAnnotOrNoop>>,
+    #[serde(default)]
+    pub decorators: Option<Vec<Decorator>>,
+    #[serde(default)]
+    pub computed: Option<bool>,
+    #[serde(default, rename = "static")]
+    pub is_static: Option<bool>,
+    #[serde(default, rename = "abstract")]
+    pub is_abstract: Option<bool>,
+    #[serde(default)]
+    pub accessibility: Option<Access>,
+    #[serde(default)]
+    pub definite: Option<bool>,
+    #[serde(default)]
+    pub optional: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 #[ast_serde("StaticBlock")]
 pub struct StaticBlock {
@@ -392,6 +440,8 @@ pub enum ClassBodyEl {
     Prop(ClassProperty),
     #[tag("ClassPrivateProperty")]
     PrivateProp(ClassPrivateProperty),
+    #[tag("ClassAccessorProperty")]
+    AccessorProp(ClassAccessorProperty),
     #[tag("TSDeclareMethod")]
     TSMethod(TSDeclareMethod),
     #[tag("TSIndexSignature")]
@@ -429,7 +479,9 @@ impl From<TSExpressionWithTypeArguments> for ClassImpl {
 pub struct ClassDeclaration {
     #[serde(flatten)]
     pub base: BaseNode,
-    pub id: Identifier,
+    /// `None` for `export default class {}`.
+    #[serde(default)]
+    pub id: Option<Identifier>,
     #[serde(default)]
     pub super_class: Option<Box<Expression>>,
     pub body: ClassBody,
@@ -457,7 +509,7 @@ impl From<ClassExpression> for ClassDeclaration {
     fn from(expr: ClassExpression) -> Self {
         ClassDeclaration {
             base: expr.base,
-            id: expr.id.unwrap(),
+            id: expr.id,
             super_class: expr.super_class.map(|s| Box::new(*s)),
             body: expr.body,
             decorators: expr.decorators,
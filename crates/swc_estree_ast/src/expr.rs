@@ -17,7 +17,8 @@ use crate::{
     object::{ObjectMethod, ObjectProperty},
     stmt::{BlockStatement, ExpressionStatement},
     typescript::{
-        TSAsExpression, TSNonNullExpression, TSTypeAssertion, TSTypeParameterInstantiation,
+        TSAsExpression, TSInstantiationExpression, TSNonNullExpression, TSSatisfiesExpression,
+        TSTypeAssertion, TSTypeParameterInstantiation,
     },
 };
 
@@ -110,6 +111,10 @@ pub enum Expression {
     TSTypeAssertion(TSTypeAssertion),
     #[tag("TSNonNullExpression")]
     TSNonNull(TSNonNullExpression),
+    #[tag("TSSatisfiesExpression")]
+    TSSatisfies(TSSatisfiesExpression),
+    #[tag("TSInstantiationExpression")]
+    TSInstantiation(TSInstantiationExpression),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -11,7 +11,7 @@ use crate::{
     expr::Expression,
     lit::StringLiteral,
     stmt::Statement,
-    typescript::TSDeclareFunction,
+    typescript::{TSDeclareFunction, TSInterfaceDeclaration},
 };
 
 #[derive(Debug, Clone, PartialEq)]
@@ -157,6 +157,8 @@ pub enum ExportDefaultDeclType {
     TSFunc(TSDeclareFunction),
     #[tag("ClassDeclaration")]
     Class(ClassDeclaration),
+    #[tag("TSInterfaceDeclaration")]
+    TSInterface(TSInterfaceDeclaration),
     #[tag("*")]
     Expr(Box<Expression>),
 }
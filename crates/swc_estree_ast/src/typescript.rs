@@ -5,8 +5,9 @@ use swc_common::ast_serde;
 use crate::{
     class::ClassMethodKind,
     common::{Access, BaseNode, Decorator, IdOrRest, IdOrString, Identifier, Noop, Param},
-    expr::Expression,
-    lit::{BigIntLiteral, BooleanLiteral, NumericLiteral, StringLiteral},
+    expr::{Expression, UnaryExpression},
+    lit::{BigIntLiteral, BooleanLiteral, NumericLiteral, StringLiteral, TemplateLiteral},
+    module::ImportKind,
     object::ObjectKey,
     pat::AssignmentPattern,
     stmt::Statement,
@@ -78,6 +79,9 @@ pub enum TSType {
     Optional(TSOptionalType),
     #[tag("TSRestType")]
     Rest(TSRestType),
+    /// Only used as the type of [TSRestType], e.g. `[...rest: T[]]`
+    #[tag("TSNamedTupleMember")]
+    NamedTupleMember(TSNamedTupleMember),
     #[tag("TSUnionType")]
     Union(TSUnionType),
     #[tag("TSIntersectionType")]
@@ -165,6 +169,10 @@ pub struct TSParameterProperty {
     pub accessibility: Option<Access>,
     #[serde(default)]
     pub readonly: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
+    #[serde(default)]
+    pub decorators: Option<Vec<Decorator>>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -240,6 +248,8 @@ pub struct TSDeclareMethod {
     pub optional: Option<bool>,
     #[serde(default, rename = "static")]
     pub is_static: Option<bool>,
+    #[serde(default, rename = "override")]
+    pub is_override: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -313,6 +323,19 @@ pub struct TSMethodSignature {
     pub computed: Option<bool>,
     #[serde(default)]
     pub optional: Option<bool>,
+    #[serde(default)]
+    pub kind: TSMethodSignatureKind,
+}
+
+/// Getter and setter signatures are method signatures with `kind` of `get`
+/// and `set`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TSMethodSignatureKind {
+    #[default]
+    Method,
+    Get,
+    Set,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -327,6 +350,8 @@ pub struct TSIndexSignature {
     pub type_annotation: Option<Box<TSTypeAnnotation>>,
     #[serde(default)]
     pub readonly: Option<bool>,
+    #[serde(default, rename = "static")]
+    pub is_static: Option<bool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -515,6 +540,8 @@ pub struct TSTypeQuery {
     #[serde(flatten)]
     pub base: BaseNode,
     pub expr_name: TSTypeQueryExprName,
+    #[serde(default)]
+    pub type_parameters: Option<TSTypeParameterInstantiation>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -579,7 +606,7 @@ pub struct TSNamedTupleMember {
     #[serde(flatten)]
     pub base: BaseNode,
     pub label: Identifier,
-    pub element_type: TSType,
+    pub element_type: Box<TSType>,
     #[serde(default)]
     pub optional: bool,
 }
@@ -665,9 +692,53 @@ pub struct TSMappedType {
     #[serde(default)]
     pub name_type: Option<Box<TSType>>,
     #[serde(default)]
-    pub optional: Option<bool>,
-    #[serde(default)]
-    pub readonly: Option<bool>,
+    pub optional: Option<TSMappedTypeModifier>,
+    #[serde(default)]
+    pub readonly: Option<TSMappedTypeModifier>,
+}
+
+/// `true`, `"+"` or `"-"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TSMappedTypeModifier {
+    True,
+    Plus,
+    Minus,
+}
+
+impl Serialize for TSMappedTypeModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            TSMappedTypeModifier::True => serializer.serialize_bool(true),
+            TSMappedTypeModifier::Plus => serializer.serialize_str("+"),
+            TSMappedTypeModifier::Minus => serializer.serialize_str("-"),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for TSMappedTypeModifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Bool(bool),
+            Str(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Bool(true) => Ok(TSMappedTypeModifier::True),
+            Repr::Str(s) if s == "+" => Ok(TSMappedTypeModifier::Plus),
+            Repr::Str(s) if s == "-" => Ok(TSMappedTypeModifier::Minus),
+            _ => Err(serde::de::Error::custom(
+                "expected `true`, `\"+\"` or `\"-\"`",
+            )),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -681,6 +752,12 @@ pub enum TSLiteralTypeLiteral {
     Boolean(BooleanLiteral),
     #[tag("BigIntLiteral")]
     BigInt(BigIntLiteral),
+    /// A template literal type. Its expressions are types.
+    #[tag("TemplateLiteral")]
+    Template(TemplateLiteral),
+    /// A negative number, e.g. `-1`.
+    #[tag("UnaryExpression")]
+    Unary(UnaryExpression),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -712,7 +789,7 @@ pub struct TSInterfaceDeclaration {
     #[serde(default)]
     pub type_parameters: Option<TSTypeParameterDeclaration>,
     #[serde(default)]
-    pub extends: Option<TSExpressionWithTypeArguments>,
+    pub extends: Option<Vec<TSExpressionWithTypeArguments>>,
     pub body: TSInterfaceBody,
     #[serde(default)]
     pub declare: Option<bool>,
@@ -802,11 +879,23 @@ pub struct TSModuleDeclaration {
     #[serde(flatten)]
     pub base: BaseNode,
     pub id: IdOrString,
-    pub body: Box<TSModuleDeclBody>,
+    /// `None` for shorthand ambient modules, e.g. `declare module "foo";`
+    #[serde(default)]
+    pub body: Option<Box<TSModuleDeclBody>>,
     #[serde(default)]
     pub declare: Option<bool>,
     #[serde(default)]
     pub global: Option<bool>,
+    #[serde(default)]
+    pub kind: Option<TSModuleDeclarationKind>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TSModuleDeclarationKind {
+    Global,
+    Module,
+    Namespace,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -851,6 +940,8 @@ pub struct TSImportEqualsDeclaration {
     pub module_reference: TSImportEqualsDeclModuleRef,
     #[serde(default)]
     pub is_export: bool,
+    #[serde(default)]
+    pub import_kind: Option<ImportKind>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -861,6 +952,26 @@ pub struct TSExternalModuleReference {
     pub expression: StringLiteral,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub struct TSSatisfiesExpression {
+    #[serde(flatten)]
+    pub base: BaseNode,
+    pub expression: Box<Expression>,
+    pub type_annotation: TSType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub struct TSInstantiationExpression {
+    #[serde(flatten)]
+    pub base: BaseNode,
+    pub expression: Box<Expression>,
+    pub type_parameters: TSTypeParameterInstantiation,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(tag = "type")]
 pub struct TSNonNullExpression {
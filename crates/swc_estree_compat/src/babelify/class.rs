@@ -1,13 +1,14 @@
 use copyless::BoxHelper;
 use serde_json::value::Value;
 use swc_ecma_ast::{
-    Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, MethodKind, PrivateMethod,
-    PrivateProp, StaticBlock,
+    AutoAccessor, Class, ClassMember, ClassMethod, ClassProp, Constructor, Decorator, Key,
+    MethodKind, PrivateMethod, PrivateProp, StaticBlock,
 };
 use swc_estree_ast::{
-    ClassBody, ClassBodyEl, ClassExpression, ClassMethod as BabelClassMethod, ClassMethodKind,
-    ClassPrivateMethod, ClassPrivateProperty, ClassProperty, Decorator as BabelDecorator,
-    StaticBlock as BabelStaticBlock,
+    ClassAccessorProperty, ClassAccessorPropertyKey, ClassBody, ClassBodyEl, ClassExpression,
+    ClassMethod as BabelClassMethod, ClassMethodKind, ClassPrivateMethod, ClassPrivateProperty,
+    ClassProperty, Decorator as BabelDecorator, StaticBlock as BabelStaticBlock, TSDeclareMethod,
+    TSFuncDeclTypeAnnot, TSFuncDeclTypeParams, TypeAnnotOrNoop, TypeParamDeclOrNoop,
 };
 
 use crate::babelify::{extract_class_body_span, Babelify, Context};
@@ -16,8 +17,9 @@ impl Babelify for Class {
     type Output = ClassExpression;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
+        let body_span = extract_class_body_span(&self, ctx);
         let body = ClassBody {
-            base: ctx.base(extract_class_body_span(&self, ctx)),
+            base: ctx.base_with_inner(body_span, self.body.is_empty()),
             body: self.body.babelify(ctx),
         };
 
@@ -49,6 +51,13 @@ impl Babelify for ClassMember {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
+            // Overloads and abstract methods don't have a body.
+            ClassMember::Constructor(c) if c.body.is_none() => {
+                ClassBodyEl::TSMethod(declare_method(c.babelify(ctx)))
+            }
+            ClassMember::Method(m) if m.function.body.is_none() => {
+                ClassBodyEl::TSMethod(declare_method(m.babelify(ctx)))
+            }
             ClassMember::Constructor(c) => ClassBodyEl::Method(c.babelify(ctx)),
             ClassMember::Method(m) => ClassBodyEl::Method(m.babelify(ctx)),
             ClassMember::PrivateMethod(m) => ClassBodyEl::PrivateMethod(m.babelify(ctx)),
@@ -60,11 +69,41 @@ impl Babelify for ClassMember {
                 &self
             ),
             ClassMember::StaticBlock(s) => ClassBodyEl::StaticBlock(s.babelify(ctx)),
-            ClassMember::AutoAccessor(..) => todo!("auto accessor"),
+            ClassMember::AutoAccessor(a) => ClassBodyEl::AccessorProp(a.babelify(ctx)),
         }
     }
 }
 
+/// Converts a method without a body to a `TSDeclareMethod`.
+fn declare_method(method: BabelClassMethod) -> TSDeclareMethod {
+    TSDeclareMethod {
+        base: method.base,
+        decorators: method.decorators,
+        key: method.key,
+        type_parameters: method.type_parameters.map(|params| match params {
+            TypeParamDeclOrNoop::TS(params) => TSFuncDeclTypeParams::Type(params),
+            TypeParamDeclOrNoop::Noop(noop) => TSFuncDeclTypeParams::Noop(noop),
+            TypeParamDeclOrNoop::Flow(..) => unreachable!("swc does not support flow"),
+        }),
+        params: method.params,
+        return_type: method.return_type.map(|ann| match *ann {
+            TypeAnnotOrNoop::TS(ann) => TSFuncDeclTypeAnnot::Type(ann),
+            TypeAnnotOrNoop::Noop(noop) => TSFuncDeclTypeAnnot::Noop(noop),
+            TypeAnnotOrNoop::Flow(..) => unreachable!("swc does not support flow"),
+        }),
+        is_abstract: method.is_abstract,
+        access: method.access,
+        accessibility: method.accessibility,
+        is_async: method.is_async,
+        computed: method.computed,
+        generator: method.generator,
+        kind: method.kind,
+        optional: method.optional,
+        is_static: method.is_static,
+        is_override: method.is_override,
+    }
+}
+
 impl Babelify for ClassProp {
     type Output = ClassProperty;
 
@@ -89,6 +128,41 @@ impl Babelify for ClassProp {
             readonly: Some(self.readonly),
             declare: Some(self.declare),
             definite: Some(self.definite),
+            is_override: self.is_override.then_some(true),
+        }
+    }
+}
+
+impl Babelify for AutoAccessor {
+    type Output = ClassAccessorProperty;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        let base = ctx.base(self.span);
+        let (key, computed) = match self.key {
+            Key::Private(key) => (ClassAccessorPropertyKey::Private(key.babelify(ctx)), false),
+            Key::Public(key) => {
+                let computed = key.is_computed();
+                (ClassAccessorPropertyKey::Key(key.babelify(ctx)), computed)
+            }
+        };
+
+        ClassAccessorProperty {
+            base,
+            key,
+            value: self
+                .value
+                .map(|val| Box::alloc().init(val.babelify(ctx).into())),
+            type_annotation: self
+                .type_ann
+                .map(|ann| Box::alloc().init(ann.babelify(ctx).into())),
+            decorators: Some(self.decorators.babelify(ctx)),
+            computed: Some(computed),
+            is_static: Some(self.is_static),
+            is_abstract: Some(self.is_abstract),
+            accessibility: self.accessibility.map(|access| access.babelify(ctx)),
+            definite: Some(self.definite),
+            optional: Default::default(),
+            is_override: self.is_override.then_some(true),
         }
     }
 }
@@ -116,6 +190,8 @@ impl Babelify for ClassMethod {
     type Output = BabelClassMethod;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
+        let computed = Some(self.key.is_computed());
+
         BabelClassMethod {
             base: ctx.base(self.span),
             key: self.key.babelify(ctx),
@@ -125,8 +201,9 @@ impl Babelify for ClassMethod {
             accessibility: self.accessibility.map(|access| access.babelify(ctx)),
             is_abstract: Some(self.is_abstract),
             optional: Some(self.is_optional),
+            is_override: self.is_override.then_some(true),
             params: self.function.params.babelify(ctx),
-            body: self.function.body.unwrap().babelify(ctx),
+            body: self.function.body.unwrap_or_default().babelify(ctx),
             generator: Some(self.function.is_generator),
             is_async: Some(self.function.is_async),
             decorators: Some(self.function.decorators.babelify(ctx)),
@@ -135,7 +212,7 @@ impl Babelify for ClassMethod {
                 .function
                 .return_type
                 .map(|t| Box::alloc().init(t.babelify(ctx).into())),
-            computed: Default::default(),
+            computed,
         }
     }
 }
@@ -172,16 +249,19 @@ impl Babelify for Constructor {
     type Output = BabelClassMethod;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
+        let computed = Some(self.key.is_computed());
+
         BabelClassMethod {
             base: ctx.base(self.span),
             kind: Some(ClassMethodKind::Constructor),
             key: self.key.babelify(ctx),
             params: self.params.babelify(ctx),
-            body: self.body.unwrap().babelify(ctx),
+            body: self.body.unwrap_or_default().babelify(ctx),
             access: self.accessibility.map(|access| access.babelify(ctx)),
             accessibility: self.accessibility.map(|access| access.babelify(ctx)),
             optional: Some(self.is_optional),
-            computed: Default::default(),
+            is_override: Default::default(),
+            computed,
             is_static: Default::default(),
             generator: Default::default(),
             is_async: Default::default(),
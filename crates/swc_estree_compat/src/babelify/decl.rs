@@ -1,11 +1,14 @@
 use copyless::BoxHelper;
-use swc_ecma_ast::{ClassDecl, Decl, FnDecl, UsingDecl, VarDecl, VarDeclKind, VarDeclarator};
+use swc_ecma_ast::{
+    ClassDecl, Decl, FnDecl, Function, Ident, UsingDecl, VarDecl, VarDeclKind, VarDeclarator,
+};
 use swc_estree_ast::{
-    ClassBody, ClassDeclaration, Declaration, FunctionDeclaration, UsingDeclaration,
-    VariableDeclaration, VariableDeclarationKind, VariableDeclarator,
+    ClassDeclaration, Declaration, FunctionDeclaration, TSDeclareFunction, TSFuncDeclTypeAnnot,
+    TSFuncDeclTypeParams, UsingDeclaration, VariableDeclaration, VariableDeclarationKind,
+    VariableDeclarator,
 };
 
-use crate::babelify::{extract_class_body_span, Babelify, Context};
+use crate::babelify::{Babelify, Context};
 
 impl Babelify for Decl {
     type Output = Declaration;
@@ -13,6 +16,12 @@ impl Babelify for Decl {
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
             Decl::Class(d) => Declaration::ClassDecl(d.babelify(ctx)),
+            Decl::Fn(d) if d.function.body.is_none() => Declaration::TSDeclFunc(declare_function(
+                Some(d.ident),
+                d.declare,
+                *d.function,
+                ctx,
+            )),
             Decl::Fn(d) => Declaration::FuncDecl(d.babelify(ctx)),
             Decl::Var(d) => Declaration::VarDecl(d.babelify(ctx)),
             Decl::Using(d) => Declaration::UsingDecl(d.babelify(ctx)),
@@ -43,39 +52,41 @@ impl Babelify for FnDecl {
     }
 }
 
+/// Converts a function without a body, e.g. an overload or `declare
+/// function`, to a `TSDeclareFunction`.
+pub(crate) fn declare_function(
+    ident: Option<Ident>,
+    declare: bool,
+    func: Function,
+    ctx: &Context,
+) -> TSDeclareFunction {
+    TSDeclareFunction {
+        base: ctx.base(func.span),
+        id: ident.map(|ident| ident.babelify(ctx)),
+        type_parameters: func
+            .type_params
+            .map(|t| TSFuncDeclTypeParams::Type(t.babelify(ctx))),
+        params: func.params.babelify(ctx),
+        return_type: func
+            .return_type
+            .map(|t| TSFuncDeclTypeAnnot::Type(Box::alloc().init(t.babelify(ctx)))),
+        is_async: Some(func.is_async),
+        declare: Some(declare),
+        generator: Some(func.is_generator),
+    }
+}
+
 impl Babelify for ClassDecl {
     type Output = ClassDeclaration;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         let is_abstract = self.class.is_abstract;
-        // NOTE: The body field needs a bit of special handling because babel
-        // represents the body as a node, whereas swc represents it as a vector of
-        // statements. This means that swc does not have a span corresponding the
-        // class body base node for babel. To solve this, we generate a new span
-        // starting from the end of the identifier to the end of the body.
-        // For example,
-        //
-        // babel ClassBody node starts here
-        //                  v
-        //     class MyClass {
-        //         a = 0;
-        //     }
-        //     ^
-        // and ends here
-        //
-        // TODO: Verify that this implementation of class body span is correct.
-        // It may need to be modified to take into account implements, super classes,
-        // etc.
-        let body_span = extract_class_body_span(&self.class, ctx);
         let class = self.class.babelify(ctx);
         ClassDeclaration {
             base: class.base,
-            id: self.ident.babelify(ctx),
+            id: Some(self.ident.babelify(ctx)),
             super_class: class.super_class.map(|s| Box::alloc().init(*s)),
-            body: ClassBody {
-                base: ctx.base(body_span),
-                ..class.body
-            },
+            body: class.body,
             decorators: class.decorators,
             is_abstract: Some(is_abstract),
             declare: Some(self.declare),
@@ -3,21 +3,23 @@ use serde::{Deserialize, Serialize};
 use swc_common::{BytePos, Span, Spanned};
 use swc_ecma_ast::{
     ArrayLit, ArrowExpr, AssignExpr, AssignTarget, AssignTargetPat, AwaitExpr, BinExpr, BinaryOp,
-    BlockStmtOrExpr, CallExpr, Callee, ClassExpr, CondExpr, Expr, ExprOrSpread, FnExpr, Ident,
-    Import, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, NewExpr, ObjectLit, ParenExpr,
-    PropOrSpread, SeqExpr, SimpleAssignTarget, SpreadElement, Super, SuperProp, SuperPropExpr,
-    TaggedTpl, ThisExpr, Tpl, TplElement, UnaryExpr, UpdateExpr, YieldExpr,
+    BlockStmtOrExpr, CallExpr, Callee, ClassExpr, CondExpr, DoExpr, Expr, ExprOrSpread, FnExpr,
+    Ident, Import, Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, NewExpr, ObjectLit,
+    OptChainBase, OptChainExpr, ParenExpr, PropOrSpread, SeqExpr, SimpleAssignTarget,
+    SpreadElement, Super, SuperProp, SuperPropExpr, TaggedTpl, ThisExpr, TopicExpr, Tpl,
+    TplElement, UnaryExpr, UpdateExpr, YieldExpr,
 };
 use swc_estree_ast::{
     flavor::Flavor, ArrayExprEl, ArrayExpression, ArrowFuncExprBody, ArrowFunctionExpression,
     AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExpression, CallExpression,
-    Callee as BabelCallee, ClassExpression, ConditionalExpression, Expression, FunctionExpression,
-    Import as BabelImport, LVal, Literal, LogicalExpression, MemberExprProp, MemberExpression,
-    MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey, ObjectMember,
-    ParenthesizedExpression, PrivateName, SequenceExpression, SpreadElement as BabelSpreadElement,
-    Super as BabelSuper, TaggedTemplateExprTypeParams, TaggedTemplateExpression, TemplateElVal,
-    TemplateElement, TemplateLiteral, TemplateLiteralExpr, ThisExpression, UnaryExpression,
-    UpdateExpression, YieldExpression,
+    Callee as BabelCallee, ClassExpression, ConditionalExpression, DoExpression, Expression,
+    FunctionExpression, Import as BabelImport, LVal, Literal, LogicalExpression, MemberExprProp,
+    MemberExpression, MetaProperty, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey,
+    ObjectMember, OptionalCallExpression, OptionalMemberExprProp, OptionalMemberExpression,
+    ParenthesizedExpression, PipelinePrimaryTopicReference, PrivateName, SequenceExpression,
+    SpreadElement as BabelSpreadElement, Super as BabelSuper, TaggedTemplateExprTypeParams,
+    TaggedTemplateExpression, TemplateElVal, TemplateElement, TemplateLiteral, TemplateLiteralExpr,
+    ThisExpression, UnaryExpression, UpdateExpression, YieldExpression,
 };
 
 use crate::babelify::{Babelify, Context};
@@ -136,8 +138,21 @@ impl Babelify for Expr {
                 ExprOutput::Expr(Box::alloc().init(Expression::TSNonNull(n.babelify(ctx))))
             }
             Expr::TsAs(a) => ExprOutput::Expr(Box::alloc().init(Expression::TSAs(a.babelify(ctx)))),
-            Expr::TsInstantiation(..) => unimplemented!("Babel doesn't support this right now."),
+            Expr::TsConstAssertion(a) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::TSAs(a.babelify(ctx))))
+            }
+            Expr::TsSatisfies(s) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::TSSatisfies(s.babelify(ctx))))
+            }
+            Expr::TsInstantiation(i) => {
+                ExprOutput::Expr(Box::alloc().init(Expression::TSInstantiation(i.babelify(ctx))))
+            }
+            Expr::OptChain(o) => ExprOutput::Expr(Box::alloc().init(o.babelify(ctx))),
             Expr::PrivateName(p) => ExprOutput::Private(p.babelify(ctx)),
+            Expr::Topic(t) => ExprOutput::Expr(
+                Box::alloc().init(Expression::PipelinePrimaryTopicRef(t.babelify(ctx))),
+            ),
+            Expr::Do(d) => ExprOutput::Expr(Box::alloc().init(Expression::Do(d.babelify(ctx)))),
 
             // TODO(dwoznicki): how does babel handle these?
             Expr::JSXMember(_) => panic!(
@@ -152,15 +167,11 @@ impl Babelify for Expr {
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivalent",
                 &self
             ),
-            Expr::TsConstAssertion(_) => panic!(
+            Expr::Match(_) => panic!(
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivalent",
                 &self
             ),
-            Expr::TsSatisfies(_) => panic!(
-                "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivalent",
-                &self
-            ),
-            Expr::OptChain(_) => panic!(
+            Expr::Pipeline(_) => panic!(
                 "illegal conversion: Cannot convert {:?} to ExprOutput - babel has no equivalent",
                 &self
             ),
@@ -232,12 +243,33 @@ impl Babelify for ThisExpr {
     }
 }
 
+impl Babelify for DoExpr {
+    type Output = DoExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        DoExpression {
+            base: ctx.base(self.span),
+            body: self.body.babelify(ctx),
+        }
+    }
+}
+
+impl Babelify for TopicExpr {
+    type Output = PipelinePrimaryTopicReference;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        PipelinePrimaryTopicReference {
+            base: ctx.base(self.span),
+        }
+    }
+}
+
 impl Babelify for ArrayLit {
     type Output = ArrayExpression;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         ArrayExpression {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.elems.is_empty()),
             elements: self.elems.babelify(ctx),
         }
     }
@@ -248,7 +280,7 @@ impl Babelify for ObjectLit {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         ObjectExpression {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.props.is_empty()),
             properties: self.props.babelify(ctx),
         }
     }
@@ -395,6 +427,45 @@ impl Babelify for MemberExpr {
     }
 }
 
+impl Babelify for OptChainExpr {
+    type Output = Expression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        match *self.base {
+            OptChainBase::Member(m) => {
+                let computed = m.prop.is_computed();
+
+                Expression::OptionalMember(OptionalMemberExpression {
+                    base: ctx.base(self.span),
+                    object: Box::alloc().init(m.obj.babelify(ctx).into()),
+                    property: match m.prop.babelify(ctx) {
+                        MemberExprProp::Id(i) => OptionalMemberExprProp::Id(i),
+                        MemberExprProp::Expr(e) => OptionalMemberExprProp::Expr(e),
+                        MemberExprProp::PrivateName(..) => panic!(
+                            "illegal conversion: Cannot convert optional private member to \
+                             OptionalMemberExpression - babel has no equivalent"
+                        ),
+                    },
+                    computed,
+                    optional: self.optional,
+                })
+            }
+            OptChainBase::Call(c) => Expression::OptionalCall(OptionalCallExpression {
+                base: ctx.base_with_inner(self.span, c.args.is_empty()),
+                callee: Box::alloc().init(c.callee.babelify(ctx).into()),
+                arguments: c
+                    .args
+                    .into_iter()
+                    .map(|arg| arg.babelify(ctx).into())
+                    .collect(),
+                optional: self.optional,
+                type_arguments: Default::default(),
+                type_parameters: c.type_args.map(|t| t.babelify(ctx)),
+            }),
+        }
+    }
+}
+
 impl Babelify for MemberProp {
     type Output = MemberExprProp;
 
@@ -452,7 +523,7 @@ impl Babelify for CallExpr {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         CallExpression {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.args.is_empty()),
             callee: Box::alloc().init(self.callee.babelify(ctx).into()),
             arguments: self
                 .args
@@ -471,7 +542,10 @@ impl Babelify for NewExpr {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         NewExpression {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(
+                self.span,
+                self.args.as_ref().map_or(false, |args| args.is_empty()),
+            ),
             callee: BabelCallee::Expr(Box::alloc().init(self.callee.babelify(ctx).into())),
             arguments: match self.args {
                 Some(args) => args
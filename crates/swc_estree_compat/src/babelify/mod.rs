@@ -112,7 +112,6 @@ impl Context {
 
         BaseNode {
             leading_comments,
-            // TODO(kdy1): Use this field.
             inner_comments: Default::default(),
             trailing_comments,
             start,
@@ -128,6 +127,25 @@ impl Context {
             },
         }
     }
+
+    /// Creates a [BaseNode] for a node which encloses a list, like a block
+    /// statement or an object literal.
+    ///
+    /// If the list is empty, comments in it are attached as inner comments
+    /// like babel does.
+    fn base_with_inner(&self, span: Span, is_empty: bool) -> BaseNode {
+        let mut base = self.base(span);
+
+        if is_empty && !span.is_dummy() {
+            base.inner_comments = self
+                .comments
+                .take_leading(span.hi - BytePos(1))
+                .map(|v| self.convert_comments(v))
+                .unwrap_or_default();
+        }
+
+        base
+    }
 }
 
 pub trait Babelify: Send + Sync {
@@ -173,6 +191,8 @@ where
     }
 }
 
+/// Babel represents the body of a class as a node, but swc doesn't have a span
+/// for it. So we use the span from `{` to the end of the class.
 fn extract_class_body_span(class: &Class, ctx: &Context) -> Span {
     let sp = ctx.cm.span_take_while(class.span, |ch| *ch != '{');
     class.span.with_lo(sp.hi())
@@ -1,20 +1,18 @@
 use serde::{Deserialize, Serialize};
 use swc_common::{comments::Comment, Span};
 use swc_ecma_ast::{Module, ModuleItem, Program, Script};
-use swc_ecma_visit::{Visit, VisitWith};
 use swc_estree_ast::{
     flavor::Flavor, BaseNode, File, InterpreterDirective, LineCol, Loc, ModuleDeclaration,
     Program as BabelProgram, SrcType, Statement,
 };
-use swc_node_comments::SwcComments;
 
-use crate::babelify::{Babelify, Context};
+use crate::babelify::{module_decl::ModuleDeclOutput, Babelify, Context};
 
 impl Babelify for Program {
     type Output = File;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
-        let comments = extract_all_comments(&self, ctx);
+        let comments = extract_all_comments(ctx);
         let program = match self {
             Program::Module(module) => module.babelify(ctx),
             Program::Script(script) => script.babelify(ctx),
@@ -123,7 +121,7 @@ fn base_with_trailing_newline(span: Span, ctx: &Context) -> BaseNode {
 /// line comments. Swc ignores them and starts the program on the next line
 /// down, while babel includes them in the file start/end.
 fn has_comment_first_line(sp: Span, ctx: &Context) -> bool {
-    if let Some(comments) = ctx.comments.leading.get(&sp.hi) {
+    if let Some(comments) = ctx.comments.leading.get(sp.hi) {
         !comments
             .first()
             .map(|c| c.span.lo == ctx.fm.start_pos)
@@ -148,7 +146,18 @@ impl Babelify for ModuleItem {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
-            ModuleItem::ModuleDecl(d) => ModuleItemOutput::ModuleDecl(d.babelify(ctx).into()),
+            ModuleItem::ModuleDecl(d) => match d.babelify(ctx) {
+                ModuleDeclOutput::TsImportEquals(d) => {
+                    ModuleItemOutput::Stmt(Statement::TSImportEqualsDecl(d))
+                }
+                ModuleDeclOutput::TsExportAssignment(d) => {
+                    ModuleItemOutput::Stmt(Statement::TSExportAssignment(d))
+                }
+                ModuleDeclOutput::TsNamespaceExport(d) => {
+                    ModuleItemOutput::Stmt(Statement::TSNamespaceExportDecl(d))
+                }
+                d => ModuleItemOutput::ModuleDecl(d.into()),
+            },
             ModuleItem::Stmt(s) => ModuleItemOutput::Stmt(s.babelify(ctx)),
         }
     }
@@ -172,41 +181,21 @@ fn extract_shebang_span(span: Span, ctx: &Context) -> Span {
     ctx.cm.span_take_while(span, |ch| *ch != '\n')
 }
 
-fn extract_all_comments(program: &Program, ctx: &Context) -> Vec<Comment> {
-    let mut collector = CommentCollector {
-        comments: ctx.comments.clone(),
-        collected: Vec::new(),
-    };
-    program.visit_with(&mut collector);
-    collector.collected
-}
-
-struct CommentCollector {
-    comments: SwcComments,
-    collected: Vec<Comment>,
-}
-
-impl Visit for CommentCollector {
-    fn visit_span(&mut self, sp: &Span) {
-        let mut span_comments: Vec<Comment> = Vec::new();
-        // Comments must be deduped since it's possible for a single comment to show up
-        // multiple times since they are not removed from the comments map.
-        // For example, this happens when the first line in a file is a comment.
-        if let Some(comments) = self.comments.leading.get(&sp.lo) {
-            for comment in comments.iter() {
-                if !self.collected.iter().any(|c| *c == *comment) {
-                    span_comments.push(comment.clone());
-                }
-            }
-        }
-
-        if let Some(comments) = self.comments.trailing.get(&sp.hi) {
-            for comment in comments.iter() {
-                if !self.collected.iter().any(|c| *c == *comment) {
-                    span_comments.push(comment.clone());
-                }
-            }
-        }
-        self.collected.append(&mut span_comments);
-    }
+/// Returns all comments in the source order, like `File.comments` of babel.
+fn extract_all_comments(ctx: &Context) -> Vec<Comment> {
+    let leading = ctx.comments.leading.read();
+    let trailing = ctx.comments.trailing.read();
+    let mut comments: Vec<Comment> = leading
+        .values()
+        .chain(trailing.values())
+        .flatten()
+        .cloned()
+        .collect();
+
+    // A comment can be stored multiple times, e.g. when the first line of a file is
+    // a comment.
+    comments.sort_by_key(|comment| comment.span.lo);
+    comments.dedup_by_key(|comment| comment.span.lo);
+
+    comments
 }
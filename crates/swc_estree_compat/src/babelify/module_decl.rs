@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use swc_ecma_ast::{
     DefaultDecl, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
     ExportDefaultSpecifier, ExportNamedSpecifier, ExportNamespaceSpecifier, ExportSpecifier, Expr,
-    ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier,
+    FnExpr, ImportDecl, ImportDefaultSpecifier, ImportNamedSpecifier, ImportSpecifier,
     ImportStarAsSpecifier, Lit, ModuleDecl, ModuleExportName, NamedExport, ObjectLit, Prop,
     PropName, PropOrSpread,
 };
@@ -18,7 +18,7 @@ use swc_estree_ast::{
     TSNamespaceExportDeclaration,
 };
 
-use crate::babelify::{Babelify, Context};
+use crate::babelify::{decl::declare_function, Babelify, Context};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ModuleDeclOutput {
@@ -237,8 +237,11 @@ impl Babelify for DefaultDecl {
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
             DefaultDecl::Class(c) => ExportDefaultDeclType::Class(c.babelify(ctx).into()),
+            DefaultDecl::Fn(FnExpr { ident, function }) if function.body.is_none() => {
+                ExportDefaultDeclType::TSFunc(declare_function(ident, false, *function, ctx))
+            }
             DefaultDecl::Fn(f) => ExportDefaultDeclType::Func(f.babelify(ctx).into()),
-            DefaultDecl::TsInterfaceDecl(_) => panic!("unimplemented"), /* TODO(dwoznicki): Babel expects a TSDeclareFunction here, which does not map cleanly to TsInterfaceDecl expected by swc */
+            DefaultDecl::TsInterfaceDecl(i) => ExportDefaultDeclType::TSInterface(i.babelify(ctx)),
         }
     }
 }
@@ -355,7 +358,7 @@ impl Babelify for ModuleExportName {
     fn babelify(self, ctx: &Context) -> Self::Output {
         match self {
             ModuleExportName::Ident(ident) => ModuleExportNameType::Ident(ident.babelify(ctx)),
-            ModuleExportName::Str(..) => unimplemented!("module string names unimplemented"),
+            ModuleExportName::Str(s) => ModuleExportNameType::Str(s.babelify(ctx)),
         }
     }
 }
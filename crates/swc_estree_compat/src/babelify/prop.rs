@@ -44,9 +44,9 @@ impl Babelify for KeyValueProp {
     fn babelify(self, ctx: &Context) -> Self::Output {
         ObjectProperty {
             base: ctx.base(self.span()),
+            computed: self.key.is_computed(),
             key: self.key.babelify(ctx),
             value: ObjectPropVal::Expr(Box::alloc().init(self.value.babelify(ctx).into())),
-            computed: Default::default(),
             shorthand: Default::default(),
             decorators: Default::default(),
         }
@@ -76,13 +76,13 @@ impl Babelify for GetterProp {
         ObjectMethod {
             base: ctx.base(self.span),
             kind: ObjectMethodKind::Get,
+            computed: self.key.is_computed(),
             key: self.key.babelify(ctx),
             return_type: self
                 .type_ann
                 .map(|ann| Box::alloc().init(ann.babelify(ctx).into())),
             body: self.body.unwrap().babelify(ctx),
             params: Default::default(),
-            computed: Default::default(),
             generator: Default::default(),
             is_async: Default::default(),
             decorator: Default::default(),
@@ -98,11 +98,11 @@ impl Babelify for SetterProp {
         ObjectMethod {
             base: ctx.base(self.span),
             kind: ObjectMethodKind::Set,
+            computed: self.key.is_computed(),
             key: self.key.babelify(ctx),
             params: vec![self.param.babelify(ctx).into()],
             body: self.body.unwrap().babelify(ctx),
             return_type: Default::default(),
-            computed: Default::default(),
             generator: Default::default(),
             is_async: Default::default(),
             decorator: Default::default(),
@@ -119,10 +119,10 @@ impl Babelify for MethodProp {
         ObjectMethod {
             base: func.base,
             kind: ObjectMethodKind::Method,
+            computed: self.key.is_computed(),
             key: self.key.babelify(ctx),
             params: func.params,
             body: func.body,
-            computed: Default::default(),
             generator: func.generator,
             is_async: func.is_async,
             decorator: Default::default(),
@@ -12,14 +12,14 @@ use swc_estree_ast::{
     TryStatement, WhileStatement, WithStatement,
 };
 
-use crate::babelify::{Babelify, Context};
+use crate::babelify::{decl::declare_function, Babelify, Context};
 
 impl Babelify for BlockStmt {
     type Output = BlockStatement;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         BlockStatement {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.stmts.is_empty()),
             body: self.stmts.babelify(ctx),
             directives: Default::default(),
         }
@@ -54,6 +54,9 @@ impl Babelify for Stmt {
             Stmt::ForOf(s) => Statement::ForOf(s.babelify(ctx)),
             Stmt::Decl(decl) => match decl {
                 Decl::Class(d) => Statement::ClassDecl(d.babelify(ctx)),
+                Decl::Fn(d) if d.function.body.is_none() => Statement::TSDeclFunc(
+                    declare_function(Some(d.ident), d.declare, *d.function, ctx),
+                ),
                 Decl::Fn(d) => Statement::FuncDecl(d.babelify(ctx)),
                 Decl::Var(d) => Statement::VarDecl(d.babelify(ctx)),
                 Decl::Using(d) => Statement::UsingDecl(d.babelify(ctx)),
@@ -175,7 +178,7 @@ impl Babelify for SwitchStmt {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         SwitchStatement {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.cases.is_empty()),
             discriminant: Box::alloc().init(self.discriminant.babelify(ctx).into()),
             cases: self.cases.babelify(ctx),
         }
@@ -270,7 +273,7 @@ impl Babelify for ForOfStmt {
             left: self.left.babelify(ctx),
             right: Box::alloc().init(self.right.babelify(ctx).into()),
             body: Box::alloc().init(self.body.babelify(ctx)),
-            // await_token not yet implemented
+            is_await: self.is_await,
         }
     }
 }
@@ -1,41 +1,44 @@
 use copyless::BoxHelper;
 use serde::{Deserialize, Serialize};
 use swc_atoms::Atom;
-use swc_common::Spanned;
+use swc_common::{BytePos, Spanned};
 use swc_ecma_ast::{
-    Accessibility, Expr, MemberProp, Pat, TruePlusMinus, TsArrayType, TsAsExpr,
-    TsCallSignatureDecl, TsConditionalType, TsConstAssertion, TsConstructSignatureDecl,
+    Accessibility, BigIntValue, Expr, IdentName, MemberProp, Pat, TruePlusMinus, TsArrayType,
+    TsAsExpr, TsCallSignatureDecl, TsConditionalType, TsConstAssertion, TsConstructSignatureDecl,
     TsConstructorType, TsEntityName, TsEnumDecl, TsEnumMember, TsEnumMemberId, TsExportAssignment,
     TsExprWithTypeArgs, TsExternalModuleRef, TsFnOrConstructorType, TsFnParam, TsFnType,
-    TsImportEqualsDecl, TsImportType, TsIndexSignature, TsIndexedAccessType, TsInferType,
-    TsInterfaceBody, TsInterfaceDecl, TsIntersectionType, TsKeywordType, TsKeywordTypeKind, TsLit,
-    TsLitType, TsMappedType, TsMethodSignature, TsModuleBlock, TsModuleDecl, TsModuleName,
-    TsModuleRef, TsNamespaceBody, TsNamespaceDecl, TsNamespaceExportDecl, TsNonNullExpr,
-    TsOptionalType, TsParamProp, TsParamPropParam, TsParenthesizedType, TsPropertySignature,
-    TsQualifiedName, TsRestType, TsThisType, TsThisTypeOrIdent, TsTplLitType, TsTupleElement,
-    TsTupleType, TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeAssertion, TsTypeElement, TsTypeLit,
-    TsTypeOperator, TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation,
-    TsTypePredicate, TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType,
-    TsUnionType,
+    TsGetterSignature, TsImportEqualsDecl, TsImportType, TsIndexSignature, TsIndexedAccessType,
+    TsInferType, TsInstantiation, TsInterfaceBody, TsInterfaceDecl, TsIntersectionType,
+    TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsMappedType, TsMethodSignature,
+    TsModuleBlock, TsModuleDecl, TsModuleName, TsModuleRef, TsNamespaceBody, TsNamespaceDecl,
+    TsNamespaceExportDecl, TsNonNullExpr, TsOptionalType, TsParamProp, TsParamPropParam,
+    TsParenthesizedType, TsPropertySignature, TsQualifiedName, TsRestType, TsSatisfiesExpr,
+    TsSetterSignature, TsThisType, TsThisTypeOrIdent, TsTplLitType, TsTupleElement, TsTupleType,
+    TsType, TsTypeAliasDecl, TsTypeAnn, TsTypeAssertion, TsTypeElement, TsTypeLit, TsTypeOperator,
+    TsTypeOperatorOp, TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypePredicate,
+    TsTypeQuery, TsTypeQueryExpr, TsTypeRef, TsUnionOrIntersectionType, TsUnionType,
 };
 use swc_estree_ast::{
-    Access, ArrayPattern, IdOrRest, IdOrString, Identifier, ObjectPattern, RestElement,
-    TSAnyKeyword, TSArrayType, TSAsExpression, TSBigIntKeyword, TSBooleanKeyword,
-    TSCallSignatureDeclaration, TSConditionalType, TSConstructSignatureDeclaration,
-    TSConstructorType, TSEntityName, TSEnumDeclaration, TSEnumMember, TSExportAssignment,
-    TSExpressionWithTypeArguments, TSExternalModuleReference, TSFunctionType,
-    TSImportEqualsDeclModuleRef, TSImportEqualsDeclaration, TSImportType, TSIndexSignature,
-    TSIndexedAccessType, TSInferType, TSInterfaceBody, TSInterfaceDeclaration, TSIntersectionType,
-    TSIntrinsicKeyword, TSLiteralType, TSLiteralTypeLiteral, TSMappedType, TSMethodSignature,
-    TSModuleBlock, TSModuleDeclBody, TSModuleDeclaration, TSNamedTupleMember,
-    TSNamespaceExportDeclaration, TSNeverKeyword, TSNonNullExpression, TSNullKeyword,
-    TSNumberKeyword, TSObjectKeyword, TSOptionalType, TSParamPropParam, TSParameterProperty,
-    TSParenthesizedType, TSPropertySignature, TSQualifiedName, TSRestType, TSStringKeyword,
-    TSSymbolKeyword, TSThisType, TSTupleType, TSTupleTypeElType, TSType, TSTypeAliasDeclaration,
-    TSTypeAnnotation, TSTypeAssertion, TSTypeElement, TSTypeLiteral, TSTypeOperator,
-    TSTypeParameter, TSTypeParameterDeclaration, TSTypeParameterInstantiation, TSTypePredicate,
+    Access, ArrayPattern, BigIntLiteral, Expression, IdOrRest, IdOrString, Identifier, ImportKind,
+    NumericLiteral, ObjectPattern, RestElement, TSAnyKeyword, TSArrayType, TSAsExpression,
+    TSBigIntKeyword, TSBooleanKeyword, TSCallSignatureDeclaration, TSConditionalType,
+    TSConstructSignatureDeclaration, TSConstructorType, TSEntityName, TSEnumDeclaration,
+    TSEnumMember, TSExportAssignment, TSExpressionWithTypeArguments, TSExternalModuleReference,
+    TSFunctionType, TSImportEqualsDeclModuleRef, TSImportEqualsDeclaration, TSImportType,
+    TSIndexSignature, TSIndexedAccessType, TSInferType, TSInstantiationExpression, TSInterfaceBody,
+    TSInterfaceDeclaration, TSIntersectionType, TSIntrinsicKeyword, TSLiteralType,
+    TSLiteralTypeLiteral, TSMappedType, TSMappedTypeModifier, TSMethodSignature,
+    TSMethodSignatureKind, TSModuleBlock, TSModuleDeclBody, TSModuleDeclaration,
+    TSModuleDeclarationKind, TSNamedTupleMember, TSNamespaceExportDeclaration, TSNeverKeyword,
+    TSNonNullExpression, TSNullKeyword, TSNumberKeyword, TSObjectKeyword, TSOptionalType,
+    TSParamPropParam, TSParameterProperty, TSParenthesizedType, TSPropertySignature,
+    TSQualifiedName, TSRestType, TSSatisfiesExpression, TSStringKeyword, TSSymbolKeyword,
+    TSThisType, TSTupleType, TSTupleTypeElType, TSType, TSTypeAliasDeclaration, TSTypeAnnotation,
+    TSTypeAssertion, TSTypeElement, TSTypeLiteral, TSTypeOperator, TSTypeParameter,
+    TSTypeParameterDeclaration, TSTypeParameterInstantiation, TSTypePredicate,
     TSTypePredicateParamName, TSTypeQuery, TSTypeQueryExprName, TSTypeReference,
-    TSUndefinedKeyword, TSUnionType, TSUnknownKeyword, TSVoidKeyword,
+    TSUndefinedKeyword, TSUnionType, TSUnknownKeyword, TSVoidKeyword, TemplateLiteral,
+    TemplateLiteralExpr, UnaryExprOp, UnaryExpression,
 };
 
 use crate::babelify::{Babelify, Context};
@@ -155,6 +158,8 @@ impl Babelify for TsParamProp {
             parameter: self.param.babelify(ctx),
             accessibility: self.accessibility.map(|access| access.babelify(ctx)),
             readonly: Some(self.readonly),
+            is_override: self.is_override.then_some(true),
+            decorators: Some(self.decorators.babelify(ctx)),
         }
     }
 }
@@ -207,8 +212,8 @@ impl Babelify for TsTypeElement {
             TsTypeElement::TsPropertySignature(t) => TSTypeElement::PropSignature(t.babelify(ctx)),
             TsTypeElement::TsMethodSignature(t) => TSTypeElement::MethodSignature(t.babelify(ctx)),
             TsTypeElement::TsIndexSignature(t) => TSTypeElement::IndexSignature(t.babelify(ctx)),
-            TsTypeElement::TsGetterSignature(_) => panic!("unimplemented"),
-            TsTypeElement::TsSetterSignature(_) => panic!("unimplemented"),
+            TsTypeElement::TsGetterSignature(t) => TSTypeElement::MethodSignature(t.babelify(ctx)),
+            TsTypeElement::TsSetterSignature(t) => TSTypeElement::MethodSignature(t.babelify(ctx)),
         }
     }
 }
@@ -286,6 +291,43 @@ impl Babelify for TsMethodSignature {
                 .map(|ann| Box::alloc().init(ann.babelify(ctx))),
             computed: Some(self.computed),
             optional: Some(self.optional),
+            kind: TSMethodSignatureKind::Method,
+        }
+    }
+}
+
+impl Babelify for TsGetterSignature {
+    type Output = TSMethodSignature;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TSMethodSignature {
+            base: ctx.base(self.span),
+            key: Box::alloc().init(self.key.babelify(ctx).into()),
+            type_parameters: Default::default(),
+            parameters: Default::default(),
+            type_annotation: self
+                .type_ann
+                .map(|ann| Box::alloc().init(ann.babelify(ctx))),
+            computed: Some(self.computed),
+            optional: Some(false),
+            kind: TSMethodSignatureKind::Get,
+        }
+    }
+}
+
+impl Babelify for TsSetterSignature {
+    type Output = TSMethodSignature;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TSMethodSignature {
+            base: ctx.base(self.span),
+            key: Box::alloc().init(self.key.babelify(ctx).into()),
+            type_parameters: Default::default(),
+            parameters: vec![self.param.babelify(ctx).into()],
+            type_annotation: Default::default(),
+            computed: Some(self.computed),
+            optional: Some(false),
+            kind: TSMethodSignatureKind::Set,
         }
     }
 }
@@ -305,6 +347,7 @@ impl Babelify for TsIndexSignature {
                 .type_ann
                 .map(|ann| Box::alloc().init(ann.babelify(ctx))),
             readonly: Some(self.readonly),
+            is_static: Some(self.is_static),
         }
     }
 }
@@ -521,6 +564,7 @@ impl Babelify for TsTypeQuery {
         TSTypeQuery {
             base: ctx.base(self.span),
             expr_name: self.expr_name.babelify(ctx),
+            type_parameters: self.type_args.map(|args| args.babelify(ctx)),
         }
     }
 }
@@ -554,7 +598,7 @@ impl Babelify for TsTypeLit {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         TSTypeLiteral {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.members.is_empty()),
             members: self.members.babelify(ctx),
         }
     }
@@ -576,7 +620,7 @@ impl Babelify for TsTupleType {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         TSTupleType {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.elem_types.is_empty()),
             element_types: self.elem_types.babelify(ctx),
         }
     }
@@ -586,27 +630,51 @@ impl Babelify for TsTupleElement {
     type Output = TSTupleTypeElType;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
-        match self.label {
-            None => TSTupleTypeElType::TSType(self.ty.babelify(ctx)),
-            Some(pat) => TSTupleTypeElType::Member(TSNamedTupleMember {
-                base: ctx.base(self.span),
-                label: match pat {
-                    Pat::Ident(id) => id.babelify(ctx),
-                    Pat::Rest(rest) => match *rest.arg {
-                        Pat::Ident(id) => id.babelify(ctx),
-                        _ => panic!(
-                            "illegal conversion: Cannot convert {:?} to Identifier",
-                            &rest.arg
-                        ),
-                    },
-                    _ => panic!(
-                        "illegal conversion: Cannot convert {:?} to Identifier",
-                        &pat
-                    ),
-                },
-                element_type: self.ty.babelify(ctx),
-                optional: Default::default(),
-            }),
+        let label = match self.label {
+            None => return TSTupleTypeElType::TSType(self.ty.babelify(ctx)),
+            Some(label) => label,
+        };
+
+        // Babel wraps named rest members, e.g. `...rest: T[]`, in a rest type.
+        let (rest_span, label) = match label {
+            Pat::Rest(rest) => (Some(self.span), *rest.arg),
+            label => (None, label),
+        };
+        let mut id = match label {
+            Pat::Ident(id) => id.id,
+            _ => panic!(
+                "illegal conversion: Cannot convert {:?} to Identifier",
+                &label
+            ),
+        };
+        let optional = id.optional;
+        if optional {
+            // The span of the label includes `?`.
+            id.optional = false;
+            id.span.hi = id.span.hi - BytePos(1);
+        }
+
+        let base = match rest_span {
+            Some(span) => ctx.base(span),
+            None => ctx.base(self.span),
+        };
+        let member = TSNamedTupleMember {
+            base: if rest_span.is_some() {
+                ctx.base(self.span.with_lo(id.span.lo))
+            } else {
+                base.clone()
+            },
+            label: IdentName::from(id).babelify(ctx),
+            element_type: Box::alloc().init(self.ty.babelify(ctx)),
+            optional,
+        };
+
+        match rest_span {
+            Some(..) => TSTupleTypeElType::TSType(TSType::Rest(TSRestType {
+                base,
+                type_annotation: Box::alloc().init(TSType::NamedTupleMember(member)),
+            })),
+            None => TSTupleTypeElType::Member(member),
         }
     }
 }
@@ -748,8 +816,6 @@ impl Babelify for TsIndexedAccessType {
     }
 }
 
-// TODO(dwoznicki): I don't understand how Babel handles the +/- symbol, so this
-// conversion will not work properly yet.
 impl Babelify for TsMappedType {
     type Output = TSMappedType;
 
@@ -761,8 +827,20 @@ impl Babelify for TsMappedType {
                 .type_ann
                 .map(|ann| Box::alloc().init(ann.babelify(ctx))),
             name_type: self.name_type.map(|t| Box::alloc().init(t.babelify(ctx))),
-            optional: self.optional.map(|val| val == TruePlusMinus::True),
-            readonly: self.readonly.map(|val| val == TruePlusMinus::True),
+            optional: self.optional.babelify(ctx),
+            readonly: self.readonly.babelify(ctx),
+        }
+    }
+}
+
+impl Babelify for TruePlusMinus {
+    type Output = TSMappedTypeModifier;
+
+    fn babelify(self, _ctx: &Context) -> Self::Output {
+        match self {
+            TruePlusMinus::True => TSMappedTypeModifier::True,
+            TruePlusMinus::Plus => TSMappedTypeModifier::Plus,
+            TruePlusMinus::Minus => TSMappedTypeModifier::Minus,
         }
     }
 }
@@ -771,10 +849,39 @@ impl Babelify for TsLitType {
     type Output = TSLiteralType;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
-        TSLiteralType {
-            base: ctx.base(self.span),
-            literal: self.lit.babelify(ctx),
-        }
+        let base = ctx.base(self.span);
+
+        // Babel represents negative literals, e.g. `-1`, as unary expressions.
+        let negated = |argument: Expression| {
+            TSLiteralTypeLiteral::Unary(UnaryExpression {
+                base: base.clone(),
+                operator: UnaryExprOp::Negation,
+                argument: Box::alloc().init(argument),
+                prefix: true,
+            })
+        };
+
+        let literal = match self.lit {
+            TsLit::Number(n) if n.value.is_sign_negative() => negated(Expression::Literal(
+                swc_estree_ast::Literal::Numeric(NumericLiteral {
+                    base: ctx.base(n.span),
+                    value: -n.value,
+                }),
+            )),
+            TsLit::BigInt(i) if *i.value < BigIntValue::default() => negated(Expression::Literal(
+                swc_estree_ast::Literal::BigInt(BigIntLiteral {
+                    base: ctx.base(i.span),
+                    value: (-*i.value).to_string(),
+                    raw: match i.raw {
+                        Some(raw) => raw.trim_start_matches('-').into(),
+                        None => "".into(),
+                    },
+                }),
+            )),
+            lit => lit.babelify(ctx),
+        };
+
+        TSLiteralType { base, literal }
     }
 }
 
@@ -787,21 +894,24 @@ impl Babelify for TsLit {
             TsLit::Str(s) => TSLiteralTypeLiteral::String(s.babelify(ctx)),
             TsLit::Bool(b) => TSLiteralTypeLiteral::Boolean(b.babelify(ctx)),
             TsLit::BigInt(i) => TSLiteralTypeLiteral::BigInt(i.babelify(ctx)),
-            _ => panic!(
-                "illegal conversion: Cannot convert {:?} to TSLiteralTypeLiteral",
-                &self
-            ),
+            TsLit::Tpl(t) => TSLiteralTypeLiteral::Template(t.babelify(ctx)),
         }
     }
 }
 
-// TODO(dwoznicki): Babel does not appear to have a corresponding template
-// literal TS node.
 impl Babelify for TsTplLitType {
-    type Output = String;
+    type Output = TemplateLiteral;
 
-    fn babelify(self, _ctx: &Context) -> Self::Output {
-        panic!("unimplemented");
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TemplateLiteral {
+            base: ctx.base(self.span),
+            expressions: self
+                .types
+                .into_iter()
+                .map(|t| TemplateLiteralExpr::TSType(t.babelify(ctx)))
+                .collect(),
+            quasis: self.quasis.babelify(ctx),
+        }
     }
 }
 
@@ -813,7 +923,11 @@ impl Babelify for TsInterfaceDecl {
             base: ctx.base(self.span),
             id: self.id.babelify(ctx),
             type_parameters: self.type_params.map(|t| t.babelify(ctx)),
-            extends: self.extends.into_iter().next().babelify(ctx),
+            extends: if self.extends.is_empty() {
+                None
+            } else {
+                Some(self.extends.babelify(ctx))
+            },
             body: self.body.babelify(ctx),
             declare: Some(self.declare),
         }
@@ -825,7 +939,7 @@ impl Babelify for TsInterfaceBody {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         TSInterfaceBody {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.body.is_empty()),
             body: self.body.babelify(ctx),
         }
     }
@@ -876,7 +990,7 @@ impl Babelify for TsEnumDecl {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         TSEnumDeclaration {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.members.is_empty()),
             id: self.id.babelify(ctx),
             members: self.members.babelify(ctx),
             is_const: Some(self.is_const),
@@ -913,12 +1027,21 @@ impl Babelify for TsModuleDecl {
     type Output = TSModuleDeclaration;
 
     fn babelify(self, ctx: &Context) -> Self::Output {
+        let kind = if self.global {
+            TSModuleDeclarationKind::Global
+        } else if self.namespace {
+            TSModuleDeclarationKind::Namespace
+        } else {
+            TSModuleDeclarationKind::Module
+        };
+
         TSModuleDeclaration {
             base: ctx.base(self.span),
             id: self.id.babelify(ctx),
-            body: Box::alloc().init(self.body.unwrap().babelify(ctx)),
+            body: self.body.map(|body| Box::alloc().init(body.babelify(ctx))),
             declare: Some(self.declare),
             global: Some(self.global),
+            kind: Some(kind),
         }
     }
 }
@@ -939,7 +1062,7 @@ impl Babelify for TsModuleBlock {
 
     fn babelify(self, ctx: &Context) -> Self::Output {
         TSModuleBlock {
-            base: ctx.base(self.span),
+            base: ctx.base_with_inner(self.span, self.body.is_empty()),
             body: self
                 .body
                 .into_iter()
@@ -956,9 +1079,10 @@ impl Babelify for TsNamespaceDecl {
         TSModuleDeclaration {
             base: ctx.base(self.span),
             id: IdOrString::Id(self.id.babelify(ctx)),
-            body: Box::alloc().init(self.body.babelify(ctx)),
+            body: Some(Box::alloc().init(self.body.babelify(ctx))),
             declare: Some(self.declare),
             global: Some(self.global),
+            kind: Some(TSModuleDeclarationKind::Namespace),
         }
     }
 }
@@ -983,6 +1107,11 @@ impl Babelify for TsImportEqualsDecl {
             id: self.id.babelify(ctx),
             module_reference: self.module_ref.babelify(ctx),
             is_export: self.is_export,
+            import_kind: Some(if self.is_type_only {
+                ImportKind::Type
+            } else {
+                ImportKind::Value
+            }),
         }
     }
 }
@@ -1080,12 +1209,53 @@ impl Babelify for Accessibility {
     }
 }
 
-// TODO(dwoznicki): There does not appear to be a corresponding Babel node for
-// this.
+/// Babel represents `expr as const` as an `as` expression with a type
+/// reference to `const`.
 impl Babelify for TsConstAssertion {
-    type Output = String;
+    type Output = TSAsExpression;
 
-    fn babelify(self, _ctx: &Context) -> Self::Output {
-        panic!("unimplemented");
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        let base = ctx.base(self.span);
+        let const_span = self.span.with_lo(self.span.hi - BytePos(5));
+
+        TSAsExpression {
+            base,
+            expression: Box::alloc().init(self.expr.babelify(ctx).into()),
+            type_annotation: TSType::TypeRef(TSTypeReference {
+                base: ctx.base(const_span),
+                type_name: TSEntityName::Id(Identifier {
+                    base: ctx.base(const_span),
+                    name: "const".into(),
+                    optional: Default::default(),
+                    decorators: Default::default(),
+                    type_annotation: Default::default(),
+                }),
+                type_parameters: Default::default(),
+            }),
+        }
+    }
+}
+
+impl Babelify for TsSatisfiesExpr {
+    type Output = TSSatisfiesExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TSSatisfiesExpression {
+            base: ctx.base(self.span),
+            expression: Box::alloc().init(self.expr.babelify(ctx).into()),
+            type_annotation: self.type_ann.babelify(ctx),
+        }
+    }
+}
+
+impl Babelify for TsInstantiation {
+    type Output = TSInstantiationExpression;
+
+    fn babelify(self, ctx: &Context) -> Self::Output {
+        TSInstantiationExpression {
+            base: ctx.base(self.span),
+            expression: Box::alloc().init(self.expr.babelify(ctx).into()),
+            type_parameters: self.type_args.babelify(ctx),
+        }
     }
 }
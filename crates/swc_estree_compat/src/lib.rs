@@ -2,9 +2,92 @@
 #![allow(clippy::large_enum_variant)]
 #![allow(clippy::upper_case_acronyms)]
 
-use std::convert::Infallible;
+use std::{convert::Infallible, fmt};
+
+use swc_ecma_ast::Program;
+use swc_estree_ast::File;
+
+use crate::{
+    babelify::Babelify,
+    swcify::{Swcify, UnsupportedNode},
+};
 
 pub mod babelify;
 pub mod swcify;
 
 pub type Never = Infallible;
+
+/// Converts `program` to the json of the babel ast, which can be passed to
+/// tools like eslint or babel plugins.
+///
+/// Nodes have `start`, `end` and `loc`, and the comments in `ctx` are
+/// attached to them.
+///
+/// # Panics
+///
+/// Panics if `program` contains a node which babel can't represent, like
+/// [Invalid](swc_ecma_ast::Invalid) or a pipeline expression.
+pub fn to_babel_json(program: Program, ctx: &babelify::Context) -> String {
+    serde_json::to_string(&program.babelify(ctx)).expect("failed to serialize babel ast")
+}
+
+/// Converts the json of a babel ast, which has a `File` node at the root, to
+/// [Program].
+///
+/// Spans are recovered from `start` and `end`, or `loc` if they are missing,
+/// and comments are added to the comment map of `ctx`.
+///
+/// # Panics
+///
+/// Panics if a node breaks an invariant of the babel ast, like a function
+/// declaration without `id`. Nodes which are valid but can't be represented
+/// in swc ast are reported as [FromBabelJsonError::Unsupported].
+pub fn from_babel_json(json: &str, ctx: &swcify::Context) -> Result<Program, FromBabelJsonError> {
+    let file: File = serde_json::from_str(json).map_err(FromBabelJsonError::Json)?;
+    let program = file.swcify(ctx);
+
+    let unsupported = ctx.take_unsupported();
+    if !unsupported.is_empty() {
+        return Err(FromBabelJsonError::Unsupported(unsupported));
+    }
+
+    Ok(program)
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FromBabelJsonError {
+    /// The input is not a json of babel ast.
+    Json(serde_json::Error),
+    /// The input contains nodes which can't be represented in swc ast.
+    Unsupported(Vec<UnsupportedNode>),
+}
+
+impl fmt::Display for FromBabelJsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FromBabelJsonError::Json(err) => write!(f, "invalid babel ast: {}", err),
+            FromBabelJsonError::Unsupported(nodes) => {
+                f.write_str("babel ast contains unsupported nodes: ")?;
+
+                for (i, node) in nodes.iter().enumerate() {
+                    if i != 0 {
+                        f.write_str(", ")?;
+                    }
+                    f.write_str(node.kind)?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for FromBabelJsonError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FromBabelJsonError::Json(err) => Some(err),
+            FromBabelJsonError::Unsupported(..) => None,
+        }
+    }
+}
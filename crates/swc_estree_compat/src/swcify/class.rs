@@ -1,19 +1,24 @@
 use swc_ecma_ast::{
-    ClassMember, Expr, Function, MemberExpr, MemberProp, MethodKind, ParamOrTsParamProp,
-    TsExprWithTypeArgs,
+    AutoAccessor, BlockStmt, ClassMember, Expr, Function, Key, MemberExpr, MemberProp, MethodKind,
+    ParamOrTsParamProp, TsExprWithTypeArgs,
 };
 use swc_estree_ast::{
-    ClassBody, ClassBodyEl, ClassImpl, ClassMethodKind, TSEntityName,
-    TSExpressionWithTypeArguments, TSQualifiedName,
+    ClassAccessorProperty, ClassAccessorPropertyKey, ClassBody, ClassBodyEl, ClassImpl,
+    ClassMethodKind, Param, TSDeclareMethod, TSEntityName, TSExpressionWithTypeArguments,
+    TSQualifiedName,
 };
 
-use super::Context;
+use super::{expr::swcify_prop_name, Context};
 use crate::swcify::Swcify;
 
 impl Swcify for ClassBody {
     type Output = Vec<ClassMember>;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        // There's no node for the body in swc, but we still need its comments,
+        // e.g. inner comments of an empty class.
+        ctx.span(&self.base);
+
         self.body.swcify(ctx)
     }
 }
@@ -27,68 +32,126 @@ impl Swcify for ClassBodyEl {
             ClassBodyEl::PrivateMethod(v) => v.swcify(ctx).into(),
             ClassBodyEl::Prop(v) => v.swcify(ctx).into(),
             ClassBodyEl::PrivateProp(v) => v.swcify(ctx).into(),
-            _ => {
-                unimplemented!("swcify: {:?}", self)
+            ClassBodyEl::AccessorProp(v) => v.swcify(ctx).into(),
+            ClassBodyEl::TSMethod(v) => v.swcify(ctx),
+            ClassBodyEl::TSIndex(v) => v.swcify(ctx).into(),
+            ClassBodyEl::StaticBlock(v) => v.swcify(ctx).into(),
+        }
+    }
+}
+
+impl Swcify for ClassMethodKind {
+    type Output = MethodKind;
+
+    fn swcify(self, _: &Context) -> Self::Output {
+        match self {
+            ClassMethodKind::Get => MethodKind::Getter,
+            ClassMethodKind::Set => MethodKind::Setter,
+            ClassMethodKind::Method => MethodKind::Method,
+            ClassMethodKind::Constructor => {
+                unreachable!("constructors are not converted to methods")
             }
         }
     }
 }
 
+/// Converts parameters of a constructor, which may be parameter properties.
+fn swcify_constructor_params(params: Vec<Param>, ctx: &Context) -> Vec<ParamOrTsParamProp> {
+    params
+        .into_iter()
+        .map(|param| match param {
+            Param::TSProp(v) => ParamOrTsParamProp::TsParamProp(v.swcify(ctx)),
+            _ => ParamOrTsParamProp::Param(param.swcify(ctx)),
+        })
+        .collect()
+}
+
 impl Swcify for swc_estree_ast::ClassMethod {
     type Output = swc_ecma_ast::ClassMember;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let key = swcify_prop_name(self.key, self.computed.unwrap_or_default(), ctx);
+
         match self.kind.unwrap_or(ClassMethodKind::Method) {
-            ClassMethodKind::Get | ClassMethodKind::Set | ClassMethodKind::Method => {
-                swc_ecma_ast::ClassMethod {
-                    span: ctx.span(&self.base),
-                    key: self.key.swcify(ctx),
-                    function: Function {
-                        params: self.params.swcify(ctx),
-                        decorators: self.decorators.swcify(ctx).unwrap_or_default(),
-                        span: ctx.span(&self.base),
-                        body: Some(self.body.swcify(ctx)),
-                        is_generator: self.generator.unwrap_or_default(),
-                        is_async: self.is_async.unwrap_or_default(),
-                        type_params: self.type_parameters.swcify(ctx).flatten().map(Box::new),
-                        return_type: self.return_type.swcify(ctx).flatten().map(Box::new),
-                        ..Default::default()
-                    }
-                    .into(),
-                    kind: self
-                        .kind
-                        .map(|kind| match kind {
-                            ClassMethodKind::Get => MethodKind::Getter,
-                            ClassMethodKind::Set => MethodKind::Setter,
-                            ClassMethodKind::Method => MethodKind::Getter,
-                            ClassMethodKind::Constructor => {
-                                unreachable!()
-                            }
-                        })
-                        .unwrap_or(MethodKind::Method),
-                    is_static: self.is_static.unwrap_or_default(),
-                    accessibility: self.accessibility.swcify(ctx),
-                    is_abstract: self.is_abstract.unwrap_or_default(),
-                    is_optional: self.optional.unwrap_or_default(),
-                    is_override: false,
+            ClassMethodKind::Constructor => swc_ecma_ast::Constructor {
+                span,
+                key,
+                params: swcify_constructor_params(self.params, ctx),
+                body: Some(self.body.swcify(ctx)),
+                accessibility: self.accessibility.swcify(ctx),
+                is_optional: self.optional.unwrap_or_default(),
+                ..Default::default()
+            }
+            .into(),
+            kind => swc_ecma_ast::ClassMethod {
+                span,
+                key,
+                function: Function {
+                    params: self.params.swcify(ctx),
+                    decorators: self.decorators.swcify(ctx).unwrap_or_default(),
+                    span,
+                    body: Some(self.body.swcify(ctx)),
+                    is_generator: self.generator.unwrap_or_default(),
+                    is_async: self.is_async.unwrap_or_default(),
+                    type_params: self.type_parameters.swcify(ctx).flatten().map(Box::new),
+                    return_type: self.return_type.swcify(ctx).flatten().map(Box::new),
+                    ..Default::default()
                 }
-                .into()
+                .into(),
+                kind: kind.swcify(ctx),
+                is_static: self.is_static.unwrap_or_default(),
+                accessibility: self.accessibility.swcify(ctx),
+                is_abstract: self.is_abstract.unwrap_or_default(),
+                is_optional: self.optional.unwrap_or_default(),
+                is_override: self.is_override.unwrap_or_default(),
             }
+            .into(),
+        }
+    }
+}
+
+impl Swcify for TSDeclareMethod {
+    type Output = ClassMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let key = swcify_prop_name(self.key, self.computed.unwrap_or_default(), ctx);
+
+        match self.kind.unwrap_or(ClassMethodKind::Method) {
             ClassMethodKind::Constructor => swc_ecma_ast::Constructor {
-                span: ctx.span(&self.base),
-                key: self.key.swcify(ctx),
-                params: self
-                    .params
-                    .into_iter()
-                    .map(|v| v.swcify(ctx))
-                    .map(ParamOrTsParamProp::Param)
-                    .collect(),
-                body: Some(self.body.swcify(ctx)),
+                span,
+                key,
+                params: swcify_constructor_params(self.params, ctx),
+                body: None,
                 accessibility: self.accessibility.swcify(ctx),
                 is_optional: self.optional.unwrap_or_default(),
                 ..Default::default()
             }
             .into(),
+            kind => swc_ecma_ast::ClassMethod {
+                span,
+                key,
+                function: Function {
+                    params: self.params.swcify(ctx),
+                    decorators: self.decorators.swcify(ctx).unwrap_or_default(),
+                    span,
+                    body: None,
+                    is_generator: self.generator.unwrap_or_default(),
+                    is_async: self.is_async.unwrap_or_default(),
+                    type_params: self.type_parameters.swcify(ctx).flatten().map(Box::new),
+                    return_type: self.return_type.swcify(ctx).flatten().map(Box::new),
+                    ..Default::default()
+                }
+                .into(),
+                kind: kind.swcify(ctx),
+                is_static: self.is_static.unwrap_or_default(),
+                accessibility: self.accessibility.swcify(ctx),
+                is_abstract: self.is_abstract.unwrap_or_default(),
+                is_optional: self.optional.unwrap_or_default(),
+                is_override: self.is_override.unwrap_or_default(),
+            }
+            .into(),
         }
     }
 }
@@ -97,13 +160,15 @@ impl Swcify for swc_estree_ast::ClassPrivateMethod {
     type Output = swc_ecma_ast::PrivateMethod;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
         swc_ecma_ast::PrivateMethod {
-            span: ctx.span(&self.base),
+            span,
             key: self.key.swcify(ctx),
             function: Function {
                 params: self.params.swcify(ctx),
                 decorators: self.decorators.swcify(ctx).unwrap_or_default(),
-                span: ctx.span(&self.base),
+                span,
                 body: Some(self.body.swcify(ctx)),
                 is_generator: self.generator.unwrap_or_default(),
                 is_async: self.is_async.unwrap_or_default(),
@@ -112,14 +177,7 @@ impl Swcify for swc_estree_ast::ClassPrivateMethod {
                 ..Default::default()
             }
             .into(),
-            kind: match self.kind.unwrap_or(ClassMethodKind::Method) {
-                ClassMethodKind::Get => MethodKind::Getter,
-                ClassMethodKind::Set => MethodKind::Setter,
-                ClassMethodKind::Method => MethodKind::Getter,
-                ClassMethodKind::Constructor => {
-                    unreachable!()
-                }
-            },
+            kind: self.kind.unwrap_or(ClassMethodKind::Method).swcify(ctx),
             is_static: self.is_static.unwrap_or_default(),
             accessibility: self.accessibility.swcify(ctx),
             is_abstract: self.is_abstract.unwrap_or_default(),
@@ -133,10 +191,11 @@ impl Swcify for swc_estree_ast::ClassProperty {
     type Output = swc_ecma_ast::ClassProp;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        let key = self.key.swcify(ctx);
+        let span = ctx.span(&self.base);
+        let key = swcify_prop_name(self.key, self.computed.unwrap_or_default(), ctx);
 
         swc_ecma_ast::ClassProp {
-            span: ctx.span(&self.base),
+            span,
             key,
             value: self.value.swcify(ctx),
             type_ann: self.type_annotation.swcify(ctx).flatten().map(Box::new),
@@ -145,7 +204,7 @@ impl Swcify for swc_estree_ast::ClassProperty {
             accessibility: self.accessibility.swcify(ctx),
             is_abstract: self.is_abstract.unwrap_or_default(),
             is_optional: self.optional.unwrap_or_default(),
-            is_override: false,
+            is_override: self.is_override.unwrap_or_default(),
             readonly: self.readonly.unwrap_or_default(),
             declare: self.declare.unwrap_or_default(),
             definite: self.definite.unwrap_or_default(),
@@ -162,8 +221,8 @@ impl Swcify for swc_estree_ast::ClassPrivateProperty {
             key: self.key.swcify(ctx),
             value: self.value.swcify(ctx),
             type_ann: self.type_annotation.swcify(ctx).flatten().map(Box::new),
-            is_static: false,
-            decorators: Default::default(),
+            is_static: self.static_any.as_bool().unwrap_or_default(),
+            decorators: self.decorators.swcify(ctx).unwrap_or_default(),
             accessibility: Default::default(),
             is_optional: false,
             is_override: false,
@@ -174,6 +233,55 @@ impl Swcify for swc_estree_ast::ClassPrivateProperty {
     }
 }
 
+impl Swcify for ClassAccessorProperty {
+    type Output = AutoAccessor;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let key = match self.key {
+            ClassAccessorPropertyKey::Private(v) => Key::Private(v.swcify(ctx)),
+            ClassAccessorPropertyKey::Key(v) => {
+                Key::Public(swcify_prop_name(v, self.computed.unwrap_or_default(), ctx))
+            }
+        };
+
+        AutoAccessor {
+            span,
+            key,
+            value: self.value.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx).flatten().map(Box::new),
+            is_static: self.is_static.unwrap_or_default(),
+            decorators: self.decorators.swcify(ctx).unwrap_or_default(),
+            accessibility: self.accessibility.swcify(ctx),
+            is_abstract: self.is_abstract.unwrap_or_default(),
+            is_override: self.is_override.unwrap_or_default(),
+            definite: self.definite.unwrap_or_default(),
+        }
+    }
+}
+
+impl Swcify for swc_estree_ast::StaticBlock {
+    type Output = swc_ecma_ast::StaticBlock;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        swc_ecma_ast::StaticBlock {
+            span,
+            body: BlockStmt {
+                span,
+                stmts: self
+                    .body
+                    .swcify(ctx)
+                    .into_iter()
+                    .map(|v| v.expect_stmt())
+                    .collect(),
+                ..Default::default()
+            },
+        }
+    }
+}
+
 impl Swcify for ClassImpl {
     type Output = TsExprWithTypeArgs;
 
@@ -1,22 +1,71 @@
-use swc_common::{sync::Lrc, BytePos, FileName, SourceFile, SourceMap, Span, DUMMY_SP};
-use swc_estree_ast::{BaseNode, LineCol, Loc};
+use std::{fmt, mem::take};
+
+use rustc_hash::FxHashSet;
+use swc_common::{
+    comments::{Comment, CommentKind, Comments},
+    sync::{Lock, Lrc},
+    BytePos, FileName, SourceFile, SourceMap, Span, DUMMY_SP,
+};
+use swc_estree_ast::{BaseNode, Comment as BabelComment, LineCol, Loc};
 use swc_node_comments::SwcComments;
 
 pub struct Context {
     #[allow(unused)]
     pub(crate) cm: Lrc<SourceMap>,
     pub(crate) fm: Lrc<SourceFile>,
-    #[allow(unused)]
     pub(crate) comments: SwcComments,
+    /// Start positions of comments which are already added to `comments`.
+    ///
+    /// Babel attaches a comment to multiple nodes, e.g. as a trailing comment
+    /// of a statement and as a leading comment of the next one.
+    seen_comments: Lock<FxHashSet<BytePos>>,
+    unsupported: Lock<Vec<UnsupportedNode>>,
+}
+
+/// A babel node which can't be represented in swc ast, like a bind
+/// expression.
+///
+/// The node is replaced with a placeholder, like
+/// [Invalid](swc_ecma_ast::Invalid).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedNode {
+    pub span: Span,
+    pub kind: &'static str,
+}
+
+impl fmt::Display for UnsupportedNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "swc does not support {}", self.kind)
+    }
 }
 
 impl Context {
+    /// `line` is 1-based and `column` is counted in UTF-16 code units, like
+    /// babel.
     fn locate_line_col(&self, loc: LineCol) -> BytePos {
-        if let Some(&line_start) = self.fm.analyze().lines.get(loc.line) {
-            line_start + BytePos(loc.column as _)
-        } else {
-            BytePos(0)
-        }
+        let analysis = self.fm.analyze();
+
+        let Some(&line_start) = loc
+            .line
+            .checked_sub(1)
+            .and_then(|line| analysis.lines.get(line))
+        else {
+            return BytePos::DUMMY;
+        };
+
+        let line_offset = (line_start - self.fm.start_pos).0 as usize;
+        let mut utf16_column = 0;
+        let column = self.fm.src[line_offset..]
+            .char_indices()
+            .find(|&(_, c)| {
+                let found = utf16_column >= loc.column;
+                utf16_column += c.len_utf16();
+                found
+            })
+            .map(|(offset, _)| offset)
+            .unwrap_or(self.fm.src.len() - line_offset);
+
+        line_start + BytePos(column as _)
     }
 
     fn locate_loc(&self, loc: Option<Loc>) -> Span {
@@ -31,24 +80,111 @@ impl Context {
         Span::new(start, end)
     }
 
+    /// Converts an offset in UTF-16 code units, which is used by babel, to a
+    /// [BytePos].
+    fn locate_offset(&self, offset: u32) -> BytePos {
+        let mut pos = self.fm.start_pos + BytePos(offset);
+
+        for mbc in &self.fm.analyze().multibyte_chars {
+            if mbc.pos >= pos {
+                break;
+            }
+
+            pos = pos + BytePos(mbc.byte_to_char_diff() as _);
+        }
+
+        pos
+    }
+
+    /// Returns the span of `node`, and adds the comments attached to it to
+    /// the comment map.
     pub(crate) fn span(&self, node: &BaseNode) -> Span {
-        let span = self.locate_loc(node.loc);
+        let span = match (node.start, node.end) {
+            (Some(start), Some(end)) => {
+                Span::new(self.locate_offset(start), self.locate_offset(end))
+            }
+            _ => self.locate_loc(node.loc),
+        };
 
         if !span.is_dummy() {
-            return span;
+            self.add_comments(span, node);
+        }
+
+        span
+    }
+
+    fn add_comments(&self, span: Span, node: &BaseNode) {
+        let leading = self.convert_comments(&node.leading_comments);
+        if !leading.is_empty() {
+            self.comments.add_leading_comments(span.lo, leading);
         }
 
-        let start = node
-            .start
-            .map(|offset| self.fm.start_pos + BytePos(offset as _))
-            .unwrap_or(BytePos::DUMMY);
+        let trailing = self.convert_comments(&node.trailing_comments);
+        if !trailing.is_empty() {
+            self.comments.add_trailing_comments(span.hi, trailing);
+        }
 
-        let end = node
-            .end
-            .map(|offset| self.fm.start_pos + BytePos(offset as _))
-            .unwrap_or(BytePos::DUMMY);
+        // Inner comments are leading comments of the closing token, e.g. `}`.
+        let inner = self.convert_comments(&node.inner_comments);
+        if !inner.is_empty() {
+            self.comments
+                .add_leading_comments(span.hi - BytePos(1), inner);
+        }
+    }
 
-        Span::new(start, end)
+    /// Records a node which can't be converted, and returns `span` so the
+    /// caller can use it for a placeholder.
+    pub(crate) fn unsupported(&self, span: Span, kind: &'static str) -> Span {
+        self.unsupported.lock().push(UnsupportedNode { span, kind });
+        span
+    }
+
+    /// Returns the nodes which were replaced with placeholders since the last
+    /// call.
+    pub fn take_unsupported(&self) -> Vec<UnsupportedNode> {
+        take(&mut *self.unsupported.lock())
+    }
+
+    /// Returns the source code of `span`, e.g. to recover `raw` of literals.
+    pub(crate) fn source_text(&self, span: Span) -> Option<&str> {
+        if span.is_dummy() || span.lo < self.fm.start_pos || span.hi > self.fm.end_pos {
+            return None;
+        }
+
+        let lo = (span.lo - self.fm.start_pos).0 as usize;
+        let hi = (span.hi - self.fm.start_pos).0 as usize;
+
+        self.fm.src.get(lo..hi)
+    }
+
+    /// Converts comments which are not added yet.
+    pub(crate) fn convert_comments(&self, comments: &[BabelComment]) -> Vec<Comment> {
+        let mut seen = self.seen_comments.lock();
+
+        comments
+            .iter()
+            .filter_map(|comment| {
+                let (kind, comment) = match comment {
+                    BabelComment::Line(c) => (CommentKind::Line, c),
+                    BabelComment::Block(c) => (CommentKind::Block, c),
+                };
+
+                let span = Span::new(
+                    self.locate_offset(comment.start),
+                    self.locate_offset(comment.end),
+                );
+
+                if !seen.insert(span.lo) {
+                    return None;
+                }
+
+                Some(Comment {
+                    kind,
+                    span,
+                    text: comment.value.clone(),
+                })
+            })
+            .collect()
     }
 
     /// This accepts source string because the spans of an ast node of swc are
@@ -65,6 +201,12 @@ impl Context {
         comments: SwcComments,
         fm: Lrc<SourceFile>,
     ) -> Self {
-        Self { cm, comments, fm }
+        Self {
+            cm,
+            comments,
+            fm,
+            seen_comments: Default::default(),
+            unsupported: Default::default(),
+        }
     }
 }
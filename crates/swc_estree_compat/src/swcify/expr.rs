@@ -1,33 +1,35 @@
 use swc_common::Spanned;
 use swc_ecma_ast::{
     op, ArrayLit, ArrowExpr, AssignExpr, AwaitExpr, BinExpr, BinaryOp, BindingIdent,
-    BlockStmtOrExpr, CallExpr, Callee, ClassExpr, ComputedPropName, CondExpr, Expr, ExprOrSpread,
-    FnExpr, Function, Ident, Import, JSXAttr, JSXAttrOrSpread, JSXAttrValue, JSXEmptyExpr, JSXExpr,
-    JSXExprContainer, JSXMemberExpr, JSXObject, KeyValueProp, Lit, MemberExpr, MemberProp,
-    MetaPropExpr, MetaPropKind, MethodProp, NewExpr, ObjectLit, OptCall, OptChainBase,
-    OptChainExpr, ParenExpr, Prop, PropName, PropOrSpread, SeqExpr, SpreadElement, SuperProp,
-    SuperPropExpr, TaggedTpl, ThisExpr, TsAsExpr, TsNonNullExpr, TsTypeAssertion,
+    BlockStmtOrExpr, CallExpr, Callee, ClassExpr, ComputedPropName, CondExpr, DoExpr, Expr,
+    ExprOrSpread, FnExpr, Function, GetterProp, Ident, Import, Invalid, JSXAttr, JSXAttrOrSpread,
+    JSXAttrValue, JSXEmptyExpr, JSXExpr, JSXExprContainer, JSXMemberExpr, JSXObject, KeyValueProp,
+    Lit, MemberExpr, MemberProp, MetaPropExpr, MetaPropKind, MethodProp, NewExpr, ObjectLit,
+    OptCall, OptChainBase, OptChainExpr, ParenExpr, Prop, PropName, PropOrSpread, SeqExpr,
+    SetterProp, SpreadElement, SuperProp, SuperPropExpr, TaggedTpl, ThisExpr, TopicExpr, TsAsExpr,
+    TsConstAssertion, TsInstantiation, TsNonNullExpr, TsSatisfiesExpr, TsTypeAssertion,
     TsTypeParamInstantiation, UnaryExpr, UnaryOp, UpdateExpr, YieldExpr,
 };
 use swc_estree_ast::{
     Arg, ArrayExprEl, ArrayExpression, ArrowFuncExprBody, ArrowFunctionExpression,
-    AssignmentExpression, AwaitExpression, BinaryExprLeft, BinaryExprOp, BinaryExpression,
-    BindExpression, CallExpression, Callee as BabelCallee, ClassExpression, ConditionalExpression,
-    DoExpression, Expression, FunctionExpression, Identifier, Import as BabelImport, JSXAttrVal,
-    JSXAttribute, JSXEmptyExpression, JSXExprContainerExpr, JSXExpressionContainer,
-    JSXMemberExprObject, JSXMemberExpression, JSXSpreadAttribute, Literal, LogicalExprOp,
-    LogicalExpression, MemberExprProp, MemberExpression, MetaProperty, ModuleExpression,
-    NewExpression, ObjectExprProp, ObjectExpression, ObjectKey, ObjectMethod, ObjectPropVal,
-    ObjectProperty, OptionalCallExpression, OptionalMemberExprProp, OptionalMemberExpression,
-    ParenthesizedExpression, PatternLike, PipelinePrimaryTopicReference, RecordExpression,
-    SequenceExpression, TSAsExpression, TSNonNullExpression, TSTypeAssertion,
-    TaggedTemplateExprTypeParams, TaggedTemplateExpression, ThisExpression, TupleExpression,
-    TypeCastExpression, UnaryExprOp, UnaryExpression, UpdateExprOp, UpdateExpression,
-    YieldExpression,
+    AssignmentExpression, AwaitExpression, BaseNode, BinaryExprLeft, BinaryExprOp,
+    BinaryExpression, BindExpression, CallExpression, Callee as BabelCallee, ClassExpression,
+    ConditionalExpression, DoExpression, Expression, FunctionExpression, Identifier,
+    Import as BabelImport, JSXAttrVal, JSXAttribute, JSXEmptyExpression, JSXExprContainerExpr,
+    JSXExpressionContainer, JSXMemberExprObject, JSXMemberExpression, JSXSpreadAttribute, Literal,
+    LogicalExprOp, LogicalExpression, MemberExprProp, MemberExpression, MetaProperty,
+    ModuleExpression, NewExpression, ObjectExprProp, ObjectExpression, ObjectKey, ObjectMethod,
+    ObjectMethodKind, ObjectPropVal, ObjectProperty, OptionalCallExpression,
+    OptionalMemberExprProp, OptionalMemberExpression, ParenthesizedExpression, PatternLike,
+    PipelinePrimaryTopicReference, RecordExpression, SequenceExpression, TSAsExpression,
+    TSEntityName, TSInstantiationExpression, TSNonNullExpression, TSSatisfiesExpression, TSType,
+    TSTypeAssertion, TSTypeReference, TaggedTemplateExprTypeParams, TaggedTemplateExpression,
+    ThisExpression, TupleExpression, TypeCastExpression, UnaryExprOp, UnaryExpression,
+    UpdateExprOp, UpdateExpression, YieldExpression,
 };
 
 use super::Context;
-use crate::{swcify::Swcify, Never};
+use crate::swcify::Swcify;
 
 impl Swcify for Expression {
     type Output = Box<Expr>;
@@ -53,14 +55,16 @@ impl Swcify for Expression {
             Expression::Sequence(e) => e.swcify(ctx).into(),
             Expression::Parenthesized(e) => e.swcify(ctx).into(),
             Expression::This(e) => e.swcify(ctx).into(),
-            Expression::Unary(e) => e.swcify(ctx).into(),
+            Expression::Unary(e) => e.swcify(ctx),
             Expression::Update(e) => e.swcify(ctx).into(),
             Expression::ArrowFunc(e) => e.swcify(ctx).into(),
             Expression::Class(e) => e.swcify(ctx).into(),
             Expression::MetaProp(e) => e.swcify(ctx).into(),
-            Expression::Super(..) => unreachable!(),
+            Expression::Super(e) => unsupported(ctx, &e.base, "standalone `super`"),
             Expression::TaggedTemplate(e) => e.swcify(ctx).into(),
-            Expression::TemplateLiteral(e) => e.swcify(ctx).into(),
+            Expression::TemplateLiteral(e) | Expression::Literal(Literal::Template(e)) => {
+                e.swcify(ctx).into()
+            }
             Expression::Yield(e) => e.swcify(ctx).into(),
             Expression::Await(e) => e.swcify(ctx).into(),
             Expression::Literal(Literal::BigInt(e)) => e.swcify(ctx).into(),
@@ -69,16 +73,31 @@ impl Swcify for Expression {
             Expression::JSXElement(e) => return e.swcify(ctx).into(),
             Expression::JSXFragment(e) => e.swcify(ctx).into(),
             Expression::Literal(Literal::Decimal(e)) => e.swcify(ctx).into(),
-            Expression::TSAs(e) => e.swcify(ctx).into(),
+            Expression::TSAs(e) => e.swcify(ctx),
+            Expression::TSSatisfies(e) => e.swcify(ctx).into(),
+            Expression::TSInstantiation(e) => e.swcify(ctx).into(),
             Expression::TSTypeAssertion(e) => e.swcify(ctx).into(),
             Expression::TSNonNull(e) => e.swcify(ctx).into(),
-            _ => {
-                unimplemented!("swcify: {:?}", self)
-            }
+            Expression::Do(e) => e.swcify(ctx).into(),
+            Expression::TypeCast(e) => return e.swcify(ctx),
+            Expression::PipelinePrimaryTopicRef(e) => e.swcify(ctx).into(),
+            Expression::Import(e) => unsupported(ctx, &e.base, "standalone `import`"),
+            Expression::Bind(e) => e.swcify(ctx),
+            Expression::Record(e) => e.swcify(ctx),
+            Expression::Tuple(e) => e.swcify(ctx),
+            Expression::Module(e) => e.swcify(ctx),
         })
     }
 }
 
+/// Records `node` as unsupported and returns a placeholder for it.
+fn unsupported(ctx: &Context, node: &BaseNode, kind: &'static str) -> Expr {
+    Invalid {
+        span: ctx.unsupported(ctx.span(node), kind),
+    }
+    .into()
+}
+
 impl Swcify for ArrayExpression {
     type Output = ArrayLit;
 
@@ -368,6 +387,14 @@ impl Swcify for MemberExpression {
                 obj: s.swcify(ctx),
                 prop: match (*self.property, self.computed) {
                     (MemberExprProp::Id(i), false) => SuperProp::Ident(i.swcify(ctx).into()),
+                    // A computed identifier, e.g. `super[b]`
+                    (MemberExprProp::Id(i), true) => {
+                        let expr: Box<Expr> = i.swcify(ctx).into();
+                        SuperProp::Computed(ComputedPropName {
+                            span: expr.span(),
+                            expr,
+                        })
+                    }
                     (MemberExprProp::Expr(e), true) => {
                         let expr = e.swcify(ctx);
                         SuperProp::Computed(ComputedPropName {
@@ -387,6 +414,14 @@ impl Swcify for MemberExpression {
                     (MemberExprProp::PrivateName(e), false) => {
                         MemberProp::PrivateName(e.swcify(ctx))
                     }
+                    // A computed identifier, e.g. `a[b]`
+                    (MemberExprProp::Id(i), true) => {
+                        let expr: Box<Expr> = i.swcify(ctx).into();
+                        MemberProp::Computed(ComputedPropName {
+                            span: expr.span(),
+                            expr,
+                        })
+                    }
                     (MemberExprProp::Expr(e), true) => {
                         let expr = e.swcify(ctx);
                         MemberProp::Computed(ComputedPropName {
@@ -406,22 +441,38 @@ impl Swcify for NewExpression {
     type Output = NewExpr;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        NewExpr {
-            span: ctx.span(&self.base),
-            callee: match self.callee {
-                BabelCallee::V8Id(..) => {
-                    unreachable!()
-                }
-                BabelCallee::Expr(e) => e.swcify(ctx),
-            },
-            args: Some(
+        let span = ctx.span(&self.base);
+        let callee = match self.callee {
+            BabelCallee::V8Id(..) => {
+                unreachable!()
+            }
+            BabelCallee::Expr(e) => e.swcify(ctx),
+        };
+        let type_args: Option<Box<TsTypeParamInstantiation>> =
+            self.type_parameters.swcify(ctx).map(From::from);
+
+        // Babel does not distinguish `new Foo` from `new Foo()`, so we check if the
+        // expression ends with the callee or the type arguments.
+        let end = type_args
+            .as_ref()
+            .map_or_else(|| callee.span_hi(), |type_args| type_args.span.hi);
+        let args = if self.arguments.is_empty() && end == span.hi {
+            None
+        } else {
+            Some(
                 self.arguments
                     .swcify(ctx)
                     .into_iter()
                     .map(|v| v.expect("failed to swcify arguments"))
                     .collect(),
-            ),
-            type_args: self.type_parameters.swcify(ctx).map(From::from),
+            )
+        };
+
+        NewExpr {
+            span,
+            callee,
+            args,
+            type_args,
             ..Default::default()
         }
     }
@@ -443,8 +494,8 @@ impl Swcify for ObjectExprProp {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            ObjectExprProp::Method(m) => PropOrSpread::Prop(Box::new(Prop::Method(m.swcify(ctx)))),
-            ObjectExprProp::Prop(p) => PropOrSpread::Prop(Box::new(Prop::KeyValue(p.swcify(ctx)))),
+            ObjectExprProp::Method(m) => PropOrSpread::Prop(Box::new(m.swcify(ctx))),
+            ObjectExprProp::Prop(p) => PropOrSpread::Prop(Box::new(p.swcify(ctx))),
             ObjectExprProp::Spread(p) => PropOrSpread::Spread(SpreadElement {
                 // TODO: Use exact span
                 dot3_token: ctx.span(&p.base),
@@ -455,21 +506,46 @@ impl Swcify for ObjectExprProp {
 }
 
 impl Swcify for ObjectMethod {
-    type Output = MethodProp;
+    type Output = Prop;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        MethodProp {
-            key: self.key.swcify(ctx),
-            function: Box::new(Function {
-                params: self.params.swcify(ctx),
-                decorators: self.decorator.swcify(ctx).unwrap_or_default(),
-                span: ctx.span(&self.base),
+        let span = ctx.span(&self.base);
+        let key = swcify_prop_name(self.key, self.computed, ctx);
+
+        match self.kind {
+            ObjectMethodKind::Get => Prop::Getter(GetterProp {
+                span,
+                key,
+                type_ann: self.return_type.swcify(ctx).flatten().map(Box::new),
                 body: Some(self.body.swcify(ctx)),
-                is_generator: self.generator.unwrap_or(false),
-                is_async: self.is_async.unwrap_or(false),
-                type_params: self.type_parameters.swcify(ctx).flatten().map(Box::new),
-                return_type: self.return_type.swcify(ctx).flatten().map(Box::new),
-                ..Default::default()
+            }),
+            ObjectMethodKind::Set => Prop::Setter(SetterProp {
+                span,
+                key,
+                this_param: None,
+                param: Box::new(
+                    self.params
+                        .into_iter()
+                        .next()
+                        .expect("setters should have a parameter")
+                        .swcify(ctx)
+                        .pat,
+                ),
+                body: Some(self.body.swcify(ctx)),
+            }),
+            ObjectMethodKind::Method => Prop::Method(MethodProp {
+                key,
+                function: Box::new(Function {
+                    params: self.params.swcify(ctx),
+                    decorators: self.decorator.swcify(ctx).unwrap_or_default(),
+                    span,
+                    body: Some(self.body.swcify(ctx)),
+                    is_generator: self.generator.unwrap_or(false),
+                    is_async: self.is_async.unwrap_or(false),
+                    type_params: self.type_parameters.swcify(ctx).flatten().map(Box::new),
+                    return_type: self.return_type.swcify(ctx).flatten().map(Box::new),
+                    ..Default::default()
+                }),
             }),
         }
     }
@@ -505,22 +581,51 @@ impl Swcify for ObjectKey {
     }
 }
 
+/// Converts the key of a property, which is `[key]` if `computed` is true.
+pub(crate) fn swcify_prop_name(key: ObjectKey, computed: bool, ctx: &Context) -> PropName {
+    if !computed {
+        return key.swcify(ctx);
+    }
+
+    let expr: Box<Expr> = match key {
+        ObjectKey::Id(v) => v.swcify(ctx).id.into(),
+        ObjectKey::String(v) => v.swcify(ctx).into(),
+        ObjectKey::Numeric(v) => v.swcify(ctx).into(),
+        ObjectKey::Expr(v) => v.swcify(ctx),
+    };
+
+    PropName::Computed(ComputedPropName {
+        span: expr.span(),
+        expr,
+    })
+}
+
 impl Swcify for ObjectProperty {
-    type Output = KeyValueProp;
+    type Output = Prop;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        KeyValueProp {
-            key: self.key.swcify(ctx),
+        if self.shorthand {
+            if let ObjectPropVal::Expr(e) = &self.value {
+                if let Expression::Id(i) = &**e {
+                    return Prop::Shorthand(i.clone().swcify(ctx).into());
+                }
+            }
+        }
+
+        Prop::KeyValue(KeyValueProp {
+            key: swcify_prop_name(self.key, self.computed, ctx),
             value: match self.value {
                 ObjectPropVal::Pattern(pat) => match pat {
                     PatternLike::Id(i) => i.swcify(ctx).into(),
-                    _ => {
-                        panic!("swc does not support ObjectPropVal::Pattern({:?})", pat)
+                    _ => Invalid {
+                        span: ctx
+                            .unsupported(pat.swcify(ctx).span(), "patterns as property values"),
                     }
+                    .into(),
                 },
                 ObjectPropVal::Expr(e) => e.swcify(ctx),
             },
-        }
+        })
     }
 }
 
@@ -557,28 +662,32 @@ impl Swcify for ThisExpression {
 }
 
 impl Swcify for UnaryExpression {
-    type Output = UnaryExpr;
+    type Output = Expr;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let Some(op) = self.operator.swcify(ctx) else {
+            return unsupported(ctx, &self.base, "throw expressions");
+        };
+
         UnaryExpr {
             span: ctx.span(&self.base),
-            op: self.operator.swcify(ctx),
+            op,
             arg: self.argument.swcify(ctx),
         }
+        .into()
     }
 }
 
 impl Swcify for UnaryExprOp {
-    type Output = UnaryOp;
+    type Output = Option<UnaryOp>;
 
     fn swcify(self, _: &Context) -> Self::Output {
-        match self {
+        // `throw` is not an unary operator of swc.
+        Some(match self {
             UnaryExprOp::Void => {
                 op!("void")
             }
-            UnaryExprOp::Throw => {
-                panic!("swc does not support throw expressions")
-            }
+            UnaryExprOp::Throw => return None,
             UnaryExprOp::Delete => {
                 op!("delete")
             }
@@ -597,7 +706,7 @@ impl Swcify for UnaryExprOp {
             UnaryExprOp::Typeof => {
                 op!("typeof")
             }
-        }
+        })
     }
 }
 
@@ -662,7 +771,11 @@ impl Swcify for ClassExpression {
                 super_class: self.super_class.swcify(ctx),
                 is_abstract: false,
                 type_params: self.type_parameters.swcify(ctx).flatten().map(Box::new),
-                super_type_params: self.super_type_parameters.swcify(ctx).map(Box::new),
+                super_type_params: self
+                    .super_type_parameters
+                    .swcify(ctx)
+                    .flatten()
+                    .map(Box::new),
                 implements: self.implements.swcify(ctx).unwrap_or_default(),
                 ..Default::default()
             }),
@@ -682,7 +795,7 @@ impl Swcify for MetaProperty {
                 span: ctx.span(&self.base),
             },
             ("import", "meta") => MetaPropExpr {
-                kind: MetaPropKind::NewTarget,
+                kind: MetaPropKind::ImportMeta,
                 span: ctx.span(&self.base),
             },
             _ => unreachable!("there are only two kind of meta prop"),
@@ -707,7 +820,7 @@ impl Swcify for TaggedTemplateExpression {
         TaggedTpl {
             span: ctx.span(&self.base),
             tag: self.tag.swcify(ctx),
-            type_params: self.type_parameters.swcify(ctx).map(From::from),
+            type_params: self.type_parameters.swcify(ctx).flatten().map(From::from),
             tpl: Box::new(self.quasi.swcify(ctx)),
             ..Default::default()
         }
@@ -715,12 +828,13 @@ impl Swcify for TaggedTemplateExpression {
 }
 
 impl Swcify for TaggedTemplateExprTypeParams {
-    type Output = TsTypeParamInstantiation;
+    type Output = Option<TsTypeParamInstantiation>;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TaggedTemplateExprTypeParams::Flow(_) => unimplemented!("flow types"),
-            TaggedTemplateExprTypeParams::TS(v) => v.swcify(ctx),
+            // Flow types are not preserved.
+            TaggedTemplateExprTypeParams::Flow(_) => None,
+            TaggedTemplateExprTypeParams::TS(v) => Some(v.swcify(ctx)),
         }
     }
 }
@@ -775,6 +889,14 @@ impl Swcify for OptionalMemberExpression {
                     (OptionalMemberExprProp::Id(i), false) => {
                         MemberProp::Ident(i.swcify(ctx).into())
                     }
+                    // A computed identifier, e.g. `a[b]`
+                    (OptionalMemberExprProp::Id(i), true) => {
+                        let expr: Box<Expr> = i.swcify(ctx).into();
+                        MemberProp::Computed(ComputedPropName {
+                            span: expr.span(),
+                            expr,
+                        })
+                    }
                     (OptionalMemberExprProp::Expr(e), true) => {
                         let expr = e.swcify(ctx);
                         MemberProp::Computed(ComputedPropName {
@@ -819,10 +941,11 @@ impl Swcify for OptionalCallExpression {
 }
 
 impl Swcify for TypeCastExpression {
-    type Output = Never;
+    type Output = Box<Expr>;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow type cast")
+    /// Flow types are not preserved, so this returns the expression itself.
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        self.expression.swcify(ctx)
     }
 }
 
@@ -1083,58 +1206,91 @@ impl Swcify for swc_estree_ast::JSXClosingFragment {
 }
 
 impl Swcify for BindExpression {
-    type Output = Never;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support bind expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        unsupported(ctx, &self.base, "bind expressions")
     }
 }
 
 impl Swcify for DoExpression {
-    type Output = Never;
+    type Output = DoExpr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support do expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        DoExpr {
+            span: ctx.span(&self.base),
+            body: self.body.swcify(ctx),
+        }
     }
 }
 
 impl Swcify for PipelinePrimaryTopicReference {
-    type Output = Never;
+    type Output = TopicExpr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support `PipelinePrimaryTopicReference`")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TopicExpr {
+            span: ctx.span(&self.base),
+        }
     }
 }
 
 impl Swcify for RecordExpression {
-    type Output = Never;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support record expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        unsupported(ctx, &self.base, "record expressions")
     }
 }
 
 impl Swcify for TupleExpression {
-    type Output = Never;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support tuple expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        unsupported(ctx, &self.base, "tuple expressions")
     }
 }
 
 impl Swcify for ModuleExpression {
-    type Output = Never;
+    type Output = Expr;
 
-    fn swcify(self, _: &Context) -> Self::Output {
-        panic!("swc does not support module expressions")
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        unsupported(ctx, &self.base, "module expressions")
     }
 }
 
 impl Swcify for TSAsExpression {
-    type Output = TsAsExpr;
+    type Output = Expr;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
-        TsAsExpr {
+        let span = ctx.span(&self.base);
+
+        match self.type_annotation {
+            // Babel represents `expr as const` as an `as` expression with a type reference to
+            // `const`.
+            TSType::TypeRef(TSTypeReference {
+                type_name: TSEntityName::Id(ref id),
+                type_parameters: None,
+                ..
+            }) if &*id.name == "const" => TsConstAssertion {
+                span,
+                expr: self.expression.swcify(ctx),
+            }
+            .into(),
+            type_ann => TsAsExpr {
+                span,
+                expr: self.expression.swcify(ctx),
+                type_ann: type_ann.swcify(ctx),
+            }
+            .into(),
+        }
+    }
+}
+
+impl Swcify for TSSatisfiesExpression {
+    type Output = TsSatisfiesExpr;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsSatisfiesExpr {
             span: ctx.span(&self.base),
             expr: self.expression.swcify(ctx),
             type_ann: self.type_annotation.swcify(ctx),
@@ -1142,6 +1298,18 @@ impl Swcify for TSAsExpression {
     }
 }
 
+impl Swcify for TSInstantiationExpression {
+    type Output = TsInstantiation;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInstantiation {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+            type_args: Box::new(self.type_parameters.swcify(ctx)),
+        }
+    }
+}
+
 impl Swcify for TSTypeAssertion {
     type Output = TsTypeAssertion;
 
@@ -1,4 +1,6 @@
-use swc_ecma_ast::{BigInt, Bool, Expr, Lit, Null, Number, Regex, Str, Tpl, TplElement};
+use swc_atoms::Atom;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{BigInt, Bool, Expr, Invalid, Lit, Null, Number, Regex, Str, Tpl, TplElement};
 use swc_estree_ast::{
     BigIntLiteral, BooleanLiteral, DecimalLiteral, Literal, NullLiteral, NumberLiteral,
     NumericLiteral, RegExpLiteral, StringLiteral, TemplateElement, TemplateLiteral,
@@ -18,7 +20,9 @@ impl Swcify for Literal {
             Literal::Null(v) => v.swcify(ctx).into(),
             Literal::Boolean(v) => v.swcify(ctx).into(),
             Literal::RegExp(v) => v.swcify(ctx).into(),
-            Literal::Template(..) => unreachable!(),
+            Literal::Template(..) => {
+                unreachable!("template literals are converted as expressions")
+            }
             Literal::BigInt(v) => v.swcify(ctx).into(),
             Literal::Decimal(v) => v.swcify(ctx).into(),
         }
@@ -41,11 +45,12 @@ impl Swcify for NumberLiteral {
     type Output = Number;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
         Number {
-            span: ctx.span(&self.base),
+            span,
             value: self.value,
-            // TODO improve me
-            raw: None,
+            raw: number_raw(span, ctx),
         }
     }
 }
@@ -54,15 +59,23 @@ impl Swcify for NumericLiteral {
     type Output = Number;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
         Number {
-            span: ctx.span(&self.base),
+            span,
             value: self.value,
-            // TODO improve me
-            raw: None,
+            raw: number_raw(span, ctx),
         }
     }
 }
 
+/// Babel does not store `raw` of numbers, so we get it from the source code.
+fn number_raw(span: Span, ctx: &Context) -> Option<Atom> {
+    ctx.source_text(span)
+        .filter(|raw| raw.starts_with(|c: char| c.is_ascii_digit() || c == '.'))
+        .map(Atom::from)
+}
+
 impl Swcify for NullLiteral {
     type Output = Null;
 
@@ -113,7 +126,13 @@ impl Swcify for TemplateLiteralExpr {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            TemplateLiteralExpr::TSType(..) => todo!(),
+            TemplateLiteralExpr::TSType(ty) => Invalid {
+                span: ctx.unsupported(
+                    ty.swcify(ctx).span(),
+                    "types in template literal expressions",
+                ),
+            }
+            .into(),
             TemplateLiteralExpr::Expr(v) => v.swcify(ctx),
         }
     }
@@ -136,15 +155,23 @@ impl Swcify for BigIntLiteral {
     type Output = BigInt;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+        let raw = if self.raw.is_empty() {
+            ctx.source_text(span)
+                .filter(|raw| raw.ends_with('n'))
+                .map(Atom::from)
+        } else {
+            Some(self.raw)
+        };
+
         BigInt {
-            span: ctx.span(&self.base),
+            span,
             value: self
                 .value
                 .parse()
                 .map(Box::new)
                 .expect("failed to parse the value of BigIntLiteral"),
-            // TODO improve me
-            raw: None,
+            raw,
         }
     }
 }
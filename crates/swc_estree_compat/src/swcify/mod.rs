@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-pub use self::ctx::Context;
+pub use self::ctx::{Context, UnsupportedNode};
 
 mod class;
 mod ctx;
@@ -1,4 +1,4 @@
-use swc_common::Spanned;
+use swc_common::{BytePos, Spanned};
 use swc_ecma_ast::*;
 use swc_estree_ast::{
     ArrayPattern, AssignmentPattern, AssignmentPatternLeft, LVal, ObjectPattern, ObjectPatternProp,
@@ -18,7 +18,13 @@ impl Swcify for LVal {
             LVal::AssignmentPat(e) => e.swcify(ctx).into(),
             LVal::ArrayPat(e) => e.swcify(ctx).into(),
             LVal::ObjectPat(e) => e.swcify(ctx).into(),
-            LVal::TSParamProp(..) => todo!(),
+            LVal::TSParamProp(p) => Invalid {
+                span: ctx.unsupported(
+                    ctx.span(&p.base),
+                    "parameter properties outside of constructors",
+                ),
+            }
+            .into(),
         }
     }
 }
@@ -31,9 +37,9 @@ impl Swcify for RestElement {
 
         RestPat {
             span,
-            dot3_token: span,
+            dot3_token: span.with_hi(span.lo + BytePos(3)),
             arg: Box::new(self.argument.swcify(ctx)),
-            type_ann: None,
+            type_ann: self.type_annotation.swcify(ctx).flatten().map(Box::new),
         }
     }
 }
@@ -71,7 +77,7 @@ impl Swcify for ArrayPattern {
             span: ctx.span(&self.base),
             elems: self.elements.swcify(ctx),
             optional: false,
-            type_ann: None,
+            type_ann: self.type_annotation.swcify(ctx).flatten().map(Box::new),
         }
     }
 }
@@ -98,7 +104,7 @@ impl Swcify for ObjectPattern {
             span: ctx.span(&self.base),
             props: self.properties.swcify(ctx),
             optional: false,
-            type_ann: None,
+            type_ann: self.type_annotation.swcify(ctx).flatten().map(Box::new),
         }
     }
 }
@@ -156,29 +162,49 @@ impl Swcify for swc_estree_ast::Param {
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
             swc_estree_ast::Param::Id(v) => {
+                let decorators = v.decorators.clone().swcify(ctx).unwrap_or_default();
                 let pat = v.swcify(ctx);
 
                 swc_ecma_ast::Param {
                     span: pat.span(),
-                    decorators: Default::default(),
+                    decorators,
                     pat: pat.into(),
                 }
             }
             swc_estree_ast::Param::Pat(v) => {
+                let decorators = match &v {
+                    swc_estree_ast::Pattern::Assignment(v) => &v.decorators,
+                    swc_estree_ast::Pattern::Array(v) => &v.decorators,
+                    swc_estree_ast::Pattern::Object(v) => &v.decorators,
+                }
+                .clone()
+                .swcify(ctx)
+                .unwrap_or_default();
                 let pat = v.swcify(ctx);
 
                 swc_ecma_ast::Param {
                     span: pat.span(),
-                    decorators: Default::default(),
+                    decorators,
                     pat,
                 }
             }
             swc_estree_ast::Param::Rest(v) => swc_ecma_ast::Param {
                 span: ctx.span(&v.base),
-                decorators: v.decorators.swcify(ctx).unwrap_or_default(),
-                pat: v.argument.swcify(ctx),
+                decorators: v.decorators.clone().swcify(ctx).unwrap_or_default(),
+                pat: v.swcify(ctx).into(),
             },
-            swc_estree_ast::Param::TSProp(..) => todo!(),
+            swc_estree_ast::Param::TSProp(p) => {
+                let span = ctx.unsupported(
+                    ctx.span(&p.base),
+                    "parameter properties outside of constructors",
+                );
+
+                swc_ecma_ast::Param {
+                    span,
+                    decorators: Default::default(),
+                    pat: Invalid { span }.into(),
+                }
+            }
         }
     }
 }
@@ -1 +1,95 @@
+use swc_common::{comments::Comments, BytePos};
+use swc_ecma_ast::{Module, Program, Script};
+use swc_estree_ast::{File, Program as BabelProgram, SrcType};
 
+use super::Context;
+use crate::swcify::Swcify;
+
+impl Swcify for File {
+    type Output = Program;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let program = self.program.swcify(ctx);
+
+        // Comments which are not attached to any node, e.g. comments in an empty
+        // function.
+        if let Some(comments) = self.comments {
+            for comment in ctx.convert_comments(&comments) {
+                match trailing_pos(ctx, comment.span.lo) {
+                    Some(pos) => ctx.comments.add_trailing(pos, comment),
+                    None => ctx
+                        .comments
+                        .add_leading(leading_pos(ctx, comment.span.hi), comment),
+                }
+            }
+        }
+
+        program
+    }
+}
+
+impl Swcify for BabelProgram {
+    type Output = Program;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        // Babel includes the trailing newline of the file.
+        let mut base = self.base;
+        base.end = base.end.map(|end| end.saturating_sub(1));
+        base.loc = None;
+
+        let span = ctx.span(&base);
+        let shebang = self.interpreter.map(|v| v.value);
+
+        match self.source_type {
+            SrcType::Module => Program::Module(Module {
+                span,
+                body: self.body.swcify(ctx),
+                shebang,
+            }),
+            SrcType::Script => Program::Script(Script {
+                span,
+                body: self
+                    .body
+                    .swcify(ctx)
+                    .into_iter()
+                    .map(|v| v.expect_stmt())
+                    .collect(),
+                shebang,
+            }),
+        }
+    }
+}
+
+/// Returns the end of the code before `pos` if it's on the same line.
+fn trailing_pos(ctx: &Context, pos: BytePos) -> Option<BytePos> {
+    let offset = (pos - ctx.fm.start_pos).0 as usize;
+    let before = ctx.fm.src[..offset].trim_end_matches([' ', '\t']);
+
+    if before.is_empty() || before.ends_with(['\n', '\r']) {
+        return None;
+    }
+
+    Some(ctx.fm.start_pos + BytePos(before.len() as u32))
+}
+
+/// Returns the start of the code after `pos`, skipping other comments.
+fn leading_pos(ctx: &Context, pos: BytePos) -> BytePos {
+    let src = &*ctx.fm.src;
+    let mut offset = (pos - ctx.fm.start_pos).0 as usize;
+
+    loop {
+        let rest = &src[offset..];
+        let trimmed = rest.trim_start();
+        offset += rest.len() - trimmed.len();
+
+        if trimmed.starts_with("//") {
+            offset += trimmed.find('\n').unwrap_or(trimmed.len());
+        } else if trimmed.starts_with("/*") {
+            offset += trimmed.find("*/").map_or(trimmed.len(), |end| end + 2);
+        } else {
+            break;
+        }
+    }
+
+    ctx.fm.start_pos + BytePos(offset as u32)
+}
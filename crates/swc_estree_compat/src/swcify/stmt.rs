@@ -1,29 +1,30 @@
-use swc_common::DUMMY_SP;
+use swc_common::{Spanned, DUMMY_SP};
 use swc_ecma_ast::{
-    BlockStmt, BreakStmt, ClassDecl, ClassExpr, ContinueStmt, DebuggerStmt, DefaultDecl,
-    DoWhileStmt, EmptyStmt, ExportAll, ExportDecl, ExportDefaultDecl, ExportDefaultExpr,
-    ExportNamedSpecifier, ExprStmt, FnDecl, FnExpr, ForHead, ForInStmt, ForOfStmt, ForStmt, IfStmt,
-    ImportDecl, ImportNamedSpecifier, ImportSpecifier, ImportStarAsSpecifier, KeyValueProp,
-    LabeledStmt, Lit, ModuleDecl, ModuleItem, NamedExport, ObjectLit, Pat, Prop, PropName,
-    PropOrSpread, ReturnStmt, SwitchStmt, ThrowStmt, TryStmt, TsExportAssignment, TsInterfaceDecl,
-    TsModuleDecl, TsTypeAliasDecl, VarDecl, VarDeclKind, VarDeclOrExpr, VarDeclarator, WhileStmt,
-    WithStmt,
+    BlockStmt, BreakStmt, Class, ClassDecl, ClassExpr, ContinueStmt, DebuggerStmt, Decl,
+    DefaultDecl, DoWhileStmt, EmptyStmt, ExportAll, ExportDecl, ExportDefaultDecl,
+    ExportDefaultExpr, ExportNamedSpecifier, ExprStmt, FnDecl, FnExpr, ForHead, ForInStmt,
+    ForOfStmt, ForStmt, Function, Ident, IfStmt, ImportDecl, ImportNamedSpecifier, ImportSpecifier,
+    ImportStarAsSpecifier, KeyValueProp, LabeledStmt, Lit, ModuleDecl, ModuleItem, NamedExport,
+    ObjectLit, Pat, Prop, PropName, PropOrSpread, ReturnStmt, Stmt, SwitchStmt, ThrowStmt, TryStmt,
+    UsingDecl, VarDecl, VarDeclKind, VarDeclOrExpr, VarDeclarator, WhileStmt, WithStmt,
 };
 use swc_estree_ast::{
     BlockStatement, BreakStatement, ClassDeclaration, ContinueStatement, DebuggerStatement,
-    DeclareClass, DeclareExportAllDeclaration, DeclareExportDeclaration, DeclareFunction,
-    DeclareInterface, DeclareModule, DeclareModuleExports, DeclareTypeAlias, DeclareVariable,
-    DoWhileStatement, EmptyStatement, ExportAllDeclaration, ExportDefaultDeclType,
-    ExportDefaultDeclaration, ExportKind, ExportNamedDeclaration, ExpressionStatement,
-    ForInStatement, ForOfStatement, ForStatement, ForStmtInit, ForStmtLeft, FunctionDeclaration,
-    IdOrString, IfStatement, ImportAttribute, ImportDeclaration, ImportKind,
-    ImportNamespaceSpecifier, ImportSpecifierType, LabeledStatement, ReturnStatement, Statement,
-    SwitchStatement, ThrowStatement, TryStatement, VariableDeclaration, VariableDeclarationKind,
-    VariableDeclarator, WhileStatement, WithStatement,
+    Declaration, DeclareClass, DeclareExportAllDeclaration, DeclareExportDeclaration,
+    DeclareFunction, DeclareInterface, DeclareModule, DeclareModuleExports, DeclareOpaqueType,
+    DeclareTypeAlias, DeclareVariable, DoWhileStatement, EmptyStatement, EnumDeclaration,
+    ExportAllDeclaration, ExportDefaultDeclType, ExportDefaultDeclaration, ExportKind,
+    ExportNamedDeclaration, ExpressionStatement, ForInStatement, ForOfStatement, ForStatement,
+    ForStmtInit, ForStmtLeft, FunctionDeclaration, IdOrString, IfStatement, ImportAttribute,
+    ImportDeclaration, ImportKind, ImportNamespaceSpecifier, ImportSpecifierType,
+    InterfaceDeclaration, LabeledStatement, OpaqueType, ReturnStatement, Statement,
+    SwitchStatement, ThrowStatement, TryStatement, TypeAlias, UsingDeclaration,
+    VariableDeclaration, VariableDeclarationKind, VariableDeclarator, WhileStatement,
+    WithStatement,
 };
 
 use super::Context;
-use crate::swcify::Swcify;
+use crate::swcify::{typescript::swcify_declared_function, Swcify};
 
 impl Swcify for BlockStatement {
     type Output = BlockStmt;
@@ -69,21 +70,47 @@ impl Swcify for Statement {
             Statement::ClassDecl(v) => v.swcify(ctx).into(),
             Statement::ExportAllDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
             Statement::ExportDefaultDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx)),
-            Statement::ExportNamedDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
+            Statement::ExportNamedDecl(v)
+                if v.declaration.as_deref().is_some_and(is_flow_type_only) =>
+            {
+                EmptyStmt {
+                    span: ctx.span(&v.base),
+                }
+                .into()
+            }
+            Statement::ExportNamedDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx)),
             Statement::ForOf(v) => v.swcify(ctx).into(),
             Statement::ImportDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
-            Statement::DeclClass(v) => v.swcify(ctx).into(),
-            Statement::DeclFunc(v) => v.swcify(ctx).into(),
-            Statement::DeclInterface(v) => v.swcify(ctx).into(),
-            Statement::DeclModule(v) => v.swcify(ctx).into(),
-            Statement::DeclareModuleExports(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
-            Statement::DeclTypeAlias(v) => v.swcify(ctx).into(),
-            Statement::DeclVar(v) => v.swcify(ctx).into(),
-            Statement::DeclExportDeclaration(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
+            // Flow types are not preserved.
+            Statement::DeclClass(DeclareClass { base, .. })
+            | Statement::DeclFunc(DeclareFunction { base, .. })
+            | Statement::DeclInterface(DeclareInterface { base, .. })
+            | Statement::DeclModule(DeclareModule { base, .. })
+            | Statement::DeclareModuleExports(DeclareModuleExports { base, .. })
+            | Statement::DeclTypeAlias(DeclareTypeAlias { base, .. })
+            | Statement::DeclOpaqueType(DeclareOpaqueType { base, .. })
+            | Statement::DeclVar(DeclareVariable { base, .. })
+            | Statement::DeclExportDeclaration(DeclareExportDeclaration { base, .. })
+            | Statement::InterfaceDecl(InterfaceDeclaration { base, .. })
+            | Statement::OpaqueType(OpaqueType { base, .. })
+            | Statement::TypeAlias(TypeAlias { base, .. }) => EmptyStmt {
+                span: ctx.span(&base),
+            }
+            .into(),
             Statement::DeclExportAllDeclaration(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
-            _ => {
-                todo!("swcify: {:?}", self)
+            Statement::EnumDecl(v) => EmptyStmt {
+                span: ctx.unsupported(ctx.span(&v.base), "flow enums"),
             }
+            .into(),
+            Statement::UsingDecl(v) => Stmt::from(Decl::from(v.swcify(ctx))).into(),
+            Statement::TSDeclFunc(v) => v.swcify(ctx).into(),
+            Statement::TSInterfaceDecl(v) => v.swcify(ctx).into(),
+            Statement::TSTypeAliasDecl(v) => v.swcify(ctx).into(),
+            Statement::TSEnumDecl(v) => v.swcify(ctx).into(),
+            Statement::TSModuleDecl(v) => v.swcify(ctx).into(),
+            Statement::TSImportEqualsDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
+            Statement::TSExportAssignment(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
+            Statement::TSNamespaceExportDecl(v) => ModuleItem::ModuleDecl(v.swcify(ctx).into()),
         }
     }
 }
@@ -205,24 +232,36 @@ impl Swcify for ForStmtInit {
 impl Swcify for FunctionDeclaration {
     type Output = FnDecl;
 
-    fn swcify(self, ctx: &Context) -> Self::Output {
+    fn swcify(mut self, ctx: &Context) -> Self::Output {
         FnDecl {
-            ident: self.id.swcify(ctx).map(|v| v.into()).unwrap(),
+            ident: self
+                .id
+                .take()
+                .expect("function declarations should have a name")
+                .swcify(ctx)
+                .into(),
             declare: false,
-            function: swc_ecma_ast::Function {
-                params: self.params.swcify(ctx),
-                decorators: Default::default(),
-                span: ctx.span(&self.base),
-                body: Some(self.body.swcify(ctx)),
-                is_generator: false,
-                is_async: self.is_async.unwrap_or_default(),
-                ..Default::default()
-            }
-            .into(),
+            function: swcify_function(self, ctx),
         }
     }
 }
 
+/// Converts a function declaration except its name, which is optional for
+/// `export default`.
+fn swcify_function(decl: FunctionDeclaration, ctx: &Context) -> Box<Function> {
+    Box::new(Function {
+        params: decl.params.swcify(ctx),
+        decorators: Default::default(),
+        span: ctx.span(&decl.base),
+        body: Some(decl.body.swcify(ctx)),
+        is_generator: decl.generator.unwrap_or_default(),
+        is_async: decl.is_async.unwrap_or_default(),
+        type_params: decl.type_parameters.swcify(ctx).flatten().map(Box::new),
+        return_type: decl.return_type.swcify(ctx).flatten().map(Box::new),
+        ..Default::default()
+    })
+}
+
 impl Swcify for IfStatement {
     type Output = IfStmt;
 
@@ -358,6 +397,18 @@ impl Swcify for VariableDeclaration {
     }
 }
 
+impl Swcify for UsingDeclaration {
+    type Output = UsingDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        UsingDecl {
+            span: ctx.span(&self.base),
+            is_await: false,
+            decls: self.declarations.swcify(ctx),
+        }
+    }
+}
+
 impl Swcify for VariableDeclarator {
     type Output = VarDeclarator;
 
@@ -398,23 +449,40 @@ impl Swcify for WithStatement {
 impl Swcify for ClassDeclaration {
     type Output = ClassDecl;
 
-    fn swcify(self, ctx: &Context) -> Self::Output {
+    fn swcify(mut self, ctx: &Context) -> Self::Output {
         ClassDecl {
-            ident: self.id.swcify(ctx).into(),
+            ident: self
+                .id
+                .take()
+                .expect("class declarations should have a name")
+                .swcify(ctx)
+                .into(),
             declare: self.declare.unwrap_or_default(),
-            class: swc_ecma_ast::Class {
-                span: ctx.span(&self.base),
-                decorators: self.decorators.swcify(ctx).unwrap_or_default(),
-                body: self.body.swcify(ctx),
-                super_class: self.super_class.swcify(ctx),
-                is_abstract: self.is_abstract.unwrap_or_default(),
-                ..Default::default()
-            }
-            .into(),
+            class: swcify_class(self, ctx),
         }
     }
 }
 
+/// Converts a class declaration except its name, which is optional for
+/// `export default`.
+fn swcify_class(decl: ClassDeclaration, ctx: &Context) -> Box<Class> {
+    Box::new(Class {
+        span: ctx.span(&decl.base),
+        decorators: decl.decorators.swcify(ctx).unwrap_or_default(),
+        body: decl.body.swcify(ctx),
+        super_class: decl.super_class.swcify(ctx),
+        is_abstract: decl.is_abstract.unwrap_or_default(),
+        type_params: decl.type_parameters.swcify(ctx).flatten().map(Box::new),
+        super_type_params: decl
+            .super_type_parameters
+            .swcify(ctx)
+            .flatten()
+            .map(Box::new),
+        implements: decl.implements.swcify(ctx).unwrap_or_default(),
+        ..Default::default()
+    })
+}
+
 impl Swcify for ExportAllDeclaration {
     type Output = ExportAll;
 
@@ -472,49 +540,61 @@ impl Swcify for ExportDefaultDeclaration {
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self.declaration {
-            ExportDefaultDeclType::Func(v) => {
-                let d = v.swcify(ctx);
-                ExportDefaultDecl {
-                    span: ctx.span(&self.base),
-                    decl: DefaultDecl::Fn(FnExpr {
-                        ident: Some(d.ident),
-                        function: d.function,
-                    }),
-                }
-                .into()
+            ExportDefaultDeclType::Func(mut v) => ExportDefaultDecl {
+                span: ctx.span(&self.base),
+                decl: DefaultDecl::Fn(FnExpr {
+                    ident: v.id.take().map(|id| id.swcify(ctx).into()),
+                    function: swcify_function(v, ctx),
+                }),
             }
-            ExportDefaultDeclType::Class(v) => {
-                let d = v.swcify(ctx);
-                ExportDefaultDecl {
-                    span: ctx.span(&self.base),
-                    decl: DefaultDecl::Class(ClassExpr {
-                        ident: Some(d.ident),
-                        class: d.class,
-                    }),
-                }
-                .into()
+            .into(),
+            ExportDefaultDeclType::Class(mut v) => ExportDefaultDecl {
+                span: ctx.span(&self.base),
+                decl: DefaultDecl::Class(ClassExpr {
+                    ident: v.id.take().map(|id| id.swcify(ctx).into()),
+                    class: swcify_class(v, ctx),
+                }),
+            }
+            .into(),
+            ExportDefaultDeclType::TSFunc(mut v) => ExportDefaultDecl {
+                span: ctx.span(&self.base),
+                decl: DefaultDecl::Fn(FnExpr {
+                    ident: v.id.take().map(|id| id.swcify(ctx).into()),
+                    function: swcify_declared_function(v, ctx),
+                }),
+            }
+            .into(),
+            ExportDefaultDeclType::TSInterface(v) => ExportDefaultDecl {
+                span: ctx.span(&self.base),
+                decl: DefaultDecl::TsInterfaceDecl(Box::new(v.swcify(ctx))),
             }
+            .into(),
             ExportDefaultDeclType::Expr(v) => ExportDefaultExpr {
                 span: ctx.span(&self.base),
                 expr: v.swcify(ctx),
             }
             .into(),
-            _ => {
-                todo!("swcify: {:?}", self)
-            }
         }
     }
 }
 
 impl Swcify for ExportNamedDeclaration {
-    type Output = NamedExport;
+    type Output = ModuleDecl;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        if let Some(decl) = self.declaration {
+            return ExportDecl {
+                span: ctx.span(&self.base),
+                decl: decl.swcify(ctx),
+            }
+            .into();
+        }
+
         NamedExport {
             span: ctx.span(&self.base),
             specifiers: self.specifiers.swcify(ctx),
             src: self.source.swcify(ctx).map(Box::new),
-            type_only: false,
+            type_only: self.export_kind == Some(ExportKind::Type),
             with: self
                 .with
                 .swcify(ctx)
@@ -534,9 +614,71 @@ impl Swcify for ExportNamedDeclaration {
                     .into()
                 }),
         }
+        .into()
+    }
+}
+
+impl Swcify for Declaration {
+    type Output = Decl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            Declaration::FuncDecl(v) => v.swcify(ctx).into(),
+            Declaration::VarDecl(v) => v.swcify(ctx).into(),
+            Declaration::ClassDecl(v) => v.swcify(ctx).into(),
+            Declaration::TSDeclFunc(v) => v.swcify(ctx).into(),
+            Declaration::TSInterfaceDecl(v) => v.swcify(ctx).into(),
+            Declaration::TSTypeAliasDecl(v) => v.swcify(ctx).into(),
+            Declaration::TSEnumDecl(v) => v.swcify(ctx).into(),
+            Declaration::TSModuleDecl(v) => v.swcify(ctx).into(),
+            Declaration::UsingDecl(v) => v.swcify(ctx).into(),
+            // Flow declarations, which are removed by the caller if they only declare types, and
+            // module declarations, which are not declarations of swc.
+            Declaration::ExportAllDecl(ExportAllDeclaration { base, .. })
+            | Declaration::ExportDefaultDecl(ExportDefaultDeclaration { base, .. })
+            | Declaration::ExportNamedDecl(ExportNamedDeclaration { base, .. })
+            | Declaration::ImportDecl(ImportDeclaration { base, .. })
+            | Declaration::DeclClass(DeclareClass { base, .. })
+            | Declaration::DeclFunc(DeclareFunction { base, .. })
+            | Declaration::DeclInterface(DeclareInterface { base, .. })
+            | Declaration::DeclModule(DeclareModule { base, .. })
+            | Declaration::DeclModuleExports(DeclareModuleExports { base, .. })
+            | Declaration::DeclTypeAlias(DeclareTypeAlias { base, .. })
+            | Declaration::DeclOpaqueType(DeclareOpaqueType { base, .. })
+            | Declaration::DeclVar(DeclareVariable { base, .. })
+            | Declaration::DeclExportDecl(DeclareExportDeclaration { base, .. })
+            | Declaration::DeclExportAllDecl(DeclareExportAllDeclaration { base, .. })
+            | Declaration::InterfaceDecl(InterfaceDeclaration { base, .. })
+            | Declaration::OpaqueType(OpaqueType { base, .. })
+            | Declaration::TypeAlias(TypeAlias { base, .. })
+            | Declaration::EnumDecl(EnumDeclaration { base, .. }) => VarDecl {
+                span: ctx.unsupported(ctx.span(&base), "this kind of declaration"),
+                ..Default::default()
+            }
+            .into(),
+        }
     }
 }
 
+/// Returns `true` if `decl` is a flow declaration which only declares types.
+fn is_flow_type_only(decl: &Declaration) -> bool {
+    matches!(
+        decl,
+        Declaration::DeclClass(..)
+            | Declaration::DeclFunc(..)
+            | Declaration::DeclInterface(..)
+            | Declaration::DeclModule(..)
+            | Declaration::DeclModuleExports(..)
+            | Declaration::DeclTypeAlias(..)
+            | Declaration::DeclOpaqueType(..)
+            | Declaration::DeclVar(..)
+            | Declaration::DeclExportDecl(..)
+            | Declaration::InterfaceDecl(..)
+            | Declaration::OpaqueType(..)
+            | Declaration::TypeAlias(..)
+    )
+}
+
 impl Swcify for swc_estree_ast::ExportSpecifierType {
     type Output = swc_ecma_ast::ExportSpecifier;
 
@@ -559,10 +701,14 @@ impl Swcify for swc_estree_ast::ExportSpecifier {
     type Output = ExportNamedSpecifier;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let orig = self.local.swcify(ctx);
+        let exported = self.exported.swcify(ctx);
+
         ExportNamedSpecifier {
             span: ctx.span(&self.base),
-            orig: self.local.swcify(ctx),
-            exported: Some(self.exported.swcify(ctx)),
+            // Babel uses the same node for both names of `export { a }`.
+            exported: (exported.span() != orig.span()).then_some(exported),
+            orig,
             is_type_only: matches!(self.export_kind, ExportKind::Type),
         }
     }
@@ -595,7 +741,7 @@ impl Swcify for ForOfStatement {
     fn swcify(self, ctx: &Context) -> Self::Output {
         ForOfStmt {
             span: ctx.span(&self.base),
-            is_await: false,
+            is_await: self.is_await,
             left: self.left.swcify(ctx),
             right: self.right.swcify(ctx),
             body: Box::new(self.body.swcify(ctx).expect_stmt()),
@@ -611,7 +757,7 @@ impl Swcify for ImportDeclaration {
             span: ctx.span(&self.base),
             specifiers: self.specifiers.swcify(ctx),
             src: self.source.swcify(ctx).into(),
-            type_only: false,
+            type_only: self.import_kind == Some(ImportKind::Type),
             with: self
                 .with
                 .swcify(ctx)
@@ -678,10 +824,14 @@ impl Swcify for swc_estree_ast::ImportSpecifier {
     type Output = ImportNamedSpecifier;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
+        let local: Ident = self.local.swcify(ctx).into();
+        let imported = self.imported.swcify(ctx);
+
         ImportNamedSpecifier {
             span: ctx.span(&self.base),
-            local: self.local.swcify(ctx).into(),
-            imported: Some(self.imported.swcify(ctx)),
+            // Babel uses the same node for both names of `import { a }`.
+            imported: (imported.span() != local.span).then_some(imported),
+            local,
             is_type_only: matches!(self.import_kind, Some(ImportKind::Type)),
         }
     }
@@ -709,70 +859,6 @@ impl Swcify for ImportNamespaceSpecifier {
     }
 }
 
-impl Swcify for DeclareClass {
-    type Output = ClassDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareFunction {
-    type Output = FnDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareInterface {
-    type Output = TsInterfaceDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareModule {
-    type Output = TsModuleDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareModuleExports {
-    type Output = TsExportAssignment;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareTypeAlias {
-    type Output = TsTypeAliasDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareVariable {
-    type Output = VarDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
-impl Swcify for DeclareExportDeclaration {
-    type Output = ExportDecl;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unimplemented!("flow")
-    }
-}
-
 impl Swcify for DeclareExportAllDeclaration {
     type Output = ExportAll;
 
@@ -1,15 +1,38 @@
+use swc_common::{BytePos, Spanned};
 use swc_ecma_ast::{
-    Accessibility, Ident, TsEntityName, TsQualifiedName, TsType, TsTypeAnn, TsTypeParam,
-    TsTypeParamDecl, TsTypeParamInstantiation,
+    Accessibility, BigInt, BindingIdent, FnDecl, Function, Ident, Number, RestPat, TruePlusMinus,
+    TsArrayType, TsCallSignatureDecl, TsConditionalType, TsConstructSignatureDecl,
+    TsConstructorType, TsEntityName, TsEnumDecl, TsEnumMember, TsEnumMemberId, TsExportAssignment,
+    TsExternalModuleRef, TsFnParam, TsFnType, TsGetterSignature, TsImportEqualsDecl, TsImportType,
+    TsIndexSignature, TsIndexedAccessType, TsInferType, TsInterfaceBody, TsInterfaceDecl,
+    TsIntersectionType, TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsMappedType,
+    TsMethodSignature, TsModuleBlock, TsModuleDecl, TsModuleName, TsModuleRef, TsNamespaceBody,
+    TsNamespaceDecl, TsNamespaceExportDecl, TsOptionalType, TsParamProp, TsParamPropParam,
+    TsParenthesizedType, TsPropertySignature, TsQualifiedName, TsRestType, TsSetterSignature,
+    TsThisType, TsThisTypeOrIdent, TsTplLitType, TsTupleElement, TsTupleType, TsType,
+    TsTypeAliasDecl, TsTypeAnn, TsTypeElement, TsTypeLit, TsTypeOperator, TsTypeOperatorOp,
+    TsTypeParam, TsTypeParamDecl, TsTypeParamInstantiation, TsTypePredicate, TsTypeQuery,
+    TsTypeQueryExpr, TsTypeRef, TsUnionType,
 };
 use swc_estree_ast::{
-    Access, FlowType, SuperTypeParams, TSEntityName, TSQualifiedName, TSType, TSTypeAnnotation,
-    TSTypeParameter, TSTypeParameterDeclaration, TSTypeParameterInstantiation, TypeAnnotOrNoop,
-    TypeParamDeclOrNoop,
+    Access, BaseNode, Expression, IdOrRest, IdOrString, ImportKind, Literal, SuperTypeParams,
+    TSArrayType, TSConditionalType, TSConstructorType, TSDeclareFunction, TSEntityName,
+    TSEnumDeclaration, TSEnumMember, TSExportAssignment, TSFuncDeclTypeAnnot, TSFuncDeclTypeParams,
+    TSFunctionType, TSImportEqualsDeclModuleRef, TSImportEqualsDeclaration, TSImportType,
+    TSIndexSignature, TSIndexedAccessType, TSInferType, TSInterfaceDeclaration, TSIntersectionType,
+    TSLiteralType, TSLiteralTypeLiteral, TSMappedType, TSMappedTypeModifier, TSMethodSignature,
+    TSMethodSignatureKind, TSModuleBlock, TSModuleDeclBody, TSModuleDeclaration,
+    TSModuleDeclarationKind, TSNamedTupleMember, TSNamespaceExportDeclaration, TSOptionalType,
+    TSParamPropParam, TSParameterProperty, TSParenthesizedType, TSQualifiedName, TSRestType,
+    TSThisType, TSTupleType, TSTupleTypeElType, TSType, TSTypeAliasDeclaration, TSTypeAnnotation,
+    TSTypeElement, TSTypeLiteral, TSTypeOperator, TSTypeParameter, TSTypeParameterDeclaration,
+    TSTypeParameterInstantiation, TSTypePredicate, TSTypePredicateParamName, TSTypeQuery,
+    TSTypeQueryExprName, TSTypeReference, TSUnionType, TemplateLiteralExpr, TypeAnnotOrNoop,
+    TypeParamDeclOrNoop, UnaryExprOp, UnaryExpression,
 };
 
 use super::Context;
-use crate::{swcify::Swcify, Never};
+use crate::swcify::Swcify;
 
 impl Swcify for TSTypeParameterInstantiation {
     type Output = TsTypeParamInstantiation;
@@ -22,14 +45,6 @@ impl Swcify for TSTypeParameterInstantiation {
     }
 }
 
-impl Swcify for FlowType {
-    type Output = Never;
-
-    fn swcify(self, _: &Context) -> Self::Output {
-        unreachable!("swc does not support flow types")
-    }
-}
-
 impl Swcify for TypeParamDeclOrNoop {
     type Output = Option<TsTypeParamDecl>;
 
@@ -96,18 +111,806 @@ impl Swcify for TSTypeAnnotation {
 impl Swcify for TSType {
     type Output = Box<TsType>;
 
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let keyword = |base: &BaseNode, kind: TsKeywordTypeKind| {
+            TsType::TsKeywordType(TsKeywordType {
+                span: ctx.span(base),
+                kind,
+            })
+        };
+
+        Box::new(match self {
+            TSType::AnyKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsAnyKeyword),
+            TSType::BooleanKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsBooleanKeyword),
+            TSType::BigIntKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsBigIntKeyword),
+            TSType::IntrinsicKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsIntrinsicKeyword),
+            TSType::NeverKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsNeverKeyword),
+            TSType::NullKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsNullKeyword),
+            TSType::NumberKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsNumberKeyword),
+            TSType::ObjectKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsObjectKeyword),
+            TSType::StringKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsStringKeyword),
+            TSType::SymbolKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsSymbolKeyword),
+            TSType::UndefinedKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsUndefinedKeyword),
+            TSType::UnknownKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsUnknownKeyword),
+            TSType::VoidKeyword(t) => keyword(&t.base, TsKeywordTypeKind::TsVoidKeyword),
+            TSType::This(t) => TsType::TsThisType(t.swcify(ctx)),
+            TSType::Function(t) => TsType::TsFnOrConstructorType(t.swcify(ctx).into()),
+            TSType::Constructor(t) => TsType::TsFnOrConstructorType(t.swcify(ctx).into()),
+            TSType::TypeRef(t) => TsType::TsTypeRef(t.swcify(ctx)),
+            TSType::TypePredicate(t) => TsType::TsTypePredicate(t.swcify(ctx)),
+            TSType::TypeQuery(t) => TsType::TsTypeQuery(t.swcify(ctx)),
+            TSType::TypeLiteral(t) => TsType::TsTypeLit(t.swcify(ctx)),
+            TSType::Array(t) => TsType::TsArrayType(t.swcify(ctx)),
+            TSType::Tuple(t) => TsType::TsTupleType(t.swcify(ctx)),
+            TSType::Optional(t) => TsType::TsOptionalType(t.swcify(ctx)),
+            TSType::Rest(t) => TsType::TsRestType(t.swcify(ctx)),
+            TSType::NamedTupleMember(..) => {
+                unreachable!("named tuple members are only allowed in tuple types")
+            }
+            TSType::Union(t) => TsType::TsUnionOrIntersectionType(t.swcify(ctx).into()),
+            TSType::Intersection(t) => TsType::TsUnionOrIntersectionType(t.swcify(ctx).into()),
+            TSType::Conditional(t) => TsType::TsConditionalType(t.swcify(ctx)),
+            TSType::Infer(t) => TsType::TsInferType(t.swcify(ctx)),
+            TSType::Parenthesized(t) => TsType::TsParenthesizedType(t.swcify(ctx)),
+            TSType::TypeOp(t) => TsType::TsTypeOperator(t.swcify(ctx)),
+            TSType::IndexedAccess(t) => TsType::TsIndexedAccessType(t.swcify(ctx)),
+            TSType::Mapped(t) => TsType::TsMappedType(t.swcify(ctx)),
+            TSType::Literal(t) => TsType::TsLitType(t.swcify(ctx)),
+            TSType::ExprWithArgs(t) => TsType::TsTypeRef(TsTypeRef {
+                span: ctx.span(&t.base),
+                type_name: t.expression.swcify(ctx),
+                type_params: t.type_parameters.swcify(ctx).map(Box::new),
+            }),
+            TSType::Import(t) => TsType::TsImportType(t.swcify(ctx)),
+        })
+    }
+}
+
+impl Swcify for TSThisType {
+    type Output = TsThisType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsThisType {
+            span: ctx.span(&self.base),
+        }
+    }
+}
+
+impl Swcify for IdOrRest {
+    type Output = TsFnParam;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            IdOrRest::Id(v) => TsFnParam::Ident(v.swcify(ctx)),
+            IdOrRest::Rest(v) => TsFnParam::Rest(v.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSFunctionType {
+    type Output = TsFnType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsFnType {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx).map(Box::new),
+            type_ann: Box::new(
+                self.type_annotation
+                    .swcify(ctx)
+                    .expect("function types should have a return type"),
+            ),
+        }
+    }
+}
+
+impl Swcify for TSConstructorType {
+    type Output = TsConstructorType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsConstructorType {
+            span: ctx.span(&self.base),
+            params: self.parameters.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx).map(Box::new),
+            type_ann: Box::new(
+                self.type_annotation
+                    .swcify(ctx)
+                    .expect("constructor types should have a return type"),
+            ),
+            is_abstract: self.is_abstract.unwrap_or_default(),
+        }
+    }
+}
+
+impl Swcify for TSTypeReference {
+    type Output = TsTypeRef;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeRef {
+            span: ctx.span(&self.base),
+            type_name: self.type_name.swcify(ctx),
+            type_params: self.type_parameters.swcify(ctx).map(Box::new),
+        }
+    }
+}
+
+impl Swcify for TSTypePredicate {
+    type Output = TsTypePredicate;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypePredicate {
+            span: ctx.span(&self.base),
+            asserts: self.asserts.unwrap_or_default(),
+            param_name: match self.parameter_name {
+                TSTypePredicateParamName::Id(v) => TsThisTypeOrIdent::Ident(v.swcify(ctx).into()),
+                TSTypePredicateParamName::This(v) => TsThisTypeOrIdent::TsThisType(v.swcify(ctx)),
+            },
+            type_ann: self.type_annotation.swcify(ctx).map(Box::new),
+        }
+    }
+}
+
+impl Swcify for TSTypeQuery {
+    type Output = TsTypeQuery;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeQuery {
+            span: ctx.span(&self.base),
+            expr_name: match self.expr_name {
+                TSTypeQueryExprName::EntityName(v) => TsTypeQueryExpr::TsEntityName(v.swcify(ctx)),
+                TSTypeQueryExprName::ImportType(v) => TsTypeQueryExpr::Import(v.swcify(ctx)),
+            },
+            type_args: self.type_parameters.swcify(ctx).map(Box::new),
+        }
+    }
+}
+
+impl Swcify for TSImportType {
+    type Output = TsImportType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsImportType {
+            span: ctx.span(&self.base),
+            arg: self.argument.swcify(ctx),
+            qualifier: self.qualifier.swcify(ctx),
+            type_args: self.type_parameters.swcify(ctx).map(Box::new),
+            attributes: None,
+        }
+    }
+}
+
+impl Swcify for TSTypeLiteral {
+    type Output = TsTypeLit;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeLit {
+            span: ctx.span(&self.base),
+            members: self.members.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypeElement {
+    type Output = TsTypeElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTypeElement::CallSignatureDecl(v) => {
+                TsTypeElement::TsCallSignatureDecl(TsCallSignatureDecl {
+                    span: ctx.span(&v.base),
+                    params: v.parameters.swcify(ctx),
+                    type_ann: v.type_annotation.swcify(ctx).map(Box::new),
+                    type_params: v.type_parameters.swcify(ctx).map(Box::new),
+                })
+            }
+            TSTypeElement::ConstructSignatureDecl(v) => {
+                TsTypeElement::TsConstructSignatureDecl(TsConstructSignatureDecl {
+                    span: ctx.span(&v.base),
+                    params: v.parameters.swcify(ctx),
+                    type_ann: v.type_annotation.swcify(ctx).map(Box::new),
+                    type_params: v.type_parameters.swcify(ctx).map(Box::new),
+                })
+            }
+            TSTypeElement::PropSignature(v) => {
+                TsTypeElement::TsPropertySignature(TsPropertySignature {
+                    span: ctx.span(&v.base),
+                    readonly: v.readonly.unwrap_or_default(),
+                    key: v.key.swcify(ctx),
+                    computed: v.computed.unwrap_or_default(),
+                    optional: v.optional.unwrap_or_default(),
+                    type_ann: v.type_annotation.swcify(ctx).map(Box::new),
+                })
+            }
+            TSTypeElement::MethodSignature(v) => v.swcify(ctx),
+            TSTypeElement::IndexSignature(v) => TsTypeElement::TsIndexSignature(v.swcify(ctx)),
+        }
+    }
+}
+
+impl Swcify for TSMethodSignature {
+    type Output = TsTypeElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        match self.kind {
+            TSMethodSignatureKind::Method => TsTypeElement::TsMethodSignature(TsMethodSignature {
+                span,
+                key: self.key.swcify(ctx),
+                computed: self.computed.unwrap_or_default(),
+                optional: self.optional.unwrap_or_default(),
+                params: self.parameters.swcify(ctx),
+                type_ann: self.type_annotation.swcify(ctx).map(Box::new),
+                type_params: self.type_parameters.swcify(ctx).map(Box::new),
+            }),
+            TSMethodSignatureKind::Get => TsTypeElement::TsGetterSignature(TsGetterSignature {
+                span,
+                key: self.key.swcify(ctx),
+                computed: self.computed.unwrap_or_default(),
+                type_ann: self.type_annotation.swcify(ctx).map(Box::new),
+            }),
+            TSMethodSignatureKind::Set => TsTypeElement::TsSetterSignature(TsSetterSignature {
+                span,
+                key: self.key.swcify(ctx),
+                computed: self.computed.unwrap_or_default(),
+                param: self
+                    .parameters
+                    .into_iter()
+                    .next()
+                    .expect("setter signatures should have a parameter")
+                    .swcify(ctx),
+            }),
+        }
+    }
+}
+
+impl Swcify for TSIndexSignature {
+    type Output = TsIndexSignature;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsIndexSignature {
+            span: ctx.span(&self.base),
+            params: self
+                .parameters
+                .into_iter()
+                .map(|v| TsFnParam::Ident(v.swcify(ctx)))
+                .collect(),
+            type_ann: self.type_annotation.swcify(ctx).map(Box::new),
+            readonly: self.readonly.unwrap_or_default(),
+            is_static: self.is_static.unwrap_or_default(),
+        }
+    }
+}
+
+impl Swcify for TSArrayType {
+    type Output = TsArrayType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsArrayType {
+            span: ctx.span(&self.base),
+            elem_type: self.element_type.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTupleType {
+    type Output = TsTupleType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTupleType {
+            span: ctx.span(&self.base),
+            elem_types: self.element_types.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTupleTypeElType {
+    type Output = TsTupleElement;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSTupleTypeElType::Member(member) => {
+                let span = ctx.span(&member.base);
+                let (label, ty) = swcify_tuple_member(member, ctx);
+
+                TsTupleElement {
+                    span,
+                    label: Some(label.into()),
+                    ty,
+                }
+            }
+            // Babel wraps named rest members, e.g. `...rest: T[]`, in a rest type.
+            TSTupleTypeElType::TSType(TSType::Rest(TSRestType {
+                base,
+                type_annotation,
+            })) if matches!(*type_annotation, TSType::NamedTupleMember(..)) => {
+                let span = ctx.span(&base);
+                let TSType::NamedTupleMember(member) = *type_annotation else {
+                    unreachable!()
+                };
+                let (label, ty) = swcify_tuple_member(member, ctx);
+
+                TsTupleElement {
+                    span,
+                    label: Some(
+                        RestPat {
+                            span: span.with_hi(label.span.hi + BytePos(1)),
+                            dot3_token: span.with_hi(span.lo + BytePos(3)),
+                            arg: Box::new(label.into()),
+                            type_ann: None,
+                        }
+                        .into(),
+                    ),
+                    ty,
+                }
+            }
+            TSTupleTypeElType::TSType(ty) => {
+                let ty = ty.swcify(ctx);
+
+                TsTupleElement {
+                    span: ty.span(),
+                    label: None,
+                    ty,
+                }
+            }
+        }
+    }
+}
+
+/// Returns the label and the type of a named tuple member.
+fn swcify_tuple_member(member: TSNamedTupleMember, ctx: &Context) -> (BindingIdent, Box<TsType>) {
+    let mut label = member.label.swcify(ctx);
+
+    if member.optional {
+        // The span of the label includes `?`.
+        label.id.optional = true;
+        label.id.span.hi = label.id.span.hi + BytePos(1);
+    }
+
+    (label, member.element_type.swcify(ctx))
+}
+
+impl Swcify for TSOptionalType {
+    type Output = TsOptionalType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsOptionalType {
+            span: ctx.span(&self.base),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSRestType {
+    type Output = TsRestType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsRestType {
+            span: ctx.span(&self.base),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSUnionType {
+    type Output = TsUnionType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsUnionType {
+            span: ctx.span(&self.base),
+            types: self.types.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSIntersectionType {
+    type Output = TsIntersectionType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsIntersectionType {
+            span: ctx.span(&self.base),
+            types: self.types.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSConditionalType {
+    type Output = TsConditionalType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsConditionalType {
+            span: ctx.span(&self.base),
+            check_type: self.check_type.swcify(ctx),
+            extends_type: self.extends_type.swcify(ctx),
+            true_type: self.true_type.swcify(ctx),
+            false_type: self.false_type.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSInferType {
+    type Output = TsInferType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInferType {
+            span: ctx.span(&self.base),
+            type_param: self.type_parameter.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSParenthesizedType {
+    type Output = TsParenthesizedType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsParenthesizedType {
+            span: ctx.span(&self.base),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSTypeOperator {
+    type Output = TsTypeOperator;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeOperator {
+            span: ctx.span(&self.base),
+            op: match &*self.operator {
+                "keyof" => TsTypeOperatorOp::KeyOf,
+                "unique" => TsTypeOperatorOp::Unique,
+                "readonly" => TsTypeOperatorOp::ReadOnly,
+                _ => panic!("unknown type operator: {}", self.operator),
+            },
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSIndexedAccessType {
+    type Output = TsIndexedAccessType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsIndexedAccessType {
+            span: ctx.span(&self.base),
+            readonly: false,
+            obj_type: self.object_type.swcify(ctx),
+            index_type: self.index_type.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSMappedType {
+    type Output = TsMappedType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsMappedType {
+            span: ctx.span(&self.base),
+            readonly: self.readonly.swcify(ctx),
+            type_param: self.type_parameter.swcify(ctx),
+            name_type: self.name_type.swcify(ctx),
+            optional: self.optional.swcify(ctx),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSMappedTypeModifier {
+    type Output = TruePlusMinus;
+
     fn swcify(self, _: &Context) -> Self::Output {
-        todo!("swc currently does not support importing typescript module from babel")
+        match self {
+            TSMappedTypeModifier::True => TruePlusMinus::True,
+            TSMappedTypeModifier::Plus => TruePlusMinus::Plus,
+            TSMappedTypeModifier::Minus => TruePlusMinus::Minus,
+        }
+    }
+}
+
+impl Swcify for TSLiteralType {
+    type Output = TsLitType;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let span = ctx.span(&self.base);
+
+        let lit = match self.literal {
+            TSLiteralTypeLiteral::Numeric(v) => TsLit::Number(v.swcify(ctx)),
+            TSLiteralTypeLiteral::String(v) => TsLit::Str(v.swcify(ctx)),
+            TSLiteralTypeLiteral::Boolean(v) => TsLit::Bool(v.swcify(ctx)),
+            TSLiteralTypeLiteral::BigInt(v) => TsLit::BigInt(v.swcify(ctx)),
+            TSLiteralTypeLiteral::Template(v) => TsLit::Tpl(TsTplLitType {
+                span: ctx.span(&v.base),
+                types: v
+                    .expressions
+                    .into_iter()
+                    .map(|expr| match expr {
+                        TemplateLiteralExpr::TSType(ty) => ty.swcify(ctx),
+                        TemplateLiteralExpr::Expr(..) => {
+                            panic!("template literal types should only contain types")
+                        }
+                    })
+                    .collect(),
+                quasis: v.quasis.swcify(ctx),
+            }),
+            // Babel represents negative literals, e.g. `-1`, as unary expressions.
+            TSLiteralTypeLiteral::Unary(UnaryExpression {
+                operator: UnaryExprOp::Negation,
+                argument,
+                ..
+            }) => match *argument {
+                Expression::Literal(Literal::Numeric(v)) => {
+                    let n = v.swcify(ctx);
+
+                    TsLit::Number(Number {
+                        value: -n.value,
+                        raw: n.raw.map(|raw| format!("-{raw}").into()),
+                        ..n
+                    })
+                }
+                Expression::Literal(Literal::BigInt(v)) => {
+                    let i = v.swcify(ctx);
+
+                    TsLit::BigInt(BigInt {
+                        value: Box::new(-*i.value),
+                        raw: i.raw.map(|raw| format!("-{raw}").into()),
+                        ..i
+                    })
+                }
+                _ => panic!("unsupported literal type: {:?}", argument),
+            },
+            TSLiteralTypeLiteral::Unary(v) => {
+                panic!("unsupported literal type: {:?}", v)
+            }
+        };
+
+        TsLitType { span, lit }
+    }
+}
+
+impl Swcify for TSInterfaceDeclaration {
+    type Output = TsInterfaceDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsInterfaceDecl {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).into(),
+            declare: self.declare.unwrap_or_default(),
+            type_params: self.type_parameters.swcify(ctx).map(Box::new),
+            extends: self.extends.swcify(ctx).unwrap_or_default(),
+            body: TsInterfaceBody {
+                span: ctx.span(&self.body.base),
+                body: self.body.body.swcify(ctx),
+            },
+        }
+    }
+}
+
+impl Swcify for TSTypeAliasDeclaration {
+    type Output = TsTypeAliasDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsTypeAliasDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or_default(),
+            id: self.id.swcify(ctx).into(),
+            type_params: self.type_parameters.swcify(ctx).map(Box::new),
+            type_ann: self.type_annotation.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSEnumDeclaration {
+    type Output = TsEnumDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsEnumDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or_default(),
+            is_const: self.is_const.unwrap_or_default(),
+            id: self.id.swcify(ctx).into(),
+            members: self.members.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSEnumMember {
+    type Output = TsEnumMember;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsEnumMember {
+            span: ctx.span(&self.base),
+            id: match self.id {
+                IdOrString::Id(v) => TsEnumMemberId::Ident(v.swcify(ctx).into()),
+                IdOrString::String(v) => TsEnumMemberId::Str(v.swcify(ctx)),
+            },
+            init: self.initializer.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSModuleDeclaration {
+    type Output = TsModuleDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        let global =
+            self.global.unwrap_or_default() || self.kind == Some(TSModuleDeclarationKind::Global);
+
+        TsModuleDecl {
+            span: ctx.span(&self.base),
+            declare: self.declare.unwrap_or_default(),
+            global,
+            namespace: self.kind == Some(TSModuleDeclarationKind::Namespace),
+            id: match self.id {
+                IdOrString::Id(v) => TsModuleName::Ident(v.swcify(ctx).into()),
+                IdOrString::String(v) => TsModuleName::Str(v.swcify(ctx)),
+            },
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSModuleDeclBody {
+    type Output = TsNamespaceBody;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSModuleDeclBody::Block(v) => TsNamespaceBody::TsModuleBlock(v.swcify(ctx)),
+            // e.g. `B` of `namespace A.B {}`
+            TSModuleDeclBody::Decl(v) => TsNamespaceBody::TsNamespaceDecl(TsNamespaceDecl {
+                span: ctx.span(&v.base),
+                declare: v.declare.unwrap_or_default(),
+                global: v.global.unwrap_or_default(),
+                id: match v.id {
+                    IdOrString::Id(id) => id.swcify(ctx).into(),
+                    IdOrString::String(..) => {
+                        unreachable!("nested namespaces should have an identifier")
+                    }
+                },
+                body: Box::new(
+                    v.body
+                        .expect("nested namespaces should have a body")
+                        .swcify(ctx),
+                ),
+            }),
+        }
+    }
+}
+
+impl Swcify for TSModuleBlock {
+    type Output = TsModuleBlock;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsModuleBlock {
+            span: ctx.span(&self.base),
+            body: self.body.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSImportEqualsDeclaration {
+    type Output = TsImportEqualsDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsImportEqualsDecl {
+            span: ctx.span(&self.base),
+            is_export: self.is_export,
+            is_type_only: self.import_kind == Some(ImportKind::Type),
+            id: self.id.swcify(ctx).into(),
+            module_ref: match self.module_reference {
+                TSImportEqualsDeclModuleRef::Name(v) => TsModuleRef::TsEntityName(v.swcify(ctx)),
+                TSImportEqualsDeclModuleRef::External(v) => {
+                    TsModuleRef::TsExternalModuleRef(TsExternalModuleRef {
+                        span: ctx.span(&v.base),
+                        expr: v.expression.swcify(ctx),
+                    })
+                }
+            },
+        }
+    }
+}
+
+impl Swcify for TSExportAssignment {
+    type Output = TsExportAssignment;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsExportAssignment {
+            span: ctx.span(&self.base),
+            expr: self.expression.swcify(ctx),
+        }
+    }
+}
+
+impl Swcify for TSNamespaceExportDeclaration {
+    type Output = TsNamespaceExportDecl;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsNamespaceExportDecl {
+            span: ctx.span(&self.base),
+            id: self.id.swcify(ctx).into(),
+        }
+    }
+}
+
+impl Swcify for TSDeclareFunction {
+    type Output = FnDecl;
+
+    fn swcify(mut self, ctx: &Context) -> Self::Output {
+        FnDecl {
+            ident: self
+                .id
+                .take()
+                .expect("declared functions should have a name")
+                .swcify(ctx)
+                .into(),
+            declare: self.declare.unwrap_or_default(),
+            function: swcify_declared_function(self, ctx),
+        }
+    }
+}
+
+/// Converts a function without a body except its name, which is optional for
+/// `export default`.
+pub(crate) fn swcify_declared_function(decl: TSDeclareFunction, ctx: &Context) -> Box<Function> {
+    Box::new(Function {
+        params: decl.params.swcify(ctx),
+        span: ctx.span(&decl.base),
+        body: None,
+        is_generator: decl.generator.unwrap_or_default(),
+        is_async: decl.is_async.unwrap_or_default(),
+        type_params: decl.type_parameters.swcify(ctx).flatten().map(Box::new),
+        return_type: decl.return_type.swcify(ctx).flatten().map(Box::new),
+        ..Default::default()
+    })
+}
+
+impl Swcify for TSFuncDeclTypeParams {
+    type Output = Option<TsTypeParamDecl>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSFuncDeclTypeParams::Type(v) => Some(v.swcify(ctx)),
+            TSFuncDeclTypeParams::Noop(..) => None,
+        }
+    }
+}
+
+impl Swcify for TSFuncDeclTypeAnnot {
+    type Output = Option<TsTypeAnn>;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        match self {
+            TSFuncDeclTypeAnnot::Type(v) => Some(v.swcify(ctx)),
+            TSFuncDeclTypeAnnot::Noop(..) => None,
+        }
+    }
+}
+
+impl Swcify for TSParameterProperty {
+    type Output = TsParamProp;
+
+    fn swcify(self, ctx: &Context) -> Self::Output {
+        TsParamProp {
+            span: ctx.span(&self.base),
+            decorators: self.decorators.swcify(ctx).unwrap_or_default(),
+            accessibility: self.accessibility.swcify(ctx),
+            is_override: self.is_override.unwrap_or_default(),
+            readonly: self.readonly.unwrap_or_default(),
+            param: match self.parameter {
+                TSParamPropParam::Id(v) => TsParamPropParam::Ident(v.swcify(ctx)),
+                TSParamPropParam::Assignment(v) => TsParamPropParam::Assign(v.swcify(ctx)),
+            },
+        }
     }
 }
 
 impl Swcify for SuperTypeParams {
-    type Output = TsTypeParamInstantiation;
+    type Output = Option<TsTypeParamInstantiation>;
 
     fn swcify(self, ctx: &Context) -> Self::Output {
         match self {
-            SuperTypeParams::Flow(_) => unimplemented!("flow type"),
-            SuperTypeParams::TS(v) => v.swcify(ctx),
+            // Flow types are not preserved.
+            SuperTypeParams::Flow(_) => None,
+            SuperTypeParams::TS(v) => Some(v.swcify(ctx)),
         }
     }
 }
@@ -0,0 +1,40 @@
+// Leading comment of the import
+import a from "a"; // Trailing comment of the import
+
+/**
+ * JSDoc of a function
+ * @param value The value
+ */
+export function fn(/* inner param */ value: string /* after param */): void {
+    // Inner comment of a block
+}
+
+const empty = {
+    // Inner comment of an object
+};
+
+const arr = [
+    /* inner comment of an array */
+];
+
+class Foo {
+    // Inner comment of a class body
+}
+
+interface Bar {
+    /* inner comment of an interface */
+}
+
+call(/* inner comment of arguments */);
+
+type Union =
+    // Leading comment of a member
+    | "a"
+    /* trailing */
+    | "b";
+
+/* Detached comment */
+
+const value = /* before expression */ 1 + /* between operands */ 2; /* after statement */
+
+// Comment at the end of the file
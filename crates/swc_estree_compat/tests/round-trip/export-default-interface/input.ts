@@ -0,0 +1,3 @@
+export default interface Props<T> {
+    value: T;
+}
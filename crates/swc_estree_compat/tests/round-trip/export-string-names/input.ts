@@ -0,0 +1,4 @@
+const value = 1;
+export { value as "string name" };
+export { "other name" as renamed } from "module";
+export * as "all names" from "module";
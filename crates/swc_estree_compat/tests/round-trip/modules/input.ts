@@ -0,0 +1,16 @@
+#!/usr/bin/env node
+"use strict";
+import def, { named, other as renamed, type OnlyType } from "module";
+import * as ns from "namespace";
+import type { T } from "types";
+import "side-effect";
+export const value = 1;
+export let a, b = 2;
+export function fn() {}
+export class Cls {}
+export { value as renamedValue, fn };
+export * from "all";
+export * as allNs from "all";
+export { default } from "default";
+export type { T as U } from "types";
+export default function () {}
@@ -0,0 +1,53 @@
+@sealed
+@component({ selector: "app" })
+export abstract class Base<T> extends Parent<T> implements A, B.C<T> {
+    [key: string]: any;
+    static readonly instances: number = 0;
+    private declare declared: string;
+    protected optional?: number;
+    public definite!: string;
+    override overridden = 1;
+    accessor auto = 2;
+    static accessor staticAuto: string;
+    #private = 3;
+    @observable
+    decorated = "a";
+
+    constructor(
+        private readonly a: string,
+        public b = 1,
+        protected override c?: number,
+        @inject("d") readonly d: string,
+        e: number,
+    ) {
+        super();
+    }
+
+    abstract abstractMethod(): void;
+    overload(a: string): void;
+    overload(a: number): void;
+    overload(a: any) {}
+
+    @bound
+    method<U>(@param a: U): U {
+        return a;
+    }
+
+    get value(): number {
+        return 1;
+    }
+
+    set value(v: number) {}
+
+    static async *gen() {}
+
+    #privateMethod(): void {}
+
+    static {
+        Base.instances++;
+    }
+}
+
+export default class {
+    x = 1;
+}
@@ -0,0 +1,47 @@
+interface Base<T> {
+    value: T;
+}
+interface Child extends Base<string>, Other, A.B {
+    method(): void;
+}
+export interface Exported {}
+declare interface Declared {}
+type Alias<T = string> = Base<T>;
+export declare type DeclaredAlias = string;
+enum Plain {
+    A,
+    B = 2,
+    "C" = B << 1,
+}
+const enum Const {
+    A = "a",
+}
+declare enum Declared {}
+namespace Outer.Inner.Deep {
+    export const value = 1;
+}
+declare namespace Ambient {
+    function fn(): void;
+}
+declare module "module-name" {
+    export default function (): void;
+}
+declare module "shorthand";
+declare global {
+    interface Window {
+        custom: string;
+    }
+}
+import fs = require("fs");
+export import Alias2 = Outer.Inner;
+import type Types = require("types");
+declare function overloaded(a: string): string;
+declare function overloaded(a: number): number;
+export function impl(a: string): string;
+export function impl(a: any) {
+    return a;
+}
+declare const declared: number;
+declare let declaredLet: string, other: number;
+export = Outer;
+export as namespace Lib;
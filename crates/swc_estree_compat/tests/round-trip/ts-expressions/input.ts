@@ -0,0 +1,17 @@
+const a = value as string;
+const b = value satisfies Record<string, number>;
+const c = { a: 1 } as const;
+const d = value!.prop!;
+const e = <number>value;
+const f = fn<string, number>;
+const g = fn<string>(1);
+const h = new Map<string, number>();
+const i = obj?.a?.[b]?.(c);
+const j = async <T,>(a: T): Promise<T> => a;
+const k = tag<string>`template ${a}`;
+let l: string | undefined, m!: number;
+for (const [n, o] of entries as [string, number][]) {
+}
+const { p, q = 1, ...r }: { p: string; q?: number } = obj;
+const [s, , t = 2, ...u]: number[] = arr;
+function params({ a, b }: Props, [c]: [number], d = 1, ...e: string[]): void {}
@@ -0,0 +1,33 @@
+type Keywords = [any, unknown, number, object, boolean, bigint, string, symbol, void, undefined, null, never, this];
+type Union = "a" | 1 | -1 | true | 10n;
+type Intersection = { a: string } & { b: number };
+type Fn = <T extends object = {}>(a: T, b?: number, ...rest: string[]) => void;
+type Ctor = abstract new (a: string) => object;
+type Members = {
+    (a: number): string;
+    new (a: number): Members;
+    readonly prop?: string;
+    method?<T>(a: T): T;
+    [key: string]: unknown;
+    get value(): number;
+    set value(v: number);
+    ["computed"]: boolean;
+};
+type Tuple = [a: string, b?: number, ...c: boolean[]];
+type Unnamed = [string, number?, ...boolean[]];
+type Cond<T> = T extends Array<infer U extends string> ? U : never;
+type Mapped<T> = { readonly [K in keyof T]?: T[K] };
+type Removed<T> = { -readonly [K in keyof T]-?: T[K] };
+type Added<T> = { +readonly [K in keyof T as `get${K & string}`]+?: () => T[K] };
+type Query = typeof import("./mod").value;
+type Imported = import("./mod").Foo<string>;
+type Tpl = `prefix-${string}-${number}`;
+type Ops = keyof Union | readonly string[] | unique symbol;
+type Paren = (string | number)[];
+type Qualified = A.B.C<string>;
+type Generic<in out T, const U extends readonly unknown[]> = T;
+function isString(value: unknown): value is string {
+    return typeof value === "string";
+}
+function assertString(value: unknown): asserts value is string {}
+function assertThis(this: Foo): asserts this {}
@@ -0,0 +1,7 @@
+// Ünïcödé çömmënt 😀
+const emoji = "😀😀"; /* 🎉 */ const after = 1;
+const 変数: string = "値";
+function über(ß: number /* ✓ */): number {
+    return ß; // ✓ trailing
+}
+type Ω = { "ключ": "значение" };
@@ -0,0 +1,226 @@
+//! Converts swc ast to babel ast and back, and checks that nothing is lost.
+
+use std::{path::PathBuf, sync::Arc};
+
+use pretty_assertions::assert_eq;
+use swc_common::{comments::Comments, FileName, SourceFile, SourceMap, GLOBALS};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_codegen::to_code_default;
+use swc_ecma_parser::{parse_file_as_program, Syntax, TsSyntax};
+use swc_estree_ast::File;
+use swc_estree_compat::{
+    babelify::{self, Babelify},
+    swcify::{self, Swcify},
+};
+use swc_node_comments::SwcComments;
+
+#[testing::fixture("tests/round-trip/**/input.ts")]
+fn round_trip(input: PathBuf) {
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.load_file(&input).unwrap();
+        // Both of printing and babelify take comments, so we parse the input twice.
+        let (program, comments) = parse(&fm);
+        let expected_code = print(&cm, &comments, &program);
+
+        let (program, comments) = parse(&fm);
+        let babel = program.babelify(&babelify::Context {
+            fm: fm.clone(),
+            cm: cm.clone(),
+            comments: comments.clone(),
+        });
+        let expected_json = serde_json::to_string_pretty(&babel).unwrap();
+
+        // Deserialize the ast, like a plugin which receives it from javascript.
+        let babel: File = serde_json::from_str(&expected_json).unwrap();
+
+        let (swcified, swcified_comments) = swcify(&cm, &fm, babel.clone());
+        let actual_code = print(&cm, &swcified_comments, &swcified);
+
+        let (swcified, swcified_comments) = swcify(&cm, &fm, babel);
+        let actual_json = serde_json::to_string_pretty(&swcified.babelify(&babelify::Context {
+            fm: fm.clone(),
+            cm: cm.clone(),
+            comments: swcified_comments,
+        }))
+        .unwrap();
+
+        assert_eq!(actual_code, expected_code);
+        assert_eq!(actual_json, expected_json);
+    })
+}
+
+fn parse(fm: &SourceFile) -> (Program, SwcComments) {
+    let comments = SwcComments::default();
+
+    let program = parse_file_as_program(
+        fm,
+        Syntax::Typescript(TsSyntax {
+            decorators: true,
+            ..Default::default()
+        }),
+        EsVersion::latest(),
+        Some(&comments),
+        &mut Vec::new(),
+    )
+    .expect("failed to parse input");
+
+    (program, comments)
+}
+
+fn swcify(cm: &Arc<SourceMap>, fm: &Arc<SourceFile>, babel: File) -> (Program, SwcComments) {
+    let comments = SwcComments::default();
+
+    let program = babel.swcify(&swcify::Context::new_without_alloc(
+        cm.clone(),
+        comments.clone(),
+        fm.clone(),
+    ));
+
+    (program, comments)
+}
+
+fn print(cm: &Arc<SourceMap>, comments: &dyn Comments, program: &Program) -> String {
+    to_code_default(cm.clone(), Some(comments), program)
+}
+
+#[test]
+fn swcify_positions() {
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.new_source_file(
+            FileName::Anon.into(),
+            "const a = '😀';\nconst ü = 1;".into(),
+        );
+
+        let comments = SwcComments::default();
+        let program = parse_file_as_program(
+            &fm,
+            Default::default(),
+            EsVersion::latest(),
+            Some(&comments),
+            &mut Vec::new(),
+        )
+        .unwrap();
+
+        let mut babel = program.clone().babelify(&babelify::Context {
+            fm: fm.clone(),
+            cm: cm.clone(),
+            comments,
+        });
+
+        // Only `loc` is used if offsets are missing.
+        babel.program.body.iter_mut().for_each(|stmt| {
+            if let swc_estree_ast::Statement::VarDecl(decl) = stmt {
+                decl.base.start = None;
+                decl.base.end = None;
+            }
+        });
+
+        let swcified = babel.swcify(&swcify::Context::new_without_alloc(
+            cm,
+            Default::default(),
+            fm,
+        ));
+
+        let (Program::Script(expected), Program::Script(actual)) = (program, swcified) else {
+            unreachable!()
+        };
+
+        for (expected, actual) in expected.body.iter().zip(&actual.body) {
+            assert_eq!(
+                swc_common::Spanned::span(actual),
+                swc_common::Spanned::span(expected)
+            );
+        }
+    })
+}
+
+#[test]
+fn swcify_unsupported_nodes() {
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.new_source_file(FileName::Anon.into(), "a::b;\ntype T = any;".into());
+
+        let babel: File = serde_json::from_str(
+            r#"{
+                "type": "File",
+                "program": {
+                    "type": "Program",
+                    "sourceType": "script",
+                    "body": [
+                        {
+                            "type": "ExpressionStatement",
+                            "start": 0,
+                            "end": 5,
+                            "expression": {
+                                "type": "BindExpression",
+                                "start": 0,
+                                "end": 4,
+                                "object": { "type": "Identifier", "name": "a" },
+                                "callee": { "type": "Identifier", "name": "b" }
+                            }
+                        },
+                        {
+                            "type": "TypeAlias",
+                            "id": { "type": "Identifier", "name": "T" },
+                            "right": { "type": "AnyTypeAnnotation" }
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let ctx = swcify::Context::new_without_alloc(cm, Default::default(), fm.clone());
+        let program = babel.swcify(&ctx);
+
+        let Program::Script(script) = program else {
+            unreachable!()
+        };
+        assert_eq!(script.body.len(), 2);
+        assert!(script.body[0].as_expr().unwrap().expr.is_invalid());
+        assert!(script.body[1].is_empty());
+
+        let unsupported = ctx.take_unsupported();
+        assert_eq!(unsupported.len(), 1);
+        assert_eq!(unsupported[0].kind, "bind expressions");
+        assert_eq!(unsupported[0].span.lo, fm.start_pos);
+    })
+}
+
+#[test]
+fn from_babel_json_unsupported() {
+    GLOBALS.set(&Default::default(), || {
+        let cm = Arc::new(SourceMap::default());
+        let fm = cm.new_source_file(FileName::Anon.into(), "#{};".into());
+        let ctx = swcify::Context::new_without_alloc(cm, Default::default(), fm);
+
+        let err = swc_estree_compat::from_babel_json(
+            r#"{
+                "type": "File",
+                "program": {
+                    "type": "Program",
+                    "sourceType": "script",
+                    "body": [
+                        {
+                            "type": "ExpressionStatement",
+                            "expression": { "type": "RecordExpression", "properties": [] }
+                        }
+                    ]
+                }
+            }"#,
+            &ctx,
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "babel ast contains unsupported nodes: record expressions"
+        );
+        assert!(matches!(
+            swc_estree_compat::from_babel_json("{}", &ctx),
+            Err(swc_estree_compat::FromBabelJsonError::Json(..))
+        ));
+    })
+}
@@ -19,6 +19,9 @@ fn test(input: PathBuf) {
         if let Ok(code) = operate(&cm, handler, input_code.clone(), opts(Mode::StripOnly)) {
             let code = code.code;
 
+            // Types are blanked out in place, so positions are preserved.
+            assert_eq!(code.len(), input_code.len());
+
             NormalizedOutput::new_raw(code)
                 .compare_to_file(output_file)
                 .unwrap();
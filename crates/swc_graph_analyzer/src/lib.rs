@@ -4,6 +4,8 @@ use auto_impl::auto_impl;
 use petgraph::{prelude::GraphMap, Directed};
 use rustc_hash::{FxBuildHasher, FxHashSet};
 
+pub mod module_graph;
+
 #[auto_impl(&, Box, Rc, Arc)]
 pub trait DepGraph {
     type ModuleId: Debug + Copy + Eq + Hash + Ord;
@@ -0,0 +1,463 @@
+//! Import graph of the modules of a project.
+//!
+//! [ModuleGraph] records every import of each module with its kind, so that a
+//! tool can choose which imports to follow. For example, a watcher invalidates
+//! all dependents including type-only imports, while a bundler ignores them.
+//!
+//! ```ignore
+//! let graph = ModuleGraph::load(&[entry], &resolver, &loader)?;
+//!
+//! let changed = graph.module_id(&changed_file).unwrap();
+//! let affected = graph.transitive_dependents(&[changed], |_| true);
+//!
+//! let cycles = graph.cycles(|import| !import.type_only);
+//! ```
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Error};
+use auto_impl::auto_impl;
+use rustc_hash::{FxHashMap, FxHashSet};
+use swc_atoms::Atom;
+use swc_common::{FileName, Span, Spanned};
+use swc_ecma_ast::*;
+use swc_ecma_loader::resolve::Resolve;
+use swc_ecma_visit::{Visit, VisitWith};
+
+use crate::{DepGraph, GraphAnalyzer};
+
+/// The index of a module in a [ModuleGraph].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ModuleId(u32);
+
+impl ModuleId {
+    fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ImportKind {
+    /// `import`, and `export ... from`.
+    Static,
+    /// `import()`
+    Dynamic,
+    /// `require()`, and `import foo = require()` of typescript.
+    Require,
+}
+
+/// A resolved import.
+#[derive(Debug, Clone)]
+pub struct Import {
+    pub from: ModuleId,
+    pub to: ModuleId,
+    pub specifier: Atom,
+    pub kind: ImportKind,
+    /// True if the import is erased by typescript, e.g. `import type`.
+    pub type_only: bool,
+    pub span: Span,
+}
+
+/// An import which failed to be resolved.
+#[derive(Debug)]
+pub struct UnresolvedImport {
+    pub from: ModuleId,
+    pub specifier: Atom,
+    pub kind: ImportKind,
+    pub type_only: bool,
+    pub span: Span,
+    pub error: Error,
+}
+
+/// Loads a module to find its imports.
+#[auto_impl(&, Box, Arc)]
+pub trait Load {
+    /// Returns `None` for modules which should not be loaded, e.g. files in
+    /// `node_modules`. They are still in the graph, without imports.
+    fn load(&self, file: &FileName) -> Result<Option<Program>, Error>;
+}
+
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    file_names: Vec<FileName>,
+    ids: FxHashMap<FileName, ModuleId>,
+    /// Whether [ModuleGraphBuilder::add_module] is called for the module.
+    added: Vec<bool>,
+    imports: Vec<Import>,
+    /// Indices of `imports` from each module.
+    deps: Vec<Vec<usize>>,
+    /// Indices of `imports` to each module.
+    dependents: Vec<Vec<usize>>,
+    unresolved: Vec<UnresolvedImport>,
+}
+
+impl ModuleGraph {
+    /// Builds the graph of modules which are reachable from `entries`.
+    pub fn load(
+        entries: &[FileName],
+        resolver: &dyn Resolve,
+        loader: &dyn Load,
+    ) -> Result<Self, Error> {
+        let mut builder = ModuleGraphBuilder::new(resolver);
+        let mut queue = entries.to_vec();
+
+        while let Some(file_name) = queue.pop() {
+            if builder.is_added(&file_name) {
+                continue;
+            }
+
+            let program = loader
+                .load(&file_name)
+                .with_context(|| format!("failed to load `{}`", file_name))?;
+
+            let id = match &program {
+                Some(program) => builder.add_module(&file_name, program),
+                None => builder.add_external(&file_name),
+            };
+
+            queue.extend(
+                builder
+                    .graph
+                    .dependencies(id)
+                    .map(|import| builder.graph.file_name(import.to).clone()),
+            );
+        }
+
+        Ok(builder.build())
+    }
+
+    /// `file_name` is normalized, so a path with symlinks can be used.
+    pub fn module_id(&self, file_name: &FileName) -> Option<ModuleId> {
+        self.ids.get(&normalize(file_name)).copied()
+    }
+
+    /// Returns the first file name used for the module.
+    pub fn file_name(&self, id: ModuleId) -> &FileName {
+        &self.file_names[id.index()]
+    }
+
+    pub fn modules(&self) -> impl '_ + Iterator<Item = ModuleId> {
+        (0..self.file_names.len() as u32).map(ModuleId)
+    }
+
+    pub fn imports(&self) -> &[Import] {
+        &self.imports
+    }
+
+    pub fn unresolved_imports(&self) -> &[UnresolvedImport] {
+        &self.unresolved
+    }
+
+    /// Returns imports of `id`, in source order.
+    pub fn dependencies(&self, id: ModuleId) -> impl '_ + Iterator<Item = &Import> {
+        self.deps[id.index()].iter().map(|&idx| &self.imports[idx])
+    }
+
+    /// Returns imports to `id`, i.e. reverse dependencies.
+    pub fn dependents(&self, id: ModuleId) -> impl '_ + Iterator<Item = &Import> {
+        self.dependents[id.index()]
+            .iter()
+            .map(|&idx| &self.imports[idx])
+    }
+
+    /// Returns modules reachable from `from`, including `from` itself, through
+    /// imports for which `follow` returns true.
+    pub fn reachable_from(
+        &self,
+        from: &[ModuleId],
+        follow: impl Fn(&Import) -> bool,
+    ) -> Vec<ModuleId> {
+        self.walk(from, |id| self.dependencies(id), |import| import.to, follow)
+    }
+
+    /// Returns modules which import `of` directly or indirectly, including
+    /// `of` itself, through imports for which `follow` returns true.
+    ///
+    /// This is the list of modules to invalidate when `of` is changed.
+    pub fn transitive_dependents(
+        &self,
+        of: &[ModuleId],
+        follow: impl Fn(&Import) -> bool,
+    ) -> Vec<ModuleId> {
+        self.walk(of, |id| self.dependents(id), |import| import.from, follow)
+    }
+
+    fn walk<'a, I>(
+        &'a self,
+        start: &[ModuleId],
+        edges: impl Fn(ModuleId) -> I,
+        next: impl Fn(&Import) -> ModuleId,
+        follow: impl Fn(&Import) -> bool,
+    ) -> Vec<ModuleId>
+    where
+        I: Iterator<Item = &'a Import>,
+    {
+        let mut visited = FxHashSet::default();
+        let mut result = Vec::new();
+        let mut queue = start.to_vec();
+
+        while let Some(id) = queue.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+
+            result.push(id);
+            queue.extend(edges(id).filter(|import| follow(import)).map(&next));
+        }
+
+        result.sort();
+        result
+    }
+
+    /// Returns import cycles through imports for which `follow` returns true.
+    ///
+    /// Each cycle is reported once, starting from the module which is found
+    /// first. The last module of a cycle imports the first one.
+    pub fn cycles(&self, follow: impl Fn(&Import) -> bool) -> Vec<Vec<ModuleId>> {
+        let deps = Deps {
+            graph: self,
+            follow: &follow,
+        };
+        let mut analyzer = GraphAnalyzer::new(&deps);
+
+        for id in self.modules() {
+            analyzer.load(id);
+        }
+
+        let mut seen = FxHashSet::default();
+
+        analyzer
+            .into_result()
+            .cycles
+            .into_iter()
+            // A path which visits a module twice consists of cycles which are reported
+            // separately.
+            .filter(|cycle| {
+                let mut modules = FxHashSet::default();
+                cycle.iter().all(|id| modules.insert(*id))
+            })
+            .filter(|cycle| seen.insert(canonical(cycle)))
+            .collect()
+    }
+}
+
+struct Deps<'a, F> {
+    graph: &'a ModuleGraph,
+    follow: &'a F,
+}
+
+impl<F> DepGraph for Deps<'_, F>
+where
+    F: Fn(&Import) -> bool,
+{
+    type ModuleId = ModuleId;
+
+    fn deps_of(&self, module_id: ModuleId) -> Vec<ModuleId> {
+        let mut deps: Vec<_> = self
+            .graph
+            .dependencies(module_id)
+            .filter(|import| (self.follow)(import))
+            .map(|import| import.to)
+            .collect();
+
+        // A module can be imported multiple times.
+        let mut seen = FxHashSet::default();
+        deps.retain(|id| seen.insert(*id));
+        deps
+    }
+}
+
+/// Rotates a cycle so that it starts with its smallest module.
+fn canonical(cycle: &[ModuleId]) -> Vec<ModuleId> {
+    let start = cycle
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, id)| **id)
+        .map(|(pos, _)| pos)
+        .unwrap_or(0);
+
+    cycle[start..]
+        .iter()
+        .chain(&cycle[..start])
+        .copied()
+        .collect()
+}
+
+/// Builds a [ModuleGraph] from modules which are already parsed.
+pub struct ModuleGraphBuilder<'a> {
+    resolver: &'a dyn Resolve,
+    graph: ModuleGraph,
+}
+
+impl<'a> ModuleGraphBuilder<'a> {
+    pub fn new(resolver: &'a dyn Resolve) -> Self {
+        Self {
+            resolver,
+            graph: Default::default(),
+        }
+    }
+
+    fn is_added(&self, file_name: &FileName) -> bool {
+        self.graph
+            .module_id(file_name)
+            .map_or(false, |id| self.graph.added[id.index()])
+    }
+
+    fn id_of(&mut self, file_name: &FileName) -> ModuleId {
+        let graph = &mut self.graph;
+
+        *graph.ids.entry(normalize(file_name)).or_insert_with(|| {
+            graph.file_names.push(file_name.clone());
+            graph.added.push(false);
+            graph.deps.push(Vec::new());
+            graph.dependents.push(Vec::new());
+
+            ModuleId(graph.file_names.len() as u32 - 1)
+        })
+    }
+
+    /// Adds a module without imports.
+    fn add_external(&mut self, file_name: &FileName) -> ModuleId {
+        let id = self.id_of(file_name);
+        self.graph.added[id.index()] = true;
+        id
+    }
+
+    /// Adds `program` and resolves its imports. Imported modules are added to
+    /// the graph, but their imports are not.
+    ///
+    /// Adding a module twice adds its imports twice.
+    pub fn add_module(&mut self, file_name: &FileName, program: &Program) -> ModuleId {
+        let from = self.add_external(file_name);
+
+        let mut collector = ImportCollector::default();
+        program.visit_with(&mut collector);
+
+        for found in collector.imports {
+            match self.resolver.resolve(file_name, &found.specifier) {
+                Ok(resolution) => {
+                    let to = self.id_of(&resolution.filename);
+                    let idx = self.graph.imports.len();
+
+                    self.graph.imports.push(Import {
+                        from,
+                        to,
+                        specifier: found.specifier,
+                        kind: found.kind,
+                        type_only: found.type_only,
+                        span: found.span,
+                    });
+                    self.graph.deps[from.index()].push(idx);
+                    self.graph.dependents[to.index()].push(idx);
+                }
+                Err(error) => self.graph.unresolved.push(UnresolvedImport {
+                    from,
+                    specifier: found.specifier,
+                    kind: found.kind,
+                    type_only: found.type_only,
+                    span: found.span,
+                    error,
+                }),
+            }
+        }
+
+        from
+    }
+
+    pub fn build(self) -> ModuleGraph {
+        self.graph
+    }
+}
+
+fn normalize(file_name: &FileName) -> FileName {
+    match file_name {
+        FileName::Real(path) => {
+            FileName::Real(path.canonicalize().unwrap_or_else(|_| PathBuf::from(path)))
+        }
+        _ => file_name.clone(),
+    }
+}
+
+struct FoundImport {
+    specifier: Atom,
+    kind: ImportKind,
+    type_only: bool,
+    span: Span,
+}
+
+#[derive(Default)]
+struct ImportCollector {
+    imports: Vec<FoundImport>,
+}
+
+impl ImportCollector {
+    fn add(&mut self, specifier: &Atom, kind: ImportKind, type_only: bool, span: Span) {
+        self.imports.push(FoundImport {
+            specifier: specifier.clone(),
+            kind,
+            type_only,
+            span,
+        });
+    }
+}
+
+impl Visit for ImportCollector {
+    fn visit_module_decl(&mut self, decl: &ModuleDecl) {
+        let (src, type_only) = match decl {
+            ModuleDecl::Import(i) => (
+                &i.src,
+                i.type_only
+                    || (!i.specifiers.is_empty() && i.specifiers.iter().all(|s| s.is_type_only())),
+            ),
+            ModuleDecl::ExportNamed(NamedExport {
+                src: Some(src),
+                type_only,
+                ..
+            }) => (src, *type_only),
+            ModuleDecl::ExportAll(e) => (&e.src, e.type_only),
+            _ => {
+                decl.visit_children_with(self);
+                return;
+            }
+        };
+
+        self.add(&src.value, ImportKind::Static, type_only, decl.span());
+    }
+
+    fn visit_ts_import_equals_decl(&mut self, decl: &TsImportEqualsDecl) {
+        if let TsModuleRef::TsExternalModuleRef(r) = &decl.module_ref {
+            self.add(
+                &r.expr.value,
+                ImportKind::Require,
+                decl.is_type_only,
+                decl.span,
+            );
+        }
+    }
+
+    fn visit_call_expr(&mut self, call: &CallExpr) {
+        call.visit_children_with(self);
+
+        let kind = match &call.callee {
+            Callee::Import(..) => ImportKind::Dynamic,
+            Callee::Expr(callee) if matches!(&**callee, Expr::Ident(i) if i.sym == "require") => {
+                ImportKind::Require
+            }
+            _ => return,
+        };
+
+        let specifier = match call.args.first() {
+            Some(ExprOrSpread { spread: None, expr }) => match &**expr {
+                Expr::Lit(Lit::Str(s)) => s.value.clone(),
+                Expr::Tpl(tpl) if tpl.exprs.is_empty() => match tpl.quasis[0].cooked.clone() {
+                    Some(cooked) => cooked,
+                    None => return,
+                },
+                _ => return,
+            },
+            _ => return,
+        };
+
+        self.add(&specifier, kind, false, call.span);
+    }
+}
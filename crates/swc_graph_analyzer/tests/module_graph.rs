@@ -0,0 +1,205 @@
+use anyhow::{bail, Error};
+use swc_common::{sync::Lrc, FileName, SourceMap};
+use swc_ecma_ast::{EsVersion, Program};
+use swc_ecma_loader::resolve::{Resolution, Resolve};
+use swc_ecma_parser::{parse_file_as_program, Syntax};
+use swc_graph_analyzer::module_graph::{Import, ImportKind, Load, ModuleGraph, ModuleId};
+
+/// Resolves `./name` to a file named `name`.
+struct TestResolver;
+
+impl Resolve for TestResolver {
+    fn resolve(&self, _: &FileName, src: &str) -> Result<Resolution, Error> {
+        match src.strip_prefix("./") {
+            Some(name) => Ok(Resolution {
+                filename: FileName::Custom(name.to_string()),
+                slug: None,
+            }),
+            None => bail!("cannot resolve `{}`", src),
+        }
+    }
+}
+
+struct TestLoader {
+    cm: Lrc<SourceMap>,
+    files: &'static [(&'static str, &'static str)],
+}
+
+impl Load for TestLoader {
+    fn load(&self, file: &FileName) -> Result<Option<Program>, Error> {
+        let FileName::Custom(name) = file else {
+            unreachable!()
+        };
+
+        let Some((_, src)) = self.files.iter().find(|(n, _)| n == name) else {
+            return Ok(None);
+        };
+
+        let fm = self
+            .cm
+            .new_source_file(file.clone().into(), src.to_string());
+
+        Ok(Some(
+            parse_file_as_program(
+                &fm,
+                Syntax::Typescript(Default::default()),
+                EsVersion::latest(),
+                None,
+                &mut Vec::new(),
+            )
+            .unwrap(),
+        ))
+    }
+}
+
+const FILES: &[(&str, &str)] = &[
+    (
+        "main",
+        "import { a } from './a';
+        import type { T } from './types';
+        export * from './b';
+        const lazy = () => import('./lazy');
+        const c = require(`./c`);
+        import 'pkg';",
+    ),
+    ("a", "import { b } from './b'; export const a = 1;"),
+    ("b", "import { a } from './a'; export const b = 1;"),
+    ("c", "import x = require('./main');"),
+    ("lazy", "export {};"),
+    (
+        "types",
+        "import type { Main } from './main'; export type T = 1;",
+    ),
+];
+
+fn load() -> ModuleGraph {
+    ModuleGraph::load(
+        &[FileName::Custom("main".into())],
+        &TestResolver,
+        &TestLoader {
+            cm: Default::default(),
+            files: FILES,
+        },
+    )
+    .unwrap()
+}
+
+fn id(graph: &ModuleGraph, name: &str) -> ModuleId {
+    graph.module_id(&FileName::Custom(name.into())).unwrap()
+}
+
+fn names(graph: &ModuleGraph, ids: Vec<ModuleId>) -> Vec<String> {
+    let mut names: Vec<_> = ids
+        .into_iter()
+        .map(|id| graph.file_name(id).to_string())
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn imports_are_flagged() {
+    let graph = load();
+
+    let imports: Vec<_> = graph
+        .dependencies(id(&graph, "main"))
+        .map(|import| {
+            (
+                graph.file_name(import.to).to_string(),
+                import.kind,
+                import.type_only,
+            )
+        })
+        .collect();
+
+    assert_eq!(
+        imports,
+        vec![
+            ("a".into(), ImportKind::Static, false),
+            ("types".into(), ImportKind::Static, true),
+            ("b".into(), ImportKind::Static, false),
+            ("lazy".into(), ImportKind::Dynamic, false),
+            ("c".into(), ImportKind::Require, false),
+        ]
+    );
+
+    let unresolved = graph.unresolved_imports();
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(&*unresolved[0].specifier, "pkg");
+}
+
+#[test]
+fn reachability() {
+    let graph = load();
+
+    assert_eq!(
+        names(
+            &graph,
+            graph.reachable_from(&[id(&graph, "main")], |import| {
+                import.kind == ImportKind::Static && !import.type_only
+            })
+        ),
+        vec!["a", "b", "main"]
+    );
+    assert_eq!(
+        names(
+            &graph,
+            graph.reachable_from(&[id(&graph, "main")], |_| true)
+        ),
+        vec!["a", "b", "c", "lazy", "main", "types"]
+    );
+}
+
+#[test]
+fn reverse_dependencies() {
+    let graph = load();
+
+    assert_eq!(
+        names(
+            &graph,
+            graph
+                .dependents(id(&graph, "a"))
+                .map(|import| import.from)
+                .collect()
+        ),
+        vec!["b", "main"]
+    );
+    assert_eq!(
+        names(
+            &graph,
+            graph.transitive_dependents(&[id(&graph, "lazy")], |_| true)
+        ),
+        vec!["c", "lazy", "main", "types"]
+    );
+    assert_eq!(
+        names(
+            &graph,
+            graph.transitive_dependents(&[id(&graph, "lazy")], |import| !import.type_only)
+        ),
+        vec!["c", "lazy", "main"]
+    );
+}
+
+#[test]
+fn cycles() {
+    let graph = load();
+
+    let cycles = |follow: &dyn Fn(&Import) -> bool| {
+        let mut cycles: Vec<_> = graph
+            .cycles(follow)
+            .into_iter()
+            .map(|cycle| names(&graph, cycle))
+            .collect();
+        cycles.sort();
+        cycles
+    };
+
+    assert_eq!(
+        cycles(&|import| import.kind == ImportKind::Static && !import.type_only),
+        vec![vec!["a", "b"]]
+    );
+    assert_eq!(
+        cycles(&|_| true),
+        vec![vec!["a", "b"], vec!["c", "main"], vec!["main", "types"]]
+    );
+}
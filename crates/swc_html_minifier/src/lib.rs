@@ -1222,6 +1222,13 @@ impl<C: MinifyCss> Minifier<'_, C> {
                 && right.tag_name == "script";
 
             if is_style_tag || is_script_tag {
+                // Merged elements are minified
+                if self.is_content_minification_skipped(left)
+                    || self.is_content_minification_skipped(right)
+                {
+                    return false;
+                }
+
                 let mut need_skip = false;
 
                 let mut left_attributes = left
@@ -1753,6 +1760,17 @@ impl<C: MinifyCss> Minifier<'_, C> {
         type_attribute_value
     }
 
+    fn is_content_minification_skipped(&self, element: &Element) -> bool {
+        match &self.options.skip_content_minification_attributes {
+            Some(patterns) => element.attributes.iter().any(|attribute| {
+                patterns
+                    .iter()
+                    .any(|pattern| pattern.is_match(&attribute.name))
+            }),
+            None => false,
+        }
+    }
+
     fn is_additional_scripts_content(&self, name: &str) -> Option<MinifierType> {
         if let Some(minify_additional_scripts_content) =
             &self.options.minify_additional_scripts_content
@@ -2106,6 +2124,139 @@ impl<C: MinifyCss> Minifier<'_, C> {
         }
     }
 
+    fn is_srcset_attribute(&self, element: &Element, attribute: &Attribute) -> bool {
+        element.namespace == Namespace::HTML
+            && matches!(
+                (&*element.tag_name, &*attribute.name),
+                ("img" | "source", "srcset") | ("link", "imagesrcset")
+            )
+    }
+
+    /// Returns `None` if `value` is not a valid list of image candidates.
+    fn minify_srcset(&self, value: &str) -> Option<String> {
+        let mut candidates = Vec::new();
+        let mut rest = value;
+
+        loop {
+            rest = rest.trim_start_matches(|c: char| is_whitespace(c) || c == ',');
+
+            if rest.is_empty() {
+                break;
+            }
+
+            let url_end = rest.find(is_whitespace).unwrap_or(rest.len());
+            let (url, after_url) = rest.split_at(url_end);
+            let mut descriptors = Vec::new();
+
+            // An url can contain commas, i.e. `data:` urls, but trailing commas
+            // separate candidates
+            if let Some(url) = url.strip_suffix(',') {
+                candidates.push((url.trim_end_matches(','), descriptors));
+                rest = after_url;
+
+                continue;
+            }
+
+            let mut depth = 0usize;
+            let mut descriptors_end = after_url.len();
+
+            for (idx, c) in after_url.char_indices() {
+                match c {
+                    '(' => depth += 1,
+                    ')' => depth = depth.checked_sub(1)?,
+                    ',' if depth == 0 => {
+                        descriptors_end = idx;
+
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+
+            descriptors.extend(
+                after_url[..descriptors_end]
+                    .split(is_whitespace)
+                    .filter(|descriptor| !descriptor.is_empty())
+                    // `1x` is the default pixel density
+                    .filter(|descriptor| {
+                        descriptor
+                            .strip_suffix('x')
+                            .and_then(|density| density.parse::<f64>().ok())
+                            != Some(1.0)
+                    }),
+            );
+
+            candidates.push((url, descriptors));
+            rest = &after_url[descriptors_end..];
+        }
+
+        if candidates.is_empty() || candidates.iter().any(|(url, _)| url.is_empty()) {
+            return None;
+        }
+
+        let mut minified = String::with_capacity(value.len());
+
+        for (url, descriptors) in candidates {
+            if !minified.is_empty() {
+                minified.push(',');
+            }
+
+            minified.push_str(url);
+
+            if descriptors.is_empty() {
+                // Urls end with whitespace, so the comma would be a part of the url
+                minified.push(' ');
+            }
+
+            for descriptor in descriptors {
+                minified.push(' ');
+                minified.push_str(descriptor);
+            }
+        }
+
+        Some(minified.trim_end().to_string())
+    }
+
+    /// Returns `None` if `value` is not a list of `key=value` properties.
+    fn minify_meta_viewport(&self, value: &str) -> Option<String> {
+        let mut properties = value
+            .split(',')
+            .map(str::trim)
+            .filter(|property| !property.is_empty())
+            .map(|property| {
+                let (key, value) = property.split_once('=')?;
+                let (key, value) = (key.trim(), value.trim());
+
+                if key.is_empty() || value.is_empty() || key.contains(is_whitespace) {
+                    return None;
+                }
+
+                // Remove trailing zeros of numbers, i.e. `1.0` to `1`
+                let value = if value.contains('.')
+                    && value.chars().all(|c| c.is_ascii_digit() || c == '.')
+                    && value.parse::<f64>().is_ok()
+                {
+                    value.trim_end_matches('0').trim_end_matches('.')
+                } else {
+                    value
+                };
+
+                Some((key, if value.is_empty() { "0" } else { value }))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        // The order of properties doesn't matter
+        properties.sort_by(|a, b| a.0.cmp(b.0));
+
+        Some(
+            properties
+                .into_iter()
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+
     fn minify_sizes(&self, value: &str) -> Option<String> {
         let values = value
             .rsplitn(2, ['\t', '\n', '\x0C', '\r', ' '])
@@ -2242,6 +2393,8 @@ impl<C: MinifyCss> Minifier<'_, C> {
     }
 
     fn minify_attribute(&self, element: &Element, n: &mut Attribute) {
+        let minify_content = !self.is_content_minification_skipped(element);
+
         if let Some(value) = &n.value {
             if value.is_empty() {
                 if (self.options.collapse_boolean_attributes
@@ -2294,7 +2447,7 @@ impl<C: MinifyCss> Minifier<'_, C> {
                         }
                     }
 
-                    if self.need_minify_js() {
+                    if minify_content && self.need_minify_js() {
                         if let Some(minified) = self.minify_js(value, false, true) {
                             n.value = Some(minified.into());
                         };
@@ -2370,6 +2523,21 @@ impl<C: MinifyCss> Minifier<'_, C> {
                             .into(),
                     );
                 }
+                _ if self.options.minify_srcset && self.is_srcset_attribute(element, n) => {
+                    if let Some(minified) = self.minify_srcset(value) {
+                        n.value = Some(minified.into());
+                    }
+                }
+                _ if self.options.minify_meta_viewport
+                    && element.namespace == Namespace::HTML
+                    && element.tag_name == "meta"
+                    && n.name == "content"
+                    && self.element_has_attribute_with_value(element, "name", &["viewport"]) =>
+                {
+                    if let Some(minified) = self.minify_meta_viewport(value) {
+                        n.value = Some(minified.into());
+                    }
+                }
                 _ if self.is_comma_separated_attribute(element, n) => {
                     let mut value = value.to_string();
 
@@ -2396,7 +2564,11 @@ impl<C: MinifyCss> Minifier<'_, C> {
                             .join(",");
                     }
 
-                    if self.need_minify_css() && n.name == "media" && !value.is_empty() {
+                    if minify_content
+                        && self.need_minify_css()
+                        && n.name == "media"
+                        && !value.is_empty()
+                    {
                         if let Some(minified) =
                             self.minify_css(value, CssMinificationMode::MediaQueryList)
                         {
@@ -2415,7 +2587,11 @@ impl<C: MinifyCss> Minifier<'_, C> {
                         }
                     };
 
-                    if self.need_minify_css() && n.name == "style" && !value.is_empty() {
+                    if minify_content
+                        && self.need_minify_css()
+                        && n.name == "style"
+                        && !value.is_empty()
+                    {
                         let value = value.trim();
 
                         if let Some(minified) = self
@@ -2425,7 +2601,10 @@ impl<C: MinifyCss> Minifier<'_, C> {
                         } else {
                             fallback(n);
                         }
-                    } else if self.need_minify_js() && self.is_javascript_url_element(element) {
+                    } else if minify_content
+                        && self.need_minify_js()
+                        && self.is_javascript_url_element(element)
+                    {
                         if value.trim().to_lowercase().starts_with("javascript:") {
                             value = value.trim().chars().skip(11).collect();
 
@@ -2447,22 +2626,22 @@ impl<C: MinifyCss> Minifier<'_, C> {
                 }
                 _ if self.options.minify_additional_attributes.is_some() => {
                     match self.is_additional_minifier_attribute(&n.name) {
-                        Some(MinifierType::JsScript) if self.need_minify_js() => {
+                        Some(MinifierType::JsScript) if minify_content && self.need_minify_js() => {
                             if let Some(minified) = self.minify_js(value.to_string(), false, true) {
                                 n.value = Some(minified.into());
                             }
                         }
-                        Some(MinifierType::JsModule) if self.need_minify_js() => {
+                        Some(MinifierType::JsModule) if minify_content && self.need_minify_js() => {
                             if let Some(minified) = self.minify_js(value.to_string(), true, true) {
                                 n.value = Some(minified.into());
                             }
                         }
-                        Some(MinifierType::Json) if self.need_minify_json() => {
+                        Some(MinifierType::Json) if minify_content && self.need_minify_json() => {
                             if let Some(minified) = self.minify_json(value.to_string()) {
                                 n.value = Some(minified.into());
                             }
                         }
-                        Some(MinifierType::Css) if self.need_minify_css() => {
+                        Some(MinifierType::Css) if minify_content && self.need_minify_css() => {
                             if let Some(minified) = self.minify_css(
                                 value.to_string(),
                                 CssMinificationMode::ListOfDeclarations,
@@ -2470,7 +2649,7 @@ impl<C: MinifyCss> Minifier<'_, C> {
                                 n.value = Some(minified.into());
                             }
                         }
-                        Some(MinifierType::Html) => {
+                        Some(MinifierType::Html) if minify_content => {
                             if let Some(minified) = self.minify_html(
                                 value.to_string(),
                                 HtmlMinificationMode::DocumentIframeSrcdoc,
@@ -2491,8 +2670,9 @@ impl<C: MinifyCss> VisitMut for Minifier<'_, C> {
     fn visit_mut_document(&mut self, n: &mut Document) {
         n.visit_mut_children_with(self);
 
-        n.children
-            .retain(|child| !matches!(child, Child::Comment(_) if self.options.remove_comments));
+        n.children.retain(|child| {
+            !matches!(child, Child::Comment(comment) if self.options.remove_comments && !self.is_preserved_comment(&comment.data))
+        });
     }
 
     fn visit_mut_document_fragment(&mut self, n: &mut DocumentFragment) {
@@ -2649,7 +2829,11 @@ impl<C: MinifyCss> VisitMut for Minifier<'_, C> {
 
         let mut text_type = None;
 
-        if let Some(current_element) = &self.current_element {
+        if let Some(current_element) = self
+            .current_element
+            .as_ref()
+            .filter(|element| !self.is_content_minification_skipped(element))
+        {
             match &*current_element.tag_name {
                 "script"
                     if (self.need_minify_json() || self.need_minify_js())
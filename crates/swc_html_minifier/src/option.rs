@@ -169,6 +169,15 @@ pub struct MinifyOptions<CO> {
     /// attributes
     #[serde(default = "true_by_default")]
     pub normalize_attributes: bool,
+    /// Remove extra whitespace and default `1x` descriptors in `srcset`
+    /// attributes
+    #[serde(default = "true_by_default")]
+    pub minify_srcset: bool,
+    /// Sort properties and minify numbers in the `content` attribute of
+    /// `<meta name="viewport">`, i.e. `width=device-width, initial-scale=1.0`
+    /// to `initial-scale=1,width=device-width`
+    #[serde(default = "true_by_default")]
+    pub minify_meta_viewport: bool,
     #[serde(default = "minify_json_by_default")]
     pub minify_json: MinifyJsonOption,
     #[serde(default = "minify_js_by_default")]
@@ -190,6 +199,14 @@ pub struct MinifyOptions<CO> {
     /// The second is attribute name
     /// The third is type of minifier
     pub minify_additional_attributes: Option<Vec<(CachedRegex, MinifierType)>>,
+    /// Opt out elements from the minification of scripts and styles, i.e.
+    /// `<script data-no-minify>...</script>` is kept as-is
+    ///
+    /// Elements which have an attribute matching one of these patterns keep the
+    /// content of `script` and `style` elements and the values of the `style`,
+    /// event handler and other minified attributes
+    #[serde(default = "default_skip_content_minification_attributes")]
+    pub skip_content_minification_attributes: Option<Vec<CachedRegex>>,
     /// Sorting the values of `class`, `rel`, etc. of attributes
     #[serde(default = "true_by_default")]
     pub sort_space_separated_attribute_values: bool,
@@ -220,6 +237,10 @@ const fn minify_css_by_default<CO>() -> MinifyCssOption<CO> {
     MinifyCssOption::Bool(true)
 }
 
+fn default_skip_content_minification_attributes() -> Option<Vec<CachedRegex>> {
+    Some(vec![CachedRegex::new("^data-no-minify$").unwrap()])
+}
+
 fn default_preserve_comments() -> Option<Vec<CachedRegex>> {
     Some(vec![
         // License comments
@@ -234,5 +255,9 @@ fn default_preserve_comments() -> Option<Vec<CachedRegex>> {
         // Conditional IE comments
         CachedRegex::new("^\\[if\\s[^\\]+]").unwrap(),
         CachedRegex::new("\\[endif]").unwrap(),
+        // Hydration markers of frameworks, i.e. `<!--$-->` and `<!--/$-->` of React
+        // and `<!--[-->` and `<!--]-->` of Vue
+        CachedRegex::new("^/?\\$[?!]?$").unwrap(),
+        CachedRegex::new("^[\\[\\]]$").unwrap(),
     ])
 }
@@ -0,0 +1,84 @@
+use swc_atoms::Atom;
+use swc_common::{EqIgnoreSpan, Span, Spanned};
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub enum Value {
+    Null(Null),
+    Bool(Bool),
+    Number(Number),
+    Str(Str),
+    Array(Array),
+    Object(Object),
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct Null {
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct Bool {
+    pub span: Span,
+    pub value: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct Number {
+    pub span: Span,
+    pub value: f64,
+    /// Use `None` value only for transformations to avoid recalculate
+    /// characters in number literal
+    pub raw: Option<Atom>,
+}
+
+impl Number {
+    /// Returns the value as an integer if the number is written without a
+    /// fraction or an exponent, e.g. `42` but not `42.0`.
+    pub fn as_i128(&self) -> Option<i128> {
+        match &self.raw {
+            Some(raw) => raw.parse().ok(),
+            None => (self.value.fract() == 0.0).then_some(self.value as i128),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct Str {
+    pub span: Span,
+    pub value: Atom,
+    /// Use `None` value only for transformations to avoid recalculate escaped
+    /// characters in strings
+    pub raw: Option<Atom>,
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct Array {
+    pub span: Span,
+    pub elems: Vec<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct Object {
+    pub span: Span,
+    pub props: Vec<KeyValueProp>,
+}
+
+impl Object {
+    /// Returns the value of the property named `key`.
+    ///
+    /// If the key is duplicated, the last one wins, like `JSON.parse`.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.props
+            .iter()
+            .rev()
+            .find(|prop| prop.key.value == *key)
+            .map(|prop| &prop.value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, EqIgnoreSpan, Spanned)]
+pub struct KeyValueProp {
+    pub span: Span,
+    pub key: Str,
+    pub value: Value,
+}
@@ -0,0 +1,276 @@
+use serde::{
+    de::{
+        self, DeserializeOwned, DeserializeSeed, EnumAccess, MapAccess, SeqAccess, VariantAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any, Deserialize, Deserializer,
+};
+use swc_common::{Span, Spanned};
+
+use crate::{ast::*, error::Error};
+
+/// Deserializes `T` from a parsed value.
+///
+/// Unlike deserializing with `serde_json`, errors have the span of the value
+/// which failed to deserialize, e.g. the key of an unknown field.
+pub fn from_value<T>(value: &Value) -> Result<T, Error>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(value)
+}
+
+impl<'de> Deserializer<'de> for &'de Value {
+    type Error = Error;
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let res = match self {
+            Value::Null(..) => visitor.visit_unit(),
+            Value::Bool(b) => visitor.visit_bool(b.value),
+            Value::Number(n) => match n.as_i128() {
+                Some(value) => match (u64::try_from(value), i64::try_from(value)) {
+                    (Ok(value), _) => visitor.visit_u64(value),
+                    (_, Ok(value)) => visitor.visit_i64(value),
+                    _ => visitor.visit_f64(n.value),
+                },
+                None => visitor.visit_f64(n.value),
+            },
+            Value::Str(s) => visitor.visit_borrowed_str(&s.value),
+            Value::Array(array) => visitor.visit_seq(SeqDeserializer {
+                iter: array.elems.iter(),
+            }),
+            Value::Object(object) => visitor.visit_map(MapDeserializer {
+                iter: object.props.iter(),
+                value: None,
+            }),
+        };
+
+        res.map_err(|err| err.or_span(self.span()))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let res = match self {
+            Value::Null(..) => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        };
+
+        res.map_err(|err| err.or_span(self.span()))
+    }
+
+    fn deserialize_newtype_struct<V>(self, _: &'static str, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor
+            .visit_newtype_struct(self)
+            .map_err(|err| err.or_span(self.span()))
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        let res = match self {
+            // A unit variant
+            Value::Str(s) => visitor.visit_enum(EnumDeserializer {
+                variant: s,
+                value: None,
+            }),
+            // `{ "Variant": value }`
+            Value::Object(Object { props, .. }) if props.len() == 1 => {
+                visitor.visit_enum(EnumDeserializer {
+                    variant: &props[0].key,
+                    value: Some(&props[0].value),
+                })
+            }
+            _ => Err(de::Error::invalid_type(self.unexpected(), &"string or map")),
+        };
+
+        res.map_err(|err| err.or_span(self.span()))
+    }
+}
+
+impl Value {
+    fn unexpected(&self) -> de::Unexpected<'_> {
+        match self {
+            Value::Null(..) => de::Unexpected::Unit,
+            Value::Bool(b) => de::Unexpected::Bool(b.value),
+            Value::Number(n) => de::Unexpected::Float(n.value),
+            Value::Str(s) => de::Unexpected::Str(&s.value),
+            Value::Array(..) => de::Unexpected::Seq,
+            Value::Object(..) => de::Unexpected::Map,
+        }
+    }
+}
+
+/// Deserializes keys of objects.
+struct KeyDeserializer<'de> {
+    key: &'de Str,
+}
+
+impl<'de> Deserializer<'de> for KeyDeserializer<'de> {
+    type Error = Error;
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        visitor
+            .visit_borrowed_str(&self.key.value)
+            .map_err(|err: Error| err.or_span(self.key.span))
+    }
+}
+
+struct SeqDeserializer<'de> {
+    iter: std::slice::Iter<'de, Value>,
+}
+
+impl<'de> SeqAccess<'de> for SeqDeserializer<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some(value) => seed.deserialize(value).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct MapDeserializer<'de> {
+    iter: std::slice::Iter<'de, KeyValueProp>,
+    value: Option<&'de Value>,
+}
+
+impl<'de> MapAccess<'de> for MapDeserializer<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K>(&mut self, seed: K) -> Result<Option<K::Value>, Error>
+    where
+        K: DeserializeSeed<'de>,
+    {
+        match self.iter.next() {
+            Some(prop) => {
+                self.value = Some(&prop.value);
+
+                seed.deserialize(KeyDeserializer { key: &prop.key })
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V>(&mut self, seed: V) -> Result<V::Value, Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        match self.value.take() {
+            Some(value) => seed.deserialize(value),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+struct EnumDeserializer<'de> {
+    variant: &'de Str,
+    value: Option<&'de Value>,
+}
+
+impl<'de> EnumAccess<'de> for EnumDeserializer<'de> {
+    type Error = Error;
+    type Variant = VariantDeserializer<'de>;
+
+    fn variant_seed<V>(self, seed: V) -> Result<(V::Value, Self::Variant), Error>
+    where
+        V: DeserializeSeed<'de>,
+    {
+        let variant = seed.deserialize(KeyDeserializer { key: self.variant })?;
+
+        Ok((
+            variant,
+            VariantDeserializer {
+                span: self.variant.span,
+                value: self.value,
+            },
+        ))
+    }
+}
+
+struct VariantDeserializer<'de> {
+    span: Span,
+    value: Option<&'de Value>,
+}
+
+impl<'de> VariantDeserializer<'de> {
+    fn value(self) -> Result<&'de Value, Error> {
+        self.value.ok_or_else(|| {
+            <Error as de::Error>::invalid_type(de::Unexpected::UnitVariant, &"non-unit variant")
+                .or_span(self.span)
+        })
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantDeserializer<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            Some(value) => Deserialize::deserialize(value),
+            None => Ok(()),
+        }
+    }
+
+    fn newtype_variant_seed<T>(self, seed: T) -> Result<T::Value, Error>
+    where
+        T: DeserializeSeed<'de>,
+    {
+        seed.deserialize(self.value()?)
+    }
+
+    fn tuple_variant<V>(self, _: usize, visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value()?.deserialize_seq(visitor)
+    }
+
+    fn struct_variant<V>(self, _: &'static [&'static str], visitor: V) -> Result<V::Value, Error>
+    where
+        V: Visitor<'de>,
+    {
+        self.value()?.deserialize_map(visitor)
+    }
+}
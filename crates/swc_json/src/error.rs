@@ -0,0 +1,106 @@
+use std::{borrow::Cow, fmt};
+
+use swc_common::{
+    errors::{DiagnosticBuilder, Handler},
+    Span, DUMMY_SP,
+};
+
+/// Size is same as a size of a pointer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    inner: Box<(Span, ErrorKind)>,
+}
+
+impl Error {
+    pub fn kind(&self) -> &ErrorKind {
+        &self.inner.1
+    }
+
+    pub fn span(&self) -> Span {
+        self.inner.0
+    }
+
+    pub fn into_inner(self) -> Box<(Span, ErrorKind)> {
+        self.inner
+    }
+
+    pub fn new(span: Span, kind: ErrorKind) -> Self {
+        Error {
+            inner: Box::new((span, kind)),
+        }
+    }
+
+    /// Sets the span of the error if it's not known yet.
+    pub(crate) fn or_span(mut self, span: Span) -> Self {
+        if self.inner.0.is_dummy() {
+            self.inner.0 = span;
+        }
+
+        self
+    }
+
+    pub fn message(&self) -> Cow<'static, str> {
+        match &self.inner.1 {
+            ErrorKind::Eof => "Unexpected end of file".into(),
+            ErrorKind::UnexpectedChar(c) => format!("Unexpected character {:?}", c).into(),
+            ErrorKind::Expected(s) => format!("Expected {}", s).into(),
+            ErrorKind::InvalidNumber => "Invalid number".into(),
+            ErrorKind::InvalidEscape => "Invalid escape sequence".into(),
+            ErrorKind::InvalidUnicodeEscape => "Invalid unicode escape sequence".into(),
+            ErrorKind::UnterminatedString => "Unterminated string".into(),
+            ErrorKind::ControlCharacterInString => {
+                "Control characters must be escaped in strings".into()
+            }
+            ErrorKind::UnterminatedComment => "Unterminated comment".into(),
+            ErrorKind::CommentNotAllowed => "Comments are not allowed".into(),
+            ErrorKind::TrailingCommaNotAllowed => "Trailing commas are not allowed".into(),
+            ErrorKind::RecursionLimitExceeded => "Recursion limit exceeded".into(),
+            ErrorKind::Deserialize(msg) => msg.clone().into(),
+        }
+    }
+
+    pub fn to_diagnostics<'a>(&self, handler: &'a Handler) -> DiagnosticBuilder<'a> {
+        handler.struct_span_err(self.inner.0, &self.message())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::new(DUMMY_SP, ErrorKind::Deserialize(msg.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    Eof,
+    UnexpectedChar(char),
+    Expected(&'static str),
+    InvalidNumber,
+    InvalidEscape,
+    InvalidUnicodeEscape,
+    UnterminatedString,
+    ControlCharacterInString,
+    UnterminatedComment,
+    /// Comments are only allowed by [ParserConfig::allow_comments].
+    ///
+    /// [ParserConfig::allow_comments]: crate::parser::ParserConfig::allow_comments
+    CommentNotAllowed,
+    /// Trailing commas are only allowed by
+    /// [ParserConfig::allow_trailing_commas].
+    ///
+    /// [ParserConfig::allow_trailing_commas]: crate::parser::ParserConfig::allow_trailing_commas
+    TrailingCommaNotAllowed,
+    RecursionLimitExceeded,
+
+    /// An error of [crate::from_value].
+    Deserialize(String),
+}
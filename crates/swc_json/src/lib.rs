@@ -0,0 +1,35 @@
+//! A parser for JSON and JSONC, i.e. JSON with comments and trailing commas.
+//!
+//! Unlike `serde_json`, the parsed [Value] keeps the span of each node, so
+//! both syntax errors and errors of [from_value] can be reported with the
+//! location in the source file.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(clippy::all)]
+
+use swc_common::SourceFile;
+
+pub use self::de::from_value;
+use crate::{
+    ast::Value,
+    error::Error,
+    parser::{PResult, Parser, ParserConfig},
+};
+
+pub mod ast;
+mod de;
+pub mod error;
+pub mod parser;
+
+/// Parses a given file as a single JSON value.
+pub fn parse_file(fm: &SourceFile, config: ParserConfig) -> PResult<Value> {
+    Parser::new(fm, config).parse_all()
+}
+
+/// Parses a given file and deserializes it as `T`.
+pub fn from_file<T>(fm: &SourceFile, config: ParserConfig) -> Result<T, Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    from_value(&parse_file(fm, config)?)
+}
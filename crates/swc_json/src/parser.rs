@@ -0,0 +1,476 @@
+use swc_common::{BytePos, SourceFile, Span};
+
+use crate::{
+    ast::*,
+    error::{Error, ErrorKind},
+};
+
+pub type PResult<T> = Result<T, Error>;
+
+/// Arrays and objects nested deeper than this are rejected, to avoid stack
+/// overflows.
+const RECURSION_LIMIT: usize = 128;
+
+/// The default config parses strict JSON.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ParserConfig {
+    /// Allows `//` and `/* */` comments, like JSONC.
+    pub allow_comments: bool,
+
+    /// Allows a comma after the last element of arrays and objects, like
+    /// JSONC.
+    pub allow_trailing_commas: bool,
+}
+
+impl ParserConfig {
+    /// Config for JSON with comments, which is used by files like `.swcrc`
+    /// and `tsconfig.json`.
+    pub fn jsonc() -> Self {
+        ParserConfig {
+            allow_comments: true,
+            allow_trailing_commas: true,
+        }
+    }
+}
+
+pub struct Parser<'a> {
+    input: &'a str,
+    start_pos: BytePos,
+    pos: usize,
+    config: ParserConfig,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(fm: &'a SourceFile, config: ParserConfig) -> Self {
+        let start_pos = fm.start_pos;
+        let input = &**fm.src;
+
+        // Byte order mark
+        let pos = if input.starts_with('\u{feff}') {
+            '\u{feff}'.len_utf8()
+        } else {
+            0
+        };
+
+        Parser {
+            input,
+            start_pos,
+            pos,
+            config,
+            depth: 0,
+        }
+    }
+
+    /// Parses the whole input as a single value.
+    pub fn parse_all(&mut self) -> PResult<Value> {
+        self.skip_trivia()?;
+
+        let value = self.parse_value()?;
+
+        self.skip_trivia()?;
+
+        match self.cur_char() {
+            Some(c) => Err(self.unexpected_char(c)),
+            None => Ok(value),
+        }
+    }
+
+    fn span(&self, start: usize) -> Span {
+        Span::new(
+            self.start_pos + BytePos(start as u32),
+            self.start_pos + BytePos(self.pos as u32),
+        )
+    }
+
+    fn cur(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn cur_char(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.cur() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the span of the current character, `c`.
+    fn char_span(&self, c: char) -> Span {
+        Span::new(
+            self.start_pos + BytePos(self.pos as u32),
+            self.start_pos + BytePos((self.pos + c.len_utf8()) as u32),
+        )
+    }
+
+    fn unexpected_char(&self, c: char) -> Error {
+        Error::new(self.char_span(c), ErrorKind::UnexpectedChar(c))
+    }
+
+    /// Returns an error for the current character, which should have been
+    /// `expected`.
+    fn expected(&self, expected: &'static str) -> Error {
+        match self.cur_char() {
+            Some(c) => Error::new(self.char_span(c), ErrorKind::Expected(expected)),
+            None => Error::new(self.span(self.pos), ErrorKind::Eof),
+        }
+    }
+
+    fn skip_trivia(&mut self) -> PResult<()> {
+        loop {
+            match self.cur() {
+                Some(b' ' | b'\t' | b'\n' | b'\r') => {
+                    self.pos += 1;
+                }
+                Some(b'/') => self.skip_comment()?,
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    fn skip_comment(&mut self) -> PResult<()> {
+        let start = self.pos;
+        let rest = &self.input[self.pos..];
+
+        let len = if rest.starts_with("//") {
+            rest.find(['\n', '\r']).unwrap_or(rest.len())
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            match comment.find("*/") {
+                Some(end) => end + 4,
+                None => {
+                    self.pos = self.input.len();
+
+                    return Err(Error::new(self.span(start), ErrorKind::UnterminatedComment));
+                }
+            }
+        } else {
+            return Err(self.unexpected_char('/'));
+        };
+
+        self.pos += len;
+
+        if !self.config.allow_comments {
+            return Err(Error::new(self.span(start), ErrorKind::CommentNotAllowed));
+        }
+
+        Ok(())
+    }
+
+    fn parse_value(&mut self) -> PResult<Value> {
+        match self.cur() {
+            Some(b'{') => self.parse_object().map(Value::Object),
+            Some(b'[') => self.parse_array().map(Value::Array),
+            Some(b'"') => self.parse_str().map(Value::Str),
+            Some(b'-' | b'0'..=b'9') => self.parse_number().map(Value::Number),
+            Some(b't') => self
+                .parse_keyword("true")
+                .map(|span| Value::Bool(Bool { span, value: true })),
+            Some(b'f') => self
+                .parse_keyword("false")
+                .map(|span| Value::Bool(Bool { span, value: false })),
+            Some(b'n') => self
+                .parse_keyword("null")
+                .map(|span| Value::Null(Null { span })),
+            _ => Err(self.expected("value")),
+        }
+    }
+
+    fn parse_keyword(&mut self, keyword: &'static str) -> PResult<Span> {
+        let start = self.pos;
+
+        if !self.input[self.pos..].starts_with(keyword) {
+            return Err(self.expected(keyword));
+        }
+
+        self.pos += keyword.len();
+
+        Ok(self.span(start))
+    }
+
+    fn enter(&mut self) -> PResult<()> {
+        self.depth += 1;
+
+        if self.depth > RECURSION_LIMIT {
+            return Err(Error::new(
+                self.span(self.pos - 1),
+                ErrorKind::RecursionLimitExceeded,
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Parses a comma or the end of a list, and returns true if the list is
+    /// closed.
+    fn parse_list_separator(&mut self, end: u8, expected: &'static str) -> PResult<bool> {
+        self.skip_trivia()?;
+
+        if self.eat(end) {
+            return Ok(true);
+        }
+
+        let comma_start = self.pos;
+
+        if !self.eat(b',') {
+            return Err(self.expected(expected));
+        }
+
+        let comma_span = self.span(comma_start);
+
+        self.skip_trivia()?;
+
+        if self.eat(end) {
+            if !self.config.allow_trailing_commas {
+                return Err(Error::new(comma_span, ErrorKind::TrailingCommaNotAllowed));
+            }
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
+
+    fn parse_array(&mut self) -> PResult<Array> {
+        let start = self.pos;
+
+        self.pos += 1;
+        self.enter()?;
+        self.skip_trivia()?;
+
+        let mut elems = Vec::new();
+
+        if !self.eat(b']') {
+            loop {
+                elems.push(self.parse_value()?);
+
+                if self.parse_list_separator(b']', "',' or ']'")? {
+                    break;
+                }
+            }
+        }
+
+        self.depth -= 1;
+
+        Ok(Array {
+            span: self.span(start),
+            elems,
+        })
+    }
+
+    fn parse_object(&mut self) -> PResult<Object> {
+        let start = self.pos;
+
+        self.pos += 1;
+        self.enter()?;
+        self.skip_trivia()?;
+
+        let mut props = Vec::new();
+
+        if !self.eat(b'}') {
+            loop {
+                let prop_start = self.pos;
+
+                if self.cur() != Some(b'"') {
+                    return Err(self.expected("string"));
+                }
+
+                let key = self.parse_str()?;
+
+                self.skip_trivia()?;
+
+                if !self.eat(b':') {
+                    return Err(self.expected("':'"));
+                }
+
+                self.skip_trivia()?;
+
+                let value = self.parse_value()?;
+
+                props.push(KeyValueProp {
+                    span: self.span(prop_start),
+                    key,
+                    value,
+                });
+
+                if self.parse_list_separator(b'}', "',' or '}'")? {
+                    break;
+                }
+            }
+        }
+
+        self.depth -= 1;
+
+        Ok(Object {
+            span: self.span(start),
+            props,
+        })
+    }
+
+    fn parse_str(&mut self) -> PResult<Str> {
+        let start = self.pos;
+        let mut value = String::new();
+
+        self.pos += 1;
+
+        loop {
+            let Some(c) = self.cur_char() else {
+                return Err(Error::new(self.span(start), ErrorKind::UnterminatedString));
+            };
+
+            match c {
+                '"' => {
+                    self.pos += 1;
+                    break;
+                }
+                '\\' => {
+                    value.push(self.parse_escape()?);
+                }
+                '\n' | '\r' => {
+                    return Err(Error::new(self.span(start), ErrorKind::UnterminatedString));
+                }
+                '\u{0}'..='\u{1f}' => {
+                    return Err(Error::new(
+                        self.char_span(c),
+                        ErrorKind::ControlCharacterInString,
+                    ));
+                }
+                _ => {
+                    self.pos += c.len_utf8();
+                    value.push(c);
+                }
+            }
+        }
+
+        Ok(Str {
+            span: self.span(start),
+            value: value.into(),
+            raw: Some(self.input[start..self.pos].into()),
+        })
+    }
+
+    fn parse_escape(&mut self) -> PResult<char> {
+        let start = self.pos;
+
+        self.pos += 1;
+
+        let c = match self.cur() {
+            Some(b'"') => '"',
+            Some(b'\\') => '\\',
+            Some(b'/') => '/',
+            Some(b'b') => '\u{8}',
+            Some(b'f') => '\u{c}',
+            Some(b'n') => '\n',
+            Some(b'r') => '\r',
+            Some(b't') => '\t',
+            Some(b'u') => {
+                self.pos += 1;
+
+                let high = self.parse_hex4(start)?;
+
+                if !(0xd800..0xdc00).contains(&high) {
+                    return char::from_u32(high).ok_or_else(|| {
+                        Error::new(self.span(start), ErrorKind::InvalidUnicodeEscape)
+                    });
+                }
+
+                // A surrogate pair, e.g. `😀`
+                if !self.input[self.pos..].starts_with("\\u") {
+                    return Err(Error::new(
+                        self.span(start),
+                        ErrorKind::InvalidUnicodeEscape,
+                    ));
+                }
+
+                self.pos += 2;
+
+                let low = self.parse_hex4(start)?;
+
+                if !(0xdc00..0xe000).contains(&low) {
+                    return Err(Error::new(
+                        self.span(start),
+                        ErrorKind::InvalidUnicodeEscape,
+                    ));
+                }
+
+                let c = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+
+                return char::from_u32(c)
+                    .ok_or_else(|| Error::new(self.span(start), ErrorKind::InvalidUnicodeEscape));
+            }
+            Some(_) => {
+                self.pos += self.cur_char().map_or(1, char::len_utf8);
+
+                return Err(Error::new(self.span(start), ErrorKind::InvalidEscape));
+            }
+            None => return Err(Error::new(self.span(start), ErrorKind::UnterminatedString)),
+        };
+
+        self.pos += 1;
+
+        Ok(c)
+    }
+
+    fn parse_hex4(&mut self, start: usize) -> PResult<u32> {
+        let digits = self
+            .input
+            .get(self.pos..self.pos + 4)
+            .filter(|digits| digits.bytes().all(|b| b.is_ascii_hexdigit()));
+
+        match digits {
+            Some(digits) => {
+                self.pos += 4;
+
+                Ok(u32::from_str_radix(digits, 16).unwrap())
+            }
+            None => Err(Error::new(
+                self.span(start),
+                ErrorKind::InvalidUnicodeEscape,
+            )),
+        }
+    }
+
+    fn eat_digits(&mut self) -> bool {
+        let start = self.pos;
+
+        while matches!(self.cur(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+
+        self.pos != start
+    }
+
+    fn parse_number(&mut self) -> PResult<Number> {
+        let start = self.pos;
+
+        self.eat(b'-');
+
+        // Leading zeros are not allowed
+        let mut is_valid = self.eat(b'0') || self.eat_digits();
+
+        if is_valid && self.eat(b'.') {
+            is_valid = self.eat_digits();
+        }
+
+        if is_valid && (self.eat(b'e') || self.eat(b'E')) {
+            let _ = self.eat(b'+') || self.eat(b'-');
+
+            is_valid = self.eat_digits();
+        }
+
+        let raw = &self.input[start..self.pos];
+
+        match raw.parse() {
+            Ok(value) if is_valid => Ok(Number {
+                span: self.span(start),
+                value,
+                raw: Some(raw.into()),
+            }),
+            _ => Err(Error::new(self.span(start), ErrorKind::InvalidNumber)),
+        }
+    }
+}
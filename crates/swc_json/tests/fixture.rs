@@ -0,0 +1,115 @@
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use swc_json::{from_value, parse_file, parser::ParserConfig};
+use testing::NormalizedOutput;
+
+fn config(input: &Path) -> ParserConfig {
+    match input.extension() {
+        Some(ext) if ext == "jsonc" => ParserConfig::jsonc(),
+        _ => ParserConfig::default(),
+    }
+}
+
+#[testing::fixture("tests/fixture/**/input.json")]
+#[testing::fixture("tests/fixture/**/input.jsonc")]
+fn pass(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+
+    testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+        let value = match parse_file(&fm, config(&input)) {
+            Ok(value) => value,
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+
+                return Err(());
+            }
+        };
+
+        let value: serde_json::Value = from_value(&value).expect("failed to deserialize");
+
+        NormalizedOutput::from(serde_json::to_string_pretty(&value).unwrap())
+            .compare_to_file(dir.join("output.json"))
+            .unwrap();
+
+        Ok(())
+    })
+    .unwrap();
+}
+
+#[testing::fixture("tests/errors/**/input.json")]
+#[testing::fixture("tests/errors/**/input.jsonc")]
+fn errors(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+
+    let stderr = testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+
+        if let Err(err) = parse_file(&fm, config(&input)) {
+            err.to_diagnostics(&handler).emit();
+        }
+
+        Err::<(), _>(())
+    })
+    .unwrap_err();
+
+    stderr.compare_to_file(dir.join("output.stderr")).unwrap();
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+#[allow(dead_code)]
+struct Config {
+    #[serde(default)]
+    minify: bool,
+    #[serde(default)]
+    env: Option<Env>,
+    #[serde(default)]
+    mode: Option<Mode>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(dead_code)]
+struct Env {
+    targets: Vec<String>,
+    #[serde(default)]
+    core_js: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum Mode {
+    Usage,
+    Entry,
+}
+
+#[testing::fixture("tests/de/**/input.jsonc")]
+fn de(input: PathBuf) {
+    let dir = input.parent().unwrap().to_path_buf();
+
+    let stderr = testing::run_test2(false, |cm, handler| {
+        let fm = cm.load_file(&input).unwrap();
+        let value = parse_file(&fm, ParserConfig::jsonc()).expect("failed to parse");
+
+        match from_value::<Config>(&value) {
+            Ok(config) => {
+                NormalizedOutput::from(format!("{:#?}", config))
+                    .compare_to_file(dir.join("output.txt"))
+                    .unwrap();
+
+                Ok(())
+            }
+            Err(err) => {
+                err.to_diagnostics(&handler).emit();
+
+                Err(())
+            }
+        }
+    });
+
+    if let Err(stderr) = stderr {
+        stderr.compare_to_file(dir.join("output.stderr")).unwrap();
+    }
+}
@@ -57,6 +57,9 @@ pub struct Config {
 
     #[serde(default)]
     pub preserve_symlinks: bool,
+    /// Emit byte-identical output across runs and thread counts.
+    #[serde(default)]
+    pub deterministic: bool,
 }
 
 impl Config {
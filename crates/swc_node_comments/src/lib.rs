@@ -1,16 +1,14 @@
 #![cfg_attr(test, deny(warnings))]
 
-use std::sync::Arc;
-
-use dashmap::DashMap;
-use rustc_hash::FxBuildHasher;
 use swc_atoms::atom;
 use swc_common::{
     comments::{Comment, CommentKind, Comments},
-    BytePos, DUMMY_SP,
+    BytePos, Span, DUMMY_SP,
 };
 
-type CommentMap = Arc<DashMap<BytePos, Vec<Comment>, FxBuildHasher>>;
+pub use self::map::{CommentMap, CommentMapInner};
+
+mod map;
 
 /// Multi-threaded implementation of [Comments]
 #[derive(Clone, Default)]
@@ -19,21 +17,39 @@ pub struct SwcComments {
     pub trailing: CommentMap,
 }
 
+impl SwcComments {
+    /// Returns comments attached to positions in `span`, including `span.hi`,
+    /// in source order.
+    pub fn comments_in(&self, span: Span) -> Vec<Comment> {
+        let mut comments = Vec::new();
+
+        // Trailing comments at a position come before leading comments at the same
+        // position.
+        for (order, map) in [(1, &self.leading), (0, &self.trailing)] {
+            map.with_range(span.lo..=span.hi, |iter| {
+                comments.extend(iter.flat_map(|(pos, comments)| {
+                    comments.iter().map(move |c| ((pos, order), c.clone()))
+                }))
+            });
+        }
+
+        comments.sort_by_key(|(key, _)| *key);
+
+        comments.into_iter().map(|(_, c)| c).collect()
+    }
+}
+
 impl Comments for SwcComments {
     fn add_leading(&self, pos: BytePos, cmt: Comment) {
-        self.leading.entry(pos).or_default().push(cmt);
+        self.leading.push(pos, cmt);
     }
 
     fn add_leading_comments(&self, pos: BytePos, comments: Vec<Comment>) {
-        self.leading.entry(pos).or_default().extend(comments);
+        self.leading.extend(pos, comments);
     }
 
     fn has_leading(&self, pos: BytePos) -> bool {
-        if let Some(v) = self.leading.get(&pos) {
-            !v.is_empty()
-        } else {
-            false
-        }
+        self.leading.contains(pos)
     }
 
     fn move_leading(&self, from: BytePos, to: BytePos) {
@@ -49,27 +65,23 @@ impl Comments for SwcComments {
     }
 
     fn take_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
-        self.leading.remove(&pos).map(|v| v.1)
+        self.leading.remove(pos)
     }
 
     fn get_leading(&self, pos: BytePos) -> Option<Vec<Comment>> {
-        self.leading.get(&pos).map(|v| v.to_owned())
+        self.leading.get(pos).map(|v| v.to_owned())
     }
 
     fn add_trailing(&self, pos: BytePos, cmt: Comment) {
-        self.trailing.entry(pos).or_default().push(cmt)
+        self.trailing.push(pos, cmt)
     }
 
     fn add_trailing_comments(&self, pos: BytePos, comments: Vec<Comment>) {
-        self.trailing.entry(pos).or_default().extend(comments)
+        self.trailing.extend(pos, comments)
     }
 
     fn has_trailing(&self, pos: BytePos) -> bool {
-        if let Some(v) = self.trailing.get(&pos) {
-            !v.is_empty()
-        } else {
-            false
-        }
+        self.trailing.contains(pos)
     }
 
     fn move_trailing(&self, from: BytePos, to: BytePos) {
@@ -85,15 +97,16 @@ impl Comments for SwcComments {
     }
 
     fn take_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
-        self.trailing.remove(&pos).map(|v| v.1)
+        self.trailing.remove(pos)
     }
 
     fn get_trailing(&self, pos: BytePos) -> Option<Vec<Comment>> {
-        self.trailing.get(&pos).map(|v| v.to_owned())
+        self.trailing.get(pos).map(|v| v.to_owned())
     }
 
     fn add_pure_comment(&self, pos: BytePos) {
-        let mut leading = self.leading.entry(pos).or_default();
+        let mut leading = self.leading.write();
+        let leading = leading.entry(pos).or_default();
         let pure_comment = Comment {
             kind: CommentKind::Block,
             span: DUMMY_SP,
@@ -110,7 +123,7 @@ impl Comments for SwcComments {
         Self: Sized,
         F: FnOnce(&[Comment]) -> Ret,
     {
-        let ret = if let Some(cmts) = self.leading.get(&pos) {
+        let ret = if let Some(cmts) = self.leading.get(pos) {
             f(&cmts)
         } else {
             f(&[])
@@ -124,7 +137,7 @@ impl Comments for SwcComments {
         Self: Sized,
         F: FnOnce(&[Comment]) -> Ret,
     {
-        let ret = if let Some(cmts) = &self.trailing.get(&pos) {
+        let ret = if let Some(cmts) = &self.trailing.get(pos) {
             f(cmts)
         } else {
             f(&[])
@@ -0,0 +1,100 @@
+use std::{collections::BTreeMap, ops::RangeBounds, sync::Arc};
+
+use parking_lot::{MappedRwLockReadGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use swc_common::{comments::Comment, BytePos};
+
+pub type CommentMapInner = BTreeMap<BytePos, Vec<Comment>>;
+
+/// Comments indexed by the position they are attached to.
+///
+/// Positions are sorted, so comments can be iterated in source order and
+/// comments in a range can be found without scanning all comments.
+///
+/// Clones share the same storage.
+#[derive(Clone, Default)]
+pub struct CommentMap {
+    inner: Arc<RwLock<CommentMapInner>>,
+}
+
+impl CommentMap {
+    /// Returns comments at `pos`, which may be empty.
+    pub fn get(&self, pos: BytePos) -> Option<MappedRwLockReadGuard<'_, Vec<Comment>>> {
+        RwLockReadGuard::try_map(self.inner.read(), |map| map.get(&pos)).ok()
+    }
+
+    /// Returns true if there's a comment at `pos`.
+    pub fn contains(&self, pos: BytePos) -> bool {
+        self.inner
+            .read()
+            .get(&pos)
+            .map_or(false, |comments| !comments.is_empty())
+    }
+
+    pub fn push(&self, pos: BytePos, comment: Comment) {
+        self.inner.write().entry(pos).or_default().push(comment);
+    }
+
+    pub fn extend(&self, pos: BytePos, comments: impl IntoIterator<Item = Comment>) {
+        self.inner.write().entry(pos).or_default().extend(comments);
+    }
+
+    pub fn remove(&self, pos: BytePos) -> Option<Vec<Comment>> {
+        self.inner.write().remove(&pos)
+    }
+
+    /// Removes all comments in `range`, and returns them in source order.
+    pub fn remove_range(&self, range: impl RangeBounds<BytePos>) -> Vec<(BytePos, Vec<Comment>)> {
+        let mut map = self.inner.write();
+        let positions: Vec<_> = map.range(range).map(|(pos, _)| *pos).collect();
+
+        positions
+            .into_iter()
+            .filter_map(|pos| map.remove_entry(&pos))
+            .collect()
+    }
+
+    /// Calls `op` with comments in `range`, in source order.
+    pub fn with_range<R, F, Ret>(&self, range: R, op: F) -> Ret
+    where
+        R: RangeBounds<BytePos>,
+        F: FnOnce(&mut dyn Iterator<Item = (BytePos, &[Comment])>) -> Ret,
+    {
+        let map = self.inner.read();
+
+        op(&mut map.range(range).map(|(pos, comments)| (*pos, &**comments)))
+    }
+
+    /// Keeps comments at a position only if `f` returns true. `f` may also
+    /// modify the comments.
+    pub fn retain(&self, mut f: impl FnMut(&BytePos, &mut Vec<Comment>) -> bool) {
+        self.inner.write().retain(|pos, comments| f(pos, comments));
+    }
+
+    /// Returns the number of positions with comments.
+    pub fn len(&self) -> usize {
+        self.inner.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.inner.read().is_empty()
+    }
+
+    pub fn clear(&self) {
+        self.inner.write().clear();
+    }
+
+    /// Locks the map for reading, e.g. to iterate over all comments in source
+    /// order.
+    pub fn read(&self) -> RwLockReadGuard<'_, CommentMapInner> {
+        self.inner.read()
+    }
+
+    pub fn write(&self) -> RwLockWriteGuard<'_, CommentMapInner> {
+        self.inner.write()
+    }
+
+    /// Takes all comments out of the map.
+    pub fn take_all(&self) -> CommentMapInner {
+        std::mem::take(&mut *self.inner.write())
+    }
+}
@@ -0,0 +1,59 @@
+use swc_atoms::Atom;
+use swc_common::{
+    comments::{Comment, CommentKind, Comments},
+    BytePos, Span,
+};
+use swc_node_comments::SwcComments;
+
+fn comment(lo: u32, text: &str) -> Comment {
+    Comment {
+        kind: CommentKind::Block,
+        span: Span::new(BytePos(lo), BytePos(lo + 1)),
+        text: Atom::from(text),
+    }
+}
+
+fn texts(comments: Vec<Comment>) -> Vec<Atom> {
+    comments.into_iter().map(|c| c.text).collect()
+}
+
+#[test]
+fn comments_in_span() {
+    let comments = SwcComments::default();
+
+    comments.add_leading(BytePos(30), comment(25, "d"));
+    comments.add_leading(BytePos(10), comment(5, "a"));
+    comments.add_trailing(BytePos(20), comment(21, "b"));
+    comments.add_leading(BytePos(20), comment(22, "c"));
+    comments.add_trailing(BytePos(40), comment(41, "e"));
+
+    assert_eq!(
+        texts(comments.comments_in(Span::new(BytePos(10), BytePos(30)))),
+        vec!["a", "b", "c", "d"]
+    );
+    assert_eq!(
+        texts(comments.comments_in(Span::new(BytePos(11), BytePos(19)))),
+        Vec::<Atom>::new()
+    );
+}
+
+#[test]
+fn iteration_is_sorted() {
+    let comments = SwcComments::default();
+
+    for pos in [5, 3, 9, 1] {
+        comments.add_leading(BytePos(pos), comment(pos, &pos.to_string()));
+    }
+
+    let positions: Vec<_> = comments.leading.read().keys().map(|pos| pos.0).collect();
+    assert_eq!(positions, vec![1, 3, 5, 9]);
+
+    let removed = comments.leading.remove_range(BytePos(3)..BytePos(9));
+    assert_eq!(
+        removed.iter().map(|(pos, _)| pos.0).collect::<Vec<_>>(),
+        vec![3, 5]
+    );
+    assert!(comments.has_leading(BytePos(1)));
+    assert!(!comments.has_leading(BytePos(3)));
+    assert_eq!(comments.leading.len(), 2);
+}
@@ -1,6 +1,10 @@
 #[doc(hidden)]
 pub extern crate tracing;
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::cell::RefCell;
+use std::time::Duration;
+
 use tracing::{info, span::EnteredSpan};
 
 /// Prints time elapsed since `start` when dropped.
@@ -11,6 +15,9 @@ pub struct Timer {
     _span: EnteredSpan,
     #[cfg(not(target_arch = "wasm32"))]
     start: std::time::Instant,
+    /// Index of this timer in the current [profile].
+    #[cfg(not(target_arch = "wasm32"))]
+    index: Option<usize>,
 }
 
 impl Timer {
@@ -21,6 +28,40 @@ impl Timer {
             _span,
             #[cfg(not(target_arch = "wasm32"))]
             start: std::time::Instant::now(),
+            #[cfg(not(target_arch = "wasm32"))]
+            index: None,
+        }
+    }
+
+    /// Creates a timer which is recorded as `name` by [profile].
+    ///
+    /// [timer] calls this with its first argument. This can be used if the
+    /// name is not known at compile time.
+    pub fn with_name(name: &str, _span: EnteredSpan) -> Self {
+        #[cfg(not(target_arch = "wasm32"))]
+        let index = PROFILE.with(|profile| {
+            let mut profile = profile.borrow_mut();
+            let profile = profile.as_mut()?;
+
+            profile.timings.push(Timing {
+                name: name.to_string(),
+                depth: profile.depth,
+                duration: Duration::ZERO,
+            });
+            profile.depth += 1;
+
+            Some(profile.timings.len() - 1)
+        });
+        #[cfg(target_arch = "wasm32")]
+        let _ = name;
+
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            _span,
+            #[cfg(not(target_arch = "wasm32"))]
+            start: std::time::Instant::now(),
+            #[cfg(not(target_arch = "wasm32"))]
+            index,
         }
     }
 }
@@ -29,10 +70,79 @@ impl Timer {
 impl Drop for Timer {
     fn drop(&mut self) {
         let dur = self.start.elapsed();
+
+        if let Some(index) = self.index {
+            PROFILE.with(|profile| {
+                if let Some(profile) = profile.borrow_mut().as_mut() {
+                    if let Some(timing) = profile.timings.get_mut(index) {
+                        timing.duration = dur;
+                    }
+                    profile.depth = profile.depth.saturating_sub(1);
+                }
+            });
+        }
+
         info!(kind = "perf", "Done in {:?}", dur);
     }
 }
 
+/// A timer recorded by [profile].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub name: String,
+    /// The number of timers which were running when this timer started.
+    pub depth: usize,
+    pub duration: Duration,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct Profile {
+    timings: Vec<Timing>,
+    depth: usize,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+thread_local! {
+    static PROFILE: RefCell<Option<Profile>> = const { RefCell::new(None) };
+}
+
+/// Calls `op` and returns timings of [timer]s created on the current thread
+/// while `op` is running, in the order they are started.
+///
+/// Timers created on other threads are not recorded. This returns nothing on
+/// `wasm32`.
+///
+/// # Example usage
+///
+/// ```
+/// use swc_timer::{profile, timer};
+///
+/// let ((), timings) = profile(|| {
+///     let _timer = timer!("operation");
+/// });
+///
+/// assert_eq!(timings[0].name, "operation");
+/// ```
+pub fn profile<F, Ret>(op: F) -> (Ret, Vec<Timing>)
+where
+    F: FnOnce() -> Ret,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let prev = PROFILE.with(|profile| profile.replace(Some(Default::default())));
+        let ret = op();
+        let timings = PROFILE.with(|profile| profile.replace(prev));
+
+        (ret, timings.map(|v| v.timings).unwrap_or_default())
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        (op(), Vec::new())
+    }
+}
+
 /// Creates a timer. For input arguments, see [tracing::span].
 ///
 /// # Convention
@@ -56,6 +166,14 @@ impl Drop for Timer {
 /// ```
 #[macro_export]
 macro_rules! timer {
+    ($name:literal $($args:tt)*) => {{
+        #[cfg(not(target_arch = "wasm32"))]
+        let span = $crate::tracing::span!($crate::tracing::Level::INFO, $name $($args)*).entered();
+
+        #[cfg(not(target_arch = "wasm32"))]
+        $crate::Timer::with_name($name, span)
+    }};
+
     ($($args:tt)*) => {{
         #[cfg(not(target_arch = "wasm32"))]
         let span = $crate::tracing::span!($crate::tracing::Level::INFO, $($args)*).entered();
@@ -9,3 +9,21 @@ fn logging() {
     })
     .unwrap();
 }
+
+#[test]
+fn profile_nested() {
+    let ((), timings) = swc_timer::profile(|| {
+        let _outer = timer!("outer");
+
+        for i in 0..2 {
+            let _inner = timer!("inner", i = i);
+        }
+    });
+
+    let names = timings
+        .iter()
+        .map(|t| (&*t.name, t.depth))
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec![("outer", 0), ("inner", 1), ("inner", 1)]);
+    assert!(timings[0].duration >= timings[1].duration);
+}
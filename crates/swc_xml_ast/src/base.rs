@@ -44,6 +44,8 @@ pub struct DocumentType {
     pub name: Option<Atom>,
     pub public_id: Option<Atom>,
     pub system_id: Option<Atom>,
+    /// The internal DTD subset, i.e. the declarations between `[` and `]`.
+    pub internal_subset: Option<Atom>,
     pub raw: Option<Atom>,
 }
 
@@ -67,6 +69,11 @@ pub enum Namespace {
 #[derive(Eq, Hash, EqIgnoreSpan)]
 pub struct Element {
     pub span: Span,
+    /// The namespace uri, resolved from `xmlns` attributes of the element and
+    /// its ancestors.
+    pub namespace: Option<Atom>,
+    pub prefix: Option<Atom>,
+    /// The local name, without the prefix.
     pub tag_name: Atom,
     pub attributes: Vec<Attribute>,
     pub children: Vec<Child>,
@@ -76,8 +83,11 @@ pub struct Element {
 #[derive(Eq, Hash, EqIgnoreSpan)]
 pub struct Attribute {
     pub span: Span,
+    /// The namespace of the attribute. Attributes without a prefix have no
+    /// namespace, except `xmlns`.
     pub namespace: Option<Namespace>,
     pub prefix: Option<Atom>,
+    /// The local name, without the prefix.
     pub name: Atom,
     pub raw_name: Option<Atom>,
     pub value: Option<Atom>,
@@ -30,6 +30,8 @@ pub enum Token {
         public_id: Option<Atom>,
         // System identifier
         system_id: Option<Atom>,
+        // Internal subset
+        internal_subset: Option<Atom>,
         // Raw value
         raw: Option<Atom>,
     },
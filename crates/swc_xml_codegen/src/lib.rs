@@ -147,9 +147,15 @@ where
             doctype.push(system_id_quote);
         }
 
+        if let Some(internal_subset) = &n.internal_subset {
+            doctype.push_str(" [");
+            doctype.push_str(internal_subset);
+            doctype.push(']');
+        }
+
         doctype.push('>');
 
-        write_raw!(self, n.span, &doctype);
+        write_multiline_raw!(self, n.span, &doctype);
         formatting_newline!(self);
     }
 
@@ -158,6 +164,12 @@ where
         let is_void_element = n.children.is_empty();
 
         write_raw!(self, "<");
+
+        if let Some(prefix) = &n.prefix {
+            write_raw!(self, prefix);
+            write_raw!(self, ":");
+        }
+
         write_raw!(self, &n.tag_name);
 
         if has_attributes {
@@ -189,6 +201,12 @@ where
 
         write_raw!(self, "<");
         write_raw!(self, "/");
+
+        if let Some(prefix) = &n.prefix {
+            write_raw!(self, prefix);
+            write_raw!(self, ":");
+        }
+
         write_raw!(self, &n.tag_name);
         write_raw!(self, ">");
 
@@ -74,6 +74,7 @@ struct Doctype {
     name: Option<String>,
     public_id: Option<String>,
     system_id: Option<String>,
+    internal_subset: Option<String>,
 }
 
 #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug)]
@@ -135,6 +136,9 @@ where
     pending_tokens: VecDeque<TokenAndSpan>,
     doctype_raw: Option<String>,
     current_doctype_token: Option<Doctype>,
+    /// The end of the literal, comment or processing instruction in the
+    /// internal subset, where `]` and quotes don't have their usual meaning.
+    doctype_internal_subset_end: Option<&'static str>,
     current_comment_token: Option<Comment>,
     current_processing_instruction: Option<ProcessingInstruction>,
     current_tag_token: Option<Tag>,
@@ -162,6 +166,7 @@ where
             pending_tokens: VecDeque::new(),
             doctype_raw: None,
             current_doctype_token: None,
+            doctype_internal_subset_end: None,
             current_comment_token: None,
             current_processing_instruction: None,
             current_tag_token: None,
@@ -559,6 +564,7 @@ where
             name: new_name,
             public_id: None,
             system_id: None,
+            internal_subset: None,
         });
     }
 
@@ -641,6 +647,42 @@ where
         }
     }
 
+    fn set_doctype_token_internal_subset(&mut self) {
+        if let Some(Doctype {
+            internal_subset, ..
+        }) = &mut self.current_doctype_token
+        {
+            *internal_subset = Some(String::new());
+        }
+
+        self.doctype_internal_subset_end = None;
+    }
+
+    fn consume_doctype_token_internal_subset_str(&mut self, s: &str) {
+        for c in s.chars() {
+            self.consume();
+            self.append_to_doctype_token_internal_subset(c);
+        }
+    }
+
+    fn append_to_doctype_token_internal_subset(&mut self, c: char) {
+        let start = self.doctype_raw.as_ref().map_or(0, |raw| raw.len());
+
+        self.append_raw_to_doctype_token(c);
+
+        // The raw value is used to keep newlines as-is
+        if let (
+            Some(Doctype {
+                internal_subset: Some(internal_subset),
+                ..
+            }),
+            Some(raw),
+        ) = (&mut self.current_doctype_token, &self.doctype_raw)
+        {
+            internal_subset.push_str(&raw[start..]);
+        }
+    }
+
     fn emit_doctype_token(&mut self) {
         let current_doctype_token = self.current_doctype_token.take().unwrap();
 
@@ -655,6 +697,7 @@ where
             name: current_doctype_token.name.map(Atom::from),
             public_id: current_doctype_token.public_id.map(Atom::from),
             system_id: current_doctype_token.system_id.map(Atom::from),
+            internal_subset: current_doctype_token.internal_subset.map(Atom::from),
             raw: Some(Atom::from(raw)),
         };
 
@@ -2433,6 +2476,7 @@ where
                     // Switch to the doctype internal subset state.
                     Some(c @ '[') => {
                         self.append_raw_to_doctype_token(c);
+                        self.set_doctype_token_internal_subset();
                         self.state = State::DoctypeTypeInternalSubSet;
                     }
                     // EOF
@@ -3022,6 +3066,7 @@ where
                     // Switch to the doctype internal subset state.
                     Some(c @ '[') => {
                         self.append_raw_to_doctype_token(c);
+                        self.set_doctype_token_internal_subset();
                         self.state = State::DoctypeTypeInternalSubSet;
                     }
                     // EOF
@@ -3045,9 +3090,9 @@ where
             State::DoctypeTypeInternalSubSet => {
                 // Consume the next input character:
                 match self.consume_next_char() {
-                    // U+005D RIGHT SQUARE BRACKET (])
-                    // Switch to the CDATA bracket state.
-                    Some(c @ ']') => {
+                    // U+005D RIGHT SQUARE BRACKET (]) outside of a literal
+                    // Switch to the after DOCTYPE name state.
+                    Some(c @ ']') if self.doctype_internal_subset_end.is_none() => {
                         self.append_raw_to_doctype_token(c);
                         self.state = State::AfterDoctypeName;
                     }
@@ -3061,12 +3106,38 @@ where
                         self.reconsume();
                     }
                     // Anything else
-                    // Append the current input character to the current DOCTYPE token's system
-                    // identifier.
+                    // Append the current input character to the current DOCTYPE token's internal
+                    // subset.
                     Some(c) => {
                         // TODO improve parse legacy declarations
                         self.validate_input_stream_character(c);
-                        self.append_raw_to_doctype_token(c);
+
+                        self.append_to_doctype_token_internal_subset(c);
+
+                        match self.doctype_internal_subset_end {
+                            Some(end) if end.starts_with(c) && self.input.is_str(&end[1..]) => {
+                                self.consume_doctype_token_internal_subset_str(&end[1..]);
+                                self.doctype_internal_subset_end = None;
+                            }
+                            Some(_) => {}
+                            None if c == '"' => {
+                                self.doctype_internal_subset_end = Some("\"");
+                            }
+                            None if c == '\'' => {
+                                self.doctype_internal_subset_end = Some("'");
+                            }
+                            // Comments and processing instructions can contain quotes, so they
+                            // are skipped as a whole
+                            None if c == '<' && self.input.is_str("!--") => {
+                                self.consume_doctype_token_internal_subset_str("!--");
+                                self.doctype_internal_subset_end = Some("-->");
+                            }
+                            None if c == '<' && self.input.is_str("?") => {
+                                self.consume_doctype_token_internal_subset_str("?");
+                                self.doctype_internal_subset_end = Some("?>");
+                            }
+                            None => {}
+                        }
                     }
                 }
             }
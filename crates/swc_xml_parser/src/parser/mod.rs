@@ -2,6 +2,7 @@ use std::{cell::RefCell, mem, rc::Rc};
 
 use node::*;
 use open_elements_stack::*;
+use swc_atoms::Atom;
 use swc_common::{Span, DUMMY_SP};
 use swc_xml_ast::*;
 
@@ -39,6 +40,10 @@ where
     open_elements_stack: OpenElementsStack,
     errors: Vec<Error>,
     phase: Phase,
+    /// Namespaces declared by `xmlns` attributes of the current element and its
+    /// ancestors, as pairs of the prefix and the uri. `None` is the default
+    /// namespace.
+    namespaces: Vec<(Option<Atom>, Option<Atom>)>,
 }
 
 impl<I> Parser<I>
@@ -54,6 +59,7 @@ where
             open_elements_stack: OpenElementsStack::new(),
             errors: Default::default(),
             phase: Phase::default(),
+            namespaces: Default::default(),
         }
     }
 
@@ -117,18 +123,49 @@ where
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => Child::DocumentType(DocumentType {
                 span: start_span,
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             }),
             Data::Element {
                 tag_name,
                 attributes,
             } => {
+                let mut attributes = attributes.take();
+                let namespaces_len = self.namespaces.len();
+
+                for attribute in &attributes {
+                    let uri = attribute.value.clone().filter(|value| !value.is_empty());
+
+                    match (&attribute.prefix, &*attribute.name) {
+                        (None, "xmlns") => self.namespaces.push((None, uri)),
+                        (Some(prefix), name) if prefix == "xmlns" => {
+                            self.namespaces.push((Some(name.into()), uri))
+                        }
+                        _ => {}
+                    }
+                }
+
+                let (prefix, tag_name) = split_qualified_name(&tag_name);
+                let namespace = self.resolve_namespace(prefix.as_ref(), true);
+
+                for attribute in &mut attributes {
+                    // Only the well-known namespaces can be represented on attributes
+                    attribute.namespace = match &attribute.prefix {
+                        None if attribute.name == "xmlns" => Some(Namespace::XMLNS),
+                        None => None,
+                        prefix => self
+                            .resolve_namespace(prefix.as_ref(), false)
+                            .and_then(|uri| uri.parse().ok()),
+                    };
+                }
+
                 let nodes = node.children.take();
                 let mut new_children = Vec::with_capacity(nodes.len());
 
@@ -136,7 +173,7 @@ where
                     new_children.push(self.node_to_child(node));
                 }
 
-                let attributes = attributes.take();
+                self.namespaces.truncate(namespaces_len);
 
                 let span = if start_span.is_dummy() {
                     start_span
@@ -154,6 +191,8 @@ where
 
                 Child::Element(Element {
                     span,
+                    namespace,
+                    prefix,
                     tag_name,
                     attributes,
                     children: new_children,
@@ -195,6 +234,23 @@ where
         }
     }
 
+    fn resolve_namespace(&self, prefix: Option<&Atom>, is_element: bool) -> Option<Atom> {
+        match prefix.map(|prefix| &**prefix) {
+            Some("xml") => return Some(Namespace::XML.as_str().into()),
+            Some("xmlns") => return Some(Namespace::XMLNS.as_str().into()),
+            // Unprefixed attributes have no namespace
+            None if !is_element => return None,
+            _ => {}
+        }
+
+        // Unbound prefixes have no namespace
+        self.namespaces
+            .iter()
+            .rev()
+            .find(|(declared, _)| declared.as_ref() == prefix)
+            .and_then(|(_, uri)| uri.clone())
+    }
+
     fn run(&mut self) -> PResult<()> {
         while !self.stopped {
             let mut token_and_info = match self.input.cur()? {
@@ -431,16 +487,18 @@ where
     }
 
     fn create_document_type_for_token(&self, token_and_info: &mut TokenAndInfo) -> RcNode {
-        let (name, public_id, system_id, raw) = match &token_and_info.token {
+        let (name, public_id, system_id, internal_subset, raw) = match &token_and_info.token {
             Token::Doctype {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => (
                 name.clone(),
                 public_id.clone(),
                 system_id.clone(),
+                internal_subset.clone(),
                 raw.clone(),
             ),
             _ => {
@@ -453,6 +511,7 @@ where
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             },
             token_and_info.span,
@@ -478,14 +537,19 @@ where
             } => {
                 let attributes = attributes
                     .into_iter()
-                    .map(|attribute_token| Attribute {
-                        span: attribute_token.span,
-                        namespace: None,
-                        prefix: None,
-                        name: attribute_token.name,
-                        raw_name: attribute_token.raw_name,
-                        value: attribute_token.value,
-                        raw_value: attribute_token.raw_value,
+                    .map(|attribute_token| {
+                        let (prefix, name) = split_qualified_name(&attribute_token.name);
+
+                        Attribute {
+                            span: attribute_token.span,
+                            // Namespaces are resolved when the tree is built
+                            namespace: None,
+                            prefix,
+                            name,
+                            raw_name: attribute_token.raw_name,
+                            value: attribute_token.value,
+                            raw_value: attribute_token.raw_value,
+                        }
                     })
                     .collect();
 
@@ -648,3 +712,13 @@ where
 fn is_whitespace(c: char) -> bool {
     matches!(c, '\t' | '\r' | '\n' | '\x0C' | ' ')
 }
+
+/// Splits `prefix:name` to the prefix and the local name.
+fn split_qualified_name(name: &Atom) -> (Option<Atom>, Atom) {
+    match name.split_once(':') {
+        Some((prefix, local_name)) if !prefix.is_empty() && !local_name.is_empty() => {
+            (Some(prefix.into()), local_name.into())
+        }
+        _ => (None, name.clone()),
+    }
+}
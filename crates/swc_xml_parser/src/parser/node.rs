@@ -24,6 +24,7 @@ pub enum Data {
         name: Option<Atom>,
         public_id: Option<Atom>,
         system_id: Option<Atom>,
+        internal_subset: Option<Atom>,
         raw: Option<Atom>,
     },
     Element {
@@ -279,6 +279,12 @@ impl VisitMut for DomVisualizer<'_> {
             document_type.push('"');
         }
 
+        if let Some(internal_subset) = &n.internal_subset {
+            document_type.push_str(" [");
+            document_type.push_str(internal_subset);
+            document_type.push(']');
+        }
+
         document_type.push('>');
         document_type.push('\n');
 
@@ -292,6 +298,12 @@ impl VisitMut for DomVisualizer<'_> {
 
         element.push_str(&self.get_ident());
         element.push('<');
+
+        if let Some(prefix) = &n.prefix {
+            element.push_str(prefix);
+            element.push(' ');
+        }
+
         element.push_str(&n.tag_name);
         element.push('>');
         element.push('\n');
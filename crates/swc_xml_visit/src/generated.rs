@@ -914,6 +914,7 @@ impl<V: ?Sized + Visit> VisitWith<V> for DocumentType {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 {
@@ -928,6 +929,9 @@ impl<V: ?Sized + Visit> VisitWith<V> for DocumentType {
                 {
                     <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(system_id, visitor)
                 };
+                {
+                    <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(internal_subset, visitor)
+                };
                 {
                     <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(raw, visitor)
                 };
@@ -945,6 +949,8 @@ impl<V: ?Sized + Visit> VisitWith<V> for Element {
         match self {
             Element {
                 span,
+                namespace,
+                prefix,
                 tag_name,
                 attributes,
                 children,
@@ -952,6 +958,12 @@ impl<V: ?Sized + Visit> VisitWith<V> for Element {
                 {
                     <swc_common::Span as VisitWith<V>>::visit_with(span, visitor)
                 };
+                {
+                    <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(namespace, visitor)
+                };
+                {
+                    <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(prefix, visitor)
+                };
                 {
                     <swc_atoms::Atom as VisitWith<V>>::visit_with(tag_name, visitor)
                 };
@@ -1038,6 +1050,7 @@ impl<V: ?Sized + Visit> VisitWith<V> for Token {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 {
@@ -1049,6 +1062,9 @@ impl<V: ?Sized + Visit> VisitWith<V> for Token {
                 {
                     <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(system_id, visitor)
                 };
+                {
+                    <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(internal_subset, visitor)
+                };
                 {
                     <Option<swc_atoms::Atom> as VisitWith<V>>::visit_with(raw, visitor)
                 };
@@ -2950,6 +2966,7 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DocumentType {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 {
@@ -2996,6 +3013,17 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for DocumentType {
                         &mut *__ast_path,
                     )
                 };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::DocumentType(
+                        self,
+                        self::fields::DocumentTypeField::InternalSubset,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        internal_subset,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 {
                     let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::DocumentType(
                         self,
@@ -3031,6 +3059,8 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Element {
         match self {
             Element {
                 span,
+                namespace,
+                prefix,
                 tag_name,
                 attributes,
                 children,
@@ -3046,6 +3076,28 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Element {
                         &mut *__ast_path,
                     )
                 };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Element(
+                        self,
+                        self::fields::ElementField::Namespace,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        namespace,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Element(
+                        self,
+                        self::fields::ElementField::Prefix,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        prefix,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 {
                     let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Element(
                         self,
@@ -3241,6 +3293,7 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Token {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Token(
@@ -3280,6 +3333,17 @@ impl<V: ?Sized + VisitAstPath> VisitWithAstPath<V> for Token {
                         &mut *__ast_path,
                     )
                 };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentNodeRef::Token(
+                        self,
+                        self::fields::TokenField::InternalSubset,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitWithAstPath<V>>::visit_with_ast_path(
+                        internal_subset,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 {
                     let mut __ast_path = __ast_path
                         .with_guard(AstParentNodeRef::Token(self, self::fields::TokenField::Raw));
@@ -4680,6 +4744,7 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for DocumentType {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 {
@@ -4694,6 +4759,12 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for DocumentType {
                 {
                     <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(system_id, visitor)
                 };
+                {
+                    <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(
+                        internal_subset,
+                        visitor,
+                    )
+                };
                 {
                     <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(raw, visitor)
                 };
@@ -4711,6 +4782,8 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Element {
         match self {
             Element {
                 span,
+                namespace,
+                prefix,
                 tag_name,
                 attributes,
                 children,
@@ -4718,6 +4791,12 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Element {
                 {
                     <swc_common::Span as VisitMutWith<V>>::visit_mut_with(span, visitor)
                 };
+                {
+                    <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(namespace, visitor)
+                };
+                {
+                    <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(prefix, visitor)
+                };
                 {
                     <swc_atoms::Atom as VisitMutWith<V>>::visit_mut_with(tag_name, visitor)
                 };
@@ -4804,6 +4883,7 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Token {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 {
@@ -4815,6 +4895,12 @@ impl<V: ?Sized + VisitMut> VisitMutWith<V> for Token {
                 {
                     <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(system_id, visitor)
                 };
+                {
+                    <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(
+                        internal_subset,
+                        visitor,
+                    )
+                };
                 {
                     <Option<swc_atoms::Atom> as VisitMutWith<V>>::visit_mut_with(raw, visitor)
                 };
@@ -6358,6 +6444,7 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DocumentType {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 {
@@ -6400,6 +6487,16 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for DocumentType {
                         &mut *__ast_path,
                     )
                 };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::DocumentType(
+                        self::fields::DocumentTypeField::InternalSubset,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        internal_subset,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 {
                     let mut __ast_path = __ast_path.with_guard(AstParentKind::DocumentType(
                         self::fields::DocumentTypeField::Raw,
@@ -6426,6 +6523,8 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Element {
         match self {
             Element {
                 span,
+                namespace,
+                prefix,
                 tag_name,
                 attributes,
                 children,
@@ -6439,6 +6538,25 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Element {
                         &mut *__ast_path,
                     )
                 };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::Element(
+                        self::fields::ElementField::Namespace,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        namespace,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::Element(self::fields::ElementField::Prefix));
+                    <Option<swc_atoms::Atom> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        prefix,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 {
                     let mut __ast_path = __ast_path
                         .with_guard(AstParentKind::Element(self::fields::ElementField::TagName));
@@ -6595,6 +6713,7 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Token {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 let mut __ast_path =
@@ -6626,6 +6745,16 @@ impl<V: ?Sized + VisitMutAstPath> VisitMutWithAstPath<V> for Token {
                         &mut *__ast_path,
                     )
                 };
+                {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::Token(
+                        self::fields::TokenField::InternalSubset,
+                    ));
+                    <Option<swc_atoms::Atom> as VisitMutWithAstPath<V>>::visit_mut_with_ast_path(
+                        internal_subset,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 {
                     let mut __ast_path =
                         __ast_path.with_guard(AstParentKind::Token(self::fields::TokenField::Raw));
@@ -7925,6 +8054,7 @@ impl<V: ?Sized + Fold> FoldWith<V> for DocumentType {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
@@ -7933,12 +8063,16 @@ impl<V: ?Sized + Fold> FoldWith<V> for DocumentType {
                     { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(public_id, visitor) };
                 let system_id =
                     { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(system_id, visitor) };
+                let internal_subset = {
+                    <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(internal_subset, visitor)
+                };
                 let raw = { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(raw, visitor) };
                 DocumentType {
                     span,
                     name,
                     public_id,
                     system_id,
+                    internal_subset,
                     raw,
                 }
             }
@@ -7955,17 +8089,25 @@ impl<V: ?Sized + Fold> FoldWith<V> for Element {
         match self {
             Element {
                 span,
+                namespace,
+                prefix,
                 tag_name,
                 attributes,
                 children,
             } => {
                 let span = { <swc_common::Span as FoldWith<V>>::fold_with(span, visitor) };
+                let namespace =
+                    { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(namespace, visitor) };
+                let prefix =
+                    { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(prefix, visitor) };
                 let tag_name = { <swc_atoms::Atom as FoldWith<V>>::fold_with(tag_name, visitor) };
                 let attributes =
                     { <Vec<Attribute> as FoldWith<V>>::fold_with(attributes, visitor) };
                 let children = { <Vec<Child> as FoldWith<V>>::fold_with(children, visitor) };
                 Element {
                     span,
+                    namespace,
+                    prefix,
                     tag_name,
                     attributes,
                     children,
@@ -8037,6 +8179,7 @@ impl<V: ?Sized + Fold> FoldWith<V> for Token {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 let name = { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(name, visitor) };
@@ -8044,11 +8187,15 @@ impl<V: ?Sized + Fold> FoldWith<V> for Token {
                     { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(public_id, visitor) };
                 let system_id =
                     { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(system_id, visitor) };
+                let internal_subset = {
+                    <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(internal_subset, visitor)
+                };
                 let raw = { <Option<swc_atoms::Atom> as FoldWith<V>>::fold_with(raw, visitor) };
                 Token::Doctype {
                     name,
                     public_id,
                     system_id,
+                    internal_subset,
                     raw,
                 }
             }
@@ -9712,6 +9859,7 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DocumentType {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 let span = {
@@ -9754,6 +9902,16 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DocumentType {
                         &mut *__ast_path,
                     )
                 };
+                let internal_subset = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::DocumentType(
+                        self::fields::DocumentTypeField::InternalSubset,
+                    ));
+                    <Option<swc_atoms::Atom> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        internal_subset,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 let raw = {
                     let mut __ast_path = __ast_path.with_guard(AstParentKind::DocumentType(
                         self::fields::DocumentTypeField::Raw,
@@ -9769,6 +9927,7 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for DocumentType {
                     name,
                     public_id,
                     system_id,
+                    internal_subset,
                     raw,
                 }
             }
@@ -9787,6 +9946,8 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Element {
         match self {
             Element {
                 span,
+                namespace,
+                prefix,
                 tag_name,
                 attributes,
                 children,
@@ -9800,6 +9961,25 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Element {
                         &mut *__ast_path,
                     )
                 };
+                let namespace = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::Element(
+                        self::fields::ElementField::Namespace,
+                    ));
+                    <Option<swc_atoms::Atom> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        namespace,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
+                let prefix = {
+                    let mut __ast_path = __ast_path
+                        .with_guard(AstParentKind::Element(self::fields::ElementField::Prefix));
+                    <Option<swc_atoms::Atom> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        prefix,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 let tag_name = {
                     let mut __ast_path = __ast_path
                         .with_guard(AstParentKind::Element(self::fields::ElementField::TagName));
@@ -9831,6 +10011,8 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Element {
                 };
                 Element {
                     span,
+                    namespace,
+                    prefix,
                     tag_name,
                     attributes,
                     children,
@@ -9964,6 +10146,7 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Token {
                 name,
                 public_id,
                 system_id,
+                internal_subset,
                 raw,
             } => {
                 let mut __ast_path =
@@ -9995,6 +10178,16 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Token {
                         &mut *__ast_path,
                     )
                 };
+                let internal_subset = {
+                    let mut __ast_path = __ast_path.with_guard(AstParentKind::Token(
+                        self::fields::TokenField::InternalSubset,
+                    ));
+                    <Option<swc_atoms::Atom> as FoldWithAstPath<V>>::fold_with_ast_path(
+                        internal_subset,
+                        visitor,
+                        &mut *__ast_path,
+                    )
+                };
                 let raw = {
                     let mut __ast_path =
                         __ast_path.with_guard(AstParentKind::Token(self::fields::TokenField::Raw));
@@ -10008,6 +10201,7 @@ impl<V: ?Sized + FoldAstPath> FoldWithAstPath<V> for Token {
                     name,
                     public_id,
                     system_id,
+                    internal_subset,
                     raw,
                 }
             }
@@ -10545,6 +10739,8 @@ pub mod fields {
         PublicId,
         #[doc = "Represents [`DocumentType::system_id`]"]
         SystemId,
+        #[doc = "Represents [`DocumentType::internal_subset`]"]
+        InternalSubset,
         #[doc = "Represents [`DocumentType::raw`]"]
         Raw,
     }
@@ -10568,6 +10764,10 @@ pub mod fields {
     pub enum ElementField {
         #[doc = "Represents [`Element::span`]"]
         Span,
+        #[doc = "Represents [`Element::namespace`]"]
+        Namespace,
+        #[doc = "Represents [`Element::prefix`]"]
+        Prefix,
         #[doc = "Represents [`Element::tag_name`]"]
         TagName,
         #[doc = "Represents [`Element::attributes`]"]
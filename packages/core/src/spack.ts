@@ -89,6 +89,13 @@ export interface SpackConfig {
      * Modules to exclude from bundle.
      */
     externalModules?: string[];
+
+    /**
+     * Emit byte-identical output across runs and thread counts.
+     *
+     * @default false
+     */
+    deterministic?: boolean;
 }
 
 export interface OutputConfig {
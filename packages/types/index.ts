@@ -233,6 +233,12 @@ export interface TerserCompressOptions {
 
     inline?: 0 | 1 | 2 | 3;
 
+    /**
+     * Number of bytes the output may grow by when inlining a function, which
+     * is used more than once, into all of its call sites.
+     */
+    inline_fns_budget?: number;
+
     join_vars?: boolean;
 
     keep_classnames?: boolean;
@@ -262,6 +268,12 @@ export interface TerserCompressOptions {
 
     sequences?: any;
 
+    /**
+     * Maximum number of following expressions an expression is compared with
+     * when merging sequential expressions.
+     */
+    sequences_lookahead?: number;
+
     side_effects?: boolean;
 
     switches?: boolean;
@@ -304,6 +316,12 @@ export interface TerserCompressOptions {
 export interface TerserMangleOptions {
     props?: TerserManglePropertiesOptions;
 
+    /**
+     * Mangle names of class fields and methods, without mangling the other
+     * properties.
+     */
+    classProps?: TerserMangleClassPropsOptions;
+
     /**
      * Pass `true` to mangle names declared in the top level scope.
      */
@@ -353,6 +371,13 @@ export interface TerserMangleOptions {
 
 export interface TerserManglePropertiesOptions { }
 
+export interface TerserMangleClassPropsOptions {
+    /**
+     * Class members and `#private` names matching this regex are not mangled.
+     */
+    keep?: string;
+}
+
 /**
  * Programmatic options.
  */
@@ -736,6 +761,17 @@ export interface TsParserConfig {
      * @deprecated Always true because it's in ecmascript spec.
      */
     dynamicImport?: boolean;
+    /**
+     * Defaults to `false`
+     */
+    preserveParens?: boolean;
+    /**
+     * Version of TypeScript to match, like `"5.0"`.
+     *
+     * Syntax added in later versions of TypeScript is rejected.
+     * Defaults to accepting all syntax.
+     */
+    tsVersion?: string;
 }
 
 export interface EsParserConfig {
@@ -824,6 +860,10 @@ export interface EsParserConfig {
      * Defaults to `false`
      */
     explicitResourceManagement?: boolean;
+    /**
+     * Defaults to `false`
+     */
+    preserveParens?: boolean;
 }
 
 /**
@@ -896,10 +936,27 @@ export interface ReactConfig {
      * Toggles plugins that aid in development, such as @swc/plugin-transform-react-jsx-self
      * and @swc/plugin-transform-react-jsx-source.
      *
+     * Use an object to choose which of the debug props are added.
+     *
      * Defaults to `false`,
      *
      */
-    development?: boolean;
+    development?:
+    | boolean
+    | {
+        /**
+         * Add `__source` to JSX elements.
+         *
+         * Defaults to `true`
+         */
+        jsxSource?: boolean;
+        /**
+         * Add `__self` to JSX elements.
+         *
+         * Defaults to `true`
+         */
+        jsxSelf?: boolean;
+    };
     /**
      * Use `Object.assign()` instead of `_extends`. Defaults to false.
      * @deprecated
@@ -1155,6 +1212,18 @@ export interface BaseModuleConfig {
      * ```
      */
     importInterop?: "swc" | "babel" | "node" | "none";
+    /**
+     * Matches the CommonJS interop of another toolchain.
+     *
+     * - `babel` - Same as `importInterop: "babel"`.
+     * - `node16` - Same as `importInterop: "node"`.
+     * - `tsc-legacy` - Same as `importInterop: "none"`, but defines `__esModule` and copies named re-exports
+     *   with `exports.foo = _mod.foo` like `tsc` before 3.9.
+     * - `bun` - Same as `importInterop: "babel"`.
+     *
+     * `importInterop` takes precedence over this option.
+     */
+    esModuleInterop?: "babel" | "node16" | "tsc-legacy" | "bun";
     /**
      * Output extension for generated files.
      * 
@@ -1175,6 +1244,22 @@ export interface BaseModuleConfig {
     ignoreDynamic?: boolean;
     allowTopLevelThis?: boolean;
     preserveImportMeta?: boolean;
+    /**
+     * Configures how `import.meta` is lowered for CommonJS, AMD and UMD.
+     */
+    importMeta?: {
+        /**
+         * Expressions replacing properties of `import.meta`, like
+         * `{ "env": "process.env" }`.
+         */
+        replacements?: { [property: string]: string };
+        /**
+         * What to do with uses of `import.meta` which can't be lowered.
+         *
+         * Defaults to `preserve`.
+         */
+        unsupported?: "preserve" | "error";
+    };
 }
 
 export interface Es6Config extends BaseModuleConfig {
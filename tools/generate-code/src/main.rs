@@ -128,6 +128,8 @@ fn test_ecmascript() {
             "EncodeBigInt".into(),
             "EsVersion".into(),
             "FnPass".into(),
+            "BinaryProgram".into(),
+            "BinaryError".into(),
         ],
     )
     .unwrap();
@@ -143,6 +145,8 @@ fn test_ecmascript_std() {
             "EncodeBigInt".into(),
             "EsVersion".into(),
             "FnPass".into(),
+            "BinaryProgram".into(),
+            "BinaryError".into(),
             "Accessibility".into(),
             "^Ts.*".into(),
         ],